@@ -0,0 +1,32 @@
+use std::env;
+
+// The last GSL minor version (of the 2.x series) known by this crate.
+const LAST_MINOR: u32 = 7;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=DEP_GSL_VERSION");
+    let mut check_cfg = vec!["gsl_version_detected".to_owned()];
+    check_cfg.extend((1..=LAST_MINOR).map(|minor| format!("gsl_2_{}", minor)));
+    println!("cargo:rustc-check-cfg=cfg({})", check_cfg.join(", "));
+
+    // Set by GSL-sys when it found the version of the GSL library it links to.
+    let version = match env::var("DEP_GSL_VERSION") {
+        Ok(version) => version,
+        Err(_) => return,
+    };
+    let mut parts = version.split(|c: char| !c.is_ascii_digit());
+    let major = parts.next().and_then(|s| s.parse::<u32>().ok());
+    let minor = parts
+        .next()
+        .and_then(|s| s.parse::<u32>().ok())
+        .unwrap_or(0);
+    if major != Some(2) {
+        return;
+    }
+
+    println!("cargo:rustc-cfg=gsl_version_detected");
+    println!("cargo:rustc-env=GSL_VERSION_DETECTED={}", version);
+    for minor in 1..=minor.min(LAST_MINOR) {
+        println!("cargo:rustc-cfg=gsl_2_{}", minor);
+    }
+}
@@ -4,7 +4,7 @@
 
 extern crate rgsl;
 
-use rgsl::{Combination, Value};
+use rgsl::Combination;
 
 fn main() {
     println!("All subsets of {{0,1,2,3}} by size:");
@@ -14,7 +14,7 @@ fn main() {
             Combination::new_with_init(4, i).expect("Combination::new_init_first failed...");
         loop {
             println!("{:?}", c);
-            if c.next() != Value::Success {
+            if c.next().is_err() {
                 break;
             }
         }
@@ -0,0 +1,61 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+// Two-dimensional version of the wavelet compression example of the GSL manual: the
+// non-standard transform of an image is computed, only the largest coefficients are kept and
+// the image is reconstructed from them.
+
+extern crate rgsl;
+
+use rgsl::wavelet_transforms::two_dimension;
+use rgsl::{Wavelet, WaveletType, WaveletWorkspace};
+
+const N: usize = 64;
+const KEPT: usize = N * N / 20;
+
+fn main() {
+    let w = Wavelet::new(WaveletType::daubechies(), 4).expect("Wavelet::new failed");
+    let mut work = WaveletWorkspace::new(N).expect("WaveletWorkspace::new failed");
+
+    // A smooth "image" with a bright square in it.
+    let mut image = vec![0.; N * N];
+    for i in 0..N {
+        for j in 0..N {
+            let (x, y) = (i as f64 / N as f64, j as f64 / N as f64);
+            let square = if (16..32).contains(&i) && (24..48).contains(&j) {
+                1.
+            } else {
+                0.
+            };
+            image[i * N + j] = (x * 3.).sin() * (y * 5.).cos() + square;
+        }
+    }
+
+    let mut data = image.clone();
+    two_dimension::nstransform_forward(&w, &mut data, N, N, N, &mut work).unwrap();
+
+    let mut magnitudes = data.iter().map(|x| x.abs()).collect::<Vec<_>>();
+    magnitudes.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    let threshold = magnitudes[KEPT - 1];
+    for x in data.iter_mut() {
+        if x.abs() < threshold {
+            *x = 0.;
+        }
+    }
+
+    two_dimension::nstransform_inverse(&w, &mut data, N, N, N, &mut work).unwrap();
+
+    let error = image
+        .iter()
+        .zip(&data)
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum::<f64>()
+        .sqrt();
+    println!(
+        "kept {} of {} coefficients, rms error: {}",
+        KEPT,
+        N * N,
+        error / N as f64
+    );
+}
@@ -20,10 +20,12 @@ fn main() {
         EigenNonSymmetricVWorkspace::new(4).expect("EigenNonSymmetricVWorkspace::new failed...");
 
     m.matrix_mut(|m| {
-        w.nonsymmv(m.expect("Failed to get matrix"), &mut eval, &mut evec);
+        w.nonsymmv(m.expect("Failed to get matrix"), &mut eval, &mut evec)
+            .expect("nonsymmv failed...");
     });
 
-    eigen::nonsymmv_sort(&mut eval, &mut evec, EigenSort::AbsDesc);
+    eigen::nonsymmv_sort(&mut eval, &mut evec, EigenSort::AbsDesc)
+        .expect("nonsymmv_sort failed...");
 
     for i in 0..4 {
         let eval_i = eval.get(i);
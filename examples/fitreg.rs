@@ -50,7 +50,7 @@ fn main() {
     let mut g = VectorF64::new(NPOINTS).expect("VectorF64::new failed");
 
     // compute SVD of X
-    w.linear_svd(&mut x);
+    w.linear_svd(&x).expect("linear_svd failed");
 
     // Get reciprocal condition number of X
     let rcond = w.linear_rcond();
@@ -58,7 +58,9 @@ fn main() {
     eprintln!("");
 
     // unregularized (standard) least squares fit, lambda = 0
-    let (_, rnorm, snorm) = w.linear_solve(0., &x, &y, &mut c);
+    let (rnorm, snorm) = w
+        .linear_solve(0., &x, &y, &mut c)
+        .expect("linear_solve failed");
     let chisq = rnorm.powi(2);
 
     eprintln!("\n=== Unregularized fit ===");
@@ -68,14 +70,17 @@ fn main() {
     eprintln!("chisq/dof = {}", chisq / (N - P) as f64);
 
     // calculate L-curve and find its corner
-    w.linear_lcurve(&y, &mut reg_param, &mut rho, &mut eta);
-    let (_, reg_idx) = multifit::linear_lcorner(&rho, &eta);
+    w.linear_lcurve(&y, &mut reg_param, &mut rho, &mut eta)
+        .expect("linear_lcurve failed");
+    let reg_idx = multifit::linear_lcorner(&rho, &eta).expect("linear_lcorner failed");
 
     // store optimal regularization parameter
     let lambda_l = reg_param.get(reg_idx);
 
     // regularize with lambda_l
-    let (_, rnorm, snorm) = w.linear_solve(lambda_l, &x, &y, &mut c_lcurve);
+    let (rnorm, snorm) = w
+        .linear_solve(lambda_l, &x, &y, &mut c_lcurve)
+        .expect("linear_solve failed");
     let chisq = rnorm.powi(2) + (lambda_l * snorm).powi(2);
 
     eprintln!("\n=== Regularized fit (L-curve) ===");
@@ -90,10 +95,14 @@ fn main() {
     eprintln!("chisq/dof = {}", chisq / (N - P) as f64);
 
     // calculate GCV curve and find its minimum
-    let (_, lambda_gcv, g_gcv) = w.linear_gcv(&y, &mut reg_param, &mut g);
+    let (lambda_gcv, g_gcv) = w
+        .linear_gcv(&y, &mut reg_param, &mut g)
+        .expect("linear_gcv failed");
 
     // regularize with lambda_gcv
-    let (_, rnorm, snorm) = w.linear_solve(lambda_gcv, &x, &y, &mut c_gcv);
+    let (rnorm, snorm) = w
+        .linear_solve(lambda_gcv, &x, &y, &mut c_gcv)
+        .expect("linear_solve failed");
     let chisq = rnorm.powi(2) + (lambda_gcv * snorm).powi(2);
 
     eprintln!("\n=== Regularized fit (GCV) ===\n");
@@ -13,12 +13,15 @@ fn main() {
     let y = &[12., 11., 14., 13.];
     let w = &[0.1, 0.2, 0.3, 0.4];
 
-    let (_, c0, c1, cov00, cov01, cov11, chisq) = fit::wlinear(x, 1, w, 1, y, 1, N);
+    let f = fit::wlinear(x, 1, w, 1, y, 1, N).expect("wlinear failed");
 
-    println!("# best fit: Y = {} + {} X", c0, c1);
+    println!("# best fit: Y = {} + {} X", f.c0, f.c1);
     println!("# covariance matrix:");
-    println!("# [ {}, {}\n#   {}, {}]", cov00, cov01, cov01, cov11);
-    println!("# chisq = {}", chisq);
+    println!(
+        "# [ {}, {}\n#   {}, {}]",
+        f.cov00, f.cov01, f.cov01, f.cov11
+    );
+    println!("# chisq = {}", f.sumsq);
 
     for i in 0..N {
         println!("data: {} {} {}", x[i], y[i], 1. / w[i].sqrt());
@@ -28,7 +31,7 @@ fn main() {
 
     for i in -30..130 {
         let xf = x[0] + (i as f64 / 100.) * (x[N - 1] - x[0]);
-        let (_, yf, yf_err) = fit::linear_est(xf, c0, c1, cov00, cov01, cov11);
+        let (yf, yf_err) = f.est(xf).expect("linear_est failed");
 
         println!("fit: {} {}", xf, yf);
         println!("hi : {} {}", xf, yf + yf_err);
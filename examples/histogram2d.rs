@@ -9,24 +9,25 @@ use rgsl::{Histogram2D, Histogram2DPdf, Rng, RngType};
 fn main() {
     let mut h = Histogram2D::new(10, 10).expect("Histogram2D::new failed");
 
-    h.set_ranges_uniform(0., 1., 0., 1.);
+    h.set_ranges_uniform(0., 1., 0., 1.)
+        .expect("set_ranges_uniform failed");
 
-    h.accumulate(0.3, 0.3, 1.);
-    h.accumulate(0.8, 0.1, 5.);
-    h.accumulate(0.7, 0.9, 0.5);
+    h.accumulate(0.3, 0.3, 1.).unwrap();
+    h.accumulate(0.8, 0.1, 5.).unwrap();
+    h.accumulate(0.7, 0.9, 0.5).unwrap();
 
     RngType::env_setup();
 
     let mut r = Rng::new(RngType::default()).expect("Rng::new failed");
 
     let mut p = Histogram2DPdf::new(h.nx(), h.ny()).expect("Histogram2DPdf::new failed");
-    p.init(&mut h);
+    p.init(&mut h).expect("init failed");
 
     for _ in 0..1000 {
         let u = r.uniform();
         let v = r.uniform();
 
-        let (_, x, y) = p.sample(u, v);
+        let (x, y) = p.sample(u, v).expect("sample failed");
 
         println!("{} {}", x, y);
     }
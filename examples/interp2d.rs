@@ -0,0 +1,45 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+extern crate rgsl;
+
+use rgsl::interpolation::twodim::{Interp2dType, Spline2d};
+use rgsl::InterpAccel;
+
+fn main() {
+    // This is the example from https://www.gnu.org/software/gsl/doc/html/interp.html#d-interpolation-example-programs
+    const N: usize = 100; // number of points to interpolate
+    let xa = [0., 1.]; // define unit square
+    let ya = [0., 1.];
+    let nx = xa.len();
+    let ny = ya.len();
+    let mut za = vec![0.; nx * ny];
+
+    let mut spline = Spline2d::new(Interp2dType::bilinear(), nx, ny).expect("Spline2d::new failed");
+    let mut xacc = InterpAccel::new();
+    let mut yacc = InterpAccel::new();
+
+    // set z grid values
+    let mut set = |i: usize, j: usize, z: f64| za[j * nx + i] = z;
+    set(0, 0, 0.);
+    set(0, 1, 1.);
+    set(1, 1, 0.5);
+    set(1, 0, 1.);
+
+    // initialize interpolation
+    spline.init(&xa, &ya, &za).expect("Spline2d::init failed");
+
+    // interpolate N values in x and y and print out grid for plotting
+    for i in 0..N {
+        let xi = i as f64 / (N as f64 - 1.);
+
+        for j in 0..N {
+            let yj = j as f64 / (N as f64 - 1.);
+            let zij = spline.eval(xi, yj, &mut xacc, &mut yacc);
+
+            println!("{:.6} {:.6} {:.6}", xi, yj, zij);
+        }
+        println!();
+    }
+}
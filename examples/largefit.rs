@@ -95,7 +95,8 @@ fn solve_system(print_data: bool, t: MultilargeLinearType, c: &mut VectorF64) {
                 w.accumulate(
                     matrix.expect("Failed to get matrix"),
                     vector.expect("Failed to get vector"),
-                );
+                )
+                .expect("accumulate failed");
             });
         });
 
@@ -108,13 +109,14 @@ fn solve_system(print_data: bool, t: MultilargeLinearType, c: &mut VectorF64) {
     }
 
     // compute L-curve
-    w.lcurve(&mut reg_param, &mut rho, &mut eta);
+    w.lcurve(&mut reg_param, &mut rho, &mut eta)
+        .expect("lcurve failed");
 
     // solve large LS system and store solution in c
-    let (_, rnorm, snorm) = w.solve(LAMBDA, c);
+    let (rnorm, snorm) = w.solve(LAMBDA, c).expect("solve failed");
 
     // compute reciprocal condition number
-    let (_, rcond) = w.rcond();
+    let rcond = w.rcond().expect("rcond failed");
 
     eprintln!("=== Method {} ===\n", w.name().expect("Failed to get name"));
     eprintln!("condition number = {}", 1. / rcond);
@@ -156,8 +158,8 @@ fn main() {
         let f_exact = func(t);
         build_row(t, &mut v);
 
-        let (_, f_tsqr) = blas::level1::ddot(&v, &c_tsqr);
-        let (_, f_normal) = blas::level1::ddot(&v, &c_normal);
+        let f_tsqr = blas::level1::ddot(&v, &c_tsqr).expect("ddot failed");
+        let f_normal = blas::level1::ddot(&v, &c_normal).expect("ddot failed");
 
         println!("{} {:.6} {:.6} {:.6}", t, f_exact, f_tsqr, f_normal);
 
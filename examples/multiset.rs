@@ -0,0 +1,18 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+extern crate rgsl;
+
+use rgsl::MultiSet;
+
+fn main() {
+    println!("All multisets of {{0,1,2,3}} by size:");
+
+    for i in 0..=4 {
+        let mut c = MultiSet::new_with_init(4, i).expect("MultiSet::new_with_init failed...");
+        for m in c.iter() {
+            println!("{:?}", m);
+        }
+    }
+}
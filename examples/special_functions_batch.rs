@@ -0,0 +1,75 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+// Compares the time taken by the scalar special functions called in a loop with their `_slice`
+// batch versions. Build it with `--release` to get meaningful numbers.
+
+extern crate rgsl;
+
+use rgsl::{bessel, error, gamma_beta::gamma};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const N: usize = 1_000_000;
+const RUNS: usize = 10;
+
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn compare(name: &str, xs: &[f64], scalar: fn(f64) -> f64, batch: fn(&[f64], &mut [f64])) {
+    let mut out = vec![0.; xs.len()];
+    let looped = time(|| {
+        for (o, &x) in out.iter_mut().zip(black_box(xs)) {
+            *o = scalar(x);
+        }
+        black_box(&out);
+    });
+    let sliced = time(|| {
+        batch(black_box(xs), &mut out);
+        black_box(&out);
+    });
+    println!(
+        "{:<28} loop: {:>10.3?}  slice: {:>10.3?}",
+        name, looped, sliced
+    );
+}
+
+fn main() {
+    let reals = (0..N)
+        .map(|i| 0.5 + 20. * i as f64 / N as f64)
+        .collect::<Vec<_>>();
+    // Half of the arguments are trivial cases handled without calling GSL.
+    let integers = (0..N)
+        .map(|i| {
+            if i % 2 == 0 {
+                (1 + i % 21) as f64
+            } else {
+                reals[i]
+            }
+        })
+        .collect::<Vec<_>>();
+    let zeros = (0..N)
+        .map(|i| if i % 2 == 0 { 0. } else { reals[i] })
+        .collect::<Vec<_>>();
+
+    compare("gamma", &reals, gamma::gamma, gamma::gamma_slice);
+    compare(
+        "gamma (half integers)",
+        &integers,
+        gamma::gamma,
+        gamma::gamma_slice,
+    );
+    compare("erf", &reals, error::erf, error::erf_slice);
+    compare("erf (half zeros)", &zeros, error::erf, error::erf_slice);
+    compare("J0", &reals, bessel::J0, bessel::J0_slice);
+    compare("J0 (half zeros)", &zeros, bessel::J0, bessel::J0_slice);
+}
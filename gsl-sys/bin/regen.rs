@@ -34,6 +34,10 @@ const VERSIONS: &[(&str, &str)] = &[
     ("pub fn gsl_multilarge_*(", "v2_1"),
     ("pub static mut gsl_multilarge_*:", "v2_1"),
     // 2.2
+    // --> little exception here...
+    ("pub fn gsl_linalg_tri_rcond(", "v2_6"),
+    // --> little exception here...
+    ("pub fn gsl_linalg_tri_invert(", "v2_6"),
     ("pub fn gsl_linalg_tri_*(", "v2_2"),
     ("pub fn gsl_linalg_COD_*(", "v2_2"),
     ("pub fn gsl_rstat_quantile_reset(", "v2_2"),
@@ -343,6 +347,8 @@ fn run_bindgen(folder: &Path, commit_hash: String) {
         .header(HEADER_FILE)
         .layout_tests(false)
         .size_t_is_usize(true)
+        .use_core()
+        .ctypes_prefix("::core::ffi")
         .clang_args(&[format!("-I{}", folder.display())])
         .whitelist_function("(gsl|cblas)_.*")
         .whitelist_type("(gsl|cblas)_.*")
@@ -1,16 +1,117 @@
+#[cfg(feature = "build-from-source")]
+extern crate autotools;
 extern crate pkg_config;
+#[cfg(target_env = "msvc")]
+extern crate vcpkg;
+
+#[cfg(feature = "build-from-source")]
+mod from_source {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+
+    // Builds GSL from the sources in `GSL_SRC_DIR` (or the `gsl` folder next to this file) and
+    // links it statically. Returns the version of GSL which was built.
+    pub fn build() -> Option<String> {
+        println!("cargo:rerun-if-env-changed=GSL_SRC_DIR");
+        let src = match env::var_os("GSL_SRC_DIR") {
+            Some(dir) => PathBuf::from(dir),
+            None => PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("gsl"),
+        };
+        if !src.join("configure.ac").exists() {
+            panic!(
+                "The `build-from-source` feature needs the GSL sources, which aren't included in \
+                 the crate: extract a GSL release (or clone git://git.savannah.gnu.org/gsl.git) \
+                 into `{}` or set the `GSL_SRC_DIR` environment variable to their location",
+                src.display()
+            );
+        }
+
+        let mut config = autotools::Config::new(&src);
+        if !src.join("configure").exists() {
+            // A git checkout doesn't come with the `configure` script.
+            config.reconf("-ivf");
+        }
+        let dst = config
+            .enable_static()
+            .disable_shared()
+            .with("pic", None)
+            .build();
+
+        println!(
+            "cargo:rustc-link-search=native={}",
+            dst.join("lib").display()
+        );
+        println!("cargo:rustc-link-lib=static=gsl");
+        println!("cargo:rustc-link-lib=static=gslcblas");
+        if env::var("CARGO_CFG_TARGET_FAMILY").as_deref() == Ok("unix") {
+            println!("cargo:rustc-link-lib=m");
+        }
+
+        // The version is set with `AC_INIT([gsl],[2.7.1])`.
+        let configure = fs::read_to_string(src.join("configure.ac")).ok()?;
+        let init = &configure[configure.find("AC_INIT([gsl],[")? + 15..];
+        Some(init[..init.find(']')?].to_owned())
+    }
+}
 
 fn main() {
-    if std::process::Command::new("pkg-config").output().is_err() {
+    #[cfg(feature = "build-from-source")]
+    let version = from_source::build();
+    #[cfg(not(feature = "build-from-source"))]
+    let version = link_system();
+
+    // Made available to the crates depending on this one as `DEP_GSL_VERSION`.
+    if let Some(version) = version {
+        println!("cargo:version={}", version);
+    }
+}
+
+// Returns the version of the GSL library found by pkg-config, if any.
+#[cfg_attr(feature = "build-from-source", allow(dead_code))]
+fn link_system() -> Option<String> {
+    // Location of prebuilt binaries (for example on Windows, where GSL is rarely installed as a
+    // system library).
+    println!("cargo:rerun-if-env-changed=GSL_LIB_DIR");
+    if let Some(dir) = std::env::var_os("GSL_LIB_DIR") {
+        println!(
+            "cargo:rustc-link-search=native={}",
+            std::path::Path::new(&dir).display()
+        );
         println!("cargo:rustc-link-lib=gsl");
         println!("cargo:rustc-link-lib=gslcblas");
-        return;
+        return None;
+    }
+
+    // On MSVC, GSL is usually installed with vcpkg (`vcpkg install gsl:x64-windows`). Its `gsl`
+    // package contains both the `gsl` and `gslcblas` import libraries. `vcpkg` is only a
+    // build dependency on MSVC hosts.
+    #[cfg(target_env = "msvc")]
+    if std::env::var("CARGO_CFG_TARGET_ENV").as_deref() == Ok("msvc") {
+        match vcpkg::Config::new()
+            .emit_includes(false)
+            .find_package("gsl")
+        {
+            Ok(_) => return None,
+            Err(e) => println!("cargo:warning=GSL not found with vcpkg: {}", e),
+        }
     }
 
-    if pkg_config::probe_library("gsl").is_err() {
+    if std::process::Command::new("pkg-config").output().is_err() {
         println!("cargo:rustc-link-lib=gsl");
+        println!("cargo:rustc-link-lib=gslcblas");
+        return None;
     }
+
+    let version = match pkg_config::probe_library("gsl") {
+        Ok(lib) => Some(lib.version),
+        Err(_) => {
+            println!("cargo:rustc-link-lib=gsl");
+            None
+        }
+    };
     if pkg_config::probe_library("gslcblas").is_err() {
         println!("cargo:rustc-link-lib=gslcblas");
     }
+    version
 }
@@ -131,78 +131,78 @@ pub const GSL_MAJOR_VERSION: u32 = 2;
 pub const GSL_MINOR_VERSION: u32 = 6;
 pub const GSL_SF_MATHIEU_COEFF: u32 = 100;
 pub const GSL_MESSAGE_MASK: u32 = 4294967295;
-pub type __off_t = ::std::os::raw::c_long;
-pub type __off64_t = ::std::os::raw::c_long;
+pub type __off_t = ::core::ffi::c_long;
+pub type __off64_t = ::core::ffi::c_long;
 pub type FILE = libc::FILE;
-pub const GSL_SUCCESS: ::std::os::raw::c_int = 0;
-pub const GSL_FAILURE: ::std::os::raw::c_int = -1;
-pub const GSL_CONTINUE: ::std::os::raw::c_int = -2;
-pub const GSL_EDOM: ::std::os::raw::c_int = 1;
-pub const GSL_ERANGE: ::std::os::raw::c_int = 2;
-pub const GSL_EFAULT: ::std::os::raw::c_int = 3;
-pub const GSL_EINVAL: ::std::os::raw::c_int = 4;
-pub const GSL_EFAILED: ::std::os::raw::c_int = 5;
-pub const GSL_EFACTOR: ::std::os::raw::c_int = 6;
-pub const GSL_ESANITY: ::std::os::raw::c_int = 7;
-pub const GSL_ENOMEM: ::std::os::raw::c_int = 8;
-pub const GSL_EBADFUNC: ::std::os::raw::c_int = 9;
-pub const GSL_ERUNAWAY: ::std::os::raw::c_int = 10;
-pub const GSL_EMAXITER: ::std::os::raw::c_int = 11;
-pub const GSL_EZERODIV: ::std::os::raw::c_int = 12;
-pub const GSL_EBADTOL: ::std::os::raw::c_int = 13;
-pub const GSL_ETOL: ::std::os::raw::c_int = 14;
-pub const GSL_EUNDRFLW: ::std::os::raw::c_int = 15;
-pub const GSL_EOVRFLW: ::std::os::raw::c_int = 16;
-pub const GSL_ELOSS: ::std::os::raw::c_int = 17;
-pub const GSL_EROUND: ::std::os::raw::c_int = 18;
-pub const GSL_EBADLEN: ::std::os::raw::c_int = 19;
-pub const GSL_ENOTSQR: ::std::os::raw::c_int = 20;
-pub const GSL_ESING: ::std::os::raw::c_int = 21;
-pub const GSL_EDIVERGE: ::std::os::raw::c_int = 22;
-pub const GSL_EUNSUP: ::std::os::raw::c_int = 23;
-pub const GSL_EUNIMPL: ::std::os::raw::c_int = 24;
-pub const GSL_ECACHE: ::std::os::raw::c_int = 25;
-pub const GSL_ETABLE: ::std::os::raw::c_int = 26;
-pub const GSL_ENOPROG: ::std::os::raw::c_int = 27;
-pub const GSL_ENOPROGJ: ::std::os::raw::c_int = 28;
-pub const GSL_ETOLF: ::std::os::raw::c_int = 29;
-pub const GSL_ETOLX: ::std::os::raw::c_int = 30;
-pub const GSL_ETOLG: ::std::os::raw::c_int = 31;
-pub const GSL_EOF: ::std::os::raw::c_int = 32;
-pub type _bindgen_ty_1 = ::std::os::raw::c_int;
+pub const GSL_SUCCESS: ::core::ffi::c_int = 0;
+pub const GSL_FAILURE: ::core::ffi::c_int = -1;
+pub const GSL_CONTINUE: ::core::ffi::c_int = -2;
+pub const GSL_EDOM: ::core::ffi::c_int = 1;
+pub const GSL_ERANGE: ::core::ffi::c_int = 2;
+pub const GSL_EFAULT: ::core::ffi::c_int = 3;
+pub const GSL_EINVAL: ::core::ffi::c_int = 4;
+pub const GSL_EFAILED: ::core::ffi::c_int = 5;
+pub const GSL_EFACTOR: ::core::ffi::c_int = 6;
+pub const GSL_ESANITY: ::core::ffi::c_int = 7;
+pub const GSL_ENOMEM: ::core::ffi::c_int = 8;
+pub const GSL_EBADFUNC: ::core::ffi::c_int = 9;
+pub const GSL_ERUNAWAY: ::core::ffi::c_int = 10;
+pub const GSL_EMAXITER: ::core::ffi::c_int = 11;
+pub const GSL_EZERODIV: ::core::ffi::c_int = 12;
+pub const GSL_EBADTOL: ::core::ffi::c_int = 13;
+pub const GSL_ETOL: ::core::ffi::c_int = 14;
+pub const GSL_EUNDRFLW: ::core::ffi::c_int = 15;
+pub const GSL_EOVRFLW: ::core::ffi::c_int = 16;
+pub const GSL_ELOSS: ::core::ffi::c_int = 17;
+pub const GSL_EROUND: ::core::ffi::c_int = 18;
+pub const GSL_EBADLEN: ::core::ffi::c_int = 19;
+pub const GSL_ENOTSQR: ::core::ffi::c_int = 20;
+pub const GSL_ESING: ::core::ffi::c_int = 21;
+pub const GSL_EDIVERGE: ::core::ffi::c_int = 22;
+pub const GSL_EUNSUP: ::core::ffi::c_int = 23;
+pub const GSL_EUNIMPL: ::core::ffi::c_int = 24;
+pub const GSL_ECACHE: ::core::ffi::c_int = 25;
+pub const GSL_ETABLE: ::core::ffi::c_int = 26;
+pub const GSL_ENOPROG: ::core::ffi::c_int = 27;
+pub const GSL_ENOPROGJ: ::core::ffi::c_int = 28;
+pub const GSL_ETOLF: ::core::ffi::c_int = 29;
+pub const GSL_ETOLX: ::core::ffi::c_int = 30;
+pub const GSL_ETOLG: ::core::ffi::c_int = 31;
+pub const GSL_EOF: ::core::ffi::c_int = 32;
+pub type _bindgen_ty_1 = ::core::ffi::c_int;
 extern "C" {
     pub fn gsl_error(
-        reason: *const ::std::os::raw::c_char,
-        file: *const ::std::os::raw::c_char,
-        line: ::std::os::raw::c_int,
-        gsl_errno: ::std::os::raw::c_int,
+        reason: *const ::core::ffi::c_char,
+        file: *const ::core::ffi::c_char,
+        line: ::core::ffi::c_int,
+        gsl_errno: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn gsl_stream_printf(
-        label: *const ::std::os::raw::c_char,
-        file: *const ::std::os::raw::c_char,
-        line: ::std::os::raw::c_int,
-        reason: *const ::std::os::raw::c_char,
+        label: *const ::core::ffi::c_char,
+        file: *const ::core::ffi::c_char,
+        line: ::core::ffi::c_int,
+        reason: *const ::core::ffi::c_char,
     );
 }
 extern "C" {
-    pub fn gsl_strerror(gsl_errno: ::std::os::raw::c_int) -> *const ::std::os::raw::c_char;
+    pub fn gsl_strerror(gsl_errno: ::core::ffi::c_int) -> *const ::core::ffi::c_char;
 }
-pub type gsl_error_handler_t = ::std::option::Option<
+pub type gsl_error_handler_t = ::core::option::Option<
     unsafe extern "C" fn(
-        reason: *const ::std::os::raw::c_char,
-        file: *const ::std::os::raw::c_char,
-        line: ::std::os::raw::c_int,
-        gsl_errno: ::std::os::raw::c_int,
+        reason: *const ::core::ffi::c_char,
+        file: *const ::core::ffi::c_char,
+        line: ::core::ffi::c_int,
+        gsl_errno: ::core::ffi::c_int,
     ),
 >;
-pub type gsl_stream_handler_t = ::std::option::Option<
+pub type gsl_stream_handler_t = ::core::option::Option<
     unsafe extern "C" fn(
-        label: *const ::std::os::raw::c_char,
-        file: *const ::std::os::raw::c_char,
-        line: ::std::os::raw::c_int,
-        reason: *const ::std::os::raw::c_char,
+        label: *const ::core::ffi::c_char,
+        file: *const ::core::ffi::c_char,
+        line: ::core::ffi::c_int,
+        reason: *const ::core::ffi::c_char,
     ),
 >;
 extern "C" {
@@ -218,7 +218,7 @@ extern "C" {
     pub fn gsl_set_stream(new_stream: *mut FILE) -> *mut FILE;
 }
 extern "C" {
-    pub static mut gsl_check_range: ::std::os::raw::c_int;
+    pub static mut gsl_check_range: ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -243,32 +243,27 @@ extern "C" {
     pub fn gsl_permutation_memcpy(
         dest: *mut gsl_permutation,
         src: *const gsl_permutation,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_permutation_fread(
-        stream: *mut FILE,
-        p: *mut gsl_permutation,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_permutation_fread(stream: *mut FILE, p: *mut gsl_permutation) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permutation_fwrite(
         stream: *mut FILE,
         p: *const gsl_permutation,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_permutation_fscanf(
-        stream: *mut FILE,
-        p: *mut gsl_permutation,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_permutation_fscanf(stream: *mut FILE, p: *mut gsl_permutation)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permutation_fprintf(
         stream: *mut FILE,
         p: *const gsl_permutation,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permutation_size(p: *const gsl_permutation) -> usize;
@@ -277,14 +272,10 @@ extern "C" {
     pub fn gsl_permutation_data(p: *const gsl_permutation) -> *mut usize;
 }
 extern "C" {
-    pub fn gsl_permutation_swap(
-        p: *mut gsl_permutation,
-        i: usize,
-        j: usize,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_permutation_swap(p: *mut gsl_permutation, i: usize, j: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_permutation_valid(p: *const gsl_permutation) -> ::std::os::raw::c_int;
+    pub fn gsl_permutation_valid(p: *const gsl_permutation) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permutation_reverse(p: *mut gsl_permutation);
@@ -293,32 +284,32 @@ extern "C" {
     pub fn gsl_permutation_inverse(
         inv: *mut gsl_permutation,
         p: *const gsl_permutation,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_permutation_next(p: *mut gsl_permutation) -> ::std::os::raw::c_int;
+    pub fn gsl_permutation_next(p: *mut gsl_permutation) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_permutation_prev(p: *mut gsl_permutation) -> ::std::os::raw::c_int;
+    pub fn gsl_permutation_prev(p: *mut gsl_permutation) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permutation_mul(
         p: *mut gsl_permutation,
         pa: *const gsl_permutation,
         pb: *const gsl_permutation,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permutation_linear_to_canonical(
         q: *mut gsl_permutation,
         p: *const gsl_permutation,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permutation_canonical_to_linear(
         p: *mut gsl_permutation,
         q: *const gsl_permutation,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permutation_inversions(p: *const gsl_permutation) -> usize;
@@ -346,75 +337,69 @@ extern "C" {
     pub fn gsl_block_long_free(b: *mut gsl_block_long);
 }
 extern "C" {
-    pub fn gsl_block_long_fread(stream: *mut FILE, b: *mut gsl_block_long)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_block_long_fread(stream: *mut FILE, b: *mut gsl_block_long) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_block_long_fwrite(
-        stream: *mut FILE,
-        b: *const gsl_block_long,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_long_fwrite(stream: *mut FILE, b: *const gsl_block_long)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_block_long_fscanf(
-        stream: *mut FILE,
-        b: *mut gsl_block_long,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_long_fscanf(stream: *mut FILE, b: *mut gsl_block_long) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_long_fprintf(
         stream: *mut FILE,
         b: *const gsl_block_long,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_long_raw_fread(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_long,
+        b: *mut ::core::ffi::c_long,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_long_raw_fwrite(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_long,
+        b: *const ::core::ffi::c_long,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_long_raw_fscanf(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_long,
+        b: *mut ::core::ffi::c_long,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_long_raw_fprintf(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_long,
+        b: *const ::core::ffi::c_long,
         n: usize,
         stride: usize,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_long_size(b: *const gsl_block_long) -> usize;
 }
 extern "C" {
-    pub fn gsl_block_long_data(b: *const gsl_block_long) -> *mut ::std::os::raw::c_long;
+    pub fn gsl_block_long_data(b: *const gsl_block_long) -> *mut ::core::ffi::c_long;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_vector_long {
     pub size: usize,
     pub stride: usize,
-    pub data: *mut ::std::os::raw::c_long,
+    pub data: *mut ::core::ffi::c_long,
     pub block: *mut gsl_block_long,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -455,26 +440,26 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_vector_long_view_array(
-        v: *mut ::std::os::raw::c_long,
+        v: *mut ::core::ffi::c_long,
         n: usize,
     ) -> _gsl_vector_long_view;
 }
 extern "C" {
     pub fn gsl_vector_long_view_array_with_stride(
-        base: *mut ::std::os::raw::c_long,
+        base: *mut ::core::ffi::c_long,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_long_view;
 }
 extern "C" {
     pub fn gsl_vector_long_const_view_array(
-        v: *const ::std::os::raw::c_long,
+        v: *const ::core::ffi::c_long,
         n: usize,
     ) -> _gsl_vector_long_const_view;
 }
 extern "C" {
     pub fn gsl_vector_long_const_view_array_with_stride(
-        base: *const ::std::os::raw::c_long,
+        base: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_long_const_view;
@@ -513,69 +498,64 @@ extern "C" {
     pub fn gsl_vector_long_set_zero(v: *mut gsl_vector_long);
 }
 extern "C" {
-    pub fn gsl_vector_long_set_all(v: *mut gsl_vector_long, x: ::std::os::raw::c_long);
+    pub fn gsl_vector_long_set_all(v: *mut gsl_vector_long, x: ::core::ffi::c_long);
 }
 extern "C" {
-    pub fn gsl_vector_long_set_basis(v: *mut gsl_vector_long, i: usize) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_long_set_basis(v: *mut gsl_vector_long, i: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_fread(
-        stream: *mut FILE,
-        v: *mut gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_long_fread(stream: *mut FILE, v: *mut gsl_vector_long) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_fwrite(
         stream: *mut FILE,
         v: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_fscanf(
-        stream: *mut FILE,
-        v: *mut gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_long_fscanf(stream: *mut FILE, v: *mut gsl_vector_long)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_fprintf(
         stream: *mut FILE,
         v: *const gsl_vector_long,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_memcpy(
         dest: *mut gsl_vector_long,
         src: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_reverse(v: *mut gsl_vector_long) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_long_reverse(v: *mut gsl_vector_long) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_swap(
         v: *mut gsl_vector_long,
         w: *mut gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_swap_elements(
         v: *mut gsl_vector_long,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_max(v: *const gsl_vector_long) -> ::std::os::raw::c_long;
+    pub fn gsl_vector_long_max(v: *const gsl_vector_long) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn gsl_vector_long_min(v: *const gsl_vector_long) -> ::std::os::raw::c_long;
+    pub fn gsl_vector_long_min(v: *const gsl_vector_long) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn gsl_vector_long_minmax(
         v: *const gsl_vector_long,
-        min_out: *mut ::std::os::raw::c_long,
-        max_out: *mut ::std::os::raw::c_long,
+        min_out: *mut ::core::ffi::c_long,
+        max_out: *mut ::core::ffi::c_long,
     );
 }
 extern "C" {
@@ -595,104 +575,104 @@ extern "C" {
     pub fn gsl_vector_long_add(
         a: *mut gsl_vector_long,
         b: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_sub(
         a: *mut gsl_vector_long,
         b: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_mul(
         a: *mut gsl_vector_long,
         b: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_div(
         a: *mut gsl_vector_long,
         b: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_scale(
         a: *mut gsl_vector_long,
-        x: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_long,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_add_constant(a: *mut gsl_vector_long, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_long_add_constant(a: *mut gsl_vector_long, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_axpby(
-        alpha: ::std::os::raw::c_long,
+        alpha: ::core::ffi::c_long,
         x: *const gsl_vector_long,
-        beta: ::std::os::raw::c_long,
+        beta: ::core::ffi::c_long,
         y: *mut gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_sum(a: *const gsl_vector_long) -> ::std::os::raw::c_long;
+    pub fn gsl_vector_long_sum(a: *const gsl_vector_long) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn gsl_vector_long_equal(
         u: *const gsl_vector_long,
         v: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_isnull(v: *const gsl_vector_long) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_long_isnull(v: *const gsl_vector_long) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_ispos(v: *const gsl_vector_long) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_long_ispos(v: *const gsl_vector_long) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_isneg(v: *const gsl_vector_long) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_long_isneg(v: *const gsl_vector_long) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_isnonneg(v: *const gsl_vector_long) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_long_isnonneg(v: *const gsl_vector_long) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_get(v: *const gsl_vector_long, i: usize) -> ::std::os::raw::c_long;
+    pub fn gsl_vector_long_get(v: *const gsl_vector_long, i: usize) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn gsl_vector_long_set(v: *mut gsl_vector_long, i: usize, x: ::std::os::raw::c_long);
+    pub fn gsl_vector_long_set(v: *mut gsl_vector_long, i: usize, x: ::core::ffi::c_long);
 }
 extern "C" {
-    pub fn gsl_vector_long_ptr(v: *mut gsl_vector_long, i: usize) -> *mut ::std::os::raw::c_long;
+    pub fn gsl_vector_long_ptr(v: *mut gsl_vector_long, i: usize) -> *mut ::core::ffi::c_long;
 }
 extern "C" {
     pub fn gsl_vector_long_const_ptr(
         v: *const gsl_vector_long,
         i: usize,
-    ) -> *const ::std::os::raw::c_long;
+    ) -> *const ::core::ffi::c_long;
 }
 extern "C" {
     pub fn gsl_permute_vector_long(
         p: *const gsl_permutation,
         v: *mut gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_long_inverse(
         p: *const gsl_permutation,
         v: *mut gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
-pub const GSL_SPMATRIX_COO: ::std::os::raw::c_uint = 0;
-pub const GSL_SPMATRIX_CSC: ::std::os::raw::c_uint = 1;
-pub const GSL_SPMATRIX_CSR: ::std::os::raw::c_uint = 2;
-pub const GSL_SPMATRIX_TRIPLET: ::std::os::raw::c_uint = 0;
-pub const GSL_SPMATRIX_CCS: ::std::os::raw::c_uint = 1;
-pub const GSL_SPMATRIX_CRS: ::std::os::raw::c_uint = 2;
-pub type _bindgen_ty_2 = ::std::os::raw::c_uint;
+pub const GSL_SPMATRIX_COO: ::core::ffi::c_uint = 0;
+pub const GSL_SPMATRIX_CSC: ::core::ffi::c_uint = 1;
+pub const GSL_SPMATRIX_CSR: ::core::ffi::c_uint = 2;
+pub const GSL_SPMATRIX_TRIPLET: ::core::ffi::c_uint = 0;
+pub const GSL_SPMATRIX_CCS: ::core::ffi::c_uint = 1;
+pub const GSL_SPMATRIX_CRS: ::core::ffi::c_uint = 2;
+pub type _bindgen_ty_2 = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_spmatrix_pool_node;
 pub type gsl_spmatrix_pool = gsl_spmatrix_pool_node;
 extern "C" {
-    pub fn gsl_spmatrix_cumsum(n: usize, c: *mut ::std::os::raw::c_int);
+    pub fn gsl_spmatrix_cumsum(n: usize, c: *mut ::core::ffi::c_int);
 }
 extern "C" {
     pub fn gsl_log1p(x: f64) -> f64;
@@ -716,13 +696,13 @@ extern "C" {
     pub fn gsl_atanh(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_isnan(x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_isnan(x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_isinf(x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_isinf(x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_finite(x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_finite(x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_nan() -> f64;
@@ -746,15 +726,15 @@ extern "C" {
     pub fn gsl_coerce_long_double(x: u128) -> u128;
 }
 extern "C" {
-    pub fn gsl_ldexp(x: f64, e: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_ldexp(x: f64, e: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
-    pub fn gsl_frexp(x: f64, e: *mut ::std::os::raw::c_int) -> f64;
+    pub fn gsl_frexp(x: f64, e: *mut ::core::ffi::c_int) -> f64;
 }
 extern "C" {
-    pub fn gsl_fcmp(x1: f64, x2: f64, epsilon: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_fcmp(x1: f64, x2: f64, epsilon: f64) -> ::core::ffi::c_int;
 }
-pub type gsl_prec_t = ::std::os::raw::c_uint;
+pub type gsl_prec_t = ::core::ffi::c_uint;
 extern "C" {
     pub static mut gsl_prec_eps: [f64; 0usize];
 }
@@ -798,10 +778,10 @@ extern "C" {
     pub fn gsl_pow_9(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_pow_int(x: f64, n: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_pow_int(x: f64, n: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
-    pub fn gsl_pow_uint(x: f64, n: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_pow_uint(x: f64, n: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_max(a: f64, b: f64) -> f64;
@@ -812,30 +792,25 @@ extern "C" {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_function_struct {
-    pub function: ::std::option::Option<
-        unsafe extern "C" fn(x: f64, params: *mut ::std::os::raw::c_void) -> f64,
+    pub function: ::core::option::Option<
+        unsafe extern "C" fn(x: f64, params: *mut ::core::ffi::c_void) -> f64,
     >,
-    pub params: *mut ::std::os::raw::c_void,
+    pub params: *mut ::core::ffi::c_void,
 }
 pub type gsl_function = gsl_function_struct;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_function_fdf_struct {
-    pub f: ::std::option::Option<
-        unsafe extern "C" fn(x: f64, params: *mut ::std::os::raw::c_void) -> f64,
+    pub f: ::core::option::Option<
+        unsafe extern "C" fn(x: f64, params: *mut ::core::ffi::c_void) -> f64,
     >,
-    pub df: ::std::option::Option<
-        unsafe extern "C" fn(x: f64, params: *mut ::std::os::raw::c_void) -> f64,
+    pub df: ::core::option::Option<
+        unsafe extern "C" fn(x: f64, params: *mut ::core::ffi::c_void) -> f64,
     >,
-    pub fdf: ::std::option::Option<
-        unsafe extern "C" fn(
-            x: f64,
-            params: *mut ::std::os::raw::c_void,
-            f: *mut f64,
-            df: *mut f64,
-        ),
+    pub fdf: ::core::option::Option<
+        unsafe extern "C" fn(x: f64, params: *mut ::core::ffi::c_void, f: *mut f64, df: *mut f64),
     >,
-    pub params: *mut ::std::os::raw::c_void,
+    pub params: *mut ::core::ffi::c_void,
 }
 pub type gsl_function_fdf = gsl_function_fdf_struct;
 #[repr(C)]
@@ -844,12 +819,12 @@ pub struct gsl_function_vec_struct;
 pub type gsl_function_vec = gsl_function_vec_struct;
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
-pub type gsl_bst_cmp_function = ::std::option::Option<
+pub type gsl_bst_cmp_function = ::core::option::Option<
     unsafe extern "C" fn(
-        a: *const ::std::os::raw::c_void,
-        b: *const ::std::os::raw::c_void,
-        params: *mut ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int,
+        a: *const ::core::ffi::c_void,
+        b: *const ::core::ffi::c_void,
+        params: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int,
 >;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -918,7 +893,7 @@ extern "C" {
         T: *const gsl_bst_type,
         allocator: *const gsl_bst_allocator,
         compare: gsl_bst_cmp_function,
-        params: *mut ::std::os::raw::c_void,
+        params: *mut ::core::ffi::c_void,
     ) -> *mut gsl_bst_workspace;
 }
 extern "C" {
@@ -929,31 +904,31 @@ extern "C" {
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
-    pub fn gsl_bst_empty(w: *mut gsl_bst_workspace) -> ::std::os::raw::c_int;
+    pub fn gsl_bst_empty(w: *mut gsl_bst_workspace) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_insert(
-        item: *mut ::std::os::raw::c_void,
+        item: *mut ::core::ffi::c_void,
         w: *mut gsl_bst_workspace,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_find(
-        item: *const ::std::os::raw::c_void,
+        item: *const ::core::ffi::c_void,
         w: *const gsl_bst_workspace,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_remove(
-        item: *const ::std::os::raw::c_void,
+        item: *const ::core::ffi::c_void,
         w: *mut gsl_bst_workspace,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -968,7 +943,7 @@ extern "C" {
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
-    pub fn gsl_bst_name(w: *const gsl_bst_workspace) -> *const ::std::os::raw::c_char;
+    pub fn gsl_bst_name(w: *const gsl_bst_workspace) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -976,7 +951,7 @@ extern "C" {
     pub fn gsl_bst_trav_init(
         trav: *mut gsl_bst_trav,
         w: *const gsl_bst_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -984,7 +959,7 @@ extern "C" {
     pub fn gsl_bst_trav_first(
         trav: *mut gsl_bst_trav,
         w: *const gsl_bst_workspace,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -992,25 +967,25 @@ extern "C" {
     pub fn gsl_bst_trav_last(
         trav: *mut gsl_bst_trav,
         w: *const gsl_bst_workspace,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_trav_find(
-        item: *const ::std::os::raw::c_void,
+        item: *const ::core::ffi::c_void,
         trav: *mut gsl_bst_trav,
         w: *const gsl_bst_workspace,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_trav_insert(
-        item: *mut ::std::os::raw::c_void,
+        item: *mut ::core::ffi::c_void,
         trav: *mut gsl_bst_trav,
         w: *mut gsl_bst_workspace,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -1018,30 +993,30 @@ extern "C" {
     pub fn gsl_bst_trav_copy(
         dest: *mut gsl_bst_trav,
         src: *const gsl_bst_trav,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
-    pub fn gsl_bst_trav_next(trav: *mut gsl_bst_trav) -> *mut ::std::os::raw::c_void;
+    pub fn gsl_bst_trav_next(trav: *mut gsl_bst_trav) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
-    pub fn gsl_bst_trav_prev(trav: *mut gsl_bst_trav) -> *mut ::std::os::raw::c_void;
+    pub fn gsl_bst_trav_prev(trav: *mut gsl_bst_trav) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
-    pub fn gsl_bst_trav_cur(trav: *const gsl_bst_trav) -> *mut ::std::os::raw::c_void;
+    pub fn gsl_bst_trav_cur(trav: *const gsl_bst_trav) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_bst_trav_replace(
         trav: *mut gsl_bst_trav,
-        new_item: *mut ::std::os::raw::c_void,
-    ) -> *mut ::std::os::raw::c_void;
+        new_item: *mut ::core::ffi::c_void,
+    ) -> *mut ::core::ffi::c_void;
 }
 pub type gsl_complex_packed = *mut f64;
 pub type gsl_complex_packed_float = *mut f32;
@@ -1094,26 +1069,26 @@ extern "C" {
     pub fn gsl_block_long_double_fread(
         stream: *mut FILE,
         b: *mut gsl_block_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_long_double_fwrite(
         stream: *mut FILE,
         b: *const gsl_block_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_long_double_fscanf(
         stream: *mut FILE,
         b: *mut gsl_block_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_long_double_fprintf(
         stream: *mut FILE,
         b: *const gsl_block_long_double,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_long_double_raw_fread(
@@ -1121,7 +1096,7 @@ extern "C" {
         b: *mut u128,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_long_double_raw_fwrite(
@@ -1129,7 +1104,7 @@ extern "C" {
         b: *const u128,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_long_double_raw_fscanf(
@@ -1137,7 +1112,7 @@ extern "C" {
         b: *mut u128,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_long_double_raw_fprintf(
@@ -1145,8 +1120,8 @@ extern "C" {
         b: *const u128,
         n: usize,
         stride: usize,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_long_double_size(b: *const gsl_block_long_double) -> usize;
@@ -1161,7 +1136,7 @@ pub struct gsl_vector_long_double {
     pub stride: usize,
     pub data: *mut u128,
     pub block: *mut gsl_block_long_double,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -1266,54 +1241,54 @@ extern "C" {
     pub fn gsl_vector_long_double_set_basis(
         v: *mut gsl_vector_long_double,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_fread(
         stream: *mut FILE,
         v: *mut gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_fwrite(
         stream: *mut FILE,
         v: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_fscanf(
         stream: *mut FILE,
         v: *mut gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_fprintf(
         stream: *mut FILE,
         v: *const gsl_vector_long_double,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_memcpy(
         dest: *mut gsl_vector_long_double,
         src: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_double_reverse(v: *mut gsl_vector_long_double) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_long_double_reverse(v: *mut gsl_vector_long_double) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_swap(
         v: *mut gsl_vector_long_double,
         w: *mut gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_swap_elements(
         v: *mut gsl_vector_long_double,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_max(v: *const gsl_vector_long_double) -> u128;
@@ -1345,37 +1320,37 @@ extern "C" {
     pub fn gsl_vector_long_double_add(
         a: *mut gsl_vector_long_double,
         b: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_sub(
         a: *mut gsl_vector_long_double,
         b: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_mul(
         a: *mut gsl_vector_long_double,
         b: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_div(
         a: *mut gsl_vector_long_double,
         b: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_scale(
         a: *mut gsl_vector_long_double,
         x: u128,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_add_constant(
         a: *mut gsl_vector_long_double,
         x: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_axpby(
@@ -1383,7 +1358,7 @@ extern "C" {
         x: *const gsl_vector_long_double,
         beta: u128,
         y: *mut gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_sum(a: *const gsl_vector_long_double) -> u128;
@@ -1392,22 +1367,19 @@ extern "C" {
     pub fn gsl_vector_long_double_equal(
         u: *const gsl_vector_long_double,
         v: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_double_isnull(v: *const gsl_vector_long_double)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_vector_long_double_isnull(v: *const gsl_vector_long_double) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_double_ispos(v: *const gsl_vector_long_double) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_long_double_ispos(v: *const gsl_vector_long_double) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_double_isneg(v: *const gsl_vector_long_double) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_long_double_isneg(v: *const gsl_vector_long_double) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_long_double_isnonneg(
-        v: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_long_double_isnonneg(v: *const gsl_vector_long_double) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_long_double_get(v: *const gsl_vector_long_double, i: usize) -> u128;
@@ -1441,26 +1413,26 @@ extern "C" {
     pub fn gsl_block_complex_long_double_fread(
         stream: *mut FILE,
         b: *mut gsl_block_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_long_double_fwrite(
         stream: *mut FILE,
         b: *const gsl_block_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_long_double_fscanf(
         stream: *mut FILE,
         b: *mut gsl_block_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_long_double_fprintf(
         stream: *mut FILE,
         b: *const gsl_block_complex_long_double,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_long_double_raw_fread(
@@ -1468,7 +1440,7 @@ extern "C" {
         b: *mut u128,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_long_double_raw_fwrite(
@@ -1476,7 +1448,7 @@ extern "C" {
         b: *const u128,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_long_double_raw_fscanf(
@@ -1484,7 +1456,7 @@ extern "C" {
         b: *mut u128,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_long_double_raw_fprintf(
@@ -1492,8 +1464,8 @@ extern "C" {
         b: *const u128,
         n: usize,
         stride: usize,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_long_double_size(b: *const gsl_block_complex_long_double) -> usize;
@@ -1509,7 +1481,7 @@ pub struct gsl_vector_complex_long_double {
     pub stride: usize,
     pub data: *mut u128,
     pub block: *mut gsl_block_complex_long_double,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -1637,118 +1609,118 @@ extern "C" {
     pub fn gsl_vector_complex_long_double_set_basis(
         v: *mut gsl_vector_complex_long_double,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_fread(
         stream: *mut FILE,
         v: *mut gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_fwrite(
         stream: *mut FILE,
         v: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_fscanf(
         stream: *mut FILE,
         v: *mut gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_fprintf(
         stream: *mut FILE,
         v: *const gsl_vector_complex_long_double,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_memcpy(
         dest: *mut gsl_vector_complex_long_double,
         src: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_reverse(
         v: *mut gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_swap(
         v: *mut gsl_vector_complex_long_double,
         w: *mut gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_swap_elements(
         v: *mut gsl_vector_complex_long_double,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_equal(
         u: *const gsl_vector_complex_long_double,
         v: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_isnull(
         v: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_ispos(
         v: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_isneg(
         v: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_isnonneg(
         v: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_add(
         a: *mut gsl_vector_complex_long_double,
         b: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_sub(
         a: *mut gsl_vector_complex_long_double,
         b: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_mul(
         a: *mut gsl_vector_complex_long_double,
         b: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_div(
         a: *mut gsl_vector_complex_long_double,
         b: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_scale(
         a: *mut gsl_vector_complex_long_double,
         x: gsl_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_add_constant(
         a: *mut gsl_vector_complex_long_double,
         x: gsl_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_axpby(
@@ -1756,7 +1728,7 @@ extern "C" {
         x: *const gsl_vector_complex_long_double,
         beta: gsl_complex_long_double,
         y: *mut gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_long_double_get(
@@ -1785,279 +1757,267 @@ extern "C" {
 }
 pub const CBLAS_ORDER_CblasRowMajor: CBLAS_ORDER = 101;
 pub const CBLAS_ORDER_CblasColMajor: CBLAS_ORDER = 102;
-pub type CBLAS_ORDER = ::std::os::raw::c_uint;
+pub type CBLAS_ORDER = ::core::ffi::c_uint;
 pub const CBLAS_TRANSPOSE_CblasNoTrans: CBLAS_TRANSPOSE = 111;
 pub const CBLAS_TRANSPOSE_CblasTrans: CBLAS_TRANSPOSE = 112;
 pub const CBLAS_TRANSPOSE_CblasConjTrans: CBLAS_TRANSPOSE = 113;
-pub type CBLAS_TRANSPOSE = ::std::os::raw::c_uint;
+pub type CBLAS_TRANSPOSE = ::core::ffi::c_uint;
 pub const CBLAS_UPLO_CblasUpper: CBLAS_UPLO = 121;
 pub const CBLAS_UPLO_CblasLower: CBLAS_UPLO = 122;
-pub type CBLAS_UPLO = ::std::os::raw::c_uint;
+pub type CBLAS_UPLO = ::core::ffi::c_uint;
 pub const CBLAS_DIAG_CblasNonUnit: CBLAS_DIAG = 131;
 pub const CBLAS_DIAG_CblasUnit: CBLAS_DIAG = 132;
-pub type CBLAS_DIAG = ::std::os::raw::c_uint;
+pub type CBLAS_DIAG = ::core::ffi::c_uint;
 pub const CBLAS_SIDE_CblasLeft: CBLAS_SIDE = 141;
 pub const CBLAS_SIDE_CblasRight: CBLAS_SIDE = 142;
-pub type CBLAS_SIDE = ::std::os::raw::c_uint;
+pub type CBLAS_SIDE = ::core::ffi::c_uint;
 extern "C" {
     pub fn cblas_sdsdot(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *const f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     ) -> f32;
 }
 extern "C" {
     pub fn cblas_dsdot(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *const f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
     pub fn cblas_sdot(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *const f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     ) -> f32;
 }
 extern "C" {
     pub fn cblas_ddot(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         X: *const f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *const f64,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
     pub fn cblas_cdotu_sub(
-        N: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *const ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
-        dotu: *mut ::std::os::raw::c_void,
+        N: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *const ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
+        dotu: *mut ::core::ffi::c_void,
     );
 }
 extern "C" {
     pub fn cblas_cdotc_sub(
-        N: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *const ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
-        dotc: *mut ::std::os::raw::c_void,
+        N: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *const ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
+        dotc: *mut ::core::ffi::c_void,
     );
 }
 extern "C" {
     pub fn cblas_zdotu_sub(
-        N: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *const ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
-        dotu: *mut ::std::os::raw::c_void,
+        N: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *const ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
+        dotu: *mut ::core::ffi::c_void,
     );
 }
 extern "C" {
     pub fn cblas_zdotc_sub(
-        N: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *const ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
-        dotc: *mut ::std::os::raw::c_void,
+        N: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *const ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
+        dotc: *mut ::core::ffi::c_void,
     );
 }
 extern "C" {
-    pub fn cblas_snrm2(N: ::std::os::raw::c_int, X: *const f32, incX: ::std::os::raw::c_int)
-        -> f32;
+    pub fn cblas_snrm2(N: ::core::ffi::c_int, X: *const f32, incX: ::core::ffi::c_int) -> f32;
 }
 extern "C" {
-    pub fn cblas_sasum(N: ::std::os::raw::c_int, X: *const f32, incX: ::std::os::raw::c_int)
-        -> f32;
+    pub fn cblas_sasum(N: ::core::ffi::c_int, X: *const f32, incX: ::core::ffi::c_int) -> f32;
 }
 extern "C" {
-    pub fn cblas_dnrm2(N: ::std::os::raw::c_int, X: *const f64, incX: ::std::os::raw::c_int)
-        -> f64;
+    pub fn cblas_dnrm2(N: ::core::ffi::c_int, X: *const f64, incX: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
-    pub fn cblas_dasum(N: ::std::os::raw::c_int, X: *const f64, incX: ::std::os::raw::c_int)
-        -> f64;
+    pub fn cblas_dasum(N: ::core::ffi::c_int, X: *const f64, incX: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
     pub fn cblas_scnrm2(
-        N: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     ) -> f32;
 }
 extern "C" {
     pub fn cblas_scasum(
-        N: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     ) -> f32;
 }
 extern "C" {
     pub fn cblas_dznrm2(
-        N: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
     pub fn cblas_dzasum(
-        N: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
-    pub fn cblas_isamax(
-        N: ::std::os::raw::c_int,
-        X: *const f32,
-        incX: ::std::os::raw::c_int,
-    ) -> usize;
+    pub fn cblas_isamax(N: ::core::ffi::c_int, X: *const f32, incX: ::core::ffi::c_int) -> usize;
 }
 extern "C" {
-    pub fn cblas_idamax(
-        N: ::std::os::raw::c_int,
-        X: *const f64,
-        incX: ::std::os::raw::c_int,
-    ) -> usize;
+    pub fn cblas_idamax(N: ::core::ffi::c_int, X: *const f64, incX: ::core::ffi::c_int) -> usize;
 }
 extern "C" {
     pub fn cblas_icamax(
-        N: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     ) -> usize;
 }
 extern "C" {
     pub fn cblas_izamax(
-        N: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     ) -> usize;
 }
 extern "C" {
     pub fn cblas_sswap(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         X: *mut f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *mut f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_scopy(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *mut f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_saxpy(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *mut f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_dswap(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         X: *mut f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *mut f64,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_dcopy(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         X: *const f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *mut f64,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_daxpy(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
         X: *const f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *mut f64,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_cswap(
-        N: ::std::os::raw::c_int,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_ccopy(
-        N: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_caxpy(
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_zswap(
-        N: ::std::os::raw::c_int,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_zcopy(
-        N: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_zaxpy(
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2068,22 +2028,22 @@ extern "C" {
 }
 extern "C" {
     pub fn cblas_srot(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         X: *mut f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *mut f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
         c: f32,
         s: f32,
     );
 }
 extern "C" {
     pub fn cblas_srotm(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         X: *mut f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *mut f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
         P: *const f32,
     );
 }
@@ -2095,105 +2055,95 @@ extern "C" {
 }
 extern "C" {
     pub fn cblas_drot(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         X: *mut f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *mut f64,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
         c: f64,
         s: f64,
     );
 }
 extern "C" {
     pub fn cblas_drotm(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         X: *mut f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *mut f64,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
         P: *const f64,
     );
 }
 extern "C" {
-    pub fn cblas_sscal(
-        N: ::std::os::raw::c_int,
-        alpha: f32,
-        X: *mut f32,
-        incX: ::std::os::raw::c_int,
-    );
+    pub fn cblas_sscal(N: ::core::ffi::c_int, alpha: f32, X: *mut f32, incX: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn cblas_dscal(
-        N: ::std::os::raw::c_int,
-        alpha: f64,
-        X: *mut f64,
-        incX: ::std::os::raw::c_int,
-    );
+    pub fn cblas_dscal(N: ::core::ffi::c_int, alpha: f64, X: *mut f64, incX: ::core::ffi::c_int);
 }
 extern "C" {
     pub fn cblas_cscal(
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_zscal(
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_csscal(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_zdscal(
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_sgemv(
         order: CBLAS_ORDER,
         TransA: CBLAS_TRANSPOSE,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
         A: *const f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         beta: f32,
         Y: *mut f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_sgbmv(
         order: CBLAS_ORDER,
         TransA: CBLAS_TRANSPOSE,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        KL: ::std::os::raw::c_int,
-        KU: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        KL: ::core::ffi::c_int,
+        KU: ::core::ffi::c_int,
         alpha: f32,
         A: *const f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         beta: f32,
         Y: *mut f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2202,11 +2152,11 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         A: *const f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *mut f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2215,12 +2165,12 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
         A: *const f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *mut f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2229,10 +2179,10 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         Ap: *const f32,
         X: *mut f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2241,11 +2191,11 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         A: *const f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *mut f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2254,12 +2204,12 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
         A: *const f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *mut f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2268,44 +2218,44 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         Ap: *const f32,
         X: *mut f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_dgemv(
         order: CBLAS_ORDER,
         TransA: CBLAS_TRANSPOSE,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
         A: *const f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *const f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         beta: f64,
         Y: *mut f64,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_dgbmv(
         order: CBLAS_ORDER,
         TransA: CBLAS_TRANSPOSE,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        KL: ::std::os::raw::c_int,
-        KU: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        KL: ::core::ffi::c_int,
+        KU: ::core::ffi::c_int,
         alpha: f64,
         A: *const f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *const f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         beta: f64,
         Y: *mut f64,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2314,11 +2264,11 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         A: *const f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *mut f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2327,12 +2277,12 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
         A: *const f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *mut f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2341,10 +2291,10 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         Ap: *const f64,
         X: *mut f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2353,11 +2303,11 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         A: *const f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *mut f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2366,12 +2316,12 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
         A: *const f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *mut f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2380,44 +2330,44 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         Ap: *const f64,
         X: *mut f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_cgemv(
         order: CBLAS_ORDER,
         TransA: CBLAS_TRANSPOSE,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_cgbmv(
         order: CBLAS_ORDER,
         TransA: CBLAS_TRANSPOSE,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        KL: ::std::os::raw::c_int,
-        KU: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        KL: ::core::ffi::c_int,
+        KU: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2426,11 +2376,11 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2439,12 +2389,12 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2453,10 +2403,10 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        Ap: *const ::std::os::raw::c_void,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        Ap: *const ::core::ffi::c_void,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2465,11 +2415,11 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2478,12 +2428,12 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2492,44 +2442,44 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        Ap: *const ::std::os::raw::c_void,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        Ap: *const ::core::ffi::c_void,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_zgemv(
         order: CBLAS_ORDER,
         TransA: CBLAS_TRANSPOSE,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_zgbmv(
         order: CBLAS_ORDER,
         TransA: CBLAS_TRANSPOSE,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        KL: ::std::os::raw::c_int,
-        KU: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        KL: ::core::ffi::c_int,
+        KU: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2538,11 +2488,11 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2551,12 +2501,12 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2565,10 +2515,10 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        Ap: *const ::std::os::raw::c_void,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        Ap: *const ::core::ffi::c_void,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2577,11 +2527,11 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2590,12 +2540,12 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -2604,91 +2554,91 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        N: ::std::os::raw::c_int,
-        Ap: *const ::std::os::raw::c_void,
-        X: *mut ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        Ap: *const ::core::ffi::c_void,
+        X: *mut ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_ssymv(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
         A: *const f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         beta: f32,
         Y: *mut f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_ssbmv(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
         alpha: f32,
         A: *const f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         beta: f32,
         Y: *mut f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_sspmv(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
         Ap: *const f32,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         beta: f32,
         Y: *mut f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_sger(
         order: CBLAS_ORDER,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *const f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
         A: *mut f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_ssyr(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         A: *mut f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_sspr(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Ap: *mut f32,
     );
 }
@@ -2696,26 +2646,26 @@ extern "C" {
     pub fn cblas_ssyr2(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *const f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
         A: *mut f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_sspr2(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
         X: *const f32,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *const f32,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
         A: *mut f32,
     );
 }
@@ -2723,81 +2673,81 @@ extern "C" {
     pub fn cblas_dsymv(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
         A: *const f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *const f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         beta: f64,
         Y: *mut f64,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_dsbmv(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
         alpha: f64,
         A: *const f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         X: *const f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         beta: f64,
         Y: *mut f64,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_dspmv(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
         Ap: *const f64,
         X: *const f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         beta: f64,
         Y: *mut f64,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_dger(
         order: CBLAS_ORDER,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
         X: *const f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *const f64,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
         A: *mut f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_dsyr(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
         X: *const f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         A: *mut f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_dspr(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
         X: *const f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Ap: *mut f64,
     );
 }
@@ -2805,26 +2755,26 @@ extern "C" {
     pub fn cblas_dsyr2(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
         X: *const f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *const f64,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
         A: *mut f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_dspr2(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
         X: *const f64,
-        incX: ::std::os::raw::c_int,
+        incX: ::core::ffi::c_int,
         Y: *const f64,
-        incY: ::std::os::raw::c_int,
+        incY: ::core::ffi::c_int,
         A: *mut f64,
     );
 }
@@ -2832,246 +2782,246 @@ extern "C" {
     pub fn cblas_chemv(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_chbmv(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_chpmv(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        Ap: *const ::std::os::raw::c_void,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        Ap: *const ::core::ffi::c_void,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_cgeru(
         order: CBLAS_ORDER,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *const ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
-        A: *mut ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *const ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
+        A: *mut ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_cgerc(
         order: CBLAS_ORDER,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *const ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
-        A: *mut ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *const ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
+        A: *mut ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_cher(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        A: *mut ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        A: *mut ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_chpr(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        A: *mut ::std::os::raw::c_void,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        A: *mut ::core::ffi::c_void,
     );
 }
 extern "C" {
     pub fn cblas_cher2(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *const ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
-        A: *mut ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *const ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
+        A: *mut ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_chpr2(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *const ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
-        Ap: *mut ::std::os::raw::c_void,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *const ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
+        Ap: *mut ::core::ffi::c_void,
     );
 }
 extern "C" {
     pub fn cblas_zhemv(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_zhbmv(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_zhpmv(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        Ap: *const ::std::os::raw::c_void,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        Y: *mut ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        Ap: *const ::core::ffi::c_void,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        Y: *mut ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_zgeru(
         order: CBLAS_ORDER,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *const ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
-        A: *mut ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *const ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
+        A: *mut ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_zgerc(
         order: CBLAS_ORDER,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *const ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
-        A: *mut ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *const ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
+        A: *mut ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_zher(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        A: *mut ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        A: *mut ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_zhpr(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        A: *mut ::std::os::raw::c_void,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        A: *mut ::core::ffi::c_void,
     );
 }
 extern "C" {
     pub fn cblas_zher2(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *const ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
-        A: *mut ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *const ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
+        A: *mut ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_zhpr2(
         order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        X: *const ::std::os::raw::c_void,
-        incX: ::std::os::raw::c_int,
-        Y: *const ::std::os::raw::c_void,
-        incY: ::std::os::raw::c_int,
-        Ap: *mut ::std::os::raw::c_void,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        X: *const ::core::ffi::c_void,
+        incX: ::core::ffi::c_int,
+        Y: *const ::core::ffi::c_void,
+        incY: ::core::ffi::c_int,
+        Ap: *mut ::core::ffi::c_void,
     );
 }
 extern "C" {
@@ -3079,17 +3029,17 @@ extern "C" {
         Order: CBLAS_ORDER,
         TransA: CBLAS_TRANSPOSE,
         TransB: CBLAS_TRANSPOSE,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
         alpha: f32,
         A: *const f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         B: *const f32,
-        ldb: ::std::os::raw::c_int,
+        ldb: ::core::ffi::c_int,
         beta: f32,
         C: *mut f32,
-        ldc: ::std::os::raw::c_int,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3097,16 +3047,16 @@ extern "C" {
         Order: CBLAS_ORDER,
         Side: CBLAS_SIDE,
         Uplo: CBLAS_UPLO,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
         A: *const f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         B: *const f32,
-        ldb: ::std::os::raw::c_int,
+        ldb: ::core::ffi::c_int,
         beta: f32,
         C: *mut f32,
-        ldc: ::std::os::raw::c_int,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3114,14 +3064,14 @@ extern "C" {
         Order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
         Trans: CBLAS_TRANSPOSE,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
         alpha: f32,
         A: *const f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         beta: f32,
         C: *mut f32,
-        ldc: ::std::os::raw::c_int,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3129,16 +3079,16 @@ extern "C" {
         Order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
         Trans: CBLAS_TRANSPOSE,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
         alpha: f32,
         A: *const f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         B: *const f32,
-        ldb: ::std::os::raw::c_int,
+        ldb: ::core::ffi::c_int,
         beta: f32,
         C: *mut f32,
-        ldc: ::std::os::raw::c_int,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3148,13 +3098,13 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
         A: *const f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         B: *mut f32,
-        ldb: ::std::os::raw::c_int,
+        ldb: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3164,13 +3114,13 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
         alpha: f32,
         A: *const f32,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         B: *mut f32,
-        ldb: ::std::os::raw::c_int,
+        ldb: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3178,17 +3128,17 @@ extern "C" {
         Order: CBLAS_ORDER,
         TransA: CBLAS_TRANSPOSE,
         TransB: CBLAS_TRANSPOSE,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
         alpha: f64,
         A: *const f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         B: *const f64,
-        ldb: ::std::os::raw::c_int,
+        ldb: ::core::ffi::c_int,
         beta: f64,
         C: *mut f64,
-        ldc: ::std::os::raw::c_int,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3196,16 +3146,16 @@ extern "C" {
         Order: CBLAS_ORDER,
         Side: CBLAS_SIDE,
         Uplo: CBLAS_UPLO,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
         A: *const f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         B: *const f64,
-        ldb: ::std::os::raw::c_int,
+        ldb: ::core::ffi::c_int,
         beta: f64,
         C: *mut f64,
-        ldc: ::std::os::raw::c_int,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3213,14 +3163,14 @@ extern "C" {
         Order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
         Trans: CBLAS_TRANSPOSE,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
         alpha: f64,
         A: *const f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         beta: f64,
         C: *mut f64,
-        ldc: ::std::os::raw::c_int,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3228,16 +3178,16 @@ extern "C" {
         Order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
         Trans: CBLAS_TRANSPOSE,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
         alpha: f64,
         A: *const f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         B: *const f64,
-        ldb: ::std::os::raw::c_int,
+        ldb: ::core::ffi::c_int,
         beta: f64,
         C: *mut f64,
-        ldc: ::std::os::raw::c_int,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3247,13 +3197,13 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
         A: *const f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         B: *mut f64,
-        ldb: ::std::os::raw::c_int,
+        ldb: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3263,13 +3213,13 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
         alpha: f64,
         A: *const f64,
-        lda: ::std::os::raw::c_int,
+        lda: ::core::ffi::c_int,
         B: *mut f64,
-        ldb: ::std::os::raw::c_int,
+        ldb: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3277,17 +3227,17 @@ extern "C" {
         Order: CBLAS_ORDER,
         TransA: CBLAS_TRANSPOSE,
         TransB: CBLAS_TRANSPOSE,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        B: *const ::std::os::raw::c_void,
-        ldb: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        C: *mut ::std::os::raw::c_void,
-        ldc: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        B: *const ::core::ffi::c_void,
+        ldb: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        C: *mut ::core::ffi::c_void,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3295,16 +3245,16 @@ extern "C" {
         Order: CBLAS_ORDER,
         Side: CBLAS_SIDE,
         Uplo: CBLAS_UPLO,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        B: *const ::std::os::raw::c_void,
-        ldb: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        C: *mut ::std::os::raw::c_void,
-        ldc: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        B: *const ::core::ffi::c_void,
+        ldb: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        C: *mut ::core::ffi::c_void,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3312,14 +3262,14 @@ extern "C" {
         Order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
         Trans: CBLAS_TRANSPOSE,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        C: *mut ::std::os::raw::c_void,
-        ldc: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        C: *mut ::core::ffi::c_void,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3327,16 +3277,16 @@ extern "C" {
         Order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
         Trans: CBLAS_TRANSPOSE,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        B: *const ::std::os::raw::c_void,
-        ldb: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        C: *mut ::std::os::raw::c_void,
-        ldc: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        B: *const ::core::ffi::c_void,
+        ldb: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        C: *mut ::core::ffi::c_void,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3346,13 +3296,13 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        B: *mut ::std::os::raw::c_void,
-        ldb: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        B: *mut ::core::ffi::c_void,
+        ldb: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3362,13 +3312,13 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        B: *mut ::std::os::raw::c_void,
-        ldb: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        B: *mut ::core::ffi::c_void,
+        ldb: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3376,17 +3326,17 @@ extern "C" {
         Order: CBLAS_ORDER,
         TransA: CBLAS_TRANSPOSE,
         TransB: CBLAS_TRANSPOSE,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        B: *const ::std::os::raw::c_void,
-        ldb: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        C: *mut ::std::os::raw::c_void,
-        ldc: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        B: *const ::core::ffi::c_void,
+        ldb: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        C: *mut ::core::ffi::c_void,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3394,16 +3344,16 @@ extern "C" {
         Order: CBLAS_ORDER,
         Side: CBLAS_SIDE,
         Uplo: CBLAS_UPLO,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        B: *const ::std::os::raw::c_void,
-        ldb: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        C: *mut ::std::os::raw::c_void,
-        ldc: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        B: *const ::core::ffi::c_void,
+        ldb: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        C: *mut ::core::ffi::c_void,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3411,14 +3361,14 @@ extern "C" {
         Order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
         Trans: CBLAS_TRANSPOSE,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        C: *mut ::std::os::raw::c_void,
-        ldc: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        C: *mut ::core::ffi::c_void,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3426,16 +3376,16 @@ extern "C" {
         Order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
         Trans: CBLAS_TRANSPOSE,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        B: *const ::std::os::raw::c_void,
-        ldb: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        C: *mut ::std::os::raw::c_void,
-        ldc: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        B: *const ::core::ffi::c_void,
+        ldb: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        C: *mut ::core::ffi::c_void,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3445,13 +3395,13 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        B: *mut ::std::os::raw::c_void,
-        ldb: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        B: *mut ::core::ffi::c_void,
+        ldb: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3461,13 +3411,13 @@ extern "C" {
         Uplo: CBLAS_UPLO,
         TransA: CBLAS_TRANSPOSE,
         Diag: CBLAS_DIAG,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        B: *mut ::std::os::raw::c_void,
-        ldb: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        B: *mut ::core::ffi::c_void,
+        ldb: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3475,16 +3425,16 @@ extern "C" {
         Order: CBLAS_ORDER,
         Side: CBLAS_SIDE,
         Uplo: CBLAS_UPLO,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        B: *const ::std::os::raw::c_void,
-        ldb: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        C: *mut ::std::os::raw::c_void,
-        ldc: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        B: *const ::core::ffi::c_void,
+        ldb: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        C: *mut ::core::ffi::c_void,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3492,14 +3442,14 @@ extern "C" {
         Order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
         Trans: CBLAS_TRANSPOSE,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
         alpha: f32,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
         beta: f32,
-        C: *mut ::std::os::raw::c_void,
-        ldc: ::std::os::raw::c_int,
+        C: *mut ::core::ffi::c_void,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3507,16 +3457,16 @@ extern "C" {
         Order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
         Trans: CBLAS_TRANSPOSE,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        B: *const ::std::os::raw::c_void,
-        ldb: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        B: *const ::core::ffi::c_void,
+        ldb: ::core::ffi::c_int,
         beta: f32,
-        C: *mut ::std::os::raw::c_void,
-        ldc: ::std::os::raw::c_int,
+        C: *mut ::core::ffi::c_void,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3524,16 +3474,16 @@ extern "C" {
         Order: CBLAS_ORDER,
         Side: CBLAS_SIDE,
         Uplo: CBLAS_UPLO,
-        M: ::std::os::raw::c_int,
-        N: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        B: *const ::std::os::raw::c_void,
-        ldb: ::std::os::raw::c_int,
-        beta: *const ::std::os::raw::c_void,
-        C: *mut ::std::os::raw::c_void,
-        ldc: ::std::os::raw::c_int,
+        M: ::core::ffi::c_int,
+        N: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        B: *const ::core::ffi::c_void,
+        ldb: ::core::ffi::c_int,
+        beta: *const ::core::ffi::c_void,
+        C: *mut ::core::ffi::c_void,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3541,14 +3491,14 @@ extern "C" {
         Order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
         Trans: CBLAS_TRANSPOSE,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
         alpha: f64,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
         beta: f64,
-        C: *mut ::std::os::raw::c_void,
-        ldc: ::std::os::raw::c_int,
+        C: *mut ::core::ffi::c_void,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -3556,23 +3506,23 @@ extern "C" {
         Order: CBLAS_ORDER,
         Uplo: CBLAS_UPLO,
         Trans: CBLAS_TRANSPOSE,
-        N: ::std::os::raw::c_int,
-        K: ::std::os::raw::c_int,
-        alpha: *const ::std::os::raw::c_void,
-        A: *const ::std::os::raw::c_void,
-        lda: ::std::os::raw::c_int,
-        B: *const ::std::os::raw::c_void,
-        ldb: ::std::os::raw::c_int,
+        N: ::core::ffi::c_int,
+        K: ::core::ffi::c_int,
+        alpha: *const ::core::ffi::c_void,
+        A: *const ::core::ffi::c_void,
+        lda: ::core::ffi::c_int,
+        B: *const ::core::ffi::c_void,
+        ldb: ::core::ffi::c_int,
         beta: f64,
-        C: *mut ::std::os::raw::c_void,
-        ldc: ::std::os::raw::c_int,
+        C: *mut ::core::ffi::c_void,
+        ldc: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn cblas_xerbla(
-        p: ::std::os::raw::c_int,
-        rout: *const ::std::os::raw::c_char,
-        form: *const ::std::os::raw::c_char,
+        p: ::core::ffi::c_int,
+        rout: *const ::core::ffi::c_char,
+        form: *const ::core::ffi::c_char,
         ...
     );
 }
@@ -3589,7 +3539,7 @@ pub struct gsl_matrix_complex_long_double {
     pub tda: usize,
     pub data: *mut u128,
     pub block: *mut gsl_block_complex_long_double,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -3832,38 +3782,38 @@ extern "C" {
     pub fn gsl_matrix_complex_long_double_fread(
         stream: *mut FILE,
         m: *mut gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_fwrite(
         stream: *mut FILE,
         m: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_fscanf(
         stream: *mut FILE,
         m: *mut gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_fprintf(
         stream: *mut FILE,
         m: *const gsl_matrix_complex_long_double,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_memcpy(
         dest: *mut gsl_matrix_complex_long_double,
         src: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_swap(
         m1: *mut gsl_matrix_complex_long_double,
         m2: *mut gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_tricpy(
@@ -3871,39 +3821,39 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_complex_long_double,
         src: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_swap_rows(
         m: *mut gsl_matrix_complex_long_double,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_swap_columns(
         m: *mut gsl_matrix_complex_long_double,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_swap_rowcol(
         m: *mut gsl_matrix_complex_long_double,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_transpose(
         m: *mut gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_transpose_memcpy(
         dest: *mut gsl_matrix_complex_long_double,
         src: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_transpose_tricpy(
@@ -3911,121 +3861,121 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_complex_long_double,
         src: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_conjtrans_memcpy(
         dest: *mut gsl_matrix_complex_long_double,
         src: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_equal(
         a: *const gsl_matrix_complex_long_double,
         b: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_isnull(
         m: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_ispos(
         m: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_isneg(
         m: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_isnonneg(
         m: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_add(
         a: *mut gsl_matrix_complex_long_double,
         b: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_sub(
         a: *mut gsl_matrix_complex_long_double,
         b: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_mul_elements(
         a: *mut gsl_matrix_complex_long_double,
         b: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_div_elements(
         a: *mut gsl_matrix_complex_long_double,
         b: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_scale(
         a: *mut gsl_matrix_complex_long_double,
         x: gsl_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_scale_rows(
         a: *mut gsl_matrix_complex_long_double,
         x: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_scale_columns(
         a: *mut gsl_matrix_complex_long_double,
         x: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_add_constant(
         a: *mut gsl_matrix_complex_long_double,
         x: gsl_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_add_diagonal(
         a: *mut gsl_matrix_complex_long_double,
         x: gsl_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_get_row(
         v: *mut gsl_vector_complex_long_double,
         m: *const gsl_matrix_complex_long_double,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_get_col(
         v: *mut gsl_vector_complex_long_double,
         m: *const gsl_matrix_complex_long_double,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_set_row(
         m: *mut gsl_matrix_complex_long_double,
         i: usize,
         v: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_set_col(
         m: *mut gsl_matrix_complex_long_double,
         j: usize,
         v: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_long_double_get(
@@ -4070,7 +4020,7 @@ extern "C" {
         n1: usize,
         n2: usize,
         nzmax: usize,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_complex_long_double;
 }
 extern "C" {
@@ -4080,7 +4030,7 @@ extern "C" {
     pub fn gsl_spmatrix_complex_long_double_realloc(
         nzmax: usize,
         m: *mut gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_nnz(
@@ -4090,34 +4040,34 @@ extern "C" {
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_type(
         m: *const gsl_spmatrix_complex_long_double,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_set_zero(
         m: *mut gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_tree_rebuild(
         m: *mut gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_csc(
         dest: *mut gsl_spmatrix_complex_long_double,
         src: *const gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_csr(
         dest: *mut gsl_spmatrix_complex_long_double,
         src: *const gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_compress(
         src: *const gsl_spmatrix_complex_long_double,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_complex_long_double;
 }
 extern "C" {
@@ -4139,14 +4089,14 @@ extern "C" {
     pub fn gsl_spmatrix_complex_long_double_memcpy(
         dest: *mut gsl_spmatrix_complex_long_double,
         src: *const gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_fprintf(
         stream: *mut FILE,
         m: *const gsl_spmatrix_complex_long_double,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_fscanf(
@@ -4157,13 +4107,13 @@ extern "C" {
     pub fn gsl_spmatrix_complex_long_double_fwrite(
         stream: *mut FILE,
         m: *const gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_fread(
         stream: *mut FILE,
         m: *mut gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_get(
@@ -4178,7 +4128,7 @@ extern "C" {
         i: usize,
         j: usize,
         x: gsl_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_ptr(
@@ -4191,78 +4141,78 @@ extern "C" {
     pub fn gsl_spmatrix_complex_long_double_scale(
         m: *mut gsl_spmatrix_complex_long_double,
         x: gsl_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_scale_columns(
         m: *mut gsl_spmatrix_complex_long_double,
         x: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_scale_rows(
         m: *mut gsl_spmatrix_complex_long_double,
         x: *const gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_add(
         c: *mut gsl_spmatrix_complex_long_double,
         a: *const gsl_spmatrix_complex_long_double,
         b: *const gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_dense_add(
         a: *mut gsl_matrix_complex_long_double,
         b: *const gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_dense_sub(
         a: *mut gsl_matrix_complex_long_double,
         b: *const gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_d2sp(
         T: *mut gsl_spmatrix_complex_long_double,
         A: *const gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_sp2d(
         A: *mut gsl_matrix_complex_long_double,
         S: *const gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_add_to_dense(
         a: *mut gsl_matrix_complex_long_double,
         b: *const gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_equal(
         a: *const gsl_spmatrix_complex_long_double,
         b: *const gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_transpose(
         m: *mut gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_transpose2(
         m: *mut gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_long_double_transpose_memcpy(
         dest: *mut gsl_spmatrix_complex_long_double,
         src: *const gsl_spmatrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -4278,20 +4228,20 @@ extern "C" {
     pub fn gsl_block_free(b: *mut gsl_block);
 }
 extern "C" {
-    pub fn gsl_block_fread(stream: *mut FILE, b: *mut gsl_block) -> ::std::os::raw::c_int;
+    pub fn gsl_block_fread(stream: *mut FILE, b: *mut gsl_block) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_block_fwrite(stream: *mut FILE, b: *const gsl_block) -> ::std::os::raw::c_int;
+    pub fn gsl_block_fwrite(stream: *mut FILE, b: *const gsl_block) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_block_fscanf(stream: *mut FILE, b: *mut gsl_block) -> ::std::os::raw::c_int;
+    pub fn gsl_block_fscanf(stream: *mut FILE, b: *mut gsl_block) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_fprintf(
         stream: *mut FILE,
         b: *const gsl_block,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_raw_fread(
@@ -4299,7 +4249,7 @@ extern "C" {
         b: *mut f64,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_raw_fwrite(
@@ -4307,7 +4257,7 @@ extern "C" {
         b: *const f64,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_raw_fscanf(
@@ -4315,7 +4265,7 @@ extern "C" {
         b: *mut f64,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_raw_fprintf(
@@ -4323,8 +4273,8 @@ extern "C" {
         b: *const f64,
         n: usize,
         stride: usize,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_size(b: *const gsl_block) -> usize;
@@ -4339,7 +4289,7 @@ pub struct gsl_vector {
     pub stride: usize,
     pub data: *mut f64,
     pub block: *mut gsl_block,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -4431,42 +4381,35 @@ extern "C" {
     pub fn gsl_vector_set_all(v: *mut gsl_vector, x: f64);
 }
 extern "C" {
-    pub fn gsl_vector_set_basis(v: *mut gsl_vector, i: usize) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_set_basis(v: *mut gsl_vector, i: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_fread(stream: *mut FILE, v: *mut gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_fread(stream: *mut FILE, v: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_fwrite(stream: *mut FILE, v: *const gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_fwrite(stream: *mut FILE, v: *const gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_fscanf(stream: *mut FILE, v: *mut gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_fscanf(stream: *mut FILE, v: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_fprintf(
         stream: *mut FILE,
         v: *const gsl_vector,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_memcpy(
-        dest: *mut gsl_vector,
-        src: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_memcpy(dest: *mut gsl_vector, src: *const gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_reverse(v: *mut gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_reverse(v: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_swap(v: *mut gsl_vector, w: *mut gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_swap(v: *mut gsl_vector, w: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_swap_elements(
-        v: *mut gsl_vector,
-        i: usize,
-        j: usize,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_swap_elements(v: *mut gsl_vector, i: usize, j: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_max(v: *const gsl_vector) -> f64;
@@ -4487,22 +4430,22 @@ extern "C" {
     pub fn gsl_vector_minmax_index(v: *const gsl_vector, imin: *mut usize, imax: *mut usize);
 }
 extern "C" {
-    pub fn gsl_vector_add(a: *mut gsl_vector, b: *const gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_add(a: *mut gsl_vector, b: *const gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_sub(a: *mut gsl_vector, b: *const gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_sub(a: *mut gsl_vector, b: *const gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_mul(a: *mut gsl_vector, b: *const gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_mul(a: *mut gsl_vector, b: *const gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_div(a: *mut gsl_vector, b: *const gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_div(a: *mut gsl_vector, b: *const gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_scale(a: *mut gsl_vector, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_scale(a: *mut gsl_vector, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_add_constant(a: *mut gsl_vector, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_add_constant(a: *mut gsl_vector, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -4512,7 +4455,7 @@ extern "C" {
         x: *const gsl_vector,
         beta: f64,
         y: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -4520,19 +4463,19 @@ extern "C" {
     pub fn gsl_vector_sum(a: *const gsl_vector) -> f64;
 }
 extern "C" {
-    pub fn gsl_vector_equal(u: *const gsl_vector, v: *const gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_equal(u: *const gsl_vector, v: *const gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_isnull(v: *const gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_isnull(v: *const gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ispos(v: *const gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ispos(v: *const gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_isneg(v: *const gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_isneg(v: *const gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_isnonneg(v: *const gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_isnonneg(v: *const gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_get(v: *const gsl_vector, i: usize) -> f64;
@@ -4563,26 +4506,26 @@ extern "C" {
     pub fn gsl_block_complex_fread(
         stream: *mut FILE,
         b: *mut gsl_block_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_fwrite(
         stream: *mut FILE,
         b: *const gsl_block_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_fscanf(
         stream: *mut FILE,
         b: *mut gsl_block_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_fprintf(
         stream: *mut FILE,
         b: *const gsl_block_complex,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_raw_fread(
@@ -4590,7 +4533,7 @@ extern "C" {
         b: *mut f64,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_raw_fwrite(
@@ -4598,7 +4541,7 @@ extern "C" {
         b: *const f64,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_raw_fscanf(
@@ -4606,7 +4549,7 @@ extern "C" {
         b: *mut f64,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_raw_fprintf(
@@ -4614,8 +4557,8 @@ extern "C" {
         b: *const f64,
         n: usize,
         stride: usize,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_size(b: *const gsl_block_complex) -> usize;
@@ -4630,7 +4573,7 @@ pub struct gsl_vector_complex {
     pub stride: usize,
     pub data: *mut f64,
     pub block: *mut gsl_block_complex,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -4741,111 +4684,109 @@ extern "C" {
     pub fn gsl_vector_complex_set_all(v: *mut gsl_vector_complex, z: gsl_complex);
 }
 extern "C" {
-    pub fn gsl_vector_complex_set_basis(
-        v: *mut gsl_vector_complex,
-        i: usize,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_complex_set_basis(v: *mut gsl_vector_complex, i: usize)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_fread(
         stream: *mut FILE,
         v: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_fwrite(
         stream: *mut FILE,
         v: *const gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_fscanf(
         stream: *mut FILE,
         v: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_fprintf(
         stream: *mut FILE,
         v: *const gsl_vector_complex,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_memcpy(
         dest: *mut gsl_vector_complex,
         src: *const gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_complex_reverse(v: *mut gsl_vector_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_complex_reverse(v: *mut gsl_vector_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_swap(
         v: *mut gsl_vector_complex,
         w: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_swap_elements(
         v: *mut gsl_vector_complex,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_equal(
         u: *const gsl_vector_complex,
         v: *const gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_complex_isnull(v: *const gsl_vector_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_complex_isnull(v: *const gsl_vector_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_complex_ispos(v: *const gsl_vector_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_complex_ispos(v: *const gsl_vector_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_complex_isneg(v: *const gsl_vector_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_complex_isneg(v: *const gsl_vector_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_complex_isnonneg(v: *const gsl_vector_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_complex_isnonneg(v: *const gsl_vector_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_add(
         a: *mut gsl_vector_complex,
         b: *const gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_sub(
         a: *mut gsl_vector_complex,
         b: *const gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_mul(
         a: *mut gsl_vector_complex,
         b: *const gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_div(
         a: *mut gsl_vector_complex,
         b: *const gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_scale(
         a: *mut gsl_vector_complex,
         x: gsl_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_add_constant(
         a: *mut gsl_vector_complex,
         x: gsl_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_axpby(
@@ -4853,7 +4794,7 @@ extern "C" {
         x: *const gsl_vector_complex,
         beta: gsl_complex,
         y: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_get(v: *const gsl_vector_complex, i: usize) -> gsl_complex;
@@ -4878,7 +4819,7 @@ pub struct gsl_matrix_complex {
     pub tda: usize,
     pub data: *mut f64,
     pub block: *mut gsl_block_complex,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -5108,38 +5049,38 @@ extern "C" {
     pub fn gsl_matrix_complex_fread(
         stream: *mut FILE,
         m: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_fwrite(
         stream: *mut FILE,
         m: *const gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_fscanf(
         stream: *mut FILE,
         m: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_fprintf(
         stream: *mut FILE,
         m: *const gsl_matrix_complex,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_memcpy(
         dest: *mut gsl_matrix_complex,
         src: *const gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_swap(
         m1: *mut gsl_matrix_complex,
         m2: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_tricpy(
@@ -5147,37 +5088,37 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_complex,
         src: *const gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_swap_rows(
         m: *mut gsl_matrix_complex,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_swap_columns(
         m: *mut gsl_matrix_complex,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_swap_rowcol(
         m: *mut gsl_matrix_complex,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_complex_transpose(m: *mut gsl_matrix_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_complex_transpose(m: *mut gsl_matrix_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_transpose_memcpy(
         dest: *mut gsl_matrix_complex,
         src: *const gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_transpose_tricpy(
@@ -5185,7 +5126,7 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_complex,
         src: *const gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -5193,107 +5134,107 @@ extern "C" {
     pub fn gsl_matrix_complex_conjtrans_memcpy(
         dest: *mut gsl_matrix_complex,
         src: *const gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_equal(
         a: *const gsl_matrix_complex,
         b: *const gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_complex_isnull(m: *const gsl_matrix_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_complex_isnull(m: *const gsl_matrix_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_complex_ispos(m: *const gsl_matrix_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_complex_ispos(m: *const gsl_matrix_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_complex_isneg(m: *const gsl_matrix_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_complex_isneg(m: *const gsl_matrix_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_complex_isnonneg(m: *const gsl_matrix_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_complex_isnonneg(m: *const gsl_matrix_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_add(
         a: *mut gsl_matrix_complex,
         b: *const gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_sub(
         a: *mut gsl_matrix_complex,
         b: *const gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_mul_elements(
         a: *mut gsl_matrix_complex,
         b: *const gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_div_elements(
         a: *mut gsl_matrix_complex,
         b: *const gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_scale(
         a: *mut gsl_matrix_complex,
         x: gsl_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_scale_rows(
         a: *mut gsl_matrix_complex,
         x: *const gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_scale_columns(
         a: *mut gsl_matrix_complex,
         x: *const gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_add_constant(
         a: *mut gsl_matrix_complex,
         x: gsl_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_add_diagonal(
         a: *mut gsl_matrix_complex,
         x: gsl_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_get_row(
         v: *mut gsl_vector_complex,
         m: *const gsl_matrix_complex,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_get_col(
         v: *mut gsl_vector_complex,
         m: *const gsl_matrix_complex,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_set_row(
         m: *mut gsl_matrix_complex,
         i: usize,
         v: *const gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_set_col(
         m: *mut gsl_matrix_complex,
         j: usize,
         v: *const gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_get(m: *const gsl_matrix_complex, i: usize, j: usize) -> gsl_complex;
@@ -5326,7 +5267,7 @@ extern "C" {
         n1: usize,
         n2: usize,
         nzmax: usize,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_complex;
 }
 extern "C" {
@@ -5336,39 +5277,36 @@ extern "C" {
     pub fn gsl_spmatrix_complex_realloc(
         nzmax: usize,
         m: *mut gsl_spmatrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_nnz(m: *const gsl_spmatrix_complex) -> usize;
 }
 extern "C" {
-    pub fn gsl_spmatrix_complex_type(
-        m: *const gsl_spmatrix_complex,
-    ) -> *const ::std::os::raw::c_char;
+    pub fn gsl_spmatrix_complex_type(m: *const gsl_spmatrix_complex) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_spmatrix_complex_set_zero(m: *mut gsl_spmatrix_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_complex_set_zero(m: *mut gsl_spmatrix_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_complex_tree_rebuild(m: *mut gsl_spmatrix_complex)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_complex_tree_rebuild(m: *mut gsl_spmatrix_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_csc(
         dest: *mut gsl_spmatrix_complex,
         src: *const gsl_spmatrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_csr(
         dest: *mut gsl_spmatrix_complex,
         src: *const gsl_spmatrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_compress(
         src: *const gsl_spmatrix_complex,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_complex;
 }
 extern "C" {
@@ -5386,14 +5324,14 @@ extern "C" {
     pub fn gsl_spmatrix_complex_memcpy(
         dest: *mut gsl_spmatrix_complex,
         src: *const gsl_spmatrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_fprintf(
         stream: *mut FILE,
         m: *const gsl_spmatrix_complex,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_fscanf(stream: *mut FILE) -> *mut gsl_spmatrix_complex;
@@ -5402,13 +5340,13 @@ extern "C" {
     pub fn gsl_spmatrix_complex_fwrite(
         stream: *mut FILE,
         m: *const gsl_spmatrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_fread(
         stream: *mut FILE,
         m: *mut gsl_spmatrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_get(
@@ -5423,7 +5361,7 @@ extern "C" {
         i: usize,
         j: usize,
         x: gsl_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_ptr(
@@ -5436,74 +5374,74 @@ extern "C" {
     pub fn gsl_spmatrix_complex_scale(
         m: *mut gsl_spmatrix_complex,
         x: gsl_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_scale_columns(
         m: *mut gsl_spmatrix_complex,
         x: *const gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_scale_rows(
         m: *mut gsl_spmatrix_complex,
         x: *const gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_add(
         c: *mut gsl_spmatrix_complex,
         a: *const gsl_spmatrix_complex,
         b: *const gsl_spmatrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_dense_add(
         a: *mut gsl_matrix_complex,
         b: *const gsl_spmatrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_dense_sub(
         a: *mut gsl_matrix_complex,
         b: *const gsl_spmatrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_d2sp(
         T: *mut gsl_spmatrix_complex,
         A: *const gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_sp2d(
         A: *mut gsl_matrix_complex,
         S: *const gsl_spmatrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_add_to_dense(
         a: *mut gsl_matrix_complex,
         b: *const gsl_spmatrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_equal(
         a: *const gsl_spmatrix_complex,
         b: *const gsl_spmatrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_complex_transpose(m: *mut gsl_spmatrix_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_complex_transpose(m: *mut gsl_spmatrix_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_complex_transpose2(m: *mut gsl_spmatrix_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_complex_transpose2(m: *mut gsl_spmatrix_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_transpose_memcpy(
         dest: *mut gsl_spmatrix_complex,
         src: *const gsl_spmatrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -5519,29 +5457,24 @@ extern "C" {
     pub fn gsl_block_float_free(b: *mut gsl_block_float);
 }
 extern "C" {
-    pub fn gsl_block_float_fread(
-        stream: *mut FILE,
-        b: *mut gsl_block_float,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_float_fread(stream: *mut FILE, b: *mut gsl_block_float) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_float_fwrite(
         stream: *mut FILE,
         b: *const gsl_block_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_block_float_fscanf(
-        stream: *mut FILE,
-        b: *mut gsl_block_float,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_float_fscanf(stream: *mut FILE, b: *mut gsl_block_float)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_float_fprintf(
         stream: *mut FILE,
         b: *const gsl_block_float,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_float_raw_fread(
@@ -5549,7 +5482,7 @@ extern "C" {
         b: *mut f32,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_float_raw_fwrite(
@@ -5557,7 +5490,7 @@ extern "C" {
         b: *const f32,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_float_raw_fscanf(
@@ -5565,7 +5498,7 @@ extern "C" {
         b: *mut f32,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_float_raw_fprintf(
@@ -5573,8 +5506,8 @@ extern "C" {
         b: *const f32,
         n: usize,
         stride: usize,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_float_size(b: *const gsl_block_float) -> usize;
@@ -5589,7 +5522,7 @@ pub struct gsl_vector_float {
     pub stride: usize,
     pub data: *mut f32,
     pub block: *mut gsl_block_float,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -5688,54 +5621,54 @@ extern "C" {
     pub fn gsl_vector_float_set_all(v: *mut gsl_vector_float, x: f32);
 }
 extern "C" {
-    pub fn gsl_vector_float_set_basis(v: *mut gsl_vector_float, i: usize) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_float_set_basis(v: *mut gsl_vector_float, i: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_float_fread(
         stream: *mut FILE,
         v: *mut gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_float_fwrite(
         stream: *mut FILE,
         v: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_float_fscanf(
         stream: *mut FILE,
         v: *mut gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_float_fprintf(
         stream: *mut FILE,
         v: *const gsl_vector_float,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_float_memcpy(
         dest: *mut gsl_vector_float,
         src: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_float_reverse(v: *mut gsl_vector_float) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_float_reverse(v: *mut gsl_vector_float) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_float_swap(
         v: *mut gsl_vector_float,
         w: *mut gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_float_swap_elements(
         v: *mut gsl_vector_float,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_float_max(v: *const gsl_vector_float) -> f32;
@@ -5767,32 +5700,31 @@ extern "C" {
     pub fn gsl_vector_float_add(
         a: *mut gsl_vector_float,
         b: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_float_sub(
         a: *mut gsl_vector_float,
         b: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_float_mul(
         a: *mut gsl_vector_float,
         b: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_float_div(
         a: *mut gsl_vector_float,
         b: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_float_scale(a: *mut gsl_vector_float, x: f32) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_float_scale(a: *mut gsl_vector_float, x: f32) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_float_add_constant(a: *mut gsl_vector_float, x: f64)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_vector_float_add_constant(a: *mut gsl_vector_float, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_float_axpby(
@@ -5800,7 +5732,7 @@ extern "C" {
         x: *const gsl_vector_float,
         beta: f32,
         y: *mut gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_float_sum(a: *const gsl_vector_float) -> f32;
@@ -5809,19 +5741,19 @@ extern "C" {
     pub fn gsl_vector_float_equal(
         u: *const gsl_vector_float,
         v: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_float_isnull(v: *const gsl_vector_float) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_float_isnull(v: *const gsl_vector_float) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_float_ispos(v: *const gsl_vector_float) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_float_ispos(v: *const gsl_vector_float) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_float_isneg(v: *const gsl_vector_float) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_float_isneg(v: *const gsl_vector_float) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_float_isnonneg(v: *const gsl_vector_float) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_float_isnonneg(v: *const gsl_vector_float) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_float_get(v: *const gsl_vector_float, i: usize) -> f32;
@@ -5852,26 +5784,26 @@ extern "C" {
     pub fn gsl_block_complex_float_fread(
         stream: *mut FILE,
         b: *mut gsl_block_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_float_fwrite(
         stream: *mut FILE,
         b: *const gsl_block_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_float_fscanf(
         stream: *mut FILE,
         b: *mut gsl_block_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_float_fprintf(
         stream: *mut FILE,
         b: *const gsl_block_complex_float,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_float_raw_fread(
@@ -5879,7 +5811,7 @@ extern "C" {
         b: *mut f32,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_float_raw_fwrite(
@@ -5887,7 +5819,7 @@ extern "C" {
         b: *const f32,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_float_raw_fscanf(
@@ -5895,7 +5827,7 @@ extern "C" {
         b: *mut f32,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_float_raw_fprintf(
@@ -5903,8 +5835,8 @@ extern "C" {
         b: *const f32,
         n: usize,
         stride: usize,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_complex_float_size(b: *const gsl_block_complex_float) -> usize;
@@ -5919,7 +5851,7 @@ pub struct gsl_vector_complex_float {
     pub stride: usize,
     pub data: *mut f32,
     pub block: *mut gsl_block_complex_float,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -6044,118 +5976,115 @@ extern "C" {
     pub fn gsl_vector_complex_float_set_basis(
         v: *mut gsl_vector_complex_float,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_fread(
         stream: *mut FILE,
         v: *mut gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_fwrite(
         stream: *mut FILE,
         v: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_fscanf(
         stream: *mut FILE,
         v: *mut gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_fprintf(
         stream: *mut FILE,
         v: *const gsl_vector_complex_float,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_memcpy(
         dest: *mut gsl_vector_complex_float,
         src: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_complex_float_reverse(
-        v: *mut gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_complex_float_reverse(v: *mut gsl_vector_complex_float)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_swap(
         v: *mut gsl_vector_complex_float,
         w: *mut gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_swap_elements(
         v: *mut gsl_vector_complex_float,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_equal(
         u: *const gsl_vector_complex_float,
         v: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_isnull(
         v: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_complex_float_ispos(
-        v: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_complex_float_ispos(v: *const gsl_vector_complex_float)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_complex_float_isneg(
-        v: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_complex_float_isneg(v: *const gsl_vector_complex_float)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_isnonneg(
         v: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_add(
         a: *mut gsl_vector_complex_float,
         b: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_sub(
         a: *mut gsl_vector_complex_float,
         b: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_mul(
         a: *mut gsl_vector_complex_float,
         b: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_div(
         a: *mut gsl_vector_complex_float,
         b: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_scale(
         a: *mut gsl_vector_complex_float,
         x: gsl_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_add_constant(
         a: *mut gsl_vector_complex_float,
         x: gsl_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_axpby(
@@ -6163,7 +6092,7 @@ extern "C" {
         x: *const gsl_vector_complex_float,
         beta: gsl_complex_float,
         y: *mut gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_complex_float_get(
@@ -6198,7 +6127,7 @@ pub struct gsl_matrix_complex_float {
     pub tda: usize,
     pub data: *mut f32,
     pub block: *mut gsl_block_complex_float,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -6432,38 +6361,38 @@ extern "C" {
     pub fn gsl_matrix_complex_float_fread(
         stream: *mut FILE,
         m: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_fwrite(
         stream: *mut FILE,
         m: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_fscanf(
         stream: *mut FILE,
         m: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_fprintf(
         stream: *mut FILE,
         m: *const gsl_matrix_complex_float,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_memcpy(
         dest: *mut gsl_matrix_complex_float,
         src: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_swap(
         m1: *mut gsl_matrix_complex_float,
         m2: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_tricpy(
@@ -6471,39 +6400,39 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_complex_float,
         src: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_swap_rows(
         m: *mut gsl_matrix_complex_float,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_swap_columns(
         m: *mut gsl_matrix_complex_float,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_swap_rowcol(
         m: *mut gsl_matrix_complex_float,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_transpose(
         m: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_transpose_memcpy(
         dest: *mut gsl_matrix_complex_float,
         src: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_transpose_tricpy(
@@ -6511,121 +6440,119 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_complex_float,
         src: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_conjtrans_memcpy(
         dest: *mut gsl_matrix_complex_float,
         src: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_equal(
         a: *const gsl_matrix_complex_float,
         b: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_isnull(
         m: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_complex_float_ispos(
-        m: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_complex_float_ispos(m: *const gsl_matrix_complex_float)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_complex_float_isneg(
-        m: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_complex_float_isneg(m: *const gsl_matrix_complex_float)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_isnonneg(
         m: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_add(
         a: *mut gsl_matrix_complex_float,
         b: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_sub(
         a: *mut gsl_matrix_complex_float,
         b: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_mul_elements(
         a: *mut gsl_matrix_complex_float,
         b: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_div_elements(
         a: *mut gsl_matrix_complex_float,
         b: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_scale(
         a: *mut gsl_matrix_complex_float,
         x: gsl_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_scale_rows(
         a: *mut gsl_matrix_complex_float,
         x: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_scale_columns(
         a: *mut gsl_matrix_complex_float,
         x: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_add_constant(
         a: *mut gsl_matrix_complex_float,
         x: gsl_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_add_diagonal(
         a: *mut gsl_matrix_complex_float,
         x: gsl_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_get_row(
         v: *mut gsl_vector_complex_float,
         m: *const gsl_matrix_complex_float,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_get_col(
         v: *mut gsl_vector_complex_float,
         m: *const gsl_matrix_complex_float,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_set_row(
         m: *mut gsl_matrix_complex_float,
         i: usize,
         v: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_set_col(
         m: *mut gsl_matrix_complex_float,
         j: usize,
         v: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_complex_float_get(
@@ -6670,7 +6597,7 @@ extern "C" {
         n1: usize,
         n2: usize,
         nzmax: usize,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_complex_float;
 }
 extern "C" {
@@ -6680,7 +6607,7 @@ extern "C" {
     pub fn gsl_spmatrix_complex_float_realloc(
         nzmax: usize,
         m: *mut gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_nnz(m: *const gsl_spmatrix_complex_float) -> usize;
@@ -6688,34 +6615,34 @@ extern "C" {
 extern "C" {
     pub fn gsl_spmatrix_complex_float_type(
         m: *const gsl_spmatrix_complex_float,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_set_zero(
         m: *mut gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_tree_rebuild(
         m: *mut gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_csc(
         dest: *mut gsl_spmatrix_complex_float,
         src: *const gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_csr(
         dest: *mut gsl_spmatrix_complex_float,
         src: *const gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_compress(
         src: *const gsl_spmatrix_complex_float,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_complex_float;
 }
 extern "C" {
@@ -6737,14 +6664,14 @@ extern "C" {
     pub fn gsl_spmatrix_complex_float_memcpy(
         dest: *mut gsl_spmatrix_complex_float,
         src: *const gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_fprintf(
         stream: *mut FILE,
         m: *const gsl_spmatrix_complex_float,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_fscanf(stream: *mut FILE) -> *mut gsl_spmatrix_complex_float;
@@ -6753,13 +6680,13 @@ extern "C" {
     pub fn gsl_spmatrix_complex_float_fwrite(
         stream: *mut FILE,
         m: *const gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_fread(
         stream: *mut FILE,
         m: *mut gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_get(
@@ -6774,7 +6701,7 @@ extern "C" {
         i: usize,
         j: usize,
         x: gsl_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_ptr(
@@ -6787,78 +6714,78 @@ extern "C" {
     pub fn gsl_spmatrix_complex_float_scale(
         m: *mut gsl_spmatrix_complex_float,
         x: gsl_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_scale_columns(
         m: *mut gsl_spmatrix_complex_float,
         x: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_scale_rows(
         m: *mut gsl_spmatrix_complex_float,
         x: *const gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_add(
         c: *mut gsl_spmatrix_complex_float,
         a: *const gsl_spmatrix_complex_float,
         b: *const gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_dense_add(
         a: *mut gsl_matrix_complex_float,
         b: *const gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_dense_sub(
         a: *mut gsl_matrix_complex_float,
         b: *const gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_d2sp(
         T: *mut gsl_spmatrix_complex_float,
         A: *const gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_sp2d(
         A: *mut gsl_matrix_complex_float,
         S: *const gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_add_to_dense(
         a: *mut gsl_matrix_complex_float,
         b: *const gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_equal(
         a: *const gsl_spmatrix_complex_float,
         b: *const gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_transpose(
         m: *mut gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_transpose2(
         m: *mut gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_complex_float_transpose_memcpy(
         dest: *mut gsl_spmatrix_complex_float,
         src: *const gsl_spmatrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -6868,7 +6795,7 @@ pub struct gsl_matrix_long_double {
     pub tda: usize,
     pub data: *mut u128,
     pub block: *mut gsl_block_long_double,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -7102,38 +7029,38 @@ extern "C" {
     pub fn gsl_matrix_long_double_fread(
         stream: *mut FILE,
         m: *mut gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_fwrite(
         stream: *mut FILE,
         m: *const gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_fscanf(
         stream: *mut FILE,
         m: *mut gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_fprintf(
         stream: *mut FILE,
         m: *const gsl_matrix_long_double,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_memcpy(
         dest: *mut gsl_matrix_long_double,
         src: *const gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_swap(
         m1: *mut gsl_matrix_long_double,
         m2: *mut gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_tricpy(
@@ -7141,39 +7068,37 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_long_double,
         src: *const gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_swap_rows(
         m: *mut gsl_matrix_long_double,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_swap_columns(
         m: *mut gsl_matrix_long_double,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_swap_rowcol(
         m: *mut gsl_matrix_long_double,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_double_transpose(
-        m: *mut gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_double_transpose(m: *mut gsl_matrix_long_double) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_transpose_memcpy(
         dest: *mut gsl_matrix_long_double,
         src: *const gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_transpose_tricpy(
@@ -7181,7 +7106,7 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_long_double,
         src: *const gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_max(m: *const gsl_matrix_long_double) -> u128;
@@ -7223,22 +7148,19 @@ extern "C" {
     pub fn gsl_matrix_long_double_equal(
         a: *const gsl_matrix_long_double,
         b: *const gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_double_isnull(m: *const gsl_matrix_long_double)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_double_isnull(m: *const gsl_matrix_long_double) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_double_ispos(m: *const gsl_matrix_long_double) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_double_ispos(m: *const gsl_matrix_long_double) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_double_isneg(m: *const gsl_matrix_long_double) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_double_isneg(m: *const gsl_matrix_long_double) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_double_isnonneg(
-        m: *const gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_double_isnonneg(m: *const gsl_matrix_long_double) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_norm1(m: *const gsl_matrix_long_double) -> u128;
@@ -7247,83 +7169,83 @@ extern "C" {
     pub fn gsl_matrix_long_double_add(
         a: *mut gsl_matrix_long_double,
         b: *const gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_sub(
         a: *mut gsl_matrix_long_double,
         b: *const gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_mul_elements(
         a: *mut gsl_matrix_long_double,
         b: *const gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_div_elements(
         a: *mut gsl_matrix_long_double,
         b: *const gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_scale(
         a: *mut gsl_matrix_long_double,
         x: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_scale_rows(
         a: *mut gsl_matrix_long_double,
         x: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_scale_columns(
         a: *mut gsl_matrix_long_double,
         x: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_add_constant(
         a: *mut gsl_matrix_long_double,
         x: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_add_diagonal(
         a: *mut gsl_matrix_long_double,
         x: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_get_row(
         v: *mut gsl_vector_long_double,
         m: *const gsl_matrix_long_double,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_get_col(
         v: *mut gsl_vector_long_double,
         m: *const gsl_matrix_long_double,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_set_row(
         m: *mut gsl_matrix_long_double,
         i: usize,
         v: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_set_col(
         m: *mut gsl_matrix_long_double,
         j: usize,
         v: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_double_get(m: *const gsl_matrix_long_double, i: usize, j: usize)
@@ -7357,7 +7279,7 @@ extern "C" {
         n1: usize,
         n2: usize,
         nzmax: usize,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_long_double;
 }
 extern "C" {
@@ -7367,7 +7289,7 @@ extern "C" {
     pub fn gsl_spmatrix_long_double_realloc(
         nzmax: usize,
         m: *mut gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_nnz(m: *const gsl_spmatrix_long_double) -> usize;
@@ -7375,34 +7297,34 @@ extern "C" {
 extern "C" {
     pub fn gsl_spmatrix_long_double_type(
         m: *const gsl_spmatrix_long_double,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_set_zero(
         m: *mut gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_tree_rebuild(
         m: *mut gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_csc(
         dest: *mut gsl_spmatrix_long_double,
         src: *const gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_csr(
         dest: *mut gsl_spmatrix_long_double,
         src: *const gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_compress(
         src: *const gsl_spmatrix_long_double,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_long_double;
 }
 extern "C" {
@@ -7424,14 +7346,14 @@ extern "C" {
     pub fn gsl_spmatrix_long_double_memcpy(
         dest: *mut gsl_spmatrix_long_double,
         src: *const gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_fprintf(
         stream: *mut FILE,
         m: *const gsl_spmatrix_long_double,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_fscanf(stream: *mut FILE) -> *mut gsl_spmatrix_long_double;
@@ -7440,13 +7362,13 @@ extern "C" {
     pub fn gsl_spmatrix_long_double_fwrite(
         stream: *mut FILE,
         m: *const gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_fread(
         stream: *mut FILE,
         m: *mut gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_get(
@@ -7461,7 +7383,7 @@ extern "C" {
         i: usize,
         j: usize,
         x: u128,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_ptr(
@@ -7475,75 +7397,75 @@ extern "C" {
         m: *const gsl_spmatrix_long_double,
         min_out: *mut u128,
         max_out: *mut u128,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_min_index(
         m: *const gsl_spmatrix_long_double,
         imin_out: *mut usize,
         jmin_out: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_scale(
         m: *mut gsl_spmatrix_long_double,
         x: u128,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_scale_columns(
         m: *mut gsl_spmatrix_long_double,
         x: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_scale_rows(
         m: *mut gsl_spmatrix_long_double,
         x: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_add(
         c: *mut gsl_spmatrix_long_double,
         a: *const gsl_spmatrix_long_double,
         b: *const gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_dense_add(
         a: *mut gsl_matrix_long_double,
         b: *const gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_dense_sub(
         a: *mut gsl_matrix_long_double,
         b: *const gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_d2sp(
         T: *mut gsl_spmatrix_long_double,
         A: *const gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_sp2d(
         A: *mut gsl_matrix_long_double,
         S: *const gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_add_to_dense(
         a: *mut gsl_matrix_long_double,
         b: *const gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_equal(
         a: *const gsl_spmatrix_long_double,
         b: *const gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_norm1(a: *const gsl_spmatrix_long_double) -> u128;
@@ -7551,18 +7473,18 @@ extern "C" {
 extern "C" {
     pub fn gsl_spmatrix_long_double_transpose(
         m: *mut gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_transpose2(
         m: *mut gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_double_transpose_memcpy(
         dest: *mut gsl_spmatrix_long_double,
         src: *const gsl_spmatrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -7572,7 +7494,7 @@ pub struct gsl_matrix {
     pub tda: usize,
     pub data: *mut f64,
     pub block: *mut gsl_block,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -7762,29 +7684,26 @@ extern "C" {
     pub fn gsl_matrix_set_all(m: *mut gsl_matrix, x: f64);
 }
 extern "C" {
-    pub fn gsl_matrix_fread(stream: *mut FILE, m: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_fread(stream: *mut FILE, m: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_fwrite(stream: *mut FILE, m: *const gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_fwrite(stream: *mut FILE, m: *const gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_fscanf(stream: *mut FILE, m: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_fscanf(stream: *mut FILE, m: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_fprintf(
         stream: *mut FILE,
         m: *const gsl_matrix,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_memcpy(
-        dest: *mut gsl_matrix,
-        src: *const gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_memcpy(dest: *mut gsl_matrix, src: *const gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_swap(m1: *mut gsl_matrix, m2: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_swap(m1: *mut gsl_matrix, m2: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_tricpy(
@@ -7792,26 +7711,25 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix,
         src: *const gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_swap_rows(m: *mut gsl_matrix, i: usize, j: usize) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_swap_rows(m: *mut gsl_matrix, i: usize, j: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_swap_columns(m: *mut gsl_matrix, i: usize, j: usize)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_swap_columns(m: *mut gsl_matrix, i: usize, j: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_swap_rowcol(m: *mut gsl_matrix, i: usize, j: usize) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_swap_rowcol(m: *mut gsl_matrix, i: usize, j: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_transpose(m: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_transpose(m: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_transpose_memcpy(
         dest: *mut gsl_matrix,
         src: *const gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_transpose_tricpy(
@@ -7819,7 +7737,7 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix,
         src: *const gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_max(m: *const gsl_matrix) -> f64;
@@ -7846,19 +7764,19 @@ extern "C" {
     );
 }
 extern "C" {
-    pub fn gsl_matrix_equal(a: *const gsl_matrix, b: *const gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_equal(a: *const gsl_matrix, b: *const gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_isnull(m: *const gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_isnull(m: *const gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ispos(m: *const gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ispos(m: *const gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_isneg(m: *const gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_isneg(m: *const gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_isnonneg(m: *const gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_isnonneg(m: *const gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -7866,73 +7784,64 @@ extern "C" {
     pub fn gsl_matrix_norm1(m: *const gsl_matrix) -> f64;
 }
 extern "C" {
-    pub fn gsl_matrix_add(a: *mut gsl_matrix, b: *const gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_add(a: *mut gsl_matrix, b: *const gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_sub(a: *mut gsl_matrix, b: *const gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_sub(a: *mut gsl_matrix, b: *const gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_mul_elements(
-        a: *mut gsl_matrix,
-        b: *const gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_mul_elements(a: *mut gsl_matrix, b: *const gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_div_elements(
-        a: *mut gsl_matrix,
-        b: *const gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_div_elements(a: *mut gsl_matrix, b: *const gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_scale(a: *mut gsl_matrix, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_scale(a: *mut gsl_matrix, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
-    pub fn gsl_matrix_scale_rows(a: *mut gsl_matrix, x: *const gsl_vector)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_scale_rows(a: *mut gsl_matrix, x: *const gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
-    pub fn gsl_matrix_scale_columns(
-        a: *mut gsl_matrix,
-        x: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_scale_columns(a: *mut gsl_matrix, x: *const gsl_vector)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_add_constant(a: *mut gsl_matrix, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_add_constant(a: *mut gsl_matrix, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_add_diagonal(a: *mut gsl_matrix, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_add_diagonal(a: *mut gsl_matrix, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_get_row(
         v: *mut gsl_vector,
         m: *const gsl_matrix,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_get_col(
         v: *mut gsl_vector,
         m: *const gsl_matrix,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_set_row(
         m: *mut gsl_matrix,
         i: usize,
         v: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_set_col(
         m: *mut gsl_matrix,
         j: usize,
         v: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_get(m: *const gsl_matrix, i: usize, j: usize) -> f64;
@@ -7957,43 +7866,43 @@ extern "C" {
         n1: usize,
         n2: usize,
         nzmax: usize,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix;
 }
 extern "C" {
     pub fn gsl_spmatrix_free(m: *mut gsl_spmatrix);
 }
 extern "C" {
-    pub fn gsl_spmatrix_realloc(nzmax: usize, m: *mut gsl_spmatrix) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_realloc(nzmax: usize, m: *mut gsl_spmatrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_nnz(m: *const gsl_spmatrix) -> usize;
 }
 extern "C" {
-    pub fn gsl_spmatrix_type(m: *const gsl_spmatrix) -> *const ::std::os::raw::c_char;
+    pub fn gsl_spmatrix_type(m: *const gsl_spmatrix) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_spmatrix_set_zero(m: *mut gsl_spmatrix) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_set_zero(m: *mut gsl_spmatrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_tree_rebuild(m: *mut gsl_spmatrix) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_tree_rebuild(m: *mut gsl_spmatrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_csc(
         dest: *mut gsl_spmatrix,
         src: *const gsl_spmatrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_csr(
         dest: *mut gsl_spmatrix,
         src: *const gsl_spmatrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_compress(
         src: *const gsl_spmatrix,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix;
 }
 extern "C" {
@@ -8009,34 +7918,30 @@ extern "C" {
     pub fn gsl_spmatrix_memcpy(
         dest: *mut gsl_spmatrix,
         src: *const gsl_spmatrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_fprintf(
         stream: *mut FILE,
         m: *const gsl_spmatrix,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_fscanf(stream: *mut FILE) -> *mut gsl_spmatrix;
 }
 extern "C" {
-    pub fn gsl_spmatrix_fwrite(stream: *mut FILE, m: *const gsl_spmatrix) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_fwrite(stream: *mut FILE, m: *const gsl_spmatrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_fread(stream: *mut FILE, m: *mut gsl_spmatrix) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_fread(stream: *mut FILE, m: *mut gsl_spmatrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_get(m: *const gsl_spmatrix, i: usize, j: usize) -> f64;
 }
 extern "C" {
-    pub fn gsl_spmatrix_set(
-        m: *mut gsl_spmatrix,
-        i: usize,
-        j: usize,
-        x: f64,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_set(m: *mut gsl_spmatrix, i: usize, j: usize, x: f64)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ptr(m: *const gsl_spmatrix, i: usize, j: usize) -> *mut f64;
@@ -8046,7 +7951,7 @@ extern "C" {
         m: *const gsl_spmatrix,
         min_out: *mut f64,
         max_out: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -8055,10 +7960,10 @@ extern "C" {
         m: *const gsl_spmatrix,
         imin_out: *mut usize,
         jmin_out: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_scale(m: *mut gsl_spmatrix, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_scale(m: *mut gsl_spmatrix, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -8066,7 +7971,7 @@ extern "C" {
     pub fn gsl_spmatrix_scale_columns(
         m: *mut gsl_spmatrix,
         x: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -8074,34 +7979,30 @@ extern "C" {
     pub fn gsl_spmatrix_scale_rows(
         m: *mut gsl_spmatrix,
         x: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_add(
         c: *mut gsl_spmatrix,
         a: *const gsl_spmatrix,
         b: *const gsl_spmatrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_dense_add(
-        a: *mut gsl_matrix,
-        b: *const gsl_spmatrix,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_dense_add(a: *mut gsl_matrix, b: *const gsl_spmatrix)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
-    pub fn gsl_spmatrix_dense_sub(
-        a: *mut gsl_matrix,
-        b: *const gsl_spmatrix,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_dense_sub(a: *mut gsl_matrix, b: *const gsl_spmatrix)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_d2sp(T: *mut gsl_spmatrix, A: *const gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_d2sp(T: *mut gsl_spmatrix, A: *const gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_sp2d(A: *mut gsl_matrix, S: *const gsl_spmatrix) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_sp2d(A: *mut gsl_matrix, S: *const gsl_spmatrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -8109,13 +8010,11 @@ extern "C" {
     pub fn gsl_spmatrix_add_to_dense(
         a: *mut gsl_matrix,
         b: *const gsl_spmatrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_equal(
-        a: *const gsl_spmatrix,
-        b: *const gsl_spmatrix,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_equal(a: *const gsl_spmatrix, b: *const gsl_spmatrix)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -8123,16 +8022,16 @@ extern "C" {
     pub fn gsl_spmatrix_norm1(a: *const gsl_spmatrix) -> f64;
 }
 extern "C" {
-    pub fn gsl_spmatrix_transpose(m: *mut gsl_spmatrix) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_transpose(m: *mut gsl_spmatrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_transpose2(m: *mut gsl_spmatrix) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_transpose2(m: *mut gsl_spmatrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_transpose_memcpy(
         dest: *mut gsl_spmatrix,
         src: *const gsl_spmatrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -8142,7 +8041,7 @@ pub struct gsl_matrix_float {
     pub tda: usize,
     pub data: *mut f32,
     pub block: *mut gsl_block_float,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -8368,38 +8267,38 @@ extern "C" {
     pub fn gsl_matrix_float_fread(
         stream: *mut FILE,
         m: *mut gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_fwrite(
         stream: *mut FILE,
         m: *const gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_fscanf(
         stream: *mut FILE,
         m: *mut gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_fprintf(
         stream: *mut FILE,
         m: *const gsl_matrix_float,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_memcpy(
         dest: *mut gsl_matrix_float,
         src: *const gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_swap(
         m1: *mut gsl_matrix_float,
         m2: *mut gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_tricpy(
@@ -8407,37 +8306,37 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_float,
         src: *const gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_swap_rows(
         m: *mut gsl_matrix_float,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_swap_columns(
         m: *mut gsl_matrix_float,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_swap_rowcol(
         m: *mut gsl_matrix_float,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_float_transpose(m: *mut gsl_matrix_float) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_float_transpose(m: *mut gsl_matrix_float) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_transpose_memcpy(
         dest: *mut gsl_matrix_float,
         src: *const gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_transpose_tricpy(
@@ -8445,7 +8344,7 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_float,
         src: *const gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_max(m: *const gsl_matrix_float) -> f32;
@@ -8487,19 +8386,19 @@ extern "C" {
     pub fn gsl_matrix_float_equal(
         a: *const gsl_matrix_float,
         b: *const gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_float_isnull(m: *const gsl_matrix_float) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_float_isnull(m: *const gsl_matrix_float) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_float_ispos(m: *const gsl_matrix_float) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_float_ispos(m: *const gsl_matrix_float) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_float_isneg(m: *const gsl_matrix_float) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_float_isneg(m: *const gsl_matrix_float) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_float_isnonneg(m: *const gsl_matrix_float) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_float_isnonneg(m: *const gsl_matrix_float) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_norm1(m: *const gsl_matrix_float) -> f32;
@@ -8508,76 +8407,74 @@ extern "C" {
     pub fn gsl_matrix_float_add(
         a: *mut gsl_matrix_float,
         b: *const gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_sub(
         a: *mut gsl_matrix_float,
         b: *const gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_mul_elements(
         a: *mut gsl_matrix_float,
         b: *const gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_div_elements(
         a: *mut gsl_matrix_float,
         b: *const gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_float_scale(a: *mut gsl_matrix_float, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_float_scale(a: *mut gsl_matrix_float, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_scale_rows(
         a: *mut gsl_matrix_float,
         x: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_scale_columns(
         a: *mut gsl_matrix_float,
         x: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_float_add_constant(a: *mut gsl_matrix_float, x: f64)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_float_add_constant(a: *mut gsl_matrix_float, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_float_add_diagonal(a: *mut gsl_matrix_float, x: f64)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_float_add_diagonal(a: *mut gsl_matrix_float, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_get_row(
         v: *mut gsl_vector_float,
         m: *const gsl_matrix_float,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_get_col(
         v: *mut gsl_vector_float,
         m: *const gsl_matrix_float,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_set_row(
         m: *mut gsl_matrix_float,
         i: usize,
         v: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_set_col(
         m: *mut gsl_matrix_float,
         j: usize,
         v: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_float_get(m: *const gsl_matrix_float, i: usize, j: usize) -> f32;
@@ -8603,7 +8500,7 @@ extern "C" {
         n1: usize,
         n2: usize,
         nzmax: usize,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_float;
 }
 extern "C" {
@@ -8613,36 +8510,36 @@ extern "C" {
     pub fn gsl_spmatrix_float_realloc(
         nzmax: usize,
         m: *mut gsl_spmatrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_nnz(m: *const gsl_spmatrix_float) -> usize;
 }
 extern "C" {
-    pub fn gsl_spmatrix_float_type(m: *const gsl_spmatrix_float) -> *const ::std::os::raw::c_char;
+    pub fn gsl_spmatrix_float_type(m: *const gsl_spmatrix_float) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_spmatrix_float_set_zero(m: *mut gsl_spmatrix_float) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_float_set_zero(m: *mut gsl_spmatrix_float) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_float_tree_rebuild(m: *mut gsl_spmatrix_float) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_float_tree_rebuild(m: *mut gsl_spmatrix_float) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_csc(
         dest: *mut gsl_spmatrix_float,
         src: *const gsl_spmatrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_csr(
         dest: *mut gsl_spmatrix_float,
         src: *const gsl_spmatrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_compress(
         src: *const gsl_spmatrix_float,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_float;
 }
 extern "C" {
@@ -8658,14 +8555,14 @@ extern "C" {
     pub fn gsl_spmatrix_float_memcpy(
         dest: *mut gsl_spmatrix_float,
         src: *const gsl_spmatrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_fprintf(
         stream: *mut FILE,
         m: *const gsl_spmatrix_float,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_fscanf(stream: *mut FILE) -> *mut gsl_spmatrix_float;
@@ -8674,13 +8571,13 @@ extern "C" {
     pub fn gsl_spmatrix_float_fwrite(
         stream: *mut FILE,
         m: *const gsl_spmatrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_fread(
         stream: *mut FILE,
         m: *mut gsl_spmatrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_get(m: *const gsl_spmatrix_float, i: usize, j: usize) -> f32;
@@ -8691,7 +8588,7 @@ extern "C" {
         i: usize,
         j: usize,
         x: f32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_ptr(m: *const gsl_spmatrix_float, i: usize, j: usize) -> *mut f32;
@@ -8701,87 +8598,87 @@ extern "C" {
         m: *const gsl_spmatrix_float,
         min_out: *mut f32,
         max_out: *mut f32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_min_index(
         m: *const gsl_spmatrix_float,
         imin_out: *mut usize,
         jmin_out: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_float_scale(m: *mut gsl_spmatrix_float, x: f32) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_float_scale(m: *mut gsl_spmatrix_float, x: f32) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_scale_columns(
         m: *mut gsl_spmatrix_float,
         x: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_scale_rows(
         m: *mut gsl_spmatrix_float,
         x: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_add(
         c: *mut gsl_spmatrix_float,
         a: *const gsl_spmatrix_float,
         b: *const gsl_spmatrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_dense_add(
         a: *mut gsl_matrix_float,
         b: *const gsl_spmatrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_dense_sub(
         a: *mut gsl_matrix_float,
         b: *const gsl_spmatrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_d2sp(
         T: *mut gsl_spmatrix_float,
         A: *const gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_sp2d(
         A: *mut gsl_matrix_float,
         S: *const gsl_spmatrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_add_to_dense(
         a: *mut gsl_matrix_float,
         b: *const gsl_spmatrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_equal(
         a: *const gsl_spmatrix_float,
         b: *const gsl_spmatrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_norm1(a: *const gsl_spmatrix_float) -> f32;
 }
 extern "C" {
-    pub fn gsl_spmatrix_float_transpose(m: *mut gsl_spmatrix_float) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_float_transpose(m: *mut gsl_spmatrix_float) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_float_transpose2(m: *mut gsl_spmatrix_float) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_float_transpose2(m: *mut gsl_spmatrix_float) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_float_transpose_memcpy(
         dest: *mut gsl_spmatrix_float,
         src: *const gsl_spmatrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -8797,77 +8694,72 @@ extern "C" {
     pub fn gsl_block_ulong_free(b: *mut gsl_block_ulong);
 }
 extern "C" {
-    pub fn gsl_block_ulong_fread(
-        stream: *mut FILE,
-        b: *mut gsl_block_ulong,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_ulong_fread(stream: *mut FILE, b: *mut gsl_block_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ulong_fwrite(
         stream: *mut FILE,
         b: *const gsl_block_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_block_ulong_fscanf(
-        stream: *mut FILE,
-        b: *mut gsl_block_ulong,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_ulong_fscanf(stream: *mut FILE, b: *mut gsl_block_ulong)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ulong_fprintf(
         stream: *mut FILE,
         b: *const gsl_block_ulong,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ulong_raw_fread(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_ulong,
+        b: *mut ::core::ffi::c_ulong,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ulong_raw_fwrite(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_ulong,
+        b: *const ::core::ffi::c_ulong,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ulong_raw_fscanf(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_ulong,
+        b: *mut ::core::ffi::c_ulong,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ulong_raw_fprintf(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_ulong,
+        b: *const ::core::ffi::c_ulong,
         n: usize,
         stride: usize,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ulong_size(b: *const gsl_block_ulong) -> usize;
 }
 extern "C" {
-    pub fn gsl_block_ulong_data(b: *const gsl_block_ulong) -> *mut ::std::os::raw::c_ulong;
+    pub fn gsl_block_ulong_data(b: *const gsl_block_ulong) -> *mut ::core::ffi::c_ulong;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_vector_ulong {
     pub size: usize,
     pub stride: usize,
-    pub data: *mut ::std::os::raw::c_ulong,
+    pub data: *mut ::core::ffi::c_ulong,
     pub block: *mut gsl_block_ulong,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -8908,26 +8800,26 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_vector_ulong_view_array(
-        v: *mut ::std::os::raw::c_ulong,
+        v: *mut ::core::ffi::c_ulong,
         n: usize,
     ) -> _gsl_vector_ulong_view;
 }
 extern "C" {
     pub fn gsl_vector_ulong_view_array_with_stride(
-        base: *mut ::std::os::raw::c_ulong,
+        base: *mut ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_ulong_view;
 }
 extern "C" {
     pub fn gsl_vector_ulong_const_view_array(
-        v: *const ::std::os::raw::c_ulong,
+        v: *const ::core::ffi::c_ulong,
         n: usize,
     ) -> _gsl_vector_ulong_const_view;
 }
 extern "C" {
     pub fn gsl_vector_ulong_const_view_array_with_stride(
-        base: *const ::std::os::raw::c_ulong,
+        base: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_ulong_const_view;
@@ -8966,69 +8858,69 @@ extern "C" {
     pub fn gsl_vector_ulong_set_zero(v: *mut gsl_vector_ulong);
 }
 extern "C" {
-    pub fn gsl_vector_ulong_set_all(v: *mut gsl_vector_ulong, x: ::std::os::raw::c_ulong);
+    pub fn gsl_vector_ulong_set_all(v: *mut gsl_vector_ulong, x: ::core::ffi::c_ulong);
 }
 extern "C" {
-    pub fn gsl_vector_ulong_set_basis(v: *mut gsl_vector_ulong, i: usize) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ulong_set_basis(v: *mut gsl_vector_ulong, i: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ulong_fread(
         stream: *mut FILE,
         v: *mut gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ulong_fwrite(
         stream: *mut FILE,
         v: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ulong_fscanf(
         stream: *mut FILE,
         v: *mut gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ulong_fprintf(
         stream: *mut FILE,
         v: *const gsl_vector_ulong,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ulong_memcpy(
         dest: *mut gsl_vector_ulong,
         src: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ulong_reverse(v: *mut gsl_vector_ulong) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ulong_reverse(v: *mut gsl_vector_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ulong_swap(
         v: *mut gsl_vector_ulong,
         w: *mut gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ulong_swap_elements(
         v: *mut gsl_vector_ulong,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ulong_max(v: *const gsl_vector_ulong) -> ::std::os::raw::c_ulong;
+    pub fn gsl_vector_ulong_max(v: *const gsl_vector_ulong) -> ::core::ffi::c_ulong;
 }
 extern "C" {
-    pub fn gsl_vector_ulong_min(v: *const gsl_vector_ulong) -> ::std::os::raw::c_ulong;
+    pub fn gsl_vector_ulong_min(v: *const gsl_vector_ulong) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_vector_ulong_minmax(
         v: *const gsl_vector_ulong,
-        min_out: *mut ::std::os::raw::c_ulong,
-        max_out: *mut ::std::os::raw::c_ulong,
+        min_out: *mut ::core::ffi::c_ulong,
+        max_out: *mut ::core::ffi::c_ulong,
     );
 }
 extern "C" {
@@ -9048,80 +8940,78 @@ extern "C" {
     pub fn gsl_vector_ulong_add(
         a: *mut gsl_vector_ulong,
         b: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ulong_sub(
         a: *mut gsl_vector_ulong,
         b: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ulong_mul(
         a: *mut gsl_vector_ulong,
         b: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ulong_div(
         a: *mut gsl_vector_ulong,
         b: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ulong_scale(
         a: *mut gsl_vector_ulong,
-        x: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ulong_add_constant(a: *mut gsl_vector_ulong, x: f64)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ulong_add_constant(a: *mut gsl_vector_ulong, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ulong_axpby(
-        alpha: ::std::os::raw::c_ulong,
+        alpha: ::core::ffi::c_ulong,
         x: *const gsl_vector_ulong,
-        beta: ::std::os::raw::c_ulong,
+        beta: ::core::ffi::c_ulong,
         y: *mut gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ulong_sum(a: *const gsl_vector_ulong) -> ::std::os::raw::c_ulong;
+    pub fn gsl_vector_ulong_sum(a: *const gsl_vector_ulong) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_vector_ulong_equal(
         u: *const gsl_vector_ulong,
         v: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ulong_isnull(v: *const gsl_vector_ulong) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ulong_isnull(v: *const gsl_vector_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ulong_ispos(v: *const gsl_vector_ulong) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ulong_ispos(v: *const gsl_vector_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ulong_isneg(v: *const gsl_vector_ulong) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ulong_isneg(v: *const gsl_vector_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ulong_isnonneg(v: *const gsl_vector_ulong) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ulong_isnonneg(v: *const gsl_vector_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ulong_get(v: *const gsl_vector_ulong, i: usize) -> ::std::os::raw::c_ulong;
+    pub fn gsl_vector_ulong_get(v: *const gsl_vector_ulong, i: usize) -> ::core::ffi::c_ulong;
 }
 extern "C" {
-    pub fn gsl_vector_ulong_set(v: *mut gsl_vector_ulong, i: usize, x: ::std::os::raw::c_ulong);
+    pub fn gsl_vector_ulong_set(v: *mut gsl_vector_ulong, i: usize, x: ::core::ffi::c_ulong);
 }
 extern "C" {
-    pub fn gsl_vector_ulong_ptr(v: *mut gsl_vector_ulong, i: usize)
-        -> *mut ::std::os::raw::c_ulong;
+    pub fn gsl_vector_ulong_ptr(v: *mut gsl_vector_ulong, i: usize) -> *mut ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_vector_ulong_const_ptr(
         v: *const gsl_vector_ulong,
         i: usize,
-    ) -> *const ::std::os::raw::c_ulong;
+    ) -> *const ::core::ffi::c_ulong;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -9129,9 +9019,9 @@ pub struct gsl_matrix_ulong {
     pub size1: usize,
     pub size2: usize,
     pub tda: usize,
-    pub data: *mut ::std::os::raw::c_ulong,
+    pub data: *mut ::core::ffi::c_ulong,
     pub block: *mut gsl_block_ulong,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -9232,14 +9122,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_ulong_view_array(
-        base: *mut ::std::os::raw::c_ulong,
+        base: *mut ::core::ffi::c_ulong,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_ulong_view;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_view_array_with_tda(
-        base: *mut ::std::os::raw::c_ulong,
+        base: *mut ::core::ffi::c_ulong,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -9316,14 +9206,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_ulong_const_view_array(
-        base: *const ::std::os::raw::c_ulong,
+        base: *const ::core::ffi::c_ulong,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_ulong_const_view;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_const_view_array_with_tda(
-        base: *const ::std::os::raw::c_ulong,
+        base: *const ::core::ffi::c_ulong,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -9351,44 +9241,44 @@ extern "C" {
     pub fn gsl_matrix_ulong_set_identity(m: *mut gsl_matrix_ulong);
 }
 extern "C" {
-    pub fn gsl_matrix_ulong_set_all(m: *mut gsl_matrix_ulong, x: ::std::os::raw::c_ulong);
+    pub fn gsl_matrix_ulong_set_all(m: *mut gsl_matrix_ulong, x: ::core::ffi::c_ulong);
 }
 extern "C" {
     pub fn gsl_matrix_ulong_fread(
         stream: *mut FILE,
         m: *mut gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_fwrite(
         stream: *mut FILE,
         m: *const gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_fscanf(
         stream: *mut FILE,
         m: *mut gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_fprintf(
         stream: *mut FILE,
         m: *const gsl_matrix_ulong,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_memcpy(
         dest: *mut gsl_matrix_ulong,
         src: *const gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_swap(
         m1: *mut gsl_matrix_ulong,
         m2: *mut gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_tricpy(
@@ -9396,37 +9286,37 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_ulong,
         src: *const gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_swap_rows(
         m: *mut gsl_matrix_ulong,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_swap_columns(
         m: *mut gsl_matrix_ulong,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_swap_rowcol(
         m: *mut gsl_matrix_ulong,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ulong_transpose(m: *mut gsl_matrix_ulong) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ulong_transpose(m: *mut gsl_matrix_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_transpose_memcpy(
         dest: *mut gsl_matrix_ulong,
         src: *const gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_transpose_tricpy(
@@ -9434,19 +9324,19 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_ulong,
         src: *const gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ulong_max(m: *const gsl_matrix_ulong) -> ::std::os::raw::c_ulong;
+    pub fn gsl_matrix_ulong_max(m: *const gsl_matrix_ulong) -> ::core::ffi::c_ulong;
 }
 extern "C" {
-    pub fn gsl_matrix_ulong_min(m: *const gsl_matrix_ulong) -> ::std::os::raw::c_ulong;
+    pub fn gsl_matrix_ulong_min(m: *const gsl_matrix_ulong) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_minmax(
         m: *const gsl_matrix_ulong,
-        min_out: *mut ::std::os::raw::c_ulong,
-        max_out: *mut ::std::os::raw::c_ulong,
+        min_out: *mut ::core::ffi::c_ulong,
+        max_out: *mut ::core::ffi::c_ulong,
     );
 }
 extern "C" {
@@ -9476,111 +9366,109 @@ extern "C" {
     pub fn gsl_matrix_ulong_equal(
         a: *const gsl_matrix_ulong,
         b: *const gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ulong_isnull(m: *const gsl_matrix_ulong) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ulong_isnull(m: *const gsl_matrix_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ulong_ispos(m: *const gsl_matrix_ulong) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ulong_ispos(m: *const gsl_matrix_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ulong_isneg(m: *const gsl_matrix_ulong) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ulong_isneg(m: *const gsl_matrix_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ulong_isnonneg(m: *const gsl_matrix_ulong) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ulong_isnonneg(m: *const gsl_matrix_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ulong_norm1(m: *const gsl_matrix_ulong) -> ::std::os::raw::c_ulong;
+    pub fn gsl_matrix_ulong_norm1(m: *const gsl_matrix_ulong) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_add(
         a: *mut gsl_matrix_ulong,
         b: *const gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_sub(
         a: *mut gsl_matrix_ulong,
         b: *const gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_mul_elements(
         a: *mut gsl_matrix_ulong,
         b: *const gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_div_elements(
         a: *mut gsl_matrix_ulong,
         b: *const gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ulong_scale(a: *mut gsl_matrix_ulong, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ulong_scale(a: *mut gsl_matrix_ulong, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_scale_rows(
         a: *mut gsl_matrix_ulong,
         x: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_scale_columns(
         a: *mut gsl_matrix_ulong,
         x: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ulong_add_constant(a: *mut gsl_matrix_ulong, x: f64)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ulong_add_constant(a: *mut gsl_matrix_ulong, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ulong_add_diagonal(a: *mut gsl_matrix_ulong, x: f64)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ulong_add_diagonal(a: *mut gsl_matrix_ulong, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_get_row(
         v: *mut gsl_vector_ulong,
         m: *const gsl_matrix_ulong,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_get_col(
         v: *mut gsl_vector_ulong,
         m: *const gsl_matrix_ulong,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_set_row(
         m: *mut gsl_matrix_ulong,
         i: usize,
         v: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_set_col(
         m: *mut gsl_matrix_ulong,
         j: usize,
         v: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_get(
         m: *const gsl_matrix_ulong,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_ulong;
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_set(
         m: *mut gsl_matrix_ulong,
         i: usize,
         j: usize,
-        x: ::std::os::raw::c_ulong,
+        x: ::core::ffi::c_ulong,
     );
 }
 extern "C" {
@@ -9588,14 +9476,14 @@ extern "C" {
         m: *mut gsl_matrix_ulong,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_ulong;
+    ) -> *mut ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_matrix_ulong_const_ptr(
         m: *const gsl_matrix_ulong,
         i: usize,
         j: usize,
-    ) -> *const ::std::os::raw::c_ulong;
+    ) -> *const ::core::ffi::c_ulong;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -9608,7 +9496,7 @@ extern "C" {
         n1: usize,
         n2: usize,
         nzmax: usize,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_ulong;
 }
 extern "C" {
@@ -9618,36 +9506,36 @@ extern "C" {
     pub fn gsl_spmatrix_ulong_realloc(
         nzmax: usize,
         m: *mut gsl_spmatrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_nnz(m: *const gsl_spmatrix_ulong) -> usize;
 }
 extern "C" {
-    pub fn gsl_spmatrix_ulong_type(m: *const gsl_spmatrix_ulong) -> *const ::std::os::raw::c_char;
+    pub fn gsl_spmatrix_ulong_type(m: *const gsl_spmatrix_ulong) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_spmatrix_ulong_set_zero(m: *mut gsl_spmatrix_ulong) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_ulong_set_zero(m: *mut gsl_spmatrix_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_ulong_tree_rebuild(m: *mut gsl_spmatrix_ulong) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_ulong_tree_rebuild(m: *mut gsl_spmatrix_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_csc(
         dest: *mut gsl_spmatrix_ulong,
         src: *const gsl_spmatrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_csr(
         dest: *mut gsl_spmatrix_ulong,
         src: *const gsl_spmatrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_compress(
         src: *const gsl_spmatrix_ulong,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_ulong;
 }
 extern "C" {
@@ -9663,14 +9551,14 @@ extern "C" {
     pub fn gsl_spmatrix_ulong_memcpy(
         dest: *mut gsl_spmatrix_ulong,
         src: *const gsl_spmatrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_fprintf(
         stream: *mut FILE,
         m: *const gsl_spmatrix_ulong,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_fscanf(stream: *mut FILE) -> *mut gsl_spmatrix_ulong;
@@ -9679,125 +9567,125 @@ extern "C" {
     pub fn gsl_spmatrix_ulong_fwrite(
         stream: *mut FILE,
         m: *const gsl_spmatrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_fread(
         stream: *mut FILE,
         m: *mut gsl_spmatrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_get(
         m: *const gsl_spmatrix_ulong,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_ulong;
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_set(
         m: *mut gsl_spmatrix_ulong,
         i: usize,
         j: usize,
-        x: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_ptr(
         m: *const gsl_spmatrix_ulong,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_ulong;
+    ) -> *mut ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_minmax(
         m: *const gsl_spmatrix_ulong,
-        min_out: *mut ::std::os::raw::c_ulong,
-        max_out: *mut ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_int;
+        min_out: *mut ::core::ffi::c_ulong,
+        max_out: *mut ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_min_index(
         m: *const gsl_spmatrix_ulong,
         imin_out: *mut usize,
         jmin_out: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_scale(
         m: *mut gsl_spmatrix_ulong,
-        x: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_scale_columns(
         m: *mut gsl_spmatrix_ulong,
         x: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_scale_rows(
         m: *mut gsl_spmatrix_ulong,
         x: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_add(
         c: *mut gsl_spmatrix_ulong,
         a: *const gsl_spmatrix_ulong,
         b: *const gsl_spmatrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_dense_add(
         a: *mut gsl_matrix_ulong,
         b: *const gsl_spmatrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_dense_sub(
         a: *mut gsl_matrix_ulong,
         b: *const gsl_spmatrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_d2sp(
         T: *mut gsl_spmatrix_ulong,
         A: *const gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_sp2d(
         A: *mut gsl_matrix_ulong,
         S: *const gsl_spmatrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_add_to_dense(
         a: *mut gsl_matrix_ulong,
         b: *const gsl_spmatrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_equal(
         a: *const gsl_spmatrix_ulong,
         b: *const gsl_spmatrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_ulong_norm1(a: *const gsl_spmatrix_ulong) -> ::std::os::raw::c_ulong;
+    pub fn gsl_spmatrix_ulong_norm1(a: *const gsl_spmatrix_ulong) -> ::core::ffi::c_ulong;
 }
 extern "C" {
-    pub fn gsl_spmatrix_ulong_transpose(m: *mut gsl_spmatrix_ulong) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_ulong_transpose(m: *mut gsl_spmatrix_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_ulong_transpose2(m: *mut gsl_spmatrix_ulong) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_ulong_transpose2(m: *mut gsl_spmatrix_ulong) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ulong_transpose_memcpy(
         dest: *mut gsl_spmatrix_ulong,
         src: *const gsl_spmatrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -9805,9 +9693,9 @@ pub struct gsl_matrix_long {
     pub size1: usize,
     pub size2: usize,
     pub tda: usize,
-    pub data: *mut ::std::os::raw::c_long,
+    pub data: *mut ::core::ffi::c_long,
     pub block: *mut gsl_block_long,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -9905,14 +9793,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_long_view_array(
-        base: *mut ::std::os::raw::c_long,
+        base: *mut ::core::ffi::c_long,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_long_view;
 }
 extern "C" {
     pub fn gsl_matrix_long_view_array_with_tda(
-        base: *mut ::std::os::raw::c_long,
+        base: *mut ::core::ffi::c_long,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -9988,14 +9876,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_long_const_view_array(
-        base: *const ::std::os::raw::c_long,
+        base: *const ::core::ffi::c_long,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_long_const_view;
 }
 extern "C" {
     pub fn gsl_matrix_long_const_view_array_with_tda(
-        base: *const ::std::os::raw::c_long,
+        base: *const ::core::ffi::c_long,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -10023,44 +9911,39 @@ extern "C" {
     pub fn gsl_matrix_long_set_identity(m: *mut gsl_matrix_long);
 }
 extern "C" {
-    pub fn gsl_matrix_long_set_all(m: *mut gsl_matrix_long, x: ::std::os::raw::c_long);
+    pub fn gsl_matrix_long_set_all(m: *mut gsl_matrix_long, x: ::core::ffi::c_long);
 }
 extern "C" {
-    pub fn gsl_matrix_long_fread(
-        stream: *mut FILE,
-        m: *mut gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_fread(stream: *mut FILE, m: *mut gsl_matrix_long) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_fwrite(
         stream: *mut FILE,
         m: *const gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_fscanf(
-        stream: *mut FILE,
-        m: *mut gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_fscanf(stream: *mut FILE, m: *mut gsl_matrix_long)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_fprintf(
         stream: *mut FILE,
         m: *const gsl_matrix_long,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_memcpy(
         dest: *mut gsl_matrix_long,
         src: *const gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_swap(
         m1: *mut gsl_matrix_long,
         m2: *mut gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_tricpy(
@@ -10068,37 +9951,37 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_long,
         src: *const gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_swap_rows(
         m: *mut gsl_matrix_long,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_swap_columns(
         m: *mut gsl_matrix_long,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_swap_rowcol(
         m: *mut gsl_matrix_long,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_transpose(m: *mut gsl_matrix_long) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_transpose(m: *mut gsl_matrix_long) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_transpose_memcpy(
         dest: *mut gsl_matrix_long,
         src: *const gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_transpose_tricpy(
@@ -10106,19 +9989,19 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_long,
         src: *const gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_max(m: *const gsl_matrix_long) -> ::std::os::raw::c_long;
+    pub fn gsl_matrix_long_max(m: *const gsl_matrix_long) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn gsl_matrix_long_min(m: *const gsl_matrix_long) -> ::std::os::raw::c_long;
+    pub fn gsl_matrix_long_min(m: *const gsl_matrix_long) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn gsl_matrix_long_minmax(
         m: *const gsl_matrix_long,
-        min_out: *mut ::std::os::raw::c_long,
-        max_out: *mut ::std::os::raw::c_long,
+        min_out: *mut ::core::ffi::c_long,
+        max_out: *mut ::core::ffi::c_long,
     );
 }
 extern "C" {
@@ -10140,124 +10023,119 @@ extern "C" {
     pub fn gsl_matrix_long_equal(
         a: *const gsl_matrix_long,
         b: *const gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_isnull(m: *const gsl_matrix_long) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_isnull(m: *const gsl_matrix_long) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_ispos(m: *const gsl_matrix_long) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_ispos(m: *const gsl_matrix_long) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_isneg(m: *const gsl_matrix_long) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_isneg(m: *const gsl_matrix_long) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_isnonneg(m: *const gsl_matrix_long) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_isnonneg(m: *const gsl_matrix_long) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_norm1(m: *const gsl_matrix_long) -> ::std::os::raw::c_long;
+    pub fn gsl_matrix_long_norm1(m: *const gsl_matrix_long) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn gsl_matrix_long_add(
         a: *mut gsl_matrix_long,
         b: *const gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_sub(
         a: *mut gsl_matrix_long,
         b: *const gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_mul_elements(
         a: *mut gsl_matrix_long,
         b: *const gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_div_elements(
         a: *mut gsl_matrix_long,
         b: *const gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_scale(a: *mut gsl_matrix_long, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_scale(a: *mut gsl_matrix_long, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_scale_rows(
         a: *mut gsl_matrix_long,
         x: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_scale_columns(
         a: *mut gsl_matrix_long,
         x: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_add_constant(a: *mut gsl_matrix_long, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_add_constant(a: *mut gsl_matrix_long, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_long_add_diagonal(a: *mut gsl_matrix_long, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_long_add_diagonal(a: *mut gsl_matrix_long, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_get_row(
         v: *mut gsl_vector_long,
         m: *const gsl_matrix_long,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_get_col(
         v: *mut gsl_vector_long,
         m: *const gsl_matrix_long,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_set_row(
         m: *mut gsl_matrix_long,
         i: usize,
         v: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_set_col(
         m: *mut gsl_matrix_long,
         j: usize,
         v: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_long_get(
         m: *const gsl_matrix_long,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_long;
+    ) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn gsl_matrix_long_set(
-        m: *mut gsl_matrix_long,
-        i: usize,
-        j: usize,
-        x: ::std::os::raw::c_long,
-    );
+    pub fn gsl_matrix_long_set(m: *mut gsl_matrix_long, i: usize, j: usize, x: ::core::ffi::c_long);
 }
 extern "C" {
     pub fn gsl_matrix_long_ptr(
         m: *mut gsl_matrix_long,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_long;
+    ) -> *mut ::core::ffi::c_long;
 }
 extern "C" {
     pub fn gsl_matrix_long_const_ptr(
         m: *const gsl_matrix_long,
         i: usize,
         j: usize,
-    ) -> *const ::std::os::raw::c_long;
+    ) -> *const ::core::ffi::c_long;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -10270,46 +10148,44 @@ extern "C" {
         n1: usize,
         n2: usize,
         nzmax: usize,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_long;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_free(m: *mut gsl_spmatrix_long);
 }
 extern "C" {
-    pub fn gsl_spmatrix_long_realloc(
-        nzmax: usize,
-        m: *mut gsl_spmatrix_long,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_long_realloc(nzmax: usize, m: *mut gsl_spmatrix_long)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_nnz(m: *const gsl_spmatrix_long) -> usize;
 }
 extern "C" {
-    pub fn gsl_spmatrix_long_type(m: *const gsl_spmatrix_long) -> *const ::std::os::raw::c_char;
+    pub fn gsl_spmatrix_long_type(m: *const gsl_spmatrix_long) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_spmatrix_long_set_zero(m: *mut gsl_spmatrix_long) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_long_set_zero(m: *mut gsl_spmatrix_long) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_long_tree_rebuild(m: *mut gsl_spmatrix_long) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_long_tree_rebuild(m: *mut gsl_spmatrix_long) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_csc(
         dest: *mut gsl_spmatrix_long,
         src: *const gsl_spmatrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_csr(
         dest: *mut gsl_spmatrix_long,
         src: *const gsl_spmatrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_compress(
         src: *const gsl_spmatrix_long,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_long;
 }
 extern "C" {
@@ -10325,14 +10201,14 @@ extern "C" {
     pub fn gsl_spmatrix_long_memcpy(
         dest: *mut gsl_spmatrix_long,
         src: *const gsl_spmatrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_fprintf(
         stream: *mut FILE,
         m: *const gsl_spmatrix_long,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_fscanf(stream: *mut FILE) -> *mut gsl_spmatrix_long;
@@ -10341,125 +10217,125 @@ extern "C" {
     pub fn gsl_spmatrix_long_fwrite(
         stream: *mut FILE,
         m: *const gsl_spmatrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_fread(
         stream: *mut FILE,
         m: *mut gsl_spmatrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_get(
         m: *const gsl_spmatrix_long,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_long;
+    ) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_set(
         m: *mut gsl_spmatrix_long,
         i: usize,
         j: usize,
-        x: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_long,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_ptr(
         m: *const gsl_spmatrix_long,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_long;
+    ) -> *mut ::core::ffi::c_long;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_minmax(
         m: *const gsl_spmatrix_long,
-        min_out: *mut ::std::os::raw::c_long,
-        max_out: *mut ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_int;
+        min_out: *mut ::core::ffi::c_long,
+        max_out: *mut ::core::ffi::c_long,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_min_index(
         m: *const gsl_spmatrix_long,
         imin_out: *mut usize,
         jmin_out: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_scale(
         m: *mut gsl_spmatrix_long,
-        x: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_long,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_scale_columns(
         m: *mut gsl_spmatrix_long,
         x: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_scale_rows(
         m: *mut gsl_spmatrix_long,
         x: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_add(
         c: *mut gsl_spmatrix_long,
         a: *const gsl_spmatrix_long,
         b: *const gsl_spmatrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_dense_add(
         a: *mut gsl_matrix_long,
         b: *const gsl_spmatrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_dense_sub(
         a: *mut gsl_matrix_long,
         b: *const gsl_spmatrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_d2sp(
         T: *mut gsl_spmatrix_long,
         A: *const gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_sp2d(
         A: *mut gsl_matrix_long,
         S: *const gsl_spmatrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_add_to_dense(
         a: *mut gsl_matrix_long,
         b: *const gsl_spmatrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_equal(
         a: *const gsl_spmatrix_long,
         b: *const gsl_spmatrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_long_norm1(a: *const gsl_spmatrix_long) -> ::std::os::raw::c_long;
+    pub fn gsl_spmatrix_long_norm1(a: *const gsl_spmatrix_long) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn gsl_spmatrix_long_transpose(m: *mut gsl_spmatrix_long) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_long_transpose(m: *mut gsl_spmatrix_long) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_long_transpose2(m: *mut gsl_spmatrix_long) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_long_transpose2(m: *mut gsl_spmatrix_long) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_long_transpose_memcpy(
         dest: *mut gsl_spmatrix_long,
         src: *const gsl_spmatrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -10475,75 +10351,69 @@ extern "C" {
     pub fn gsl_block_uint_free(b: *mut gsl_block_uint);
 }
 extern "C" {
-    pub fn gsl_block_uint_fread(stream: *mut FILE, b: *mut gsl_block_uint)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_block_uint_fread(stream: *mut FILE, b: *mut gsl_block_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_block_uint_fwrite(
-        stream: *mut FILE,
-        b: *const gsl_block_uint,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_uint_fwrite(stream: *mut FILE, b: *const gsl_block_uint)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_block_uint_fscanf(
-        stream: *mut FILE,
-        b: *mut gsl_block_uint,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_uint_fscanf(stream: *mut FILE, b: *mut gsl_block_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_uint_fprintf(
         stream: *mut FILE,
         b: *const gsl_block_uint,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_uint_raw_fread(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_uint,
+        b: *mut ::core::ffi::c_uint,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_uint_raw_fwrite(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_uint,
+        b: *const ::core::ffi::c_uint,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_uint_raw_fscanf(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_uint,
+        b: *mut ::core::ffi::c_uint,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_uint_raw_fprintf(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_uint,
+        b: *const ::core::ffi::c_uint,
         n: usize,
         stride: usize,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_uint_size(b: *const gsl_block_uint) -> usize;
 }
 extern "C" {
-    pub fn gsl_block_uint_data(b: *const gsl_block_uint) -> *mut ::std::os::raw::c_uint;
+    pub fn gsl_block_uint_data(b: *const gsl_block_uint) -> *mut ::core::ffi::c_uint;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_vector_uint {
     pub size: usize,
     pub stride: usize,
-    pub data: *mut ::std::os::raw::c_uint,
+    pub data: *mut ::core::ffi::c_uint,
     pub block: *mut gsl_block_uint,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -10584,26 +10454,26 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_vector_uint_view_array(
-        v: *mut ::std::os::raw::c_uint,
+        v: *mut ::core::ffi::c_uint,
         n: usize,
     ) -> _gsl_vector_uint_view;
 }
 extern "C" {
     pub fn gsl_vector_uint_view_array_with_stride(
-        base: *mut ::std::os::raw::c_uint,
+        base: *mut ::core::ffi::c_uint,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_uint_view;
 }
 extern "C" {
     pub fn gsl_vector_uint_const_view_array(
-        v: *const ::std::os::raw::c_uint,
+        v: *const ::core::ffi::c_uint,
         n: usize,
     ) -> _gsl_vector_uint_const_view;
 }
 extern "C" {
     pub fn gsl_vector_uint_const_view_array_with_stride(
-        base: *const ::std::os::raw::c_uint,
+        base: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_uint_const_view;
@@ -10642,69 +10512,64 @@ extern "C" {
     pub fn gsl_vector_uint_set_zero(v: *mut gsl_vector_uint);
 }
 extern "C" {
-    pub fn gsl_vector_uint_set_all(v: *mut gsl_vector_uint, x: ::std::os::raw::c_uint);
+    pub fn gsl_vector_uint_set_all(v: *mut gsl_vector_uint, x: ::core::ffi::c_uint);
 }
 extern "C" {
-    pub fn gsl_vector_uint_set_basis(v: *mut gsl_vector_uint, i: usize) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uint_set_basis(v: *mut gsl_vector_uint, i: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uint_fread(
-        stream: *mut FILE,
-        v: *mut gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uint_fread(stream: *mut FILE, v: *mut gsl_vector_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uint_fwrite(
         stream: *mut FILE,
         v: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uint_fscanf(
-        stream: *mut FILE,
-        v: *mut gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uint_fscanf(stream: *mut FILE, v: *mut gsl_vector_uint)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uint_fprintf(
         stream: *mut FILE,
         v: *const gsl_vector_uint,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uint_memcpy(
         dest: *mut gsl_vector_uint,
         src: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uint_reverse(v: *mut gsl_vector_uint) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uint_reverse(v: *mut gsl_vector_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uint_swap(
         v: *mut gsl_vector_uint,
         w: *mut gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uint_swap_elements(
         v: *mut gsl_vector_uint,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uint_max(v: *const gsl_vector_uint) -> ::std::os::raw::c_uint;
+    pub fn gsl_vector_uint_max(v: *const gsl_vector_uint) -> ::core::ffi::c_uint;
 }
 extern "C" {
-    pub fn gsl_vector_uint_min(v: *const gsl_vector_uint) -> ::std::os::raw::c_uint;
+    pub fn gsl_vector_uint_min(v: *const gsl_vector_uint) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_vector_uint_minmax(
         v: *const gsl_vector_uint,
-        min_out: *mut ::std::os::raw::c_uint,
-        max_out: *mut ::std::os::raw::c_uint,
+        min_out: *mut ::core::ffi::c_uint,
+        max_out: *mut ::core::ffi::c_uint,
     );
 }
 extern "C" {
@@ -10724,78 +10589,78 @@ extern "C" {
     pub fn gsl_vector_uint_add(
         a: *mut gsl_vector_uint,
         b: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uint_sub(
         a: *mut gsl_vector_uint,
         b: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uint_mul(
         a: *mut gsl_vector_uint,
         b: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uint_div(
         a: *mut gsl_vector_uint,
         b: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uint_scale(
         a: *mut gsl_vector_uint,
-        x: ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uint_add_constant(a: *mut gsl_vector_uint, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uint_add_constant(a: *mut gsl_vector_uint, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uint_axpby(
-        alpha: ::std::os::raw::c_uint,
+        alpha: ::core::ffi::c_uint,
         x: *const gsl_vector_uint,
-        beta: ::std::os::raw::c_uint,
+        beta: ::core::ffi::c_uint,
         y: *mut gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uint_sum(a: *const gsl_vector_uint) -> ::std::os::raw::c_uint;
+    pub fn gsl_vector_uint_sum(a: *const gsl_vector_uint) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_vector_uint_equal(
         u: *const gsl_vector_uint,
         v: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uint_isnull(v: *const gsl_vector_uint) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uint_isnull(v: *const gsl_vector_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uint_ispos(v: *const gsl_vector_uint) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uint_ispos(v: *const gsl_vector_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uint_isneg(v: *const gsl_vector_uint) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uint_isneg(v: *const gsl_vector_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uint_isnonneg(v: *const gsl_vector_uint) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uint_isnonneg(v: *const gsl_vector_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uint_get(v: *const gsl_vector_uint, i: usize) -> ::std::os::raw::c_uint;
+    pub fn gsl_vector_uint_get(v: *const gsl_vector_uint, i: usize) -> ::core::ffi::c_uint;
 }
 extern "C" {
-    pub fn gsl_vector_uint_set(v: *mut gsl_vector_uint, i: usize, x: ::std::os::raw::c_uint);
+    pub fn gsl_vector_uint_set(v: *mut gsl_vector_uint, i: usize, x: ::core::ffi::c_uint);
 }
 extern "C" {
-    pub fn gsl_vector_uint_ptr(v: *mut gsl_vector_uint, i: usize) -> *mut ::std::os::raw::c_uint;
+    pub fn gsl_vector_uint_ptr(v: *mut gsl_vector_uint, i: usize) -> *mut ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_vector_uint_const_ptr(
         v: *const gsl_vector_uint,
         i: usize,
-    ) -> *const ::std::os::raw::c_uint;
+    ) -> *const ::core::ffi::c_uint;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -10803,9 +10668,9 @@ pub struct gsl_matrix_uint {
     pub size1: usize,
     pub size2: usize,
     pub tda: usize,
-    pub data: *mut ::std::os::raw::c_uint,
+    pub data: *mut ::core::ffi::c_uint,
     pub block: *mut gsl_block_uint,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -10903,14 +10768,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_uint_view_array(
-        base: *mut ::std::os::raw::c_uint,
+        base: *mut ::core::ffi::c_uint,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_uint_view;
 }
 extern "C" {
     pub fn gsl_matrix_uint_view_array_with_tda(
-        base: *mut ::std::os::raw::c_uint,
+        base: *mut ::core::ffi::c_uint,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -10986,14 +10851,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_uint_const_view_array(
-        base: *const ::std::os::raw::c_uint,
+        base: *const ::core::ffi::c_uint,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_uint_const_view;
 }
 extern "C" {
     pub fn gsl_matrix_uint_const_view_array_with_tda(
-        base: *const ::std::os::raw::c_uint,
+        base: *const ::core::ffi::c_uint,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -11021,44 +10886,39 @@ extern "C" {
     pub fn gsl_matrix_uint_set_identity(m: *mut gsl_matrix_uint);
 }
 extern "C" {
-    pub fn gsl_matrix_uint_set_all(m: *mut gsl_matrix_uint, x: ::std::os::raw::c_uint);
+    pub fn gsl_matrix_uint_set_all(m: *mut gsl_matrix_uint, x: ::core::ffi::c_uint);
 }
 extern "C" {
-    pub fn gsl_matrix_uint_fread(
-        stream: *mut FILE,
-        m: *mut gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uint_fread(stream: *mut FILE, m: *mut gsl_matrix_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_fwrite(
         stream: *mut FILE,
         m: *const gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uint_fscanf(
-        stream: *mut FILE,
-        m: *mut gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uint_fscanf(stream: *mut FILE, m: *mut gsl_matrix_uint)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_fprintf(
         stream: *mut FILE,
         m: *const gsl_matrix_uint,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_memcpy(
         dest: *mut gsl_matrix_uint,
         src: *const gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_swap(
         m1: *mut gsl_matrix_uint,
         m2: *mut gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_tricpy(
@@ -11066,37 +10926,37 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_uint,
         src: *const gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_swap_rows(
         m: *mut gsl_matrix_uint,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_swap_columns(
         m: *mut gsl_matrix_uint,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_swap_rowcol(
         m: *mut gsl_matrix_uint,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uint_transpose(m: *mut gsl_matrix_uint) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uint_transpose(m: *mut gsl_matrix_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_transpose_memcpy(
         dest: *mut gsl_matrix_uint,
         src: *const gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_transpose_tricpy(
@@ -11104,19 +10964,19 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_uint,
         src: *const gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uint_max(m: *const gsl_matrix_uint) -> ::std::os::raw::c_uint;
+    pub fn gsl_matrix_uint_max(m: *const gsl_matrix_uint) -> ::core::ffi::c_uint;
 }
 extern "C" {
-    pub fn gsl_matrix_uint_min(m: *const gsl_matrix_uint) -> ::std::os::raw::c_uint;
+    pub fn gsl_matrix_uint_min(m: *const gsl_matrix_uint) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_matrix_uint_minmax(
         m: *const gsl_matrix_uint,
-        min_out: *mut ::std::os::raw::c_uint,
-        max_out: *mut ::std::os::raw::c_uint,
+        min_out: *mut ::core::ffi::c_uint,
+        max_out: *mut ::core::ffi::c_uint,
     );
 }
 extern "C" {
@@ -11138,124 +10998,119 @@ extern "C" {
     pub fn gsl_matrix_uint_equal(
         a: *const gsl_matrix_uint,
         b: *const gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uint_isnull(m: *const gsl_matrix_uint) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uint_isnull(m: *const gsl_matrix_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uint_ispos(m: *const gsl_matrix_uint) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uint_ispos(m: *const gsl_matrix_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uint_isneg(m: *const gsl_matrix_uint) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uint_isneg(m: *const gsl_matrix_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uint_isnonneg(m: *const gsl_matrix_uint) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uint_isnonneg(m: *const gsl_matrix_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uint_norm1(m: *const gsl_matrix_uint) -> ::std::os::raw::c_uint;
+    pub fn gsl_matrix_uint_norm1(m: *const gsl_matrix_uint) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_matrix_uint_add(
         a: *mut gsl_matrix_uint,
         b: *const gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_sub(
         a: *mut gsl_matrix_uint,
         b: *const gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_mul_elements(
         a: *mut gsl_matrix_uint,
         b: *const gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_div_elements(
         a: *mut gsl_matrix_uint,
         b: *const gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uint_scale(a: *mut gsl_matrix_uint, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uint_scale(a: *mut gsl_matrix_uint, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_scale_rows(
         a: *mut gsl_matrix_uint,
         x: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_scale_columns(
         a: *mut gsl_matrix_uint,
         x: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uint_add_constant(a: *mut gsl_matrix_uint, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uint_add_constant(a: *mut gsl_matrix_uint, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uint_add_diagonal(a: *mut gsl_matrix_uint, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uint_add_diagonal(a: *mut gsl_matrix_uint, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_get_row(
         v: *mut gsl_vector_uint,
         m: *const gsl_matrix_uint,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_get_col(
         v: *mut gsl_vector_uint,
         m: *const gsl_matrix_uint,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_set_row(
         m: *mut gsl_matrix_uint,
         i: usize,
         v: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_set_col(
         m: *mut gsl_matrix_uint,
         j: usize,
         v: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uint_get(
         m: *const gsl_matrix_uint,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_uint;
+    ) -> ::core::ffi::c_uint;
 }
 extern "C" {
-    pub fn gsl_matrix_uint_set(
-        m: *mut gsl_matrix_uint,
-        i: usize,
-        j: usize,
-        x: ::std::os::raw::c_uint,
-    );
+    pub fn gsl_matrix_uint_set(m: *mut gsl_matrix_uint, i: usize, j: usize, x: ::core::ffi::c_uint);
 }
 extern "C" {
     pub fn gsl_matrix_uint_ptr(
         m: *mut gsl_matrix_uint,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_uint;
+    ) -> *mut ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_matrix_uint_const_ptr(
         m: *const gsl_matrix_uint,
         i: usize,
         j: usize,
-    ) -> *const ::std::os::raw::c_uint;
+    ) -> *const ::core::ffi::c_uint;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -11268,46 +11123,44 @@ extern "C" {
         n1: usize,
         n2: usize,
         nzmax: usize,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_uint;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_free(m: *mut gsl_spmatrix_uint);
 }
 extern "C" {
-    pub fn gsl_spmatrix_uint_realloc(
-        nzmax: usize,
-        m: *mut gsl_spmatrix_uint,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_uint_realloc(nzmax: usize, m: *mut gsl_spmatrix_uint)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_nnz(m: *const gsl_spmatrix_uint) -> usize;
 }
 extern "C" {
-    pub fn gsl_spmatrix_uint_type(m: *const gsl_spmatrix_uint) -> *const ::std::os::raw::c_char;
+    pub fn gsl_spmatrix_uint_type(m: *const gsl_spmatrix_uint) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_spmatrix_uint_set_zero(m: *mut gsl_spmatrix_uint) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_uint_set_zero(m: *mut gsl_spmatrix_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_uint_tree_rebuild(m: *mut gsl_spmatrix_uint) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_uint_tree_rebuild(m: *mut gsl_spmatrix_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_csc(
         dest: *mut gsl_spmatrix_uint,
         src: *const gsl_spmatrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_csr(
         dest: *mut gsl_spmatrix_uint,
         src: *const gsl_spmatrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_compress(
         src: *const gsl_spmatrix_uint,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_uint;
 }
 extern "C" {
@@ -11323,14 +11176,14 @@ extern "C" {
     pub fn gsl_spmatrix_uint_memcpy(
         dest: *mut gsl_spmatrix_uint,
         src: *const gsl_spmatrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_fprintf(
         stream: *mut FILE,
         m: *const gsl_spmatrix_uint,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_fscanf(stream: *mut FILE) -> *mut gsl_spmatrix_uint;
@@ -11339,125 +11192,125 @@ extern "C" {
     pub fn gsl_spmatrix_uint_fwrite(
         stream: *mut FILE,
         m: *const gsl_spmatrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_fread(
         stream: *mut FILE,
         m: *mut gsl_spmatrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_get(
         m: *const gsl_spmatrix_uint,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_uint;
+    ) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_set(
         m: *mut gsl_spmatrix_uint,
         i: usize,
         j: usize,
-        x: ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_ptr(
         m: *const gsl_spmatrix_uint,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_uint;
+    ) -> *mut ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_minmax(
         m: *const gsl_spmatrix_uint,
-        min_out: *mut ::std::os::raw::c_uint,
-        max_out: *mut ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_int;
+        min_out: *mut ::core::ffi::c_uint,
+        max_out: *mut ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_min_index(
         m: *const gsl_spmatrix_uint,
         imin_out: *mut usize,
         jmin_out: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_scale(
         m: *mut gsl_spmatrix_uint,
-        x: ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_scale_columns(
         m: *mut gsl_spmatrix_uint,
         x: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_scale_rows(
         m: *mut gsl_spmatrix_uint,
         x: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_add(
         c: *mut gsl_spmatrix_uint,
         a: *const gsl_spmatrix_uint,
         b: *const gsl_spmatrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_dense_add(
         a: *mut gsl_matrix_uint,
         b: *const gsl_spmatrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_dense_sub(
         a: *mut gsl_matrix_uint,
         b: *const gsl_spmatrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_d2sp(
         T: *mut gsl_spmatrix_uint,
         A: *const gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_sp2d(
         A: *mut gsl_matrix_uint,
         S: *const gsl_spmatrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_add_to_dense(
         a: *mut gsl_matrix_uint,
         b: *const gsl_spmatrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_equal(
         a: *const gsl_spmatrix_uint,
         b: *const gsl_spmatrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_uint_norm1(a: *const gsl_spmatrix_uint) -> ::std::os::raw::c_uint;
+    pub fn gsl_spmatrix_uint_norm1(a: *const gsl_spmatrix_uint) -> ::core::ffi::c_uint;
 }
 extern "C" {
-    pub fn gsl_spmatrix_uint_transpose(m: *mut gsl_spmatrix_uint) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_uint_transpose(m: *mut gsl_spmatrix_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_uint_transpose2(m: *mut gsl_spmatrix_uint) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_uint_transpose2(m: *mut gsl_spmatrix_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uint_transpose_memcpy(
         dest: *mut gsl_spmatrix_uint,
         src: *const gsl_spmatrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -11473,71 +11326,68 @@ extern "C" {
     pub fn gsl_block_int_free(b: *mut gsl_block_int);
 }
 extern "C" {
-    pub fn gsl_block_int_fread(stream: *mut FILE, b: *mut gsl_block_int) -> ::std::os::raw::c_int;
+    pub fn gsl_block_int_fread(stream: *mut FILE, b: *mut gsl_block_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_block_int_fwrite(
-        stream: *mut FILE,
-        b: *const gsl_block_int,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_int_fwrite(stream: *mut FILE, b: *const gsl_block_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_block_int_fscanf(stream: *mut FILE, b: *mut gsl_block_int) -> ::std::os::raw::c_int;
+    pub fn gsl_block_int_fscanf(stream: *mut FILE, b: *mut gsl_block_int) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_int_fprintf(
         stream: *mut FILE,
         b: *const gsl_block_int,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_int_raw_fread(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_int,
+        b: *mut ::core::ffi::c_int,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_int_raw_fwrite(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_int,
+        b: *const ::core::ffi::c_int,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_int_raw_fscanf(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_int,
+        b: *mut ::core::ffi::c_int,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_int_raw_fprintf(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_int,
+        b: *const ::core::ffi::c_int,
         n: usize,
         stride: usize,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_int_size(b: *const gsl_block_int) -> usize;
 }
 extern "C" {
-    pub fn gsl_block_int_data(b: *const gsl_block_int) -> *mut ::std::os::raw::c_int;
+    pub fn gsl_block_int_data(b: *const gsl_block_int) -> *mut ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_vector_int {
     pub size: usize,
     pub stride: usize,
-    pub data: *mut ::std::os::raw::c_int,
+    pub data: *mut ::core::ffi::c_int,
     pub block: *mut gsl_block_int,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -11577,27 +11427,24 @@ extern "C" {
     pub fn gsl_vector_int_free(v: *mut gsl_vector_int);
 }
 extern "C" {
-    pub fn gsl_vector_int_view_array(
-        v: *mut ::std::os::raw::c_int,
-        n: usize,
-    ) -> _gsl_vector_int_view;
+    pub fn gsl_vector_int_view_array(v: *mut ::core::ffi::c_int, n: usize) -> _gsl_vector_int_view;
 }
 extern "C" {
     pub fn gsl_vector_int_view_array_with_stride(
-        base: *mut ::std::os::raw::c_int,
+        base: *mut ::core::ffi::c_int,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_int_view;
 }
 extern "C" {
     pub fn gsl_vector_int_const_view_array(
-        v: *const ::std::os::raw::c_int,
+        v: *const ::core::ffi::c_int,
         n: usize,
     ) -> _gsl_vector_int_const_view;
 }
 extern "C" {
     pub fn gsl_vector_int_const_view_array_with_stride(
-        base: *const ::std::os::raw::c_int,
+        base: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_int_const_view;
@@ -11636,67 +11483,61 @@ extern "C" {
     pub fn gsl_vector_int_set_zero(v: *mut gsl_vector_int);
 }
 extern "C" {
-    pub fn gsl_vector_int_set_all(v: *mut gsl_vector_int, x: ::std::os::raw::c_int);
+    pub fn gsl_vector_int_set_all(v: *mut gsl_vector_int, x: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn gsl_vector_int_set_basis(v: *mut gsl_vector_int, i: usize) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_int_set_basis(v: *mut gsl_vector_int, i: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_int_fread(stream: *mut FILE, v: *mut gsl_vector_int)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_vector_int_fread(stream: *mut FILE, v: *mut gsl_vector_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_int_fwrite(
-        stream: *mut FILE,
-        v: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_int_fwrite(stream: *mut FILE, v: *const gsl_vector_int)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_int_fscanf(
-        stream: *mut FILE,
-        v: *mut gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_int_fscanf(stream: *mut FILE, v: *mut gsl_vector_int) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_int_fprintf(
         stream: *mut FILE,
         v: *const gsl_vector_int,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_int_memcpy(
         dest: *mut gsl_vector_int,
         src: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_int_reverse(v: *mut gsl_vector_int) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_int_reverse(v: *mut gsl_vector_int) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_int_swap(
         v: *mut gsl_vector_int,
         w: *mut gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_int_swap_elements(
         v: *mut gsl_vector_int,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_int_max(v: *const gsl_vector_int) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_int_max(v: *const gsl_vector_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_int_min(v: *const gsl_vector_int) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_int_min(v: *const gsl_vector_int) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_int_minmax(
         v: *const gsl_vector_int,
-        min_out: *mut ::std::os::raw::c_int,
-        max_out: *mut ::std::os::raw::c_int,
+        min_out: *mut ::core::ffi::c_int,
+        max_out: *mut ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -11716,78 +11557,78 @@ extern "C" {
     pub fn gsl_vector_int_add(
         a: *mut gsl_vector_int,
         b: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_int_sub(
         a: *mut gsl_vector_int,
         b: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_int_mul(
         a: *mut gsl_vector_int,
         b: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_int_div(
         a: *mut gsl_vector_int,
         b: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_int_scale(
         a: *mut gsl_vector_int,
-        x: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_int_add_constant(a: *mut gsl_vector_int, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_int_add_constant(a: *mut gsl_vector_int, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_int_axpby(
-        alpha: ::std::os::raw::c_int,
+        alpha: ::core::ffi::c_int,
         x: *const gsl_vector_int,
-        beta: ::std::os::raw::c_int,
+        beta: ::core::ffi::c_int,
         y: *mut gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_int_sum(a: *const gsl_vector_int) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_int_sum(a: *const gsl_vector_int) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_int_equal(
         u: *const gsl_vector_int,
         v: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_int_isnull(v: *const gsl_vector_int) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_int_isnull(v: *const gsl_vector_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_int_ispos(v: *const gsl_vector_int) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_int_ispos(v: *const gsl_vector_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_int_isneg(v: *const gsl_vector_int) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_int_isneg(v: *const gsl_vector_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_int_isnonneg(v: *const gsl_vector_int) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_int_isnonneg(v: *const gsl_vector_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_int_get(v: *const gsl_vector_int, i: usize) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_int_get(v: *const gsl_vector_int, i: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_int_set(v: *mut gsl_vector_int, i: usize, x: ::std::os::raw::c_int);
+    pub fn gsl_vector_int_set(v: *mut gsl_vector_int, i: usize, x: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn gsl_vector_int_ptr(v: *mut gsl_vector_int, i: usize) -> *mut ::std::os::raw::c_int;
+    pub fn gsl_vector_int_ptr(v: *mut gsl_vector_int, i: usize) -> *mut ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_int_const_ptr(
         v: *const gsl_vector_int,
         i: usize,
-    ) -> *const ::std::os::raw::c_int;
+    ) -> *const ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -11795,9 +11636,9 @@ pub struct gsl_matrix_int {
     pub size1: usize,
     pub size2: usize,
     pub tda: usize,
-    pub data: *mut ::std::os::raw::c_int,
+    pub data: *mut ::core::ffi::c_int,
     pub block: *mut gsl_block_int,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -11892,14 +11733,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_int_view_array(
-        base: *mut ::std::os::raw::c_int,
+        base: *mut ::core::ffi::c_int,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_int_view;
 }
 extern "C" {
     pub fn gsl_matrix_int_view_array_with_tda(
-        base: *mut ::std::os::raw::c_int,
+        base: *mut ::core::ffi::c_int,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -11974,14 +11815,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_int_const_view_array(
-        base: *const ::std::os::raw::c_int,
+        base: *const ::core::ffi::c_int,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_int_const_view;
 }
 extern "C" {
     pub fn gsl_matrix_int_const_view_array_with_tda(
-        base: *const ::std::os::raw::c_int,
+        base: *const ::core::ffi::c_int,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -12009,42 +11850,36 @@ extern "C" {
     pub fn gsl_matrix_int_set_identity(m: *mut gsl_matrix_int);
 }
 extern "C" {
-    pub fn gsl_matrix_int_set_all(m: *mut gsl_matrix_int, x: ::std::os::raw::c_int);
+    pub fn gsl_matrix_int_set_all(m: *mut gsl_matrix_int, x: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn gsl_matrix_int_fread(stream: *mut FILE, m: *mut gsl_matrix_int)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_fread(stream: *mut FILE, m: *mut gsl_matrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_fwrite(
-        stream: *mut FILE,
-        m: *const gsl_matrix_int,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_fwrite(stream: *mut FILE, m: *const gsl_matrix_int)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_fscanf(
-        stream: *mut FILE,
-        m: *mut gsl_matrix_int,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_fscanf(stream: *mut FILE, m: *mut gsl_matrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_fprintf(
         stream: *mut FILE,
         m: *const gsl_matrix_int,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_memcpy(
         dest: *mut gsl_matrix_int,
         src: *const gsl_matrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_swap(
         m1: *mut gsl_matrix_int,
         m2: *mut gsl_matrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_tricpy(
@@ -12052,37 +11887,37 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_int,
         src: *const gsl_matrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_swap_rows(
         m: *mut gsl_matrix_int,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_swap_columns(
         m: *mut gsl_matrix_int,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_swap_rowcol(
         m: *mut gsl_matrix_int,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_transpose(m: *mut gsl_matrix_int) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_transpose(m: *mut gsl_matrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_transpose_memcpy(
         dest: *mut gsl_matrix_int,
         src: *const gsl_matrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_transpose_tricpy(
@@ -12090,19 +11925,19 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_int,
         src: *const gsl_matrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_max(m: *const gsl_matrix_int) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_max(m: *const gsl_matrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_min(m: *const gsl_matrix_int) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_min(m: *const gsl_matrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_minmax(
         m: *const gsl_matrix_int,
-        min_out: *mut ::std::os::raw::c_int,
-        max_out: *mut ::std::os::raw::c_int,
+        min_out: *mut ::core::ffi::c_int,
+        max_out: *mut ::core::ffi::c_int,
     );
 }
 extern "C" {
@@ -12124,119 +11959,115 @@ extern "C" {
     pub fn gsl_matrix_int_equal(
         a: *const gsl_matrix_int,
         b: *const gsl_matrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_isnull(m: *const gsl_matrix_int) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_isnull(m: *const gsl_matrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_ispos(m: *const gsl_matrix_int) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_ispos(m: *const gsl_matrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_isneg(m: *const gsl_matrix_int) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_isneg(m: *const gsl_matrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_isnonneg(m: *const gsl_matrix_int) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_isnonneg(m: *const gsl_matrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_norm1(m: *const gsl_matrix_int) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_norm1(m: *const gsl_matrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_add(
         a: *mut gsl_matrix_int,
         b: *const gsl_matrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_sub(
         a: *mut gsl_matrix_int,
         b: *const gsl_matrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_mul_elements(
         a: *mut gsl_matrix_int,
         b: *const gsl_matrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_div_elements(
         a: *mut gsl_matrix_int,
         b: *const gsl_matrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_scale(a: *mut gsl_matrix_int, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_scale(a: *mut gsl_matrix_int, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_scale_rows(
         a: *mut gsl_matrix_int,
         x: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_scale_columns(
         a: *mut gsl_matrix_int,
         x: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_add_constant(a: *mut gsl_matrix_int, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_add_constant(a: *mut gsl_matrix_int, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_add_diagonal(a: *mut gsl_matrix_int, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_add_diagonal(a: *mut gsl_matrix_int, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_get_row(
         v: *mut gsl_vector_int,
         m: *const gsl_matrix_int,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_get_col(
         v: *mut gsl_vector_int,
         m: *const gsl_matrix_int,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_set_row(
         m: *mut gsl_matrix_int,
         i: usize,
         v: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_set_col(
         m: *mut gsl_matrix_int,
         j: usize,
         v: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_get(
-        m: *const gsl_matrix_int,
-        i: usize,
-        j: usize,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_int_get(m: *const gsl_matrix_int, i: usize, j: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_int_set(m: *mut gsl_matrix_int, i: usize, j: usize, x: ::std::os::raw::c_int);
+    pub fn gsl_matrix_int_set(m: *mut gsl_matrix_int, i: usize, j: usize, x: ::core::ffi::c_int);
 }
 extern "C" {
     pub fn gsl_matrix_int_ptr(
         m: *mut gsl_matrix_int,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_int;
+    ) -> *mut ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_int_const_ptr(
         m: *const gsl_matrix_int,
         i: usize,
         j: usize,
-    ) -> *const ::std::os::raw::c_int;
+    ) -> *const ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -12249,46 +12080,43 @@ extern "C" {
         n1: usize,
         n2: usize,
         nzmax: usize,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_free(m: *mut gsl_spmatrix_int);
 }
 extern "C" {
-    pub fn gsl_spmatrix_int_realloc(
-        nzmax: usize,
-        m: *mut gsl_spmatrix_int,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_int_realloc(nzmax: usize, m: *mut gsl_spmatrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_nnz(m: *const gsl_spmatrix_int) -> usize;
 }
 extern "C" {
-    pub fn gsl_spmatrix_int_type(m: *const gsl_spmatrix_int) -> *const ::std::os::raw::c_char;
+    pub fn gsl_spmatrix_int_type(m: *const gsl_spmatrix_int) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_spmatrix_int_set_zero(m: *mut gsl_spmatrix_int) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_int_set_zero(m: *mut gsl_spmatrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_int_tree_rebuild(m: *mut gsl_spmatrix_int) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_int_tree_rebuild(m: *mut gsl_spmatrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_csc(
         dest: *mut gsl_spmatrix_int,
         src: *const gsl_spmatrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_csr(
         dest: *mut gsl_spmatrix_int,
         src: *const gsl_spmatrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_compress(
         src: *const gsl_spmatrix_int,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_int;
 }
 extern "C" {
@@ -12304,14 +12132,14 @@ extern "C" {
     pub fn gsl_spmatrix_int_memcpy(
         dest: *mut gsl_spmatrix_int,
         src: *const gsl_spmatrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_fprintf(
         stream: *mut FILE,
         m: *const gsl_spmatrix_int,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_fscanf(stream: *mut FILE) -> *mut gsl_spmatrix_int;
@@ -12320,125 +12148,125 @@ extern "C" {
     pub fn gsl_spmatrix_int_fwrite(
         stream: *mut FILE,
         m: *const gsl_spmatrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_fread(
         stream: *mut FILE,
         m: *mut gsl_spmatrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_get(
         m: *const gsl_spmatrix_int,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_set(
         m: *mut gsl_spmatrix_int,
         i: usize,
         j: usize,
-        x: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_ptr(
         m: *const gsl_spmatrix_int,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_int;
+    ) -> *mut ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_minmax(
         m: *const gsl_spmatrix_int,
-        min_out: *mut ::std::os::raw::c_int,
-        max_out: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        min_out: *mut ::core::ffi::c_int,
+        max_out: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_min_index(
         m: *const gsl_spmatrix_int,
         imin_out: *mut usize,
         jmin_out: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_scale(
         m: *mut gsl_spmatrix_int,
-        x: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_scale_columns(
         m: *mut gsl_spmatrix_int,
         x: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_scale_rows(
         m: *mut gsl_spmatrix_int,
         x: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_add(
         c: *mut gsl_spmatrix_int,
         a: *const gsl_spmatrix_int,
         b: *const gsl_spmatrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_dense_add(
         a: *mut gsl_matrix_int,
         b: *const gsl_spmatrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_dense_sub(
         a: *mut gsl_matrix_int,
         b: *const gsl_spmatrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_d2sp(
         T: *mut gsl_spmatrix_int,
         A: *const gsl_matrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_sp2d(
         A: *mut gsl_matrix_int,
         S: *const gsl_spmatrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_add_to_dense(
         a: *mut gsl_matrix_int,
         b: *const gsl_spmatrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_equal(
         a: *const gsl_spmatrix_int,
         b: *const gsl_spmatrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_int_norm1(a: *const gsl_spmatrix_int) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_int_norm1(a: *const gsl_spmatrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_int_transpose(m: *mut gsl_spmatrix_int) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_int_transpose(m: *mut gsl_spmatrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_int_transpose2(m: *mut gsl_spmatrix_int) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_int_transpose2(m: *mut gsl_spmatrix_int) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_int_transpose_memcpy(
         dest: *mut gsl_spmatrix_int,
         src: *const gsl_spmatrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -12457,74 +12285,74 @@ extern "C" {
     pub fn gsl_block_ushort_fread(
         stream: *mut FILE,
         b: *mut gsl_block_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ushort_fwrite(
         stream: *mut FILE,
         b: *const gsl_block_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ushort_fscanf(
         stream: *mut FILE,
         b: *mut gsl_block_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ushort_fprintf(
         stream: *mut FILE,
         b: *const gsl_block_ushort,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ushort_raw_fread(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_ushort,
+        b: *mut ::core::ffi::c_ushort,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ushort_raw_fwrite(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_ushort,
+        b: *const ::core::ffi::c_ushort,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ushort_raw_fscanf(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_ushort,
+        b: *mut ::core::ffi::c_ushort,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ushort_raw_fprintf(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_ushort,
+        b: *const ::core::ffi::c_ushort,
         n: usize,
         stride: usize,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_ushort_size(b: *const gsl_block_ushort) -> usize;
 }
 extern "C" {
-    pub fn gsl_block_ushort_data(b: *const gsl_block_ushort) -> *mut ::std::os::raw::c_ushort;
+    pub fn gsl_block_ushort_data(b: *const gsl_block_ushort) -> *mut ::core::ffi::c_ushort;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_vector_ushort {
     pub size: usize,
     pub stride: usize,
-    pub data: *mut ::std::os::raw::c_ushort,
+    pub data: *mut ::core::ffi::c_ushort,
     pub block: *mut gsl_block_ushort,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -12565,26 +12393,26 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_vector_ushort_view_array(
-        v: *mut ::std::os::raw::c_ushort,
+        v: *mut ::core::ffi::c_ushort,
         n: usize,
     ) -> _gsl_vector_ushort_view;
 }
 extern "C" {
     pub fn gsl_vector_ushort_view_array_with_stride(
-        base: *mut ::std::os::raw::c_ushort,
+        base: *mut ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_ushort_view;
 }
 extern "C" {
     pub fn gsl_vector_ushort_const_view_array(
-        v: *const ::std::os::raw::c_ushort,
+        v: *const ::core::ffi::c_ushort,
         n: usize,
     ) -> _gsl_vector_ushort_const_view;
 }
 extern "C" {
     pub fn gsl_vector_ushort_const_view_array_with_stride(
-        base: *const ::std::os::raw::c_ushort,
+        base: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_ushort_const_view;
@@ -12623,72 +12451,69 @@ extern "C" {
     pub fn gsl_vector_ushort_set_zero(v: *mut gsl_vector_ushort);
 }
 extern "C" {
-    pub fn gsl_vector_ushort_set_all(v: *mut gsl_vector_ushort, x: ::std::os::raw::c_ushort);
+    pub fn gsl_vector_ushort_set_all(v: *mut gsl_vector_ushort, x: ::core::ffi::c_ushort);
 }
 extern "C" {
-    pub fn gsl_vector_ushort_set_basis(
-        v: *mut gsl_vector_ushort,
-        i: usize,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ushort_set_basis(v: *mut gsl_vector_ushort, i: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ushort_fread(
         stream: *mut FILE,
         v: *mut gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ushort_fwrite(
         stream: *mut FILE,
         v: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ushort_fscanf(
         stream: *mut FILE,
         v: *mut gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ushort_fprintf(
         stream: *mut FILE,
         v: *const gsl_vector_ushort,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ushort_memcpy(
         dest: *mut gsl_vector_ushort,
         src: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ushort_reverse(v: *mut gsl_vector_ushort) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ushort_reverse(v: *mut gsl_vector_ushort) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ushort_swap(
         v: *mut gsl_vector_ushort,
         w: *mut gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ushort_swap_elements(
         v: *mut gsl_vector_ushort,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ushort_max(v: *const gsl_vector_ushort) -> ::std::os::raw::c_ushort;
+    pub fn gsl_vector_ushort_max(v: *const gsl_vector_ushort) -> ::core::ffi::c_ushort;
 }
 extern "C" {
-    pub fn gsl_vector_ushort_min(v: *const gsl_vector_ushort) -> ::std::os::raw::c_ushort;
+    pub fn gsl_vector_ushort_min(v: *const gsl_vector_ushort) -> ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn gsl_vector_ushort_minmax(
         v: *const gsl_vector_ushort,
-        min_out: *mut ::std::os::raw::c_ushort,
-        max_out: *mut ::std::os::raw::c_ushort,
+        min_out: *mut ::core::ffi::c_ushort,
+        max_out: *mut ::core::ffi::c_ushort,
     );
 }
 extern "C" {
@@ -12708,85 +12533,79 @@ extern "C" {
     pub fn gsl_vector_ushort_add(
         a: *mut gsl_vector_ushort,
         b: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ushort_sub(
         a: *mut gsl_vector_ushort,
         b: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ushort_mul(
         a: *mut gsl_vector_ushort,
         b: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ushort_div(
         a: *mut gsl_vector_ushort,
         b: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ushort_scale(
         a: *mut gsl_vector_ushort,
-        x: ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_ushort,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ushort_add_constant(
-        a: *mut gsl_vector_ushort,
-        x: f64,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ushort_add_constant(a: *mut gsl_vector_ushort, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_ushort_axpby(
-        alpha: ::std::os::raw::c_ushort,
+        alpha: ::core::ffi::c_ushort,
         x: *const gsl_vector_ushort,
-        beta: ::std::os::raw::c_ushort,
+        beta: ::core::ffi::c_ushort,
         y: *mut gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ushort_sum(a: *const gsl_vector_ushort) -> ::std::os::raw::c_ushort;
+    pub fn gsl_vector_ushort_sum(a: *const gsl_vector_ushort) -> ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn gsl_vector_ushort_equal(
         u: *const gsl_vector_ushort,
         v: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ushort_isnull(v: *const gsl_vector_ushort) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ushort_isnull(v: *const gsl_vector_ushort) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ushort_ispos(v: *const gsl_vector_ushort) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ushort_ispos(v: *const gsl_vector_ushort) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ushort_isneg(v: *const gsl_vector_ushort) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ushort_isneg(v: *const gsl_vector_ushort) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ushort_isnonneg(v: *const gsl_vector_ushort) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_ushort_isnonneg(v: *const gsl_vector_ushort) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_ushort_get(v: *const gsl_vector_ushort, i: usize)
-        -> ::std::os::raw::c_ushort;
+    pub fn gsl_vector_ushort_get(v: *const gsl_vector_ushort, i: usize) -> ::core::ffi::c_ushort;
 }
 extern "C" {
-    pub fn gsl_vector_ushort_set(v: *mut gsl_vector_ushort, i: usize, x: ::std::os::raw::c_ushort);
+    pub fn gsl_vector_ushort_set(v: *mut gsl_vector_ushort, i: usize, x: ::core::ffi::c_ushort);
 }
 extern "C" {
-    pub fn gsl_vector_ushort_ptr(
-        v: *mut gsl_vector_ushort,
-        i: usize,
-    ) -> *mut ::std::os::raw::c_ushort;
+    pub fn gsl_vector_ushort_ptr(v: *mut gsl_vector_ushort, i: usize)
+        -> *mut ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn gsl_vector_ushort_const_ptr(
         v: *const gsl_vector_ushort,
         i: usize,
-    ) -> *const ::std::os::raw::c_ushort;
+    ) -> *const ::core::ffi::c_ushort;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -12794,9 +12613,9 @@ pub struct gsl_matrix_ushort {
     pub size1: usize,
     pub size2: usize,
     pub tda: usize,
-    pub data: *mut ::std::os::raw::c_ushort,
+    pub data: *mut ::core::ffi::c_ushort,
     pub block: *mut gsl_block_ushort,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -12898,14 +12717,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_ushort_view_array(
-        base: *mut ::std::os::raw::c_ushort,
+        base: *mut ::core::ffi::c_ushort,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_ushort_view;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_view_array_with_tda(
-        base: *mut ::std::os::raw::c_ushort,
+        base: *mut ::core::ffi::c_ushort,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -12982,14 +12801,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_ushort_const_view_array(
-        base: *const ::std::os::raw::c_ushort,
+        base: *const ::core::ffi::c_ushort,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_ushort_const_view;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_const_view_array_with_tda(
-        base: *const ::std::os::raw::c_ushort,
+        base: *const ::core::ffi::c_ushort,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -13017,44 +12836,44 @@ extern "C" {
     pub fn gsl_matrix_ushort_set_identity(m: *mut gsl_matrix_ushort);
 }
 extern "C" {
-    pub fn gsl_matrix_ushort_set_all(m: *mut gsl_matrix_ushort, x: ::std::os::raw::c_ushort);
+    pub fn gsl_matrix_ushort_set_all(m: *mut gsl_matrix_ushort, x: ::core::ffi::c_ushort);
 }
 extern "C" {
     pub fn gsl_matrix_ushort_fread(
         stream: *mut FILE,
         m: *mut gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_fwrite(
         stream: *mut FILE,
         m: *const gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_fscanf(
         stream: *mut FILE,
         m: *mut gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_fprintf(
         stream: *mut FILE,
         m: *const gsl_matrix_ushort,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_memcpy(
         dest: *mut gsl_matrix_ushort,
         src: *const gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_swap(
         m1: *mut gsl_matrix_ushort,
         m2: *mut gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_tricpy(
@@ -13062,37 +12881,37 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_ushort,
         src: *const gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_swap_rows(
         m: *mut gsl_matrix_ushort,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_swap_columns(
         m: *mut gsl_matrix_ushort,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_swap_rowcol(
         m: *mut gsl_matrix_ushort,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ushort_transpose(m: *mut gsl_matrix_ushort) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ushort_transpose(m: *mut gsl_matrix_ushort) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_transpose_memcpy(
         dest: *mut gsl_matrix_ushort,
         src: *const gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_transpose_tricpy(
@@ -13100,19 +12919,19 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_ushort,
         src: *const gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ushort_max(m: *const gsl_matrix_ushort) -> ::std::os::raw::c_ushort;
+    pub fn gsl_matrix_ushort_max(m: *const gsl_matrix_ushort) -> ::core::ffi::c_ushort;
 }
 extern "C" {
-    pub fn gsl_matrix_ushort_min(m: *const gsl_matrix_ushort) -> ::std::os::raw::c_ushort;
+    pub fn gsl_matrix_ushort_min(m: *const gsl_matrix_ushort) -> ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_minmax(
         m: *const gsl_matrix_ushort,
-        min_out: *mut ::std::os::raw::c_ushort,
-        max_out: *mut ::std::os::raw::c_ushort,
+        min_out: *mut ::core::ffi::c_ushort,
+        max_out: *mut ::core::ffi::c_ushort,
     );
 }
 extern "C" {
@@ -13142,115 +12961,109 @@ extern "C" {
     pub fn gsl_matrix_ushort_equal(
         a: *const gsl_matrix_ushort,
         b: *const gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ushort_isnull(m: *const gsl_matrix_ushort) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ushort_isnull(m: *const gsl_matrix_ushort) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ushort_ispos(m: *const gsl_matrix_ushort) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ushort_ispos(m: *const gsl_matrix_ushort) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ushort_isneg(m: *const gsl_matrix_ushort) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ushort_isneg(m: *const gsl_matrix_ushort) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ushort_isnonneg(m: *const gsl_matrix_ushort) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ushort_isnonneg(m: *const gsl_matrix_ushort) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ushort_norm1(m: *const gsl_matrix_ushort) -> ::std::os::raw::c_ushort;
+    pub fn gsl_matrix_ushort_norm1(m: *const gsl_matrix_ushort) -> ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_add(
         a: *mut gsl_matrix_ushort,
         b: *const gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_sub(
         a: *mut gsl_matrix_ushort,
         b: *const gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_mul_elements(
         a: *mut gsl_matrix_ushort,
         b: *const gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_div_elements(
         a: *mut gsl_matrix_ushort,
         b: *const gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ushort_scale(a: *mut gsl_matrix_ushort, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ushort_scale(a: *mut gsl_matrix_ushort, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_scale_rows(
         a: *mut gsl_matrix_ushort,
         x: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_scale_columns(
         a: *mut gsl_matrix_ushort,
         x: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ushort_add_constant(
-        a: *mut gsl_matrix_ushort,
-        x: f64,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ushort_add_constant(a: *mut gsl_matrix_ushort, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_ushort_add_diagonal(
-        a: *mut gsl_matrix_ushort,
-        x: f64,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_ushort_add_diagonal(a: *mut gsl_matrix_ushort, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_get_row(
         v: *mut gsl_vector_ushort,
         m: *const gsl_matrix_ushort,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_get_col(
         v: *mut gsl_vector_ushort,
         m: *const gsl_matrix_ushort,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_set_row(
         m: *mut gsl_matrix_ushort,
         i: usize,
         v: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_set_col(
         m: *mut gsl_matrix_ushort,
         j: usize,
         v: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_get(
         m: *const gsl_matrix_ushort,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_ushort;
+    ) -> ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_set(
         m: *mut gsl_matrix_ushort,
         i: usize,
         j: usize,
-        x: ::std::os::raw::c_ushort,
+        x: ::core::ffi::c_ushort,
     );
 }
 extern "C" {
@@ -13258,14 +13071,14 @@ extern "C" {
         m: *mut gsl_matrix_ushort,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_ushort;
+    ) -> *mut ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn gsl_matrix_ushort_const_ptr(
         m: *const gsl_matrix_ushort,
         i: usize,
         j: usize,
-    ) -> *const ::std::os::raw::c_ushort;
+    ) -> *const ::core::ffi::c_ushort;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -13278,7 +13091,7 @@ extern "C" {
         n1: usize,
         n2: usize,
         nzmax: usize,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_ushort;
 }
 extern "C" {
@@ -13288,37 +13101,36 @@ extern "C" {
     pub fn gsl_spmatrix_ushort_realloc(
         nzmax: usize,
         m: *mut gsl_spmatrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_nnz(m: *const gsl_spmatrix_ushort) -> usize;
 }
 extern "C" {
-    pub fn gsl_spmatrix_ushort_type(m: *const gsl_spmatrix_ushort)
-        -> *const ::std::os::raw::c_char;
+    pub fn gsl_spmatrix_ushort_type(m: *const gsl_spmatrix_ushort) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_spmatrix_ushort_set_zero(m: *mut gsl_spmatrix_ushort) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_ushort_set_zero(m: *mut gsl_spmatrix_ushort) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_ushort_tree_rebuild(m: *mut gsl_spmatrix_ushort) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_ushort_tree_rebuild(m: *mut gsl_spmatrix_ushort) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_csc(
         dest: *mut gsl_spmatrix_ushort,
         src: *const gsl_spmatrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_csr(
         dest: *mut gsl_spmatrix_ushort,
         src: *const gsl_spmatrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_compress(
         src: *const gsl_spmatrix_ushort,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_ushort;
 }
 extern "C" {
@@ -13335,14 +13147,14 @@ extern "C" {
     pub fn gsl_spmatrix_ushort_memcpy(
         dest: *mut gsl_spmatrix_ushort,
         src: *const gsl_spmatrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_fprintf(
         stream: *mut FILE,
         m: *const gsl_spmatrix_ushort,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_fscanf(stream: *mut FILE) -> *mut gsl_spmatrix_ushort;
@@ -13351,125 +13163,125 @@ extern "C" {
     pub fn gsl_spmatrix_ushort_fwrite(
         stream: *mut FILE,
         m: *const gsl_spmatrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_fread(
         stream: *mut FILE,
         m: *mut gsl_spmatrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_get(
         m: *const gsl_spmatrix_ushort,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_ushort;
+    ) -> ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_set(
         m: *mut gsl_spmatrix_ushort,
         i: usize,
         j: usize,
-        x: ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_ushort,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_ptr(
         m: *const gsl_spmatrix_ushort,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_ushort;
+    ) -> *mut ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_minmax(
         m: *const gsl_spmatrix_ushort,
-        min_out: *mut ::std::os::raw::c_ushort,
-        max_out: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_int;
+        min_out: *mut ::core::ffi::c_ushort,
+        max_out: *mut ::core::ffi::c_ushort,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_min_index(
         m: *const gsl_spmatrix_ushort,
         imin_out: *mut usize,
         jmin_out: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_scale(
         m: *mut gsl_spmatrix_ushort,
-        x: ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_ushort,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_scale_columns(
         m: *mut gsl_spmatrix_ushort,
         x: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_scale_rows(
         m: *mut gsl_spmatrix_ushort,
         x: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_add(
         c: *mut gsl_spmatrix_ushort,
         a: *const gsl_spmatrix_ushort,
         b: *const gsl_spmatrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_dense_add(
         a: *mut gsl_matrix_ushort,
         b: *const gsl_spmatrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_dense_sub(
         a: *mut gsl_matrix_ushort,
         b: *const gsl_spmatrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_d2sp(
         T: *mut gsl_spmatrix_ushort,
         A: *const gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_sp2d(
         A: *mut gsl_matrix_ushort,
         S: *const gsl_spmatrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_add_to_dense(
         a: *mut gsl_matrix_ushort,
         b: *const gsl_spmatrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_equal(
         a: *const gsl_spmatrix_ushort,
         b: *const gsl_spmatrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_ushort_norm1(a: *const gsl_spmatrix_ushort) -> ::std::os::raw::c_ushort;
+    pub fn gsl_spmatrix_ushort_norm1(a: *const gsl_spmatrix_ushort) -> ::core::ffi::c_ushort;
 }
 extern "C" {
-    pub fn gsl_spmatrix_ushort_transpose(m: *mut gsl_spmatrix_ushort) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_ushort_transpose(m: *mut gsl_spmatrix_ushort) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_ushort_transpose2(m: *mut gsl_spmatrix_ushort) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_ushort_transpose2(m: *mut gsl_spmatrix_ushort) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_ushort_transpose_memcpy(
         dest: *mut gsl_spmatrix_ushort,
         src: *const gsl_spmatrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -13485,77 +13297,72 @@ extern "C" {
     pub fn gsl_block_short_free(b: *mut gsl_block_short);
 }
 extern "C" {
-    pub fn gsl_block_short_fread(
-        stream: *mut FILE,
-        b: *mut gsl_block_short,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_short_fread(stream: *mut FILE, b: *mut gsl_block_short) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_short_fwrite(
         stream: *mut FILE,
         b: *const gsl_block_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_block_short_fscanf(
-        stream: *mut FILE,
-        b: *mut gsl_block_short,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_short_fscanf(stream: *mut FILE, b: *mut gsl_block_short)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_short_fprintf(
         stream: *mut FILE,
         b: *const gsl_block_short,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_short_raw_fread(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_short,
+        b: *mut ::core::ffi::c_short,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_short_raw_fwrite(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_short,
+        b: *const ::core::ffi::c_short,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_short_raw_fscanf(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_short,
+        b: *mut ::core::ffi::c_short,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_short_raw_fprintf(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_short,
+        b: *const ::core::ffi::c_short,
         n: usize,
         stride: usize,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_short_size(b: *const gsl_block_short) -> usize;
 }
 extern "C" {
-    pub fn gsl_block_short_data(b: *const gsl_block_short) -> *mut ::std::os::raw::c_short;
+    pub fn gsl_block_short_data(b: *const gsl_block_short) -> *mut ::core::ffi::c_short;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_vector_short {
     pub size: usize,
     pub stride: usize,
-    pub data: *mut ::std::os::raw::c_short,
+    pub data: *mut ::core::ffi::c_short,
     pub block: *mut gsl_block_short,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -13596,26 +13403,26 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_vector_short_view_array(
-        v: *mut ::std::os::raw::c_short,
+        v: *mut ::core::ffi::c_short,
         n: usize,
     ) -> _gsl_vector_short_view;
 }
 extern "C" {
     pub fn gsl_vector_short_view_array_with_stride(
-        base: *mut ::std::os::raw::c_short,
+        base: *mut ::core::ffi::c_short,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_short_view;
 }
 extern "C" {
     pub fn gsl_vector_short_const_view_array(
-        v: *const ::std::os::raw::c_short,
+        v: *const ::core::ffi::c_short,
         n: usize,
     ) -> _gsl_vector_short_const_view;
 }
 extern "C" {
     pub fn gsl_vector_short_const_view_array_with_stride(
-        base: *const ::std::os::raw::c_short,
+        base: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_short_const_view;
@@ -13654,69 +13461,69 @@ extern "C" {
     pub fn gsl_vector_short_set_zero(v: *mut gsl_vector_short);
 }
 extern "C" {
-    pub fn gsl_vector_short_set_all(v: *mut gsl_vector_short, x: ::std::os::raw::c_short);
+    pub fn gsl_vector_short_set_all(v: *mut gsl_vector_short, x: ::core::ffi::c_short);
 }
 extern "C" {
-    pub fn gsl_vector_short_set_basis(v: *mut gsl_vector_short, i: usize) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_short_set_basis(v: *mut gsl_vector_short, i: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_short_fread(
         stream: *mut FILE,
         v: *mut gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_short_fwrite(
         stream: *mut FILE,
         v: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_short_fscanf(
         stream: *mut FILE,
         v: *mut gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_short_fprintf(
         stream: *mut FILE,
         v: *const gsl_vector_short,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_short_memcpy(
         dest: *mut gsl_vector_short,
         src: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_short_reverse(v: *mut gsl_vector_short) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_short_reverse(v: *mut gsl_vector_short) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_short_swap(
         v: *mut gsl_vector_short,
         w: *mut gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_short_swap_elements(
         v: *mut gsl_vector_short,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_short_max(v: *const gsl_vector_short) -> ::std::os::raw::c_short;
+    pub fn gsl_vector_short_max(v: *const gsl_vector_short) -> ::core::ffi::c_short;
 }
 extern "C" {
-    pub fn gsl_vector_short_min(v: *const gsl_vector_short) -> ::std::os::raw::c_short;
+    pub fn gsl_vector_short_min(v: *const gsl_vector_short) -> ::core::ffi::c_short;
 }
 extern "C" {
     pub fn gsl_vector_short_minmax(
         v: *const gsl_vector_short,
-        min_out: *mut ::std::os::raw::c_short,
-        max_out: *mut ::std::os::raw::c_short,
+        min_out: *mut ::core::ffi::c_short,
+        max_out: *mut ::core::ffi::c_short,
     );
 }
 extern "C" {
@@ -13736,80 +13543,78 @@ extern "C" {
     pub fn gsl_vector_short_add(
         a: *mut gsl_vector_short,
         b: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_short_sub(
         a: *mut gsl_vector_short,
         b: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_short_mul(
         a: *mut gsl_vector_short,
         b: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_short_div(
         a: *mut gsl_vector_short,
         b: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_short_scale(
         a: *mut gsl_vector_short,
-        x: ::std::os::raw::c_short,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_short,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_short_add_constant(a: *mut gsl_vector_short, x: f64)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_vector_short_add_constant(a: *mut gsl_vector_short, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_short_axpby(
-        alpha: ::std::os::raw::c_short,
+        alpha: ::core::ffi::c_short,
         x: *const gsl_vector_short,
-        beta: ::std::os::raw::c_short,
+        beta: ::core::ffi::c_short,
         y: *mut gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_short_sum(a: *const gsl_vector_short) -> ::std::os::raw::c_short;
+    pub fn gsl_vector_short_sum(a: *const gsl_vector_short) -> ::core::ffi::c_short;
 }
 extern "C" {
     pub fn gsl_vector_short_equal(
         u: *const gsl_vector_short,
         v: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_short_isnull(v: *const gsl_vector_short) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_short_isnull(v: *const gsl_vector_short) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_short_ispos(v: *const gsl_vector_short) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_short_ispos(v: *const gsl_vector_short) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_short_isneg(v: *const gsl_vector_short) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_short_isneg(v: *const gsl_vector_short) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_short_isnonneg(v: *const gsl_vector_short) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_short_isnonneg(v: *const gsl_vector_short) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_short_get(v: *const gsl_vector_short, i: usize) -> ::std::os::raw::c_short;
+    pub fn gsl_vector_short_get(v: *const gsl_vector_short, i: usize) -> ::core::ffi::c_short;
 }
 extern "C" {
-    pub fn gsl_vector_short_set(v: *mut gsl_vector_short, i: usize, x: ::std::os::raw::c_short);
+    pub fn gsl_vector_short_set(v: *mut gsl_vector_short, i: usize, x: ::core::ffi::c_short);
 }
 extern "C" {
-    pub fn gsl_vector_short_ptr(v: *mut gsl_vector_short, i: usize)
-        -> *mut ::std::os::raw::c_short;
+    pub fn gsl_vector_short_ptr(v: *mut gsl_vector_short, i: usize) -> *mut ::core::ffi::c_short;
 }
 extern "C" {
     pub fn gsl_vector_short_const_ptr(
         v: *const gsl_vector_short,
         i: usize,
-    ) -> *const ::std::os::raw::c_short;
+    ) -> *const ::core::ffi::c_short;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -13817,9 +13622,9 @@ pub struct gsl_matrix_short {
     pub size1: usize,
     pub size2: usize,
     pub tda: usize,
-    pub data: *mut ::std::os::raw::c_short,
+    pub data: *mut ::core::ffi::c_short,
     pub block: *mut gsl_block_short,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -13920,14 +13725,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_short_view_array(
-        base: *mut ::std::os::raw::c_short,
+        base: *mut ::core::ffi::c_short,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_short_view;
 }
 extern "C" {
     pub fn gsl_matrix_short_view_array_with_tda(
-        base: *mut ::std::os::raw::c_short,
+        base: *mut ::core::ffi::c_short,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -14004,14 +13809,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_short_const_view_array(
-        base: *const ::std::os::raw::c_short,
+        base: *const ::core::ffi::c_short,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_short_const_view;
 }
 extern "C" {
     pub fn gsl_matrix_short_const_view_array_with_tda(
-        base: *const ::std::os::raw::c_short,
+        base: *const ::core::ffi::c_short,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -14039,44 +13844,44 @@ extern "C" {
     pub fn gsl_matrix_short_set_identity(m: *mut gsl_matrix_short);
 }
 extern "C" {
-    pub fn gsl_matrix_short_set_all(m: *mut gsl_matrix_short, x: ::std::os::raw::c_short);
+    pub fn gsl_matrix_short_set_all(m: *mut gsl_matrix_short, x: ::core::ffi::c_short);
 }
 extern "C" {
     pub fn gsl_matrix_short_fread(
         stream: *mut FILE,
         m: *mut gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_fwrite(
         stream: *mut FILE,
         m: *const gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_fscanf(
         stream: *mut FILE,
         m: *mut gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_fprintf(
         stream: *mut FILE,
         m: *const gsl_matrix_short,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_memcpy(
         dest: *mut gsl_matrix_short,
         src: *const gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_swap(
         m1: *mut gsl_matrix_short,
         m2: *mut gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_tricpy(
@@ -14084,37 +13889,37 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_short,
         src: *const gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_swap_rows(
         m: *mut gsl_matrix_short,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_swap_columns(
         m: *mut gsl_matrix_short,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_swap_rowcol(
         m: *mut gsl_matrix_short,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_short_transpose(m: *mut gsl_matrix_short) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_short_transpose(m: *mut gsl_matrix_short) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_transpose_memcpy(
         dest: *mut gsl_matrix_short,
         src: *const gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_transpose_tricpy(
@@ -14122,19 +13927,19 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_short,
         src: *const gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_short_max(m: *const gsl_matrix_short) -> ::std::os::raw::c_short;
+    pub fn gsl_matrix_short_max(m: *const gsl_matrix_short) -> ::core::ffi::c_short;
 }
 extern "C" {
-    pub fn gsl_matrix_short_min(m: *const gsl_matrix_short) -> ::std::os::raw::c_short;
+    pub fn gsl_matrix_short_min(m: *const gsl_matrix_short) -> ::core::ffi::c_short;
 }
 extern "C" {
     pub fn gsl_matrix_short_minmax(
         m: *const gsl_matrix_short,
-        min_out: *mut ::std::os::raw::c_short,
-        max_out: *mut ::std::os::raw::c_short,
+        min_out: *mut ::core::ffi::c_short,
+        max_out: *mut ::core::ffi::c_short,
     );
 }
 extern "C" {
@@ -14164,111 +13969,109 @@ extern "C" {
     pub fn gsl_matrix_short_equal(
         a: *const gsl_matrix_short,
         b: *const gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_short_isnull(m: *const gsl_matrix_short) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_short_isnull(m: *const gsl_matrix_short) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_short_ispos(m: *const gsl_matrix_short) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_short_ispos(m: *const gsl_matrix_short) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_short_isneg(m: *const gsl_matrix_short) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_short_isneg(m: *const gsl_matrix_short) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_short_isnonneg(m: *const gsl_matrix_short) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_short_isnonneg(m: *const gsl_matrix_short) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_short_norm1(m: *const gsl_matrix_short) -> ::std::os::raw::c_short;
+    pub fn gsl_matrix_short_norm1(m: *const gsl_matrix_short) -> ::core::ffi::c_short;
 }
 extern "C" {
     pub fn gsl_matrix_short_add(
         a: *mut gsl_matrix_short,
         b: *const gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_sub(
         a: *mut gsl_matrix_short,
         b: *const gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_mul_elements(
         a: *mut gsl_matrix_short,
         b: *const gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_div_elements(
         a: *mut gsl_matrix_short,
         b: *const gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_short_scale(a: *mut gsl_matrix_short, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_short_scale(a: *mut gsl_matrix_short, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_scale_rows(
         a: *mut gsl_matrix_short,
         x: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_scale_columns(
         a: *mut gsl_matrix_short,
         x: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_short_add_constant(a: *mut gsl_matrix_short, x: f64)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_short_add_constant(a: *mut gsl_matrix_short, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_short_add_diagonal(a: *mut gsl_matrix_short, x: f64)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_short_add_diagonal(a: *mut gsl_matrix_short, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_get_row(
         v: *mut gsl_vector_short,
         m: *const gsl_matrix_short,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_get_col(
         v: *mut gsl_vector_short,
         m: *const gsl_matrix_short,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_set_row(
         m: *mut gsl_matrix_short,
         i: usize,
         v: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_set_col(
         m: *mut gsl_matrix_short,
         j: usize,
         v: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_short_get(
         m: *const gsl_matrix_short,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_short;
+    ) -> ::core::ffi::c_short;
 }
 extern "C" {
     pub fn gsl_matrix_short_set(
         m: *mut gsl_matrix_short,
         i: usize,
         j: usize,
-        x: ::std::os::raw::c_short,
+        x: ::core::ffi::c_short,
     );
 }
 extern "C" {
@@ -14276,14 +14079,14 @@ extern "C" {
         m: *mut gsl_matrix_short,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_short;
+    ) -> *mut ::core::ffi::c_short;
 }
 extern "C" {
     pub fn gsl_matrix_short_const_ptr(
         m: *const gsl_matrix_short,
         i: usize,
         j: usize,
-    ) -> *const ::std::os::raw::c_short;
+    ) -> *const ::core::ffi::c_short;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -14296,7 +14099,7 @@ extern "C" {
         n1: usize,
         n2: usize,
         nzmax: usize,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_short;
 }
 extern "C" {
@@ -14306,36 +14109,36 @@ extern "C" {
     pub fn gsl_spmatrix_short_realloc(
         nzmax: usize,
         m: *mut gsl_spmatrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_nnz(m: *const gsl_spmatrix_short) -> usize;
 }
 extern "C" {
-    pub fn gsl_spmatrix_short_type(m: *const gsl_spmatrix_short) -> *const ::std::os::raw::c_char;
+    pub fn gsl_spmatrix_short_type(m: *const gsl_spmatrix_short) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_spmatrix_short_set_zero(m: *mut gsl_spmatrix_short) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_short_set_zero(m: *mut gsl_spmatrix_short) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_short_tree_rebuild(m: *mut gsl_spmatrix_short) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_short_tree_rebuild(m: *mut gsl_spmatrix_short) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_csc(
         dest: *mut gsl_spmatrix_short,
         src: *const gsl_spmatrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_csr(
         dest: *mut gsl_spmatrix_short,
         src: *const gsl_spmatrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_compress(
         src: *const gsl_spmatrix_short,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_short;
 }
 extern "C" {
@@ -14351,14 +14154,14 @@ extern "C" {
     pub fn gsl_spmatrix_short_memcpy(
         dest: *mut gsl_spmatrix_short,
         src: *const gsl_spmatrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_fprintf(
         stream: *mut FILE,
         m: *const gsl_spmatrix_short,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_fscanf(stream: *mut FILE) -> *mut gsl_spmatrix_short;
@@ -14367,125 +14170,125 @@ extern "C" {
     pub fn gsl_spmatrix_short_fwrite(
         stream: *mut FILE,
         m: *const gsl_spmatrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_fread(
         stream: *mut FILE,
         m: *mut gsl_spmatrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_get(
         m: *const gsl_spmatrix_short,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_short;
+    ) -> ::core::ffi::c_short;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_set(
         m: *mut gsl_spmatrix_short,
         i: usize,
         j: usize,
-        x: ::std::os::raw::c_short,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_short,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_ptr(
         m: *const gsl_spmatrix_short,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_short;
+    ) -> *mut ::core::ffi::c_short;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_minmax(
         m: *const gsl_spmatrix_short,
-        min_out: *mut ::std::os::raw::c_short,
-        max_out: *mut ::std::os::raw::c_short,
-    ) -> ::std::os::raw::c_int;
+        min_out: *mut ::core::ffi::c_short,
+        max_out: *mut ::core::ffi::c_short,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_min_index(
         m: *const gsl_spmatrix_short,
         imin_out: *mut usize,
         jmin_out: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_scale(
         m: *mut gsl_spmatrix_short,
-        x: ::std::os::raw::c_short,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_short,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_scale_columns(
         m: *mut gsl_spmatrix_short,
         x: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_scale_rows(
         m: *mut gsl_spmatrix_short,
         x: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_add(
         c: *mut gsl_spmatrix_short,
         a: *const gsl_spmatrix_short,
         b: *const gsl_spmatrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_dense_add(
         a: *mut gsl_matrix_short,
         b: *const gsl_spmatrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_dense_sub(
         a: *mut gsl_matrix_short,
         b: *const gsl_spmatrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_d2sp(
         T: *mut gsl_spmatrix_short,
         A: *const gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_sp2d(
         A: *mut gsl_matrix_short,
         S: *const gsl_spmatrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_add_to_dense(
         a: *mut gsl_matrix_short,
         b: *const gsl_spmatrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_equal(
         a: *const gsl_spmatrix_short,
         b: *const gsl_spmatrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_short_norm1(a: *const gsl_spmatrix_short) -> ::std::os::raw::c_short;
+    pub fn gsl_spmatrix_short_norm1(a: *const gsl_spmatrix_short) -> ::core::ffi::c_short;
 }
 extern "C" {
-    pub fn gsl_spmatrix_short_transpose(m: *mut gsl_spmatrix_short) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_short_transpose(m: *mut gsl_spmatrix_short) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_short_transpose2(m: *mut gsl_spmatrix_short) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_short_transpose2(m: *mut gsl_spmatrix_short) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_short_transpose_memcpy(
         dest: *mut gsl_spmatrix_short,
         src: *const gsl_spmatrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -14501,77 +14304,72 @@ extern "C" {
     pub fn gsl_block_uchar_free(b: *mut gsl_block_uchar);
 }
 extern "C" {
-    pub fn gsl_block_uchar_fread(
-        stream: *mut FILE,
-        b: *mut gsl_block_uchar,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_uchar_fread(stream: *mut FILE, b: *mut gsl_block_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_uchar_fwrite(
         stream: *mut FILE,
         b: *const gsl_block_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_block_uchar_fscanf(
-        stream: *mut FILE,
-        b: *mut gsl_block_uchar,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_uchar_fscanf(stream: *mut FILE, b: *mut gsl_block_uchar)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_uchar_fprintf(
         stream: *mut FILE,
         b: *const gsl_block_uchar,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_uchar_raw_fread(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_uchar,
+        b: *mut ::core::ffi::c_uchar,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_uchar_raw_fwrite(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_uchar,
+        b: *const ::core::ffi::c_uchar,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_uchar_raw_fscanf(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_uchar,
+        b: *mut ::core::ffi::c_uchar,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_uchar_raw_fprintf(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_uchar,
+        b: *const ::core::ffi::c_uchar,
         n: usize,
         stride: usize,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_uchar_size(b: *const gsl_block_uchar) -> usize;
 }
 extern "C" {
-    pub fn gsl_block_uchar_data(b: *const gsl_block_uchar) -> *mut ::std::os::raw::c_uchar;
+    pub fn gsl_block_uchar_data(b: *const gsl_block_uchar) -> *mut ::core::ffi::c_uchar;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_vector_uchar {
     pub size: usize,
     pub stride: usize,
-    pub data: *mut ::std::os::raw::c_uchar,
+    pub data: *mut ::core::ffi::c_uchar,
     pub block: *mut gsl_block_uchar,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -14612,26 +14410,26 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_vector_uchar_view_array(
-        v: *mut ::std::os::raw::c_uchar,
+        v: *mut ::core::ffi::c_uchar,
         n: usize,
     ) -> _gsl_vector_uchar_view;
 }
 extern "C" {
     pub fn gsl_vector_uchar_view_array_with_stride(
-        base: *mut ::std::os::raw::c_uchar,
+        base: *mut ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_uchar_view;
 }
 extern "C" {
     pub fn gsl_vector_uchar_const_view_array(
-        v: *const ::std::os::raw::c_uchar,
+        v: *const ::core::ffi::c_uchar,
         n: usize,
     ) -> _gsl_vector_uchar_const_view;
 }
 extern "C" {
     pub fn gsl_vector_uchar_const_view_array_with_stride(
-        base: *const ::std::os::raw::c_uchar,
+        base: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_uchar_const_view;
@@ -14670,69 +14468,69 @@ extern "C" {
     pub fn gsl_vector_uchar_set_zero(v: *mut gsl_vector_uchar);
 }
 extern "C" {
-    pub fn gsl_vector_uchar_set_all(v: *mut gsl_vector_uchar, x: ::std::os::raw::c_uchar);
+    pub fn gsl_vector_uchar_set_all(v: *mut gsl_vector_uchar, x: ::core::ffi::c_uchar);
 }
 extern "C" {
-    pub fn gsl_vector_uchar_set_basis(v: *mut gsl_vector_uchar, i: usize) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uchar_set_basis(v: *mut gsl_vector_uchar, i: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uchar_fread(
         stream: *mut FILE,
         v: *mut gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uchar_fwrite(
         stream: *mut FILE,
         v: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uchar_fscanf(
         stream: *mut FILE,
         v: *mut gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uchar_fprintf(
         stream: *mut FILE,
         v: *const gsl_vector_uchar,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uchar_memcpy(
         dest: *mut gsl_vector_uchar,
         src: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uchar_reverse(v: *mut gsl_vector_uchar) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uchar_reverse(v: *mut gsl_vector_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uchar_swap(
         v: *mut gsl_vector_uchar,
         w: *mut gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uchar_swap_elements(
         v: *mut gsl_vector_uchar,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uchar_max(v: *const gsl_vector_uchar) -> ::std::os::raw::c_uchar;
+    pub fn gsl_vector_uchar_max(v: *const gsl_vector_uchar) -> ::core::ffi::c_uchar;
 }
 extern "C" {
-    pub fn gsl_vector_uchar_min(v: *const gsl_vector_uchar) -> ::std::os::raw::c_uchar;
+    pub fn gsl_vector_uchar_min(v: *const gsl_vector_uchar) -> ::core::ffi::c_uchar;
 }
 extern "C" {
     pub fn gsl_vector_uchar_minmax(
         v: *const gsl_vector_uchar,
-        min_out: *mut ::std::os::raw::c_uchar,
-        max_out: *mut ::std::os::raw::c_uchar,
+        min_out: *mut ::core::ffi::c_uchar,
+        max_out: *mut ::core::ffi::c_uchar,
     );
 }
 extern "C" {
@@ -14752,80 +14550,78 @@ extern "C" {
     pub fn gsl_vector_uchar_add(
         a: *mut gsl_vector_uchar,
         b: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uchar_sub(
         a: *mut gsl_vector_uchar,
         b: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uchar_mul(
         a: *mut gsl_vector_uchar,
         b: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uchar_div(
         a: *mut gsl_vector_uchar,
         b: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uchar_scale(
         a: *mut gsl_vector_uchar,
-        x: ::std::os::raw::c_uchar,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_uchar,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uchar_add_constant(a: *mut gsl_vector_uchar, x: f64)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uchar_add_constant(a: *mut gsl_vector_uchar, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_uchar_axpby(
-        alpha: ::std::os::raw::c_uchar,
+        alpha: ::core::ffi::c_uchar,
         x: *const gsl_vector_uchar,
-        beta: ::std::os::raw::c_uchar,
+        beta: ::core::ffi::c_uchar,
         y: *mut gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uchar_sum(a: *const gsl_vector_uchar) -> ::std::os::raw::c_uchar;
+    pub fn gsl_vector_uchar_sum(a: *const gsl_vector_uchar) -> ::core::ffi::c_uchar;
 }
 extern "C" {
     pub fn gsl_vector_uchar_equal(
         u: *const gsl_vector_uchar,
         v: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uchar_isnull(v: *const gsl_vector_uchar) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uchar_isnull(v: *const gsl_vector_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uchar_ispos(v: *const gsl_vector_uchar) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uchar_ispos(v: *const gsl_vector_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uchar_isneg(v: *const gsl_vector_uchar) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uchar_isneg(v: *const gsl_vector_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uchar_isnonneg(v: *const gsl_vector_uchar) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_uchar_isnonneg(v: *const gsl_vector_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_uchar_get(v: *const gsl_vector_uchar, i: usize) -> ::std::os::raw::c_uchar;
+    pub fn gsl_vector_uchar_get(v: *const gsl_vector_uchar, i: usize) -> ::core::ffi::c_uchar;
 }
 extern "C" {
-    pub fn gsl_vector_uchar_set(v: *mut gsl_vector_uchar, i: usize, x: ::std::os::raw::c_uchar);
+    pub fn gsl_vector_uchar_set(v: *mut gsl_vector_uchar, i: usize, x: ::core::ffi::c_uchar);
 }
 extern "C" {
-    pub fn gsl_vector_uchar_ptr(v: *mut gsl_vector_uchar, i: usize)
-        -> *mut ::std::os::raw::c_uchar;
+    pub fn gsl_vector_uchar_ptr(v: *mut gsl_vector_uchar, i: usize) -> *mut ::core::ffi::c_uchar;
 }
 extern "C" {
     pub fn gsl_vector_uchar_const_ptr(
         v: *const gsl_vector_uchar,
         i: usize,
-    ) -> *const ::std::os::raw::c_uchar;
+    ) -> *const ::core::ffi::c_uchar;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -14833,9 +14629,9 @@ pub struct gsl_matrix_uchar {
     pub size1: usize,
     pub size2: usize,
     pub tda: usize,
-    pub data: *mut ::std::os::raw::c_uchar,
+    pub data: *mut ::core::ffi::c_uchar,
     pub block: *mut gsl_block_uchar,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -14936,14 +14732,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_uchar_view_array(
-        base: *mut ::std::os::raw::c_uchar,
+        base: *mut ::core::ffi::c_uchar,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_uchar_view;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_view_array_with_tda(
-        base: *mut ::std::os::raw::c_uchar,
+        base: *mut ::core::ffi::c_uchar,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -15020,14 +14816,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_uchar_const_view_array(
-        base: *const ::std::os::raw::c_uchar,
+        base: *const ::core::ffi::c_uchar,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_uchar_const_view;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_const_view_array_with_tda(
-        base: *const ::std::os::raw::c_uchar,
+        base: *const ::core::ffi::c_uchar,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -15055,44 +14851,44 @@ extern "C" {
     pub fn gsl_matrix_uchar_set_identity(m: *mut gsl_matrix_uchar);
 }
 extern "C" {
-    pub fn gsl_matrix_uchar_set_all(m: *mut gsl_matrix_uchar, x: ::std::os::raw::c_uchar);
+    pub fn gsl_matrix_uchar_set_all(m: *mut gsl_matrix_uchar, x: ::core::ffi::c_uchar);
 }
 extern "C" {
     pub fn gsl_matrix_uchar_fread(
         stream: *mut FILE,
         m: *mut gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_fwrite(
         stream: *mut FILE,
         m: *const gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_fscanf(
         stream: *mut FILE,
         m: *mut gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_fprintf(
         stream: *mut FILE,
         m: *const gsl_matrix_uchar,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_memcpy(
         dest: *mut gsl_matrix_uchar,
         src: *const gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_swap(
         m1: *mut gsl_matrix_uchar,
         m2: *mut gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_tricpy(
@@ -15100,37 +14896,37 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_uchar,
         src: *const gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_swap_rows(
         m: *mut gsl_matrix_uchar,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_swap_columns(
         m: *mut gsl_matrix_uchar,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_swap_rowcol(
         m: *mut gsl_matrix_uchar,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uchar_transpose(m: *mut gsl_matrix_uchar) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uchar_transpose(m: *mut gsl_matrix_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_transpose_memcpy(
         dest: *mut gsl_matrix_uchar,
         src: *const gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_transpose_tricpy(
@@ -15138,19 +14934,19 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_uchar,
         src: *const gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uchar_max(m: *const gsl_matrix_uchar) -> ::std::os::raw::c_uchar;
+    pub fn gsl_matrix_uchar_max(m: *const gsl_matrix_uchar) -> ::core::ffi::c_uchar;
 }
 extern "C" {
-    pub fn gsl_matrix_uchar_min(m: *const gsl_matrix_uchar) -> ::std::os::raw::c_uchar;
+    pub fn gsl_matrix_uchar_min(m: *const gsl_matrix_uchar) -> ::core::ffi::c_uchar;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_minmax(
         m: *const gsl_matrix_uchar,
-        min_out: *mut ::std::os::raw::c_uchar,
-        max_out: *mut ::std::os::raw::c_uchar,
+        min_out: *mut ::core::ffi::c_uchar,
+        max_out: *mut ::core::ffi::c_uchar,
     );
 }
 extern "C" {
@@ -15180,111 +14976,109 @@ extern "C" {
     pub fn gsl_matrix_uchar_equal(
         a: *const gsl_matrix_uchar,
         b: *const gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uchar_isnull(m: *const gsl_matrix_uchar) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uchar_isnull(m: *const gsl_matrix_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uchar_ispos(m: *const gsl_matrix_uchar) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uchar_ispos(m: *const gsl_matrix_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uchar_isneg(m: *const gsl_matrix_uchar) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uchar_isneg(m: *const gsl_matrix_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uchar_isnonneg(m: *const gsl_matrix_uchar) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uchar_isnonneg(m: *const gsl_matrix_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uchar_norm1(m: *const gsl_matrix_uchar) -> ::std::os::raw::c_uchar;
+    pub fn gsl_matrix_uchar_norm1(m: *const gsl_matrix_uchar) -> ::core::ffi::c_uchar;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_add(
         a: *mut gsl_matrix_uchar,
         b: *const gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_sub(
         a: *mut gsl_matrix_uchar,
         b: *const gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_mul_elements(
         a: *mut gsl_matrix_uchar,
         b: *const gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_div_elements(
         a: *mut gsl_matrix_uchar,
         b: *const gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uchar_scale(a: *mut gsl_matrix_uchar, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uchar_scale(a: *mut gsl_matrix_uchar, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_scale_rows(
         a: *mut gsl_matrix_uchar,
         x: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_scale_columns(
         a: *mut gsl_matrix_uchar,
         x: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uchar_add_constant(a: *mut gsl_matrix_uchar, x: f64)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uchar_add_constant(a: *mut gsl_matrix_uchar, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_uchar_add_diagonal(a: *mut gsl_matrix_uchar, x: f64)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_uchar_add_diagonal(a: *mut gsl_matrix_uchar, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_get_row(
         v: *mut gsl_vector_uchar,
         m: *const gsl_matrix_uchar,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_get_col(
         v: *mut gsl_vector_uchar,
         m: *const gsl_matrix_uchar,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_set_row(
         m: *mut gsl_matrix_uchar,
         i: usize,
         v: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_set_col(
         m: *mut gsl_matrix_uchar,
         j: usize,
         v: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_get(
         m: *const gsl_matrix_uchar,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_uchar;
+    ) -> ::core::ffi::c_uchar;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_set(
         m: *mut gsl_matrix_uchar,
         i: usize,
         j: usize,
-        x: ::std::os::raw::c_uchar,
+        x: ::core::ffi::c_uchar,
     );
 }
 extern "C" {
@@ -15292,14 +15086,14 @@ extern "C" {
         m: *mut gsl_matrix_uchar,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_uchar;
+    ) -> *mut ::core::ffi::c_uchar;
 }
 extern "C" {
     pub fn gsl_matrix_uchar_const_ptr(
         m: *const gsl_matrix_uchar,
         i: usize,
         j: usize,
-    ) -> *const ::std::os::raw::c_uchar;
+    ) -> *const ::core::ffi::c_uchar;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -15312,7 +15106,7 @@ extern "C" {
         n1: usize,
         n2: usize,
         nzmax: usize,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_uchar;
 }
 extern "C" {
@@ -15322,36 +15116,36 @@ extern "C" {
     pub fn gsl_spmatrix_uchar_realloc(
         nzmax: usize,
         m: *mut gsl_spmatrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_nnz(m: *const gsl_spmatrix_uchar) -> usize;
 }
 extern "C" {
-    pub fn gsl_spmatrix_uchar_type(m: *const gsl_spmatrix_uchar) -> *const ::std::os::raw::c_char;
+    pub fn gsl_spmatrix_uchar_type(m: *const gsl_spmatrix_uchar) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_spmatrix_uchar_set_zero(m: *mut gsl_spmatrix_uchar) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_uchar_set_zero(m: *mut gsl_spmatrix_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_uchar_tree_rebuild(m: *mut gsl_spmatrix_uchar) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_uchar_tree_rebuild(m: *mut gsl_spmatrix_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_csc(
         dest: *mut gsl_spmatrix_uchar,
         src: *const gsl_spmatrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_csr(
         dest: *mut gsl_spmatrix_uchar,
         src: *const gsl_spmatrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_compress(
         src: *const gsl_spmatrix_uchar,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_uchar;
 }
 extern "C" {
@@ -15367,14 +15161,14 @@ extern "C" {
     pub fn gsl_spmatrix_uchar_memcpy(
         dest: *mut gsl_spmatrix_uchar,
         src: *const gsl_spmatrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_fprintf(
         stream: *mut FILE,
         m: *const gsl_spmatrix_uchar,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_fscanf(stream: *mut FILE) -> *mut gsl_spmatrix_uchar;
@@ -15383,125 +15177,125 @@ extern "C" {
     pub fn gsl_spmatrix_uchar_fwrite(
         stream: *mut FILE,
         m: *const gsl_spmatrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_fread(
         stream: *mut FILE,
         m: *mut gsl_spmatrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_get(
         m: *const gsl_spmatrix_uchar,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_uchar;
+    ) -> ::core::ffi::c_uchar;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_set(
         m: *mut gsl_spmatrix_uchar,
         i: usize,
         j: usize,
-        x: ::std::os::raw::c_uchar,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_uchar,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_ptr(
         m: *const gsl_spmatrix_uchar,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_uchar;
+    ) -> *mut ::core::ffi::c_uchar;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_minmax(
         m: *const gsl_spmatrix_uchar,
-        min_out: *mut ::std::os::raw::c_uchar,
-        max_out: *mut ::std::os::raw::c_uchar,
-    ) -> ::std::os::raw::c_int;
+        min_out: *mut ::core::ffi::c_uchar,
+        max_out: *mut ::core::ffi::c_uchar,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_min_index(
         m: *const gsl_spmatrix_uchar,
         imin_out: *mut usize,
         jmin_out: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_scale(
         m: *mut gsl_spmatrix_uchar,
-        x: ::std::os::raw::c_uchar,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_uchar,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_scale_columns(
         m: *mut gsl_spmatrix_uchar,
         x: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_scale_rows(
         m: *mut gsl_spmatrix_uchar,
         x: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_add(
         c: *mut gsl_spmatrix_uchar,
         a: *const gsl_spmatrix_uchar,
         b: *const gsl_spmatrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_dense_add(
         a: *mut gsl_matrix_uchar,
         b: *const gsl_spmatrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_dense_sub(
         a: *mut gsl_matrix_uchar,
         b: *const gsl_spmatrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_d2sp(
         T: *mut gsl_spmatrix_uchar,
         A: *const gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_sp2d(
         A: *mut gsl_matrix_uchar,
         S: *const gsl_spmatrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_add_to_dense(
         a: *mut gsl_matrix_uchar,
         b: *const gsl_spmatrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_equal(
         a: *const gsl_spmatrix_uchar,
         b: *const gsl_spmatrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_uchar_norm1(a: *const gsl_spmatrix_uchar) -> ::std::os::raw::c_uchar;
+    pub fn gsl_spmatrix_uchar_norm1(a: *const gsl_spmatrix_uchar) -> ::core::ffi::c_uchar;
 }
 extern "C" {
-    pub fn gsl_spmatrix_uchar_transpose(m: *mut gsl_spmatrix_uchar) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_uchar_transpose(m: *mut gsl_spmatrix_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_uchar_transpose2(m: *mut gsl_spmatrix_uchar) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_uchar_transpose2(m: *mut gsl_spmatrix_uchar) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_uchar_transpose_memcpy(
         dest: *mut gsl_spmatrix_uchar,
         src: *const gsl_spmatrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -15517,75 +15311,69 @@ extern "C" {
     pub fn gsl_block_char_free(b: *mut gsl_block_char);
 }
 extern "C" {
-    pub fn gsl_block_char_fread(stream: *mut FILE, b: *mut gsl_block_char)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_block_char_fread(stream: *mut FILE, b: *mut gsl_block_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_block_char_fwrite(
-        stream: *mut FILE,
-        b: *const gsl_block_char,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_char_fwrite(stream: *mut FILE, b: *const gsl_block_char)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_block_char_fscanf(
-        stream: *mut FILE,
-        b: *mut gsl_block_char,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_block_char_fscanf(stream: *mut FILE, b: *mut gsl_block_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_char_fprintf(
         stream: *mut FILE,
         b: *const gsl_block_char,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_char_raw_fread(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_char,
+        b: *mut ::core::ffi::c_char,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_char_raw_fwrite(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_char,
+        b: *const ::core::ffi::c_char,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_char_raw_fscanf(
         stream: *mut FILE,
-        b: *mut ::std::os::raw::c_char,
+        b: *mut ::core::ffi::c_char,
         n: usize,
         stride: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_char_raw_fprintf(
         stream: *mut FILE,
-        b: *const ::std::os::raw::c_char,
+        b: *const ::core::ffi::c_char,
         n: usize,
         stride: usize,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_block_char_size(b: *const gsl_block_char) -> usize;
 }
 extern "C" {
-    pub fn gsl_block_char_data(b: *const gsl_block_char) -> *mut ::std::os::raw::c_char;
+    pub fn gsl_block_char_data(b: *const gsl_block_char) -> *mut ::core::ffi::c_char;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_vector_char {
     pub size: usize,
     pub stride: usize,
-    pub data: *mut ::std::os::raw::c_char,
+    pub data: *mut ::core::ffi::c_char,
     pub block: *mut gsl_block_char,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -15626,26 +15414,26 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_vector_char_view_array(
-        v: *mut ::std::os::raw::c_char,
+        v: *mut ::core::ffi::c_char,
         n: usize,
     ) -> _gsl_vector_char_view;
 }
 extern "C" {
     pub fn gsl_vector_char_view_array_with_stride(
-        base: *mut ::std::os::raw::c_char,
+        base: *mut ::core::ffi::c_char,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_char_view;
 }
 extern "C" {
     pub fn gsl_vector_char_const_view_array(
-        v: *const ::std::os::raw::c_char,
+        v: *const ::core::ffi::c_char,
         n: usize,
     ) -> _gsl_vector_char_const_view;
 }
 extern "C" {
     pub fn gsl_vector_char_const_view_array_with_stride(
-        base: *const ::std::os::raw::c_char,
+        base: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
     ) -> _gsl_vector_char_const_view;
@@ -15684,69 +15472,64 @@ extern "C" {
     pub fn gsl_vector_char_set_zero(v: *mut gsl_vector_char);
 }
 extern "C" {
-    pub fn gsl_vector_char_set_all(v: *mut gsl_vector_char, x: ::std::os::raw::c_char);
+    pub fn gsl_vector_char_set_all(v: *mut gsl_vector_char, x: ::core::ffi::c_char);
 }
 extern "C" {
-    pub fn gsl_vector_char_set_basis(v: *mut gsl_vector_char, i: usize) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_char_set_basis(v: *mut gsl_vector_char, i: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_char_fread(
-        stream: *mut FILE,
-        v: *mut gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_char_fread(stream: *mut FILE, v: *mut gsl_vector_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_char_fwrite(
         stream: *mut FILE,
         v: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_char_fscanf(
-        stream: *mut FILE,
-        v: *mut gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_char_fscanf(stream: *mut FILE, v: *mut gsl_vector_char)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_char_fprintf(
         stream: *mut FILE,
         v: *const gsl_vector_char,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_char_memcpy(
         dest: *mut gsl_vector_char,
         src: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_char_reverse(v: *mut gsl_vector_char) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_char_reverse(v: *mut gsl_vector_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_char_swap(
         v: *mut gsl_vector_char,
         w: *mut gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_char_swap_elements(
         v: *mut gsl_vector_char,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_char_max(v: *const gsl_vector_char) -> ::std::os::raw::c_char;
+    pub fn gsl_vector_char_max(v: *const gsl_vector_char) -> ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_vector_char_min(v: *const gsl_vector_char) -> ::std::os::raw::c_char;
+    pub fn gsl_vector_char_min(v: *const gsl_vector_char) -> ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_vector_char_minmax(
         v: *const gsl_vector_char,
-        min_out: *mut ::std::os::raw::c_char,
-        max_out: *mut ::std::os::raw::c_char,
+        min_out: *mut ::core::ffi::c_char,
+        max_out: *mut ::core::ffi::c_char,
     );
 }
 extern "C" {
@@ -15766,78 +15549,78 @@ extern "C" {
     pub fn gsl_vector_char_add(
         a: *mut gsl_vector_char,
         b: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_char_sub(
         a: *mut gsl_vector_char,
         b: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_char_mul(
         a: *mut gsl_vector_char,
         b: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_char_div(
         a: *mut gsl_vector_char,
         b: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_char_scale(
         a: *mut gsl_vector_char,
-        x: ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_char_add_constant(a: *mut gsl_vector_char, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_char_add_constant(a: *mut gsl_vector_char, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_vector_char_axpby(
-        alpha: ::std::os::raw::c_char,
+        alpha: ::core::ffi::c_char,
         x: *const gsl_vector_char,
-        beta: ::std::os::raw::c_char,
+        beta: ::core::ffi::c_char,
         y: *mut gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_char_sum(a: *const gsl_vector_char) -> ::std::os::raw::c_char;
+    pub fn gsl_vector_char_sum(a: *const gsl_vector_char) -> ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_vector_char_equal(
         u: *const gsl_vector_char,
         v: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_char_isnull(v: *const gsl_vector_char) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_char_isnull(v: *const gsl_vector_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_char_ispos(v: *const gsl_vector_char) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_char_ispos(v: *const gsl_vector_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_char_isneg(v: *const gsl_vector_char) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_char_isneg(v: *const gsl_vector_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_char_isnonneg(v: *const gsl_vector_char) -> ::std::os::raw::c_int;
+    pub fn gsl_vector_char_isnonneg(v: *const gsl_vector_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_vector_char_get(v: *const gsl_vector_char, i: usize) -> ::std::os::raw::c_char;
+    pub fn gsl_vector_char_get(v: *const gsl_vector_char, i: usize) -> ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_vector_char_set(v: *mut gsl_vector_char, i: usize, x: ::std::os::raw::c_char);
+    pub fn gsl_vector_char_set(v: *mut gsl_vector_char, i: usize, x: ::core::ffi::c_char);
 }
 extern "C" {
-    pub fn gsl_vector_char_ptr(v: *mut gsl_vector_char, i: usize) -> *mut ::std::os::raw::c_char;
+    pub fn gsl_vector_char_ptr(v: *mut gsl_vector_char, i: usize) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_vector_char_const_ptr(
         v: *const gsl_vector_char,
         i: usize,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -15845,9 +15628,9 @@ pub struct gsl_matrix_char {
     pub size1: usize,
     pub size2: usize,
     pub tda: usize,
-    pub data: *mut ::std::os::raw::c_char,
+    pub data: *mut ::core::ffi::c_char,
     pub block: *mut gsl_block_char,
-    pub owner: ::std::os::raw::c_int,
+    pub owner: ::core::ffi::c_int,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -15945,14 +15728,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_char_view_array(
-        base: *mut ::std::os::raw::c_char,
+        base: *mut ::core::ffi::c_char,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_char_view;
 }
 extern "C" {
     pub fn gsl_matrix_char_view_array_with_tda(
-        base: *mut ::std::os::raw::c_char,
+        base: *mut ::core::ffi::c_char,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -16028,14 +15811,14 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_matrix_char_const_view_array(
-        base: *const ::std::os::raw::c_char,
+        base: *const ::core::ffi::c_char,
         n1: usize,
         n2: usize,
     ) -> _gsl_matrix_char_const_view;
 }
 extern "C" {
     pub fn gsl_matrix_char_const_view_array_with_tda(
-        base: *const ::std::os::raw::c_char,
+        base: *const ::core::ffi::c_char,
         n1: usize,
         n2: usize,
         tda: usize,
@@ -16063,44 +15846,39 @@ extern "C" {
     pub fn gsl_matrix_char_set_identity(m: *mut gsl_matrix_char);
 }
 extern "C" {
-    pub fn gsl_matrix_char_set_all(m: *mut gsl_matrix_char, x: ::std::os::raw::c_char);
+    pub fn gsl_matrix_char_set_all(m: *mut gsl_matrix_char, x: ::core::ffi::c_char);
 }
 extern "C" {
-    pub fn gsl_matrix_char_fread(
-        stream: *mut FILE,
-        m: *mut gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_char_fread(stream: *mut FILE, m: *mut gsl_matrix_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_fwrite(
         stream: *mut FILE,
         m: *const gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_char_fscanf(
-        stream: *mut FILE,
-        m: *mut gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_char_fscanf(stream: *mut FILE, m: *mut gsl_matrix_char)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_fprintf(
         stream: *mut FILE,
         m: *const gsl_matrix_char,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_memcpy(
         dest: *mut gsl_matrix_char,
         src: *const gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_swap(
         m1: *mut gsl_matrix_char,
         m2: *mut gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_tricpy(
@@ -16108,37 +15886,37 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_char,
         src: *const gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_swap_rows(
         m: *mut gsl_matrix_char,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_swap_columns(
         m: *mut gsl_matrix_char,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_swap_rowcol(
         m: *mut gsl_matrix_char,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_char_transpose(m: *mut gsl_matrix_char) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_char_transpose(m: *mut gsl_matrix_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_transpose_memcpy(
         dest: *mut gsl_matrix_char,
         src: *const gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_transpose_tricpy(
@@ -16146,19 +15924,19 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         dest: *mut gsl_matrix_char,
         src: *const gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_char_max(m: *const gsl_matrix_char) -> ::std::os::raw::c_char;
+    pub fn gsl_matrix_char_max(m: *const gsl_matrix_char) -> ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_matrix_char_min(m: *const gsl_matrix_char) -> ::std::os::raw::c_char;
+    pub fn gsl_matrix_char_min(m: *const gsl_matrix_char) -> ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_matrix_char_minmax(
         m: *const gsl_matrix_char,
-        min_out: *mut ::std::os::raw::c_char,
-        max_out: *mut ::std::os::raw::c_char,
+        min_out: *mut ::core::ffi::c_char,
+        max_out: *mut ::core::ffi::c_char,
     );
 }
 extern "C" {
@@ -16180,124 +15958,119 @@ extern "C" {
     pub fn gsl_matrix_char_equal(
         a: *const gsl_matrix_char,
         b: *const gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_char_isnull(m: *const gsl_matrix_char) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_char_isnull(m: *const gsl_matrix_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_char_ispos(m: *const gsl_matrix_char) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_char_ispos(m: *const gsl_matrix_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_char_isneg(m: *const gsl_matrix_char) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_char_isneg(m: *const gsl_matrix_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_char_isnonneg(m: *const gsl_matrix_char) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_char_isnonneg(m: *const gsl_matrix_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_char_norm1(m: *const gsl_matrix_char) -> ::std::os::raw::c_char;
+    pub fn gsl_matrix_char_norm1(m: *const gsl_matrix_char) -> ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_matrix_char_add(
         a: *mut gsl_matrix_char,
         b: *const gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_sub(
         a: *mut gsl_matrix_char,
         b: *const gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_mul_elements(
         a: *mut gsl_matrix_char,
         b: *const gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_div_elements(
         a: *mut gsl_matrix_char,
         b: *const gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_char_scale(a: *mut gsl_matrix_char, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_char_scale(a: *mut gsl_matrix_char, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_scale_rows(
         a: *mut gsl_matrix_char,
         x: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_scale_columns(
         a: *mut gsl_matrix_char,
         x: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_char_add_constant(a: *mut gsl_matrix_char, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_char_add_constant(a: *mut gsl_matrix_char, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_matrix_char_add_diagonal(a: *mut gsl_matrix_char, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_matrix_char_add_diagonal(a: *mut gsl_matrix_char, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_get_row(
         v: *mut gsl_vector_char,
         m: *const gsl_matrix_char,
         i: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_get_col(
         v: *mut gsl_vector_char,
         m: *const gsl_matrix_char,
         j: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_set_row(
         m: *mut gsl_matrix_char,
         i: usize,
         v: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_set_col(
         m: *mut gsl_matrix_char,
         j: usize,
         v: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_matrix_char_get(
         m: *const gsl_matrix_char,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_char;
+    ) -> ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_matrix_char_set(
-        m: *mut gsl_matrix_char,
-        i: usize,
-        j: usize,
-        x: ::std::os::raw::c_char,
-    );
+    pub fn gsl_matrix_char_set(m: *mut gsl_matrix_char, i: usize, j: usize, x: ::core::ffi::c_char);
 }
 extern "C" {
     pub fn gsl_matrix_char_ptr(
         m: *mut gsl_matrix_char,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_char;
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_matrix_char_const_ptr(
         m: *const gsl_matrix_char,
         i: usize,
         j: usize,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -16310,46 +16083,44 @@ extern "C" {
         n1: usize,
         n2: usize,
         nzmax: usize,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_char;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_free(m: *mut gsl_spmatrix_char);
 }
 extern "C" {
-    pub fn gsl_spmatrix_char_realloc(
-        nzmax: usize,
-        m: *mut gsl_spmatrix_char,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_char_realloc(nzmax: usize, m: *mut gsl_spmatrix_char)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_nnz(m: *const gsl_spmatrix_char) -> usize;
 }
 extern "C" {
-    pub fn gsl_spmatrix_char_type(m: *const gsl_spmatrix_char) -> *const ::std::os::raw::c_char;
+    pub fn gsl_spmatrix_char_type(m: *const gsl_spmatrix_char) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_spmatrix_char_set_zero(m: *mut gsl_spmatrix_char) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_char_set_zero(m: *mut gsl_spmatrix_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_char_tree_rebuild(m: *mut gsl_spmatrix_char) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_char_tree_rebuild(m: *mut gsl_spmatrix_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_csc(
         dest: *mut gsl_spmatrix_char,
         src: *const gsl_spmatrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_csr(
         dest: *mut gsl_spmatrix_char,
         src: *const gsl_spmatrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_compress(
         src: *const gsl_spmatrix_char,
-        sptype: ::std::os::raw::c_int,
+        sptype: ::core::ffi::c_int,
     ) -> *mut gsl_spmatrix_char;
 }
 extern "C" {
@@ -16365,14 +16136,14 @@ extern "C" {
     pub fn gsl_spmatrix_char_memcpy(
         dest: *mut gsl_spmatrix_char,
         src: *const gsl_spmatrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_fprintf(
         stream: *mut FILE,
         m: *const gsl_spmatrix_char,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_fscanf(stream: *mut FILE) -> *mut gsl_spmatrix_char;
@@ -16381,146 +16152,146 @@ extern "C" {
     pub fn gsl_spmatrix_char_fwrite(
         stream: *mut FILE,
         m: *const gsl_spmatrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_fread(
         stream: *mut FILE,
         m: *mut gsl_spmatrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_get(
         m: *const gsl_spmatrix_char,
         i: usize,
         j: usize,
-    ) -> ::std::os::raw::c_char;
+    ) -> ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_set(
         m: *mut gsl_spmatrix_char,
         i: usize,
         j: usize,
-        x: ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_ptr(
         m: *const gsl_spmatrix_char,
         i: usize,
         j: usize,
-    ) -> *mut ::std::os::raw::c_char;
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_minmax(
         m: *const gsl_spmatrix_char,
-        min_out: *mut ::std::os::raw::c_char,
-        max_out: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        min_out: *mut ::core::ffi::c_char,
+        max_out: *mut ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_min_index(
         m: *const gsl_spmatrix_char,
         imin_out: *mut usize,
         jmin_out: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_scale(
         m: *mut gsl_spmatrix_char,
-        x: ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        x: ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_scale_columns(
         m: *mut gsl_spmatrix_char,
         x: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_scale_rows(
         m: *mut gsl_spmatrix_char,
         x: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_add(
         c: *mut gsl_spmatrix_char,
         a: *const gsl_spmatrix_char,
         b: *const gsl_spmatrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_dense_add(
         a: *mut gsl_matrix_char,
         b: *const gsl_spmatrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_dense_sub(
         a: *mut gsl_matrix_char,
         b: *const gsl_spmatrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_d2sp(
         T: *mut gsl_spmatrix_char,
         A: *const gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_sp2d(
         A: *mut gsl_matrix_char,
         S: *const gsl_spmatrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_add_to_dense(
         a: *mut gsl_matrix_char,
         b: *const gsl_spmatrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_equal(
         a: *const gsl_spmatrix_char,
         b: *const gsl_spmatrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_char_norm1(a: *const gsl_spmatrix_char) -> ::std::os::raw::c_char;
+    pub fn gsl_spmatrix_char_norm1(a: *const gsl_spmatrix_char) -> ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_spmatrix_char_transpose(m: *mut gsl_spmatrix_char) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_char_transpose(m: *mut gsl_spmatrix_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spmatrix_char_transpose2(m: *mut gsl_spmatrix_char) -> ::std::os::raw::c_int;
+    pub fn gsl_spmatrix_char_transpose2(m: *mut gsl_spmatrix_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spmatrix_char_transpose_memcpy(
         dest: *mut gsl_spmatrix_char,
         src: *const gsl_spmatrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_complex_long_double(
         p: *const gsl_permutation,
         v: *mut gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_complex_long_double_inverse(
         p: *const gsl_permutation,
         v: *mut gsl_vector_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sort_uchar(data: *mut ::std::os::raw::c_uchar, stride: usize, n: usize);
+    pub fn gsl_sort_uchar(data: *mut ::core::ffi::c_uchar, stride: usize, n: usize);
 }
 extern "C" {
     pub fn gsl_sort2_uchar(
-        data1: *mut ::std::os::raw::c_uchar,
+        data1: *mut ::core::ffi::c_uchar,
         stride1: usize,
-        data2: *mut ::std::os::raw::c_uchar,
+        data2: *mut ::core::ffi::c_uchar,
         stride2: usize,
         n: usize,
     );
@@ -16528,46 +16299,46 @@ extern "C" {
 extern "C" {
     pub fn gsl_sort_uchar_index(
         p: *mut usize,
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_sort_uchar_smallest(
-        dest: *mut ::std::os::raw::c_uchar,
+        dest: *mut ::core::ffi::c_uchar,
         k: usize,
-        src: *const ::std::os::raw::c_uchar,
+        src: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_uchar_smallest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_uchar,
+        src: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_uchar_largest(
-        dest: *mut ::std::os::raw::c_uchar,
+        dest: *mut ::core::ffi::c_uchar,
         k: usize,
-        src: *const ::std::os::raw::c_uchar,
+        src: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_uchar_largest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_uchar,
+        src: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -16597,28 +16368,28 @@ extern "C" {
     pub fn gsl_bspline_knots(
         breakpts: *const gsl_vector,
         w: *mut gsl_bspline_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_bspline_knots_uniform(
         a: f64,
         b: f64,
         w: *mut gsl_bspline_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_bspline_knots_greville(
         abscissae: *const gsl_vector,
         w: *mut gsl_bspline_workspace,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_bspline_eval(
         x: f64,
         B: *mut gsl_vector,
         w: *mut gsl_bspline_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_bspline_eval_nonzero(
@@ -16627,7 +16398,7 @@ extern "C" {
         istart: *mut usize,
         iend: *mut usize,
         w: *mut gsl_bspline_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_bspline_deriv_eval(
@@ -16635,7 +16406,7 @@ extern "C" {
         nderiv: usize,
         dB: *mut gsl_matrix,
         w: *mut gsl_bspline_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_bspline_deriv_eval_nonzero(
@@ -16645,23 +16416,23 @@ extern "C" {
         istart: *mut usize,
         iend: *mut usize,
         w: *mut gsl_bspline_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_rng_type {
-    pub name: *const ::std::os::raw::c_char,
-    pub max: ::std::os::raw::c_ulong,
-    pub min: ::std::os::raw::c_ulong,
+    pub name: *const ::core::ffi::c_char,
+    pub max: ::core::ffi::c_ulong,
+    pub min: ::core::ffi::c_ulong,
     pub size: usize,
-    pub set: ::std::option::Option<
-        unsafe extern "C" fn(state: *mut ::std::os::raw::c_void, seed: ::std::os::raw::c_ulong),
+    pub set: ::core::option::Option<
+        unsafe extern "C" fn(state: *mut ::core::ffi::c_void, seed: ::core::ffi::c_ulong),
     >,
-    pub get: ::std::option::Option<
-        unsafe extern "C" fn(state: *mut ::std::os::raw::c_void) -> ::std::os::raw::c_ulong,
+    pub get: ::core::option::Option<
+        unsafe extern "C" fn(state: *mut ::core::ffi::c_void) -> ::core::ffi::c_ulong,
     >,
     pub get_double:
-        ::std::option::Option<unsafe extern "C" fn(state: *mut ::std::os::raw::c_void) -> f64>,
+        ::core::option::Option<unsafe extern "C" fn(state: *mut ::core::ffi::c_void) -> f64>,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -16859,13 +16630,13 @@ extern "C" {
     pub static mut gsl_rng_default: *const gsl_rng_type;
 }
 extern "C" {
-    pub static mut gsl_rng_default_seed: ::std::os::raw::c_ulong;
+    pub static mut gsl_rng_default_seed: ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_rng_alloc(T: *const gsl_rng_type) -> *mut gsl_rng;
 }
 extern "C" {
-    pub fn gsl_rng_memcpy(dest: *mut gsl_rng, src: *const gsl_rng) -> ::std::os::raw::c_int;
+    pub fn gsl_rng_memcpy(dest: *mut gsl_rng, src: *const gsl_rng) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_rng_clone(r: *const gsl_rng) -> *mut gsl_rng;
@@ -16874,28 +16645,28 @@ extern "C" {
     pub fn gsl_rng_free(r: *mut gsl_rng);
 }
 extern "C" {
-    pub fn gsl_rng_set(r: *const gsl_rng, seed: ::std::os::raw::c_ulong);
+    pub fn gsl_rng_set(r: *const gsl_rng, seed: ::core::ffi::c_ulong);
 }
 extern "C" {
-    pub fn gsl_rng_max(r: *const gsl_rng) -> ::std::os::raw::c_ulong;
+    pub fn gsl_rng_max(r: *const gsl_rng) -> ::core::ffi::c_ulong;
 }
 extern "C" {
-    pub fn gsl_rng_min(r: *const gsl_rng) -> ::std::os::raw::c_ulong;
+    pub fn gsl_rng_min(r: *const gsl_rng) -> ::core::ffi::c_ulong;
 }
 extern "C" {
-    pub fn gsl_rng_name(r: *const gsl_rng) -> *const ::std::os::raw::c_char;
+    pub fn gsl_rng_name(r: *const gsl_rng) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_rng_fread(stream: *mut FILE, r: *mut gsl_rng) -> ::std::os::raw::c_int;
+    pub fn gsl_rng_fread(stream: *mut FILE, r: *mut gsl_rng) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_rng_fwrite(stream: *mut FILE, r: *const gsl_rng) -> ::std::os::raw::c_int;
+    pub fn gsl_rng_fwrite(stream: *mut FILE, r: *const gsl_rng) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_rng_size(r: *const gsl_rng) -> usize;
 }
 extern "C" {
-    pub fn gsl_rng_state(r: *const gsl_rng) -> *mut ::std::os::raw::c_void;
+    pub fn gsl_rng_state(r: *const gsl_rng) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn gsl_rng_print_state(r: *const gsl_rng);
@@ -16904,7 +16675,7 @@ extern "C" {
     pub fn gsl_rng_env_setup() -> *const gsl_rng_type;
 }
 extern "C" {
-    pub fn gsl_rng_get(r: *const gsl_rng) -> ::std::os::raw::c_ulong;
+    pub fn gsl_rng_get(r: *const gsl_rng) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_rng_uniform(r: *const gsl_rng) -> f64;
@@ -16913,23 +16684,20 @@ extern "C" {
     pub fn gsl_rng_uniform_pos(r: *const gsl_rng) -> f64;
 }
 extern "C" {
-    pub fn gsl_rng_uniform_int(
-        r: *const gsl_rng,
-        n: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_ulong;
+    pub fn gsl_rng_uniform_int(r: *const gsl_rng, n: ::core::ffi::c_ulong) -> ::core::ffi::c_ulong;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_monte_function_struct {
-    pub f: ::std::option::Option<
+    pub f: ::core::option::Option<
         unsafe extern "C" fn(
             x_array: *mut f64,
             dim: usize,
-            params: *mut ::std::os::raw::c_void,
+            params: *mut ::core::ffi::c_void,
         ) -> f64,
     >,
     pub dim: usize,
-    pub params: *mut ::std::os::raw::c_void,
+    pub params: *mut ::core::ffi::c_void,
 }
 pub type gsl_monte_function = gsl_monte_function_struct;
 #[repr(C)]
@@ -16946,13 +16714,13 @@ extern "C" {
         state: *mut gsl_monte_plain_state,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_monte_plain_alloc(dim: usize) -> *mut gsl_monte_plain_state;
 }
 extern "C" {
-    pub fn gsl_monte_plain_init(state: *mut gsl_monte_plain_state) -> ::std::os::raw::c_int;
+    pub fn gsl_monte_plain_init(state: *mut gsl_monte_plain_state) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_monte_plain_free(state: *mut gsl_monte_plain_state);
@@ -16971,13 +16739,13 @@ extern "C" {
         state: *mut gsl_monte_miser_state,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_monte_miser_alloc(dim: usize) -> *mut gsl_monte_miser_state;
 }
 extern "C" {
-    pub fn gsl_monte_miser_init(state: *mut gsl_monte_miser_state) -> ::std::os::raw::c_int;
+    pub fn gsl_monte_miser_init(state: *mut gsl_monte_miser_state) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_monte_miser_free(state: *mut gsl_monte_miser_state);
@@ -17013,35 +16781,35 @@ extern "C" {
     pub fn gsl_sort_vector_ushort_index(
         p: *mut gsl_permutation,
         v: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_ushort_smallest(
-        dest: *mut ::std::os::raw::c_ushort,
+        dest: *mut ::core::ffi::c_ushort,
         k: usize,
         v: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_ushort_largest(
-        dest: *mut ::std::os::raw::c_ushort,
+        dest: *mut ::core::ffi::c_ushort,
         k: usize,
         v: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_ushort_smallest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_ushort_largest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_elljac_e(
@@ -17050,7 +16818,7 @@ extern "C" {
         sn: *mut f64,
         cn: *mut f64,
         dn: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -17069,7 +16837,7 @@ extern "C" {
         cov: *mut gsl_matrix,
         chisq: *mut f64,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_3")]
@@ -17083,19 +16851,19 @@ extern "C" {
         chisq: *mut f64,
         rank: *mut usize,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_svd(
         X: *const gsl_matrix,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_bsvd(
         X: *const gsl_matrix,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_3")]
@@ -17111,7 +16879,7 @@ extern "C" {
         rnorm: *mut f64,
         snorm: *mut f64,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_applyW(
@@ -17120,7 +16888,7 @@ extern "C" {
         y: *const gsl_vector,
         WX: *mut gsl_matrix,
         Wy: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_stdform1(
@@ -17130,7 +16898,7 @@ extern "C" {
         Xs: *mut gsl_matrix,
         ys: *mut gsl_vector,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_wstdform1(
@@ -17141,13 +16909,13 @@ extern "C" {
         Xs: *mut gsl_matrix,
         ys: *mut gsl_vector,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_L_decomp(
         L: *mut gsl_matrix,
         tau: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_stdform2(
@@ -17159,7 +16927,7 @@ extern "C" {
         ys: *mut gsl_vector,
         M: *mut gsl_matrix,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_wstdform2(
@@ -17172,7 +16940,7 @@ extern "C" {
         ys: *mut gsl_vector,
         M: *mut gsl_matrix,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_genform1(
@@ -17180,7 +16948,7 @@ extern "C" {
         cs: *const gsl_vector,
         c: *mut gsl_vector,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_genform2(
@@ -17192,7 +16960,7 @@ extern "C" {
         M: *const gsl_matrix,
         c: *mut gsl_vector,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_wgenform2(
@@ -17205,14 +16973,14 @@ extern "C" {
         M: *const gsl_matrix,
         c: *mut gsl_vector,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_lreg(
         smin: f64,
         smax: f64,
         reg_param: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_lcurve(
@@ -17221,24 +16989,24 @@ extern "C" {
         rho: *mut gsl_vector,
         eta: *mut gsl_vector,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_lcorner(
         rho: *const gsl_vector,
         eta: *const gsl_vector,
         idx: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_lcorner2(
         reg_param: *const gsl_vector,
         eta: *const gsl_vector,
         idx: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multifit_linear_Lk(p: usize, k: usize, L: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_multifit_linear_Lk(p: usize, k: usize, L: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_Lsobolev(
@@ -17247,7 +17015,7 @@ extern "C" {
         alpha: *const gsl_vector,
         L: *mut gsl_matrix,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_wlinear(
@@ -17258,7 +17026,7 @@ extern "C" {
         cov: *mut gsl_matrix,
         chisq: *mut f64,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_3")]
@@ -17273,7 +17041,7 @@ extern "C" {
         chisq: *mut f64,
         rank: *mut usize,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_wlinear_svd(
@@ -17286,7 +17054,7 @@ extern "C" {
         cov: *mut gsl_matrix,
         chisq: *mut f64,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_wlinear_usvd(
@@ -17299,7 +17067,7 @@ extern "C" {
         cov: *mut gsl_matrix,
         chisq: *mut f64,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_est(
@@ -17308,7 +17076,7 @@ extern "C" {
         cov: *const gsl_matrix,
         y: *mut f64,
         y_err: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -17321,7 +17089,7 @@ extern "C" {
         y: *const gsl_vector,
         c: *const gsl_vector,
         r: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_gcv_init(
@@ -17330,7 +17098,7 @@ extern "C" {
         UTy: *mut gsl_vector,
         delta0: *mut f64,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_gcv_curve(
@@ -17339,7 +17107,7 @@ extern "C" {
         delta0: f64,
         G: *mut gsl_vector,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_gcv_min(
@@ -17349,7 +17117,7 @@ extern "C" {
         delta0: f64,
         lambda: *mut f64,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_linear_gcv_calc(
@@ -17367,7 +17135,7 @@ extern "C" {
         lambda: *mut f64,
         G_lambda: *mut f64,
         work: *mut gsl_multifit_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -17413,18 +17181,18 @@ extern "C" {
     pub fn gsl_multifit_robust_tune(
         tune: f64,
         w: *mut gsl_multifit_robust_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_robust_maxiter(
         maxiter: usize,
         w: *mut gsl_multifit_robust_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_robust_name(
         w: *const gsl_multifit_robust_workspace,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_multifit_robust_statistics(
@@ -17436,7 +17204,7 @@ extern "C" {
         r: *const gsl_vector,
         wts: *mut gsl_vector,
         w: *mut gsl_multifit_robust_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_robust(
@@ -17445,7 +17213,7 @@ extern "C" {
         c: *mut gsl_vector,
         cov: *mut gsl_matrix,
         w: *mut gsl_multifit_robust_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_robust_est(
@@ -17454,7 +17222,7 @@ extern "C" {
         cov: *const gsl_matrix,
         y: *mut f64,
         y_err: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_robust_residuals(
@@ -17463,7 +17231,7 @@ extern "C" {
         c: *const gsl_vector,
         r: *mut gsl_vector,
         w: *mut gsl_multifit_robust_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_int(v: *mut gsl_vector_int);
@@ -17475,35 +17243,35 @@ extern "C" {
     pub fn gsl_sort_vector_int_index(
         p: *mut gsl_permutation,
         v: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_int_smallest(
-        dest: *mut ::std::os::raw::c_int,
+        dest: *mut ::core::ffi::c_int,
         k: usize,
         v: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_int_largest(
-        dest: *mut ::std::os::raw::c_int,
+        dest: *mut ::core::ffi::c_int,
         k: usize,
         v: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_int_smallest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_int_largest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -17517,48 +17285,48 @@ pub type gsl_sf_result = gsl_sf_result_struct;
 pub struct gsl_sf_result_e10_struct {
     pub val: f64,
     pub err: f64,
-    pub e10: ::std::os::raw::c_int,
+    pub e10: ::core::ffi::c_int,
 }
 pub type gsl_sf_result_e10 = gsl_sf_result_e10_struct;
 extern "C" {
     pub fn gsl_sf_result_smash_e(
         re: *const gsl_sf_result_e10,
         r: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_Pl_e(
-        l: ::std::os::raw::c_int,
+        l: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_legendre_Pl(l: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_legendre_Pl(l: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_legendre_Pl_array(
-        lmax: ::std::os::raw::c_int,
+        lmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_Pl_deriv_array(
-        lmax: ::std::os::raw::c_int,
+        lmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
         result_deriv_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_legendre_P1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_legendre_P1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_legendre_P2_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_legendre_P2_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_legendre_P3_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_legendre_P3_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_P1(x: f64) -> f64;
@@ -17570,99 +17338,95 @@ extern "C" {
     pub fn gsl_sf_legendre_P3(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_legendre_Q0_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_legendre_Q0_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_Q0(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_legendre_Q1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_legendre_Q1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_Q1(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_legendre_Ql_e(
-        l: ::std::os::raw::c_int,
+        l: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_legendre_Ql(l: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_legendre_Ql(l: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_legendre_Plm_e(
-        l: ::std::os::raw::c_int,
-        m: ::std::os::raw::c_int,
+        l: ::core::ffi::c_int,
+        m: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_legendre_Plm(l: ::std::os::raw::c_int, m: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_legendre_Plm(l: ::core::ffi::c_int, m: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_legendre_Plm_array(
-        lmax: ::std::os::raw::c_int,
-        m: ::std::os::raw::c_int,
+        lmax: ::core::ffi::c_int,
+        m: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_Plm_deriv_array(
-        lmax: ::std::os::raw::c_int,
-        m: ::std::os::raw::c_int,
+        lmax: ::core::ffi::c_int,
+        m: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
         result_deriv_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_sphPlm_e(
-        l: ::std::os::raw::c_int,
-        m: ::std::os::raw::c_int,
+        l: ::core::ffi::c_int,
+        m: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_legendre_sphPlm(
-        l: ::std::os::raw::c_int,
-        m: ::std::os::raw::c_int,
-        x: f64,
-    ) -> f64;
+    pub fn gsl_sf_legendre_sphPlm(l: ::core::ffi::c_int, m: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_legendre_sphPlm_array(
-        lmax: ::std::os::raw::c_int,
-        m: ::std::os::raw::c_int,
+        lmax: ::core::ffi::c_int,
+        m: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_sphPlm_deriv_array(
-        lmax: ::std::os::raw::c_int,
-        m: ::std::os::raw::c_int,
+        lmax: ::core::ffi::c_int,
+        m: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
         result_deriv_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_array_size(
-        lmax: ::std::os::raw::c_int,
-        m: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        lmax: ::core::ffi::c_int,
+        m: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_conicalP_half_e(
         lambda: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_conicalP_half(lambda: f64, x: f64) -> f64;
@@ -17672,7 +17436,7 @@ extern "C" {
         lambda: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_conicalP_mhalf(lambda: f64, x: f64) -> f64;
@@ -17682,7 +17446,7 @@ extern "C" {
         lambda: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_conicalP_0(lambda: f64, x: f64) -> f64;
@@ -17692,39 +17456,39 @@ extern "C" {
         lambda: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_conicalP_1(lambda: f64, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_conicalP_sph_reg_e(
-        l: ::std::os::raw::c_int,
+        l: ::core::ffi::c_int,
         lambda: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_conicalP_sph_reg(l: ::std::os::raw::c_int, lambda: f64, x: f64) -> f64;
+    pub fn gsl_sf_conicalP_sph_reg(l: ::core::ffi::c_int, lambda: f64, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_conicalP_cyl_reg_e(
-        m: ::std::os::raw::c_int,
+        m: ::core::ffi::c_int,
         lambda: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_conicalP_cyl_reg(m: ::std::os::raw::c_int, lambda: f64, x: f64) -> f64;
+    pub fn gsl_sf_conicalP_cyl_reg(m: ::core::ffi::c_int, lambda: f64, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_legendre_H3d_0_e(
         lambda: f64,
         eta: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_H3d_0(lambda: f64, eta: f64) -> f64;
@@ -17734,42 +17498,42 @@ extern "C" {
         lambda: f64,
         eta: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_H3d_1(lambda: f64, eta: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_legendre_H3d_e(
-        l: ::std::os::raw::c_int,
+        l: ::core::ffi::c_int,
         lambda: f64,
         eta: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_legendre_H3d(l: ::std::os::raw::c_int, lambda: f64, eta: f64) -> f64;
+    pub fn gsl_sf_legendre_H3d(l: ::core::ffi::c_int, lambda: f64, eta: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_legendre_H3d_array(
-        lmax: ::std::os::raw::c_int,
+        lmax: ::core::ffi::c_int,
         lambda: f64,
         eta: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 pub const gsl_sf_legendre_t_GSL_SF_LEGENDRE_SCHMIDT: gsl_sf_legendre_t = 0;
 pub const gsl_sf_legendre_t_GSL_SF_LEGENDRE_SPHARM: gsl_sf_legendre_t = 1;
 pub const gsl_sf_legendre_t_GSL_SF_LEGENDRE_FULL: gsl_sf_legendre_t = 2;
 pub const gsl_sf_legendre_t_GSL_SF_LEGENDRE_NONE: gsl_sf_legendre_t = 3;
-pub type gsl_sf_legendre_t = ::std::os::raw::c_uint;
+pub type gsl_sf_legendre_t = ::core::ffi::c_uint;
 extern "C" {
     pub fn gsl_sf_legendre_array(
         norm: gsl_sf_legendre_t,
         lmax: usize,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_array_e(
@@ -17778,7 +17542,7 @@ extern "C" {
         x: f64,
         csphase: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_deriv_array(
@@ -17787,7 +17551,7 @@ extern "C" {
         x: f64,
         result_array: *mut f64,
         result_deriv_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_deriv_array_e(
@@ -17797,7 +17561,7 @@ extern "C" {
         csphase: f64,
         result_array: *mut f64,
         result_deriv_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_deriv_alt_array(
@@ -17806,7 +17570,7 @@ extern "C" {
         x: f64,
         result_array: *mut f64,
         result_deriv_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_deriv_alt_array_e(
@@ -17816,7 +17580,7 @@ extern "C" {
         csphase: f64,
         result_array: *mut f64,
         result_deriv_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_deriv2_array(
@@ -17826,7 +17590,7 @@ extern "C" {
         result_array: *mut f64,
         result_deriv_array: *mut f64,
         result_deriv2_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_deriv2_array_e(
@@ -17837,7 +17601,7 @@ extern "C" {
         result_array: *mut f64,
         result_deriv_array: *mut f64,
         result_deriv2_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_deriv2_alt_array(
@@ -17847,7 +17611,7 @@ extern "C" {
         result_array: *mut f64,
         result_deriv_array: *mut f64,
         result_deriv2_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_deriv2_alt_array_e(
@@ -17858,7 +17622,7 @@ extern "C" {
         result_array: *mut f64,
         result_deriv_array: *mut f64,
         result_deriv2_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_legendre_array_n(lmax: usize) -> usize;
@@ -17870,13 +17634,13 @@ extern "C" {
     pub fn gsl_sf_legendre_array_index(l: usize, m: usize) -> usize;
 }
 extern "C" {
-    pub fn gsl_sort_ulong(data: *mut ::std::os::raw::c_ulong, stride: usize, n: usize);
+    pub fn gsl_sort_ulong(data: *mut ::core::ffi::c_ulong, stride: usize, n: usize);
 }
 extern "C" {
     pub fn gsl_sort2_ulong(
-        data1: *mut ::std::os::raw::c_ulong,
+        data1: *mut ::core::ffi::c_ulong,
         stride1: usize,
-        data2: *mut ::std::os::raw::c_ulong,
+        data2: *mut ::core::ffi::c_ulong,
         stride2: usize,
         n: usize,
     );
@@ -17884,46 +17648,46 @@ extern "C" {
 extern "C" {
     pub fn gsl_sort_ulong_index(
         p: *mut usize,
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_sort_ulong_smallest(
-        dest: *mut ::std::os::raw::c_ulong,
+        dest: *mut ::core::ffi::c_ulong,
         k: usize,
-        src: *const ::std::os::raw::c_ulong,
+        src: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_ulong_smallest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_ulong,
+        src: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_ulong_largest(
-        dest: *mut ::std::os::raw::c_ulong,
+        dest: *mut ::core::ffi::c_ulong,
         k: usize,
-        src: *const ::std::os::raw::c_ulong,
+        src: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_ulong_largest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_ulong,
+        src: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -17944,23 +17708,19 @@ extern "C" {
     pub fn gsl_dht_new(size: usize, nu: f64, xmax: f64) -> *mut gsl_dht;
 }
 extern "C" {
-    pub fn gsl_dht_init(t: *mut gsl_dht, nu: f64, xmax: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_dht_init(t: *mut gsl_dht, nu: f64, xmax: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_dht_x_sample(t: *const gsl_dht, n: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_dht_x_sample(t: *const gsl_dht, n: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
-    pub fn gsl_dht_k_sample(t: *const gsl_dht, n: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_dht_k_sample(t: *const gsl_dht, n: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
     pub fn gsl_dht_free(t: *mut gsl_dht);
 }
 extern "C" {
-    pub fn gsl_dht_apply(
-        t: *const gsl_dht,
-        f_in: *mut f64,
-        f_out: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_dht_apply(t: *const gsl_dht, f_in: *mut f64, f_out: *mut f64) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -17981,13 +17741,11 @@ extern "C" {
     pub static mut gsl_qrng_reversehalton: *const gsl_qrng_type;
 }
 extern "C" {
-    pub fn gsl_qrng_alloc(
-        T: *const gsl_qrng_type,
-        dimension: ::std::os::raw::c_uint,
-    ) -> *mut gsl_qrng;
+    pub fn gsl_qrng_alloc(T: *const gsl_qrng_type, dimension: ::core::ffi::c_uint)
+        -> *mut gsl_qrng;
 }
 extern "C" {
-    pub fn gsl_qrng_memcpy(dest: *mut gsl_qrng, src: *const gsl_qrng) -> ::std::os::raw::c_int;
+    pub fn gsl_qrng_memcpy(dest: *mut gsl_qrng, src: *const gsl_qrng) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_qrng_clone(q: *const gsl_qrng) -> *mut gsl_qrng;
@@ -17999,22 +17757,22 @@ extern "C" {
     pub fn gsl_qrng_init(q: *mut gsl_qrng);
 }
 extern "C" {
-    pub fn gsl_qrng_name(q: *const gsl_qrng) -> *const ::std::os::raw::c_char;
+    pub fn gsl_qrng_name(q: *const gsl_qrng) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_qrng_size(q: *const gsl_qrng) -> usize;
 }
 extern "C" {
-    pub fn gsl_qrng_state(q: *const gsl_qrng) -> *mut ::std::os::raw::c_void;
+    pub fn gsl_qrng_state(q: *const gsl_qrng) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
-    pub fn gsl_qrng_get(q: *const gsl_qrng, x: *mut f64) -> ::std::os::raw::c_int;
+    pub fn gsl_qrng_get(q: *const gsl_qrng, x: *mut f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_matrix_uint(
         p: *const gsl_permutation,
         A: *mut gsl_matrix_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_stats_long_double_mean(data: *const u128, stride: usize, n: usize) -> f64;
@@ -18423,7 +18181,7 @@ extern "C" {
         stride: usize,
         n: usize,
         work: *mut u128,
-        work_int: *mut ::std::os::raw::c_int,
+        work_int: *mut ::core::ffi::c_int,
     ) -> u128;
 }
 extern "C" {
@@ -18432,100 +18190,97 @@ extern "C" {
         stride: usize,
         n: usize,
         work: *mut u128,
-        work_int: *mut ::std::os::raw::c_int,
+        work_int: *mut ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_permute_matrix_complex_long_double(
         p: *const gsl_permutation,
         A: *mut gsl_matrix_complex_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_matrix_complex(
         p: *const gsl_permutation,
         A: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_matrix_complex_float(
         p: *const gsl_permutation,
         A: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_matrix_long_double(
         p: *const gsl_permutation,
         A: *mut gsl_matrix_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
-    pub fn gsl_permute_matrix(
-        p: *const gsl_permutation,
-        A: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_permute_matrix(p: *const gsl_permutation, A: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_matrix_float(
         p: *const gsl_permutation,
         A: *mut gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_matrix_ulong(
         p: *const gsl_permutation,
         A: *mut gsl_matrix_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_matrix_long(
         p: *const gsl_permutation,
         A: *mut gsl_matrix_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_matrix_int(
         p: *const gsl_permutation,
         A: *mut gsl_matrix_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_matrix_ushort(
         p: *const gsl_permutation,
         A: *mut gsl_matrix_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_matrix_short(
         p: *const gsl_permutation,
         A: *mut gsl_matrix_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_matrix_uchar(
         p: *const gsl_permutation,
         A: *mut gsl_matrix_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_matrix_char(
         p: *const gsl_permutation,
         A: *mut gsl_matrix_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_char(
         p: *const gsl_permutation,
         v: *mut gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_char_inverse(
         p: *const gsl_permutation,
         v: *mut gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -18538,7 +18293,7 @@ extern "C" {
         f: *const gsl_vector,
         epsrel: f64,
         jacobian: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -18560,15 +18315,15 @@ extern "C" {
         s: *mut gsl_multiroot_fsolver,
         f: *mut gsl_multiroot_function,
         x: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multiroot_fsolver_iterate(s: *mut gsl_multiroot_fsolver) -> ::std::os::raw::c_int;
+    pub fn gsl_multiroot_fsolver_iterate(s: *mut gsl_multiroot_fsolver) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multiroot_fsolver_name(
         s: *const gsl_multiroot_fsolver,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_multiroot_fsolver_root(s: *const gsl_multiroot_fsolver) -> *mut gsl_vector;
@@ -18600,12 +18355,10 @@ extern "C" {
         s: *mut gsl_multiroot_fdfsolver,
         fdf: *mut gsl_multiroot_function_fdf,
         x: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multiroot_fdfsolver_iterate(
-        s: *mut gsl_multiroot_fdfsolver,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_multiroot_fdfsolver_iterate(s: *mut gsl_multiroot_fdfsolver) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multiroot_fdfsolver_free(s: *mut gsl_multiroot_fdfsolver);
@@ -18613,7 +18366,7 @@ extern "C" {
 extern "C" {
     pub fn gsl_multiroot_fdfsolver_name(
         s: *const gsl_multiroot_fdfsolver,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_multiroot_fdfsolver_root(s: *const gsl_multiroot_fdfsolver) -> *mut gsl_vector;
@@ -18630,10 +18383,10 @@ extern "C" {
         x: *const gsl_vector,
         epsabs: f64,
         epsrel: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multiroot_test_residual(f: *const gsl_vector, epsabs: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_multiroot_test_residual(f: *const gsl_vector, epsabs: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub static mut gsl_multiroot_fsolver_dnewton: *const gsl_multiroot_fsolver_type;
@@ -18662,18 +18415,18 @@ extern "C" {
 extern "C" {
     pub fn gsl_permute_int(
         p: *const usize,
-        data: *mut ::std::os::raw::c_int,
+        data: *mut ::core::ffi::c_int,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_int_inverse(
         p: *const usize,
-        data: *mut ::std::os::raw::c_int,
+        data: *mut ::core::ffi::c_int,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -18713,7 +18466,7 @@ extern "C" {
     pub fn gsl_interp_accel_alloc() -> *mut gsl_interp_accel;
 }
 extern "C" {
-    pub fn gsl_interp_accel_reset(a: *mut gsl_interp_accel) -> ::std::os::raw::c_int;
+    pub fn gsl_interp_accel_reset(a: *mut gsl_interp_accel) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_interp_accel_free(a: *mut gsl_interp_accel);
@@ -18727,16 +18480,16 @@ extern "C" {
         xa: *const f64,
         ya: *const f64,
         size: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_interp_name(interp: *const gsl_interp) -> *const ::std::os::raw::c_char;
+    pub fn gsl_interp_name(interp: *const gsl_interp) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_interp_min_size(interp: *const gsl_interp) -> ::std::os::raw::c_uint;
+    pub fn gsl_interp_min_size(interp: *const gsl_interp) -> ::core::ffi::c_uint;
 }
 extern "C" {
-    pub fn gsl_interp_type_min_size(T: *const gsl_interp_type) -> ::std::os::raw::c_uint;
+    pub fn gsl_interp_type_min_size(T: *const gsl_interp_type) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_interp_eval_e(
@@ -18746,7 +18499,7 @@ extern "C" {
         x: f64,
         a: *mut gsl_interp_accel,
         y: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_interp_eval(
@@ -18765,7 +18518,7 @@ extern "C" {
         x: f64,
         a: *mut gsl_interp_accel,
         d: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_interp_eval_deriv(
@@ -18784,7 +18537,7 @@ extern "C" {
         x: f64,
         a: *mut gsl_interp_accel,
         d2: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_interp_eval_deriv2(
@@ -18804,7 +18557,7 @@ extern "C" {
         b: f64,
         acc: *mut gsl_interp_accel,
         result: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_interp_eval_integ(
@@ -18855,7 +18608,7 @@ extern "C" {
     ) -> *mut gsl_interp2d;
 }
 extern "C" {
-    pub fn gsl_interp2d_name(interp: *const gsl_interp2d) -> *const ::std::os::raw::c_char;
+    pub fn gsl_interp2d_name(interp: *const gsl_interp2d) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_interp2d_min_size(interp: *const gsl_interp2d) -> usize;
@@ -18870,7 +18623,7 @@ extern "C" {
         i: usize,
         j: usize,
         z: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_interp2d_get(
@@ -18891,7 +18644,7 @@ extern "C" {
         za: *const f64,
         xsize: usize,
         ysize: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_interp2d_free(interp: *mut gsl_interp2d);
@@ -18931,7 +18684,7 @@ extern "C" {
         xa: *mut gsl_interp_accel,
         ya: *mut gsl_interp_accel,
         z: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_interp2d_eval_e_extrap(
@@ -18944,7 +18697,7 @@ extern "C" {
         xa: *mut gsl_interp_accel,
         ya: *mut gsl_interp_accel,
         z: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_interp2d_eval_extrap_e(
@@ -18957,7 +18710,7 @@ extern "C" {
         xa: *mut gsl_interp_accel,
         ya: *mut gsl_interp_accel,
         z: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_interp2d_eval_deriv_x(
@@ -18982,7 +18735,7 @@ extern "C" {
         xa: *mut gsl_interp_accel,
         ya: *mut gsl_interp_accel,
         z: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_interp2d_eval_deriv_y(
@@ -19007,7 +18760,7 @@ extern "C" {
         xa: *mut gsl_interp_accel,
         ya: *mut gsl_interp_accel,
         z: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_interp2d_eval_deriv_xx(
@@ -19032,7 +18785,7 @@ extern "C" {
         xa: *mut gsl_interp_accel,
         ya: *mut gsl_interp_accel,
         z: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_interp2d_eval_deriv_yy(
@@ -19057,7 +18810,7 @@ extern "C" {
         xa: *mut gsl_interp_accel,
         ya: *mut gsl_interp_accel,
         z: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_interp2d_eval_deriv_xy(
@@ -19082,7 +18835,7 @@ extern "C" {
         xa: *mut gsl_interp_accel,
         ya: *mut gsl_interp_accel,
         z: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -19102,7 +18855,7 @@ extern "C" {
         za: *const f64,
         xsize: usize,
         ysize: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spline2d_free(interp: *mut gsl_spline2d);
@@ -19124,7 +18877,7 @@ extern "C" {
         xa: *mut gsl_interp_accel,
         ya: *mut gsl_interp_accel,
         z: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spline2d_eval_deriv_x(
@@ -19143,7 +18896,7 @@ extern "C" {
         xa: *mut gsl_interp_accel,
         ya: *mut gsl_interp_accel,
         z: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spline2d_eval_deriv_y(
@@ -19162,7 +18915,7 @@ extern "C" {
         xa: *mut gsl_interp_accel,
         ya: *mut gsl_interp_accel,
         z: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spline2d_eval_deriv_xx(
@@ -19181,7 +18934,7 @@ extern "C" {
         xa: *mut gsl_interp_accel,
         ya: *mut gsl_interp_accel,
         z: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spline2d_eval_deriv_yy(
@@ -19200,7 +18953,7 @@ extern "C" {
         xa: *mut gsl_interp_accel,
         ya: *mut gsl_interp_accel,
         z: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spline2d_eval_deriv_xy(
@@ -19219,13 +18972,13 @@ extern "C" {
         xa: *mut gsl_interp_accel,
         ya: *mut gsl_interp_accel,
         z: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spline2d_min_size(interp: *const gsl_spline2d) -> usize;
 }
 extern "C" {
-    pub fn gsl_spline2d_name(interp: *const gsl_spline2d) -> *const ::std::os::raw::c_char;
+    pub fn gsl_spline2d_name(interp: *const gsl_spline2d) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_spline2d_set(
@@ -19234,7 +18987,7 @@ extern "C" {
         i: usize,
         j: usize,
         z: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spline2d_get(
@@ -19254,35 +19007,35 @@ extern "C" {
     pub fn gsl_sort_vector_uint_index(
         p: *mut gsl_permutation,
         v: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_uint_smallest(
-        dest: *mut ::std::os::raw::c_uint,
+        dest: *mut ::core::ffi::c_uint,
         k: usize,
         v: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_uint_largest(
-        dest: *mut ::std::os::raw::c_uint,
+        dest: *mut ::core::ffi::c_uint,
         k: usize,
         v: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_uint_smallest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_uint_largest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_long_double(v: *mut gsl_vector_long_double);
@@ -19297,35 +19050,35 @@ extern "C" {
     pub fn gsl_sort_vector_long_double_index(
         p: *mut gsl_permutation,
         v: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_long_double_smallest(
         dest: *mut u128,
         k: usize,
         v: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_long_double_largest(
         dest: *mut u128,
         k: usize,
         v: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_long_double_smallest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_long_double_largest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector(v: *mut gsl_vector);
@@ -19337,35 +19090,35 @@ extern "C" {
     pub fn gsl_sort_vector_index(
         p: *mut gsl_permutation,
         v: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_smallest(
         dest: *mut f64,
         k: usize,
         v: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_largest(
         dest: *mut f64,
         k: usize,
         v: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_smallest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_largest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_float(v: *mut gsl_vector_float);
@@ -19377,35 +19130,35 @@ extern "C" {
     pub fn gsl_sort_vector_float_index(
         p: *mut gsl_permutation,
         v: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_float_smallest(
         dest: *mut f32,
         k: usize,
         v: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_float_largest(
         dest: *mut f32,
         k: usize,
         v: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_float_smallest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_float_largest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_ulong(v: *mut gsl_vector_ulong);
@@ -19417,35 +19170,35 @@ extern "C" {
     pub fn gsl_sort_vector_ulong_index(
         p: *mut gsl_permutation,
         v: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_ulong_smallest(
-        dest: *mut ::std::os::raw::c_ulong,
+        dest: *mut ::core::ffi::c_ulong,
         k: usize,
         v: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_ulong_largest(
-        dest: *mut ::std::os::raw::c_ulong,
+        dest: *mut ::core::ffi::c_ulong,
         k: usize,
         v: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_ulong_smallest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_ulong_largest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_long(v: *mut gsl_vector_long);
@@ -19457,35 +19210,35 @@ extern "C" {
     pub fn gsl_sort_vector_long_index(
         p: *mut gsl_permutation,
         v: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_long_smallest(
-        dest: *mut ::std::os::raw::c_long,
+        dest: *mut ::core::ffi::c_long,
         k: usize,
         v: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_long_largest(
-        dest: *mut ::std::os::raw::c_long,
+        dest: *mut ::core::ffi::c_long,
         k: usize,
         v: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_long_smallest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_long_largest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_long,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_short(v: *mut gsl_vector_short);
@@ -19497,35 +19250,35 @@ extern "C" {
     pub fn gsl_sort_vector_short_index(
         p: *mut gsl_permutation,
         v: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_short_smallest(
-        dest: *mut ::std::os::raw::c_short,
+        dest: *mut ::core::ffi::c_short,
         k: usize,
         v: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_short_largest(
-        dest: *mut ::std::os::raw::c_short,
+        dest: *mut ::core::ffi::c_short,
         k: usize,
         v: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_short_smallest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_short_largest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_uchar(v: *mut gsl_vector_uchar);
@@ -19537,35 +19290,35 @@ extern "C" {
     pub fn gsl_sort_vector_uchar_index(
         p: *mut gsl_permutation,
         v: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_uchar_smallest(
-        dest: *mut ::std::os::raw::c_uchar,
+        dest: *mut ::core::ffi::c_uchar,
         k: usize,
         v: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_uchar_largest(
-        dest: *mut ::std::os::raw::c_uchar,
+        dest: *mut ::core::ffi::c_uchar,
         k: usize,
         v: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_uchar_smallest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_uchar_largest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_char(v: *mut gsl_vector_char);
@@ -19577,93 +19330,93 @@ extern "C" {
     pub fn gsl_sort_vector_char_index(
         p: *mut gsl_permutation,
         v: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_char_smallest(
-        dest: *mut ::std::os::raw::c_char,
+        dest: *mut ::core::ffi::c_char,
         k: usize,
         v: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_char_largest(
-        dest: *mut ::std::os::raw::c_char,
+        dest: *mut ::core::ffi::c_char,
         k: usize,
         v: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_char_smallest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_vector_char_largest_index(
         p: *mut usize,
         k: usize,
         v: *const gsl_vector_char,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
-pub type gsl_mode_t = ::std::os::raw::c_uint;
+pub type gsl_mode_t = ::core::ffi::c_uint;
 extern "C" {
     pub fn gsl_blas_sdsdot(
         alpha: f32,
         X: *const gsl_vector_float,
         Y: *const gsl_vector_float,
         result: *mut f32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_dsdot(
         X: *const gsl_vector_float,
         Y: *const gsl_vector_float,
         result: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_sdot(
         X: *const gsl_vector_float,
         Y: *const gsl_vector_float,
         result: *mut f32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ddot(
         X: *const gsl_vector,
         Y: *const gsl_vector,
         result: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_cdotu(
         X: *const gsl_vector_complex_float,
         Y: *const gsl_vector_complex_float,
         dotu: *mut gsl_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_cdotc(
         X: *const gsl_vector_complex_float,
         Y: *const gsl_vector_complex_float,
         dotc: *mut gsl_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zdotu(
         X: *const gsl_vector_complex,
         Y: *const gsl_vector_complex,
         dotu: *mut gsl_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zdotc(
         X: *const gsl_vector_complex,
         Y: *const gsl_vector_complex,
         dotc: *mut gsl_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_snrm2(X: *const gsl_vector_float) -> f32;
@@ -19702,82 +19455,76 @@ extern "C" {
     pub fn gsl_blas_izamax(X: *const gsl_vector_complex) -> CBLAS_INDEX_t;
 }
 extern "C" {
-    pub fn gsl_blas_sswap(
-        X: *mut gsl_vector_float,
-        Y: *mut gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_blas_sswap(X: *mut gsl_vector_float, Y: *mut gsl_vector_float)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_scopy(
         X: *const gsl_vector_float,
         Y: *mut gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_saxpy(
         alpha: f32,
         X: *const gsl_vector_float,
         Y: *mut gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_blas_dswap(X: *mut gsl_vector, Y: *mut gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_blas_dswap(X: *mut gsl_vector, Y: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_blas_dcopy(X: *const gsl_vector, Y: *mut gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_blas_dcopy(X: *const gsl_vector, Y: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_daxpy(
         alpha: f64,
         X: *const gsl_vector,
         Y: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_cswap(
         X: *mut gsl_vector_complex_float,
         Y: *mut gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ccopy(
         X: *const gsl_vector_complex_float,
         Y: *mut gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_caxpy(
         alpha: gsl_complex_float,
         X: *const gsl_vector_complex_float,
         Y: *mut gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zswap(
         X: *mut gsl_vector_complex,
         Y: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zcopy(
         X: *const gsl_vector_complex,
         Y: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zaxpy(
         alpha: gsl_complex,
         X: *const gsl_vector_complex,
         Y: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_blas_srotg(
-        a: *mut f32,
-        b: *mut f32,
-        c: *mut f32,
-        s: *mut f32,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_blas_srotg(a: *mut f32, b: *mut f32, c: *mut f32, s: *mut f32)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_srotmg(
@@ -19786,7 +19533,7 @@ extern "C" {
         b1: *mut f32,
         b2: f32,
         P: *mut f32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_srot(
@@ -19794,22 +19541,18 @@ extern "C" {
         Y: *mut gsl_vector_float,
         c: f32,
         s: f32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_srotm(
         X: *mut gsl_vector_float,
         Y: *mut gsl_vector_float,
         P: *const f32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_blas_drotg(
-        a: *mut f64,
-        b: *mut f64,
-        c: *mut f64,
-        s: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_blas_drotg(a: *mut f64, b: *mut f64, c: *mut f64, s: *mut f64)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_drotmg(
@@ -19818,7 +19561,7 @@ extern "C" {
         b1: *mut f64,
         b2: f64,
         P: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_drot(
@@ -19826,14 +19569,14 @@ extern "C" {
         Y: *mut gsl_vector,
         c: f64,
         s: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_drotm(
         X: *mut gsl_vector,
         Y: *mut gsl_vector,
         P: *const f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_sscal(alpha: f32, X: *mut gsl_vector_float);
@@ -19861,7 +19604,7 @@ extern "C" {
         X: *const gsl_vector_float,
         beta: f32,
         Y: *mut gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_strmv(
@@ -19870,7 +19613,7 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         A: *const gsl_matrix_float,
         X: *mut gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_strsv(
@@ -19879,7 +19622,7 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         A: *const gsl_matrix_float,
         X: *mut gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_dgemv(
@@ -19889,7 +19632,7 @@ extern "C" {
         X: *const gsl_vector,
         beta: f64,
         Y: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_dtrmv(
@@ -19898,7 +19641,7 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         A: *const gsl_matrix,
         X: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_dtrsv(
@@ -19907,7 +19650,7 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         A: *const gsl_matrix,
         X: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_cgemv(
@@ -19917,7 +19660,7 @@ extern "C" {
         X: *const gsl_vector_complex_float,
         beta: gsl_complex_float,
         Y: *mut gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ctrmv(
@@ -19926,7 +19669,7 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         A: *const gsl_matrix_complex_float,
         X: *mut gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ctrsv(
@@ -19935,7 +19678,7 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         A: *const gsl_matrix_complex_float,
         X: *mut gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zgemv(
@@ -19945,7 +19688,7 @@ extern "C" {
         X: *const gsl_vector_complex,
         beta: gsl_complex,
         Y: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ztrmv(
@@ -19954,7 +19697,7 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         A: *const gsl_matrix_complex,
         X: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ztrsv(
@@ -19963,7 +19706,7 @@ extern "C" {
         Diag: CBLAS_DIAG_t,
         A: *const gsl_matrix_complex,
         X: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ssymv(
@@ -19973,7 +19716,7 @@ extern "C" {
         X: *const gsl_vector_float,
         beta: f32,
         Y: *mut gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_sger(
@@ -19981,7 +19724,7 @@ extern "C" {
         X: *const gsl_vector_float,
         Y: *const gsl_vector_float,
         A: *mut gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ssyr(
@@ -19989,7 +19732,7 @@ extern "C" {
         alpha: f32,
         X: *const gsl_vector_float,
         A: *mut gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ssyr2(
@@ -19998,7 +19741,7 @@ extern "C" {
         X: *const gsl_vector_float,
         Y: *const gsl_vector_float,
         A: *mut gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_dsymv(
@@ -20008,7 +19751,7 @@ extern "C" {
         X: *const gsl_vector,
         beta: f64,
         Y: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_dger(
@@ -20016,7 +19759,7 @@ extern "C" {
         X: *const gsl_vector,
         Y: *const gsl_vector,
         A: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_dsyr(
@@ -20024,7 +19767,7 @@ extern "C" {
         alpha: f64,
         X: *const gsl_vector,
         A: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_dsyr2(
@@ -20033,7 +19776,7 @@ extern "C" {
         X: *const gsl_vector,
         Y: *const gsl_vector,
         A: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_chemv(
@@ -20043,7 +19786,7 @@ extern "C" {
         X: *const gsl_vector_complex_float,
         beta: gsl_complex_float,
         Y: *mut gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_cgeru(
@@ -20051,7 +19794,7 @@ extern "C" {
         X: *const gsl_vector_complex_float,
         Y: *const gsl_vector_complex_float,
         A: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_cgerc(
@@ -20059,7 +19802,7 @@ extern "C" {
         X: *const gsl_vector_complex_float,
         Y: *const gsl_vector_complex_float,
         A: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_cher(
@@ -20067,7 +19810,7 @@ extern "C" {
         alpha: f32,
         X: *const gsl_vector_complex_float,
         A: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_cher2(
@@ -20076,7 +19819,7 @@ extern "C" {
         X: *const gsl_vector_complex_float,
         Y: *const gsl_vector_complex_float,
         A: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zhemv(
@@ -20086,7 +19829,7 @@ extern "C" {
         X: *const gsl_vector_complex,
         beta: gsl_complex,
         Y: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zgeru(
@@ -20094,7 +19837,7 @@ extern "C" {
         X: *const gsl_vector_complex,
         Y: *const gsl_vector_complex,
         A: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zgerc(
@@ -20102,7 +19845,7 @@ extern "C" {
         X: *const gsl_vector_complex,
         Y: *const gsl_vector_complex,
         A: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zher(
@@ -20110,7 +19853,7 @@ extern "C" {
         alpha: f64,
         X: *const gsl_vector_complex,
         A: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zher2(
@@ -20119,7 +19862,7 @@ extern "C" {
         X: *const gsl_vector_complex,
         Y: *const gsl_vector_complex,
         A: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_sgemm(
@@ -20130,7 +19873,7 @@ extern "C" {
         B: *const gsl_matrix_float,
         beta: f32,
         C: *mut gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ssymm(
@@ -20141,7 +19884,7 @@ extern "C" {
         B: *const gsl_matrix_float,
         beta: f32,
         C: *mut gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ssyrk(
@@ -20151,7 +19894,7 @@ extern "C" {
         A: *const gsl_matrix_float,
         beta: f32,
         C: *mut gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ssyr2k(
@@ -20162,7 +19905,7 @@ extern "C" {
         B: *const gsl_matrix_float,
         beta: f32,
         C: *mut gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_strmm(
@@ -20173,7 +19916,7 @@ extern "C" {
         alpha: f32,
         A: *const gsl_matrix_float,
         B: *mut gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_strsm(
@@ -20184,7 +19927,7 @@ extern "C" {
         alpha: f32,
         A: *const gsl_matrix_float,
         B: *mut gsl_matrix_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_dgemm(
@@ -20195,7 +19938,7 @@ extern "C" {
         B: *const gsl_matrix,
         beta: f64,
         C: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_dsymm(
@@ -20206,7 +19949,7 @@ extern "C" {
         B: *const gsl_matrix,
         beta: f64,
         C: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_dsyrk(
@@ -20216,7 +19959,7 @@ extern "C" {
         A: *const gsl_matrix,
         beta: f64,
         C: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_dsyr2k(
@@ -20227,7 +19970,7 @@ extern "C" {
         B: *const gsl_matrix,
         beta: f64,
         C: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_dtrmm(
@@ -20238,7 +19981,7 @@ extern "C" {
         alpha: f64,
         A: *const gsl_matrix,
         B: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_dtrsm(
@@ -20249,7 +19992,7 @@ extern "C" {
         alpha: f64,
         A: *const gsl_matrix,
         B: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_cgemm(
@@ -20260,7 +20003,7 @@ extern "C" {
         B: *const gsl_matrix_complex_float,
         beta: gsl_complex_float,
         C: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_csymm(
@@ -20271,7 +20014,7 @@ extern "C" {
         B: *const gsl_matrix_complex_float,
         beta: gsl_complex_float,
         C: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_csyrk(
@@ -20281,7 +20024,7 @@ extern "C" {
         A: *const gsl_matrix_complex_float,
         beta: gsl_complex_float,
         C: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_csyr2k(
@@ -20292,7 +20035,7 @@ extern "C" {
         B: *const gsl_matrix_complex_float,
         beta: gsl_complex_float,
         C: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ctrmm(
@@ -20303,7 +20046,7 @@ extern "C" {
         alpha: gsl_complex_float,
         A: *const gsl_matrix_complex_float,
         B: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ctrsm(
@@ -20314,7 +20057,7 @@ extern "C" {
         alpha: gsl_complex_float,
         A: *const gsl_matrix_complex_float,
         B: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zgemm(
@@ -20325,7 +20068,7 @@ extern "C" {
         B: *const gsl_matrix_complex,
         beta: gsl_complex,
         C: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zsymm(
@@ -20336,7 +20079,7 @@ extern "C" {
         B: *const gsl_matrix_complex,
         beta: gsl_complex,
         C: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zsyrk(
@@ -20346,7 +20089,7 @@ extern "C" {
         A: *const gsl_matrix_complex,
         beta: gsl_complex,
         C: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zsyr2k(
@@ -20357,7 +20100,7 @@ extern "C" {
         B: *const gsl_matrix_complex,
         beta: gsl_complex,
         C: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ztrmm(
@@ -20368,7 +20111,7 @@ extern "C" {
         alpha: gsl_complex,
         A: *const gsl_matrix_complex,
         B: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_ztrsm(
@@ -20379,7 +20122,7 @@ extern "C" {
         alpha: gsl_complex,
         A: *const gsl_matrix_complex,
         B: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_chemm(
@@ -20390,7 +20133,7 @@ extern "C" {
         B: *const gsl_matrix_complex_float,
         beta: gsl_complex_float,
         C: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_cherk(
@@ -20400,7 +20143,7 @@ extern "C" {
         A: *const gsl_matrix_complex_float,
         beta: f32,
         C: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_cher2k(
@@ -20411,7 +20154,7 @@ extern "C" {
         B: *const gsl_matrix_complex_float,
         beta: f32,
         C: *mut gsl_matrix_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zhemm(
@@ -20422,7 +20165,7 @@ extern "C" {
         B: *const gsl_matrix_complex,
         beta: gsl_complex,
         C: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zherk(
@@ -20432,7 +20175,7 @@ extern "C" {
         A: *const gsl_matrix_complex,
         beta: f64,
         C: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_blas_zher2k(
@@ -20443,18 +20186,18 @@ extern "C" {
         B: *const gsl_matrix_complex,
         beta: f64,
         C: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 pub const gsl_linalg_matrix_mod_t_GSL_LINALG_MOD_NONE: gsl_linalg_matrix_mod_t = 0;
 pub const gsl_linalg_matrix_mod_t_GSL_LINALG_MOD_TRANSPOSE: gsl_linalg_matrix_mod_t = 1;
 pub const gsl_linalg_matrix_mod_t_GSL_LINALG_MOD_CONJUGATE: gsl_linalg_matrix_mod_t = 2;
-pub type gsl_linalg_matrix_mod_t = ::std::os::raw::c_uint;
+pub type gsl_linalg_matrix_mod_t = ::core::ffi::c_uint;
 extern "C" {
     pub fn gsl_linalg_matmult(
         A: *const gsl_matrix,
         B: *const gsl_matrix,
         C: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_matmult_mod(
@@ -20463,14 +20206,14 @@ extern "C" {
         B: *const gsl_matrix,
         modB: gsl_linalg_matrix_mod_t,
         C: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_exponential_ss(
         A: *const gsl_matrix,
         eA: *mut gsl_matrix,
         mode: gsl_mode_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_householder_transform(v: *mut gsl_vector) -> f64;
@@ -20486,21 +20229,21 @@ extern "C" {
         tau: f64,
         v: *const gsl_vector,
         A: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_householder_mh(
         tau: f64,
         v: *const gsl_vector,
         A: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_householder_hv(
         tau: f64,
         v: *const gsl_vector,
         w: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_householder_left(
@@ -20508,7 +20251,7 @@ extern "C" {
         v: *const gsl_vector,
         A: *mut gsl_matrix,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_householder_right(
@@ -20516,31 +20259,31 @@ extern "C" {
         v: *const gsl_vector,
         A: *mut gsl_matrix,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_householder_hm1(tau: f64, A: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_householder_hm1(tau: f64, A: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_householder_hm(
         tau: gsl_complex,
         v: *const gsl_vector_complex,
         A: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_householder_mh(
         tau: gsl_complex,
         v: *const gsl_vector_complex,
         A: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_householder_hv(
         tau: gsl_complex,
         v: *const gsl_vector_complex,
         w: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_householder_left(
@@ -20548,30 +20291,30 @@ extern "C" {
         v: *const gsl_vector_complex,
         A: *mut gsl_matrix_complex,
         work: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_hessenberg_decomp(
         A: *mut gsl_matrix,
         tau: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_hessenberg_unpack(
         H: *mut gsl_matrix,
         tau: *mut gsl_vector,
         U: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_hessenberg_unpack_accum(
         H: *mut gsl_matrix,
         tau: *mut gsl_vector,
         U: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_hessenberg_set_zero(H: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_hessenberg_set_zero(H: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_hessenberg_submatrix(
@@ -20579,7 +20322,7 @@ extern "C" {
         A: *mut gsl_matrix,
         top: usize,
         tau: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_hesstri_decomp(
@@ -20588,7 +20331,7 @@ extern "C" {
         U: *mut gsl_matrix,
         V: *mut gsl_matrix,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_SV_decomp(
@@ -20596,7 +20339,7 @@ extern "C" {
         V: *mut gsl_matrix,
         S: *mut gsl_vector,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_SV_decomp_mod(
@@ -20605,14 +20348,14 @@ extern "C" {
         V: *mut gsl_matrix,
         S: *mut gsl_vector,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_SV_decomp_jacobi(
         A: *mut gsl_matrix,
         Q: *mut gsl_matrix,
         S: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_SV_solve(
@@ -20621,20 +20364,17 @@ extern "C" {
         S: *const gsl_vector,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_SV_leverage(
-        U: *const gsl_matrix,
-        h: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_SV_leverage(U: *const gsl_matrix, h: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LU_decomp(
         A: *mut gsl_matrix,
         p: *mut gsl_permutation,
-        signum: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        signum: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LU_solve(
@@ -20642,14 +20382,14 @@ extern "C" {
         p: *const gsl_permutation,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LU_svx(
         LU: *const gsl_matrix,
         p: *const gsl_permutation,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LU_refine(
@@ -20659,23 +20399,21 @@ extern "C" {
         b: *const gsl_vector,
         x: *mut gsl_vector,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LU_invert(
         LU: *const gsl_matrix,
         p: *const gsl_permutation,
         inverse: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_LU_invx(
-        LU: *mut gsl_matrix,
-        p: *const gsl_permutation,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_LU_invx(LU: *mut gsl_matrix, p: *const gsl_permutation)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_LU_det(LU: *mut gsl_matrix, signum: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_linalg_LU_det(LU: *mut gsl_matrix, signum: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
     pub fn gsl_linalg_LU_lndet(LU: *mut gsl_matrix) -> f64;
@@ -20683,8 +20421,8 @@ extern "C" {
 extern "C" {
     pub fn gsl_linalg_LU_sgndet(
         lu: *mut gsl_matrix,
-        signum: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        signum: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -20695,7 +20433,7 @@ extern "C" {
         ub: usize,
         AB: *mut gsl_matrix,
         piv: *mut gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -20707,7 +20445,7 @@ extern "C" {
         piv: *const gsl_vector_uint,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -20718,7 +20456,7 @@ extern "C" {
         LUB: *const gsl_matrix,
         piv: *const gsl_vector_uint,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -20731,14 +20469,14 @@ extern "C" {
         piv: *const gsl_vector_uint,
         L: *mut gsl_matrix,
         U: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_LU_decomp(
         A: *mut gsl_matrix_complex,
         p: *mut gsl_permutation,
-        signum: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        signum: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_LU_solve(
@@ -20746,14 +20484,14 @@ extern "C" {
         p: *const gsl_permutation,
         b: *const gsl_vector_complex,
         x: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_LU_svx(
         LU: *const gsl_matrix_complex,
         p: *const gsl_permutation,
         x: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_LU_refine(
@@ -20763,25 +20501,25 @@ extern "C" {
         b: *const gsl_vector_complex,
         x: *mut gsl_vector_complex,
         work: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_LU_invert(
         LU: *const gsl_matrix_complex,
         p: *const gsl_permutation,
         inverse: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_LU_invx(
         LU: *mut gsl_matrix_complex,
         p: *const gsl_permutation,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_LU_det(
         LU: *mut gsl_matrix_complex,
-        signum: ::std::os::raw::c_int,
+        signum: ::core::ffi::c_int,
     ) -> gsl_complex;
 }
 extern "C" {
@@ -20790,20 +20528,18 @@ extern "C" {
 extern "C" {
     pub fn gsl_linalg_complex_LU_sgndet(
         LU: *mut gsl_matrix_complex,
-        signum: ::std::os::raw::c_int,
+        signum: ::core::ffi::c_int,
     ) -> gsl_complex;
 }
 extern "C" {
-    pub fn gsl_linalg_QR_decomp(A: *mut gsl_matrix, tau: *mut gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_QR_decomp(A: *mut gsl_matrix, tau: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_QR_decomp_old(
-        A: *mut gsl_matrix,
-        tau: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_QR_decomp_old(A: *mut gsl_matrix, tau: *mut gsl_vector)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_QR_decomp_r(A: *mut gsl_matrix, T: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_QR_decomp_r(A: *mut gsl_matrix, T: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_solve(
@@ -20811,7 +20547,7 @@ extern "C" {
         tau: *const gsl_vector,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_solve_r(
@@ -20819,14 +20555,14 @@ extern "C" {
         T: *const gsl_matrix,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_svx(
         QR: *const gsl_matrix,
         tau: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_lssolve(
@@ -20835,7 +20571,7 @@ extern "C" {
         b: *const gsl_vector,
         x: *mut gsl_vector,
         residual: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_lssolve_r(
@@ -20844,7 +20580,7 @@ extern "C" {
         b: *const gsl_vector,
         x: *mut gsl_vector,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_QRsolve(
@@ -20852,17 +20588,17 @@ extern "C" {
         R: *mut gsl_matrix,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_Rsolve(
         QR: *const gsl_matrix,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_QR_Rsvx(QR: *const gsl_matrix, x: *mut gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_QR_Rsvx(QR: *const gsl_matrix, x: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_update(
@@ -20870,14 +20606,14 @@ extern "C" {
         R: *mut gsl_matrix,
         w: *mut gsl_vector,
         v: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_QTvec(
         QR: *const gsl_matrix,
         tau: *const gsl_vector,
         v: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_QTvec_r(
@@ -20885,21 +20621,21 @@ extern "C" {
         T: *const gsl_matrix,
         b: *mut gsl_vector,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_Qvec(
         QR: *const gsl_matrix,
         tau: *const gsl_vector,
         v: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_QTmat(
         QR: *const gsl_matrix,
         tau: *const gsl_vector,
         A: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_QTmat_r(
@@ -20907,14 +20643,14 @@ extern "C" {
         T: *const gsl_matrix,
         B: *mut gsl_matrix,
         work: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_matQ(
         QR: *const gsl_matrix,
         tau: *const gsl_vector,
         A: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_unpack(
@@ -20922,7 +20658,7 @@ extern "C" {
         tau: *const gsl_vector,
         Q: *mut gsl_matrix,
         R: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_unpack_r(
@@ -20930,24 +20666,24 @@ extern "C" {
         T: *const gsl_matrix,
         Q: *mut gsl_matrix,
         R: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_R_solve(
         R: *const gsl_matrix,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_R_svx(R: *const gsl_matrix, x: *mut gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_R_svx(R: *const gsl_matrix, x: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_rcond(
         QR: *const gsl_matrix,
         rcond: *mut f64,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -20955,7 +20691,7 @@ extern "C" {
     pub fn gsl_linalg_complex_QR_decomp(
         A: *mut gsl_matrix_complex,
         tau: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -20963,7 +20699,7 @@ extern "C" {
     pub fn gsl_linalg_complex_QR_decomp_r(
         A: *mut gsl_matrix_complex,
         T: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -20973,7 +20709,7 @@ extern "C" {
         tau: *const gsl_vector_complex,
         b: *const gsl_vector_complex,
         x: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -20983,7 +20719,7 @@ extern "C" {
         T: *const gsl_matrix_complex,
         b: *const gsl_vector_complex,
         x: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -20992,7 +20728,7 @@ extern "C" {
         QR: *const gsl_matrix_complex,
         tau: *const gsl_vector_complex,
         x: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -21003,7 +20739,7 @@ extern "C" {
         b: *const gsl_vector_complex,
         x: *mut gsl_vector_complex,
         residual: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -21014,7 +20750,7 @@ extern "C" {
         b: *const gsl_vector_complex,
         x: *mut gsl_vector_complex,
         work: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -21023,7 +20759,7 @@ extern "C" {
         QR: *const gsl_matrix_complex,
         tau: *const gsl_vector_complex,
         v: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -21033,7 +20769,7 @@ extern "C" {
         T: *const gsl_matrix_complex,
         b: *mut gsl_vector_complex,
         work: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -21042,7 +20778,7 @@ extern "C" {
         QR: *const gsl_matrix_complex,
         tau: *const gsl_vector_complex,
         v: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -21052,7 +20788,7 @@ extern "C" {
         tau: *const gsl_vector_complex,
         Q: *mut gsl_matrix_complex,
         R: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -21062,7 +20798,7 @@ extern "C" {
         T: *const gsl_matrix_complex,
         Q: *mut gsl_matrix_complex,
         R: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_band_decomp_L2(
@@ -21071,7 +20807,7 @@ extern "C" {
         q: usize,
         AB: *mut gsl_matrix,
         tau: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_band_unpack_L2(
@@ -21081,16 +20817,16 @@ extern "C" {
         tau: *const gsl_vector,
         Q: *mut gsl_matrix,
         R: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QRPT_decomp(
         A: *mut gsl_matrix,
         tau: *mut gsl_vector,
         p: *mut gsl_permutation,
-        signum: *mut ::std::os::raw::c_int,
+        signum: *mut ::core::ffi::c_int,
         norm: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QRPT_decomp2(
@@ -21099,9 +20835,9 @@ extern "C" {
         r: *mut gsl_matrix,
         tau: *mut gsl_vector,
         p: *mut gsl_permutation,
-        signum: *mut ::std::os::raw::c_int,
+        signum: *mut ::core::ffi::c_int,
         norm: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QRPT_solve(
@@ -21110,7 +20846,7 @@ extern "C" {
         p: *const gsl_permutation,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21122,7 +20858,7 @@ extern "C" {
         b: *const gsl_vector,
         x: *mut gsl_vector,
         residual: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21135,7 +20871,7 @@ extern "C" {
         rank: usize,
         x: *mut gsl_vector,
         residual: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QRPT_svx(
@@ -21143,7 +20879,7 @@ extern "C" {
         tau: *const gsl_vector,
         p: *const gsl_permutation,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QRPT_QRsolve(
@@ -21152,7 +20888,7 @@ extern "C" {
         p: *const gsl_permutation,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QRPT_Rsolve(
@@ -21160,14 +20896,14 @@ extern "C" {
         p: *const gsl_permutation,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QRPT_Rsvx(
         QR: *const gsl_matrix,
         p: *const gsl_permutation,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QRPT_update(
@@ -21176,7 +20912,7 @@ extern "C" {
         p: *const gsl_permutation,
         u: *mut gsl_vector,
         v: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QRPT_rank(QR: *const gsl_matrix, tol: f64) -> usize;
@@ -21188,19 +20924,19 @@ extern "C" {
         QR: *const gsl_matrix,
         rcond: *mut f64,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_QR_TR_decomp(
         S: *mut gsl_matrix,
         A: *mut gsl_matrix,
         T: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
-    pub fn gsl_linalg_QL_decomp(A: *mut gsl_matrix, tau: *mut gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_QL_decomp(A: *mut gsl_matrix, tau: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -21210,7 +20946,7 @@ extern "C" {
         tau: *const gsl_vector,
         Q: *mut gsl_matrix,
         L: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21222,7 +20958,7 @@ extern "C" {
         p: *mut gsl_permutation,
         rank: *mut usize,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21235,7 +20971,7 @@ extern "C" {
         tol: f64,
         rank: *mut usize,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21249,7 +20985,7 @@ extern "C" {
         b: *const gsl_vector,
         x: *mut gsl_vector,
         residual: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21266,7 +21002,7 @@ extern "C" {
         residual: *mut gsl_vector,
         S: *mut gsl_matrix,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21279,7 +21015,7 @@ extern "C" {
         Q: *mut gsl_matrix,
         R: *mut gsl_matrix,
         Z: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21290,10 +21026,10 @@ extern "C" {
         rank: usize,
         A: *mut gsl_matrix,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_LQ_decomp(A: *mut gsl_matrix, tau: *mut gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_LQ_decomp(A: *mut gsl_matrix, tau: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -21304,14 +21040,14 @@ extern "C" {
         b: *const gsl_vector,
         x: *mut gsl_vector,
         residual: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LQ_QTvec(
         LQ: *const gsl_matrix,
         tau: *const gsl_vector,
         v: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LQ_solve_T(
@@ -21319,14 +21055,14 @@ extern "C" {
         tau: *const gsl_vector,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LQ_svx_T(
         LQ: *const gsl_matrix,
         tau: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LQ_lssolve_T(
@@ -21335,39 +21071,38 @@ extern "C" {
         b: *const gsl_vector,
         x: *mut gsl_vector,
         residual: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LQ_Lsolve_T(
         LQ: *const gsl_matrix,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_LQ_Lsvx_T(LQ: *const gsl_matrix, x: *mut gsl_vector)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_LQ_Lsvx_T(LQ: *const gsl_matrix, x: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_L_solve_T(
         L: *const gsl_matrix,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LQ_vecQ(
         LQ: *const gsl_matrix,
         tau: *const gsl_vector,
         v: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LQ_vecQT(
         LQ: *const gsl_matrix,
         tau: *const gsl_vector,
         v: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LQ_unpack(
@@ -21375,7 +21110,7 @@ extern "C" {
         tau: *const gsl_vector,
         Q: *mut gsl_matrix,
         L: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LQ_update(
@@ -21383,7 +21118,7 @@ extern "C" {
         R: *mut gsl_matrix,
         v: *const gsl_vector,
         w: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_LQ_LQsolve(
@@ -21391,16 +21126,16 @@ extern "C" {
         L: *mut gsl_matrix,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_PTLQ_decomp(
         A: *mut gsl_matrix,
         tau: *mut gsl_vector,
         p: *mut gsl_permutation,
-        signum: *mut ::std::os::raw::c_int,
+        signum: *mut ::core::ffi::c_int,
         norm: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_PTLQ_decomp2(
@@ -21409,9 +21144,9 @@ extern "C" {
         r: *mut gsl_matrix,
         tau: *mut gsl_vector,
         p: *mut gsl_permutation,
-        signum: *mut ::std::os::raw::c_int,
+        signum: *mut ::core::ffi::c_int,
         norm: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_PTLQ_solve_T(
@@ -21420,7 +21155,7 @@ extern "C" {
         p: *const gsl_permutation,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_PTLQ_svx_T(
@@ -21428,7 +21163,7 @@ extern "C" {
         tau: *const gsl_vector,
         p: *const gsl_permutation,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_PTLQ_LQsolve_T(
@@ -21437,7 +21172,7 @@ extern "C" {
         p: *const gsl_permutation,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_PTLQ_Lsolve_T(
@@ -21445,14 +21180,14 @@ extern "C" {
         p: *const gsl_permutation,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_PTLQ_Lsvx_T(
         LQ: *const gsl_matrix,
         p: *const gsl_permutation,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_PTLQ_update(
@@ -21461,73 +21196,73 @@ extern "C" {
         p: *const gsl_permutation,
         v: *const gsl_vector,
         w: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_cholesky_decomp(A: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_cholesky_decomp(A: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_cholesky_decomp1(A: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_cholesky_decomp1(A: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_cholesky_solve(
         cholesky: *const gsl_matrix,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_cholesky_solve_mat(
         cholesky: *const gsl_matrix,
         B: *const gsl_matrix,
         X: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_cholesky_svx(
         cholesky: *const gsl_matrix,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_cholesky_svx_mat(
         cholesky: *const gsl_matrix,
         X: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_cholesky_invert(cholesky: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_cholesky_invert(cholesky: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_cholesky_decomp_unit(
         A: *mut gsl_matrix,
         D: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_cholesky_scale(
         A: *const gsl_matrix,
         S: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_cholesky_scale_apply(
         A: *mut gsl_matrix,
         S: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_cholesky_decomp2(
         A: *mut gsl_matrix,
         S: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_cholesky_svx2(
         LLT: *const gsl_matrix,
         S: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_cholesky_solve2(
@@ -21535,7 +21270,7 @@ extern "C" {
         S: *const gsl_vector,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21544,28 +21279,28 @@ extern "C" {
         LLT: *const gsl_matrix,
         rcond: *mut f64,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_complex_cholesky_decomp(A: *mut gsl_matrix_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_complex_cholesky_decomp(A: *mut gsl_matrix_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_cholesky_solve(
         cholesky: *const gsl_matrix_complex,
         b: *const gsl_vector_complex,
         x: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_cholesky_svx(
         cholesky: *const gsl_matrix_complex,
         x: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_cholesky_invert(
         cholesky: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21573,7 +21308,7 @@ extern "C" {
     pub fn gsl_linalg_pcholesky_decomp(
         A: *mut gsl_matrix,
         p: *mut gsl_permutation,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21583,7 +21318,7 @@ extern "C" {
         p: *const gsl_permutation,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21592,7 +21327,7 @@ extern "C" {
         LDLT: *const gsl_matrix,
         p: *const gsl_permutation,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21601,7 +21336,7 @@ extern "C" {
         A: *mut gsl_matrix,
         p: *mut gsl_permutation,
         S: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21612,7 +21347,7 @@ extern "C" {
         S: *const gsl_vector,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21622,7 +21357,7 @@ extern "C" {
         p: *const gsl_permutation,
         S: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21631,7 +21366,7 @@ extern "C" {
         LDLT: *const gsl_matrix,
         p: *const gsl_permutation,
         Ainv: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21641,7 +21376,7 @@ extern "C" {
         p: *const gsl_permutation,
         rcond: *mut f64,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21650,7 +21385,7 @@ extern "C" {
         A: *mut gsl_matrix,
         p: *mut gsl_permutation,
         E: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21660,7 +21395,7 @@ extern "C" {
         p: *const gsl_permutation,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21669,7 +21404,7 @@ extern "C" {
         LDLT: *const gsl_matrix,
         p: *const gsl_permutation,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21679,7 +21414,7 @@ extern "C" {
         p: *const gsl_permutation,
         rcond: *mut f64,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21688,12 +21423,12 @@ extern "C" {
         LDLT: *const gsl_matrix,
         p: *const gsl_permutation,
         Ainv: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
-    pub fn gsl_linalg_cholesky_band_decomp(A: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_cholesky_band_decomp(A: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -21702,7 +21437,7 @@ extern "C" {
         LLT: *const gsl_matrix,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -21710,7 +21445,7 @@ extern "C" {
     pub fn gsl_linalg_cholesky_band_svx(
         LLT: *const gsl_matrix,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -21719,7 +21454,7 @@ extern "C" {
         LLT: *const gsl_matrix,
         B: *const gsl_matrix,
         X: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -21727,7 +21462,7 @@ extern "C" {
     pub fn gsl_linalg_cholesky_band_svxm(
         LLT: *const gsl_matrix,
         X: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -21735,7 +21470,7 @@ extern "C" {
     pub fn gsl_linalg_cholesky_band_invert(
         LLT: *const gsl_matrix,
         Ainv: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -21743,7 +21478,7 @@ extern "C" {
     pub fn gsl_linalg_cholesky_band_unpack(
         LLT: *const gsl_matrix,
         L: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -21752,12 +21487,12 @@ extern "C" {
         LLT: *const gsl_matrix,
         rcond: *mut f64,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
-    pub fn gsl_linalg_ldlt_decomp(A: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_ldlt_decomp(A: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -21766,15 +21501,12 @@ extern "C" {
         LDLT: *const gsl_matrix,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
-    pub fn gsl_linalg_ldlt_svx(
-        LDLT: *const gsl_matrix,
-        x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_ldlt_svx(LDLT: *const gsl_matrix, x: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -21783,12 +21515,12 @@ extern "C" {
         LDLT: *const gsl_matrix,
         rcond: *mut f64,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
-    pub fn gsl_linalg_ldlt_band_decomp(A: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_ldlt_band_decomp(A: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -21797,7 +21529,7 @@ extern "C" {
         LDLT: *const gsl_matrix,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -21805,7 +21537,7 @@ extern "C" {
     pub fn gsl_linalg_ldlt_band_svx(
         LDLT: *const gsl_matrix,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -21814,7 +21546,7 @@ extern "C" {
         LDLT: *const gsl_matrix,
         L: *mut gsl_matrix,
         D: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_6")]
@@ -21823,13 +21555,11 @@ extern "C" {
         LDLT: *const gsl_matrix,
         rcond: *mut f64,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_symmtd_decomp(
-        A: *mut gsl_matrix,
-        tau: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_symmtd_decomp(A: *mut gsl_matrix, tau: *mut gsl_vector)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_symmtd_unpack(
@@ -21838,20 +21568,20 @@ extern "C" {
         Q: *mut gsl_matrix,
         diag: *mut gsl_vector,
         subdiag: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_symmtd_unpack_T(
         A: *const gsl_matrix,
         diag: *mut gsl_vector,
         subdiag: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_hermtd_decomp(
         A: *mut gsl_matrix_complex,
         tau: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_hermtd_unpack(
@@ -21860,24 +21590,24 @@ extern "C" {
         U: *mut gsl_matrix_complex,
         diag: *mut gsl_vector,
         sudiag: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_hermtd_unpack_T(
         A: *const gsl_matrix_complex,
         diag: *mut gsl_vector,
         subdiag: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_HH_solve(
         A: *mut gsl_matrix,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_HH_svx(A: *mut gsl_matrix, x: *mut gsl_vector) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_HH_svx(A: *mut gsl_matrix, x: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_solve_symm_tridiag(
@@ -21885,7 +21615,7 @@ extern "C" {
         offdiag: *const gsl_vector,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_solve_tridiag(
@@ -21894,7 +21624,7 @@ extern "C" {
         belowdiag: *const gsl_vector,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_solve_symm_cyc_tridiag(
@@ -21902,7 +21632,7 @@ extern "C" {
         offdiag: *const gsl_vector,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_solve_cyc_tridiag(
@@ -21911,14 +21641,14 @@ extern "C" {
         belowdiag: *const gsl_vector,
         b: *const gsl_vector,
         x: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_bidiag_decomp(
         A: *mut gsl_matrix,
         tau_U: *mut gsl_vector,
         tau_V: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_bidiag_unpack(
@@ -21929,7 +21659,7 @@ extern "C" {
         V: *mut gsl_matrix,
         diag: *mut gsl_vector,
         superdiag: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_bidiag_unpack2(
@@ -21937,42 +21667,34 @@ extern "C" {
         tau_U: *mut gsl_vector,
         tau_V: *mut gsl_vector,
         V: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_bidiag_unpack_B(
         A: *const gsl_matrix,
         diag: *mut gsl_vector,
         superdiag: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_balance_matrix(
-        A: *mut gsl_matrix,
-        D: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_balance_matrix(A: *mut gsl_matrix, D: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_balance_accum(
-        A: *mut gsl_matrix,
-        D: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_balance_accum(A: *mut gsl_matrix, D: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_balance_columns(
-        A: *mut gsl_matrix,
-        D: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_balance_columns(A: *mut gsl_matrix, D: *mut gsl_vector)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
-    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_tri_rcond(
         Uplo: CBLAS_UPLO_t,
         A: *const gsl_matrix,
         rcond: *mut f64,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21981,7 +21703,7 @@ extern "C" {
         A: *const gsl_matrix,
         rcond: *mut f64,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -21990,74 +21712,74 @@ extern "C" {
         A: *const gsl_matrix,
         rcond: *mut f64,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_invnorm1(
         N: usize,
-        Ainvx: ::std::option::Option<
+        Ainvx: ::core::option::Option<
             unsafe extern "C" fn(
                 TransA: CBLAS_TRANSPOSE_t,
                 x: *mut gsl_vector,
-                params: *mut ::std::os::raw::c_void,
-            ) -> ::std::os::raw::c_int,
+                params: *mut ::core::ffi::c_void,
+            ) -> ::core::ffi::c_int,
         >,
-        params: *mut ::std::os::raw::c_void,
+        params: *mut ::core::ffi::c_void,
         Ainvnorm: *mut f64,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
-    pub fn gsl_linalg_tri_upper_invert(T: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_tri_upper_invert(T: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
-    pub fn gsl_linalg_tri_lower_invert(T: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_tri_lower_invert(T: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
-    pub fn gsl_linalg_tri_upper_unit_invert(T: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_tri_upper_unit_invert(T: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
-    pub fn gsl_linalg_tri_lower_unit_invert(T: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_tri_lower_unit_invert(T: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    #[cfg(feature = "v2_2")]
-    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_linalg_tri_invert(
         Uplo: CBLAS_UPLO_t,
         Diag: CBLAS_DIAG_t,
         T: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_complex_tri_invert(
         Uplo: CBLAS_UPLO_t,
         Diag: CBLAS_DIAG_t,
         T: *mut gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
-    pub fn gsl_linalg_tri_LTL(L: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_tri_LTL(L: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
-    pub fn gsl_linalg_tri_UL(LU: *mut gsl_matrix) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_tri_UL(LU: *mut gsl_matrix) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_complex_tri_LHL(L: *mut gsl_matrix_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_complex_tri_LHL(L: *mut gsl_matrix_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_linalg_complex_tri_UL(LU: *mut gsl_matrix_complex) -> ::std::os::raw::c_int;
+    pub fn gsl_linalg_complex_tri_UL(LU: *mut gsl_matrix_complex) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_linalg_givens(a: f64, b: f64, c: *mut f64, s: *mut f64);
@@ -22087,7 +21809,7 @@ extern "C" {
 extern "C" {
     pub fn gsl_splinalg_itersolve_name(
         w: *const gsl_splinalg_itersolve,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_splinalg_itersolve_iterate(
@@ -22096,354 +21818,346 @@ extern "C" {
         tol: f64,
         x: *mut gsl_vector,
         w: *mut gsl_splinalg_itersolve,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_splinalg_itersolve_normr(w: *const gsl_splinalg_itersolve) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_J0_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_J0_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_J0(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_J1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_J1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_J1(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Jn_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_Jn(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_bessel_Jn(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Jn_array(
-        nmin: ::std::os::raw::c_int,
-        nmax: ::std::os::raw::c_int,
+        nmin: ::core::ffi::c_int,
+        nmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_Y0_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_Y0_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Y0(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_Y1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_Y1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Y1(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Yn_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_Yn(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_bessel_Yn(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Yn_array(
-        nmin: ::std::os::raw::c_int,
-        nmax: ::std::os::raw::c_int,
+        nmin: ::core::ffi::c_int,
+        nmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_I0_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_I0_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_I0(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_I1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_I1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_I1(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_In_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_In(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_bessel_In(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_In_array(
-        nmin: ::std::os::raw::c_int,
-        nmax: ::std::os::raw::c_int,
+        nmin: ::core::ffi::c_int,
+        nmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_I0_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_I0_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_I0_scaled(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_I1_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_I1_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_I1_scaled(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_In_scaled_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_In_scaled(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_bessel_In_scaled(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_In_scaled_array(
-        nmin: ::std::os::raw::c_int,
-        nmax: ::std::os::raw::c_int,
+        nmin: ::core::ffi::c_int,
+        nmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_K0_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_K0_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_K0(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_K1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_K1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_K1(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Kn_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_Kn(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_bessel_Kn(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Kn_array(
-        nmin: ::std::os::raw::c_int,
-        nmax: ::std::os::raw::c_int,
+        nmin: ::core::ffi::c_int,
+        nmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_K0_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_K0_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_K0_scaled(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_K1_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_K1_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_K1_scaled(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Kn_scaled_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_Kn_scaled(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_bessel_Kn_scaled(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Kn_scaled_array(
-        nmin: ::std::os::raw::c_int,
-        nmax: ::std::os::raw::c_int,
+        nmin: ::core::ffi::c_int,
+        nmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_j0_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_j0_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_j0(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_j1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_j1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_j1(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_j2_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_j2_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_j2(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_jl_e(
-        l: ::std::os::raw::c_int,
+        l: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_jl(l: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_bessel_jl(l: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_jl_array(
-        lmax: ::std::os::raw::c_int,
+        lmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_jl_steed_array(
-        lmax: ::std::os::raw::c_int,
+        lmax: ::core::ffi::c_int,
         x: f64,
         jl_x_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_y0_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_y0_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_y0(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_y1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_y1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_y1(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_y2_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_y2_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_y2(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_yl_e(
-        l: ::std::os::raw::c_int,
+        l: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_yl(l: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_bessel_yl(l: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_yl_array(
-        lmax: ::std::os::raw::c_int,
+        lmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_i0_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_i0_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_i0_scaled(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_i1_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_i1_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_i1_scaled(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_i2_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_i2_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_i2_scaled(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_il_scaled_e(
-        l: ::std::os::raw::c_int,
+        l: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_il_scaled(l: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_bessel_il_scaled(l: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_il_scaled_array(
-        lmax: ::std::os::raw::c_int,
+        lmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_k0_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_k0_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_k0_scaled(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_k1_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_k1_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_k1_scaled(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_k2_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_k2_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_k2_scaled(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_kl_scaled_e(
-        l: ::std::os::raw::c_int,
+        l: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_kl_scaled(l: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_bessel_kl_scaled(l: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_kl_scaled_array(
-        lmax: ::std::os::raw::c_int,
+        lmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_Jnu_e(
-        nu: f64,
-        x: f64,
-        result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_Jnu_e(nu: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Jnu(nu: f64, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_Ynu_e(
-        nu: f64,
-        x: f64,
-        result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_Ynu_e(nu: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Ynu(nu: f64, x: f64) -> f64;
@@ -22454,24 +22168,20 @@ extern "C" {
         mode: gsl_mode_t,
         size: usize,
         v: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Inu_scaled_e(
         nu: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Inu_scaled(nu: f64, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_Inu_e(
-        nu: f64,
-        x: f64,
-        result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_Inu_e(nu: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Inu(nu: f64, x: f64) -> f64;
@@ -22481,7 +22191,7 @@ extern "C" {
         nu: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Knu_scaled(nu: f64, x: f64) -> f64;
@@ -22491,58 +22201,51 @@ extern "C" {
         nu: f64,
         x: f64,
         result: *mut gsl_sf_result_e10,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_Knu_e(
-        nu: f64,
-        x: f64,
-        result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_Knu_e(nu: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_Knu(nu: f64, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_lnKnu_e(
-        nu: f64,
-        x: f64,
-        result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_bessel_lnKnu_e(nu: f64, x: f64, result: *mut gsl_sf_result)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_bessel_lnKnu(nu: f64, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_zero_J0_e(
-        s: ::std::os::raw::c_uint,
+        s: ::core::ffi::c_uint,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_zero_J0(s: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_sf_bessel_zero_J0(s: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_zero_J1_e(
-        s: ::std::os::raw::c_uint,
+        s: ::core::ffi::c_uint,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_zero_J1(s: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_sf_bessel_zero_J1(s: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_bessel_zero_Jnu_e(
         nu: f64,
-        s: ::std::os::raw::c_uint,
+        s: ::core::ffi::c_uint,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_bessel_zero_Jnu(nu: f64, s: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_sf_bessel_zero_Jnu(nu: f64, s: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_lngamma_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_lngamma_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_lngamma(x: f64) -> f64;
@@ -22552,22 +22255,22 @@ extern "C" {
         x: f64,
         result_lg: *mut gsl_sf_result,
         sgn: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_gamma_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_gamma_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_gamma(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_gammastar_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_gammastar_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_gammastar(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_gammainv_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_gammainv_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_gammainv(x: f64) -> f64;
@@ -22578,76 +22281,73 @@ extern "C" {
         zi: f64,
         lnr: *mut gsl_sf_result,
         arg: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_taylorcoeff_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_taylorcoeff(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_taylorcoeff(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_fact_e(
-        n: ::std::os::raw::c_uint,
-        result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_fact_e(n: ::core::ffi::c_uint, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_fact(n: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_sf_fact(n: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_doublefact_e(
-        n: ::std::os::raw::c_uint,
+        n: ::core::ffi::c_uint,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_doublefact(n: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_sf_doublefact(n: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_lnfact_e(
-        n: ::std::os::raw::c_uint,
+        n: ::core::ffi::c_uint,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_lnfact(n: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_sf_lnfact(n: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_lndoublefact_e(
-        n: ::std::os::raw::c_uint,
+        n: ::core::ffi::c_uint,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_lndoublefact(n: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_sf_lndoublefact(n: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_lnchoose_e(
-        n: ::std::os::raw::c_uint,
-        m: ::std::os::raw::c_uint,
+        n: ::core::ffi::c_uint,
+        m: ::core::ffi::c_uint,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_lnchoose(n: ::std::os::raw::c_uint, m: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_sf_lnchoose(n: ::core::ffi::c_uint, m: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_choose_e(
-        n: ::std::os::raw::c_uint,
-        m: ::std::os::raw::c_uint,
+        n: ::core::ffi::c_uint,
+        m: ::core::ffi::c_uint,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_choose(n: ::std::os::raw::c_uint, m: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_sf_choose(n: ::core::ffi::c_uint, m: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_lnpoch_e(a: f64, x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_lnpoch_e(a: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_lnpoch(a: f64, x: f64) -> f64;
@@ -22658,48 +22358,40 @@ extern "C" {
         x: f64,
         result: *mut gsl_sf_result,
         sgn: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_poch_e(a: f64, x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_poch_e(a: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_poch(a: f64, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_pochrel_e(a: f64, x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_pochrel_e(a: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_pochrel(a: f64, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_gamma_inc_Q_e(
-        a: f64,
-        x: f64,
-        result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_gamma_inc_Q_e(a: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_gamma_inc_Q(a: f64, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_gamma_inc_P_e(
-        a: f64,
-        x: f64,
-        result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_gamma_inc_P_e(a: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_gamma_inc_P(a: f64, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_gamma_inc_e(a: f64, x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_gamma_inc_e(a: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_gamma_inc(a: f64, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_lnbeta_e(a: f64, b: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_lnbeta_e(a: f64, b: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_lnbeta(a: f64, b: f64) -> f64;
@@ -22710,10 +22402,10 @@ extern "C" {
         y: f64,
         result: *mut gsl_sf_result,
         sgn: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_beta_e(a: f64, b: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_beta_e(a: f64, b: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_beta(a: f64, b: f64) -> f64;
@@ -22724,7 +22416,7 @@ extern "C" {
         b: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_beta_inc(a: f64, b: f64, x: f64) -> f64;
@@ -22732,35 +22424,31 @@ extern "C" {
 extern "C" {
     pub fn gsl_permute_ushort(
         p: *const usize,
-        data: *mut ::std::os::raw::c_ushort,
+        data: *mut ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_ushort_inverse(
         p: *const usize,
-        data: *mut ::std::os::raw::c_ushort,
+        data: *mut ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_stats_int_mean(data: *const ::std::os::raw::c_int, stride: usize, n: usize) -> f64;
+    pub fn gsl_stats_int_mean(data: *const ::core::ffi::c_int, stride: usize, n: usize) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_int_variance(
-        data: *const ::std::os::raw::c_int,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_int_variance(data: *const ::core::ffi::c_int, stride: usize, n: usize) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_int_sd(data: *const ::std::os::raw::c_int, stride: usize, n: usize) -> f64;
+    pub fn gsl_stats_int_sd(data: *const ::core::ffi::c_int, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_int_variance_with_fixed_mean(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
         mean: f64,
@@ -22768,67 +22456,62 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_int_sd_with_fixed_mean(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
         mean: f64,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_int_tss(data: *const ::std::os::raw::c_int, stride: usize, n: usize) -> f64;
+    pub fn gsl_stats_int_tss(data: *const ::core::ffi::c_int, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_int_tss_m(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
         mean: f64,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_int_absdev(data: *const ::std::os::raw::c_int, stride: usize, n: usize)
-        -> f64;
+    pub fn gsl_stats_int_absdev(data: *const ::core::ffi::c_int, stride: usize, n: usize) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_int_skew(data: *const ::std::os::raw::c_int, stride: usize, n: usize) -> f64;
+    pub fn gsl_stats_int_skew(data: *const ::core::ffi::c_int, stride: usize, n: usize) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_int_kurtosis(
-        data: *const ::std::os::raw::c_int,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_int_kurtosis(data: *const ::core::ffi::c_int, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_int_lag1_autocorrelation(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_int_covariance(
-        data1: *const ::std::os::raw::c_int,
+        data1: *const ::core::ffi::c_int,
         stride1: usize,
-        data2: *const ::std::os::raw::c_int,
+        data2: *const ::core::ffi::c_int,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_int_correlation(
-        data1: *const ::std::os::raw::c_int,
+        data1: *const ::core::ffi::c_int,
         stride1: usize,
-        data2: *const ::std::os::raw::c_int,
+        data2: *const ::core::ffi::c_int,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_int_spearman(
-        data1: *const ::std::os::raw::c_int,
+        data1: *const ::core::ffi::c_int,
         stride1: usize,
-        data2: *const ::std::os::raw::c_int,
+        data2: *const ::core::ffi::c_int,
         stride2: usize,
         n: usize,
         work: *mut f64,
@@ -22836,7 +22519,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_int_variance_m(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
         mean: f64,
@@ -22844,7 +22527,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_int_sd_m(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
         mean: f64,
@@ -22852,7 +22535,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_int_absdev_m(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
         mean: f64,
@@ -22860,7 +22543,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_int_skew_m_sd(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
         mean: f64,
@@ -22869,7 +22552,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_int_kurtosis_m_sd(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
         mean: f64,
@@ -22878,7 +22561,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_int_lag1_autocorrelation_m(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
         mean: f64,
@@ -22886,9 +22569,9 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_int_covariance_m(
-        data1: *const ::std::os::raw::c_int,
+        data1: *const ::core::ffi::c_int,
         stride1: usize,
-        data2: *const ::std::os::raw::c_int,
+        data2: *const ::core::ffi::c_int,
         stride2: usize,
         n: usize,
         mean1: f64,
@@ -22897,57 +22580,57 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_int_pvariance(
-        data1: *const ::std::os::raw::c_int,
+        data1: *const ::core::ffi::c_int,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_int,
+        data2: *const ::core::ffi::c_int,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_int_ttest(
-        data1: *const ::std::os::raw::c_int,
+        data1: *const ::core::ffi::c_int,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_int,
+        data2: *const ::core::ffi::c_int,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_int_max(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_stats_int_min(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_stats_int_minmax(
-        min: *mut ::std::os::raw::c_int,
-        max: *mut ::std::os::raw::c_int,
-        data: *const ::std::os::raw::c_int,
+        min: *mut ::core::ffi::c_int,
+        max: *mut ::core::ffi::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_int_max_index(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
     ) -> usize;
 }
 extern "C" {
     pub fn gsl_stats_int_min_index(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
     ) -> usize;
@@ -22956,36 +22639,36 @@ extern "C" {
     pub fn gsl_stats_int_minmax_index(
         min_index: *mut usize,
         max_index: *mut usize,
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_int_select(
-        data: *mut ::std::os::raw::c_int,
+        data: *mut ::core::ffi::c_int,
         stride: usize,
         n: usize,
         k: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_stats_int_median_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_int,
+        sorted_data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_int_median(
-        sorted_data: *mut ::std::os::raw::c_int,
+        sorted_data: *mut ::core::ffi::c_int,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_int_quantile_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_int,
+        sorted_data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
         f: f64,
@@ -22994,21 +22677,21 @@ extern "C" {
 extern "C" {
     pub fn gsl_stats_int_trmean_from_sorted_data(
         trim: f64,
-        sorted_data: *const ::std::os::raw::c_int,
+        sorted_data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_int_gastwirth_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_int,
+        sorted_data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_int_mad0(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -23016,7 +22699,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_int_mad(
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -23024,46 +22707,46 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_int_Sn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_int,
+        sorted_data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        work: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_stats_int_Sn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_int,
+        sorted_data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_int,
+        work: *mut ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_int_Qn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_int,
+        sorted_data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_int,
-        work_int: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        work: *mut ::core::ffi::c_int,
+        work_int: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_stats_int_Qn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_int,
+        sorted_data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_int,
-        work_int: *mut ::std::os::raw::c_int,
+        work: *mut ::core::ffi::c_int,
+        work_int: *mut ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_sort_int(data: *mut ::std::os::raw::c_int, stride: usize, n: usize);
+    pub fn gsl_sort_int(data: *mut ::core::ffi::c_int, stride: usize, n: usize);
 }
 extern "C" {
     pub fn gsl_sort2_int(
-        data1: *mut ::std::os::raw::c_int,
+        data1: *mut ::core::ffi::c_int,
         stride1: usize,
-        data2: *mut ::std::os::raw::c_int,
+        data2: *mut ::core::ffi::c_int,
         stride2: usize,
         n: usize,
     );
@@ -23071,148 +22754,148 @@ extern "C" {
 extern "C" {
     pub fn gsl_sort_int_index(
         p: *mut usize,
-        data: *const ::std::os::raw::c_int,
+        data: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_sort_int_smallest(
-        dest: *mut ::std::os::raw::c_int,
+        dest: *mut ::core::ffi::c_int,
         k: usize,
-        src: *const ::std::os::raw::c_int,
+        src: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_int_smallest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_int,
+        src: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_int_largest(
-        dest: *mut ::std::os::raw::c_int,
+        dest: *mut ::core::ffi::c_int,
         k: usize,
-        src: *const ::std::os::raw::c_int,
+        src: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_int_largest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_int,
+        src: *const ::core::ffi::c_int,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_expint_E1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_expint_E1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_expint_E1(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_expint_E2_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_expint_E2_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_expint_E2(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_expint_En_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_expint_En(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_expint_En(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_expint_E1_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_expint_E1_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_expint_E1_scaled(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_expint_E2_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_expint_E2_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_expint_E2_scaled(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_expint_En_scaled_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_expint_En_scaled(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_expint_En_scaled(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_expint_Ei_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_expint_Ei_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_expint_Ei(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_expint_Ei_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_expint_Ei_scaled_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_expint_Ei_scaled(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_Shi_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_Shi_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_Shi(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_Chi_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_Chi_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_Chi(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_expint_3_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_expint_3_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_expint_3(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_Si_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_Si_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_Si(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_Ci_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_Ci_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_Ci(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_atanint_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_atanint_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_atanint(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_clausen_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_clausen_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_clausen(x: f64) -> f64;
 }
 pub const gsl_wavelet_direction_gsl_wavelet_forward: gsl_wavelet_direction = 1;
 pub const gsl_wavelet_direction_gsl_wavelet_backward: gsl_wavelet_direction = -1;
-pub type gsl_wavelet_direction = ::std::os::raw::c_int;
+pub type gsl_wavelet_direction = ::core::ffi::c_int;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_wavelet_type;
@@ -23247,7 +22930,7 @@ extern "C" {
     pub fn gsl_wavelet_free(w: *mut gsl_wavelet);
 }
 extern "C" {
-    pub fn gsl_wavelet_name(w: *const gsl_wavelet) -> *const ::std::os::raw::c_char;
+    pub fn gsl_wavelet_name(w: *const gsl_wavelet) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_wavelet_workspace_alloc(n: usize) -> *mut gsl_wavelet_workspace;
@@ -23263,7 +22946,7 @@ extern "C" {
         n: usize,
         dir: gsl_wavelet_direction,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_wavelet_transform_forward(
@@ -23272,7 +22955,7 @@ extern "C" {
         stride: usize,
         n: usize,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_wavelet_transform_inverse(
@@ -23281,7 +22964,7 @@ extern "C" {
         stride: usize,
         n: usize,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_wavelet2d_transform(
@@ -23292,7 +22975,7 @@ extern "C" {
         size2: usize,
         dir: gsl_wavelet_direction,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_wavelet2d_transform_forward(
@@ -23302,7 +22985,7 @@ extern "C" {
         size1: usize,
         size2: usize,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_wavelet2d_transform_inverse(
@@ -23312,7 +22995,7 @@ extern "C" {
         size1: usize,
         size2: usize,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_wavelet2d_nstransform(
@@ -23323,7 +23006,7 @@ extern "C" {
         size2: usize,
         dir: gsl_wavelet_direction,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_wavelet2d_nstransform_forward(
@@ -23333,7 +23016,7 @@ extern "C" {
         size1: usize,
         size2: usize,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_wavelet2d_nstransform_inverse(
@@ -23343,7 +23026,7 @@ extern "C" {
         size1: usize,
         size2: usize,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_wavelet2d_transform_matrix(
@@ -23351,21 +23034,21 @@ extern "C" {
         a: *mut gsl_matrix,
         dir: gsl_wavelet_direction,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_wavelet2d_transform_matrix_forward(
         w: *const gsl_wavelet,
         a: *mut gsl_matrix,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_wavelet2d_transform_matrix_inverse(
         w: *const gsl_wavelet,
         a: *mut gsl_matrix,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_wavelet2d_nstransform_matrix(
@@ -23373,21 +23056,21 @@ extern "C" {
         a: *mut gsl_matrix,
         dir: gsl_wavelet_direction,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_wavelet2d_nstransform_matrix_forward(
         w: *const gsl_wavelet,
         a: *mut gsl_matrix,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_wavelet2d_nstransform_matrix_inverse(
         w: *const gsl_wavelet,
         a: *mut gsl_matrix,
         work: *mut gsl_wavelet_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -23412,21 +23095,18 @@ extern "C" {
     pub fn gsl_histogram_free(h: *mut gsl_histogram);
 }
 extern "C" {
-    pub fn gsl_histogram_increment(h: *mut gsl_histogram, x: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_histogram_increment(h: *mut gsl_histogram, x: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram_accumulate(
         h: *mut gsl_histogram,
         x: f64,
         weight: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_histogram_find(
-        h: *const gsl_histogram,
-        x: f64,
-        i: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_histogram_find(h: *const gsl_histogram, x: f64, i: *mut usize)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram_get(h: *const gsl_histogram, i: usize) -> f64;
@@ -23437,7 +23117,7 @@ extern "C" {
         i: usize,
         lower: *mut f64,
         upper: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram_max(h: *const gsl_histogram) -> f64;
@@ -23459,20 +23139,20 @@ extern "C" {
         h: *mut gsl_histogram,
         range: *const f64,
         size: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram_set_ranges_uniform(
         h: *mut gsl_histogram,
         xmin: f64,
         xmax: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram_memcpy(
         dest: *mut gsl_histogram,
         source: *const gsl_histogram,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram_clone(source: *const gsl_histogram) -> *mut gsl_histogram;
@@ -23493,37 +23173,37 @@ extern "C" {
     pub fn gsl_histogram_equal_bins_p(
         h1: *const gsl_histogram,
         h2: *const gsl_histogram,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram_add(
         h1: *mut gsl_histogram,
         h2: *const gsl_histogram,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram_sub(
         h1: *mut gsl_histogram,
         h2: *const gsl_histogram,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram_mul(
         h1: *mut gsl_histogram,
         h2: *const gsl_histogram,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram_div(
         h1: *mut gsl_histogram,
         h2: *const gsl_histogram,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_histogram_scale(h: *mut gsl_histogram, scale: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_histogram_scale(h: *mut gsl_histogram, scale: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_histogram_shift(h: *mut gsl_histogram, shift: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_histogram_shift(h: *mut gsl_histogram, shift: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram_sigma(h: *const gsl_histogram) -> f64;
@@ -23535,24 +23215,21 @@ extern "C" {
     pub fn gsl_histogram_sum(h: *const gsl_histogram) -> f64;
 }
 extern "C" {
-    pub fn gsl_histogram_fwrite(
-        stream: *mut FILE,
-        h: *const gsl_histogram,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_histogram_fwrite(stream: *mut FILE, h: *const gsl_histogram) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_histogram_fread(stream: *mut FILE, h: *mut gsl_histogram) -> ::std::os::raw::c_int;
+    pub fn gsl_histogram_fread(stream: *mut FILE, h: *mut gsl_histogram) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram_fprintf(
         stream: *mut FILE,
         h: *const gsl_histogram,
-        range_format: *const ::std::os::raw::c_char,
-        bin_format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        range_format: *const ::core::ffi::c_char,
+        bin_format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_histogram_fscanf(stream: *mut FILE, h: *mut gsl_histogram) -> ::std::os::raw::c_int;
+    pub fn gsl_histogram_fscanf(stream: *mut FILE, h: *mut gsl_histogram) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram_pdf_alloc(n: usize) -> *mut gsl_histogram_pdf;
@@ -23561,7 +23238,7 @@ extern "C" {
     pub fn gsl_histogram_pdf_init(
         p: *mut gsl_histogram_pdf,
         h: *const gsl_histogram,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram_pdf_free(p: *mut gsl_histogram_pdf);
@@ -23573,53 +23250,53 @@ extern "C" {
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_ntuple {
     pub file: *mut FILE,
-    pub ntuple_data: *mut ::std::os::raw::c_void,
+    pub ntuple_data: *mut ::core::ffi::c_void,
     pub size: usize,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_ntuple_select_fn {
-    pub function: ::std::option::Option<
+    pub function: ::core::option::Option<
         unsafe extern "C" fn(
-            ntuple_data: *mut ::std::os::raw::c_void,
-            params: *mut ::std::os::raw::c_void,
-        ) -> ::std::os::raw::c_int,
+            ntuple_data: *mut ::core::ffi::c_void,
+            params: *mut ::core::ffi::c_void,
+        ) -> ::core::ffi::c_int,
     >,
-    pub params: *mut ::std::os::raw::c_void,
+    pub params: *mut ::core::ffi::c_void,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_ntuple_value_fn {
-    pub function: ::std::option::Option<
+    pub function: ::core::option::Option<
         unsafe extern "C" fn(
-            ntuple_data: *mut ::std::os::raw::c_void,
-            params: *mut ::std::os::raw::c_void,
+            ntuple_data: *mut ::core::ffi::c_void,
+            params: *mut ::core::ffi::c_void,
         ) -> f64,
     >,
-    pub params: *mut ::std::os::raw::c_void,
+    pub params: *mut ::core::ffi::c_void,
 }
 extern "C" {
     pub fn gsl_ntuple_open(
-        filename: *mut ::std::os::raw::c_char,
-        ntuple_data: *mut ::std::os::raw::c_void,
+        filename: *mut ::core::ffi::c_char,
+        ntuple_data: *mut ::core::ffi::c_void,
         size: usize,
     ) -> *mut gsl_ntuple;
 }
 extern "C" {
     pub fn gsl_ntuple_create(
-        filename: *mut ::std::os::raw::c_char,
-        ntuple_data: *mut ::std::os::raw::c_void,
+        filename: *mut ::core::ffi::c_char,
+        ntuple_data: *mut ::core::ffi::c_void,
         size: usize,
     ) -> *mut gsl_ntuple;
 }
 extern "C" {
-    pub fn gsl_ntuple_write(ntuple: *mut gsl_ntuple) -> ::std::os::raw::c_int;
+    pub fn gsl_ntuple_write(ntuple: *mut gsl_ntuple) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_ntuple_read(ntuple: *mut gsl_ntuple) -> ::std::os::raw::c_int;
+    pub fn gsl_ntuple_read(ntuple: *mut gsl_ntuple) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_ntuple_bookdata(ntuple: *mut gsl_ntuple) -> ::std::os::raw::c_int;
+    pub fn gsl_ntuple_bookdata(ntuple: *mut gsl_ntuple) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_ntuple_project(
@@ -23627,28 +23304,27 @@ extern "C" {
         ntuple: *mut gsl_ntuple,
         value_func: *mut gsl_ntuple_value_fn,
         select_func: *mut gsl_ntuple_select_fn,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_ntuple_close(ntuple: *mut gsl_ntuple) -> ::std::os::raw::c_int;
+    pub fn gsl_ntuple_close(ntuple: *mut gsl_ntuple) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_stats_long_mean(data: *const ::std::os::raw::c_long, stride: usize, n: usize)
-        -> f64;
+    pub fn gsl_stats_long_mean(data: *const ::core::ffi::c_long, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_variance(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_long_sd(data: *const ::std::os::raw::c_long, stride: usize, n: usize) -> f64;
+    pub fn gsl_stats_long_sd(data: *const ::core::ffi::c_long, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_variance_with_fixed_mean(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
         mean: f64,
@@ -23656,71 +23332,66 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_long_sd_with_fixed_mean(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
         mean: f64,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_long_tss(data: *const ::std::os::raw::c_long, stride: usize, n: usize) -> f64;
+    pub fn gsl_stats_long_tss(data: *const ::core::ffi::c_long, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_tss_m(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
         mean: f64,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_long_absdev(
-        data: *const ::std::os::raw::c_long,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_long_absdev(data: *const ::core::ffi::c_long, stride: usize, n: usize) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_long_skew(data: *const ::std::os::raw::c_long, stride: usize, n: usize)
-        -> f64;
+    pub fn gsl_stats_long_skew(data: *const ::core::ffi::c_long, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_kurtosis(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_lag1_autocorrelation(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_covariance(
-        data1: *const ::std::os::raw::c_long,
+        data1: *const ::core::ffi::c_long,
         stride1: usize,
-        data2: *const ::std::os::raw::c_long,
+        data2: *const ::core::ffi::c_long,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_correlation(
-        data1: *const ::std::os::raw::c_long,
+        data1: *const ::core::ffi::c_long,
         stride1: usize,
-        data2: *const ::std::os::raw::c_long,
+        data2: *const ::core::ffi::c_long,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_spearman(
-        data1: *const ::std::os::raw::c_long,
+        data1: *const ::core::ffi::c_long,
         stride1: usize,
-        data2: *const ::std::os::raw::c_long,
+        data2: *const ::core::ffi::c_long,
         stride2: usize,
         n: usize,
         work: *mut f64,
@@ -23728,7 +23399,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_long_variance_m(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
         mean: f64,
@@ -23736,7 +23407,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_long_sd_m(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
         mean: f64,
@@ -23744,7 +23415,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_long_absdev_m(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
         mean: f64,
@@ -23752,7 +23423,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_long_skew_m_sd(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
         mean: f64,
@@ -23761,7 +23432,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_long_kurtosis_m_sd(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
         mean: f64,
@@ -23770,7 +23441,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_long_lag1_autocorrelation_m(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
         mean: f64,
@@ -23778,9 +23449,9 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_long_covariance_m(
-        data1: *const ::std::os::raw::c_long,
+        data1: *const ::core::ffi::c_long,
         stride1: usize,
-        data2: *const ::std::os::raw::c_long,
+        data2: *const ::core::ffi::c_long,
         stride2: usize,
         n: usize,
         mean1: f64,
@@ -23789,57 +23460,57 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_long_pvariance(
-        data1: *const ::std::os::raw::c_long,
+        data1: *const ::core::ffi::c_long,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_long,
+        data2: *const ::core::ffi::c_long,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_ttest(
-        data1: *const ::std::os::raw::c_long,
+        data1: *const ::core::ffi::c_long,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_long,
+        data2: *const ::core::ffi::c_long,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_max(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_long;
+    ) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn gsl_stats_long_min(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_long;
+    ) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn gsl_stats_long_minmax(
-        min: *mut ::std::os::raw::c_long,
-        max: *mut ::std::os::raw::c_long,
-        data: *const ::std::os::raw::c_long,
+        min: *mut ::core::ffi::c_long,
+        max: *mut ::core::ffi::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_long_max_index(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
     ) -> usize;
 }
 extern "C" {
     pub fn gsl_stats_long_min_index(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
     ) -> usize;
@@ -23848,36 +23519,36 @@ extern "C" {
     pub fn gsl_stats_long_minmax_index(
         min_index: *mut usize,
         max_index: *mut usize,
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_long_select(
-        data: *mut ::std::os::raw::c_long,
+        data: *mut ::core::ffi::c_long,
         stride: usize,
         n: usize,
         k: usize,
-    ) -> ::std::os::raw::c_long;
+    ) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn gsl_stats_long_median_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_long,
+        sorted_data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_median(
-        sorted_data: *mut ::std::os::raw::c_long,
+        sorted_data: *mut ::core::ffi::c_long,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_quantile_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_long,
+        sorted_data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
         f: f64,
@@ -23886,21 +23557,21 @@ extern "C" {
 extern "C" {
     pub fn gsl_stats_long_trmean_from_sorted_data(
         trim: f64,
-        sorted_data: *const ::std::os::raw::c_long,
+        sorted_data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_gastwirth_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_long,
+        sorted_data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_mad0(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -23908,7 +23579,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_long_mad(
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -23916,40 +23587,40 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_long_Sn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_long,
+        sorted_data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_long;
+        work: *mut ::core::ffi::c_long,
+    ) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn gsl_stats_long_Sn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_long,
+        sorted_data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_long,
+        work: *mut ::core::ffi::c_long,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_long_Qn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_long,
+        sorted_data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_long,
-        work_int: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_long;
+        work: *mut ::core::ffi::c_long,
+        work_int: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn gsl_stats_long_Qn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_long,
+        sorted_data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_long,
-        work_int: *mut ::std::os::raw::c_int,
+        work: *mut ::core::ffi::c_long,
+        work_int: *mut ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_multiply_e(x: f64, y: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_multiply_e(x: f64, y: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_multiply(x: f64, y: f64) -> f64;
@@ -23961,16 +23632,16 @@ extern "C" {
         y: f64,
         dy: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sort_short(data: *mut ::std::os::raw::c_short, stride: usize, n: usize);
+    pub fn gsl_sort_short(data: *mut ::core::ffi::c_short, stride: usize, n: usize);
 }
 extern "C" {
     pub fn gsl_sort2_short(
-        data1: *mut ::std::os::raw::c_short,
+        data1: *mut ::core::ffi::c_short,
         stride1: usize,
-        data2: *mut ::std::os::raw::c_short,
+        data2: *mut ::core::ffi::c_short,
         stride2: usize,
         n: usize,
     );
@@ -23978,77 +23649,75 @@ extern "C" {
 extern "C" {
     pub fn gsl_sort_short_index(
         p: *mut usize,
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_sort_short_smallest(
-        dest: *mut ::std::os::raw::c_short,
+        dest: *mut ::core::ffi::c_short,
         k: usize,
-        src: *const ::std::os::raw::c_short,
+        src: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_short_smallest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_short,
+        src: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_short_largest(
-        dest: *mut ::std::os::raw::c_short,
+        dest: *mut ::core::ffi::c_short,
         k: usize,
-        src: *const ::std::os::raw::c_short,
+        src: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_short_largest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_short,
+        src: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_ulong(
         p: *const gsl_permutation,
         v: *mut gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_ulong_inverse(
         p: *const gsl_permutation,
         v: *mut gsl_vector_ulong,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hyperg_0F1_e(c: f64, x: f64, result: *mut gsl_sf_result)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_sf_hyperg_0F1_e(c: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hyperg_0F1(c: f64, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hyperg_1F1_int_e(
-        m: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
+        m: ::core::ffi::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hyperg_1F1_int(m: ::std::os::raw::c_int, n: ::std::os::raw::c_int, x: f64)
-        -> f64;
+    pub fn gsl_sf_hyperg_1F1_int(m: ::core::ffi::c_int, n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hyperg_1F1_e(
@@ -24056,29 +23725,29 @@ extern "C" {
         b: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hyperg_1F1(a: f64, b: f64, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hyperg_U_int_e(
-        m: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
+        m: ::core::ffi::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hyperg_U_int(m: ::std::os::raw::c_int, n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_hyperg_U_int(m: ::core::ffi::c_int, n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hyperg_U_int_e10_e(
-        m: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
+        m: ::core::ffi::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result_e10,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hyperg_U_e(
@@ -24086,7 +23755,7 @@ extern "C" {
         b: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hyperg_U(a: f64, b: f64, x: f64) -> f64;
@@ -24097,7 +23766,7 @@ extern "C" {
         b: f64,
         x: f64,
         result: *mut gsl_sf_result_e10,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hyperg_2F1_e(
@@ -24106,7 +23775,7 @@ extern "C" {
         c: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hyperg_2F1(a: f64, b: f64, c: f64, x: f64) -> f64;
@@ -24118,7 +23787,7 @@ extern "C" {
         c: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hyperg_2F1_conj(aR: f64, aI: f64, c: f64, x: f64) -> f64;
@@ -24130,7 +23799,7 @@ extern "C" {
         c: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hyperg_2F1_renorm(a: f64, b: f64, c: f64, x: f64) -> f64;
@@ -24142,7 +23811,7 @@ extern "C" {
         c: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hyperg_2F1_conj_renorm(aR: f64, aI: f64, c: f64, x: f64) -> f64;
@@ -24153,20 +23822,20 @@ extern "C" {
         b: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hyperg_2F0(a: f64, b: f64, x: f64) -> f64;
 }
 pub const gsl_fft_direction_gsl_fft_forward: gsl_fft_direction = -1;
 pub const gsl_fft_direction_gsl_fft_backward: gsl_fft_direction = 1;
-pub type gsl_fft_direction = ::std::os::raw::c_int;
+pub type gsl_fft_direction = ::core::ffi::c_int;
 extern "C" {
     pub fn gsl_fft_real_radix2_transform(
         data: *mut f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -24193,7 +23862,7 @@ extern "C" {
         n: usize,
         wavetable: *const gsl_fft_real_wavetable,
         work: *mut gsl_fft_real_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_real_unpack(
@@ -24201,22 +23870,22 @@ extern "C" {
         complex_coefficient: *mut f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_sin_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_sin_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_sin(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_cos_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_cos_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_cos(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_hypot_e(x: f64, y: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_hypot_e(x: f64, y: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hypot(x: f64, y: f64) -> f64;
@@ -24227,7 +23896,7 @@ extern "C" {
         zi: f64,
         szr: *mut gsl_sf_result,
         szi: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_complex_cos_e(
@@ -24235,7 +23904,7 @@ extern "C" {
         zi: f64,
         czr: *mut gsl_sf_result,
         czi: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_complex_logsin_e(
@@ -24243,22 +23912,22 @@ extern "C" {
         zi: f64,
         lszr: *mut gsl_sf_result,
         lszi: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_sinc_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_sinc_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_sinc(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_lnsinh_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_lnsinh_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_lnsinh(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_lncosh_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_lncosh_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_lncosh(x: f64) -> f64;
@@ -24269,7 +23938,7 @@ extern "C" {
         theta: f64,
         x: *mut gsl_sf_result,
         y: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_rect_to_polar(
@@ -24277,22 +23946,22 @@ extern "C" {
         y: f64,
         r: *mut gsl_sf_result,
         theta: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_sin_err_e(x: f64, dx: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_sin_err_e(x: f64, dx: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_cos_err_e(x: f64, dx: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_cos_err_e(x: f64, dx: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_angle_restrict_symm_e(theta: *mut f64) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_angle_restrict_symm_e(theta: *mut f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_angle_restrict_symm(theta: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_angle_restrict_pos_e(theta: *mut f64) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_angle_restrict_pos_e(theta: *mut f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_angle_restrict_pos(theta: f64) -> f64;
@@ -24301,13 +23970,13 @@ extern "C" {
     pub fn gsl_sf_angle_restrict_symm_err_e(
         theta: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_angle_restrict_pos_err_e(
         theta: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_cdf_ugaussian_P(x: f64) -> f64;
@@ -24532,62 +24201,62 @@ extern "C" {
     pub fn gsl_cdf_logistic_Qinv(Q: f64, a: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_cdf_binomial_P(k: ::std::os::raw::c_uint, p: f64, n: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_cdf_binomial_P(k: ::core::ffi::c_uint, p: f64, n: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
-    pub fn gsl_cdf_binomial_Q(k: ::std::os::raw::c_uint, p: f64, n: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_cdf_binomial_Q(k: ::core::ffi::c_uint, p: f64, n: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
-    pub fn gsl_cdf_poisson_P(k: ::std::os::raw::c_uint, mu: f64) -> f64;
+    pub fn gsl_cdf_poisson_P(k: ::core::ffi::c_uint, mu: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_cdf_poisson_Q(k: ::std::os::raw::c_uint, mu: f64) -> f64;
+    pub fn gsl_cdf_poisson_Q(k: ::core::ffi::c_uint, mu: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_cdf_geometric_P(k: ::std::os::raw::c_uint, p: f64) -> f64;
+    pub fn gsl_cdf_geometric_P(k: ::core::ffi::c_uint, p: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_cdf_geometric_Q(k: ::std::os::raw::c_uint, p: f64) -> f64;
+    pub fn gsl_cdf_geometric_Q(k: ::core::ffi::c_uint, p: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_cdf_negative_binomial_P(k: ::std::os::raw::c_uint, p: f64, n: f64) -> f64;
+    pub fn gsl_cdf_negative_binomial_P(k: ::core::ffi::c_uint, p: f64, n: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_cdf_negative_binomial_Q(k: ::std::os::raw::c_uint, p: f64, n: f64) -> f64;
+    pub fn gsl_cdf_negative_binomial_Q(k: ::core::ffi::c_uint, p: f64, n: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_cdf_pascal_P(k: ::std::os::raw::c_uint, p: f64, n: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_cdf_pascal_P(k: ::core::ffi::c_uint, p: f64, n: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
-    pub fn gsl_cdf_pascal_Q(k: ::std::os::raw::c_uint, p: f64, n: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_cdf_pascal_Q(k: ::core::ffi::c_uint, p: f64, n: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_cdf_hypergeometric_P(
-        k: ::std::os::raw::c_uint,
-        n1: ::std::os::raw::c_uint,
-        n2: ::std::os::raw::c_uint,
-        t: ::std::os::raw::c_uint,
+        k: ::core::ffi::c_uint,
+        n1: ::core::ffi::c_uint,
+        n2: ::core::ffi::c_uint,
+        t: ::core::ffi::c_uint,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_cdf_hypergeometric_Q(
-        k: ::std::os::raw::c_uint,
-        n1: ::std::os::raw::c_uint,
-        n2: ::std::os::raw::c_uint,
-        t: ::std::os::raw::c_uint,
+        k: ::core::ffi::c_uint,
+        n1: ::core::ffi::c_uint,
+        n2: ::core::ffi::c_uint,
+        t: ::core::ffi::c_uint,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_permute_vector_ushort(
         p: *const gsl_permutation,
         v: *mut gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_ushort_inverse(
         p: *const gsl_permutation,
         v: *mut gsl_vector_ushort,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_complex_polar(r: f64, theta: f64) -> gsl_complex;
@@ -24772,7 +24441,7 @@ extern "C" {
         data: *mut f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_complex_inverse(
@@ -24780,16 +24449,16 @@ extern "C" {
         data: *mut f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_log_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_log_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_log(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_log_abs_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_log_abs_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_log_abs(x: f64) -> f64;
@@ -24800,28 +24469,28 @@ extern "C" {
         zi: f64,
         lnr: *mut gsl_sf_result,
         theta: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_log_1plusx_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_log_1plusx_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_log_1plusx(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_log_1plusx_mx_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_log_1plusx_mx_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_log_1plusx_mx(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sort_uint(data: *mut ::std::os::raw::c_uint, stride: usize, n: usize);
+    pub fn gsl_sort_uint(data: *mut ::core::ffi::c_uint, stride: usize, n: usize);
 }
 extern "C" {
     pub fn gsl_sort2_uint(
-        data1: *mut ::std::os::raw::c_uint,
+        data1: *mut ::core::ffi::c_uint,
         stride1: usize,
-        data2: *mut ::std::os::raw::c_uint,
+        data2: *mut ::core::ffi::c_uint,
         stride2: usize,
         n: usize,
     );
@@ -24829,67 +24498,67 @@ extern "C" {
 extern "C" {
     pub fn gsl_sort_uint_index(
         p: *mut usize,
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_sort_uint_smallest(
-        dest: *mut ::std::os::raw::c_uint,
+        dest: *mut ::core::ffi::c_uint,
         k: usize,
-        src: *const ::std::os::raw::c_uint,
+        src: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_uint_smallest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_uint,
+        src: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_uint_largest(
-        dest: *mut ::std::os::raw::c_uint,
+        dest: *mut ::core::ffi::c_uint,
         k: usize,
-        src: *const ::std::os::raw::c_uint,
+        src: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_uint_largest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_uint,
+        src: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_float_radix2_forward(
         data: gsl_complex_packed_array_float,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_float_radix2_backward(
         data: gsl_complex_packed_array_float,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_float_radix2_inverse(
         data: gsl_complex_packed_array_float,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_float_radix2_transform(
@@ -24897,28 +24566,28 @@ extern "C" {
         stride: usize,
         n: usize,
         sign: gsl_fft_direction,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_float_radix2_dif_forward(
         data: gsl_complex_packed_array_float,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_float_radix2_dif_backward(
         data: gsl_complex_packed_array_float,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_float_radix2_dif_inverse(
         data: gsl_complex_packed_array_float,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_float_radix2_dif_transform(
@@ -24926,7 +24595,7 @@ extern "C" {
         stride: usize,
         n: usize,
         sign: gsl_fft_direction,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -24956,7 +24625,7 @@ extern "C" {
     pub fn gsl_fft_complex_float_memcpy(
         dest: *mut gsl_fft_complex_wavetable_float,
         src: *mut gsl_fft_complex_wavetable_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_float_forward(
@@ -24965,7 +24634,7 @@ extern "C" {
         n: usize,
         wavetable: *const gsl_fft_complex_wavetable_float,
         work: *mut gsl_fft_complex_workspace_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_float_backward(
@@ -24974,7 +24643,7 @@ extern "C" {
         n: usize,
         wavetable: *const gsl_fft_complex_wavetable_float,
         work: *mut gsl_fft_complex_workspace_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_float_inverse(
@@ -24983,7 +24652,7 @@ extern "C" {
         n: usize,
         wavetable: *const gsl_fft_complex_wavetable_float,
         work: *mut gsl_fft_complex_workspace_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_float_transform(
@@ -24993,19 +24662,19 @@ extern "C" {
         wavetable: *const gsl_fft_complex_wavetable_float,
         work: *mut gsl_fft_complex_workspace_float,
         sign: gsl_fft_direction,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_complex(
         p: *const gsl_permutation,
         v: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_complex_inverse(
         p: *const gsl_permutation,
         v: *mut gsl_vector_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_long_double(data: *mut u128, stride: usize, n: usize);
@@ -25029,7 +24698,7 @@ extern "C" {
         src: *const u128,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_long_double_smallest_index(
@@ -25038,7 +24707,7 @@ extern "C" {
         src: *const u128,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_long_double_largest(
@@ -25047,7 +24716,7 @@ extern "C" {
         src: *const u128,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_long_double_largest_index(
@@ -25056,7 +24725,7 @@ extern "C" {
         src: *const u128,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort(data: *mut f64, stride: usize, n: usize);
@@ -25074,7 +24743,7 @@ extern "C" {
         src: *const f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_smallest_index(
@@ -25083,7 +24752,7 @@ extern "C" {
         src: *const f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_largest(
@@ -25092,7 +24761,7 @@ extern "C" {
         src: *const f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_largest_index(
@@ -25101,7 +24770,7 @@ extern "C" {
         src: *const f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_float(data: *mut f32, stride: usize, n: usize);
@@ -25125,7 +24794,7 @@ extern "C" {
         src: *const f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_float_smallest_index(
@@ -25134,7 +24803,7 @@ extern "C" {
         src: *const f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_float_largest(
@@ -25143,7 +24812,7 @@ extern "C" {
         src: *const f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_float_largest_index(
@@ -25152,16 +24821,16 @@ extern "C" {
         src: *const f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sort_long(data: *mut ::std::os::raw::c_long, stride: usize, n: usize);
+    pub fn gsl_sort_long(data: *mut ::core::ffi::c_long, stride: usize, n: usize);
 }
 extern "C" {
     pub fn gsl_sort2_long(
-        data1: *mut ::std::os::raw::c_long,
+        data1: *mut ::core::ffi::c_long,
         stride1: usize,
-        data2: *mut ::std::os::raw::c_long,
+        data2: *mut ::core::ffi::c_long,
         stride2: usize,
         n: usize,
     );
@@ -25169,55 +24838,55 @@ extern "C" {
 extern "C" {
     pub fn gsl_sort_long_index(
         p: *mut usize,
-        data: *const ::std::os::raw::c_long,
+        data: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_sort_long_smallest(
-        dest: *mut ::std::os::raw::c_long,
+        dest: *mut ::core::ffi::c_long,
         k: usize,
-        src: *const ::std::os::raw::c_long,
+        src: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_long_smallest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_long,
+        src: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_long_largest(
-        dest: *mut ::std::os::raw::c_long,
+        dest: *mut ::core::ffi::c_long,
         k: usize,
-        src: *const ::std::os::raw::c_long,
+        src: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_long_largest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_long,
+        src: *const ::core::ffi::c_long,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sort_ushort(data: *mut ::std::os::raw::c_ushort, stride: usize, n: usize);
+    pub fn gsl_sort_ushort(data: *mut ::core::ffi::c_ushort, stride: usize, n: usize);
 }
 extern "C" {
     pub fn gsl_sort2_ushort(
-        data1: *mut ::std::os::raw::c_ushort,
+        data1: *mut ::core::ffi::c_ushort,
         stride1: usize,
-        data2: *mut ::std::os::raw::c_ushort,
+        data2: *mut ::core::ffi::c_ushort,
         stride2: usize,
         n: usize,
     );
@@ -25225,55 +24894,55 @@ extern "C" {
 extern "C" {
     pub fn gsl_sort_ushort_index(
         p: *mut usize,
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_sort_ushort_smallest(
-        dest: *mut ::std::os::raw::c_ushort,
+        dest: *mut ::core::ffi::c_ushort,
         k: usize,
-        src: *const ::std::os::raw::c_ushort,
+        src: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_ushort_smallest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_ushort,
+        src: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_ushort_largest(
-        dest: *mut ::std::os::raw::c_ushort,
+        dest: *mut ::core::ffi::c_ushort,
         k: usize,
-        src: *const ::std::os::raw::c_ushort,
+        src: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_ushort_largest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_ushort,
+        src: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sort_char(data: *mut ::std::os::raw::c_char, stride: usize, n: usize);
+    pub fn gsl_sort_char(data: *mut ::core::ffi::c_char, stride: usize, n: usize);
 }
 extern "C" {
     pub fn gsl_sort2_char(
-        data1: *mut ::std::os::raw::c_char,
+        data1: *mut ::core::ffi::c_char,
         stride1: usize,
-        data2: *mut ::std::os::raw::c_char,
+        data2: *mut ::core::ffi::c_char,
         stride2: usize,
         n: usize,
     );
@@ -25281,56 +24950,56 @@ extern "C" {
 extern "C" {
     pub fn gsl_sort_char_index(
         p: *mut usize,
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_sort_char_smallest(
-        dest: *mut ::std::os::raw::c_char,
+        dest: *mut ::core::ffi::c_char,
         k: usize,
-        src: *const ::std::os::raw::c_char,
+        src: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_char_smallest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_char,
+        src: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_char_largest(
-        dest: *mut ::std::os::raw::c_char,
+        dest: *mut ::core::ffi::c_char,
         k: usize,
-        src: *const ::std::os::raw::c_char,
+        src: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sort_char_largest_index(
         p: *mut usize,
         k: usize,
-        src: *const ::std::os::raw::c_char,
+        src: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
-pub type gsl_comparison_fn_t = ::std::option::Option<
+pub type gsl_comparison_fn_t = ::core::option::Option<
     unsafe extern "C" fn(
-        arg1: *const ::std::os::raw::c_void,
-        arg2: *const ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int,
+        arg1: *const ::core::ffi::c_void,
+        arg2: *const ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int,
 >;
 extern "C" {
     pub fn gsl_heapsort(
-        array: *mut ::std::os::raw::c_void,
+        array: *mut ::core::ffi::c_void,
         count: usize,
         size: usize,
         compare: gsl_comparison_fn_t,
@@ -25339,11 +25008,11 @@ extern "C" {
 extern "C" {
     pub fn gsl_heapsort_index(
         p: *mut usize,
-        array: *const ::std::os::raw::c_void,
+        array: *const ::core::ffi::c_void,
         count: usize,
         size: usize,
         compare: gsl_comparison_fn_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[cfg(feature = "v2_5")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
@@ -25356,7 +25025,7 @@ pub const gsl_movstat_end_t_GSL_MOVSTAT_END_PADVALUE: gsl_movstat_end_t = 1;
 pub const gsl_movstat_end_t_GSL_MOVSTAT_END_TRUNCATE: gsl_movstat_end_t = 2;
 #[cfg(feature = "v2_5")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
-pub type gsl_movstat_end_t = ::std::os::raw::c_uint;
+pub type gsl_movstat_end_t = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 #[cfg(feature = "v2_5")]
@@ -25403,11 +25072,11 @@ extern "C" {
         endtype: gsl_movstat_end_t,
         x: *const gsl_vector,
         accum: *const gsl_movstat_accum,
-        accum_params: *mut ::std::os::raw::c_void,
+        accum_params: *mut ::core::ffi::c_void,
         y: *mut gsl_vector,
         z: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25418,7 +25087,7 @@ extern "C" {
         x: *const gsl_vector,
         y: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25440,7 +25109,7 @@ extern "C" {
         x: *const gsl_vector,
         y: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25450,7 +25119,7 @@ extern "C" {
         x: *const gsl_vector,
         y: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25460,7 +25129,7 @@ extern "C" {
         x: *const gsl_vector,
         y: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25470,7 +25139,7 @@ extern "C" {
         x: *const gsl_vector,
         y: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25480,7 +25149,7 @@ extern "C" {
         x: *const gsl_vector,
         y: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25490,7 +25159,7 @@ extern "C" {
         x: *const gsl_vector,
         y: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25501,7 +25170,7 @@ extern "C" {
         y_min: *mut gsl_vector,
         y_max: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25512,7 +25181,7 @@ extern "C" {
         xmedian: *mut gsl_vector,
         xmad: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25523,7 +25192,7 @@ extern "C" {
         xmedian: *mut gsl_vector,
         xmad: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25534,7 +25203,7 @@ extern "C" {
         q: f64,
         xqqr: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25544,7 +25213,7 @@ extern "C" {
         x: *const gsl_vector,
         xscale: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25554,7 +25223,7 @@ extern "C" {
         x: *const gsl_vector,
         xscale: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25564,7 +25233,7 @@ extern "C" {
         x: *const gsl_vector,
         y: *mut gsl_vector,
         w: *mut gsl_movstat_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25642,7 +25311,7 @@ pub const gsl_filter_end_t_GSL_FILTER_END_PADVALUE: gsl_filter_end_t = 1;
 pub const gsl_filter_end_t_GSL_FILTER_END_TRUNCATE: gsl_filter_end_t = 2;
 #[cfg(feature = "v2_5")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
-pub type gsl_filter_end_t = ::std::os::raw::c_uint;
+pub type gsl_filter_end_t = ::core::ffi::c_uint;
 #[cfg(feature = "v2_5")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub const gsl_filter_scale_t_GSL_FILTER_SCALE_MAD: gsl_filter_scale_t = 0;
@@ -25657,7 +25326,7 @@ pub const gsl_filter_scale_t_GSL_FILTER_SCALE_SN: gsl_filter_scale_t = 2;
 pub const gsl_filter_scale_t_GSL_FILTER_SCALE_QN: gsl_filter_scale_t = 3;
 #[cfg(feature = "v2_5")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
-pub type gsl_filter_scale_t = ::std::os::raw::c_uint;
+pub type gsl_filter_scale_t = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 #[cfg(feature = "v2_5")]
@@ -25683,7 +25352,7 @@ extern "C" {
         x: *const gsl_vector,
         y: *mut gsl_vector,
         w: *mut gsl_filter_gaussian_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -25691,9 +25360,9 @@ extern "C" {
     pub fn gsl_filter_gaussian_kernel(
         alpha: f64,
         order: usize,
-        normalize: ::std::os::raw::c_int,
+        normalize: ::core::ffi::c_int,
         kernel: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -25718,7 +25387,7 @@ extern "C" {
         x: *const gsl_vector,
         y: *mut gsl_vector,
         w: *mut gsl_filter_median_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -25743,7 +25412,7 @@ extern "C" {
         x: *const gsl_vector,
         y: *mut gsl_vector,
         w: *mut gsl_filter_rmedian_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -25774,25 +25443,24 @@ extern "C" {
         noutlier: *mut usize,
         ioutlier: *mut gsl_vector_int,
         w: *mut gsl_filter_impulse_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_stats_char_mean(data: *const ::std::os::raw::c_char, stride: usize, n: usize)
-        -> f64;
+    pub fn gsl_stats_char_mean(data: *const ::core::ffi::c_char, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_variance(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_char_sd(data: *const ::std::os::raw::c_char, stride: usize, n: usize) -> f64;
+    pub fn gsl_stats_char_sd(data: *const ::core::ffi::c_char, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_variance_with_fixed_mean(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
         mean: f64,
@@ -25800,71 +25468,66 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_char_sd_with_fixed_mean(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
         mean: f64,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_char_tss(data: *const ::std::os::raw::c_char, stride: usize, n: usize) -> f64;
+    pub fn gsl_stats_char_tss(data: *const ::core::ffi::c_char, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_tss_m(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
         mean: f64,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_char_absdev(
-        data: *const ::std::os::raw::c_char,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_char_absdev(data: *const ::core::ffi::c_char, stride: usize, n: usize) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_char_skew(data: *const ::std::os::raw::c_char, stride: usize, n: usize)
-        -> f64;
+    pub fn gsl_stats_char_skew(data: *const ::core::ffi::c_char, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_kurtosis(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_lag1_autocorrelation(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_covariance(
-        data1: *const ::std::os::raw::c_char,
+        data1: *const ::core::ffi::c_char,
         stride1: usize,
-        data2: *const ::std::os::raw::c_char,
+        data2: *const ::core::ffi::c_char,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_correlation(
-        data1: *const ::std::os::raw::c_char,
+        data1: *const ::core::ffi::c_char,
         stride1: usize,
-        data2: *const ::std::os::raw::c_char,
+        data2: *const ::core::ffi::c_char,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_spearman(
-        data1: *const ::std::os::raw::c_char,
+        data1: *const ::core::ffi::c_char,
         stride1: usize,
-        data2: *const ::std::os::raw::c_char,
+        data2: *const ::core::ffi::c_char,
         stride2: usize,
         n: usize,
         work: *mut f64,
@@ -25872,7 +25535,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_char_variance_m(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
         mean: f64,
@@ -25880,7 +25543,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_char_sd_m(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
         mean: f64,
@@ -25888,7 +25551,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_char_absdev_m(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
         mean: f64,
@@ -25896,7 +25559,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_char_skew_m_sd(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
         mean: f64,
@@ -25905,7 +25568,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_char_kurtosis_m_sd(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
         mean: f64,
@@ -25914,7 +25577,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_char_lag1_autocorrelation_m(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
         mean: f64,
@@ -25922,9 +25585,9 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_char_covariance_m(
-        data1: *const ::std::os::raw::c_char,
+        data1: *const ::core::ffi::c_char,
         stride1: usize,
-        data2: *const ::std::os::raw::c_char,
+        data2: *const ::core::ffi::c_char,
         stride2: usize,
         n: usize,
         mean1: f64,
@@ -25933,57 +25596,57 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_char_pvariance(
-        data1: *const ::std::os::raw::c_char,
+        data1: *const ::core::ffi::c_char,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_char,
+        data2: *const ::core::ffi::c_char,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_ttest(
-        data1: *const ::std::os::raw::c_char,
+        data1: *const ::core::ffi::c_char,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_char,
+        data2: *const ::core::ffi::c_char,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_max(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_char;
+    ) -> ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_stats_char_min(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_char;
+    ) -> ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_stats_char_minmax(
-        min: *mut ::std::os::raw::c_char,
-        max: *mut ::std::os::raw::c_char,
-        data: *const ::std::os::raw::c_char,
+        min: *mut ::core::ffi::c_char,
+        max: *mut ::core::ffi::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_char_max_index(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
     ) -> usize;
 }
 extern "C" {
     pub fn gsl_stats_char_min_index(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
     ) -> usize;
@@ -25992,36 +25655,36 @@ extern "C" {
     pub fn gsl_stats_char_minmax_index(
         min_index: *mut usize,
         max_index: *mut usize,
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_char_select(
-        data: *mut ::std::os::raw::c_char,
+        data: *mut ::core::ffi::c_char,
         stride: usize,
         n: usize,
         k: usize,
-    ) -> ::std::os::raw::c_char;
+    ) -> ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_stats_char_median_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_char,
+        sorted_data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_median(
-        sorted_data: *mut ::std::os::raw::c_char,
+        sorted_data: *mut ::core::ffi::c_char,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_quantile_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_char,
+        sorted_data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
         f: f64,
@@ -26030,21 +25693,21 @@ extern "C" {
 extern "C" {
     pub fn gsl_stats_char_trmean_from_sorted_data(
         trim: f64,
-        sorted_data: *const ::std::os::raw::c_char,
+        sorted_data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_gastwirth_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_char,
+        sorted_data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_mad0(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -26052,7 +25715,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_char_mad(
-        data: *const ::std::os::raw::c_char,
+        data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -26060,36 +25723,36 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_char_Sn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_char,
+        sorted_data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_char;
+        work: *mut ::core::ffi::c_char,
+    ) -> ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_stats_char_Sn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_char,
+        sorted_data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_char,
+        work: *mut ::core::ffi::c_char,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_char_Qn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_char,
+        sorted_data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_char,
-        work_int: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_char;
+        work: *mut ::core::ffi::c_char,
+        work_int: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_stats_char_Qn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_char,
+        sorted_data: *const ::core::ffi::c_char,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_char,
-        work_int: *mut ::std::os::raw::c_int,
+        work: *mut ::core::ffi::c_char,
+        work_int: *mut ::core::ffi::c_int,
     ) -> f64;
 }
 #[repr(C)]
@@ -26130,14 +25793,14 @@ extern "C" {
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_name(
         w: *const gsl_multilarge_linear_workspace,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_linear_reset(
         w: *mut gsl_multilarge_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -26146,7 +25809,7 @@ extern "C" {
         X: *mut gsl_matrix,
         y: *mut gsl_vector,
         w: *mut gsl_multilarge_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -26157,7 +25820,7 @@ extern "C" {
         rnorm: *mut f64,
         snorm: *mut f64,
         w: *mut gsl_multilarge_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -26165,7 +25828,7 @@ extern "C" {
     pub fn gsl_multilarge_linear_rcond(
         rcond: *mut f64,
         w: *mut gsl_multilarge_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -26175,7 +25838,7 @@ extern "C" {
         rho: *mut gsl_vector,
         eta: *mut gsl_vector,
         w: *mut gsl_multilarge_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -26188,7 +25851,7 @@ extern "C" {
         Xs: *mut gsl_matrix,
         ys: *mut gsl_vector,
         work: *mut gsl_multilarge_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -26200,7 +25863,7 @@ extern "C" {
         Xs: *mut gsl_matrix,
         ys: *mut gsl_vector,
         work: *mut gsl_multilarge_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -26208,7 +25871,7 @@ extern "C" {
     pub fn gsl_multilarge_linear_L_decomp(
         L: *mut gsl_matrix,
         tau: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -26222,7 +25885,7 @@ extern "C" {
         Xs: *mut gsl_matrix,
         ys: *mut gsl_vector,
         work: *mut gsl_multilarge_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -26235,7 +25898,7 @@ extern "C" {
         Xs: *mut gsl_matrix,
         ys: *mut gsl_vector,
         work: *mut gsl_multilarge_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -26245,7 +25908,7 @@ extern "C" {
         cs: *const gsl_vector,
         c: *mut gsl_vector,
         work: *mut gsl_multilarge_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -26256,7 +25919,7 @@ extern "C" {
         cs: *const gsl_vector,
         c: *mut gsl_vector,
         work: *mut gsl_multilarge_linear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_7")]
@@ -26318,16 +25981,16 @@ extern "C" {
     pub fn gsl_odeiv_step_alloc(T: *const gsl_odeiv_step_type, dim: usize) -> *mut gsl_odeiv_step;
 }
 extern "C" {
-    pub fn gsl_odeiv_step_reset(s: *mut gsl_odeiv_step) -> ::std::os::raw::c_int;
+    pub fn gsl_odeiv_step_reset(s: *mut gsl_odeiv_step) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv_step_free(s: *mut gsl_odeiv_step);
 }
 extern "C" {
-    pub fn gsl_odeiv_step_name(s: *const gsl_odeiv_step) -> *const ::std::os::raw::c_char;
+    pub fn gsl_odeiv_step_name(s: *const gsl_odeiv_step) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_odeiv_step_order(s: *const gsl_odeiv_step) -> ::std::os::raw::c_uint;
+    pub fn gsl_odeiv_step_order(s: *const gsl_odeiv_step) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_odeiv_step_apply(
@@ -26339,7 +26002,7 @@ extern "C" {
         dydt_in: *const f64,
         dydt_out: *mut f64,
         dydt: *const gsl_odeiv_system,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -26357,7 +26020,7 @@ extern "C" {
         eps_rel: f64,
         a_y: f64,
         a_dydt: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv_control_free(c: *mut gsl_odeiv_control);
@@ -26370,10 +26033,10 @@ extern "C" {
         yerr: *const f64,
         dydt: *const f64,
         h: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_odeiv_control_name(c: *const gsl_odeiv_control) -> *const ::std::os::raw::c_char;
+    pub fn gsl_odeiv_control_name(c: *const gsl_odeiv_control) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_odeiv_control_standard_new(
@@ -26415,10 +26078,10 @@ extern "C" {
         t1: f64,
         h: *mut f64,
         y: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_odeiv_evolve_reset(e: *mut gsl_odeiv_evolve) -> ::std::os::raw::c_int;
+    pub fn gsl_odeiv_evolve_reset(e: *mut gsl_odeiv_evolve) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv_evolve_free(e: *mut gsl_odeiv_evolve);
@@ -26426,41 +26089,41 @@ extern "C" {
 extern "C" {
     pub fn gsl_permute_short(
         p: *const usize,
-        data: *mut ::std::os::raw::c_short,
+        data: *mut ::core::ffi::c_short,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_short_inverse(
         p: *const usize,
-        data: *mut ::std::os::raw::c_short,
+        data: *mut ::core::ffi::c_short,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_odeiv2_system {
-    pub function: ::std::option::Option<
+    pub function: ::core::option::Option<
         unsafe extern "C" fn(
             t: f64,
             y: *const f64,
             dydt: *mut f64,
-            params: *mut ::std::os::raw::c_void,
-        ) -> ::std::os::raw::c_int,
+            params: *mut ::core::ffi::c_void,
+        ) -> ::core::ffi::c_int,
     >,
-    pub jacobian: ::std::option::Option<
+    pub jacobian: ::core::option::Option<
         unsafe extern "C" fn(
             t: f64,
             y: *const f64,
             dfdy: *mut f64,
             dfdt: *mut f64,
-            params: *mut ::std::os::raw::c_void,
-        ) -> ::std::os::raw::c_int,
+            params: *mut ::core::ffi::c_void,
+        ) -> ::core::ffi::c_int,
     >,
     pub dimension: usize,
-    pub params: *mut ::std::os::raw::c_void,
+    pub params: *mut ::core::ffi::c_void,
 }
 pub type gsl_odeiv2_step = gsl_odeiv2_step_struct;
 pub type gsl_odeiv2_control = gsl_odeiv2_control_struct;
@@ -26512,16 +26175,16 @@ extern "C" {
     ) -> *mut gsl_odeiv2_step;
 }
 extern "C" {
-    pub fn gsl_odeiv2_step_reset(s: *mut gsl_odeiv2_step) -> ::std::os::raw::c_int;
+    pub fn gsl_odeiv2_step_reset(s: *mut gsl_odeiv2_step) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv2_step_free(s: *mut gsl_odeiv2_step);
 }
 extern "C" {
-    pub fn gsl_odeiv2_step_name(s: *const gsl_odeiv2_step) -> *const ::std::os::raw::c_char;
+    pub fn gsl_odeiv2_step_name(s: *const gsl_odeiv2_step) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_odeiv2_step_order(s: *const gsl_odeiv2_step) -> ::std::os::raw::c_uint;
+    pub fn gsl_odeiv2_step_order(s: *const gsl_odeiv2_step) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_odeiv2_step_apply(
@@ -26533,13 +26196,13 @@ extern "C" {
         dydt_in: *const f64,
         dydt_out: *mut f64,
         dydt: *const gsl_odeiv2_system,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv2_step_set_driver(
         s: *mut gsl_odeiv2_step,
         d: *const gsl_odeiv2_driver,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -26557,7 +26220,7 @@ extern "C" {
         eps_rel: f64,
         a_y: f64,
         a_dydt: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv2_control_free(c: *mut gsl_odeiv2_control);
@@ -26570,10 +26233,10 @@ extern "C" {
         yerr: *const f64,
         dydt: *const f64,
         h: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_odeiv2_control_name(c: *const gsl_odeiv2_control) -> *const ::std::os::raw::c_char;
+    pub fn gsl_odeiv2_control_name(c: *const gsl_odeiv2_control) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_odeiv2_control_errlevel(
@@ -26583,13 +26246,13 @@ extern "C" {
         h: f64,
         ind: usize,
         errlev: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv2_control_set_driver(
         c: *mut gsl_odeiv2_control,
         d: *const gsl_odeiv2_driver,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv2_control_standard_new(
@@ -26631,7 +26294,7 @@ extern "C" {
         t1: f64,
         h: *mut f64,
         y: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv2_evolve_apply_fixed_step(
@@ -26642,10 +26305,10 @@ extern "C" {
         t: *mut f64,
         h0: f64,
         y: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_odeiv2_evolve_reset(e: *mut gsl_odeiv2_evolve) -> ::std::os::raw::c_int;
+    pub fn gsl_odeiv2_evolve_reset(e: *mut gsl_odeiv2_evolve) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv2_evolve_free(e: *mut gsl_odeiv2_evolve);
@@ -26654,7 +26317,7 @@ extern "C" {
     pub fn gsl_odeiv2_evolve_set_driver(
         e: *mut gsl_odeiv2_evolve,
         d: *const gsl_odeiv2_driver,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -26701,22 +26364,16 @@ extern "C" {
     ) -> *mut gsl_odeiv2_driver;
 }
 extern "C" {
-    pub fn gsl_odeiv2_driver_set_hmin(
-        d: *mut gsl_odeiv2_driver,
-        hmin: f64,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_odeiv2_driver_set_hmin(d: *mut gsl_odeiv2_driver, hmin: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_odeiv2_driver_set_hmax(
-        d: *mut gsl_odeiv2_driver,
-        hmax: f64,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_odeiv2_driver_set_hmax(d: *mut gsl_odeiv2_driver, hmax: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv2_driver_set_nmax(
         d: *mut gsl_odeiv2_driver,
-        nmax: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_int;
+        nmax: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv2_driver_apply(
@@ -26724,163 +26381,163 @@ extern "C" {
         t: *mut f64,
         t1: f64,
         y: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv2_driver_apply_fixed_step(
         d: *mut gsl_odeiv2_driver,
         t: *mut f64,
         h: f64,
-        n: ::std::os::raw::c_ulong,
+        n: ::core::ffi::c_ulong,
         y: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_odeiv2_driver_reset(d: *mut gsl_odeiv2_driver) -> ::std::os::raw::c_int;
+    pub fn gsl_odeiv2_driver_reset(d: *mut gsl_odeiv2_driver) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv2_driver_reset_hstart(
         d: *mut gsl_odeiv2_driver,
         hstart: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_odeiv2_driver_free(state: *mut gsl_odeiv2_driver);
 }
 extern "C" {
     pub fn gsl_sf_coupling_3j_e(
-        two_ja: ::std::os::raw::c_int,
-        two_jb: ::std::os::raw::c_int,
-        two_jc: ::std::os::raw::c_int,
-        two_ma: ::std::os::raw::c_int,
-        two_mb: ::std::os::raw::c_int,
-        two_mc: ::std::os::raw::c_int,
+        two_ja: ::core::ffi::c_int,
+        two_jb: ::core::ffi::c_int,
+        two_jc: ::core::ffi::c_int,
+        two_ma: ::core::ffi::c_int,
+        two_mb: ::core::ffi::c_int,
+        two_mc: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_coupling_3j(
-        two_ja: ::std::os::raw::c_int,
-        two_jb: ::std::os::raw::c_int,
-        two_jc: ::std::os::raw::c_int,
-        two_ma: ::std::os::raw::c_int,
-        two_mb: ::std::os::raw::c_int,
-        two_mc: ::std::os::raw::c_int,
+        two_ja: ::core::ffi::c_int,
+        two_jb: ::core::ffi::c_int,
+        two_jc: ::core::ffi::c_int,
+        two_ma: ::core::ffi::c_int,
+        two_mb: ::core::ffi::c_int,
+        two_mc: ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_coupling_6j_e(
-        two_ja: ::std::os::raw::c_int,
-        two_jb: ::std::os::raw::c_int,
-        two_jc: ::std::os::raw::c_int,
-        two_jd: ::std::os::raw::c_int,
-        two_je: ::std::os::raw::c_int,
-        two_jf: ::std::os::raw::c_int,
+        two_ja: ::core::ffi::c_int,
+        two_jb: ::core::ffi::c_int,
+        two_jc: ::core::ffi::c_int,
+        two_jd: ::core::ffi::c_int,
+        two_je: ::core::ffi::c_int,
+        two_jf: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_coupling_6j(
-        two_ja: ::std::os::raw::c_int,
-        two_jb: ::std::os::raw::c_int,
-        two_jc: ::std::os::raw::c_int,
-        two_jd: ::std::os::raw::c_int,
-        two_je: ::std::os::raw::c_int,
-        two_jf: ::std::os::raw::c_int,
+        two_ja: ::core::ffi::c_int,
+        two_jb: ::core::ffi::c_int,
+        two_jc: ::core::ffi::c_int,
+        two_jd: ::core::ffi::c_int,
+        two_je: ::core::ffi::c_int,
+        two_jf: ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_coupling_RacahW_e(
-        two_ja: ::std::os::raw::c_int,
-        two_jb: ::std::os::raw::c_int,
-        two_jc: ::std::os::raw::c_int,
-        two_jd: ::std::os::raw::c_int,
-        two_je: ::std::os::raw::c_int,
-        two_jf: ::std::os::raw::c_int,
+        two_ja: ::core::ffi::c_int,
+        two_jb: ::core::ffi::c_int,
+        two_jc: ::core::ffi::c_int,
+        two_jd: ::core::ffi::c_int,
+        two_je: ::core::ffi::c_int,
+        two_jf: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_coupling_RacahW(
-        two_ja: ::std::os::raw::c_int,
-        two_jb: ::std::os::raw::c_int,
-        two_jc: ::std::os::raw::c_int,
-        two_jd: ::std::os::raw::c_int,
-        two_je: ::std::os::raw::c_int,
-        two_jf: ::std::os::raw::c_int,
+        two_ja: ::core::ffi::c_int,
+        two_jb: ::core::ffi::c_int,
+        two_jc: ::core::ffi::c_int,
+        two_jd: ::core::ffi::c_int,
+        two_je: ::core::ffi::c_int,
+        two_jf: ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_coupling_9j_e(
-        two_ja: ::std::os::raw::c_int,
-        two_jb: ::std::os::raw::c_int,
-        two_jc: ::std::os::raw::c_int,
-        two_jd: ::std::os::raw::c_int,
-        two_je: ::std::os::raw::c_int,
-        two_jf: ::std::os::raw::c_int,
-        two_jg: ::std::os::raw::c_int,
-        two_jh: ::std::os::raw::c_int,
-        two_ji: ::std::os::raw::c_int,
+        two_ja: ::core::ffi::c_int,
+        two_jb: ::core::ffi::c_int,
+        two_jc: ::core::ffi::c_int,
+        two_jd: ::core::ffi::c_int,
+        two_je: ::core::ffi::c_int,
+        two_jf: ::core::ffi::c_int,
+        two_jg: ::core::ffi::c_int,
+        two_jh: ::core::ffi::c_int,
+        two_ji: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_coupling_9j(
-        two_ja: ::std::os::raw::c_int,
-        two_jb: ::std::os::raw::c_int,
-        two_jc: ::std::os::raw::c_int,
-        two_jd: ::std::os::raw::c_int,
-        two_je: ::std::os::raw::c_int,
-        two_jf: ::std::os::raw::c_int,
-        two_jg: ::std::os::raw::c_int,
-        two_jh: ::std::os::raw::c_int,
-        two_ji: ::std::os::raw::c_int,
+        two_ja: ::core::ffi::c_int,
+        two_jb: ::core::ffi::c_int,
+        two_jc: ::core::ffi::c_int,
+        two_jd: ::core::ffi::c_int,
+        two_je: ::core::ffi::c_int,
+        two_jf: ::core::ffi::c_int,
+        two_jg: ::core::ffi::c_int,
+        two_jh: ::core::ffi::c_int,
+        two_ji: ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_coupling_6j_INCORRECT_e(
-        two_ja: ::std::os::raw::c_int,
-        two_jb: ::std::os::raw::c_int,
-        two_jc: ::std::os::raw::c_int,
-        two_jd: ::std::os::raw::c_int,
-        two_je: ::std::os::raw::c_int,
-        two_jf: ::std::os::raw::c_int,
+        two_ja: ::core::ffi::c_int,
+        two_jb: ::core::ffi::c_int,
+        two_jc: ::core::ffi::c_int,
+        two_jd: ::core::ffi::c_int,
+        two_je: ::core::ffi::c_int,
+        two_jf: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_coupling_6j_INCORRECT(
-        two_ja: ::std::os::raw::c_int,
-        two_jb: ::std::os::raw::c_int,
-        two_jc: ::std::os::raw::c_int,
-        two_jd: ::std::os::raw::c_int,
-        two_je: ::std::os::raw::c_int,
-        two_jf: ::std::os::raw::c_int,
+        two_ja: ::core::ffi::c_int,
+        two_jb: ::core::ffi::c_int,
+        two_jc: ::core::ffi::c_int,
+        two_jd: ::core::ffi::c_int,
+        two_je: ::core::ffi::c_int,
+        two_jf: ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
-    pub static mut gsl_version: *const ::std::os::raw::c_char;
+    pub static mut gsl_version: *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_sf_gegenpoly_1_e(
         lambda: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_gegenpoly_2_e(
         lambda: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_gegenpoly_3_e(
         lambda: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_gegenpoly_1(lambda: f64, x: f64) -> f64;
@@ -26893,44 +26550,39 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_sf_gegenpoly_n_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         lambda: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_gegenpoly_n(n: ::std::os::raw::c_int, lambda: f64, x: f64) -> f64;
+    pub fn gsl_sf_gegenpoly_n(n: ::core::ffi::c_int, lambda: f64, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_gegenpoly_array(
-        nmax: ::std::os::raw::c_int,
+        nmax: ::core::ffi::c_int,
         lambda: f64,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_stats_short_mean(
-        data: *const ::std::os::raw::c_short,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_short_mean(data: *const ::core::ffi::c_short, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_variance(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_short_sd(data: *const ::std::os::raw::c_short, stride: usize, n: usize)
-        -> f64;
+    pub fn gsl_stats_short_sd(data: *const ::core::ffi::c_short, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_variance_with_fixed_mean(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
         mean: f64,
@@ -26938,22 +26590,18 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_short_sd_with_fixed_mean(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
         mean: f64,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_short_tss(
-        data: *const ::std::os::raw::c_short,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_short_tss(data: *const ::core::ffi::c_short, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_tss_m(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
         mean: f64,
@@ -26961,55 +26609,51 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_short_absdev(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_short_skew(
-        data: *const ::std::os::raw::c_short,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_short_skew(data: *const ::core::ffi::c_short, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_kurtosis(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_lag1_autocorrelation(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_covariance(
-        data1: *const ::std::os::raw::c_short,
+        data1: *const ::core::ffi::c_short,
         stride1: usize,
-        data2: *const ::std::os::raw::c_short,
+        data2: *const ::core::ffi::c_short,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_correlation(
-        data1: *const ::std::os::raw::c_short,
+        data1: *const ::core::ffi::c_short,
         stride1: usize,
-        data2: *const ::std::os::raw::c_short,
+        data2: *const ::core::ffi::c_short,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_spearman(
-        data1: *const ::std::os::raw::c_short,
+        data1: *const ::core::ffi::c_short,
         stride1: usize,
-        data2: *const ::std::os::raw::c_short,
+        data2: *const ::core::ffi::c_short,
         stride2: usize,
         n: usize,
         work: *mut f64,
@@ -27017,7 +26661,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_short_variance_m(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
         mean: f64,
@@ -27025,7 +26669,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_short_sd_m(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
         mean: f64,
@@ -27033,7 +26677,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_short_absdev_m(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
         mean: f64,
@@ -27041,7 +26685,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_short_skew_m_sd(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
         mean: f64,
@@ -27050,7 +26694,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_short_kurtosis_m_sd(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
         mean: f64,
@@ -27059,7 +26703,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_short_lag1_autocorrelation_m(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
         mean: f64,
@@ -27067,9 +26711,9 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_short_covariance_m(
-        data1: *const ::std::os::raw::c_short,
+        data1: *const ::core::ffi::c_short,
         stride1: usize,
-        data2: *const ::std::os::raw::c_short,
+        data2: *const ::core::ffi::c_short,
         stride2: usize,
         n: usize,
         mean1: f64,
@@ -27078,57 +26722,57 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_short_pvariance(
-        data1: *const ::std::os::raw::c_short,
+        data1: *const ::core::ffi::c_short,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_short,
+        data2: *const ::core::ffi::c_short,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_ttest(
-        data1: *const ::std::os::raw::c_short,
+        data1: *const ::core::ffi::c_short,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_short,
+        data2: *const ::core::ffi::c_short,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_max(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_short;
+    ) -> ::core::ffi::c_short;
 }
 extern "C" {
     pub fn gsl_stats_short_min(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_short;
+    ) -> ::core::ffi::c_short;
 }
 extern "C" {
     pub fn gsl_stats_short_minmax(
-        min: *mut ::std::os::raw::c_short,
-        max: *mut ::std::os::raw::c_short,
-        data: *const ::std::os::raw::c_short,
+        min: *mut ::core::ffi::c_short,
+        max: *mut ::core::ffi::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_short_max_index(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
     ) -> usize;
 }
 extern "C" {
     pub fn gsl_stats_short_min_index(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
     ) -> usize;
@@ -27137,36 +26781,36 @@ extern "C" {
     pub fn gsl_stats_short_minmax_index(
         min_index: *mut usize,
         max_index: *mut usize,
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_short_select(
-        data: *mut ::std::os::raw::c_short,
+        data: *mut ::core::ffi::c_short,
         stride: usize,
         n: usize,
         k: usize,
-    ) -> ::std::os::raw::c_short;
+    ) -> ::core::ffi::c_short;
 }
 extern "C" {
     pub fn gsl_stats_short_median_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_short,
+        sorted_data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_median(
-        sorted_data: *mut ::std::os::raw::c_short,
+        sorted_data: *mut ::core::ffi::c_short,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_quantile_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_short,
+        sorted_data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
         f: f64,
@@ -27175,21 +26819,21 @@ extern "C" {
 extern "C" {
     pub fn gsl_stats_short_trmean_from_sorted_data(
         trim: f64,
-        sorted_data: *const ::std::os::raw::c_short,
+        sorted_data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_gastwirth_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_short,
+        sorted_data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_mad0(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -27197,7 +26841,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_short_mad(
-        data: *const ::std::os::raw::c_short,
+        data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -27205,36 +26849,36 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_short_Sn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_short,
+        sorted_data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_short,
-    ) -> ::std::os::raw::c_short;
+        work: *mut ::core::ffi::c_short,
+    ) -> ::core::ffi::c_short;
 }
 extern "C" {
     pub fn gsl_stats_short_Sn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_short,
+        sorted_data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_short,
+        work: *mut ::core::ffi::c_short,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_short_Qn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_short,
+        sorted_data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_short,
-        work_int: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_short;
+        work: *mut ::core::ffi::c_short,
+        work_int: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_short;
 }
 extern "C" {
     pub fn gsl_stats_short_Qn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_short,
+        sorted_data: *const ::core::ffi::c_short,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_short,
-        work_int: *mut ::std::os::raw::c_int,
+        work: *mut ::core::ffi::c_short,
+        work_int: *mut ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
@@ -27243,7 +26887,7 @@ extern "C" {
         data: *mut f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_float_inverse(
@@ -27251,16 +26895,16 @@ extern "C" {
         data: *mut f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_sin_pi_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_sin_pi_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_sin_pi(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_cos_pi_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_cos_pi_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_cos_pi(x: f64) -> f64;
@@ -27269,35 +26913,30 @@ extern "C" {
     pub fn gsl_permute_vector_float(
         p: *const gsl_permutation,
         v: *mut gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_float_inverse(
         p: *const gsl_permutation,
         v: *mut gsl_vector_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_stats_ulong_mean(
-        data: *const ::std::os::raw::c_ulong,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_ulong_mean(data: *const ::core::ffi::c_ulong, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_variance(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_ulong_sd(data: *const ::std::os::raw::c_ulong, stride: usize, n: usize)
-        -> f64;
+    pub fn gsl_stats_ulong_sd(data: *const ::core::ffi::c_ulong, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_variance_with_fixed_mean(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
         mean: f64,
@@ -27305,22 +26944,18 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ulong_sd_with_fixed_mean(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
         mean: f64,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_ulong_tss(
-        data: *const ::std::os::raw::c_ulong,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_ulong_tss(data: *const ::core::ffi::c_ulong, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_tss_m(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
         mean: f64,
@@ -27328,55 +26963,51 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ulong_absdev(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_ulong_skew(
-        data: *const ::std::os::raw::c_ulong,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_ulong_skew(data: *const ::core::ffi::c_ulong, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_kurtosis(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_lag1_autocorrelation(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_covariance(
-        data1: *const ::std::os::raw::c_ulong,
+        data1: *const ::core::ffi::c_ulong,
         stride1: usize,
-        data2: *const ::std::os::raw::c_ulong,
+        data2: *const ::core::ffi::c_ulong,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_correlation(
-        data1: *const ::std::os::raw::c_ulong,
+        data1: *const ::core::ffi::c_ulong,
         stride1: usize,
-        data2: *const ::std::os::raw::c_ulong,
+        data2: *const ::core::ffi::c_ulong,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_spearman(
-        data1: *const ::std::os::raw::c_ulong,
+        data1: *const ::core::ffi::c_ulong,
         stride1: usize,
-        data2: *const ::std::os::raw::c_ulong,
+        data2: *const ::core::ffi::c_ulong,
         stride2: usize,
         n: usize,
         work: *mut f64,
@@ -27384,7 +27015,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ulong_variance_m(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
         mean: f64,
@@ -27392,7 +27023,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ulong_sd_m(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
         mean: f64,
@@ -27400,7 +27031,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ulong_absdev_m(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
         mean: f64,
@@ -27408,7 +27039,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ulong_skew_m_sd(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
         mean: f64,
@@ -27417,7 +27048,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ulong_kurtosis_m_sd(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
         mean: f64,
@@ -27426,7 +27057,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ulong_lag1_autocorrelation_m(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
         mean: f64,
@@ -27434,9 +27065,9 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ulong_covariance_m(
-        data1: *const ::std::os::raw::c_ulong,
+        data1: *const ::core::ffi::c_ulong,
         stride1: usize,
-        data2: *const ::std::os::raw::c_ulong,
+        data2: *const ::core::ffi::c_ulong,
         stride2: usize,
         n: usize,
         mean1: f64,
@@ -27445,57 +27076,57 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ulong_pvariance(
-        data1: *const ::std::os::raw::c_ulong,
+        data1: *const ::core::ffi::c_ulong,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_ulong,
+        data2: *const ::core::ffi::c_ulong,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_ttest(
-        data1: *const ::std::os::raw::c_ulong,
+        data1: *const ::core::ffi::c_ulong,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_ulong,
+        data2: *const ::core::ffi::c_ulong,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_max(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_ulong;
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_stats_ulong_min(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_ulong;
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_stats_ulong_minmax(
-        min: *mut ::std::os::raw::c_ulong,
-        max: *mut ::std::os::raw::c_ulong,
-        data: *const ::std::os::raw::c_ulong,
+        min: *mut ::core::ffi::c_ulong,
+        max: *mut ::core::ffi::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_ulong_max_index(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     ) -> usize;
 }
 extern "C" {
     pub fn gsl_stats_ulong_min_index(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     ) -> usize;
@@ -27504,36 +27135,36 @@ extern "C" {
     pub fn gsl_stats_ulong_minmax_index(
         min_index: *mut usize,
         max_index: *mut usize,
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_ulong_select(
-        data: *mut ::std::os::raw::c_ulong,
+        data: *mut ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
         k: usize,
-    ) -> ::std::os::raw::c_ulong;
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_stats_ulong_median_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_ulong,
+        sorted_data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_median(
-        sorted_data: *mut ::std::os::raw::c_ulong,
+        sorted_data: *mut ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_quantile_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_ulong,
+        sorted_data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
         f: f64,
@@ -27542,21 +27173,21 @@ extern "C" {
 extern "C" {
     pub fn gsl_stats_ulong_trmean_from_sorted_data(
         trim: f64,
-        sorted_data: *const ::std::os::raw::c_ulong,
+        sorted_data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_gastwirth_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_ulong,
+        sorted_data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_mad0(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -27564,7 +27195,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ulong_mad(
-        data: *const ::std::os::raw::c_ulong,
+        data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -27572,36 +27203,36 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ulong_Sn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_ulong,
+        sorted_data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_ulong;
+        work: *mut ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_stats_ulong_Sn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_ulong,
+        sorted_data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_ulong,
+        work: *mut ::core::ffi::c_ulong,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ulong_Qn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_ulong,
+        sorted_data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_ulong,
-        work_int: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulong;
+        work: *mut ::core::ffi::c_ulong,
+        work_int: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn gsl_stats_ulong_Qn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_ulong,
+        sorted_data: *const ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_ulong,
-        work_int: *mut ::std::os::raw::c_int,
+        work: *mut ::core::ffi::c_ulong,
+        work_int: *mut ::core::ffi::c_int,
     ) -> f64;
 }
 #[repr(C)]
@@ -27616,13 +27247,13 @@ extern "C" {
         xa: *const f64,
         ya: *const f64,
         size: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_spline_name(spline: *const gsl_spline) -> *const ::std::os::raw::c_char;
+    pub fn gsl_spline_name(spline: *const gsl_spline) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_spline_min_size(spline: *const gsl_spline) -> ::std::os::raw::c_uint;
+    pub fn gsl_spline_min_size(spline: *const gsl_spline) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_spline_eval_e(
@@ -27630,7 +27261,7 @@ extern "C" {
         x: f64,
         a: *mut gsl_interp_accel,
         y: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spline_eval(spline: *const gsl_spline, x: f64, a: *mut gsl_interp_accel) -> f64;
@@ -27641,7 +27272,7 @@ extern "C" {
         x: f64,
         a: *mut gsl_interp_accel,
         y: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spline_eval_deriv(
@@ -27656,7 +27287,7 @@ extern "C" {
         x: f64,
         a: *mut gsl_interp_accel,
         y: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spline_eval_deriv2(
@@ -27672,7 +27303,7 @@ extern "C" {
         b: f64,
         acc: *mut gsl_interp_accel,
         y: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spline_eval_integ(
@@ -27692,7 +27323,7 @@ extern "C" {
         h: f64,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_deriv_backward(
@@ -27701,7 +27332,7 @@ extern "C" {
         h: f64,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_deriv_forward(
@@ -27710,28 +27341,28 @@ extern "C" {
         h: f64,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_uint(
         p: *const usize,
-        data: *mut ::std::os::raw::c_uint,
+        data: *mut ::core::ffi::c_uint,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_uint_inverse(
         p: *const usize,
-        data: *mut ::std::os::raw::c_uint,
+        data: *mut ::core::ffi::c_uint,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
-pub const GSL_VEGAS_MODE_IMPORTANCE: ::std::os::raw::c_int = 1;
-pub const GSL_VEGAS_MODE_IMPORTANCE_ONLY: ::std::os::raw::c_int = 0;
-pub const GSL_VEGAS_MODE_STRATIFIED: ::std::os::raw::c_int = -1;
-pub type _bindgen_ty_4 = ::std::os::raw::c_int;
+pub const GSL_VEGAS_MODE_IMPORTANCE: ::core::ffi::c_int = 1;
+pub const GSL_VEGAS_MODE_IMPORTANCE_ONLY: ::core::ffi::c_int = 0;
+pub const GSL_VEGAS_MODE_STRATIFIED: ::core::ffi::c_int = -1;
+pub type _bindgen_ty_4 = ::core::ffi::c_int;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_monte_vegas_state;
@@ -27746,13 +27377,13 @@ extern "C" {
         state: *mut gsl_monte_vegas_state,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_monte_vegas_alloc(dim: usize) -> *mut gsl_monte_vegas_state;
 }
 extern "C" {
-    pub fn gsl_monte_vegas_init(state: *mut gsl_monte_vegas_state) -> ::std::os::raw::c_int;
+    pub fn gsl_monte_vegas_init(state: *mut gsl_monte_vegas_state) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_monte_vegas_free(state: *mut gsl_monte_vegas_state);
@@ -27772,9 +27403,9 @@ extern "C" {
 pub struct gsl_monte_vegas_params {
     pub alpha: f64,
     pub iterations: usize,
-    pub stage: ::std::os::raw::c_int,
-    pub mode: ::std::os::raw::c_int,
-    pub verbose: ::std::os::raw::c_int,
+    pub stage: ::core::ffi::c_int,
+    pub mode: ::core::ffi::c_int,
+    pub verbose: ::core::ffi::c_int,
     pub ostream: *mut FILE,
 }
 extern "C" {
@@ -27794,7 +27425,7 @@ extern "C" {
         x: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_airy_Ai(x: f64, mode: gsl_mode_t) -> f64;
@@ -27804,7 +27435,7 @@ extern "C" {
         x: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_airy_Bi(x: f64, mode: gsl_mode_t) -> f64;
@@ -27814,7 +27445,7 @@ extern "C" {
         x: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_airy_Ai_scaled(x: f64, mode: gsl_mode_t) -> f64;
@@ -27824,7 +27455,7 @@ extern "C" {
         x: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_airy_Bi_scaled(x: f64, mode: gsl_mode_t) -> f64;
@@ -27834,7 +27465,7 @@ extern "C" {
         x: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_airy_Ai_deriv(x: f64, mode: gsl_mode_t) -> f64;
@@ -27844,7 +27475,7 @@ extern "C" {
         x: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_airy_Bi_deriv(x: f64, mode: gsl_mode_t) -> f64;
@@ -27854,7 +27485,7 @@ extern "C" {
         x: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_airy_Ai_deriv_scaled(x: f64, mode: gsl_mode_t) -> f64;
@@ -27864,46 +27495,46 @@ extern "C" {
         x: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_airy_Bi_deriv_scaled(x: f64, mode: gsl_mode_t) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_airy_zero_Ai_e(
-        s: ::std::os::raw::c_uint,
+        s: ::core::ffi::c_uint,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_airy_zero_Ai(s: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_sf_airy_zero_Ai(s: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_airy_zero_Bi_e(
-        s: ::std::os::raw::c_uint,
+        s: ::core::ffi::c_uint,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_airy_zero_Bi(s: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_sf_airy_zero_Bi(s: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_airy_zero_Ai_deriv_e(
-        s: ::std::os::raw::c_uint,
+        s: ::core::ffi::c_uint,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_airy_zero_Ai_deriv(s: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_sf_airy_zero_Ai_deriv(s: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_airy_zero_Bi_deriv_e(
-        s: ::std::os::raw::c_uint,
+        s: ::core::ffi::c_uint,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_airy_zero_Bi_deriv(s: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_sf_airy_zero_Bi_deriv(s: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_mean(data: *const f64, stride: usize, n: usize) -> f64;
@@ -28295,7 +27926,7 @@ extern "C" {
         stride: usize,
         n: usize,
         work: *mut f64,
-        work_int: *mut ::std::os::raw::c_int,
+        work_int: *mut ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
@@ -28306,7 +27937,7 @@ extern "C" {
         stride: usize,
         n: usize,
         work: *mut f64,
-        work_int: *mut ::std::os::raw::c_int,
+        work_int: *mut ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
@@ -28685,7 +28316,7 @@ extern "C" {
         stride: usize,
         n: usize,
         work: *mut f32,
-        work_int: *mut ::std::os::raw::c_int,
+        work_int: *mut ::core::ffi::c_int,
     ) -> f32;
 }
 extern "C" {
@@ -28694,26 +28325,25 @@ extern "C" {
         stride: usize,
         n: usize,
         work: *mut f32,
-        work_int: *mut ::std::os::raw::c_int,
+        work_int: *mut ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_uint_mean(data: *const ::std::os::raw::c_uint, stride: usize, n: usize)
-        -> f64;
+    pub fn gsl_stats_uint_mean(data: *const ::core::ffi::c_uint, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_variance(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_uint_sd(data: *const ::std::os::raw::c_uint, stride: usize, n: usize) -> f64;
+    pub fn gsl_stats_uint_sd(data: *const ::core::ffi::c_uint, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_variance_with_fixed_mean(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
         mean: f64,
@@ -28721,71 +28351,66 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uint_sd_with_fixed_mean(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
         mean: f64,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_uint_tss(data: *const ::std::os::raw::c_uint, stride: usize, n: usize) -> f64;
+    pub fn gsl_stats_uint_tss(data: *const ::core::ffi::c_uint, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_tss_m(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
         mean: f64,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_uint_absdev(
-        data: *const ::std::os::raw::c_uint,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_uint_absdev(data: *const ::core::ffi::c_uint, stride: usize, n: usize) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_uint_skew(data: *const ::std::os::raw::c_uint, stride: usize, n: usize)
-        -> f64;
+    pub fn gsl_stats_uint_skew(data: *const ::core::ffi::c_uint, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_kurtosis(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_lag1_autocorrelation(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_covariance(
-        data1: *const ::std::os::raw::c_uint,
+        data1: *const ::core::ffi::c_uint,
         stride1: usize,
-        data2: *const ::std::os::raw::c_uint,
+        data2: *const ::core::ffi::c_uint,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_correlation(
-        data1: *const ::std::os::raw::c_uint,
+        data1: *const ::core::ffi::c_uint,
         stride1: usize,
-        data2: *const ::std::os::raw::c_uint,
+        data2: *const ::core::ffi::c_uint,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_spearman(
-        data1: *const ::std::os::raw::c_uint,
+        data1: *const ::core::ffi::c_uint,
         stride1: usize,
-        data2: *const ::std::os::raw::c_uint,
+        data2: *const ::core::ffi::c_uint,
         stride2: usize,
         n: usize,
         work: *mut f64,
@@ -28793,7 +28418,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uint_variance_m(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
         mean: f64,
@@ -28801,7 +28426,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uint_sd_m(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
         mean: f64,
@@ -28809,7 +28434,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uint_absdev_m(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
         mean: f64,
@@ -28817,7 +28442,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uint_skew_m_sd(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
         mean: f64,
@@ -28826,7 +28451,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uint_kurtosis_m_sd(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
         mean: f64,
@@ -28835,7 +28460,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uint_lag1_autocorrelation_m(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
         mean: f64,
@@ -28843,9 +28468,9 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uint_covariance_m(
-        data1: *const ::std::os::raw::c_uint,
+        data1: *const ::core::ffi::c_uint,
         stride1: usize,
-        data2: *const ::std::os::raw::c_uint,
+        data2: *const ::core::ffi::c_uint,
         stride2: usize,
         n: usize,
         mean1: f64,
@@ -28854,57 +28479,57 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uint_pvariance(
-        data1: *const ::std::os::raw::c_uint,
+        data1: *const ::core::ffi::c_uint,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_uint,
+        data2: *const ::core::ffi::c_uint,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_ttest(
-        data1: *const ::std::os::raw::c_uint,
+        data1: *const ::core::ffi::c_uint,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_uint,
+        data2: *const ::core::ffi::c_uint,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_max(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_uint;
+    ) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_stats_uint_min(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_uint;
+    ) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_stats_uint_minmax(
-        min: *mut ::std::os::raw::c_uint,
-        max: *mut ::std::os::raw::c_uint,
-        data: *const ::std::os::raw::c_uint,
+        min: *mut ::core::ffi::c_uint,
+        max: *mut ::core::ffi::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_uint_max_index(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
     ) -> usize;
 }
 extern "C" {
     pub fn gsl_stats_uint_min_index(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
     ) -> usize;
@@ -28913,36 +28538,36 @@ extern "C" {
     pub fn gsl_stats_uint_minmax_index(
         min_index: *mut usize,
         max_index: *mut usize,
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_uint_select(
-        data: *mut ::std::os::raw::c_uint,
+        data: *mut ::core::ffi::c_uint,
         stride: usize,
         n: usize,
         k: usize,
-    ) -> ::std::os::raw::c_uint;
+    ) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_stats_uint_median_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_uint,
+        sorted_data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_median(
-        sorted_data: *mut ::std::os::raw::c_uint,
+        sorted_data: *mut ::core::ffi::c_uint,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_quantile_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_uint,
+        sorted_data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
         f: f64,
@@ -28951,21 +28576,21 @@ extern "C" {
 extern "C" {
     pub fn gsl_stats_uint_trmean_from_sorted_data(
         trim: f64,
-        sorted_data: *const ::std::os::raw::c_uint,
+        sorted_data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_gastwirth_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_uint,
+        sorted_data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_mad0(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -28973,7 +28598,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uint_mad(
-        data: *const ::std::os::raw::c_uint,
+        data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -28981,62 +28606,58 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uint_Sn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_uint,
+        sorted_data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_uint;
+        work: *mut ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_stats_uint_Sn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_uint,
+        sorted_data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_uint,
+        work: *mut ::core::ffi::c_uint,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uint_Qn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_uint,
+        sorted_data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_uint,
-        work_int: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_uint;
+        work: *mut ::core::ffi::c_uint,
+        work_int: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_stats_uint_Qn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_uint,
+        sorted_data: *const ::core::ffi::c_uint,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_uint,
-        work_int: *mut ::std::os::raw::c_int,
+        work: *mut ::core::ffi::c_uint,
+        work_int: *mut ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_mean(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_variance(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_ushort_sd(
-        data: *const ::std::os::raw::c_ushort,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_ushort_sd(data: *const ::core::ffi::c_ushort, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_variance_with_fixed_mean(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29044,22 +28665,19 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ushort_sd_with_fixed_mean(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
         mean: f64,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_ushort_tss(
-        data: *const ::std::os::raw::c_ushort,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_ushort_tss(data: *const ::core::ffi::c_ushort, stride: usize, n: usize)
+        -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_tss_m(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29067,55 +28685,55 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ushort_absdev(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_skew(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_kurtosis(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_lag1_autocorrelation(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_covariance(
-        data1: *const ::std::os::raw::c_ushort,
+        data1: *const ::core::ffi::c_ushort,
         stride1: usize,
-        data2: *const ::std::os::raw::c_ushort,
+        data2: *const ::core::ffi::c_ushort,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_correlation(
-        data1: *const ::std::os::raw::c_ushort,
+        data1: *const ::core::ffi::c_ushort,
         stride1: usize,
-        data2: *const ::std::os::raw::c_ushort,
+        data2: *const ::core::ffi::c_ushort,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_spearman(
-        data1: *const ::std::os::raw::c_ushort,
+        data1: *const ::core::ffi::c_ushort,
         stride1: usize,
-        data2: *const ::std::os::raw::c_ushort,
+        data2: *const ::core::ffi::c_ushort,
         stride2: usize,
         n: usize,
         work: *mut f64,
@@ -29123,7 +28741,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ushort_variance_m(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29131,7 +28749,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ushort_sd_m(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29139,7 +28757,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ushort_absdev_m(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29147,7 +28765,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ushort_skew_m_sd(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29156,7 +28774,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ushort_kurtosis_m_sd(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29165,7 +28783,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ushort_lag1_autocorrelation_m(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29173,9 +28791,9 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ushort_covariance_m(
-        data1: *const ::std::os::raw::c_ushort,
+        data1: *const ::core::ffi::c_ushort,
         stride1: usize,
-        data2: *const ::std::os::raw::c_ushort,
+        data2: *const ::core::ffi::c_ushort,
         stride2: usize,
         n: usize,
         mean1: f64,
@@ -29184,57 +28802,57 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ushort_pvariance(
-        data1: *const ::std::os::raw::c_ushort,
+        data1: *const ::core::ffi::c_ushort,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_ushort,
+        data2: *const ::core::ffi::c_ushort,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_ttest(
-        data1: *const ::std::os::raw::c_ushort,
+        data1: *const ::core::ffi::c_ushort,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_ushort,
+        data2: *const ::core::ffi::c_ushort,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_max(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_ushort;
+    ) -> ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn gsl_stats_ushort_min(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_ushort;
+    ) -> ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn gsl_stats_ushort_minmax(
-        min: *mut ::std::os::raw::c_ushort,
-        max: *mut ::std::os::raw::c_ushort,
-        data: *const ::std::os::raw::c_ushort,
+        min: *mut ::core::ffi::c_ushort,
+        max: *mut ::core::ffi::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_ushort_max_index(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     ) -> usize;
 }
 extern "C" {
     pub fn gsl_stats_ushort_min_index(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     ) -> usize;
@@ -29243,36 +28861,36 @@ extern "C" {
     pub fn gsl_stats_ushort_minmax_index(
         min_index: *mut usize,
         max_index: *mut usize,
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_ushort_select(
-        data: *mut ::std::os::raw::c_ushort,
+        data: *mut ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
         k: usize,
-    ) -> ::std::os::raw::c_ushort;
+    ) -> ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn gsl_stats_ushort_median_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_ushort,
+        sorted_data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_median(
-        sorted_data: *mut ::std::os::raw::c_ushort,
+        sorted_data: *mut ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_quantile_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_ushort,
+        sorted_data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
         f: f64,
@@ -29281,21 +28899,21 @@ extern "C" {
 extern "C" {
     pub fn gsl_stats_ushort_trmean_from_sorted_data(
         trim: f64,
-        sorted_data: *const ::std::os::raw::c_ushort,
+        sorted_data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_gastwirth_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_ushort,
+        sorted_data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_mad0(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -29303,7 +28921,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ushort_mad(
-        data: *const ::std::os::raw::c_ushort,
+        data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -29311,59 +28929,54 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_ushort_Sn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_ushort,
+        sorted_data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_ushort;
+        work: *mut ::core::ffi::c_ushort,
+    ) -> ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn gsl_stats_ushort_Sn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_ushort,
+        sorted_data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_ushort,
+        work: *mut ::core::ffi::c_ushort,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_ushort_Qn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_ushort,
+        sorted_data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_ushort,
-        work_int: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ushort;
+        work: *mut ::core::ffi::c_ushort,
+        work_int: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn gsl_stats_ushort_Qn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_ushort,
+        sorted_data: *const ::core::ffi::c_ushort,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_ushort,
-        work_int: *mut ::std::os::raw::c_int,
+        work: *mut ::core::ffi::c_ushort,
+        work_int: *mut ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_uchar_mean(
-        data: *const ::std::os::raw::c_uchar,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_uchar_mean(data: *const ::core::ffi::c_uchar, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_variance(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_uchar_sd(data: *const ::std::os::raw::c_uchar, stride: usize, n: usize)
-        -> f64;
+    pub fn gsl_stats_uchar_sd(data: *const ::core::ffi::c_uchar, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_variance_with_fixed_mean(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29371,22 +28984,18 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uchar_sd_with_fixed_mean(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
         mean: f64,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_uchar_tss(
-        data: *const ::std::os::raw::c_uchar,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_uchar_tss(data: *const ::core::ffi::c_uchar, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_tss_m(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29394,55 +29003,51 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uchar_absdev(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
-    pub fn gsl_stats_uchar_skew(
-        data: *const ::std::os::raw::c_uchar,
-        stride: usize,
-        n: usize,
-    ) -> f64;
+    pub fn gsl_stats_uchar_skew(data: *const ::core::ffi::c_uchar, stride: usize, n: usize) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_kurtosis(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_lag1_autocorrelation(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_covariance(
-        data1: *const ::std::os::raw::c_uchar,
+        data1: *const ::core::ffi::c_uchar,
         stride1: usize,
-        data2: *const ::std::os::raw::c_uchar,
+        data2: *const ::core::ffi::c_uchar,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_correlation(
-        data1: *const ::std::os::raw::c_uchar,
+        data1: *const ::core::ffi::c_uchar,
         stride1: usize,
-        data2: *const ::std::os::raw::c_uchar,
+        data2: *const ::core::ffi::c_uchar,
         stride2: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_spearman(
-        data1: *const ::std::os::raw::c_uchar,
+        data1: *const ::core::ffi::c_uchar,
         stride1: usize,
-        data2: *const ::std::os::raw::c_uchar,
+        data2: *const ::core::ffi::c_uchar,
         stride2: usize,
         n: usize,
         work: *mut f64,
@@ -29450,7 +29055,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uchar_variance_m(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29458,7 +29063,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uchar_sd_m(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29466,7 +29071,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uchar_absdev_m(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29474,7 +29079,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uchar_skew_m_sd(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29483,7 +29088,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uchar_kurtosis_m_sd(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29492,7 +29097,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uchar_lag1_autocorrelation_m(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
         mean: f64,
@@ -29500,9 +29105,9 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uchar_covariance_m(
-        data1: *const ::std::os::raw::c_uchar,
+        data1: *const ::core::ffi::c_uchar,
         stride1: usize,
-        data2: *const ::std::os::raw::c_uchar,
+        data2: *const ::core::ffi::c_uchar,
         stride2: usize,
         n: usize,
         mean1: f64,
@@ -29511,57 +29116,57 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uchar_pvariance(
-        data1: *const ::std::os::raw::c_uchar,
+        data1: *const ::core::ffi::c_uchar,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_uchar,
+        data2: *const ::core::ffi::c_uchar,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_ttest(
-        data1: *const ::std::os::raw::c_uchar,
+        data1: *const ::core::ffi::c_uchar,
         stride1: usize,
         n1: usize,
-        data2: *const ::std::os::raw::c_uchar,
+        data2: *const ::core::ffi::c_uchar,
         stride2: usize,
         n2: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_max(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_uchar;
+    ) -> ::core::ffi::c_uchar;
 }
 extern "C" {
     pub fn gsl_stats_uchar_min(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_uchar;
+    ) -> ::core::ffi::c_uchar;
 }
 extern "C" {
     pub fn gsl_stats_uchar_minmax(
-        min: *mut ::std::os::raw::c_uchar,
-        max: *mut ::std::os::raw::c_uchar,
-        data: *const ::std::os::raw::c_uchar,
+        min: *mut ::core::ffi::c_uchar,
+        max: *mut ::core::ffi::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_uchar_max_index(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     ) -> usize;
 }
 extern "C" {
     pub fn gsl_stats_uchar_min_index(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     ) -> usize;
@@ -29570,36 +29175,36 @@ extern "C" {
     pub fn gsl_stats_uchar_minmax_index(
         min_index: *mut usize,
         max_index: *mut usize,
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     );
 }
 extern "C" {
     pub fn gsl_stats_uchar_select(
-        data: *mut ::std::os::raw::c_uchar,
+        data: *mut ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
         k: usize,
-    ) -> ::std::os::raw::c_uchar;
+    ) -> ::core::ffi::c_uchar;
 }
 extern "C" {
     pub fn gsl_stats_uchar_median_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_uchar,
+        sorted_data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_median(
-        sorted_data: *mut ::std::os::raw::c_uchar,
+        sorted_data: *mut ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_quantile_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_uchar,
+        sorted_data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
         f: f64,
@@ -29608,21 +29213,21 @@ extern "C" {
 extern "C" {
     pub fn gsl_stats_uchar_trmean_from_sorted_data(
         trim: f64,
-        sorted_data: *const ::std::os::raw::c_uchar,
+        sorted_data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_gastwirth_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_uchar,
+        sorted_data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_mad0(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -29630,7 +29235,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uchar_mad(
-        data: *const ::std::os::raw::c_uchar,
+        data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
         work: *mut f64,
@@ -29638,36 +29243,36 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_stats_uchar_Sn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_uchar,
+        sorted_data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_uchar,
-    ) -> ::std::os::raw::c_uchar;
+        work: *mut ::core::ffi::c_uchar,
+    ) -> ::core::ffi::c_uchar;
 }
 extern "C" {
     pub fn gsl_stats_uchar_Sn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_uchar,
+        sorted_data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_uchar,
+        work: *mut ::core::ffi::c_uchar,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_stats_uchar_Qn0_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_uchar,
+        sorted_data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_uchar,
-        work_int: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_uchar;
+        work: *mut ::core::ffi::c_uchar,
+        work_int: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_uchar;
 }
 extern "C" {
     pub fn gsl_stats_uchar_Qn_from_sorted_data(
-        sorted_data: *const ::std::os::raw::c_uchar,
+        sorted_data: *const ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
-        work: *mut ::std::os::raw::c_uchar,
-        work_int: *mut ::std::os::raw::c_int,
+        work: *mut ::core::ffi::c_uchar,
+        work_int: *mut ::core::ffi::c_int,
     ) -> f64;
 }
 extern "C" {
@@ -29676,7 +29281,7 @@ extern "C" {
         data: *mut u128,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_complex_long_double_inverse(
@@ -29684,7 +29289,7 @@ extern "C" {
         data: *mut u128,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_diff_central(
@@ -29692,7 +29297,7 @@ extern "C" {
         x: f64,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_diff_backward(
@@ -29700,7 +29305,7 @@ extern "C" {
         x: f64,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_diff_forward(
@@ -29708,54 +29313,54 @@ extern "C" {
         x: f64,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_char(
         p: *const usize,
-        data: *mut ::std::os::raw::c_char,
+        data: *mut ::core::ffi::c_char,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_char_inverse(
         p: *const usize,
-        data: *mut ::std::os::raw::c_char,
+        data: *mut ::core::ffi::c_char,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_long(
         p: *const usize,
-        data: *mut ::std::os::raw::c_long,
+        data: *mut ::core::ffi::c_long,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_long_inverse(
         p: *const usize,
-        data: *mut ::std::os::raw::c_long,
+        data: *mut ::core::ffi::c_long,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_poly_eval(c: *const f64, len: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_poly_eval(c: *const f64, len: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_poly_complex_eval(
         c: *const f64,
-        len: ::std::os::raw::c_int,
+        len: ::core::ffi::c_int,
         z: gsl_complex,
     ) -> gsl_complex;
 }
 extern "C" {
     pub fn gsl_complex_poly_complex_eval(
         c: *const gsl_complex,
-        len: ::std::os::raw::c_int,
+        len: ::core::ffi::c_int,
         z: gsl_complex,
     ) -> gsl_complex;
 }
@@ -29766,7 +29371,7 @@ extern "C" {
         x: f64,
         res: *mut f64,
         lenres: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_poly_dd_init(
@@ -29774,7 +29379,7 @@ extern "C" {
         x: *const f64,
         y: *const f64,
         size: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_poly_dd_eval(dd: *const f64, xa: *const f64, size: usize, x: f64) -> f64;
@@ -29787,7 +29392,7 @@ extern "C" {
         x: *const f64,
         size: usize,
         w: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_poly_dd_hermite_init(
@@ -29797,7 +29402,7 @@ extern "C" {
         ya: *const f64,
         dya: *const f64,
         size: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_poly_solve_quadratic(
@@ -29806,7 +29411,7 @@ extern "C" {
         c: f64,
         x0: *mut f64,
         x1: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_poly_complex_solve_quadratic(
@@ -29815,7 +29420,7 @@ extern "C" {
         c: f64,
         z0: *mut gsl_complex,
         z1: *mut gsl_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_poly_solve_cubic(
@@ -29825,7 +29430,7 @@ extern "C" {
         x0: *mut f64,
         x1: *mut f64,
         x2: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_poly_complex_solve_cubic(
@@ -29835,7 +29440,7 @@ extern "C" {
         z0: *mut gsl_complex,
         z1: *mut gsl_complex,
         z2: *mut gsl_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -29852,75 +29457,67 @@ extern "C" {
         n: usize,
         w: *mut gsl_poly_complex_workspace,
         z: gsl_complex_packed_ptr,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hydrogenicR_1_e(
-        Z: f64,
-        r: f64,
-        result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_hydrogenicR_1_e(Z: f64, r: f64, result: *mut gsl_sf_result)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hydrogenicR_1(Z: f64, r: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hydrogenicR_e(
-        n: ::std::os::raw::c_int,
-        l: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
+        l: ::core::ffi::c_int,
         Z: f64,
         r: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hydrogenicR(
-        n: ::std::os::raw::c_int,
-        l: ::std::os::raw::c_int,
-        Z: f64,
-        r: f64,
-    ) -> f64;
+    pub fn gsl_sf_hydrogenicR(n: ::core::ffi::c_int, l: ::core::ffi::c_int, Z: f64, r: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_coulomb_wave_FG_e(
         eta: f64,
         x: f64,
         lam_F: f64,
-        k_lam_G: ::std::os::raw::c_int,
+        k_lam_G: ::core::ffi::c_int,
         F: *mut gsl_sf_result,
         Fp: *mut gsl_sf_result,
         G: *mut gsl_sf_result,
         Gp: *mut gsl_sf_result,
         exp_F: *mut f64,
         exp_G: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_coulomb_wave_F_array(
         lam_min: f64,
-        kmax: ::std::os::raw::c_int,
+        kmax: ::core::ffi::c_int,
         eta: f64,
         x: f64,
         fc_array: *mut f64,
         F_exponent: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_coulomb_wave_FG_array(
         lam_min: f64,
-        kmax: ::std::os::raw::c_int,
+        kmax: ::core::ffi::c_int,
         eta: f64,
         x: f64,
         fc_array: *mut f64,
         gc_array: *mut f64,
         F_exponent: *mut f64,
         G_exponent: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_coulomb_wave_FGp_array(
         lam_min: f64,
-        kmax: ::std::os::raw::c_int,
+        kmax: ::core::ffi::c_int,
         eta: f64,
         x: f64,
         fc_array: *mut f64,
@@ -29929,77 +29526,73 @@ extern "C" {
         gcp_array: *mut f64,
         F_exponent: *mut f64,
         G_exponent: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_coulomb_wave_sphF_array(
         lam_min: f64,
-        kmax: ::std::os::raw::c_int,
+        kmax: ::core::ffi::c_int,
         eta: f64,
         x: f64,
         fc_array: *mut f64,
         F_exponent: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_coulomb_CL_e(
-        L: f64,
-        eta: f64,
-        result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_coulomb_CL_e(L: f64, eta: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_coulomb_CL_array(
         Lmin: f64,
-        kmax: ::std::os::raw::c_int,
+        kmax: ::core::ffi::c_int,
         eta: f64,
         cl: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_dawson_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_dawson_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_dawson(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_debye_1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_debye_1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_debye_1(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_debye_2_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_debye_2_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_debye_2(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_debye_3_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_debye_3_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_debye_3(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_debye_4_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_debye_4_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_debye_4(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_debye_5_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_debye_5_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_debye_5(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_debye_6_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_debye_6_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_debye_6(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_dilog_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_dilog_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_dilog(x: f64) -> f64;
@@ -30010,7 +29603,7 @@ extern "C" {
         y: f64,
         result_re: *mut gsl_sf_result,
         result_im: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_complex_dilog_e(
@@ -30018,7 +29611,7 @@ extern "C" {
         theta: f64,
         result_re: *mut gsl_sf_result,
         result_im: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_complex_spence_xy_e(
@@ -30026,14 +29619,14 @@ extern "C" {
         y: f64,
         real_sp: *mut gsl_sf_result,
         imag_sp: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_ellint_Kcomp_e(
         k: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_ellint_Kcomp(k: f64, mode: gsl_mode_t) -> f64;
@@ -30043,7 +29636,7 @@ extern "C" {
         k: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_ellint_Ecomp(k: f64, mode: gsl_mode_t) -> f64;
@@ -30054,7 +29647,7 @@ extern "C" {
         n: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_ellint_Pcomp(k: f64, n: f64, mode: gsl_mode_t) -> f64;
@@ -30064,7 +29657,7 @@ extern "C" {
         k: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_ellint_Dcomp(k: f64, mode: gsl_mode_t) -> f64;
@@ -30075,7 +29668,7 @@ extern "C" {
         k: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_ellint_F(phi: f64, k: f64, mode: gsl_mode_t) -> f64;
@@ -30086,7 +29679,7 @@ extern "C" {
         k: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_ellint_E(phi: f64, k: f64, mode: gsl_mode_t) -> f64;
@@ -30098,7 +29691,7 @@ extern "C" {
         n: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_ellint_P(phi: f64, k: f64, n: f64, mode: gsl_mode_t) -> f64;
@@ -30109,7 +29702,7 @@ extern "C" {
         k: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_ellint_D(phi: f64, k: f64, mode: gsl_mode_t) -> f64;
@@ -30120,7 +29713,7 @@ extern "C" {
         y: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_ellint_RC(x: f64, y: f64, mode: gsl_mode_t) -> f64;
@@ -30132,7 +29725,7 @@ extern "C" {
         z: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_ellint_RD(x: f64, y: f64, z: f64, mode: gsl_mode_t) -> f64;
@@ -30144,7 +29737,7 @@ extern "C" {
         z: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_ellint_RF(x: f64, y: f64, z: f64, mode: gsl_mode_t) -> f64;
@@ -30157,34 +29750,34 @@ extern "C" {
         p: f64,
         mode: gsl_mode_t,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_ellint_RJ(x: f64, y: f64, z: f64, p: f64, mode: gsl_mode_t) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_erfc_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_erfc_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_erfc(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_log_erfc_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_log_erfc_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_log_erfc(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_erf_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_erf_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_erf(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_erf_Z_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_erf_Z_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_erf_Q_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_erf_Q_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_erf_Z(x: f64) -> f64;
@@ -30193,22 +29786,22 @@ extern "C" {
     pub fn gsl_sf_erf_Q(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_hazard_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_hazard_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hazard(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_exp_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_exp_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_exp(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_exp_e10_e(x: f64, result: *mut gsl_sf_result_e10) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_exp_e10_e(x: f64, result: *mut gsl_sf_result_e10) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_exp_mult_e(x: f64, y: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_exp_mult_e(x: f64, y: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_exp_mult(x: f64, y: f64) -> f64;
@@ -30218,52 +29811,48 @@ extern "C" {
         x: f64,
         y: f64,
         result: *mut gsl_sf_result_e10,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_expm1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_expm1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_expm1(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_exprel_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_exprel_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_exprel(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_exprel_2_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_exprel_2_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_exprel_2(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_exprel_n_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_exprel_n(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_exprel_n(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_exprel_n_CF_e(
-        n: f64,
-        x: f64,
-        result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_exprel_n_CF_e(n: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_exp_err_e(x: f64, dx: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_exp_err_e(x: f64, dx: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_exp_err_e10_e(
         x: f64,
         dx: f64,
         result: *mut gsl_sf_result_e10,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_exp_mult_err_e(
@@ -30272,7 +29861,7 @@ extern "C" {
         y: f64,
         dy: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_exp_mult_err_e10_e(
@@ -30281,56 +29870,56 @@ extern "C" {
         y: f64,
         dy: f64,
         result: *mut gsl_sf_result_e10,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_fermi_dirac_m1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_fermi_dirac_m1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_fermi_dirac_m1(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_fermi_dirac_0_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_fermi_dirac_0_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_fermi_dirac_0(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_fermi_dirac_1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_fermi_dirac_1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_fermi_dirac_1(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_fermi_dirac_2_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_fermi_dirac_2_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_fermi_dirac_2(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_fermi_dirac_int_e(
-        j: ::std::os::raw::c_int,
+        j: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_fermi_dirac_int(j: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_fermi_dirac_int(j: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_fermi_dirac_mhalf_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_fermi_dirac_mhalf_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_fermi_dirac_mhalf(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_fermi_dirac_half_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_fermi_dirac_half_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_fermi_dirac_half(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_fermi_dirac_3half_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_fermi_dirac_3half_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_fermi_dirac_3half(x: f64) -> f64;
@@ -30340,319 +29929,300 @@ extern "C" {
         x: f64,
         b: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_fermi_dirac_inc_0(x: f64, b: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_prob_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_prob(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_hermite_prob(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_prob_deriv_e(
-        m: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
+        m: ::core::ffi::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_prob_deriv(
-        m: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
-        x: f64,
-    ) -> f64;
+    pub fn gsl_sf_hermite_prob_deriv(m: ::core::ffi::c_int, n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_hermite(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_deriv_e(
-        m: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
+        m: ::core::ffi::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_deriv(m: ::std::os::raw::c_int, n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_hermite_deriv(m: ::core::ffi::c_int, n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_func_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_func(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_hermite_func(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_func_fast_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_func_fast(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_hermite_func_fast(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_prob_array(
-        nmax: ::std::os::raw::c_int,
+        nmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hermite_prob_array_deriv(
-        m: ::std::os::raw::c_int,
-        nmax: ::std::os::raw::c_int,
+        m: ::core::ffi::c_int,
+        nmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hermite_prob_deriv_array(
-        mmax: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
+        mmax: ::core::ffi::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hermite_prob_series_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         a: *const f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_prob_series(n: ::std::os::raw::c_int, x: f64, a: *const f64) -> f64;
+    pub fn gsl_sf_hermite_prob_series(n: ::core::ffi::c_int, x: f64, a: *const f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_array(
-        nmax: ::std::os::raw::c_int,
+        nmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hermite_array_deriv(
-        m: ::std::os::raw::c_int,
-        nmax: ::std::os::raw::c_int,
+        m: ::core::ffi::c_int,
+        nmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hermite_deriv_array(
-        mmax: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
+        mmax: ::core::ffi::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hermite_series_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         a: *const f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_series(n: ::std::os::raw::c_int, x: f64, a: *const f64) -> f64;
+    pub fn gsl_sf_hermite_series(n: ::core::ffi::c_int, x: f64, a: *const f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_func_array(
-        nmax: ::std::os::raw::c_int,
+        nmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hermite_func_series_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         a: *const f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_func_series(n: ::std::os::raw::c_int, x: f64, a: *const f64) -> f64;
+    pub fn gsl_sf_hermite_func_series(n: ::core::ffi::c_int, x: f64, a: *const f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_func_der_e(
-        m: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
+        m: ::core::ffi::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_func_der(
-        m: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
-        x: f64,
-    ) -> f64;
+    pub fn gsl_sf_hermite_func_der(m: ::core::ffi::c_int, n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_prob_zero_e(
-        n: ::std::os::raw::c_int,
-        s: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
+        s: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_prob_zero(n: ::std::os::raw::c_int, s: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_sf_hermite_prob_zero(n: ::core::ffi::c_int, s: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_zero_e(
-        n: ::std::os::raw::c_int,
-        s: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
+        s: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_zero(n: ::std::os::raw::c_int, s: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_sf_hermite_zero(n: ::core::ffi::c_int, s: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_func_zero_e(
-        n: ::std::os::raw::c_int,
-        s: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
+        s: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_func_zero(n: ::std::os::raw::c_int, s: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_sf_hermite_func_zero(n: ::core::ffi::c_int, s: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_phys_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_phys(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_hermite_phys(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_phys_der_e(
-        m: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
+        m: ::core::ffi::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_phys_der(
-        m: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
-        x: f64,
-    ) -> f64;
+    pub fn gsl_sf_hermite_phys_der(m: ::core::ffi::c_int, n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_phys_array(
-        nmax: ::std::os::raw::c_int,
+        nmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hermite_phys_series_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         a: *const f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_phys_series(n: ::std::os::raw::c_int, x: f64, a: *const f64) -> f64;
+    pub fn gsl_sf_hermite_phys_series(n: ::core::ffi::c_int, x: f64, a: *const f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_phys_array_der(
-        m: ::std::os::raw::c_int,
-        nmax: ::std::os::raw::c_int,
+        m: ::core::ffi::c_int,
+        nmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hermite_phys_der_array(
-        mmax: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
+        mmax: ::core::ffi::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hermite_phys_zero_e(
-        n: ::std::os::raw::c_int,
-        s: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
+        s: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_phys_zero(n: ::std::os::raw::c_int, s: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_sf_hermite_phys_zero(n: ::core::ffi::c_int, s: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_hermite_prob_array_der(
-        m: ::std::os::raw::c_int,
-        nmax: ::std::os::raw::c_int,
+        m: ::core::ffi::c_int,
+        nmax: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hermite_prob_der_array(
-        mmax: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
+        mmax: ::core::ffi::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hermite_prob_der_e(
-        m: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
+        m: ::core::ffi::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_hermite_prob_der(
-        m: ::std::os::raw::c_int,
-        n: ::std::os::raw::c_int,
-        x: f64,
-    ) -> f64;
+    pub fn gsl_sf_hermite_prob_der(m: ::core::ffi::c_int, n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_laguerre_1_e(a: f64, x: f64, result: *mut gsl_sf_result)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_sf_laguerre_1_e(a: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_laguerre_2_e(a: f64, x: f64, result: *mut gsl_sf_result)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_sf_laguerre_2_e(a: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_laguerre_3_e(a: f64, x: f64, result: *mut gsl_sf_result)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_sf_laguerre_3_e(a: f64, x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_laguerre_1(a: f64, x: f64) -> f64;
@@ -30665,23 +30235,23 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_sf_laguerre_n_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         a: f64,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_laguerre_n(n: ::std::os::raw::c_int, a: f64, x: f64) -> f64;
+    pub fn gsl_sf_laguerre_n(n: ::core::ffi::c_int, a: f64, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_lambert_W0_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_lambert_W0_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_lambert_W0(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_lambert_Wm1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_lambert_Wm1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_lambert_Wm1(x: f64) -> f64;
@@ -30700,7 +30270,7 @@ extern "C" {
         A: *mut gsl_matrix,
         eval: *mut gsl_vector,
         w: *mut gsl_eigen_symm_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -30717,7 +30287,7 @@ extern "C" {
         eval: *mut gsl_vector,
         evec: *mut gsl_matrix,
         w: *mut gsl_eigen_symmv_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -30733,7 +30303,7 @@ extern "C" {
         A: *mut gsl_matrix_complex,
         eval: *mut gsl_vector,
         w: *mut gsl_eigen_herm_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -30750,7 +30320,7 @@ extern "C" {
         eval: *mut gsl_vector,
         evec: *mut gsl_matrix_complex,
         w: *mut gsl_eigen_hermv_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -30762,17 +30332,14 @@ extern "C" {
     pub fn gsl_eigen_francis_free(w: *mut gsl_eigen_francis_workspace);
 }
 extern "C" {
-    pub fn gsl_eigen_francis_T(
-        compute_t: ::std::os::raw::c_int,
-        w: *mut gsl_eigen_francis_workspace,
-    );
+    pub fn gsl_eigen_francis_T(compute_t: ::core::ffi::c_int, w: *mut gsl_eigen_francis_workspace);
 }
 extern "C" {
     pub fn gsl_eigen_francis(
         H: *mut gsl_matrix,
         eval: *mut gsl_vector_complex,
         w: *mut gsl_eigen_francis_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_eigen_francis_Z(
@@ -30780,7 +30347,7 @@ extern "C" {
         eval: *mut gsl_vector_complex,
         Z: *mut gsl_matrix,
         w: *mut gsl_eigen_francis_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -30789,7 +30356,7 @@ pub struct gsl_eigen_nonsymm_workspace {
     pub diag: *mut gsl_vector,
     pub tau: *mut gsl_vector,
     pub Z: *mut gsl_matrix,
-    pub do_balance: ::std::os::raw::c_int,
+    pub do_balance: ::core::ffi::c_int,
     pub n_evals: usize,
     pub francis_workspace_p: *mut gsl_eigen_francis_workspace,
 }
@@ -30801,8 +30368,8 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_eigen_nonsymm_params(
-        compute_t: ::std::os::raw::c_int,
-        balance: ::std::os::raw::c_int,
+        compute_t: ::core::ffi::c_int,
+        balance: ::core::ffi::c_int,
         w: *mut gsl_eigen_nonsymm_workspace,
     );
 }
@@ -30811,7 +30378,7 @@ extern "C" {
         A: *mut gsl_matrix,
         eval: *mut gsl_vector_complex,
         w: *mut gsl_eigen_nonsymm_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_eigen_nonsymm_Z(
@@ -30819,7 +30386,7 @@ extern "C" {
         eval: *mut gsl_vector_complex,
         Z: *mut gsl_matrix,
         w: *mut gsl_eigen_nonsymm_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -30832,7 +30399,7 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_eigen_nonsymmv_params(
-        balance: ::std::os::raw::c_int,
+        balance: ::core::ffi::c_int,
         w: *mut gsl_eigen_nonsymmv_workspace,
     );
 }
@@ -30842,7 +30409,7 @@ extern "C" {
         eval: *mut gsl_vector_complex,
         evec: *mut gsl_matrix_complex,
         w: *mut gsl_eigen_nonsymmv_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_eigen_nonsymmv_Z(
@@ -30851,7 +30418,7 @@ extern "C" {
         evec: *mut gsl_matrix_complex,
         Z: *mut gsl_matrix,
         w: *mut gsl_eigen_nonsymmv_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -30868,13 +30435,13 @@ extern "C" {
         B: *mut gsl_matrix,
         eval: *mut gsl_vector,
         w: *mut gsl_eigen_gensymm_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_eigen_gensymm_standardize(
         A: *mut gsl_matrix,
         B: *const gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -30892,7 +30459,7 @@ extern "C" {
         eval: *mut gsl_vector,
         evec: *mut gsl_matrix,
         w: *mut gsl_eigen_gensymmv_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -30909,13 +30476,13 @@ extern "C" {
         B: *mut gsl_matrix_complex,
         eval: *mut gsl_vector,
         w: *mut gsl_eigen_genherm_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_eigen_genherm_standardize(
         A: *mut gsl_matrix_complex,
         B: *const gsl_matrix_complex,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -30933,7 +30500,7 @@ extern "C" {
         eval: *mut gsl_vector,
         evec: *mut gsl_matrix_complex,
         w: *mut gsl_eigen_genhermv_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -30946,9 +30513,9 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_eigen_gen_params(
-        compute_s: ::std::os::raw::c_int,
-        compute_t: ::std::os::raw::c_int,
-        balance: ::std::os::raw::c_int,
+        compute_s: ::core::ffi::c_int,
+        compute_t: ::core::ffi::c_int,
+        balance: ::core::ffi::c_int,
         w: *mut gsl_eigen_gen_workspace,
     );
 }
@@ -30959,7 +30526,7 @@ extern "C" {
         alpha: *mut gsl_vector_complex,
         beta: *mut gsl_vector,
         w: *mut gsl_eigen_gen_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_eigen_gen_QZ(
@@ -30970,7 +30537,7 @@ extern "C" {
         Q: *mut gsl_matrix,
         Z: *mut gsl_matrix,
         w: *mut gsl_eigen_gen_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -30989,7 +30556,7 @@ extern "C" {
         beta: *mut gsl_vector,
         evec: *mut gsl_matrix_complex,
         w: *mut gsl_eigen_genv_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_eigen_genv_QZ(
@@ -31001,47 +30568,47 @@ extern "C" {
         Q: *mut gsl_matrix,
         Z: *mut gsl_matrix,
         w: *mut gsl_eigen_genv_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 pub const gsl_eigen_sort_t_GSL_EIGEN_SORT_VAL_ASC: gsl_eigen_sort_t = 0;
 pub const gsl_eigen_sort_t_GSL_EIGEN_SORT_VAL_DESC: gsl_eigen_sort_t = 1;
 pub const gsl_eigen_sort_t_GSL_EIGEN_SORT_ABS_ASC: gsl_eigen_sort_t = 2;
 pub const gsl_eigen_sort_t_GSL_EIGEN_SORT_ABS_DESC: gsl_eigen_sort_t = 3;
-pub type gsl_eigen_sort_t = ::std::os::raw::c_uint;
+pub type gsl_eigen_sort_t = ::core::ffi::c_uint;
 extern "C" {
     pub fn gsl_eigen_symmv_sort(
         eval: *mut gsl_vector,
         evec: *mut gsl_matrix,
         sort_type: gsl_eigen_sort_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_eigen_hermv_sort(
         eval: *mut gsl_vector,
         evec: *mut gsl_matrix_complex,
         sort_type: gsl_eigen_sort_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_eigen_nonsymmv_sort(
         eval: *mut gsl_vector_complex,
         evec: *mut gsl_matrix_complex,
         sort_type: gsl_eigen_sort_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_eigen_gensymmv_sort(
         eval: *mut gsl_vector,
         evec: *mut gsl_matrix,
         sort_type: gsl_eigen_sort_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_eigen_genhermv_sort(
         eval: *mut gsl_vector,
         evec: *mut gsl_matrix_complex,
         sort_type: gsl_eigen_sort_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_eigen_genv_sort(
@@ -31049,7 +30616,7 @@ extern "C" {
         beta: *mut gsl_vector,
         evec: *mut gsl_matrix_complex,
         sort_type: gsl_eigen_sort_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_schur_gen_eigvals(
@@ -31060,7 +30627,7 @@ extern "C" {
         wi: *mut f64,
         scale1: *mut f64,
         scale2: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_schur_solve_equation(
@@ -31074,7 +30641,7 @@ extern "C" {
         s: *mut f64,
         xnorm: *mut f64,
         smin: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_schur_solve_equation_z(
@@ -31088,80 +30655,80 @@ extern "C" {
         s: *mut f64,
         xnorm: *mut f64,
         smin: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_eigen_jacobi(
         matrix: *mut gsl_matrix,
         eval: *mut gsl_vector,
         evec: *mut gsl_matrix,
-        max_rot: ::std::os::raw::c_uint,
-        nrot: *mut ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_int;
+        max_rot: ::core::ffi::c_uint,
+        nrot: *mut ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_eigen_invert_jacobi(
         matrix: *const gsl_matrix,
         ainv: *mut gsl_matrix,
-        max_rot: ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_int;
+        max_rot: ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_sf_mathieu_workspace;
 extern "C" {
     pub fn gsl_sf_mathieu_a_array(
-        order_min: ::std::os::raw::c_int,
-        order_max: ::std::os::raw::c_int,
+        order_min: ::core::ffi::c_int,
+        order_max: ::core::ffi::c_int,
         qq: f64,
         work: *mut gsl_sf_mathieu_workspace,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_b_array(
-        order_min: ::std::os::raw::c_int,
-        order_max: ::std::os::raw::c_int,
+        order_min: ::core::ffi::c_int,
+        order_max: ::core::ffi::c_int,
         qq: f64,
         work: *mut gsl_sf_mathieu_workspace,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_a_e(
-        order: ::std::os::raw::c_int,
+        order: ::core::ffi::c_int,
         qq: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_mathieu_a(order: ::std::os::raw::c_int, qq: f64) -> f64;
+    pub fn gsl_sf_mathieu_a(order: ::core::ffi::c_int, qq: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_b_e(
-        order: ::std::os::raw::c_int,
+        order: ::core::ffi::c_int,
         qq: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_mathieu_b(order: ::std::os::raw::c_int, qq: f64) -> f64;
+    pub fn gsl_sf_mathieu_b(order: ::core::ffi::c_int, qq: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_a_coeff(
-        order: ::std::os::raw::c_int,
+        order: ::core::ffi::c_int,
         qq: f64,
         aa: f64,
         coeff: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_b_coeff(
-        order: ::std::os::raw::c_int,
+        order: ::core::ffi::c_int,
         qq: f64,
         aa: f64,
         coeff: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_alloc(nn: usize, qq: f64) -> *mut gsl_sf_mathieu_workspace;
@@ -31171,129 +30738,129 @@ extern "C" {
 }
 extern "C" {
     pub fn gsl_sf_mathieu_ce_e(
-        order: ::std::os::raw::c_int,
+        order: ::core::ffi::c_int,
         qq: f64,
         zz: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_mathieu_ce(order: ::std::os::raw::c_int, qq: f64, zz: f64) -> f64;
+    pub fn gsl_sf_mathieu_ce(order: ::core::ffi::c_int, qq: f64, zz: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_se_e(
-        order: ::std::os::raw::c_int,
+        order: ::core::ffi::c_int,
         qq: f64,
         zz: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_mathieu_se(order: ::std::os::raw::c_int, qq: f64, zz: f64) -> f64;
+    pub fn gsl_sf_mathieu_se(order: ::core::ffi::c_int, qq: f64, zz: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_ce_array(
-        nmin: ::std::os::raw::c_int,
-        nmax: ::std::os::raw::c_int,
+        nmin: ::core::ffi::c_int,
+        nmax: ::core::ffi::c_int,
         qq: f64,
         zz: f64,
         work: *mut gsl_sf_mathieu_workspace,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_se_array(
-        nmin: ::std::os::raw::c_int,
-        nmax: ::std::os::raw::c_int,
+        nmin: ::core::ffi::c_int,
+        nmax: ::core::ffi::c_int,
         qq: f64,
         zz: f64,
         work: *mut gsl_sf_mathieu_workspace,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_Mc_e(
-        kind: ::std::os::raw::c_int,
-        order: ::std::os::raw::c_int,
+        kind: ::core::ffi::c_int,
+        order: ::core::ffi::c_int,
         qq: f64,
         zz: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_Mc(
-        kind: ::std::os::raw::c_int,
-        order: ::std::os::raw::c_int,
+        kind: ::core::ffi::c_int,
+        order: ::core::ffi::c_int,
         qq: f64,
         zz: f64,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_Ms_e(
-        kind: ::std::os::raw::c_int,
-        order: ::std::os::raw::c_int,
+        kind: ::core::ffi::c_int,
+        order: ::core::ffi::c_int,
         qq: f64,
         zz: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_Ms(
-        kind: ::std::os::raw::c_int,
-        order: ::std::os::raw::c_int,
+        kind: ::core::ffi::c_int,
+        order: ::core::ffi::c_int,
         qq: f64,
         zz: f64,
     ) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_Mc_array(
-        kind: ::std::os::raw::c_int,
-        nmin: ::std::os::raw::c_int,
-        nmax: ::std::os::raw::c_int,
+        kind: ::core::ffi::c_int,
+        nmin: ::core::ffi::c_int,
+        nmax: ::core::ffi::c_int,
         qq: f64,
         zz: f64,
         work: *mut gsl_sf_mathieu_workspace,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_mathieu_Ms_array(
-        kind: ::std::os::raw::c_int,
-        nmin: ::std::os::raw::c_int,
-        nmax: ::std::os::raw::c_int,
+        kind: ::core::ffi::c_int,
+        nmin: ::core::ffi::c_int,
+        nmax: ::core::ffi::c_int,
         qq: f64,
         zz: f64,
         work: *mut gsl_sf_mathieu_workspace,
         result_array: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_pow_int_e(
         x: f64,
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_pow_int(x: f64, n: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_sf_pow_int(x: f64, n: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_psi_int_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_psi_int(n: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_sf_psi_int(n: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_psi_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_psi_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_psi(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_psi_1piy_e(y: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_psi_1piy_e(y: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_psi_1piy(y: f64) -> f64;
@@ -31304,116 +30871,116 @@ extern "C" {
         y: f64,
         result_re: *mut gsl_sf_result,
         result_im: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_psi_1_int_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_psi_1_int(n: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_sf_psi_1_int(n: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_psi_1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_psi_1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_psi_1(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_psi_n_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         x: f64,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_psi_n(n: ::std::os::raw::c_int, x: f64) -> f64;
+    pub fn gsl_sf_psi_n(n: ::core::ffi::c_int, x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_synchrotron_1_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_synchrotron_1_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_synchrotron_1(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_synchrotron_2_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_synchrotron_2_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_synchrotron_2(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_transport_2_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_transport_2_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_transport_2(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_transport_3_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_transport_3_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_transport_3(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_transport_4_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_transport_4_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_transport_4(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_transport_5_e(x: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_transport_5_e(x: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_transport_5(x: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_zeta_int_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_zeta_int(n: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_sf_zeta_int(n: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_zeta_e(s: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_zeta_e(s: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_zeta(s: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_zetam1_e(s: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_zetam1_e(s: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_zetam1(s: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_zetam1_int_e(
-        s: ::std::os::raw::c_int,
+        s: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_zetam1_int(s: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_sf_zetam1_int(s: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_hzeta_e(s: f64, q: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_hzeta_e(s: f64, q: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_hzeta(s: f64, q: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_sf_eta_int_e(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         result: *mut gsl_sf_result,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_sf_eta_int(n: ::std::os::raw::c_int) -> f64;
+    pub fn gsl_sf_eta_int(n: ::core::ffi::c_int) -> f64;
 }
 extern "C" {
-    pub fn gsl_sf_eta_e(s: f64, result: *mut gsl_sf_result) -> ::std::os::raw::c_int;
+    pub fn gsl_sf_eta_e(s: f64, result: *mut gsl_sf_result) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sf_eta(s: f64) -> f64;
@@ -31424,7 +30991,7 @@ extern "C" {
         data: *mut u128,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_long_double_inverse(
@@ -31432,14 +30999,14 @@ extern "C" {
         data: *mut u128,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_real_float_radix2_transform(
         data: *mut f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -31466,7 +31033,7 @@ extern "C" {
         n: usize,
         wavetable: *const gsl_fft_real_wavetable_float,
         work: *mut gsl_fft_real_workspace_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_real_float_unpack(
@@ -31474,28 +31041,28 @@ extern "C" {
         complex_coefficient: *mut f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_halfcomplex_float_radix2_backward(
         data: *mut f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_halfcomplex_float_radix2_inverse(
         data: *mut f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_halfcomplex_float_radix2_transform(
         data: *mut f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -31517,7 +31084,7 @@ extern "C" {
         n: usize,
         wavetable: *const gsl_fft_halfcomplex_wavetable_float,
         work: *mut gsl_fft_real_workspace_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_halfcomplex_float_inverse(
@@ -31526,7 +31093,7 @@ extern "C" {
         n: usize,
         wavetable: *const gsl_fft_halfcomplex_wavetable_float,
         work: *mut gsl_fft_real_workspace_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_halfcomplex_float_transform(
@@ -31535,7 +31102,7 @@ extern "C" {
         n: usize,
         wavetable: *const gsl_fft_halfcomplex_wavetable_float,
         work: *mut gsl_fft_real_workspace_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_halfcomplex_float_unpack(
@@ -31543,7 +31110,7 @@ extern "C" {
         complex_coefficient: *mut f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_halfcomplex_float_radix2_unpack(
@@ -31551,21 +31118,17 @@ extern "C" {
         complex_coefficient: *mut f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_test(
-        status: ::std::os::raw::c_int,
-        test_description: *const ::std::os::raw::c_char,
-        ...
-    );
+    pub fn gsl_test(status: ::core::ffi::c_int, test_description: *const ::core::ffi::c_char, ...);
 }
 extern "C" {
     pub fn gsl_test_rel(
         result: f64,
         expected: f64,
         relative_error: f64,
-        test_description: *const ::std::os::raw::c_char,
+        test_description: *const ::core::ffi::c_char,
         ...
     );
 }
@@ -31574,7 +31137,7 @@ extern "C" {
         result: f64,
         expected: f64,
         absolute_error: f64,
-        test_description: *const ::std::os::raw::c_char,
+        test_description: *const ::core::ffi::c_char,
         ...
     );
 }
@@ -31583,31 +31146,31 @@ extern "C" {
         result: f64,
         expected: f64,
         factor: f64,
-        test_description: *const ::std::os::raw::c_char,
+        test_description: *const ::core::ffi::c_char,
         ...
     );
 }
 extern "C" {
     pub fn gsl_test_int(
-        result: ::std::os::raw::c_int,
-        expected: ::std::os::raw::c_int,
-        test_description: *const ::std::os::raw::c_char,
+        result: ::core::ffi::c_int,
+        expected: ::core::ffi::c_int,
+        test_description: *const ::core::ffi::c_char,
         ...
     );
 }
 extern "C" {
     pub fn gsl_test_str(
-        result: *const ::std::os::raw::c_char,
-        expected: *const ::std::os::raw::c_char,
-        test_description: *const ::std::os::raw::c_char,
+        result: *const ::core::ffi::c_char,
+        expected: *const ::core::ffi::c_char,
+        test_description: *const ::core::ffi::c_char,
         ...
     );
 }
 extern "C" {
-    pub fn gsl_test_verbose(verbose: ::std::os::raw::c_int);
+    pub fn gsl_test_verbose(verbose: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn gsl_test_summary() -> ::std::os::raw::c_int;
+    pub fn gsl_test_summary() -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute(
@@ -31615,7 +31178,7 @@ extern "C" {
         data: *mut f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_inverse(
@@ -31623,7 +31186,7 @@ extern "C" {
         data: *mut f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -31653,8 +31216,8 @@ extern "C" {
     pub fn gsl_integration_qaws_table_alloc(
         alpha: f64,
         beta: f64,
-        mu: ::std::os::raw::c_int,
-        nu: ::std::os::raw::c_int,
+        mu: ::core::ffi::c_int,
+        nu: ::core::ffi::c_int,
     ) -> *mut gsl_integration_qaws_table;
 }
 extern "C" {
@@ -31662,16 +31225,16 @@ extern "C" {
         t: *mut gsl_integration_qaws_table,
         alpha: f64,
         beta: f64,
-        mu: ::std::os::raw::c_int,
-        nu: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        mu: ::core::ffi::c_int,
+        nu: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_integration_qaws_table_free(t: *mut gsl_integration_qaws_table);
 }
 pub const gsl_integration_qawo_enum_GSL_INTEG_COSINE: gsl_integration_qawo_enum = 0;
 pub const gsl_integration_qawo_enum_GSL_INTEG_SINE: gsl_integration_qawo_enum = 1;
-pub type gsl_integration_qawo_enum = ::std::os::raw::c_uint;
+pub type gsl_integration_qawo_enum = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_integration_qawo_table;
@@ -31689,18 +31252,18 @@ extern "C" {
         omega: f64,
         L: f64,
         sine: gsl_integration_qawo_enum,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_integration_qawo_table_set_length(
         t: *mut gsl_integration_qawo_table,
         L: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_integration_qawo_table_free(t: *mut gsl_integration_qawo_table);
 }
-pub type gsl_integration_rule = ::std::option::Option<
+pub type gsl_integration_rule = ::core::option::Option<
     unsafe extern "C" fn(
         f: *const gsl_function,
         a: f64,
@@ -31786,16 +31349,16 @@ extern "C" {
         cheb24: *mut f64,
     );
 }
-pub const GSL_INTEG_GAUSS15: ::std::os::raw::c_uint = 1;
-pub const GSL_INTEG_GAUSS21: ::std::os::raw::c_uint = 2;
-pub const GSL_INTEG_GAUSS31: ::std::os::raw::c_uint = 3;
-pub const GSL_INTEG_GAUSS41: ::std::os::raw::c_uint = 4;
-pub const GSL_INTEG_GAUSS51: ::std::os::raw::c_uint = 5;
-pub const GSL_INTEG_GAUSS61: ::std::os::raw::c_uint = 6;
-pub type _bindgen_ty_5 = ::std::os::raw::c_uint;
+pub const GSL_INTEG_GAUSS15: ::core::ffi::c_uint = 1;
+pub const GSL_INTEG_GAUSS21: ::core::ffi::c_uint = 2;
+pub const GSL_INTEG_GAUSS31: ::core::ffi::c_uint = 3;
+pub const GSL_INTEG_GAUSS41: ::core::ffi::c_uint = 4;
+pub const GSL_INTEG_GAUSS51: ::core::ffi::c_uint = 5;
+pub const GSL_INTEG_GAUSS61: ::core::ffi::c_uint = 6;
+pub type _bindgen_ty_5 = ::core::ffi::c_uint;
 extern "C" {
     pub fn gsl_integration_qk(
-        n: ::std::os::raw::c_int,
+        n: ::core::ffi::c_int,
         xgk: *const f64,
         wg: *const f64,
         wgk: *const f64,
@@ -31820,7 +31383,7 @@ extern "C" {
         result: *mut f64,
         abserr: *mut f64,
         neval: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_integration_qag(
@@ -31830,11 +31393,11 @@ extern "C" {
         epsabs: f64,
         epsrel: f64,
         limit: usize,
-        key: ::std::os::raw::c_int,
+        key: ::core::ffi::c_int,
         workspace: *mut gsl_integration_workspace,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_integration_qagi(
@@ -31845,7 +31408,7 @@ extern "C" {
         workspace: *mut gsl_integration_workspace,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_integration_qagiu(
@@ -31857,7 +31420,7 @@ extern "C" {
         workspace: *mut gsl_integration_workspace,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_integration_qagil(
@@ -31869,7 +31432,7 @@ extern "C" {
         workspace: *mut gsl_integration_workspace,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_integration_qags(
@@ -31882,7 +31445,7 @@ extern "C" {
         workspace: *mut gsl_integration_workspace,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_integration_qagp(
@@ -31895,7 +31458,7 @@ extern "C" {
         workspace: *mut gsl_integration_workspace,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_integration_qawc(
@@ -31909,7 +31472,7 @@ extern "C" {
         workspace: *mut gsl_integration_workspace,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_integration_qaws(
@@ -31923,7 +31486,7 @@ extern "C" {
         workspace: *mut gsl_integration_workspace,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_integration_qawo(
@@ -31936,7 +31499,7 @@ extern "C" {
         wf: *mut gsl_integration_qawo_table,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_integration_qawf(
@@ -31949,7 +31512,7 @@ extern "C" {
         wf: *mut gsl_integration_qawo_table,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -31976,7 +31539,7 @@ extern "C" {
         xi: *mut f64,
         wi: *mut f64,
         t: *const gsl_integration_glfixed_table,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -32001,7 +31564,7 @@ extern "C" {
         result: *mut f64,
         abserr: *mut f64,
         nevals: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -32030,7 +31593,7 @@ extern "C" {
         result: *mut f64,
         neval: *mut usize,
         w: *mut gsl_integration_romberg_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -32095,19 +31658,19 @@ extern "C" {
         func: *const gsl_function,
         result: *mut f64,
         w: *const gsl_integration_fixed_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_long_double(
         p: *const gsl_permutation,
         v: *mut gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_long_double_inverse(
         p: *const gsl_permutation,
         v: *mut gsl_vector_long_double,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_dft_complex_float_forward(
@@ -32115,7 +31678,7 @@ extern "C" {
         stride: usize,
         n: usize,
         result: *mut f32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_dft_complex_float_backward(
@@ -32123,7 +31686,7 @@ extern "C" {
         stride: usize,
         n: usize,
         result: *mut f32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_dft_complex_float_inverse(
@@ -32131,7 +31694,7 @@ extern "C" {
         stride: usize,
         n: usize,
         result: *mut f32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_dft_complex_float_transform(
@@ -32140,7 +31703,7 @@ extern "C" {
         n: usize,
         result: *mut f32,
         sign: gsl_fft_direction,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -32168,7 +31731,7 @@ extern "C" {
         w: *mut gsl_sum_levin_u_workspace,
         sum_accel: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sum_levin_u_minmax(
@@ -32179,7 +31742,7 @@ extern "C" {
         w: *mut gsl_sum_levin_u_workspace,
         sum_accel: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sum_levin_u_step(
@@ -32188,7 +31751,7 @@ extern "C" {
         nmax: usize,
         w: *mut gsl_sum_levin_u_workspace,
         sum_accel: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -32214,7 +31777,7 @@ extern "C" {
         w: *mut gsl_sum_levin_utrunc_workspace,
         sum_accel: *mut f64,
         abserr_trunc: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sum_levin_utrunc_minmax(
@@ -32225,7 +31788,7 @@ extern "C" {
         w: *mut gsl_sum_levin_utrunc_workspace,
         sum_accel: *mut f64,
         abserr_trunc: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_sum_levin_utrunc_step(
@@ -32233,28 +31796,28 @@ extern "C" {
         n: usize,
         w: *mut gsl_sum_levin_utrunc_workspace,
         sum_accel: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_radix2_forward(
         data: gsl_complex_packed_array,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_radix2_backward(
         data: gsl_complex_packed_array,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_radix2_inverse(
         data: gsl_complex_packed_array,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_radix2_transform(
@@ -32262,28 +31825,28 @@ extern "C" {
         stride: usize,
         n: usize,
         sign: gsl_fft_direction,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_radix2_dif_forward(
         data: gsl_complex_packed_array,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_radix2_dif_backward(
         data: gsl_complex_packed_array,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_radix2_dif_inverse(
         data: gsl_complex_packed_array,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_radix2_dif_transform(
@@ -32291,7 +31854,7 @@ extern "C" {
         stride: usize,
         n: usize,
         sign: gsl_fft_direction,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -32321,7 +31884,7 @@ extern "C" {
     pub fn gsl_fft_complex_memcpy(
         dest: *mut gsl_fft_complex_wavetable,
         src: *mut gsl_fft_complex_wavetable,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_forward(
@@ -32330,7 +31893,7 @@ extern "C" {
         n: usize,
         wavetable: *const gsl_fft_complex_wavetable,
         work: *mut gsl_fft_complex_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_backward(
@@ -32339,7 +31902,7 @@ extern "C" {
         n: usize,
         wavetable: *const gsl_fft_complex_wavetable,
         work: *mut gsl_fft_complex_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_inverse(
@@ -32348,7 +31911,7 @@ extern "C" {
         n: usize,
         wavetable: *const gsl_fft_complex_wavetable,
         work: *mut gsl_fft_complex_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_complex_transform(
@@ -32358,79 +31921,76 @@ extern "C" {
         wavetable: *const gsl_fft_complex_wavetable,
         work: *mut gsl_fft_complex_workspace,
         sign: gsl_fft_direction,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_complex_float(
         p: *const gsl_permutation,
         v: *mut gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_complex_float_inverse(
         p: *const gsl_permutation,
         v: *mut gsl_vector_complex_float,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_permute_vector(
-        p: *const gsl_permutation,
-        v: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_permute_vector(p: *const gsl_permutation, v: *mut gsl_vector) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_inverse(
         p: *const gsl_permutation,
         v: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_uint(
         p: *const gsl_permutation,
         v: *mut gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_uint_inverse(
         p: *const gsl_permutation,
         v: *mut gsl_vector_uint,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_int(
         p: *const gsl_permutation,
         v: *mut gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_int_inverse(
         p: *const gsl_permutation,
         v: *mut gsl_vector_int,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_short(
         p: *const gsl_permutation,
         v: *mut gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_short_inverse(
         p: *const gsl_permutation,
         v: *mut gsl_vector_short,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_uchar(
         p: *const gsl_permutation,
         v: *mut gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_vector_uchar_inverse(
         p: *const gsl_permutation,
         v: *mut gsl_vector_uchar,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -32451,7 +32011,7 @@ extern "C" {
         x_minimum: f64,
         x_lower: f64,
         x_upper: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_min_fminimizer_set_with_values(
@@ -32463,13 +32023,13 @@ extern "C" {
         f_lower: f64,
         x_upper: f64,
         f_upper: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_min_fminimizer_iterate(s: *mut gsl_min_fminimizer) -> ::std::os::raw::c_int;
+    pub fn gsl_min_fminimizer_iterate(s: *mut gsl_min_fminimizer) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_min_fminimizer_name(s: *const gsl_min_fminimizer) -> *const ::std::os::raw::c_char;
+    pub fn gsl_min_fminimizer_name(s: *const gsl_min_fminimizer) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_min_fminimizer_x_minimum(s: *const gsl_min_fminimizer) -> f64;
@@ -32498,7 +32058,7 @@ extern "C" {
         x_upper: f64,
         epsabs: f64,
         epsrel: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub static mut gsl_min_fminimizer_goldensection: *const gsl_min_fminimizer_type;
@@ -32509,7 +32069,7 @@ extern "C" {
 extern "C" {
     pub static mut gsl_min_fminimizer_quad_golden: *const gsl_min_fminimizer_type;
 }
-pub type gsl_min_bracketing_function = ::std::option::Option<
+pub type gsl_min_bracketing_function = ::core::option::Option<
     unsafe extern "C" fn(
         f: *mut gsl_function,
         x_minimum: *mut f64,
@@ -32519,7 +32079,7 @@ pub type gsl_min_bracketing_function = ::std::option::Option<
         x_upper: *mut f64,
         f_upper: *mut f64,
         eval_max: usize,
-    ) -> ::std::os::raw::c_int,
+    ) -> ::core::ffi::c_int,
 >;
 extern "C" {
     pub fn gsl_min_find_bracket(
@@ -32531,7 +32091,7 @@ extern "C" {
         x_upper: *mut f64,
         f_upper: *mut f64,
         eval_max: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -32559,11 +32119,8 @@ extern "C" {
     pub fn gsl_histogram2d_free(h: *mut gsl_histogram2d);
 }
 extern "C" {
-    pub fn gsl_histogram2d_increment(
-        h: *mut gsl_histogram2d,
-        x: f64,
-        y: f64,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_histogram2d_increment(h: *mut gsl_histogram2d, x: f64, y: f64)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_accumulate(
@@ -32571,7 +32128,7 @@ extern "C" {
         x: f64,
         y: f64,
         weight: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_find(
@@ -32580,7 +32137,7 @@ extern "C" {
         y: f64,
         i: *mut usize,
         j: *mut usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_get(h: *const gsl_histogram2d, i: usize, j: usize) -> f64;
@@ -32591,7 +32148,7 @@ extern "C" {
         i: usize,
         xlower: *mut f64,
         xupper: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_get_yrange(
@@ -32599,7 +32156,7 @@ extern "C" {
         j: usize,
         ylower: *mut f64,
         yupper: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_xmax(h: *const gsl_histogram2d) -> f64;
@@ -32637,7 +32194,7 @@ extern "C" {
         xmax: f64,
         ymin: f64,
         ymax: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_set_ranges(
@@ -32646,13 +32203,13 @@ extern "C" {
         xsize: usize,
         yrange: *const f64,
         ysize: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_memcpy(
         dest: *mut gsl_histogram2d,
         source: *const gsl_histogram2d,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_clone(source: *const gsl_histogram2d) -> *mut gsl_histogram2d;
@@ -32691,63 +32248,58 @@ extern "C" {
     pub fn gsl_histogram2d_equal_bins_p(
         h1: *const gsl_histogram2d,
         h2: *const gsl_histogram2d,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_add(
         h1: *mut gsl_histogram2d,
         h2: *const gsl_histogram2d,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_sub(
         h1: *mut gsl_histogram2d,
         h2: *const gsl_histogram2d,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_mul(
         h1: *mut gsl_histogram2d,
         h2: *const gsl_histogram2d,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_div(
         h1: *mut gsl_histogram2d,
         h2: *const gsl_histogram2d,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_histogram2d_scale(h: *mut gsl_histogram2d, scale: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_histogram2d_scale(h: *mut gsl_histogram2d, scale: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_histogram2d_shift(h: *mut gsl_histogram2d, shift: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_histogram2d_shift(h: *mut gsl_histogram2d, shift: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_fwrite(
         stream: *mut FILE,
         h: *const gsl_histogram2d,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_histogram2d_fread(
-        stream: *mut FILE,
-        h: *mut gsl_histogram2d,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_histogram2d_fread(stream: *mut FILE, h: *mut gsl_histogram2d) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_fprintf(
         stream: *mut FILE,
         h: *const gsl_histogram2d,
-        range_format: *const ::std::os::raw::c_char,
-        bin_format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        range_format: *const ::core::ffi::c_char,
+        bin_format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_histogram2d_fscanf(
-        stream: *mut FILE,
-        h: *mut gsl_histogram2d,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_histogram2d_fscanf(stream: *mut FILE, h: *mut gsl_histogram2d)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_pdf_alloc(nx: usize, ny: usize) -> *mut gsl_histogram2d_pdf;
@@ -32756,7 +32308,7 @@ extern "C" {
     pub fn gsl_histogram2d_pdf_init(
         p: *mut gsl_histogram2d_pdf,
         h: *const gsl_histogram2d,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_histogram2d_pdf_free(p: *mut gsl_histogram2d_pdf);
@@ -32768,13 +32320,13 @@ extern "C" {
         r2: f64,
         x: *mut f64,
         y: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_ran_bernoulli(r: *const gsl_rng, p: f64) -> ::std::os::raw::c_uint;
+    pub fn gsl_ran_bernoulli(r: *const gsl_rng, p: f64) -> ::core::ffi::c_uint;
 }
 extern "C" {
-    pub fn gsl_ran_bernoulli_pdf(k: ::std::os::raw::c_uint, p: f64) -> f64;
+    pub fn gsl_ran_bernoulli_pdf(k: ::core::ffi::c_uint, p: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_ran_beta(r: *const gsl_rng, a: f64, b: f64) -> f64;
@@ -32786,29 +32338,25 @@ extern "C" {
     pub fn gsl_ran_binomial(
         r: *const gsl_rng,
         p: f64,
-        n: ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_uint;
+        n: ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_ran_binomial_knuth(
         r: *const gsl_rng,
         p: f64,
-        n: ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_uint;
+        n: ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_ran_binomial_tpe(
         r: *const gsl_rng,
         p: f64,
-        n: ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_uint;
+        n: ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_uint;
 }
 extern "C" {
-    pub fn gsl_ran_binomial_pdf(
-        k: ::std::os::raw::c_uint,
-        p: f64,
-        n: ::std::os::raw::c_uint,
-    ) -> f64;
+    pub fn gsl_ran_binomial_pdf(k: ::core::ffi::c_uint, p: f64, n: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_ran_exponential(r: *const gsl_rng, mu: f64) -> f64;
@@ -32865,7 +32413,7 @@ extern "C" {
     pub fn gsl_ran_gamma(r: *const gsl_rng, a: f64, b: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_ran_gamma_int(r: *const gsl_rng, a: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_ran_gamma_int(r: *const gsl_rng, a: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_ran_gamma_pdf(x: f64, a: f64, b: f64) -> f64;
@@ -32936,7 +32484,7 @@ extern "C" {
         mu: *const gsl_vector,
         L: *const gsl_matrix,
         result: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -32947,7 +32495,7 @@ extern "C" {
         L: *const gsl_matrix,
         result: *mut f64,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -32958,7 +32506,7 @@ extern "C" {
         L: *const gsl_matrix,
         result: *mut f64,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -32966,7 +32514,7 @@ extern "C" {
     pub fn gsl_ran_multivariate_gaussian_mean(
         X: *const gsl_matrix,
         mu_hat: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_2")]
@@ -32974,7 +32522,7 @@ extern "C" {
     pub fn gsl_ran_multivariate_gaussian_vcov(
         X: *const gsl_matrix,
         sigma_hat: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -32985,7 +32533,7 @@ extern "C" {
         L: *const gsl_matrix,
         result: *mut gsl_matrix,
         work: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -32997,7 +32545,7 @@ extern "C" {
         L: *const gsl_matrix,
         result: *mut f64,
         work: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_5")]
@@ -33009,7 +32557,7 @@ extern "C" {
         L: *const gsl_matrix,
         result: *mut f64,
         work: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_ran_landau(r: *const gsl_rng) -> f64;
@@ -33018,25 +32566,25 @@ extern "C" {
     pub fn gsl_ran_landau_pdf(x: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_ran_geometric(r: *const gsl_rng, p: f64) -> ::std::os::raw::c_uint;
+    pub fn gsl_ran_geometric(r: *const gsl_rng, p: f64) -> ::core::ffi::c_uint;
 }
 extern "C" {
-    pub fn gsl_ran_geometric_pdf(k: ::std::os::raw::c_uint, p: f64) -> f64;
+    pub fn gsl_ran_geometric_pdf(k: ::core::ffi::c_uint, p: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_ran_hypergeometric(
         r: *const gsl_rng,
-        n1: ::std::os::raw::c_uint,
-        n2: ::std::os::raw::c_uint,
-        t: ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_uint;
+        n1: ::core::ffi::c_uint,
+        n2: ::core::ffi::c_uint,
+        t: ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_ran_hypergeometric_pdf(
-        k: ::std::os::raw::c_uint,
-        n1: ::std::os::raw::c_uint,
-        n2: ::std::os::raw::c_uint,
-        t: ::std::os::raw::c_uint,
+        k: ::core::ffi::c_uint,
+        n1: ::core::ffi::c_uint,
+        n2: ::core::ffi::c_uint,
+        t: ::core::ffi::c_uint,
     ) -> f64;
 }
 extern "C" {
@@ -33064,49 +32612,39 @@ extern "C" {
     pub fn gsl_ran_lognormal_pdf(x: f64, zeta: f64, sigma: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_ran_logarithmic(r: *const gsl_rng, p: f64) -> ::std::os::raw::c_uint;
+    pub fn gsl_ran_logarithmic(r: *const gsl_rng, p: f64) -> ::core::ffi::c_uint;
 }
 extern "C" {
-    pub fn gsl_ran_logarithmic_pdf(k: ::std::os::raw::c_uint, p: f64) -> f64;
+    pub fn gsl_ran_logarithmic_pdf(k: ::core::ffi::c_uint, p: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_ran_multinomial(
         r: *const gsl_rng,
         K: usize,
-        N: ::std::os::raw::c_uint,
+        N: ::core::ffi::c_uint,
         p: *const f64,
-        n: *mut ::std::os::raw::c_uint,
+        n: *mut ::core::ffi::c_uint,
     );
 }
 extern "C" {
-    pub fn gsl_ran_multinomial_pdf(
-        K: usize,
-        p: *const f64,
-        n: *const ::std::os::raw::c_uint,
-    ) -> f64;
+    pub fn gsl_ran_multinomial_pdf(K: usize, p: *const f64, n: *const ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
-    pub fn gsl_ran_multinomial_lnpdf(
-        K: usize,
-        p: *const f64,
-        n: *const ::std::os::raw::c_uint,
-    ) -> f64;
+    pub fn gsl_ran_multinomial_lnpdf(K: usize, p: *const f64, n: *const ::core::ffi::c_uint)
+        -> f64;
 }
 extern "C" {
-    pub fn gsl_ran_negative_binomial(r: *const gsl_rng, p: f64, n: f64) -> ::std::os::raw::c_uint;
+    pub fn gsl_ran_negative_binomial(r: *const gsl_rng, p: f64, n: f64) -> ::core::ffi::c_uint;
 }
 extern "C" {
-    pub fn gsl_ran_negative_binomial_pdf(k: ::std::os::raw::c_uint, p: f64, n: f64) -> f64;
+    pub fn gsl_ran_negative_binomial_pdf(k: ::core::ffi::c_uint, p: f64, n: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_ran_pascal(
-        r: *const gsl_rng,
-        p: f64,
-        n: ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_uint;
+    pub fn gsl_ran_pascal(r: *const gsl_rng, p: f64, n: ::core::ffi::c_uint)
+        -> ::core::ffi::c_uint;
 }
 extern "C" {
-    pub fn gsl_ran_pascal_pdf(k: ::std::os::raw::c_uint, p: f64, n: ::std::os::raw::c_uint) -> f64;
+    pub fn gsl_ran_pascal_pdf(k: ::core::ffi::c_uint, p: f64, n: ::core::ffi::c_uint) -> f64;
 }
 extern "C" {
     pub fn gsl_ran_pareto(r: *const gsl_rng, a: f64, b: f64) -> f64;
@@ -33115,18 +32653,18 @@ extern "C" {
     pub fn gsl_ran_pareto_pdf(x: f64, a: f64, b: f64) -> f64;
 }
 extern "C" {
-    pub fn gsl_ran_poisson(r: *const gsl_rng, mu: f64) -> ::std::os::raw::c_uint;
+    pub fn gsl_ran_poisson(r: *const gsl_rng, mu: f64) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn gsl_ran_poisson_array(
         r: *const gsl_rng,
         n: usize,
-        array: *mut ::std::os::raw::c_uint,
+        array: *mut ::core::ffi::c_uint,
         mu: f64,
     );
 }
 extern "C" {
-    pub fn gsl_ran_poisson_pdf(k: ::std::os::raw::c_uint, mu: f64) -> f64;
+    pub fn gsl_ran_poisson_pdf(k: ::core::ffi::c_uint, mu: f64) -> f64;
 }
 extern "C" {
     pub fn gsl_ran_rayleigh(r: *const gsl_rng, sigma: f64) -> f64;
@@ -33179,7 +32717,7 @@ extern "C" {
 extern "C" {
     pub fn gsl_ran_shuffle(
         r: *const gsl_rng,
-        base: *mut ::std::os::raw::c_void,
+        base: *mut ::core::ffi::c_void,
         nmembm: usize,
         size: usize,
     );
@@ -33187,19 +32725,19 @@ extern "C" {
 extern "C" {
     pub fn gsl_ran_choose(
         r: *const gsl_rng,
-        dest: *mut ::std::os::raw::c_void,
+        dest: *mut ::core::ffi::c_void,
         k: usize,
-        src: *mut ::std::os::raw::c_void,
+        src: *mut ::core::ffi::c_void,
         n: usize,
         size: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_ran_sample(
         r: *const gsl_rng,
-        dest: *mut ::std::os::raw::c_void,
+        dest: *mut ::core::ffi::c_void,
         k: usize,
-        src: *mut ::std::os::raw::c_void,
+        src: *mut ::core::ffi::c_void,
         n: usize,
         size: usize,
     );
@@ -33222,39 +32760,39 @@ extern "C" {
 extern "C" {
     pub fn gsl_permute_ulong(
         p: *const usize,
-        data: *mut ::std::os::raw::c_ulong,
+        data: *mut ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_ulong_inverse(
         p: *const usize,
-        data: *mut ::std::os::raw::c_ulong,
+        data: *mut ::core::ffi::c_ulong,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_message(
-        message: *const ::std::os::raw::c_char,
-        file: *const ::std::os::raw::c_char,
-        line: ::std::os::raw::c_int,
-        mask: ::std::os::raw::c_uint,
+        message: *const ::core::ffi::c_char,
+        file: *const ::core::ffi::c_char,
+        line: ::core::ffi::c_int,
+        mask: ::core::ffi::c_uint,
     );
 }
 extern "C" {
-    pub static mut gsl_message_mask: ::std::os::raw::c_uint;
+    pub static mut gsl_message_mask: ::core::ffi::c_uint;
 }
-pub const GSL_MESSAGE_MASK_A: ::std::os::raw::c_uint = 1;
-pub const GSL_MESSAGE_MASK_B: ::std::os::raw::c_uint = 2;
-pub const GSL_MESSAGE_MASK_C: ::std::os::raw::c_uint = 4;
-pub const GSL_MESSAGE_MASK_D: ::std::os::raw::c_uint = 8;
-pub const GSL_MESSAGE_MASK_E: ::std::os::raw::c_uint = 16;
-pub const GSL_MESSAGE_MASK_F: ::std::os::raw::c_uint = 32;
-pub const GSL_MESSAGE_MASK_G: ::std::os::raw::c_uint = 64;
-pub const GSL_MESSAGE_MASK_H: ::std::os::raw::c_uint = 128;
-pub type _bindgen_ty_6 = ::std::os::raw::c_uint;
+pub const GSL_MESSAGE_MASK_A: ::core::ffi::c_uint = 1;
+pub const GSL_MESSAGE_MASK_B: ::core::ffi::c_uint = 2;
+pub const GSL_MESSAGE_MASK_C: ::core::ffi::c_uint = 4;
+pub const GSL_MESSAGE_MASK_D: ::core::ffi::c_uint = 8;
+pub const GSL_MESSAGE_MASK_E: ::core::ffi::c_uint = 16;
+pub const GSL_MESSAGE_MASK_F: ::core::ffi::c_uint = 32;
+pub const GSL_MESSAGE_MASK_G: ::core::ffi::c_uint = 64;
+pub const GSL_MESSAGE_MASK_H: ::core::ffi::c_uint = 128;
+pub type _bindgen_ty_6 = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_multimin_function_struct;
@@ -33268,7 +32806,7 @@ extern "C" {
         f: *const gsl_multimin_function,
         x: *const gsl_vector,
         g: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -33288,7 +32826,7 @@ extern "C" {
         f: *mut gsl_multimin_function,
         x: *const gsl_vector,
         step_size: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multimin_fminimizer_free(s: *mut gsl_multimin_fminimizer);
@@ -33296,12 +32834,10 @@ extern "C" {
 extern "C" {
     pub fn gsl_multimin_fminimizer_name(
         s: *const gsl_multimin_fminimizer,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn gsl_multimin_fminimizer_iterate(
-        s: *mut gsl_multimin_fminimizer,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_multimin_fminimizer_iterate(s: *mut gsl_multimin_fminimizer) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multimin_fminimizer_x(s: *const gsl_multimin_fminimizer) -> *mut gsl_vector;
@@ -33313,10 +32849,10 @@ extern "C" {
     pub fn gsl_multimin_fminimizer_size(s: *const gsl_multimin_fminimizer) -> f64;
 }
 extern "C" {
-    pub fn gsl_multimin_test_gradient(g: *const gsl_vector, epsabs: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_multimin_test_gradient(g: *const gsl_vector, epsabs: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multimin_test_size(size: f64, epsabs: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_multimin_test_size(size: f64, epsabs: f64) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -33337,7 +32873,7 @@ extern "C" {
         x: *const gsl_vector,
         step_size: f64,
         tol: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multimin_fdfminimizer_free(s: *mut gsl_multimin_fdfminimizer);
@@ -33345,17 +32881,17 @@ extern "C" {
 extern "C" {
     pub fn gsl_multimin_fdfminimizer_name(
         s: *const gsl_multimin_fdfminimizer,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_multimin_fdfminimizer_iterate(
         s: *mut gsl_multimin_fdfminimizer,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multimin_fdfminimizer_restart(
         s: *mut gsl_multimin_fdfminimizer,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multimin_fdfminimizer_x(s: *const gsl_multimin_fdfminimizer) -> *mut gsl_vector;
@@ -33400,7 +32936,7 @@ pub const gsl_multilarge_nlinear_fdtype_GSL_MULTILARGE_NLINEAR_FWDIFF:
     gsl_multilarge_nlinear_fdtype = 0;
 pub const gsl_multilarge_nlinear_fdtype_GSL_MULTILARGE_NLINEAR_CTRDIFF:
     gsl_multilarge_nlinear_fdtype = 1;
-pub type gsl_multilarge_nlinear_fdtype = ::std::os::raw::c_uint;
+pub type gsl_multilarge_nlinear_fdtype = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 #[cfg(feature = "v2_1")]
@@ -33468,7 +33004,7 @@ extern "C" {
         x: *const gsl_vector,
         fdf: *mut gsl_multilarge_nlinear_fdf,
         w: *mut gsl_multilarge_nlinear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -33478,14 +33014,14 @@ extern "C" {
         wts: *const gsl_vector,
         fdf: *mut gsl_multilarge_nlinear_fdf,
         w: *mut gsl_multilarge_nlinear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_iterate(
         w: *mut gsl_multilarge_nlinear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -33498,7 +33034,7 @@ extern "C" {
     pub fn gsl_multilarge_nlinear_rcond(
         rcond: *mut f64,
         w: *const gsl_multilarge_nlinear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -33506,7 +33042,7 @@ extern "C" {
     pub fn gsl_multilarge_nlinear_covar(
         covar: *mut gsl_matrix,
         w: *mut gsl_multilarge_nlinear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -33516,24 +33052,24 @@ extern "C" {
         xtol: f64,
         gtol: f64,
         ftol: f64,
-        callback: ::std::option::Option<
+        callback: ::core::option::Option<
             unsafe extern "C" fn(
                 iter: usize,
-                params: *mut ::std::os::raw::c_void,
+                params: *mut ::core::ffi::c_void,
                 w: *const gsl_multilarge_nlinear_workspace,
             ),
         >,
-        callback_params: *mut ::std::os::raw::c_void,
-        info: *mut ::std::os::raw::c_int,
+        callback_params: *mut ::core::ffi::c_void,
+        info: *mut ::core::ffi::c_int,
         w: *mut gsl_multilarge_nlinear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_name(
         w: *const gsl_multilarge_nlinear_workspace,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -33566,7 +33102,7 @@ extern "C" {
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
     pub fn gsl_multilarge_nlinear_trs_name(
         w: *const gsl_multilarge_nlinear_workspace,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -33576,7 +33112,7 @@ extern "C" {
         x: *const gsl_vector,
         swts: *const gsl_vector,
         y: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -33593,7 +33129,7 @@ extern "C" {
         v: *mut gsl_vector,
         JTJ: *mut gsl_matrix,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -33607,7 +33143,7 @@ extern "C" {
         fdf: *mut gsl_multilarge_nlinear_fdf,
         yvv: *mut gsl_vector,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -33616,9 +33152,9 @@ extern "C" {
         xtol: f64,
         gtol: f64,
         ftol: f64,
-        info: *mut ::std::os::raw::c_int,
+        info: *mut ::core::ffi::c_int,
         w: *const gsl_multilarge_nlinear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -33632,7 +33168,7 @@ extern "C" {
         f: *const gsl_vector,
         J: *mut gsl_matrix,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -33647,7 +33183,7 @@ extern "C" {
         fdf: *mut gsl_multilarge_nlinear_fdf,
         fvv: *mut gsl_vector,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[cfg(feature = "v2_1")]
@@ -33726,13 +33262,13 @@ extern "C" {
 extern "C" {
     #[cfg(feature = "v2_2")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
-    pub fn gsl_rstat_quantile_reset(w: *mut gsl_rstat_quantile_workspace) -> ::std::os::raw::c_int;
+    pub fn gsl_rstat_quantile_reset(w: *mut gsl_rstat_quantile_workspace) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_rstat_quantile_add(
         x: f64,
         w: *mut gsl_rstat_quantile_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_rstat_quantile_get(w: *mut gsl_rstat_quantile_workspace) -> f64;
@@ -33750,7 +33286,7 @@ extern "C" {
     pub fn gsl_rstat_n(w: *const gsl_rstat_workspace) -> usize;
 }
 extern "C" {
-    pub fn gsl_rstat_add(x: f64, w: *mut gsl_rstat_workspace) -> ::std::os::raw::c_int;
+    pub fn gsl_rstat_add(x: f64, w: *mut gsl_rstat_workspace) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_rstat_min(w: *const gsl_rstat_workspace) -> f64;
@@ -33785,7 +33321,7 @@ extern "C" {
     pub fn gsl_rstat_kurtosis(w: *const gsl_rstat_workspace) -> f64;
 }
 extern "C" {
-    pub fn gsl_rstat_reset(w: *mut gsl_rstat_workspace) -> ::std::os::raw::c_int;
+    pub fn gsl_rstat_reset(w: *mut gsl_rstat_workspace) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -33811,13 +33347,13 @@ extern "C" {
         f: *mut gsl_function,
         x_lower: f64,
         x_upper: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_root_fsolver_iterate(s: *mut gsl_root_fsolver) -> ::std::os::raw::c_int;
+    pub fn gsl_root_fsolver_iterate(s: *mut gsl_root_fsolver) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_root_fsolver_name(s: *const gsl_root_fsolver) -> *const ::std::os::raw::c_char;
+    pub fn gsl_root_fsolver_name(s: *const gsl_root_fsolver) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_root_fsolver_root(s: *const gsl_root_fsolver) -> f64;
@@ -33836,16 +33372,16 @@ extern "C" {
         s: *mut gsl_root_fdfsolver,
         fdf: *mut gsl_function_fdf,
         root: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_root_fdfsolver_iterate(s: *mut gsl_root_fdfsolver) -> ::std::os::raw::c_int;
+    pub fn gsl_root_fdfsolver_iterate(s: *mut gsl_root_fdfsolver) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_root_fdfsolver_free(s: *mut gsl_root_fdfsolver);
 }
 extern "C" {
-    pub fn gsl_root_fdfsolver_name(s: *const gsl_root_fdfsolver) -> *const ::std::os::raw::c_char;
+    pub fn gsl_root_fdfsolver_name(s: *const gsl_root_fdfsolver) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_root_fdfsolver_root(s: *const gsl_root_fdfsolver) -> f64;
@@ -33856,14 +33392,13 @@ extern "C" {
         x_upper: f64,
         epsabs: f64,
         epsrel: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_root_test_residual(f: f64, epsabs: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_root_test_residual(f: f64, epsabs: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_root_test_delta(x1: f64, x0: f64, epsabs: f64, epsrel: f64)
-        -> ::std::os::raw::c_int;
+    pub fn gsl_root_test_delta(x1: f64, x0: f64, epsabs: f64, epsrel: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub static mut gsl_root_fsolver_bisection: *const gsl_root_fsolver_type;
@@ -33896,7 +33431,7 @@ extern "C" {
         cov01: *mut f64,
         cov11: *mut f64,
         sumsq: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fit_wlinear(
@@ -33913,7 +33448,7 @@ extern "C" {
         cov01: *mut f64,
         cov11: *mut f64,
         chisq: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fit_linear_est(
@@ -33925,7 +33460,7 @@ extern "C" {
         cov11: f64,
         y: *mut f64,
         y_err: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fit_mul(
@@ -33937,7 +33472,7 @@ extern "C" {
         c1: *mut f64,
         cov11: *mut f64,
         sumsq: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fit_wmul(
@@ -33951,7 +33486,7 @@ extern "C" {
         c1: *mut f64,
         cov11: *mut f64,
         sumsq: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fit_mul_est(
@@ -33960,28 +33495,28 @@ extern "C" {
         cov11: f64,
         y: *mut f64,
         y_err: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_halfcomplex_radix2_backward(
         data: *mut f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_halfcomplex_radix2_inverse(
         data: *mut f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_halfcomplex_radix2_transform(
         data: *mut f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -33999,7 +33534,7 @@ extern "C" {
         n: usize,
         wavetable: *const gsl_fft_halfcomplex_wavetable,
         work: *mut gsl_fft_real_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_halfcomplex_inverse(
@@ -34008,7 +33543,7 @@ extern "C" {
         n: usize,
         wavetable: *const gsl_fft_halfcomplex_wavetable,
         work: *mut gsl_fft_real_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_halfcomplex_transform(
@@ -34017,7 +33552,7 @@ extern "C" {
         n: usize,
         wavetable: *const gsl_fft_halfcomplex_wavetable,
         work: *mut gsl_fft_real_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_halfcomplex_unpack(
@@ -34025,7 +33560,7 @@ extern "C" {
         complex_coefficient: *mut f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_fft_halfcomplex_radix2_unpack(
@@ -34033,7 +33568,7 @@ extern "C" {
         complex_coefficient: *mut f64,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_dft_complex_forward(
@@ -34041,7 +33576,7 @@ extern "C" {
         stride: usize,
         n: usize,
         result: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_dft_complex_backward(
@@ -34049,7 +33584,7 @@ extern "C" {
         stride: usize,
         n: usize,
         result: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_dft_complex_inverse(
@@ -34057,7 +33592,7 @@ extern "C" {
         stride: usize,
         n: usize,
         result: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_dft_complex_transform(
@@ -34066,7 +33601,7 @@ extern "C" {
         n: usize,
         result: *mut f64,
         sign: gsl_fft_direction,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -34084,7 +33619,7 @@ extern "C" {
         func: *const gsl_function,
         a: f64,
         b: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_cheb_order(cs: *const gsl_cheb_series) -> usize;
@@ -34104,7 +33639,7 @@ extern "C" {
         x: f64,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_cheb_eval_n(cs: *const gsl_cheb_series, order: usize, x: f64) -> f64;
@@ -34116,7 +33651,7 @@ extern "C" {
         x: f64,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_cheb_eval_mode(cs: *const gsl_cheb_series, x: f64, mode: gsl_mode_t) -> f64;
@@ -34128,19 +33663,19 @@ extern "C" {
         mode: gsl_mode_t,
         result: *mut f64,
         abserr: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_cheb_calc_deriv(
         deriv: *mut gsl_cheb_series,
         cs: *const gsl_cheb_series,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_cheb_calc_integ(
         integ: *mut gsl_cheb_series,
         cs: *const gsl_cheb_series,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_complex_float(
@@ -34148,7 +33683,7 @@ extern "C" {
         data: *mut f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_complex_float_inverse(
@@ -34156,23 +33691,23 @@ extern "C" {
         data: *mut f32,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_uchar(
         p: *const usize,
-        data: *mut ::std::os::raw::c_uchar,
+        data: *mut ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_permute_uchar_inverse(
         p: *const usize,
-        data: *mut ::std::os::raw::c_uchar,
+        data: *mut ::core::ffi::c_uchar,
         stride: usize,
         n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -34201,32 +33736,27 @@ extern "C" {
     pub fn gsl_combination_memcpy(
         dest: *mut gsl_combination,
         src: *const gsl_combination,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_combination_fread(
-        stream: *mut FILE,
-        c: *mut gsl_combination,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_combination_fread(stream: *mut FILE, c: *mut gsl_combination) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_combination_fwrite(
         stream: *mut FILE,
         c: *const gsl_combination,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_combination_fscanf(
-        stream: *mut FILE,
-        c: *mut gsl_combination,
-    ) -> ::std::os::raw::c_int;
+    pub fn gsl_combination_fscanf(stream: *mut FILE, c: *mut gsl_combination)
+        -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_combination_fprintf(
         stream: *mut FILE,
         c: *const gsl_combination,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_combination_n(c: *const gsl_combination) -> usize;
@@ -34238,13 +33768,13 @@ extern "C" {
     pub fn gsl_combination_data(c: *const gsl_combination) -> *mut usize;
 }
 extern "C" {
-    pub fn gsl_combination_valid(c: *mut gsl_combination) -> ::std::os::raw::c_int;
+    pub fn gsl_combination_valid(c: *mut gsl_combination) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_combination_next(c: *mut gsl_combination) -> ::std::os::raw::c_int;
+    pub fn gsl_combination_next(c: *mut gsl_combination) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_combination_prev(c: *mut gsl_combination) -> ::std::os::raw::c_int;
+    pub fn gsl_combination_prev(c: *mut gsl_combination) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_combination_get(c: *const gsl_combination, i: usize) -> usize;
@@ -34276,23 +33806,23 @@ extern "C" {
     pub fn gsl_multiset_memcpy(
         dest: *mut gsl_multiset,
         src: *const gsl_multiset,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multiset_fread(stream: *mut FILE, c: *mut gsl_multiset) -> ::std::os::raw::c_int;
+    pub fn gsl_multiset_fread(stream: *mut FILE, c: *mut gsl_multiset) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multiset_fwrite(stream: *mut FILE, c: *const gsl_multiset) -> ::std::os::raw::c_int;
+    pub fn gsl_multiset_fwrite(stream: *mut FILE, c: *const gsl_multiset) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multiset_fscanf(stream: *mut FILE, c: *mut gsl_multiset) -> ::std::os::raw::c_int;
+    pub fn gsl_multiset_fscanf(stream: *mut FILE, c: *mut gsl_multiset) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multiset_fprintf(
         stream: *mut FILE,
         c: *const gsl_multiset,
-        format: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        format: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multiset_n(c: *const gsl_multiset) -> usize;
@@ -34304,23 +33834,23 @@ extern "C" {
     pub fn gsl_multiset_data(c: *const gsl_multiset) -> *mut usize;
 }
 extern "C" {
-    pub fn gsl_multiset_valid(c: *mut gsl_multiset) -> ::std::os::raw::c_int;
+    pub fn gsl_multiset_valid(c: *mut gsl_multiset) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multiset_next(c: *mut gsl_multiset) -> ::std::os::raw::c_int;
+    pub fn gsl_multiset_next(c: *mut gsl_multiset) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multiset_prev(c: *mut gsl_multiset) -> ::std::os::raw::c_int;
+    pub fn gsl_multiset_prev(c: *mut gsl_multiset) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multiset_get(c: *const gsl_multiset, i: usize) -> usize;
 }
-pub const GSL_IEEE_TYPE_NAN: ::std::os::raw::c_uint = 1;
-pub const GSL_IEEE_TYPE_INF: ::std::os::raw::c_uint = 2;
-pub const GSL_IEEE_TYPE_NORMAL: ::std::os::raw::c_uint = 3;
-pub const GSL_IEEE_TYPE_DENORMAL: ::std::os::raw::c_uint = 4;
-pub const GSL_IEEE_TYPE_ZERO: ::std::os::raw::c_uint = 5;
-pub type _bindgen_ty_7 = ::std::os::raw::c_uint;
+pub const GSL_IEEE_TYPE_NAN: ::core::ffi::c_uint = 1;
+pub const GSL_IEEE_TYPE_INF: ::core::ffi::c_uint = 2;
+pub const GSL_IEEE_TYPE_NORMAL: ::core::ffi::c_uint = 3;
+pub const GSL_IEEE_TYPE_DENORMAL: ::core::ffi::c_uint = 4;
+pub const GSL_IEEE_TYPE_ZERO: ::core::ffi::c_uint = 5;
+pub type _bindgen_ty_7 = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_ieee_float_rep;
@@ -34345,40 +33875,40 @@ extern "C" {
 extern "C" {
     pub fn gsl_ieee_double_to_rep(x: *const f64, r: *mut gsl_ieee_double_rep);
 }
-pub const GSL_IEEE_SINGLE_PRECISION: ::std::os::raw::c_uint = 1;
-pub const GSL_IEEE_DOUBLE_PRECISION: ::std::os::raw::c_uint = 2;
-pub const GSL_IEEE_EXTENDED_PRECISION: ::std::os::raw::c_uint = 3;
-pub type _bindgen_ty_8 = ::std::os::raw::c_uint;
-pub const GSL_IEEE_ROUND_TO_NEAREST: ::std::os::raw::c_uint = 1;
-pub const GSL_IEEE_ROUND_DOWN: ::std::os::raw::c_uint = 2;
-pub const GSL_IEEE_ROUND_UP: ::std::os::raw::c_uint = 3;
-pub const GSL_IEEE_ROUND_TO_ZERO: ::std::os::raw::c_uint = 4;
-pub type _bindgen_ty_9 = ::std::os::raw::c_uint;
-pub const GSL_IEEE_MASK_INVALID: ::std::os::raw::c_uint = 1;
-pub const GSL_IEEE_MASK_DENORMALIZED: ::std::os::raw::c_uint = 2;
-pub const GSL_IEEE_MASK_DIVISION_BY_ZERO: ::std::os::raw::c_uint = 4;
-pub const GSL_IEEE_MASK_OVERFLOW: ::std::os::raw::c_uint = 8;
-pub const GSL_IEEE_MASK_UNDERFLOW: ::std::os::raw::c_uint = 16;
-pub const GSL_IEEE_MASK_ALL: ::std::os::raw::c_uint = 31;
-pub const GSL_IEEE_TRAP_INEXACT: ::std::os::raw::c_uint = 32;
-pub type _bindgen_ty_10 = ::std::os::raw::c_uint;
+pub const GSL_IEEE_SINGLE_PRECISION: ::core::ffi::c_uint = 1;
+pub const GSL_IEEE_DOUBLE_PRECISION: ::core::ffi::c_uint = 2;
+pub const GSL_IEEE_EXTENDED_PRECISION: ::core::ffi::c_uint = 3;
+pub type _bindgen_ty_8 = ::core::ffi::c_uint;
+pub const GSL_IEEE_ROUND_TO_NEAREST: ::core::ffi::c_uint = 1;
+pub const GSL_IEEE_ROUND_DOWN: ::core::ffi::c_uint = 2;
+pub const GSL_IEEE_ROUND_UP: ::core::ffi::c_uint = 3;
+pub const GSL_IEEE_ROUND_TO_ZERO: ::core::ffi::c_uint = 4;
+pub type _bindgen_ty_9 = ::core::ffi::c_uint;
+pub const GSL_IEEE_MASK_INVALID: ::core::ffi::c_uint = 1;
+pub const GSL_IEEE_MASK_DENORMALIZED: ::core::ffi::c_uint = 2;
+pub const GSL_IEEE_MASK_DIVISION_BY_ZERO: ::core::ffi::c_uint = 4;
+pub const GSL_IEEE_MASK_OVERFLOW: ::core::ffi::c_uint = 8;
+pub const GSL_IEEE_MASK_UNDERFLOW: ::core::ffi::c_uint = 16;
+pub const GSL_IEEE_MASK_ALL: ::core::ffi::c_uint = 31;
+pub const GSL_IEEE_TRAP_INEXACT: ::core::ffi::c_uint = 32;
+pub type _bindgen_ty_10 = ::core::ffi::c_uint;
 extern "C" {
     pub fn gsl_ieee_env_setup();
 }
 extern "C" {
     pub fn gsl_ieee_read_mode_string(
-        description: *const ::std::os::raw::c_char,
-        precision: *mut ::std::os::raw::c_int,
-        rounding: *mut ::std::os::raw::c_int,
-        exception_mask: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        description: *const ::core::ffi::c_char,
+        precision: *mut ::core::ffi::c_int,
+        rounding: *mut ::core::ffi::c_int,
+        exception_mask: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_ieee_set_mode(
-        precision: ::std::os::raw::c_int,
-        rounding: ::std::os::raw::c_int,
-        exception_mask: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        precision: ::core::ffi::c_int,
+        rounding: ::core::ffi::c_int,
+        exception_mask: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spblas_dgemv(
@@ -34388,7 +33918,7 @@ extern "C" {
         x: *const gsl_vector,
         beta: f64,
         y: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spblas_dgemm(
@@ -34396,45 +33926,45 @@ extern "C" {
         A: *const gsl_spmatrix,
         B: *const gsl_spmatrix,
         C: *mut gsl_spmatrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_spblas_scatter(
         A: *const gsl_spmatrix,
         j: usize,
         alpha: f64,
-        w: *mut ::std::os::raw::c_int,
+        w: *mut ::core::ffi::c_int,
         x: *mut f64,
-        mark: ::std::os::raw::c_int,
+        mark: ::core::ffi::c_int,
         C: *mut gsl_spmatrix,
         nz: usize,
     ) -> usize;
 }
 pub type gsl_siman_Efunc_t =
-    ::std::option::Option<unsafe extern "C" fn(xp: *mut ::std::os::raw::c_void) -> f64>;
-pub type gsl_siman_step_t = ::std::option::Option<
-    unsafe extern "C" fn(r: *const gsl_rng, xp: *mut ::std::os::raw::c_void, step_size: f64),
+    ::core::option::Option<unsafe extern "C" fn(xp: *mut ::core::ffi::c_void) -> f64>;
+pub type gsl_siman_step_t = ::core::option::Option<
+    unsafe extern "C" fn(r: *const gsl_rng, xp: *mut ::core::ffi::c_void, step_size: f64),
 >;
-pub type gsl_siman_metric_t = ::std::option::Option<
-    unsafe extern "C" fn(xp: *mut ::std::os::raw::c_void, yp: *mut ::std::os::raw::c_void) -> f64,
+pub type gsl_siman_metric_t = ::core::option::Option<
+    unsafe extern "C" fn(xp: *mut ::core::ffi::c_void, yp: *mut ::core::ffi::c_void) -> f64,
 >;
 pub type gsl_siman_print_t =
-    ::std::option::Option<unsafe extern "C" fn(xp: *mut ::std::os::raw::c_void)>;
-pub type gsl_siman_copy_t = ::std::option::Option<
-    unsafe extern "C" fn(source: *mut ::std::os::raw::c_void, dest: *mut ::std::os::raw::c_void),
+    ::core::option::Option<unsafe extern "C" fn(xp: *mut ::core::ffi::c_void)>;
+pub type gsl_siman_copy_t = ::core::option::Option<
+    unsafe extern "C" fn(source: *mut ::core::ffi::c_void, dest: *mut ::core::ffi::c_void),
 >;
-pub type gsl_siman_copy_construct_t = ::std::option::Option<
-    unsafe extern "C" fn(xp: *mut ::std::os::raw::c_void) -> *mut ::std::os::raw::c_void,
+pub type gsl_siman_copy_construct_t = ::core::option::Option<
+    unsafe extern "C" fn(xp: *mut ::core::ffi::c_void) -> *mut ::core::ffi::c_void,
 >;
 pub type gsl_siman_destroy_t =
-    ::std::option::Option<unsafe extern "C" fn(xp: *mut ::std::os::raw::c_void)>;
+    ::core::option::Option<unsafe extern "C" fn(xp: *mut ::core::ffi::c_void)>;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_siman_params_t;
 extern "C" {
     pub fn gsl_siman_solve(
         r: *const gsl_rng,
-        x0_p: *mut ::std::os::raw::c_void,
+        x0_p: *mut ::core::ffi::c_void,
         Ef: gsl_siman_Efunc_t,
         take_step: gsl_siman_step_t,
         distance: gsl_siman_metric_t,
@@ -34449,7 +33979,7 @@ extern "C" {
 extern "C" {
     pub fn gsl_siman_solve_many(
         r: *const gsl_rng,
-        x0_p: *mut ::std::os::raw::c_void,
+        x0_p: *mut ::core::ffi::c_void,
         Ef: gsl_siman_Efunc_t,
         take_step: gsl_siman_step_t,
         distance: gsl_siman_metric_t,
@@ -34460,7 +33990,7 @@ extern "C" {
 }
 pub const gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_FWDIFF: gsl_multifit_nlinear_fdtype = 0;
 pub const gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_CTRDIFF: gsl_multifit_nlinear_fdtype = 1;
-pub type gsl_multifit_nlinear_fdtype = ::std::os::raw::c_uint;
+pub type gsl_multifit_nlinear_fdtype = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_multifit_nlinear_fdf;
@@ -34504,7 +34034,7 @@ extern "C" {
         x: *const gsl_vector,
         fdf: *mut gsl_multifit_nlinear_fdf,
         w: *mut gsl_multifit_nlinear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_nlinear_winit(
@@ -34512,12 +34042,12 @@ extern "C" {
         wts: *const gsl_vector,
         fdf: *mut gsl_multifit_nlinear_fdf,
         w: *mut gsl_multifit_nlinear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_nlinear_iterate(
         w: *mut gsl_multifit_nlinear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_nlinear_avratio(w: *const gsl_multifit_nlinear_workspace) -> f64;
@@ -34528,17 +34058,17 @@ extern "C" {
         xtol: f64,
         gtol: f64,
         ftol: f64,
-        callback: ::std::option::Option<
+        callback: ::core::option::Option<
             unsafe extern "C" fn(
                 iter: usize,
-                params: *mut ::std::os::raw::c_void,
+                params: *mut ::core::ffi::c_void,
                 w: *const gsl_multifit_nlinear_workspace,
             ),
         >,
-        callback_params: *mut ::std::os::raw::c_void,
-        info: *mut ::std::os::raw::c_int,
+        callback_params: *mut ::core::ffi::c_void,
+        info: *mut ::core::ffi::c_int,
         w: *mut gsl_multifit_nlinear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_nlinear_jac(w: *const gsl_multifit_nlinear_workspace) -> *mut gsl_matrix;
@@ -34546,7 +34076,7 @@ extern "C" {
 extern "C" {
     pub fn gsl_multifit_nlinear_name(
         w: *const gsl_multifit_nlinear_workspace,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_multifit_nlinear_position(
@@ -34565,12 +34095,12 @@ extern "C" {
     pub fn gsl_multifit_nlinear_rcond(
         rcond: *mut f64,
         w: *const gsl_multifit_nlinear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_nlinear_trs_name(
         w: *const gsl_multifit_nlinear_workspace,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_multifit_nlinear_eval_f(
@@ -34578,7 +34108,7 @@ extern "C" {
         x: *const gsl_vector,
         swts: *const gsl_vector,
         y: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_nlinear_eval_df(
@@ -34590,7 +34120,7 @@ extern "C" {
         fdf: *mut gsl_multifit_nlinear_fdf,
         df: *mut gsl_matrix,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_nlinear_eval_fvv(
@@ -34603,23 +34133,23 @@ extern "C" {
         fdf: *mut gsl_multifit_nlinear_fdf,
         yvv: *mut gsl_vector,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_nlinear_covar(
         J: *const gsl_matrix,
         epsrel: f64,
         covar: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_nlinear_test(
         xtol: f64,
         gtol: f64,
         ftol: f64,
-        info: *mut ::std::os::raw::c_int,
+        info: *mut ::core::ffi::c_int,
         w: *const gsl_multifit_nlinear_workspace,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_nlinear_df(
@@ -34631,7 +34161,7 @@ extern "C" {
         f: *const gsl_vector,
         J: *mut gsl_matrix,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_nlinear_fdfvv(
@@ -34644,7 +34174,7 @@ extern "C" {
         fdf: *mut gsl_multifit_nlinear_fdf,
         fvv: *mut gsl_vector,
         work: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub static mut gsl_multifit_nlinear_trust: *const gsl_multifit_nlinear_type;
@@ -34690,14 +34220,14 @@ extern "C" {
         J: *const gsl_matrix,
         f: *const gsl_vector,
         g: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_covar(
         J: *const gsl_matrix,
         epsrel: f64,
         covar: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_covar_QRPT(
@@ -34705,7 +34235,7 @@ extern "C" {
         perm: *mut gsl_permutation,
         epsrel: f64,
         covar: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -34732,10 +34262,10 @@ extern "C" {
         s: *mut gsl_multifit_fsolver,
         f: *mut gsl_multifit_function,
         x: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multifit_fsolver_iterate(s: *mut gsl_multifit_fsolver) -> ::std::os::raw::c_int;
+    pub fn gsl_multifit_fsolver_iterate(s: *mut gsl_multifit_fsolver) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_fsolver_driver(
@@ -34743,12 +34273,10 @@ extern "C" {
         maxiter: usize,
         epsabs: f64,
         epsrel: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multifit_fsolver_name(
-        s: *const gsl_multifit_fsolver,
-    ) -> *const ::std::os::raw::c_char;
+    pub fn gsl_multifit_fsolver_name(s: *const gsl_multifit_fsolver) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_multifit_fsolver_position(s: *const gsl_multifit_fsolver) -> *mut gsl_vector;
@@ -34756,31 +34284,31 @@ extern "C" {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_multifit_function_fdf_struct {
-    pub f: ::std::option::Option<
+    pub f: ::core::option::Option<
         unsafe extern "C" fn(
             x: *const gsl_vector,
-            params: *mut ::std::os::raw::c_void,
+            params: *mut ::core::ffi::c_void,
             f: *mut gsl_vector,
-        ) -> ::std::os::raw::c_int,
+        ) -> ::core::ffi::c_int,
     >,
-    pub df: ::std::option::Option<
+    pub df: ::core::option::Option<
         unsafe extern "C" fn(
             x: *const gsl_vector,
-            params: *mut ::std::os::raw::c_void,
+            params: *mut ::core::ffi::c_void,
             df: *mut gsl_matrix,
-        ) -> ::std::os::raw::c_int,
+        ) -> ::core::ffi::c_int,
     >,
-    pub fdf: ::std::option::Option<
+    pub fdf: ::core::option::Option<
         unsafe extern "C" fn(
             x: *const gsl_vector,
-            params: *mut ::std::os::raw::c_void,
+            params: *mut ::core::ffi::c_void,
             f: *mut gsl_vector,
             df: *mut gsl_matrix,
-        ) -> ::std::os::raw::c_int,
+        ) -> ::core::ffi::c_int,
     >,
     pub n: usize,
     pub p: usize,
-    pub params: *mut ::std::os::raw::c_void,
+    pub params: *mut ::core::ffi::c_void,
     pub nevalf: usize,
     pub nevaldf: usize,
 }
@@ -34799,7 +34327,7 @@ pub struct gsl_multifit_fdfsolver {
     pub g: *mut gsl_vector,
     pub sqrt_wts: *mut gsl_vector,
     pub niter: usize,
-    pub state: *mut ::std::os::raw::c_void,
+    pub state: *mut ::core::ffi::c_void,
 }
 extern "C" {
     pub fn gsl_multifit_fdfsolver_alloc(
@@ -34813,7 +34341,7 @@ extern "C" {
         s: *mut gsl_multifit_fdfsolver,
         fdf: *mut gsl_multifit_function_fdf,
         x: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_fdfsolver_wset(
@@ -34821,10 +34349,10 @@ extern "C" {
         f: *mut gsl_multifit_function_fdf,
         x: *const gsl_vector,
         wts: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multifit_fdfsolver_iterate(s: *mut gsl_multifit_fdfsolver) -> ::std::os::raw::c_int;
+    pub fn gsl_multifit_fdfsolver_iterate(s: *mut gsl_multifit_fdfsolver) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_fdfsolver_driver(
@@ -34833,14 +34361,14 @@ extern "C" {
         xtol: f64,
         gtol: f64,
         ftol: f64,
-        info: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        info: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_fdfsolver_jac(
         s: *mut gsl_multifit_fdfsolver,
         J: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_fdfsolver_free(s: *mut gsl_multifit_fdfsolver);
@@ -34848,7 +34376,7 @@ extern "C" {
 extern "C" {
     pub fn gsl_multifit_fdfsolver_name(
         s: *const gsl_multifit_fdfsolver,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_multifit_fdfsolver_position(s: *const gsl_multifit_fdfsolver) -> *mut gsl_vector;
@@ -34865,7 +34393,7 @@ extern "C" {
         x: *const gsl_vector,
         wts: *const gsl_vector,
         y: *mut gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_eval_wdf(
@@ -34873,7 +34401,7 @@ extern "C" {
         x: *const gsl_vector,
         wts: *const gsl_vector,
         dy: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_fdfsolver_test(
@@ -34881,8 +34409,8 @@ extern "C" {
         xtol: f64,
         gtol: f64,
         ftol: f64,
-        info: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        info: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_test_delta(
@@ -34890,10 +34418,10 @@ extern "C" {
         x: *const gsl_vector,
         epsabs: f64,
         epsrel: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multifit_test_gradient(g: *const gsl_vector, epsabs: f64) -> ::std::os::raw::c_int;
+    pub fn gsl_multifit_test_gradient(g: *const gsl_vector, epsabs: f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_fdfsolver_dif_df(
@@ -34902,7 +34430,7 @@ extern "C" {
         fdf: *mut gsl_multifit_function_fdf,
         f: *const gsl_vector,
         J: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_fdfsolver_dif_fdf(
@@ -34910,7 +34438,7 @@ extern "C" {
         fdf: *mut gsl_multifit_function_fdf,
         f: *mut gsl_vector,
         J: *mut gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -34928,7 +34456,7 @@ extern "C" {
 extern "C" {
     pub fn gsl_multifit_fdfridge_name(
         w: *const gsl_multifit_fdfridge,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gsl_multifit_fdfridge_position(w: *const gsl_multifit_fdfridge) -> *mut gsl_vector;
@@ -34945,7 +34473,7 @@ extern "C" {
         f: *mut gsl_multifit_function_fdf,
         x: *const gsl_vector,
         lambda: f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_fdfridge_wset(
@@ -34954,7 +34482,7 @@ extern "C" {
         x: *const gsl_vector,
         lambda: f64,
         wts: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_fdfridge_set2(
@@ -34962,7 +34490,7 @@ extern "C" {
         f: *mut gsl_multifit_function_fdf,
         x: *const gsl_vector,
         lambda: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_fdfridge_wset2(
@@ -34971,7 +34499,7 @@ extern "C" {
         x: *const gsl_vector,
         lambda: *const gsl_vector,
         wts: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_fdfridge_set3(
@@ -34979,7 +34507,7 @@ extern "C" {
         f: *mut gsl_multifit_function_fdf,
         x: *const gsl_vector,
         L: *const gsl_matrix,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_fdfridge_wset3(
@@ -34988,10 +34516,10 @@ extern "C" {
         x: *const gsl_vector,
         L: *const gsl_matrix,
         wts: *const gsl_vector,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn gsl_multifit_fdfridge_iterate(w: *mut gsl_multifit_fdfridge) -> ::std::os::raw::c_int;
+    pub fn gsl_multifit_fdfridge_iterate(w: *mut gsl_multifit_fdfridge) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn gsl_multifit_fdfridge_driver(
@@ -35000,8 +34528,8 @@ extern "C" {
         xtol: f64,
         gtol: f64,
         ftol: f64,
-        info: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        info: *mut ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub static mut gsl_multifit_fdfsolver_lmsder: *const gsl_multifit_fdfsolver_type;
@@ -2,6 +2,7 @@
 // FFI binding for the GSL library
 //
 
+#![no_std]
 #![cfg_attr(feature = "dox", feature(doc_cfg))]
 #![allow(improper_ctypes)]
 #![allow(non_camel_case_types)]
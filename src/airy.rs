@@ -2,8 +2,8 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::Value;
-use std::mem::MaybeUninit;
+use crate::Error;
+use core::mem::MaybeUninit;
 
 /// This routine computes the Airy function Ai(x) with an accuracy specified by mode.
 #[doc(alias = "gsl_sf_airy_Ai")]
@@ -13,11 +13,15 @@ pub fn Ai(x: f64, mode: ::Mode) -> f64 {
 
 /// This routine computes the Airy function Ai(x) with an accuracy specified by mode.
 #[doc(alias = "gsl_sf_airy_Ai_e")]
-pub fn Ai_e(x: f64, mode: ::Mode) -> (Value, ::types::Result) {
+pub fn Ai_e(x: f64, mode: ::Mode) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_airy_Ai_e(x, mode.into(), result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_airy_Ai_e"
+    )
 }
 
 /// This routine computes the Airy function Bi(x) with an accuracy specified by mode.
@@ -28,11 +32,15 @@ pub fn Bi(x: f64, mode: ::Mode) -> f64 {
 
 /// This routine computes the Airy function Bi(x) with an accuracy specified by mode.
 #[doc(alias = "gsl_sf_airy_Bi_e")]
-pub fn Bi_e(x: f64, mode: ::Mode) -> (Value, ::types::Result) {
+pub fn Bi_e(x: f64, mode: ::Mode) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_airy_Bi_e(x, mode.into(), result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_airy_Bi_e"
+    )
 }
 
 /// This routine computes a scaled version of the Airy function S_A(x) Ai(x). For x>0 the scaling factor S_A(x) is \exp(+(2/3) x^(3/2)), and is 1 for x<0.
@@ -43,11 +51,15 @@ pub fn Ai_scaled(x: f64, mode: ::Mode) -> f64 {
 
 /// This routine computes a scaled version of the Airy function S_A(x) Ai(x). For x>0 the scaling factor S_A(x) is \exp(+(2/3) x^(3/2)), and is 1 for x<0.
 #[doc(alias = "gsl_sf_airy_Ai_scaled_e")]
-pub fn Ai_scaled_e(x: f64, mode: ::Mode) -> (Value, ::types::Result) {
+pub fn Ai_scaled_e(x: f64, mode: ::Mode) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_airy_Ai_scaled_e(x, mode.into(), result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_airy_Ai_scaled_e"
+    )
 }
 
 /// This routine computes a scaled version of the Airy function S_B(x) Bi(x). For x>0 the scaling factor S_B(x) is exp(-(2/3) x^(3/2)), and is 1 for x<0.
@@ -58,11 +70,15 @@ pub fn Bi_scaled(x: f64, mode: ::Mode) -> f64 {
 
 /// This routine computes a scaled version of the Airy function S_B(x) Bi(x). For x>0 the scaling factor S_B(x) is exp(-(2/3) x^(3/2)), and is 1 for x<0.
 #[doc(alias = "gsl_sf_airy_Bi_scaled_e")]
-pub fn Bi_scaled_e(x: f64, mode: ::Mode) -> (Value, ::types::Result) {
+pub fn Bi_scaled_e(x: f64, mode: ::Mode) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_airy_Bi_scaled_e(x, mode.into(), result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_airy_Bi_scaled_e"
+    )
 }
 
 /// This routine computes the Airy function derivative Ai'(x) with an accuracy specified by mode.
@@ -73,11 +89,15 @@ pub fn Ai_deriv(x: f64, mode: ::Mode) -> f64 {
 
 /// This routine computes the Airy function derivative Ai'(x) with an accuracy specified by mode.
 #[doc(alias = "gsl_sf_airy_Ai_deriv_e")]
-pub fn Ai_deriv_e(x: f64, mode: ::Mode) -> (Value, ::types::Result) {
+pub fn Ai_deriv_e(x: f64, mode: ::Mode) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_airy_Ai_deriv_e(x, mode.into(), result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_airy_Ai_deriv_e"
+    )
 }
 
 /// This routine computes the Airy function derivative Bi'(x) with an accuracy specified by mode.
@@ -88,11 +108,15 @@ pub fn Bi_deriv(x: f64, mode: ::Mode) -> f64 {
 
 /// This routine computes the Airy function derivative Bi'(x) with an accuracy specified by mode.
 #[doc(alias = "gsl_sf_airy_Bi_deriv_e")]
-pub fn Bi_deriv_e(x: f64, mode: ::Mode) -> (Value, ::types::Result) {
+pub fn Bi_deriv_e(x: f64, mode: ::Mode) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_airy_Bi_deriv_e(x, mode.into(), result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_airy_Bi_deriv_e"
+    )
 }
 
 /// This routine computes the scaled Airy function derivative S_A(x) Ai'(x). For x>0 the scaling factor S_A(x) is \exp(+(2/3) x^(3/2)), and is 1 for x<0.
@@ -103,11 +127,15 @@ pub fn Ai_deriv_scaled(x: f64, mode: ::Mode) -> f64 {
 
 /// This routine computes the scaled Airy function derivative S_A(x) Ai'(x). For x>0 the scaling factor S_A(x) is \exp(+(2/3) x^(3/2)), and is 1 for x<0.
 #[doc(alias = "gsl_sf_airy_Ai_deriv_scaled_e")]
-pub fn Ai_deriv_scaled_e(x: f64, mode: ::Mode) -> (Value, ::types::Result) {
+pub fn Ai_deriv_scaled_e(x: f64, mode: ::Mode) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_airy_Ai_deriv_scaled_e(x, mode.into(), result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_airy_Ai_deriv_scaled_e"
+    )
 }
 
 /// This routine computes the scaled Airy function derivative S_B(x) Bi'(x). For x>0 the scaling factor S_B(x) is exp(-(2/3) x^(3/2)), and is 1 for x<0.
@@ -118,11 +146,15 @@ pub fn Bi_deriv_scaled(x: f64, mode: ::Mode) -> f64 {
 
 /// This routine computes the scaled Airy function derivative S_B(x) Bi'(x). For x>0 the scaling factor S_B(x) is exp(-(2/3) x^(3/2)), and is 1 for x<0.
 #[doc(alias = "gsl_sf_airy_Bi_deriv_scaled_e")]
-pub fn Bi_deriv_scaled_e(x: f64, mode: ::Mode) -> (Value, ::types::Result) {
+pub fn Bi_deriv_scaled_e(x: f64, mode: ::Mode) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_airy_Bi_deriv_scaled_e(x, mode.into(), result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_airy_Bi_deriv_scaled_e"
+    )
 }
 
 /// This routine computes the location of the s-th zero of the Airy function Ai(x).
@@ -133,11 +165,15 @@ pub fn zero_Ai(s: u32) -> f64 {
 
 /// This routine computes the location of the s-th zero of the Airy function Ai(x).
 #[doc(alias = "gsl_sf_airy_zero_Ai_e")]
-pub fn zero_Ai_e(s: u32) -> (Value, ::types::Result) {
+pub fn zero_Ai_e(s: u32) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_airy_zero_Ai_e(s, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_airy_zero_Ai_e"
+    )
 }
 
 /// This routine computes the location of the s-th zero of the Airy function Bi(x).
@@ -148,11 +184,15 @@ pub fn zero_Bi(s: u32) -> f64 {
 
 /// This routine computes the location of the s-th zero of the Airy function Bi(x).
 #[doc(alias = "gsl_sf_airy_zero_Bi_e")]
-pub fn zero_Bi_e(s: u32) -> (Value, ::types::Result) {
+pub fn zero_Bi_e(s: u32) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_airy_zero_Bi_e(s, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_airy_zero_Bi_e"
+    )
 }
 
 /// This routine computes the location of the s-th zero of the Airy function derivative Ai'(x).
@@ -163,11 +203,15 @@ pub fn zero_Ai_deriv(s: u32) -> f64 {
 
 /// This routine computes the location of the s-th zero of the Airy function derivative Ai'(x).
 #[doc(alias = "gsl_sf_airy_zero_Ai_deriv_e")]
-pub fn zero_Ai_deriv_e(s: u32) -> (Value, ::types::Result) {
+pub fn zero_Ai_deriv_e(s: u32) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_airy_zero_Ai_deriv_e(s, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_airy_zero_Ai_deriv_e"
+    )
 }
 
 /// This routine computes the location of the s-th zero of the Airy function derivative Bi'(x).
@@ -178,9 +222,13 @@ pub fn zero_Bi_deriv(s: u32) -> f64 {
 
 /// This routine computes the location of the s-th zero of the Airy function derivative Bi'(x).
 #[doc(alias = "gsl_sf_airy_zero_Bi_deriv_e")]
-pub fn zero_Bi_deriv_e(s: u32) -> (Value, ::types::Result) {
+pub fn zero_Bi_deriv_e(s: u32) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_airy_zero_Bi_deriv_e(s, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_airy_zero_Bi_deriv_e"
+    )
 }
@@ -2,8 +2,10 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
+use crate::{enums, Error};
+
 pub mod level1 {
-    use crate::Value;
+    use crate::Error;
     use ffi::FFI;
     use types::complex::CFFI;
 
@@ -12,12 +14,16 @@ pub mod level1 {
     ///
     /// Returns `result`.
     #[doc(alias = "gsl_blas_sdsdot")]
-    pub fn sdsdot(alpha: f32, x: &::types::VectorF32, y: &::types::VectorF32) -> (Value, f32) {
+    pub fn sdsdot(
+        alpha: f32,
+        x: &::types::VectorF32,
+        y: &::types::VectorF32,
+    ) -> Result<f32, Error> {
         let mut result = 0.;
         let ret = unsafe {
             sys::gsl_blas_sdsdot(alpha, x.unwrap_shared(), y.unwrap_shared(), &mut result)
         };
-        (::Value::from(ret), result)
+        result_handler!(ret, result, "gsl_blas_sdsdot")
     }
 
     /// This function computes the scalar product x^T y for the vectors x and y, returning the
@@ -25,10 +31,10 @@ pub mod level1 {
     ///
     /// Returns `result`.
     #[doc(alias = "gsl_blas_sdot")]
-    pub fn sdot(x: &::types::VectorF32, y: &::types::VectorF32) -> (Value, f32) {
+    pub fn sdot(x: &::types::VectorF32, y: &::types::VectorF32) -> Result<f32, Error> {
         let mut result = 0.;
         let ret = unsafe { sys::gsl_blas_sdot(x.unwrap_shared(), y.unwrap_shared(), &mut result) };
-        (::Value::from(ret), result)
+        result_handler!(ret, result, "gsl_blas_sdot")
     }
 
     /// This function computes the scalar product x^T y for the vectors x and y, returning the
@@ -36,10 +42,10 @@ pub mod level1 {
     ///
     /// Returns `result`.
     #[doc(alias = "gsl_blas_dsdot")]
-    pub fn dsdot(x: &::types::VectorF32, y: &::types::VectorF32) -> (Value, f64) {
+    pub fn dsdot(x: &::types::VectorF32, y: &::types::VectorF32) -> Result<f64, Error> {
         let mut result = 0.;
         let ret = unsafe { sys::gsl_blas_dsdot(x.unwrap_shared(), y.unwrap_shared(), &mut result) };
-        (::Value::from(ret), result)
+        result_handler!(ret, result, "gsl_blas_dsdot")
     }
 
     /// This function computes the scalar product x^T y for the vectors x and y, returning the
@@ -47,10 +53,10 @@ pub mod level1 {
     ///
     /// Returns `result`.
     #[doc(alias = "gsl_blas_ddot")]
-    pub fn ddot(x: &::types::VectorF64, y: &::types::VectorF64) -> (Value, f64) {
+    pub fn ddot(x: &::types::VectorF64, y: &::types::VectorF64) -> Result<f64, Error> {
         let mut result = 0.;
         let ret = unsafe { sys::gsl_blas_ddot(x.unwrap_shared(), y.unwrap_shared(), &mut result) };
-        (::Value::from(ret), result)
+        result_handler!(ret, result, "gsl_blas_ddot")
     }
 
     /// This function computes the complex scalar product x^T y for the vectors x and y, returning
@@ -61,10 +67,10 @@ pub mod level1 {
     pub fn cdotu(
         x: &::types::VectorComplexF32,
         y: &::types::VectorComplexF32,
-    ) -> (Value, ::types::ComplexF32) {
+    ) -> Result<::types::ComplexF32, Error> {
         let mut dotu = ::types::ComplexF32::default().unwrap();
         let ret = unsafe { sys::gsl_blas_cdotu(x.unwrap_shared(), y.unwrap_shared(), &mut dotu) };
-        (::Value::from(ret), ::types::ComplexF32::wrap(dotu))
+        result_handler!(ret, ::types::ComplexF32::wrap(dotu), "gsl_blas_cdotu")
     }
 
     /// This function computes the complex scalar product x^T y for the vectors x and y, returning
@@ -75,10 +81,10 @@ pub mod level1 {
     pub fn zdotu(
         x: &::types::VectorComplexF64,
         y: &::types::VectorComplexF64,
-    ) -> (Value, ::types::ComplexF64) {
+    ) -> Result<::types::ComplexF64, Error> {
         let mut dotu = ::types::ComplexF64::default().unwrap();
         let ret = unsafe { sys::gsl_blas_zdotu(x.unwrap_shared(), y.unwrap_shared(), &mut dotu) };
-        (::Value::from(ret), ::types::ComplexF64::wrap(dotu))
+        result_handler!(ret, ::types::ComplexF64::wrap(dotu), "gsl_blas_zdotu")
     }
 
     /// This function computes the complex conjugate scalar product x^H y for the vectors x and y,
@@ -89,10 +95,10 @@ pub mod level1 {
     pub fn cdotc(
         x: &::types::VectorComplexF32,
         y: &::types::VectorComplexF32,
-    ) -> (Value, ::types::ComplexF32) {
+    ) -> Result<::types::ComplexF32, Error> {
         let mut dotc = ::types::ComplexF32::default().unwrap();
         let ret = unsafe { sys::gsl_blas_cdotc(x.unwrap_shared(), y.unwrap_shared(), &mut dotc) };
-        (::Value::from(ret), ::types::ComplexF32::wrap(dotc))
+        result_handler!(ret, ::types::ComplexF32::wrap(dotc), "gsl_blas_cdotc")
     }
 
     /// This function computes the complex conjugate scalar product x^H y for the vectors x and y,
@@ -103,10 +109,10 @@ pub mod level1 {
     pub fn zdotc(
         x: &::types::VectorComplexF64,
         y: &::types::VectorComplexF64,
-    ) -> (Value, ::types::ComplexF64) {
+    ) -> Result<::types::ComplexF64, Error> {
         let mut dotc = ::types::ComplexF64::default().unwrap();
         let ret = unsafe { sys::gsl_blas_zdotc(x.unwrap_shared(), y.unwrap_shared(), &mut dotc) };
-        (::Value::from(ret), ::types::ComplexF64::wrap(dotc))
+        result_handler!(ret, ::types::ComplexF64::wrap(dotc), "gsl_blas_zdotc")
     }
 
     /// This function computes the Euclidean norm ||x||_2 = \sqrt {\sum x_i^2} of the vector x.
@@ -195,62 +201,92 @@ pub mod level1 {
 
     /// This function exchanges the elements of the vectors x and y.
     #[doc(alias = "gsl_blas_sswap")]
-    pub fn sswap(x: &mut ::types::VectorF32, y: &mut ::types::VectorF32) -> Value {
-        Value::from(unsafe { sys::gsl_blas_sswap(x.unwrap_unique(), y.unwrap_unique()) })
+    pub fn sswap(x: &mut ::types::VectorF32, y: &mut ::types::VectorF32) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_blas_sswap(x.unwrap_unique(), y.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_sswap")
     }
 
     /// This function exchanges the elements of the vectors x and y.
     #[doc(alias = "gsl_blas_dswap")]
-    pub fn dswap(x: &mut ::types::VectorF64, y: &mut ::types::VectorF64) -> Value {
-        Value::from(unsafe { sys::gsl_blas_dswap(x.unwrap_unique(), y.unwrap_unique()) })
+    pub fn dswap(x: &mut ::types::VectorF64, y: &mut ::types::VectorF64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_blas_dswap(x.unwrap_unique(), y.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_dswap")
     }
 
     /// This function exchanges the elements of the vectors x and y.
     #[doc(alias = "gsl_blas_cswap")]
-    pub fn cswap(x: &mut ::types::VectorComplexF32, y: &mut ::types::VectorComplexF32) -> Value {
-        Value::from(unsafe { sys::gsl_blas_cswap(x.unwrap_unique(), y.unwrap_unique()) })
+    pub fn cswap(
+        x: &mut ::types::VectorComplexF32,
+        y: &mut ::types::VectorComplexF32,
+    ) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_blas_cswap(x.unwrap_unique(), y.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_cswap")
     }
 
     /// This function exchanges the elements of the vectors x and y.
     #[doc(alias = "gsl_blas_zswap")]
-    pub fn zswap(x: &mut ::types::VectorComplexF64, y: &mut ::types::VectorComplexF64) -> Value {
-        Value::from(unsafe { sys::gsl_blas_zswap(x.unwrap_unique(), y.unwrap_unique()) })
+    pub fn zswap(
+        x: &mut ::types::VectorComplexF64,
+        y: &mut ::types::VectorComplexF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_blas_zswap(x.unwrap_unique(), y.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_zswap")
     }
 
     /// This function copy the elements of the vector x into the vector y.
     #[doc(alias = "gsl_blas_scopy")]
-    pub fn scopy(x: &mut ::types::VectorF32, y: &mut ::types::VectorF32) -> Value {
-        Value::from(unsafe { sys::gsl_blas_scopy(x.unwrap_unique(), y.unwrap_unique()) })
+    pub fn scopy(x: &mut ::types::VectorF32, y: &mut ::types::VectorF32) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_blas_scopy(x.unwrap_unique(), y.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_scopy")
     }
 
     /// This function copy the elements of the vector x into the vector y.
     #[doc(alias = "gsl_blas_dcopy")]
-    pub fn dcopy(x: &mut ::types::VectorF64, y: &mut ::types::VectorF64) -> Value {
-        Value::from(unsafe { sys::gsl_blas_dcopy(x.unwrap_unique(), y.unwrap_unique()) })
+    pub fn dcopy(x: &mut ::types::VectorF64, y: &mut ::types::VectorF64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_blas_dcopy(x.unwrap_unique(), y.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_dcopy")
     }
 
     /// This function copy the elements of the vector x into the vector y.
     #[doc(alias = "gsl_blas_ccopy")]
-    pub fn ccopy(x: &mut ::types::VectorComplexF32, y: &mut ::types::VectorComplexF32) -> Value {
-        Value::from(unsafe { sys::gsl_blas_ccopy(x.unwrap_unique(), y.unwrap_unique()) })
+    pub fn ccopy(
+        x: &mut ::types::VectorComplexF32,
+        y: &mut ::types::VectorComplexF32,
+    ) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_blas_ccopy(x.unwrap_unique(), y.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_ccopy")
     }
 
     /// This function copy the elements of the vector x into the vector y.
     #[doc(alias = "gsl_blas_zcopy")]
-    pub fn zcopy(x: &mut ::types::VectorComplexF64, y: &mut ::types::VectorComplexF64) -> Value {
-        Value::from(unsafe { sys::gsl_blas_zcopy(x.unwrap_unique(), y.unwrap_unique()) })
+    pub fn zcopy(
+        x: &mut ::types::VectorComplexF64,
+        y: &mut ::types::VectorComplexF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_blas_zcopy(x.unwrap_unique(), y.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_zcopy")
     }
 
     /// This function computes the sum y = \alpha x + y for the vectors x and y.
     #[doc(alias = "gsl_blas_saxpy")]
-    pub fn saxpy(alpha: f32, x: &::types::VectorF32, y: &mut ::types::VectorF32) -> Value {
-        Value::from(unsafe { sys::gsl_blas_saxpy(alpha, x.unwrap_shared(), y.unwrap_unique()) })
+    pub fn saxpy(
+        alpha: f32,
+        x: &::types::VectorF32,
+        y: &mut ::types::VectorF32,
+    ) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_blas_saxpy(alpha, x.unwrap_shared(), y.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_saxpy")
     }
 
     /// This function computes the sum y = \alpha x + y for the vectors x and y.
     #[doc(alias = "gsl_blas_daxpy")]
-    pub fn daxpy(alpha: f64, x: &::types::VectorF64, y: &mut ::types::VectorF64) -> Value {
-        Value::from(unsafe { sys::gsl_blas_daxpy(alpha, x.unwrap_shared(), y.unwrap_unique()) })
+    pub fn daxpy(
+        alpha: f64,
+        x: &::types::VectorF64,
+        y: &mut ::types::VectorF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_blas_daxpy(alpha, x.unwrap_shared(), y.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_daxpy")
     }
 
     /// This function computes the sum y = \alpha x + y for the vectors x and y.
@@ -259,14 +295,10 @@ pub mod level1 {
         alpha: &::types::ComplexF32,
         x: &::types::VectorComplexF32,
         y: &mut ::types::VectorComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
-            sys::gsl_blas_caxpy(
-                ::std::mem::transmute(*alpha),
-                x.unwrap_shared(),
-                y.unwrap_unique(),
-            )
-        })
+    ) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_blas_caxpy(alpha.unwrap(), x.unwrap_shared(), y.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_caxpy")
     }
 
     /// This function computes the sum y = \alpha x + y for the vectors x and y.
@@ -275,14 +307,10 @@ pub mod level1 {
         alpha: &::types::ComplexF64,
         x: &::types::VectorComplexF64,
         y: &mut ::types::VectorComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
-            sys::gsl_blas_zaxpy(
-                ::std::mem::transmute(*alpha),
-                x.unwrap_shared(),
-                y.unwrap_unique(),
-            )
-        })
+    ) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_blas_zaxpy(alpha.unwrap(), x.unwrap_shared(), y.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_zaxpy")
     }
 
     /// This function rescales the vector x by the multiplicative factor alpha.
@@ -300,13 +328,13 @@ pub mod level1 {
     /// This function rescales the vector x by the multiplicative factor alpha.
     #[doc(alias = "gsl_blas_cscal")]
     pub fn cscal(alpha: &::types::ComplexF32, x: &mut ::types::VectorComplexF32) {
-        unsafe { sys::gsl_blas_cscal(::std::mem::transmute(*alpha), x.unwrap_unique()) }
+        unsafe { sys::gsl_blas_cscal(alpha.unwrap(), x.unwrap_unique()) }
     }
 
     /// This function rescales the vector x by the multiplicative factor alpha.
     #[doc(alias = "gsl_blas_zscal")]
     pub fn zscal(alpha: &::types::ComplexF64, x: &mut ::types::VectorComplexF64) {
-        unsafe { sys::gsl_blas_zscal(::std::mem::transmute(*alpha), x.unwrap_unique()) }
+        unsafe { sys::gsl_blas_zscal(alpha.unwrap(), x.unwrap_unique()) }
     }
 
     /// This function rescales the vector x by the multiplicative factor alpha.
@@ -331,15 +359,16 @@ pub mod level1 {
     ///
     /// The variables a and b are overwritten by the routine.
     #[doc(alias = "gsl_blas_srotg")]
-    pub fn srotg(a: &mut [f32], b: &mut [f32], c: &mut [f32], d: &mut [f32]) -> Value {
-        Value::from(unsafe {
+    pub fn srotg(a: &mut [f32], b: &mut [f32], c: &mut [f32], d: &mut [f32]) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_srotg(
                 a.as_mut_ptr(),
                 b.as_mut_ptr(),
                 c.as_mut_ptr(),
                 d.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_srotg")
     }
 
     /// This function computes a Givens rotation (c,s) which zeroes the vector (a,b),
@@ -352,34 +381,53 @@ pub mod level1 {
     ///
     /// The variables a and b are overwritten by the routine.
     #[doc(alias = "gsl_blas_drotg")]
-    pub fn drotg(a: &mut [f64], b: &mut [f64], c: &mut [f64], d: &mut [f64]) -> Value {
-        Value::from(unsafe {
+    pub fn drotg(a: &mut [f64], b: &mut [f64], c: &mut [f64], d: &mut [f64]) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_drotg(
                 a.as_mut_ptr(),
                 b.as_mut_ptr(),
                 c.as_mut_ptr(),
                 d.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_drotg")
     }
 
     /// This function applies a Givens rotation (x', y') = (c x + s y, -s x + c y) to the vectors x, y.
     #[doc(alias = "gsl_blas_srot")]
-    pub fn srot(a: &mut ::types::VectorF32, b: &mut ::types::VectorF32, c: f32, d: f32) -> Value {
-        Value::from(unsafe { sys::gsl_blas_srot(a.unwrap_unique(), b.unwrap_unique(), c, d) })
+    pub fn srot(
+        a: &mut ::types::VectorF32,
+        b: &mut ::types::VectorF32,
+        c: f32,
+        d: f32,
+    ) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_blas_srot(a.unwrap_unique(), b.unwrap_unique(), c, d) };
+        result_handler!(ret, (), "gsl_blas_srot")
     }
 
     /// This function applies a Givens rotation (x', y') = (c x + s y, -s x + c y) to the vectors x, y.
     #[doc(alias = "gsl_blas_drot")]
-    pub fn drot(a: &mut ::types::VectorF64, b: &mut ::types::VectorF64, c: f64, d: f64) -> Value {
-        Value::from(unsafe { sys::gsl_blas_drot(a.unwrap_unique(), b.unwrap_unique(), c, d) })
+    pub fn drot(
+        a: &mut ::types::VectorF64,
+        b: &mut ::types::VectorF64,
+        c: f64,
+        d: f64,
+    ) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_blas_drot(a.unwrap_unique(), b.unwrap_unique(), c, d) };
+        result_handler!(ret, (), "gsl_blas_drot")
     }
 
     /// This function computes a modified Givens transformation.
     /// The modified Givens transformation is defined in the original Level-1 BLAS specification, given in the references.
     #[doc(alias = "gsl_blas_srotmg")]
-    pub fn srotmg(d1: &mut [f32], d2: &mut [f32], b1: &mut [f32], b2: f32, P: &mut [f32]) -> Value {
-        Value::from(unsafe {
+    pub fn srotmg(
+        d1: &mut [f32],
+        d2: &mut [f32],
+        b1: &mut [f32],
+        b2: f32,
+        P: &mut [f32],
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_srotmg(
                 d1.as_mut_ptr(),
                 d2.as_mut_ptr(),
@@ -387,14 +435,21 @@ pub mod level1 {
                 b2,
                 P.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_srotmg")
     }
 
     /// This function computes a modified Givens transformation.
     /// The modified Givens transformation is defined in the original Level-1 BLAS specification, given in the references.
     #[doc(alias = "gsl_blas_drotmg")]
-    pub fn drotmg(d1: &mut [f64], d2: &mut [f64], b1: &mut [f64], b2: f64, P: &mut [f64]) -> Value {
-        Value::from(unsafe {
+    pub fn drotmg(
+        d1: &mut [f64],
+        d2: &mut [f64],
+        b1: &mut [f64],
+        b2: f64,
+        P: &mut [f64],
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_drotmg(
                 d1.as_mut_ptr(),
                 d2.as_mut_ptr(),
@@ -402,30 +457,40 @@ pub mod level1 {
                 b2,
                 P.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_drotmg")
     }
 
     /// This function applies a modified Givens transformation.
     #[doc(alias = "gsl_blas_srotm")]
-    pub fn srotm(x: &mut ::types::VectorF32, y: &mut ::types::VectorF32, P: &mut [f32]) -> Value {
-        Value::from(unsafe {
-            sys::gsl_blas_srotm(x.unwrap_unique(), y.unwrap_unique(), P.as_mut_ptr())
-        })
+    pub fn srotm(
+        x: &mut ::types::VectorF32,
+        y: &mut ::types::VectorF32,
+        P: &mut [f32],
+    ) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_blas_srotm(x.unwrap_unique(), y.unwrap_unique(), P.as_mut_ptr()) };
+        result_handler!(ret, (), "gsl_blas_srotm")
     }
 
     /// This function applies a modified Givens transformation.
     #[doc(alias = "gsl_blas_drotm")]
-    pub fn drotm(x: &mut ::types::VectorF64, y: &mut ::types::VectorF64, P: &mut [f64]) -> Value {
-        Value::from(unsafe {
-            sys::gsl_blas_drotm(x.unwrap_unique(), y.unwrap_unique(), P.as_mut_ptr())
-        })
+    pub fn drotm(
+        x: &mut ::types::VectorF64,
+        y: &mut ::types::VectorF64,
+        P: &mut [f64],
+    ) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_blas_drotm(x.unwrap_unique(), y.unwrap_unique(), P.as_mut_ptr()) };
+        result_handler!(ret, (), "gsl_blas_drotm")
     }
 }
 
 pub mod level2 {
     use crate::enums;
-    use crate::Value;
+    use crate::Error;
     use ffi::FFI;
+    use types::complex::CFFI;
 
     /// This function computes the matrix-vector product and sum y = \alpha op(A) x + \beta y, where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
     #[doc(alias = "gsl_blas_sgemv")]
@@ -436,8 +501,8 @@ pub mod level2 {
         x: &::types::VectorF32,
         beta: f32,
         y: &mut ::types::VectorF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_sgemv(
                 transA.into(),
                 alpha,
@@ -446,7 +511,8 @@ pub mod level2 {
                 beta,
                 y.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_sgemv")
     }
 
     /// This function computes the matrix-vector product and sum y = \alpha op(A) x + \beta y, where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
@@ -458,8 +524,8 @@ pub mod level2 {
         x: &::types::VectorF64,
         beta: f64,
         y: &mut ::types::VectorF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_dgemv(
                 transA.into(),
                 alpha,
@@ -468,7 +534,8 @@ pub mod level2 {
                 beta,
                 y.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_dgemv")
     }
 
     /// This function computes the matrix-vector product and sum y = \alpha op(A) x + \beta y, where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
@@ -480,17 +547,18 @@ pub mod level2 {
         x: &::types::VectorComplexF32,
         beta: &::types::ComplexF32,
         y: &mut ::types::VectorComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_cgemv(
                 transA.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 x.unwrap_shared(),
-                ::std::mem::transmute(*beta),
+                beta.unwrap(),
                 y.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_cgemv")
     }
 
     /// This function computes the matrix-vector product and sum y = \alpha op(A) x + \beta y, where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
@@ -502,17 +570,18 @@ pub mod level2 {
         x: &::types::VectorComplexF64,
         beta: &::types::ComplexF64,
         y: &mut ::types::VectorComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_zgemv(
                 transA.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 x.unwrap_shared(),
-                ::std::mem::transmute(*beta),
+                beta.unwrap(),
                 y.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_zgemv")
     }
 
     /// This function computes the matrix-vector product x = op(A) x for the triangular matrix A, where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
@@ -525,8 +594,8 @@ pub mod level2 {
         diag: enums::CblasDiag,
         A: &::types::MatrixF32,
         x: &mut ::types::VectorF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_strmv(
                 uplo.into(),
                 transA.into(),
@@ -534,7 +603,8 @@ pub mod level2 {
                 A.unwrap_shared(),
                 x.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_strmv")
     }
 
     /// This function computes the matrix-vector product x = op(A) x for the triangular matrix A, where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
@@ -547,8 +617,8 @@ pub mod level2 {
         diag: enums::CblasDiag,
         A: &::types::MatrixF64,
         x: &mut ::types::VectorF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_dtrmv(
                 uplo.into(),
                 transA.into(),
@@ -556,7 +626,8 @@ pub mod level2 {
                 A.unwrap_shared(),
                 x.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_dtrmv")
     }
 
     /// This function computes the matrix-vector product x = op(A) x for the triangular matrix A, where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
@@ -569,8 +640,8 @@ pub mod level2 {
         diag: enums::CblasDiag,
         A: &::types::MatrixComplexF32,
         x: &mut ::types::VectorComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_ctrmv(
                 uplo.into(),
                 transA.into(),
@@ -578,7 +649,8 @@ pub mod level2 {
                 A.unwrap_shared(),
                 x.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_ctrmv")
     }
 
     /// This function computes the matrix-vector product x = op(A) x for the triangular matrix A, where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
@@ -591,8 +663,8 @@ pub mod level2 {
         diag: enums::CblasDiag,
         A: &::types::MatrixComplexF64,
         x: &mut ::types::VectorComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_ztrmv(
                 uplo.into(),
                 transA.into(),
@@ -600,7 +672,8 @@ pub mod level2 {
                 A.unwrap_shared(),
                 x.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_ztrmv")
     }
 
     /// This function computes inv(op(A)) x for x, where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
@@ -613,8 +686,8 @@ pub mod level2 {
         diag: enums::CblasDiag,
         A: &::types::MatrixF32,
         x: &mut ::types::VectorF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_strsv(
                 uplo.into(),
                 transA.into(),
@@ -622,7 +695,8 @@ pub mod level2 {
                 A.unwrap_shared(),
                 x.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_strsv")
     }
 
     /// This function computes inv(op(A)) x for x, where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
@@ -635,8 +709,8 @@ pub mod level2 {
         diag: enums::CblasDiag,
         A: &::types::MatrixF64,
         x: &mut ::types::VectorF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_dtrsv(
                 uplo.into(),
                 transA.into(),
@@ -644,7 +718,8 @@ pub mod level2 {
                 A.unwrap_shared(),
                 x.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_dtrsv")
     }
 
     /// This function computes inv(op(A)) x for x, where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
@@ -657,8 +732,8 @@ pub mod level2 {
         diag: enums::CblasDiag,
         A: &::types::MatrixComplexF32,
         x: &mut ::types::VectorComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_ctrsv(
                 uplo.into(),
                 transA.into(),
@@ -666,7 +741,8 @@ pub mod level2 {
                 A.unwrap_shared(),
                 x.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_ctrsv")
     }
 
     /// This function computes inv(op(A)) x for x, where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
@@ -679,8 +755,8 @@ pub mod level2 {
         diag: enums::CblasDiag,
         A: &::types::MatrixComplexF64,
         x: &mut ::types::VectorComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_ztrsv(
                 uplo.into(),
                 transA.into(),
@@ -688,7 +764,8 @@ pub mod level2 {
                 A.unwrap_shared(),
                 x.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_ztrsv")
     }
 
     /// These functions compute the matrix-vector product and sum y = \alpha A x + \beta y for the symmetric matrix A.
@@ -702,8 +779,8 @@ pub mod level2 {
         x: &::types::VectorF32,
         beta: f32,
         y: &mut ::types::VectorF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_ssymv(
                 uplo.into(),
                 alpha,
@@ -712,7 +789,8 @@ pub mod level2 {
                 beta,
                 y.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_ssymv")
     }
 
     /// These functions compute the matrix-vector product and sum y = \alpha A x + \beta y for the symmetric matrix A.
@@ -726,8 +804,8 @@ pub mod level2 {
         x: &::types::VectorF64,
         beta: f64,
         y: &mut ::types::VectorF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_dsymv(
                 uplo.into(),
                 alpha,
@@ -736,7 +814,8 @@ pub mod level2 {
                 beta,
                 y.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_dsymv")
     }
 
     /// These functions compute the matrix-vector product and sum y = \alpha A x + \beta y for the hermitian matrix A.
@@ -750,17 +829,18 @@ pub mod level2 {
         x: &::types::VectorComplexF32,
         beta: &::types::ComplexF32,
         y: &mut ::types::VectorComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_chemv(
                 uplo.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 x.unwrap_shared(),
-                ::std::mem::transmute(*beta),
+                beta.unwrap(),
                 y.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_chemv")
     }
 
     /// These functions compute the matrix-vector product and sum y = \alpha A x + \beta y for the hermitian matrix A.
@@ -774,17 +854,18 @@ pub mod level2 {
         x: &::types::VectorComplexF64,
         beta: &::types::ComplexF64,
         y: &mut ::types::VectorComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_zhemv(
                 uplo.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 x.unwrap_shared(),
-                ::std::mem::transmute(*beta),
+                beta.unwrap(),
                 y.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_zhemv")
     }
 
     /// This function computes the rank-1 update A = \alpha x y^T + A of the matrix A.
@@ -794,15 +875,16 @@ pub mod level2 {
         x: &::types::VectorF32,
         y: &::types::VectorF32,
         A: &mut ::types::MatrixF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_sger(
                 alpha,
                 x.unwrap_shared(),
                 y.unwrap_shared(),
                 A.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_sger")
     }
 
     /// This function computes the rank-1 update A = \alpha x y^T + A of the matrix A.
@@ -812,15 +894,16 @@ pub mod level2 {
         x: &::types::VectorF64,
         y: &::types::VectorF64,
         A: &mut ::types::MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_dger(
                 alpha,
                 x.unwrap_shared(),
                 y.unwrap_shared(),
                 A.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_dger")
     }
 
     /// This function computes the rank-1 update A = \alpha x y^T + A of the matrix A.
@@ -830,15 +913,16 @@ pub mod level2 {
         x: &::types::VectorComplexF32,
         y: &::types::VectorComplexF32,
         A: &mut ::types::MatrixComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_cgeru(
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 x.unwrap_shared(),
                 y.unwrap_shared(),
                 A.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_cgeru")
     }
 
     /// This function computes the rank-1 update A = \alpha x y^T + A of the matrix A.
@@ -848,15 +932,16 @@ pub mod level2 {
         x: &::types::VectorComplexF64,
         y: &::types::VectorComplexF64,
         A: &mut ::types::MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_zgeru(
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 x.unwrap_shared(),
                 y.unwrap_shared(),
                 A.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_zgeru")
     }
 
     /// This function computes the conjugate rank-1 update A = \alpha x y^H + A of the matrix A.
@@ -866,15 +951,16 @@ pub mod level2 {
         x: &::types::VectorComplexF32,
         y: &::types::VectorComplexF32,
         A: &mut ::types::MatrixComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_cgerc(
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 x.unwrap_shared(),
                 y.unwrap_shared(),
                 A.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_cgerc")
     }
 
     /// This function computes the conjugate rank-1 update A = \alpha x y^H + A of the matrix A.
@@ -884,15 +970,16 @@ pub mod level2 {
         x: &::types::VectorComplexF64,
         y: &::types::VectorComplexF64,
         A: &mut ::types::MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_zgerc(
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 x.unwrap_shared(),
                 y.unwrap_shared(),
                 A.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_zgerc")
     }
 
     /// This function computes the symmetric rank-1 update A = \alpha x x^T + A of the symmetric matrix A. Since the matrix A is symmetric only its upper half or lower half need to be stored.
@@ -903,10 +990,10 @@ pub mod level2 {
         alpha: f32,
         x: &::types::VectorF32,
         A: &mut ::types::MatrixF32,
-    ) -> Value {
-        Value::from(unsafe {
-            sys::gsl_blas_ssyr(uplo.into(), alpha, x.unwrap_shared(), A.unwrap_unique())
-        })
+    ) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_blas_ssyr(uplo.into(), alpha, x.unwrap_shared(), A.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_ssyr")
     }
 
     /// This function computes the symmetric rank-1 update A = \alpha x x^T + A of the symmetric matrix A. Since the matrix A is symmetric only its upper half or lower half need to be stored.
@@ -917,10 +1004,10 @@ pub mod level2 {
         alpha: f64,
         x: &::types::VectorF64,
         A: &mut ::types::MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
-            sys::gsl_blas_dsyr(uplo.into(), alpha, x.unwrap_shared(), A.unwrap_unique())
-        })
+    ) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_blas_dsyr(uplo.into(), alpha, x.unwrap_shared(), A.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_dsyr")
     }
 
     /// These functions compute the hermitian rank-1 update A = \alpha x x^H + A of the hermitian matrix A.
@@ -933,10 +1020,10 @@ pub mod level2 {
         alpha: f32,
         x: &::types::VectorComplexF32,
         A: &mut ::types::MatrixComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
-            sys::gsl_blas_cher(uplo.into(), alpha, x.unwrap_shared(), A.unwrap_unique())
-        })
+    ) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_blas_cher(uplo.into(), alpha, x.unwrap_shared(), A.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_cher")
     }
 
     /// These functions compute the hermitian rank-1 update A = \alpha x x^H + A of the hermitian matrix A.
@@ -949,10 +1036,10 @@ pub mod level2 {
         alpha: f64,
         x: &::types::VectorComplexF64,
         A: &mut ::types::MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
-            sys::gsl_blas_zher(uplo.into(), alpha, x.unwrap_shared(), A.unwrap_unique())
-        })
+    ) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_blas_zher(uplo.into(), alpha, x.unwrap_shared(), A.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_blas_zher")
     }
 
     /// These functions compute the symmetric rank-2 update A = \alpha x y^T + \alpha y x^T + A of the symmetric matrix A.
@@ -965,8 +1052,8 @@ pub mod level2 {
         x: &::types::VectorF32,
         y: &::types::VectorF32,
         A: &mut ::types::MatrixF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_ssyr2(
                 uplo.into(),
                 alpha,
@@ -974,7 +1061,8 @@ pub mod level2 {
                 y.unwrap_shared(),
                 A.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_ssyr2")
     }
 
     /// These functions compute the symmetric rank-2 update A = \alpha x y^T + \alpha y x^T + A of the symmetric matrix A.
@@ -987,8 +1075,8 @@ pub mod level2 {
         x: &::types::VectorF64,
         y: &::types::VectorF64,
         A: &mut ::types::MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_dsyr2(
                 uplo.into(),
                 alpha,
@@ -996,7 +1084,8 @@ pub mod level2 {
                 y.unwrap_shared(),
                 A.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_dsyr2")
     }
 
     /// These functions compute the hermitian rank-2 update A = \alpha x y^H + \alpha^* y x^H + A of the hermitian matrix A.
@@ -1010,16 +1099,17 @@ pub mod level2 {
         x: &::types::VectorComplexF32,
         y: &::types::VectorComplexF32,
         A: &mut ::types::MatrixComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_cher2(
                 uplo.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 x.unwrap_shared(),
                 y.unwrap_shared(),
                 A.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_cher2")
     }
 
     /// These functions compute the hermitian rank-2 update A = \alpha x y^H + \alpha^* y x^H + A of the hermitian matrix A.
@@ -1033,23 +1123,25 @@ pub mod level2 {
         x: &::types::VectorComplexF64,
         y: &::types::VectorComplexF64,
         A: &mut ::types::MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_zher2(
                 uplo.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 x.unwrap_shared(),
                 y.unwrap_shared(),
                 A.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_zher2")
     }
 }
 
 pub mod level3 {
     use crate::enums;
-    use crate::Value;
+    use crate::Error;
     use ffi::FFI;
+    use types::complex::CFFI;
 
     /// This function computes the matrix-matrix product and sum C = \alpha op(A) op(B) + \beta C where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans and similarly for the parameter TransB.
     #[doc(alias = "gsl_blas_sgemm")]
@@ -1061,8 +1153,8 @@ pub mod level3 {
         B: &::types::MatrixF32,
         beta: f32,
         C: &mut ::types::MatrixF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_sgemm(
                 transA.into(),
                 transB.into(),
@@ -1072,7 +1164,8 @@ pub mod level3 {
                 beta,
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_sgemm")
     }
 
     /// This function computes the matrix-matrix product and sum C = \alpha op(A) op(B) + \beta C where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans and similarly for the parameter TransB.
@@ -1085,8 +1178,8 @@ pub mod level3 {
         B: &::types::MatrixF64,
         beta: f64,
         C: &mut ::types::MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_dgemm(
                 transA.into(),
                 transB.into(),
@@ -1096,7 +1189,8 @@ pub mod level3 {
                 beta,
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_dgemm")
     }
 
     /// This function computes the matrix-matrix product and sum C = \alpha op(A) op(B) + \beta C where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans and similarly for the parameter TransB.
@@ -1109,18 +1203,19 @@ pub mod level3 {
         B: &::types::MatrixComplexF32,
         beta: &::types::ComplexF32,
         C: &mut ::types::MatrixComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_cgemm(
                 transA.into(),
                 transB.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 B.unwrap_shared(),
-                ::std::mem::transmute(*beta),
+                beta.unwrap(),
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_cgemm")
     }
 
     /// This function computes the matrix-matrix product and sum C = \alpha op(A) op(B) + \beta C where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans and similarly for the parameter TransB.
@@ -1133,18 +1228,19 @@ pub mod level3 {
         B: &::types::MatrixComplexF64,
         beta: &::types::ComplexF64,
         C: &mut ::types::MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_zgemm(
                 transA.into(),
                 transB.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 B.unwrap_shared(),
-                ::std::mem::transmute(*beta),
+                beta.unwrap(),
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_zgemm")
     }
 
     /// This function computes the matrix-matrix product and sum C = \alpha A B + \beta C for Side is CblasLeft and C = \alpha B A + \beta C for Side is CblasRight, where the matrix A is symmetric.
@@ -1158,8 +1254,8 @@ pub mod level3 {
         B: &::types::MatrixF32,
         beta: f32,
         C: &mut ::types::MatrixF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_ssymm(
                 side.into(),
                 uplo.into(),
@@ -1169,7 +1265,8 @@ pub mod level3 {
                 beta,
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_ssymm")
     }
 
     /// This function computes the matrix-matrix product and sum C = \alpha A B + \beta C for Side is CblasLeft and C = \alpha B A + \beta C for Side is CblasRight, where the matrix A is symmetric.
@@ -1183,8 +1280,8 @@ pub mod level3 {
         B: &::types::MatrixF64,
         beta: f64,
         C: &mut ::types::MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_dsymm(
                 side.into(),
                 uplo.into(),
@@ -1194,7 +1291,8 @@ pub mod level3 {
                 beta,
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_dsymm")
     }
 
     /// This function computes the matrix-matrix product and sum C = \alpha A B + \beta C for Side is CblasLeft and C = \alpha B A + \beta C for Side is CblasRight, where the matrix A is symmetric.
@@ -1208,18 +1306,19 @@ pub mod level3 {
         B: &::types::MatrixComplexF32,
         beta: &::types::ComplexF32,
         C: &mut ::types::MatrixComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_csymm(
                 side.into(),
                 uplo.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 B.unwrap_shared(),
-                ::std::mem::transmute(*beta),
+                beta.unwrap(),
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_csymm")
     }
 
     /// This function computes the matrix-matrix product and sum C = \alpha A B + \beta C for Side is CblasLeft and C = \alpha B A + \beta C for Side is CblasRight, where the matrix A is symmetric.
@@ -1233,18 +1332,19 @@ pub mod level3 {
         B: &::types::MatrixComplexF64,
         beta: &::types::ComplexF64,
         C: &mut ::types::MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_zsymm(
                 side.into(),
                 uplo.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 B.unwrap_shared(),
-                ::std::mem::transmute(*beta),
+                beta.unwrap(),
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_zsymm")
     }
 
     /// This function computes the matrix-matrix product and sum C = \alpha A B + \beta C for Side is Left and C = \alpha B A + \beta C for Side is Right, where the matrix A is hermitian.
@@ -1259,18 +1359,19 @@ pub mod level3 {
         B: &::types::MatrixComplexF32,
         beta: &::types::ComplexF32,
         C: &mut ::types::MatrixComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_chemm(
                 side.into(),
                 uplo.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 B.unwrap_shared(),
-                ::std::mem::transmute(*beta),
+                beta.unwrap(),
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_chemm")
     }
 
     /// This function computes the matrix-matrix product and sum C = \alpha A B + \beta C for Side is CblasLeft and C = \alpha B A + \beta C for Side is CblasRight, where the matrix A is hermitian.
@@ -1285,18 +1386,19 @@ pub mod level3 {
         B: &::types::MatrixComplexF64,
         beta: &::types::ComplexF64,
         C: &mut ::types::MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_zhemm(
                 side.into(),
                 uplo.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 B.unwrap_shared(),
-                ::std::mem::transmute(*beta),
+                beta.unwrap(),
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_zhemm")
     }
 
     /// This function computes the matrix-matrix product B = \alpha op(A) B for Side is Left and B = \alpha B op(A) for Side is CblasRight.
@@ -1312,8 +1414,8 @@ pub mod level3 {
         alpha: f32,
         A: &::types::MatrixF32,
         B: &mut ::types::MatrixF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_strmm(
                 side.into(),
                 uplo.into(),
@@ -1323,7 +1425,8 @@ pub mod level3 {
                 A.unwrap_shared(),
                 B.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_strmm")
     }
 
     /// This function computes the matrix-matrix product B = \alpha op(A) B for Side is Left and B = \alpha B op(A) for Side is CblasRight.
@@ -1339,8 +1442,8 @@ pub mod level3 {
         alpha: f64,
         A: &::types::MatrixF64,
         B: &mut ::types::MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_dtrmm(
                 side.into(),
                 uplo.into(),
@@ -1350,7 +1453,8 @@ pub mod level3 {
                 A.unwrap_shared(),
                 B.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_dtrmm")
     }
 
     /// This function computes the matrix-matrix product B = \alpha op(A) B for Side is Left and B = \alpha B op(A) for Side is CblasRight.
@@ -1366,18 +1470,19 @@ pub mod level3 {
         alpha: &::types::ComplexF32,
         A: &::types::MatrixComplexF32,
         B: &mut ::types::MatrixComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_ctrmm(
                 side.into(),
                 uplo.into(),
                 transA.into(),
                 diag.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 B.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_ctrmm")
     }
 
     /// This function computes the matrix-matrix product B = \alpha op(A) B for Side is Left and B = \alpha B op(A) for Side is CblasRight.
@@ -1393,18 +1498,19 @@ pub mod level3 {
         alpha: &::types::ComplexF64,
         A: &::types::MatrixComplexF64,
         B: &mut ::types::MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_ztrmm(
                 side.into(),
                 uplo.into(),
                 transA.into(),
                 diag.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 B.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_ztrmm")
     }
 
     /// This function computes the inverse-matrix matrix product B = \alpha op(inv(A))B for Side is Left and B = \alpha B op(inv(A)) for Side is Right.
@@ -1420,8 +1526,8 @@ pub mod level3 {
         alpha: f32,
         A: &::types::MatrixF32,
         B: &mut ::types::MatrixF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_strsm(
                 side.into(),
                 uplo.into(),
@@ -1431,7 +1537,8 @@ pub mod level3 {
                 A.unwrap_shared(),
                 B.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_strsm")
     }
 
     /// This function computes the inverse-matrix matrix product B = \alpha op(inv(A))B for Side is Left and B = \alpha B op(inv(A)) for Side is Right.
@@ -1447,8 +1554,8 @@ pub mod level3 {
         alpha: f64,
         A: &::types::MatrixF64,
         B: &mut ::types::MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_dtrsm(
                 side.into(),
                 uplo.into(),
@@ -1458,7 +1565,8 @@ pub mod level3 {
                 A.unwrap_shared(),
                 B.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_dtrsm")
     }
 
     /// This function computes the inverse-matrix matrix product B = \alpha op(inv(A))B for Side is Left and B = \alpha B op(inv(A)) for Side is Right.
@@ -1474,18 +1582,19 @@ pub mod level3 {
         alpha: &::types::ComplexF32,
         A: &::types::MatrixComplexF32,
         B: &mut ::types::MatrixComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_ctrsm(
                 side.into(),
                 uplo.into(),
                 transA.into(),
                 diag.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 B.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_ctrsm")
     }
 
     /// This function computes the inverse-matrix matrix product B = \alpha op(inv(A))B for Side is Left and B = \alpha B op(inv(A)) for Side is Right.
@@ -1501,18 +1610,19 @@ pub mod level3 {
         alpha: &::types::ComplexF64,
         A: &::types::MatrixComplexF64,
         B: &mut ::types::MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_ztrsm(
                 side.into(),
                 uplo.into(),
                 transA.into(),
                 diag.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 B.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_ztrsm")
     }
 
     /// This function computes a rank-k update of the symmetric matrix C, C = \alpha A A^T + \beta C when Trans is NoTrans and C = \alpha A^T A + \beta C when Trans is Trans.
@@ -1526,8 +1636,8 @@ pub mod level3 {
         A: &::types::MatrixF32,
         beta: f32,
         C: &mut ::types::MatrixF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_ssyrk(
                 uplo.into(),
                 trans.into(),
@@ -1536,7 +1646,8 @@ pub mod level3 {
                 beta,
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_ssyrk")
     }
 
     /// This function computes a rank-k update of the symmetric matrix C, C = \alpha A A^T + \beta C when Trans is NoTrans and C = \alpha A^T A + \beta C when Trans is Trans.
@@ -1550,8 +1661,8 @@ pub mod level3 {
         A: &::types::MatrixF64,
         beta: f64,
         C: &mut ::types::MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_dsyrk(
                 uplo.into(),
                 trans.into(),
@@ -1560,7 +1671,8 @@ pub mod level3 {
                 beta,
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_dsyrk")
     }
 
     /// This function computes a rank-k update of the symmetric matrix C, C = \alpha A A^T + \beta C when Trans is NoTrans and C = \alpha A^T A + \beta C when Trans is Trans.
@@ -1574,17 +1686,18 @@ pub mod level3 {
         A: &::types::MatrixComplexF32,
         beta: &::types::ComplexF32,
         C: &mut ::types::MatrixComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_csyrk(
                 uplo.into(),
                 trans.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
-                ::std::mem::transmute(*beta),
+                beta.unwrap(),
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_csyrk")
     }
 
     /// This function computes a rank-k update of the symmetric matrix C, C = \alpha A A^T + \beta C when Trans is NoTrans and C = \alpha A^T A + \beta C when Trans is Trans.
@@ -1598,17 +1711,18 @@ pub mod level3 {
         A: &::types::MatrixComplexF64,
         beta: &::types::ComplexF64,
         C: &mut ::types::MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_zsyrk(
                 uplo.into(),
                 trans.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
-                ::std::mem::transmute(*beta),
+                beta.unwrap(),
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_zsyrk")
     }
 
     /// These functions compute a rank-k update of the hermitian matrix C, C = \alpha A A^H + \beta C when Trans is NoTrans and C = \alpha A^H A + \beta C when Trans is ConjTrans.
@@ -1623,8 +1737,8 @@ pub mod level3 {
         A: &::types::MatrixComplexF32,
         beta: f32,
         C: &mut ::types::MatrixComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_cherk(
                 uplo.into(),
                 trans.into(),
@@ -1633,7 +1747,8 @@ pub mod level3 {
                 beta,
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_cherk")
     }
 
     /// These functions compute a rank-k update of the hermitian matrix C, C = \alpha A A^H + \beta C when Trans is NoTrans and C = \alpha A^H A + \beta C when Trans is ConjTrans.
@@ -1648,8 +1763,8 @@ pub mod level3 {
         A: &::types::MatrixComplexF64,
         beta: f64,
         C: &mut ::types::MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_zherk(
                 uplo.into(),
                 trans.into(),
@@ -1658,7 +1773,8 @@ pub mod level3 {
                 beta,
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_zherk")
     }
 
     /// This function computes a rank-2k update of the symmetric matrix C, C = \alpha A B^T + \alpha B A^T + \beta C when Trans is NoTrans and C = \alpha A^T B + \alpha B^T A + \beta C when Trans is Trans.
@@ -1673,8 +1789,8 @@ pub mod level3 {
         B: &::types::MatrixF32,
         beta: f32,
         C: &mut ::types::MatrixF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_ssyr2k(
                 uplo.into(),
                 trans.into(),
@@ -1684,7 +1800,8 @@ pub mod level3 {
                 beta,
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_ssyr2k")
     }
 
     /// This function computes a rank-2k update of the symmetric matrix C, C = \alpha A B^T + \alpha B A^T + \beta C when Trans is NoTrans and C = \alpha A^T B + \alpha B^T A + \beta C when Trans is Trans.
@@ -1699,8 +1816,8 @@ pub mod level3 {
         B: &::types::MatrixF64,
         beta: f64,
         C: &mut ::types::MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_dsyr2k(
                 uplo.into(),
                 trans.into(),
@@ -1710,7 +1827,8 @@ pub mod level3 {
                 beta,
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_dsyr2k")
     }
 
     /// This function computes a rank-2k update of the symmetric matrix C, C = \alpha A B^T + \alpha B A^T + \beta C when Trans is NoTrans and C = \alpha A^T B + \alpha B^T A + \beta C when Trans is Trans.
@@ -1725,18 +1843,19 @@ pub mod level3 {
         B: &::types::MatrixComplexF32,
         beta: &::types::ComplexF32,
         C: &mut ::types::MatrixComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_csyr2k(
                 uplo.into(),
                 trans.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 B.unwrap_shared(),
-                ::std::mem::transmute(*beta),
+                beta.unwrap(),
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_csyr2k")
     }
 
     /// This function computes a rank-2k update of the symmetric matrix C, C = \alpha A B^T + \alpha B A^T + \beta C when Trans is NoTrans and C = \alpha A^T B + \alpha B^T A + \beta C when Trans is Trans.
@@ -1751,18 +1870,19 @@ pub mod level3 {
         B: &::types::MatrixComplexF64,
         beta: &::types::ComplexF64,
         C: &mut ::types::MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_zsyr2k(
                 uplo.into(),
                 trans.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 B.unwrap_shared(),
-                ::std::mem::transmute(*beta),
+                beta.unwrap(),
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_zsyr2k")
     }
 
     /// This function computes a rank-2k update of the hermitian matrix C, C = \alpha A B^H + \alpha^* B A^H + \beta C when Trans is NoTrans and C = \alpha A^H B + \alpha^* B^H A + \beta C when Trans is ConjTrans.
@@ -1778,18 +1898,19 @@ pub mod level3 {
         B: &::types::MatrixComplexF32,
         beta: f32,
         C: &mut ::types::MatrixComplexF32,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_cher2k(
                 uplo.into(),
                 trans.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 B.unwrap_shared(),
                 beta,
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_cher2k")
     }
 
     /// This function computes a rank-2k update of the hermitian matrix C, C = \alpha A B^H + \alpha^* B A^H + \beta C when Trans is NoTrans and C = \alpha A^H B + \alpha^* B^H A + \beta C when Trans is ConjTrans.
@@ -1805,17 +1926,196 @@ pub mod level3 {
         B: &::types::MatrixComplexF64,
         beta: f64,
         C: &mut ::types::MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_blas_zher2k(
                 uplo.into(),
                 trans.into(),
-                ::std::mem::transmute(*alpha),
+                alpha.unwrap(),
                 A.unwrap_shared(),
                 B.unwrap_shared(),
                 beta,
                 C.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_blas_zher2k")
+    }
+}
+
+/// A matrix type with a BLAS `gemm` routine, used by [`gemm`].
+pub trait GemmMatrix: Sized {
+    /// The type of the `alpha` and `beta` factors.
+    type Scalar: Copy;
+
+    #[doc(hidden)]
+    fn one() -> Self::Scalar;
+
+    #[doc(hidden)]
+    fn zero() -> Self::Scalar;
+
+    #[doc(hidden)]
+    fn gemm(
+        trans_a: enums::CblasTranspose,
+        trans_b: enums::CblasTranspose,
+        alpha: Self::Scalar,
+        a: &Self,
+        b: &Self,
+        beta: Self::Scalar,
+        c: &mut Self,
+    ) -> Result<(), Error>;
+}
+
+macro_rules! gemm_matrix {
+    ($matrix:ident, $scalar:ty, $one:expr, $zero:expr, $gemm:ident $(, $by_ref:tt)?) => {
+        impl GemmMatrix for ::types::$matrix {
+            type Scalar = $scalar;
+
+            fn one() -> $scalar {
+                $one
+            }
+
+            fn zero() -> $scalar {
+                $zero
+            }
+
+            fn gemm(
+                trans_a: enums::CblasTranspose,
+                trans_b: enums::CblasTranspose,
+                alpha: $scalar,
+                a: &Self,
+                b: &Self,
+                beta: $scalar,
+                c: &mut Self,
+            ) -> Result<(), Error> {
+                level3::$gemm(trans_a, trans_b, $($by_ref)? alpha, a, b, $($by_ref)? beta, c)
+            }
+        }
+    };
+}
+
+gemm_matrix!(MatrixF32, f32, 1., 0., sgemm);
+gemm_matrix!(MatrixF64, f64, 1., 0., dgemm);
+gemm_matrix!(
+    MatrixComplexF32,
+    ::types::ComplexF32,
+    ::types::ComplexF32 { dat: [1., 0.] },
+    ::types::ComplexF32 { dat: [0., 0.] },
+    cgemm,
+    &
+);
+gemm_matrix!(
+    MatrixComplexF64,
+    ::types::ComplexF64,
+    ::types::ComplexF64 { dat: [1., 0.] },
+    ::types::ComplexF64 { dat: [0., 0.] },
+    zgemm,
+    &
+);
+
+/// Builder for the matrix-matrix product C = alpha op(A) op(B) + beta C, created by [`gemm`].
+pub struct Gemm<'a, M: GemmMatrix> {
+    a: &'a M,
+    b: &'a M,
+    trans_a: enums::CblasTranspose,
+    trans_b: enums::CblasTranspose,
+    alpha: M::Scalar,
+    beta: M::Scalar,
+}
+
+/// Starts building the product of `a` and `b`. By default, neither of them is transposed, alpha
+/// is one and beta is zero (the previous content of C is ignored).
+///
+/// ```
+/// use rgsl::{blas, MatrixF64};
+///
+/// let a = MatrixF64::new(3, 2).unwrap();
+/// let b = MatrixF64::new(3, 4).unwrap();
+/// let mut c = MatrixF64::new(2, 4).unwrap();
+/// // c = 2 a^T b
+/// blas::gemm(&a, &b).transpose_a().alpha(2.).into(&mut c);
+/// ```
+pub fn gemm<'a, M: GemmMatrix>(a: &'a M, b: &'a M) -> Gemm<'a, M> {
+    Gemm {
+        a,
+        b,
+        trans_a: enums::CblasTranspose::NoTranspose,
+        trans_b: enums::CblasTranspose::NoTranspose,
+        alpha: M::one(),
+        beta: M::zero(),
+    }
+}
+
+impl<'a, M: GemmMatrix> Gemm<'a, M> {
+    /// Uses A^T instead of A.
+    pub fn transpose_a(mut self) -> Self {
+        self.trans_a = enums::CblasTranspose::Transpose;
+        self
+    }
+
+    /// Uses B^T instead of B.
+    pub fn transpose_b(mut self) -> Self {
+        self.trans_b = enums::CblasTranspose::Transpose;
+        self
+    }
+
+    /// Uses A^H instead of A.
+    pub fn conj_transpose_a(mut self) -> Self {
+        self.trans_a = enums::CblasTranspose::ConjugateTranspose;
+        self
+    }
+
+    /// Uses B^H instead of B.
+    pub fn conj_transpose_b(mut self) -> Self {
+        self.trans_b = enums::CblasTranspose::ConjugateTranspose;
+        self
     }
+
+    /// Sets the factor of the product.
+    pub fn alpha(mut self, alpha: M::Scalar) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Sets the factor of the previous content of C.
+    pub fn beta(mut self, beta: M::Scalar) -> Self {
+        self.beta = beta;
+        self
+    }
+
+    /// Computes the product into `c`.
+    pub fn into(self, c: &mut M) -> Result<(), Error> {
+        M::gemm(
+            self.trans_a,
+            self.trans_b,
+            self.alpha,
+            self.a,
+            self.b,
+            self.beta,
+            c,
+        )
+    }
+}
+
+#[test]
+fn gemm_builder() {
+    use types::MatrixF64;
+
+    let mut a = MatrixF64::new(2, 2).unwrap();
+    a.set(0, 1, 1.);
+    let mut b = MatrixF64::new(2, 2).unwrap();
+    b.set(0, 0, 1.);
+    b.set(1, 1, 2.);
+    let mut c = MatrixF64::new(2, 2).unwrap();
+    c.set_all(1.);
+    // c = 3 a^T b + c
+    gemm(&a, &b)
+        .transpose_a()
+        .alpha(3.)
+        .beta(1.)
+        .into(&mut c)
+        .unwrap();
+    assert_eq!(
+        (c.get(0, 0), c.get(0, 1), c.get(1, 0), c.get(1, 1)),
+        (1., 1., 4., 1.)
+    );
 }
@@ -0,0 +1,192 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+The physical constants of [`physical_constant`](crate::physical_constant), tagged with the unit
+system they are expressed in.
+
+The constants of [`mksa`] are [`UnitValue<Mksa>`](UnitValue) and the ones of [`cgsm`] are
+[`UnitValue<Cgsm>`](UnitValue). Values of the same system can be combined with each other and
+with `f64`s, but combining an MKSA value with a CGSM one doesn't compile:
+
+```
+use rgsl::consts::{cgsm, mksa};
+
+let distance = mksa::SPEED_OF_LIGHT * 2.;
+assert_eq!(distance.value(), 2. * 2.99792458e8);
+assert_eq!((mksa::LIGHT_YEAR / mksa::SPEED_OF_LIGHT).value(), 9.46053620707e15 / 2.99792458e8);
+assert_eq!(cgsm::SPEED_OF_LIGHT.value(), 2.99792458e10);
+```
+
+```compile_fail
+use rgsl::consts::{cgsm, mksa};
+
+let _ = mksa::SPEED_OF_LIGHT + cgsm::SPEED_OF_LIGHT;
+```
+
+Only the unit system is tracked, not the dimension: enable the `uom` feature and use
+`physical_constant::quantity` for that. The dimensionless
+constants of [`num`] and the uncertainties of [`uncertainty`] are plain `f64`s.
+!*/
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+pub use physical_constant::{num, uncertainty};
+
+/// The MKSA system (meters, kilograms, seconds, amperes).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum Mksa {}
+
+/// The CGSM system (centimeters, grams, seconds, gauss).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum Cgsm {}
+
+/// A value expressed in the unit system `S`, such as [`Mksa`] or [`Cgsm`].
+pub struct UnitValue<S> {
+    value: f64,
+    system: PhantomData<S>,
+}
+
+impl<S> UnitValue<S> {
+    /// Tags `value` as being expressed in the unit system `S`.
+    pub const fn new(value: f64) -> Self {
+        Self {
+            value,
+            system: PhantomData,
+        }
+    }
+
+    /// Returns the value, in the units of `S`.
+    pub const fn value(self) -> f64 {
+        self.value
+    }
+}
+
+// Implemented by hand: deriving them would require `S` to implement them as well.
+impl<S> Clone for UnitValue<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for UnitValue<S> {}
+
+impl<S> PartialEq for UnitValue<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<S> PartialOrd for UnitValue<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<S> fmt::Debug for UnitValue<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+macro_rules! unit_value_ops {
+    ($($op:ident, $method:ident);*) => {
+        $(
+            impl<S> $op for UnitValue<S> {
+                type Output = Self;
+
+                fn $method(self, other: Self) -> Self {
+                    Self::new($op::$method(self.value, other.value))
+                }
+            }
+
+            impl<S> $op<f64> for UnitValue<S> {
+                type Output = Self;
+
+                fn $method(self, other: f64) -> Self {
+                    Self::new($op::$method(self.value, other))
+                }
+            }
+
+            impl<S> $op<UnitValue<S>> for f64 {
+                type Output = UnitValue<S>;
+
+                fn $method(self, other: UnitValue<S>) -> UnitValue<S> {
+                    UnitValue::new($op::$method(self, other.value))
+                }
+            }
+        )*
+    };
+}
+
+unit_value_ops!(Add, add; Sub, sub; Mul, mul; Div, div);
+
+impl<S> Neg for UnitValue<S> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.value)
+    }
+}
+
+macro_rules! unit_values {
+    ($system:ident, $module:ident: $($name:ident,)*) => {
+        paste! {
+            $(
+                #[doc = "[`" $name "`](crate::physical_constant::" $module "::" $name ") tagged as a [`" $system "`](super::" $system ") value."]
+                pub const $name: UnitValue<$system> = UnitValue::new(physical_constant::$module::$name);
+            )*
+        }
+    };
+}
+
+/// The constants in the MKSA system.
+pub mod mksa {
+    use super::{Mksa, UnitValue};
+    use crate::paste::paste;
+    use physical_constant;
+
+    unit_values!(
+        Mksa, mksa:
+        SPEED_OF_LIGHT, VACUUM_PERMEABILITY, VACUUM_PERMITTIVITY, PLANCKS_CONSTANT_H,
+        PLANCKS_CONSTANT_HBAR, FARADAY, BOLTZMANN, MOLAR_GAS, STANDARD_GAS_VOLUME,
+        STEFAN_BOLTZMANN_CONSTANT, GAUSS, ASTRONOMICAL_UNIT, GRAVITATIONAL_CONSTANT, LIGHT_YEAR,
+        PARSEC, GRAV_ACCEL, SOLAR_MASS, ELECTRON_CHARGE, ELECTRON_VOLT, UNIFIED_ATOMIC_MASS,
+        MASS_ELECTRON, MASS_MUON, MASS_PROTON, MASS_NEUTRON, RYDBERG, BOHR_RADIUS, ANGSTROM, BARN,
+        BOHR_MAGNETON, NUCLEAR_MAGNETON, ELECTRON_MAGNETIC_MOMENT, PROTON_MAGNETIC_MOMENT,
+        THOMSON_CROSS_SECTION, DEBYE, MINUTE, HOUR, DAY, WEEK, INCH, FOOT, YARD, MILE, MIL,
+        KILOMETERS_PER_HOUR, MILES_PER_HOUR, NAUTICAL_MILE, FATHOM, KNOT, POINT, TEXPOINT, MICRON,
+        HECTARE, ACRE, LITER, US_GALLON, CANADIAN_GALLON, UK_GALLON, QUART, PINT, CUP, POUND_MASS,
+        OUNCE_MASS, TON, METRIC_TON, UK_TON, TROY_OUNCE, CARAT, GRAM_FORCE, POUND_FORCE,
+        KILOPOUND_FORCE, POUNDAL, CALORIE, BTU, THERM, HORSEPOWER, BAR, STD_ATMOSPHERE, TORR,
+        METER_OF_MERCURY, INCH_OF_MERCURY, INCH_OF_WATER, PSI, POISE, STOKES, STILB, LUMEN, LUX,
+        PHOT, FOOTCANDLE, LAMBERT, FOOTLAMBERT, CURIE, ROENTGEN, RAD, NEWTON, DYNE, JOULE, ERG,
+    );
+}
+
+/// The constants in the CGSM system.
+pub mod cgsm {
+    use super::{Cgsm, UnitValue};
+    use crate::paste::paste;
+    use physical_constant;
+
+    unit_values!(
+        Cgsm, cgsm:
+        SPEED_OF_LIGHT, PLANCKS_CONSTANT_H, PLANCKS_CONSTANT_HBAR, FARADAY, BOLTZMANN, MOLAR_GAS,
+        STANDARD_GAS_VOLUME, STEFAN_BOLTZMANN_CONSTANT, GAUSS, ASTRONOMICAL_UNIT,
+        GRAVITATIONAL_CONSTANT, LIGHT_YEAR, PARSEC, GRAV_ACCEL, SOLAR_MASS, ELECTRON_CHARGE,
+        ELECTRON_VOLT, UNIFIED_ATOMIC_MASS, MASS_ELECTRON, MASS_MUON, MASS_PROTON, MASS_NEUTRON,
+        RYDBERG, BOHR_RADIUS, ANGSTROM, BARN, BOHR_MAGNETON, NUCLEAR_MAGNETON,
+        ELECTRON_MAGNETIC_MOMENT, PROTON_MAGNETIC_MOMENT, THOMSON_CROSS_SECTION, DEBYE, MINUTE,
+        HOUR, DAY, WEEK, INCH, FOOT, YARD, MILE, MIL, KILOMETERS_PER_HOUR, MILES_PER_HOUR,
+        NAUTICAL_MILE, FATHOM, KNOT, POINT, TEXPOINT, MICRON, HECTARE, ACRE, LITER, US_GALLON,
+        CANADIAN_GALLON, UK_GALLON, QUART, PINT, CUP, POUND_MASS, OUNCE_MASS, TON, METRIC_TON,
+        UK_TON, TROY_OUNCE, CARAT, GRAM_FORCE, POUND_FORCE, KILOPOUND_FORCE, POUNDAL, CALORIE, BTU,
+        THERM, HORSEPOWER, BAR, STD_ATMOSPHERE, TORR, METER_OF_MERCURY, INCH_OF_MERCURY,
+        INCH_OF_WATER, PSI, POISE, STOKES, STILB, LUMEN, LUX, PHOT, FOOTCANDLE, LAMBERT,
+        FOOTLAMBERT, CURIE, ROENTGEN, RAD, NEWTON, DYNE, JOULE, ERG,
+    );
+}
@@ -7,8 +7,8 @@ The Dawson integral is defined by \exp(-x^2) \int_0^x dt \exp(t^2).
 A table of Dawson’s integral can be found in Abramowitz & Stegun, Table 7.5.
 !*/
 
-use crate::Value;
-use std::mem::MaybeUninit;
+use crate::Error;
+use core::mem::MaybeUninit;
 
 /// This routine computes the value of Dawson’s integral for x.
 #[doc(alias = "gsl_sf_dawson")]
@@ -18,9 +18,13 @@ pub fn dawson(x: f64) -> f64 {
 
 /// This routine computes the value of Dawson’s integral for x.
 #[doc(alias = "gsl_sf_dawson_e")]
-pub fn dawson_e(x: f64) -> (Value, ::types::Result) {
+pub fn dawson_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { ::sys::gsl_sf_dawson_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_dawson_e"
+    )
 }
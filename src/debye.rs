@@ -10,8 +10,8 @@ D_n(x) = n/x^n \int_0^x dt (t^n/(e^t - 1))
 For further information see Abramowitz & Stegun, Section 27.1.
 !*/
 
-use crate::Value;
-use std::mem::MaybeUninit;
+use crate::Error;
+use core::mem::MaybeUninit;
 
 /// This routine computes the first-order Debye function D_1(x) = (1/x) \int_0^x dt (t/(e^t - 1)).
 #[doc(alias = "gsl_sf_debye_1")]
@@ -21,11 +21,15 @@ pub fn _1(x: f64) -> f64 {
 
 /// This routine computes the first-order Debye function D_1(x) = (1/x) \int_0^x dt (t/(e^t - 1)).
 #[doc(alias = "gsl_sf_debye_1_e")]
-pub fn _1_e(x: f64) -> (Value, ::types::Result) {
+pub fn _1_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { ::sys::gsl_sf_debye_1_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_debye_1_e"
+    )
 }
 
 /// This routine computes the second-order Debye function D_2(x) = (2/x^2) \int_0^x dt (t^2/(e^t - 1)).
@@ -36,11 +40,15 @@ pub fn _2(x: f64) -> f64 {
 
 /// This routine computes the second-order Debye function D_2(x) = (2/x^2) \int_0^x dt (t^2/(e^t - 1)).
 #[doc(alias = "gsl_sf_debye_2_e")]
-pub fn _2_e(x: f64) -> (Value, ::types::Result) {
+pub fn _2_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { ::sys::gsl_sf_debye_2_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_debye_2_e"
+    )
 }
 
 /// This routine computes the third-order Debye function D_3(x) = (3/x^3) \int_0^x dt (t^3/(e^t - 1)).
@@ -51,11 +59,15 @@ pub fn _3(x: f64) -> f64 {
 
 /// This routine computes the third-order Debye function D_3(x) = (3/x^3) \int_0^x dt (t^3/(e^t - 1)).
 #[doc(alias = "gsl_sf_debye_3_e")]
-pub fn _3_e(x: f64) -> (Value, ::types::Result) {
+pub fn _3_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { ::sys::gsl_sf_debye_3_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_debye_3_e"
+    )
 }
 
 /// This routine computes the fourth-order Debye function D_4(x) = (4/x^4) \int_0^x dt (t^4/(e^t - 1)).
@@ -66,11 +78,15 @@ pub fn _4(x: f64) -> f64 {
 
 /// This routine computes the fourth-order Debye function D_4(x) = (4/x^4) \int_0^x dt (t^4/(e^t - 1)).
 #[doc(alias = "gsl_sf_debye_4_e")]
-pub fn _4_e(x: f64) -> (Value, ::types::Result) {
+pub fn _4_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { ::sys::gsl_sf_debye_4_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_debye_4_e"
+    )
 }
 
 /// This routine computes the fifth-order Debye function D_5(x) = (5/x^5) \int_0^x dt (t^5/(e^t - 1)).
@@ -81,11 +97,15 @@ pub fn _5(x: f64) -> f64 {
 
 /// This routine computes the fifth-order Debye function D_5(x) = (5/x^5) \int_0^x dt (t^5/(e^t - 1)).
 #[doc(alias = "gsl_sf_debye_5_e")]
-pub fn _5_e(x: f64) -> (Value, ::types::Result) {
+pub fn _5_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { ::sys::gsl_sf_debye_5_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_debye_5_e"
+    )
 }
 
 /// This routine computes the sixth-order Debye function D_6(x) = (6/x^6) \int_0^x dt (t^6/(e^t - 1)).
@@ -96,9 +116,13 @@ pub fn _6(x: f64) -> f64 {
 
 /// This routine computes the sixth-order Debye function D_6(x) = (6/x^6) \int_0^x dt (t^6/(e^t - 1)).
 #[doc(alias = "gsl_sf_debye_6_e")]
-pub fn _6_e(x: f64) -> (Value, ::types::Result) {
+pub fn _6_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { ::sys::gsl_sf_debye_6_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_debye_6_e"
+    )
 }
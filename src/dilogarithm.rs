@@ -2,8 +2,8 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::Value;
-use std::mem::MaybeUninit;
+use crate::Error;
+use core::mem::MaybeUninit;
 
 /// These routines compute the dilogarithm for a real argument. In Lewin’s notation this is Li_2(x), the real part of the dilogarithm of a real x.
 /// It is defined by the integral representation Li_2(x) = - \Re \int_0^x ds \log(1-s) / s. Note that \Im(Li_2(x)) = 0 for x <= 1, and -\pi\log(x) for x > 1.
@@ -19,26 +19,74 @@ pub fn dilog(x: f64) -> f64 {
 ///
 /// Note that Abramowitz & Stegun refer to the Spence integral S(x)=Li_2(1-x) as the dilogarithm rather than Li_2(x).
 #[doc(alias = "gsl_sf_dilog_e")]
-pub fn dilog_e(x: f64) -> (Value, ::types::Result) {
+pub fn dilog_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { ::sys::gsl_sf_dilog_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_dilog_e"
+    )
 }
 
 /// This function computes the full complex-valued dilogarithm for the complex argument z = r \exp(i \theta).
 /// The real and imaginary parts of the result are returned in result_re, result_im.
 #[doc(alias = "gsl_sf_complex_dilog_e")]
-pub fn complex_dilog_e(r: f64, theta: f64) -> (Value, ::types::Result, ::types::Result) {
+pub fn complex_dilog_e(r: f64, theta: f64) -> Result<(::types::Result, ::types::Result), Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let mut result_im = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe {
         ::sys::gsl_sf_complex_dilog_e(r, theta, result.as_mut_ptr(), result_im.as_mut_ptr())
     };
 
-    (
-        Value::from(ret),
-        unsafe { result.assume_init() }.into(),
-        unsafe { result_im.assume_init() }.into(),
+    result_handler!(
+        ret,
+        (
+            unsafe { result.assume_init() }.into(),
+            unsafe { result_im.assume_init() }.into()
+        ),
+        "gsl_sf_complex_dilog_e"
+    )
+}
+
+/// This function computes the full complex-valued dilogarithm for the complex argument z = x + i y.
+/// The real and imaginary parts of the result are returned in result_re, result_im.
+#[doc(alias = "gsl_sf_complex_dilog_xy_e")]
+pub fn complex_dilog_xy_e(x: f64, y: f64) -> Result<(::types::Result, ::types::Result), Error> {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let mut result_im = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe {
+        ::sys::gsl_sf_complex_dilog_xy_e(x, y, result.as_mut_ptr(), result_im.as_mut_ptr())
+    };
+
+    result_handler!(
+        ret,
+        (
+            unsafe { result.assume_init() }.into(),
+            unsafe { result_im.assume_init() }.into()
+        ),
+        "gsl_sf_complex_dilog_xy_e"
+    )
+}
+
+/// This function computes the complex-valued Spence integral for the complex argument z = x + i y,
+/// defined by \int_1^z dt \log(t) / (1 - t) = Li_2(1 - z).
+/// The real and imaginary parts of the result are returned in real_sp, imag_sp.
+#[doc(alias = "gsl_sf_complex_spence_xy_e")]
+pub fn complex_spence_xy_e(x: f64, y: f64) -> Result<(::types::Result, ::types::Result), Error> {
+    let mut real_sp = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let mut imag_sp = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe {
+        ::sys::gsl_sf_complex_spence_xy_e(x, y, real_sp.as_mut_ptr(), imag_sp.as_mut_ptr())
+    };
+
+    result_handler!(
+        ret,
+        (
+            unsafe { real_sp.assume_init() }.into(),
+            unsafe { imag_sp.assume_init() }.into()
+        ),
+        "gsl_sf_complex_spence_xy_e"
     )
 }
@@ -19,17 +19,28 @@ http://www.netlib.org/lapack
 The LAPACK source code can be found at the website above along with an online copy of the users guide.
 !*/
 
-use crate::Value;
+use crate::{Error, Value, WorkspacePool};
 use ffi::FFI;
+use types::{
+    EigenGenHermVWorkspace, EigenGenHermWorkspace, EigenGenSymmVWorkspace, EigenGenSymmWorkspace,
+    EigenGenVWorkspace, EigenGenWorkspace, EigenHermitianVWorkspace, EigenHermitianWorkspace,
+    EigenNonSymmetricVWorkspace, EigenNonSymmetricWorkspace, EigenSymmetricVWorkspace,
+    EigenSymmetricWorkspace,
+};
 use types::{MatrixComplexF64, MatrixF64, VectorComplexF64, VectorF64};
 
 /// This function simultaneously sorts the eigenvalues stored in the vector eval and the corresponding real eigenvectors stored in the columns
 /// of the matrix evec into ascending or descending order according to the value of the parameter sort_type
 #[doc(alias = "gsl_eigen_symmv_sort")]
-pub fn symmv_sort(eval: &mut VectorF64, evec: &mut MatrixF64, sort_type: ::EigenSort) -> Value {
-    Value::from(unsafe {
+pub fn symmv_sort(
+    eval: &mut VectorF64,
+    evec: &mut MatrixF64,
+    sort_type: ::EigenSort,
+) -> Result<(), Error> {
+    let ret = unsafe {
         sys::gsl_eigen_symmv_sort(eval.unwrap_unique(), evec.unwrap_unique(), sort_type.into())
-    })
+    };
+    result_handler!(ret, (), "gsl_eigen_symmv_sort")
 }
 
 /// This function simultaneously sorts the eigenvalues stored in the vector eval and the corresponding complex eigenvectors stored in the columns
@@ -39,10 +50,11 @@ pub fn hermv_sort(
     eval: &mut VectorF64,
     evec: &mut MatrixComplexF64,
     sort_type: ::EigenSort,
-) -> Value {
-    Value::from(unsafe {
+) -> Result<(), Error> {
+    let ret = unsafe {
         sys::gsl_eigen_hermv_sort(eval.unwrap_unique(), evec.unwrap_unique(), sort_type.into())
-    })
+    };
+    result_handler!(ret, (), "gsl_eigen_hermv_sort")
 }
 
 /// This function simultaneously sorts the eigenvalues stored in the vector eval and the corresponding complex eigenvectors stored in the columns
@@ -53,19 +65,25 @@ pub fn nonsymmv_sort(
     eval: &mut VectorComplexF64,
     evec: &mut MatrixComplexF64,
     sort_type: ::EigenSort,
-) -> Value {
-    Value::from(unsafe {
+) -> Result<(), Error> {
+    let ret = unsafe {
         sys::gsl_eigen_nonsymmv_sort(eval.unwrap_unique(), evec.unwrap_unique(), sort_type.into())
-    })
+    };
+    result_handler!(ret, (), "gsl_eigen_nonsymmv_sort")
 }
 
 /// This function simultaneously sorts the eigenvalues stored in the vector eval and the corresponding real eigenvectors stored in the columns
 /// of the matrix evec into ascending or descending order according to the value of the parameter sort_type.
 #[doc(alias = "gsl_eigen_gensymmv_sort")]
-pub fn gensymmv_sort(eval: &mut VectorF64, evec: &mut MatrixF64, sort_type: ::EigenSort) -> Value {
-    Value::from(unsafe {
+pub fn gensymmv_sort(
+    eval: &mut VectorF64,
+    evec: &mut MatrixF64,
+    sort_type: ::EigenSort,
+) -> Result<(), Error> {
+    let ret = unsafe {
         sys::gsl_eigen_gensymmv_sort(eval.unwrap_unique(), evec.unwrap_unique(), sort_type.into())
-    })
+    };
+    result_handler!(ret, (), "gsl_eigen_gensymmv_sort")
 }
 
 /// This function simultaneously sorts the eigenvalues stored in the vector eval and the corresponding complex eigenvectors stored in the
@@ -75,10 +93,11 @@ pub fn genhermv_sort(
     eval: &mut VectorF64,
     evec: &mut MatrixComplexF64,
     sort_type: ::EigenSort,
-) -> Value {
-    Value::from(unsafe {
+) -> Result<(), Error> {
+    let ret = unsafe {
         sys::gsl_eigen_genhermv_sort(eval.unwrap_unique(), evec.unwrap_unique(), sort_type.into())
-    })
+    };
+    result_handler!(ret, (), "gsl_eigen_genhermv_sort")
 }
 
 /// This function simultaneously sorts the eigenvalues stored in the vectors (alpha, beta) and the corresponding complex eigenvectors stored
@@ -90,13 +109,256 @@ pub fn genv_sort(
     beta: &mut VectorF64,
     evec: &mut MatrixComplexF64,
     sort_type: ::EigenSort,
-) -> Value {
-    Value::from(unsafe {
+) -> Result<(), Error> {
+    let ret = unsafe {
         sys::gsl_eigen_genv_sort(
             alpha.unwrap_unique(),
             beta.unwrap_unique(),
             evec.unwrap_unique(),
             sort_type.into(),
         )
+    };
+    result_handler!(ret, (), "gsl_eigen_genv_sort")
+}
+
+/// This function computes the eigenvalues of the real symmetric matrix `A`, which is destroyed
+/// during the computation, and stores them in `eval`.
+///
+/// Same as [`EigenSymmetricWorkspace::symm`] with a workspace taken from the thread's
+/// [`WorkspacePool`].
+#[doc(alias = "gsl_eigen_symm")]
+pub fn symm(A: &mut MatrixF64, eval: &mut VectorF64) -> Result<(), Error> {
+    WorkspacePool::<EigenSymmetricWorkspace>::with(A.size1(), |w| w.symm(A, eval)).and_then(|r| r)
+}
+
+/// This function computes the eigenvalues and eigenvectors of the real symmetric matrix `A`,
+/// which is destroyed during the computation, and stores them in `eval` and `evec`.
+///
+/// Same as [`EigenSymmetricVWorkspace::symmv`] with a workspace taken from the thread's
+/// [`WorkspacePool`].
+#[doc(alias = "gsl_eigen_symmv")]
+pub fn symmv(A: &mut MatrixF64, eval: &mut VectorF64, evec: &mut MatrixF64) -> Result<(), Error> {
+    WorkspacePool::<EigenSymmetricVWorkspace>::with(A.size1(), |w| w.symmv(A, eval, evec))
+        .and_then(|r| r)
+}
+
+/// This function computes the eigenvalues of the complex hermitian matrix `A`, which is destroyed
+/// during the computation, and stores them in `eval`.
+///
+/// Same as [`EigenHermitianWorkspace::herm`] with a workspace taken from the thread's
+/// [`WorkspacePool`].
+#[doc(alias = "gsl_eigen_herm")]
+pub fn herm(A: &mut MatrixComplexF64, eval: &mut VectorF64) -> Result<(), Error> {
+    WorkspacePool::<EigenHermitianWorkspace>::with(A.size1(), |w| w.herm(A, eval)).and_then(|r| r)
+}
+
+/// This function computes the eigenvalues and eigenvectors of the complex hermitian matrix `A`,
+/// which is destroyed during the computation, and stores them in `eval` and `evec`.
+///
+/// Same as [`EigenHermitianVWorkspace::hermv`] with a workspace taken from the thread's
+/// [`WorkspacePool`].
+#[doc(alias = "gsl_eigen_hermv")]
+pub fn hermv(
+    A: &mut MatrixComplexF64,
+    eval: &mut VectorF64,
+    evec: &mut MatrixComplexF64,
+) -> Result<(), Error> {
+    WorkspacePool::<EigenHermitianVWorkspace>::with(A.size1(), |w| w.hermv(A, eval, evec))
+        .and_then(|r| r)
+}
+
+/// This function computes the eigenvalues of the real nonsymmetric matrix `A`, which is destroyed
+/// during the computation, and stores them in `eval`.
+///
+/// Same as [`EigenNonSymmetricWorkspace::nonsymm`] with the default parameters and a workspace
+/// taken from the thread's [`WorkspacePool`].
+#[doc(alias = "gsl_eigen_nonsymm")]
+pub fn nonsymm(A: &mut MatrixF64, eval: &mut VectorComplexF64) -> Result<(), Error> {
+    WorkspacePool::<EigenNonSymmetricWorkspace>::with(A.size1(), |w| {
+        w.params(0, 0);
+        w.nonsymm(A, eval)
+    })
+    .and_then(|r| r)
+}
+
+/// This function computes the eigenvalues and right eigenvectors of the real nonsymmetric matrix
+/// `A`, and stores them in `eval` and `evec`. On output, the upper portion of `A` contains the
+/// Schur form T.
+///
+/// Same as [`EigenNonSymmetricVWorkspace::nonsymmv`] with the default parameters and a workspace
+/// taken from the thread's [`WorkspacePool`].
+#[doc(alias = "gsl_eigen_nonsymmv")]
+pub fn nonsymmv(
+    A: &mut MatrixF64,
+    eval: &mut VectorComplexF64,
+    evec: &mut MatrixComplexF64,
+) -> Result<(), Error> {
+    WorkspacePool::<EigenNonSymmetricVWorkspace>::with(A.size1(), |w| {
+        w.params(0);
+        w.nonsymmv(A, eval, evec)
+    })
+    .and_then(|r| r)
+}
+
+/// This function computes the eigenvalues of the real generalized symmetric-definite matrix pair
+/// (A, B), and stores them in `eval`. On output, `B` contains its Cholesky decomposition.
+///
+/// Same as [`EigenGenSymmWorkspace::gensymm`] with a workspace taken from the thread's
+/// [`WorkspacePool`].
+#[doc(alias = "gsl_eigen_gensymm")]
+pub fn gensymm(A: MatrixF64, B: &mut MatrixF64, eval: &mut VectorF64) -> Result<(), Error> {
+    WorkspacePool::<EigenGenSymmWorkspace>::with(A.size1(), |w| w.gensymm(A, B, eval))
+        .and_then(|r| r)
+}
+
+/// This function computes the eigenvalues and eigenvectors of the real generalized
+/// symmetric-definite matrix pair (A, B), and stores them in `eval` and `evec`. On output, `B`
+/// contains its Cholesky decomposition.
+///
+/// Same as [`EigenGenSymmVWorkspace::gensymmv`] with a workspace taken from the thread's
+/// [`WorkspacePool`].
+#[doc(alias = "gsl_eigen_gensymmv")]
+pub fn gensymmv(
+    A: MatrixF64,
+    B: &mut MatrixF64,
+    eval: &mut VectorF64,
+    evec: &mut MatrixF64,
+) -> Result<(), Error> {
+    WorkspacePool::<EigenGenSymmVWorkspace>::with(A.size1(), |w| w.gensymmv(A, B, eval, evec))
+        .and_then(|r| r)
+}
+
+/// This function computes the eigenvalues of the complex generalized hermitian-definite matrix
+/// pair (A, B), and stores them in `eval`. On output, `B` contains its Cholesky decomposition.
+///
+/// Same as [`EigenGenHermWorkspace::genherm`] with a workspace taken from the thread's
+/// [`WorkspacePool`].
+#[doc(alias = "gsl_eigen_genherm")]
+pub fn genherm(
+    A: MatrixComplexF64,
+    B: &mut MatrixComplexF64,
+    eval: &mut VectorF64,
+) -> Result<(), Error> {
+    WorkspacePool::<EigenGenHermWorkspace>::with(A.size1(), |w| w.genherm(A, B, eval))
+        .and_then(|r| r)
+}
+
+/// This function computes the eigenvalues and eigenvectors of the complex generalized
+/// hermitian-definite matrix pair (A, B), and stores them in `eval` and `evec`. On output, `B`
+/// contains its Cholesky decomposition.
+///
+/// Same as [`EigenGenHermVWorkspace::genhermv`] with a workspace taken from the thread's
+/// [`WorkspacePool`].
+#[doc(alias = "gsl_eigen_genhermv")]
+pub fn genhermv(
+    A: MatrixComplexF64,
+    B: &mut MatrixComplexF64,
+    eval: &mut VectorF64,
+    evec: &mut MatrixComplexF64,
+) -> Result<(), Error> {
+    WorkspacePool::<EigenGenHermVWorkspace>::with(A.size1(), |w| w.genhermv(A, B, eval, evec))
+        .and_then(|r| r)
+}
+
+/// This function computes the eigenvalues of the real generalized nonsymmetric matrix pair
+/// (A, B), and stores them as pairs in (`alpha`, `beta`). `A` and `B` are destroyed during the
+/// computation.
+///
+/// Same as [`EigenGenWorkspace::gen`] with the default parameters and a workspace taken from the
+/// thread's [`WorkspacePool`].
+#[doc(alias = "gsl_eigen_gen")]
+pub fn gen(
+    A: &mut MatrixF64,
+    B: &mut MatrixF64,
+    alpha: &mut VectorComplexF64,
+    beta: &mut VectorF64,
+) -> Result<(), Error> {
+    WorkspacePool::<EigenGenWorkspace>::with(A.size1(), |w| {
+        w.params(0, 0, 0);
+        w.gen(A, B, alpha, beta)
     })
+    .and_then(|r| r)
+}
+
+/// This function computes the eigenvalues and right eigenvectors of the real generalized
+/// nonsymmetric matrix pair (A, B), and stores them in (`alpha`, `beta`) and `evec`. `A` and `B`
+/// are destroyed during the computation.
+///
+/// Same as [`EigenGenVWorkspace::genv`] with a workspace taken from the thread's
+/// [`WorkspacePool`].
+#[doc(alias = "gsl_eigen_genv")]
+pub fn genv(
+    A: &mut MatrixF64,
+    B: &mut MatrixF64,
+    alpha: &mut VectorComplexF64,
+    beta: &mut VectorF64,
+    evec: &mut MatrixComplexF64,
+) -> Result<(), Error> {
+    WorkspacePool::<EigenGenVWorkspace>::with(A.size1(), |w| w.genv(A, B, alpha, beta, evec))
+        .and_then(|r| r)
+}
+
+/// This function reduces the generalized symmetric-definite problem A x = lambda B x to the
+/// standard symmetric problem C y = lambda y, where C = L^{-1} A L^{-T}, using the Cholesky
+/// decomposition `LLT` of B. On output, `A` is replaced by C.
+#[doc(alias = "gsl_eigen_gensymm_standardize")]
+pub fn gensymm_standardize(A: &mut MatrixF64, LLT: &MatrixF64) -> Result<(), Error> {
+    let ret = unsafe { sys::gsl_eigen_gensymm_standardize(A.unwrap_unique(), LLT.unwrap_shared()) };
+    result_handler!(ret, (), "gsl_eigen_gensymm_standardize")
+}
+
+/// This function reduces the generalized hermitian-definite problem A x = lambda B x to the
+/// standard hermitian problem C y = lambda y, where C = L^{-1} A L^{-H}, using the Cholesky
+/// decomposition `LLH` of B. On output, `A` is replaced by C.
+#[doc(alias = "gsl_eigen_genherm_standardize")]
+pub fn genherm_standardize(A: &mut MatrixComplexF64, LLH: &MatrixComplexF64) -> Result<(), Error> {
+    let ret = unsafe { sys::gsl_eigen_genherm_standardize(A.unwrap_unique(), LLH.unwrap_shared()) };
+    result_handler!(ret, (), "gsl_eigen_genherm_standardize")
+}
+
+impl MatrixF64 {
+    /// Returns the eigenvalues of the real symmetric matrix `self` in ascending order, and the
+    /// corresponding orthonormal eigenvectors as the columns of a matrix.
+    ///
+    /// Unlike [`symmv`], `self` isn't modified: the outputs and the workspace are allocated
+    /// internally.
+    ///
+    /// ```
+    /// use rgsl::MatrixF64;
+    ///
+    /// let mut m = MatrixF64::new(2, 2).unwrap();
+    /// m.set_all(1.);
+    /// m.add_diagonal(1.).unwrap();
+    /// let (eval, evec) = m.eigen_symmetric().unwrap();
+    /// assert!((eval.get(0) - 1.).abs() < 1e-12);
+    /// assert!((eval.get(1) - 3.).abs() < 1e-12);
+    /// assert!((evec.get(0, 1) - evec.get(1, 1)).abs() < 1e-12);
+    /// ```
+    #[doc(alias = "gsl_eigen_symmv")]
+    pub fn eigen_symmetric(&self) -> Result<(VectorF64, MatrixF64), Error> {
+        let n = self.size1();
+        let mut a = self
+            .try_clone()
+            .ok_or_else(|| Error::new(Value::NoMemory, "gsl_matrix_alloc"))?;
+        let mut eval =
+            VectorF64::new(n).ok_or_else(|| Error::new(Value::NoMemory, "gsl_vector_alloc"))?;
+        let mut evec =
+            MatrixF64::new(n, n).ok_or_else(|| Error::new(Value::NoMemory, "gsl_matrix_alloc"))?;
+        symmv(&mut a, &mut eval, &mut evec)?;
+        symmv_sort(&mut eval, &mut evec, ::EigenSort::ValAsc)?;
+        Ok((eval, evec))
+    }
+}
+
+#[test]
+fn nonsymm_rotation() {
+    let mut a = MatrixF64::new(2, 2).unwrap();
+    a.set(0, 1, -1.);
+    a.set(1, 0, 1.);
+    let mut eval = VectorComplexF64::new(2).unwrap();
+    nonsymm(&mut a, &mut eval).unwrap();
+    for i in 0..2 {
+        assert!(eval.get(i).real().abs() < 1e-12);
+        assert!((eval.get(i).imaginary().abs() - 1.).abs() < 1e-12);
+    }
 }
@@ -17,8 +17,8 @@
 /// The notation used here is based on Carlson, Numerische Mathematik 33 (1979) 1 and differs slightly from that used by Abramowitz & Stegun, where the functions are given in terms of the parameter m = k^2 and n is replaced by -n.
 pub mod legendre {
     pub mod complete {
-        use crate::Value;
-        use std::mem::MaybeUninit;
+        use crate::Error;
+        use core::mem::MaybeUninit;
 
         /// This routine computes the complete elliptic integral K(k) to the accuracy specified by the mode variable mode.
         /// Note that Abramowitz & Stegun define this function in terms of the parameter m = k^2.
@@ -30,11 +30,15 @@ pub mod legendre {
         /// This routine computes the complete elliptic integral K(k) to the accuracy specified by the mode variable mode.
         /// Note that Abramowitz & Stegun define this function in terms of the parameter m = k^2.
         #[doc(alias = "gsl_sf_ellint_Kcomp_e")]
-        pub fn ellint_Kcomp_e(k: f64, mode: ::Mode) -> (Value, ::types::Result) {
+        pub fn ellint_Kcomp_e(k: f64, mode: ::Mode) -> Result<::types::Result, Error> {
             let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
             let ret = unsafe { ::sys::gsl_sf_ellint_Kcomp_e(k, mode.into(), result.as_mut_ptr()) };
 
-            (::Value::from(ret), unsafe { result.assume_init() }.into())
+            result_handler!(
+                ret,
+                unsafe { result.assume_init() }.into(),
+                "gsl_sf_ellint_Kcomp_e"
+            )
         }
 
         /// This routine computes the complete elliptic integral E(k) to the accuracy specified by the mode variable mode.
@@ -47,11 +51,15 @@ pub mod legendre {
         /// This routine computes the complete elliptic integral E(k) to the accuracy specified by the mode variable mode.
         /// Note that Abramowitz & Stegun define this function in terms of the parameter m = k^2.
         #[doc(alias = "gsl_sf_ellint_Ecomp_e")]
-        pub fn ellint_Ecomp_e(k: f64, mode: ::Mode) -> (Value, ::types::Result) {
+        pub fn ellint_Ecomp_e(k: f64, mode: ::Mode) -> Result<::types::Result, Error> {
             let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
             let ret = unsafe { ::sys::gsl_sf_ellint_Ecomp_e(k, mode.into(), result.as_mut_ptr()) };
 
-            (::Value::from(ret), unsafe { result.assume_init() }.into())
+            result_handler!(
+                ret,
+                unsafe { result.assume_init() }.into(),
+                "gsl_sf_ellint_Ecomp_e"
+            )
         }
 
         /// This routine computes the complete elliptic integral \Pi(k,n) to the accuracy specified by the mode variable mode.
@@ -64,18 +72,43 @@ pub mod legendre {
         /// This routine computes the complete elliptic integral \Pi(k,n) to the accuracy specified by the mode variable mode.
         /// Note that Abramowitz & Stegun define this function in terms of the parameters m = k^2 and \sin^2(\alpha) = k^2, with the change of sign n \to -n.
         #[doc(alias = "gsl_sf_ellint_Pcomp_e")]
-        pub fn ellint_Pcomp_e(k: f64, n: f64, mode: ::Mode) -> (Value, ::types::Result) {
+        pub fn ellint_Pcomp_e(k: f64, n: f64, mode: ::Mode) -> Result<::types::Result, Error> {
             let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
             let ret =
                 unsafe { ::sys::gsl_sf_ellint_Pcomp_e(k, n, mode.into(), result.as_mut_ptr()) };
 
-            (::Value::from(ret), unsafe { result.assume_init() }.into())
+            result_handler!(
+                ret,
+                unsafe { result.assume_init() }.into(),
+                "gsl_sf_ellint_Pcomp_e"
+            )
+        }
+
+        /// This routine computes the complete elliptic integral D(k) to the accuracy specified by the mode variable mode.
+        /// It is the incomplete integral D(\phi,k) evaluated at \phi = \pi/2.
+        #[doc(alias = "gsl_sf_ellint_Dcomp")]
+        pub fn ellint_Dcomp(k: f64, mode: ::Mode) -> f64 {
+            unsafe { sys::gsl_sf_ellint_Dcomp(k, mode.into()) }
+        }
+
+        /// This routine computes the complete elliptic integral D(k) to the accuracy specified by the mode variable mode.
+        /// It is the incomplete integral D(\phi,k) evaluated at \phi = \pi/2.
+        #[doc(alias = "gsl_sf_ellint_Dcomp_e")]
+        pub fn ellint_Dcomp_e(k: f64, mode: ::Mode) -> Result<::types::Result, Error> {
+            let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+            let ret = unsafe { ::sys::gsl_sf_ellint_Dcomp_e(k, mode.into(), result.as_mut_ptr()) };
+
+            result_handler!(
+                ret,
+                unsafe { result.assume_init() }.into(),
+                "gsl_sf_ellint_Dcomp_e"
+            )
         }
     }
 
     pub mod incomplete {
-        use crate::Value;
-        use std::mem::MaybeUninit;
+        use crate::Error;
+        use core::mem::MaybeUninit;
 
         /// This routine computes the incomplete elliptic integral F(\phi,k) to the accuracy specified by the mode variable mode.
         /// Note that Abramowitz & Stegun define this function in terms of the parameter m = k^2.
@@ -87,11 +120,15 @@ pub mod legendre {
         /// This routine computes the incomplete elliptic integral F(\phi,k) to the accuracy specified by the mode variable mode.
         /// Note that Abramowitz & Stegun define this function in terms of the parameter m = k^2.
         #[doc(alias = "gsl_sf_ellint_F_e")]
-        pub fn ellint_F_e(phi: f64, k: f64, mode: ::Mode) -> (Value, ::types::Result) {
+        pub fn ellint_F_e(phi: f64, k: f64, mode: ::Mode) -> Result<::types::Result, Error> {
             let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
             let ret = unsafe { ::sys::gsl_sf_ellint_F_e(phi, k, mode.into(), result.as_mut_ptr()) };
 
-            (::Value::from(ret), unsafe { result.assume_init() }.into())
+            result_handler!(
+                ret,
+                unsafe { result.assume_init() }.into(),
+                "gsl_sf_ellint_F_e"
+            )
         }
 
         /// This routine computes the incomplete elliptic integral E(\phi,k) to the accuracy specified by the mode variable mode.
@@ -104,11 +141,15 @@ pub mod legendre {
         /// This routine computes the incomplete elliptic integral E(\phi,k) to the accuracy specified by the mode variable mode.
         /// Note that Abramowitz & Stegun define this function in terms of the parameter m = k^2.
         #[doc(alias = "gsl_sf_ellint_E_e")]
-        pub fn ellint_E_e(phi: f64, k: f64, mode: ::Mode) -> (Value, ::types::Result) {
+        pub fn ellint_E_e(phi: f64, k: f64, mode: ::Mode) -> Result<::types::Result, Error> {
             let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
             let ret = unsafe { ::sys::gsl_sf_ellint_E_e(phi, k, mode.into(), result.as_mut_ptr()) };
 
-            (::Value::from(ret), unsafe { result.assume_init() }.into())
+            result_handler!(
+                ret,
+                unsafe { result.assume_init() }.into(),
+                "gsl_sf_ellint_E_e"
+            )
         }
 
         /// This routine computes the incomplete elliptic integral \Pi(\phi,k,n) to the accuracy specified by the mode variable mode.
@@ -121,12 +162,21 @@ pub mod legendre {
         /// This routine computes the incomplete elliptic integral \Pi(\phi,k,n) to the accuracy specified by the mode variable mode.
         /// Note that Abramowitz & Stegun define this function in terms of the parameters m = k^2 and \sin^2(\alpha) = k^2, with the change of sign n \to -n.
         #[doc(alias = "gsl_sf_ellint_P_e")]
-        pub fn ellint_P_e(phi: f64, k: f64, n: f64, mode: ::Mode) -> (Value, ::types::Result) {
+        pub fn ellint_P_e(
+            phi: f64,
+            k: f64,
+            n: f64,
+            mode: ::Mode,
+        ) -> Result<::types::Result, Error> {
             let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
             let ret =
                 unsafe { ::sys::gsl_sf_ellint_P_e(phi, k, n, mode.into(), result.as_mut_ptr()) };
 
-            (::Value::from(ret), unsafe { result.assume_init() }.into())
+            result_handler!(
+                ret,
+                unsafe { result.assume_init() }.into(),
+                "gsl_sf_ellint_P_e"
+            )
         }
 
         /// This routine computes the incomplete elliptic integral D(\phi,k) which is defined through the Carlson form RD(x,y,z) by the following relation,
@@ -143,11 +193,15 @@ pub mod legendre {
         ///
         /// The argument n is not used and will be removed in a future release.
         #[doc(alias = "gsl_sf_ellint_D_e")]
-        pub fn ellint_D_e(phi: f64, k: f64, mode: ::Mode) -> (Value, ::types::Result) {
+        pub fn ellint_D_e(phi: f64, k: f64, mode: ::Mode) -> Result<::types::Result, Error> {
             let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
             let ret = unsafe { ::sys::gsl_sf_ellint_D_e(phi, k, mode.into(), result.as_mut_ptr()) };
 
-            (::Value::from(ret), unsafe { result.assume_init() }.into())
+            result_handler!(
+                ret,
+                unsafe { result.assume_init() }.into(),
+                "gsl_sf_ellint_D_e"
+            )
         }
     }
 }
@@ -163,8 +217,8 @@ pub mod legendre {
 /// RJ(x,y,z,p) = 3/2 \int_0^\infty dt
 ///                (t+x)^(-1/2) (t+y)^(-1/2) (t+z)^(-1/2) (t+p)^(-1)
 pub mod carlson {
-    use crate::Value;
-    use std::mem::MaybeUninit;
+    use crate::Error;
+    use core::mem::MaybeUninit;
 
     /// This routine computes the incomplete elliptic integral RC(x,y) to the accuracy specified by the mode variable mode.
     #[doc(alias = "gsl_sf_ellint_RC")]
@@ -174,11 +228,15 @@ pub mod carlson {
 
     /// This routine computes the incomplete elliptic integral RC(x,y) to the accuracy specified by the mode variable mode.
     #[doc(alias = "gsl_sf_ellint_RC_e")]
-    pub fn ellint_RC_e(x: f64, y: f64, mode: ::Mode) -> (Value, ::types::Result) {
+    pub fn ellint_RC_e(x: f64, y: f64, mode: ::Mode) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { ::sys::gsl_sf_ellint_RC_e(x, y, mode.into(), result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_ellint_RC_e"
+        )
     }
 
     /// This routine computes the incomplete elliptic integral RD(x,y,z) to the accuracy specified by the mode variable mode.
@@ -189,11 +247,15 @@ pub mod carlson {
 
     /// This routine computes the incomplete elliptic integral RD(x,y,z) to the accuracy specified by the mode variable mode.
     #[doc(alias = "gsl_sf_ellint_RD_e")]
-    pub fn ellint_RD_e(x: f64, y: f64, z: f64, mode: ::Mode) -> (Value, ::types::Result) {
+    pub fn ellint_RD_e(x: f64, y: f64, z: f64, mode: ::Mode) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { ::sys::gsl_sf_ellint_RD_e(x, y, z, mode.into(), result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_ellint_RD_e"
+        )
     }
 
     /// This routine computes the incomplete elliptic integral RF(x,y,z) to the accuracy specified by the mode variable mode.
@@ -204,11 +266,15 @@ pub mod carlson {
 
     /// This routine computes the incomplete elliptic integral RF(x,y,z) to the accuracy specified by the mode variable mode.
     #[doc(alias = "gsl_sf_ellint_RF_e")]
-    pub fn ellint_RF_e(x: f64, y: f64, z: f64, mode: ::Mode) -> (Value, ::types::Result) {
+    pub fn ellint_RF_e(x: f64, y: f64, z: f64, mode: ::Mode) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { ::sys::gsl_sf_ellint_RF_e(x, y, z, mode.into(), result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_ellint_RF_e"
+        )
     }
 
     /// This routine computes the incomplete elliptic integral RJ(x,y,z,p) to the accuracy specified by the mode variable mode.
@@ -219,11 +285,21 @@ pub mod carlson {
 
     /// This routine computes the incomplete elliptic integral RJ(x,y,z,p) to the accuracy specified by the mode variable mode.
     #[doc(alias = "gsl_sf_ellint_RJ_e")]
-    pub fn ellint_RJ_e(x: f64, y: f64, z: f64, p: f64, mode: ::Mode) -> (Value, ::types::Result) {
+    pub fn ellint_RJ_e(
+        x: f64,
+        y: f64,
+        z: f64,
+        p: f64,
+        mode: ::Mode,
+    ) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret =
             unsafe { ::sys::gsl_sf_ellint_RJ_e(x, y, z, p, mode.into(), result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_ellint_RJ_e"
+        )
     }
 }
@@ -2,7 +2,7 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use std::os::raw::c_int;
+use core::ffi::c_int;
 
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
 pub enum Mode {
@@ -202,6 +202,36 @@ impl From<c_int> for Value {
     }
 }
 
+/// The outcome of a convergence test, such as [`roots::test_interval`](crate::roots::test_interval)
+/// or [`multimin::test_gradient`](crate::multimin::test_gradient).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum ConvergenceStatus {
+    /// The test is satisfied: the iteration can stop.
+    Converged,
+    /// The test isn't satisfied yet: the iteration should go on.
+    Continue,
+}
+
+impl ConvergenceStatus {
+    pub fn is_converged(self) -> bool {
+        self == Self::Converged
+    }
+
+    /// Converts the return value of the GSL test function `function`, which returns
+    /// `GSL_SUCCESS` or `GSL_CONTINUE` (or an error code for invalid tolerances).
+    #[cfg(feature = "std")]
+    pub(crate) fn from_test(ret: c_int, function: &'static str) -> Result<Self, ::Error> {
+        match Value::from(ret) {
+            Value::Success => Ok(Self::Converged),
+            Value::Continue => Ok(Self::Continue),
+            value => {
+                ::error::resume_handler_panic(ret);
+                Err(::Error::new(value, function))
+            }
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
 pub enum EigenSort {
     /// ascending order in numerical value
@@ -292,8 +322,8 @@ pub enum GaussKronrodRule {
 }
 
 #[doc(hidden)]
-impl Into<::std::os::raw::c_int> for GaussKronrodRule {
-    fn into(self) -> ::std::os::raw::c_int {
+impl Into<c_int> for GaussKronrodRule {
+    fn into(self) -> c_int {
         let x = match self {
             Self::Gauss15 => sys::GSL_INTEG_GAUSS15,
             Self::Gauss21 => sys::GSL_INTEG_GAUSS21,
@@ -307,8 +337,8 @@ impl Into<::std::os::raw::c_int> for GaussKronrodRule {
 }
 
 #[doc(hidden)]
-impl From<::std::os::raw::c_int> for GaussKronrodRule {
-    fn from(v: ::std::os::raw::c_int) -> GaussKronrodRule {
+impl From<c_int> for GaussKronrodRule {
+    fn from(v: c_int) -> GaussKronrodRule {
         match v as _ {
             sys::GSL_INTEG_GAUSS15 => Self::Gauss15,
             sys::GSL_INTEG_GAUSS21 => Self::Gauss21,
@@ -364,8 +394,8 @@ pub enum VegasMode {
 }
 
 #[doc(hidden)]
-impl Into<::std::os::raw::c_int> for VegasMode {
-    fn into(self) -> ::std::os::raw::c_int {
+impl Into<c_int> for VegasMode {
+    fn into(self) -> c_int {
         match self {
             Self::Importance => sys::GSL_VEGAS_MODE_IMPORTANCE,
             Self::ImportanceOnly => sys::GSL_VEGAS_MODE_IMPORTANCE_ONLY,
@@ -375,8 +405,8 @@ impl Into<::std::os::raw::c_int> for VegasMode {
 }
 
 #[doc(hidden)]
-impl From<::std::os::raw::c_int> for VegasMode {
-    fn from(v: ::std::os::raw::c_int) -> VegasMode {
+impl From<c_int> for VegasMode {
+    fn from(v: c_int) -> VegasMode {
         match v {
             sys::GSL_VEGAS_MODE_IMPORTANCE => Self::Importance,
             sys::GSL_VEGAS_MODE_IMPORTANCE_ONLY => Self::ImportanceOnly,
@@ -688,3 +718,111 @@ impl From<sys::gsl_filter_scale_t> for FilterScale {
         }
     }
 }
+
+/// The storage format of a [`SparseMatrix`](crate::SparseMatrix).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum SparseFormat {
+    /// Triplet (or coordinate) storage: each non-zero element is stored with its row and column.
+    /// It is the only format whose elements can be set.
+    Triplet,
+    /// Compressed column storage.
+    CompressedColumn,
+    /// Compressed row storage.
+    CompressedRow,
+}
+
+#[doc(hidden)]
+impl From<SparseFormat> for c_int {
+    fn from(v: SparseFormat) -> c_int {
+        (match v {
+            SparseFormat::Triplet => sys::GSL_SPMATRIX_TRIPLET,
+            SparseFormat::CompressedColumn => sys::GSL_SPMATRIX_CCS,
+            SparseFormat::CompressedRow => sys::GSL_SPMATRIX_CRS,
+        }) as _
+    }
+}
+
+#[doc(hidden)]
+impl From<c_int> for SparseFormat {
+    fn from(v: c_int) -> SparseFormat {
+        match v as _ {
+            sys::GSL_SPMATRIX_TRIPLET => Self::Triplet,
+            sys::GSL_SPMATRIX_CCS => Self::CompressedColumn,
+            sys::GSL_SPMATRIX_CRS => Self::CompressedRow,
+            _ => panic!("Unknown SparseFormat value"),
+        }
+    }
+}
+
+/// The finite difference method used to approximate the Jacobian when it isn't provided.
+#[cfg(feature = "v2_2")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum MultiFitNLinearFdType {
+    /// Forward finite differences, using `p` function evaluations.
+    Forward,
+    /// Centered finite differences, using `2 p` function evaluations. More accurate but slower.
+    Central,
+}
+
+#[doc(hidden)]
+#[cfg(feature = "v2_2")]
+impl Into<sys::gsl_multifit_nlinear_fdtype> for MultiFitNLinearFdType {
+    fn into(self) -> sys::gsl_multifit_nlinear_fdtype {
+        match self {
+            Self::Forward => sys::gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_FWDIFF,
+            Self::Central => sys::gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_CTRDIFF,
+        }
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "v2_2")]
+impl From<sys::gsl_multifit_nlinear_fdtype> for MultiFitNLinearFdType {
+    fn from(v: sys::gsl_multifit_nlinear_fdtype) -> MultiFitNLinearFdType {
+        match v {
+            sys::gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_FWDIFF => Self::Forward,
+            sys::gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_CTRDIFF => Self::Central,
+            _ => panic!("Unknown MultiFitNLinearFdType value"),
+        }
+    }
+}
+
+/// How the moving window statistics handle the end points of the input vector, where the window
+/// doesn't fit.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum MovStatEnd {
+    /// The input is padded with zeros.
+    PadZero,
+    /// The input is padded with its first and last values.
+    PadValue,
+    /// The window is truncated at the end points.
+    Truncate,
+}
+
+#[doc(hidden)]
+#[cfg(feature = "v2_5")]
+impl Into<sys::gsl_movstat_end_t> for MovStatEnd {
+    fn into(self) -> sys::gsl_movstat_end_t {
+        match self {
+            Self::PadZero => sys::gsl_movstat_end_t_GSL_MOVSTAT_END_PADZERO,
+            Self::PadValue => sys::gsl_movstat_end_t_GSL_MOVSTAT_END_PADVALUE,
+            Self::Truncate => sys::gsl_movstat_end_t_GSL_MOVSTAT_END_TRUNCATE,
+        }
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "v2_5")]
+impl From<sys::gsl_movstat_end_t> for MovStatEnd {
+    fn from(v: sys::gsl_movstat_end_t) -> MovStatEnd {
+        match v {
+            sys::gsl_movstat_end_t_GSL_MOVSTAT_END_PADZERO => Self::PadZero,
+            sys::gsl_movstat_end_t_GSL_MOVSTAT_END_PADVALUE => Self::PadValue,
+            sys::gsl_movstat_end_t_GSL_MOVSTAT_END_TRUNCATE => Self::Truncate,
+            _ => panic!("Unknown MovStatEnd value"),
+        }
+    }
+}
@@ -5,23 +5,127 @@
 //! The error function is described in Abramowitz & Stegun, Chapter 7.
 
 use crate::Value;
-use std::ffi::CStr;
-use std::mem::MaybeUninit;
+use core::ffi::CStr;
+use core::fmt;
+use core::mem::MaybeUninit;
+#[cfg(feature = "std")]
+use std::any::Any;
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+#[cfg(feature = "std")]
+use std::error::Error as StdError;
+#[cfg(feature = "std")]
 use std::os::raw::{c_char, c_int};
+#[cfg(feature = "std")]
+use std::panic::{self, AssertUnwindSafe};
+#[cfg(feature = "std")]
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Error returned by the GSL routines wrapped in this crate.
+///
+/// It carries the GSL error code, its description (as returned by `gsl_strerror`) and the name of
+/// the GSL function which failed.
+///
+/// ```
+/// use rgsl::{bessel, Value};
+///
+/// rgsl::error::set_error_handler_off();
+/// match bessel::K0_e(1e3) {
+///     Ok(r) => println!("K0(1e3) = {:.3e}", r.val),
+///     Err(e) => {
+///         assert_eq!(e.value(), Value::UnderFlow);
+///         println!("{}", e);
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Error {
+    value: Value,
+    function: &'static str,
+}
+
+impl Error {
+    /// Creates a new error for the GSL function `function` which failed with `value`.
+    pub fn new(value: Value, function: &'static str) -> Error {
+        Error { value, function }
+    }
+
+    /// Returns the GSL error value.
+    pub fn value(&self) -> Value {
+        self.value
+    }
+
+    /// Returns the raw GSL error code (`GSL_EDOM`, `GSL_EINVAL`, ...).
+    pub fn code(&self) -> i32 {
+        self.value.into()
+    }
+
+    /// Returns the name of the GSL function which failed.
+    pub fn function(&self) -> &'static str {
+        self.function
+    }
+
+    /// Returns the description of the error code, as given by `gsl_strerror`.
+    #[doc(alias = "gsl_strerror")]
+    pub fn reason(&self) -> &'static str {
+        unsafe {
+            let s = sys::gsl_strerror(self.code());
+            if s.is_null() {
+                str_error(self.value)
+            } else {
+                CStr::from_ptr(s)
+                    .to_str()
+                    .unwrap_or_else(|_| str_error(self.value))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} ({:?})", self.function, self.reason(), self.value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for Error {}
+
+impl From<Error> for Value {
+    fn from(e: Error) -> Value {
+        e.value
+    }
+}
 
 /// This routine computes the error function erf(x), where erf(x) = (2/\sqrt(\pi)) \int_0^x dt \exp(-t^2).
 #[doc(alias = "gsl_sf_erf")]
+#[inline]
 pub fn erf(x: f64) -> f64 {
     unsafe { ::sys::gsl_sf_erf(x) }
 }
 
+/// Computes [`erf`] for every element of `xs` and stores the results in `out`, which must have
+/// the same length. erf(0) = 0 is returned without calling GSL.
+///
+/// ```
+/// use rgsl::error;
+///
+/// let xs = [0., 0.5, -2.];
+/// let mut out = [1.; 3];
+/// error::erf_slice(&xs, &mut out);
+/// assert_eq!(out, [0., error::erf(0.5), error::erf(-2.)]);
+/// ```
+#[doc(alias = "gsl_sf_erf")]
+pub fn erf_slice(xs: &[f64], out: &mut [f64]) {
+    ::utilities::map_slice(xs, out, |x| if x == 0. { x } else { erf(x) })
+}
+
 /// This routine computes the error function erf(x), where erf(x) = (2/\sqrt(\pi)) \int_0^x dt \exp(-t^2).
 #[doc(alias = "gsl_sf_erf_e")]
-pub fn erf_e(x: f64) -> (Value, ::types::Result) {
+pub fn erf_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_erf_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(ret, unsafe { result.assume_init() }.into(), "gsl_sf_erf_e")
 }
 
 /// This routine computes the complementary error function erfc(x) = 1 - erf(x) = (2/\sqrt(\pi)) \int_x^\infty \exp(-t^2).
@@ -32,11 +136,11 @@ pub fn erfc(x: f64) -> f64 {
 
 /// This routine computes the complementary error function erfc(x) = 1 - erf(x) = (2/\sqrt(\pi)) \int_x^\infty \exp(-t^2).
 #[doc(alias = "gsl_sf_erfc_e")]
-pub fn erfc_e(x: f64) -> (Value, ::types::Result) {
+pub fn erfc_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_erfc_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(ret, unsafe { result.assume_init() }.into(), "gsl_sf_erfc_e")
 }
 
 /// This routine computes the logarithm of the complementary error function \log(\erfc(x)).
@@ -47,11 +151,15 @@ pub fn log_erfc(x: f64) -> f64 {
 
 /// This routine computes the logarithm of the complementary error function \log(\erfc(x)).
 #[doc(alias = "gsl_sf_log_erfc_e")]
-pub fn log_erfc_e(x: f64) -> (Value, ::types::Result) {
+pub fn log_erfc_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_log_erfc_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_log_erfc_e"
+    )
 }
 
 /// This routine computes the Gaussian probability density function Z(x) = (1/\sqrt{2\pi}) \exp(-x^2/2).
@@ -62,11 +170,15 @@ pub fn erf_Z(x: f64) -> f64 {
 
 /// This routine computes the Gaussian probability density function Z(x) = (1/\sqrt{2\pi}) \exp(-x^2/2).
 #[doc(alias = "gsl_sf_erf_Z_e")]
-pub fn erf_Z_e(x: f64) -> (Value, ::types::Result) {
+pub fn erf_Z_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_erf_Z_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_erf_Z_e"
+    )
 }
 
 /// This routine computes the upper tail of the Gaussian probability function Q(x) = (1/\sqrt{2\pi}) \int_x^\infty dt \exp(-t^2/2).
@@ -89,11 +201,15 @@ pub fn erf_Q(x: f64) -> f64 {
 ///
 /// It decreases rapidly as x approaches -\infty and asymptotes to h(x) \sim x as x approaches +\infty.
 #[doc(alias = "gsl_sf_erf_Q_e")]
-pub fn erf_Q_e(x: f64) -> (Value, ::types::Result) {
+pub fn erf_Q_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_erf_Q_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_erf_Q_e"
+    )
 }
 
 /// This routine computes the hazard function for the normal distribution.
@@ -104,11 +220,15 @@ pub fn hazard(x: f64) -> f64 {
 
 /// This routine computes the hazard function for the normal distribution.
 #[doc(alias = "gsl_sf_hazard_e")]
-pub fn hazard_e(x: f64) -> (Value, ::types::Result) {
+pub fn hazard_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_hazard_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_hazard_e"
+    )
 }
 
 pub fn str_error(error: ::Value) -> &'static str {
@@ -152,8 +272,20 @@ pub fn str_error(error: ::Value) -> &'static str {
     }
 }
 
+#[cfg(feature = "std")]
 static mut CALLBACK: Option<fn(&str, &str, u32, ::Value)> = None;
 
+#[cfg(feature = "std")]
+type Handler = dyn Fn(&str, &str, u32, ::Value) + Send + Sync;
+
+#[cfg(feature = "std")]
+static HANDLER: Mutex<Option<Arc<Handler>>> = Mutex::new(None);
+
+#[cfg(feature = "std")]
+fn handler() -> MutexGuard<'static, Option<Arc<Handler>>> {
+    HANDLER.lock().unwrap_or_else(|e| e.into_inner())
+}
+
 /// `f` is the type of GSL error handler functions. An error handler will be passed four arguments
 /// which specify the reason for the error (a string), the name of the source file in which it
 /// occurred (also a string), the line number in that file (an integer) and the error number (an
@@ -193,9 +325,13 @@ static mut CALLBACK: Option<fn(&str, &str, u32, ::Value)> = None;
 /// let old_handler = set_error_handler(None);
 /// ```
 #[doc(alias = "gsl_set_error_handler")]
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub fn set_error_handler(
     f: Option<fn(&str, &str, u32, ::Value)>,
 ) -> Option<fn(&str, &str, u32, ::Value)> {
+    *handler() = None;
+    clear_handler_panic();
     unsafe {
         let out = CALLBACK.take();
         match f {
@@ -216,40 +352,414 @@ pub fn set_error_handler(
 /// routines must be checked. This is the recommended behavior for production programs. The previous
 /// handler is returned (so that you can restore it later).
 #[doc(alias = "gsl_set_error_handler_off")]
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub fn set_error_handler_off() -> Option<fn(&str, &str, u32, ::Value)> {
+    *handler() = None;
+    clear_handler_panic();
     unsafe {
         sys::gsl_set_error_handler_off();
         CALLBACK.take()
     }
 }
 
+/// Puts GSL in abort-free mode.
+///
+/// By default, GSL reports errors by printing a message and aborting the program. This function
+/// replaces that default handler with one which does nothing, so the wrappers returning a `Result`
+/// report their errors through their `Err`. The other ones have no way to report them: the
+/// special functions without the `_e` suffix return `NaN`, the constructors return `None` when
+/// the allocation fails and the accessors like [`Histogram::get`](crate::Histogram::get) return
+/// 0 when given an invalid index. Install a handler with [`set_handler`] to be told about these
+/// errors as well.
+///
+/// If a custom handler (or the "off" handler) is already installed, it is kept as is, which makes
+/// this function safe to call from libraries as well as from the start of `main`.
+///
+/// ```
+/// rgsl::error::init();
+///
+/// // This underflows but doesn't abort.
+/// assert!(rgsl::bessel::K0_e(1e3).is_err());
+/// ```
+#[doc(alias = "gsl_set_error_handler_off")]
+pub fn init() {
+    unsafe {
+        let previous = sys::gsl_set_error_handler_off();
+        if previous.is_some() {
+            sys::gsl_set_error_handler(previous);
+        }
+    }
+}
+
+/// Installs `f` as the GSL error handler for the whole program.
+///
+/// Unlike [`set_error_handler`], `f` can be any closure, so it can for example log the errors or
+/// collect them instead of aborting the program. It receives the same arguments: the reason, the
+/// source file and line where the error was raised and the error value. If `f` panics, the panic
+/// is resumed once the GSL function which raised the error returns, by the wrappers returning a
+/// `Result`. The other ones, like the special functions without the `_e` suffix, can't report
+/// errors and drop the panic.
+///
+/// The handler stays installed until it is replaced by another call to `set_handler`,
+/// [`set_error_handler`] or [`set_error_handler_off`]. Use [`scoped_handler`] to only install it
+/// for a limited time.
+///
+/// ```
+/// use rgsl::error;
+/// use std::sync::{Arc, Mutex};
+///
+/// let errors = Arc::new(Mutex::new(Vec::new()));
+/// let e = errors.clone();
+/// error::set_handler(move |reason, file, line, value| {
+///     e.lock().unwrap().push(format!("{}:{}: {} ({:?})", file, line, reason, value));
+/// });
+///
+/// // Some code calling GSL...
+///
+/// for error in errors.lock().unwrap().iter() {
+///     println!("{}", error);
+/// }
+/// ```
+#[doc(alias = "gsl_set_error_handler")]
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub fn set_handler<F: Fn(&str, &str, u32, ::Value) + Send + Sync + 'static>(f: F) {
+    *handler() = Some(Arc::new(f));
+    clear_handler_panic();
+    unsafe {
+        CALLBACK = None;
+        sys::gsl_set_error_handler(Some(inner_error_handler));
+    }
+}
+
+/// Installs `f` as the GSL error handler until the returned [`ScopedHandler`] is dropped, at
+/// which point the previously installed handler is restored.
+///
+/// The GSL error handler is shared by the whole program, so the guards should be dropped in the
+/// reverse order of their creation and should not be shared between threads running GSL code
+/// concurrently.
+///
+/// ```
+/// use rgsl::{bessel, error};
+///
+/// {
+///     let _guard = error::scoped_handler(|reason, _, _, value| {
+///         eprintln!("GSL error ({:?}): {}", value, reason);
+///     });
+///     assert!(bessel::K0_e(1e3).is_err());
+/// }
+/// // The previous handler is back.
+/// ```
+#[doc(alias = "gsl_set_error_handler")]
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub fn scoped_handler<F: Fn(&str, &str, u32, ::Value) + Send + Sync + 'static>(
+    f: F,
+) -> ScopedHandler {
+    let previous_handler = handler().replace(Arc::new(f));
+    unsafe {
+        let previous_callback = CALLBACK;
+        CALLBACK = None;
+        let previous = sys::gsl_set_error_handler(Some(inner_error_handler));
+        ScopedHandler {
+            previous,
+            previous_handler,
+            previous_callback,
+        }
+    }
+}
+
+/// Guard returned by [`scoped_handler`]. The previous GSL error handler is restored when it is
+/// dropped.
+#[must_use = "the error handler is restored as soon as the guard is dropped"]
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub struct ScopedHandler {
+    previous: sys::gsl_error_handler_t,
+    previous_handler: Option<Arc<Handler>>,
+    previous_callback: Option<fn(&str, &str, u32, ::Value)>,
+}
+
+#[cfg(feature = "std")]
+impl Drop for ScopedHandler {
+    fn drop(&mut self) {
+        *handler() = self.previous_handler.take();
+        unsafe {
+            CALLBACK = self.previous_callback.take();
+            sys::gsl_set_error_handler(self.previous);
+        }
+    }
+}
+
+/// A GSL error reported to the error handler, as collected by [`capture`].
+#[derive(Clone, PartialEq, Debug)]
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub struct CapturedError {
+    /// The reason of the error.
+    pub reason: String,
+    /// The GSL source file in which the error was raised.
+    pub file: String,
+    /// The line in `file` at which the error was raised.
+    pub line: u32,
+    /// The error value.
+    pub value: ::Value,
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for CapturedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: {} ({:?})",
+            self.file, self.line, self.reason, self.value
+        )
+    }
+}
+
+/// Runs `f` with an error handler collecting every GSL error raised while it runs, and returns
+/// them alongside the result of `f`. The previous handler is restored afterwards.
+///
+/// It is useful to find out which inner routine of a composite algorithm failed, since the
+/// errors are listed in the order they were raised. As the GSL error handler is shared by the
+/// whole program, errors raised concurrently by other threads are collected as well.
+///
+/// ```
+/// use rgsl::{bessel, error};
+///
+/// let (res, errors) = error::capture(|| bessel::K0_e(1e3));
+/// assert!(res.is_err());
+/// for e in &errors {
+///     println!("{}", e);
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub fn capture<T, F: FnOnce() -> T>(f: F) -> (T, Vec<CapturedError>) {
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let ret = {
+        let errors = errors.clone();
+        let _guard = scoped_handler(move |reason, file, line, value| {
+            errors
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(CapturedError {
+                    reason: reason.to_owned(),
+                    file: file.to_owned(),
+                    line,
+                    value,
+                });
+        });
+        f()
+    };
+    let errors = match Arc::try_unwrap(errors) {
+        Ok(errors) => errors.into_inner().unwrap_or_else(|e| e.into_inner()),
+        Err(errors) => errors.lock().unwrap_or_else(|e| e.into_inner()).clone(),
+    };
+    (ret, errors)
+}
+
+/// An error returned by the function run by [`trap`], along with the report GSL gave to the error
+/// handler when it was raised (if any).
+#[derive(Clone, PartialEq, Debug)]
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub struct TrappedError {
+    /// The error returned by the wrapper.
+    pub error: Error,
+    /// The last error reported to the GSL error handler, with the reason, file and line.
+    pub report: Option<CapturedError>,
+}
+
+#[cfg(feature = "std")]
+impl TrappedError {
+    /// Returns the raw GSL error code.
+    pub fn code(&self) -> i32 {
+        self.error.code()
+    }
+
+    /// Returns the reason given by GSL, or the description of the error code if GSL didn't
+    /// report anything.
+    pub fn reason(&self) -> &str {
+        match self.report {
+            Some(ref r) => &r.reason,
+            None => self.error.reason(),
+        }
+    }
+
+    /// Returns the GSL source file and line at which the error was raised, if it was reported.
+    pub fn location(&self) -> Option<(&str, u32)> {
+        self.report.as_ref().map(|r| (r.file.as_str(), r.line))
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for TrappedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.report {
+            Some(ref r) => write!(f, "{} [{}]", self.error, r),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for TrappedError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<TrappedError> for Error {
+    fn from(e: TrappedError) -> Error {
+        e.error
+    }
+}
+
+/// Runs `f`, typically a solver driver, without letting the GSL error handler abort and returns
+/// its error along with the code, reason, file and line GSL reported for it.
+///
+/// ```
+/// use rgsl::{bessel, error};
+///
+/// let e = error::trap(|| bessel::K0_e(1e3)).unwrap_err();
+/// if let Some((file, line)) = e.location() {
+///     println!("{}:{}: {} (code {})", file, line, e.reason(), e.code());
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub fn trap<T, F: FnOnce() -> Result<T, Error>>(f: F) -> Result<T, TrappedError> {
+    let (ret, mut errors) = capture(f);
+    ret.map_err(|error| TrappedError {
+        error,
+        report: errors.pop(),
+    })
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    static CALLBACK_PANIC: RefCell<Option<Box<dyn Any + Send>>> = const { RefCell::new(None) };
+    // The panic of the last call to the error handler on this thread, with the error value it was
+    // given.
+    static HANDLER_PANIC: RefCell<Option<(c_int, Box<dyn Any + Send>)>> =
+        const { RefCell::new(None) };
+}
+
+/// Runs a user callback called from GSL.
+///
+/// Unwinding through the GSL frames is undefined behaviour, so a panic is caught and kept, and
+/// `on_panic` is returned to GSL instead. The panic is resumed by [`resume_callback_panic`] once
+/// GSL returned.
+#[cfg(feature = "std")]
+pub(crate) fn catch_callback<T, F: FnOnce() -> T>(on_panic: T, f: F) -> T {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(ret) => ret,
+        Err(payload) => {
+            CALLBACK_PANIC.with(|p| {
+                // If the callback is called again and panics again, the first panic is kept.
+                p.borrow_mut().get_or_insert(payload);
+            });
+            on_panic
+        }
+    }
+}
+
+/// Resumes the panic of a callback caught by [`catch_callback`] on this thread, if any.
+#[cfg(feature = "std")]
+pub(crate) fn resume_callback_panic() {
+    if let Some(payload) = CALLBACK_PANIC.with(|p| p.borrow_mut().take()) {
+        panic::resume_unwind(payload);
+    }
+}
+
+/// Resumes the panic of the error handler on this thread, if it was given the error `ret` which
+/// a GSL function just returned.
+///
+/// A wrapper which can't report errors drops the panic of the handler. It stays here until the
+/// next call to the handler, so the error value is compared to not resume it from an unrelated
+/// function.
+#[cfg(feature = "std")]
+pub(crate) fn resume_handler_panic(ret: c_int) {
+    let payload = HANDLER_PANIC.with(|p| {
+        let mut p = p.borrow_mut();
+        match p.take() {
+            Some((errno, payload)) if errno == ret => Some(payload),
+            other => {
+                *p = other;
+                None
+            }
+        }
+    });
+    if let Some(payload) = payload {
+        panic::resume_unwind(payload);
+    }
+}
+
+#[cfg(feature = "std")]
+fn clear_handler_panic() {
+    HANDLER_PANIC.with(|p| p.borrow_mut().take());
+}
+
+/// Without `std`, no callback can be given to GSL so there is never a panic to resume.
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn resume_callback_panic() {}
+
+/// Without `std`, no custom error handler can be installed so there is never a panic to resume.
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn resume_handler_panic(_ret: ::core::ffi::c_int) {}
+
+#[cfg(feature = "std")]
 extern "C" fn inner_error_handler(
     reason: *const c_char,
     file: *const c_char,
     line: c_int,
     gsl_errno: c_int,
 ) {
+    // The lock is released before calling the handler in case it calls GSL itself.
+    let h = handler().clone();
     unsafe {
-        if let Some(ref call) = CALLBACK {
-            let s = CStr::from_ptr(reason);
-            let f = CStr::from_ptr(file);
-            call(
-                s.to_str().unwrap_or("Unknown"),
-                f.to_str().unwrap_or("Unknown"),
-                line as _,
-                ::Value::from(gsl_errno),
-            );
-        }
+        let s = CStr::from_ptr(reason).to_str().unwrap_or("Unknown");
+        let f = CStr::from_ptr(file).to_str().unwrap_or("Unknown");
+        let ret = panic::catch_unwind(AssertUnwindSafe(|| {
+            if let Some(h) = h {
+                h(s, f, line as _, ::Value::from(gsl_errno));
+            } else if let Some(ref call) = CALLBACK {
+                call(s, f, line as _, ::Value::from(gsl_errno));
+            }
+        }));
+        // Only the panic of the last call is kept, so that a panic dropped by a wrapper which
+        // can't report errors isn't resumed by a later one.
+        HANDLER_PANIC.with(|p| *p.borrow_mut() = ret.err().map(|payload| (gsl_errno, payload)));
     }
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn test_error_handler() {
     use {bessel, Value};
 
     set_error_handler_off();
     match bessel::K0_e(1e3) {
-        (Value::UnderFlow, r) => println!("K0(1e3) underflowed: {:.3e}", r.val),
+        Err(e) if e.value() == Value::UnderFlow => println!("K0(1e3) underflowed: {}", e),
         _ => panic!("unexpected"),
     }
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn test_callback_panic() {
+    use std::panic;
+
+    set_error_handler_off();
+    let ret = panic::catch_unwind(|| {
+        let x = ::integration::qng(|_| panic!("oops"), 0., 1., 1e-8, 1e-8);
+        x.is_ok()
+    });
+    let payload = ret.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"oops"));
+}
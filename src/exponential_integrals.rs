@@ -2,8 +2,8 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::Value;
-use std::mem::MaybeUninit;
+use crate::Error;
+use core::mem::MaybeUninit;
 
 /// This routine computes the exponential integral E_1(x),
 ///
@@ -17,11 +17,34 @@ pub fn E1(x: f64) -> f64 {
 ///
 /// E_1(x) := \Re \int_1^\infty dt \exp(-xt)/t.
 #[doc(alias = "gsl_sf_expint_E1_e")]
-pub fn E1_e(x: f64) -> (Value, ::types::Result) {
+pub fn E1_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_expint_E1_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_expint_E1_e"
+    )
+}
+
+/// This routine computes the scaled exponential integral \exp(x) E_1(x).
+#[doc(alias = "gsl_sf_expint_E1_scaled")]
+pub fn E1_scaled(x: f64) -> f64 {
+    unsafe { sys::gsl_sf_expint_E1_scaled(x) }
+}
+
+/// This routine computes the scaled exponential integral \exp(x) E_1(x).
+#[doc(alias = "gsl_sf_expint_E1_scaled_e")]
+pub fn E1_scaled_e(x: f64) -> Result<::types::Result, Error> {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_expint_E1_scaled_e(x, result.as_mut_ptr()) };
+
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_expint_E1_scaled_e"
+    )
 }
 
 /// This routine computes the second-order exponential integral E_2(x),
@@ -36,11 +59,34 @@ pub fn E2(x: f64) -> f64 {
 ///
 /// E_2(x) := \Re \int_1^\infty dt \exp(-xt)/t^2.
 #[doc(alias = "gsl_sf_expint_E2_e")]
-pub fn E2_e(x: f64) -> (Value, ::types::Result) {
+pub fn E2_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_expint_E2_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_expint_E2_e"
+    )
+}
+
+/// This routine computes the scaled exponential integral \exp(x) E_2(x).
+#[doc(alias = "gsl_sf_expint_E2_scaled")]
+pub fn E2_scaled(x: f64) -> f64 {
+    unsafe { sys::gsl_sf_expint_E2_scaled(x) }
+}
+
+/// This routine computes the scaled exponential integral \exp(x) E_2(x).
+#[doc(alias = "gsl_sf_expint_E2_scaled_e")]
+pub fn E2_scaled_e(x: f64) -> Result<::types::Result, Error> {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_expint_E2_scaled_e(x, result.as_mut_ptr()) };
+
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_expint_E2_scaled_e"
+    )
 }
 
 /// This routine computes the exponential integral E_n(x) of order n,
@@ -55,11 +101,34 @@ pub fn En(n: i32, x: f64) -> f64 {
 ///
 /// E_n(x) := \Re \int_1^\infty dt \exp(-xt)/t^n.
 #[doc(alias = "gsl_sf_expint_En_e")]
-pub fn En_e(n: i32, x: f64) -> (Value, ::types::Result) {
+pub fn En_e(n: i32, x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_expint_En_e(n, x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_expint_En_e"
+    )
+}
+
+/// This routine computes the scaled exponential integral \exp(x) E_n(x).
+#[doc(alias = "gsl_sf_expint_En_scaled")]
+pub fn En_scaled(n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_expint_En_scaled(n, x) }
+}
+
+/// This routine computes the scaled exponential integral \exp(x) E_n(x).
+#[doc(alias = "gsl_sf_expint_En_scaled_e")]
+pub fn En_scaled_e(n: i32, x: f64) -> Result<::types::Result, Error> {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_expint_En_scaled_e(n, x, result.as_mut_ptr()) };
+
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_expint_En_scaled_e"
+    )
 }
 
 /// This routine computes the exponential integral Ei(x),
@@ -78,11 +147,34 @@ pub fn Ei(x: f64) -> f64 {
 ///
 /// where PV denotes the principal value of the integral.
 #[doc(alias = "gsl_sf_expint_Ei_e")]
-pub fn Ei_e(x: f64) -> (Value, ::types::Result) {
+pub fn Ei_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_expint_Ei_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_expint_Ei_e"
+    )
+}
+
+/// This routine computes the scaled exponential integral \exp(-x) Ei(x).
+#[doc(alias = "gsl_sf_expint_Ei_scaled")]
+pub fn Ei_scaled(x: f64) -> f64 {
+    unsafe { sys::gsl_sf_expint_Ei_scaled(x) }
+}
+
+/// This routine computes the scaled exponential integral \exp(-x) Ei(x).
+#[doc(alias = "gsl_sf_expint_Ei_scaled_e")]
+pub fn Ei_scaled_e(x: f64) -> Result<::types::Result, Error> {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_expint_Ei_scaled_e(x, result.as_mut_ptr()) };
+
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_expint_Ei_scaled_e"
+    )
 }
 
 /// This routine computes the integral Shi(x) = \int_0^x dt \sinh(t)/t.
@@ -93,11 +185,11 @@ pub fn Shi(x: f64) -> f64 {
 
 /// This routine computes the integral Shi(x) = \int_0^x dt \sinh(t)/t.
 #[doc(alias = "gsl_sf_Shi_e")]
-pub fn Shi_e(x: f64) -> (Value, ::types::Result) {
+pub fn Shi_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_Shi_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(ret, unsafe { result.assume_init() }.into(), "gsl_sf_Shi_e")
 }
 
 /// This routine computes the integral Chi(x) := \Re[ \gamma_E + \log(x) + \int_0^x dt (\cosh(t)-1)/t] , where \gamma_E is the Euler constant (available as the macro M_EULER).
@@ -108,11 +200,11 @@ pub fn Chi(x: f64) -> f64 {
 
 /// This routine computes the integral Chi(x) := \Re[ \gamma_E + \log(x) + \int_0^x dt (\cosh(t)-1)/t] , where \gamma_E is the Euler constant (available as the macro M_EULER).
 #[doc(alias = "gsl_sf_Chi_e")]
-pub fn Chi_e(x: f64) -> (Value, ::types::Result) {
+pub fn Chi_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_Chi_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(ret, unsafe { result.assume_init() }.into(), "gsl_sf_Chi_e")
 }
 
 /// This routine computes the third-order exponential integral Ei_3(x) = \int_0^xdt \exp(-t^3) for x >= 0.
@@ -123,11 +215,15 @@ pub fn _3(x: f64) -> f64 {
 
 /// This routine computes the third-order exponential integral Ei_3(x) = \int_0^xdt \exp(-t^3) for x >= 0.
 #[doc(alias = "gsl_sf_expint_3_e")]
-pub fn _3_e(x: f64) -> (Value, ::types::Result) {
+pub fn _3_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_expint_3_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_expint_3_e"
+    )
 }
 
 /// This routine computes the Sine integral Si(x) = \int_0^x dt \sin(t)/t.
@@ -138,11 +234,11 @@ pub fn Si(x: f64) -> f64 {
 
 /// This routine computes the Sine integral Si(x) = \int_0^x dt \sin(t)/t.
 #[doc(alias = "gsl_sf_Si_e")]
-pub fn Si_e(x: f64) -> (Value, ::types::Result) {
+pub fn Si_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_Si_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(ret, unsafe { result.assume_init() }.into(), "gsl_sf_Si_e")
 }
 
 /// This routine computes the Cosine integral Ci(x) = -\int_x^\infty dt \cos(t)/t for x > 0.
@@ -153,11 +249,11 @@ pub fn Ci(x: f64) -> f64 {
 
 /// This routine computes the Cosine integral Ci(x) = -\int_x^\infty dt \cos(t)/t for x > 0.
 #[doc(alias = "gsl_sf_Ci_e")]
-pub fn Ci_e(x: f64) -> (Value, ::types::Result) {
+pub fn Ci_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_Ci_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(ret, unsafe { result.assume_init() }.into(), "gsl_sf_Ci_e")
 }
 
 /// This routine computes the Arctangent integral, which is defined as AtanInt(x) = \int_0^x dt \arctan(t)/t.
@@ -168,9 +264,13 @@ pub fn atanint(x: f64) -> f64 {
 
 /// This routine computes the Arctangent integral, which is defined as AtanInt(x) = \int_0^x dt \arctan(t)/t.
 #[doc(alias = "gsl_sf_atanint_e")]
-pub fn atanint_e(x: f64) -> (Value, ::types::Result) {
+pub fn atanint_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_atanint_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_atanint_e"
+    )
 }
@@ -8,8 +8,8 @@
 ///
 /// Note that the Fermi-Dirac integral is sometimes defined without the normalisation factor in other texts.
 pub mod complete_integrals {
-    use crate::Value;
-    use std::mem::MaybeUninit;
+    use crate::Error;
+    use core::mem::MaybeUninit;
 
     /// This routine computes the complete Fermi-Dirac integral with an index of -1.
     /// This integral is given by F_{-1}(x) = e^x / (1 + e^x).
@@ -21,11 +21,15 @@ pub mod complete_integrals {
     /// This routine computes the complete Fermi-Dirac integral with an index of -1.
     /// This integral is given by F_{-1}(x) = e^x / (1 + e^x).
     #[doc(alias = "gsl_sf_fermi_dirac_m1_e")]
-    pub fn fermi_dirac_m1_e(x: f64) -> (Value, ::types::Result) {
+    pub fn fermi_dirac_m1_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { ::sys::gsl_sf_fermi_dirac_m1_e(x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_fermi_dirac_m1_e"
+        )
     }
 
     /// This routine computes the complete Fermi-Dirac integral with an index of 0.
@@ -38,11 +42,15 @@ pub mod complete_integrals {
     /// This routine computes the complete Fermi-Dirac integral with an index of 0.
     /// This integral is given by F_0(x) = \ln(1 + e^x).
     #[doc(alias = "gsl_sf_fermi_dirac_0_e")]
-    pub fn fermi_dirac_0_e(x: f64) -> (Value, ::types::Result) {
+    pub fn fermi_dirac_0_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { ::sys::gsl_sf_fermi_dirac_0_e(x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_fermi_dirac_0_e"
+        )
     }
 
     /// This routine computes the complete Fermi-Dirac integral with an index of 1, F_1(x) = \int_0^\infty dt (t /(\exp(t-x)+1)).
@@ -53,11 +61,15 @@ pub mod complete_integrals {
 
     /// This routine computes the complete Fermi-Dirac integral with an index of 1, F_1(x) = \int_0^\infty dt (t /(\exp(t-x)+1)).
     #[doc(alias = "gsl_sf_fermi_dirac_1_e")]
-    pub fn fermi_dirac_1_e(x: f64) -> (Value, ::types::Result) {
+    pub fn fermi_dirac_1_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { ::sys::gsl_sf_fermi_dirac_1_e(x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_fermi_dirac_1_e"
+        )
     }
 
     /// This routine computes the complete Fermi-Dirac integral with an index of 2, F_2(x) = (1/2) \int_0^\infty dt (t^2 /(\exp(t-x)+1)).
@@ -68,11 +80,15 @@ pub mod complete_integrals {
 
     /// This routine computes the complete Fermi-Dirac integral with an index of 2, F_2(x) = (1/2) \int_0^\infty dt (t^2 /(\exp(t-x)+1)).
     #[doc(alias = "gsl_sf_fermi_dirac_2_e")]
-    pub fn fermi_dirac_2_e(x: f64) -> (Value, ::types::Result) {
+    pub fn fermi_dirac_2_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { ::sys::gsl_sf_fermi_dirac_2_e(x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_fermi_dirac_2_e"
+        )
     }
 
     /// This routine computes the complete Fermi-Dirac integral with an integer index of j, F_j(x) = (1/\Gamma(j+1)) \int_0^\infty dt (t^j /(\exp(t-x)+1)).
@@ -83,11 +99,15 @@ pub mod complete_integrals {
 
     /// This routine computes the complete Fermi-Dirac integral with an integer index of j, F_j(x) = (1/\Gamma(j+1)) \int_0^\infty dt (t^j /(\exp(t-x)+1)).
     #[doc(alias = "gsl_sf_fermi_dirac_int_e")]
-    pub fn fermi_dirac_int_e(j: i32, x: f64) -> (Value, ::types::Result) {
+    pub fn fermi_dirac_int_e(j: i32, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { ::sys::gsl_sf_fermi_dirac_int_e(j, x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_fermi_dirac_int_e"
+        )
     }
 
     /// This routine computes the complete Fermi-Dirac integral F_{-1/2}(x).
@@ -98,11 +118,15 @@ pub mod complete_integrals {
 
     /// This routine computes the complete Fermi-Dirac integral F_{-1/2}(x).
     #[doc(alias = "gsl_sf_fermi_dirac_mhalf_e")]
-    pub fn fermi_dirac_mhalf_e(x: f64) -> (Value, ::types::Result) {
+    pub fn fermi_dirac_mhalf_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { ::sys::gsl_sf_fermi_dirac_mhalf_e(x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_fermi_dirac_mhalf_e"
+        )
     }
 
     /// This routine computes the complete Fermi-Dirac integral F_{1/2}(x).
@@ -113,11 +137,15 @@ pub mod complete_integrals {
 
     /// This routine computes the complete Fermi-Dirac integral F_{1/2}(x).
     #[doc(alias = "gsl_sf_fermi_dirac_half_e")]
-    pub fn fermi_dirac_half_e(x: f64) -> (Value, ::types::Result) {
+    pub fn fermi_dirac_half_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { ::sys::gsl_sf_fermi_dirac_half_e(x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_fermi_dirac_half_e"
+        )
     }
 
     /// This routine computes the complete Fermi-Dirac integral F_{3/2}(x).
@@ -128,11 +156,15 @@ pub mod complete_integrals {
 
     /// This routine computes the complete Fermi-Dirac integral F_{3/2}(x).
     #[doc(alias = "gsl_sf_fermi_dirac_3half_e")]
-    pub fn fermi_dirac_3half_e(x: f64) -> (Value, ::types::Result) {
+    pub fn fermi_dirac_3half_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { ::sys::gsl_sf_fermi_dirac_3half_e(x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_fermi_dirac_3half_e"
+        )
     }
 }
 
@@ -140,8 +172,8 @@ pub mod complete_integrals {
 ///
 /// F_j(x,b)   := (1/\Gamma(j+1)) \int_b^\infty dt (t^j / (\Exp(t-x) + 1))
 pub mod incomplete_integrals {
-    use crate::Value;
-    use std::mem::MaybeUninit;
+    use crate::Error;
+    use core::mem::MaybeUninit;
 
     /// This routine computes the incomplete Fermi-Dirac integral with an index of zero, F_0(x,b) = \ln(1 + e^{b-x}) - (b-x).
     #[doc(alias = "gsl_sf_fermi_dirac_inc_0")]
@@ -151,10 +183,14 @@ pub mod incomplete_integrals {
 
     /// This routine computes the incomplete Fermi-Dirac integral with an index of zero, F_0(x,b) = \ln(1 + e^{b-x}) - (b-x).
     #[doc(alias = "gsl_sf_fermi_dirac_inc_0_e")]
-    pub fn fermi_dirac_inc_0_e(x: f64, b: f64) -> (Value, ::types::Result) {
+    pub fn fermi_dirac_inc_0_e(x: f64, b: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { ::sys::gsl_sf_fermi_dirac_inc_0_e(x, b, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_fermi_dirac_inc_0_e"
+        )
     }
 }
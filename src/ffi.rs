@@ -2,9 +2,217 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
+use std::cell::RefCell;
+use std::mem::ManuallyDrop;
+use std::rc::Rc;
+
 pub trait FFI<T> {
     fn wrap(r: *mut T) -> Self;
     fn soft_wrap(r: *mut T) -> Self;
     fn unwrap_shared(&self) -> *const T;
     fn unwrap_unique(&mut self) -> *mut T;
 }
+
+/// Lends a non-owning instance wrapping `ptr` (see [`FFI::soft_wrap`]) to `f`.
+///
+/// Safe code can move the instance out of the reference, with `mem::replace` for example, and keep
+/// it after the memory it wraps was freed. Since this can't be prevented, the program is aborted if
+/// the instance was replaced once `f` returns (or panics), while that memory is still alive.
+pub(crate) fn lend_mut<T, W: FFI<T>, R, F: FnOnce(&mut W) -> R>(ptr: *mut T, f: F) -> R {
+    struct Lent<T, W: FFI<T>> {
+        ptr: *mut T,
+        // The memory is owned by the caller of `lend_mut`, it must not be freed here.
+        wrapper: ManuallyDrop<W>,
+    }
+
+    impl<T, W: FFI<T>> Drop for Lent<T, W> {
+        fn drop(&mut self) {
+            if !::std::ptr::eq(self.wrapper.unwrap_shared(), self.ptr) {
+                ::std::process::abort();
+            }
+        }
+    }
+
+    let mut lent = Lent {
+        ptr,
+        wrapper: ManuallyDrop::new(W::soft_wrap(ptr)),
+    };
+    f(&mut lent.wrapper)
+}
+
+/// Owns a closure and the `gsl_function` pointing to it.
+///
+/// Some GSL objects (like the root solvers) keep the pointer to the `gsl_function` they were given
+/// and call it until they're reset, so they have to keep both alive until then.
+pub(crate) struct Function<'a> {
+    raw: Box<sys::gsl_function>,
+    _callback: Box<dyn FnMut(f64) -> f64 + 'a>,
+}
+
+impl<'a> Function<'a> {
+    pub(crate) fn new<F: FnMut(f64) -> f64 + 'a>(f: F) -> Function<'a> {
+        unsafe extern "C" fn trampoline<F: FnMut(f64) -> f64>(
+            x: f64,
+            params: *mut ::std::os::raw::c_void,
+        ) -> f64 {
+            let f: &mut F = &mut *(params as *mut F);
+            ::error::catch_callback(f64::NAN, || f(x))
+        }
+
+        let mut callback = Box::new(f);
+        let raw = Box::new(sys::gsl_function {
+            function: Some(trampoline::<F>),
+            // Moving the box afterwards doesn't move the closure it points to.
+            params: &mut *callback as *mut F as *mut _,
+        });
+        Function {
+            raw,
+            _callback: callback,
+        }
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut sys::gsl_function {
+        &mut *self.raw
+    }
+}
+
+struct FdfCallbacks<'a> {
+    f: Box<dyn FnMut(f64) -> f64 + 'a>,
+    df: Box<dyn FnMut(f64) -> f64 + 'a>,
+    fdf: Box<dyn FnMut(f64, &mut f64, &mut f64) + 'a>,
+}
+
+/// Same as [`Function`] but for `gsl_function_fdf`.
+pub(crate) struct FunctionFdf<'a> {
+    raw: Box<sys::gsl_function_fdf>,
+    _callbacks: Box<FdfCallbacks<'a>>,
+}
+
+impl<'a> FunctionFdf<'a> {
+    pub(crate) fn new<
+        F: FnMut(f64) -> f64 + 'a,
+        DF: FnMut(f64) -> f64 + 'a,
+        FDF: FnMut(f64, &mut f64, &mut f64) + 'a,
+    >(
+        f: F,
+        df: DF,
+        fdf: FDF,
+    ) -> FunctionFdf<'a> {
+        unsafe extern "C" fn inner_f(x: f64, params: *mut ::std::os::raw::c_void) -> f64 {
+            let callbacks = &mut *(params as *mut FdfCallbacks);
+            ::error::catch_callback(f64::NAN, || (callbacks.f)(x))
+        }
+        unsafe extern "C" fn inner_df(x: f64, params: *mut ::std::os::raw::c_void) -> f64 {
+            let callbacks = &mut *(params as *mut FdfCallbacks);
+            ::error::catch_callback(f64::NAN, || (callbacks.df)(x))
+        }
+        unsafe extern "C" fn inner_fdf(
+            x: f64,
+            params: *mut ::std::os::raw::c_void,
+            y: *mut f64,
+            dy: *mut f64,
+        ) {
+            let callbacks = &mut *(params as *mut FdfCallbacks);
+            ::error::catch_callback((), || (callbacks.fdf)(x, &mut *y, &mut *dy));
+        }
+
+        let mut callbacks = Box::new(FdfCallbacks {
+            f: Box::new(f),
+            df: Box::new(df),
+            fdf: Box::new(fdf),
+        });
+        let raw = Box::new(sys::gsl_function_fdf {
+            f: Some(inner_f),
+            df: Some(inner_df),
+            fdf: Some(inner_fdf),
+            params: &mut *callbacks as *mut FdfCallbacks as *mut _,
+        });
+        FunctionFdf {
+            raw,
+            _callbacks: callbacks,
+        }
+    }
+
+    /// Same as [`new`](Self::new), but with a single closure returning `(f(x), f'(x))`. It's also
+    /// called when GSL only needs one of the two values.
+    pub(crate) fn from_fdf<FDF: FnMut(f64) -> (f64, f64) + 'a>(fdf: FDF) -> FunctionFdf<'a> {
+        // GSL never calls the three functions at the same time, so the borrows don't overlap.
+        let fdf = Rc::new(RefCell::new(fdf));
+        let (f, df) = (Rc::clone(&fdf), Rc::clone(&fdf));
+        FunctionFdf::new(
+            move |x| (*f.borrow_mut())(x).0,
+            move |x| (*df.borrow_mut())(x).1,
+            move |x, y, dy| {
+                let (fx, dfx) = (*fdf.borrow_mut())(x);
+                *y = fx;
+                *dy = dfx;
+            },
+        )
+    }
+
+    /// Same as [`new`](Self::new), but the derivative of `f` is computed by central differences
+    /// with [`numerical_differentiation::derivative`](crate::numerical_differentiation::derivative).
+    /// The derivative is NaN where it can't be computed.
+    pub(crate) fn with_numerical_derivative<F: FnMut(f64) -> f64 + 'a>(f: F) -> FunctionFdf<'a> {
+        fn derivative(f: &RefCell<impl FnMut(f64) -> f64>, x: f64) -> f64 {
+            ::numerical_differentiation::derivative(&mut *f.borrow_mut(), x)
+                .map_or(f64::NAN, |(df, _)| df)
+        }
+
+        let f = Rc::new(RefCell::new(f));
+        let (df, fdf) = (Rc::clone(&f), Rc::clone(&f));
+        FunctionFdf::new(
+            move |x| (*f.borrow_mut())(x),
+            move |x| derivative(&df, x),
+            move |x, y, dy| {
+                *y = (*fdf.borrow_mut())(x);
+                *dy = derivative(&fdf, x);
+            },
+        )
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut sys::gsl_function_fdf {
+        &mut *self.raw
+    }
+}
+
+/// Same as [`Function`] but for `gsl_monte_function`.
+///
+/// The Monte Carlo integrators don't keep the pointer after returning, but they keep it so the
+/// same integrand can be integrated again (to continue an adaptive VEGAS run for example).
+pub(crate) struct MonteFunction<'a> {
+    raw: Box<sys::gsl_monte_function>,
+    _callback: Box<dyn FnMut(&[f64]) -> f64 + 'a>,
+}
+
+impl<'a> MonteFunction<'a> {
+    pub(crate) fn new<F: FnMut(&[f64]) -> f64 + 'a>(dim: usize, f: F) -> MonteFunction<'a> {
+        unsafe extern "C" fn trampoline<F: FnMut(&[f64]) -> f64>(
+            x: *mut f64,
+            dim: usize,
+            params: *mut ::std::os::raw::c_void,
+        ) -> f64 {
+            let f: &mut F = &mut *(params as *mut F);
+            ::error::catch_callback(f64::NAN, || f(::std::slice::from_raw_parts(x, dim)))
+        }
+
+        let mut callback = Box::new(f);
+        let raw = Box::new(sys::gsl_monte_function {
+            f: Some(trampoline::<F>),
+            dim,
+            params: &mut *callback as *mut F as *mut _,
+        });
+        MonteFunction {
+            raw,
+            _callback: callback,
+        }
+    }
+
+    pub(crate) fn dim(&self) -> usize {
+        self.raw.dim
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut sys::gsl_monte_function {
+        &mut *self.raw
+    }
+}
@@ -163,69 +163,81 @@ is desirable for better locality of memory accesses).
 /// decimation-in-time algorithm. The length of the transform is restricted to powers of two. For the transform version of the function
 /// the sign argument can be either forward (-1) or backward (+1).
 ///
-/// The functions return a value of ::Value::Success if no errors were detected, or Value::Dom if the length n is not a power of two.
+/// The functions return a `Value::Dom` error if the length n is not a power of two.
 pub mod radix2 {
-    use crate::Value;
+    use crate::Error;
 
     #[doc(alias = "gsl_fft_complex_radix2_forward")]
-    pub fn forward(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe { sys::gsl_fft_complex_radix2_forward(data.as_mut_ptr(), stride, n) })
+    pub fn forward(data: &mut [f64], stride: usize, n: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_fft_complex_radix2_forward(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, (), "gsl_fft_complex_radix2_forward")
     }
 
     #[doc(alias = "gsl_fft_complex_radix2_transform")]
-    pub fn transform(data: &mut [f64], stride: usize, n: usize, sign: ::FftDirection) -> Value {
-        Value::from(unsafe {
+    pub fn transform(
+        data: &mut [f64],
+        stride: usize,
+        n: usize,
+        sign: ::FftDirection,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_fft_complex_radix2_transform(data.as_mut_ptr(), stride, n, sign.into())
-        })
+        };
+        result_handler!(ret, (), "gsl_fft_complex_radix2_transform")
     }
 
     #[doc(alias = "gsl_fft_complex_radix2_backward")]
-    pub fn backward(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe { sys::gsl_fft_complex_radix2_backward(data.as_mut_ptr(), stride, n) })
+    pub fn backward(data: &mut [f64], stride: usize, n: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_fft_complex_radix2_backward(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, (), "gsl_fft_complex_radix2_backward")
     }
 
     #[doc(alias = "gsl_fft_complex_radix2_inverse")]
-    pub fn inverse(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe { sys::gsl_fft_complex_radix2_inverse(data.as_mut_ptr(), stride, n) })
+    pub fn inverse(data: &mut [f64], stride: usize, n: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_fft_complex_radix2_inverse(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, (), "gsl_fft_complex_radix2_inverse")
     }
 
     /// This is decimation-in-frequency version of the radix-2 FFT function.
     #[doc(alias = "gsl_fft_complex_radix2_dif_forward")]
-    pub fn dif_forward(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_fft_complex_radix2_dif_forward(data.as_mut_ptr(), stride, n)
-        })
+    pub fn dif_forward(data: &mut [f64], stride: usize, n: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_fft_complex_radix2_dif_forward(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, (), "gsl_fft_complex_radix2_dif_forward")
     }
 
     /// This is decimation-in-frequency version of the radix-2 FFT function.
     #[doc(alias = "gsl_fft_complex_radix2_dif_transform")]
-    pub fn dif_transform(data: &mut [f64], stride: usize, n: usize, sign: ::FftDirection) -> Value {
-        Value::from(unsafe {
+    pub fn dif_transform(
+        data: &mut [f64],
+        stride: usize,
+        n: usize,
+        sign: ::FftDirection,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_fft_complex_radix2_dif_transform(data.as_mut_ptr(), stride, n, sign.into())
-        })
+        };
+        result_handler!(ret, (), "gsl_fft_complex_radix2_dif_transform")
     }
 
     /// This is decimation-in-frequency version of the radix-2 FFT function.
     #[doc(alias = "gsl_fft_complex_radix2_dif_backward")]
-    pub fn dif_backward(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_fft_complex_radix2_dif_backward(data.as_mut_ptr(), stride, n)
-        })
+    pub fn dif_backward(data: &mut [f64], stride: usize, n: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_fft_complex_radix2_dif_backward(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, (), "gsl_fft_complex_radix2_dif_backward")
     }
 
     /// This is decimation-in-frequency version of the radix-2 FFT function.
     #[doc(alias = "gsl_fft_complex_radix2_dif_inverse")]
-    pub fn dif_inverse(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_fft_complex_radix2_dif_inverse(data.as_mut_ptr(), stride, n)
-        })
+    pub fn dif_inverse(data: &mut [f64], stride: usize, n: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_fft_complex_radix2_dif_inverse(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, (), "gsl_fft_complex_radix2_dif_inverse")
     }
 }
 
 /// This section describes radix-2 FFT algorithms for real data. They use the Cooley-Tukey algorithm to compute in-place FFTs for lengths which
 /// are a power of 2.
 pub mod real_radix2 {
-    use crate::Value;
+    use crate::Error;
 
     /// This function computes an in-place radix-2 FFT of length n and stride stride on the real array data. The output is a half-complex sequence,
     /// which is stored in-place. The arrangement of the half-complex terms uses the following scheme: for k < n/2 the real part of the k-th term
@@ -258,26 +270,25 @@ pub mod real_radix2 {
     /// Note that the output data can be converted into the full complex sequence using the function gsl_fft_halfcomplex_radix2_unpack described
     /// below.
     #[doc(alias = "gsl_fft_real_radix2_transform")]
-    pub fn transform(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe { sys::gsl_fft_real_radix2_transform(data.as_mut_ptr(), stride, n) })
+    pub fn transform(data: &mut [f64], stride: usize, n: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_fft_real_radix2_transform(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, (), "gsl_fft_real_radix2_transform")
     }
 
     /// This function computes the inverse or backwards in-place radix-2 FFT of length n and stride stride on the half-complex sequence data
     /// stored according the output scheme used by gsl_fft_real_radix2. The result is a real array stored in natural order.
     #[doc(alias = "gsl_fft_halfcomplex_radix2_inverse")]
-    pub fn inverse(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_fft_halfcomplex_radix2_inverse(data.as_mut_ptr(), stride, n)
-        })
+    pub fn inverse(data: &mut [f64], stride: usize, n: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_fft_halfcomplex_radix2_inverse(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, (), "gsl_fft_halfcomplex_radix2_inverse")
     }
 
     /// This function computes the inverse or backwards in-place radix-2 FFT of length n and stride stride on the half-complex sequence data
     /// stored according the output scheme used by gsl_fft_real_radix2. The result is a real array stored in natural order.
     #[doc(alias = "gsl_fft_halfcomplex_radix2_backward")]
-    pub fn backward(data: &mut [f64], stride: usize, n: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_fft_halfcomplex_radix2_backward(data.as_mut_ptr(), stride, n)
-        })
+    pub fn backward(data: &mut [f64], stride: usize, n: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_fft_halfcomplex_radix2_backward(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, (), "gsl_fft_halfcomplex_radix2_backward")
     }
 
     /// This function converts halfcomplex_coefficient, an array of half-complex coefficients as returned by gsl_fft_real_radix2_transform,
@@ -316,14 +327,282 @@ pub mod real_radix2 {
         complex_coefficient: &mut [f64],
         stride: usize,
         n: usize,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_fft_halfcomplex_radix2_unpack(
                 halfcomplex_coefficient.as_mut_ptr(),
                 complex_coefficient.as_mut_ptr(),
                 stride,
                 n,
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_fft_halfcomplex_radix2_unpack")
+    }
+}
+
+/// The mixed-radix algorithms work for FFTs of any length, using the factors of the length. These
+/// functions compute the transforms of length n with stride stride on the packed complex array
+/// data.
+///
+/// The wavetable and the workspace needed by the algorithm are taken from the thread's
+/// [`WorkspacePool`](crate::WorkspacePool). Use the methods of
+/// [`FftComplexF64Workspace`](crate::FftComplexF64Workspace) to manage them yourself.
+pub mod mixed_radix {
+    use crate::{Error, FftComplexF64WaveTable, FftComplexF64Workspace, WorkspacePool};
+
+    macro_rules! mixed_radix {
+        ($name:ident, $c_name:expr $(, $sign:ident)?) => {
+            #[doc(alias = $c_name)]
+            pub fn $name(
+                data: &mut [f64],
+                stride: usize,
+                n: usize
+                $(, $sign: ::FftDirection)?
+            ) -> Result<(), Error> {
+                WorkspacePool::<FftComplexF64WaveTable>::with(n, |wavetable| {
+                    WorkspacePool::<FftComplexF64Workspace>::with(n, |workspace| {
+                        workspace.$name(data, stride, n, wavetable $(, $sign)?)
+                    })
+                })
+                .and_then(|r| r)
+                .and_then(|r| r)
+            }
+        };
+    }
+
+    mixed_radix!(forward, "gsl_fft_complex_forward");
+    mixed_radix!(transform, "gsl_fft_complex_transform", sign);
+    mixed_radix!(backward, "gsl_fft_complex_backward");
+    mixed_radix!(inverse, "gsl_fft_complex_inverse");
+}
+
+/// The mixed-radix algorithms for real data work for FFTs of any length, using the factors of the
+/// length. The half-complex sequences are stored with the real and imaginary parts of each term
+/// in neighboring locations, unlike the radix-2 ones: for a length n, the output of
+/// [`transform`](real_mixed_radix::transform) is
+/// `[z_0.real, z_1.real, z_1.imag, ..., z_{n/2}.real]` (the last term is only stored when n is
+/// even).
+///
+/// The wavetables and the workspace needed by the algorithm are taken from the thread's
+/// [`WorkspacePool`](crate::WorkspacePool). Use the methods of
+/// [`FftRealF64Workspace`](crate::FftRealF64Workspace) to manage them yourself.
+pub mod real_mixed_radix {
+    use crate::{
+        Error, FftHalfComplexF64WaveTable, FftRealF64WaveTable, FftRealF64Workspace, WorkspacePool,
+    };
+
+    macro_rules! real_mixed_radix {
+        ($name:ident, $method:ident, $wavetable:ident, $c_name:expr) => {
+            #[doc(alias = $c_name)]
+            pub fn $name(data: &mut [f64], stride: usize, n: usize) -> Result<(), Error> {
+                WorkspacePool::<$wavetable>::with(n, |wavetable| {
+                    WorkspacePool::<FftRealF64Workspace>::with(n, |workspace| {
+                        workspace.$method(data, stride, n, wavetable)
+                    })
+                })
+                .and_then(|r| r)
+                .and_then(|r| r)
+            }
+        };
+    }
+
+    real_mixed_radix!(
+        transform,
+        real_transform,
+        FftRealF64WaveTable,
+        "gsl_fft_real_transform"
+    );
+    real_mixed_radix!(
+        halfcomplex_transform,
+        halfcomplex_transform,
+        FftHalfComplexF64WaveTable,
+        "gsl_fft_halfcomplex_transform"
+    );
+    real_mixed_radix!(
+        backward,
+        halfcomplex_backward,
+        FftHalfComplexF64WaveTable,
+        "gsl_fft_halfcomplex_backward"
+    );
+    real_mixed_radix!(
+        inverse,
+        halfcomplex_inverse,
+        FftHalfComplexF64WaveTable,
+        "gsl_fft_halfcomplex_inverse"
+    );
+
+    /// This function converts a single real array, real_coefficient into an equivalent complex
+    /// array, complex_coefficient, (with imaginary part set to zero), suitable for
+    /// [`mixed_radix`](crate::fft::mixed_radix) routines.
+    #[doc(alias = "gsl_fft_real_unpack")]
+    pub fn unpack(
+        real_coefficient: &[f64],
+        complex_coefficient: &mut [f64],
+        stride: usize,
+        n: usize,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_fft_real_unpack(
+                real_coefficient.as_ptr(),
+                complex_coefficient.as_mut_ptr(),
+                stride,
+                n,
+            )
+        };
+        result_handler!(ret, (), "gsl_fft_real_unpack")
+    }
+
+    /// This function converts halfcomplex_coefficient, an array of half-complex coefficients as
+    /// returned by [`transform`], into an ordinary complex array, complex_coefficient. It fills in
+    /// the complex array using the symmetry z_k = z_{n-k}^* to reconstruct the redundant elements.
+    #[doc(alias = "gsl_fft_halfcomplex_unpack")]
+    pub fn halfcomplex_unpack(
+        halfcomplex_coefficient: &[f64],
+        complex_coefficient: &mut [f64],
+        stride: usize,
+        n: usize,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_fft_halfcomplex_unpack(
+                halfcomplex_coefficient.as_ptr(),
+                complex_coefficient.as_mut_ptr(),
+                stride,
+                n,
+            )
+        };
+        result_handler!(ret, (), "gsl_fft_halfcomplex_unpack")
+    }
+
+    #[test]
+    fn real_round_trip() {
+        // A length which isn't a power of two.
+        let signal = [1., 2., 0., -1., 3.];
+        let mut data = signal;
+        transform(&mut data, 1, 5).unwrap();
+        // The first term is the sum of the signal.
+        assert!((data[0] - 5.).abs() < 1e-12);
+
+        let mut complex = [0.; 10];
+        halfcomplex_unpack(&data, &mut complex, 1, 5).unwrap();
+        assert_eq!((complex[2], complex[3]), (data[1], data[2]));
+        assert_eq!((complex[8], complex[9]), (data[1], -data[2]));
+
+        inverse(&mut data, 1, 5).unwrap();
+        for (x, y) in data.iter().zip(&signal) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+}
+
+/// Mixed-radix FFTs working directly on slices of [`ComplexF64`](crate::ComplexF64), instead of
+/// packed arrays of `f64`. The length of the transform is the length of the slice, and the
+/// elements are contiguous.
+///
+/// The [`halfcomplex_unpack`](complex::halfcomplex_unpack) and
+/// [`halfcomplex_pack`](complex::halfcomplex_pack) helpers convert between the half-complex
+/// output of [`real_mixed_radix::transform`] and the full complex spectrum.
+pub mod complex {
+    use crate::{ComplexF64, Error};
+
+    fn as_packed(data: &mut [ComplexF64]) -> &mut [f64] {
+        // `ComplexF64` is `#[repr(C)]` around `[f64; 2]`, so this is a packed complex array.
+        unsafe { std::slice::from_raw_parts_mut(data.as_mut_ptr() as *mut f64, data.len() * 2) }
+    }
+
+    /// This function computes the forward FFT of data.
+    #[doc(alias = "gsl_fft_complex_forward")]
+    pub fn forward(data: &mut [ComplexF64]) -> Result<(), Error> {
+        let n = data.len();
+        super::mixed_radix::forward(as_packed(data), 1, n)
+    }
+
+    /// This function computes the FFT of data in the given direction.
+    #[doc(alias = "gsl_fft_complex_transform")]
+    pub fn transform(data: &mut [ComplexF64], sign: ::FftDirection) -> Result<(), Error> {
+        let n = data.len();
+        super::mixed_radix::transform(as_packed(data), 1, n, sign)
+    }
+
+    /// This function computes the backward FFT of data, which is the inverse FFT without the
+    /// 1/n scaling.
+    #[doc(alias = "gsl_fft_complex_backward")]
+    pub fn backward(data: &mut [ComplexF64]) -> Result<(), Error> {
+        let n = data.len();
+        super::mixed_radix::backward(as_packed(data), 1, n)
+    }
+
+    /// This function computes the inverse FFT of data.
+    #[doc(alias = "gsl_fft_complex_inverse")]
+    pub fn inverse(data: &mut [ComplexF64]) -> Result<(), Error> {
+        let n = data.len();
+        super::mixed_radix::inverse(as_packed(data), 1, n)
+    }
+
+    /// This function converts a half-complex sequence of length n, as returned by
+    /// [`real_mixed_radix::transform`](super::real_mixed_radix::transform), into the full complex
+    /// spectrum of n terms. The redundant terms are reconstructed using the symmetry
+    /// z_k = z_{n-k}^*.
+    #[doc(alias = "gsl_fft_halfcomplex_unpack")]
+    pub fn halfcomplex_unpack(halfcomplex: &[f64]) -> Result<Vec<ComplexF64>, Error> {
+        let n = halfcomplex.len();
+        let mut spectrum = vec![ComplexF64::default(); n];
+        super::real_mixed_radix::halfcomplex_unpack(halfcomplex, as_packed(&mut spectrum), 1, n)?;
+        Ok(spectrum)
+    }
+
+    /// This function is the reverse of [`halfcomplex_unpack`]: it keeps the non-redundant terms of
+    /// a complex spectrum of length n and stores them in the half-complex layout expected by
+    /// [`real_mixed_radix::backward`](super::real_mixed_radix::backward) and
+    /// [`real_mixed_radix::inverse`](super::real_mixed_radix::inverse).
+    ///
+    /// The spectrum is assumed to be the one of a real sequence, so the imaginary parts of
+    /// z_0 (and of z_{n/2} when n is even) are dropped, like the terms after the middle.
+    pub fn halfcomplex_pack(spectrum: &[ComplexF64]) -> Vec<f64> {
+        let n = spectrum.len();
+        let middle = n / 2;
+        let mut halfcomplex = Vec::with_capacity(n);
+
+        if let Some(z) = spectrum.first() {
+            halfcomplex.push(z.dat[0]);
+        }
+        for z in spectrum.iter().take(n - middle).skip(1) {
+            halfcomplex.extend_from_slice(&z.dat);
+        }
+        if n > 1 && middle * 2 == n {
+            halfcomplex.push(spectrum[middle].dat[0]);
+        }
+        halfcomplex
+    }
+
+    #[test]
+    fn halfcomplex_pack_unpack() {
+        for n in 1..8 {
+            let halfcomplex = (0..n).map(|i| i as f64 + 1.).collect::<Vec<_>>();
+            let spectrum = halfcomplex_unpack(&halfcomplex).unwrap();
+            assert_eq!(spectrum.len(), n);
+            assert_eq!(spectrum[0].dat, [1., 0.]);
+            for k in 1..n {
+                assert_eq!(spectrum[k].dat[0], spectrum[n - k].dat[0]);
+                assert_eq!(spectrum[k].dat[1], -spectrum[n - k].dat[1]);
+            }
+            assert_eq!(halfcomplex_pack(&spectrum), halfcomplex);
+        }
+    }
+
+    #[test]
+    fn complex_round_trip() {
+        let signal = (0..6)
+            .map(|i| ComplexF64 {
+                dat: [i as f64, 1. - i as f64],
+            })
+            .collect::<Vec<_>>();
+        let mut data = signal.clone();
+        forward(&mut data).unwrap();
+        assert!((data[0].dat[0] - 15.).abs() < 1e-12);
+        inverse(&mut data).unwrap();
+        for (x, y) in data.iter().zip(&signal) {
+            assert!((x.dat[0] - y.dat[0]).abs() < 1e-12);
+            assert!((x.dat[1] - y.dat[1]).abs() < 1e-12);
+        }
     }
 }
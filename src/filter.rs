@@ -2,7 +2,7 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::{Value, VectorF64};
+use crate::{Error, VectorF64};
 use ffi::FFI;
 
 /// This function constructs a Gaussian kernel parameterized by `alpha` and stores the output in
@@ -11,8 +11,14 @@ use ffi::FFI;
 /// `true`, then the kernel will be normalized to sum to one on output. If `normalize` is set to
 /// `false`, no normalization is performed.
 #[doc(alias = "gsl_filter_gaussian_kernel")]
-pub fn gaussian_kernel(alpha: f64, order: usize, normalize: bool, kernel: &mut VectorF64) -> Value {
-    Value::from(unsafe {
+pub fn gaussian_kernel(
+    alpha: f64,
+    order: usize,
+    normalize: bool,
+    kernel: &mut VectorF64,
+) -> Result<(), Error> {
+    let ret = unsafe {
         sys::gsl_filter_gaussian_kernel(alpha, order, normalize as _, kernel.unwrap_unique())
-    })
+    };
+    result_handler!(ret, (), "gsl_filter_gaussian_kernel")
 }
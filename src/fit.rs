@@ -8,7 +8,57 @@ Linear Regression
 The functions described in this section can be used to perform least-squares fits to a straight line model, Y(c,x) = c_0 + c_1 x.
 !*/
 
-use crate::Value;
+use crate::Error;
+
+/// The result of a straight line fit `Y = c0 + c1 X`, as returned by [`linear`] and [`wlinear`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LinearFit {
+    pub c0: f64,
+    pub c1: f64,
+    pub cov00: f64,
+    pub cov01: f64,
+    pub cov11: f64,
+    /// Sum of squares of the residuals, weighted for [`wlinear`] (in which case it is the
+    /// \chi^2 of the fit).
+    pub sumsq: f64,
+}
+
+impl LinearFit {
+    /// Computes the fitted function and its standard deviation at `x`, see [`linear_est`].
+    ///
+    /// Returns `(y, y_err)`.
+    pub fn est(&self, x: f64) -> Result<(f64, f64), Error> {
+        linear_est(x, self.c0, self.c1, self.cov00, self.cov01, self.cov11)
+    }
+}
+
+/// The result of a fit without constant term `Y = c1 X`, as returned by [`mul`] and [`wmul`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MulFit {
+    pub c1: f64,
+    pub cov11: f64,
+    /// Sum of squares of the residuals, weighted for [`wmul`].
+    pub sumsq: f64,
+}
+
+impl MulFit {
+    /// Computes the fitted function and its standard deviation at `x`, see [`mul_est`].
+    ///
+    /// Returns `(y, y_err)`.
+    pub fn est(&self, x: f64) -> Result<(f64, f64), Error> {
+        mul_est(x, self.c1, self.cov11)
+    }
+}
+
+fn check_len(data: &[f64], stride: usize, n: usize) {
+    assert!(
+        stride > 0
+            && (n == 0
+                || (n - 1)
+                    .checked_mul(stride)
+                    .is_some_and(|last| last < data.len()))
+    );
+}
 
 /// This function computes the best-fit linear regression coefficients (c0,c1) of the model
 /// Y = c_0 + c_1 X for the dataset (x, y), two vectors of length n with strides xstride and
@@ -23,7 +73,6 @@ use crate::Value;
 /// [`Correlation`](http://www.gnu.org/software/gsl/manual/html_node/Correlation.html#Correlation)),
 /// it does not depend on the fit.
 ///
-/// Returns `(Value, c0, c1, cov00, cov01, cov11, sumsq)`.
 #[doc(alias = "gsl_fit_linear")]
 pub fn linear(
     x: &[f64],
@@ -31,7 +80,9 @@ pub fn linear(
     y: &[f64],
     ystride: usize,
     n: usize,
-) -> (Value, f64, f64, f64, f64, f64, f64) {
+) -> Result<LinearFit, Error> {
+    check_len(x, xstride, n);
+    check_len(y, ystride, n);
     let mut c0 = 0.;
     let mut c1 = 0.;
     let mut cov00 = 0.;
@@ -53,7 +104,18 @@ pub fn linear(
             &mut sumsq,
         )
     };
-    (Value::from(ret), c0, c1, cov00, cov01, cov11, sumsq)
+    result_handler!(
+        ret,
+        LinearFit {
+            c0,
+            c1,
+            cov00,
+            cov01,
+            cov11,
+            sumsq
+        },
+        "gsl_fit_linear"
+    )
 }
 
 /// This function computes the best-fit linear regression coefficients (c0,c1) of the model
@@ -68,7 +130,6 @@ pub fn linear(
 /// the parameters (cov00, cov01, cov11).
 /// The weighted sum of squares of the residuals from the best-fit line, \chi^2, is returned in chisq.
 ///
-/// Returns `(Value, c0, c1, cov00, cov01, cov11, chisq)`.
 #[doc(alias = "gsl_fit_wlinear")]
 pub fn wlinear(
     x: &[f64],
@@ -78,7 +139,10 @@ pub fn wlinear(
     y: &[f64],
     ystride: usize,
     n: usize,
-) -> (Value, f64, f64, f64, f64, f64, f64) {
+) -> Result<LinearFit, Error> {
+    check_len(x, xstride, n);
+    check_len(w, wstride, n);
+    check_len(y, ystride, n);
     let mut c0 = 0.;
     let mut c1 = 0.;
     let mut cov00 = 0.;
@@ -102,14 +166,25 @@ pub fn wlinear(
             &mut chisq,
         )
     };
-    (Value::from(ret), c0, c1, cov00, cov01, cov11, chisq)
+    result_handler!(
+        ret,
+        LinearFit {
+            c0,
+            c1,
+            cov00,
+            cov01,
+            cov11,
+            sumsq: chisq,
+        },
+        "gsl_fit_wlinear"
+    )
 }
 
 /// This function uses the best-fit linear regression coefficients c0, c1 and their covariance
 /// cov00, cov01, cov11 to compute the fitted function y and its standard deviation y_err for the
 /// model Y = c_0 + c_1 X at the point x.
 ///
-/// Returns `(Value, y, y_err)`.
+/// Returns `(y, y_err)`.
 #[doc(alias = "gsl_fit_linear_est")]
 pub fn linear_est(
     x: f64,
@@ -118,12 +193,12 @@ pub fn linear_est(
     cov00: f64,
     cov01: f64,
     cov11: f64,
-) -> (Value, f64, f64) {
+) -> Result<(f64, f64), Error> {
     let mut y = 0.;
     let mut y_err = 0.;
     let ret =
         unsafe { ::sys::gsl_fit_linear_est(x, c0, c1, cov00, cov01, cov11, &mut y, &mut y_err) };
-    (Value::from(ret), y, y_err)
+    result_handler!(ret, (y, y_err), "gsl_fit_linear_est")
 }
 
 /// This function computes the best-fit linear regression coefficient c1 of the model Y = c_1 X for
@@ -132,7 +207,6 @@ pub fn linear_est(
 /// scatter of the points around the best-fit line and returned via the parameter cov11.
 /// The sum of squares of the residuals from the best-fit line is returned in sumsq.
 ///
-/// Returns `(Value, c1, cov11, sumsq)`.
 #[doc(alias = "gsl_fit_mul")]
 pub fn mul(
     x: &[f64],
@@ -140,7 +214,9 @@ pub fn mul(
     y: &[f64],
     ystride: usize,
     n: usize,
-) -> (Value, f64, f64, f64) {
+) -> Result<MulFit, Error> {
+    check_len(x, xstride, n);
+    check_len(y, ystride, n);
     let mut c1 = 0.;
     let mut cov11 = 0.;
     let mut sumsq = 0.;
@@ -156,10 +232,9 @@ pub fn mul(
             &mut sumsq,
         )
     };
-    (Value::from(ret), c1, cov11, sumsq)
+    result_handler!(ret, MulFit { c1, cov11, sumsq }, "gsl_fit_mul")
 }
 
-/// Returns `(Value, c1, cov11, sumsq)`.
 #[doc(alias = "gsl_fit_wmul")]
 pub fn wmul(
     x: &[f64],
@@ -169,7 +244,10 @@ pub fn wmul(
     y: &[f64],
     ystride: usize,
     n: usize,
-) -> (Value, f64, f64, f64) {
+) -> Result<MulFit, Error> {
+    check_len(x, xstride, n);
+    check_len(w, wstride, n);
+    check_len(y, ystride, n);
     let mut c1 = 0.;
     let mut cov11 = 0.;
     let mut sumsq = 0.;
@@ -187,18 +265,33 @@ pub fn wmul(
             &mut sumsq,
         )
     };
-    (Value::from(ret), c1, cov11, sumsq)
+    result_handler!(ret, MulFit { c1, cov11, sumsq }, "gsl_fit_wmul")
 }
 
 /// This function uses the best-fit linear regression coefficient c1 and its covariance cov11 to
 /// compute the fitted function y and its standard deviation y_err for the model Y = c_1 X at the
 /// point x.
 ///
-/// Returns `(Value, y, y_err)`.
+/// Returns `(y, y_err)`.
 #[doc(alias = "gsl_fit_mul_est")]
-pub fn mul_est(x: f64, c1: f64, cov11: f64) -> (Value, f64, f64) {
+pub fn mul_est(x: f64, c1: f64, cov11: f64) -> Result<(f64, f64), Error> {
     let mut y = 0.;
     let mut y_err = 0.;
     let ret = unsafe { ::sys::gsl_fit_mul_est(x, c1, cov11, &mut y, &mut y_err) };
-    (Value::from(ret), y, y_err)
+    result_handler!(ret, (y, y_err), "gsl_fit_mul_est")
+}
+
+#[test]
+fn straight_line() {
+    let x = [1., 2., 3., 4.];
+    let y = [3., 5., 7., 9.];
+
+    let f = linear(&x, 1, &y, 1, 4).unwrap();
+    assert!((f.c0 - 1.).abs() < 1e-12 && (f.c1 - 2.).abs() < 1e-12);
+    assert!(f.sumsq < 1e-20);
+    assert!((f.est(5.).unwrap().0 - 11.).abs() < 1e-12);
+
+    // Every other point only.
+    let f = mul(&x, 2, &x, 2, 2).unwrap();
+    assert!((f.c1 - 1.).abs() < 1e-12);
 }
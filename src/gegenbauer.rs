@@ -4,8 +4,8 @@
 
 //! The Gegenbauer polynomials are defined in Abramowitz & Stegun, Chapter 22, where they are known as Ultraspherical polynomials.
 
-use crate::Value;
-use std::mem::MaybeUninit;
+use crate::Error;
+use core::mem::MaybeUninit;
 
 /// This function evaluates the Gegenbauer polynomials C^{(\lambda)}_n(x) using explicit representations for n =1, 2, 3.
 #[doc(alias = "gsl_sf_gegenpoly_1")]
@@ -27,29 +27,41 @@ pub fn gegenpoly_3(lambda: f64, x: f64) -> f64 {
 
 /// This function evaluates the Gegenbauer polynomials C^{(\lambda)}_n(x) using explicit representations for n =1, 2, 3.
 #[doc(alias = "gsl_sf_gegenpoly_1_e")]
-pub fn gegenpoly_1_e(lambda: f64, x: f64) -> (Value, ::types::Result) {
+pub fn gegenpoly_1_e(lambda: f64, x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_gegenpoly_1_e(lambda, x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_gegenpoly_1_e"
+    )
 }
 
 /// This function evaluates the Gegenbauer polynomials C^{(\lambda)}_n(x) using explicit representations for n =1, 2, 3.
 #[doc(alias = "gsl_sf_gegenpoly_2_e")]
-pub fn gegenpoly_2_e(lambda: f64, x: f64) -> (Value, ::types::Result) {
+pub fn gegenpoly_2_e(lambda: f64, x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_gegenpoly_2_e(lambda, x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_gegenpoly_2_e"
+    )
 }
 
 /// This function evaluates the Gegenbauer polynomials C^{(\lambda)}_n(x) using explicit representations for n =1, 2, 3.
 #[doc(alias = "gsl_sf_gegenpoly_3_e")]
-pub fn gegenpoly_3_e(lambda: f64, x: f64) -> (Value, ::types::Result) {
+pub fn gegenpoly_3_e(lambda: f64, x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_gegenpoly_3_e(lambda, x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_gegenpoly_3_e"
+    )
 }
 
 /// This function evaluates the Gegenbauer polynomial C^{(\lambda)}_n(x) for a specific value of n, lambda, x subject to \lambda > -1/2, n >= 0.
@@ -60,22 +72,27 @@ pub fn gegenpoly_n(n: i32, lambda: f64, x: f64) -> f64 {
 
 /// This function evaluates the Gegenbauer polynomial C^{(\lambda)}_n(x) for a specific value of n, lambda, x subject to \lambda > -1/2, n >= 0.
 #[doc(alias = "gsl_sf_gegenpoly_n_e")]
-pub fn gegenpoly_n_e(n: i32, lambda: f64, x: f64) -> (Value, ::types::Result) {
+pub fn gegenpoly_n_e(n: i32, lambda: f64, x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_gegenpoly_n_e(n, lambda, x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_gegenpoly_n_e"
+    )
 }
 
 /// This function computes an array of Gegenbauer polynomials C^{(\lambda)}_n(x) for n = 0, 1, 2, \dots, nmax, subject to \lambda > -1/2, nmax >= 0.
 #[doc(alias = "gsl_sf_gegenpoly_array")]
-pub fn gegenpoly_array(lambda: f64, x: f64, result_array: &mut [f64]) -> Value {
-    Value::from(unsafe {
+pub fn gegenpoly_array(lambda: f64, x: f64, result_array: &mut [f64]) -> Result<(), Error> {
+    let ret = unsafe {
         sys::gsl_sf_gegenpoly_array(
             result_array.len() as _,
             lambda,
             x,
             result_array.as_mut_ptr(),
         )
-    })
+    };
+    result_handler!(ret, (), "gsl_sf_gegenpoly_array")
 }
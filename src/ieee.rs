@@ -0,0 +1,429 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# IEEE floating-point arithmetic
+
+This chapter describes functions for examining the representation of floating point numbers and
+controlling the floating point environment of your program.
+
+## Representation of floating point numbers
+
+The IEEE Standard for Binary Floating-Point Arithmetic defines binary formats for single and double
+precision numbers. Each number is composed of three parts: a sign bit (s), an exponent (E) and a
+fraction (f). The numerical value of the combination (s,E,f) is given by the following formula,
+
+(-1)^s (1.fffff...) 2^E
+
+The functions [`format_float`] and [`format_double`] return this representation as a string, in
+the same format as `gsl_ieee_fprintf_float` and `gsl_ieee_fprintf_double`. [`float_to_rep`] and
+[`double_to_rep`] return its parts as a [`Representation`].
+
+## Setting up your IEEE environment
+
+The IEEE standard defines several modes for controlling the behavior of floating point
+operations. These modes specify the important properties of computer arithmetic: the direction
+used for rounding (e.g. whether numbers should be rounded up, down or to the nearest number), the
+rounding precision and how the program should handle arithmetic exceptions, such as division by
+zero.
+
+They can be set with [`set_mode`], or from the `GSL_IEEE_MODE` environment variable with
+[`env_setup`].
+!*/
+
+use crate::Error;
+use std::ffi::CString;
+use std::ops::{BitOr, BitOrAssign};
+use std::os::raw::c_int;
+
+/// The rounding precision of the floating point unit.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum Precision {
+    Single,
+    Double,
+    Extended,
+}
+
+#[doc(hidden)]
+impl From<Precision> for c_int {
+    fn from(p: Precision) -> c_int {
+        (match p {
+            Precision::Single => sys::GSL_IEEE_SINGLE_PRECISION,
+            Precision::Double => sys::GSL_IEEE_DOUBLE_PRECISION,
+            Precision::Extended => sys::GSL_IEEE_EXTENDED_PRECISION,
+        }) as _
+    }
+}
+
+#[doc(hidden)]
+impl From<c_int> for Precision {
+    fn from(v: c_int) -> Precision {
+        match v as _ {
+            sys::GSL_IEEE_SINGLE_PRECISION => Self::Single,
+            sys::GSL_IEEE_DOUBLE_PRECISION => Self::Double,
+            sys::GSL_IEEE_EXTENDED_PRECISION => Self::Extended,
+            _ => panic!("Unknown Precision value"),
+        }
+    }
+}
+
+/// The rounding mode of the floating point unit.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum Rounding {
+    ToNearest,
+    Down,
+    Up,
+    ToZero,
+}
+
+#[doc(hidden)]
+impl From<Rounding> for c_int {
+    fn from(r: Rounding) -> c_int {
+        (match r {
+            Rounding::ToNearest => sys::GSL_IEEE_ROUND_TO_NEAREST,
+            Rounding::Down => sys::GSL_IEEE_ROUND_DOWN,
+            Rounding::Up => sys::GSL_IEEE_ROUND_UP,
+            Rounding::ToZero => sys::GSL_IEEE_ROUND_TO_ZERO,
+        }) as _
+    }
+}
+
+#[doc(hidden)]
+impl From<c_int> for Rounding {
+    fn from(v: c_int) -> Rounding {
+        match v as _ {
+            sys::GSL_IEEE_ROUND_TO_NEAREST => Self::ToNearest,
+            sys::GSL_IEEE_ROUND_DOWN => Self::Down,
+            sys::GSL_IEEE_ROUND_UP => Self::Up,
+            sys::GSL_IEEE_ROUND_TO_ZERO => Self::ToZero,
+            _ => panic!("Unknown Rounding value"),
+        }
+    }
+}
+
+/// The floating point exceptions which are masked (i.e. which don't trap). The masks can be
+/// combined with `|`:
+///
+/// ```
+/// use rgsl::ieee::ExceptionMask;
+///
+/// let mask = ExceptionMask::UNDERFLOW | ExceptionMask::DENORMALIZED;
+/// assert!(mask.contains(ExceptionMask::UNDERFLOW));
+/// assert!(!mask.contains(ExceptionMask::OVERFLOW));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Copy, Default)]
+pub struct ExceptionMask(c_int);
+
+impl ExceptionMask {
+    /// No exception is masked.
+    pub const NONE: ExceptionMask = ExceptionMask(0);
+    pub const INVALID: ExceptionMask = ExceptionMask(sys::GSL_IEEE_MASK_INVALID as _);
+    pub const DENORMALIZED: ExceptionMask = ExceptionMask(sys::GSL_IEEE_MASK_DENORMALIZED as _);
+    pub const DIVISION_BY_ZERO: ExceptionMask =
+        ExceptionMask(sys::GSL_IEEE_MASK_DIVISION_BY_ZERO as _);
+    pub const OVERFLOW: ExceptionMask = ExceptionMask(sys::GSL_IEEE_MASK_OVERFLOW as _);
+    pub const UNDERFLOW: ExceptionMask = ExceptionMask(sys::GSL_IEEE_MASK_UNDERFLOW as _);
+    /// All the exceptions above are masked.
+    pub const ALL: ExceptionMask = ExceptionMask(sys::GSL_IEEE_MASK_ALL as _);
+    /// Enables trapping of inexact results. It is not a mask: inexact results are ignored
+    /// unless this flag is set.
+    pub const TRAP_INEXACT: ExceptionMask = ExceptionMask(sys::GSL_IEEE_TRAP_INEXACT as _);
+
+    /// Returns `true` if all the flags of `other` are set in `self`.
+    pub fn contains(&self, other: ExceptionMask) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for ExceptionMask {
+    type Output = ExceptionMask;
+
+    fn bitor(self, other: ExceptionMask) -> ExceptionMask {
+        ExceptionMask(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for ExceptionMask {
+    fn bitor_assign(&mut self, other: ExceptionMask) {
+        self.0 |= other.0;
+    }
+}
+
+#[doc(hidden)]
+impl From<ExceptionMask> for c_int {
+    fn from(m: ExceptionMask) -> c_int {
+        m.0
+    }
+}
+
+#[doc(hidden)]
+impl From<c_int> for ExceptionMask {
+    fn from(v: c_int) -> ExceptionMask {
+        ExceptionMask(v)
+    }
+}
+
+/// This function sets the IEEE arithmetic mode of the floating point unit: its rounding
+/// `precision`, its `rounding` mode and the exceptions which are masked by `exception_mask`.
+///
+/// An error is returned if the platform doesn't support the requested mode.
+#[doc(alias = "gsl_ieee_set_mode")]
+pub fn set_mode(
+    precision: Precision,
+    rounding: Rounding,
+    exception_mask: ExceptionMask,
+) -> Result<(), Error> {
+    let ret =
+        unsafe { sys::gsl_ieee_set_mode(precision.into(), rounding.into(), exception_mask.into()) };
+    result_handler!(ret, (), "gsl_ieee_set_mode")
+}
+
+/// This function reads the environment variable `GSL_IEEE_MODE` and uses it to set the IEEE
+/// arithmetic mode. It is a list of comma separated keywords such as
+/// `"double-precision,round-to-nearest,mask-underflow"`.
+#[doc(alias = "gsl_ieee_env_setup")]
+pub fn env_setup() {
+    unsafe { sys::gsl_ieee_env_setup() }
+}
+
+/// This function parses `description`, in the same format as the `GSL_IEEE_MODE` environment
+/// variable, and returns the matching mode. The precision and the rounding mode are `None` if
+/// `description` doesn't specify them.
+///
+/// Returns `(precision, rounding, exception_mask)`.
+#[doc(alias = "gsl_ieee_read_mode_string")]
+pub fn read_mode_string(
+    description: &str,
+) -> Result<(Option<Precision>, Option<Rounding>, ExceptionMask), Error> {
+    let description = match CString::new(description) {
+        Ok(s) => s,
+        Err(_) => return Err(Error::new(::Value::Invalid, "gsl_ieee_read_mode_string")),
+    };
+    let mut precision = 0;
+    let mut rounding = 0;
+    let mut exception_mask = 0;
+    let ret = unsafe {
+        sys::gsl_ieee_read_mode_string(
+            description.as_ptr(),
+            &mut precision,
+            &mut rounding,
+            &mut exception_mask,
+        )
+    };
+    result_handler!(
+        ret,
+        (
+            // GSL leaves them to 0 if they aren't specified.
+            if precision == 0 {
+                None
+            } else {
+                Some(Precision::from(precision))
+            },
+            if rounding == 0 {
+                None
+            } else {
+                Some(Rounding::from(rounding))
+            },
+            ExceptionMask::from(exception_mask)
+        ),
+        "gsl_ieee_read_mode_string"
+    )
+}
+
+/// The kind of number described by a [`Representation`].
+#[derive(Clone, PartialEq, Eq, Debug, Copy)]
+pub enum NumberType {
+    NaN,
+    Inf,
+    Normal,
+    Denormal,
+    Zero,
+}
+
+#[doc(hidden)]
+impl From<c_int> for NumberType {
+    fn from(v: c_int) -> NumberType {
+        match v as _ {
+            sys::GSL_IEEE_TYPE_NAN => Self::NaN,
+            sys::GSL_IEEE_TYPE_INF => Self::Inf,
+            sys::GSL_IEEE_TYPE_NORMAL => Self::Normal,
+            sys::GSL_IEEE_TYPE_DENORMAL => Self::Denormal,
+            sys::GSL_IEEE_TYPE_ZERO => Self::Zero,
+            _ => panic!("Unknown NumberType value"),
+        }
+    }
+}
+
+/// The parts of the IEEE representation of a floating point number, as computed by
+/// `gsl_ieee_float_to_rep` and `gsl_ieee_double_to_rep`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Representation {
+    /// `true` if the sign bit is set.
+    pub sign: bool,
+    /// The fraction bits, as a string of `'0'` and `'1'` (the leading bit isn't included).
+    pub mantissa: String,
+    /// The unbiased exponent.
+    pub exponent: i32,
+    pub kind: NumberType,
+}
+
+// `gsl_ieee_float_rep` and `gsl_ieee_double_rep` are opaque in the bindings.
+#[repr(C)]
+struct RepHeader<M> {
+    sign: c_int,
+    mantissa: M,
+    exponent: c_int,
+    type_: c_int,
+}
+
+impl<M: AsRef<[u8]>> From<RepHeader<M>> for Representation {
+    fn from(r: RepHeader<M>) -> Representation {
+        let mantissa = r.mantissa.as_ref();
+        let len = mantissa
+            .iter()
+            .position(|&c| c == 0)
+            .unwrap_or(mantissa.len());
+        Representation {
+            sign: r.sign != 0,
+            mantissa: String::from_utf8_lossy(&mantissa[..len]).into_owned(),
+            exponent: r.exponent,
+            kind: NumberType::from(r.type_),
+        }
+    }
+}
+
+/// Returns the parts of the IEEE representation of `x`.
+///
+/// ```
+/// use rgsl::ieee::{float_to_rep, NumberType};
+///
+/// let r = float_to_rep(-0.75);
+/// assert!(r.sign);
+/// assert_eq!(r.mantissa, "10000000000000000000000");
+/// assert_eq!(r.exponent, -1);
+/// assert_eq!(r.kind, NumberType::Normal);
+/// ```
+#[doc(alias = "gsl_ieee_float_to_rep")]
+pub fn float_to_rep(x: f32) -> Representation {
+    let mut r = RepHeader {
+        sign: 0,
+        mantissa: [0u8; 24],
+        exponent: 0,
+        type_: 0,
+    };
+    unsafe { sys::gsl_ieee_float_to_rep(&x, &mut r as *mut _ as *mut _) };
+    r.into()
+}
+
+/// Returns the parts of the IEEE representation of `x`.
+#[doc(alias = "gsl_ieee_double_to_rep")]
+pub fn double_to_rep(x: f64) -> Representation {
+    let mut r = RepHeader {
+        sign: 0,
+        mantissa: [0u8; 53],
+        exponent: 0,
+        type_: 0,
+    };
+    unsafe { sys::gsl_ieee_double_to_rep(&x, &mut r as *mut _ as *mut _) };
+    r.into()
+}
+
+fn format_rep(
+    sign: bool,
+    exponent: u64,
+    mantissa: u64,
+    exponent_bits: u32,
+    mantissa_bits: u32,
+) -> String {
+    let bias = (1 << (exponent_bits - 1)) - 1;
+    let sign = if sign { "-" } else { " " };
+
+    if exponent == (1 << exponent_bits) - 1 {
+        if mantissa != 0 {
+            "NaN".to_owned()
+        } else {
+            format!("{}Inf", sign.trim())
+        }
+    } else if exponent == 0 && mantissa == 0 {
+        format!("{}0", sign)
+    } else if exponent == 0 {
+        format!(
+            "{}0.{:0width$b}*2^{}",
+            sign,
+            mantissa,
+            1 - bias,
+            width = mantissa_bits as usize
+        )
+    } else {
+        format!(
+            "{}1.{:0width$b}*2^{}",
+            sign,
+            mantissa,
+            exponent as i64 - bias,
+            width = mantissa_bits as usize
+        )
+    }
+}
+
+/// Returns the IEEE representation of `x`, formatted like `gsl_ieee_fprintf_float`: its sign
+/// (a space for positive numbers), its mantissa in binary and its exponent.
+///
+/// ```
+/// use rgsl::ieee::format_float;
+///
+/// assert_eq!(format_float(0.25), " 1.00000000000000000000000*2^-2");
+/// assert_eq!(format_float(-0.), "-0");
+/// ```
+#[doc(alias = "gsl_ieee_fprintf_float")]
+#[doc(alias = "gsl_ieee_printf_float")]
+pub fn format_float(x: f32) -> String {
+    let b = x.to_bits() as u64;
+    format_rep(b >> 31 == 1, (b >> 23) & 0xff, b & 0x7f_ffff, 8, 23)
+}
+
+/// Returns the IEEE representation of `x`, formatted like `gsl_ieee_fprintf_double`: its sign
+/// (a space for positive numbers), its mantissa in binary and its exponent.
+///
+/// ```
+/// use rgsl::ieee::format_double;
+///
+/// assert_eq!(
+///     format_double(1. / 3.),
+///     " 1.0101010101010101010101010101010101010101010101010101*2^-2"
+/// );
+/// ```
+#[doc(alias = "gsl_ieee_fprintf_double")]
+#[doc(alias = "gsl_ieee_printf_double")]
+pub fn format_double(x: f64) -> String {
+    let b = x.to_bits();
+    format_rep(
+        b >> 63 == 1,
+        (b >> 52) & 0x7ff,
+        b & 0xf_ffff_ffff_ffff,
+        11,
+        52,
+    )
+}
+
+#[test]
+fn ieee_format() {
+    assert_eq!(format_double(0.), " 0");
+    assert_eq!(format_double(f64::INFINITY), "Inf");
+    assert_eq!(format_double(-f64::INFINITY), "-Inf");
+    assert_eq!(format_double(f64::NAN), "NaN");
+    assert_eq!(
+        format_double(-1.),
+        "-1.0000000000000000000000000000000000000000000000000000*2^0"
+    );
+    assert_eq!(
+        format_double(f64::MIN_POSITIVE / 2.),
+        " 0.1000000000000000000000000000000000000000000000000000*2^-1022"
+    );
+    assert_eq!(format_float(3.), " 1.10000000000000000000000*2^1");
+
+    let r = double_to_rep(f64::MIN_POSITIVE / 2.);
+    assert_eq!(r.kind, NumberType::Denormal);
+    assert_eq!(r.mantissa.len(), 52);
+    assert!(!r.sign);
+}
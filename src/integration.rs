@@ -80,7 +80,10 @@ P. Gonnet, “Increasing the Reliability of Adaptive Quadrature Using Explicit I
 (2010), Issue 3, Article 26.
 !*/
 
-use crate::Value;
+use crate::{
+    CquadWorkspace, Error, IntegrationQawoTable, IntegrationQawsTable, IntegrationWorkspace,
+    WorkspacePool,
+};
 use ffi::FFI;
 
 /// This function applies the Gauss-Kronrod 10-point, 21-point, 43-point and 87-point integration
@@ -93,13 +96,13 @@ use ffi::FFI;
 ///
 /// Returns `(result, abs_err, n_eval)`.
 #[doc(alias = "gsl_integration_qng")]
-pub fn qng<F: Fn(f64) -> f64>(
-    f: F,
+pub fn qng<F: FnMut(f64) -> f64>(
+    mut f: F,
     a: f64,
     b: f64,
     eps_abs: f64,
     eps_rel: f64,
-) -> (::Value, f64, f64, usize) {
+) -> Result<(f64, f64, usize), Error> {
     let function = wrap_callback!(f, F);
     let mut result = 0.;
     let mut abs_err = 0.;
@@ -117,7 +120,7 @@ pub fn qng<F: Fn(f64) -> f64>(
             &mut n_eval,
         )
     };
-    (::Value::from(ret), result, abs_err, n_eval)
+    result_handler!(ret, (result, abs_err, n_eval), "gsl_integration_qng")
 }
 
 /// Gauss quadrature weights and kronrod quadrature abscissae and weights as evaluated with 80
@@ -127,7 +130,7 @@ pub fn qng<F: Fn(f64) -> f64>(
 ///
 /// Returns `(result, abs_err, resabs, resasc)`.
 #[doc(alias = "gsl_integration_qk15")]
-pub fn qk15<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
+pub fn qk15<F: FnMut(f64) -> f64>(mut f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
     let function = wrap_callback!(f, F);
     let mut result = 0.;
     let mut abs_err = 0.;
@@ -145,12 +148,13 @@ pub fn qk15<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
             &mut resasc,
         )
     };
+    ::error::resume_callback_panic();
     (result, abs_err, resabs, resasc)
 }
 
 /// Returns `(result, abs_err, resabs, resasc)`.
 #[doc(alias = "gsl_integration_qk21")]
-pub fn qk21<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
+pub fn qk21<F: FnMut(f64) -> f64>(mut f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
     let function = wrap_callback!(f, F);
     let mut result = 0.;
     let mut abs_err = 0.;
@@ -168,12 +172,13 @@ pub fn qk21<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
             &mut resasc,
         )
     };
+    ::error::resume_callback_panic();
     (result, abs_err, resabs, resasc)
 }
 
 /// Returns `(result, abs_err, resabs, resasc)`.
 #[doc(alias = "gsl_integration_qk31")]
-pub fn qk31<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
+pub fn qk31<F: FnMut(f64) -> f64>(mut f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
     let function = wrap_callback!(f, F);
     let mut result = 0.;
     let mut abs_err = 0.;
@@ -191,12 +196,13 @@ pub fn qk31<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
             &mut resasc,
         )
     };
+    ::error::resume_callback_panic();
     (result, abs_err, resabs, resasc)
 }
 
 /// Returns `(result, abs_err, resabs, resasc)`.
 #[doc(alias = "gsl_integration_qk41")]
-pub fn qk41<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
+pub fn qk41<F: FnMut(f64) -> f64>(mut f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
     let function = wrap_callback!(f, F);
     let mut result = 0.;
     let mut abs_err = 0.;
@@ -214,12 +220,13 @@ pub fn qk41<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
             &mut resasc,
         )
     };
+    ::error::resume_callback_panic();
     (result, abs_err, resabs, resasc)
 }
 
 /// Returns `(result, abs_err, resabs, resasc)`.
 #[doc(alias = "gsl_integration_qk51")]
-pub fn qk51<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
+pub fn qk51<F: FnMut(f64) -> f64>(mut f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
     let function = wrap_callback!(f, F);
     let mut result = 0.;
     let mut abs_err = 0.;
@@ -237,12 +244,13 @@ pub fn qk51<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
             &mut resasc,
         )
     };
+    ::error::resume_callback_panic();
     (result, abs_err, resabs, resasc)
 }
 
 /// Returns `(result, abs_err, resabs, resasc)`.
 #[doc(alias = "gsl_integration_qk61")]
-pub fn qk61<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
+pub fn qk61<F: FnMut(f64) -> f64>(mut f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
     let function = wrap_callback!(f, F);
     let mut result = 0.;
     let mut abs_err = 0.;
@@ -260,18 +268,19 @@ pub fn qk61<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> (f64, f64, f64, f64) {
             &mut resasc,
         )
     };
+    ::error::resume_callback_panic();
     (result, abs_err, resabs, resasc)
 }
 
 /// Returns `(result, abs_err, resabs, resasc)`.
 #[doc(alias = "gsl_integration_qk")]
-pub fn qk<F: Fn(f64) -> f64>(
+pub fn qk<F: FnMut(f64) -> f64>(
     xgk: &[f64],
     wg: &[f64],
     wgk: &[f64],
     fv1: &mut [f64],
     fv2: &mut [f64],
-    f: F,
+    mut f: F,
     a: f64,
     b: f64,
 ) -> (f64, f64, f64, f64) {
@@ -303,6 +312,7 @@ pub fn qk<F: Fn(f64) -> f64>(
             &mut resasc,
         );
     }
+    ::error::resume_callback_panic();
     (result, abs_err, resabs, resasc)
 }
 
@@ -356,15 +366,15 @@ pub fn qk<F: Fn(f64) -> f64>(
 ///
 /// Returns `(result, abs_err)`.
 #[doc(alias = "gsl_integration_qawf")]
-pub fn qawf<F: Fn(f64) -> f64>(
-    f: F,
+pub fn qawf<F: FnMut(f64) -> f64>(
+    mut f: F,
     a: f64,
     epsabs: f64,
     limit: usize,
     workspace: &mut ::IntegrationWorkspace,
     cycle_workspace: &mut ::IntegrationWorkspace,
     wf: &mut ::IntegrationQawoTable,
-) -> (Value, f64, f64) {
+) -> Result<(f64, f64), Error> {
     let mut result = 0.;
     let mut abs_err = 0.;
 
@@ -382,5 +392,239 @@ pub fn qawf<F: Fn(f64) -> f64>(
             &mut abs_err,
         )
     };
-    (::Value::from(ret), result, abs_err)
+    result_handler!(ret, (result, abs_err), "gsl_integration_qawf")
+}
+
+/// This function applies an integration rule adaptively until an estimate of the integral of f over
+/// (a,b) is achieved within the desired absolute and relative error limits, epsabs and epsrel.
+///
+/// Same as [`IntegrationWorkspace::qag`] with a workspace of `limit` intervals taken from the
+/// thread's [`WorkspacePool`].
+///
+/// Returns `(result, abs_err)`.
+#[doc(alias = "gsl_integration_qag")]
+pub fn qag<F: FnMut(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    epsabs: f64,
+    epsrel: f64,
+    limit: usize,
+    key: ::GaussKronrodRule,
+) -> Result<(f64, f64), Error> {
+    WorkspacePool::<IntegrationWorkspace>::with(limit, |w| {
+        w.qag(f, a, b, epsabs, epsrel, limit, key)
+    })
+    .and_then(|r| r)
+}
+
+/// This function applies the Gauss-Kronrod 21-point integration rule adaptively until an estimate
+/// of the integral of f over (a,b) is achieved within the desired absolute and relative error
+/// limits, epsabs and epsrel. The results are extrapolated using the epsilon-algorithm, which
+/// accelerates the convergence of the integral in the presence of discontinuities and integrable
+/// singularities.
+///
+/// Same as [`IntegrationWorkspace::qags`] with a workspace of `limit` intervals taken from the
+/// thread's [`WorkspacePool`].
+///
+/// Returns `(result, abs_err)`.
+#[doc(alias = "gsl_integration_qags")]
+pub fn qags<F: FnMut(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    epsabs: f64,
+    epsrel: f64,
+    limit: usize,
+) -> Result<(f64, f64), Error> {
+    WorkspacePool::<IntegrationWorkspace>::with(limit, |w| w.qags(f, a, b, epsabs, epsrel, limit))
+        .and_then(|r| r)
+}
+
+/// This function computes the integral of the function f over the infinite interval
+/// (-\infty,+\infty).
+///
+/// Same as [`IntegrationWorkspace::qagi`] with a workspace of `limit` intervals taken from the
+/// thread's [`WorkspacePool`].
+///
+/// Returns `(result, abs_err)`.
+#[doc(alias = "gsl_integration_qagi")]
+pub fn qagi<F: FnMut(f64) -> f64>(
+    f: F,
+    epsabs: f64,
+    epsrel: f64,
+    limit: usize,
+) -> Result<(f64, f64), Error> {
+    WorkspacePool::<IntegrationWorkspace>::with(limit, |w| w.qagi(f, epsabs, epsrel, limit))
+        .and_then(|r| r)
+}
+
+/// This function computes the integral of the function f over the semi-infinite interval
+/// (a,+\infty).
+///
+/// Same as [`IntegrationWorkspace::qagiu`] with a workspace of `limit` intervals taken from the
+/// thread's [`WorkspacePool`].
+///
+/// Returns `(result, abs_err)`.
+#[doc(alias = "gsl_integration_qagiu")]
+pub fn qagiu<F: FnMut(f64) -> f64>(
+    f: F,
+    a: f64,
+    epsabs: f64,
+    epsrel: f64,
+    limit: usize,
+) -> Result<(f64, f64), Error> {
+    WorkspacePool::<IntegrationWorkspace>::with(limit, |w| w.qagiu(f, a, epsabs, epsrel, limit))
+        .and_then(|r| r)
+}
+
+/// This function computes the integral of the function f over the semi-infinite interval
+/// (-\infty,b).
+///
+/// Same as [`IntegrationWorkspace::qagil`] with a workspace of `limit` intervals taken from the
+/// thread's [`WorkspacePool`].
+///
+/// Returns `(result, abs_err)`.
+#[doc(alias = "gsl_integration_qagil")]
+pub fn qagil<F: FnMut(f64) -> f64>(
+    f: F,
+    b: f64,
+    epsabs: f64,
+    epsrel: f64,
+    limit: usize,
+) -> Result<(f64, f64), Error> {
+    WorkspacePool::<IntegrationWorkspace>::with(limit, |w| w.qagil(f, b, epsabs, epsrel, limit))
+        .and_then(|r| r)
+}
+
+/// This function computes the Cauchy principal value of the integral of f over (a,b), with a
+/// singularity at c.
+///
+/// Same as [`IntegrationWorkspace::qawc`] with a workspace of `limit` intervals taken from the
+/// thread's [`WorkspacePool`].
+///
+/// Returns `(result, abs_err)`.
+#[doc(alias = "gsl_integration_qawc")]
+pub fn qawc<F: FnMut(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    c: f64,
+    epsabs: f64,
+    epsrel: f64,
+    limit: usize,
+) -> Result<(f64, f64), Error> {
+    WorkspacePool::<IntegrationWorkspace>::with(limit, |w| {
+        w.qawc(f, a, b, c, epsabs, epsrel, limit)
+    })
+    .and_then(|r| r)
+}
+
+/// This function computes the integral of f over (a,b) with the singular weight function
+/// described by `table`.
+///
+/// Same as [`IntegrationQawsTable::qaws`] with a workspace of `limit` intervals taken from the
+/// thread's [`WorkspacePool`].
+///
+/// Returns `(result, abs_err)`.
+#[doc(alias = "gsl_integration_qaws")]
+pub fn qaws<F: FnMut(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    table: &mut IntegrationQawsTable,
+    epsabs: f64,
+    epsrel: f64,
+    limit: usize,
+) -> Result<(f64, f64), Error> {
+    WorkspacePool::<IntegrationWorkspace>::with(limit, |w| {
+        table.qaws(f, a, b, epsabs, epsrel, limit, w)
+    })
+    .and_then(|r| r)
+}
+
+/// This function computes the integral of f over (a, a + L) with the oscillatory weight function
+/// described by `table`, L being the length the table was set up for.
+///
+/// Same as [`IntegrationQawoTable::qawo`] with a workspace of `limit` intervals taken from the
+/// thread's [`WorkspacePool`].
+///
+/// Returns `(result, abs_err)`.
+#[doc(alias = "gsl_integration_qawo")]
+pub fn qawo<F: FnMut(f64) -> f64>(
+    f: F,
+    a: f64,
+    table: &mut IntegrationQawoTable,
+    epsabs: f64,
+    epsrel: f64,
+    limit: usize,
+) -> Result<(f64, f64), Error> {
+    WorkspacePool::<IntegrationWorkspace>::with(limit, |w| {
+        table.qawo(f, a, epsabs, epsrel, limit, w)
+    })
+    .and_then(|r| r)
+}
+
+/// This function computes the integral of f over (a,b) within the desired absolute and relative
+/// error limits, epsabs and epsrel using the CQUAD algorithm.
+///
+/// Same as [`CquadWorkspace::cquad`] with a workspace of `n` intervals taken from the thread's
+/// [`WorkspacePool`].
+///
+/// Returns `(result, abs_err, n_evals)`.
+#[doc(alias = "gsl_integration_cquad")]
+pub fn cquad<F: FnMut(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    epsabs: f64,
+    epsrel: f64,
+    n: usize,
+) -> Result<(f64, f64, usize), Error> {
+    WorkspacePool::<CquadWorkspace>::with(n, |w| w.cquad(f, a, b, epsabs, epsrel)).and_then(|r| r)
+}
+
+/// This function integrates f over (a,b) with the Romberg method, using at most n iterations.
+///
+/// Same as [`RombergWorkspace::romberg`] with a workspace taken from the thread's
+/// [`WorkspacePool`].
+///
+/// Returns `(result, n_evals)`.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_integration_romberg")]
+pub fn romberg<F: FnMut(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    epsabs: f64,
+    epsrel: f64,
+    n: usize,
+) -> Result<(f64, usize), Error> {
+    WorkspacePool::<::RombergWorkspace>::with(n, |w| w.romberg(f, a, b, epsabs, epsrel))
+        .and_then(|r| r)
+}
+
+#[test]
+fn weighted_integrals() {
+    // \int_0^1 log(x) dx = -1
+    let mut t = IntegrationQawsTable::new(0., 0., 1, 0).unwrap();
+    let (r, _) = qaws(|_| 1., 0., 1., &mut t, 0., 1e-10, 100).unwrap();
+    assert!((r + 1.).abs() < 1e-10);
+
+    // \int_0^1 cos(10 x) dx = sin(10) / 10
+    let mut t = IntegrationQawoTable::new(10., 1., ::IntegrationQawo::Cosine, 10).unwrap();
+    let (r, _) = qawo(|_| 1., 0., &mut t, 0., 1e-10, 100).unwrap();
+    assert!((r - 10f64.sin() / 10.).abs() < 1e-10);
+
+    // \int_0^\infty exp(-x) cos(x) dx = 1/2
+    let mut t = IntegrationQawoTable::new(1., 1., ::IntegrationQawo::Cosine, 10).unwrap();
+    let mut w = IntegrationWorkspace::new(100).unwrap();
+    let mut cw = IntegrationWorkspace::new(100).unwrap();
+    let (r, _) = qawf(|x| (-x).exp(), 0., 1e-10, 100, &mut w, &mut cw, &mut t).unwrap();
+    assert!((r - 0.5).abs() < 1e-9);
+
+    // The principal value of \int_{-1}^{1} 1/x dx is 0.
+    let (r, _) = qawc(|_| 1., -1., 1., 0., 0., 1e-10, 100).unwrap();
+    assert!(r.abs() < 1e-10);
 }
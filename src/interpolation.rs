@@ -2,9 +2,11 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::Value;
+use crate::Error;
 use ffi::FFI;
 
+pub mod twodim;
+
 /// This function returns the index i of the array x_array such that `x_array[i] <= x < x_array[i+1]`.
 /// The index is searched for in the range `[index_lo,index_hi]`.
 #[doc(alias = "gsl_interp_bsearch")]
@@ -28,6 +30,21 @@ pub fn eval(interp: &::Interp, xa: &[f64], ya: &[f64], x: f64, acc: &mut ::Inter
     }
 }
 
+/// Same as [`eval`], but without accelerator: the interval of `x` is found with a binary search.
+/// Useful for one-off evaluations.
+#[doc(alias = "gsl_interp_eval")]
+pub fn eval_no_accel(interp: &::Interp, xa: &[f64], ya: &[f64], x: f64) -> f64 {
+    unsafe {
+        sys::gsl_interp_eval(
+            interp.unwrap_shared(),
+            xa.as_ptr(),
+            ya.as_ptr(),
+            x,
+            ::std::ptr::null_mut(),
+        )
+    }
+}
+
 /// This function returns the interpolated value of y for a given point x, using the interpolation
 /// object interp, data arrays xa and ya and the accelerator acc. When x is outside the range of xa,
 /// the error code ::Dom is returned with a value of rgsl::NAN for y.
@@ -40,7 +57,7 @@ pub fn eval_e(
     ya: &[f64],
     x: f64,
     acc: &mut ::InterpAccel,
-) -> (Value, f64) {
+) -> Result<f64, Error> {
     let mut y = 0.;
     let ret = unsafe {
         sys::gsl_interp_eval_e(
@@ -52,7 +69,26 @@ pub fn eval_e(
             &mut y,
         )
     };
-    (::Value::from(ret), y)
+    result_handler!(ret, y, "gsl_interp_eval_e")
+}
+
+/// Same as [`eval_e`], but without accelerator.
+///
+/// Returns `y`.
+#[doc(alias = "gsl_interp_eval_e")]
+pub fn eval_e_no_accel(interp: &::Interp, xa: &[f64], ya: &[f64], x: f64) -> Result<f64, Error> {
+    let mut y = 0.;
+    let ret = unsafe {
+        sys::gsl_interp_eval_e(
+            interp.unwrap_shared(),
+            xa.as_ptr(),
+            ya.as_ptr(),
+            x,
+            ::std::ptr::null_mut(),
+            &mut y,
+        )
+    };
+    result_handler!(ret, y, "gsl_interp_eval_e")
 }
 
 /// This function returns the derivative d of an interpolated function for a given point x, using
@@ -79,7 +115,7 @@ pub fn eval_deriv(
 /// This function returns the derivative d of an interpolated function for a given point x, using
 /// the interpolation object interp, data arrays xa and ya and the accelerator acc.
 ///
-/// Returns `(Value, d)`.
+/// Returns `d`.
 #[doc(alias = "gsl_interp_eval_deriv_e")]
 pub fn eval_deriv_e(
     interp: &::Interp,
@@ -87,7 +123,7 @@ pub fn eval_deriv_e(
     ya: &[f64],
     x: f64,
     acc: &mut ::InterpAccel,
-) -> (Value, f64) {
+) -> Result<f64, Error> {
     let mut d = 0.;
     let ret = unsafe {
         sys::gsl_interp_eval_deriv_e(
@@ -99,7 +135,7 @@ pub fn eval_deriv_e(
             &mut d,
         )
     };
-    (Value::from(ret), d)
+    result_handler!(ret, d, "gsl_interp_eval_deriv_e")
 }
 
 /// This function returns the second derivative d2 of an interpolated function for a given point x,
@@ -126,7 +162,7 @@ pub fn eval_deriv2(
 /// This function returns the second derivative d2 of an interpolated function for a given point x,
 /// using the interpolation object interp, data arrays xa and ya and the accelerator acc.
 ///
-/// Returns `(Value, d2)`.
+/// Returns `d2`.
 #[doc(alias = "gsl_interp_eval_deriv2_e")]
 pub fn eval_deriv2_e(
     interp: &::Interp,
@@ -134,7 +170,7 @@ pub fn eval_deriv2_e(
     ya: &[f64],
     x: f64,
     acc: &mut ::InterpAccel,
-) -> (Value, f64) {
+) -> Result<f64, Error> {
     let mut d2 = 0.;
     let ret = unsafe {
         sys::gsl_interp_eval_deriv2_e(
@@ -146,7 +182,7 @@ pub fn eval_deriv2_e(
             &mut d2,
         )
     };
-    (Value::from(ret), d2)
+    result_handler!(ret, d2, "gsl_interp_eval_deriv2_e")
 }
 
 /// This function returns the numerical integral result of an interpolated function over the range
@@ -175,7 +211,7 @@ pub fn eval_integ(
 /// This function returns the numerical integral result of an interpolated function over the range
 /// [a, b], using the interpolation object interp, data arrays xa and ya and the accelerator acc.
 ///
-/// Returns `(Value, result)`.
+/// Returns `result`.
 #[doc(alias = "gsl_interp_eval_integ_e")]
 pub fn eval_integ_e(
     interp: &::Interp,
@@ -184,7 +220,7 @@ pub fn eval_integ_e(
     a: f64,
     b: f64,
     acc: &mut ::InterpAccel,
-) -> (Value, f64) {
+) -> Result<f64, Error> {
     let mut result = 0.;
     let ret = unsafe {
         sys::gsl_interp_eval_integ_e(
@@ -197,5 +233,5 @@ pub fn eval_integ_e(
             &mut result,
         )
     };
-    (Value::from(ret), result)
+    result_handler!(ret, result, "gsl_interp_eval_integ_e")
 }
@@ -0,0 +1,374 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# 2D Interpolation
+
+These functions perform two-dimensional interpolation of a function z(x, y) known on a grid of
+points (x_i, y_j), i = 0 ... xsize-1, j = 0 ... ysize-1. The values z_ij are stored in a single
+array `za` of length xsize * ysize, where z_ij is `za[j * xsize + i]` (see [`Interp2d::idx`]).
+
+Like in one dimension, [`Interp2d`] needs the data arrays at each evaluation whereas
+[`Spline2d`] keeps a copy of them.
+
+```
+use rgsl::interpolation::twodim::{Interp2dType, Spline2d};
+use rgsl::InterpAccel;
+
+let xa = [0., 1.];
+let ya = [0., 1.];
+let za = [0., 1., 1., 0.5];
+let mut spline = Spline2d::new(Interp2dType::bilinear(), xa.len(), ya.len()).unwrap();
+spline.init(&xa, &ya, &za).unwrap();
+
+let mut xacc = InterpAccel::new();
+let mut yacc = InterpAccel::new();
+assert_eq!(spline.eval(0.5, 0., &mut xacc, &mut yacc), 0.5);
+```
+!*/
+
+use crate::paste::paste;
+use crate::{Error, InterpAccel, Value};
+use ffi::FFI;
+
+fn check_sizes(xa: &[f64], ya: &[f64], za: &[f64], function: &'static str) -> Result<(), Error> {
+    if za.len() != xa.len() * ya.len() {
+        Err(Error::new(Value::BadLength, function))
+    } else {
+        Ok(())
+    }
+}
+
+ffi_wrapper!(Interp2dType, *const sys::gsl_interp2d_type);
+
+unsafe impl Send for Interp2dType {}
+unsafe impl Sync for Interp2dType {}
+
+impl Interp2dType {
+    /// Bilinear interpolation. This interpolation method does not require any additional memory.
+    pub fn bilinear() -> Interp2dType {
+        ffi_wrap!(gsl_interp2d_bilinear)
+    }
+
+    /// Bicubic interpolation.
+    pub fn bicubic() -> Interp2dType {
+        ffi_wrap!(gsl_interp2d_bicubic)
+    }
+
+    /// This function returns the minimum number of points required by the interpolation type, in
+    /// each direction.
+    #[doc(alias = "gsl_interp2d_type_min_size")]
+    pub fn min_size(&self) -> usize {
+        unsafe { sys::gsl_interp2d_type_min_size(self.unwrap_shared()) }
+    }
+}
+
+macro_rules! interp2d_eval {
+    ($name:ident, $doc:expr) => {
+        paste! {
+            interp2d_eval!($name, [<gsl_interp2d_ $name _e>], $doc);
+        }
+    };
+    ($name:ident, $c_name_e:ident, $doc:expr) => {
+        paste! {
+            #[doc = $doc]
+            #[doc(alias = gsl_interp2d_ $name)]
+            pub fn $name(
+                &self,
+                xa: &[f64],
+                ya: &[f64],
+                za: &[f64],
+                x: f64,
+                y: f64,
+                xacc: &mut InterpAccel,
+                yacc: &mut InterpAccel,
+            ) -> f64 {
+                unsafe {
+                    sys::[<gsl_interp2d_ $name>](
+                        self.unwrap_shared(),
+                        xa.as_ptr(),
+                        ya.as_ptr(),
+                        za.as_ptr(),
+                        x,
+                        y,
+                        &mut xacc.0,
+                        &mut yacc.0,
+                    )
+                }
+            }
+
+            #[doc = $doc]
+            #[doc(alias = $c_name_e "")]
+            pub fn [<$name _e>](
+                &self,
+                xa: &[f64],
+                ya: &[f64],
+                za: &[f64],
+                x: f64,
+                y: f64,
+                xacc: &mut InterpAccel,
+                yacc: &mut InterpAccel,
+            ) -> Result<f64, Error> {
+                let mut z = 0.;
+                let ret = unsafe {
+                    sys::$c_name_e(
+                        self.unwrap_shared(),
+                        xa.as_ptr(),
+                        ya.as_ptr(),
+                        za.as_ptr(),
+                        x,
+                        y,
+                        &mut xacc.0,
+                        &mut yacc.0,
+                        &mut z,
+                    )
+                };
+                result_handler!(ret, z, stringify!($c_name_e))
+            }
+        }
+    };
+}
+
+ffi_wrapper!(
+    Interp2d,
+    *mut sys::gsl_interp2d,
+    gsl_interp2d_free,
+    "Two dimensional interpolation object, which doesn't store the data arrays."
+);
+
+unsafe impl Send for Interp2d {}
+
+impl Interp2d {
+    /// This function allocates an interpolation object of type `t` for a grid of `xsize` by
+    /// `ysize` points.
+    #[doc(alias = "gsl_interp2d_alloc")]
+    pub fn new(t: Interp2dType, xsize: usize, ysize: usize) -> Option<Interp2d> {
+        let tmp = unsafe { sys::gsl_interp2d_alloc(t.unwrap_shared(), xsize, ysize) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function initializes the interpolation object for the grid `(xa, ya)` and the values
+    /// `za`. `xa` and `ya` must be sorted in increasing order, and `za` must contain
+    /// `xa.len() * ya.len()` values. The arrays aren't copied: the same ones must be given to the
+    /// evaluation functions.
+    #[doc(alias = "gsl_interp2d_init")]
+    pub fn init(&mut self, xa: &[f64], ya: &[f64], za: &[f64]) -> Result<(), Error> {
+        check_sizes(xa, ya, za, "gsl_interp2d_init")?;
+        let ret = unsafe {
+            sys::gsl_interp2d_init(
+                self.unwrap_unique(),
+                xa.as_ptr(),
+                ya.as_ptr(),
+                za.as_ptr(),
+                xa.len() as _,
+                ya.len() as _,
+            )
+        };
+        result_handler!(ret, (), "gsl_interp2d_init")
+    }
+
+    /// This function returns the name of the interpolation type.
+    #[doc(alias = "gsl_interp2d_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_interp2d_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// This function returns the minimum number of points required by the interpolation object,
+    /// in each direction.
+    #[doc(alias = "gsl_interp2d_min_size")]
+    pub fn min_size(&self) -> usize {
+        unsafe { sys::gsl_interp2d_min_size(self.unwrap_shared()) }
+    }
+
+    /// This function returns the index in `za` of the value z_ij.
+    #[doc(alias = "gsl_interp2d_idx")]
+    pub fn idx(&self, i: usize, j: usize) -> usize {
+        unsafe { sys::gsl_interp2d_idx(self.unwrap_shared(), i, j) }
+    }
+
+    /// This function sets the value z_ij of `za` to `z`.
+    #[doc(alias = "gsl_interp2d_set")]
+    pub fn set(&self, za: &mut [f64], i: usize, j: usize, z: f64) -> Result<(), Error> {
+        assert!(self.idx(i, j) < za.len());
+        let ret = unsafe { sys::gsl_interp2d_set(self.unwrap_shared(), za.as_mut_ptr(), i, j, z) };
+        result_handler!(ret, (), "gsl_interp2d_set")
+    }
+
+    /// This function returns the value z_ij of `za`.
+    #[doc(alias = "gsl_interp2d_get")]
+    pub fn get(&self, za: &[f64], i: usize, j: usize) -> f64 {
+        assert!(self.idx(i, j) < za.len());
+        unsafe { sys::gsl_interp2d_get(self.unwrap_shared(), za.as_ptr(), i, j) }
+    }
+
+    interp2d_eval!(
+        eval,
+        "This function returns the interpolated value of z for the point `(x, y)`, which must be \
+         inside the grid."
+    );
+    interp2d_eval!(
+        eval_extrap,
+        // `gsl_interp2d_eval_extrap_e` was only added in GSL 2.6.
+        gsl_interp2d_eval_e_extrap,
+        "This function returns the interpolated value of z for the point `(x, y)`. Points outside \
+         of the grid are extrapolated."
+    );
+    interp2d_eval!(
+        eval_deriv_x,
+        "This function returns the interpolated value of dz/dx for the point `(x, y)`."
+    );
+    interp2d_eval!(
+        eval_deriv_y,
+        "This function returns the interpolated value of dz/dy for the point `(x, y)`."
+    );
+    interp2d_eval!(
+        eval_deriv_xx,
+        "This function returns the interpolated value of d^2z/dx^2 for the point `(x, y)`."
+    );
+    interp2d_eval!(
+        eval_deriv_yy,
+        "This function returns the interpolated value of d^2z/dy^2 for the point `(x, y)`."
+    );
+    interp2d_eval!(
+        eval_deriv_xy,
+        "This function returns the interpolated value of d^2z/dxdy for the point `(x, y)`."
+    );
+}
+
+macro_rules! spline2d_eval {
+    ($name:ident, $doc:expr) => {
+        paste! {
+            #[doc = $doc]
+            #[doc(alias = gsl_spline2d_ $name)]
+            pub fn $name(
+                &self,
+                x: f64,
+                y: f64,
+                xacc: &mut InterpAccel,
+                yacc: &mut InterpAccel,
+            ) -> f64 {
+                unsafe {
+                    sys::[<gsl_spline2d_ $name>](self.unwrap_shared(), x, y, &mut xacc.0, &mut yacc.0)
+                }
+            }
+
+            #[doc = $doc]
+            #[doc(alias = gsl_spline2d_ $name _e)]
+            pub fn [<$name _e>](
+                &self,
+                x: f64,
+                y: f64,
+                xacc: &mut InterpAccel,
+                yacc: &mut InterpAccel,
+            ) -> Result<f64, Error> {
+                let mut z = 0.;
+                let ret = unsafe {
+                    sys::[<gsl_spline2d_ $name _e>](
+                        self.unwrap_shared(),
+                        x,
+                        y,
+                        &mut xacc.0,
+                        &mut yacc.0,
+                        &mut z,
+                    )
+                };
+                result_handler!(ret, z, stringify!([<gsl_spline2d_ $name _e>]))
+            }
+        }
+    };
+}
+
+ffi_wrapper!(
+    Spline2d,
+    *mut sys::gsl_spline2d,
+    gsl_spline2d_free,
+    "Two dimensional interpolation object, keeping a copy of the data arrays."
+);
+
+unsafe impl Send for Spline2d {}
+
+impl Spline2d {
+    /// This function allocates a spline of type `t` for a grid of `xsize` by `ysize` points.
+    #[doc(alias = "gsl_spline2d_alloc")]
+    pub fn new(t: Interp2dType, xsize: usize, ysize: usize) -> Option<Spline2d> {
+        let tmp = unsafe { sys::gsl_spline2d_alloc(t.unwrap_shared(), xsize, ysize) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function initializes the spline for the grid `(xa, ya)` and the values `za`, which
+    /// are copied. `xa` and `ya` must be sorted in increasing order, and `za` must contain
+    /// `xa.len() * ya.len()` values.
+    #[doc(alias = "gsl_spline2d_init")]
+    pub fn init(&mut self, xa: &[f64], ya: &[f64], za: &[f64]) -> Result<(), Error> {
+        check_sizes(xa, ya, za, "gsl_spline2d_init")?;
+        let ret = unsafe {
+            sys::gsl_spline2d_init(
+                self.unwrap_unique(),
+                xa.as_ptr(),
+                ya.as_ptr(),
+                za.as_ptr(),
+                xa.len() as _,
+                ya.len() as _,
+            )
+        };
+        result_handler!(ret, (), "gsl_spline2d_init")
+    }
+
+    /// This function returns the name of the interpolation type.
+    #[doc(alias = "gsl_spline2d_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_spline2d_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// This function returns the minimum number of points required by the spline, in each
+    /// direction.
+    #[doc(alias = "gsl_spline2d_min_size")]
+    pub fn min_size(&self) -> usize {
+        unsafe { sys::gsl_spline2d_min_size(self.unwrap_shared()) }
+    }
+
+    spline2d_eval!(
+        eval,
+        "This function returns the interpolated value of z for the point `(x, y)`, which must be \
+         inside the grid."
+    );
+    spline2d_eval!(
+        eval_deriv_x,
+        "This function returns the interpolated value of dz/dx for the point `(x, y)`."
+    );
+    spline2d_eval!(
+        eval_deriv_y,
+        "This function returns the interpolated value of dz/dy for the point `(x, y)`."
+    );
+    spline2d_eval!(
+        eval_deriv_xx,
+        "This function returns the interpolated value of d^2z/dx^2 for the point `(x, y)`."
+    );
+    spline2d_eval!(
+        eval_deriv_yy,
+        "This function returns the interpolated value of d^2z/dy^2 for the point `(x, y)`."
+    );
+    spline2d_eval!(
+        eval_deriv_xy,
+        "This function returns the interpolated value of d^2z/dxdy for the point `(x, y)`."
+    );
+}
@@ -7,8 +7,8 @@ The generalized Laguerre polynomials are defined in terms of confluent hypergeom
 They are related to the plain Laguerre polynomials L_n(x) by L^0_n(x) = L_n(x) and L^k_n(x) = (-1)^k (d^k/dx^k) L_(n+k)(x). For more information see Abramowitz & Stegun, Chapter 22.
 !*/
 
-use crate::Value;
-use std::mem::MaybeUninit;
+use crate::Error;
+use core::mem::MaybeUninit;
 
 /// This function evaluates the generalized Laguerre polynomials L^a_1(x), L^a_2(x), L^a_3(x) using explicit representations.
 #[doc(alias = "gsl_sf_laguerre_1")]
@@ -30,29 +30,41 @@ pub fn laguerre_3(a: f64, x: f64) -> f64 {
 
 /// This function evaluates the generalized Laguerre polynomials L^a_1(x), L^a_2(x), L^a_3(x) using explicit representations.
 #[doc(alias = "gsl_sf_laguerre_1_e")]
-pub fn laguerre_1_e(a: f64, x: f64) -> (Value, ::types::Result) {
+pub fn laguerre_1_e(a: f64, x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_laguerre_1_e(a, x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_laguerre_1_e"
+    )
 }
 
 /// This function evaluates the generalized Laguerre polynomials L^a_1(x), L^a_2(x), L^a_3(x) using explicit representations.
 #[doc(alias = "gsl_sf_laguerre_2_e")]
-pub fn laguerre_2_e(a: f64, x: f64) -> (Value, ::types::Result) {
+pub fn laguerre_2_e(a: f64, x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_laguerre_2_e(a, x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_laguerre_2_e"
+    )
 }
 
 /// This function evaluates the generalized Laguerre polynomials L^a_1(x), L^a_2(x), L^a_3(x) using explicit representations.
 #[doc(alias = "gsl_sf_laguerre_3_e")]
-pub fn laguerre_3_e(a: f64, x: f64) -> (Value, ::types::Result) {
+pub fn laguerre_3_e(a: f64, x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_laguerre_3_e(a, x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_laguerre_3_e"
+    )
 }
 
 /// the generalized Laguerre polynomials L^a_n(x) for a > -1, n >= 0.
@@ -63,9 +75,13 @@ pub fn laguerre_n(n: i32, a: f64, x: f64) -> f64 {
 
 /// the generalized Laguerre polynomials L^a_n(x) for a > -1, n >= 0.
 #[doc(alias = "gsl_sf_laguerre_n_e")]
-pub fn laguerre_n_e(n: i32, a: f64, x: f64) -> (Value, ::types::Result) {
+pub fn laguerre_n_e(n: i32, a: f64, x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_laguerre_n_e(n, a, x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_laguerre_n_e"
+    )
 }
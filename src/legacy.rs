@@ -0,0 +1,1682 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Legacy tuple-returning functions
+
+The functions which used to return a `(Value, T...)` tuple now return a [`Result`], the values
+being only available when the call succeeded. This module keeps the previous signatures under
+the same paths (`rgsl::legacy::airy::Ai_e` for `rgsl::airy::Ai_e`) to ease porting, and will be
+removed in the next release.
+
+On failure, the returned values are the [`Default`] ones instead of the ones written by GSL.
+
+```
+#![allow(deprecated)]
+use rgsl::{legacy, Mode, Value};
+
+let (value, result) = legacy::airy::Ai_e(0.5, Mode::PrecDouble);
+assert_eq!(value, Value::Success);
+assert_eq!(result.val, rgsl::airy::Ai_e(0.5, Mode::PrecDouble).unwrap().val);
+```
+
+Only the free functions are kept here: the methods (of [`IntegrationWorkspace`] for example, or
+of the [`Elementary`] and [`Trigonometric`] traits) were changed in place.
+
+[`Result`]: std::result::Result
+[`IntegrationWorkspace`]: crate::IntegrationWorkspace
+[`Elementary`]: crate::Elementary
+[`Trigonometric`]: crate::Trigonometric
+!*/
+
+#![allow(clippy::too_many_arguments)]
+
+use crate::{Error, Value};
+
+fn split<T: Default>(r: Result<T, Error>) -> (Value, T) {
+    match r {
+        Ok(v) => (Value::Success, v),
+        Err(e) => (e.value(), T::default()),
+    }
+}
+
+pub mod airy {
+    #[deprecated(note = "use `rgsl::airy::Ai_e`, which returns a `Result`")]
+    pub fn Ai_e(x: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+        ::legacy::split(::airy::Ai_e(x, mode))
+    }
+
+    #[deprecated(note = "use `rgsl::airy::Bi_e`, which returns a `Result`")]
+    pub fn Bi_e(x: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+        ::legacy::split(::airy::Bi_e(x, mode))
+    }
+
+    #[deprecated(note = "use `rgsl::airy::Ai_scaled_e`, which returns a `Result`")]
+    pub fn Ai_scaled_e(x: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+        ::legacy::split(::airy::Ai_scaled_e(x, mode))
+    }
+
+    #[deprecated(note = "use `rgsl::airy::Bi_scaled_e`, which returns a `Result`")]
+    pub fn Bi_scaled_e(x: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+        ::legacy::split(::airy::Bi_scaled_e(x, mode))
+    }
+
+    #[deprecated(note = "use `rgsl::airy::Ai_deriv_e`, which returns a `Result`")]
+    pub fn Ai_deriv_e(x: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+        ::legacy::split(::airy::Ai_deriv_e(x, mode))
+    }
+
+    #[deprecated(note = "use `rgsl::airy::Bi_deriv_e`, which returns a `Result`")]
+    pub fn Bi_deriv_e(x: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+        ::legacy::split(::airy::Bi_deriv_e(x, mode))
+    }
+
+    #[deprecated(note = "use `rgsl::airy::Ai_deriv_scaled_e`, which returns a `Result`")]
+    pub fn Ai_deriv_scaled_e(x: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+        ::legacy::split(::airy::Ai_deriv_scaled_e(x, mode))
+    }
+
+    #[deprecated(note = "use `rgsl::airy::Bi_deriv_scaled_e`, which returns a `Result`")]
+    pub fn Bi_deriv_scaled_e(x: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+        ::legacy::split(::airy::Bi_deriv_scaled_e(x, mode))
+    }
+
+    #[deprecated(note = "use `rgsl::airy::zero_Ai_e`, which returns a `Result`")]
+    pub fn zero_Ai_e(s: u32) -> (::Value, ::types::Result) {
+        ::legacy::split(::airy::zero_Ai_e(s))
+    }
+
+    #[deprecated(note = "use `rgsl::airy::zero_Bi_e`, which returns a `Result`")]
+    pub fn zero_Bi_e(s: u32) -> (::Value, ::types::Result) {
+        ::legacy::split(::airy::zero_Bi_e(s))
+    }
+
+    #[deprecated(note = "use `rgsl::airy::zero_Ai_deriv_e`, which returns a `Result`")]
+    pub fn zero_Ai_deriv_e(s: u32) -> (::Value, ::types::Result) {
+        ::legacy::split(::airy::zero_Ai_deriv_e(s))
+    }
+
+    #[deprecated(note = "use `rgsl::airy::zero_Bi_deriv_e`, which returns a `Result`")]
+    pub fn zero_Bi_deriv_e(s: u32) -> (::Value, ::types::Result) {
+        ::legacy::split(::airy::zero_Bi_deriv_e(s))
+    }
+}
+
+pub mod bessel {
+    #[deprecated(note = "use `rgsl::bessel::I0_e`, which returns a `Result`")]
+    pub fn I0_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::I0_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::I1_e`, which returns a `Result`")]
+    pub fn I1_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::I1_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::In_e`, which returns a `Result`")]
+    pub fn In_e(n: i32, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::In_e(n, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::I0_scaled_e`, which returns a `Result`")]
+    pub fn I0_scaled_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::I0_scaled_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::I1_scaled_e`, which returns a `Result`")]
+    pub fn I1_scaled_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::I1_scaled_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::In_scaled_e`, which returns a `Result`")]
+    pub fn In_scaled_e(n: i32, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::In_scaled_e(n, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::i0_scaled_e`, which returns a `Result`")]
+    pub fn i0_scaled_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::i0_scaled_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::i1_scaled_e`, which returns a `Result`")]
+    pub fn i1_scaled_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::i1_scaled_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::i2_scaled_e`, which returns a `Result`")]
+    pub fn i2_scaled_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::i2_scaled_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::il_scaled_e`, which returns a `Result`")]
+    pub fn il_scaled_e(l: i32, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::il_scaled_e(l, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::Inu_e`, which returns a `Result`")]
+    pub fn Inu_e(nu: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::Inu_e(nu, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::Inu_scaled_e`, which returns a `Result`")]
+    pub fn Inu_scaled_e(nu: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::Inu_scaled_e(nu, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::J0_e`, which returns a `Result`")]
+    pub fn J0_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::J0_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::J1_e`, which returns a `Result`")]
+    pub fn J1_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::J1_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::Jn_e`, which returns a `Result`")]
+    pub fn Jn_e(n: i32, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::Jn_e(n, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::j0_e`, which returns a `Result`")]
+    pub fn j0_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::j0_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::j1_e`, which returns a `Result`")]
+    pub fn j1_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::j1_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::j2_e`, which returns a `Result`")]
+    pub fn j2_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::j2_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::jl_e`, which returns a `Result`")]
+    pub fn jl_e(l: i32, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::jl_e(l, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::Jnu_e`, which returns a `Result`")]
+    pub fn Jnu_e(nu: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::Jnu_e(nu, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::K0_e`, which returns a `Result`")]
+    pub fn K0_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::K0_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::K1_e`, which returns a `Result`")]
+    pub fn K1_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::K1_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::Kn_e`, which returns a `Result`")]
+    pub fn Kn_e(n: i32, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::Kn_e(n, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::K0_scaled_e`, which returns a `Result`")]
+    pub fn K0_scaled_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::K0_scaled_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::K1_scaled_e`, which returns a `Result`")]
+    pub fn K1_scaled_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::K1_scaled_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::Kn_scaled_e`, which returns a `Result`")]
+    pub fn Kn_scaled_e(n: i32, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::Kn_scaled_e(n, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::k0_scaled_e`, which returns a `Result`")]
+    pub fn k0_scaled_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::k0_scaled_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::k1_scaled_e`, which returns a `Result`")]
+    pub fn k1_scaled_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::k1_scaled_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::k2_scaled_e`, which returns a `Result`")]
+    pub fn k2_scaled_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::k2_scaled_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::kl_scaled_e`, which returns a `Result`")]
+    pub fn kl_scaled_e(l: i32, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::kl_scaled_e(l, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::Knu_e`, which returns a `Result`")]
+    pub fn Knu_e(nu: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::Knu_e(nu, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::lnKnu_e`, which returns a `Result`")]
+    pub fn lnKnu_e(nu: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::lnKnu_e(nu, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::Knu_scaled_e`, which returns a `Result`")]
+    pub fn Knu_scaled_e(nu: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::Knu_scaled_e(nu, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::Y0_e`, which returns a `Result`")]
+    pub fn Y0_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::Y0_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::Y1_e`, which returns a `Result`")]
+    pub fn Y1_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::Y1_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::Yn_e`, which returns a `Result`")]
+    pub fn Yn_e(n: i32, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::Yn_e(n, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::y0_e`, which returns a `Result`")]
+    pub fn y0_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::y0_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::y1_e`, which returns a `Result`")]
+    pub fn y1_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::y1_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::y2_e`, which returns a `Result`")]
+    pub fn y2_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::y2_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::yl_e`, which returns a `Result`")]
+    pub fn yl_e(l: i32, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::yl_e(l, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::Ynu_e`, which returns a `Result`")]
+    pub fn Ynu_e(nu: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::Ynu_e(nu, x))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::zero_J0_e`, which returns a `Result`")]
+    pub fn zero_J0_e(s: u32) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::zero_J0_e(s))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::zero_J1_e`, which returns a `Result`")]
+    pub fn zero_J1_e(s: u32) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::zero_J1_e(s))
+    }
+
+    #[deprecated(note = "use `rgsl::bessel::zero_Jnu_e`, which returns a `Result`")]
+    pub fn zero_Jnu_e(nu: f64, s: u32) -> (::Value, ::types::Result) {
+        ::legacy::split(::bessel::zero_Jnu_e(nu, s))
+    }
+}
+
+pub mod blas {
+    pub mod level1 {
+        #[deprecated(note = "use `rgsl::blas::level1::sdsdot`, which returns a `Result`")]
+        pub fn sdsdot(
+            alpha: f32,
+            x: &::types::VectorF32,
+            y: &::types::VectorF32,
+        ) -> (::Value, f32) {
+            ::legacy::split(::blas::level1::sdsdot(alpha, x, y))
+        }
+
+        #[deprecated(note = "use `rgsl::blas::level1::sdot`, which returns a `Result`")]
+        pub fn sdot(x: &::types::VectorF32, y: &::types::VectorF32) -> (::Value, f32) {
+            ::legacy::split(::blas::level1::sdot(x, y))
+        }
+
+        #[deprecated(note = "use `rgsl::blas::level1::dsdot`, which returns a `Result`")]
+        pub fn dsdot(x: &::types::VectorF32, y: &::types::VectorF32) -> (::Value, f64) {
+            ::legacy::split(::blas::level1::dsdot(x, y))
+        }
+
+        #[deprecated(note = "use `rgsl::blas::level1::ddot`, which returns a `Result`")]
+        pub fn ddot(x: &::types::VectorF64, y: &::types::VectorF64) -> (::Value, f64) {
+            ::legacy::split(::blas::level1::ddot(x, y))
+        }
+
+        #[deprecated(note = "use `rgsl::blas::level1::cdotu`, which returns a `Result`")]
+        pub fn cdotu(
+            x: &::types::VectorComplexF32,
+            y: &::types::VectorComplexF32,
+        ) -> (::Value, ::types::ComplexF32) {
+            ::legacy::split(::blas::level1::cdotu(x, y))
+        }
+
+        #[deprecated(note = "use `rgsl::blas::level1::zdotu`, which returns a `Result`")]
+        pub fn zdotu(
+            x: &::types::VectorComplexF64,
+            y: &::types::VectorComplexF64,
+        ) -> (::Value, ::types::ComplexF64) {
+            ::legacy::split(::blas::level1::zdotu(x, y))
+        }
+
+        #[deprecated(note = "use `rgsl::blas::level1::cdotc`, which returns a `Result`")]
+        pub fn cdotc(
+            x: &::types::VectorComplexF32,
+            y: &::types::VectorComplexF32,
+        ) -> (::Value, ::types::ComplexF32) {
+            ::legacy::split(::blas::level1::cdotc(x, y))
+        }
+
+        #[deprecated(note = "use `rgsl::blas::level1::zdotc`, which returns a `Result`")]
+        pub fn zdotc(
+            x: &::types::VectorComplexF64,
+            y: &::types::VectorComplexF64,
+        ) -> (::Value, ::types::ComplexF64) {
+            ::legacy::split(::blas::level1::zdotc(x, y))
+        }
+    }
+}
+
+pub mod clausen {
+    #[deprecated(note = "use `rgsl::clausen::clausen_e`, which returns a `Result`")]
+    pub fn clausen_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::clausen::clausen_e(x))
+    }
+}
+
+pub mod coulomb {
+    #[deprecated(note = "use `rgsl::coulomb::hydrogenicR_1_e`, which returns a `Result`")]
+    pub fn hydrogenicR_1_e(Z: f64, r: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::coulomb::hydrogenicR_1_e(Z, r))
+    }
+
+    #[deprecated(note = "use `rgsl::coulomb::hydrogenicR_e`, which returns a `Result`")]
+    pub fn hydrogenicR_e(n: i32, l: i32, Z: f64, r: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::coulomb::hydrogenicR_e(n, l, Z, r))
+    }
+
+    #[deprecated(note = "use `rgsl::coulomb::wave_F_array`, which returns a `Result`")]
+    pub fn wave_F_array(L_min: f64, eta: f64, x: f64, fc_array: &mut [f64]) -> (::Value, f64) {
+        ::legacy::split(::coulomb::wave_F_array(L_min, eta, x, fc_array))
+    }
+
+    #[deprecated(note = "use `rgsl::coulomb::wave_FG_array`, which returns a `Result`")]
+    pub fn wave_FG_array(
+        L_min: f64,
+        eta: f64,
+        x: f64,
+        fc_array: &mut [f64],
+        gc_array: &mut [f64],
+    ) -> (::Value, f64, f64) {
+        let (value, (r0, r1)) =
+            ::legacy::split(::coulomb::wave_FG_array(L_min, eta, x, fc_array, gc_array));
+        (value, r0, r1)
+    }
+
+    #[deprecated(note = "use `rgsl::coulomb::wave_FGp_array`, which returns a `Result`")]
+    pub fn wave_FGp_array(
+        L_min: f64,
+        eta: f64,
+        x: f64,
+        fc_array: &mut [f64],
+        fcp_array: &mut [f64],
+        gc_array: &mut [f64],
+        gcp_array: &mut [f64],
+    ) -> (::Value, f64, f64) {
+        let (value, (r0, r1)) = ::legacy::split(::coulomb::wave_FGp_array(
+            L_min, eta, x, fc_array, fcp_array, gc_array, gcp_array,
+        ));
+        (value, r0, r1)
+    }
+
+    #[deprecated(note = "use `rgsl::coulomb::wave_sphF_array`, which returns a `Result`")]
+    pub fn wave_sphF_array(L_min: f64, eta: f64, x: f64, fc_array: &mut [f64]) -> (::Value, f64) {
+        ::legacy::split(::coulomb::wave_sphF_array(L_min, eta, x, fc_array))
+    }
+
+    #[deprecated(note = "use `rgsl::coulomb::CL_e`, which returns a `Result`")]
+    pub fn CL_e(L: f64, eta: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::coulomb::CL_e(L, eta))
+    }
+}
+
+pub mod coupling_coefficients {
+    #[deprecated(note = "use `rgsl::coupling_coefficients::_3j_e`, which returns a `Result`")]
+    pub fn _3j_e(
+        two_ja: i32,
+        two_jb: i32,
+        two_jc: i32,
+        two_ma: i32,
+        two_mb: i32,
+        two_mc: i32,
+    ) -> (::Value, ::types::Result) {
+        ::legacy::split(::coupling_coefficients::_3j_e(
+            two_ja, two_jb, two_jc, two_ma, two_mb, two_mc,
+        ))
+    }
+
+    #[deprecated(note = "use `rgsl::coupling_coefficients::_6j_e`, which returns a `Result`")]
+    pub fn _6j_e(
+        two_ja: i32,
+        two_jb: i32,
+        two_jc: i32,
+        two_jd: i32,
+        two_je: i32,
+        two_jf: i32,
+    ) -> (::Value, ::types::Result) {
+        ::legacy::split(::coupling_coefficients::_6j_e(
+            two_ja, two_jb, two_jc, two_jd, two_je, two_jf,
+        ))
+    }
+
+    #[deprecated(note = "use `rgsl::coupling_coefficients::_9j_e`, which returns a `Result`")]
+    pub fn _9j_e(
+        two_ja: i32,
+        two_jb: i32,
+        two_jc: i32,
+        two_jd: i32,
+        two_je: i32,
+        two_jf: i32,
+        two_jg: i32,
+        two_jh: i32,
+        two_ji: i32,
+    ) -> (::Value, ::types::Result) {
+        ::legacy::split(::coupling_coefficients::_9j_e(
+            two_ja, two_jb, two_jc, two_jd, two_je, two_jf, two_jg, two_jh, two_ji,
+        ))
+    }
+}
+
+pub mod dawson {
+    #[deprecated(note = "use `rgsl::dawson::dawson_e`, which returns a `Result`")]
+    pub fn dawson_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::dawson::dawson_e(x))
+    }
+}
+
+pub mod debye {
+    #[deprecated(note = "use `rgsl::debye::_1_e`, which returns a `Result`")]
+    pub fn _1_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::debye::_1_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::debye::_2_e`, which returns a `Result`")]
+    pub fn _2_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::debye::_2_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::debye::_3_e`, which returns a `Result`")]
+    pub fn _3_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::debye::_3_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::debye::_4_e`, which returns a `Result`")]
+    pub fn _4_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::debye::_4_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::debye::_5_e`, which returns a `Result`")]
+    pub fn _5_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::debye::_5_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::debye::_6_e`, which returns a `Result`")]
+    pub fn _6_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::debye::_6_e(x))
+    }
+}
+
+pub mod dilogarithm {
+    #[deprecated(note = "use `rgsl::dilogarithm::dilog_e`, which returns a `Result`")]
+    pub fn dilog_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::dilogarithm::dilog_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::dilogarithm::complex_dilog_e`, which returns a `Result`")]
+    pub fn complex_dilog_e(r: f64, theta: f64) -> (::Value, ::types::Result, ::types::Result) {
+        let (value, (r0, r1)) = ::legacy::split(::dilogarithm::complex_dilog_e(r, theta));
+        (value, r0, r1)
+    }
+}
+
+pub mod elementary_operations {
+    #[deprecated(note = "use `rgsl::elementary_operations::multiply_e`, which returns a `Result`")]
+    pub fn multiply_e(x: f64, y: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::elementary_operations::multiply_e(x, y))
+    }
+
+    #[deprecated(
+        note = "use `rgsl::elementary_operations::multiply_err_e`, which returns a `Result`"
+    )]
+    pub fn multiply_err_e(x: f64, dx: f64, y: f64, dy: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::elementary_operations::multiply_err_e(x, dx, y, dy))
+    }
+}
+
+pub mod elliptic {
+    pub mod carlson {
+        #[deprecated(note = "use `rgsl::elliptic::carlson::ellint_RC_e`, which returns a `Result`")]
+        pub fn ellint_RC_e(x: f64, y: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+            ::legacy::split(::elliptic::carlson::ellint_RC_e(x, y, mode))
+        }
+
+        #[deprecated(note = "use `rgsl::elliptic::carlson::ellint_RD_e`, which returns a `Result`")]
+        pub fn ellint_RD_e(x: f64, y: f64, z: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+            ::legacy::split(::elliptic::carlson::ellint_RD_e(x, y, z, mode))
+        }
+
+        #[deprecated(note = "use `rgsl::elliptic::carlson::ellint_RF_e`, which returns a `Result`")]
+        pub fn ellint_RF_e(x: f64, y: f64, z: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+            ::legacy::split(::elliptic::carlson::ellint_RF_e(x, y, z, mode))
+        }
+
+        #[deprecated(note = "use `rgsl::elliptic::carlson::ellint_RJ_e`, which returns a `Result`")]
+        pub fn ellint_RJ_e(
+            x: f64,
+            y: f64,
+            z: f64,
+            p: f64,
+            mode: ::Mode,
+        ) -> (::Value, ::types::Result) {
+            ::legacy::split(::elliptic::carlson::ellint_RJ_e(x, y, z, p, mode))
+        }
+    }
+
+    pub mod legendre {
+        pub mod complete {
+            #[deprecated(
+                note = "use `rgsl::elliptic::legendre::complete::ellint_Kcomp_e`, which returns a `Result`"
+            )]
+            pub fn ellint_Kcomp_e(k: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+                ::legacy::split(::elliptic::legendre::complete::ellint_Kcomp_e(k, mode))
+            }
+
+            #[deprecated(
+                note = "use `rgsl::elliptic::legendre::complete::ellint_Ecomp_e`, which returns a `Result`"
+            )]
+            pub fn ellint_Ecomp_e(k: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+                ::legacy::split(::elliptic::legendre::complete::ellint_Ecomp_e(k, mode))
+            }
+
+            #[deprecated(
+                note = "use `rgsl::elliptic::legendre::complete::ellint_Pcomp_e`, which returns a `Result`"
+            )]
+            pub fn ellint_Pcomp_e(k: f64, n: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+                ::legacy::split(::elliptic::legendre::complete::ellint_Pcomp_e(k, n, mode))
+            }
+        }
+
+        pub mod incomplete {
+            #[deprecated(
+                note = "use `rgsl::elliptic::legendre::incomplete::ellint_F_e`, which returns a `Result`"
+            )]
+            pub fn ellint_F_e(phi: f64, k: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+                ::legacy::split(::elliptic::legendre::incomplete::ellint_F_e(phi, k, mode))
+            }
+
+            #[deprecated(
+                note = "use `rgsl::elliptic::legendre::incomplete::ellint_E_e`, which returns a `Result`"
+            )]
+            pub fn ellint_E_e(phi: f64, k: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+                ::legacy::split(::elliptic::legendre::incomplete::ellint_E_e(phi, k, mode))
+            }
+
+            #[deprecated(
+                note = "use `rgsl::elliptic::legendre::incomplete::ellint_P_e`, which returns a `Result`"
+            )]
+            pub fn ellint_P_e(
+                phi: f64,
+                k: f64,
+                n: f64,
+                mode: ::Mode,
+            ) -> (::Value, ::types::Result) {
+                ::legacy::split(::elliptic::legendre::incomplete::ellint_P_e(
+                    phi, k, n, mode,
+                ))
+            }
+
+            #[deprecated(
+                note = "use `rgsl::elliptic::legendre::incomplete::ellint_D_e`, which returns a `Result`"
+            )]
+            pub fn ellint_D_e(phi: f64, k: f64, mode: ::Mode) -> (::Value, ::types::Result) {
+                ::legacy::split(::elliptic::legendre::incomplete::ellint_D_e(phi, k, mode))
+            }
+        }
+    }
+}
+
+pub mod error {
+    #[deprecated(note = "use `rgsl::error::erf_e`, which returns a `Result`")]
+    pub fn erf_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::error::erf_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::error::erfc_e`, which returns a `Result`")]
+    pub fn erfc_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::error::erfc_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::error::log_erfc_e`, which returns a `Result`")]
+    pub fn log_erfc_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::error::log_erfc_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::error::erf_Z_e`, which returns a `Result`")]
+    pub fn erf_Z_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::error::erf_Z_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::error::erf_Q_e`, which returns a `Result`")]
+    pub fn erf_Q_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::error::erf_Q_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::error::hazard_e`, which returns a `Result`")]
+    pub fn hazard_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::error::hazard_e(x))
+    }
+}
+
+pub mod exponential {
+    #[deprecated(note = "use `rgsl::exponential::exp_e`, which returns a `Result`")]
+    pub fn exp_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential::exp_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential::exp_e10_e`, which returns a `Result`")]
+    pub fn exp_e10_e(x: f64) -> (::Value, ::types::ResultE10) {
+        ::legacy::split(::exponential::exp_e10_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential::exp_mult_e`, which returns a `Result`")]
+    pub fn exp_mult_e(x: f64, y: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential::exp_mult_e(x, y))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential::exp_mult_e10_e`, which returns a `Result`")]
+    pub fn exp_mult_e10_e(x: f64, y: f64) -> (::Value, ::types::ResultE10) {
+        ::legacy::split(::exponential::exp_mult_e10_e(x, y))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential::expm1_e`, which returns a `Result`")]
+    pub fn expm1_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential::expm1_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential::exprel_e`, which returns a `Result`")]
+    pub fn exprel_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential::exprel_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential::exprel_2_e`, which returns a `Result`")]
+    pub fn exprel_2_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential::exprel_2_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential::exprel_n_e`, which returns a `Result`")]
+    pub fn exprel_n_e(n: i32, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential::exprel_n_e(n, x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential::exp_err_e`, which returns a `Result`")]
+    pub fn exp_err_e(x: f64, dx: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential::exp_err_e(x, dx))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential::exp_err_e10_e`, which returns a `Result`")]
+    pub fn exp_err_e10_e(x: f64, dx: f64) -> (::Value, ::types::ResultE10) {
+        ::legacy::split(::exponential::exp_err_e10_e(x, dx))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential::exp_mult_err_e`, which returns a `Result`")]
+    pub fn exp_mult_err_e(x: f64, dx: f64, y: f64, dy: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential::exp_mult_err_e(x, dx, y, dy))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential::exp_mult_err_e10_e`, which returns a `Result`")]
+    pub fn exp_mult_err_e10_e(x: f64, dx: f64, y: f64, dy: f64) -> (::Value, ::types::ResultE10) {
+        ::legacy::split(::exponential::exp_mult_err_e10_e(x, dx, y, dy))
+    }
+}
+
+pub mod exponential_integrals {
+    #[deprecated(note = "use `rgsl::exponential_integrals::E1_e`, which returns a `Result`")]
+    pub fn E1_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential_integrals::E1_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential_integrals::E2_e`, which returns a `Result`")]
+    pub fn E2_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential_integrals::E2_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential_integrals::En_e`, which returns a `Result`")]
+    pub fn En_e(n: i32, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential_integrals::En_e(n, x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential_integrals::Ei_e`, which returns a `Result`")]
+    pub fn Ei_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential_integrals::Ei_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential_integrals::Shi_e`, which returns a `Result`")]
+    pub fn Shi_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential_integrals::Shi_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential_integrals::Chi_e`, which returns a `Result`")]
+    pub fn Chi_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential_integrals::Chi_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential_integrals::_3_e`, which returns a `Result`")]
+    pub fn _3_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential_integrals::_3_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential_integrals::Si_e`, which returns a `Result`")]
+    pub fn Si_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential_integrals::Si_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential_integrals::Ci_e`, which returns a `Result`")]
+    pub fn Ci_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential_integrals::Ci_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::exponential_integrals::atanint_e`, which returns a `Result`")]
+    pub fn atanint_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::exponential_integrals::atanint_e(x))
+    }
+}
+
+pub mod fermi_dirac {
+    pub mod complete_integrals {
+        #[deprecated(
+            note = "use `rgsl::fermi_dirac::complete_integrals::fermi_dirac_m1_e`, which returns a `Result`"
+        )]
+        pub fn fermi_dirac_m1_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::fermi_dirac::complete_integrals::fermi_dirac_m1_e(x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::fermi_dirac::complete_integrals::fermi_dirac_0_e`, which returns a `Result`"
+        )]
+        pub fn fermi_dirac_0_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::fermi_dirac::complete_integrals::fermi_dirac_0_e(x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::fermi_dirac::complete_integrals::fermi_dirac_1_e`, which returns a `Result`"
+        )]
+        pub fn fermi_dirac_1_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::fermi_dirac::complete_integrals::fermi_dirac_1_e(x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::fermi_dirac::complete_integrals::fermi_dirac_2_e`, which returns a `Result`"
+        )]
+        pub fn fermi_dirac_2_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::fermi_dirac::complete_integrals::fermi_dirac_2_e(x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::fermi_dirac::complete_integrals::fermi_dirac_int_e`, which returns a `Result`"
+        )]
+        pub fn fermi_dirac_int_e(j: i32, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::fermi_dirac::complete_integrals::fermi_dirac_int_e(j, x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::fermi_dirac::complete_integrals::fermi_dirac_mhalf_e`, which returns a `Result`"
+        )]
+        pub fn fermi_dirac_mhalf_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::fermi_dirac::complete_integrals::fermi_dirac_mhalf_e(x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::fermi_dirac::complete_integrals::fermi_dirac_half_e`, which returns a `Result`"
+        )]
+        pub fn fermi_dirac_half_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::fermi_dirac::complete_integrals::fermi_dirac_half_e(x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::fermi_dirac::complete_integrals::fermi_dirac_3half_e`, which returns a `Result`"
+        )]
+        pub fn fermi_dirac_3half_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::fermi_dirac::complete_integrals::fermi_dirac_3half_e(x))
+        }
+    }
+
+    pub mod incomplete_integrals {
+        #[deprecated(
+            note = "use `rgsl::fermi_dirac::incomplete_integrals::fermi_dirac_inc_0_e`, which returns a `Result`"
+        )]
+        pub fn fermi_dirac_inc_0_e(x: f64, b: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::fermi_dirac::incomplete_integrals::fermi_dirac_inc_0_e(
+                x, b,
+            ))
+        }
+    }
+}
+
+pub mod fit {
+    #[deprecated(note = "use `rgsl::fit::linear`, which returns a `Result`")]
+    pub fn linear(
+        x: &[f64],
+        xstride: usize,
+        y: &[f64],
+        ystride: usize,
+        n: usize,
+    ) -> (::Value, f64, f64, f64, f64, f64, f64) {
+        let (value, f) = ::legacy::split(::fit::linear(x, xstride, y, ystride, n));
+        (value, f.c0, f.c1, f.cov00, f.cov01, f.cov11, f.sumsq)
+    }
+
+    #[deprecated(note = "use `rgsl::fit::wlinear`, which returns a `Result`")]
+    pub fn wlinear(
+        x: &[f64],
+        xstride: usize,
+        w: &[f64],
+        wstride: usize,
+        y: &[f64],
+        ystride: usize,
+        n: usize,
+    ) -> (::Value, f64, f64, f64, f64, f64, f64) {
+        let (value, f) = ::legacy::split(::fit::wlinear(x, xstride, w, wstride, y, ystride, n));
+        (value, f.c0, f.c1, f.cov00, f.cov01, f.cov11, f.sumsq)
+    }
+
+    #[deprecated(note = "use `rgsl::fit::linear_est`, which returns a `Result`")]
+    pub fn linear_est(
+        x: f64,
+        c0: f64,
+        c1: f64,
+        cov00: f64,
+        cov01: f64,
+        cov11: f64,
+    ) -> (::Value, f64, f64) {
+        let (value, (r0, r1)) = ::legacy::split(::fit::linear_est(x, c0, c1, cov00, cov01, cov11));
+        (value, r0, r1)
+    }
+
+    #[deprecated(note = "use `rgsl::fit::mul`, which returns a `Result`")]
+    pub fn mul(
+        x: &[f64],
+        xstride: usize,
+        y: &[f64],
+        ystride: usize,
+        n: usize,
+    ) -> (::Value, f64, f64, f64) {
+        let (value, f) = ::legacy::split(::fit::mul(x, xstride, y, ystride, n));
+        (value, f.c1, f.cov11, f.sumsq)
+    }
+
+    #[deprecated(note = "use `rgsl::fit::wmul`, which returns a `Result`")]
+    pub fn wmul(
+        x: &[f64],
+        xstride: usize,
+        w: &[f64],
+        wstride: usize,
+        y: &[f64],
+        ystride: usize,
+        n: usize,
+    ) -> (::Value, f64, f64, f64) {
+        let (value, f) = ::legacy::split(::fit::wmul(x, xstride, w, wstride, y, ystride, n));
+        (value, f.c1, f.cov11, f.sumsq)
+    }
+
+    #[deprecated(note = "use `rgsl::fit::mul_est`, which returns a `Result`")]
+    pub fn mul_est(x: f64, c1: f64, cov11: f64) -> (::Value, f64, f64) {
+        let (value, (r0, r1)) = ::legacy::split(::fit::mul_est(x, c1, cov11));
+        (value, r0, r1)
+    }
+}
+
+pub mod gamma_beta {
+    pub mod beta {
+        #[deprecated(note = "use `rgsl::gamma_beta::beta::beta_e`, which returns a `Result`")]
+        pub fn beta_e(a: f64, b: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::beta::beta_e(a, b))
+        }
+
+        #[deprecated(note = "use `rgsl::gamma_beta::beta::lnbeta_e`, which returns a `Result`")]
+        pub fn lnbeta_e(a: f64, b: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::beta::lnbeta_e(a, b))
+        }
+    }
+
+    pub mod factorials {
+        #[deprecated(note = "use `rgsl::gamma_beta::factorials::fact_e`, which returns a `Result`")]
+        pub fn fact_e(n: u32) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::factorials::fact_e(n))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::factorials::doublefact_e`, which returns a `Result`"
+        )]
+        pub fn doublefact_e(n: u32) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::factorials::doublefact_e(n))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::factorials::lnfact_e`, which returns a `Result`"
+        )]
+        pub fn lnfact_e(n: u32) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::factorials::lnfact_e(n))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::factorials::lndoublefact_e`, which returns a `Result`"
+        )]
+        pub fn lndoublefact_e(n: u32) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::factorials::lndoublefact_e(n))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::factorials::choose_e`, which returns a `Result`"
+        )]
+        pub fn choose_e(n: u32, m: u32) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::factorials::choose_e(n, m))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::factorials::lnchoose_e`, which returns a `Result`"
+        )]
+        pub fn lnchoose_e(n: u32, m: u32) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::factorials::lnchoose_e(n, m))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::factorials::taylorcoeff_e`, which returns a `Result`"
+        )]
+        pub fn taylorcoeff_e(n: i32, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::factorials::taylorcoeff_e(n, x))
+        }
+    }
+
+    pub mod gamma {
+        #[deprecated(note = "use `rgsl::gamma_beta::gamma::gamma_e`, which returns a `Result`")]
+        pub fn gamma_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::gamma::gamma_e(x))
+        }
+
+        #[deprecated(note = "use `rgsl::gamma_beta::gamma::lngamma_e`, which returns a `Result`")]
+        pub fn lngamma_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::gamma::lngamma_e(x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::gamma::lngamma_sgn_e`, which returns a `Result`"
+        )]
+        pub fn lngamma_sgn_e(x: f64, sgn: &mut f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::gamma::lngamma_sgn_e(x, sgn))
+        }
+
+        #[deprecated(note = "use `rgsl::gamma_beta::gamma::gammastar_e`, which returns a `Result`")]
+        pub fn gammastar_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::gamma::gammastar_e(x))
+        }
+
+        #[deprecated(note = "use `rgsl::gamma_beta::gamma::gammainv_e`, which returns a `Result`")]
+        pub fn gammainv_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::gamma::gammainv_e(x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::gamma::lngamma_complex_e`, which returns a `Result`"
+        )]
+        pub fn lngamma_complex_e(zr: f64, zi: f64) -> (::Value, ::types::Result, ::types::Result) {
+            let (value, (r0, r1)) = ::legacy::split(::gamma_beta::gamma::lngamma_complex_e(zr, zi));
+            (value, r0, r1)
+        }
+    }
+
+    pub mod incomplete_beta {
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::incomplete_beta::beta_inc_e`, which returns a `Result`"
+        )]
+        pub fn beta_inc_e(a: f64, b: f64, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::incomplete_beta::beta_inc_e(a, b, x))
+        }
+    }
+
+    pub mod incomplete_gamma {
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::incomplete_gamma::gamma_inc_e`, which returns a `Result`"
+        )]
+        pub fn gamma_inc_e(a: f64, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::incomplete_gamma::gamma_inc_e(a, x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::incomplete_gamma::gamma_inc_Q_e`, which returns a `Result`"
+        )]
+        pub fn gamma_inc_Q_e(a: f64, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::incomplete_gamma::gamma_inc_Q_e(a, x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::incomplete_gamma::gamma_inc_P_e`, which returns a `Result`"
+        )]
+        pub fn gamma_inc_P_e(a: f64, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::incomplete_gamma::gamma_inc_P_e(a, x))
+        }
+    }
+
+    pub mod pochhammer_symbol {
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::pochhammer_symbol::poch_e`, which returns a `Result`"
+        )]
+        pub fn poch_e(a: f64, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::pochhammer_symbol::poch_e(a, x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::pochhammer_symbol::lnpoch_e`, which returns a `Result`"
+        )]
+        pub fn lnpoch_e(a: f64, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::pochhammer_symbol::lnpoch_e(a, x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::pochhammer_symbol::lnpoch_sgn_e`, which returns a `Result`"
+        )]
+        pub fn lnpoch_sgn_e(a: f64, x: f64, sgn: &mut f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::pochhammer_symbol::lnpoch_sgn_e(a, x, sgn))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::gamma_beta::pochhammer_symbol::pochrel_e`, which returns a `Result`"
+        )]
+        pub fn pochrel_e(a: f64, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::gamma_beta::pochhammer_symbol::pochrel_e(a, x))
+        }
+    }
+}
+
+pub mod gegenbauer {
+    #[deprecated(note = "use `rgsl::gegenbauer::gegenpoly_1_e`, which returns a `Result`")]
+    pub fn gegenpoly_1_e(lambda: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::gegenbauer::gegenpoly_1_e(lambda, x))
+    }
+
+    #[deprecated(note = "use `rgsl::gegenbauer::gegenpoly_2_e`, which returns a `Result`")]
+    pub fn gegenpoly_2_e(lambda: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::gegenbauer::gegenpoly_2_e(lambda, x))
+    }
+
+    #[deprecated(note = "use `rgsl::gegenbauer::gegenpoly_3_e`, which returns a `Result`")]
+    pub fn gegenpoly_3_e(lambda: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::gegenbauer::gegenpoly_3_e(lambda, x))
+    }
+
+    #[deprecated(note = "use `rgsl::gegenbauer::gegenpoly_n_e`, which returns a `Result`")]
+    pub fn gegenpoly_n_e(n: i32, lambda: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::gegenbauer::gegenpoly_n_e(n, lambda, x))
+    }
+}
+
+pub mod hypergeometric {
+    #[deprecated(note = "use `rgsl::hypergeometric::hyperg_0F1_e`, which returns a `Result`")]
+    pub fn hyperg_0F1_e(c: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::hypergeometric::hyperg_0F1_e(c, x))
+    }
+
+    #[deprecated(note = "use `rgsl::hypergeometric::hyperg_1F1_int_e`, which returns a `Result`")]
+    pub fn hyperg_1F1_int_e(m: i32, n: i32, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::hypergeometric::hyperg_1F1_int_e(m, n, x))
+    }
+
+    #[deprecated(note = "use `rgsl::hypergeometric::hyperg_1F1_e`, which returns a `Result`")]
+    pub fn hyperg_1F1_e(a: f64, b: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::hypergeometric::hyperg_1F1_e(a, b, x))
+    }
+
+    #[deprecated(note = "use `rgsl::hypergeometric::hyperg_1F1_U_int_e`, which returns a `Result`")]
+    pub fn hyperg_1F1_U_int_e(m: i32, n: i32, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::hypergeometric::hyperg_1F1_U_int_e(m, n, x))
+    }
+
+    #[deprecated(
+        note = "use `rgsl::hypergeometric::hyperg_1F1_U_int_e10_e`, which returns a `Result`"
+    )]
+    pub fn hyperg_1F1_U_int_e10_e(m: i32, n: i32, x: f64) -> (::Value, ::types::ResultE10) {
+        ::legacy::split(::hypergeometric::hyperg_1F1_U_int_e10_e(m, n, x))
+    }
+
+    #[deprecated(note = "use `rgsl::hypergeometric::hyperg_U_e`, which returns a `Result`")]
+    pub fn hyperg_U_e(a: f64, b: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::hypergeometric::hyperg_U_e(a, b, x))
+    }
+
+    #[deprecated(note = "use `rgsl::hypergeometric::hyperg_U_e10_e`, which returns a `Result`")]
+    pub fn hyperg_U_e10_e(a: f64, b: f64, x: f64) -> (::Value, ::types::ResultE10) {
+        ::legacy::split(::hypergeometric::hyperg_U_e10_e(a, b, x))
+    }
+
+    #[deprecated(note = "use `rgsl::hypergeometric::hyperg_2F1_e`, which returns a `Result`")]
+    pub fn hyperg_2F1_e(a: f64, b: f64, c: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::hypergeometric::hyperg_2F1_e(a, b, c, x))
+    }
+
+    #[deprecated(note = "use `rgsl::hypergeometric::hyperg_2F1_conj_e`, which returns a `Result`")]
+    pub fn hyperg_2F1_conj_e(aR: f64, aI: f64, c: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::hypergeometric::hyperg_2F1_conj_e(aR, aI, c, x))
+    }
+
+    #[deprecated(
+        note = "use `rgsl::hypergeometric::hyperg_2F1_renorm_e`, which returns a `Result`"
+    )]
+    pub fn hyperg_2F1_renorm_e(a: f64, b: f64, c: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::hypergeometric::hyperg_2F1_renorm_e(a, b, c, x))
+    }
+
+    #[deprecated(
+        note = "use `rgsl::hypergeometric::hyperg_2F1_conj_renorm_e`, which returns a `Result`"
+    )]
+    pub fn hyperg_2F1_conj_renorm_e(
+        aR: f64,
+        aI: f64,
+        c: f64,
+        x: f64,
+    ) -> (::Value, ::types::Result) {
+        ::legacy::split(::hypergeometric::hyperg_2F1_conj_renorm_e(aR, aI, c, x))
+    }
+
+    #[deprecated(note = "use `rgsl::hypergeometric::hyperg_2F0_e`, which returns a `Result`")]
+    pub fn hyperg_2F0_e(a: f64, b: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::hypergeometric::hyperg_2F0_e(a, b, x))
+    }
+}
+
+pub mod interpolation {
+    #[deprecated(note = "use `rgsl::interpolation::eval_e`, which returns a `Result`")]
+    pub fn eval_e(
+        interp: &::Interp,
+        xa: &[f64],
+        ya: &[f64],
+        x: f64,
+        acc: &mut ::InterpAccel,
+    ) -> (::Value, f64) {
+        ::legacy::split(::interpolation::eval_e(interp, xa, ya, x, acc))
+    }
+
+    #[deprecated(note = "use `rgsl::interpolation::eval_deriv_e`, which returns a `Result`")]
+    pub fn eval_deriv_e(
+        interp: &::Interp,
+        xa: &[f64],
+        ya: &[f64],
+        x: f64,
+        acc: &mut ::InterpAccel,
+    ) -> (::Value, f64) {
+        ::legacy::split(::interpolation::eval_deriv_e(interp, xa, ya, x, acc))
+    }
+
+    #[deprecated(note = "use `rgsl::interpolation::eval_deriv2_e`, which returns a `Result`")]
+    pub fn eval_deriv2_e(
+        interp: &::Interp,
+        xa: &[f64],
+        ya: &[f64],
+        x: f64,
+        acc: &mut ::InterpAccel,
+    ) -> (::Value, f64) {
+        ::legacy::split(::interpolation::eval_deriv2_e(interp, xa, ya, x, acc))
+    }
+
+    #[deprecated(note = "use `rgsl::interpolation::eval_integ_e`, which returns a `Result`")]
+    pub fn eval_integ_e(
+        interp: &::Interp,
+        xa: &[f64],
+        ya: &[f64],
+        a: f64,
+        b: f64,
+        acc: &mut ::InterpAccel,
+    ) -> (::Value, f64) {
+        ::legacy::split(::interpolation::eval_integ_e(interp, xa, ya, a, b, acc))
+    }
+}
+
+pub mod jacobian_elliptic {
+    #[deprecated(note = "use `rgsl::jacobian_elliptic::elljac_e`, which returns a `Result`")]
+    pub fn elljac_e(u: f64, m: f64) -> (::Value, f64, f64, f64) {
+        let (value, (r0, r1, r2)) = ::legacy::split(::jacobian_elliptic::elljac_e(u, m));
+        (value, r0, r1, r2)
+    }
+}
+
+pub mod laguerre {
+    #[deprecated(note = "use `rgsl::laguerre::laguerre_1_e`, which returns a `Result`")]
+    pub fn laguerre_1_e(a: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::laguerre::laguerre_1_e(a, x))
+    }
+
+    #[deprecated(note = "use `rgsl::laguerre::laguerre_2_e`, which returns a `Result`")]
+    pub fn laguerre_2_e(a: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::laguerre::laguerre_2_e(a, x))
+    }
+
+    #[deprecated(note = "use `rgsl::laguerre::laguerre_3_e`, which returns a `Result`")]
+    pub fn laguerre_3_e(a: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::laguerre::laguerre_3_e(a, x))
+    }
+
+    #[deprecated(note = "use `rgsl::laguerre::laguerre_n_e`, which returns a `Result`")]
+    pub fn laguerre_n_e(n: i32, a: f64, x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::laguerre::laguerre_n_e(n, a, x))
+    }
+}
+
+pub mod lambert_w {
+    #[deprecated(note = "use `rgsl::lambert_w::lambert_W0_e`, which returns a `Result`")]
+    pub fn lambert_W0_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::lambert_w::lambert_W0_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::lambert_w::lambert_Wm1_e`, which returns a `Result`")]
+    pub fn lambert_Wm1_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::lambert_w::lambert_Wm1_e(x))
+    }
+}
+
+pub mod legendre {
+    pub mod associated_polynomials {
+        #[deprecated(
+            note = "use `rgsl::legendre::associated_polynomials::legendre_Plm_e`, which returns a `Result`"
+        )]
+        pub fn legendre_Plm_e(l: i32, m: i32, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::associated_polynomials::legendre_Plm_e(l, m, x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::legendre::associated_polynomials::legendre_sphPlm_e`, which returns a `Result`"
+        )]
+        pub fn legendre_sphPlm_e(l: i32, m: i32, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::associated_polynomials::legendre_sphPlm_e(
+                l, m, x,
+            ))
+        }
+    }
+
+    pub mod conical {
+        #[deprecated(note = "use `rgsl::legendre::conical::half_e`, which returns a `Result`")]
+        pub fn half_e(lambda: f64, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::conical::half_e(lambda, x))
+        }
+
+        #[deprecated(note = "use `rgsl::legendre::conical::mhalf_e`, which returns a `Result`")]
+        pub fn mhalf_e(lambda: f64, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::conical::mhalf_e(lambda, x))
+        }
+
+        #[deprecated(note = "use `rgsl::legendre::conical::_0_e`, which returns a `Result`")]
+        pub fn _0_e(lambda: f64, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::conical::_0_e(lambda, x))
+        }
+
+        #[deprecated(note = "use `rgsl::legendre::conical::_1_e`, which returns a `Result`")]
+        pub fn _1_e(lambda: f64, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::conical::_1_e(lambda, x))
+        }
+
+        #[deprecated(note = "use `rgsl::legendre::conical::sph_reg_e`, which returns a `Result`")]
+        pub fn sph_reg_e(l: i32, lambda: f64, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::conical::sph_reg_e(l, lambda, x))
+        }
+
+        #[deprecated(note = "use `rgsl::legendre::conical::cyl_reg_e`, which returns a `Result`")]
+        pub fn cyl_reg_e(m: i32, lambda: f64, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::conical::cyl_reg_e(m, lambda, x))
+        }
+    }
+
+    pub mod polynomials {
+        #[deprecated(
+            note = "use `rgsl::legendre::polynomials::legendre_P1_e`, which returns a `Result`"
+        )]
+        pub fn legendre_P1_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::polynomials::legendre_P1_e(x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::legendre::polynomials::legendre_P2_e`, which returns a `Result`"
+        )]
+        pub fn legendre_P2_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::polynomials::legendre_P2_e(x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::legendre::polynomials::legendre_P3_e`, which returns a `Result`"
+        )]
+        pub fn legendre_P3_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::polynomials::legendre_P3_e(x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::legendre::polynomials::legendre_Pl_e`, which returns a `Result`"
+        )]
+        pub fn legendre_Pl_e(l: i32, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::polynomials::legendre_Pl_e(l, x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::legendre::polynomials::legendre_Q0_e`, which returns a `Result`"
+        )]
+        pub fn legendre_Q0_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::polynomials::legendre_Q0_e(x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::legendre::polynomials::legendre_Q1_e`, which returns a `Result`"
+        )]
+        pub fn legendre_Q1_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::polynomials::legendre_Q1_e(x))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::legendre::polynomials::legendre_Ql_e`, which returns a `Result`"
+        )]
+        pub fn legendre_Ql_e(l: i32, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::polynomials::legendre_Ql_e(l, x))
+        }
+    }
+
+    pub mod radial {
+        #[deprecated(
+            note = "use `rgsl::legendre::radial::legendre_H3d_0_e`, which returns a `Result`"
+        )]
+        pub fn legendre_H3d_0_e(lambda: f64, eta: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::radial::legendre_H3d_0_e(lambda, eta))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::legendre::radial::legendre_H3d_1_e`, which returns a `Result`"
+        )]
+        pub fn legendre_H3d_1_e(lambda: f64, eta: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::radial::legendre_H3d_1_e(lambda, eta))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::legendre::radial::legendre_H3d_e`, which returns a `Result`"
+        )]
+        pub fn legendre_H3d_e(l: i32, lambda: f64, eta: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::legendre::radial::legendre_H3d_e(l, lambda, eta))
+        }
+    }
+}
+
+pub mod linear_algebra {
+    #[cfg(feature = "v2_2")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    #[deprecated(note = "use `rgsl::linear_algebra::pcholesky_rcond`, which returns a `Result`")]
+    pub fn pcholesky_rcond(
+        LDLT: &::MatrixF64,
+        p: &::Permutation,
+        work: &mut ::VectorF64,
+    ) -> (::Value, f64) {
+        ::legacy::split(::linear_algebra::pcholesky_rcond(LDLT, p, work))
+    }
+
+    #[cfg(feature = "v2_2")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    #[deprecated(note = "use `rgsl::linear_algebra::mcholesky_rcond`, which returns a `Result`")]
+    pub fn mcholesky_rcond(
+        LDLT: &::MatrixF64,
+        p: &::Permutation,
+        work: &mut ::VectorF64,
+    ) -> (::Value, f64) {
+        ::legacy::split(::linear_algebra::mcholesky_rcond(LDLT, p, work))
+    }
+
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+    #[deprecated(
+        note = "use `rgsl::linear_algebra::cholesky_band_rcond`, which returns a `Result`"
+    )]
+    pub fn cholesky_band_rcond(LLT: &::MatrixF64, work: &mut ::VectorF64) -> (::Value, f64) {
+        ::legacy::split(::linear_algebra::cholesky_band_rcond(LLT, work))
+    }
+
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+    #[deprecated(note = "use `rgsl::linear_algebra::ldlt_rcond`, which returns a `Result`")]
+    pub fn ldlt_rcond(LDLT: &::MatrixF64, work: &mut ::VectorF64) -> (::Value, f64) {
+        ::legacy::split(::linear_algebra::ldlt_rcond(LDLT, work))
+    }
+
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+    #[deprecated(note = "use `rgsl::linear_algebra::ldlt_band_rcond`, which returns a `Result`")]
+    pub fn ldlt_band_rcond(LDLT: &::MatrixF64, work: &mut ::VectorF64) -> (::Value, f64) {
+        ::legacy::split(::linear_algebra::ldlt_band_rcond(LDLT, work))
+    }
+}
+
+pub mod logarithm {
+    #[deprecated(note = "use `rgsl::logarithm::log_e`, which returns a `Result`")]
+    pub fn log_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::logarithm::log_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::logarithm::log_abs_e`, which returns a `Result`")]
+    pub fn log_abs_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::logarithm::log_abs_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::logarithm::complex_log_e`, which returns a `Result`")]
+    pub fn complex_log_e(zr: f64, zi: f64) -> (::Value, ::types::Result, ::types::Result) {
+        let (value, (r0, r1)) = ::legacy::split(::logarithm::complex_log_e(zr, zi));
+        (value, r0, r1)
+    }
+
+    #[deprecated(note = "use `rgsl::logarithm::log_1plusx_e`, which returns a `Result`")]
+    pub fn log_1plusx_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::logarithm::log_1plusx_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::logarithm::log_1plusx_mx_e`, which returns a `Result`")]
+    pub fn log_1plusx_mx_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::logarithm::log_1plusx_mx_e(x))
+    }
+}
+
+pub mod multifit {
+    #[deprecated(note = "use `rgsl::multifit::linear_lcorner`, which returns a `Result`")]
+    pub fn linear_lcorner(rho: &::VectorF64, eta: &::VectorF64) -> (::Value, usize) {
+        ::legacy::split(::multifit::linear_lcorner(rho, eta))
+    }
+
+    #[deprecated(note = "use `rgsl::multifit::linear_lcorner2`, which returns a `Result`")]
+    pub fn linear_lcorner2(rho: &::VectorF64, eta: &::VectorF64) -> (::Value, usize) {
+        ::legacy::split(::multifit::linear_lcorner2(rho, eta))
+    }
+}
+
+pub mod multilinear {
+    #[deprecated(note = "use `rgsl::multilinear::lcorner`, which returns a `Result`")]
+    pub fn lcorner(rho: &::VectorF64, eta: &::VectorF64) -> (::Value, usize) {
+        ::legacy::split(::multilinear::lcorner(rho, eta))
+    }
+
+    #[deprecated(note = "use `rgsl::multilinear::lcorner2`, which returns a `Result`")]
+    pub fn lcorner2(reg_param: &::VectorF64, eta: &::VectorF64) -> (::Value, usize) {
+        ::legacy::split(::multilinear::lcorner2(reg_param, eta))
+    }
+
+    #[deprecated(note = "use `rgsl::multilinear::linear_est`, which returns a `Result`")]
+    pub fn linear_est(x: &::VectorF64, c: &::VectorF64, cov: &::MatrixF64) -> (::Value, f64, f64) {
+        let (value, (r0, r1)) = ::legacy::split(::multilinear::linear_est(x, c, cov));
+        (value, r0, r1)
+    }
+}
+
+pub mod polynomials {
+    pub mod quadratic_equations {
+        use polynomials::quadratic_equations::QuadraticRoots;
+
+        /// The old version returned the number of roots as a [`Value`](crate::Value).
+        #[deprecated(
+            note = "use `rgsl::polynomials::quadratic_equations::poly_solve_quadratic`, which returns the roots"
+        )]
+        pub fn poly_solve_quadratic(a: f64, b: f64, c: f64) -> (::Value, f64, f64) {
+            match ::polynomials::quadratic_equations::poly_solve_quadratic(a, b, c) {
+                QuadraticRoots::None => (::Value::from(0), 0., 0.),
+                QuadraticRoots::One(x0) => (::Value::from(1), x0, 0.),
+                QuadraticRoots::Two(x0, x1) => (::Value::from(2), x0, x1),
+            }
+        }
+    }
+
+    pub mod cubic_equations {
+        use polynomials::cubic_equations::CubicRoots;
+
+        /// The old version returned the number of roots as a [`Value`](crate::Value).
+        #[deprecated(
+            note = "use `rgsl::polynomials::cubic_equations::poly_solve_cubic`, which returns the roots"
+        )]
+        pub fn poly_solve_cubic(a: f64, b: f64, c: f64) -> (::Value, f64, f64, f64) {
+            match ::polynomials::cubic_equations::poly_solve_cubic(a, b, c) {
+                CubicRoots::One(x0) => (::Value::from(1), x0, 0., 0.),
+                CubicRoots::Three(x0, x1, x2) => (::Value::from(3), x0, x1, x2),
+            }
+        }
+    }
+}
+
+pub mod power {
+    #[deprecated(note = "use `rgsl::power::pow_int_e`, which returns a `Result`")]
+    pub fn pow_int_e(x: f64, n: i32) -> (::Value, ::types::Result) {
+        ::legacy::split(::power::pow_int_e(x, n))
+    }
+}
+
+pub mod psi {
+    pub mod diagamma {
+        #[deprecated(note = "use `rgsl::psi::diagamma::psi_int_e`, which returns a `Result`")]
+        pub fn psi_int_e(n: i32) -> (::Value, ::types::Result) {
+            ::legacy::split(::psi::diagamma::psi_int_e(n))
+        }
+
+        #[deprecated(note = "use `rgsl::psi::diagamma::psi_e`, which returns a `Result`")]
+        pub fn psi_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::psi::diagamma::psi_e(x))
+        }
+
+        #[deprecated(note = "use `rgsl::psi::diagamma::psi_1piy_e`, which returns a `Result`")]
+        pub fn psi_1piy_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::psi::diagamma::psi_1piy_e(x))
+        }
+    }
+
+    pub mod polygamma {
+        #[deprecated(note = "use `rgsl::psi::polygamma::psi_n_e`, which returns a `Result`")]
+        pub fn psi_n_e(n: i32, x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::psi::polygamma::psi_n_e(n, x))
+        }
+    }
+
+    pub mod trigamma {
+        #[deprecated(note = "use `rgsl::psi::trigamma::psi_1_int_e`, which returns a `Result`")]
+        pub fn psi_1_int_e(n: i32) -> (::Value, ::types::Result) {
+            ::legacy::split(::psi::trigamma::psi_1_int_e(n))
+        }
+
+        #[deprecated(note = "use `rgsl::psi::trigamma::psi_1_e`, which returns a `Result`")]
+        pub fn psi_1_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::psi::trigamma::psi_1_e(x))
+        }
+    }
+}
+
+pub mod synchrotron {
+    #[deprecated(note = "use `rgsl::synchrotron::synchrotron_1_e`, which returns a `Result`")]
+    pub fn synchrotron_1_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::synchrotron::synchrotron_1_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::synchrotron::synchrotron_2_e`, which returns a `Result`")]
+    pub fn synchrotron_2_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::synchrotron::synchrotron_2_e(x))
+    }
+}
+
+pub mod transport {
+    #[deprecated(note = "use `rgsl::transport::transport_2_e`, which returns a `Result`")]
+    pub fn transport_2_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::transport::transport_2_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::transport::transport_3_e`, which returns a `Result`")]
+    pub fn transport_3_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::transport::transport_3_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::transport::transport_4_e`, which returns a `Result`")]
+    pub fn transport_4_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::transport::transport_4_e(x))
+    }
+
+    #[deprecated(note = "use `rgsl::transport::transport_5_e`, which returns a `Result`")]
+    pub fn transport_5_e(x: f64) -> (::Value, ::types::Result) {
+        ::legacy::split(::transport::transport_5_e(x))
+    }
+}
+
+pub mod zeta {
+    pub mod eta {
+        #[deprecated(note = "use `rgsl::zeta::eta::eta_int_e`, which returns a `Result`")]
+        pub fn eta_int_e(n: i32) -> (::Value, ::types::Result) {
+            ::legacy::split(::zeta::eta::eta_int_e(n))
+        }
+
+        #[deprecated(note = "use `rgsl::zeta::eta::eta_e`, which returns a `Result`")]
+        pub fn eta_e(s: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::zeta::eta::eta_e(s))
+        }
+    }
+
+    pub mod hurwitz {
+        #[deprecated(note = "use `rgsl::zeta::hurwitz::hzeta_e`, which returns a `Result`")]
+        pub fn hzeta_e(s: f64, q: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::zeta::hurwitz::hzeta_e(s, q))
+        }
+    }
+
+    pub mod riemann {
+        #[deprecated(note = "use `rgsl::zeta::riemann::zeta_int_e`, which returns a `Result`")]
+        pub fn zeta_int_e(n: i32) -> (::Value, ::types::Result) {
+            ::legacy::split(::zeta::riemann::zeta_int_e(n))
+        }
+
+        #[deprecated(note = "use `rgsl::zeta::riemann::zeta_e`, which returns a `Result`")]
+        pub fn zeta_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::zeta::riemann::zeta_e(x))
+        }
+    }
+
+    pub mod riemann_mins_one {
+        #[deprecated(
+            note = "use `rgsl::zeta::riemann_mins_one::zetam1_int_e`, which returns a `Result`"
+        )]
+        pub fn zetam1_int_e(n: i32) -> (::Value, ::types::Result) {
+            ::legacy::split(::zeta::riemann_mins_one::zetam1_int_e(n))
+        }
+
+        #[deprecated(
+            note = "use `rgsl::zeta::riemann_mins_one::zetam1_e`, which returns a `Result`"
+        )]
+        pub fn zetam1_e(x: f64) -> (::Value, ::types::Result) {
+            ::legacy::split(::zeta::riemann_mins_one::zetam1_e(x))
+        }
+    }
+}
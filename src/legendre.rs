@@ -5,8 +5,8 @@
 //! The Legendre Functions and Legendre Polynomials are described in Abramowitz & Stegun, Chapter 8.
 
 pub mod polynomials {
-    use crate::Value;
-    use std::mem::MaybeUninit;
+    use crate::Error;
+    use core::mem::MaybeUninit;
 
     /// This function evaluates the Legendre polynomials P_l(x) using explicit representations for l=1, 2, 3.
     #[doc(alias = "gsl_sf_legendre_P1")]
@@ -28,29 +28,41 @@ pub mod polynomials {
 
     /// This function evaluates the Legendre polynomials P_l(x) using explicit representations for l=1, 2, 3.
     #[doc(alias = "gsl_sf_legendre_P1_e")]
-    pub fn legendre_P1_e(x: f64) -> (Value, ::types::Result) {
+    pub fn legendre_P1_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_legendre_P1_e(x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_legendre_P1_e"
+        )
     }
 
     /// This function evaluates the Legendre polynomials P_l(x) using explicit representations for l=1, 2, 3.
     #[doc(alias = "gsl_sf_legendre_P2_e")]
-    pub fn legendre_P2_e(x: f64) -> (Value, ::types::Result) {
+    pub fn legendre_P2_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_legendre_P2_e(x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_legendre_P2_e"
+        )
     }
 
     /// This function evaluates the Legendre polynomials P_l(x) using explicit representations for l=1, 2, 3.
     #[doc(alias = "gsl_sf_legendre_P3_e")]
-    pub fn legendre_P3_e(x: f64) -> (Value, ::types::Result) {
+    pub fn legendre_P3_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_legendre_P3_e(x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_legendre_P3_e"
+        )
     }
 
     /// This function evaluates the Legendre polynomial P_l(x) for a specific value of l, x subject to l >= 0, |x| <= 1
@@ -61,36 +73,53 @@ pub mod polynomials {
 
     /// This function evaluates the Legendre polynomial P_l(x) for a specific value of l, x subject to l >= 0, |x| <= 1
     #[doc(alias = "gsl_sf_legendre_Pl_e")]
-    pub fn legendre_Pl_e(l: i32, x: f64) -> (Value, ::types::Result) {
+    pub fn legendre_Pl_e(l: i32, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_legendre_Pl_e(l, x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_legendre_Pl_e"
+        )
     }
 
     /// This function computes arrays of Legendre polynomials P_l(x) and derivatives dP_l(x)/dx, for l = 0, \dots, lmax, |x| <= 1
+    ///
+    /// lmax is `result_array.len() - 1`.
     #[doc(alias = "gsl_sf_legendre_Pl_array")]
-    pub fn legendre_Pl_array(x: f64, result_array: &mut [f64]) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sf_legendre_Pl_array(result_array.len() as i32, x, result_array.as_mut_ptr())
-        })
+    pub fn legendre_Pl_array(x: f64, result_array: &mut [f64]) -> Result<(), Error> {
+        assert!(!result_array.is_empty());
+        let ret = unsafe {
+            sys::gsl_sf_legendre_Pl_array(
+                result_array.len() as i32 - 1,
+                x,
+                result_array.as_mut_ptr(),
+            )
+        };
+        result_handler!(ret, (), "gsl_sf_legendre_Pl_array")
     }
 
     /// This function computes arrays of Legendre polynomials P_l(x) and derivatives dP_l(x)/dx, for l = 0, \dots, lmax, |x| <= 1
+    ///
+    /// lmax is `result_array.len() - 1`.
     #[doc(alias = "gsl_sf_legendre_Pl_deriv_array")]
     pub fn legendre_Pl_deriv_array(
         x: f64,
         result_array: &mut [f64],
         result_deriv_array: &mut [f64],
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        assert!(!result_array.is_empty());
+        assert!(result_deriv_array.len() >= result_array.len());
+        let ret = unsafe {
             sys::gsl_sf_legendre_Pl_deriv_array(
-                result_array.len() as _,
+                result_array.len() as i32 - 1,
                 x,
                 result_array.as_mut_ptr(),
                 result_deriv_array.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_sf_legendre_Pl_deriv_array")
     }
 
     /// This function computes the Legendre function Q_0(x) for x > -1, x != 1
@@ -101,11 +130,15 @@ pub mod polynomials {
 
     /// This function computes the Legendre function Q_0(x) for x > -1, x != 1
     #[doc(alias = "gsl_sf_legendre_Q0_e")]
-    pub fn legendre_Q0_e(x: f64) -> (Value, ::types::Result) {
+    pub fn legendre_Q0_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_legendre_Q0_e(x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_legendre_Q0_e"
+        )
     }
 
     /// This function computes the Legendre function Q_0(x) for x > -1, x != 1.
@@ -116,11 +149,15 @@ pub mod polynomials {
 
     /// This function computes the Legendre function Q_0(x) for x > -1, x != 1.
     #[doc(alias = "gsl_sf_legendre_Q1_e")]
-    pub fn legendre_Q1_e(x: f64) -> (Value, ::types::Result) {
+    pub fn legendre_Q1_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_legendre_Q1_e(x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_legendre_Q1_e"
+        )
     }
 
     /// This function computes the Legendre function Q_l(x) for x > -1, x != 1 and l >= 0.
@@ -131,11 +168,15 @@ pub mod polynomials {
 
     /// This function computes the Legendre function Q_l(x) for x > -1, x != 1 and l >= 0.
     #[doc(alias = "gsl_sf_legendre_Ql_e")]
-    pub fn legendre_Ql_e(l: i32, x: f64) -> (Value, ::types::Result) {
+    pub fn legendre_Ql_e(l: i32, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_legendre_Ql_e(l, x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_legendre_Ql_e"
+        )
     }
 }
 
@@ -147,8 +188,8 @@ pub mod polynomials {
 /// If you want to calculate a spherical harmonic, then do not use these functions. Instead use [`legendre_sphPlm`](fn.legendre_sphPlm.html) below, which uses a similar recursion, but with the normalized functions.
 pub mod associated_polynomials {
     use crate::enums;
-    use crate::Value;
-    use std::mem::MaybeUninit;
+    use crate::Error;
+    use core::mem::MaybeUninit;
 
     /// This routine computes the associated Legendre polynomial P_l^m(x) for m >= 0, l >= m, |x| <= 1.
     #[doc(alias = "gsl_sf_legendre_Plm")]
@@ -158,11 +199,15 @@ pub mod associated_polynomials {
 
     /// This routine computes the associated Legendre polynomial P_l^m(x) for m >= 0, l >= m, |x| <= 1.
     #[doc(alias = "gsl_sf_legendre_Plm_e")]
-    pub fn legendre_Plm_e(l: i32, m: i32, x: f64) -> (Value, ::types::Result) {
+    pub fn legendre_Plm_e(l: i32, m: i32, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_legendre_Plm_e(l, m, x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_legendre_Plm_e"
+        )
     }
 
     /// This routine computes the normalized associated Legendre polynomial \sqrt{(2l+1)/(4\pi)} \sqrt{(l-m)!/(l+m)!} P_l^m(x) suitable for use in spherical harmonics.
@@ -177,11 +222,15 @@ pub mod associated_polynomials {
     /// The parameters must satisfy m >= 0, l >= m, |x| <= 1.
     /// This routine avoids the overflows that occur for the standard normalization of P_l^m(x).
     #[doc(alias = "gsl_sf_legendre_sphPlm_e")]
-    pub fn legendre_sphPlm_e(l: i32, m: i32, x: f64) -> (Value, ::types::Result) {
+    pub fn legendre_sphPlm_e(l: i32, m: i32, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_legendre_sphPlm_e(l, m, x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_legendre_sphPlm_e"
+        )
     }
 
     /// Returns the size of the array needed for these functions, including GSL workspace.
@@ -190,23 +239,51 @@ pub mod associated_polynomials {
         unsafe { sys::gsl_sf_legendre_array_n(lmax as _) }
     }
 
+    /// Returns the index of P_l^m(x) in the arrays filled by [`legendre_array`] and the other
+    /// `legendre_*array` functions.
     #[doc(alias = "gsl_sf_legendre_array_index")]
     pub fn legendre_array_index(l: usize, m: usize) -> usize {
         unsafe { sys::gsl_sf_legendre_array_index(l as _, m as _) }
     }
 
+    /// This function calculates all normalized associated Legendre polynomials for 0 <= l <= lmax
+    /// and 0 <= m <= l for |x| <= 1. The norm parameter specifies which normalization is used.
+    /// The normalized P_l^m(x) values are stored in result, whose minimum size can be obtained
+    /// from [`legendre_array_n`]. Use [`legendre_array_index`] to find the position of a given
+    /// (l, m) in result.
+    ///
+    /// The Condon-Shortley phase factor (-1)^m is not included.
     #[doc(alias = "gsl_sf_legendre_array")]
     pub fn legendre_array(
         norm: enums::SfLegendreNorm,
         lmax: usize,
         x: f64,
         result: &mut [f64],
-    ) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sf_legendre_array(norm.into(), lmax, x, result.as_mut_ptr())
-        })
+    ) -> Result<(), Error> {
+        assert!(result.len() >= legendre_array_n(lmax));
+        let ret = unsafe { sys::gsl_sf_legendre_array(norm.into(), lmax, x, result.as_mut_ptr()) };
+        result_handler!(ret, (), "gsl_sf_legendre_array")
     }
 
+    /// Same as [`legendre_array`], but the phase factor (-1)^m is applied when csphase is -1.
+    /// csphase must be either 1 or -1.
+    #[doc(alias = "gsl_sf_legendre_array_e")]
+    pub fn legendre_array_e(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        csphase: f64,
+        result: &mut [f64],
+    ) -> Result<(), Error> {
+        assert!(result.len() >= legendre_array_n(lmax));
+        let ret = unsafe {
+            sys::gsl_sf_legendre_array_e(norm.into(), lmax, x, csphase, result.as_mut_ptr())
+        };
+        result_handler!(ret, (), "gsl_sf_legendre_array_e")
+    }
+
+    /// This function calculates all normalized associated Legendre functions as in
+    /// [`legendre_array`], and also stores the first derivatives dP_l^m(x)/dx in deriv.
     #[doc(alias = "gsl_sf_legendre_deriv_array")]
     pub fn legendre_deriv_array(
         norm: enums::SfLegendreNorm,
@@ -214,8 +291,11 @@ pub mod associated_polynomials {
         x: f64,
         result: &mut [f64],
         deriv: &mut [f64],
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let len = legendre_array_n(lmax);
+        assert!(result.len() >= len);
+        assert!(deriv.len() >= len);
+        let ret = unsafe {
             sys::gsl_sf_legendre_deriv_array(
                 norm.into(),
                 lmax,
@@ -223,14 +303,213 @@ pub mod associated_polynomials {
                 result.as_mut_ptr(),
                 deriv.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_sf_legendre_deriv_array")
+    }
+
+    /// Same as [`legendre_deriv_array`], but the phase factor (-1)^m is applied when csphase is
+    /// -1. csphase must be either 1 or -1.
+    #[doc(alias = "gsl_sf_legendre_deriv_array_e")]
+    pub fn legendre_deriv_array_e(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        csphase: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+    ) -> Result<(), Error> {
+        let len = legendre_array_n(lmax);
+        assert!(result.len() >= len);
+        assert!(deriv.len() >= len);
+        let ret = unsafe {
+            sys::gsl_sf_legendre_deriv_array_e(
+                norm.into(),
+                lmax,
+                x,
+                csphase,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+            )
+        };
+        result_handler!(ret, (), "gsl_sf_legendre_deriv_array_e")
+    }
+
+    /// This function calculates all normalized associated Legendre functions as in
+    /// [`legendre_array`], and also stores the first derivatives dP_l^m(\cos\theta)/d\theta in
+    /// deriv, where x = \cos\theta.
+    #[doc(alias = "gsl_sf_legendre_deriv_alt_array")]
+    pub fn legendre_deriv_alt_array(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+    ) -> Result<(), Error> {
+        let len = legendre_array_n(lmax);
+        assert!(result.len() >= len);
+        assert!(deriv.len() >= len);
+        let ret = unsafe {
+            sys::gsl_sf_legendre_deriv_alt_array(
+                norm.into(),
+                lmax,
+                x,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+            )
+        };
+        result_handler!(ret, (), "gsl_sf_legendre_deriv_alt_array")
+    }
+
+    /// Same as [`legendre_deriv_alt_array`], but the phase factor (-1)^m is applied when csphase is
+    /// -1. csphase must be either 1 or -1.
+    #[doc(alias = "gsl_sf_legendre_deriv_alt_array_e")]
+    pub fn legendre_deriv_alt_array_e(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        csphase: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+    ) -> Result<(), Error> {
+        let len = legendre_array_n(lmax);
+        assert!(result.len() >= len);
+        assert!(deriv.len() >= len);
+        let ret = unsafe {
+            sys::gsl_sf_legendre_deriv_alt_array_e(
+                norm.into(),
+                lmax,
+                x,
+                csphase,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+            )
+        };
+        result_handler!(ret, (), "gsl_sf_legendre_deriv_alt_array_e")
+    }
+
+    /// This function calculates all normalized associated Legendre functions as in
+    /// [`legendre_array`], and also stores the first and second derivatives with respect to x in
+    /// deriv and deriv2.
+    #[doc(alias = "gsl_sf_legendre_deriv2_array")]
+    pub fn legendre_deriv2_array(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+        deriv2: &mut [f64],
+    ) -> Result<(), Error> {
+        let len = legendre_array_n(lmax);
+        assert!(result.len() >= len);
+        assert!(deriv.len() >= len);
+        assert!(deriv2.len() >= len);
+        let ret = unsafe {
+            sys::gsl_sf_legendre_deriv2_array(
+                norm.into(),
+                lmax,
+                x,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+                deriv2.as_mut_ptr(),
+            )
+        };
+        result_handler!(ret, (), "gsl_sf_legendre_deriv2_array")
+    }
+
+    /// Same as [`legendre_deriv2_array`], but the phase factor (-1)^m is applied when csphase is
+    /// -1. csphase must be either 1 or -1.
+    #[doc(alias = "gsl_sf_legendre_deriv2_array_e")]
+    pub fn legendre_deriv2_array_e(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        csphase: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+        deriv2: &mut [f64],
+    ) -> Result<(), Error> {
+        let len = legendre_array_n(lmax);
+        assert!(result.len() >= len);
+        assert!(deriv.len() >= len);
+        assert!(deriv2.len() >= len);
+        let ret = unsafe {
+            sys::gsl_sf_legendre_deriv2_array_e(
+                norm.into(),
+                lmax,
+                x,
+                csphase,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+                deriv2.as_mut_ptr(),
+            )
+        };
+        result_handler!(ret, (), "gsl_sf_legendre_deriv2_array_e")
+    }
+
+    /// This function calculates all normalized associated Legendre functions as in
+    /// [`legendre_array`], and also stores the first and second derivatives with respect to
+    /// \theta, where x = \cos\theta, in deriv and deriv2.
+    #[doc(alias = "gsl_sf_legendre_deriv2_alt_array")]
+    pub fn legendre_deriv2_alt_array(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+        deriv2: &mut [f64],
+    ) -> Result<(), Error> {
+        let len = legendre_array_n(lmax);
+        assert!(result.len() >= len);
+        assert!(deriv.len() >= len);
+        assert!(deriv2.len() >= len);
+        let ret = unsafe {
+            sys::gsl_sf_legendre_deriv2_alt_array(
+                norm.into(),
+                lmax,
+                x,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+                deriv2.as_mut_ptr(),
+            )
+        };
+        result_handler!(ret, (), "gsl_sf_legendre_deriv2_alt_array")
+    }
+
+    /// Same as [`legendre_deriv2_alt_array`], but the phase factor (-1)^m is applied when csphase is
+    /// -1. csphase must be either 1 or -1.
+    #[doc(alias = "gsl_sf_legendre_deriv2_alt_array_e")]
+    pub fn legendre_deriv2_alt_array_e(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        csphase: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+        deriv2: &mut [f64],
+    ) -> Result<(), Error> {
+        let len = legendre_array_n(lmax);
+        assert!(result.len() >= len);
+        assert!(deriv.len() >= len);
+        assert!(deriv2.len() >= len);
+        let ret = unsafe {
+            sys::gsl_sf_legendre_deriv2_alt_array_e(
+                norm.into(),
+                lmax,
+                x,
+                csphase,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+                deriv2.as_mut_ptr(),
+            )
+        };
+        result_handler!(ret, (), "gsl_sf_legendre_deriv2_alt_array_e")
     }
 }
 
 /// The Conical Functions P^\mu_{-(1/2)+i\lambda}(x) and Q^\mu_{-(1/2)+i\lambda} are described in Abramowitz & Stegun, Section 8.12.
 pub mod conical {
-    use crate::Value;
-    use std::mem::MaybeUninit;
+    use crate::Error;
+    use core::mem::MaybeUninit;
 
     /// This routine computes the irregular Spherical Conical Function P^{1/2}_{-1/2 + i \lambda}(x) for x > -1.
     #[doc(alias = "gsl_sf_conicalP_half")]
@@ -240,11 +519,15 @@ pub mod conical {
 
     /// This routine computes the irregular Spherical Conical Function P^{1/2}_{-1/2 + i \lambda}(x) for x > -1.
     #[doc(alias = "gsl_sf_conicalP_half_e")]
-    pub fn half_e(lambda: f64, x: f64) -> (Value, ::types::Result) {
+    pub fn half_e(lambda: f64, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_conicalP_half_e(lambda, x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_conicalP_half_e"
+        )
     }
 
     /// This routine computes the regular Spherical Conical Function P^{-1/2}_{-1/2 + i \lambda}(x) for x > -1.
@@ -255,11 +538,15 @@ pub mod conical {
 
     /// This routine computes the regular Spherical Conical Function P^{-1/2}_{-1/2 + i \lambda}(x) for x > -1.
     #[doc(alias = "gsl_sf_conicalP_mhalf_e")]
-    pub fn mhalf_e(lambda: f64, x: f64) -> (Value, ::types::Result) {
+    pub fn mhalf_e(lambda: f64, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_conicalP_mhalf_e(lambda, x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_conicalP_mhalf_e"
+        )
     }
 
     /// This routine computes the conical function P^0_{-1/2 + i \lambda}(x) for x > -1.
@@ -270,11 +557,15 @@ pub mod conical {
 
     /// This routine computes the conical function P^0_{-1/2 + i \lambda}(x) for x > -1.
     #[doc(alias = "gsl_sf_conicalP_0_e")]
-    pub fn _0_e(lambda: f64, x: f64) -> (Value, ::types::Result) {
+    pub fn _0_e(lambda: f64, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_conicalP_0_e(lambda, x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_conicalP_0_e"
+        )
     }
 
     /// This routine computes the conical function P^1_{-1/2 + i \lambda}(x) for x > -1.
@@ -285,11 +576,15 @@ pub mod conical {
 
     /// This routine computes the conical function P^1_{-1/2 + i \lambda}(x) for x > -1.
     #[doc(alias = "gsl_sf_conicalP_1_e")]
-    pub fn _1_e(lambda: f64, x: f64) -> (Value, ::types::Result) {
+    pub fn _1_e(lambda: f64, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_conicalP_1_e(lambda, x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_conicalP_1_e"
+        )
     }
 
     /// This routine computes the Regular Spherical Conical Function P^{-1/2-l}_{-1/2 + i \lambda}(x) for x > -1, l >= -1.
@@ -300,11 +595,15 @@ pub mod conical {
 
     /// This routine computes the Regular Spherical Conical Function P^{-1/2-l}_{-1/2 + i \lambda}(x) for x > -1, l >= -1.
     #[doc(alias = "gsl_sf_conicalP_sph_reg_e")]
-    pub fn sph_reg_e(l: i32, lambda: f64, x: f64) -> (Value, ::types::Result) {
+    pub fn sph_reg_e(l: i32, lambda: f64, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_conicalP_sph_reg_e(l, lambda, x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_conicalP_sph_reg_e"
+        )
     }
 
     /// This routine computes the Regular Cylindrical Conical Function P^{-m}_{-1/2 + i \lambda}(x) for x > -1, m >= -1.
@@ -315,19 +614,23 @@ pub mod conical {
 
     /// This routine computes the Regular Cylindrical Conical Function P^{-m}_{-1/2 + i \lambda}(x) for x > -1, m >= -1.
     #[doc(alias = "gsl_sf_conicalP_cyl_reg_e")]
-    pub fn cyl_reg_e(m: i32, lambda: f64, x: f64) -> (Value, ::types::Result) {
+    pub fn cyl_reg_e(m: i32, lambda: f64, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_conicalP_cyl_reg_e(m, lambda, x, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_conicalP_cyl_reg_e"
+        )
     }
 }
 
 /// The following spherical functions are specializations of Legendre functions which give the regular eigenfunctions of the Laplacian on a 3-dimensional hyperbolic space H3d.
 /// Of particular interest is the flat limit, \lambda \to \infty, \eta \to 0, \lambda\eta fixed.
 pub mod radial {
-    use crate::Value;
-    use std::mem::MaybeUninit;
+    use crate::Error;
+    use core::mem::MaybeUninit;
 
     /// This routine computes the zeroth radial eigenfunction of the Laplacian on the 3-dimensional hyperbolic space, L^{H3d}_0(\lambda,\eta) := \sin(\lambda\eta)/(\lambda\sinh(\eta)) for \eta >= 0.
     /// In the flat limit this takes the form L^{H3d}_0(\lambda,\eta) = j_0(\lambda\eta).
@@ -339,11 +642,15 @@ pub mod radial {
     /// This routine computes the zeroth radial eigenfunction of the Laplacian on the 3-dimensional hyperbolic space, L^{H3d}_0(\lambda,\eta) := \sin(\lambda\eta)/(\lambda\sinh(\eta)) for \eta >= 0.
     /// In the flat limit this takes the form L^{H3d}_0(\lambda,\eta) = j_0(\lambda\eta).
     #[doc(alias = "gsl_sf_legendre_H3d_0_e")]
-    pub fn legendre_H3d_0_e(lambda: f64, eta: f64) -> (Value, ::types::Result) {
+    pub fn legendre_H3d_0_e(lambda: f64, eta: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_legendre_H3d_0_e(lambda, eta, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_legendre_H3d_0_e"
+        )
     }
 
     /// This routine computes the first radial eigenfunction of the Laplacian on the 3-dimensional hyperbolic space, L^{H3d}_1(\lambda,\eta) := 1/\sqrt{\lambda^2 + 1} \sin(\lambda \eta)/(\lambda \sinh(\eta))
@@ -358,11 +665,15 @@ pub mod radial {
     /// (\coth(\eta) - \lambda \cot(\lambda\eta)) for \eta >= 0.
     /// In the flat limit this takes the form L^{H3d}_1(\lambda,\eta) = j_1(\lambda\eta).
     #[doc(alias = "gsl_sf_legendre_H3d_1_e")]
-    pub fn legendre_H3d_1_e(lambda: f64, eta: f64) -> (Value, ::types::Result) {
+    pub fn legendre_H3d_1_e(lambda: f64, eta: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_legendre_H3d_1_e(lambda, eta, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_legendre_H3d_1_e"
+        )
     }
 
     /// This routine computes the l-th radial eigenfunction of the Laplacian on the 3-dimensional hyperbolic space \eta >= 0, l >= 0. In the flat limit this takes the form L^{H3d}_l(\lambda,\eta) = j_l(\lambda\eta).
@@ -373,23 +684,63 @@ pub mod radial {
 
     /// This routine computes the l-th radial eigenfunction of the Laplacian on the 3-dimensional hyperbolic space \eta >= 0, l >= 0. In the flat limit this takes the form L^{H3d}_l(\lambda,\eta) = j_l(\lambda\eta).
     #[doc(alias = "gsl_sf_legendre_H3d_e")]
-    pub fn legendre_H3d_e(l: i32, lambda: f64, eta: f64) -> (Value, ::types::Result) {
+    pub fn legendre_H3d_e(l: i32, lambda: f64, eta: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_legendre_H3d_e(l, lambda, eta, result.as_mut_ptr()) };
 
-        (Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_legendre_H3d_e"
+        )
     }
 
     /// This function computes an array of radial eigenfunctions L^{H3d}_l(\lambda, \eta) for 0 <= l <= lmax.
+    ///
+    /// lmax is `result_array.len() - 1`.
     #[doc(alias = "gsl_sf_legendre_H3d_array")]
-    pub fn legendre_H3d_array(lambda: f64, eta: f64, result_array: &mut [f64]) -> Value {
-        Value::from(unsafe {
+    pub fn legendre_H3d_array(
+        lambda: f64,
+        eta: f64,
+        result_array: &mut [f64],
+    ) -> Result<(), Error> {
+        assert!(!result_array.is_empty());
+        let ret = unsafe {
             sys::gsl_sf_legendre_H3d_array(
-                result_array.len() as _,
+                result_array.len() as i32 - 1,
                 lambda,
                 eta,
                 result_array.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_sf_legendre_H3d_array")
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn legendre_arrays() {
+    use self::associated_polynomials::{
+        legendre_Plm, legendre_array, legendre_array_index, legendre_array_n,
+    };
+    use self::polynomials::{legendre_Pl, legendre_Pl_array};
+
+    let x: f64 = 0.3;
+    let mut pl = [0.; 5];
+    legendre_Pl_array(x, &mut pl).unwrap();
+    for (l, v) in pl.iter().enumerate() {
+        assert!((v - legendre_Pl(l as i32, x)).abs() < 1e-12);
+    }
+
+    let lmax = 4;
+    let mut plm = vec![0.; legendre_array_n(lmax)];
+    legendre_array(::enums::SfLegendreNorm::None, lmax, x, &mut plm).unwrap();
+    for l in 0..=lmax {
+        for m in 0..=l {
+            let expected = legendre_Plm(l as i32, m as i32, x);
+            // `legendre_array` doesn't include the Condon-Shortley phase.
+            let expected = if m % 2 == 1 { -expected } else { expected };
+            assert!((plm[legendre_array_index(l, m)] - expected).abs() < 1e-12);
+        }
     }
 }
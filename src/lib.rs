@@ -13,6 +13,67 @@
 //! You can access the latest version of the documentation via the internet
 //! [here](https://docs.rs/crate/GSL/).
 //!
+//! ## Error handling
+//!
+//! Wrappers of GSL functions which can fail return a `Result` with an [`Error`] describing what
+//! went wrong. However, GSL's default error handler aborts the program before the error can be
+//! returned, so you should call [`error::init`] before using the crate:
+//!
+//! ```
+//! rgsl::error::init();
+//!
+//! match rgsl::bessel::K0_e(1e3) {
+//!     Ok(r) => println!("{}", r.val),
+//!     Err(e) => println!("{}", e),
+//! }
+//! ```
+//!
+//! The convergence tests return a [`ConvergenceStatus`] telling whether the iteration can stop,
+//! and the functions stepping through sequences, like [`Permutation::next`], return a
+//! [`Value::Failure`] error after the last element.
+//!
+//! See the [`error`] module to install your own error handler instead, or
+//! [`error::trap`] to get the reason, file and line GSL reported along with the error.
+//!
+//! The closures given to GSL (integrands, solver functions, etc.) may panic: the panic is caught
+//! before it reaches the GSL code and is resumed once the GSL function returns.
+//!
+//! ## Thread safety
+//!
+//! The types owning their GSL object (vectors, matrices, random number generators, workspaces,
+//! etc.) are `Send`, so they can be moved to another thread (with `std::thread`, `rayon` or
+//! `tokio` for example). They are not `Sync` though: most GSL objects are modified even by the
+//! functions which look like they only read them (the random number generators or the
+//! interpolation accelerators for example), so every thread needs its own. A
+//! [`Rng`] can be cloned for that. The workspaces can be reused by several threads with a
+//! [`SharedWorkspacePool`].
+//!
+//! With the `rayon` feature, the [`parallel`] module provides helpers to evaluate functions over
+//! many points in parallel, creating the generators or accelerators each thread needs.
+//!
+//! The types describing an algorithm (like [`RngType`]) only point to immutable
+//! GSL data, so they are both `Send` and `Sync`. So is [`Spline`], since it isn't modified when
+//! it is evaluated: only its [`InterpAccel`] is.
+//!
+//! Some types can't be moved to another thread at all:
+//!
+//!  * the views, which borrow the vector or matrix they come from;
+//!  * the solvers keeping the closure they were given, like [`RootFSolver`]
+//!    or [`Minimizer`], since the closure might not be `Send` itself;
+//!  * [`MultiFitFdfSolver`], because GSL keeps a pointer to the function it was given. The
+//!    vectors it returns are views borrowing the solver, and its callbacks only get references
+//!    to the vectors and matrices pointing to the solver's memory.
+//!
+//! Note that the error handler (see the [`error`] module) is global to the program.
+//!
+//! ## `no_std`
+//!
+//! The `std` feature is enabled by default. Without it, the crate is `no_std` and only provides
+//! the modules which don't need it: the special functions, the physical constants, [`ComplexF64`]
+//! and [`ComplexF32`], the [`Error`] type and the [`stats`] module. The `alloc` feature adds the
+//! [`statistics`] module, whose functions allocate their workspaces. Everything else (the
+//! vectors and matrices, the solvers, the file I/O and the custom error handlers) requires `std`.
+//!
 //! ## Examples
 //!
 //! Examples are available in the `examples` folder. Don't hesitate to take a look!
@@ -27,7 +88,7 @@
 
 #![crate_name = "rgsl"]
 #![crate_type = "rlib"]
-#![crate_type = "dylib"]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "dox", feature(doc_cfg))]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -37,16 +98,119 @@
 #![allow(clippy::should_implement_trait)]
 #![allow(clippy::type_complexity)]
 
+// The `gsl_2_*` cfgs are set by the build script from the version of the installed GSL library.
+#[cfg(all(
+    feature = "v2_1",
+    gsl_version_detected,
+    not(gsl_2_1),
+    not(feature = "dox")
+))]
+compile_error!(concat!(
+    "the `v2_1` feature requires GSL >= 2.1, but GSL ",
+    env!("GSL_VERSION_DETECTED"),
+    " was found"
+));
+#[cfg(all(
+    feature = "v2_2",
+    gsl_version_detected,
+    not(gsl_2_2),
+    not(feature = "dox")
+))]
+compile_error!(concat!(
+    "the `v2_2` feature requires GSL >= 2.2, but GSL ",
+    env!("GSL_VERSION_DETECTED"),
+    " was found"
+));
+#[cfg(all(
+    feature = "v2_3",
+    gsl_version_detected,
+    not(gsl_2_3),
+    not(feature = "dox")
+))]
+compile_error!(concat!(
+    "the `v2_3` feature requires GSL >= 2.3, but GSL ",
+    env!("GSL_VERSION_DETECTED"),
+    " was found"
+));
+#[cfg(all(
+    feature = "v2_4",
+    gsl_version_detected,
+    not(gsl_2_4),
+    not(feature = "dox")
+))]
+compile_error!(concat!(
+    "the `v2_4` feature requires GSL >= 2.4, but GSL ",
+    env!("GSL_VERSION_DETECTED"),
+    " was found"
+));
+#[cfg(all(
+    feature = "v2_5",
+    gsl_version_detected,
+    not(gsl_2_5),
+    not(feature = "dox")
+))]
+compile_error!(concat!(
+    "the `v2_5` feature requires GSL >= 2.5, but GSL ",
+    env!("GSL_VERSION_DETECTED"),
+    " was found"
+));
+#[cfg(all(
+    feature = "v2_6",
+    gsl_version_detected,
+    not(gsl_2_6),
+    not(feature = "dox")
+))]
+compile_error!(concat!(
+    "the `v2_6` feature requires GSL >= 2.6, but GSL ",
+    env!("GSL_VERSION_DETECTED"),
+    " was found"
+));
+#[cfg(all(
+    feature = "v2_7",
+    gsl_version_detected,
+    not(gsl_2_7),
+    not(feature = "dox")
+))]
+compile_error!(concat!(
+    "the `v2_7` feature requires GSL >= 2.7, but GSL ",
+    env!("GSL_VERSION_DETECTED"),
+    " was found"
+));
+
+// The modules which are available without `std` use `core` and `alloc` directly.
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate core;
 extern crate gsl_sys as sys;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "ndarray")]
+extern crate ndarray;
+#[cfg(feature = "num-complex")]
+extern crate num_complex;
 extern crate paste;
+#[cfg(feature = "rand")]
+extern crate rand_core;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "uom")]
+extern crate uom;
 
 pub use types::*;
 
 pub use elementary::Elementary;
+pub use error::Error;
 pub use pow::Pow;
 pub use trigonometric::Trigonometric;
+#[cfg(feature = "std")]
 pub use types::rng;
+#[cfg(feature = "std")]
 pub use utilities::IOStream;
+#[cfg(feature = "std")]
+pub use workspace_pool::{PooledWorkspace, SharedWorkspacePool, WorkspacePool};
 
 // enums part
 pub use self::enums::*;
@@ -55,22 +219,32 @@ mod enums;
 mod macros;
 mod utilities;
 
+#[cfg(feature = "std")]
 #[doc(hidden)]
 pub mod ffi;
 
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod randist;
 pub mod types;
 
 pub mod airy;
 pub mod bessel;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod blas;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod cblas;
 pub mod clausen;
+pub mod consts;
 pub mod coulomb;
 pub mod coupling_coefficients;
 pub mod dawson;
 pub mod debye;
 pub mod dilogarithm;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod eigen;
 pub mod elementary;
 pub mod elementary_operations;
@@ -79,45 +253,108 @@ pub mod error;
 pub mod exponential;
 pub mod exponential_integrals;
 pub mod fermi_dirac;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod fft;
-#[cfg(feature = "v2_5")]
-#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[cfg(all(feature = "std", feature = "v2_5"))]
+#[cfg_attr(feature = "dox", doc(cfg(all(feature = "std", feature = "v2_5"))))]
 pub mod filter;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod fit;
 pub mod gamma_beta;
 pub mod gegenbauer;
 pub mod hypergeometric;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub mod ieee;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod integration;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod interpolation;
 pub mod jacobian_elliptic;
 pub mod laguerre;
 pub mod lambert_w;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub mod legacy;
 pub mod legendre;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod linear_algebra;
 pub mod logarithm;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod minimizer;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod multifit;
-#[cfg(feature = "v2_1")]
-#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
+#[cfg(all(feature = "std", feature = "v2_1"))]
+#[cfg_attr(feature = "dox", doc(cfg(all(feature = "std", feature = "v2_1"))))]
 pub mod multilarge;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod multilinear;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub mod multimin;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub mod multiroot;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod numerical_differentiation;
+#[cfg(feature = "rayon")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+pub mod parallel;
 pub mod physical_constant;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod polynomials;
 pub mod pow;
 pub mod power;
 pub mod psi;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod roots;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod sort;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub mod spblas;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "alloc")))]
 pub mod statistics;
 pub mod stats;
 pub mod synchrotron;
 pub mod transport;
 pub mod trigonometric;
 pub mod util;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod wavelet_transforms;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub mod workspace_pool;
 pub mod zeta;
 
+/// Returns the version of the GSL library this program is linked to.
+///
+/// ```
+/// println!("GSL version: {}", rgsl::version());
+/// ```
+#[doc(alias = "gsl_version")]
+pub fn version() -> &'static str {
+    unsafe {
+        ::core::ffi::CStr::from_ptr(sys::gsl_version)
+            .to_str()
+            .unwrap_or("unknown")
+    }
+}
+
 /// The maximum x such that gamma(x) is not considered an overflow.
 pub static SF_GAMMA_XMAX: f64 = 171.0;
 /// The maximum n such that gsl_sf_fact(n) does not give an overflow.
@@ -141,7 +378,7 @@ pub static ROOT4_DBL_MIN: f64 = 1.221_338_669_755_462_0e-77;
 pub static ROOT5_DBL_MIN: f64 = 2.947_602_296_969_176_3e-62;
 pub static ROOT6_DBL_MIN: f64 = 5.303_436_890_579_821_8e-52;
 
-pub static DBL_MAX: f64 = ::std::f64::MAX; //1.7976931348623156e+308;
+pub static DBL_MAX: f64 = f64::MAX; //1.7976931348623156e+308;
 pub static SQRT_DBL_MAX: f64 = 1.340_780_792_994_259_6e+154;
 pub static ROOT3_DBL_MAX: f64 = 5.643_803_094_122_289_7e+102;
 pub static ROOT4_DBL_MAX: f64 = 1.157_920_892_373_162_0e+77;
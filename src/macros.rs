@@ -4,6 +4,7 @@
 
 #![macro_use]
 
+#[cfg(feature = "std")]
 #[doc(hidden)]
 macro_rules! ffi_wrap {
     ($name:tt) => {
@@ -11,26 +12,87 @@ macro_rules! ffi_wrap {
     };
 }
 
+/// Converts the status code `$ret` returned by the GSL function `$func` into a
+/// `Result<_, Error>`, `$value` being only evaluated on success.
+#[doc(hidden)]
+macro_rules! result_handler {
+    ($ret:expr, $value:expr, $func:expr) => {{
+        let ret = $ret;
+        // A panic in a callback is resumed once GSL returned, and one in the error handler if it
+        // was raised for the error GSL returned.
+        $crate::error::resume_callback_panic();
+        if ret == sys::GSL_SUCCESS {
+            Ok($value)
+        } else {
+            $crate::error::resume_handler_panic(ret);
+            Err($crate::Error::new($crate::Value::from(ret), $func))
+        }
+    }};
+}
+
+#[cfg(feature = "std")]
 #[doc(hidden)]
 macro_rules! wrap_callback {
     ($f:expr, $F:ident) => {{
-        unsafe extern "C" fn trampoline<F: Fn(f64) -> f64>(
+        unsafe extern "C" fn trampoline<F: FnMut(f64) -> f64>(
             x: f64,
             params: *mut ::std::os::raw::c_void,
         ) -> f64 {
-            let f: &F = &*(params as *const F);
-            f(x)
+            let f: &mut F = &mut *(params as *mut F);
+            $crate::error::catch_callback(f64::NAN, || f(x))
         }
 
         sys::gsl_function_struct {
             function: Some(trampoline::<$F>),
-            params: &$f as *const _ as *mut _,
+            params: &mut $f as *mut _ as *mut _,
         }
     }};
 }
 
+// `Send` and `Sync` aren't implemented here: whether a wrapper can be sent to another thread
+// depends on whether non-owning instances of it can be created, so it is done next to each type.
+#[cfg(feature = "std")]
 #[doc(hidden)]
 macro_rules! ffi_wrapper {
+    // For the GSL objects keeping a pointer to the function they were given: it is stored in the
+    // `function` field so it lives as long as the object uses it.
+    ($name:ident<$lt:lifetime>, *mut $ty:ty, $drop:ident, $function:ty $(, $doc:expr)?) => {
+        $(#[doc = $doc])?
+        pub struct $name<$lt> {
+            inner: *mut $ty,
+            function: Option<$function>,
+        }
+
+        impl<$lt> FFI<$ty> for $name<$lt> {
+            fn wrap(inner: *mut $ty) -> Self {
+                Self {
+                    inner,
+                    function: None,
+                }
+            }
+
+            fn soft_wrap(r: *mut $ty) -> Self {
+                Self::wrap(r)
+            }
+
+            #[inline]
+            fn unwrap_shared(&self) -> *const $ty {
+                self.inner as *const _
+            }
+
+            #[inline]
+            fn unwrap_unique(&mut self) -> *mut $ty {
+                self.inner
+            }
+        }
+
+        impl<$lt> Drop for $name<$lt> {
+            fn drop(&mut self) {
+                unsafe { sys::$drop(self.inner) };
+                self.inner = ::std::ptr::null_mut();
+            }
+        }
+    };
     ($name:ident, *mut $ty:ty, $drop:ident $(, $doc:expr)?) => {
         ffi_wrapper!($name, *mut $ty $(, $doc)?);
 
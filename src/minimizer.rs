@@ -2,10 +2,11 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::Value;
+use crate::{ConvergenceStatus, Error};
 
 /// This function tests for the convergence of the interval [x_lower, x_upper] with absolute error epsabs and relative error epsrel. The
-/// test returns ::Value::Success if the following condition is achieved,
+/// test returns [`ConvergenceStatus::Converged`] if the following condition is achieved (and
+/// [`ConvergenceStatus::Continue`] otherwise),
 ///
 /// ```text
 /// |a - b| < epsabs + epsrel min(|a|,|b|)
@@ -24,6 +25,14 @@ use crate::Value;
 ///
 /// assuming that the true minimum x_m^* is contained within the interval.
 #[doc(alias = "gsl_min_test_interval")]
-pub fn test_interval(x_lower: f64, x_upper: f64, epsabs: f64, epsrel: f64) -> Value {
-    Value::from(unsafe { sys::gsl_min_test_interval(x_lower, x_upper, epsabs, epsrel) })
+pub fn test_interval(
+    x_lower: f64,
+    x_upper: f64,
+    epsabs: f64,
+    epsrel: f64,
+) -> Result<ConvergenceStatus, Error> {
+    ConvergenceStatus::from_test(
+        unsafe { sys::gsl_min_test_interval(x_lower, x_upper, epsabs, epsrel) },
+        "gsl_min_test_interval",
+    )
 }
@@ -2,57 +2,93 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::{MatrixF64, Value, VectorF64};
+use crate::{
+    ConvergenceStatus, Error, MatrixF64, MultifitLinearWorkspace, VectorF64, WorkspacePool,
+};
 use ffi::FFI;
 
 /// Compute the covariance matrix cov = inv (J^T J) by QRP^T decomposition of J
 #[doc(alias = "gsl_multifit_covar")]
-pub fn covar(J: &MatrixF64, epsrel: f64, covar: &mut MatrixF64) -> Value {
-    Value::from(unsafe {
-        sys::gsl_multifit_covar(J.unwrap_shared(), epsrel, covar.unwrap_unique())
-    })
+pub fn covar(J: &MatrixF64, epsrel: f64, covar: &mut MatrixF64) -> Result<(), Error> {
+    let ret = unsafe { sys::gsl_multifit_covar(J.unwrap_shared(), epsrel, covar.unwrap_unique()) };
+    result_handler!(ret, (), "gsl_multifit_covar")
 }
 
+/// Tests for the convergence of the sequence by comparing the last step `dx` with the absolute
+/// error `epsabs` and relative error `epsrel` to the current position `x`. It has converged when
+/// |dx_i| < epsabs + epsrel |x_i| for each component of `x`.
 #[doc(alias = "gsl_multifit_test_delta")]
-pub fn test_delta(dx: &VectorF64, x: &VectorF64, epsabs: f64, epsrel: f64) -> Value {
-    Value::from(unsafe {
-        sys::gsl_multifit_test_delta(dx.unwrap_shared(), x.unwrap_shared(), epsabs, epsrel)
-    })
+pub fn test_delta(
+    dx: &VectorF64,
+    x: &VectorF64,
+    epsabs: f64,
+    epsrel: f64,
+) -> Result<ConvergenceStatus, Error> {
+    ConvergenceStatus::from_test(
+        unsafe {
+            sys::gsl_multifit_test_delta(dx.unwrap_shared(), x.unwrap_shared(), epsabs, epsrel)
+        },
+        "gsl_multifit_test_delta",
+    )
 }
 
 #[doc(alias = "gsl_multifit_gradient")]
-pub fn gradient(J: &MatrixF64, f: &VectorF64, g: &mut VectorF64) -> Value {
-    Value::from(unsafe {
+pub fn gradient(J: &MatrixF64, f: &VectorF64, g: &mut VectorF64) -> Result<(), Error> {
+    let ret = unsafe {
         sys::gsl_multifit_gradient(J.unwrap_shared(), f.unwrap_shared(), g.unwrap_unique())
-    })
+    };
+    result_handler!(ret, (), "gsl_multifit_gradient")
 }
 
 #[doc(alias = "gsl_multifit_linear_lreg")]
-pub fn linear_lreg(smin: f64, smax: f64, reg_param: &mut VectorF64) -> Value {
-    Value::from(unsafe { sys::gsl_multifit_linear_lreg(smin, smax, reg_param.unwrap_unique()) })
+pub fn linear_lreg(smin: f64, smax: f64, reg_param: &mut VectorF64) -> Result<(), Error> {
+    let ret = unsafe { sys::gsl_multifit_linear_lreg(smin, smax, reg_param.unwrap_unique()) };
+    result_handler!(ret, (), "gsl_multifit_linear_lreg")
 }
 
-/// Returns `(Value, idx)`.
+/// Returns `idx`.
 #[doc(alias = "gsl_multifit_linear_lcorner")]
-pub fn linear_lcorner(rho: &VectorF64, eta: &VectorF64) -> (Value, usize) {
+pub fn linear_lcorner(rho: &VectorF64, eta: &VectorF64) -> Result<usize, Error> {
     let mut idx = 0;
     let ret = unsafe {
         sys::gsl_multifit_linear_lcorner(rho.unwrap_shared(), eta.unwrap_shared(), &mut idx)
     };
-    (Value::from(ret), idx)
+    result_handler!(ret, idx, "gsl_multifit_linear_lcorner")
 }
 
-/// Returns `(Value, idx)`.
+/// Returns `idx`.
 #[doc(alias = "gsl_multifit_linear_lcorner2")]
-pub fn linear_lcorner2(rho: &VectorF64, eta: &VectorF64) -> (Value, usize) {
+pub fn linear_lcorner2(rho: &VectorF64, eta: &VectorF64) -> Result<usize, Error> {
     let mut idx = 0;
     let ret = unsafe {
         sys::gsl_multifit_linear_lcorner2(rho.unwrap_shared(), eta.unwrap_shared(), &mut idx)
     };
-    (Value::from(ret), idx)
+    result_handler!(ret, idx, "gsl_multifit_linear_lcorner2")
 }
 
 #[doc(alias = "gsl_multifit_linear_Lk")]
-pub fn linear_Lk(p: usize, k: usize, L: &mut MatrixF64) -> Value {
-    Value::from(unsafe { sys::gsl_multifit_linear_Lk(p, k, L.unwrap_unique()) })
+pub fn linear_Lk(p: usize, k: usize, L: &mut MatrixF64) -> Result<(), Error> {
+    let ret = unsafe { sys::gsl_multifit_linear_Lk(p, k, L.unwrap_unique()) };
+    result_handler!(ret, (), "gsl_multifit_linear_Lk")
+}
+
+/// This function computes the best-fit parameters `c` of the model y = X c for the observations
+/// `y` and the matrix of predictor variables `X`. The variance-covariance matrix of the model
+/// parameters `cov` is computed too.
+///
+/// Same as [`MultifitLinearWorkspace::linear`] with a workspace taken from the thread's
+/// [`WorkspacePool`].
+///
+/// Returns `chisq`.
+#[doc(alias = "gsl_multifit_linear")]
+pub fn linear(
+    X: &MatrixF64,
+    y: &VectorF64,
+    c: &mut VectorF64,
+    cov: &mut MatrixF64,
+) -> Result<f64, Error> {
+    WorkspacePool::<MultifitLinearWorkspace>::with((X.size1(), X.size2()), |w| {
+        w.linear(X, y, c, cov)
+    })
+    .and_then(|r| r)
 }
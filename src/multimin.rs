@@ -0,0 +1,33 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+use crate::{ConvergenceStatus, Error};
+use ffi::FFI;
+
+/// This function tests the norm of the gradient `g` against the absolute tolerance `epsabs`.
+/// The gradient of a multidimensional function goes to zero at a minimum. The test returns
+/// [`ConvergenceStatus::Converged`] if the following condition is achieved,
+///
+/// |g| < epsabs
+///
+/// and returns [`ConvergenceStatus::Continue`] otherwise.
+#[doc(alias = "gsl_multimin_test_gradient")]
+pub fn test_gradient(g: &::VectorF64, epsabs: f64) -> Result<ConvergenceStatus, Error> {
+    ConvergenceStatus::from_test(
+        unsafe { sys::gsl_multimin_test_gradient(g.unwrap_shared(), epsabs) },
+        "gsl_multimin_test_gradient",
+    )
+}
+
+/// This function tests the minimizer specific characteristic size (if applicable to the used
+/// minimizer) against the absolute tolerance `epsabs`. The test returns
+/// [`ConvergenceStatus::Converged`] if the size is smaller than tolerance, otherwise
+/// [`ConvergenceStatus::Continue`] is returned.
+#[doc(alias = "gsl_multimin_test_size")]
+pub fn test_size(size: f64, epsabs: f64) -> Result<ConvergenceStatus, Error> {
+    ConvergenceStatus::from_test(
+        unsafe { sys::gsl_multimin_test_size(size, epsabs) },
+        "gsl_multimin_test_size",
+    )
+}
@@ -0,0 +1,42 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+use crate::{ConvergenceStatus, Error};
+use ffi::FFI;
+
+/// This function tests for the convergence of the sequence by comparing the last step `dx` with
+/// the absolute error `epsabs` and relative error `epsrel` to the current position `x`. The test
+/// returns [`ConvergenceStatus::Converged`] if the following condition is achieved,
+///
+/// |dx_i| < epsabs + epsrel |x_i|
+///
+/// for each component of `x` and returns [`ConvergenceStatus::Continue`] otherwise.
+#[doc(alias = "gsl_multiroot_test_delta")]
+pub fn test_delta(
+    dx: &::VectorF64,
+    x: &::VectorF64,
+    epsabs: f64,
+    epsrel: f64,
+) -> Result<ConvergenceStatus, Error> {
+    ConvergenceStatus::from_test(
+        unsafe {
+            sys::gsl_multiroot_test_delta(dx.unwrap_shared(), x.unwrap_shared(), epsabs, epsrel)
+        },
+        "gsl_multiroot_test_delta",
+    )
+}
+
+/// This function tests the residual value `f` against the absolute error bound `epsabs`. The
+/// test returns [`ConvergenceStatus::Converged`] if the following condition is achieved,
+///
+/// \sum_i |f_i| < epsabs
+///
+/// and returns [`ConvergenceStatus::Continue`] otherwise.
+#[doc(alias = "gsl_multiroot_test_residual")]
+pub fn test_residual(f: &::VectorF64, epsabs: f64) -> Result<ConvergenceStatus, Error> {
+    ConvergenceStatus::from_test(
+        unsafe { sys::gsl_multiroot_test_residual(f.unwrap_shared(), epsabs) },
+        "gsl_multiroot_test_residual",
+    )
+}
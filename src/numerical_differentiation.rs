@@ -16,7 +16,7 @@ Abramowitz and Stegun, Handbook of Mathematical Functions, Section 25.3.4, and T
 S.D. Conte and Carl de Boor, Elementary Numerical Analysis: An Algorithmic Approach, McGraw-Hill, 1972.
 !*/
 
-use crate::Value;
+use crate::Error;
 
 /// This function computes the numerical derivative of the function f at the point x using an
 /// adaptive central difference algorithm with a step-size of h. The derivative is returned in
@@ -31,13 +31,13 @@ use crate::Value;
 ///
 /// Returns `(result, abs_err)`.
 #[doc(alias = "gsl_deriv_central")]
-pub fn deriv_central<F: Fn(f64) -> f64>(f: F, x: f64, h: f64) -> (Value, f64, f64) {
+pub fn deriv_central<F: FnMut(f64) -> f64>(mut f: F, x: f64, h: f64) -> Result<(f64, f64), Error> {
     let mut result = 0.;
     let mut abs_err = 0.;
     let function = wrap_callback!(f, F);
 
     let ret = unsafe { sys::gsl_deriv_central(&function, x, h, &mut result, &mut abs_err) };
-    (::Value::from(ret), result, abs_err)
+    result_handler!(ret, (result, abs_err), "gsl_deriv_central")
 }
 
 /// This function computes the numerical derivative of the function f at the point x using an
@@ -54,13 +54,13 @@ pub fn deriv_central<F: Fn(f64) -> f64>(f: F, x: f64, h: f64) -> (Value, f64, f6
 ///
 /// Returns `(result, abs_err)`.
 #[doc(alias = "gsl_deriv_forward")]
-pub fn deriv_forward<F: Fn(f64) -> f64>(f: F, x: f64, h: f64) -> (Value, f64, f64) {
+pub fn deriv_forward<F: FnMut(f64) -> f64>(mut f: F, x: f64, h: f64) -> Result<(f64, f64), Error> {
     let mut result = 0.;
     let mut abs_err = 0.;
     let function = wrap_callback!(f, F);
 
     let ret = unsafe { sys::gsl_deriv_forward(&function, x, h, &mut result, &mut abs_err) };
-    (::Value::from(ret), result, abs_err)
+    result_handler!(ret, (result, abs_err), "gsl_deriv_forward")
 }
 
 /// This function computes the numerical derivative of the function f at the point x using an
@@ -73,11 +73,82 @@ pub fn deriv_forward<F: Fn(f64) -> f64>(f: F, x: f64, h: f64) -> (Value, f64, f6
 ///
 /// Returns `(result, abs_err)`.
 #[doc(alias = "gsl_deriv_backward")]
-pub fn deriv_backward<F: Fn(f64) -> f64>(f: F, x: f64, h: f64) -> (Value, f64, f64) {
+pub fn deriv_backward<F: FnMut(f64) -> f64>(mut f: F, x: f64, h: f64) -> Result<(f64, f64), Error> {
     let mut result = 0.;
     let mut abs_err = 0.;
     let function = wrap_callback!(f, F);
 
     let ret = unsafe { sys::gsl_deriv_backward(&function, x, h, &mut result, &mut abs_err) };
-    (::Value::from(ret), result, abs_err)
+    result_handler!(ret, (result, abs_err), "gsl_deriv_backward")
+}
+
+/// Returns a step size suited to a central difference at `x`: the cube root of the machine
+/// epsilon, which balances truncation and round-off errors, scaled by the magnitude of `x`. The
+/// step is adjusted so that `x + h` is exactly representable.
+fn central_step(x: f64) -> f64 {
+    let h = f64::EPSILON.cbrt() * x.abs().max(1.);
+    (x + h) - x
+}
+
+/// This function computes the derivative of `f` at `x` with [`deriv_central`], picking the
+/// initial step size from the machine epsilon and the magnitude of `x`.
+///
+/// Returns `(result, abs_err)`.
+#[doc(alias = "gsl_deriv_central")]
+pub fn derivative<F: FnMut(f64) -> f64>(f: F, x: f64) -> Result<(f64, f64), Error> {
+    deriv_central(f, x, central_step(x))
+}
+
+/// This function computes the derivative of `f` at `x` by Richardson extrapolation of central
+/// differences (Ridders' method). The step size starts at `h` and is halved at each level, at
+/// most ten times. Extrapolation stops as soon as the error starts growing again.
+///
+/// Unlike [`derivative`], `h` should not be tiny: the extrapolation removes the truncation error,
+/// so a step of the order of the scale on which `f` varies gives the best results.
+///
+/// Returns `(result, abs_err)`.
+pub fn derivative_richardson<F: FnMut(f64) -> f64>(mut f: F, x: f64, h: f64) -> (f64, f64) {
+    const LEVELS: usize = 10;
+
+    assert!(h != 0.);
+    let mut central = |h: f64| (f(x + h) - f(x - h)) / (2. * h);
+    let mut h = h;
+    // Only the last two rows of the extrapolation table are needed.
+    let mut prev = vec![central(h)];
+    let mut result = prev[0];
+    let mut abs_err = f64::MAX;
+
+    for i in 1..LEVELS {
+        h /= 2.;
+        let mut row = Vec::with_capacity(i + 1);
+        row.push(central(h));
+        let mut fac = 4.;
+        for j in 1..=i {
+            let v = (row[j - 1] * fac - prev[j - 1]) / (fac - 1.);
+            fac *= 4.;
+            let err = (v - row[j - 1]).abs().max((v - prev[j - 1]).abs());
+            if err <= abs_err {
+                abs_err = err;
+                result = v;
+            }
+            row.push(v);
+        }
+        if (row[i] - prev[i - 1]).abs() >= 2. * abs_err {
+            break;
+        }
+        prev = row;
+    }
+    (result, abs_err)
+}
+
+#[test]
+fn derivative_helpers() {
+    let (d, err) = derivative(f64::sin, 1.).unwrap();
+    assert!((d - 1f64.cos()).abs() < 1e-8 && err < 1e-6);
+
+    let (d, err) = derivative(|x| x * x, 1e6).unwrap();
+    assert!((d - 2e6).abs() < 1e-6 * 2e6 && err < 1.);
+
+    let (d, err) = derivative_richardson(f64::exp, 0., 0.5);
+    assert!((d - 1.).abs() < 1e-12 && err < 1e-10);
 }
@@ -0,0 +1,103 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Parallel helpers
+
+Helpers to evaluate GSL functions over many points with [`rayon`]. They are only available with
+the `rayon` feature.
+
+Most special functions only take `f64` arguments and don't keep any state, so any of them can be
+given to [`map`] or [`apply`]:
+
+```
+use rgsl::{bessel, parallel};
+
+let xs: Vec<f64> = (0..1000).map(|i| i as f64 / 10.).collect();
+let ys = parallel::map(&xs, bessel::J0);
+assert_eq!(ys[0], 1.);
+assert_eq!(ys[10], bessel::J0(1.));
+```
+
+The objects which are modified during the evaluation (like random number generators or
+interpolation accelerators) can't be shared between threads, so the helpers below create one per
+thread: see [`sample`] or [`Spline::eval_many_par`](crate::Spline::eval_many_par).
+!*/
+
+use crate::{Error, Rng, RngType, Value};
+use rayon::prelude::*;
+
+/// Number of values generated with each random number generator in [`sample`].
+const SAMPLE_CHUNK: usize = 4096;
+
+/// Returns `f(x)` for every `x` of `xs`, computed in parallel.
+pub fn map<F: Fn(f64) -> f64 + Sync>(xs: &[f64], f: F) -> Vec<f64> {
+    xs.par_iter().map(|&x| f(x)).collect()
+}
+
+/// Replaces every `x` of `xs` with `f(x)`, computed in parallel.
+///
+/// ```
+/// use rgsl::{gamma_beta, parallel};
+///
+/// let mut xs = vec![1., 2., 3., 4.];
+/// parallel::apply(&mut xs, gamma_beta::gamma::gamma);
+/// assert_eq!(xs, [1., 1., 2., 6.]);
+/// ```
+pub fn apply<F: Fn(f64) -> f64 + Sync>(xs: &mut [f64], f: F) {
+    xs.par_iter_mut().for_each(|x| *x = f(*x));
+}
+
+/// Returns `n` values drawn with `f` from random number generators of type `t`, computed in
+/// parallel.
+///
+/// The values are generated by chunks, each with its own generator seeded with `seed + i` (where
+/// `i` is the index of the chunk), so the result only depends on `seed` and `n`, not on the
+/// number of threads.
+///
+/// ```
+/// use rgsl::{parallel, RngType};
+///
+/// let xs = parallel::sample(RngType::default(), 42, 10_000, |r| r.gaussian(1.)).unwrap();
+/// assert_eq!(xs.len(), 10_000);
+/// assert_eq!(xs, parallel::sample(RngType::default(), 42, 10_000, |r| r.gaussian(1.)).unwrap());
+/// ```
+pub fn sample<F: Fn(&mut Rng) -> f64 + Sync>(
+    t: RngType,
+    seed: usize,
+    n: usize,
+    f: F,
+) -> Result<Vec<f64>, Error> {
+    let mut out = vec![0.; n];
+    out.par_chunks_mut(SAMPLE_CHUNK).enumerate().try_for_each(
+        |(i, chunk)| -> Result<(), Error> {
+            let mut rng =
+                Rng::new(t).ok_or_else(|| Error::new(Value::NoMemory, "gsl_rng_alloc"))?;
+            rng.set(seed.wrapping_add(i));
+            for x in chunk {
+                *x = f(&mut rng);
+            }
+            Ok(())
+        },
+    )?;
+    Ok(out)
+}
+
+#[test]
+fn parallel_helpers() {
+    use crate::bessel;
+    use crate::{InterpType, Spline};
+
+    let xs: Vec<f64> = (0..10_000).map(|i| i as f64 / 100.).collect();
+    let ys = map(&xs, bessel::J0);
+    assert!(xs.iter().zip(&ys).all(|(&x, &y)| y == bessel::J0(x)));
+
+    let mut zs = xs.clone();
+    apply(&mut zs, bessel::J0);
+    assert_eq!(ys, zs);
+
+    let mut spline = Spline::new(InterpType::linear(), 3).unwrap();
+    spline.init(&[0., 1., 2.], &[0., 2., 6.]).unwrap();
+    assert_eq!(spline.eval_many_par(&[0.5, 1.5, 2.]), [1., 4., 6.]);
+}
@@ -16,274 +16,869 @@ information on the values of physical constants is also available from the NIST
 P.J. Mohr, B.N. Taylor, D.B. Newell, “CODATA Recommended Values of the Fundamental Physical Constants: 2006”, Reviews of Modern Physics,
 80(2), pp. 633–730 (2008).
 
+The constants are grouped by unit system in the [`mksa`] and [`cgsm`] modules, the dimensionless
+ones being in [`num`]. The constants are all `const`s, so they can be used in `const` contexts:
+
+```
+use rgsl::physical_constant::{cgsm, mksa};
+
+const C2: f64 = mksa::SPEED_OF_LIGHT * mksa::SPEED_OF_LIGHT;
+
+assert_eq!(cgsm::SPEED_OF_LIGHT, mksa::SPEED_OF_LIGHT * 100.);
+```
+
+Being plain `f64`s, nothing prevents mixing the two unit systems. The [`consts`](crate::consts)
+module provides the same constants tagged with their unit system, so that doing so doesn't
+compile.
+
+The relative standard uncertainties of the measured constants are available in [`uncertainty`].
+
 http://www.physics.nist.gov/cuu/Constants/index.html
 http://physics.nist.gov/Pubs/SP811/appenB9.html
 !*/
 
-// Fundamental Constants
-/// The speed of light in vacuum, c. m / s
-pub static MKSA_SPEED_OF_LIGHT: f64 = 2.99792458e8;
-/// The permeability of free space, \mu_0. This constant is defined in the MKSA system only.
-/// `kg m / A^2 s^2`
-pub static MKSA_VACUUM_PERMEABILITY: f64 = 1.25663706144e-6;
-/// The permittivity of free space, \epsilon_0. This constant is defined in the MKSA system only.
-/// `A^2 s^4 / kg m^3`
-pub static MKSA_VACUUM_PERMITTIVITY: f64 = 8.854187817e-12;
-/// Planck’s constant, h. kg m^2 / s
-pub static MKSA_PLANCKS_CONSTANT_H: f64 = 6.62606896e-34;
-/// Planck’s constant divided by 2\pi, \hbar. kg m^2 / s
-pub static MKSA_PLANCKS_CONSTANT_HBAR: f64 = 1.05457162825e-34;
-/// Avogadro’s number, N_a. 1 / mol
-pub static NUM_AVOGADRO: f64 = 6.02214199e23;
-/// The molar charge of 1 Faraday. A s / mol
-pub static MKSA_FARADAY: f64 = 9.64853429775e4;
-/// The Boltzmann constant, k. kg m^2 / K s^2
-pub static MKSA_BOLTZMANN: f64 = 1.3806504e-23;
-/// The molar gas constant, R_0. kg m^2 / K mol s^2
-pub static MKSA_MOLAR_GAS: f64 = 8.314472e0;
-/// The standard gas volume, V_0. m^3 / mol
-pub static MKSA_STANDARD_GAS_VOLUME: f64 = 2.2710981e-2;
-/// The Stefan-Boltzmann radiation constant, \sigma. kg / K^4 s^3
-pub static MKSA_STEFAN_BOLTZMANN_CONSTANT: f64 = 5.67040047374e-8;
-/// The magnetic field of 1 Gauss. kg / A s^2
-pub static MKSA_GAUSS: f64 = 1e-4;
-
-// Astronomy and Astrophysics
-/// The length of 1 astronomical unit (mean earth-sun distance), au. m
-pub static MKSA_ASTRONOMICAL_UNIT: f64 = 1.49597870691e11;
-/// The gravitational constant, G. m^3 / kg s^2
-pub static MKSA_GRAVITATIONAL_CONSTANT: f64 = 6.673e-11;
-/// The distance of 1 light-year, ly. m
-pub static MKSA_LIGHT_YEAR: f64 = 9.46053620707e15;
-/// The distance of 1 parsec, pc. m
-pub static MKSA_PARSEC: f64 = 3.08567758135e16;
-/// The standard gravitational acceleration on Earth, g. m / s^2
-pub static MKSA_GRAV_ACCEL: f64 = 9.80665e0;
-/// The mass of the Sun. kg
-pub static MKSA_SOLAR_MASS: f64 = 1.98892e30;
-
-// Atomic and Nuclear Physics
-/// The charge of the electron, e. A s
-pub static MKSA_ELECTRON_CHARGE: f64 = 1.602176487e-19;
-/// The energy of 1 electron volt, eV. kg m^2 / s^2
-pub static MKSA_ELECTRON_VOLT: f64 = 1.602176487e-19;
-/// The unified atomic mass, amu. kg
-pub static MKSA_UNIFIED_ATOMIC_MASS: f64 = 1.660538782e-27;
-/// The mass of the electron, m_e. kg
-pub static MKSA_MASS_ELECTRON: f64 = 9.10938188e-31;
-/// The mass of the muon, m_\mu. kg
-pub static MKSA_MASS_MUON: f64 = 1.88353109e-28;
-/// The mass of the proton, m_p. kg
-pub static MKSA_MASS_PROTON: f64 = 1.67262158e-27;
-/// The mass of the neutron, m_n. kg
-pub static MKSA_MASS_NEUTRON: f64 = 1.67492716e-27;
-/// The electromagnetic fine structure constant \alpha. 1
-pub static NUM_FINE_STRUCTURE: f64 = 7.297352533e-3;
-/// The Rydberg constant, Ry, in units of energy. This is related to the Rydberg inverse
-/// wavelength `R_\infty by Ry = h c R_\infty. kg m^2 / s^2`
-pub static MKSA_RYDBERG: f64 = 2.17987196968e-18;
-/// The Bohr radius, a_0. m
-pub static MKSA_BOHR_RADIUS: f64 = 5.291772083e-11;
-/// The length of 1 angstrom. m
-pub static MKSA_ANGSTROM: f64 = 1e-10;
-/// The area of 1 barn. m^2
-pub static MKSA_BARN: f64 = 1e-28;
-/// The Bohr Magneton, \mu_B. A m^2
-pub static MKSA_BOHR_MAGNETON: f64 = 9.27400899e-24;
-/// The Nuclear Magneton, \mu_N. A m^2
-pub static MKSA_NUCLEAR_MAGNETON: f64 = 5.05078317e-27;
-/// The absolute value of the magnetic moment of the electron, \mu_e. The physical magnetic moment
-/// of the electron is negative. A m^2
-pub static MKSA_ELECTRON_MAGNETIC_MOMENT: f64 = 9.28476362e-24;
-/// The magnetic moment of the proton, \mu_p. A m^2
-pub static MKSA_PROTON_MAGNETIC_MOMENT: f64 = 1.410606633e-26;
-/// The Thomson cross section, \sigma_T. m^2
-pub static MKSA_THOMSON_CROSS_SECTION: f64 = 6.65245893699e-29;
-/// The electric dipole moment of 1 Debye, D. A s^2 / m^2
-pub static MKSA_DEBYE: f64 = 3.33564095198e-30;
-
-// Measurement of Time
-/// The number of seconds in 1 minute. s
-pub static MKSA_MINUTE: f64 = 6e1f64;
-/// The number of seconds in 1 hour. s
-pub static MKSA_HOUR: f64 = 3.6e3f64;
-/// The number of seconds in 1 day. s
-pub static MKSA_DAY: f64 = 8.64e4f64;
-/// The number of seconds in 1 week. s
-pub static MKSA_WEEK: f64 = 6.048e5f64;
-
-// Imperial Units
-/// The length of 1 inch. m
-pub static MKSA_INCH: f64 = 2.54e-2;
-/// The length of 1 foot. m
-pub static MKSA_FOOT: f64 = 3.048e-1;
-/// The length of 1 yard. m
-pub static MKSA_YARD: f64 = 9.144e-1;
-/// The length of 1 mile. m
-pub static MKSA_MILE: f64 = 1.609344e3;
-/// The length of 1 mil (1/1000th of an inch). m
-pub static MKSA_MIL: f64 = 2.54e-5;
-
-// Speed and Nautical Units
-/// The speed of 1 kilometer per hour. m / s
-pub static MKSA_KILOMETERS_PER_HOUR: f64 = 2.77777777778e-1;
-/// The speed of 1 mile per hour. m / s
-pub static MKSA_MILES_PER_HOUR: f64 = 4.4704e-1;
-/// The length of 1 nautical mile. m
-pub static MKSA_NAUTICAL_MILE: f64 = 1.852e3;
-/// The length of 1 fathom. m
-pub static MKSA_FATHOM: f64 = 1.8288e0;
-/// The speed of 1 knot. m / s
-pub static MKSA_KNOT: f64 = 5.14444444444e-1;
-
-// Printers Units
-/// The length of 1 printer’s point (1/72 inch). m
-pub static MKSA_POINT: f64 = 3.52777777778e-4;
-/// The length of 1 TeX point (1/72.27 inch). m
-pub static MKSA_TEXPOINT: f64 = 3.51459803515e-4;
-
-// Volume, Area and Length
-/// The length of 1 micron. m
-pub static MKSA_MICRON: f64 = 1e-6;
-/// The area of 1 hectare. m^2
-pub static MKSA_HECTARE: f64 = 1e4;
-/// The area of 1 acre. m^2
-pub static MKSA_ACRE: f64 = 4.04685642241e3;
-/// The volume of 1 liter. m^3
-pub static MKSA_LITER: f64 = 1e-3;
-/// The volume of 1 US gallon. m^3
-pub static MKSA_US_GALLON: f64 = 3.78541178402e-3;
-/// The volume of 1 Canadian gallon. m^3
-pub static MKSA_CANADIAN_GALLON: f64 = 4.54609e-3;
-/// The volume of 1 UK gallon. m^3
-pub static MKSA_UK_GALLON: f64 = 4.546092e-3;
-/// The volume of 1 quart. m^3
-pub static MKSA_QUART: f64 = 9.46352946004e-4;
-/// The volume of 1 pint. m^3
-pub static MKSA_PINT: f64 = 4.73176473002e-4;
-/// m^3
-pub static MKSA_CUP: f64 = 2.36588236501e-4;
-
-// Mass and Weight
-/// The mass of 1 pound. kg
-pub static MKSA_POUND_MASS: f64 = 4.5359237e-1;
-/// The mass of 1 ounce. kg
-pub static MKSA_OUNCE_MASS: f64 = 2.8349523125e-2;
-/// The mass of 1 ton. kg
-pub static MKSA_TON: f64 = 9.0718474e2;
-/// The mass of 1 metric ton (1000 kg). kg
-pub static MKSA_METRIC_TON: f64 = 1e3;
-/// The mass of 1 UK ton. kg
-pub static MKSA_UK_TON: f64 = 1.0160469088e3;
-/// The mass of 1 troy ounce. kg
-pub static MKSA_TROY_OUNCE: f64 = 3.1103475e-2;
-/// The mass of 1 carat. kg
-pub static MKSA_CARAT: f64 = 2e-4;
-/// The force of 1 gram weight. kg m / s^2
-pub static MKSA_GRAM_FORCE: f64 = 9.80665e-3;
-/// The force of 1 pound weight. kg m / s^2
-pub static MKSA_POUND_FORCE: f64 = 4.44822161526e0;
-/// The force of 1 kilopound weight. kg m / s^2
-pub static MKSA_KILOPOUND_FORCE: f64 = 4.44822161526e3;
-/// The force of 1 poundal. kg m / s^2
-pub static MKSA_POUNDAL: f64 = 1.38255e-1;
-
-// Thermal Energy and Power
-/// The energy of 1 calorie. kg m^2 / s^2
-pub static MKSA_CALORIE: f64 = 4.1868e0;
-/// The energy of 1 British Thermal Unit, btu. kg m^2 / s^2
-pub static MKSA_BTU: f64 = 1.05505585262e3;
-/// The energy of 1 Therm. kg m^2 / s^2
-pub static MKSA_THERM: f64 = 1.05506e8;
-/// The power of 1 horsepower. kg m^2 / s^3
-pub static MKSA_HORSEPOWER: f64 = 7.457e2;
-
-// Pressure
-/// The pressure of 1 bar. kg / m s^2
-pub static MKSA_BAR: f64 = 1e5;
-/// The pressure of 1 standard atmosphere. kg / m s^2
-pub static MKSA_STD_ATMOSPHERE: f64 = 1.01325e5;
-/// The pressure of 1 torr. kg / m s^2
-pub static MKSA_TORR: f64 = 1.33322368421e2;
-/// The pressure of 1 meter of mercury. kg / m s^2
-pub static MKSA_METER_OF_MERCURY: f64 = 1.33322368421e5;
-/// The pressure of 1 inch of mercury. kg / m s^2
-pub static MKSA_INCH_OF_MERCURY: f64 = 3.38638815789e3;
-/// The pressure of 1 inch of water. kg / m s^2
-pub static MKSA_INCH_OF_WATER: f64 = 2.490889e2;
-/// The pressure of 1 pound per square inch. kg / m s^2
-pub static MKSA_PSI: f64 = 6.89475729317e3;
-
-// Viscosity
-/// The dynamic viscosity of 1 poise. kg m^-1 s^-1
-pub static MKSA_POISE: f64 = 1e-1;
-/// The kinematic viscosity of 1 stokes. m^2 / s
-pub static MKSA_STOKES: f64 = 1e-4;
-
-// Light and Illumination
-/// The luminance of 1 stilb. cd / m^2
-pub static MKSA_STILB: f64 = 1e4;
-/// The luminous flux of 1 lumen. cd sr
-pub static MKSA_LUMEN: f64 = 1e0;
-/// The illuminance of 1 lux. cd sr / m^2
-pub static MKSA_LUX: f64 = 1e0;
-/// The illuminance of 1 phot. cd sr / m^2
-pub static MKSA_PHOT: f64 = 1e4;
-/// The illuminance of 1 footcandle. cd sr / m^2
-pub static MKSA_FOOTCANDLE: f64 = 1.076e1;
-/// The luminance of 1 lambert. cd sr / m^2
-pub static MKSA_LAMBERT: f64 = 1e4;
-/// The luminance of 1 footlambert. cd sr / m^2
-pub static MKSA_FOOTLAMBERT: f64 = 1.07639104e1;
-
-// Radioactivity
-/// The activity of 1 curie. 1 / s
-pub static MKSA_CURIE: f64 = 3.7e10;
-/// The exposure of 1 roentgen. A s / kg
-pub static MKSA_ROENTGEN: f64 = 2.58e-4;
-/// The absorbed dose of 1 rad. m^2 / s^2
-pub static MKSA_RAD: f64 = 1e-2;
-
-// Force and Energy
-/// The SI unit of force, 1 Newton. kg m / s^2
-pub static MKSA_NEWTON: f64 = 1e0;
-/// The force of 1 Dyne = 10^-5 Newton. kg m / s^2
-pub static MKSA_DYNE: f64 = 1e-5;
-/// The SI unit of energy, 1 Joule. kg m^2 / s^2
-pub static MKSA_JOULE: f64 = 1e0;
-/// The energy 1 erg = 10^-7 Joule. kg m^2 / s^2
-pub static MKSA_ERG: f64 = 1e-7;
-
-// Prefixes : These constants are dimensionless scaling factors.
-/// 10^24
-pub static NUM_YOTTA: f64 = 1e24;
-/// 10^21
-pub static NUM_ZETTA: f64 = 1e21;
-/// 10^18
-pub static NUM_EXA: f64 = 1e18;
-/// 10^15
-pub static NUM_PETA: f64 = 1e15;
-/// 10^12
-pub static NUM_TERA: f64 = 1e12;
-/// 10^9
-pub static NUM_GIGA: f64 = 1e9;
-/// 10^6
-pub static NUM_MEGA: f64 = 1e6;
-/// 10^3
-pub static NUM_KILO: f64 = 1e3;
-/// 10^-3
-pub static NUM_MILLI: f64 = 1e-3;
-/// 10^-6
-pub static NUM_MICRO: f64 = 1e-6;
-/// 10^-9
-pub static NUM_NANO: f64 = 1e-9;
-/// 10^-12
-pub static NUM_PICO: f64 = 1e-12;
-/// 10^-15
-pub static NUM_FEMTO: f64 = 1e-15;
-/// 10^-18
-pub static NUM_ATTO: f64 = 1e-18;
-/// 10^-21
-pub static NUM_ZEPTO: f64 = 1e-21;
-/// 10^-24
-pub static NUM_YOCTO: f64 = 1e-24;
+/// Constants in the MKSA system (meters, kilograms, seconds, amperes).
+pub mod mksa {
+    // Fundamental Constants
+    /// The speed of light in vacuum, c. m / s
+    pub const SPEED_OF_LIGHT: f64 = 2.99792458e8;
+    /// The permeability of free space, \mu_0. This constant is defined in the MKSA system only.
+    /// `kg m / A^2 s^2`
+    pub const VACUUM_PERMEABILITY: f64 = 1.25663706144e-6;
+    /// The permittivity of free space, \epsilon_0. This constant is defined in the MKSA system only.
+    /// `A^2 s^4 / kg m^3`
+    pub const VACUUM_PERMITTIVITY: f64 = 8.854187817e-12;
+    /// Planck’s constant, h. kg m^2 / s
+    pub const PLANCKS_CONSTANT_H: f64 = 6.62606896e-34;
+    /// Planck’s constant divided by 2\pi, \hbar. kg m^2 / s
+    pub const PLANCKS_CONSTANT_HBAR: f64 = 1.05457162825e-34;
+    /// The molar charge of 1 Faraday. A s / mol
+    pub const FARADAY: f64 = 9.64853429775e4;
+    /// The Boltzmann constant, k. kg m^2 / K s^2
+    pub const BOLTZMANN: f64 = 1.3806504e-23;
+    /// The molar gas constant, R_0. kg m^2 / K mol s^2
+    pub const MOLAR_GAS: f64 = 8.314472e0;
+    /// The standard gas volume, V_0. m^3 / mol
+    pub const STANDARD_GAS_VOLUME: f64 = 2.2710981e-2;
+    /// The Stefan-Boltzmann radiation constant, \sigma. kg / K^4 s^3
+    pub const STEFAN_BOLTZMANN_CONSTANT: f64 = 5.67040047374e-8;
+    /// The magnetic field of 1 Gauss. kg / A s^2
+    pub const GAUSS: f64 = 1e-4;
+
+    // Astronomy and Astrophysics
+    /// The length of 1 astronomical unit (mean earth-sun distance), au. m
+    pub const ASTRONOMICAL_UNIT: f64 = 1.49597870691e11;
+    /// The gravitational constant, G. m^3 / kg s^2
+    pub const GRAVITATIONAL_CONSTANT: f64 = 6.673e-11;
+    /// The distance of 1 light-year, ly. m
+    pub const LIGHT_YEAR: f64 = 9.46053620707e15;
+    /// The distance of 1 parsec, pc. m
+    pub const PARSEC: f64 = 3.08567758135e16;
+    /// The standard gravitational acceleration on Earth, g. m / s^2
+    pub const GRAV_ACCEL: f64 = 9.80665e0;
+    /// The mass of the Sun. kg
+    pub const SOLAR_MASS: f64 = 1.98892e30;
+
+    // Atomic and Nuclear Physics
+    /// The charge of the electron, e. A s
+    pub const ELECTRON_CHARGE: f64 = 1.602176487e-19;
+    /// The energy of 1 electron volt, eV. kg m^2 / s^2
+    pub const ELECTRON_VOLT: f64 = 1.602176487e-19;
+    /// The unified atomic mass, amu. kg
+    pub const UNIFIED_ATOMIC_MASS: f64 = 1.660538782e-27;
+    /// The mass of the electron, m_e. kg
+    pub const MASS_ELECTRON: f64 = 9.10938188e-31;
+    /// The mass of the muon, m_\mu. kg
+    pub const MASS_MUON: f64 = 1.88353109e-28;
+    /// The mass of the proton, m_p. kg
+    pub const MASS_PROTON: f64 = 1.67262158e-27;
+    /// The mass of the neutron, m_n. kg
+    pub const MASS_NEUTRON: f64 = 1.67492716e-27;
+    /// The Rydberg constant, Ry, in units of energy. This is related to the Rydberg inverse
+    /// wavelength R_\infty by Ry = h c R_\infty. kg m^2 / s^2
+    pub const RYDBERG: f64 = 2.17987196968e-18;
+    /// The Bohr radius, a_0. m
+    pub const BOHR_RADIUS: f64 = 5.291772083e-11;
+    /// The length of 1 angstrom. m
+    pub const ANGSTROM: f64 = 1e-10;
+    /// The area of 1 barn. m^2
+    pub const BARN: f64 = 1e-28;
+    /// The Bohr Magneton, \mu_B. A m^2
+    pub const BOHR_MAGNETON: f64 = 9.27400899e-24;
+    /// The Nuclear Magneton, \mu_N. A m^2
+    pub const NUCLEAR_MAGNETON: f64 = 5.05078317e-27;
+    /// The absolute value of the magnetic moment of the electron, \mu_e. The physical magnetic
+    /// moment of the electron is negative. A m^2
+    pub const ELECTRON_MAGNETIC_MOMENT: f64 = 9.28476362e-24;
+    /// The magnetic moment of the proton, \mu_p. A m^2
+    pub const PROTON_MAGNETIC_MOMENT: f64 = 1.410606633e-26;
+    /// The Thomson cross section, \sigma_T. m^2
+    pub const THOMSON_CROSS_SECTION: f64 = 6.65245893699e-29;
+    /// The electric dipole moment of 1 Debye, D. A s m
+    pub const DEBYE: f64 = 3.33564095198e-30;
+
+    // Measurement of Time
+    /// The number of seconds in 1 minute. s
+    pub const MINUTE: f64 = 6e1;
+    /// The number of seconds in 1 hour. s
+    pub const HOUR: f64 = 3.6e3;
+    /// The number of seconds in 1 day. s
+    pub const DAY: f64 = 8.64e4;
+    /// The number of seconds in 1 week. s
+    pub const WEEK: f64 = 6.048e5;
+
+    // Imperial Units
+    /// The length of 1 inch. m
+    pub const INCH: f64 = 2.54e-2;
+    /// The length of 1 foot. m
+    pub const FOOT: f64 = 3.048e-1;
+    /// The length of 1 yard. m
+    pub const YARD: f64 = 9.144e-1;
+    /// The length of 1 mile. m
+    pub const MILE: f64 = 1.609344e3;
+    /// The length of 1 mil (1/1000th of an inch). m
+    pub const MIL: f64 = 2.54e-5;
+
+    // Speed and Nautical Units
+    /// The speed of 1 kilometer per hour. m / s
+    pub const KILOMETERS_PER_HOUR: f64 = 2.77777777778e-1;
+    /// The speed of 1 mile per hour. m / s
+    pub const MILES_PER_HOUR: f64 = 4.4704e-1;
+    /// The length of 1 nautical mile. m
+    pub const NAUTICAL_MILE: f64 = 1.852e3;
+    /// The length of 1 fathom. m
+    pub const FATHOM: f64 = 1.8288e0;
+    /// The speed of 1 knot. m / s
+    pub const KNOT: f64 = 5.14444444444e-1;
+
+    // Printers Units
+    /// The length of 1 printer’s point (1/72 inch). m
+    pub const POINT: f64 = 3.52777777778e-4;
+    /// The length of 1 TeX point (1/72.27 inch). m
+    pub const TEXPOINT: f64 = 3.51459803515e-4;
+
+    // Volume, Area and Length
+    /// The length of 1 micron. m
+    pub const MICRON: f64 = 1e-6;
+    /// The area of 1 hectare. m^2
+    pub const HECTARE: f64 = 1e4;
+    /// The area of 1 acre. m^2
+    pub const ACRE: f64 = 4.04685642241e3;
+    /// The volume of 1 liter. m^3
+    pub const LITER: f64 = 1e-3;
+    /// The volume of 1 US gallon. m^3
+    pub const US_GALLON: f64 = 3.78541178402e-3;
+    /// The volume of 1 Canadian gallon. m^3
+    pub const CANADIAN_GALLON: f64 = 4.54609e-3;
+    /// The volume of 1 UK gallon. m^3
+    pub const UK_GALLON: f64 = 4.546092e-3;
+    /// The volume of 1 quart. m^3
+    pub const QUART: f64 = 9.46352946004e-4;
+    /// The volume of 1 pint. m^3
+    pub const PINT: f64 = 4.73176473002e-4;
+    /// The volume of 1 cup. m^3
+    pub const CUP: f64 = 2.36588236501e-4;
+
+    // Mass and Weight
+    /// The mass of 1 pound. kg
+    pub const POUND_MASS: f64 = 4.5359237e-1;
+    /// The mass of 1 ounce. kg
+    pub const OUNCE_MASS: f64 = 2.8349523125e-2;
+    /// The mass of 1 ton. kg
+    pub const TON: f64 = 9.0718474e2;
+    /// The mass of 1 metric ton (1000 kg). kg
+    pub const METRIC_TON: f64 = 1e3;
+    /// The mass of 1 UK ton. kg
+    pub const UK_TON: f64 = 1.0160469088e3;
+    /// The mass of 1 troy ounce. kg
+    pub const TROY_OUNCE: f64 = 3.1103475e-2;
+    /// The mass of 1 carat. kg
+    pub const CARAT: f64 = 2e-4;
+    /// The force of 1 gram weight. kg m / s^2
+    pub const GRAM_FORCE: f64 = 9.80665e-3;
+    /// The force of 1 pound weight. kg m / s^2
+    pub const POUND_FORCE: f64 = 4.44822161526e0;
+    /// The force of 1 kilopound weight. kg m / s^2
+    pub const KILOPOUND_FORCE: f64 = 4.44822161526e3;
+    /// The force of 1 poundal. kg m / s^2
+    pub const POUNDAL: f64 = 1.38255e-1;
+
+    // Thermal Energy and Power
+    /// The energy of 1 calorie. kg m^2 / s^2
+    pub const CALORIE: f64 = 4.1868e0;
+    /// The energy of 1 British Thermal Unit, btu. kg m^2 / s^2
+    pub const BTU: f64 = 1.05505585262e3;
+    /// The energy of 1 Therm. kg m^2 / s^2
+    pub const THERM: f64 = 1.05506e8;
+    /// The power of 1 horsepower. kg m^2 / s^3
+    pub const HORSEPOWER: f64 = 7.457e2;
+
+    // Pressure
+    /// The pressure of 1 bar. kg / m s^2
+    pub const BAR: f64 = 1e5;
+    /// The pressure of 1 standard atmosphere. kg / m s^2
+    pub const STD_ATMOSPHERE: f64 = 1.01325e5;
+    /// The pressure of 1 torr. kg / m s^2
+    pub const TORR: f64 = 1.33322368421e2;
+    /// The pressure of 1 meter of mercury. kg / m s^2
+    pub const METER_OF_MERCURY: f64 = 1.33322368421e5;
+    /// The pressure of 1 inch of mercury. kg / m s^2
+    pub const INCH_OF_MERCURY: f64 = 3.38638815789e3;
+    /// The pressure of 1 inch of water. kg / m s^2
+    pub const INCH_OF_WATER: f64 = 2.490889e2;
+    /// The pressure of 1 pound per square inch. kg / m s^2
+    pub const PSI: f64 = 6.89475729317e3;
+
+    // Viscosity
+    /// The dynamic viscosity of 1 poise. kg m^-1 s^-1
+    pub const POISE: f64 = 1e-1;
+    /// The kinematic viscosity of 1 stokes. m^2 / s
+    pub const STOKES: f64 = 1e-4;
+
+    // Light and Illumination
+    /// The luminance of 1 stilb. cd / m^2
+    pub const STILB: f64 = 1e4;
+    /// The luminous flux of 1 lumen. cd sr
+    pub const LUMEN: f64 = 1e0;
+    /// The illuminance of 1 lux. cd sr / m^2
+    pub const LUX: f64 = 1e0;
+    /// The illuminance of 1 phot. cd sr / m^2
+    pub const PHOT: f64 = 1e4;
+    /// The illuminance of 1 footcandle. cd sr / m^2
+    pub const FOOTCANDLE: f64 = 1.076e1;
+    /// The luminance of 1 lambert. cd sr / m^2
+    pub const LAMBERT: f64 = 1e4;
+    /// The luminance of 1 footlambert. cd sr / m^2
+    pub const FOOTLAMBERT: f64 = 1.07639104e1;
+
+    // Radioactivity
+    /// The activity of 1 curie. 1 / s
+    pub const CURIE: f64 = 3.7e10;
+    /// The exposure of 1 roentgen. A s / kg
+    pub const ROENTGEN: f64 = 2.58e-4;
+    /// The absorbed dose of 1 rad. m^2 / s^2
+    pub const RAD: f64 = 1e-2;
+
+    // Force and Energy
+    /// The SI unit of force, 1 Newton. kg m / s^2
+    pub const NEWTON: f64 = 1e0;
+    /// The force of 1 Dyne = 10^-5 Newton. kg m / s^2
+    pub const DYNE: f64 = 1e-5;
+    /// The SI unit of energy, 1 Joule. kg m^2 / s^2
+    pub const JOULE: f64 = 1e0;
+    /// The energy 1 erg = 10^-7 Joule. kg m^2 / s^2
+    pub const ERG: f64 = 1e-7;
+}
+
+/// Constants in the CGSM system (centimeters, grams, seconds, gauss).
+///
+/// Electric currents are expressed in abamperes (1 abamp = 10 A).
+pub mod cgsm {
+    // Fundamental Constants
+    /// The speed of light in vacuum, c. cm / s
+    pub const SPEED_OF_LIGHT: f64 = 2.99792458e10;
+    /// Planck’s constant, h. g cm^2 / s
+    pub const PLANCKS_CONSTANT_H: f64 = 6.62606896e-27;
+    /// Planck’s constant divided by 2\pi, \hbar. g cm^2 / s
+    pub const PLANCKS_CONSTANT_HBAR: f64 = 1.05457162825e-27;
+    /// The molar charge of 1 Faraday. abamp s / mol
+    pub const FARADAY: f64 = 9.64853429775e3;
+    /// The Boltzmann constant, k. g cm^2 / K s^2
+    pub const BOLTZMANN: f64 = 1.3806504e-16;
+    /// The molar gas constant, R_0. g cm^2 / K mol s^2
+    pub const MOLAR_GAS: f64 = 8.314472e7;
+    /// The standard gas volume, V_0. cm^3 / mol
+    pub const STANDARD_GAS_VOLUME: f64 = 2.2710981e4;
+    /// The Stefan-Boltzmann radiation constant, \sigma. g / K^4 s^3
+    pub const STEFAN_BOLTZMANN_CONSTANT: f64 = 5.67040047374e-5;
+    /// The magnetic field of 1 Gauss. g / abamp s^2
+    pub const GAUSS: f64 = 1e0;
+
+    // Astronomy and Astrophysics
+    /// The length of 1 astronomical unit (mean earth-sun distance), au. cm
+    pub const ASTRONOMICAL_UNIT: f64 = 1.49597870691e13;
+    /// The gravitational constant, G. cm^3 / g s^2
+    pub const GRAVITATIONAL_CONSTANT: f64 = 6.673e-8;
+    /// The distance of 1 light-year, ly. cm
+    pub const LIGHT_YEAR: f64 = 9.46053620707e17;
+    /// The distance of 1 parsec, pc. cm
+    pub const PARSEC: f64 = 3.08567758135e18;
+    /// The standard gravitational acceleration on Earth, g. cm / s^2
+    pub const GRAV_ACCEL: f64 = 9.80665e2;
+    /// The mass of the Sun. g
+    pub const SOLAR_MASS: f64 = 1.98892e33;
+
+    // Atomic and Nuclear Physics
+    /// The charge of the electron, e. abamp s
+    pub const ELECTRON_CHARGE: f64 = 1.602176487e-20;
+    /// The energy of 1 electron volt, eV. g cm^2 / s^2
+    pub const ELECTRON_VOLT: f64 = 1.602176487e-12;
+    /// The unified atomic mass, amu. g
+    pub const UNIFIED_ATOMIC_MASS: f64 = 1.660538782e-24;
+    /// The mass of the electron, m_e. g
+    pub const MASS_ELECTRON: f64 = 9.10938188e-28;
+    /// The mass of the muon, m_\mu. g
+    pub const MASS_MUON: f64 = 1.88353109e-25;
+    /// The mass of the proton, m_p. g
+    pub const MASS_PROTON: f64 = 1.67262158e-24;
+    /// The mass of the neutron, m_n. g
+    pub const MASS_NEUTRON: f64 = 1.67492716e-24;
+    /// The Rydberg constant, Ry, in units of energy. This is related to the Rydberg inverse
+    /// wavelength R_\infty by Ry = h c R_\infty. g cm^2 / s^2
+    pub const RYDBERG: f64 = 2.17987196968e-11;
+    /// The Bohr radius, a_0. cm
+    pub const BOHR_RADIUS: f64 = 5.291772083e-9;
+    /// The length of 1 angstrom. cm
+    pub const ANGSTROM: f64 = 1e-8;
+    /// The area of 1 barn. cm^2
+    pub const BARN: f64 = 1e-24;
+    /// The Bohr Magneton, \mu_B. abamp cm^2
+    pub const BOHR_MAGNETON: f64 = 9.27400899e-21;
+    /// The Nuclear Magneton, \mu_N. abamp cm^2
+    pub const NUCLEAR_MAGNETON: f64 = 5.05078317e-24;
+    /// The absolute value of the magnetic moment of the electron, \mu_e. The physical magnetic
+    /// moment of the electron is negative. abamp cm^2
+    pub const ELECTRON_MAGNETIC_MOMENT: f64 = 9.28476362e-21;
+    /// The magnetic moment of the proton, \mu_p. abamp cm^2
+    pub const PROTON_MAGNETIC_MOMENT: f64 = 1.410606633e-23;
+    /// The Thomson cross section, \sigma_T. cm^2
+    pub const THOMSON_CROSS_SECTION: f64 = 6.65245893699e-25;
+    /// The electric dipole moment of 1 Debye, D. abamp s cm
+    pub const DEBYE: f64 = 3.33564095198e-29;
+
+    // Measurement of Time
+    /// The number of seconds in 1 minute. s
+    pub const MINUTE: f64 = 6e1;
+    /// The number of seconds in 1 hour. s
+    pub const HOUR: f64 = 3.6e3;
+    /// The number of seconds in 1 day. s
+    pub const DAY: f64 = 8.64e4;
+    /// The number of seconds in 1 week. s
+    pub const WEEK: f64 = 6.048e5;
+
+    // Imperial Units
+    /// The length of 1 inch. cm
+    pub const INCH: f64 = 2.54e0;
+    /// The length of 1 foot. cm
+    pub const FOOT: f64 = 3.048e1;
+    /// The length of 1 yard. cm
+    pub const YARD: f64 = 9.144e1;
+    /// The length of 1 mile. cm
+    pub const MILE: f64 = 1.609344e5;
+    /// The length of 1 mil (1/1000th of an inch). cm
+    pub const MIL: f64 = 2.54e-3;
+
+    // Speed and Nautical Units
+    /// The speed of 1 kilometer per hour. cm / s
+    pub const KILOMETERS_PER_HOUR: f64 = 2.77777777778e1;
+    /// The speed of 1 mile per hour. cm / s
+    pub const MILES_PER_HOUR: f64 = 4.4704e1;
+    /// The length of 1 nautical mile. cm
+    pub const NAUTICAL_MILE: f64 = 1.852e5;
+    /// The length of 1 fathom. cm
+    pub const FATHOM: f64 = 1.8288e2;
+    /// The speed of 1 knot. cm / s
+    pub const KNOT: f64 = 5.14444444444e1;
+
+    // Printers Units
+    /// The length of 1 printer’s point (1/72 inch). cm
+    pub const POINT: f64 = 3.52777777778e-2;
+    /// The length of 1 TeX point (1/72.27 inch). cm
+    pub const TEXPOINT: f64 = 3.51459803515e-2;
+
+    // Volume, Area and Length
+    /// The length of 1 micron. cm
+    pub const MICRON: f64 = 1e-4;
+    /// The area of 1 hectare. cm^2
+    pub const HECTARE: f64 = 1e8;
+    /// The area of 1 acre. cm^2
+    pub const ACRE: f64 = 4.04685642241e7;
+    /// The volume of 1 liter. cm^3
+    pub const LITER: f64 = 1e3;
+    /// The volume of 1 US gallon. cm^3
+    pub const US_GALLON: f64 = 3.78541178402e3;
+    /// The volume of 1 Canadian gallon. cm^3
+    pub const CANADIAN_GALLON: f64 = 4.54609e3;
+    /// The volume of 1 UK gallon. cm^3
+    pub const UK_GALLON: f64 = 4.546092e3;
+    /// The volume of 1 quart. cm^3
+    pub const QUART: f64 = 9.46352946004e2;
+    /// The volume of 1 pint. cm^3
+    pub const PINT: f64 = 4.73176473002e2;
+    /// The volume of 1 cup. cm^3
+    pub const CUP: f64 = 2.36588236501e2;
+
+    // Mass and Weight
+    /// The mass of 1 pound. g
+    pub const POUND_MASS: f64 = 4.5359237e2;
+    /// The mass of 1 ounce. g
+    pub const OUNCE_MASS: f64 = 2.8349523125e1;
+    /// The mass of 1 ton. g
+    pub const TON: f64 = 9.0718474e5;
+    /// The mass of 1 metric ton (1000 kg). g
+    pub const METRIC_TON: f64 = 1e6;
+    /// The mass of 1 UK ton. g
+    pub const UK_TON: f64 = 1.0160469088e6;
+    /// The mass of 1 troy ounce. g
+    pub const TROY_OUNCE: f64 = 3.1103475e1;
+    /// The mass of 1 carat. g
+    pub const CARAT: f64 = 2e-1;
+    /// The force of 1 gram weight. g cm / s^2
+    pub const GRAM_FORCE: f64 = 9.80665e2;
+    /// The force of 1 pound weight. g cm / s^2
+    pub const POUND_FORCE: f64 = 4.44822161526e5;
+    /// The force of 1 kilopound weight. g cm / s^2
+    pub const KILOPOUND_FORCE: f64 = 4.44822161526e8;
+    /// The force of 1 poundal. g cm / s^2
+    pub const POUNDAL: f64 = 1.38255e4;
+
+    // Thermal Energy and Power
+    /// The energy of 1 calorie. g cm^2 / s^2
+    pub const CALORIE: f64 = 4.1868e7;
+    /// The energy of 1 British Thermal Unit, btu. g cm^2 / s^2
+    pub const BTU: f64 = 1.05505585262e10;
+    /// The energy of 1 Therm. g cm^2 / s^2
+    pub const THERM: f64 = 1.05506e15;
+    /// The power of 1 horsepower. g cm^2 / s^3
+    pub const HORSEPOWER: f64 = 7.457e9;
+
+    // Pressure
+    /// The pressure of 1 bar. g / cm s^2
+    pub const BAR: f64 = 1e6;
+    /// The pressure of 1 standard atmosphere. g / cm s^2
+    pub const STD_ATMOSPHERE: f64 = 1.01325e6;
+    /// The pressure of 1 torr. g / cm s^2
+    pub const TORR: f64 = 1.33322368421e3;
+    /// The pressure of 1 meter of mercury. g / cm s^2
+    pub const METER_OF_MERCURY: f64 = 1.33322368421e6;
+    /// The pressure of 1 inch of mercury. g / cm s^2
+    pub const INCH_OF_MERCURY: f64 = 3.38638815789e4;
+    /// The pressure of 1 inch of water. g / cm s^2
+    pub const INCH_OF_WATER: f64 = 2.490889e3;
+    /// The pressure of 1 pound per square inch. g / cm s^2
+    pub const PSI: f64 = 6.89475729317e4;
+
+    // Viscosity
+    /// The dynamic viscosity of 1 poise. g cm^-1 s^-1
+    pub const POISE: f64 = 1e0;
+    /// The kinematic viscosity of 1 stokes. cm^2 / s
+    pub const STOKES: f64 = 1e0;
+
+    // Light and Illumination
+    /// The luminance of 1 stilb. cd / cm^2
+    pub const STILB: f64 = 1e0;
+    /// The luminous flux of 1 lumen. cd sr
+    pub const LUMEN: f64 = 1e0;
+    /// The illuminance of 1 lux. cd sr / cm^2
+    pub const LUX: f64 = 1e-4;
+    /// The illuminance of 1 phot. cd sr / cm^2
+    pub const PHOT: f64 = 1e0;
+    /// The illuminance of 1 footcandle. cd sr / cm^2
+    pub const FOOTCANDLE: f64 = 1.076e-3;
+    /// The luminance of 1 lambert. cd sr / cm^2
+    pub const LAMBERT: f64 = 1e0;
+    /// The luminance of 1 footlambert. cd sr / cm^2
+    pub const FOOTLAMBERT: f64 = 1.07639104e-3;
+
+    // Radioactivity
+    /// The activity of 1 curie. 1 / s
+    pub const CURIE: f64 = 3.7e10;
+    /// The exposure of 1 roentgen. abamp s / g
+    pub const ROENTGEN: f64 = 2.58e-8;
+    /// The absorbed dose of 1 rad. cm^2 / s^2
+    pub const RAD: f64 = 1e2;
+
+    // Force and Energy
+    /// The SI unit of force, 1 Newton. g cm / s^2
+    pub const NEWTON: f64 = 1e5;
+    /// The force of 1 Dyne = 10^-5 Newton. g cm / s^2
+    pub const DYNE: f64 = 1e0;
+    /// The SI unit of energy, 1 Joule. g cm^2 / s^2
+    pub const JOULE: f64 = 1e7;
+    /// The energy 1 erg = 10^-7 Joule. g cm^2 / s^2
+    pub const ERG: f64 = 1e0;
+}
+
+/// Dimensionless constants.
+pub mod num {
+    // Fundamental Constants
+    /// Avogadro’s number, N_a. 1 / mol
+    pub const AVOGADRO: f64 = 6.02214199e23;
+
+    // Atomic and Nuclear Physics
+    /// The electromagnetic fine structure constant \alpha. 1
+    pub const FINE_STRUCTURE: f64 = 7.297352533e-3;
+
+    // Prefixes : These constants are dimensionless scaling factors.
+    /// 10^24
+    pub const YOTTA: f64 = 1e24;
+    /// 10^21
+    pub const ZETTA: f64 = 1e21;
+    /// 10^18
+    pub const EXA: f64 = 1e18;
+    /// 10^15
+    pub const PETA: f64 = 1e15;
+    /// 10^12
+    pub const TERA: f64 = 1e12;
+    /// 10^9
+    pub const GIGA: f64 = 1e9;
+    /// 10^6
+    pub const MEGA: f64 = 1e6;
+    /// 10^3
+    pub const KILO: f64 = 1e3;
+    /// 10^-3
+    pub const MILLI: f64 = 1e-3;
+    /// 10^-6
+    pub const MICRO: f64 = 1e-6;
+    /// 10^-9
+    pub const NANO: f64 = 1e-9;
+    /// 10^-12
+    pub const PICO: f64 = 1e-12;
+    /// 10^-15
+    pub const FEMTO: f64 = 1e-15;
+    /// 10^-18
+    pub const ATTO: f64 = 1e-18;
+    /// 10^-21
+    pub const ZEPTO: f64 = 1e-21;
+    /// 10^-24
+    pub const YOCTO: f64 = 1e-24;
+}
+
+/// Relative standard uncertainties of the measured constants, as given by the 2006 CODATA
+/// recommended values. They are the same in every unit system, so the absolute uncertainty of a
+/// constant is obtained by multiplying its value with the matching constant of this module:
+///
+/// ```
+/// use rgsl::physical_constant::{mksa, uncertainty};
+///
+/// let dh = mksa::PLANCKS_CONSTANT_H * uncertainty::PLANCKS_CONSTANT_H;
+/// ```
+///
+/// Exact constants (such as the speed of light or the unit conversion factors) have no entry.
+pub mod uncertainty {
+    // Fundamental Constants
+    /// Relative standard uncertainty of [`PLANCKS_CONSTANT_H`](super::mksa::PLANCKS_CONSTANT_H).
+    pub const PLANCKS_CONSTANT_H: f64 = 5.0e-8;
+    /// Relative standard uncertainty of [`PLANCKS_CONSTANT_HBAR`](super::mksa::PLANCKS_CONSTANT_HBAR).
+    pub const PLANCKS_CONSTANT_HBAR: f64 = 5.0e-8;
+    /// Relative standard uncertainty of [`AVOGADRO`](super::num::AVOGADRO).
+    pub const AVOGADRO: f64 = 5.0e-8;
+    /// Relative standard uncertainty of [`FARADAY`](super::mksa::FARADAY).
+    pub const FARADAY: f64 = 2.5e-8;
+    /// Relative standard uncertainty of [`BOLTZMANN`](super::mksa::BOLTZMANN).
+    pub const BOLTZMANN: f64 = 1.7e-6;
+    /// Relative standard uncertainty of [`MOLAR_GAS`](super::mksa::MOLAR_GAS).
+    pub const MOLAR_GAS: f64 = 1.7e-6;
+    /// Relative standard uncertainty of [`STANDARD_GAS_VOLUME`](super::mksa::STANDARD_GAS_VOLUME).
+    pub const STANDARD_GAS_VOLUME: f64 = 1.7e-6;
+    /// Relative standard uncertainty of [`STEFAN_BOLTZMANN_CONSTANT`](super::mksa::STEFAN_BOLTZMANN_CONSTANT).
+    pub const STEFAN_BOLTZMANN_CONSTANT: f64 = 7.0e-6;
+
+    // Astronomy and Astrophysics
+    /// Relative standard uncertainty of [`GRAVITATIONAL_CONSTANT`](super::mksa::GRAVITATIONAL_CONSTANT).
+    pub const GRAVITATIONAL_CONSTANT: f64 = 1.0e-4;
+
+    // Atomic and Nuclear Physics
+    /// Relative standard uncertainty of [`ELECTRON_CHARGE`](super::mksa::ELECTRON_CHARGE).
+    pub const ELECTRON_CHARGE: f64 = 2.5e-8;
+    /// Relative standard uncertainty of [`ELECTRON_VOLT`](super::mksa::ELECTRON_VOLT).
+    pub const ELECTRON_VOLT: f64 = 2.5e-8;
+    /// Relative standard uncertainty of [`UNIFIED_ATOMIC_MASS`](super::mksa::UNIFIED_ATOMIC_MASS).
+    pub const UNIFIED_ATOMIC_MASS: f64 = 5.0e-8;
+    /// Relative standard uncertainty of [`MASS_ELECTRON`](super::mksa::MASS_ELECTRON).
+    pub const MASS_ELECTRON: f64 = 5.0e-8;
+    /// Relative standard uncertainty of [`MASS_MUON`](super::mksa::MASS_MUON).
+    pub const MASS_MUON: f64 = 5.6e-8;
+    /// Relative standard uncertainty of [`MASS_PROTON`](super::mksa::MASS_PROTON).
+    pub const MASS_PROTON: f64 = 5.0e-8;
+    /// Relative standard uncertainty of [`MASS_NEUTRON`](super::mksa::MASS_NEUTRON).
+    pub const MASS_NEUTRON: f64 = 5.0e-8;
+    /// Relative standard uncertainty of [`FINE_STRUCTURE`](super::num::FINE_STRUCTURE).
+    pub const FINE_STRUCTURE: f64 = 6.8e-10;
+    /// Relative standard uncertainty of [`RYDBERG`](super::mksa::RYDBERG).
+    pub const RYDBERG: f64 = 5.0e-8;
+    /// Relative standard uncertainty of [`BOHR_RADIUS`](super::mksa::BOHR_RADIUS).
+    pub const BOHR_RADIUS: f64 = 6.8e-10;
+    /// Relative standard uncertainty of [`BOHR_MAGNETON`](super::mksa::BOHR_MAGNETON).
+    pub const BOHR_MAGNETON: f64 = 2.5e-8;
+    /// Relative standard uncertainty of [`NUCLEAR_MAGNETON`](super::mksa::NUCLEAR_MAGNETON).
+    pub const NUCLEAR_MAGNETON: f64 = 2.5e-8;
+    /// Relative standard uncertainty of [`ELECTRON_MAGNETIC_MOMENT`](super::mksa::ELECTRON_MAGNETIC_MOMENT).
+    pub const ELECTRON_MAGNETIC_MOMENT: f64 = 2.5e-8;
+    /// Relative standard uncertainty of [`PROTON_MAGNETIC_MOMENT`](super::mksa::PROTON_MAGNETIC_MOMENT).
+    pub const PROTON_MAGNETIC_MOMENT: f64 = 2.6e-8;
+    /// Relative standard uncertainty of [`THOMSON_CROSS_SECTION`](super::mksa::THOMSON_CROSS_SECTION).
+    pub const THOMSON_CROSS_SECTION: f64 = 4.1e-9;
+}
+
+/// Physical constants as [`uom`] quantities, so that unit errors are caught at compile time.
+///
+/// Only the constants having a matching quantity in `uom` are available here; unit conversion
+/// factors aren't provided since `uom` already handles unit conversions.
+///
+/// ```
+/// # extern crate rgsl;
+/// # extern crate uom;
+/// use rgsl::physical_constant::quantity;
+/// use uom::si::f64::{Length, Time};
+/// use uom::si::length::meter;
+/// use uom::si::time::second;
+///
+/// let distance: Length = quantity::speed_of_light() * Time::new::<second>(2.);
+/// assert_eq!(distance.get::<meter>(), 2. * 2.99792458e8);
+/// ```
+#[cfg(feature = "uom")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "uom")))]
+pub mod quantity {
+    use std::marker::PhantomData;
+    use uom::si::f64::{
+        Acceleration, Action, Area, ElectricCharge, ElectricDipoleMoment, ElectricPermittivity,
+        Energy, HeatCapacity, Length, MagneticFluxDensity, MagneticMoment, MagneticPermeability,
+        Mass, MolarHeatCapacity, MolarVolume, Velocity,
+    };
+
+    use super::mksa;
+
+    // The MKSA values are expressed in SI base units, which is how `uom` stores them.
+    macro_rules! quantity {
+        ($(#[$attr:meta])* $name:ident, $ty:ident, $value:expr) => {
+            $(#[$attr])*
+            pub const fn $name() -> $ty {
+                $ty {
+                    dimension: PhantomData,
+                    units: PhantomData,
+                    value: $value,
+                }
+            }
+        };
+    }
+
+    // Fundamental Constants
+    quantity!(
+        /// The speed of light in vacuum, c.
+        speed_of_light,
+        Velocity,
+        mksa::SPEED_OF_LIGHT
+    );
+    quantity!(
+        /// The permeability of free space, \mu_0.
+        vacuum_permeability,
+        MagneticPermeability,
+        mksa::VACUUM_PERMEABILITY
+    );
+    quantity!(
+        /// The permittivity of free space, \epsilon_0.
+        vacuum_permittivity,
+        ElectricPermittivity,
+        mksa::VACUUM_PERMITTIVITY
+    );
+    quantity!(
+        /// Planck’s constant, h.
+        plancks_constant_h,
+        Action,
+        mksa::PLANCKS_CONSTANT_H
+    );
+    quantity!(
+        /// Planck’s constant divided by 2\pi, \hbar.
+        plancks_constant_hbar,
+        Action,
+        mksa::PLANCKS_CONSTANT_HBAR
+    );
+    quantity!(
+        /// The Boltzmann constant, k.
+        boltzmann,
+        HeatCapacity,
+        mksa::BOLTZMANN
+    );
+    quantity!(
+        /// The molar gas constant, R_0.
+        molar_gas,
+        MolarHeatCapacity,
+        mksa::MOLAR_GAS
+    );
+    quantity!(
+        /// The standard gas volume, V_0.
+        standard_gas_volume,
+        MolarVolume,
+        mksa::STANDARD_GAS_VOLUME
+    );
+    quantity!(
+        /// The magnetic field of 1 Gauss.
+        gauss,
+        MagneticFluxDensity,
+        mksa::GAUSS
+    );
+
+    // Astronomy and Astrophysics
+    quantity!(
+        /// The length of 1 astronomical unit (mean earth-sun distance), au.
+        astronomical_unit,
+        Length,
+        mksa::ASTRONOMICAL_UNIT
+    );
+    quantity!(
+        /// The distance of 1 light-year, ly.
+        light_year,
+        Length,
+        mksa::LIGHT_YEAR
+    );
+    quantity!(
+        /// The distance of 1 parsec, pc.
+        parsec,
+        Length,
+        mksa::PARSEC
+    );
+    quantity!(
+        /// The standard gravitational acceleration on Earth, g.
+        grav_accel,
+        Acceleration,
+        mksa::GRAV_ACCEL
+    );
+    quantity!(
+        /// The mass of the Sun.
+        solar_mass,
+        Mass,
+        mksa::SOLAR_MASS
+    );
+
+    // Atomic and Nuclear Physics
+    quantity!(
+        /// The charge of the electron, e.
+        electron_charge,
+        ElectricCharge,
+        mksa::ELECTRON_CHARGE
+    );
+    quantity!(
+        /// The energy of 1 electron volt, eV.
+        electron_volt,
+        Energy,
+        mksa::ELECTRON_VOLT
+    );
+    quantity!(
+        /// The unified atomic mass, amu.
+        unified_atomic_mass,
+        Mass,
+        mksa::UNIFIED_ATOMIC_MASS
+    );
+    quantity!(
+        /// The mass of the electron, m_e.
+        mass_electron,
+        Mass,
+        mksa::MASS_ELECTRON
+    );
+    quantity!(
+        /// The mass of the muon, m_\mu.
+        mass_muon,
+        Mass,
+        mksa::MASS_MUON
+    );
+    quantity!(
+        /// The mass of the proton, m_p.
+        mass_proton,
+        Mass,
+        mksa::MASS_PROTON
+    );
+    quantity!(
+        /// The mass of the neutron, m_n.
+        mass_neutron,
+        Mass,
+        mksa::MASS_NEUTRON
+    );
+    quantity!(
+        /// The Rydberg constant, Ry, in units of energy.
+        rydberg,
+        Energy,
+        mksa::RYDBERG
+    );
+    quantity!(
+        /// The Bohr radius, a_0.
+        bohr_radius,
+        Length,
+        mksa::BOHR_RADIUS
+    );
+    quantity!(
+        /// The Bohr Magneton, \mu_B.
+        bohr_magneton,
+        MagneticMoment,
+        mksa::BOHR_MAGNETON
+    );
+    quantity!(
+        /// The Nuclear Magneton, \mu_N.
+        nuclear_magneton,
+        MagneticMoment,
+        mksa::NUCLEAR_MAGNETON
+    );
+    quantity!(
+        /// The absolute value of the magnetic moment of the electron, \mu_e.
+        electron_magnetic_moment,
+        MagneticMoment,
+        mksa::ELECTRON_MAGNETIC_MOMENT
+    );
+    quantity!(
+        /// The magnetic moment of the proton, \mu_p.
+        proton_magnetic_moment,
+        MagneticMoment,
+        mksa::PROTON_MAGNETIC_MOMENT
+    );
+    quantity!(
+        /// The Thomson cross section, \sigma_T.
+        thomson_cross_section,
+        Area,
+        mksa::THOMSON_CROSS_SECTION
+    );
+    quantity!(
+        /// The electric dipole moment of 1 Debye, D.
+        debye,
+        ElectricDipoleMoment,
+        mksa::DEBYE
+    );
+}
+
+// These names were previously the only way to access the constants; they are kept for
+// compatibility.
+pub use self::mksa::{
+    ACRE as MKSA_ACRE, ANGSTROM as MKSA_ANGSTROM, ASTRONOMICAL_UNIT as MKSA_ASTRONOMICAL_UNIT,
+    BAR as MKSA_BAR, BARN as MKSA_BARN, BOHR_MAGNETON as MKSA_BOHR_MAGNETON,
+    BOHR_RADIUS as MKSA_BOHR_RADIUS, BOLTZMANN as MKSA_BOLTZMANN, BTU as MKSA_BTU,
+    CALORIE as MKSA_CALORIE, CANADIAN_GALLON as MKSA_CANADIAN_GALLON, CARAT as MKSA_CARAT,
+    CUP as MKSA_CUP, CURIE as MKSA_CURIE, DAY as MKSA_DAY, DEBYE as MKSA_DEBYE, DYNE as MKSA_DYNE,
+    ELECTRON_CHARGE as MKSA_ELECTRON_CHARGE,
+    ELECTRON_MAGNETIC_MOMENT as MKSA_ELECTRON_MAGNETIC_MOMENT, ELECTRON_VOLT as MKSA_ELECTRON_VOLT,
+    ERG as MKSA_ERG, FARADAY as MKSA_FARADAY, FATHOM as MKSA_FATHOM, FOOT as MKSA_FOOT,
+    FOOTCANDLE as MKSA_FOOTCANDLE, FOOTLAMBERT as MKSA_FOOTLAMBERT, GAUSS as MKSA_GAUSS,
+    GRAM_FORCE as MKSA_GRAM_FORCE, GRAVITATIONAL_CONSTANT as MKSA_GRAVITATIONAL_CONSTANT,
+    GRAV_ACCEL as MKSA_GRAV_ACCEL, HECTARE as MKSA_HECTARE, HORSEPOWER as MKSA_HORSEPOWER,
+    HOUR as MKSA_HOUR, INCH as MKSA_INCH, INCH_OF_MERCURY as MKSA_INCH_OF_MERCURY,
+    INCH_OF_WATER as MKSA_INCH_OF_WATER, JOULE as MKSA_JOULE,
+    KILOMETERS_PER_HOUR as MKSA_KILOMETERS_PER_HOUR, KILOPOUND_FORCE as MKSA_KILOPOUND_FORCE,
+    KNOT as MKSA_KNOT, LAMBERT as MKSA_LAMBERT, LIGHT_YEAR as MKSA_LIGHT_YEAR, LITER as MKSA_LITER,
+    LUMEN as MKSA_LUMEN, LUX as MKSA_LUX, MASS_ELECTRON as MKSA_MASS_ELECTRON,
+    MASS_MUON as MKSA_MASS_MUON, MASS_NEUTRON as MKSA_MASS_NEUTRON,
+    MASS_PROTON as MKSA_MASS_PROTON, METER_OF_MERCURY as MKSA_METER_OF_MERCURY,
+    METRIC_TON as MKSA_METRIC_TON, MICRON as MKSA_MICRON, MIL as MKSA_MIL, MILE as MKSA_MILE,
+    MILES_PER_HOUR as MKSA_MILES_PER_HOUR, MINUTE as MKSA_MINUTE, MOLAR_GAS as MKSA_MOLAR_GAS,
+    NAUTICAL_MILE as MKSA_NAUTICAL_MILE, NEWTON as MKSA_NEWTON,
+    NUCLEAR_MAGNETON as MKSA_NUCLEAR_MAGNETON, OUNCE_MASS as MKSA_OUNCE_MASS,
+    PARSEC as MKSA_PARSEC, PHOT as MKSA_PHOT, PINT as MKSA_PINT,
+    PLANCKS_CONSTANT_H as MKSA_PLANCKS_CONSTANT_H,
+    PLANCKS_CONSTANT_HBAR as MKSA_PLANCKS_CONSTANT_HBAR, POINT as MKSA_POINT, POISE as MKSA_POISE,
+    POUNDAL as MKSA_POUNDAL, POUND_FORCE as MKSA_POUND_FORCE, POUND_MASS as MKSA_POUND_MASS,
+    PROTON_MAGNETIC_MOMENT as MKSA_PROTON_MAGNETIC_MOMENT, PSI as MKSA_PSI, QUART as MKSA_QUART,
+    RAD as MKSA_RAD, ROENTGEN as MKSA_ROENTGEN, RYDBERG as MKSA_RYDBERG,
+    SOLAR_MASS as MKSA_SOLAR_MASS, SPEED_OF_LIGHT as MKSA_SPEED_OF_LIGHT,
+    STANDARD_GAS_VOLUME as MKSA_STANDARD_GAS_VOLUME, STD_ATMOSPHERE as MKSA_STD_ATMOSPHERE,
+    STEFAN_BOLTZMANN_CONSTANT as MKSA_STEFAN_BOLTZMANN_CONSTANT, STILB as MKSA_STILB,
+    STOKES as MKSA_STOKES, TEXPOINT as MKSA_TEXPOINT, THERM as MKSA_THERM,
+    THOMSON_CROSS_SECTION as MKSA_THOMSON_CROSS_SECTION, TON as MKSA_TON, TORR as MKSA_TORR,
+    TROY_OUNCE as MKSA_TROY_OUNCE, UK_GALLON as MKSA_UK_GALLON, UK_TON as MKSA_UK_TON,
+    UNIFIED_ATOMIC_MASS as MKSA_UNIFIED_ATOMIC_MASS, US_GALLON as MKSA_US_GALLON,
+    VACUUM_PERMEABILITY as MKSA_VACUUM_PERMEABILITY,
+    VACUUM_PERMITTIVITY as MKSA_VACUUM_PERMITTIVITY, WEEK as MKSA_WEEK, YARD as MKSA_YARD,
+};
+pub use self::num::{
+    ATTO as NUM_ATTO, AVOGADRO as NUM_AVOGADRO, EXA as NUM_EXA, FEMTO as NUM_FEMTO,
+    FINE_STRUCTURE as NUM_FINE_STRUCTURE, GIGA as NUM_GIGA, KILO as NUM_KILO, MEGA as NUM_MEGA,
+    MICRO as NUM_MICRO, MILLI as NUM_MILLI, NANO as NUM_NANO, PETA as NUM_PETA, PICO as NUM_PICO,
+    TERA as NUM_TERA, YOCTO as NUM_YOCTO, YOTTA as NUM_YOTTA, ZEPTO as NUM_ZEPTO,
+    ZETTA as NUM_ZETTA,
+};
@@ -26,8 +26,8 @@ R. L. Burden and J. D. Faires, Numerical Analysis, 9th edition, ISBN 0-538-73351
 /// `P(x) = c[0] + c[1] x + c[2] x^2 + \dots + c[len-1] x^{len-1}` using Horner’s method for
 /// stability.
 pub mod evaluation {
-    use crate::Value;
-    use std::mem::transmute;
+    use crate::Error;
+    use types::complex::FFFI;
     use types::ComplexF64;
 
     /// This function evaluates a polynomial with real coefficients for the real variable x.
@@ -39,37 +39,25 @@ pub mod evaluation {
     /// This function evaluates a polynomial with real coefficients for the complex variable z.
     #[doc(alias = "gsl_poly_complex_eval")]
     pub fn poly_complex_eval(c: &[f64], z: &ComplexF64) -> ComplexF64 {
-        unsafe {
-            transmute(sys::gsl_poly_complex_eval(
-                c.as_ptr(),
-                c.len() as i32,
-                transmute(*z),
-            ))
-        }
+        let z = sys::gsl_complex { dat: z.dat };
+        unsafe { sys::gsl_poly_complex_eval(c.as_ptr(), c.len() as i32, z).wrap() }
     }
 
     /// This function evaluates a polynomial with complex coefficients for the complex variable z.
     #[doc(alias = "gsl_complex_poly_complex_eval")]
     pub fn complex_poly_complex_eval(c: &[ComplexF64], z: &ComplexF64) -> ComplexF64 {
-        let mut tmp = Vec::new();
-
-        for it in c.iter() {
-            unsafe { tmp.push(transmute(*it)) };
-        }
+        let z = sys::gsl_complex { dat: z.dat };
+        // `ComplexF64` has the same layout as `gsl_complex`.
         unsafe {
-            transmute(sys::gsl_complex_poly_complex_eval(
-                tmp.as_ptr(),
-                tmp.len() as i32,
-                transmute(*z),
-            ))
+            sys::gsl_complex_poly_complex_eval(c.as_ptr() as *const _, c.len() as i32, z).wrap()
         }
     }
 
     /// This function evaluates a polynomial and its derivatives storing the results in the array res of size lenres. The output array contains
     /// the values of d^k P/d x^k for the specified value of x starting with k = 0.
     #[doc(alias = "gsl_poly_eval_derivs")]
-    pub fn poly_eval_derivs(c: &[f64], x: f64, res: &mut [f64]) -> Value {
-        Value::from(unsafe {
+    pub fn poly_eval_derivs(c: &[f64], x: f64, res: &mut [f64]) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_poly_eval_derivs(
                 c.as_ptr(),
                 c.len() as _,
@@ -77,7 +65,8 @@ pub mod evaluation {
                 res.as_mut_ptr(),
                 res.len() as _,
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_poly_eval_derivs")
     }
 }
 
@@ -98,16 +87,18 @@ pub mod evaluation {
 /// where the elements of z = \{x_0,x_0,x_1,x_1,...,x_n,x_n\} are defined by z_{2k} = z_{2k+1} = x_k. The divided-differences [z_0,z_1,...,z_k]
 /// are discussed in Burden and Faires, section 3.4.
 pub mod divided_difference_representation {
-    use crate::Value;
+    use crate::Error;
 
     /// This function computes a divided-difference representation of the interpolating polynomial for the points (x, y) stored in the arrays
     /// xa and ya of length size. On output the divided-differences of (xa,ya) are stored in the array dd, also of length size. Using the
     /// notation above, `dd[k] = [x_0,x_1,...,x_k]`.
     #[doc(alias = "gsl_poly_dd_init")]
-    pub fn poly_dd_init(dd: &mut [f64], xa: &[f64], ya: &[f64]) -> Value {
-        Value::from(unsafe {
+    pub fn poly_dd_init(dd: &mut [f64], xa: &[f64], ya: &[f64]) -> Result<(), Error> {
+        assert!(xa.len() == dd.len() && ya.len() == dd.len());
+        let ret = unsafe {
             sys::gsl_poly_dd_init(dd.as_mut_ptr(), xa.as_ptr(), ya.as_ptr(), dd.len() as _)
-        })
+        };
+        result_handler!(ret, (), "gsl_poly_dd_init")
     }
 
     /// This function evaluates the polynomial stored in divided-difference form in the arrays dd and xa of length size at the point x.
@@ -120,8 +111,15 @@ pub mod divided_difference_representation {
     /// is supplied in the arrays dd and xa of length size. On output the Taylor coefficients of the polynomial expanded about the point xp are
     /// stored in the array c also of length size. A workspace of length size must be provided in the array w.
     #[doc(alias = "gsl_poly_dd_taylor")]
-    pub fn poly_dd_taylor(c: &mut [f64], xp: f64, dd: &[f64], xa: &[f64], w: &mut [f64]) -> Value {
-        Value::from(unsafe {
+    pub fn poly_dd_taylor(
+        c: &mut [f64],
+        xp: f64,
+        dd: &[f64],
+        xa: &[f64],
+        w: &mut [f64],
+    ) -> Result<(), Error> {
+        assert!(c.len() >= dd.len() && xa.len() >= dd.len() && w.len() >= dd.len());
+        let ret = unsafe {
             sys::gsl_poly_dd_taylor(
                 c.as_mut_ptr(),
                 xp,
@@ -130,7 +128,8 @@ pub mod divided_difference_representation {
                 dd.len() as _,
                 w.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_poly_dd_taylor")
     }
 
     /// This function computes a divided-difference representation of the interpolating Hermite polynomial for the points (x, y) stored in the
@@ -146,32 +145,70 @@ pub mod divided_difference_representation {
         xa: &[f64],
         ya: &[f64],
         dya: &[f64],
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        assert!(dd.len() == 2 * xa.len() && za.len() == dd.len());
+        assert!(ya.len() == xa.len() && dya.len() == xa.len());
+        let ret = unsafe {
             sys::gsl_poly_dd_hermite_init(
                 dd.as_mut_ptr(),
                 za.as_mut_ptr(),
                 xa.as_ptr(),
                 ya.as_ptr(),
                 dya.as_ptr(),
-                dd.len() as _,
+                xa.len() as _,
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_poly_dd_hermite_init")
     }
 }
 
 pub mod quadratic_equations {
-    use std::mem::transmute;
+    use types::complex::FFFI;
     use types::ComplexF64;
 
+    /// Real roots of a quadratic equation, as returned by [`poly_solve_quadratic`].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum QuadraticRoots {
+        /// The discriminant is negative: there is no real root.
+        None,
+        /// The equation is degenerate (`a == 0`) and has a single root.
+        One(f64),
+        /// Two roots, in ascending order. Coincident roots are reported twice.
+        Two(f64, f64),
+    }
+
+    impl QuadraticRoots {
+        /// Returns the number of roots found.
+        pub fn len(&self) -> usize {
+            match *self {
+                QuadraticRoots::None => 0,
+                QuadraticRoots::One(_) => 1,
+                QuadraticRoots::Two(_, _) => 2,
+            }
+        }
+
+        /// Returns `true` if no real root was found.
+        pub fn is_empty(&self) -> bool {
+            *self == QuadraticRoots::None
+        }
+    }
+
+    /// Complex roots of a quadratic equation, as returned by [`poly_complex_solve_quadratic`].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum ComplexQuadraticRoots {
+        /// The equation is degenerate (`a == 0`) and has a single root.
+        One(ComplexF64),
+        /// Two roots, sorted by real then imaginary part.
+        Two(ComplexF64, ComplexF64),
+    }
+
     /// This function finds the real roots of the quadratic equation,
     ///
     /// a x^2 + b x + c = 0
     ///
-    /// The number of real roots (either zero, one or two) is returned, and their locations are
-    /// stored in x0 and x1. If no real roots are found then x0 and x1 are not modified. If one real
-    /// root is found (i.e. if a=0) then it is stored in x0. When two real roots are found they
-    /// are stored in x0 and x1 in ascending order. The case of coincident roots is not considered
+    /// The number of real roots is either zero, one or two. If one real root is found (i.e. if
+    /// a=0) then it is returned as [`QuadraticRoots::One`]. When two real roots are found they
+    /// are returned in ascending order. The case of coincident roots is not considered
     /// special. For example (x-1)^2=0 will have two roots, which happen to have exactly equal
     /// values.
     ///
@@ -180,81 +217,98 @@ pub mod quadratic_equations {
     /// be subject to errors if the coefficients of the polynomial are inexact. These errors
     /// may cause a discrete change in the number of roots. However, for polynomials with small
     /// integer coefficients the discriminant can always be computed exactly.
-    ///
-    /// Returns `(Value, x0, x1)`.
     #[doc(alias = "gsl_poly_solve_quadratic")]
-    pub fn poly_solve_quadratic(a: f64, b: f64, c: f64) -> (::Value, f64, f64) {
+    pub fn poly_solve_quadratic(a: f64, b: f64, c: f64) -> QuadraticRoots {
         let mut x0 = 0.;
         let mut x1 = 0.;
-        let ret = unsafe { sys::gsl_poly_solve_quadratic(a, b, c, &mut x0, &mut x1) };
-        (::Value::from(ret), x0, x1)
+        match unsafe { sys::gsl_poly_solve_quadratic(a, b, c, &mut x0, &mut x1) } {
+            0 => QuadraticRoots::None,
+            1 => QuadraticRoots::One(x0),
+            _ => QuadraticRoots::Two(x0, x1),
+        }
     }
 
     /// This function finds the complex roots of the quadratic equation,
     ///
     /// a z^2 + b z + c = 0
     ///
-    /// The number of complex roots is returned (either one or two) and the locations of the roots are stored in z0 and z1. The roots are returned
-    /// in ascending order, sorted first by their real components and then by their imaginary components. If only one real root is found (i.e. if
-    /// a=0) then it is stored in z0.
+    /// The roots are returned in ascending order, sorted first by their real components and then
+    /// by their imaginary components. If only one root is found (i.e. if a=0) then
+    /// [`ComplexQuadraticRoots::One`] is returned.
     #[doc(alias = "gsl_poly_complex_solve_quadratic")]
-    pub fn poly_complex_solve_quadratic(
-        a: f64,
-        b: f64,
-        c: f64,
-        z0: &mut ComplexF64,
-        z1: &mut ComplexF64,
-    ) -> ::Value {
-        ::Value::from(unsafe {
-            sys::gsl_poly_complex_solve_quadratic(a, b, c, transmute(z0), transmute(z1))
-        })
+    pub fn poly_complex_solve_quadratic(a: f64, b: f64, c: f64) -> ComplexQuadraticRoots {
+        let mut z0 = sys::gsl_complex { dat: [0.; 2] };
+        let mut z1 = sys::gsl_complex { dat: [0.; 2] };
+        let n = unsafe { sys::gsl_poly_complex_solve_quadratic(a, b, c, &mut z0, &mut z1) };
+        if n == 1 {
+            ComplexQuadraticRoots::One(z0.wrap())
+        } else {
+            ComplexQuadraticRoots::Two(z0.wrap(), z1.wrap())
+        }
     }
 }
 
 pub mod cubic_equations {
-    use std::mem::transmute;
+    use types::complex::FFFI;
     use types::ComplexF64;
 
+    /// Real roots of a cubic equation, as returned by [`poly_solve_cubic`].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum CubicRoots {
+        /// A single real root; the two others are complex conjugates.
+        One(f64),
+        /// Three real roots, in ascending order. Coincident roots are repeated.
+        Three(f64, f64, f64),
+    }
+
+    impl CubicRoots {
+        /// Returns the number of real roots found.
+        #[allow(clippy::len_without_is_empty)]
+        pub fn len(&self) -> usize {
+            match *self {
+                CubicRoots::One(_) => 1,
+                CubicRoots::Three(_, _, _) => 3,
+            }
+        }
+    }
+
     /// This function finds the real roots of the cubic equation,
     ///
     /// x^3 + a x^2 + b x + c = 0
     ///
-    /// with a leading coefficient of unity. The number of real roots (either one or three) is
-    /// returned, and their locations are stored in x0, x1 and x2. If one real root is found then
-    /// only x0 is modified. When three real roots are found they are stored in x0, x1 and x2 in
-    /// ascending order. The case of coincident roots is not considered special. For example, the
-    /// equation (x-1)^3=0 will have three roots with exactly equal values. As in the quadratic
-    /// case, finite precision may cause equal or closely-spaced real roots to move off the
-    /// real axis into the complex plane, leading to a discrete change in the number of real roots.
-    ///
-    /// Returns `(Value, x0, x1, x2)`.
+    /// with a leading coefficient of unity. The number of real roots is either one or three.
+    /// When three real roots are found they are returned in ascending order. The case of
+    /// coincident roots is not considered special. For example, the equation (x-1)^3=0 will have
+    /// three roots with exactly equal values. As in the quadratic case, finite precision may cause
+    /// equal or closely-spaced real roots to move off the real axis into the complex plane,
+    /// leading to a discrete change in the number of real roots.
     #[doc(alias = "gsl_poly_solve_cubic")]
-    pub fn poly_solve_cubic(a: f64, b: f64, c: f64) -> (::Value, f64, f64, f64) {
+    pub fn poly_solve_cubic(a: f64, b: f64, c: f64) -> CubicRoots {
         let mut x0 = 0.;
         let mut x1 = 0.;
         let mut x2 = 0.;
-        let ret = unsafe { sys::gsl_poly_solve_cubic(a, b, c, &mut x0, &mut x1, &mut x2) };
-        (::Value::from(ret), x0, x1, x2)
+        match unsafe { sys::gsl_poly_solve_cubic(a, b, c, &mut x0, &mut x1, &mut x2) } {
+            1 => CubicRoots::One(x0),
+            _ => CubicRoots::Three(x0, x1, x2),
+        }
     }
 
     /// This function finds the complex roots of the cubic equation,
     ///
     /// z^3 + a z^2 + b z + c = 0
     ///
-    /// The number of complex roots is returned (always three) and the locations of the roots are
-    /// stored in z0, z1 and z2. The roots are returned in ascending order, sorted first by their
-    /// real components and then by their imaginary components.
+    /// There are always three complex roots. They are returned in ascending order, sorted first by
+    /// their real components and then by their imaginary components.
     #[doc(alias = "gsl_poly_complex_solve_cubic")]
     pub fn poly_complex_solve_cubic(
         a: f64,
         b: f64,
         c: f64,
-        z0: &mut ComplexF64,
-        z1: &mut ComplexF64,
-        z2: &mut ComplexF64,
-    ) -> ::Value {
-        ::Value::from(unsafe {
-            sys::gsl_poly_complex_solve_cubic(a, b, c, transmute(z0), transmute(z1), transmute(z2))
-        })
+    ) -> (ComplexF64, ComplexF64, ComplexF64) {
+        let mut z0 = sys::gsl_complex { dat: [0.; 2] };
+        let mut z1 = sys::gsl_complex { dat: [0.; 2] };
+        let mut z2 = sys::gsl_complex { dat: [0.; 2] };
+        unsafe { sys::gsl_poly_complex_solve_cubic(a, b, c, &mut z0, &mut z1, &mut z2) };
+        (z0.wrap(), z1.wrap(), z2.wrap())
     }
 }
@@ -5,8 +5,8 @@
 //! The following functions are equivalent to the function gsl_pow_int (see
 //! [Small integer powers](Pow.html)) with an error estimate.
 
-use crate::Value;
-use std::mem::MaybeUninit;
+use crate::Error;
+use core::mem::MaybeUninit;
 
 /// This routine computes the power x^n for integer n. The power is computed using the minimum
 /// number of multiplications. For example, x^8 is computed as ((x^2)^2)^2, requiring only 3
@@ -36,9 +36,13 @@ pub fn pow_int(x: f64, n: i32) -> f64 {
 /// println!("{:?}", pow_int_e(3., 12));
 /// ```
 #[doc(alias = "gsl_sf_pow_int_e")]
-pub fn pow_int_e(x: f64, n: i32) -> (Value, ::types::Result) {
+pub fn pow_int_e(x: f64, n: i32) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_pow_int_e(x, n, result.as_mut_ptr()) };
 
-    (Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_pow_int_e"
+    )
 }
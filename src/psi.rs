@@ -11,8 +11,8 @@ where \psi(x) = \Gamma'(x)/\Gamma(x) is known as the digamma function.
 !*/
 
 pub mod diagamma {
-    use crate::Value;
-    use std::mem::MaybeUninit;
+    use crate::Error;
+    use core::mem::MaybeUninit;
 
     /// This routine computes the digamma function \psi(n) for positive integer n. The digamma function is also called the Psi function.
     #[doc(alias = "gsl_sf_psi_int")]
@@ -22,11 +22,15 @@ pub mod diagamma {
 
     /// This routine computes the digamma function \psi(n) for positive integer n. The digamma function is also called the Psi function.
     #[doc(alias = "gsl_sf_psi_int_e")]
-    pub fn psi_int_e(n: i32) -> (Value, ::types::Result) {
+    pub fn psi_int_e(n: i32) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_psi_int_e(n, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_psi_int_e"
+        )
     }
 
     /// This routine computes the digamma function \psi(x) for general x, x \ne 0.
@@ -37,11 +41,11 @@ pub mod diagamma {
 
     /// This routine computes the digamma function \psi(x) for general x, x \ne 0.
     #[doc(alias = "gsl_sf_psi_e")]
-    pub fn psi_e(x: f64) -> (Value, ::types::Result) {
+    pub fn psi_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_psi_e(x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(ret, unsafe { result.assume_init() }.into(), "gsl_sf_psi_e")
     }
 
     /// This routine computes the real part of the digamma function on the line 1+i y, \Re[\psi(1 + i y)].
@@ -52,17 +56,41 @@ pub mod diagamma {
 
     /// This routine computes the real part of the digamma function on the line 1+i y, \Re[\psi(1 + i y)].
     #[doc(alias = "gsl_sf_psi_1piy_e")]
-    pub fn psi_1piy_e(x: f64) -> (Value, ::types::Result) {
+    pub fn psi_1piy_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_psi_1piy_e(x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_psi_1piy_e"
+        )
+    }
+
+    /// This routine computes the complex digamma function \psi(x + i y). The real and imaginary
+    /// parts of the result are returned in result_re, result_im.
+    #[doc(alias = "gsl_sf_complex_psi_e")]
+    pub fn complex_psi_e(x: f64, y: f64) -> Result<(::types::Result, ::types::Result), Error> {
+        let mut result_re = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let mut result_im = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let ret = unsafe {
+            sys::gsl_sf_complex_psi_e(x, y, result_re.as_mut_ptr(), result_im.as_mut_ptr())
+        };
+
+        result_handler!(
+            ret,
+            (
+                unsafe { result_re.assume_init() }.into(),
+                unsafe { result_im.assume_init() }.into()
+            ),
+            "gsl_sf_complex_psi_e"
+        )
     }
 }
 
 pub mod trigamma {
-    use crate::Value;
-    use std::mem::MaybeUninit;
+    use crate::Error;
+    use core::mem::MaybeUninit;
 
     /// This routine computes the Trigamma function \psi'(n) for positive integer n.
     #[doc(alias = "gsl_sf_psi_1_int")]
@@ -72,11 +100,15 @@ pub mod trigamma {
 
     /// This routine computes the Trigamma function \psi'(n) for positive integer n.
     #[doc(alias = "gsl_sf_psi_1_int_e")]
-    pub fn psi_1_int_e(n: i32) -> (Value, ::types::Result) {
+    pub fn psi_1_int_e(n: i32) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_psi_1_int_e(n, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_psi_1_int_e"
+        )
     }
 
     /// This routine computes the Trigamma function \psi'(x) for general x.
@@ -87,17 +119,21 @@ pub mod trigamma {
 
     /// This routine computes the Trigamma function \psi'(x) for general x.
     #[doc(alias = "gsl_sf_psi_1_e")]
-    pub fn psi_1_e(x: f64) -> (Value, ::types::Result) {
+    pub fn psi_1_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_psi_1_e(x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_psi_1_e"
+        )
     }
 }
 
 pub mod polygamma {
-    use crate::Value;
-    use std::mem::MaybeUninit;
+    use crate::Error;
+    use core::mem::MaybeUninit;
 
     /// This routine computes the polygamma function \psi^{(n)}(x) for n >= 0, x > 0.
     #[doc(alias = "gsl_sf_psi_n")]
@@ -107,10 +143,14 @@ pub mod polygamma {
 
     /// This routine computes the polygamma function \psi^{(n)}(x) for n >= 0, x > 0.
     #[doc(alias = "gsl_sf_psi_n_e")]
-    pub fn psi_n_e(n: i32, x: f64) -> (Value, ::types::Result) {
+    pub fn psi_n_e(n: i32, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_psi_n_e(n, x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_psi_n_e"
+        )
     }
 }
@@ -6,6 +6,7 @@
 /// for a Dirichlet distribution with parameters `alpha[K]`, using the formula given above.
 #[doc(alias = "gsl_ran_dirichlet_pdf")]
 pub fn dirichlet_pdf(alpha: &[f64], theta: &[f64]) -> f64 {
+    assert!(theta.len() == alpha.len());
     unsafe { sys::gsl_ran_dirichlet_pdf(alpha.len() as _, alpha.as_ptr(), theta.as_ptr()) }
 }
 
@@ -13,5 +14,6 @@ pub fn dirichlet_pdf(alpha: &[f64], theta: &[f64]) -> f64 {
 /// for a Dirichlet distribution with parameters `alpha[K]`.
 #[doc(alias = "gsl_ran_dirichlet_lnpdf")]
 pub fn dirichlet_lnpdf(alpha: &[f64], theta: &[f64]) -> f64 {
+    assert!(theta.len() == alpha.len());
     unsafe { sys::gsl_ran_dirichlet_lnpdf(alpha.len() as _, alpha.as_ptr(), theta.as_ptr()) }
 }
@@ -76,6 +76,9 @@ pub mod logarithmic;
 pub mod logistic;
 pub mod lognormal;
 pub mod multinomial;
+#[cfg(feature = "v2_2")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+pub mod multivariate_gaussian;
 pub mod negative_binomial;
 pub mod pareto;
 pub mod pascal;
@@ -84,3 +87,6 @@ pub mod rayleigh;
 pub mod rayleigh_tail;
 pub mod t_distribution;
 pub mod weibull;
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+pub mod wishart;
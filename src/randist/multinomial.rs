@@ -6,6 +6,7 @@
 /// multinomial distribution with parameters `p[K]`, using the formula given above.
 #[doc(alias = "gsl_ran_multinomial_pdf")]
 pub fn multinomial_pdf(p: &[f64], n: &[u32]) -> f64 {
+    assert!(n.len() == p.len());
     unsafe { sys::gsl_ran_multinomial_pdf(p.len() as _, p.as_ptr(), n.as_ptr()) }
 }
 
@@ -13,5 +14,6 @@ pub fn multinomial_pdf(p: &[f64], n: &[u32]) -> f64 {
 /// distribution P(n_1, n_2, ..., n_K) with parameters `p[K]`.
 #[doc(alias = "gsl_ran_multinomial_lnpdf")]
 pub fn multinomial_lnpdf(p: &[f64], n: &[u32]) -> f64 {
+    assert!(n.len() == p.len());
     unsafe { sys::gsl_ran_multinomial_lnpdf(p.len() as _, p.as_ptr(), n.as_ptr()) }
 }
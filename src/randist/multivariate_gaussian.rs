@@ -0,0 +1,107 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+//! The multivariate Gaussian distribution with mean `mu` and covariance matrix `Sigma`. All the
+//! functions take the Cholesky factor `L` of `Sigma` (so that `Sigma = L L^T`), as computed by
+//! [`cholesky_decomp1`](../../linear_algebra/fn.cholesky_decomp1.html). Only the lower triangle
+//! of `L` is used.
+
+use crate::Error;
+use ffi::FFI;
+
+/// This function computes the probability density p(x) at the point `x` for the multivariate
+/// Gaussian distribution with mean `mu` and Cholesky factor `L`. `work` must have the same
+/// length as `x`.
+#[doc(alias = "gsl_ran_multivariate_gaussian_pdf")]
+pub fn multivariate_gaussian_pdf(
+    x: &::VectorF64,
+    mu: &::VectorF64,
+    L: &::MatrixF64,
+    work: &mut ::VectorF64,
+) -> Result<f64, Error> {
+    let mut result = 0.;
+    let ret = unsafe {
+        sys::gsl_ran_multivariate_gaussian_pdf(
+            x.unwrap_shared(),
+            mu.unwrap_shared(),
+            L.unwrap_shared(),
+            &mut result,
+            work.unwrap_unique(),
+        )
+    };
+    result_handler!(ret, result, "gsl_ran_multivariate_gaussian_pdf")
+}
+
+/// This function computes the logarithm of the probability density p(x) at the point `x`, see
+/// [`multivariate_gaussian_pdf`].
+#[doc(alias = "gsl_ran_multivariate_gaussian_log_pdf")]
+pub fn multivariate_gaussian_log_pdf(
+    x: &::VectorF64,
+    mu: &::VectorF64,
+    L: &::MatrixF64,
+    work: &mut ::VectorF64,
+) -> Result<f64, Error> {
+    let mut result = 0.;
+    let ret = unsafe {
+        sys::gsl_ran_multivariate_gaussian_log_pdf(
+            x.unwrap_shared(),
+            mu.unwrap_shared(),
+            L.unwrap_shared(),
+            &mut result,
+            work.unwrap_unique(),
+        )
+    };
+    result_handler!(ret, result, "gsl_ran_multivariate_gaussian_log_pdf")
+}
+
+/// This function computes the maximum-likelihood estimate of the mean of the distribution from
+/// the samples stored in the rows of `X` (one sample per row), and stores it in `mu_hat`.
+#[doc(alias = "gsl_ran_multivariate_gaussian_mean")]
+pub fn multivariate_gaussian_mean(X: &::MatrixF64, mu_hat: &mut ::VectorF64) -> Result<(), Error> {
+    let ret = unsafe {
+        sys::gsl_ran_multivariate_gaussian_mean(X.unwrap_shared(), mu_hat.unwrap_unique())
+    };
+    result_handler!(ret, (), "gsl_ran_multivariate_gaussian_mean")
+}
+
+/// This function computes the maximum-likelihood estimate of the variance-covariance matrix of
+/// the distribution from the samples stored in the rows of `X`, and stores it in `sigma_hat`.
+#[doc(alias = "gsl_ran_multivariate_gaussian_vcov")]
+pub fn multivariate_gaussian_vcov(
+    X: &::MatrixF64,
+    sigma_hat: &mut ::MatrixF64,
+) -> Result<(), Error> {
+    let ret = unsafe {
+        sys::gsl_ran_multivariate_gaussian_vcov(X.unwrap_shared(), sigma_hat.unwrap_unique())
+    };
+    result_handler!(ret, (), "gsl_ran_multivariate_gaussian_vcov")
+}
+
+#[test]
+fn multivariate_gaussian() {
+    use std::f64::consts::PI;
+
+    // With the identity as Cholesky factor this is the standard normal in two dimensions.
+    let mut L = ::MatrixF64::new(2, 2).unwrap();
+    L.set_identity();
+    let mu = ::VectorF64::from_slice(&[1., -1.]).unwrap();
+    let mut work = ::VectorF64::new(2).unwrap();
+
+    let p = multivariate_gaussian_pdf(&mu, &mu, &L, &mut work).unwrap();
+    assert!((p - 1. / (2. * PI)).abs() < 1e-12);
+    let lp = multivariate_gaussian_log_pdf(&mu, &mu, &L, &mut work).unwrap();
+    assert!((lp - p.ln()).abs() < 1e-12);
+
+    // One sample per row: (0, 0) and (2, -2).
+    let mut X = ::MatrixF64::new(2, 2).unwrap();
+    X.set(1, 0, 2.);
+    X.set(1, 1, -2.);
+    let mut mu_hat = ::VectorF64::new(2).unwrap();
+    multivariate_gaussian_mean(&X, &mut mu_hat).unwrap();
+    assert_eq!(mu_hat.as_slice(), Some(&[1., -1.][..]));
+
+    let mut r = ::Rng::new(::RngType::default()).unwrap();
+    let mut x = ::VectorF64::new(2).unwrap();
+    r.multivariate_gaussian(&mu, &L, &mut x).unwrap();
+}
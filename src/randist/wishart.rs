@@ -0,0 +1,58 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+//! The Wishart distribution of `p x p` positive definite matrices with `df` degrees of freedom
+//! and scale matrix `V = L L^T`. As for the multivariate Gaussian, the functions take the
+//! Cholesky factor `L` rather than `V` itself.
+
+use crate::Error;
+use ffi::FFI;
+
+/// This function computes the probability density at the matrix `X`, whose Cholesky factor must
+/// be given in `L_X`, for a Wishart distribution with `df` degrees of freedom and scale factor
+/// `L`. `work` is a `p x p` workspace.
+#[doc(alias = "gsl_ran_wishart_pdf")]
+pub fn wishart_pdf(
+    X: &::MatrixF64,
+    L_X: &::MatrixF64,
+    df: f64,
+    L: &::MatrixF64,
+    work: &mut ::MatrixF64,
+) -> Result<f64, Error> {
+    let mut result = 0.;
+    let ret = unsafe {
+        sys::gsl_ran_wishart_pdf(
+            X.unwrap_shared(),
+            L_X.unwrap_shared(),
+            df,
+            L.unwrap_shared(),
+            &mut result,
+            work.unwrap_unique(),
+        )
+    };
+    result_handler!(ret, result, "gsl_ran_wishart_pdf")
+}
+
+/// This function computes the logarithm of the probability density at `X`, see [`wishart_pdf`].
+#[doc(alias = "gsl_ran_wishart_log_pdf")]
+pub fn wishart_log_pdf(
+    X: &::MatrixF64,
+    L_X: &::MatrixF64,
+    df: f64,
+    L: &::MatrixF64,
+    work: &mut ::MatrixF64,
+) -> Result<f64, Error> {
+    let mut result = 0.;
+    let ret = unsafe {
+        sys::gsl_ran_wishart_log_pdf(
+            X.unwrap_shared(),
+            L_X.unwrap_shared(),
+            df,
+            L.unwrap_shared(),
+            &mut result,
+            work.unwrap_unique(),
+        )
+    };
+    result_handler!(ret, result, "gsl_ran_wishart_log_pdf")
+}
@@ -2,17 +2,66 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
+/*!
+Convergence tests for the one-dimensional root finding algorithms.
+
+They return [`ConvergenceStatus::Converged`](crate::ConvergenceStatus::Converged) when the
+criterion is satisfied and [`ConvergenceStatus::Continue`](crate::ConvergenceStatus::Continue)
+otherwise, and fail if the tolerances are invalid (for example if `epsrel` is negative):
+
+```
+use rgsl::{roots, ConvergenceStatus};
+
+assert_eq!(roots::test_interval(1., 1.001, 0., 1e-2), Ok(ConvergenceStatus::Converged));
+assert_eq!(roots::test_interval(1., 2., 0., 1e-2), Ok(ConvergenceStatus::Continue));
+assert!(roots::test_interval(1., 2., 0., -1.).is_err());
+```
+!*/
+
+use crate::{ConvergenceStatus, Error};
+
+/// This function tests for the convergence of the interval [x_lower, x_upper] with absolute
+/// error `epsabs` and relative error `epsrel`. The interval has converged when
+///
+/// ```text
+/// |a - b| < epsabs + epsrel min(|a|,|b|)
+/// ```
+///
+/// when the interval x = [a,b] does not include the origin. If the interval includes the origin
+/// then min(|a|,|b|) is replaced by zero.
 #[doc(alias = "gsl_root_test_interval")]
-pub fn test_interval(x_lower: f64, x_upper: f64, epsabs: f64, epsrel: f64) -> ::Value {
-    ::Value::from(unsafe { sys::gsl_root_test_interval(x_lower, x_upper, epsabs, epsrel) })
+pub fn test_interval(
+    x_lower: f64,
+    x_upper: f64,
+    epsabs: f64,
+    epsrel: f64,
+) -> Result<ConvergenceStatus, Error> {
+    ConvergenceStatus::from_test(
+        unsafe { sys::gsl_root_test_interval(x_lower, x_upper, epsabs, epsrel) },
+        "gsl_root_test_interval",
+    )
 }
 
+/// This function tests the residual value `f` against the absolute error bound `epsabs`. It
+/// has converged when |f| < epsabs.
 #[doc(alias = "gsl_root_test_residual")]
-pub fn test_residual(f: f64, epsabs: f64) -> ::Value {
-    ::Value::from(unsafe { sys::gsl_root_test_residual(f, epsabs) })
+pub fn test_residual(f: f64, epsabs: f64) -> Result<ConvergenceStatus, Error> {
+    ConvergenceStatus::from_test(
+        unsafe { sys::gsl_root_test_residual(f, epsabs) },
+        "gsl_root_test_residual",
+    )
 }
 
+/// This function tests for the convergence of the sequence ..., `x0`, `x1` with absolute error
+/// `epsabs` and relative error `epsrel`. It has converged when
+///
+/// ```text
+/// |x_1 - x_0| < epsabs + epsrel |x_1|
+/// ```
 #[doc(alias = "gsl_root_test_delta")]
-pub fn test_delta(x1: f64, x0: f64, epsabs: f64, epsrel: f64) -> ::Value {
-    ::Value::from(unsafe { sys::gsl_root_test_delta(x1, x0, epsabs, epsrel) })
+pub fn test_delta(x1: f64, x0: f64, epsabs: f64, epsrel: f64) -> Result<ConvergenceStatus, Error> {
+    ConvergenceStatus::from_test(
+        unsafe { sys::gsl_root_test_delta(x1, x0, epsabs, epsrel) },
+        "gsl_root_test_delta",
+    )
 }
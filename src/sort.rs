@@ -21,30 +21,203 @@ The Heapsort algorithm is described in the following book,
 Robert Sedgewick, Algorithms in C, Addison-Wesley, ISBN 0201514257.
 !*/
 
-/// The following functions will sort the elements of an array or vector, either directly or indirectly. They are defined for all real and
-/// integer types using the normal suffix rules. For example, the float versions of the array functions are gsl_sort_float and gsl_sort_float_index.
-/// The corresponding vector functions are gsl_sort_vector_float and gsl_sort_vector_float_index. The prototypes are available in the header files
-/// gsl_sort_float.h gsl_sort_vector_float.h. The complete set of prototypes can be included using the header files gsl_sort.h and gsl_sort_vector.h.
+use crate::{Error, Value};
+
+/// The element types which can be sorted by GSL.
+///
+/// It is implemented for `f64`, `f32`, `i32`, `u32`, `i16`, `u16` and `u8`. The free functions of
+/// the [`vectors`] and [`select`] modules are generic over it.
+pub trait Sortable: Copy + Default {
+    /// Sorts `data` into ascending numerical order.
+    fn sort(data: &mut [Self]);
+    /// Sorts `data1` into ascending numerical order while making the same rearrangement of
+    /// `data2`. Both slices must have the same length.
+    fn sort2(data1: &mut [Self], data2: &mut [Self]);
+    /// Returns the permutation which would sort `data`.
+    fn sort_index(data: &[Self]) -> Vec<usize>;
+    /// Returns the `k` smallest elements of `src`, in ascending order.
+    fn smallest(src: &[Self], k: usize) -> Result<Vec<Self>, Error>;
+    /// Returns the `k` largest elements of `src`, in descending order.
+    fn largest(src: &[Self], k: usize) -> Result<Vec<Self>, Error>;
+    /// Returns the indices of the `k` smallest elements of `src`, in ascending order of the data.
+    fn smallest_index(src: &[Self], k: usize) -> Result<Vec<usize>, Error>;
+    /// Returns the indices of the `k` largest elements of `src`, in descending order of the data.
+    fn largest_index(src: &[Self], k: usize) -> Result<Vec<usize>, Error>;
+}
+
+// GSL reports `k > src.len()` through the error handler; it is checked beforehand so nothing is
+// read out of the output buffer in that case.
+fn check_subset_size(k: usize, n: usize, function: &'static str) -> Result<(), Error> {
+    if k > n {
+        Err(Error::new(Value::Invalid, function))
+    } else {
+        Ok(())
+    }
+}
+
+macro_rules! sortable {
+    ($t:ty, $sort:ident, $sort2:ident, $index:ident, $smallest:ident, $largest:ident,
+     $smallest_index:ident, $largest_index:ident) => {
+        impl Sortable for $t {
+            fn sort(data: &mut [$t]) {
+                unsafe { sys::$sort(data.as_mut_ptr(), 1, data.len()) }
+            }
+
+            fn sort2(data1: &mut [$t], data2: &mut [$t]) {
+                assert_eq!(
+                    data1.len(),
+                    data2.len(),
+                    "the slices must have the same length"
+                );
+                unsafe { sys::$sort2(data1.as_mut_ptr(), 1, data2.as_mut_ptr(), 1, data1.len()) }
+            }
+
+            fn sort_index(data: &[$t]) -> Vec<usize> {
+                let mut p = vec![0; data.len()];
+                unsafe { sys::$index(p.as_mut_ptr(), data.as_ptr(), 1, data.len()) };
+                p
+            }
+
+            fn smallest(src: &[$t], k: usize) -> Result<Vec<$t>, Error> {
+                check_subset_size(k, src.len(), stringify!($smallest))?;
+                let mut dest = vec![<$t>::default(); k];
+                let ret =
+                    unsafe { sys::$smallest(dest.as_mut_ptr(), k, src.as_ptr(), 1, src.len()) };
+                result_handler!(ret, dest, stringify!($smallest))
+            }
+
+            fn largest(src: &[$t], k: usize) -> Result<Vec<$t>, Error> {
+                check_subset_size(k, src.len(), stringify!($largest))?;
+                let mut dest = vec![<$t>::default(); k];
+                let ret =
+                    unsafe { sys::$largest(dest.as_mut_ptr(), k, src.as_ptr(), 1, src.len()) };
+                result_handler!(ret, dest, stringify!($largest))
+            }
+
+            fn smallest_index(src: &[$t], k: usize) -> Result<Vec<usize>, Error> {
+                check_subset_size(k, src.len(), stringify!($smallest_index))?;
+                let mut p = vec![0; k];
+                let ret =
+                    unsafe { sys::$smallest_index(p.as_mut_ptr(), k, src.as_ptr(), 1, src.len()) };
+                result_handler!(ret, p, stringify!($smallest_index))
+            }
+
+            fn largest_index(src: &[$t], k: usize) -> Result<Vec<usize>, Error> {
+                check_subset_size(k, src.len(), stringify!($largest_index))?;
+                let mut p = vec![0; k];
+                let ret =
+                    unsafe { sys::$largest_index(p.as_mut_ptr(), k, src.as_ptr(), 1, src.len()) };
+                result_handler!(ret, p, stringify!($largest_index))
+            }
+        }
+    };
+}
+
+sortable!(
+    f64,
+    gsl_sort,
+    gsl_sort2,
+    gsl_sort_index,
+    gsl_sort_smallest,
+    gsl_sort_largest,
+    gsl_sort_smallest_index,
+    gsl_sort_largest_index
+);
+sortable!(
+    f32,
+    gsl_sort_float,
+    gsl_sort2_float,
+    gsl_sort_float_index,
+    gsl_sort_float_smallest,
+    gsl_sort_float_largest,
+    gsl_sort_float_smallest_index,
+    gsl_sort_float_largest_index
+);
+sortable!(
+    i32,
+    gsl_sort_int,
+    gsl_sort2_int,
+    gsl_sort_int_index,
+    gsl_sort_int_smallest,
+    gsl_sort_int_largest,
+    gsl_sort_int_smallest_index,
+    gsl_sort_int_largest_index
+);
+sortable!(
+    u32,
+    gsl_sort_uint,
+    gsl_sort2_uint,
+    gsl_sort_uint_index,
+    gsl_sort_uint_smallest,
+    gsl_sort_uint_largest,
+    gsl_sort_uint_smallest_index,
+    gsl_sort_uint_largest_index
+);
+sortable!(
+    i16,
+    gsl_sort_short,
+    gsl_sort2_short,
+    gsl_sort_short_index,
+    gsl_sort_short_smallest,
+    gsl_sort_short_largest,
+    gsl_sort_short_smallest_index,
+    gsl_sort_short_largest_index
+);
+sortable!(
+    u16,
+    gsl_sort_ushort,
+    gsl_sort2_ushort,
+    gsl_sort_ushort_index,
+    gsl_sort_ushort_smallest,
+    gsl_sort_ushort_largest,
+    gsl_sort_ushort_smallest_index,
+    gsl_sort_ushort_largest_index
+);
+sortable!(
+    u8,
+    gsl_sort_uchar,
+    gsl_sort2_uchar,
+    gsl_sort_uchar_index,
+    gsl_sort_uchar_smallest,
+    gsl_sort_uchar_largest,
+    gsl_sort_uchar_smallest_index,
+    gsl_sort_uchar_largest_index
+);
+
+/// The following functions will sort the elements of a slice or vector, either directly or
+/// indirectly. The slice functions are defined for all the [`Sortable`] types.
 ///
 /// There are no functions for sorting complex arrays or vectors, since the ordering of complex numbers is not uniquely defined. To sort a complex
 /// vector by magnitude compute a real vector containing the magnitudes of the complex elements, and sort this vector indirectly. The resulting index
 /// gives the appropriate ordering of the original complex vector.
+///
+/// ```
+/// use rgsl::sort::vectors;
+///
+/// let mut data = [3., 1., 2.];
+/// assert_eq!(vectors::sort_index(&data), [1, 2, 0]);
+/// vectors::sort(&mut data);
+/// assert_eq!(data, [1., 2., 3.]);
+/// ```
 pub mod vectors {
-    use crate::Value;
+    use super::Sortable;
+    use crate::{Error, Value};
     use ffi::FFI;
     use types::{Permutation, VectorF64};
 
-    /// This function sorts the n elements of the array data with stride stride into ascending numerical order.
+    /// This function sorts the elements of `data` into ascending numerical order.
     #[doc(alias = "gsl_sort")]
-    pub fn sort(data: &mut [f64], stride: usize, n: usize) {
-        unsafe { sys::gsl_sort(data.as_mut_ptr(), stride, n) }
+    pub fn sort<T: Sortable>(data: &mut [T]) {
+        T::sort(data)
     }
 
-    /// This function sorts the n elements of the array data1 with stride stride1 into ascending numerical order, while making the same rearrangement
-    /// of the array data2 with stride stride2, also of size n.
+    /// This function sorts the elements of `data1` into ascending numerical order, while making
+    /// the same rearrangement of `data2`.
+    ///
+    /// Panics if the slices don't have the same length.
     #[doc(alias = "gsl_sort2")]
-    pub fn sort2(data1: &mut [f64], stride1: usize, data2: &mut [f64], stride2: usize, n: usize) {
-        unsafe { sys::gsl_sort2(data1.as_mut_ptr(), stride1, data2.as_mut_ptr(), stride2, n) }
+    pub fn sort2<T: Sortable>(data1: &mut [T], data2: &mut [T]) {
+        T::sort2(data1, data2)
     }
 
     /// This function sorts the elements of the vector v into ascending numerical order.
@@ -54,25 +227,31 @@ pub mod vectors {
     }
 
     /// This function sorts the elements of the vector v1 into ascending numerical order, while making the same rearrangement of the vector v2.
+    ///
+    /// Panics if the vectors don't have the same length.
     #[doc(alias = "gsl_sort_vector2")]
     pub fn sort_vector2(v1: &mut VectorF64, v2: &mut VectorF64) {
+        assert_eq!(v1.len(), v2.len(), "the vectors must have the same length");
         unsafe { sys::gsl_sort_vector2(v1.unwrap_unique(), v2.unwrap_unique()) }
     }
 
-    /// This function indirectly sorts the n elements of the array data with stride stride into ascending order, storing the resulting
-    /// permutation in p. The array p must be allocated with a sufficient length to store the n elements of the permutation. The elements of p
-    /// give the index of the array element which would have been stored in that position if the array had been sorted in place. The array data is not changed.
+    /// This function indirectly sorts the elements of `data` into ascending order and returns the
+    /// resulting permutation. Its elements give the index of the element which would have been
+    /// stored in that position if the slice had been sorted in place. `data` is not changed.
     #[doc(alias = "gsl_sort_index")]
-    pub fn sort_index(p: &mut [usize], data: &[f64], stride: usize, n: usize) {
-        unsafe { sys::gsl_sort_index(p.as_mut_ptr(), data.as_ptr(), stride, n) }
+    pub fn sort_index<T: Sortable>(data: &[T]) -> Vec<usize> {
+        T::sort_index(data)
     }
 
-    /// This function indirectly sorts the elements of the vector v into ascending order, storing the resulting permutation in p. The elements of p give the
-    /// index of the vector element which would have been stored in that position if the vector had been sorted in place. The first element of p gives the index
-    /// of the least element in v, and the last element of p gives the index of the greatest element in v. The vector v is not changed.
+    /// This function indirectly sorts the elements of the vector v into ascending order and returns the resulting permutation. Its elements give the
+    /// index of the vector element which would have been stored in that position if the vector had been sorted in place. The first element gives the index
+    /// of the least element in v, and the last element gives the index of the greatest element in v. The vector v is not changed.
     #[doc(alias = "gsl_sort_vector_index")]
-    pub fn sort_vector_index(p: &mut Permutation, v: &VectorF64) -> Value {
-        Value::from(unsafe { sys::gsl_sort_vector_index(p.unwrap_unique(), v.unwrap_shared()) })
+    pub fn sort_vector_index(v: &VectorF64) -> Result<Permutation, Error> {
+        let mut p = Permutation::new(v.len())
+            .ok_or_else(|| Error::new(Value::NoMemory, "gsl_permutation_alloc"))?;
+        let ret = unsafe { sys::gsl_sort_vector_index(p.unwrap_unique(), v.unwrap_shared()) };
+        result_handler!(ret, p, "gsl_sort_vector_index")
     }
 }
 
@@ -80,78 +259,102 @@ pub mod vectors {
 /// algorithm which is suited to subsets that are small compared with the total size of the dataset. For example, the routines are useful for selecting the
 /// 10 largest values from one million data points, but not for selecting the largest 100,000 values. If the subset is a significant part of the total dataset
 /// it may be faster to sort all the elements of the dataset directly with an O(N \log N) algorithm and obtain the smallest or largest values that way.
+///
+/// All of them return an error if `k` is greater than the size of the data set.
+///
+/// ```
+/// use rgsl::sort::select;
+///
+/// let data = [5., 1., 4., 2., 3.];
+/// assert_eq!(select::sort_largest(&data, 2).unwrap(), [5., 4.]);
+/// assert_eq!(select::sort_smallest_index(&data, 2).unwrap(), [1, 3]);
+/// ```
 pub mod select {
-    use crate::Value;
+    use super::{check_subset_size, Sortable};
+    use crate::Error;
     use ffi::FFI;
     use types::VectorF64;
 
-    /// This function copies the k smallest elements of the array src, of size n and stride stride, in ascending numerical order into the array dest. The size
-    /// k of the subset must be less than or equal to n. The data src is not modified by this operation.
+    /// This function returns the k smallest elements of `src` in ascending numerical order. `src`
+    /// is not modified by this operation.
     #[doc(alias = "gsl_sort_smallest")]
-    pub fn sort_smallest(dest: &mut [f64], k: usize, src: &[f64], stride: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_smallest(dest.as_mut_ptr(), k, src.as_ptr(), stride, src.len() as _)
-        })
+    pub fn sort_smallest<T: Sortable>(src: &[T], k: usize) -> Result<Vec<T>, Error> {
+        T::smallest(src, k)
     }
 
-    /// This function copies the k largest elements of the array src, of size n and stride stride, in descending numerical order into the array dest. k must
-    /// be less than or equal to n. The data src is not modified by this operation.
+    /// This function returns the k largest elements of `src` in descending numerical order. `src`
+    /// is not modified by this operation.
     #[doc(alias = "gsl_sort_largest")]
-    pub fn sort_largest(dest: &mut [f64], k: usize, src: &[f64], stride: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_largest(dest.as_mut_ptr(), k, src.as_ptr(), stride, src.len() as _)
-        })
+    pub fn sort_largest<T: Sortable>(src: &[T], k: usize) -> Result<Vec<T>, Error> {
+        T::largest(src, k)
     }
 
-    /// This function copies the k smallest or largest elements of the vector v into the array dest. k must be less than or equal to the length of the vector v.
+    /// This function returns the k smallest elements of the vector v in ascending numerical order.
     #[doc(alias = "gsl_sort_vector_smallest")]
-    pub fn sort_vector_smallest(dest: &mut [f64], k: usize, v: &VectorF64) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_vector_smallest(dest.as_mut_ptr(), k, v.unwrap_shared())
-        })
+    pub fn sort_vector_smallest(v: &VectorF64, k: usize) -> Result<Vec<f64>, Error> {
+        check_subset_size(k, v.len(), "gsl_sort_vector_smallest")?;
+        let mut dest = vec![0.; k];
+        let ret = unsafe { sys::gsl_sort_vector_smallest(dest.as_mut_ptr(), k, v.unwrap_shared()) };
+        result_handler!(ret, dest, "gsl_sort_vector_smallest")
     }
 
-    /// This function copies the k smallest or largest elements of the vector v into the array dest. k must be less than or equal to the length of the vector v.
+    /// This function returns the k largest elements of the vector v in descending numerical order.
     #[doc(alias = "gsl_sort_vector_largest")]
-    pub fn sort_vector_largest(dest: &mut [f64], k: usize, v: &VectorF64) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_vector_largest(dest.as_mut_ptr(), k, v.unwrap_shared())
-        })
+    pub fn sort_vector_largest(v: &VectorF64, k: usize) -> Result<Vec<f64>, Error> {
+        check_subset_size(k, v.len(), "gsl_sort_vector_largest")?;
+        let mut dest = vec![0.; k];
+        let ret = unsafe { sys::gsl_sort_vector_largest(dest.as_mut_ptr(), k, v.unwrap_shared()) };
+        result_handler!(ret, dest, "gsl_sort_vector_largest")
     }
 
-    /// This function stores the indices of the k smallest elements of the array src, of size n and stride stride, in the array p. The indices are chosen so that
-    /// the corresponding data is in ascending numerical order. k must be less than or equal to n. The data src is not modified by this operation.
+    /// This function returns the indices of the k smallest elements of `src`. The indices are
+    /// chosen so that the corresponding data is in ascending numerical order.
     #[doc(alias = "gsl_sort_smallest_index")]
-    pub fn sort_smallest_index(p: &mut [usize], k: usize, src: &[f64], stride: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_smallest_index(p.as_mut_ptr(), k, src.as_ptr(), stride, src.len() as _)
-        })
+    pub fn sort_smallest_index<T: Sortable>(src: &[T], k: usize) -> Result<Vec<usize>, Error> {
+        T::smallest_index(src, k)
     }
 
-    /// This function stores the indices of the k largest elements of the array src, of size n and stride stride, in the array p. The indices are chosen so that
-    /// the corresponding data is in descending numerical order. k must be less than or equal to n. The data src is not modified by this operation.
+    /// This function returns the indices of the k largest elements of `src`. The indices are
+    /// chosen so that the corresponding data is in descending numerical order.
     #[doc(alias = "gsl_sort_largest_index")]
-    pub fn sort_largest_index(p: &mut [usize], k: usize, src: &[f64], stride: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_largest_index(p.as_mut_ptr(), k, src.as_ptr(), stride, src.len() as _)
-        })
+    pub fn sort_largest_index<T: Sortable>(src: &[T], k: usize) -> Result<Vec<usize>, Error> {
+        T::largest_index(src, k)
     }
 
-    /// This function stores the indices of the k smallest or largest elements of the vector v in the array p. k must be less than or equal to the length of
-    /// the vector v.
+    /// This function returns the indices of the k smallest elements of the vector v, in ascending
+    /// numerical order of the data.
     #[doc(alias = "gsl_sort_vector_smallest_index")]
-    pub fn sort_vector_smallest_index(p: &mut [usize], k: usize, v: &VectorF64) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_vector_smallest_index(p.as_mut_ptr(), k, v.unwrap_shared())
-        })
+    pub fn sort_vector_smallest_index(v: &VectorF64, k: usize) -> Result<Vec<usize>, Error> {
+        check_subset_size(k, v.len(), "gsl_sort_vector_smallest_index")?;
+        let mut p = vec![0; k];
+        let ret =
+            unsafe { sys::gsl_sort_vector_smallest_index(p.as_mut_ptr(), k, v.unwrap_shared()) };
+        result_handler!(ret, p, "gsl_sort_vector_smallest_index")
     }
 
-    /// This function stores the indices of the k smallest or largest elements of the vector v in the array p. k must be less than or equal to the length of
-    /// the vector v.
+    /// This function returns the indices of the k largest elements of the vector v, in descending
+    /// numerical order of the data.
     #[doc(alias = "gsl_sort_vector_largest_index")]
-    pub fn sort_vector_largest_index(p: &mut [usize], k: usize, v: &VectorF64) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_vector_largest_index(p.as_mut_ptr(), k, v.unwrap_shared())
-        })
+    pub fn sort_vector_largest_index(v: &VectorF64, k: usize) -> Result<Vec<usize>, Error> {
+        check_subset_size(k, v.len(), "gsl_sort_vector_largest_index")?;
+        let mut p = vec![0; k];
+        let ret =
+            unsafe { sys::gsl_sort_vector_largest_index(p.as_mut_ptr(), k, v.unwrap_shared()) };
+        result_handler!(ret, p, "gsl_sort_vector_largest_index")
     }
 }
+
+#[test]
+fn sort_slices() {
+    let mut data = [3i32, -1, 2, 0];
+    let mut other = [0i32, 1, 2, 3];
+    assert_eq!(vectors::sort_index(&data), [1, 3, 2, 0]);
+    vectors::sort2(&mut data, &mut other);
+    assert_eq!(data, [-1, 0, 2, 3]);
+    assert_eq!(other, [1, 3, 2, 0]);
+
+    let data = [0.5f32, 3., -2., 1.];
+    assert_eq!(select::sort_smallest(&data, 2).unwrap(), [-2., 0.5]);
+    assert_eq!(select::sort_largest_index(&data, 3).unwrap(), [1, 3, 0]);
+    assert!(select::sort_largest(&data, 5).is_err());
+}
@@ -0,0 +1,61 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Sparse BLAS Support
+
+The Sparse Basic Linear Algebra Subprograms (BLAS) define a set of fundamental operations on
+vectors and sparse matrices which can be used to create optimized higher-level linear algebra
+functionality. GSL supports a limited number of BLAS operations for sparse matrices.
+!*/
+
+use crate::{enums, Error, SparseFormat, Value};
+use ffi::FFI;
+
+/// This function computes the matrix-vector product and sum y = \alpha op(A) x + \beta y, where
+/// op(A) = A, A^T for `transA` = `NoTranspose`, `Transpose`. In-place computations are not
+/// supported, so `x` and `y` must be distinct vectors. The matrix `A` may be in triplet or
+/// compressed format.
+#[doc(alias = "gsl_spblas_dgemv")]
+pub fn dgemv(
+    transA: enums::CblasTranspose,
+    alpha: f64,
+    A: &::SparseMatrix,
+    x: &::VectorF64,
+    beta: f64,
+    y: &mut ::VectorF64,
+) -> Result<(), Error> {
+    let ret = unsafe {
+        sys::gsl_spblas_dgemv(
+            transA.into(),
+            alpha,
+            A.unwrap_shared(),
+            x.unwrap_shared(),
+            beta,
+            y.unwrap_unique(),
+        )
+    };
+    result_handler!(ret, (), "gsl_spblas_dgemv")
+}
+
+/// This function computes the sparse matrix-matrix product C = \alpha A B. The matrices `A` and
+/// `B` must be in compressed column format.
+///
+/// Returns `C`, in compressed column format.
+#[doc(alias = "gsl_spblas_dgemm")]
+pub fn dgemm(alpha: f64, A: &::SparseMatrix, B: &::SparseMatrix) -> Result<::SparseMatrix, Error> {
+    // GSL grows `C` as needed.
+    let mut C =
+        ::SparseMatrix::with_format(A.size1(), B.size2(), 1, SparseFormat::CompressedColumn)
+            .ok_or_else(|| Error::new(Value::NoMemory, "gsl_spmatrix_alloc_nzmax"))?;
+    let ret = unsafe {
+        sys::gsl_spblas_dgemm(
+            alpha,
+            A.unwrap_shared(),
+            B.unwrap_shared(),
+            C.unwrap_unique(),
+        )
+    };
+    result_handler!(ret, C, "gsl_spblas_dgemm")
+}
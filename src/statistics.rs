@@ -10,6 +10,18 @@ variance and standard deviation. More advanced functions allow you to calculate
 median and arbitrary percentiles. The algorithms use recurrence relations to compute average quantities in a stable way, without large
 intermediate values that might overflow.
 
+The functions take the whole dataset as a slice. Each of them also has a `_strided` variant
+mirroring the GSL signature, which only uses `n` elements of the slice separated by a stride:
+
+```
+use rgsl::statistics;
+
+let data = [17.2, 18.1, 16.5, 18.3, 12.6];
+assert_eq!(statistics::max(&data), 18.3);
+// Only uses 17.2, 16.5 and 12.6.
+assert_eq!(statistics::max_strided(&data, 2, 3), 17.2);
+```
+
 ##Weighted Samples
 
 The functions described in this section allow the computation of statistics for weighted samples. The functions accept an array of
@@ -27,6 +39,12 @@ returned, since the maximum or minimum value is undefined. For functions which r
 The median and percentile functions described in this section operate on sorted data. For convenience we use quantiles, measured on a
 scale of 0 to 1, instead of percentiles (which use a scale of 0 to 100).
 
+##Robust Location and Scale Estimates
+
+With GSL 2.5 or newer, [`median`] and [`select`] work on unsorted data, and the trimmed mean,
+Gastwirth estimator, median absolute deviation and the S_n and Q_n estimators can be computed:
+they are less sensitive to outliers than the mean and the standard deviation.
+
 ##References and Further Reading
 
 The standard reference for almost any topic in statistics is the multi-volume Advanced Theory of Statistics by Kendall and Stuart.
@@ -44,20 +62,32 @@ Review of Particle Properties R.M. Barnett et al., Physical Review D54, 1 (1996)
 The Review of Particle Physics is available online at the website http://pdg.lbl.gov/.
 !*/
 
-/// This function returns the arithmetic mean of data, a dataset of length n with stride stride. The
-/// arithmetic mean, or sample mean, is denoted by \Hat\mu and defined as,
+#[cfg(feature = "v2_5")]
+use alloc::vec::Vec;
+
+/// This function returns the arithmetic mean of data. The arithmetic mean, or sample mean, is
+/// denoted by \Hat\mu and defined as,
 ///
 /// \Hat\mu = (1/N) \sum x_i
 ///
 /// where x_i are the elements of the dataset data. For samples drawn from a gaussian distribution
 /// the variance of \Hat\mu is \sigma^2 / N.
 #[doc(alias = "gsl_stats_mean")]
-pub fn mean(data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn mean(data: &[f64]) -> f64 {
+    mean_strided(data, 1, data.len())
+}
+
+/// Same as [`mean`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_mean")]
+pub fn mean_strided(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_mean(data.as_ptr(), stride, n) }
 }
 
-/// This function returns the estimated, or sample, variance of data, a dataset of length n with
-/// stride stride. The estimated variance is denoted by \Hat\sigma^2 and is defined by,
+/// This function returns the estimated, or sample, variance of data. The estimated variance is
+/// denoted by \Hat\sigma^2 and is defined by,
 ///
 /// \Hat\sigma^2 = (1/(N-1)) \sum (x_i - \Hat\mu)^2
 ///
@@ -69,7 +99,16 @@ pub fn mean(data: &[f64], stride: usize, n: usize) -> f64 {
 /// This function computes the mean via a call to gsl_stats_mean. If you have already computed the
 /// mean then you can pass it directly to gsl_stats_variance_m.
 #[doc(alias = "gsl_stats_variance")]
-pub fn variance(data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn variance(data: &[f64]) -> f64 {
+    variance_strided(data, 1, data.len())
+}
+
+/// Same as [`variance`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_variance")]
+pub fn variance_strided(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_variance(data.as_ptr(), stride, n) }
 }
 
@@ -78,21 +117,48 @@ pub fn variance(data: &[f64], stride: usize, n: usize) -> f64 {
 ///
 /// \Hat\sigma^2 = (1/(N-1)) \sum (x_i - mean)^2
 #[doc(alias = "gsl_stats_variance_m")]
-pub fn variance_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+pub fn variance_m(data: &[f64], mean: f64) -> f64 {
+    variance_m_strided(data, 1, data.len(), mean)
+}
+
+/// Same as [`variance_m`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_variance_m")]
+pub fn variance_m_strided(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_variance_m(data.as_ptr(), stride, n, mean) }
 }
 
 /// The standard deviation is defined as the square root of the variance. This function returns the
 /// square root of the corresponding variance functions above.
 #[doc(alias = "gsl_stats_sd")]
-pub fn sd(data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn sd(data: &[f64]) -> f64 {
+    sd_strided(data, 1, data.len())
+}
+
+/// Same as [`sd`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_sd")]
+pub fn sd_strided(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_sd(data.as_ptr(), stride, n) }
 }
 
 /// The standard deviation is defined as the square root of the variance. This function returns the
 /// square root of the corresponding variance functions above.
 #[doc(alias = "gsl_stats_sd_m")]
-pub fn sd_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+pub fn sd_m(data: &[f64], mean: f64) -> f64 {
+    sd_m_strided(data, 1, data.len(), mean)
+}
+
+/// Same as [`sd_m`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_sd_m")]
+pub fn sd_m_strided(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_sd_m(data.as_ptr(), stride, n, mean) }
 }
 
@@ -102,7 +168,16 @@ pub fn sd_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
 ///
 /// TSS =  \sum (x_i - mean)^2
 #[doc(alias = "gsl_stats_tss")]
-pub fn tss(data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn tss(data: &[f64]) -> f64 {
+    tss_strided(data, 1, data.len())
+}
+
+/// Same as [`tss`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_tss")]
+pub fn tss_strided(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_tss(data.as_ptr(), stride, n) }
 }
 
@@ -112,7 +187,16 @@ pub fn tss(data: &[f64], stride: usize, n: usize) -> f64 {
 ///
 /// TSS =  \sum (x_i - mean)^2
 #[doc(alias = "gsl_stats_tss_m")]
-pub fn tss_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+pub fn tss_m(data: &[f64], mean: f64) -> f64 {
+    tss_m_strided(data, 1, data.len(), mean)
+}
+
+/// Same as [`tss_m`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_tss_m")]
+pub fn tss_m_strided(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_tss_m(data.as_ptr(), stride, n, mean) }
 }
 
@@ -123,19 +207,38 @@ pub fn tss_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
 ///
 /// \Hat\sigma^2 = (1/N) \sum (x_i - \mu)^2
 #[doc(alias = "gsl_stats_variance_with_fixed_mean")]
-pub fn variance_with_fixed_mean(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+pub fn variance_with_fixed_mean(data: &[f64], mean: f64) -> f64 {
+    variance_with_fixed_mean_strided(data, 1, data.len(), mean)
+}
+
+/// Same as [`variance_with_fixed_mean`], but only uses the `n` elements of `data` separated by
+/// `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_variance_with_fixed_mean")]
+pub fn variance_with_fixed_mean_strided(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_variance_with_fixed_mean(data.as_ptr(), stride, n, mean) }
 }
 
 /// This function calculates the standard deviation of data for a fixed population mean mean. The
 /// result is the square root of the corresponding variance function.
 #[doc(alias = "gsl_stats_sd_with_fixed_mean")]
-pub fn sd_with_fixed_mean(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+pub fn sd_with_fixed_mean(data: &[f64], mean: f64) -> f64 {
+    sd_with_fixed_mean_strided(data, 1, data.len(), mean)
+}
+
+/// Same as [`sd_with_fixed_mean`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_sd_with_fixed_mean")]
+pub fn sd_with_fixed_mean_strided(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_sd_with_fixed_mean(data.as_ptr(), stride, n, mean) }
 }
 
-/// This function computes the absolute deviation from the mean of data, a dataset of length n with
-/// stride stride. The absolute deviation from the mean is defined as,
+/// This function computes the absolute deviation from the mean of data. The absolute deviation from
+/// the mean is defined as,
 ///
 /// absdev  = (1/N) \sum |x_i - \Hat\mu|
 ///
@@ -143,7 +246,16 @@ pub fn sd_with_fixed_mean(data: &[f64], stride: usize, n: usize, mean: f64) -> f
 /// more robust measure of the width of a distribution than the variance. This function computes the
 /// mean of data via a call to gsl_stats_mean.
 #[doc(alias = "gsl_stats_absdev")]
-pub fn absdev(data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn absdev(data: &[f64]) -> f64 {
+    absdev_strided(data, 1, data.len())
+}
+
+/// Same as [`absdev`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_absdev")]
+pub fn absdev_strided(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_absdev(data.as_ptr(), stride, n) }
 }
 
@@ -156,12 +268,20 @@ pub fn absdev(data: &[f64], stride: usize, n: usize) -> f64 {
 /// recomputing it), or wish to calculate the absolute deviation relative to another value (such as
 /// zero, or the median).
 #[doc(alias = "gsl_stats_absdev_m")]
-pub fn absdev_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+pub fn absdev_m(data: &[f64], mean: f64) -> f64 {
+    absdev_m_strided(data, 1, data.len(), mean)
+}
+
+/// Same as [`absdev_m`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_absdev_m")]
+pub fn absdev_m_strided(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_absdev_m(data.as_ptr(), stride, n, mean) }
 }
 
-/// This function computes the skewness of data, a dataset of length n with stride stride. The
-/// skewness is defined as,
+/// This function computes the skewness of data. The skewness is defined as,
 ///
 /// skew = (1/N) \sum ((x_i - \Hat\mu)/\Hat\sigma)^3
 ///
@@ -171,7 +291,16 @@ pub fn absdev_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
 /// The function computes the mean and estimated standard deviation of data via calls to [`mean`]
 /// and [`sd`].
 #[doc(alias = "gsl_stats_skew")]
-pub fn skew(data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn skew(data: &[f64]) -> f64 {
+    skew_strided(data, 1, data.len())
+}
+
+/// Same as [`skew`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_skew")]
+pub fn skew_strided(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_skew(data.as_ptr(), stride, n) }
 }
 
@@ -183,19 +312,36 @@ pub fn skew(data: &[f64], stride: usize, n: usize) -> f64 {
 /// These functions are useful if you have already computed the mean and standard deviation of data
 /// and want to avoid recomputing them.
 #[doc(alias = "gsl_stats_skew_m_sd")]
-pub fn skew_m_sd(data: &[f64], stride: usize, n: usize, mean: f64, sd: f64) -> f64 {
+pub fn skew_m_sd(data: &[f64], mean: f64, sd: f64) -> f64 {
+    skew_m_sd_strided(data, 1, data.len(), mean, sd)
+}
+
+/// Same as [`skew_m_sd`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_skew_m_sd")]
+pub fn skew_m_sd_strided(data: &[f64], stride: usize, n: usize, mean: f64, sd: f64) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_skew_m_sd(data.as_ptr(), stride, n, mean, sd) }
 }
 
-/// This function computes the kurtosis of data, a dataset of length n with stride stride. The
-/// kurtosis is defined as,
+/// This function computes the kurtosis of data. The kurtosis is defined as,
 ///
 /// kurtosis = ((1/N) \sum ((x_i - \Hat\mu)/\Hat\sigma)^4)  - 3
 ///
 /// The kurtosis measures how sharply peaked a distribution is, relative to its width. The kurtosis
 /// is normalized to zero for a Gaussian distribution.
 #[doc(alias = "gsl_stats_kurtosis")]
-pub fn kurtosis(data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn kurtosis(data: &[f64]) -> f64 {
+    kurtosis_strided(data, 1, data.len())
+}
+
+/// Same as [`kurtosis`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_kurtosis")]
+pub fn kurtosis_strided(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_kurtosis(data.as_ptr(), stride, n) }
 }
 
@@ -207,7 +353,16 @@ pub fn kurtosis(data: &[f64], stride: usize, n: usize) -> f64 {
 /// This function is useful if you have already computed the mean and standard deviation of data and
 /// want to avoid recomputing them.
 #[doc(alias = "gsl_stats_kurtosis_m_sd")]
-pub fn kurtosis_m_sd(data: &[f64], stride: usize, n: usize, mean: f64, sd: f64) -> f64 {
+pub fn kurtosis_m_sd(data: &[f64], mean: f64, sd: f64) -> f64 {
+    kurtosis_m_sd_strided(data, 1, data.len(), mean, sd)
+}
+
+/// Same as [`kurtosis_m_sd`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_kurtosis_m_sd")]
+pub fn kurtosis_m_sd_strided(data: &[f64], stride: usize, n: usize, mean: f64, sd: f64) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_kurtosis_m_sd(data.as_ptr(), stride, n, mean, sd) }
 }
 
@@ -217,31 +372,91 @@ pub fn kurtosis_m_sd(data: &[f64], stride: usize, n: usize, mean: f64, sd: f64)
 ///        \over
 ///        \sum_{i = 1}^{n} (x_{i} - \Hat\mu) (x_{i} - \Hat\mu)}
 #[doc(alias = "gsl_stats_lag1_autocorrelation")]
-pub fn lag1_autocorrelation(data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn lag1_autocorrelation(data: &[f64]) -> f64 {
+    lag1_autocorrelation_strided(data, 1, data.len())
+}
+
+/// Same as [`lag1_autocorrelation`], but only uses the `n` elements of `data` separated by
+/// `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_lag1_autocorrelation")]
+pub fn lag1_autocorrelation_strided(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_lag1_autocorrelation(data.as_ptr(), stride, n) }
 }
 
 /// This function computes the lag-1 autocorrelation of the dataset data using the given value of
 /// the mean mean.
 #[doc(alias = "gsl_stats_lag1_autocorrelation_m")]
-pub fn lag1_autocorrelation_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+pub fn lag1_autocorrelation_m(data: &[f64], mean: f64) -> f64 {
+    lag1_autocorrelation_m_strided(data, 1, data.len(), mean)
+}
+
+/// Same as [`lag1_autocorrelation_m`], but only uses the `n` elements of `data` separated by
+/// `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_lag1_autocorrelation_m")]
+pub fn lag1_autocorrelation_m_strided(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_lag1_autocorrelation_m(data.as_ptr(), stride, n, mean) }
 }
 
 /// This function computes the covariance of the datasets data1 and data2 which must both be of the
-/// same length n.
+/// same length.
 ///
 /// covar = (1/(n - 1)) \sum_{i = 1}^{n} (x_i - \Hat x) (y_i - \Hat y)
+///
+/// Panics if the datasets don't have the same length.
+#[doc(alias = "gsl_stats_covariance")]
+pub fn covariance(data1: &[f64], data2: &[f64]) -> f64 {
+    assert_eq!(
+        data2.len(),
+        data1.len(),
+        "the datasets must have the same length"
+    );
+    covariance_strided(data1, 1, data2, 1, data1.len())
+}
+
+/// Same as [`covariance`], but only uses the `n` elements of `data1` and `data2` separated by
+/// `stride1` and `stride2`.
+///
+/// Panics if one of the datasets is too short.
 #[doc(alias = "gsl_stats_covariance")]
-pub fn covariance(data1: &[f64], stride1: usize, data2: &[f64], stride2: usize, n: usize) -> f64 {
+pub fn covariance_strided(
+    data1: &[f64],
+    stride1: usize,
+    data2: &[f64],
+    stride2: usize,
+    n: usize,
+) -> f64 {
+    check_strided(data1.len(), stride1, n);
+    check_strided(data2.len(), stride2, n);
     unsafe { sys::gsl_stats_covariance(data1.as_ptr(), stride1, data2.as_ptr(), stride2, n) }
 }
 
 /// This function computes the covariance of the datasets data1 and data2 using the given values of
 /// the means, mean1 and mean2. This is useful if you have already computed the means of data1 and
 /// data2 and want to avoid recomputing them.
+///
+/// Panics if the datasets don't have the same length.
 #[doc(alias = "gsl_stats_covariance_m")]
-pub fn covariance_m(
+pub fn covariance_m(data1: &[f64], data2: &[f64], mean1: f64, mean2: f64) -> f64 {
+    assert_eq!(
+        data2.len(),
+        data1.len(),
+        "the datasets must have the same length"
+    );
+    covariance_m_strided(data1, 1, data2, 1, data1.len(), mean1, mean2)
+}
+
+/// Same as [`covariance_m`], but only uses the `n` elements of `data1` and `data2` separated by
+/// `stride1` and `stride2`.
+///
+/// Panics if one of the datasets is too short.
+#[doc(alias = "gsl_stats_covariance_m")]
+pub fn covariance_m_strided(
     data1: &[f64],
     stride1: usize,
     data2: &[f64],
@@ -250,6 +465,8 @@ pub fn covariance_m(
     mean1: f64,
     mean2: f64,
 ) -> f64 {
+    check_strided(data1.len(), stride1, n);
+    check_strided(data2.len(), stride2, n);
     unsafe {
         sys::gsl_stats_covariance_m(
             data1.as_ptr(),
@@ -264,32 +481,85 @@ pub fn covariance_m(
 }
 
 /// This function efficiently computes the Pearson correlation coefficient between the datasets
-/// data1 and data2 which must both be of the same length n.
+/// data1 and data2 which must both be of the same length.
 ///
 /// r = cov(x, y) / (\Hat\sigma_x \Hat\sigma_y)
 ///   = {1/(n-1) \sum (x_i - \Hat x) (y_i - \Hat y)
 ///      \over
 ///      \sqrt{1/(n-1) \sum (x_i - \Hat x)^2} \sqrt{1/(n-1) \sum (y_i - \Hat y)^2}
 ///     }
+///
+/// Panics if the datasets don't have the same length.
+#[doc(alias = "gsl_stats_correlation")]
+pub fn correlation(data1: &[f64], data2: &[f64]) -> f64 {
+    assert_eq!(
+        data2.len(),
+        data1.len(),
+        "the datasets must have the same length"
+    );
+    correlation_strided(data1, 1, data2, 1, data1.len())
+}
+
+/// Same as [`correlation`], but only uses the `n` elements of `data1` and `data2` separated by
+/// `stride1` and `stride2`.
+///
+/// Panics if one of the datasets is too short.
 #[doc(alias = "gsl_stats_correlation")]
-pub fn correlation(data1: &[f64], stride1: usize, data2: &[f64], stride2: usize, n: usize) -> f64 {
+pub fn correlation_strided(
+    data1: &[f64],
+    stride1: usize,
+    data2: &[f64],
+    stride2: usize,
+    n: usize,
+) -> f64 {
+    check_strided(data1.len(), stride1, n);
+    check_strided(data2.len(), stride2, n);
     unsafe { sys::gsl_stats_correlation(data1.as_ptr(), stride1, data2.as_ptr(), stride2, n) }
 }
 
 /// This function computes the Spearman rank correlation coefficient between the datasets data1 and
-/// data2 which must both be of the same length n. Additional workspace of size 2*n is required in
-/// work. The Spearman rank correlation between vectors x and y is equivalent to the Pearson
-/// correlation between the ranked vectors x_R and y_R, where ranks are defined to be the average of
-/// the positions of an element in the ascending order of the values.
+/// data2 which must both be of the same length. The Spearman rank correlation between vectors x and
+/// y is equivalent to the Pearson correlation between the ranked vectors x_R and y_R, where ranks are
+/// defined to be the average of the positions of an element in the ascending order of the values.
+///
+/// The workspace GSL needs (twice the length of the datasets) is allocated by this function.
+///
+/// Panics if the datasets don't have the same length.
+///
+/// ```
+/// use rgsl::statistics;
+///
+/// // Monotonic, but not linear.
+/// let x = [1., 2., 3., 4.];
+/// let y = [1., 8., 27., 64.];
+/// assert_eq!(statistics::spearman(&x, &y), 1.);
+/// assert!(statistics::correlation(&x, &y) < 1.);
+/// ```
 #[doc(alias = "gsl_stats_spearman")]
-pub fn spearman(
+pub fn spearman(data1: &[f64], data2: &[f64]) -> f64 {
+    assert_eq!(
+        data2.len(),
+        data1.len(),
+        "the datasets must have the same length"
+    );
+    spearman_strided(data1, 1, data2, 1, data1.len())
+}
+
+/// Same as [`spearman`], but only uses the `n` elements of `data1` and `data2` separated by
+/// `stride1` and `stride2`.
+///
+/// Panics if one of the datasets is too short.
+#[doc(alias = "gsl_stats_spearman")]
+pub fn spearman_strided(
     data1: &[f64],
     stride1: usize,
     data2: &[f64],
     stride2: usize,
     n: usize,
-    work: &mut [f64],
 ) -> f64 {
+    check_strided(data1.len(), stride1, n);
+    check_strided(data2.len(), stride2, n);
+    let mut work = alloc::vec![0.; 2 * n];
     unsafe {
         sys::gsl_stats_spearman(
             data1.as_ptr(),
@@ -302,33 +572,84 @@ pub fn spearman(
     }
 }
 
-/// This function returns the weighted mean of the dataset data with stride stride and length n,
-/// using the set of weights w with stride wstride and length n. The weighted mean is defined as,
+/// This function returns the weighted mean of the dataset data, using the set of weights w. The
+/// weighted mean is defined as,
 ///
 /// \Hat\mu = (\sum w_i x_i) / (\sum w_i)
+///
+/// Panics if the datasets don't have the same length.
+#[doc(alias = "gsl_stats_wmean")]
+pub fn wmean(w: &[f64], data: &[f64]) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wmean_strided(w, 1, data, 1, data.len())
+}
+
+/// Same as [`wmean`], but only uses the `n` elements of `w` and `data` separated by `wstride` and
+/// `stride`.
+///
+/// Panics if one of the datasets is too short.
 #[doc(alias = "gsl_stats_wmean")]
-pub fn wmean(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn wmean_strided(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_wmean(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
 }
 
-/// This function returns the estimated variance of the dataset data with stride stride and length
-/// n, using the set of weights w with
-/// stride wstride and length n. The estimated variance of a weighted dataset is calculated as,
+/// This function returns the estimated variance of the dataset data, using the set of weights w.
+/// The estimated variance of a weighted dataset is calculated as,
 ///
 /// \Hat\sigma^2 = ((\sum w_i)/((\sum w_i)^2 - \sum (w_i^2)))
 ///                 \sum w_i (x_i - \Hat\mu)^2
 ///
 /// Note that this expression reduces to an unweighted variance with the familiar 1/(N-1) factor
 /// when there are N equal non-zero weights.
+///
+/// Panics if the datasets don't have the same length.
+#[doc(alias = "gsl_stats_wvariance")]
+pub fn wvariance(w: &[f64], data: &[f64]) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wvariance_strided(w, 1, data, 1, data.len())
+}
+
+/// Same as [`wvariance`], but only uses the `n` elements of `w` and `data` separated by `wstride`
+/// and `stride`.
+///
+/// Panics if one of the datasets is too short.
 #[doc(alias = "gsl_stats_wvariance")]
-pub fn wvariance(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn wvariance_strided(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_wvariance(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
 }
 
 /// This function returns the estimated variance of the weighted dataset data using the given
 /// weighted mean wmean.
+///
+/// Panics if the datasets don't have the same length.
+#[doc(alias = "gsl_stats_wvariance_m")]
+pub fn wvariance_m(w: &[f64], data: &[f64], wmean: f64) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wvariance_m_strided(w, 1, data, 1, data.len(), wmean)
+}
+
+/// Same as [`wvariance_m`], but only uses the `n` elements of `w` and `data` separated by `wstride`
+/// and `stride`.
+///
+/// Panics if one of the datasets is too short.
 #[doc(alias = "gsl_stats_wvariance_m")]
-pub fn wvariance_m(
+pub fn wvariance_m_strided(
     w: &[f64],
     wstride: usize,
     data: &[f64],
@@ -336,20 +657,65 @@ pub fn wvariance_m(
     n: usize,
     wmean: f64,
 ) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_wvariance_m(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean) }
 }
 
 /// The standard deviation is defined as the square root of the variance. This function returns the
 /// square root of the corresponding variance function [`wvariance`] above.
+///
+/// Panics if the datasets don't have the same length.
+#[doc(alias = "gsl_stats_wsd")]
+pub fn wsd(w: &[f64], data: &[f64]) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wsd_strided(w, 1, data, 1, data.len())
+}
+
+/// Same as [`wsd`], but only uses the `n` elements of `w` and `data` separated by `wstride` and
+/// `stride`.
+///
+/// Panics if one of the datasets is too short.
 #[doc(alias = "gsl_stats_wsd")]
-pub fn wsd(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn wsd_strided(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_wsd(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
 }
 
-/// This function returns the square root of the corresponding variance function
-/// [`wvariance_m`] above.
+/// This function returns the square root of the corresponding variance function [`wvariance_m`]
+/// above.
+///
+/// Panics if the datasets don't have the same length.
+#[doc(alias = "gsl_stats_wsd_m")]
+pub fn wsd_m(w: &[f64], data: &[f64], wmean: f64) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wsd_m_strided(w, 1, data, 1, data.len(), wmean)
+}
+
+/// Same as [`wsd_m`], but only uses the `n` elements of `w` and `data` separated by `wstride` and
+/// `stride`.
+///
+/// Panics if one of the datasets is too short.
 #[doc(alias = "gsl_stats_wsd_m")]
-pub fn wsd_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize, wmean: f64) -> f64 {
+pub fn wsd_m_strided(
+    w: &[f64],
+    wstride: usize,
+    data: &[f64],
+    stride: usize,
+    n: usize,
+    wmean: f64,
+) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_wsd_m(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean) }
 }
 
@@ -358,8 +724,24 @@ pub fn wsd_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize, w
 /// estimator for the variance replaces the sample mean \Hat\mu by the known population mean \mu,
 ///
 /// \Hat\sigma^2 = (\sum w_i (x_i - \mu)^2) / (\sum w_i)
+///
+/// Panics if the datasets don't have the same length.
 #[doc(alias = "gsl_stats_wvariance_with_fixed_mean")]
-pub fn wvariance_with_fixed_mean(
+pub fn wvariance_with_fixed_mean(w: &[f64], data: &[f64], mean: f64) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wvariance_with_fixed_mean_strided(w, 1, data, 1, data.len(), mean)
+}
+
+/// Same as [`wvariance_with_fixed_mean`], but only uses the `n` elements of `w` and `data`
+/// separated by `wstride` and `stride`.
+///
+/// Panics if one of the datasets is too short.
+#[doc(alias = "gsl_stats_wvariance_with_fixed_mean")]
+pub fn wvariance_with_fixed_mean_strided(
     w: &[f64],
     wstride: usize,
     data: &[f64],
@@ -367,6 +749,8 @@ pub fn wvariance_with_fixed_mean(
     n: usize,
     mean: f64,
 ) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe {
         sys::gsl_stats_wvariance_with_fixed_mean(
             w.as_ptr(),
@@ -381,8 +765,24 @@ pub fn wvariance_with_fixed_mean(
 
 /// The standard deviation is defined as the square root of the variance. This function returns the
 /// square root of the corresponding variance function above.
+///
+/// Panics if the datasets don't have the same length.
 #[doc(alias = "gsl_stats_wsd_with_fixed_mean")]
-pub fn wsd_with_fixed_mean(
+pub fn wsd_with_fixed_mean(w: &[f64], data: &[f64], mean: f64) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wsd_with_fixed_mean_strided(w, 1, data, 1, data.len(), mean)
+}
+
+/// Same as [`wsd_with_fixed_mean`], but only uses the `n` elements of `w` and `data` separated by
+/// `wstride` and `stride`.
+///
+/// Panics if one of the datasets is too short.
+#[doc(alias = "gsl_stats_wsd_with_fixed_mean")]
+pub fn wsd_with_fixed_mean_strided(
     w: &[f64],
     wstride: usize,
     data: &[f64],
@@ -390,6 +790,8 @@ pub fn wsd_with_fixed_mean(
     n: usize,
     mean: f64,
 ) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe {
         sys::gsl_stats_wsd_with_fixed_mean(w.as_ptr(), wstride, data.as_ptr(), stride, n, mean)
     }
@@ -400,8 +802,26 @@ pub fn wsd_with_fixed_mean(
 /// computed using gsl_stats_wmean.
 ///
 /// TSS =  \sum w_i (x_i - wmean)^2
+///
+/// Panics if the datasets don't have the same length.
 #[doc(alias = "gsl_stats_wtss")]
-pub fn wtss(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn wtss(w: &[f64], data: &[f64]) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wtss_strided(w, 1, data, 1, data.len())
+}
+
+/// Same as [`wtss`], but only uses the `n` elements of `w` and `data` separated by `wstride` and
+/// `stride`.
+///
+/// Panics if one of the datasets is too short.
+#[doc(alias = "gsl_stats_wtss")]
+pub fn wtss_strided(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_wtss(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
 }
 
@@ -410,23 +830,83 @@ pub fn wtss(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) ->
 /// computed using gsl_stats_wmean.
 ///
 /// TSS =  \sum w_i (x_i - wmean)^2
+///
+/// Panics if the datasets don't have the same length.
 #[doc(alias = "gsl_stats_wtss_m")]
-pub fn wtss_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize, wmean: f64) -> f64 {
+pub fn wtss_m(w: &[f64], data: &[f64], wmean: f64) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wtss_m_strided(w, 1, data, 1, data.len(), wmean)
+}
+
+/// Same as [`wtss_m`], but only uses the `n` elements of `w` and `data` separated by `wstride` and
+/// `stride`.
+///
+/// Panics if one of the datasets is too short.
+#[doc(alias = "gsl_stats_wtss_m")]
+pub fn wtss_m_strided(
+    w: &[f64],
+    wstride: usize,
+    data: &[f64],
+    stride: usize,
+    n: usize,
+    wmean: f64,
+) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_wtss_m(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean) }
 }
 
-/// This function computes the weighted absolute deviation from the weighted mean of data. The absolute deviation from the mean is defined
-/// as,
+/// This function computes the weighted absolute deviation from the weighted mean of data. The
+/// absolute deviation from the mean is defined as,
 ///
 /// absdev = (\sum w_i |x_i - \Hat\mu|) / (\sum w_i)
+///
+/// Panics if the datasets don't have the same length.
+#[doc(alias = "gsl_stats_wabsdev")]
+pub fn wabsdev(w: &[f64], data: &[f64]) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wabsdev_strided(w, 1, data, 1, data.len())
+}
+
+/// Same as [`wabsdev`], but only uses the `n` elements of `w` and `data` separated by `wstride` and
+/// `stride`.
+///
+/// Panics if one of the datasets is too short.
 #[doc(alias = "gsl_stats_wabsdev")]
-pub fn wabsdev(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn wabsdev_strided(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_wabsdev(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
 }
 
-/// This function computes the absolute deviation of the weighted dataset data about the given weighted mean wmean.
+/// This function computes the absolute deviation of the weighted dataset data about the given
+/// weighted mean wmean.
+///
+/// Panics if the datasets don't have the same length.
+#[doc(alias = "gsl_stats_wabsdev_m")]
+pub fn wabsdev_m(w: &[f64], data: &[f64], wmean: f64) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wabsdev_m_strided(w, 1, data, 1, data.len(), wmean)
+}
+
+/// Same as [`wabsdev_m`], but only uses the `n` elements of `w` and `data` separated by `wstride`
+/// and `stride`.
+///
+/// Panics if one of the datasets is too short.
 #[doc(alias = "gsl_stats_wabsdev_m")]
-pub fn wabsdev_m(
+pub fn wabsdev_m_strided(
     w: &[f64],
     wstride: usize,
     data: &[f64],
@@ -434,21 +914,57 @@ pub fn wabsdev_m(
     n: usize,
     wmean: f64,
 ) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_wabsdev_m(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean) }
 }
 
 /// This function computes the weighted skewness of the dataset data.
 ///
 /// skew = (\sum w_i ((x_i - \Hat x)/\Hat \sigma)^3) / (\sum w_i)
+///
+/// Panics if the datasets don't have the same length.
 #[doc(alias = "gsl_stats_wskew")]
-pub fn wskew(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn wskew(w: &[f64], data: &[f64]) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wskew_strided(w, 1, data, 1, data.len())
+}
+
+/// Same as [`wskew`], but only uses the `n` elements of `w` and `data` separated by `wstride` and
+/// `stride`.
+///
+/// Panics if one of the datasets is too short.
+#[doc(alias = "gsl_stats_wskew")]
+pub fn wskew_strided(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_wskew(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
 }
 
 /// This function computes the weighted skewness of the dataset data using the given values of the
 /// weighted mean and weighted standard deviation, wmean and wsd.
+///
+/// Panics if the datasets don't have the same length.
 #[doc(alias = "gsl_stats_wskew_m_sd")]
-pub fn wskew_m_sd(
+pub fn wskew_m_sd(w: &[f64], data: &[f64], wmean: f64, wsd: f64) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wskew_m_sd_strided(w, 1, data, 1, data.len(), wmean, wsd)
+}
+
+/// Same as [`wskew_m_sd`], but only uses the `n` elements of `w` and `data` separated by `wstride`
+/// and `stride`.
+///
+/// Panics if one of the datasets is too short.
+#[doc(alias = "gsl_stats_wskew_m_sd")]
+pub fn wskew_m_sd_strided(
     w: &[f64],
     wstride: usize,
     data: &[f64],
@@ -457,21 +973,57 @@ pub fn wskew_m_sd(
     wmean: f64,
     wsd: f64,
 ) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_wskew_m_sd(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean, wsd) }
 }
 
 /// This function computes the weighted kurtosis of the dataset data.
 ///
 /// kurtosis = ((\sum w_i ((x_i - \Hat x)/\Hat \sigma)^4) / (\sum w_i)) - 3
+///
+/// Panics if the datasets don't have the same length.
 #[doc(alias = "gsl_stats_wkurtosis")]
-pub fn wkurtosis(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn wkurtosis(w: &[f64], data: &[f64]) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wkurtosis_strided(w, 1, data, 1, data.len())
+}
+
+/// Same as [`wkurtosis`], but only uses the `n` elements of `w` and `data` separated by `wstride`
+/// and `stride`.
+///
+/// Panics if one of the datasets is too short.
+#[doc(alias = "gsl_stats_wkurtosis")]
+pub fn wkurtosis_strided(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_wkurtosis(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
 }
 
 /// This function computes the weighted kurtosis of the dataset data using the given values of the
 /// weighted mean and weighted standard deviation, wmean and wsd.
+///
+/// Panics if the datasets don't have the same length.
 #[doc(alias = "gsl_stats_wkurtosis_m_sd")]
-pub fn wkurtosis_m_sd(
+pub fn wkurtosis_m_sd(w: &[f64], data: &[f64], wmean: f64, wsd: f64) -> f64 {
+    assert_eq!(
+        w.len(),
+        data.len(),
+        "the datasets must have the same length"
+    );
+    wkurtosis_m_sd_strided(w, 1, data, 1, data.len(), wmean, wsd)
+}
+
+/// Same as [`wkurtosis_m_sd`], but only uses the `n` elements of `w` and `data` separated by
+/// `wstride` and `stride`.
+///
+/// Panics if one of the datasets is too short.
+#[doc(alias = "gsl_stats_wkurtosis_m_sd")]
+pub fn wkurtosis_m_sd_strided(
     w: &[f64],
     wstride: usize,
     data: &[f64],
@@ -480,28 +1032,48 @@ pub fn wkurtosis_m_sd(
     wmean: f64,
     wsd: f64,
 ) -> f64 {
+    check_strided(w.len(), wstride, n);
+    check_strided(data.len(), stride, n);
     unsafe {
         sys::gsl_stats_wkurtosis_m_sd(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean, wsd)
     }
 }
 
-/// This function returns the maximum value in data, a dataset of length n with stride stride. The
-/// maximum value is defined as the value of the element x_i which satisfies x_i >= x_j for all j.
+/// This function returns the maximum value in data. The maximum value is defined as the value of
+/// the element x_i which satisfies x_i >= x_j for all j.
 ///
 /// If you want instead to find the element with the largest absolute magnitude you will need to
 /// apply fabs or abs to your data before calling this function.
 #[doc(alias = "gsl_stats_max")]
-pub fn max(data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn max(data: &[f64]) -> f64 {
+    max_strided(data, 1, data.len())
+}
+
+/// Same as [`max`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_max")]
+pub fn max_strided(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_max(data.as_ptr(), stride, n) }
 }
 
-/// This function returns the minimum value in data, a dataset of length n with stride stride. The
-/// minimum value is defined as the value of the element x_i which satisfies x_i <= x_j for all j.
+/// This function returns the minimum value in data. The minimum value is defined as the value of
+/// the element x_i which satisfies x_i <= x_j for all j.
 ///
 /// If you want instead to find the element with the smallest absolute magnitude you will need to
 /// apply fabs or abs to your data before calling this function.
 #[doc(alias = "gsl_stats_min")]
-pub fn min(data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn min(data: &[f64]) -> f64 {
+    min_strided(data, 1, data.len())
+}
+
+/// Same as [`min`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_min")]
+pub fn min_strided(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_min(data.as_ptr(), stride, n) }
 }
 
@@ -509,26 +1081,53 @@ pub fn min(data: &[f64], stride: usize, n: usize) -> f64 {
 ///
 /// Returns `(min, max)`.
 #[doc(alias = "gsl_stats_minmax")]
-pub fn minmax(data: &[f64], stride: usize, n: usize) -> (f64, f64) {
+pub fn minmax(data: &[f64]) -> (f64, f64) {
+    minmax_strided(data, 1, data.len())
+}
+
+/// Same as [`minmax`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_minmax")]
+pub fn minmax_strided(data: &[f64], stride: usize, n: usize) -> (f64, f64) {
+    check_strided(data.len(), stride, n);
     let mut min = 0.;
     let mut max = 0.;
     unsafe { sys::gsl_stats_minmax(&mut min, &mut max, data.as_ptr(), stride, n) };
     (min, max)
 }
 
-/// This function returns the index of the maximum value in data, a dataset of length n with stride
-/// stride. The maximum value is defined as the value of the element x_i which satisfies x_i >= x_j
-/// for all j. When there are several equal maximum elements then the first one is chosen.
+/// This function returns the index of the maximum value in data. The maximum value is defined as
+/// the value of the element x_i which satisfies x_i >= x_j for all j. When there are several equal
+/// maximum elements then the first one is chosen.
+#[doc(alias = "gsl_stats_max_index")]
+pub fn max_index(data: &[f64]) -> usize {
+    max_index_strided(data, 1, data.len())
+}
+
+/// Same as [`max_index`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
 #[doc(alias = "gsl_stats_max_index")]
-pub fn max_index(data: &[f64], stride: usize, n: usize) -> usize {
+pub fn max_index_strided(data: &[f64], stride: usize, n: usize) -> usize {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_max_index(data.as_ptr(), stride, n) }
 }
 
-/// This function returns the index of the minimum value in data, a dataset of length n with stride
-/// stride. The minimum value is defined as the value of the element x_i which satisfies x_i >= x_j
-/// for all j. When there are several equal minimum elements then the first one is chosen.
+/// This function returns the index of the minimum value in data. The minimum value is defined as
+/// the value of the element x_i which satisfies x_i >= x_j for all j. When there are several equal
+/// minimum elements then the first one is chosen.
+#[doc(alias = "gsl_stats_min_index")]
+pub fn min_index(data: &[f64]) -> usize {
+    min_index_strided(data, 1, data.len())
+}
+
+/// Same as [`min_index`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
 #[doc(alias = "gsl_stats_min_index")]
-pub fn min_index(data: &[f64], stride: usize, n: usize) -> usize {
+pub fn min_index_strided(data: &[f64], stride: usize, n: usize) -> usize {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_min_index(data.as_ptr(), stride, n) }
 }
 
@@ -537,7 +1136,16 @@ pub fn min_index(data: &[f64], stride: usize, n: usize) -> usize {
 ///
 /// Returns `(min_index, max_index)`.
 #[doc(alias = "gsl_stats_minmax_index")]
-pub fn minmax_index(data: &[f64], stride: usize, n: usize) -> (usize, usize) {
+pub fn minmax_index(data: &[f64]) -> (usize, usize) {
+    minmax_index_strided(data, 1, data.len())
+}
+
+/// Same as [`minmax_index`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_minmax_index")]
+pub fn minmax_index_strided(data: &[f64], stride: usize, n: usize) -> (usize, usize) {
+    check_strided(data.len(), stride, n);
     let mut min_index = 0;
     let mut max_index = 0;
     unsafe {
@@ -546,23 +1154,32 @@ pub fn minmax_index(data: &[f64], stride: usize, n: usize) -> (usize, usize) {
     (min_index, max_index)
 }
 
-/// This function returns the median value of sorted_data, a dataset of length n with stride stride.
-/// The elements of the array must be in ascending numerical order. There are no checks to see
-/// whether the data are sorted, so the function gsl_sort should always be used first.
+/// This function returns the median value of sorted_data. The elements of the array must be in
+/// ascending numerical order. There are no checks to see whether the data are sorted, so the
+/// function gsl_sort should always be used first.
 ///
 /// When the dataset has an odd number of elements the median is the value of element (n-1)/2. When
 /// the dataset has an even number of elements the median is the mean of the two nearest middle
 /// values, elements (n-1)/2 and n/2. Since the algorithm for computing the median involves
 /// interpolation this function always returns a floating-point number, even for integer data types.
 #[doc(alias = "gsl_stats_median_from_sorted_data")]
-pub fn median_from_sorted_data(data: &[f64], stride: usize, n: usize) -> f64 {
+pub fn median_from_sorted_data(data: &[f64]) -> f64 {
+    median_from_sorted_data_strided(data, 1, data.len())
+}
+
+/// Same as [`median_from_sorted_data`], but only uses the `n` elements of `data` separated by
+/// `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_median_from_sorted_data")]
+pub fn median_from_sorted_data_strided(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_median_from_sorted_data(data.as_ptr(), stride, n) }
 }
 
-/// This function returns a quantile value of sorted_data, a double-precision array of length n with
-/// stride stride. The elements of the array must be in ascending numerical order. The quantile is
-/// determined by the f, a fraction between 0 and 1. For example, to compute the value of the 75th
-/// percentile f should have the value 0.75.
+/// This function returns a quantile value of sorted_data. The elements of the array must be in
+/// ascending numerical order. The quantile is determined by the f, a fraction between 0 and 1. For
+/// example, to compute the value of the 75th percentile f should have the value 0.75.
 ///
 /// There are no checks to see whether the data are sorted, so the function gsl_sort should always
 /// be used first.
@@ -573,11 +1190,285 @@ pub fn median_from_sorted_data(data: &[f64], stride: usize, n: usize) -> f64 {
 ///
 /// where i is floor((n - 1)f) and \delta is (n-1)f - i.
 ///
-/// Thus the minimum value of the array (data[0*stride]) is given by f equal to zero, the maximum
-/// value (data[(n-1)*stride]) is given by f equal to one and the median value is given by f equal
+/// Thus the minimum value of the array (`data[0]`) is given by f equal to zero, the maximum
+/// value (`data[n - 1]`) is given by f equal to one and the median value is given by f equal
 /// to 0.5. Since the algorithm for computing quantiles involves interpolation this function always
 /// returns a floating-point number, even for integer data types.
 #[doc(alias = "gsl_stats_quantile_from_sorted_data")]
-pub fn quantile_from_sorted_data(data: &[f64], stride: usize, n: usize, f: f64) -> f64 {
+pub fn quantile_from_sorted_data(data: &[f64], f: f64) -> f64 {
+    quantile_from_sorted_data_strided(data, 1, data.len(), f)
+}
+
+/// Same as [`quantile_from_sorted_data`], but only uses the `n` elements of `data` separated by
+/// `stride`.
+///
+/// Panics if `data` is too short.
+#[doc(alias = "gsl_stats_quantile_from_sorted_data")]
+pub fn quantile_from_sorted_data_strided(data: &[f64], stride: usize, n: usize, f: f64) -> f64 {
+    check_strided(data.len(), stride, n);
     unsafe { sys::gsl_stats_quantile_from_sorted_data(data.as_ptr(), stride, n, f) }
 }
+
+/// This function returns the median value of `data`, which doesn't need to be sorted. `data` is
+/// copied first, so it isn't modified: use [`select`] to avoid the copy.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_median")]
+pub fn median(data: &[f64]) -> f64 {
+    median_strided(data, 1, data.len())
+}
+
+/// Same as [`median`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_median")]
+pub fn median_strided(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(data.len(), stride, n);
+    let mut copy: Vec<f64> = data
+        .iter()
+        .step_by(stride.max(1))
+        .take(n)
+        .copied()
+        .collect();
+    unsafe { sys::gsl_stats_median(copy.as_mut_ptr(), 1, n) }
+}
+
+/// This function finds the k-th smallest element of `data` (starting from 0), using the
+/// quickselect method. `data` is rearranged in the process.
+///
+/// Panics if `k` isn't smaller than the length of `data`.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_select")]
+pub fn select(data: &mut [f64], k: usize) -> f64 {
+    let n = data.len();
+    select_strided(data, 1, n, k)
+}
+
+/// Same as [`select`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short or if `k` isn't smaller than `n`.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_select")]
+pub fn select_strided(data: &mut [f64], stride: usize, n: usize, k: usize) -> f64 {
+    check_strided(data.len(), stride, n);
+    assert!(k < n, "`k` must be smaller than the number of elements");
+    unsafe { sys::gsl_stats_select(data.as_mut_ptr(), stride, n, k) }
+}
+
+/// This function returns the trimmed mean of `sorted_data`, which must be in ascending numerical
+/// order. The trimmed mean is the mean of the data once the `trim` fraction (between 0 and 0.5)
+/// of the smallest and of the largest elements are discarded,
+///
+/// \Hat\mu_\alpha = 1/(n - 2k) \sum_{i=k+1}^{n-k} x_i
+///
+/// where k = floor(\alpha n). It is a robust estimator of the location of the data.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_trmean_from_sorted_data")]
+pub fn trmean_from_sorted_data(trim: f64, sorted_data: &[f64]) -> f64 {
+    trmean_from_sorted_data_strided(trim, sorted_data, 1, sorted_data.len())
+}
+
+/// Same as [`trmean_from_sorted_data`], but only uses the `n` elements of `sorted_data`
+/// separated by `stride`.
+///
+/// Panics if `sorted_data` is too short.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_trmean_from_sorted_data")]
+pub fn trmean_from_sorted_data_strided(
+    trim: f64,
+    sorted_data: &[f64],
+    stride: usize,
+    n: usize,
+) -> f64 {
+    check_strided(sorted_data.len(), stride, n);
+    unsafe { sys::gsl_stats_trmean_from_sorted_data(trim, sorted_data.as_ptr(), stride, n) }
+}
+
+/// This function returns the Gastwirth location estimator of `sorted_data`, which must be in
+/// ascending numerical order. It is a weighted sum of the 1/3, 1/2 and 2/3 quantiles,
+///
+/// \Hat\mu_G = 0.3 Q_{1/3} + 0.4 Q_{1/2} + 0.3 Q_{2/3}
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_gastwirth_from_sorted_data")]
+pub fn gastwirth_from_sorted_data(sorted_data: &[f64]) -> f64 {
+    gastwirth_from_sorted_data_strided(sorted_data, 1, sorted_data.len())
+}
+
+/// Same as [`gastwirth_from_sorted_data`], but only uses the `n` elements of `sorted_data`
+/// separated by `stride`.
+///
+/// Panics if `sorted_data` is too short.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_gastwirth_from_sorted_data")]
+pub fn gastwirth_from_sorted_data_strided(sorted_data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(sorted_data.len(), stride, n);
+    unsafe { sys::gsl_stats_gastwirth_from_sorted_data(sorted_data.as_ptr(), stride, n) }
+}
+
+/// This function returns the median absolute deviation (MAD) of `data`, which doesn't need to be
+/// sorted,
+///
+/// MAD = 1.4826 \times median { | x_i - median(x) | }
+///
+/// The 1.4826 factor makes it an unbiased estimator of the standard deviation for Gaussian
+/// data. See [`mad0`] for the MAD without this factor.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_mad")]
+pub fn mad(data: &[f64]) -> f64 {
+    mad_strided(data, 1, data.len())
+}
+
+/// Same as [`mad`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_mad")]
+pub fn mad_strided(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(data.len(), stride, n);
+    let mut work = alloc::vec![0.; n];
+    unsafe { sys::gsl_stats_mad(data.as_ptr(), stride, n, work.as_mut_ptr()) }
+}
+
+/// This function returns the median absolute deviation of `data`, without the 1.4826 scale factor
+/// of [`mad`].
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_mad0")]
+pub fn mad0(data: &[f64]) -> f64 {
+    mad0_strided(data, 1, data.len())
+}
+
+/// Same as [`mad0`], but only uses the `n` elements of `data` separated by `stride`.
+///
+/// Panics if `data` is too short.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_mad0")]
+pub fn mad0_strided(data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(data.len(), stride, n);
+    let mut work = alloc::vec![0.; n];
+    unsafe { sys::gsl_stats_mad0(data.as_ptr(), stride, n, work.as_mut_ptr()) }
+}
+
+/// This function returns the S_n scale estimator of Croux and Rousseeuw of `sorted_data`, which
+/// must be in ascending numerical order,
+///
+/// S_n = 1.1926 \times c_n \times median_i { median_j ( | x_i - x_j | ) }
+///
+/// where c_n is a correction factor for small samples. Like the MAD, it is a robust estimator of
+/// the standard deviation for Gaussian data, but it is more efficient and doesn't assume the
+/// distribution is symmetric.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Sn_from_sorted_data")]
+pub fn sn_from_sorted_data(sorted_data: &[f64]) -> f64 {
+    sn_from_sorted_data_strided(sorted_data, 1, sorted_data.len())
+}
+
+/// Same as [`sn_from_sorted_data`], but only uses the `n` elements of `sorted_data` separated by
+/// `stride`.
+///
+/// Panics if `sorted_data` is too short.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Sn_from_sorted_data")]
+pub fn sn_from_sorted_data_strided(sorted_data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(sorted_data.len(), stride, n);
+    let mut work = alloc::vec![0.; n];
+    unsafe {
+        sys::gsl_stats_Sn_from_sorted_data(sorted_data.as_ptr(), stride, n, work.as_mut_ptr())
+    }
+}
+
+/// This function returns the Q_n scale estimator of Croux and Rousseeuw of `sorted_data`, which
+/// must be in ascending numerical order,
+///
+/// Q_n = 2.21914 \times d_n \times { | x_i - x_j |, i < j }_{(k)}
+///
+/// the k-th order statistic of the pairwise distances, with k = (floor(n / 2) + 1 choose 2) and
+/// d_n a correction factor for small samples.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Qn_from_sorted_data")]
+pub fn qn_from_sorted_data(sorted_data: &[f64]) -> f64 {
+    qn_from_sorted_data_strided(sorted_data, 1, sorted_data.len())
+}
+
+/// Same as [`qn_from_sorted_data`], but only uses the `n` elements of `sorted_data` separated by
+/// `stride`.
+///
+/// Panics if `sorted_data` is too short.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Qn_from_sorted_data")]
+pub fn qn_from_sorted_data_strided(sorted_data: &[f64], stride: usize, n: usize) -> f64 {
+    check_strided(sorted_data.len(), stride, n);
+    let mut work = alloc::vec![0.; 3 * n];
+    let mut work_int = alloc::vec![0; 5 * n];
+    unsafe {
+        sys::gsl_stats_Qn_from_sorted_data(
+            sorted_data.as_ptr(),
+            stride,
+            n,
+            work.as_mut_ptr(),
+            work_int.as_mut_ptr(),
+        )
+    }
+}
+
+fn check_strided(len: usize, stride: usize, n: usize) {
+    assert!(
+        n == 0 || (n - 1).checked_mul(stride).is_some_and(|last| last < len),
+        "a dataset of length {} can't hold {} elements with a stride of {}",
+        len,
+        n,
+        stride
+    );
+}
+
+#[test]
+#[should_panic]
+fn strided_out_of_bounds() {
+    mean_strided(&[1., 2., 3., 4.], 2, 3);
+}
+
+#[test]
+#[should_panic]
+fn strided_overflow() {
+    mean_strided(&[1., 2., 3., 4.], 1 << (usize::BITS - 1), 3);
+}
+
+#[test]
+fn covariance_and_correlation() {
+    let x = [1., 2., 3., 4., 5.];
+    let y = [2., 4., 6., 8., 10.];
+    assert_eq!(covariance(&x, &y), 5.);
+    assert_eq!(covariance_m(&x, &y, 3., 6.), 5.);
+    assert!((correlation(&x, &y) - 1.).abs() < 1e-15);
+    assert_eq!(spearman(&x, &[5., 4., 3., 2., 1.]), -1.);
+}
+
+#[cfg(feature = "v2_5")]
+#[test]
+fn robust_estimators() {
+    let data = [4., 1., 3., 100., 2.];
+    assert_eq!(median(&data), 3.);
+    assert_eq!(data[3], 100.);
+    assert_eq!(median_strided(&data, 2, 3), 3.);
+    assert_eq!(select(&mut data.clone(), 1), 2.);
+
+    let mut sorted = data;
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(trmean_from_sorted_data(0.2, &sorted), 3.);
+    assert_eq!(mad0(&data), 1.);
+}
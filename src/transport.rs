@@ -4,8 +4,8 @@
 
 //! The transport functions J(n,x) are defined by the integral representations J(n,x) := \int_0^x dt t^n e^t /(e^t - 1)^2.
 
-use crate::Value;
-use std::mem::MaybeUninit;
+use crate::Error;
+use core::mem::MaybeUninit;
 
 /// This routine computes the transport function J(2,x).
 #[doc(alias = "gsl_sf_transport_2")]
@@ -15,11 +15,15 @@ pub fn transport_2(x: f64) -> f64 {
 
 /// This routine computes the transport function J(2,x).
 #[doc(alias = "gsl_sf_transport_2_e")]
-pub fn transport_2_e(x: f64) -> (Value, ::types::Result) {
+pub fn transport_2_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_transport_2_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_transport_2_e"
+    )
 }
 
 /// This routine computes the transport function J(3,x).
@@ -30,11 +34,15 @@ pub fn transport_3(x: f64) -> f64 {
 
 /// This routine computes the transport function J(3,x).
 #[doc(alias = "gsl_sf_transport_3_e")]
-pub fn transport_3_e(x: f64) -> (Value, ::types::Result) {
+pub fn transport_3_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_transport_3_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_transport_3_e"
+    )
 }
 
 /// This routine computes the transport function J(4,x).
@@ -45,11 +53,15 @@ pub fn transport_4(x: f64) -> f64 {
 
 /// This routine computes the transport function J(4,x).
 #[doc(alias = "gsl_sf_transport_4_e")]
-pub fn transport_4_e(x: f64) -> (Value, ::types::Result) {
+pub fn transport_4_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_transport_4_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_transport_4_e"
+    )
 }
 
 /// This routine computes the transport function J(5,x).
@@ -60,9 +72,13 @@ pub fn transport_5(x: f64) -> f64 {
 
 /// This routine computes the transport function J(5,x).
 #[doc(alias = "gsl_sf_transport_5_e")]
-pub fn transport_5_e(x: f64) -> (Value, ::types::Result) {
+pub fn transport_5_e(x: f64) -> Result<::types::Result, Error> {
     let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
     let ret = unsafe { sys::gsl_sf_transport_5_e(x, result.as_mut_ptr()) };
 
-    (::Value::from(ret), unsafe { result.assume_init() }.into())
+    result_handler!(
+        ret,
+        unsafe { result.assume_init() }.into(),
+        "gsl_sf_transport_5_e"
+    )
 }
@@ -47,9 +47,9 @@ A large collection of B-spline routines is available in the PPPACK library avail
 http://www.netlib.org/pppack, which is also part of SLATEC.
 !*/
 
-use crate::Value;
+use crate::Error;
 use ffi::FFI;
-use types::VectorF64;
+use types::{MatrixF64, VectorF64};
 
 ffi_wrapper!(
     BSpLineWorkspace,
@@ -57,6 +57,8 @@ ffi_wrapper!(
     gsl_bspline_free
 );
 
+unsafe impl Send for BSpLineWorkspace {}
+
 impl BSpLineWorkspace {
     /// This function allocates a workspace for computing B-splines of order k.
     ///
@@ -78,18 +80,18 @@ impl BSpLineWorkspace {
     /// This function computes the knots associated with the given breakpoints and stores them
     /// internally in w->knots.
     #[doc(alias = "gsl_bspline_knots")]
-    pub fn knots(&mut self, breakpts: &VectorF64) -> Value {
-        Value::from(unsafe {
-            sys::gsl_bspline_knots(breakpts.unwrap_shared(), self.unwrap_unique())
-        })
+    pub fn knots(&mut self, breakpts: &VectorF64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_bspline_knots(breakpts.unwrap_shared(), self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_bspline_knots")
     }
 
     /// This function assumes uniformly spaced breakpoints on [a,b] and constructs the corresponding
     /// knot vector using the previously specified nbreak parameter.
     /// The knots are stored in w->knots.
     #[doc(alias = "gsl_bspline_knots_uniform")]
-    pub fn knots_uniform(&mut self, a: f64, b: f64) -> Value {
-        Value::from(unsafe { sys::gsl_bspline_knots_uniform(a, b, self.unwrap_unique()) })
+    pub fn knots_uniform(&mut self, a: f64, b: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_bspline_knots_uniform(a, b, self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_bspline_knots_uniform")
     }
 
     /// This function evaluates all B-spline basis functions at the position x and stores them in
@@ -101,8 +103,9 @@ impl BSpLineWorkspace {
     /// Computing all the basis functions at once is more efficient than computing them
     /// individually, due to the nature of the defining recurrence relation.
     #[doc(alias = "gsl_bspline_eval")]
-    pub fn eval(&mut self, x: f64, B: &mut VectorF64) -> Value {
-        Value::from(unsafe { sys::gsl_bspline_eval(x, B.unwrap_unique(), self.unwrap_unique()) })
+    pub fn eval(&mut self, x: f64, B: &mut VectorF64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_bspline_eval(x, B.unwrap_unique(), self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_bspline_eval")
     }
 
     /// This function evaluates all potentially nonzero B-spline basis functions at the position x
@@ -113,9 +116,9 @@ impl BSpLineWorkspace {
     /// linear combinations of the B_i(x) to be computed without unnecessary terms (such linear
     /// combinations occur, for example, when evaluating an interpolated function).
     ///
-    /// Returns `(Value, istart, iend)`.
+    /// Returns `(istart, iend)`.
     #[doc(alias = "gsl_bspline_eval_nonzero")]
-    pub fn eval_non_zero(&mut self, x: f64, Bk: &mut VectorF64) -> (Value, usize, usize) {
+    pub fn eval_non_zero(&mut self, x: f64, Bk: &mut VectorF64) -> Result<(usize, usize), Error> {
         let mut istart = 0;
         let mut iend = 0;
         let ret = unsafe {
@@ -127,7 +130,85 @@ impl BSpLineWorkspace {
                 self.unwrap_unique(),
             )
         };
-        (Value::from(ret), istart, iend)
+        result_handler!(ret, (istart, iend), "gsl_bspline_eval_nonzero")
+    }
+
+    /// This function computes the knots from the Greville abscissae `abscissae` (of length
+    /// n = nbreak + k - 2), which is useful for B-spline interpolation.
+    ///
+    /// Returns the absolute error of the abscissae computed from the knots, which should be small if
+    /// the given abscissae are consistent with a knot vector.
+    #[doc(alias = "gsl_bspline_knots_greville")]
+    pub fn knots_greville(&mut self, abscissae: &VectorF64) -> Result<f64, Error> {
+        let mut abserr = 0.;
+        let ret = unsafe {
+            sys::gsl_bspline_knots_greville(
+                abscissae.unwrap_shared(),
+                self.unwrap_unique(),
+                &mut abserr,
+            )
+        };
+        result_handler!(ret, abserr, "gsl_bspline_knots_greville")
+    }
+
+    /// This function evaluates all B-spline basis function derivatives of orders 0 through
+    /// `nderiv` (inclusive) at the position x and stores them in the matrix dB. The (i,j)-th
+    /// element of dB is d^jB_i(x)/dx^j.
+    ///
+    /// The matrix dB must be of size n = nbreak + k - 2 by nderiv + 1.
+    #[doc(alias = "gsl_bspline_deriv_eval")]
+    pub fn deriv_eval(&mut self, x: f64, nderiv: usize, dB: &mut MatrixF64) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_bspline_deriv_eval(x, nderiv, dB.unwrap_unique(), self.unwrap_unique())
+        };
+        result_handler!(ret, (), "gsl_bspline_deriv_eval")
+    }
+
+    /// This function evaluates all potentially nonzero B-spline basis function derivatives of
+    /// orders 0 through `nderiv` (inclusive) at the position x and stores them in the matrix dB.
+    /// The (i,j)-th element of dB is d^jB_(istart+i)(x)/dx^j.
+    ///
+    /// The matrix dB must be of size k by at least nderiv + 1.
+    ///
+    /// Returns `(istart, iend)`.
+    #[doc(alias = "gsl_bspline_deriv_eval_nonzero")]
+    pub fn deriv_eval_non_zero(
+        &mut self,
+        x: f64,
+        nderiv: usize,
+        dB: &mut MatrixF64,
+    ) -> Result<(usize, usize), Error> {
+        let mut istart = 0;
+        let mut iend = 0;
+        let ret = unsafe {
+            sys::gsl_bspline_deriv_eval_nonzero(
+                x,
+                nderiv,
+                dB.unwrap_unique(),
+                &mut istart,
+                &mut iend,
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, (istart, iend), "gsl_bspline_deriv_eval_nonzero")
+    }
+
+    /// This function returns the order k of the B-splines.
+    #[doc(alias = "gsl_bspline_order")]
+    pub fn order(&mut self) -> usize {
+        unsafe { sys::gsl_bspline_order(self.unwrap_unique()) }
+    }
+
+    /// This function returns the number of breakpoints.
+    #[doc(alias = "gsl_bspline_nbreak")]
+    pub fn nbreak(&mut self) -> usize {
+        unsafe { sys::gsl_bspline_nbreak(self.unwrap_unique()) }
+    }
+
+    /// This function returns the i-th breakpoint.
+    #[doc(alias = "gsl_bspline_breakpoint")]
+    pub fn breakpoint(&mut self, i: usize) -> f64 {
+        unsafe { sys::gsl_bspline_breakpoint(i, self.unwrap_unique()) }
     }
 
     /// This function returns the number of B-spline coefficients given by n = nbreak + k - 2.
@@ -30,11 +30,13 @@ R. Broucke, `Ten Subroutines for the Manipulation of Chebyshev Series [C1] (Algo
 Communications of the ACM 16(4), 254–256 (1973)
 !*/
 
-use crate::Value;
+use crate::{Error, Value};
 use ffi::FFI;
 
 ffi_wrapper!(ChebSeries, *mut sys::gsl_cheb_series, gsl_cheb_free);
 
+unsafe impl Send for ChebSeries {}
+
 impl ChebSeries {
     #[doc(alias = "gsl_cheb_alloc")]
     pub fn new(n: usize) -> Option<Self> {
@@ -51,10 +53,14 @@ impl ChebSeries {
     /// (a,b) to the previously specified order. The computation of the Chebyshev approximation is
     /// an O(n^2) process, and requires n function evaluations.
     #[doc(alias = "gsl_cheb_init")]
-    pub fn init<F: Fn(f64) -> f64>(&mut self, f: F, a: f64, b: f64) -> Value {
+    pub fn init<F: FnMut(f64) -> f64>(&mut self, mut f: F, a: f64, b: f64) -> Result<(), Error> {
         let function = wrap_callback!(f, F);
 
-        Value::from(unsafe { sys::gsl_cheb_init(self.unwrap_unique(), &function, a, b) })
+        result_handler!(
+            unsafe { sys::gsl_cheb_init(self.unwrap_unique(), &function, a, b) },
+            (),
+            "gsl_cheb_init"
+        )
     }
 
     /// This function returns the order of Chebyshev series cs.
@@ -70,6 +76,14 @@ impl ChebSeries {
         unsafe { sys::gsl_cheb_size(self.unwrap_shared()) }
     }
 
+    /// This function returns the Chebyshev coefficient array c[] for the Chebyshev series cs.
+    #[doc(alias = "gsl_cheb_coeffs")]
+    pub fn coeffs(&self) -> &[f64] {
+        unsafe {
+            ::std::slice::from_raw_parts(sys::gsl_cheb_coeffs(self.unwrap_shared()), self.size())
+        }
+    }
+
     /// This function evaluates the Chebyshev series cs at a given point x.
     #[doc(alias = "gsl_cheb_eval")]
     pub fn eval(&self, x: f64) -> f64 {
@@ -82,13 +96,40 @@ impl ChebSeries {
     ///
     /// Returns `(result, abs_err)`.
     #[doc(alias = "gsl_cheb_eval_err")]
-    pub fn eval_err(&self, x: f64) -> (Value, f64, f64) {
+    pub fn eval_err(&self, x: f64) -> Result<(f64, f64), Error> {
         let mut result = 0.;
         let mut abs_err = 0.;
 
         let ret =
             unsafe { sys::gsl_cheb_eval_err(self.unwrap_shared(), x, &mut result, &mut abs_err) };
-        (::Value::from(ret), result, abs_err)
+        result_handler!(ret, (result, abs_err), "gsl_cheb_eval_err")
+    }
+
+    /// This function evaluates the Chebyshev series cs at a given point x, to the precision given by
+    /// `mode`.
+    #[doc(alias = "gsl_cheb_eval_mode")]
+    pub fn eval_mode(&self, x: f64, mode: ::Mode) -> f64 {
+        unsafe { sys::gsl_cheb_eval_mode(self.unwrap_shared(), x, mode.into()) }
+    }
+
+    /// Same as [`eval_mode`](Self::eval_mode), estimating the absolute error as well.
+    ///
+    /// Returns `(result, abs_err)`.
+    #[doc(alias = "gsl_cheb_eval_mode_e")]
+    pub fn eval_mode_e(&self, x: f64, mode: ::Mode) -> Result<(f64, f64), Error> {
+        let mut result = 0.;
+        let mut abs_err = 0.;
+
+        let ret = unsafe {
+            sys::gsl_cheb_eval_mode_e(
+                self.unwrap_shared(),
+                x,
+                mode.into(),
+                &mut result,
+                &mut abs_err,
+            )
+        };
+        result_handler!(ret, (result, abs_err), "gsl_cheb_eval_mode_e")
     }
 
     /// This function evaluates the Chebyshev series cs at a given point x, to (at most) the given
@@ -104,24 +145,23 @@ impl ChebSeries {
     ///
     /// Returns `(result, abs_err)`.
     #[doc(alias = "gsl_cheb_eval_n_err")]
-    pub fn eval_n_err(&self, order: usize, x: f64) -> (Value, f64, f64) {
+    pub fn eval_n_err(&self, order: usize, x: f64) -> Result<(f64, f64), Error> {
         let mut result = 0.;
         let mut abs_err = 0.;
 
         let ret = unsafe {
             sys::gsl_cheb_eval_n_err(self.unwrap_shared(), order, x, &mut result, &mut abs_err)
         };
-        (::Value::from(ret), result, abs_err)
+        result_handler!(ret, (result, abs_err), "gsl_cheb_eval_n_err")
     }
 
     /// This function computes the derivative of the series cs, storing the derivative coefficients
     /// in the previously allocated deriv. The two series cs and deriv must have been allocated with
     /// the same order.
     #[doc(alias = "gsl_cheb_calc_deriv")]
-    pub fn calc_deriv(&self, deriv: &mut ChebSeries) -> Value {
-        Value::from(unsafe {
-            sys::gsl_cheb_calc_deriv(deriv.unwrap_unique(), self.unwrap_shared())
-        })
+    pub fn calc_deriv(&self, deriv: &mut ChebSeries) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_cheb_calc_deriv(deriv.unwrap_unique(), self.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_cheb_calc_deriv")
     }
 
     /// This function computes the integral of the series cs, storing the integral coefficients in
@@ -129,9 +169,39 @@ impl ChebSeries {
     /// the same order. The lower limit of the integration is taken to be the left hand end of the
     /// range a.
     #[doc(alias = "gsl_cheb_calc_integ")]
-    pub fn calc_integ(&self, integ: &mut ChebSeries) -> Value {
-        Value::from(unsafe {
-            sys::gsl_cheb_calc_integ(integ.unwrap_unique(), self.unwrap_shared())
-        })
+    pub fn calc_integ(&self, integ: &mut ChebSeries) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_cheb_calc_integ(integ.unwrap_unique(), self.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_cheb_calc_integ")
+    }
+
+    /// Returns a new series of the same order holding the derivative of this one (see
+    /// [`calc_deriv`](Self::calc_deriv)).
+    pub fn deriv(&self) -> Result<ChebSeries, Error> {
+        let mut deriv = ChebSeries::new(self.order())
+            .ok_or_else(|| Error::new(Value::NoMemory, "gsl_cheb_alloc"))?;
+        self.calc_deriv(&mut deriv)?;
+        Ok(deriv)
+    }
+
+    /// Returns a new series of the same order holding the integral of this one, starting from the
+    /// left hand end of the range (see [`calc_integ`](Self::calc_integ)).
+    pub fn integ(&self) -> Result<ChebSeries, Error> {
+        let mut integ = ChebSeries::new(self.order())
+            .ok_or_else(|| Error::new(Value::NoMemory, "gsl_cheb_alloc"))?;
+        self.calc_integ(&mut integ)?;
+        Ok(integ)
     }
 }
+
+#[test]
+fn cheb_deriv_integ() {
+    let mut cs = ChebSeries::new(40).unwrap();
+    cs.init(|x| x.sin(), 0., 3.).unwrap();
+    assert!((cs.eval(1.) - 1f64.sin()).abs() < 1e-12);
+    assert_eq!(cs.coeffs().len(), 41);
+
+    let deriv = cs.deriv().unwrap();
+    assert!((deriv.eval(1.) - 1f64.cos()).abs() < 1e-10);
+    let integ = cs.integ().unwrap();
+    assert!((integ.eval(1.) - (1. - 1f64.cos())).abs() < 1e-10);
+}
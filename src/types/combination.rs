@@ -18,12 +18,14 @@ Donald L. Kreher, Douglas R. Stinson, Combinatorial Algorithms: Generation, Enum
 1998, CRC Press LLC, ISBN 084933988X
 !*/
 
-use crate::Value;
+use crate::{Error, Value};
 use ffi::FFI;
 use std::fmt::{self, Debug, Formatter};
 
 ffi_wrapper!(Combination, *mut sys::gsl_combination, gsl_combination_free);
 
+unsafe impl Send for Combination {}
+
 impl Combination {
     /// This function allocates memory for a new combination with parameters n, k. The combination
     /// is not initialized and its elements are undefined. Use the function
@@ -72,10 +74,10 @@ impl Combination {
     /// This function copies the elements of the combination self into the combination dest. The two
     /// combinations must have the same size.
     #[doc(alias = "gsl_combination_memcpy")]
-    pub fn copy(&self, dest: &mut Combination) -> Value {
-        Value::from(unsafe {
-            sys::gsl_combination_memcpy(dest.unwrap_unique(), self.unwrap_shared())
-        })
+    pub fn copy(&self, dest: &mut Combination) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_combination_memcpy(dest.unwrap_unique(), self.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_combination_memcpy")
     }
 
     /// This function returns the value of the i-th element of the combination self. If i lies
@@ -113,32 +115,166 @@ impl Combination {
         }
     }
 
+    /// Returns a copy of the elements of the combination.
+    pub fn to_vec(&self) -> Vec<usize> {
+        self.as_slice().to_vec()
+    }
+
+    /// Creates a combination of k = `data.len()` elements taken from the range 0 to n-1 and
+    /// initializes it with `data`. Returns `None` if the allocation failed or if `data` isn't a
+    /// valid combination (elements must lie in the range 0 to n-1 and be strictly increasing).
+    pub fn from_slice(n: usize, data: &[usize]) -> Option<Self> {
+        if !is_valid_slice(n, data) {
+            return None;
+        }
+        let mut c = Self::new(n, data.len())?;
+        c.as_mut_slice().copy_from_slice(data);
+        Some(c)
+    }
+
     /// This function checks that the combination self is valid. The k elements should lie in the
     /// range 0 to n-1, with each value occurring once at most and in increasing order.
     #[doc(alias = "gsl_combination_valid")]
-    pub fn is_valid(&self) -> Value {
+    pub fn is_valid(&self) -> bool {
         // Little hack because `gsl_combination_valid` doesn't in fact need a mutable object...
-        Value::from(unsafe { sys::gsl_combination_valid(self.inner) })
+        Value::from(unsafe { sys::gsl_combination_valid(self.inner) }) == Value::Success
     }
 
     /// This function advances the combination self to the next combination in lexicographic order
-    /// and returns `Success`. If no further combinations are available it returns Failure and
-    /// leaves self unmodified. Starting with the first combination and repeatedly applying this
+    /// and returns `Ok(())`. If no further combinations are available it returns a `Failure`
+    /// error and leaves self unmodified. Starting with the first combination and repeatedly applying this
     /// function will iterate through all possible combinations of a given order.
     #[doc(alias = "gsl_combination_next")]
-    pub fn next(&mut self) -> Value {
-        Value::from(unsafe { sys::gsl_combination_next(self.unwrap_unique()) })
+    pub fn next(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_combination_next(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_combination_next")
     }
 
     /// This function steps backwards from the combination self to the previous combination in
-    /// lexicographic order, returning `Success`. If no previous combination is available it returns
-    /// `Failure` and leaves self unmodified.
+    /// lexicographic order, returning `Ok(())`. If no previous combination is available it returns
+    /// a `Failure` error and leaves self unmodified.
     #[doc(alias = "gsl_combination_prev")]
-    pub fn prev(&mut self) -> Value {
-        Value::from(unsafe { sys::gsl_combination_prev(self.unwrap_unique()) })
+    pub fn prev(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_combination_prev(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_combination_prev")
+    }
+
+    /// Returns an iterator yielding the current combination followed by all the next ones in
+    /// lexicographic order (using `Combination::next`).
+    pub fn iter(&mut self) -> CombinationIter<'_> {
+        CombinationIter {
+            c: self,
+            forward: true,
+            done: false,
+        }
+    }
+
+    /// Returns an iterator yielding the current combination followed by all the previous ones in
+    /// lexicographic order (using `Combination::prev`).
+    pub fn iter_rev(&mut self) -> CombinationIter<'_> {
+        CombinationIter {
+            c: self,
+            forward: false,
+            done: false,
+        }
     }
 }
 
+/// Iterator over successive combinations, created by [`Combination::iter`] and
+/// [`Combination::iter_rev`].
+pub struct CombinationIter<'a> {
+    c: &'a mut Combination,
+    forward: bool,
+    done: bool,
+}
+
+impl<'a> Iterator for CombinationIter<'a> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let current = self.c.to_vec();
+        let ret = if self.forward {
+            self.c.next()
+        } else {
+            self.c.prev()
+        };
+        self.done = ret.is_err();
+        Some(current)
+    }
+}
+
+/// Owning iterator over successive combinations, created by [`all_combinations`] or by calling
+/// `into_iter` on a [`Combination`].
+pub struct CombinationIntoIter {
+    c: Combination,
+    done: bool,
+}
+
+impl Iterator for CombinationIntoIter {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let current = self.c.to_vec();
+        self.done = self.c.next().is_err();
+        Some(current)
+    }
+}
+
+impl IntoIterator for Combination {
+    type Item = Vec<usize>;
+    type IntoIter = CombinationIntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        CombinationIntoIter {
+            c: self,
+            done: false,
+        }
+    }
+}
+
+fn is_valid_slice(n: usize, data: &[usize]) -> bool {
+    data.len() <= n && data.iter().all(|&v| v < n) && data.windows(2).all(|w| w[0] < w[1])
+}
+
+/// Creates a combination of k elements taken from n where n is the last element of `data` plus
+/// one. An empty `data` gives the empty combination of 1 element, since GSL doesn't allow n to be
+/// 0. Use [`Combination::from_slice`] to specify n explicitly.
+///
+/// Panics if `data` isn't strictly increasing or if the allocation fails.
+impl<'a> From<&'a [usize]> for Combination {
+    fn from(data: &'a [usize]) -> Self {
+        let n = data.last().map(|v| v + 1).unwrap_or(1);
+        assert!(
+            is_valid_slice(n, data),
+            "the elements of a combination must be strictly increasing, got {:?}",
+            data
+        );
+        Self::from_slice(n, data).expect("Combination::new failed")
+    }
+}
+
+/// Returns an iterator over all the k-element subsets of `{0, ..., n-1}` in lexicographic order.
+///
+/// ```
+/// use rgsl::combination::all_combinations;
+///
+/// let subsets: Vec<Vec<usize>> = all_combinations(4, 2).collect();
+/// assert_eq!(subsets.len(), 6);
+/// assert_eq!(subsets[0], vec![0, 1]);
+/// assert_eq!(subsets[5], vec![2, 3]);
+/// ```
+pub fn all_combinations(n: usize, k: usize) -> CombinationIntoIter {
+    Combination::new_with_init(n, k)
+        .expect("Combination::new_with_init failed")
+        .into_iter()
+}
+
 impl Debug for Combination {
     #[allow(unused_must_use)]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -154,3 +290,15 @@ impl Debug for Combination {
         write!(f, "]")
     }
 }
+
+#[test]
+#[should_panic(expected = "strictly increasing")]
+fn from_unsorted_slice() {
+    let _ = Combination::from(&[3, 1][..]);
+}
+
+#[test]
+fn from_empty_slice() {
+    let c = Combination::from(&[][..]);
+    assert_eq!((c.n(), c.k()), (1, 0));
+}
@@ -4,9 +4,9 @@
 
 // TODO : port to Rust type : http://doc.rust-lang.org/num/complex/struct.Complex.html
 
-use std::default::Default;
-use std::fmt;
-use std::fmt::{Debug, Formatter};
+use core::default::Default;
+use core::fmt;
+use core::fmt::{Debug, Formatter};
 
 #[doc(hidden)]
 pub trait CFFI<T> {
@@ -467,11 +467,11 @@ impl Default for ComplexF64 {
 
 impl CFFI<sys::gsl_complex> for ComplexF64 {
     fn wrap(t: sys::gsl_complex) -> ComplexF64 {
-        unsafe { ::std::mem::transmute(t) }
+        unsafe { ::core::mem::transmute(t) }
     }
 
     fn unwrap(self) -> sys::gsl_complex {
-        unsafe { ::std::mem::transmute(self) }
+        unsafe { ::core::mem::transmute(self) }
     }
 }
 
@@ -505,11 +505,11 @@ impl FFFI<ComplexF32> for sys::gsl_complex {
 
 impl FFFI<ComplexF64> for sys::gsl_complex {
     fn wrap(self) -> ComplexF64 {
-        unsafe { ::std::mem::transmute(self) }
+        unsafe { ::core::mem::transmute(self) }
     }
 
     fn unwrap(t: ComplexF64) -> sys::gsl_complex {
-        unsafe { ::std::mem::transmute(t) }
+        unsafe { ::core::mem::transmute(t) }
     }
 }
 
@@ -971,11 +971,11 @@ impl CFFI<sys::gsl_complex> for ComplexF32 {
 
 impl CFFI<sys::gsl_complex_float> for ComplexF32 {
     fn wrap(s: sys::gsl_complex_float) -> ComplexF32 {
-        unsafe { ::std::mem::transmute(s) }
+        unsafe { ::core::mem::transmute(s) }
     }
 
     fn unwrap(self) -> sys::gsl_complex_float {
-        unsafe { ::std::mem::transmute(self) }
+        unsafe { ::core::mem::transmute(self) }
     }
 }
 
@@ -1228,6 +1228,7 @@ impl CFFI<sys::gsl_complex_float> for ComplexF32 {
 //   return 0;
 // }
 // ```
+#[cfg(feature = "std")]
 #[test]
 fn complex_f64() {
     let v = ComplexF64::rect(10., 10.);
@@ -1520,6 +1521,7 @@ fn complex_f64() {
     );
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn complex_f32() {
     let v = ComplexF32::rect(10., 10.);
@@ -53,11 +53,13 @@ H. Fisk Johnson, Comp. Phys. Comm. 43, 181 (1987).
 D. Lemoine, J. Chem. Phys. 101, 3936 (1994).
 !*/
 
-use crate::Value;
+use crate::{Error, VectorF64};
 use ffi::FFI;
 
 ffi_wrapper!(DiscreteHankel, *mut sys::gsl_dht, gsl_dht_free);
 
+unsafe impl Send for DiscreteHankel {}
+
 impl DiscreteHankel {
     /// This function allocates a Discrete Hankel transform object of size `size`.
     #[doc(alias = "gsl_dht_alloc")]
@@ -86,8 +88,12 @@ impl DiscreteHankel {
 
     /// This function initializes the transform `self` for the given values of `nu` and `xmax`.
     #[doc(alias = "gsl_dht_init")]
-    pub fn init(&mut self, nu: f64, xmax: f64) -> Value {
-        Value::from(unsafe { sys::gsl_dht_init(self.unwrap_unique(), nu, xmax) })
+    pub fn init(&mut self, nu: f64, xmax: f64) -> Result<(), Error> {
+        result_handler!(
+            unsafe { sys::gsl_dht_init(self.unwrap_unique(), nu, xmax) },
+            (),
+            "gsl_dht_init"
+        )
     }
 
     /// This function applies the transform t to the array f_in whose size is equal to the size of
@@ -96,22 +102,59 @@ impl DiscreteHankel {
     /// Applying this function to its output gives the original data multiplied by (1/j_(\nu,M))^2,
     /// up to numerical errors.
     #[doc(alias = "gsl_dht_apply")]
-    pub fn apply(&mut self, f_in: &[f64]) -> (Value, Vec<f64>) {
-        unsafe {
-            assert!(
-                (*self.unwrap_shared()).size == f_in.len() as _,
-                "f_in and f_out must have the same length as this struct"
-            );
-            let mut f_out: Vec<f64> = ::std::iter::repeat(0.).take(f_in.len()).collect();
-            let ret = sys::gsl_dht_apply(
+    pub fn apply(&mut self, f_in: &[f64]) -> Result<Vec<f64>, Error> {
+        let mut f_out = vec![0.; f_in.len()];
+        self.apply_into(f_in, &mut f_out)?;
+        Ok(f_out)
+    }
+
+    /// Same as [`apply`](Self::apply), storing the result in `f_out`.
+    #[doc(alias = "gsl_dht_apply")]
+    pub fn apply_into(&mut self, f_in: &[f64], f_out: &mut [f64]) -> Result<(), Error> {
+        assert!(
+            self.size() == f_in.len() && f_in.len() == f_out.len(),
+            "f_in and f_out must have the same length as this struct"
+        );
+        let ret = unsafe {
+            sys::gsl_dht_apply(
                 self.unwrap_unique(),
                 f_in.as_ptr() as usize as *mut _,
                 f_out.as_mut_ptr(),
-            );
-            (Value::from(ret), f_out)
+            )
+        };
+        result_handler!(ret, (), "gsl_dht_apply")
+    }
+
+    /// Same as [`apply`](Self::apply) with vectors: `f_in` and `f_out` must have the same length
+    /// as the transform. They are copied if their stride isn't 1.
+    #[doc(alias = "gsl_dht_apply")]
+    pub fn apply_vector(&mut self, f_in: &VectorF64, f_out: &mut VectorF64) -> Result<(), Error> {
+        let f_in_copy;
+        let f_in = match f_in.as_slice() {
+            Some(f_in) => f_in,
+            None => {
+                f_in_copy = (0..f_in.len()).map(|i| f_in.get(i)).collect::<Vec<_>>();
+                &f_in_copy
+            }
+        };
+        match f_out.as_slice_mut() {
+            Some(f_out) => self.apply_into(f_in, f_out),
+            None => {
+                let mut out = vec![0.; f_out.len()];
+                self.apply_into(f_in, &mut out)?;
+                for (i, x) in out.into_iter().enumerate() {
+                    f_out.set(i, x);
+                }
+                Ok(())
+            }
         }
     }
 
+    /// Returns the size of the transform.
+    pub fn size(&self) -> usize {
+        unsafe { (*self.unwrap_shared()).size }
+    }
+
     /// This function returns the value of the n-th sample point in the unit interval,
     /// (j_{\nu,n+1}/j_{\nu,M}) X. These are the points where the function f(t) is assumed to be
     /// sampled.
@@ -147,15 +190,18 @@ impl DiscreteHankel {
 #[test]
 fn discrete_hankel() {
     let mut d = DiscreteHankel::new(3).unwrap();
-    assert_eq!(d.init(3., 2.), ::Value::Success);
+    assert_eq!(d.init(3., 2.), Ok(()));
     assert_eq!(
         &format!("{:.4} {:.4}", d.x_sample(1), d.k_sample(1)),
         "1.2033 4.8805"
     );
-    let (res, v) = d.apply(&[100., 2., 3.]);
-    assert_eq!(true, res == ::Value::Success);
+    let v = d.apply(&[100., 2., 3.]).unwrap();
     assert_eq!(
         &format!("{:.4} {:.4} {:.4}", v[0], v[1], v[2]),
         "8.5259 13.9819 11.7320"
     );
+    let f_in = VectorF64::from_slice(&[100., 2., 3.]).unwrap();
+    let mut f_out = VectorF64::new(3).unwrap();
+    d.apply_vector(&f_in, &mut f_out).unwrap();
+    assert_eq!(f_out.as_slice(), Some(&v[..]));
 }
@@ -95,7 +95,7 @@ the generalized Schur form whose diagonal elements give the \alpha and \beta val
 used is the QZ method due to Moler and Stewart (see references).
 !*/
 
-use crate::Value;
+use crate::Error;
 use ffi::FFI;
 use types::{MatrixComplexF64, MatrixF64, VectorComplexF64, VectorF64};
 
@@ -105,6 +105,8 @@ ffi_wrapper!(
     gsl_eigen_symm_free
 );
 
+unsafe impl Send for EigenSymmetricWorkspace {}
+
 impl EigenSymmetricWorkspace {
     /// This function allocates a workspace for computing eigenvalues of n-by-n real symmetric
     /// matrices. The size of the workspace is O(2n).
@@ -124,14 +126,15 @@ impl EigenSymmetricWorkspace {
     /// triangular part is not referenced. The eigenvalues are stored in the vector `eval` and are
     /// unordered.
     #[doc(alias = "gsl_eigen_symm")]
-    pub fn symm(&mut self, A: &mut MatrixF64, eval: &mut VectorF64) -> Value {
-        Value::from(unsafe {
+    pub fn symm(&mut self, A: &mut MatrixF64, eval: &mut VectorF64) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_symm(
                 A.unwrap_unique(),
                 eval.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_symm")
     }
 }
 
@@ -141,6 +144,8 @@ ffi_wrapper!(
     gsl_eigen_symmv_free
 );
 
+unsafe impl Send for EigenSymmetricVWorkspace {}
+
 impl EigenSymmetricVWorkspace {
     /// This function allocates a workspace for computing eigenvalues and eigenvectors of n-by-n
     /// real symmetric matrices. The size of the workspace is O(4n).
@@ -168,15 +173,16 @@ impl EigenSymmetricVWorkspace {
         A: &mut MatrixF64,
         eval: &mut VectorF64,
         evec: &mut MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_symmv(
                 A.unwrap_unique(),
                 eval.unwrap_unique(),
                 evec.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_symmv")
     }
 }
 
@@ -186,6 +192,8 @@ ffi_wrapper!(
     gsl_eigen_herm_free
 );
 
+unsafe impl Send for EigenHermitianWorkspace {}
+
 impl EigenHermitianWorkspace {
     /// This function allocates a workspace for computing eigenvalues of n-by-n complex hermitian
     /// matrices. The size of the workspace is O(3n).
@@ -206,14 +214,15 @@ impl EigenHermitianWorkspace {
     /// part is not referenced. The imaginary parts of the diagonal are assumed to be zero and are
     /// not referenced. The eigenvalues are stored in the vector `eval` and are unordered.
     #[doc(alias = "gsl_eigen_herm")]
-    pub fn herm(&mut self, A: &mut MatrixComplexF64, eval: &mut VectorF64) -> Value {
-        Value::from(unsafe {
+    pub fn herm(&mut self, A: &mut MatrixComplexF64, eval: &mut VectorF64) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_herm(
                 A.unwrap_unique(),
                 eval.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_herm")
     }
 }
 
@@ -223,6 +232,8 @@ ffi_wrapper!(
     gsl_eigen_hermv_free
 );
 
+unsafe impl Send for EigenHermitianVWorkspace {}
+
 impl EigenHermitianVWorkspace {
     /// This function allocates a workspace for computing eigenvalues and eigenvectors of n-by-n
     /// complex hermitian matrices. The size of the workspace is O(5n).
@@ -252,15 +263,16 @@ impl EigenHermitianVWorkspace {
         A: &mut MatrixComplexF64,
         eval: &mut VectorF64,
         evec: &mut MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_hermv(
                 A.unwrap_unique(),
                 eval.unwrap_unique(),
                 evec.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_hermv")
     }
 }
 
@@ -270,6 +282,8 @@ ffi_wrapper!(
     gsl_eigen_nonsymm_free
 );
 
+unsafe impl Send for EigenNonSymmetricWorkspace {}
+
 impl EigenNonSymmetricWorkspace {
     /// This function allocates a workspace for computing eigenvalues of n-by-n complex hermitian
     /// matrices. The size of the workspace is O(3n).
@@ -323,14 +337,15 @@ impl EigenNonSymmetricWorkspace {
     /// returned and the number of converged eigenvalues is stored in w->n_evals. The converged
     /// eigenvalues are stored in the beginning of `eval`.
     #[doc(alias = "gsl_eigen_nonsymm")]
-    pub fn nonsymm(&mut self, A: &mut MatrixF64, eval: &mut VectorComplexF64) -> Value {
-        Value::from(unsafe {
+    pub fn nonsymm(&mut self, A: &mut MatrixF64, eval: &mut VectorComplexF64) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_nonsymm(
                 A.unwrap_unique(),
                 eval.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_nonsymm")
     }
 
     /// This function is identical to gsl_eigen_nonsymm except that it also computes the Schur
@@ -341,15 +356,16 @@ impl EigenNonSymmetricWorkspace {
         A: &mut MatrixF64,
         eval: &mut VectorComplexF64,
         Z: &mut MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_nonsymm_Z(
                 A.unwrap_unique(),
                 eval.unwrap_unique(),
                 Z.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_nonsymm_Z")
     }
 
     pub fn n_evals(&self) -> usize {
@@ -363,6 +379,8 @@ ffi_wrapper!(
     gsl_eigen_nonsymmv_free
 );
 
+unsafe impl Send for EigenNonSymmetricVWorkspace {}
+
 impl EigenNonSymmetricVWorkspace {
     /// This function allocates a workspace for computing eigenvalues and eigenvectors of n-by-n
     /// real nonsymmetric matrices. The size of the workspace is O(5n).
@@ -399,15 +417,16 @@ impl EigenNonSymmetricVWorkspace {
         A: &mut MatrixF64,
         eval: &mut VectorComplexF64,
         evec: &mut MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_nonsymmv(
                 A.unwrap_unique(),
                 eval.unwrap_unique(),
                 evec.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_nonsymmv")
     }
 
     /// This function is identical to gsl_eigen_nonsymmv except that it also saves the Schur vectors
@@ -419,8 +438,8 @@ impl EigenNonSymmetricVWorkspace {
         eval: &mut VectorComplexF64,
         evec: &mut MatrixComplexF64,
         Z: &mut MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_nonsymmv_Z(
                 A.unwrap_unique(),
                 eval.unwrap_unique(),
@@ -428,7 +447,8 @@ impl EigenNonSymmetricVWorkspace {
                 Z.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_nonsymmv_Z")
     }
 }
 
@@ -438,6 +458,8 @@ ffi_wrapper!(
     gsl_eigen_gensymm_free
 );
 
+unsafe impl Send for EigenGenSymmWorkspace {}
+
 impl EigenGenSymmWorkspace {
     /// This function allocates a workspace for computing eigenvalues of n-by-n real generalized
     /// symmetric-definite eigensystems. The size of the workspace is O(2n).
@@ -456,15 +478,21 @@ impl EigenGenSymmWorkspace {
     /// pair (A, B), and stores them in `eval`, using the method outlined above. On output, `B`
     /// contains its Cholesky decomposition and `A` is destroyed.
     #[doc(alias = "gsl_eigen_gensymm")]
-    pub fn gensymm(&mut self, mut A: MatrixF64, B: &mut MatrixF64, eval: &mut VectorF64) -> Value {
-        Value::from(unsafe {
+    pub fn gensymm(
+        &mut self,
+        mut A: MatrixF64,
+        B: &mut MatrixF64,
+        eval: &mut VectorF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_gensymm(
                 A.unwrap_unique(),
                 B.unwrap_unique(),
                 eval.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_gensymm")
     }
 }
 
@@ -474,6 +502,8 @@ ffi_wrapper!(
     gsl_eigen_gensymmv_free
 );
 
+unsafe impl Send for EigenGenSymmVWorkspace {}
+
 impl EigenGenSymmVWorkspace {
     /// This function allocates a workspace for computing eigenvalues and eigenvectors of n-by-n
     /// real generalized symmetric-definite eigensystems. The size of the workspace is O(4n).
@@ -499,8 +529,8 @@ impl EigenGenSymmVWorkspace {
         B: &mut MatrixF64,
         eval: &mut VectorF64,
         evec: &mut MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_gensymmv(
                 A.unwrap_unique(),
                 B.unwrap_unique(),
@@ -508,7 +538,8 @@ impl EigenGenSymmVWorkspace {
                 evec.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_gensymmv")
     }
 }
 
@@ -518,6 +549,8 @@ ffi_wrapper!(
     gsl_eigen_genherm_free
 );
 
+unsafe impl Send for EigenGenHermWorkspace {}
+
 impl EigenGenHermWorkspace {
     /// This function allocates a workspace for computing eigenvalues of n-by-n complex generalized
     /// hermitian-definite eigensystems. The size of the workspace is O(3n).
@@ -541,15 +574,16 @@ impl EigenGenHermWorkspace {
         mut A: MatrixComplexF64,
         B: &mut MatrixComplexF64,
         eval: &mut VectorF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_genherm(
                 A.unwrap_unique(),
                 B.unwrap_unique(),
                 eval.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_genherm")
     }
 }
 
@@ -559,6 +593,8 @@ ffi_wrapper!(
     gsl_eigen_genhermv_free
 );
 
+unsafe impl Send for EigenGenHermVWorkspace {}
+
 impl EigenGenHermVWorkspace {
     /// This function allocates a workspace for computing eigenvalues of n-by-n complex generalized
     /// hermitian-definite eigensystems. The size of the workspace is O(3n).
@@ -583,8 +619,8 @@ impl EigenGenHermVWorkspace {
         B: &mut MatrixComplexF64,
         eval: &mut VectorF64,
         evec: &mut MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_genhermv(
                 A.unwrap_unique(),
                 B.unwrap_unique(),
@@ -592,7 +628,8 @@ impl EigenGenHermVWorkspace {
                 evec.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_genhermv")
     }
 }
 
@@ -602,6 +639,8 @@ ffi_wrapper!(
     gsl_eigen_gen_free
 );
 
+unsafe impl Send for EigenGenWorkspace {}
+
 impl EigenGenWorkspace {
     /// This function allocates a workspace for computing eigenvalues of n-by-n real generalized
     /// nonsymmetric eigensystems. The size of the workspace is O(n).
@@ -653,8 +692,8 @@ impl EigenGenWorkspace {
         B: &mut MatrixF64,
         alpha: &mut VectorComplexF64,
         beta: &mut VectorF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_gen(
                 A.unwrap_unique(),
                 B.unwrap_unique(),
@@ -662,7 +701,8 @@ impl EigenGenWorkspace {
                 beta.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_gen")
     }
 
     /// This function is identical to gsl_eigen_gen except that it also computes the left and right
@@ -676,8 +716,8 @@ impl EigenGenWorkspace {
         beta: &mut VectorF64,
         Q: &mut MatrixF64,
         Z: &mut MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_gen_QZ(
                 A.unwrap_unique(),
                 B.unwrap_unique(),
@@ -687,7 +727,8 @@ impl EigenGenWorkspace {
                 Z.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_gen_QZ")
     }
 }
 
@@ -697,6 +738,8 @@ ffi_wrapper!(
     gsl_eigen_genv_free
 );
 
+unsafe impl Send for EigenGenVWorkspace {}
+
 impl EigenGenVWorkspace {
     /// This function allocates a workspace for computing eigenvalues of n-by-n real generalized
     /// nonsymmetric eigensystems. The size of the workspace is O(n).
@@ -727,8 +770,8 @@ impl EigenGenVWorkspace {
         alpha: &mut VectorComplexF64,
         beta: &mut VectorF64,
         evec: &mut MatrixComplexF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_genv(
                 A.unwrap_unique(),
                 B.unwrap_unique(),
@@ -737,7 +780,8 @@ impl EigenGenVWorkspace {
                 evec.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_genv")
     }
 
     /// This function is identical to gsl_eigen_genv except that it also computes the left and right
@@ -752,8 +796,8 @@ impl EigenGenVWorkspace {
         evec: &mut MatrixComplexF64,
         Q: &mut MatrixF64,
         Z: &mut MatrixF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_eigen_genv_QZ(
                 A.unwrap_unique(),
                 B.unwrap_unique(),
@@ -764,7 +808,8 @@ impl EigenGenVWorkspace {
                 Z.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_eigen_genv_QZ")
     }
 }
 
@@ -782,7 +827,7 @@ fn eigen_symmetric_workspace() {
     m.set(1, 0, data[2]);
     m.set(1, 1, data[3]);
     let mut v = VectorF64::new(2).unwrap();
-    e.symm(&mut m, &mut v);
+    e.symm(&mut m, &mut v).unwrap();
     assert_eq!(&format!("{:.4} {:.4}", v.get(0), v.get(1)), "4.3820 6.6180");
 }
 
@@ -828,7 +873,7 @@ fn eigen_symmetric_vworkspace() {
     m.set(1, 1, data[3]);
     let mut m2 = MatrixF64::new(2, 2).unwrap();
     let mut v = VectorF64::new(2).unwrap();
-    e.symmv(&mut m, &mut v, &mut m2);
+    e.symmv(&mut m, &mut v, &mut m2).unwrap();
     assert_eq!(&format!("{:.4} {:.4}", v.get(0), v.get(1)), "4.3820 6.6180");
     assert_eq!(
         &format!("{:.4} {:.4}", m2.get(0, 0), m2.get(0, 1)),
@@ -882,7 +927,7 @@ fn eigen_hermitian_workspace() {
     m.set(1, 1, &ComplexF64::rect(5., 7.));
 
     let mut v = VectorF64::new(2).unwrap();
-    e.herm(&mut m, &mut v);
+    e.herm(&mut m, &mut v).unwrap();
     assert_eq!(&format!("{:.4} {:.4}", v.get(0), v.get(1)), "8.6056 1.3944");
 }
 
@@ -935,7 +980,7 @@ fn eigen_hermitian_vworkspace() {
 
     let mut v = VectorF64::new(2).unwrap();
     let mut m2 = MatrixComplexF64::new(2, 2).unwrap();
-    e.hermv(&mut m, &mut v, &mut m2);
+    e.hermv(&mut m, &mut v, &mut m2).unwrap();
     assert_eq!(&format!("{:.4} {:.4}", v.get(0), v.get(1)), "8.6056 1.3944");
     assert_eq!(
         &format!(
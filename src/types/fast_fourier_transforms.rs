@@ -3,7 +3,7 @@
 //
 
 use crate::paste::paste;
-use crate::Value;
+use crate::Error;
 use ffi::FFI;
 
 macro_rules! gsl_fft_wavetable {
@@ -16,6 +16,8 @@ ffi_wrapper!(
     [<$name _wavetable $($extra)? _free>]
 );
 
+unsafe impl Send for $rust_name {}
+
 impl $rust_name {
     /// This function prepares a trigonometric lookup table for a complex FFT of length n. The
     /// function returns a pointer to the newly allocated gsl_fft_complex_wavetable if no errors
@@ -60,6 +62,8 @@ ffi_wrapper!(
     [<$complex_name _free>]
 );
 
+unsafe impl Send for $complex_rust_name {}
+
 impl $complex_rust_name {
     /// This function allocates a workspace for a complex transform of length n.
     #[doc(alias = $complex_name _alloc)]
@@ -80,75 +84,238 @@ impl $complex_rust_name {
         stride: usize,
         n: usize,
         wavetable: &$rust_name,
-    ) -> Value {
-        Value::from(unsafe {
-            sys::[<$name $($extra)? _forward>](
+    ) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name $($extra)? _forward>](data.as_mut_ptr(), stride, n, wavetable.unwrap_shared(), self.unwrap_unique()) };
+        result_handler!(ret, (), stringify!([<$name $($extra)? _forward>]))
+    }
+
+    #[doc(alias = $name $($extra)? _transform)]
+    pub fn transform(
+        &mut self,
+        data: &mut [$ty],
+        stride: usize,
+        n: usize,
+        wavetable: &$rust_name,
+        sign: ::FftDirection,
+    ) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name $($extra)? _transform>](data.as_mut_ptr(), stride, n, wavetable.unwrap_shared(), self.unwrap_unique(), sign.into()) };
+        result_handler!(ret, (), stringify!([<$name $($extra)? _transform>]))
+    }
+
+    #[doc(alias = $name $($extra)? _backward)]
+    pub fn backward(
+        &mut self,
+        data: &mut [$ty],
+        stride: usize,
+        n: usize,
+        wavetable: &$rust_name,
+    ) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name $($extra)? _backward>](data.as_mut_ptr(), stride, n, wavetable.unwrap_shared(), self.unwrap_unique()) };
+        result_handler!(ret, (), stringify!([<$name $($extra)? _backward>]))
+    }
+
+    #[doc(alias = $name $($extra)? _inverse)]
+    pub fn inverse(
+        &mut self,
+        data: &mut [$ty],
+        stride: usize,
+        n: usize,
+        wavetable: &$rust_name,
+    ) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name $($extra)? _inverse>](data.as_mut_ptr(), stride, n, wavetable.unwrap_shared(), self.unwrap_unique()) };
+        result_handler!(ret, (), stringify!([<$name $($extra)? _inverse>]))
+    }
+}
+
+} // end of paste! block
+); // end of macro block
+}
+
+gsl_fft_wavetable!(
+    FftComplexF64WaveTable,
+    gsl_fft_complex,
+    FftComplexF64Workspace,
+    gsl_fft_complex_workspace,
+    f64
+);
+gsl_fft_wavetable!(
+    FftComplexF32WaveTable,
+    gsl_fft_complex,
+    FftComplexF32Workspace,
+    gsl_fft_complex_workspace_float,
+    f32,
+    _float
+);
+
+macro_rules! gsl_fft_real_wavetable {
+    (
+        $real_rust_name:ident,
+        $halfcomplex_rust_name:ident,
+        $workspace_rust_name:ident,
+        $ty:ident
+        $(, $extra:ident)?
+    ) => (
+paste! {
+
+ffi_wrapper!(
+    $real_rust_name,
+    *mut sys::[<gsl_fft_real_wavetable $($extra)?>],
+    [<gsl_fft_real_wavetable $($extra)? _free>]
+);
+
+unsafe impl Send for $real_rust_name {}
+
+impl $real_rust_name {
+    /// This function prepares a trigonometric lookup table for a real FFT of length n. The length
+    /// n is factorized into a product of subtransforms, and the factors and their trigonometric
+    /// coefficients are stored in the wavetable.
+    ///
+    /// The wavetable structure can be used repeatedly for any transform of the same length.
+    #[doc(alias = gsl_fft_real_wavetable $($extra)? _alloc)]
+    pub fn new(n: usize) -> Option<Self> {
+        let tmp = unsafe { sys::[<gsl_fft_real_wavetable $($extra)? _alloc>](n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+}
+
+ffi_wrapper!(
+    $halfcomplex_rust_name,
+    *mut sys::[<gsl_fft_halfcomplex_wavetable $($extra)?>],
+    [<gsl_fft_halfcomplex_wavetable $($extra)? _free>]
+);
+
+unsafe impl Send for $halfcomplex_rust_name {}
+
+impl $halfcomplex_rust_name {
+    /// This function prepares a trigonometric lookup table for the inverse FFT of a half-complex
+    /// sequence of length n. The real and half-complex transforms use different wavetables.
+    #[doc(alias = gsl_fft_halfcomplex_wavetable $($extra)? _alloc)]
+    pub fn new(n: usize) -> Option<Self> {
+        let tmp = unsafe { sys::[<gsl_fft_halfcomplex_wavetable $($extra)? _alloc>](n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+}
+
+ffi_wrapper!(
+    $workspace_rust_name,
+    *mut sys::[<gsl_fft_real_workspace $($extra)?>],
+    [<gsl_fft_real_workspace $($extra)? _free>]
+);
+
+unsafe impl Send for $workspace_rust_name {}
+
+impl $workspace_rust_name {
+    /// This function allocates a workspace for a real transform of length n. The same workspace
+    /// can be used for both forward real and inverse halfcomplex transforms.
+    #[doc(alias = gsl_fft_real_workspace $($extra)? _alloc)]
+    pub fn new(n: usize) -> Option<Self> {
+        let tmp = unsafe { sys::[<gsl_fft_real_workspace $($extra)? _alloc>](n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function computes the FFT of data, a real array of length n and stride stride, using
+    /// a mixed radix decimation-in-frequency algorithm. The output is a half-complex sequence
+    /// stored in-place: the real and imaginary parts of each term are stored in neighboring
+    /// locations.
+    #[doc(alias = gsl_fft_real $($extra)? _transform)]
+    pub fn real_transform(
+        &mut self,
+        data: &mut [$ty],
+        stride: usize,
+        n: usize,
+        wavetable: &$real_rust_name,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::[<gsl_fft_real $($extra)? _transform>](
                 data.as_mut_ptr(),
                 stride,
                 n,
                 wavetable.unwrap_shared(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), stringify!([<gsl_fft_real $($extra)? _transform>]))
     }
 
-    #[doc(alias = $name $($extra)? _transform)]
-    pub fn transform(
+    /// This function computes the FFT of data, a half-complex array of length n and stride stride,
+    /// using a mixed radix decimation-in-frequency algorithm.
+    #[doc(alias = gsl_fft_halfcomplex $($extra)? _transform)]
+    pub fn halfcomplex_transform(
         &mut self,
         data: &mut [$ty],
         stride: usize,
         n: usize,
-        wavetable: &$rust_name,
-        sign: ::FftDirection,
-    ) -> Value {
-        Value::from(unsafe {
-            sys::[<$name $($extra)? _transform>](
+        wavetable: &$halfcomplex_rust_name,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::[<gsl_fft_halfcomplex $($extra)? _transform>](
                 data.as_mut_ptr(),
                 stride,
                 n,
                 wavetable.unwrap_shared(),
                 self.unwrap_unique(),
-                sign.into(),
             )
-        })
+        };
+        result_handler!(ret, (), stringify!([<gsl_fft_halfcomplex $($extra)? _transform>]))
     }
 
-    #[doc(alias = $name $($extra)? _backward)]
-    pub fn backward(
+    /// This function computes the backward FFT of the half-complex array data of length n and
+    /// stride stride. The result is a real array, which isn't scaled by 1/n.
+    #[doc(alias = gsl_fft_halfcomplex $($extra)? _backward)]
+    pub fn halfcomplex_backward(
         &mut self,
         data: &mut [$ty],
         stride: usize,
         n: usize,
-        wavetable: &$rust_name,
-    ) -> Value {
-        Value::from(unsafe {
-            sys::[<$name $($extra)? _backward>](
+        wavetable: &$halfcomplex_rust_name,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::[<gsl_fft_halfcomplex $($extra)? _backward>](
                 data.as_mut_ptr(),
                 stride,
                 n,
                 wavetable.unwrap_shared(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), stringify!([<gsl_fft_halfcomplex $($extra)? _backward>]))
     }
 
-    #[doc(alias = $name $($extra)? _inverse)]
-    pub fn inverse(
+    /// This function computes the inverse FFT of the half-complex array data of length n and
+    /// stride stride. The result is a real array, scaled by 1/n.
+    #[doc(alias = gsl_fft_halfcomplex $($extra)? _inverse)]
+    pub fn halfcomplex_inverse(
         &mut self,
         data: &mut [$ty],
         stride: usize,
         n: usize,
-        wavetable: &$rust_name,
-    ) -> Value {
-        Value::from(unsafe {
-            sys::[<$name $($extra)? _inverse>](
+        wavetable: &$halfcomplex_rust_name,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::[<gsl_fft_halfcomplex $($extra)? _inverse>](
                 data.as_mut_ptr(),
                 stride,
                 n,
                 wavetable.unwrap_shared(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), stringify!([<gsl_fft_halfcomplex $($extra)? _inverse>]))
     }
 }
 
@@ -156,18 +323,16 @@ impl $complex_rust_name {
 ); // end of macro block
 }
 
-gsl_fft_wavetable!(
-    FftComplexF64WaveTable,
-    gsl_fft_complex,
-    FftComplexF64Workspace,
-    gsl_fft_complex_workspace,
+gsl_fft_real_wavetable!(
+    FftRealF64WaveTable,
+    FftHalfComplexF64WaveTable,
+    FftRealF64Workspace,
     f64
 );
-gsl_fft_wavetable!(
-    FftComplexF32WaveTable,
-    gsl_fft_complex,
-    FftComplexF32Workspace,
-    gsl_fft_complex_workspace_float,
+gsl_fft_real_wavetable!(
+    FftRealF32WaveTable,
+    FftHalfComplexF32WaveTable,
+    FftRealF32Workspace,
     f32,
     _float
 );
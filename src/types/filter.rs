@@ -2,7 +2,26 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::{FilterEnd, FilterScale, Value, VectorF64, VectorI32};
+/*!
+# Digital Filtering
+
+This chapter describes functions for applying digital filters to data sets, both linear (the
+Gaussian filter) and nonlinear (the median, recursive median and impulse detection filters).
+They all work on a symmetric window of `K` samples around each sample of the input, the edges
+being handled according to a [`FilterEnd`].
+
+```
+use rgsl::{FilterEnd, FilterMedianWorkspace, VectorF64};
+
+let x = VectorF64::from_slice(&[1., 1., 9., 1., 1.]).unwrap();
+let mut y = VectorF64::new(5).unwrap();
+let mut w = FilterMedianWorkspace::new(3).unwrap();
+w.median(FilterEnd::PadValue, &x, &mut y);
+assert_eq!(y.as_slice().unwrap(), &[1.; 5]);
+```
+!*/
+
+use crate::{Error, FilterEnd, FilterScale, VectorF64, VectorI32};
 use ffi::FFI;
 
 ffi_wrapper!(
@@ -11,7 +30,11 @@ ffi_wrapper!(
     gsl_filter_gaussian_free
 );
 
+unsafe impl Send for FilterGaussianWorkspace {}
+
 impl FilterGaussianWorkspace {
+    /// This function allocates a workspace for Gaussian filtering using a symmetric moving
+    /// window of size `K`. If `K` is even, it is rounded up to the next odd integer.
     #[doc(alias = "gsl_filter_gaussian_alloc")]
     pub fn new(K: usize) -> Option<Self> {
         let s = unsafe { sys::gsl_filter_gaussian_alloc(K) };
@@ -35,8 +58,8 @@ impl FilterGaussianWorkspace {
         order: usize,
         x: &VectorF64,
         y: &mut VectorF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_filter_gaussian(
                 endtype.into(),
                 alpha,
@@ -45,7 +68,8 @@ impl FilterGaussianWorkspace {
                 y.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_filter_gaussian")
     }
 }
 
@@ -55,7 +79,11 @@ ffi_wrapper!(
     gsl_filter_median_free
 );
 
+unsafe impl Send for FilterMedianWorkspace {}
+
 impl FilterMedianWorkspace {
+    /// This function allocates a workspace for standard median filtering using a symmetric
+    /// moving window of size `K`. If `K` is even, it is rounded up to the next odd integer.
     #[doc(alias = "gsl_filter_median_alloc")]
     pub fn new(K: usize) -> Option<Self> {
         let s = unsafe { sys::gsl_filter_median_alloc(K) };
@@ -66,16 +94,25 @@ impl FilterMedianWorkspace {
         }
     }
 
+    /// This function applies a standard median filter to the input `x`, storing the output in
+    /// `y`. The parameter `endtype` specifies how the signal end points are handled. It is
+    /// allowed to have `x` = `y` for an in-place filter.
     #[doc(alias = "gsl_filter_median")]
-    pub fn median(&mut self, endtype: FilterEnd, x: &VectorF64, y: &mut VectorF64) -> Value {
-        Value::from(unsafe {
+    pub fn median(
+        &mut self,
+        endtype: FilterEnd,
+        x: &VectorF64,
+        y: &mut VectorF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_filter_median(
                 endtype.into(),
                 x.unwrap_shared(),
                 y.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_filter_median")
     }
 }
 
@@ -85,7 +122,11 @@ ffi_wrapper!(
     gsl_filter_rmedian_free
 );
 
+unsafe impl Send for FilterRMedianWorkspace {}
+
 impl FilterRMedianWorkspace {
+    /// This function allocates a workspace for recursive median filtering using a symmetric
+    /// moving window of size `K`. If `K` is even, it is rounded up to the next odd integer.
     #[doc(alias = "gsl_filter_rmedian_alloc")]
     pub fn new(K: usize) -> Option<Self> {
         let s = unsafe { sys::gsl_filter_rmedian_alloc(K) };
@@ -96,16 +137,26 @@ impl FilterRMedianWorkspace {
         }
     }
 
+    /// This function applies a recursive median filter to the input `x`, storing the output in
+    /// `y`: the median of each window is computed from the already filtered samples before the
+    /// current one. The parameter `endtype` specifies how the signal end points are handled. It
+    /// is allowed to have `x` = `y` for an in-place filter.
     #[doc(alias = "gsl_filter_rmedian")]
-    pub fn rmedian(&mut self, endtype: FilterEnd, x: &VectorF64, y: &mut VectorF64) -> Value {
-        Value::from(unsafe {
+    pub fn rmedian(
+        &mut self,
+        endtype: FilterEnd,
+        x: &VectorF64,
+        y: &mut VectorF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_filter_rmedian(
                 endtype.into(),
                 x.unwrap_shared(),
                 y.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_filter_rmedian")
     }
 }
 
@@ -115,7 +166,11 @@ ffi_wrapper!(
     gsl_filter_impulse_free
 );
 
+unsafe impl Send for FilterImpulseWorkspace {}
+
 impl FilterImpulseWorkspace {
+    /// This function allocates a workspace for impulse detection filtering using a symmetric
+    /// moving window of size `K`. If `K` is even, it is rounded up to the next odd integer.
     #[doc(alias = "gsl_filter_impulse_alloc")]
     pub fn new(K: usize) -> Option<Self> {
         let s = unsafe { sys::gsl_filter_impulse_alloc(K) };
@@ -126,7 +181,13 @@ impl FilterImpulseWorkspace {
         }
     }
 
-    /// Returns `(Value, noutlier)`.
+    /// This function applies an impulse detection filter to the input vector `x`, storing the
+    /// filtered output in `y`. The tuning parameter `t` is provided in `t`. The window medians
+    /// are stored in `xmedian` and the scale estimates S_n (chosen by `scale_type`) in
+    /// `xsigma` on output. `ioutlier` is set to 1 for each sample detected as an outlier, and
+    /// to 0 otherwise. It is allowed to have `x` = `y` for an in-place filter.
+    ///
+    /// Returns `noutlier`, the number of outliers detected.
     #[doc(alias = "gsl_filter_impulse")]
     pub fn impulse(
         &mut self,
@@ -138,7 +199,7 @@ impl FilterImpulseWorkspace {
         xmedian: &mut VectorF64,
         xsigma: &mut VectorF64,
         ioutlier: &mut VectorI32,
-    ) -> (Value, usize) {
+    ) -> Result<usize, Error> {
         let mut noutlier = 0;
         let ret = unsafe {
             sys::gsl_filter_impulse(
@@ -154,6 +215,6 @@ impl FilterImpulseWorkspace {
                 self.unwrap_unique(),
             )
         };
-        (Value::from(ret), noutlier)
+        result_handler!(ret, noutlier, "gsl_filter_impulse")
     }
 }
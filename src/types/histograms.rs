@@ -24,12 +24,81 @@ In this equation n_i is the number of events in the bin which contains x, w_i is
 The distribution of events within each bin is assumed to be uniform.
 !*/
 
-use crate::Value;
+use crate::{Error, Value};
 use ffi::FFI;
+use std::fmt;
 use std::io::{self, Write};
+use std::slice;
+use utilities::Preview;
+
+enum BinRanges<'a> {
+    Uniform { n: usize, xmin: f64, xmax: f64 },
+    Edges(&'a [f64]),
+}
+
+/// Builds a [`Histogram`] with uniform or custom bins, optionally filled with some data.
+///
+/// ```
+/// use rgsl::HistogramBuilder;
+///
+/// let h = HistogramBuilder::edges(&[1., 10., 100., 1000.])
+///     .data(&[2., 20., 30., 5000.])
+///     .build()
+///     .unwrap();
+/// assert_eq!(h.iter().map(|(_, _, n)| n).collect::<Vec<_>>(), [1., 2., 0.]);
+/// ```
+pub struct HistogramBuilder<'a> {
+    ranges: BinRanges<'a>,
+    data: &'a [f64],
+}
+
+impl<'a> HistogramBuilder<'a> {
+    /// `n` bins of equal width covering the range [xmin, xmax).
+    pub fn uniform(n: usize, xmin: f64, xmax: f64) -> HistogramBuilder<'a> {
+        HistogramBuilder {
+            ranges: BinRanges::Uniform { n, xmin, xmax },
+            data: &[],
+        }
+    }
+
+    /// `edges.len() - 1` bins, the i-th one covering the range `[edges[i], edges[i + 1])`. The
+    /// edges must be increasing.
+    pub fn edges(edges: &'a [f64]) -> HistogramBuilder<'a> {
+        HistogramBuilder {
+            ranges: BinRanges::Edges(edges),
+            data: &[],
+        }
+    }
+
+    /// Fills the histogram with `data` (see [`Histogram::fill`]).
+    pub fn data(mut self, data: &'a [f64]) -> HistogramBuilder<'a> {
+        self.data = data;
+        self
+    }
+
+    pub fn build(self) -> Result<Histogram, Error> {
+        let n = match self.ranges {
+            BinRanges::Uniform { n, .. } => n,
+            BinRanges::Edges(edges) => edges.len().saturating_sub(1),
+        };
+        if n == 0 {
+            return Err(Error::new(Value::Invalid, "gsl_histogram_alloc"));
+        }
+        let mut h =
+            Histogram::new(n).ok_or_else(|| Error::new(Value::NoMemory, "gsl_histogram_alloc"))?;
+        match self.ranges {
+            BinRanges::Uniform { xmin, xmax, .. } => h.set_ranges_uniform(xmin, xmax)?,
+            BinRanges::Edges(edges) => h.set_ranges(edges)?,
+        }
+        h.fill(self.data);
+        Ok(h)
+    }
+}
 
 ffi_wrapper!(Histogram, *mut sys::gsl_histogram, gsl_histogram_free);
 
+unsafe impl Send for Histogram {}
+
 impl Histogram {
     /// This function allocates memory for a histogram with n bins, and returns a pointer to a newly created gsl_histogram struct. If insufficient
     /// memory is available a null pointer is returned and the error handler is invoked with an error code of Value::NoMem. The bins and ranges are
@@ -66,10 +135,18 @@ impl Histogram {
     /// Note that the size of the range array should be defined to be one element bigger than the number of bins. The additional element is
     /// required for the upper value of the final bin.
     #[doc(alias = "gsl_histogram_set_ranges")]
-    pub fn set_ranges(&mut self, range: &[f64]) -> Value {
-        Value::from(unsafe {
-            sys::gsl_histogram_set_ranges(self.unwrap_unique(), range.as_ptr(), range.len() as _)
-        })
+    pub fn set_ranges(&mut self, range: &[f64]) -> Result<(), Error> {
+        result_handler!(
+            unsafe {
+                sys::gsl_histogram_set_ranges(
+                    self.unwrap_unique(),
+                    range.as_ptr(),
+                    range.len() as _,
+                )
+            },
+            (),
+            "gsl_histogram_set_ranges"
+        )
     }
 
     /// This function sets the ranges of the existing histogram h to cover the range xmin to xmax uniformly. The values of the histogram bins
@@ -81,24 +158,26 @@ impl Histogram {
     /// bin[n-1] corresponds to xmin + (n-1)d <= x < xmax
     /// where d is the bin spacing, d = (xmax-xmin)/n.
     #[doc(alias = "gsl_histogram_set_ranges_uniform")]
-    pub fn set_ranges_uniform(&mut self, xmin: f64, xmax: f64) -> Value {
-        Value::from(unsafe {
-            sys::gsl_histogram_set_ranges_uniform(self.unwrap_unique(), xmin, xmax)
-        })
+    pub fn set_ranges_uniform(&mut self, xmin: f64, xmax: f64) -> Result<(), Error> {
+        result_handler!(
+            unsafe { sys::gsl_histogram_set_ranges_uniform(self.unwrap_unique(), xmin, xmax) },
+            (),
+            "gsl_histogram_set_ranges_uniform"
+        )
     }
 
     /// This function copies the self histogram into the pre-existing histogram dest, making dest into an exact copy of self. The two histograms
     /// must be of the same size.
     #[doc(alias = "gsl_histogram_memcpy")]
-    pub fn copy(&self, dest: &mut Histogram) -> Value {
-        Value::from(unsafe {
-            sys::gsl_histogram_memcpy(dest.unwrap_unique(), self.unwrap_shared())
-        })
+    pub fn copy(&self, dest: &mut Histogram) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram_memcpy(dest.unwrap_unique(), self.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_histogram_memcpy")
     }
 
-    /// This function returns a pointer to a newly created histogram which is an exact copy of the self histogram.
+    /// This function returns a pointer to a newly created histogram which is an exact copy of the self histogram,
+    /// or `None` if the allocation failed.
     #[doc(alias = "gsl_histogram_clone")]
-    pub fn clone(&self) -> Option<Histogram> {
+    pub fn try_clone(&self) -> Option<Histogram> {
         let tmp = unsafe { sys::gsl_histogram_clone(self.unwrap_shared()) };
 
         if tmp.is_null() {
@@ -116,15 +195,17 @@ impl Histogram {
     /// called, however, since it is often necessary to compute histograms for a small range of a larger dataset, ignoring the values outside
     /// the range of interest.
     #[doc(alias = "gsl_histogram_increment")]
-    pub fn increment(&mut self, x: f64) -> Value {
-        Value::from(unsafe { sys::gsl_histogram_increment(self.unwrap_unique(), x) })
+    pub fn increment(&mut self, x: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram_increment(self.unwrap_unique(), x) };
+        result_handler!(ret, (), "gsl_histogram_increment")
     }
 
     /// This function is similar to gsl_histogram_increment but increases the value of the appropriate bin in the histogram h by the floating-point
     /// number weight.
     #[doc(alias = "gsl_histogram_accumulate")]
-    pub fn accumulate(&mut self, x: f64, weight: f64) -> Value {
-        Value::from(unsafe { sys::gsl_histogram_accumulate(self.unwrap_unique(), x, weight) })
+    pub fn accumulate(&mut self, x: f64, weight: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram_accumulate(self.unwrap_unique(), x, weight) };
+        result_handler!(ret, (), "gsl_histogram_accumulate")
     }
 
     /// This function returns the contents of the i-th bin of the histogram h. If i lies outside the valid range of indices for the histogram then
@@ -142,15 +223,15 @@ impl Histogram {
     /// indicate success. If i lies outside the valid range of indices for the histogram then
     /// the error handler is called and the function returns an error code of Value::Dom.
     ///
-    /// Returns `(Value, lower, upper)`.
+    /// Returns `(lower, upper)`.
     #[doc(alias = "gsl_histogram_get_range")]
-    pub fn get_range(&self, i: usize) -> (Value, f64, f64) {
+    pub fn get_range(&self, i: usize) -> Result<(f64, f64), Error> {
         let mut lower = 0.;
         let mut upper = 0.;
         let ret = unsafe {
             sys::gsl_histogram_get_range(self.unwrap_shared(), i, &mut lower, &mut upper)
         };
-        (Value::from(ret), lower, upper)
+        result_handler!(ret, (lower, upper), "gsl_histogram_get_range")
     }
 
     /// This function returns the maximum upper and minimum lower range limits and the number of bins of the self histogram. They provide a way
@@ -187,12 +268,12 @@ impl Histogram {
     /// and returns ::Value::Success. If x lies outside the valid range of the histogram then the
     /// function returns Value::Dom and the error handler is invoked.
     ///
-    /// Returns `(Value, i)`.
+    /// Returns `i`.
     #[doc(alias = "gsl_histogram_find")]
-    pub fn find(&self, x: f64) -> (Value, usize) {
+    pub fn find(&self, x: f64) -> Result<usize, Error> {
         let mut i = 0;
         let ret = unsafe { sys::gsl_histogram_find(self.unwrap_shared(), x, &mut i) };
-        (Value::from(ret), i)
+        result_handler!(ret, i, "gsl_histogram_find")
     }
 
     /// This function returns the maximum value contained in the histogram bins.
@@ -250,41 +331,138 @@ impl Histogram {
     /// This function adds the contents of the bins in histogram other to the corresponding bins of self histogram, i.e. h'_1(i) = h_1(i) + h_2(i).
     /// The two histograms must have identical bin ranges.
     #[doc(alias = "gsl_histogram_add")]
-    pub fn add(&mut self, other: &Histogram) -> Value {
-        Value::from(unsafe { sys::gsl_histogram_add(self.unwrap_unique(), other.unwrap_shared()) })
+    pub fn add(&mut self, other: &Histogram) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram_add(self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_histogram_add")
     }
 
     /// This function subtracts the contents of the bins in histogram other from the corresponding bins of self histogram, i.e. h'_1(i) = h_1(i) - h_2(i).
     /// The two histograms must have identical bin ranges.
     #[doc(alias = "gsl_histogram_sub")]
-    pub fn sub(&mut self, other: &Histogram) -> Value {
-        Value::from(unsafe { sys::gsl_histogram_sub(self.unwrap_unique(), other.unwrap_shared()) })
+    pub fn sub(&mut self, other: &Histogram) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram_sub(self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_histogram_sub")
     }
 
     /// This function multiplies the contents of the bins of self histogram by the contents of the corresponding bins in other histogram, i.e. h'_1(i) =
     /// h_1(i) * h_2(i). The two histograms must have identical bin ranges.
     #[doc(alias = "gsl_histogram_mul")]
-    pub fn mul(&mut self, other: &Histogram) -> Value {
-        Value::from(unsafe { sys::gsl_histogram_mul(self.unwrap_unique(), other.unwrap_shared()) })
+    pub fn mul(&mut self, other: &Histogram) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram_mul(self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_histogram_mul")
     }
 
     /// This function divides the contents of the bins of self histogram by the contents of the corresponding bins in other histogram, i.e. h'_1(i) = h_1(i)
     /// / h_2(i). The two histograms must have identical bin ranges.
     #[doc(alias = "gsl_histogram_div")]
-    pub fn div(&mut self, other: &Histogram) -> Value {
-        Value::from(unsafe { sys::gsl_histogram_div(self.unwrap_unique(), other.unwrap_shared()) })
+    pub fn div(&mut self, other: &Histogram) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram_div(self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_histogram_div")
     }
 
     /// This function multiplies the contents of the bins of self histogram by the constant scale, i.e. h'_1(i) = h_1(i) * scale.
     #[doc(alias = "gsl_histogram_scale")]
-    pub fn scale(&mut self, scale: f64) -> Value {
-        Value::from(unsafe { sys::gsl_histogram_scale(self.unwrap_unique(), scale) })
+    pub fn scale(&mut self, scale: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram_scale(self.unwrap_unique(), scale) };
+        result_handler!(ret, (), "gsl_histogram_scale")
     }
 
     /// This function shifts the contents of the bins of self histogram by the constant offset, i.e. h'_1(i) = h_1(i) + offset.
     #[doc(alias = "gsl_histogram_shift")]
-    pub fn shift(&mut self, offset: f64) -> Value {
-        Value::from(unsafe { sys::gsl_histogram_shift(self.unwrap_unique(), offset) })
+    pub fn shift(&mut self, offset: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram_shift(self.unwrap_unique(), offset) };
+        result_handler!(ret, (), "gsl_histogram_shift")
+    }
+
+    fn ranges(&self) -> &[f64] {
+        unsafe {
+            let h = &*self.unwrap_shared();
+            slice::from_raw_parts(h.range, h.n + 1)
+        }
+    }
+
+    fn values(&self) -> &[f64] {
+        unsafe {
+            let h = &*self.unwrap_shared();
+            slice::from_raw_parts(h.bin, h.n)
+        }
+    }
+
+    /// Returns `true` if `self` and `other` have the same number of bins and all their ranges
+    /// and bin values differ by at most `tol`.
+    pub fn approx_eq(&self, other: &Histogram, tol: f64) -> bool {
+        self.bins() == other.bins()
+            && approx_eq_slices(self.ranges(), other.ranges(), tol)
+            && approx_eq_slices(self.values(), other.values(), tol)
+    }
+
+    // Same as `gsl_histogram_find`, without calling the error handler when `x` is out of range.
+    fn bin_index(&self, x: f64) -> Option<usize> {
+        let range = self.ranges();
+        // Written this way so that NaN isn't in range.
+        if !(x >= range[0] && x < range[range.len() - 1]) {
+            return None;
+        }
+        Some(range.partition_point(|&r| r <= x) - 1)
+    }
+
+    fn increment_bin(&mut self, i: usize) {
+        unsafe { *(*self.unwrap_unique()).bin.add(i) += 1. };
+    }
+
+    /// Returns an iterator over the bins, yielding their `(lower, upper, value)`.
+    pub fn iter(&self) -> HistogramIter<'_> {
+        HistogramIter { h: self, i: 0 }
+    }
+
+    /// Same as [`increment`](Self::increment), but returns the index of the bin containing `x`.
+    /// If `x` lies outside the range of the histogram it returns [`Value::Domain`] (without
+    /// calling the error handler).
+    pub fn find_and_increment(&mut self, x: f64) -> Result<usize, Error> {
+        match self.bin_index(x) {
+            Some(i) => {
+                self.increment_bin(i);
+                Ok(i)
+            }
+            None => Err(Error::new(Value::Domain, "gsl_histogram_increment")),
+        }
+    }
+
+    /// Adds one to the bin containing each value of `data`. The bins are searched on the Rust
+    /// side, instead of calling `gsl_histogram_increment` on each value. The values outside the
+    /// range of the histogram are ignored, and their number is returned.
+    pub fn fill(&mut self, data: &[f64]) -> usize {
+        let mut ignored = 0;
+        for &x in data {
+            match self.bin_index(x) {
+                Some(i) => self.increment_bin(i),
+                None => ignored += 1,
+            }
+        }
+        ignored
+    }
+
+    /// Adds the bins of `other` to the ones of `self`, like [`add`](Self::add). It returns
+    /// [`Value::Invalid`] if the two histograms don't have the same bins.
+    #[doc(alias = "gsl_histogram_add")]
+    pub fn merge(&mut self, other: &Histogram) -> Result<(), Error> {
+        if !self.equal_bins_p(other) {
+            return Err(Error::new(Value::Invalid, "gsl_histogram_add"));
+        }
+        result_handler!(
+            unsafe { sys::gsl_histogram_add(self.unwrap_unique(), other.unwrap_shared()) },
+            (),
+            "gsl_histogram_add"
+        )
+    }
+
+    /// Returns the probability distribution of the histogram, which can be used to sample it
+    /// (see [`HistogramPdf::init`]).
+    pub fn normalize(&self) -> Result<HistogramPdf, Error> {
+        let mut pdf = HistogramPdf::new(self.bins())
+            .ok_or_else(|| Error::new(Value::NoMemory, "gsl_histogram_pdf_alloc"))?;
+        pdf.init(self)?;
+        Ok(pdf)
     }
 
     #[allow(unused_must_use)]
@@ -310,6 +488,75 @@ impl Histogram {
     }
 }
 
+/// Iterator over the bins of a [`Histogram`], created by [`Histogram::iter`].
+pub struct HistogramIter<'a> {
+    h: &'a Histogram,
+    i: usize,
+}
+
+impl<'a> Iterator for HistogramIter<'a> {
+    type Item = (f64, f64, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let h = unsafe { &*self.h.unwrap_shared() };
+        if self.i >= h.n {
+            return None;
+        }
+        let item = unsafe {
+            (
+                *h.range.add(self.i),
+                *h.range.add(self.i + 1),
+                *h.bin.add(self.i),
+            )
+        };
+        self.i += 1;
+        Some(item)
+    }
+}
+
+impl Extend<f64> for Histogram {
+    /// Same as [`Histogram::fill`].
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        for x in iter {
+            if let Some(i) = self.bin_index(x) {
+                self.increment_bin(i);
+            }
+        }
+    }
+}
+
+impl Clone for Histogram {
+    /// Returns a new histogram with the same ranges and bin values as `self`.
+    ///
+    /// Panics if the allocation fails: use [`try_clone`](Self::try_clone) to handle it.
+    fn clone(&self) -> Histogram {
+        self.try_clone().expect("gsl_histogram_clone failed")
+    }
+}
+
+/// Two histograms are equal if they have the same ranges and the same bin values.
+impl PartialEq for Histogram {
+    fn eq(&self, other: &Histogram) -> bool {
+        self.ranges() == other.ranges() && self.values() == other.values()
+    }
+}
+
+impl fmt::Debug for Histogram {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ranges = self.ranges();
+        let values = self.values();
+        f.debug_struct("Histogram")
+            .field("bins", &self.bins())
+            .field("range", &Preview(ranges.len(), |i| ranges[i]))
+            .field("bin", &Preview(values.len(), |i| values[i]))
+            .finish()
+    }
+}
+
+fn approx_eq_slices(a: &[f64], b: &[f64], tol: f64) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| (x - y).abs() <= tol)
+}
+
 ffi_wrapper!(HistogramPdf, *mut sys::gsl_histogram_pdf, gsl_histogram_pdf_free,
 "The probability distribution function for a histogram consists of a set of bins which measure the \
 probability of an event falling into a given range of a continuous variable x. A probability \
@@ -320,6 +567,8 @@ distribution and the range [0,1]. It can be shown that by taking a uniform rando
 range and finding its corresponding coordinate in the cumulative probability distribution we obtain \
 samples with the desired probability distribution.");
 
+unsafe impl Send for HistogramPdf {}
+
 impl HistogramPdf {
     /// This function allocates memory for a probability distribution with n bins and returns a pointer to a newly initialized gsl_histogram_pdf
     /// struct. If insufficient memory is available a null pointer is returned and the error handler is invoked with an error code of Value::NoMem.
@@ -337,8 +586,12 @@ impl HistogramPdf {
     /// This function initializes the probability distribution self with the contents of the histogram h. If any of the bins of h are negative then
     /// the error handler is invoked with an error code of Value::Dom because a probability distribution cannot contain negative values.
     #[doc(alias = "gsl_histogram_pdf_init")]
-    pub fn init(&mut self, h: &Histogram) -> Value {
-        Value::from(unsafe { sys::gsl_histogram_pdf_init(self.unwrap_unique(), h.unwrap_shared()) })
+    pub fn init(&mut self, h: &Histogram) -> Result<(), Error> {
+        result_handler!(
+            unsafe { sys::gsl_histogram_pdf_init(self.unwrap_unique(), h.unwrap_shared()) },
+            (),
+            "gsl_histogram_pdf_init"
+        )
     }
 
     /// This function uses r, a uniform random number between zero and one, to compute a single random sample from the probability distribution
@@ -364,6 +617,8 @@ by recording related variables. For example a detector might record both the pos
 (x) and the amount of energy it deposited E. These could be histogrammed as the joint distribution \
 n(x,E).");
 
+unsafe impl Send for Histogram2D {}
+
 impl Histogram2D {
     /// This function allocates memory for a two-dimensional histogram with nx bins in the x direction and ny bins in the y direction. The
     /// function returns a pointer to a newly created gsl_histogram2d struct. If insufficient memory is available a null pointer is returned
@@ -383,39 +638,60 @@ impl Histogram2D {
     /// This function sets the ranges of the existing histogram h using the arrays xrange and yrange of size xsize and ysize respectively.
     /// The values of the histogram bins are reset to zero.
     #[doc(alias = "gsl_histogram2d_set_ranges")]
-    pub fn set_ranges(&mut self, xrange: &[f64], yrange: &[f64]) -> Value {
-        Value::from(unsafe {
-            sys::gsl_histogram2d_set_ranges(
-                self.unwrap_unique(),
-                xrange.as_ptr(),
-                xrange.len() as _,
-                yrange.as_ptr(),
-                yrange.len() as _,
-            )
-        })
+    pub fn set_ranges(&mut self, xrange: &[f64], yrange: &[f64]) -> Result<(), Error> {
+        result_handler!(
+            unsafe {
+                sys::gsl_histogram2d_set_ranges(
+                    self.unwrap_unique(),
+                    xrange.as_ptr(),
+                    xrange.len() as _,
+                    yrange.as_ptr(),
+                    yrange.len() as _,
+                )
+            },
+            (),
+            "gsl_histogram2d_set_ranges"
+        )
     }
 
     /// This function sets the ranges of the existing histogram h to cover the ranges xmin to xmax and ymin to ymax uniformly. The values
     /// of the histogram bins are reset to zero.
     #[doc(alias = "gsl_histogram2d_set_ranges_uniform")]
-    pub fn set_ranges_uniform(&mut self, xmin: f64, xmax: f64, ymin: f64, ymax: f64) -> Value {
-        Value::from(unsafe {
-            sys::gsl_histogram2d_set_ranges_uniform(self.unwrap_unique(), xmin, xmax, ymin, ymax)
-        })
+    pub fn set_ranges_uniform(
+        &mut self,
+        xmin: f64,
+        xmax: f64,
+        ymin: f64,
+        ymax: f64,
+    ) -> Result<(), Error> {
+        result_handler!(
+            unsafe {
+                sys::gsl_histogram2d_set_ranges_uniform(
+                    self.unwrap_unique(),
+                    xmin,
+                    xmax,
+                    ymin,
+                    ymax,
+                )
+            },
+            (),
+            "gsl_histogram2d_set_ranges_uniform"
+        )
     }
 
     /// This function copies the histogram src into the pre-existing histogram dest, making dest into an exact copy of src. The two histograms
     /// must be of the same size.
     #[doc(alias = "gsl_histogram2d_memcpy")]
-    pub fn copy(&self, dest: &mut Histogram2D) -> Value {
-        Value::from(unsafe {
-            sys::gsl_histogram2d_memcpy(dest.unwrap_unique(), self.unwrap_shared())
-        })
+    pub fn copy(&self, dest: &mut Histogram2D) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_histogram2d_memcpy(dest.unwrap_unique(), self.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_histogram2d_memcpy")
     }
 
-    /// his function returns a pointer to a newly created histogram which is an exact copy of the histogram self.
+    /// This function returns a pointer to a newly created histogram which is an exact copy of the histogram self,
+    /// or `None` if the allocation failed.
     #[doc(alias = "gsl_histogram2d_clone")]
-    pub fn clone(&self) -> Option<Histogram2D> {
+    pub fn try_clone(&self) -> Option<Histogram2D> {
         let tmp = unsafe { sys::gsl_histogram2d_clone(self.unwrap_shared()) };
 
         if tmp.is_null() {
@@ -432,15 +708,17 @@ impl Histogram2D {
     /// called, since it is often necessary to compute histograms for a small range of a larger dataset, ignoring any coordinates outside the
     /// range of interest.
     #[doc(alias = "gsl_histogram2d_increment")]
-    pub fn increment(&mut self, x: f64, y: f64) -> Value {
-        Value::from(unsafe { sys::gsl_histogram2d_increment(self.unwrap_unique(), x, y) })
+    pub fn increment(&mut self, x: f64, y: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram2d_increment(self.unwrap_unique(), x, y) };
+        result_handler!(ret, (), "gsl_histogram2d_increment")
     }
 
     /// This function is similar to gsl_histogram2d_increment but increases the value of the appropriate bin in the histogram h by the floating-point
     /// number weight.
     #[doc(alias = "gsl_histogram2d_accumulate")]
-    pub fn accumulate(&mut self, x: f64, y: f64, weight: f64) -> Value {
-        Value::from(unsafe { sys::gsl_histogram2d_accumulate(self.unwrap_unique(), x, y, weight) })
+    pub fn accumulate(&mut self, x: f64, y: f64, weight: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram2d_accumulate(self.unwrap_unique(), x, y, weight) };
+        result_handler!(ret, (), "gsl_histogram2d_accumulate")
     }
 
     /// This function returns the contents of the (i,j)-th bin of the histogram h. If (i,j) lies outside the valid range of indices for the
@@ -458,15 +736,15 @@ impl Histogram2D {
     /// return 0 to indicate success. If i or j lies outside the valid range of indices for the
     /// histogram then the error handler is called with an error code of Value::Dom.
     ///
-    /// Returns `(Value, xlower, xupper)`.
+    /// Returns `(xlower, xupper)`.
     #[doc(alias = "gsl_histogram2d_get_xrange")]
-    pub fn get_xrange(&self, i: usize) -> (Value, f64, f64) {
+    pub fn get_xrange(&self, i: usize) -> Result<(f64, f64), Error> {
         let mut xlower = 0.;
         let mut xupper = 0.;
         let ret = unsafe {
             sys::gsl_histogram2d_get_xrange(self.unwrap_shared(), i, &mut xlower, &mut xupper)
         };
-        (Value::from(ret), xlower, xupper)
+        result_handler!(ret, (xlower, xupper), "gsl_histogram2d_get_xrange")
     }
 
     /// This function finds the upper and lower range limits of the i-th and j-th bins in the x and
@@ -477,15 +755,15 @@ impl Histogram2D {
     /// return 0 to indicate success. If i or j lies outside the valid range of indices for the
     /// histogram then the error handler is called with an error code of Value::Dom.
     ///
-    /// Returns `(Value, ylower, yupper)`.
+    /// Returns `(ylower, yupper)`.
     #[doc(alias = "gsl_histogram2d_get_yrange")]
-    pub fn get_yrange(&self, j: usize) -> (Value, f64, f64) {
+    pub fn get_yrange(&self, j: usize) -> Result<(f64, f64), Error> {
         let mut ylower = 0.;
         let mut yupper = 0.;
         let ret = unsafe {
             sys::gsl_histogram2d_get_yrange(self.unwrap_shared(), j, &mut ylower, &mut yupper)
         };
-        (Value::from(ret), ylower, yupper)
+        result_handler!(ret, (ylower, yupper), "gsl_histogram2d_get_yrange")
     }
 
     /// This function returns the maximum upper and minimum lower range limits and the number of bins for the x and y directions of the histogram h.
@@ -543,13 +821,13 @@ impl Histogram2D {
     /// (x,y) lies outside the valid range of the histogram then the function returns Value::Dom and
     /// the error handler is invoked.
     ///
-    /// Returns `(Value, i, j)`.
+    /// Returns `(i, j)`.
     #[doc(alias = "gsl_histogram2d_find")]
-    pub fn find(&self, x: f64, y: f64) -> (Value, usize, usize) {
+    pub fn find(&self, x: f64, y: f64) -> Result<(usize, usize), Error> {
         let mut i = 0;
         let mut j = 0;
         let ret = unsafe { sys::gsl_histogram2d_find(self.unwrap_shared(), x, y, &mut i, &mut j) };
-        (Value::from(ret), i, j)
+        result_handler!(ret, (i, j), "gsl_histogram2d_find")
     }
 
     /// This function returns the maximum value contained in the histogram bins.
@@ -642,49 +920,154 @@ impl Histogram2D {
     /// This function adds the contents of the bins in histogram h2 to the corresponding bins of histogram h1, i.e. h'_1(i,j) = h_1(i,j)
     /// + h_2(i,j). The two histograms must have identical bin ranges.
     #[doc(alias = "gsl_histogram2d_add")]
-    pub fn add(&mut self, other: &Histogram2D) -> Value {
-        Value::from(unsafe {
-            sys::gsl_histogram2d_add(self.unwrap_unique(), other.unwrap_shared())
-        })
+    pub fn add(&mut self, other: &Histogram2D) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram2d_add(self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_histogram2d_add")
     }
 
     /// This function subtracts the contents of the bins in histogram h2 from the corresponding bins of histogram h1, i.e. h'_1(i,j) = h_1(i,j)
     /// - h_2(i,j). The two histograms must have identical bin ranges.
     #[doc(alias = "gsl_histogram2d_sub")]
-    pub fn sub(&mut self, other: &Histogram2D) -> Value {
-        Value::from(unsafe {
-            sys::gsl_histogram2d_sub(self.unwrap_unique(), other.unwrap_shared())
-        })
+    pub fn sub(&mut self, other: &Histogram2D) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram2d_sub(self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_histogram2d_sub")
     }
 
     /// This function multiplies the contents of the bins of histogram h1 by the contents of the corresponding bins in histogram h2, i.e. h'_1(i,j)
     /// = h_1(i,j) * h_2(i,j). The two histograms must have identical bin ranges.
     #[doc(alias = "gsl_histogram2d_mul")]
-    pub fn mul(&mut self, other: &Histogram2D) -> Value {
-        Value::from(unsafe {
-            sys::gsl_histogram2d_mul(self.unwrap_unique(), other.unwrap_shared())
-        })
+    pub fn mul(&mut self, other: &Histogram2D) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram2d_mul(self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_histogram2d_mul")
     }
 
     /// This function divides the contents of the bins of histogram h1 by the contents of the corresponding bins in histogram h2, i.e. h'_1(i,j) =
     /// h_1(i,j) / h_2(i,j). The two histograms must have identical bin ranges.
     #[doc(alias = "gsl_histogram2d_div")]
-    pub fn div(&mut self, other: &Histogram2D) -> Value {
-        Value::from(unsafe {
-            sys::gsl_histogram2d_div(self.unwrap_unique(), other.unwrap_shared())
-        })
+    pub fn div(&mut self, other: &Histogram2D) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram2d_div(self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_histogram2d_div")
     }
 
     /// This function multiplies the contents of the bins of histogram h by the constant scale, i.e. h'_1(i,j) = h_1(i,j) scale.
     #[doc(alias = "gsl_histogram2d_scale")]
-    pub fn scale(&mut self, scale: f64) -> Value {
-        Value::from(unsafe { sys::gsl_histogram2d_scale(self.unwrap_unique(), scale) })
+    pub fn scale(&mut self, scale: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram2d_scale(self.unwrap_unique(), scale) };
+        result_handler!(ret, (), "gsl_histogram2d_scale")
     }
 
     /// This function shifts the contents of the bins of histogram h by the constant offset, i.e. h'_1(i,j) = h_1(i,j) + offset.
     #[doc(alias = "gsl_histogram2d_shift")]
-    pub fn shift(&mut self, offset: f64) -> Value {
-        Value::from(unsafe { sys::gsl_histogram2d_shift(self.unwrap_unique(), offset) })
+    pub fn shift(&mut self, offset: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_histogram2d_shift(self.unwrap_unique(), offset) };
+        result_handler!(ret, (), "gsl_histogram2d_shift")
+    }
+
+    /// Adds one to the bin containing each point `(x[k], y[k])`. The points outside the range of
+    /// the histogram are ignored, and their number is returned.
+    ///
+    /// Panics if `x` and `y` don't have the same length.
+    pub fn fill(&mut self, x: &[f64], y: &[f64]) -> usize {
+        assert_eq!(x.len(), y.len(), "slices of different lengths");
+        let h = self.unwrap_unique();
+        x.iter()
+            .zip(y)
+            .filter(|&(&x, &y)| unsafe { sys::gsl_histogram2d_increment(h, x, y) } != 0)
+            .count()
+    }
+
+    /// Adds `weights[k]` to the bin containing each point `(x[k], y[k])`. The points outside the
+    /// range of the histogram are ignored, and their number is returned.
+    ///
+    /// Panics if `x`, `y` and `weights` don't have the same length.
+    pub fn accumulate_slices(&mut self, x: &[f64], y: &[f64], weights: &[f64]) -> usize {
+        assert!(
+            x.len() == y.len() && x.len() == weights.len(),
+            "slices of different lengths"
+        );
+        let h = self.unwrap_unique();
+        x.iter()
+            .zip(y)
+            .zip(weights)
+            .filter(|&((&x, &y), &w)| unsafe { sys::gsl_histogram2d_accumulate(h, x, y, w) } != 0)
+            .count()
+    }
+
+    /// Returns the probability distribution of the histogram, which can be used to sample it
+    /// (see [`Histogram2DPdf::init`]).
+    pub fn normalize(&self) -> Result<Histogram2DPdf, Error> {
+        let mut pdf = Histogram2DPdf::new(self.nx(), self.ny())
+            .ok_or_else(|| Error::new(Value::NoMemory, "gsl_histogram2d_pdf_alloc"))?;
+        pdf.init(self)?;
+        Ok(pdf)
+    }
+
+    // `gsl_histogram2d` is opaque in the bindings, so the ranges and bins are read one by one.
+    fn xranges(&self) -> Vec<f64> {
+        (0..self.nx())
+            .filter_map(|i| self.get_xrange(i).ok().map(|(lower, _)| lower))
+            .chain(Some(self.xmax()))
+            .collect()
+    }
+
+    fn yranges(&self) -> Vec<f64> {
+        (0..self.ny())
+            .filter_map(|j| self.get_yrange(j).ok().map(|(lower, _)| lower))
+            .chain(Some(self.ymax()))
+            .collect()
+    }
+
+    fn values(&self) -> Vec<f64> {
+        let ny = self.ny();
+        (0..self.nx())
+            .flat_map(|i| (0..ny).map(move |j| self.get(i, j)))
+            .collect()
+    }
+
+    /// Returns `true` if `self` and `other` have the same number of bins and all their ranges
+    /// and bin values differ by at most `tol`.
+    pub fn approx_eq(&self, other: &Histogram2D, tol: f64) -> bool {
+        self.nx() == other.nx()
+            && self.ny() == other.ny()
+            && approx_eq_slices(&self.xranges(), &other.xranges(), tol)
+            && approx_eq_slices(&self.yranges(), &other.yranges(), tol)
+            && approx_eq_slices(&self.values(), &other.values(), tol)
+    }
+}
+
+impl Clone for Histogram2D {
+    /// Returns a new histogram with the same ranges and bin values as `self`.
+    ///
+    /// Panics if the allocation fails: use [`try_clone`](Self::try_clone) to handle it.
+    fn clone(&self) -> Histogram2D {
+        self.try_clone().expect("gsl_histogram2d_clone failed")
+    }
+}
+
+/// Two histograms are equal if they have the same ranges and the same bin values.
+impl PartialEq for Histogram2D {
+    fn eq(&self, other: &Histogram2D) -> bool {
+        self.xranges() == other.xranges()
+            && self.yranges() == other.yranges()
+            && self.values() == other.values()
+    }
+}
+
+impl fmt::Debug for Histogram2D {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (nx, ny) = (self.nx(), self.ny());
+        let xranges = self.xranges();
+        let yranges = self.yranges();
+        f.debug_struct("Histogram2D")
+            .field("nx", &nx)
+            .field("ny", &ny)
+            .field("xrange", &Preview(xranges.len(), |i| xranges[i]))
+            .field("yrange", &Preview(yranges.len(), |i| yranges[i]))
+            .field(
+                "bin",
+                &Preview(nx, |i| Preview(ny, move |j| self.get(i, j))),
+            )
+            .finish()
     }
 }
 
@@ -705,6 +1088,8 @@ area of the bin and N is the total number of events. The distribution of events
 assumed to be uniform."
 );
 
+unsafe impl Send for Histogram2DPdf {}
+
 impl Histogram2DPdf {
     /// This function allocates memory for a two-dimensional probability distribution of size nx-by-ny and returns a pointer to a newly initialized
     /// gsl_histogram2d_pdf struct. If insufficient memory is available a null pointer is returned and the error handler is invoked with an error
@@ -724,23 +1109,60 @@ impl Histogram2DPdf {
     /// negative then the error handler is invoked with an error code of GSL_EDOM because a probability distribution cannot contain negative
     /// values.
     #[doc(alias = "gsl_histogram2d_pdf_init")]
-    pub fn init(&mut self, h: &Histogram2D) -> Value {
-        Value::from(unsafe {
-            sys::gsl_histogram2d_pdf_init(self.unwrap_unique(), h.unwrap_shared())
-        })
+    pub fn init(&mut self, h: &Histogram2D) -> Result<(), Error> {
+        result_handler!(
+            unsafe { sys::gsl_histogram2d_pdf_init(self.unwrap_unique(), h.unwrap_shared()) },
+            (),
+            "gsl_histogram2d_pdf_init"
+        )
     }
 
     /// This function uses two uniform random numbers between zero and one, r1 and r2, to compute a
     /// single random sample from the two-dimensional probability distribution p.
     ///
-    /// Returns `(Value, x, y)`.
+    /// Returns `(x, y)`.
     #[doc(alias = "gsl_histogram2d_pdf_sample")]
-    pub fn sample(&self, r1: f64, r2: f64) -> (Value, f64, f64) {
+    pub fn sample(&self, r1: f64, r2: f64) -> Result<(f64, f64), Error> {
         let mut x = 0.;
         let mut y = 0.;
         let ret = unsafe {
             sys::gsl_histogram2d_pdf_sample(self.unwrap_shared(), r1, r2, &mut x, &mut y)
         };
-        (Value::from(ret), x, y)
+        result_handler!(ret, (x, y), "gsl_histogram2d_pdf_sample")
     }
 }
+
+#[test]
+fn histogram_fill_merge() {
+    let mut h = HistogramBuilder::uniform(4, 0., 4.)
+        .data(&[0., 0.5, 3.9, 4., -1., f64::NAN])
+        .build()
+        .unwrap();
+    assert_eq!(h.find_and_increment(2.5).unwrap(), 2);
+    assert!(h.find_and_increment(10.).is_err());
+    assert_eq!(h.fill(&[1., 5.]), 1);
+    assert_eq!(
+        h.iter().collect::<Vec<_>>(),
+        [(0., 1., 2.), (1., 2., 1.), (2., 3., 1.), (3., 4., 1.)]
+    );
+
+    let other = h.clone();
+    h.merge(&other).unwrap();
+    assert_eq!(h.sum(), 10.);
+    let coarse = HistogramBuilder::uniform(2, 0., 4.).build().unwrap();
+    assert!(h.merge(&coarse).is_err());
+    assert!(h.normalize().unwrap().sample(0.1) < 1.);
+}
+
+#[test]
+fn histogram2d_fill_sample() {
+    let mut h = Histogram2D::new(2, 2).unwrap();
+    h.set_ranges_uniform(0., 2., 0., 2.).unwrap();
+    assert_eq!(h.fill(&[0.5, 1.5, 3.], &[0.5, 0.5, 0.5]), 1);
+    assert_eq!(h.accumulate_slices(&[1.5], &[1.5], &[2.]), 0);
+    assert_eq!((h.get(0, 0), h.get(1, 0), h.get(1, 1)), (1., 1., 2.));
+
+    let pdf = h.normalize().unwrap();
+    let (x, y) = pdf.sample(0.9, 0.5).unwrap();
+    assert!(x >= 1. && y >= 1.);
+}
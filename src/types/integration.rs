@@ -3,36 +3,73 @@
 //
 
 use crate::enums;
-use crate::Value;
+use crate::Error;
 use ffi::FFI;
 
 ffi_wrapper!(IntegrationFixedType, *const sys::gsl_integration_fixed_type);
 
+unsafe impl Send for IntegrationFixedType {}
+unsafe impl Sync for IntegrationFixedType {}
+
 impl IntegrationFixedType {
+    /// Gauss-Legendre quadrature on (a, b), with `w(x) = 1`.
+    #[doc(alias = "gsl_integration_fixed_legendre")]
     pub fn legendre() -> IntegrationFixedType {
         ffi_wrap!(gsl_integration_fixed_legendre)
     }
+
+    /// Gauss-Chebyshev quadrature of the first kind on (a, b), with
+    /// `w(x) = 1 / sqrt((b - x) (x - a))`.
+    #[doc(alias = "gsl_integration_fixed_chebyshev")]
     pub fn chebyshev() -> IntegrationFixedType {
         ffi_wrap!(gsl_integration_fixed_chebyshev)
     }
+
+    /// Gauss-Chebyshev quadrature of the second kind on (a, b), with
+    /// `w(x) = sqrt((b - x) (x - a))`.
+    #[doc(alias = "gsl_integration_fixed_chebyshev2")]
     pub fn chebyshev2() -> IntegrationFixedType {
         ffi_wrap!(gsl_integration_fixed_chebyshev2)
     }
+
+    /// Gauss-Gegenbauer quadrature on (a, b), with `w(x) = ((b - x) (x - a))^alpha` and
+    /// `alpha > -1`.
+    #[doc(alias = "gsl_integration_fixed_gegenbauer")]
     pub fn gegenbauer() -> IntegrationFixedType {
         ffi_wrap!(gsl_integration_fixed_gegenbauer)
     }
+
+    /// Gauss-Jacobi quadrature on (a, b), with `w(x) = (b - x)^alpha (x - a)^beta` and
+    /// `alpha, beta > -1`.
+    #[doc(alias = "gsl_integration_fixed_jacobi")]
     pub fn jacobi() -> IntegrationFixedType {
         ffi_wrap!(gsl_integration_fixed_jacobi)
     }
+
+    /// Gauss-Laguerre quadrature on (a, +inf), with `w(x) = (x - a)^alpha exp(-b (x - a))`,
+    /// `alpha > -1` and `b > 0`.
+    #[doc(alias = "gsl_integration_fixed_laguerre")]
     pub fn laguerre() -> IntegrationFixedType {
         ffi_wrap!(gsl_integration_fixed_laguerre)
     }
+
+    /// Gauss-Hermite quadrature on (-inf, +inf), with `w(x) = |x - a|^alpha exp(-b (x - a)^2)`,
+    /// `alpha > -1` and `b > 0`.
+    #[doc(alias = "gsl_integration_fixed_hermite")]
     pub fn hermite() -> IntegrationFixedType {
         ffi_wrap!(gsl_integration_fixed_hermite)
     }
+
+    /// Exponential quadrature on (a, b), with `w(x) = |x - (a + b) / 2|^alpha` and
+    /// `alpha > -1`.
+    #[doc(alias = "gsl_integration_fixed_exponential")]
     pub fn exponential() -> IntegrationFixedType {
         ffi_wrap!(gsl_integration_fixed_exponential)
     }
+
+    /// Rational quadrature on (a, +inf), with `w(x) = (x - a)^alpha (x + b)^beta`,
+    /// `alpha > -1`, `alpha + beta + 2 n < 0` and `a + b > 0`.
+    #[doc(alias = "gsl_integration_fixed_rational")]
     pub fn rational() -> IntegrationFixedType {
         ffi_wrap!(gsl_integration_fixed_rational)
     }
@@ -44,7 +81,16 @@ ffi_wrapper!(
     gsl_integration_fixed_free
 );
 
+unsafe impl Send for IntegrationFixedWorkspace {}
+
 impl IntegrationFixedWorkspace {
+    /// This function computes the `n` nodes and weights of the quadrature rule `type_` for the
+    /// interval and weight function parameters `a`, `b`, `alpha` and `beta`. See
+    /// [`IntegrationFixedType`] for which parameters each rule uses.
+    ///
+    /// The nodes and weights are computed once, so the workspace can be reused to integrate any
+    /// number of functions with [`fixed`](#method.fixed), or directly through
+    /// [`nodes`](#method.nodes) and [`weights`](#method.weights).
     #[doc(alias = "gsl_integration_fixed_alloc")]
     pub fn new(
         type_: IntegrationFixedType,
@@ -65,11 +111,13 @@ impl IntegrationFixedWorkspace {
         }
     }
 
+    /// Returns the number of nodes of the quadrature rule.
     #[doc(alias = "gsl_integration_fixed_n")]
     pub fn n(&self) -> usize {
         unsafe { sys::gsl_integration_fixed_n(self.unwrap_shared()) }
     }
 
+    /// Returns the nodes of the quadrature rule.
     #[doc(alias = "gsl_integration_fixed_nodes")]
     pub fn nodes(&self) -> Option<&[f64]> {
         let tmp = unsafe { sys::gsl_integration_fixed_nodes(self.unwrap_shared()) };
@@ -79,6 +127,7 @@ impl IntegrationFixedWorkspace {
         unsafe { Some(::std::slice::from_raw_parts(tmp, self.n())) }
     }
 
+    /// Returns the weights of the quadrature rule, matching the [`nodes`](#method.nodes).
     #[doc(alias = "gsl_integration_fixed_weights")]
     pub fn weights(&self) -> Option<&[f64]> {
         let tmp = unsafe { sys::gsl_integration_fixed_weights(self.unwrap_shared()) };
@@ -88,14 +137,16 @@ impl IntegrationFixedWorkspace {
         unsafe { Some(::std::slice::from_raw_parts(tmp, self.n())) }
     }
 
+    /// This function integrates `f` with the quadrature rule, that is it returns
+    /// `sum_i weights[i] f(nodes[i])`.
     #[doc(alias = "gsl_integration_fixed")]
-    pub fn fixed<F: Fn(f64) -> f64>(&self, f: F) -> (::Value, f64) {
+    pub fn fixed<F: FnMut(f64) -> f64>(&self, mut f: F) -> Result<f64, Error> {
         let mut result = 0.;
         let function = wrap_callback!(f, F);
 
         let ret =
             unsafe { sys::gsl_integration_fixed(&function, &mut result, self.unwrap_shared()) };
-        (::Value::from(ret), result)
+        result_handler!(ret, result, "gsl_integration_fixed")
     }
 }
 
@@ -107,6 +158,8 @@ around local difficulties in the integrand. These subintervals are managed by a
 gsl_integration_workspace struct, which handles the memory for the subinterval ranges, results
 and error estimates.");
 
+unsafe impl Send for IntegrationWorkspace {}
+
 impl IntegrationWorkspace {
     /// This function allocates a workspace sufficient to hold n double precision intervals, their
     /// integration results and error estimates. One workspace may be used multiple times as all
@@ -167,16 +220,16 @@ impl IntegrationWorkspace {
     ///
     /// Returns `(result, abs_err)`.
     #[doc(alias = "gsl_integration_qag")]
-    pub fn qag<F: Fn(f64) -> f64>(
+    pub fn qag<F: FnMut(f64) -> f64>(
         &mut self,
-        f: F,
+        mut f: F,
         a: f64,
         b: f64,
         epsabs: f64,
         epsrel: f64,
         limit: usize,
         key: enums::GaussKronrodRule,
-    ) -> (::Value, f64, f64) {
+    ) -> Result<(f64, f64), Error> {
         let mut result = 0.;
         let mut abs_err = 0.;
         let function = wrap_callback!(f, F);
@@ -195,7 +248,7 @@ impl IntegrationWorkspace {
                 &mut abs_err,
             )
         };
-        (::Value::from(ret), result, abs_err)
+        result_handler!(ret, (result, abs_err), "gsl_integration_qag")
     }
 
     /// This function applies the Gauss-Kronrod 21-point integration rule adaptively until an
@@ -210,15 +263,15 @@ impl IntegrationWorkspace {
     ///
     /// Returns `(result, abs_err)`.
     #[doc(alias = "gsl_integration_qags")]
-    pub fn qags<F: Fn(f64) -> f64>(
+    pub fn qags<F: FnMut(f64) -> f64>(
         &mut self,
-        f: F,
+        mut f: F,
         a: f64,
         b: f64,
         epsabs: f64,
         epsrel: f64,
         limit: usize,
-    ) -> (::Value, f64, f64) {
+    ) -> Result<(f64, f64), Error> {
         let mut result = 0.;
         let mut abs_err = 0.;
         let function = wrap_callback!(f, F);
@@ -236,7 +289,7 @@ impl IntegrationWorkspace {
                 &mut abs_err,
             )
         };
-        (::Value::from(ret), result, abs_err)
+        result_handler!(ret, (result, abs_err), "gsl_integration_qags")
     }
 
     /// This function applies the adaptive integration algorithm QAGS taking account of the
@@ -261,14 +314,14 @@ impl IntegrationWorkspace {
     ///
     /// Returns `(result, abs_err)`.
     #[doc(alias = "gsl_integration_qagp")]
-    pub fn qagp<F: Fn(f64) -> f64>(
+    pub fn qagp<F: FnMut(f64) -> f64>(
         &mut self,
-        f: F,
+        mut f: F,
         pts: &mut [f64],
         epsabs: f64,
         epsrel: f64,
         limit: usize,
-    ) -> (::Value, f64, f64) {
+    ) -> Result<(f64, f64), Error> {
         let mut result = 0.;
         let mut abs_err = 0.;
         let function = wrap_callback!(f, F);
@@ -286,7 +339,7 @@ impl IntegrationWorkspace {
                 &mut abs_err,
             )
         };
-        (::Value::from(ret), result, abs_err)
+        result_handler!(ret, (result, abs_err), "gsl_integration_qagp")
     }
 
     /// This function computes the integral of the function f over the infinite interval
@@ -306,13 +359,13 @@ impl IntegrationWorkspace {
     ///
     /// Returns `(result, abs_err)`.
     #[doc(alias = "gsl_integration_qagi")]
-    pub fn qagi<F: Fn(f64) -> f64>(
+    pub fn qagi<F: FnMut(f64) -> f64>(
         &mut self,
-        f: F,
+        mut f: F,
         epsabs: f64,
         epsrel: f64,
         limit: usize,
-    ) -> (::Value, f64, f64) {
+    ) -> Result<(f64, f64), Error> {
         let mut result = 0.;
         let mut abs_err = 0.;
         let mut function = wrap_callback!(f, F);
@@ -328,7 +381,7 @@ impl IntegrationWorkspace {
                 &mut abs_err,
             )
         };
-        (::Value::from(ret), result, abs_err)
+        result_handler!(ret, (result, abs_err), "gsl_integration_qagi")
     }
 
     /// This function computes the integral of the function f over the semi-infinite interval
@@ -346,14 +399,14 @@ impl IntegrationWorkspace {
     ///
     /// Returns `(result, abs_err)`.
     #[doc(alias = "gsl_integration_qagiu")]
-    pub fn qagiu<F: Fn(f64) -> f64>(
+    pub fn qagiu<F: FnMut(f64) -> f64>(
         &mut self,
-        f: F,
+        mut f: F,
         a: f64,
         epsabs: f64,
         epsrel: f64,
         limit: usize,
-    ) -> (::Value, f64, f64) {
+    ) -> Result<(f64, f64), Error> {
         let mut result = 0.;
         let mut abs_err = 0.;
         let mut function = wrap_callback!(f, F);
@@ -370,7 +423,7 @@ impl IntegrationWorkspace {
                 &mut abs_err,
             )
         };
-        (::Value::from(ret), result, abs_err)
+        result_handler!(ret, (result, abs_err), "gsl_integration_qagiu")
     }
 
     /// This function computes the integral of the function f over the semi-infinite interval
@@ -388,14 +441,14 @@ impl IntegrationWorkspace {
     ///
     /// Returns `(result, abs_err)`.
     #[doc(alias = "gsl_integration_qagil")]
-    pub fn qagil<F: Fn(f64) -> f64>(
+    pub fn qagil<F: FnMut(f64) -> f64>(
         &mut self,
-        f: F,
+        mut f: F,
         b: f64,
         epsabs: f64,
         epsrel: f64,
         limit: usize,
-    ) -> (::Value, f64, f64) {
+    ) -> Result<(f64, f64), Error> {
         let mut result = 0.;
         let mut abs_err = 0.;
         let mut function = wrap_callback!(f, F);
@@ -412,7 +465,7 @@ impl IntegrationWorkspace {
                 &mut abs_err,
             )
         };
-        (::Value::from(ret), result, abs_err)
+        result_handler!(ret, (result, abs_err), "gsl_integration_qagil")
     }
 
     /// This function computes the Cauchy principal value of the integral of f over `(a,b)`, with a
@@ -431,16 +484,16 @@ impl IntegrationWorkspace {
     ///
     /// Returns `(result, abs_err)`.
     #[doc(alias = "gsl_integration_qawc")]
-    pub fn qawc<F: Fn(f64) -> f64>(
+    pub fn qawc<F: FnMut(f64) -> f64>(
         &mut self,
-        f: F,
+        mut f: F,
         a: f64,
         b: f64,
         c: f64,
         epsabs: f64,
         epsrel: f64,
         limit: usize,
-    ) -> (::Value, f64, f64) {
+    ) -> Result<(f64, f64), Error> {
         let mut result = 0.;
         let mut abs_err = 0.;
         let mut function = wrap_callback!(f, F);
@@ -459,7 +512,7 @@ impl IntegrationWorkspace {
                 &mut abs_err,
             )
         };
-        (::Value::from(ret), result, abs_err)
+        result_handler!(ret, (result, abs_err), "gsl_integration_qawc")
     }
 }
 
@@ -472,6 +525,8 @@ end-points of an integration region. In order to work efficiently the algorithm
 precomputed table of Chebyshev moments."
 );
 
+unsafe impl Send for IntegrationQawsTable {}
+
 impl IntegrationQawsTable {
     /// This function allocates space for a gsl_integration_qaws_table struct describing a singular
     /// weight function W(x) with the parameters `alpha`, `beta`, `mu` and `nu`,
@@ -508,10 +563,14 @@ impl IntegrationQawsTable {
 
     /// This function modifies the parameters (\alpha, \beta, \mu, \nu)
     #[doc(alias = "gsl_integration_qaws_table_set")]
-    pub fn set(&mut self, alpha: f64, beta: f64, mu: i32, nu: i32) -> ::Value {
-        ::Value::from(unsafe {
-            sys::gsl_integration_qaws_table_set(self.unwrap_unique(), alpha, beta, mu, nu)
-        })
+    pub fn set(&mut self, alpha: f64, beta: f64, mu: i32, nu: i32) -> Result<(), Error> {
+        result_handler!(
+            unsafe {
+                sys::gsl_integration_qaws_table_set(self.unwrap_unique(), alpha, beta, mu, nu)
+            },
+            (),
+            "gsl_integration_qaws_table_set"
+        )
     }
 
     /// This function computes the integral of the function f(x) over the interval (a,b) with the
@@ -530,16 +589,16 @@ impl IntegrationQawsTable {
     ///
     /// Returns `(result, abs_err)`
     #[doc(alias = "gsl_integration_qaws")]
-    pub fn qaws<F: Fn(f64) -> f64>(
+    pub fn qaws<F: FnMut(f64) -> f64>(
         &mut self,
-        f: F,
+        mut f: F,
         a: f64,
         b: f64,
         epsabs: f64,
         epsrel: f64,
         limit: usize,
         workspace: &mut IntegrationWorkspace,
-    ) -> (::Value, f64, f64) {
+    ) -> Result<(f64, f64), Error> {
         let mut result = 0.;
         let mut abs_err = 0.;
         let mut function = wrap_callback!(f, F);
@@ -558,7 +617,7 @@ impl IntegrationQawsTable {
                 &mut abs_err,
             )
         };
-        (::Value::from(ret), result, abs_err)
+        result_handler!(ret, (result, abs_err), "gsl_integration_qaws")
     }
 }
 
@@ -571,6 +630,8 @@ ffi_wrapper!(
 which must be pre-computed with calls to the functions below."
 );
 
+unsafe impl Send for IntegrationQawoTable {}
+
 impl IntegrationQawoTable {
     /// This function allocates space for a gsl_integration_qawo_table struct and its associated
     /// workspace describing a sine or cosine weight function W(x) with the parameters (\omega, L),
@@ -585,7 +646,7 @@ impl IntegrationQawoTable {
     /// should be chosen from one of the two following symbolic values:
     ///
     /// ```text
-    /// ::Cosine
+    /// ::IntegrationQawo::Cosine
     /// ::IntegrationQawo::Sine
     /// ```
     ///
@@ -613,18 +674,24 @@ impl IntegrationQawoTable {
 
     /// This function changes the parameters omega, L and sine of the existing self workspace.
     #[doc(alias = "gsl_integration_qawo_table_set")]
-    pub fn set(&mut self, omega: f64, l: f64, sine: ::IntegrationQawo) -> ::Value {
-        ::Value::from(unsafe {
-            sys::gsl_integration_qawo_table_set(self.unwrap_unique(), omega, l, sine.into())
-        })
+    pub fn set(&mut self, omega: f64, l: f64, sine: ::IntegrationQawo) -> Result<(), Error> {
+        result_handler!(
+            unsafe {
+                sys::gsl_integration_qawo_table_set(self.unwrap_unique(), omega, l, sine.into())
+            },
+            (),
+            "gsl_integration_qawo_table_set"
+        )
     }
 
     /// This function allows the length parameter l of the self workspace to be changed.
     #[doc(alias = "gsl_integration_qawo_table_set_length")]
-    pub fn set_length(&mut self, l: f64) -> ::Value {
-        ::Value::from(unsafe {
-            sys::gsl_integration_qawo_table_set_length(self.unwrap_unique(), l)
-        })
+    pub fn set_length(&mut self, l: f64) -> Result<(), Error> {
+        result_handler!(
+            unsafe { sys::gsl_integration_qawo_table_set_length(self.unwrap_unique(), l) },
+            (),
+            "gsl_integration_qawo_table_set_length"
+        )
     }
 
     /// This function uses an adaptive algorithm to compute the integral of f over (a,b) with the
@@ -645,15 +712,15 @@ impl IntegrationQawoTable {
     ///
     /// Returns `(result, abserr)`.
     #[doc(alias = "gsl_integration_qawo")]
-    pub fn qawo<F: Fn(f64) -> f64>(
+    pub fn qawo<F: FnMut(f64) -> f64>(
         &mut self,
-        f: F,
+        mut f: F,
         a: f64,
         epsabs: f64,
         epsrel: f64,
         limit: usize,
         workspace: &mut IntegrationWorkspace,
-    ) -> (::Value, f64, f64) {
+    ) -> Result<(f64, f64), Error> {
         let mut function = wrap_callback!(f, F);
         let mut result = 0.;
         let mut abserr = 0.;
@@ -671,7 +738,7 @@ impl IntegrationQawoTable {
                 &mut abserr,
             )
         };
-        (::Value::from(ret), result, abserr)
+        result_handler!(ret, (result, abserr), "gsl_integration_qawo")
     }
 }
 
@@ -687,6 +754,8 @@ difference between the underlying interpolatory polynomials of two successive ru
 an error estimate. The interval is subdivided if the difference between two successive rules is
 too large or a rule of maximum degree has been reached.");
 
+unsafe impl Send for CquadWorkspace {}
+
 impl CquadWorkspace {
     /// This function allocates a workspace sufficient to hold the data for n intervals. The number
     /// n is not the maximum number of intervals that will be evaluated. If the workspace is full,
@@ -723,14 +792,14 @@ impl CquadWorkspace {
     ///
     /// Returns `(result, abs_err, n_evals)`.
     #[doc(alias = "gsl_integration_cquad")]
-    pub fn cquad<F: Fn(f64) -> f64>(
+    pub fn cquad<F: FnMut(f64) -> f64>(
         &mut self,
-        f: F,
+        mut f: F,
         a: f64,
         b: f64,
         epsabs: f64,
         epsrel: f64,
-    ) -> (::Value, f64, f64, usize) {
+    ) -> Result<(f64, f64, usize), Error> {
         let function = wrap_callback!(f, F);
         let mut result = 0.;
         let mut abs_err = 0.;
@@ -749,7 +818,71 @@ impl CquadWorkspace {
                 &mut n_evals,
             )
         };
-        (::Value::from(ret), result, abs_err, n_evals)
+        result_handler!(ret, (result, abs_err, n_evals), "gsl_integration_cquad")
+    }
+}
+
+#[cfg(feature = "v2_5")]
+ffi_wrapper!(
+    RombergWorkspace,
+    *mut sys::gsl_integration_romberg_workspace,
+    gsl_integration_romberg_free,
+    "Romberg integration repeatedly halves the step of the trapezoidal rule and applies Richardson
+extrapolation to the results. It converges very quickly for smooth integrands on finite
+intervals."
+);
+
+#[cfg(feature = "v2_5")]
+unsafe impl Send for RombergWorkspace {}
+
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+impl RombergWorkspace {
+    /// This function allocates a workspace for Romberg integration, allowing up to n iterations
+    /// (that is `2^n + 1` function evaluations). n must be between 1 and 30, and 20 is a good
+    /// choice for most integrands.
+    #[doc(alias = "gsl_integration_romberg_alloc")]
+    pub fn new(n: usize) -> Option<RombergWorkspace> {
+        let tmp = unsafe { sys::gsl_integration_romberg_alloc(n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function integrates f over (a,b) with the Romberg method, stopping once the difference
+    /// between two successive extrapolations is below max(epsabs, epsrel |result|), or after the
+    /// maximum number of iterations of the workspace.
+    ///
+    /// Returns `(result, n_evals)`.
+    #[doc(alias = "gsl_integration_romberg")]
+    pub fn romberg<F: FnMut(f64) -> f64>(
+        &mut self,
+        mut f: F,
+        a: f64,
+        b: f64,
+        epsabs: f64,
+        epsrel: f64,
+    ) -> Result<(f64, usize), Error> {
+        let function = wrap_callback!(f, F);
+        let mut result = 0.;
+        let mut n_evals = 0;
+
+        let ret = unsafe {
+            sys::gsl_integration_romberg(
+                &function,
+                a,
+                b,
+                epsabs,
+                epsrel,
+                &mut result,
+                &mut n_evals,
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, (result, n_evals), "gsl_integration_romberg")
     }
 }
 
@@ -760,6 +893,17 @@ of order 2*n-1 or less. For example, these rules are useful when integrating bas
 form mass matrices for the Galerkin method. Unlike other numerical integration routines within
 the library, these routines do not accept absolute or relative error bounds.");
 
+unsafe impl Send for GLFixedTable {}
+
+// `gsl_integration_glfixed_table` is opaque in the bindings.
+#[repr(C)]
+struct GLFixedTableHeader {
+    n: usize,
+    x: *mut f64,
+    w: *mut f64,
+    precomputed: ::std::os::raw::c_int,
+}
+
 impl GLFixedTable {
     /// This function determines the Gauss-Legendre abscissae and weights necessary for an n-point
     /// fixed order integration scheme. If possible, high precision precomputed coefficients are
@@ -776,42 +920,84 @@ impl GLFixedTable {
         }
     }
 
+    /// Returns the order n of the rule, which is also its number of points.
+    pub fn n(&self) -> usize {
+        unsafe { (*(self.unwrap_shared() as *const GLFixedTableHeader)).n }
+    }
+
     /// For i in [0, …, t->n - 1], this function obtains the i-th Gauss-Legendre point xi and weight
     /// wi on the interval [a,b]. The points and weights are ordered by increasing point value. A
     /// function f may be integrated on [a,b] by summing wi * f(xi) over i.
     ///
-    /// Returns `(Value, xi, wi)`.
+    /// Returns `(xi, wi)`.
     #[doc(alias = "gsl_integration_glfixed_point")]
-    pub fn point(&self, a: f64, b: f64, i: usize) -> (::Value, f64, f64) {
+    pub fn point(&self, a: f64, b: f64, i: usize) -> Result<(f64, f64), Error> {
         let mut xi = 0.;
         let mut wi = 0.;
         let ret = unsafe {
             sys::gsl_integration_glfixed_point(a, b, i, &mut xi, &mut wi, self.unwrap_shared())
         };
-        (::Value::from(ret), xi, wi)
+        result_handler!(ret, (xi, wi), "gsl_integration_glfixed_point")
     }
 
     /// This function applies the Gauss-Legendre integration rule contained in table self and
     /// returns the result.
     #[doc(alias = "gsl_integration_glfixed")]
-    pub fn glfixed<F: Fn(f64) -> f64>(&self, f: F, a: f64, b: f64) -> f64 {
+    pub fn glfixed<F: FnMut(f64) -> f64>(&self, mut f: F, a: f64, b: f64) -> f64 {
         let function = wrap_callback!(f, F);
-        unsafe { sys::gsl_integration_glfixed(&function, a, b, self.unwrap_shared()) }
+        let ret = unsafe { sys::gsl_integration_glfixed(&function, a, b, self.unwrap_shared()) };
+        ::error::resume_callback_panic();
+        ret
     }
 
+    /// This function fills `xi` and `wi` with all the Gauss-Legendre points and weights on the
+    /// interval [a,b]. Both slices must have a length of [`n`](#method.n).
     #[doc(alias = "gsl_integration_glfixed_point")]
-    pub fn glfixed_point(&self, a: f64, b: f64, xi: &mut [f64], wi: &mut [f64]) -> Value {
-        assert!(xi.len() == wi.len());
+    pub fn points(&self, a: f64, b: f64, xi: &mut [f64], wi: &mut [f64]) -> Result<(), Error> {
+        assert!(xi.len() == self.n() && wi.len() == self.n());
 
-        Value::from(unsafe {
-            sys::gsl_integration_glfixed_point(
-                a,
-                b,
-                xi.len() as _,
-                xi.as_mut_ptr(),
-                wi.as_mut_ptr(),
-                self.unwrap_shared(),
-            )
-        })
+        for (i, (x, w)) in xi.iter_mut().zip(wi.iter_mut()).enumerate() {
+            let (p, q) = self.point(a, b, i)?;
+            *x = p;
+            *w = q;
+        }
+        Ok(())
     }
 }
+
+#[test]
+fn fixed_quadrature() {
+    // Gauss-Legendre with n points is exact for polynomials of degree 2 n - 1.
+    let w = IntegrationFixedWorkspace::new(IntegrationFixedType::legendre(), 4, 0., 2., 0., 0.)
+        .unwrap();
+    assert_eq!(w.n(), 4);
+    let r = w.fixed(|x| x.powi(7)).unwrap();
+    assert!((r - 32.).abs() < 1e-12);
+    let nodes = w.nodes().unwrap();
+    let sum: f64 = w
+        .weights()
+        .unwrap()
+        .iter()
+        .zip(nodes)
+        .map(|(w, x)| w * x)
+        .sum();
+    assert!((sum - 2.).abs() < 1e-12);
+
+    let t = GLFixedTable::new(5).unwrap();
+    assert_eq!(t.n(), 5);
+    let mut xi = [0.; 5];
+    let mut wi = [0.; 5];
+    t.points(-1., 1., &mut xi, &mut wi).unwrap();
+    assert!(xi.windows(2).all(|p| p[0] < p[1]));
+    assert!((wi.iter().sum::<f64>() - 2.).abs() < 1e-12);
+    assert!((t.glfixed(|x| x * x, -1., 1.) - 2. / 3.).abs() < 1e-12);
+}
+
+#[cfg(feature = "v2_5")]
+#[test]
+fn romberg() {
+    let mut w = RombergWorkspace::new(20).unwrap();
+    let (r, n_evals) = w.romberg(f64::exp, 0., 1., 0., 1e-12).unwrap();
+    assert!((r - (1f64.exp() - 1.)).abs() < 1e-10);
+    assert!(n_evals > 0 && n_evals <= (1 << 20) + 1);
+}
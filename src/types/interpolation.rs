@@ -27,7 +27,8 @@ value of an index lookup. When the subsequent interpolation point falls in the s
 
 The functions described in the previous sections required the user to supply pointers to the x and y arrays on each call. The following
 functions are equivalent to the corresponding gsl_interp functions but maintain a copy of this data in the gsl_spline object. This removes
-the need to pass both xa and ya as arguments on each evaluation.
+the need to pass both xa and ya as arguments on each evaluation. [`OwnedInterp`] also keeps its own
+accelerator, so only the point to evaluate has to be given.
 
 ##References and Further Reading
 
@@ -37,10 +38,125 @@ C.W. Ueberhuber, Numerical Computation (Volume 1), Chapter 9 “Interpolation”
 D.M. Young, R.T. Gregory A Survey of Numerical Mathematics (Volume 1), Chapter 6.8, Dover (1988), ISBN 0-486-65691-8.
 !*/
 
-use crate::Value;
+use crate::{Error, Value};
 use ffi::FFI;
+use std::fmt;
+
+/// Why some data can't be interpolated, returned by [`Interp::with_data`] and
+/// [`Spline::with_data`] instead of letting GSL reject it (or silently misbehave).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum InterpDataError {
+    /// The `x` and `y` data don't have the same length.
+    LengthMismatch { x: usize, y: usize },
+    /// There are fewer points than the interpolation type requires (see
+    /// [`InterpType::min_size`]).
+    TooFewPoints { len: usize, min_size: usize },
+    /// `x[index]` isn't strictly greater than `x[index - 1]` (or is NaN).
+    NotIncreasing { index: usize },
+    /// The allocation or the initialization failed in GSL.
+    Gsl(Error),
+}
+
+impl InterpDataError {
+    /// Checks the data `(x, y)` for an interpolation of type `t`.
+    fn check(t: InterpType, x: &[f64], y: &[f64]) -> Result<(), InterpDataError> {
+        if x.len() != y.len() {
+            return Err(InterpDataError::LengthMismatch {
+                x: x.len(),
+                y: y.len(),
+            });
+        }
+        let min_size = t.min_size() as usize;
+        if x.len() < min_size {
+            return Err(InterpDataError::TooFewPoints {
+                len: x.len(),
+                min_size,
+            });
+        }
+        // The comparisons below are all false with NaN.
+        if let Some(index) = x.iter().position(|x| x.is_nan()) {
+            return Err(InterpDataError::NotIncreasing { index });
+        }
+        match x.windows(2).position(|w| w[0] >= w[1]) {
+            Some(i) => Err(InterpDataError::NotIncreasing { index: i + 1 }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for InterpDataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InterpDataError::LengthMismatch { x, y } => write!(
+                f,
+                "the x and y data have different lengths ({} and {})",
+                x, y
+            ),
+            InterpDataError::TooFewPoints { len, min_size } => write!(
+                f,
+                "{} points were given but the interpolation type requires at least {}",
+                len, min_size
+            ),
+            InterpDataError::NotIncreasing { index } => write!(
+                f,
+                "the x data must be strictly increasing, but x[{}] isn't greater than the \
+                 previous value",
+                index
+            ),
+            InterpDataError::Gsl(ref e) => e.fmt(f),
+        }
+    }
+}
+
+impl ::std::error::Error for InterpDataError {}
+
+impl From<Error> for InterpDataError {
+    fn from(e: Error) -> InterpDataError {
+        InterpDataError::Gsl(e)
+    }
+}
+
+impl From<InterpDataError> for Error {
+    fn from(e: InterpDataError) -> Error {
+        match e {
+            InterpDataError::LengthMismatch { .. } => {
+                Error::new(Value::BadLength, "gsl_interp_init")
+            }
+            InterpDataError::TooFewPoints { .. } | InterpDataError::NotIncreasing { .. } => {
+                Error::new(Value::Invalid, "gsl_interp_init")
+            }
+            InterpDataError::Gsl(e) => e,
+        }
+    }
+}
 
 /// Evaluation accelerator.
+///
+/// The accelerator is the only state modified when evaluating a [`Spline`]: the spline can be
+/// shared between threads as long as each of them has its own accelerator.
+///
+/// ```
+/// use rgsl::{InterpAccel, InterpType, Spline};
+///
+/// let mut spline = Spline::new(InterpType::cspline(), 4).unwrap();
+/// spline.init(&[0., 1., 2., 3.], &[0., 1., 8., 27.]).unwrap();
+/// std::thread::scope(|s| {
+///     for i in 0..4 {
+///         let spline = &spline;
+///         s.spawn(move || {
+///             let mut acc = InterpAccel::new();
+///             for j in 0..100 {
+///                 spline.eval(i as f64 * 0.75 + j as f64 / 200., &mut acc);
+///             }
+///             // The successive points were (almost) always in the same interval.
+///             assert!(acc.hit_count() > acc.miss_count());
+///         });
+///     }
+/// });
+/// ```
+///
+/// To reuse accelerators between tasks, put them in a
+/// [`SharedWorkspacePool`](crate::SharedWorkspacePool).
 #[derive(Clone)]
 pub struct InterpAccel(pub sys::gsl_interp_accel);
 
@@ -48,7 +164,6 @@ impl InterpAccel {
     /// This function returns a pointer to an accelerator object, which is a kind of iterator for
     /// interpolation lookups. It tracks the state of lookups, thus allowing for application of
     /// various acceleration strategies.
-    #[allow(clippy::new_without_default)]
     pub fn new() -> InterpAccel {
         InterpAccel(sys::gsl_interp_accel {
             cache: 0,
@@ -59,12 +174,28 @@ impl InterpAccel {
 
     /// This function reinitializes the accelerator object acc. It should be used when the cached
     /// information is no longer applicable-for example, when switching to a new dataset.
+    #[doc(alias = "gsl_interp_accel_reset")]
     pub fn reset(&mut self) {
         self.0.cache = 0;
         self.0.miss_count = 0;
         self.0.hit_count = 0;
     }
 
+    /// Returns the index of the interval found by the last lookup.
+    pub fn cache(&self) -> usize {
+        self.0.cache
+    }
+
+    /// Returns how many lookups found their point in the cached interval.
+    pub fn hit_count(&self) -> usize {
+        self.0.hit_count
+    }
+
+    /// Returns how many lookups had to search for the interval of their point.
+    pub fn miss_count(&self) -> usize {
+        self.0.miss_count
+    }
+
     /// This function performs a lookup action on the data array x_array of size size, using the
     /// given accelerator a. This is how lookups are performed during evaluation of an
     /// interpolation. The function returns an index i such that `x_array[i] <= x < x_array[i+1]`.
@@ -74,8 +205,16 @@ impl InterpAccel {
     }
 }
 
+impl Default for InterpAccel {
+    fn default() -> InterpAccel {
+        InterpAccel::new()
+    }
+}
+
 ffi_wrapper!(Interp, *mut sys::gsl_interp, gsl_interp_free);
 
+unsafe impl Send for Interp {}
+
 impl Interp {
     /// This function returns a pointer to a newly allocated interpolation object of type T for
     /// size data-points.
@@ -97,21 +236,60 @@ impl Interp {
         }
     }
 
+    /// Allocates an interpolation object of type `t` and initializes it for the data `(xa, ya)`
+    /// (see [`Interp::init`]). The data is checked first: `xa` and `ya` must have the same
+    /// length, at least [`InterpType::min_size`] points, and `xa` must be strictly increasing.
+    ///
+    /// ```
+    /// use rgsl::{interpolation, Interp, InterpDataError, InterpType};
+    ///
+    /// let (xa, ya) = ([0., 1., 2.], [0., 2., 6.]);
+    /// let interp = Interp::with_data(InterpType::linear(), &xa, &ya).unwrap();
+    /// assert_eq!(interpolation::eval_no_accel(&interp, &xa, &ya, 1.5), 4.);
+    ///
+    /// assert_eq!(
+    ///     Interp::with_data(InterpType::akima(), &xa, &ya).err(),
+    ///     Some(InterpDataError::TooFewPoints { len: 3, min_size: 5 }),
+    /// );
+    /// assert_eq!(
+    ///     Interp::with_data(InterpType::linear(), &[0., 2., 1.], &ya).err(),
+    ///     Some(InterpDataError::NotIncreasing { index: 2 }),
+    /// );
+    /// ```
+    #[doc(alias = "gsl_interp_alloc")]
+    #[doc(alias = "gsl_interp_init")]
+    pub fn with_data(t: InterpType, xa: &[f64], ya: &[f64]) -> Result<Interp, InterpDataError> {
+        InterpDataError::check(t, xa, ya)?;
+        let mut interp = Interp::new(t, xa.len())
+            .ok_or_else(|| Error::new(Value::NoMemory, "gsl_interp_alloc"))?;
+        interp.init(xa, ya)?;
+        Ok(interp)
+    }
+
     /// This function initializes the interpolation object interp for the data (xa,ya) where xa and
     /// ya are arrays of size size. The interpolation object (gsl_interp) does not save the data
     /// arrays xa and ya and only stores the static state computed from the data. The xa data array
     /// is always assumed to be strictly ordered, with increasing x values; the behavior for other
-    /// arrangements is not defined.
+    /// arrangements is not defined (use [`Interp::with_data`] to check it).
+    ///
+    /// Returns [`Value::BadLength`] if `xa` and `ya` have different lengths.
     #[doc(alias = "gsl_interp_init")]
-    pub fn init(&mut self, xa: &[f64], ya: &[f64]) -> Value {
-        Value::from(unsafe {
-            sys::gsl_interp_init(
-                self.unwrap_unique(),
-                xa.as_ptr(),
-                ya.as_ptr(),
-                xa.len() as _,
-            )
-        })
+    pub fn init(&mut self, xa: &[f64], ya: &[f64]) -> Result<(), Error> {
+        if xa.len() != ya.len() {
+            return Err(Error::new(Value::BadLength, "gsl_interp_init"));
+        }
+        result_handler!(
+            unsafe {
+                sys::gsl_interp_init(
+                    self.unwrap_unique(),
+                    xa.as_ptr(),
+                    ya.as_ptr(),
+                    xa.len() as _,
+                )
+            },
+            (),
+            "gsl_interp_init"
+        )
     }
 
     /// This function returns the name of the interpolation type used by interp. For example,
@@ -153,6 +331,9 @@ impl Interp {
 
 ffi_wrapper!(InterpType, *const sys::gsl_interp_type);
 
+unsafe impl Send for InterpType {}
+unsafe impl Sync for InterpType {}
+
 impl InterpType {
     /// This function returns the minimum number of points required by the interpolation object
     /// interp or interpolation type T. For example, Akima spline interpolation requires a minimum
@@ -162,6 +343,54 @@ impl InterpType {
         unsafe { sys::gsl_interp_type_min_size(self.unwrap_shared()) }
     }
 
+    // `gsl_interp_type` is opaque in the bindings, so the names are kept here (they are the ones
+    // returned by `gsl_interp_name`).
+    fn named_types() -> [(&'static str, InterpType); 7] {
+        [
+            ("linear", InterpType::linear()),
+            ("polynomial", InterpType::polynomial()),
+            ("cspline", InterpType::cspline()),
+            ("cspline-periodic", InterpType::cspline_periodic()),
+            ("akima", InterpType::akima()),
+            ("akima-periodic", InterpType::akima_periodic()),
+            ("steffen", InterpType::steffen()),
+        ]
+    }
+
+    /// Returns the GSL name of this interpolation type, like `"cspline-periodic"`.
+    #[doc(alias = "gsl_interp_name")]
+    pub fn name(&self) -> String {
+        InterpType::named_types()
+            .iter()
+            .find(|(_, t)| t.unwrap_shared() == self.unwrap_shared())
+            .map_or_else(String::new, |(name, _)| name.to_string())
+    }
+
+    /// Returns all the interpolation types.
+    pub fn types() -> Vec<InterpType> {
+        InterpType::named_types().iter().map(|&(_, t)| t).collect()
+    }
+
+    /// Returns the interpolation type called `name` (see [`InterpType::name`]), to choose it at
+    /// runtime. Underscores are accepted in place of the dashes, so the names of the constructors
+    /// (like `"cspline_periodic"`) work as well.
+    ///
+    /// ```
+    /// use rgsl::InterpType;
+    ///
+    /// let t = InterpType::from_name("akima_periodic").unwrap();
+    /// assert_eq!(t.name(), "akima-periodic");
+    /// assert_eq!(t.min_size(), 5);
+    /// assert!(InterpType::from_name("cubic").is_none());
+    /// ```
+    pub fn from_name(name: &str) -> Option<InterpType> {
+        let name = name.replace('_', "-");
+        InterpType::named_types()
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|&(_, t)| t)
+    }
+
     /// Linear interpolation. This interpolation method does not require any additional memory.
     pub fn linear() -> InterpType {
         ffi_wrap!(gsl_interp_linear)
@@ -202,6 +431,15 @@ impl InterpType {
     pub fn akima_periodic() -> InterpType {
         ffi_wrap!(gsl_interp_akima_periodic)
     }
+
+    /// Steffen's method guarantees the monotonicity of the interpolating function between the
+    /// given data points. Therefore, minima and maxima can only occur exactly at the data points,
+    /// and there can never be spurious oscillations between data points. The interpolated function
+    /// is piecewise cubic in each interval. The resulting curve and its first derivative are
+    /// guaranteed to be continuous, but the second derivative may be discontinuous.
+    pub fn steffen() -> InterpType {
+        ffi_wrap!(gsl_interp_steffen)
+    }
 }
 
 ffi_wrapper!(
@@ -211,6 +449,8 @@ ffi_wrapper!(
     "General interpolation object."
 );
 
+unsafe impl Send for Spline {}
+
 impl Spline {
     #[doc(alias = "gsl_spline_alloc")]
     pub fn new(t: InterpType, size: usize) -> Option<Spline> {
@@ -223,16 +463,35 @@ impl Spline {
         }
     }
 
+    /// Allocates a spline of type `t` and initializes it for the data `(xa, ya)`, after checking
+    /// it like [`Interp::with_data`].
+    #[doc(alias = "gsl_spline_alloc")]
     #[doc(alias = "gsl_spline_init")]
-    pub fn init(&mut self, xa: &[f64], ya: &[f64]) -> Value {
-        Value::from(unsafe {
-            sys::gsl_spline_init(
-                self.unwrap_unique(),
-                xa.as_ptr(),
-                ya.as_ptr(),
-                xa.len() as _,
-            )
-        })
+    pub fn with_data(t: InterpType, xa: &[f64], ya: &[f64]) -> Result<Spline, InterpDataError> {
+        InterpDataError::check(t, xa, ya)?;
+        let mut spline = Spline::new(t, xa.len())
+            .ok_or_else(|| Error::new(Value::NoMemory, "gsl_spline_alloc"))?;
+        spline.init(xa, ya)?;
+        Ok(spline)
+    }
+
+    #[doc(alias = "gsl_spline_init")]
+    pub fn init(&mut self, xa: &[f64], ya: &[f64]) -> Result<(), Error> {
+        if xa.len() != ya.len() {
+            return Err(Error::new(Value::BadLength, "gsl_spline_init"));
+        }
+        result_handler!(
+            unsafe {
+                sys::gsl_spline_init(
+                    self.unwrap_unique(),
+                    xa.as_ptr(),
+                    ya.as_ptr(),
+                    xa.len() as _,
+                )
+            },
+            (),
+            "gsl_spline_init"
+        )
     }
 
     #[doc(alias = "gsl_spline_name")]
@@ -258,12 +517,12 @@ impl Spline {
         unsafe { sys::gsl_spline_eval(self.unwrap_shared(), x, &mut acc.0) }
     }
 
-    /// Returns `(Value, y)`.
+    /// Returns `y`.
     #[doc(alias = "gsl_spline_eval_e")]
-    pub fn eval_e(&self, x: f64, acc: &mut InterpAccel) -> (Value, f64) {
+    pub fn eval_e(&self, x: f64, acc: &mut InterpAccel) -> Result<f64, Error> {
         let mut y = 0.;
         let ret = unsafe { sys::gsl_spline_eval_e(self.unwrap_shared(), x, &mut acc.0, &mut y) };
-        (Value::from(ret), y)
+        result_handler!(ret, y, "gsl_spline_eval_e")
     }
 
     #[doc(alias = "gsl_spline_eval_deriv")]
@@ -271,13 +530,13 @@ impl Spline {
         unsafe { sys::gsl_spline_eval_deriv(self.unwrap_shared(), x, &mut acc.0) }
     }
 
-    /// Returns `(Value, d)`.
+    /// Returns `d`.
     #[doc(alias = "gsl_spline_eval_deriv_e")]
-    pub fn eval_deriv_e(&self, x: f64, acc: &mut InterpAccel) -> (Value, f64) {
+    pub fn eval_deriv_e(&self, x: f64, acc: &mut InterpAccel) -> Result<f64, Error> {
         let mut d = 0.;
         let ret =
             unsafe { sys::gsl_spline_eval_deriv_e(self.unwrap_shared(), x, &mut acc.0, &mut d) };
-        (Value::from(ret), d)
+        result_handler!(ret, d, "gsl_spline_eval_deriv_e")
     }
 
     #[doc(alias = "gsl_spline_eval_deriv2")]
@@ -285,13 +544,13 @@ impl Spline {
         unsafe { sys::gsl_spline_eval_deriv2(self.unwrap_shared(), x, &mut acc.0) }
     }
 
-    /// Returns `(Value, d2)`.
+    /// Returns `d2`.
     #[doc(alias = "gsl_spline_eval_deriv2_e")]
-    pub fn eval_deriv2_e(&self, x: f64, acc: &mut InterpAccel) -> (Value, f64) {
+    pub fn eval_deriv2_e(&self, x: f64, acc: &mut InterpAccel) -> Result<f64, Error> {
         let mut d2 = 0.;
         let ret =
             unsafe { sys::gsl_spline_eval_deriv2_e(self.unwrap_shared(), x, &mut acc.0, &mut d2) };
-        (Value::from(ret), d2)
+        result_handler!(ret, d2, "gsl_spline_eval_deriv2_e")
     }
 
     #[doc(alias = "gsl_spline_eval_integ")]
@@ -299,13 +558,198 @@ impl Spline {
         unsafe { sys::gsl_spline_eval_integ(self.unwrap_shared(), a, b, &mut acc.0) }
     }
 
-    /// Returns `(Value, d2)`.
+    /// Returns `d2`.
     #[doc(alias = "gsl_spline_eval_integ_e")]
-    pub fn eval_integ_e(&self, a: f64, b: f64, acc: &mut InterpAccel) -> (Value, f64) {
+    pub fn eval_integ_e(&self, a: f64, b: f64, acc: &mut InterpAccel) -> Result<f64, Error> {
         let mut result = 0.;
         let ret = unsafe {
             sys::gsl_spline_eval_integ_e(self.unwrap_shared(), a, b, &mut acc.0, &mut result)
         };
-        (Value::from(ret), result)
+        result_handler!(ret, result, "gsl_spline_eval_integ_e")
+    }
+
+    /// Same as [`Spline::eval`], but without accelerator: the interval of `x` is found with a
+    /// binary search. Useful for one-off evaluations.
+    #[doc(alias = "gsl_spline_eval")]
+    pub fn eval_no_accel(&self, x: f64) -> f64 {
+        unsafe { sys::gsl_spline_eval(self.unwrap_shared(), x, ::std::ptr::null_mut()) }
+    }
+
+    /// Same as [`Spline::eval_e`], but without accelerator.
+    #[doc(alias = "gsl_spline_eval_e")]
+    pub fn eval_e_no_accel(&self, x: f64) -> Result<f64, Error> {
+        let mut y = 0.;
+        let ret = unsafe {
+            sys::gsl_spline_eval_e(self.unwrap_shared(), x, ::std::ptr::null_mut(), &mut y)
+        };
+        result_handler!(ret, y, "gsl_spline_eval_e")
+    }
+
+    /// Same as [`Spline::eval_deriv`], but without accelerator.
+    #[doc(alias = "gsl_spline_eval_deriv")]
+    pub fn eval_deriv_no_accel(&self, x: f64) -> f64 {
+        unsafe { sys::gsl_spline_eval_deriv(self.unwrap_shared(), x, ::std::ptr::null_mut()) }
+    }
+
+    /// Same as [`Spline::eval_deriv2`], but without accelerator.
+    #[doc(alias = "gsl_spline_eval_deriv2")]
+    pub fn eval_deriv2_no_accel(&self, x: f64) -> f64 {
+        unsafe { sys::gsl_spline_eval_deriv2(self.unwrap_shared(), x, ::std::ptr::null_mut()) }
+    }
+
+    /// Same as [`Spline::eval_integ`], but without accelerator.
+    #[doc(alias = "gsl_spline_eval_integ")]
+    pub fn eval_integ_no_accel(&self, a: f64, b: f64) -> f64 {
+        unsafe { sys::gsl_spline_eval_integ(self.unwrap_shared(), a, b, ::std::ptr::null_mut()) }
+    }
+
+    /// Evaluates the spline at every point of `xs` in parallel, with one accelerator per thread.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+    #[doc(alias = "gsl_spline_eval")]
+    pub fn eval_many_par(&self, xs: &[f64]) -> Vec<f64> {
+        use rayon::prelude::*;
+
+        xs.par_iter()
+            .map_init(InterpAccel::new, |acc, &x| self.eval(x, acc))
+            .collect()
+    }
+}
+
+// The `gsl_spline_eval*` functions only read the spline: everything they modify is in the
+// accelerator, which is given separately.
+unsafe impl Sync for Spline {}
+
+/// An interpolation owning its data and its accelerator, so it can be evaluated from `x` only.
+///
+/// ```
+/// use rgsl::{InterpType, OwnedInterp};
+///
+/// let mut interp = OwnedInterp::new(InterpType::linear(), &[0., 1., 2.], &[0., 2., 6.]).unwrap();
+/// assert_eq!(interp.eval(0.5), 1.);
+/// assert_eq!(interp.eval(1.5), 4.);
+/// assert_eq!(interp.eval_integ(0., 2.), 5.);
+/// ```
+pub struct OwnedInterp {
+    t: InterpType,
+    spline: Spline,
+    acc: InterpAccel,
+    x: Vec<f64>,
+    y: Vec<f64>,
+}
+
+impl OwnedInterp {
+    /// Creates an interpolation of type `t` of the data points `(x[i], y[i])`. The data is
+    /// checked like in [`Interp::with_data`]: use [`Spline::with_data`] to know why it was
+    /// rejected.
+    pub fn new(t: InterpType, x: &[f64], y: &[f64]) -> Result<OwnedInterp, Error> {
+        Ok(OwnedInterp {
+            t,
+            spline: Spline::with_data(t, x, y)?,
+            acc: InterpAccel::new(),
+            x: x.to_vec(),
+            y: y.to_vec(),
+        })
+    }
+
+    /// The `x` values of the data points.
+    pub fn x(&self) -> &[f64] {
+        &self.x
+    }
+
+    /// The `y` values of the data points.
+    pub fn y(&self) -> &[f64] {
+        &self.y
     }
+
+    /// The underlying spline.
+    pub fn spline(&self) -> &Spline {
+        &self.spline
+    }
+
+    /// Resets the accelerator, which caches the interval of the last evaluation.
+    pub fn reset_accel(&mut self) {
+        self.acc.reset();
+    }
+
+    #[doc(alias = "gsl_spline_eval")]
+    pub fn eval(&mut self, x: f64) -> f64 {
+        self.spline.eval(x, &mut self.acc)
+    }
+
+    #[doc(alias = "gsl_spline_eval_e")]
+    pub fn eval_e(&mut self, x: f64) -> Result<f64, Error> {
+        self.spline.eval_e(x, &mut self.acc)
+    }
+
+    #[doc(alias = "gsl_spline_eval_deriv")]
+    pub fn eval_deriv(&mut self, x: f64) -> f64 {
+        self.spline.eval_deriv(x, &mut self.acc)
+    }
+
+    #[doc(alias = "gsl_spline_eval_deriv_e")]
+    pub fn eval_deriv_e(&mut self, x: f64) -> Result<f64, Error> {
+        self.spline.eval_deriv_e(x, &mut self.acc)
+    }
+
+    #[doc(alias = "gsl_spline_eval_deriv2")]
+    pub fn eval_deriv2(&mut self, x: f64) -> f64 {
+        self.spline.eval_deriv2(x, &mut self.acc)
+    }
+
+    #[doc(alias = "gsl_spline_eval_deriv2_e")]
+    pub fn eval_deriv2_e(&mut self, x: f64) -> Result<f64, Error> {
+        self.spline.eval_deriv2_e(x, &mut self.acc)
+    }
+
+    /// Returns the integral of the interpolated function over `[a, b]`.
+    #[doc(alias = "gsl_spline_eval_integ")]
+    pub fn eval_integ(&mut self, a: f64, b: f64) -> f64 {
+        self.spline.eval_integ(a, b, &mut self.acc)
+    }
+
+    #[doc(alias = "gsl_spline_eval_integ_e")]
+    pub fn eval_integ_e(&mut self, a: f64, b: f64) -> Result<f64, Error> {
+        self.spline.eval_integ_e(a, b, &mut self.acc)
+    }
+}
+
+impl Clone for OwnedInterp {
+    fn clone(&self) -> OwnedInterp {
+        let mut other =
+            OwnedInterp::new(self.t, &self.x, &self.y).expect("failed to clone OwnedInterp");
+        other.acc = self.acc.clone();
+        other
+    }
+}
+
+#[test]
+fn interp_with_data() {
+    let xa = [0., 1., 2., 3., 4.];
+    let ya = [0., 1., 8., 27., 64.];
+    assert!(Interp::with_data(InterpType::akima(), &xa, &ya).is_ok());
+    assert_eq!(
+        Spline::with_data(InterpType::akima(), &xa[..4], &ya[..4]).err(),
+        Some(InterpDataError::TooFewPoints {
+            len: 4,
+            min_size: 5
+        })
+    );
+    assert_eq!(
+        Spline::with_data(InterpType::linear(), &xa, &ya[..3]).err(),
+        Some(InterpDataError::LengthMismatch { x: 5, y: 3 })
+    );
+    assert_eq!(
+        Spline::with_data(InterpType::linear(), &[0., 1., 1., 2.], &ya[..4]).err(),
+        Some(InterpDataError::NotIncreasing { index: 2 })
+    );
+    assert_eq!(
+        Spline::with_data(InterpType::linear(), &[f64::NAN, 1.], &ya[..2]).err(),
+        Some(InterpDataError::NotIncreasing { index: 0 })
+    );
+
+    let err = OwnedInterp::new(InterpType::linear(), &[1., 0.], &[0., 1.]).err();
+    assert_eq!(err.map(|e| e.value()), Some(Value::Invalid));
 }
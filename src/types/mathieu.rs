@@ -16,7 +16,7 @@ The radial Mathieu functions Mc^{(j)}_{r}(z,q), Ms^{(j)}_{r}(z,q) are the soluti
 For more information on the Mathieu functions, see Abramowitz and Stegun, Chapter 20.
 !*/
 
-use crate::Value;
+use crate::Error;
 use ffi::FFI;
 use std::mem::MaybeUninit;
 
@@ -24,6 +24,8 @@ ffi_wrapper!(MathieuWorkspace, *mut sys::gsl_sf_mathieu_workspace, gsl_sf_mathie
 "The Mathieu functions can be computed for a single order or for multiple orders, using array-based
 routines. The array-based routines require a preallocated workspace.");
 
+unsafe impl Send for MathieuWorkspace {}
+
 impl MathieuWorkspace {
     /// This function returns a workspace for the array versions of the Mathieu routines.
     /// The arguments n and qmax specify the maximum order and q-value of Mathieu functions which can be computed with this workspace.
@@ -40,20 +42,56 @@ impl MathieuWorkspace {
 
     /// This routine computes the characteristic values a_n(q), b_n(q) of the Mathieu functions ce_n(q,x) and se_n(q,x), respectively.
     #[doc(alias = "gsl_sf_mathieu_a_e")]
-    pub fn mathieu_a(n: i32, q: f64) -> (Value, ::types::Result) {
+    pub fn mathieu_a(n: i32, q: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_mathieu_a_e(n, q, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_mathieu_a_e"
+        )
     }
 
     /// This routine computes the characteristic values a_n(q), b_n(q) of the Mathieu functions ce_n(q,x) and se_n(q,x), respectively.
     #[doc(alias = "gsl_sf_mathieu_b_e")]
-    pub fn mathieu_b(n: i32, q: f64) -> (Value, ::types::Result) {
+    pub fn mathieu_b(n: i32, q: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_mathieu_b_e(n, q, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_mathieu_b_e"
+        )
+    }
+
+    /// This routine computes the Fourier coefficients of the even angular Mathieu function
+    /// ce_n(q,x), given its characteristic value aa = a_n(q) as returned by [`Self::mathieu_a`].
+    #[doc(alias = "gsl_sf_mathieu_a_coeff")]
+    pub fn mathieu_a_coeff(
+        n: i32,
+        q: f64,
+        aa: f64,
+    ) -> Result<[f64; sys::GSL_SF_MATHIEU_COEFF as usize], Error> {
+        let mut coeff = [0.; sys::GSL_SF_MATHIEU_COEFF as usize];
+        let ret = unsafe { sys::gsl_sf_mathieu_a_coeff(n, q, aa, coeff.as_mut_ptr()) };
+
+        result_handler!(ret, coeff, "gsl_sf_mathieu_a_coeff")
+    }
+
+    /// This routine computes the Fourier coefficients of the odd angular Mathieu function
+    /// se_n(q,x), given its characteristic value bb = b_n(q) as returned by [`Self::mathieu_b`].
+    #[doc(alias = "gsl_sf_mathieu_b_coeff")]
+    pub fn mathieu_b_coeff(
+        n: i32,
+        q: f64,
+        bb: f64,
+    ) -> Result<[f64; sys::GSL_SF_MATHIEU_COEFF as usize], Error> {
+        let mut coeff = [0.; sys::GSL_SF_MATHIEU_COEFF as usize];
+        let ret = unsafe { sys::gsl_sf_mathieu_b_coeff(n, q, bb, coeff.as_mut_ptr()) };
+
+        result_handler!(ret, coeff, "gsl_sf_mathieu_b_coeff")
     }
 
     /// This routine computes a series of Mathieu characteristic values a_n(q), b_n(q) for n from order_min to order_max inclusive, storing the results in the array result_array.
@@ -64,8 +102,9 @@ impl MathieuWorkspace {
         order_max: i32,
         q: f64,
         result_array: &mut [f64],
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        assert!(order_max >= order_min && result_array.len() > (order_max - order_min) as usize);
+        let ret = unsafe {
             sys::gsl_sf_mathieu_a_array(
                 order_min,
                 order_max,
@@ -73,7 +112,8 @@ impl MathieuWorkspace {
                 self.unwrap_unique(),
                 result_array.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_sf_mathieu_a_array")
     }
 
     /// This routine computes a series of Mathieu characteristic values a_n(q), b_n(q) for n from order_min to order_max inclusive, storing the results in the array result_array.
@@ -84,8 +124,9 @@ impl MathieuWorkspace {
         order_max: i32,
         q: f64,
         result_array: &mut [f64],
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        assert!(order_max >= order_min && result_array.len() > (order_max - order_min) as usize);
+        let ret = unsafe {
             sys::gsl_sf_mathieu_b_array(
                 order_min,
                 order_max,
@@ -93,25 +134,34 @@ impl MathieuWorkspace {
                 self.unwrap_unique(),
                 result_array.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_sf_mathieu_b_array")
     }
 
     /// This routine computes the angular Mathieu functions ce_n(q,x) and se_n(q,x), respectively.
     #[doc(alias = "gsl_sf_mathieu_ce_e")]
-    pub fn mathieu_ce(n: i32, q: f64, x: f64) -> (Value, ::types::Result) {
+    pub fn mathieu_ce(n: i32, q: f64, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_mathieu_ce_e(n, q, x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_mathieu_ce_e"
+        )
     }
 
     /// This routine computes the angular Mathieu functions ce_n(q,x) and se_n(q,x), respectively.
     #[doc(alias = "gsl_sf_mathieu_se_e")]
-    pub fn mathieu_se(n: i32, q: f64, x: f64) -> (Value, ::types::Result) {
+    pub fn mathieu_se(n: i32, q: f64, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_mathieu_se_e(n, q, x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_mathieu_se_e"
+        )
     }
 
     /// This routine computes a series of the angular Mathieu functions ce_n(q,x) and se_n(q,x) of order n from nmin to nmax inclusive, storing the results in the array result_array.
@@ -123,8 +173,9 @@ impl MathieuWorkspace {
         q: f64,
         x: f64,
         result_array: &mut [f64],
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        assert!(nmax >= nmin && result_array.len() > (nmax - nmin) as usize);
+        let ret = unsafe {
             sys::gsl_sf_mathieu_ce_array(
                 nmin,
                 nmax,
@@ -133,7 +184,8 @@ impl MathieuWorkspace {
                 self.unwrap_unique(),
                 result_array.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_sf_mathieu_ce_array")
     }
 
     /// This routine computes a series of the angular Mathieu functions ce_n(q,x) and se_n(q,x) of order n from nmin to nmax inclusive, storing the results in the array result_array.
@@ -145,8 +197,9 @@ impl MathieuWorkspace {
         q: f64,
         x: f64,
         result_array: &mut [f64],
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        assert!(nmax >= nmin && result_array.len() > (nmax - nmin) as usize);
+        let ret = unsafe {
             sys::gsl_sf_mathieu_se_array(
                 nmin,
                 nmax,
@@ -155,29 +208,38 @@ impl MathieuWorkspace {
                 self.unwrap_unique(),
                 result_array.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_sf_mathieu_se_array")
     }
 
     /// This routine computes the radial j-th kind Mathieu functions Mc_n^{(j)}(q,x) and Ms_n^{(j)}(q,x) of order n.
     ///
     /// The allowed values of j are 1 and 2. The functions for j = 3,4 can be computed as M_n^{(3)} = M_n^{(1)} + iM_n^{(2)} and M_n^{(4)} = M_n^{(1)} - iM_n^{(2)}, where M_n^{(j)} = Mc_n^{(j)} or Ms_n^{(j)}.
     #[doc(alias = "gsl_sf_mathieu_Mc_e")]
-    pub fn mathieu_Mc(j: i32, n: i32, q: f64, x: f64) -> (Value, ::types::Result) {
+    pub fn mathieu_Mc(j: i32, n: i32, q: f64, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_mathieu_Mc_e(j, n, q, x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_mathieu_Mc_e"
+        )
     }
 
     /// This routine computes the radial j-th kind Mathieu functions Mc_n^{(j)}(q,x) and Ms_n^{(j)}(q,x) of order n.
     ///
     /// The allowed values of j are 1 and 2. The functions for j = 3,4 can be computed as M_n^{(3)} = M_n^{(1)} + iM_n^{(2)} and M_n^{(4)} = M_n^{(1)} - iM_n^{(2)}, where M_n^{(j)} = Mc_n^{(j)} or Ms_n^{(j)}.
     #[doc(alias = "gsl_sf_mathieu_Ms_e")]
-    pub fn mathieu_Ms(j: i32, n: i32, q: f64, x: f64) -> (Value, ::types::Result) {
+    pub fn mathieu_Ms(j: i32, n: i32, q: f64, x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_mathieu_Ms_e(j, n, q, x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_mathieu_Ms_e"
+        )
     }
 
     /// This routine computes a series of the radial Mathieu functions of kind j, with order from nmin to nmax inclusive, storing the results in the array result_array.
@@ -190,8 +252,9 @@ impl MathieuWorkspace {
         q: f64,
         x: f64,
         result_array: &mut [f64],
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        assert!(nmax >= nmin && result_array.len() > (nmax - nmin) as usize);
+        let ret = unsafe {
             sys::gsl_sf_mathieu_Mc_array(
                 j,
                 nmin,
@@ -201,7 +264,8 @@ impl MathieuWorkspace {
                 self.unwrap_unique(),
                 result_array.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_sf_mathieu_Mc_array")
     }
 
     /// This routine computes a series of the radial Mathieu functions of kind j, with order from nmin to nmax inclusive, storing the results in the array result_array.
@@ -214,8 +278,9 @@ impl MathieuWorkspace {
         q: f64,
         x: f64,
         result_array: &mut [f64],
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        assert!(nmax >= nmin && result_array.len() > (nmax - nmin) as usize);
+        let ret = unsafe {
             sys::gsl_sf_mathieu_Ms_array(
                 j,
                 nmin,
@@ -225,6 +290,18 @@ impl MathieuWorkspace {
                 self.unwrap_unique(),
                 result_array.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_sf_mathieu_Ms_array")
+    }
+}
+
+#[test]
+fn mathieu_arrays() {
+    let mut w = MathieuWorkspace::new(5, 10.).unwrap();
+    let mut ce = [0.; 4];
+    w.mathieu_ce_array(0, 3, 2., 0.5, &mut ce).unwrap();
+    for (n, v) in ce.iter().enumerate() {
+        let expected = MathieuWorkspace::mathieu_ce(n as i32, 2., 0.5).unwrap().val;
+        assert!((v - expected).abs() < 1e-10);
     }
 }
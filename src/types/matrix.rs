@@ -51,12 +51,14 @@ B. Stroustrup, The C++ Programming Language (3rd Ed), Section 22.4 Vector Arithm
 !*/
 
 use crate::paste::paste;
-use crate::Value;
+use crate::{Error, Value};
 use ffi::{self, FFI};
 use std::fmt::{self, Debug, Formatter};
 use std::marker::PhantomData;
 use types::{VectorF32, VectorF64, VectorI32, VectorU32};
+use types::{VectorF32ConstView, VectorF64ConstView, VectorI32ConstView, VectorU32ConstView};
 use types::{VectorF32View, VectorF64View, VectorI32View, VectorU32View};
+use utilities::Preview;
 
 macro_rules! gsl_matrix {
     ($rust_name:ident, $name:ident, $rust_ty:ident, $vec_name:ident, $vec_c_name:ident) => (
@@ -129,161 +131,190 @@ impl $rust_name {
     /// This function copies the elements of the other matrix into the self matrix. The two matrices
     /// must have the same size.
     #[doc(alias = $name _memcpy)]
-    pub fn copy_from(&mut self, other: &$rust_name) -> Value {
-        Value::from(unsafe { sys::[<$name _memcpy>](self.unwrap_unique(), other.unwrap_shared()) })
+    pub fn copy_from(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _memcpy>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _memcpy>]))
     }
 
     /// This function copies the elements of the self matrix into the other matrix. The two matrices
     /// must have the same size.
     #[doc(alias = $name _memcpy)]
-    pub fn copy_to(&self, other: &mut $rust_name) -> Value {
-        Value::from(unsafe { sys::[<$name _memcpy>](other.unwrap_unique(), self.unwrap_shared()) })
+    pub fn copy_to(&self, other: &mut $rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _memcpy>](other.unwrap_unique(), self.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _memcpy>]))
     }
 
     /// This function exchanges the elements of the matrices self and other by copying. The two
     /// matrices must have the same size.
     #[doc(alias = $name _swap)]
-    pub fn swap(&mut self, other: &mut $rust_name) -> Value {
-        Value::from(unsafe { sys::[<$name _swap>](self.unwrap_unique(), other.unwrap_unique()) })
+    pub fn swap(&mut self, other: &mut $rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _swap>](self.unwrap_unique(), other.unwrap_unique()) };
+        result_handler!(ret, (), stringify!([<$name _swap>]))
     }
 
     /// This function copies the elements of the y-th row of the matrix into the returned vector.
     #[doc(alias = $name _get_row)]
-    pub fn get_row(&self, y: usize) -> Option<(Value, $vec_name)> {
+    pub fn get_row(&self, y: usize) -> Result<$vec_name, Error> {
         let tmp = unsafe { sys::[<$vec_c_name _alloc>](self.size2()) };
 
         if tmp.is_null() {
-            None
+            Err(Error::new(Value::NoMemory, stringify!([<$vec_c_name _alloc>])))
         } else {
             let ret = unsafe { sys::[<$name _get_row>](tmp, self.unwrap_shared(), y) };
 
-            Some((Value::from(ret), ffi::FFI::wrap(tmp)))
+            let out = ffi::FFI::wrap(tmp);
+
+            result_handler!(ret, out, stringify!([<$name _get_row>]))
         }
     }
 
     /// This function copies the elements of the x-th column of the matrix into the returned vector.
     #[doc(alias = $name _get_col)]
-    pub fn get_col(&self, x: usize) -> Option<(Value, $vec_name)> {
+    pub fn get_col(&self, x: usize) -> Result<$vec_name, Error> {
         let tmp = unsafe { sys::[<$vec_c_name _alloc>](self.size1()) };
 
         if tmp.is_null() {
-            None
+            Err(Error::new(Value::NoMemory, stringify!([<$vec_c_name _alloc>])))
         } else {
             let ret = unsafe { sys::[<$name _get_col>](tmp, self.unwrap_shared(), x) };
 
-            Some((Value::from(ret), ffi::FFI::wrap(tmp)))
+            let out = ffi::FFI::wrap(tmp);
+
+            result_handler!(ret, out, stringify!([<$name _get_col>]))
         }
     }
 
     /// This function copies the elements of the vector v into the y-th row of the matrix.
     /// The length of the vector must be the same as the length of the row.
     #[doc(alias = $name _set_row)]
-    pub fn set_row(&mut self, y: usize, v: &$vec_name) -> Value {
-        Value::from(unsafe { sys::[<$name _set_row>](self.unwrap_unique(), y, v.unwrap_shared()) })
+    pub fn set_row(&mut self, y: usize, v: &$vec_name) -> Result<(), Error> {
+        result_handler!(unsafe { sys::[<$name _set_row>](self.unwrap_unique(), y, v.unwrap_shared()) }, (), stringify!([<$name _set_row>]))
     }
 
     /// This function copies the elements of the vector v into the x-th column of the matrix.
     /// The length of the vector must be the same as the length of the column.
     #[doc(alias = $name _set_col)]
-    pub fn set_col(&mut self, x: usize, v: &$vec_name) -> Value {
-        Value::from(unsafe { sys::[<$name _set_col>](self.unwrap_unique(), x, v.unwrap_shared()) })
+    pub fn set_col(&mut self, x: usize, v: &$vec_name) -> Result<(), Error> {
+        result_handler!(unsafe { sys::[<$name _set_col>](self.unwrap_unique(), x, v.unwrap_shared()) }, (), stringify!([<$name _set_col>]))
     }
 
     /// This function exchanges the y1-th and y2-th rows of the matrix in-place.
     #[doc(alias = $name _swap_rows)]
-    pub fn swap_rows(&mut self, y1: usize, y2: usize) -> Value {
-        Value::from(unsafe { sys::[<$name _swap_rows>](self.unwrap_unique(), y1, y2) })
+    pub fn swap_rows(&mut self, y1: usize, y2: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _swap_rows>](self.unwrap_unique(), y1, y2) };
+        result_handler!(ret, (), stringify!([<$name _swap_rows>]))
     }
 
     /// This function exchanges the x1-th and x2-th columns of the matrix in-place.
     #[doc(alias = $name _swap_columns)]
-    pub fn swap_columns(&mut self, x1: usize, x2: usize) -> Value {
-        Value::from(unsafe { sys::[<$name _swap_columns>](self.unwrap_unique(), x1, x2) })
+    pub fn swap_columns(&mut self, x1: usize, x2: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _swap_columns>](self.unwrap_unique(), x1, x2) };
+        result_handler!(ret, (), stringify!([<$name _swap_columns>]))
     }
 
     /// This function exchanges the i-th row and j-th column of the matrix in-place.
     /// The matrix must be square for this operation to be possible.
     #[doc(alias = $name _swap_row_col)]
-    pub fn swap_row_col(&mut self, i: usize, j: usize) -> Value {
-        Value::from(unsafe { sys::[<$name _swap_rowcol>](self.unwrap_unique(), i, j) })
+    pub fn swap_row_col(&mut self, i: usize, j: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _swap_rowcol>](self.unwrap_unique(), i, j) };
+        result_handler!(ret, (), stringify!([<$name _swap_rowcol>]))
     }
 
     /// This function returns the transpose of the matrix by copying the elements into it.
     /// This function works for all matrices provided that the dimensions of the matrix dest match
     /// the transposed dimensions of the matrix.
     #[doc(alias = $name _transpose_memcpy)]
-    pub fn transpose_memcpy(&self) -> Option<(Value, $rust_name)> {
+    pub fn transpose_memcpy(&self) -> Result<$rust_name, Error> {
         let dest = unsafe { sys::[<$name _alloc>](self.size2(), self.size1()) };
 
         if dest.is_null() {
-            None
+            Err(Error::new(Value::NoMemory, stringify!([<$name _alloc>])))
         } else {
             let ret = unsafe { sys::[<$name _transpose_memcpy>](dest, self.unwrap_shared()) };
 
-            Some((Value::from(ret), $rust_name::wrap(dest)))
+            let out = $rust_name::wrap(dest);
+
+            result_handler!(ret, out, stringify!([<$name _transpose_memcpy>]))
         }
     }
 
     /// This function replaces the matrix m by its transpose by copying the elements of the matrix
     /// in-place. The matrix must be square for this operation to be possible.
     #[doc(alias = $name _transpose)]
-    pub fn transpose(&mut self) -> Value {
-        Value::from(unsafe { sys::[<$name _transpose>](self.unwrap_unique()) })
+    pub fn transpose(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _transpose>](self.unwrap_unique()) };
+        result_handler!(ret, (), stringify!([<$name _transpose>]))
     }
 
     /// This function adds the elements of the other matrix to the elements of the self matrix.
     /// The result self(i,j) <- self(i,j) + other(i,j) is stored in self and other remains
     /// unchanged. The two matrices must have the same dimensions.
     #[doc(alias = $name _add)]
-    pub fn add(&mut self, other: &$rust_name) -> Value {
-        Value::from(unsafe { sys::[<$name _add>](self.unwrap_unique(), other.unwrap_shared()) })
+    pub fn add(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _add>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _add>]))
     }
 
     /// This function subtracts the elements of the other matrix from the elements of the self
     /// matrix. The result self(i,j) <- self(i,j) - other(i,j) is stored in self and other remains
     /// unchanged. The two matrices must have the same dimensions.
     #[doc(alias = $name _sub)]
-    pub fn sub(&mut self, other: &$rust_name) -> Value {
-        Value::from(unsafe { sys::[<$name _sub>](self.unwrap_unique(), other.unwrap_shared()) })
+    pub fn sub(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _sub>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _sub>]))
     }
 
     /// This function multiplies the elements of the self matrix by the elements of the other
     /// matrix. The result self(i,j) <- self(i,j) * other(i,j) is stored in self and other remains
     /// unchanged. The two matrices must have the same dimensions.
     #[doc(alias = $name _mul_elements)]
-    pub fn mul_elements(&mut self, other: &$rust_name) -> Value {
-        Value::from(unsafe {
-            sys::[<$name _mul_elements>](self.unwrap_unique(), other.unwrap_shared())
-        })
+    pub fn mul_elements(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _mul_elements>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _mul_elements>]))
     }
 
     /// This function divides the elements of the self matrix by the elements of the other matrix.
     /// The result self(i,j) <- self(i,j) / other(i,j) is stored in self and other remains
     /// unchanged. The two matrices must have the same dimensions.
     #[doc(alias = $name _div_elements)]
-    pub fn div_elements(&mut self, other: &$rust_name) -> Value {
-        Value::from(unsafe {
-            sys::[<$name _div_elements>](self.unwrap_unique(), other.unwrap_shared())
-        })
+    pub fn div_elements(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _div_elements>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _div_elements>]))
     }
 
     /// This function multiplies the elements of the self matrix by the constant factor x. The
     /// result self(i,j) <- x self(i,j) is stored in self.
     #[doc(alias = $name _scale)]
-    pub fn scale(&mut self, x: f64) -> Value {
-        Value::from(unsafe { sys::[<$name _scale>](self.unwrap_unique(), x) })
+    pub fn scale(&mut self, x: f64) -> &mut $rust_name {
+        // It can't fail.
+        unsafe { sys::[<$name _scale>](self.unwrap_unique(), x) };
+        self
     }
 
     /// This function adds the constant value x to the elements of the self matrix. The result
     /// self(i,j) <- self(i,j) + x is stored in self.
     #[doc(alias = $name _add_constant)]
-    pub fn add_constant(&mut self, x: f64) -> Value {
-        Value::from(unsafe { sys::[<$name _add_constant>](self.unwrap_unique(), x) })
+    pub fn add_constant(&mut self, x: f64) -> &mut $rust_name {
+        // It can't fail.
+        unsafe { sys::[<$name _add_constant>](self.unwrap_unique(), x) };
+        self
     }
 
-    #[doc(alias = $name _add_constant)]
-    pub fn add_diagonal(&mut self, x: f64) -> Value {
-        Value::from(unsafe { sys::[<$name _add_constant>](self.unwrap_unique(), x) })
+    /// This function adds the constant value x to the elements of the diagonal of the self
+    /// matrix. The result self(i,i) <- self(i,i) + x is stored in self.
+    #[doc(alias = $name _add_diagonal)]
+    pub fn add_diagonal(&mut self, x: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _add_diagonal>](self.unwrap_unique(), x) };
+        result_handler!(ret, (), stringify!([<$name _add_diagonal>]))
+    }
+
+    /// This function returns the 1-norm of the self matrix, the maximum of the sums of the
+    /// absolute values of the elements of each column.
+    #[cfg(feature = "v2_7")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    #[doc(alias = $name _norm1)]
+    pub fn norm1(&self) -> $rust_ty {
+        unsafe { sys::[<$name _norm1>](self.unwrap_shared()) }
     }
 
     /// This function returns the maximum value in the self matrix.
@@ -429,6 +460,54 @@ impl $rust_name {
         [<$rust_name View>]::from_matrix(self, k1, k2, n1, n2)
     }
 
+    /// Same as [`submatrix`](Self::submatrix), but only borrows the matrix so the view can't
+    /// modify it.
+    #[doc(alias = $name _const_submatrix)]
+    pub fn const_submatrix<'a>(
+        &'a self,
+        k1: usize,
+        k2: usize,
+        n1: usize,
+        n2: usize,
+    ) -> [<$rust_name ConstView>]<'a> {
+        [<$rust_name ConstView>]::from_matrix(self, k1, k2, n1, n2)
+    }
+
+    /// Returns a read-only view of the `i`-th row of the matrix.
+    #[doc(alias = $name _const_row)]
+    pub fn const_row<'a>(&'a self, i: usize) -> [<$vec_name ConstView>]<'a> {
+        [<$vec_name ConstView>]::wrap(unsafe { sys::[<$name _const_row>](self.unwrap_shared(), i) })
+    }
+
+    /// Returns a read-only view of the `j`-th column of the matrix.
+    #[doc(alias = $name _const_column)]
+    pub fn const_column<'a>(&'a self, j: usize) -> [<$vec_name ConstView>]<'a> {
+        [<$vec_name ConstView>]::wrap(unsafe { sys::[<$name _const_column>](self.unwrap_shared(), j) })
+    }
+
+    /// Returns a read-only view of the diagonal of the matrix. The matrix doesn't have to be
+    /// square.
+    #[doc(alias = $name _const_diagonal)]
+    pub fn const_diagonal<'a>(&'a self) -> [<$vec_name ConstView>]<'a> {
+        [<$vec_name ConstView>]::wrap(unsafe { sys::[<$name _const_diagonal>](self.unwrap_shared()) })
+    }
+
+    /// Returns a read-only view of the `k`-th subdiagonal of the matrix.
+    #[doc(alias = $name _const_subdiagonal)]
+    pub fn const_subdiagonal<'a>(&'a self, k: usize) -> [<$vec_name ConstView>]<'a> {
+        [<$vec_name ConstView>]::wrap(unsafe {
+            sys::[<$name _const_subdiagonal>](self.unwrap_shared(), k)
+        })
+    }
+
+    /// Returns a read-only view of the `k`-th superdiagonal of the matrix.
+    #[doc(alias = $name _const_superdiagonal)]
+    pub fn const_superdiagonal<'a>(&'a self, k: usize) -> [<$vec_name ConstView>]<'a> {
+        [<$vec_name ConstView>]::wrap(unsafe {
+            sys::[<$name _const_superdiagonal>](self.unwrap_shared(), k)
+        })
+    }
+
     pub fn size1(&self) -> usize {
         if self.unwrap_shared().is_null() {
             0
@@ -445,13 +524,15 @@ impl $rust_name {
         }
     }
 
-    pub fn clone(&self) -> Option<Self> {
+    /// Returns a new matrix holding a copy of the elements of `self`, or `None` if the allocation
+    /// failed.
+    pub fn try_clone(&self) -> Option<Self> {
         if self.unwrap_shared().is_null() {
             None
         } else {
             match Self::new(self.size1(), self.size2()) {
                 Some(mut m) => {
-                    m.copy_from(self);
+                    m.copy_from(self).ok()?;
                     Some(m)
                 }
                 None => None,
@@ -459,12 +540,94 @@ impl $rust_name {
         }
     }
 
+    /// Writes the elements of the matrix to `writer` in the native binary format of the
+    /// architecture, so it can be read back with [`read_binary`](Self::read_binary) (or by a C
+    /// program using GSL) on the same architecture.
+    #[doc(alias = $name _fwrite)]
+    pub fn to_binary_writer<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        ::utilities::write_through_file(writer, stringify!([<$name _fwrite>]), |file| unsafe {
+            sys::[<$name _fwrite>](file, self.unwrap_shared())
+        })
+    }
+
+    /// Reads the elements of the matrix from `reader`, in the format written by
+    /// [`to_binary_writer`](Self::to_binary_writer). Exactly as many bytes as the matrix holds
+    /// are read.
+    #[doc(alias = $name _fread)]
+    pub fn read_binary<R: ::std::io::Read>(&mut self, reader: &mut R) -> ::std::io::Result<()> {
+        let len = self.size1() * self.size2() * ::std::mem::size_of::<$rust_ty>();
+        ::utilities::read_exact_through_file(
+            reader,
+            len,
+            stringify!([<$name _fread>]),
+            |file| unsafe { sys::[<$name _fread>](file, self.unwrap_unique()) },
+        )
+    }
+
+    /// Writes the elements of the matrix to `writer` as text, one per line in row-major order, with the printf
+    /// `format` (like `"%g"`).
+    ///
+    /// Returns an error of kind [`InvalidInput`](::std::io::ErrorKind::InvalidInput) if `format`
+    /// isn't a printf format for a single element.
+    #[doc(alias = $name _fprintf)]
+    pub fn to_text_writer<W: ::std::io::Write>(
+        &self,
+        writer: &mut W,
+        format: &str,
+    ) -> ::std::io::Result<()> {
+        let format = ::utilities::printf_format(format, stringify!($rust_ty))?;
+        ::utilities::write_through_file(writer, stringify!([<$name _fprintf>]), |file| unsafe {
+            sys::[<$name _fprintf>](file, self.unwrap_shared(), format.as_ptr())
+        })
+    }
+
+    /// Reads the elements of the matrix from `reader`, written as text separated by whitespace
+    /// (like [`to_text_writer`](Self::to_text_writer) does). `reader` is read until its end.
+    #[doc(alias = $name _fscanf)]
+    pub fn read_text<R: ::std::io::Read>(&mut self, reader: &mut R) -> ::std::io::Result<()> {
+        ::utilities::read_to_end_through_file(reader, stringify!([<$name _fscanf>]), |file| unsafe {
+            sys::[<$name _fscanf>](file, self.unwrap_unique())
+        })
+    }
+
+    /// Creates a `n1` x `n2` matrix and reads its elements from `reader` with
+    /// [`read_binary`](Self::read_binary).
+    pub fn from_binary_reader<R: ::std::io::Read>(
+        reader: &mut R,
+        n1: usize,
+        n2: usize,
+    ) -> ::std::io::Result<$rust_name> {
+        let mut ret = $rust_name::new(n1, n2).ok_or_else(|| {
+            ::std::io::Error::other(::Error::new(::Value::NoMemory, stringify!([<$name _calloc>])))
+        })?;
+        ret.read_binary(reader)?;
+        Ok(ret)
+    }
+
+    /// Creates a `n1` x `n2` matrix and reads its elements from `reader` with
+    /// [`read_text`](Self::read_text).
+    pub fn from_text_reader<R: ::std::io::Read>(
+        reader: &mut R,
+        n1: usize,
+        n2: usize,
+    ) -> ::std::io::Result<$rust_name> {
+        let mut ret = $rust_name::new(n1, n2).ok_or_else(|| {
+            ::std::io::Error::other(::Error::new(::Value::NoMemory, stringify!([<$name _calloc>])))
+        })?;
+        ret.read_text(reader)?;
+        Ok(ret)
+    }
+
     #[doc(hidden)]
     pub fn is_ptr_null(&self) -> bool {
         self.unwrap_shared().is_null()
     }
 }
 
+// Like the vectors, the non-owning matrices are only lent by shared reference or through
+// `ffi::lend_mut`, so an instance held by value owns its data.
+unsafe impl Send for $rust_name {}
+
 impl Drop for $rust_name {
     #[doc(alias = $name _free)]
     fn drop(&mut self) {
@@ -500,32 +663,37 @@ impl FFI<sys::$name> for $rust_name {
 }
 
 impl Debug for $rust_name {
-    #[allow(unused_must_use)]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let ptr = self.unwrap_shared();
         if ptr.is_null() {
             write!(f, "<null>")
         } else {
-            let size1 = self.size1();
-            let size2 = self.size2();
-            for y in 0..size1 {
-                write!(f, "[");
-                for x in 0..size2 {
-                    if x < size2 - 1 {
-                        write!(f, "{}, ", self.get(y, x));
-                    } else {
-                        write!(f, "{}", self.get(y, x));
-                    }
-                }
-                if y < size1 - 1 {
-                    write!(f, "]\n");
-                }
-            }
-            write!(f, "]")
+            let rows = Preview(self.size1(), |i| Preview(self.size2(), move |j| self.get(i, j)));
+            f.debug_struct(stringify!($rust_name))
+                .field("size1", &self.size1())
+                .field("size2", &self.size2())
+                .field("data", &rows)
+                .finish()
         }
     }
 }
 
+impl Clone for $rust_name {
+    /// Returns a new matrix holding a copy of the elements of `self`.
+    ///
+    /// Panics if the allocation fails: use [`try_clone`](Self::try_clone) to handle it.
+    fn clone(&self) -> Self {
+        self.try_clone().expect(concat!(stringify!($name), "_alloc failed"))
+    }
+}
+
+/// Two matrices are equal if they have the same dimensions and the same elements.
+impl PartialEq for $rust_name {
+    fn eq(&self, other: &$rust_name) -> bool {
+        self.size1() == other.size1() && self.size2() == other.size2() && self.equal(other)
+    }
+}
+
 pub struct [<$rust_name View>]<'a> {
     mat: sys::[<$name _view>],
     #[allow(dead_code)]
@@ -689,14 +857,57 @@ impl<'a> [<$rust_name View>]<'a> {
 
     pub fn matrix_mut<F: FnOnce(Option<&mut $rust_name>)>(&mut self, f: F) {
         let tmp = &mut self.mat.matrix;
-        let mut tmp_mat = $rust_name::soft_wrap(tmp as *mut _);
-        if tmp_mat.is_ptr_null() {
+        ::ffi::lend_mut(tmp as *mut _, |tmp_mat: &mut $rust_name| {
+            if tmp_mat.is_ptr_null() {
+                f(None)
+            } else {
+                f(Some(tmp_mat))
+            }
+        })
+    }
+} // end of impl block
+
+/// A read-only view of a matrix or of an array. It borrows its parent, so it can't outlive it.
+pub struct [<$rust_name ConstView>]<'a> {
+    mat: sys::[<$name _const_view>],
+    #[allow(dead_code)]
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> [<$rust_name ConstView>]<'a> {
+    /// Same as [`from_matrix`]([<$rust_name View>]::from_matrix), but for a read-only view.
+    #[doc(alias = $name _const_submatrix)]
+    pub fn from_matrix(m: &'a $rust_name, k1: usize, k2: usize, n1: usize, n2: usize) -> Self {
+        Self {
+            mat: unsafe { sys::[<$name _const_submatrix>](m.unwrap_shared(), k1, k2, n1, n2) },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Same as [`from_array`]([<$rust_name View>]::from_array), but for a read-only view.
+    #[doc(alias = $name _const_view_array)]
+    pub fn from_array(base: &'a [$rust_ty], n1: usize, n2: usize) -> Self {
+        assert!(
+            n1 * n2 <= base.len() as _,
+            "n1 * n2 cannot be longer than base"
+        );
+        Self {
+            mat: unsafe { sys::[<$name _const_view_array>](base.as_ptr(), n1, n2) },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Gives the view as a matrix to `f`, or `None` if the view is out of the bounds of its
+    /// parent.
+    pub fn matrix<F: FnOnce(Option<&$rust_name>)>(&self, f: F) {
+        let tmp = &self.mat.matrix;
+        if tmp.data.is_null() {
             f(None)
         } else {
-            f(Some(&mut tmp_mat))
+            f(Some(&$rust_name::soft_wrap(tmp as *const _ as usize as *mut _)))
         }
     }
-} // end of impl block
+}
 } // end of paste! block
 
     ); // end of the gsl_matrix macro
@@ -712,3 +923,105 @@ gsl_matrix!(
 gsl_matrix!(MatrixF64, gsl_matrix, f64, VectorF64, gsl_vector);
 gsl_matrix!(MatrixI32, gsl_matrix_int, i32, VectorI32, gsl_vector_int);
 gsl_matrix!(MatrixU32, gsl_matrix_uint, u32, VectorU32, gsl_vector_uint);
+
+macro_rules! float_matrix {
+    ($rust_name:ident, $rust_ty:ident) => {
+        impl $rust_name {
+            /// Returns `true` if `self` and `other` have the same dimensions and all their
+            /// elements differ by at most `tol`.
+            pub fn approx_eq(&self, other: &$rust_name, tol: $rust_ty) -> bool {
+                self.size1() == other.size1()
+                    && self.size2() == other.size2()
+                    && (0..self.size1()).all(|i| {
+                        (0..self.size2()).all(|j| (self.get(i, j) - other.get(i, j)).abs() <= tol)
+                    })
+            }
+        }
+    };
+}
+
+float_matrix!(MatrixF32, f32);
+float_matrix!(MatrixF64, f64);
+
+#[test]
+fn const_views() {
+    let mut m = MatrixF64::new(2, 3).unwrap();
+    for i in 0..2 {
+        for j in 0..3 {
+            m.set(i, j, (i * 3 + j) as f64);
+        }
+    }
+    let mut column = Vec::new();
+    m.const_column(1).vector(|v| {
+        let v = v.unwrap();
+        column.extend((0..v.len()).map(|i| v.get(i)));
+    });
+    assert_eq!(column, [1., 4.]);
+    m.const_diagonal()
+        .vector(|v| assert_eq!(v.unwrap().get(1), 4.));
+    m.const_submatrix(1, 1, 1, 2)
+        .matrix(|s| assert_eq!(s.unwrap().get(0, 1), 5.));
+
+    let v = VectorF64::from_slice(&[1., 2., 3., 4., 5.]).unwrap();
+    v.const_subvector_with_stride(1, 2, 2)
+        .vector(|s| assert_eq!(s.unwrap().get(1), 4.));
+}
+
+#[test]
+fn binary_and_text_io() {
+    let mut m = MatrixF64::new(2, 2).unwrap();
+    m.set(0, 0, 1.);
+    m.set(0, 1, 2.5);
+    m.set(1, 0, -3.);
+    m.set(1, 1, 4.);
+
+    let mut bytes = Vec::new();
+    m.to_binary_writer(&mut bytes).unwrap();
+    assert_eq!(bytes.len(), 4 * 8);
+    let copy = MatrixF64::from_binary_reader(&mut &bytes[..], 2, 2).unwrap();
+    assert!(copy.equal(&m));
+
+    let mut text = Vec::new();
+    m.to_text_writer(&mut text, "%g").unwrap();
+    assert_eq!(text, b"1\n2.5\n-3\n4\n");
+    let copy = MatrixF64::from_text_reader(&mut &text[..], 2, 2).unwrap();
+    assert!(copy.equal(&m));
+    assert!(m.to_text_writer(&mut text, "%s").is_err());
+
+    let v = VectorI32::from_text_reader(&mut &b"1 2\n3"[..], 3).unwrap();
+    let mut text = Vec::new();
+    v.to_text_writer(&mut text, "%d").unwrap();
+    assert_eq!(text, b"1\n2\n3\n");
+}
+
+#[test]
+fn clone_eq_debug() {
+    let v = VectorF64::from_slice(&[1., 2., 3.]).unwrap();
+    let mut w = v.clone();
+    assert_eq!(v, w);
+    w.set(0, 1. + 1e-12);
+    assert_ne!(v, w);
+    assert!(v.approx_eq(&w, 1e-9));
+    assert!(!v.approx_eq(&VectorF64::from_slice(&[1., 2.]).unwrap(), 1.));
+    assert_eq!(
+        format!("{:?}", v),
+        "VectorF64 { len: 3, data: [1.0, 2.0, 3.0] }"
+    );
+
+    let mut m = MatrixF64::new(2, 10).unwrap();
+    m.set_all(1.);
+    let n = m.clone();
+    assert_eq!(m, n);
+    assert_ne!(m, MatrixF64::new(10, 2).unwrap());
+    assert!(m.approx_eq(&n, 0.));
+    let mut k = MatrixI32::new(1, 2).unwrap();
+    k.set(0, 1, 2);
+    k.set(0, 0, 1);
+    assert_eq!(
+        format!("{:?}", k),
+        "MatrixI32 { size1: 1, size2: 2, data: [[1, 2]] }"
+    );
+    assert!(
+        format!("{:?}", m).ends_with("1.0, ...], [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, ...]] }")
+    );
+}
@@ -3,21 +3,60 @@
 //
 
 use crate::paste::paste;
-use crate::Value;
+use crate::{Error, Value};
 use ffi::FFI;
 use std::fmt::{self, Debug, Formatter};
+use std::marker::PhantomData;
+use types::complex::CFFI;
+use utilities::Preview;
 
 macro_rules! gsl_matrix_complex {
     ($rust_name:ident, $name:ident, $complex:ident, $complex_c:ident) => (
 paste! {
 
-use types::{$complex, [<Vector $complex>], [<Vector $complex View>]};
+use types::{$complex, [<Vector $complex>], [<Vector $complex View>], [<Vector $complex ConstView>]};
 
-ffi_wrapper!(
-    $rust_name,
-    *mut sys::$name,
-    [<$name _free>]
-);
+pub struct $rust_name {
+    mat: *mut sys::$name,
+    can_free: bool,
+}
+
+// Same as for the real matrices: the non-owning instances are never held by value.
+unsafe impl Send for $rust_name {}
+
+impl Drop for $rust_name {
+    #[doc(alias = $name _free)]
+    fn drop(&mut self) {
+        if self.can_free {
+            unsafe { sys::[<$name _free>](self.mat) };
+            self.mat = ::std::ptr::null_mut();
+        }
+    }
+}
+
+impl FFI<sys::$name> for $rust_name {
+    fn wrap(mat: *mut sys::$name) -> Self {
+        Self {
+            mat,
+            can_free: true,
+        }
+    }
+
+    fn soft_wrap(mat: *mut sys::$name) -> Self {
+        Self {
+            mat,
+            can_free: false,
+        }
+    }
+
+    fn unwrap_shared(&self) -> *const sys::$name {
+        self.mat as *const _
+    }
+
+    fn unwrap_unique(&mut self) -> *mut sys::$name {
+        self.mat
+    }
+}
 
 impl $rust_name {
     /// Creates a new MatrixF64.
@@ -46,7 +85,7 @@ impl $rust_name {
     /// If y or x lie outside the allowed range of 0 to n1-1 and 0 to n2-1 then the error handler is
     /// invoked and 0 is returned.
     pub fn get(&self, y: usize, x: usize) -> $complex {
-        unsafe { ::std::mem::transmute(sys::[<$name _get>](self.unwrap_shared(), y, x)) }
+        unsafe { $complex::wrap(sys::[<$name _get>](self.unwrap_shared(), y, x)) }
     }
 
     /// This function sets the value of the (i,j)-th element of the matrix to value.
@@ -54,14 +93,14 @@ impl $rust_name {
     /// is invoked.
     pub fn set(&mut self, y: usize, x: usize, value: &$complex) -> &Self {
         unsafe {
-            sys::[<$name _set>](self.unwrap_unique(), y, x, ::std::mem::transmute(*value))
+            sys::[<$name _set>](self.unwrap_unique(), y, x, value.unwrap())
         };
         self
     }
 
     /// This function sets all the elements of the matrix to the value x.
     pub fn set_all(&mut self, x: &$complex) -> &Self {
-        unsafe { sys::[<$name _set_all>](self.unwrap_unique(), ::std::mem::transmute(*x)) };
+        unsafe { sys::[<$name _set_all>](self.unwrap_unique(), x.unwrap()) };
         self
     }
 
@@ -81,158 +120,183 @@ impl $rust_name {
 
     /// This function copies the elements of the other matrix into the self matrix. The two matrices
     /// must have the same size.
-    pub fn copy_from(&mut self, other: &$rust_name) -> Value {
-        Value::from(unsafe {
-            sys::[<$name _memcpy>](self.unwrap_unique(), other.unwrap_shared())
-        })
+    pub fn copy_from(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _memcpy>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _memcpy>]))
     }
 
     /// This function copies the elements of the self matrix into the other matrix. The two matrices
     /// must have the same size.
-    pub fn copy_to(&self, other: &mut $rust_name) -> Value {
-        Value::from(unsafe {
-            sys::[<$name _memcpy>](other.unwrap_unique(), self.unwrap_shared())
-        })
+    pub fn copy_to(&self, other: &mut $rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _memcpy>](other.unwrap_unique(), self.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _memcpy>]))
     }
 
     /// This function exchanges the elements of the matrices self and other by copying. The two
     /// matrices must have the same size.
-    pub fn swap(&mut self, other: &mut $rust_name) -> Value {
-        Value::from(unsafe {
-            sys::[<$name _swap>](self.unwrap_unique(), other.unwrap_unique())
-        })
+    pub fn swap(&mut self, other: &mut $rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _swap>](self.unwrap_unique(), other.unwrap_unique()) };
+        result_handler!(ret, (), stringify!([<$name _swap>]))
     }
 
     /// This function copies the elements of the y-th row of the matrix into the returned vector.
-    pub fn get_row(&self, y: usize) -> Option<(Value, [<Vector $complex>])> {
+    pub fn get_row(&self, y: usize) -> Result<[<Vector $complex>], Error> {
         let tmp = unsafe { sys::[<$complex_c _alloc>](self.size2()) };
 
         if tmp.is_null() {
-            None
+            Err(Error::new(Value::NoMemory, stringify!([<$complex_c _alloc>])))
         } else {
             let ret = unsafe { sys::[<$name _get_row>](tmp, self.unwrap_shared(), y) };
 
-            Some((Value::from(ret), FFI::wrap(tmp)))
+            let out = FFI::wrap(tmp);
+
+            result_handler!(ret, out, stringify!([<$name _get_row>]))
         }
     }
 
     /// This function copies the elements of the x-th column of the matrix into the returned vector.
-    pub fn get_col(&self, x: usize) -> Option<(Value, [<Vector $complex>])> {
+    pub fn get_col(&self, x: usize) -> Result<[<Vector $complex>], Error> {
         let tmp = unsafe { sys::[<$complex_c _alloc>](self.size1()) };
 
         if tmp.is_null() {
-            None
+            Err(Error::new(Value::NoMemory, stringify!([<$complex_c _alloc>])))
         } else {
             let ret = unsafe { sys::[<$name _get_col>](tmp, self.unwrap_shared(), x) };
 
-            Some((Value::from(ret), FFI::wrap(tmp)))
+            let out = FFI::wrap(tmp);
+
+            result_handler!(ret, out, stringify!([<$name _get_col>]))
         }
     }
 
     /// This function copies the elements of the vector v into the y-th row of the matrix.
     /// The length of the vector must be the same as the length of the row.
-    pub fn set_row(&mut self, y: usize, v: &[<Vector $complex>]) -> Value {
-        Value::from(unsafe {
+    pub fn set_row(&mut self, y: usize, v: &[<Vector $complex>]) -> Result<(), Error> {
+        result_handler!(unsafe {
             sys::[<$name _set_row>](self.unwrap_unique(), y, v.unwrap_shared())
-        })
+        }, (), stringify!([<$name _set_row>]))
     }
 
     /// This function copies the elements of the vector v into the x-th column of the matrix.
     /// The length of the vector must be the same as the length of the column.
-    pub fn set_col(&mut self, x: usize, v: &[<Vector $complex>]) -> Value {
-        Value::from(unsafe {
+    pub fn set_col(&mut self, x: usize, v: &[<Vector $complex>]) -> Result<(), Error> {
+        result_handler!(unsafe {
             sys::[<$name _set_col>](self.unwrap_unique(), x, v.unwrap_shared())
-        })
+        }, (), stringify!([<$name _set_col>]))
     }
 
     /// This function exchanges the y1-th and y2-th rows of the matrix in-place.
-    pub fn swap_rows(&mut self, y1: usize, y2: usize) -> Value {
-        Value::from(unsafe { sys::[<$name _swap_rows>](self.unwrap_unique(), y1, y2) })
+    pub fn swap_rows(&mut self, y1: usize, y2: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _swap_rows>](self.unwrap_unique(), y1, y2) };
+        result_handler!(ret, (), stringify!([<$name _swap_rows>]))
     }
 
     /// This function exchanges the x1-th and x2-th columns of the matrix in-place.
-    pub fn swap_columns(&mut self, x1: usize, x2: usize) -> Value {
-        Value::from(unsafe { sys::[<$name _swap_columns>](self.unwrap_unique(), x1, x2) })
+    pub fn swap_columns(&mut self, x1: usize, x2: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _swap_columns>](self.unwrap_unique(), x1, x2) };
+        result_handler!(ret, (), stringify!([<$name _swap_columns>]))
     }
 
     /// This function exchanges the i-th row and j-th column of the matrix in-place. The matrix must
     /// be square for this operation to be possible.
-    pub fn swap_row_col(&mut self, i: usize, j: usize) -> Value {
-        Value::from(unsafe { sys::[<$name _swap_rowcol>](self.unwrap_unique(), i, j) })
+    pub fn swap_row_col(&mut self, i: usize, j: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _swap_rowcol>](self.unwrap_unique(), i, j) };
+        result_handler!(ret, (), stringify!([<$name _swap_rowcol>]))
     }
 
     /// This function returns the transpose of the matrix by copying the elements into it.
     /// This function works for all matrices provided that the dimensions of the matrix dest match
     /// the transposed dimensions of the matrix.
-    pub fn transpose_memcpy(&self) -> Option<(Value, $rust_name)> {
+    pub fn transpose_memcpy(&self) -> Result<$rust_name, Error> {
         let dest = unsafe { sys::[<$name _alloc>](self.size2(), self.size1()) };
 
         if dest.is_null() {
-            None
+            Err(Error::new(Value::NoMemory, stringify!([<$name _alloc>])))
         } else {
             let ret =
                 unsafe { sys::[<$name _transpose_memcpy>](dest, self.unwrap_shared()) };
 
-            Some((Value::from(ret), Self::wrap(dest)))
+            let out = Self::wrap(dest);
+            result_handler!(ret, out, stringify!([<$name _transpose_memcpy>]))
         }
     }
 
     /// This function replaces the matrix m by its transpose by copying the elements of the matrix
     /// in-place. The matrix must be square for this operation to be possible.
-    pub fn transpose(&mut self) -> Value {
-        Value::from(unsafe { sys::[<$name _transpose>](self.unwrap_unique()) })
+    pub fn transpose(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _transpose>](self.unwrap_unique()) };
+        result_handler!(ret, (), stringify!([<$name _transpose>]))
     }
 
     /// This function adds the elements of the other matrix to the elements of the `self` matrix.
     /// The result self(i,j) <- self(i,j) + other(i,j) is stored in `self` and other remains
     /// unchanged. The two matrices must have the same dimensions.
-    pub fn add(&mut self, other: &$rust_name) -> Value {
-        Value::from(unsafe {
-            sys::[<$name _add>](self.unwrap_unique(), other.unwrap_shared())
-        })
+    pub fn add(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _add>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _add>]))
     }
 
     /// This function subtracts the elements of the other matrix from the elements of the `self`
     /// matrix. The result self(i,j) <- self(i,j) - other(i,j) is stored in `self` and other remains
     /// unchanged. The two matrices must have the same dimensions.
-    pub fn sub(&mut self, other: &$rust_name) -> Value {
-        Value::from(unsafe {
-            sys::[<$name _sub>](self.unwrap_unique(), other.unwrap_shared())
-        })
+    pub fn sub(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _sub>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _sub>]))
     }
 
     /// This function multiplies the elements of the self matrix by the elements of the other
     /// matrix. The result self(i,j) <- self(i,j) * other(i,j) is stored in self and other remains
     /// unchanged. The two matrices must have the same dimensions.
-    pub fn mul_elements(&mut self, other: &$rust_name) -> Value {
-        Value::from(unsafe {
-            sys::[<$name _mul_elements>](self.unwrap_unique(), other.unwrap_shared())
-        })
+    pub fn mul_elements(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _mul_elements>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _mul_elements>]))
     }
 
     /// This function divides the elements of the self matrix by the elements of the other matrix.
     /// The result self(i,j) <- self(i,j) / other(i,j) is stored in self and other remains
     /// unchanged. The two matrices must have the same dimensions.
-    pub fn div_elements(&mut self, other: &$rust_name) -> Value {
-        Value::from(unsafe {
-            sys::[<$name _div_elements>](self.unwrap_unique(), other.unwrap_shared())
-        })
+    pub fn div_elements(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _div_elements>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _div_elements>]))
     }
 
     /// This function multiplies the elements of the self matrix by the constant factor x. The
     /// result self(i,j) <- x self(i,j) is stored in self.
-    pub fn scale(&mut self, x: &$complex) -> Value {
-        Value::from(unsafe {
-            sys::[<$name _scale>](self.unwrap_unique(), ::std::mem::transmute(*x))
-        })
+    pub fn scale(&mut self, x: &$complex) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _scale>](self.unwrap_unique(), x.unwrap()) };
+        result_handler!(ret, (), stringify!([<$name _scale>]))
     }
 
     /// This function adds the constant value x to the elements of the self matrix. The result
     /// self(i,j) <- self(i,j) + x is stored in self.
-    pub fn add_constant(&mut self, x: &$complex) -> Value {
-        Value::from(unsafe {
-            sys::[<$name _add_constant>](self.unwrap_unique(), ::std::mem::transmute(*x))
-        })
+    pub fn add_constant(&mut self, x: &$complex) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _add_constant>](self.unwrap_unique(), x.unwrap()) };
+        result_handler!(ret, (), stringify!([<$name _add_constant>]))
+    }
+
+    /// This function adds the constant value x to the diagonal elements of the self matrix. The
+    /// matrix doesn't have to be square.
+    #[doc(alias = $name _add_diagonal)]
+    pub fn add_diagonal(&mut self, x: &$complex) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _add_diagonal>](self.unwrap_unique(), x.unwrap()) };
+        result_handler!(ret, (), stringify!([<$name _add_diagonal>]))
+    }
+
+    /// Replaces every element by its complex conjugate.
+    pub fn conjugate(&mut self) {
+        for i in 0..self.size1() {
+            for j in 0..self.size2() {
+                let z = self.get(i, j).conjugate();
+                self.set(i, j, &z);
+            }
+        }
+    }
+
+    /// Returns the conjugate transpose (or Hermitian adjoint) of the matrix.
+    pub fn adjoint(&self) -> Result<$rust_name, Error> {
+        let mut out = self.transpose_memcpy()?;
+        out.conjugate();
+        Ok(out)
     }
 
     /// This function returns true if all the elements of the self matrix are stricly zero.
@@ -288,6 +352,49 @@ impl $rust_name {
         [<Vector $complex View>]::wrap(unsafe { sys::[<$name _subcolumn>](self.unwrap_unique(), i, offset, n) }, f)
     }
 
+    #[doc(alias = $name _submatrix)]
+    pub fn submatrix(
+        &mut self,
+        k1: usize,
+        k2: usize,
+        n1: usize,
+        n2: usize,
+    ) -> [<$rust_name View>]<'_> {
+        [<$rust_name View>]::from_matrix(self, k1, k2, n1, n2)
+    }
+
+    /// Same as [`submatrix`](Self::submatrix), but only borrows the matrix so the view can't
+    /// modify it.
+    #[doc(alias = $name _const_submatrix)]
+    pub fn const_submatrix(
+        &self,
+        k1: usize,
+        k2: usize,
+        n1: usize,
+        n2: usize,
+    ) -> [<$rust_name ConstView>]<'_> {
+        [<$rust_name ConstView>]::from_matrix(self, k1, k2, n1, n2)
+    }
+
+    /// Returns a read-only view of the `i`-th row of the matrix.
+    #[doc(alias = $name _const_row)]
+    pub fn const_row(&self, i: usize) -> [<Vector $complex ConstView>]<'_> {
+        [<Vector $complex ConstView>]::wrap(unsafe { sys::[<$name _const_row>](self.unwrap_shared(), i) })
+    }
+
+    /// Returns a read-only view of the `j`-th column of the matrix.
+    #[doc(alias = $name _const_column)]
+    pub fn const_column(&self, j: usize) -> [<Vector $complex ConstView>]<'_> {
+        [<Vector $complex ConstView>]::wrap(unsafe { sys::[<$name _const_column>](self.unwrap_shared(), j) })
+    }
+
+    /// Returns a read-only view of the diagonal of the matrix. The matrix doesn't have to be
+    /// square.
+    #[doc(alias = $name _const_diagonal)]
+    pub fn const_diagonal(&self) -> [<Vector $complex ConstView>]<'_> {
+        [<Vector $complex ConstView>]::wrap(unsafe { sys::[<$name _const_diagonal>](self.unwrap_shared()) })
+    }
+
     pub fn size1(&self) -> usize {
         if self.unwrap_shared().is_null() {
             0
@@ -304,19 +411,26 @@ impl $rust_name {
         }
     }
 
-    pub fn clone(&self) -> Option<Self> {
+    /// Returns a new matrix holding a copy of the elements of `self`, or `None` if the allocation
+    /// failed.
+    pub fn try_clone(&self) -> Option<Self> {
         if self.unwrap_shared().is_null() {
             None
         } else {
             match Self::new(self.size1(), self.size2()) {
                 Some(mut m) => {
-                    m.copy_from(self);
+                    m.copy_from(self).ok()?;
                     Some(m)
                 }
                 None => None,
             }
         }
     }
+
+    #[doc(hidden)]
+    pub fn is_ptr_null(&self) -> bool {
+        self.unwrap_shared().is_null()
+    }
 }
 
 impl Debug for $rust_name {
@@ -324,22 +438,156 @@ impl Debug for $rust_name {
         if self.unwrap_shared().is_null() {
             write!(f, "<null>")
         } else {
-            let size1 = self.size1();
-            let size2 = self.size2();
-            for y in 0..size1 {
-                write!(f, "[")?;
-                for x in 0..size2 {
-                    if x < size2 - 1 {
-                        write!(f, "{:?}, ", self.get(y, x))?;
-                    } else {
-                        write!(f, "{:?}", self.get(y, x))?;
-                    }
-                }
-                if y < size1 - 1 {
-                    write!(f, "]\n")?;
-                }
-            }
-            write!(f, "]")
+            let rows = Preview(self.size1(), |i| Preview(self.size2(), move |j| self.get(i, j)));
+            f.debug_struct(stringify!($rust_name))
+                .field("size1", &self.size1())
+                .field("size2", &self.size2())
+                .field("data", &rows)
+                .finish()
+        }
+    }
+}
+
+impl Clone for $rust_name {
+    /// Returns a new matrix holding a copy of the elements of `self`.
+    ///
+    /// Panics if the allocation fails: use [`try_clone`](Self::try_clone) to handle it.
+    fn clone(&self) -> Self {
+        self.try_clone().expect(concat!(stringify!($name), "_alloc failed"))
+    }
+}
+
+/// Two matrices are equal if they have the same dimensions and the same elements.
+impl PartialEq for $rust_name {
+    fn eq(&self, other: &$rust_name) -> bool {
+        self.size1() == other.size1() && self.size2() == other.size2() && self.equal(other)
+    }
+}
+
+pub struct [<$rust_name View>]<'a> {
+    mat: sys::[<$name _view>],
+    #[allow(dead_code)]
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> [<$rust_name View>]<'a> {
+    /// Returns a matrix view of the n1 x n2 submatrix of `m` whose upper-left element is the
+    /// element (k1,k2) of `m`. The view is null if it overruns the end of the matrix.
+    #[doc(alias = $name _submatrix)]
+    pub fn from_matrix(
+        m: &'a mut $rust_name,
+        k1: usize,
+        k2: usize,
+        n1: usize,
+        n2: usize,
+    ) -> Self {
+        Self {
+            mat: unsafe { sys::[<$name _submatrix>](m.unwrap_unique(), k1, k2, n1, n2) },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns a matrix view of the array `base`, stored in row-major order. The matrix has n1
+    /// rows and n2 columns.
+    #[doc(alias = $name _view_array)]
+    pub fn from_array(base: &'a mut [$complex], n1: usize, n2: usize) -> Self {
+        assert!(
+            n1 * n2 <= base.len(),
+            "n1 * n2 cannot be longer than base"
+        );
+        Self {
+            mat: unsafe { sys::[<$name _view_array>](base.as_mut_ptr() as *mut _, n1, n2) },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Same as [`from_array`](Self::from_array), but with a physical number of columns `tda`
+    /// which may differ from n2.
+    #[doc(alias = $name _view_array_with_tda)]
+    pub fn from_array_with_tda(base: &'a mut [$complex], n1: usize, n2: usize, tda: usize) -> Self {
+        assert!(n2 <= tda, "n2 cannot be larger than tda");
+        assert!(
+            n1 == 0 || (n1 - 1) * tda + n2 <= base.len(),
+            "the matrix cannot be longer than base"
+        );
+        Self {
+            mat: unsafe {
+                sys::[<$name _view_array_with_tda>](base.as_mut_ptr() as *mut _, n1, n2, tda)
+            },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns a matrix view of the vector `v`, which must have unit stride. The matrix has n1
+    /// rows and n2 columns.
+    #[doc(alias = $name _view_vector)]
+    pub fn from_vector(v: &'a mut [<Vector $complex>], n1: usize, n2: usize) -> Self {
+        Self {
+            mat: unsafe { sys::[<$name _view_vector>](v.unwrap_unique(), n1, n2) },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Gives the view as a matrix to `f`, or `None` if the view is out of the bounds of its
+    /// parent.
+    pub fn matrix<F: FnOnce(Option<&$rust_name>)>(&self, f: F) {
+        let tmp = &self.mat.matrix;
+        if tmp.data.is_null() {
+            f(None)
+        } else {
+            f(Some(&$rust_name::soft_wrap(tmp as *const _ as usize as *mut _)))
+        }
+    }
+
+    /// Mutable version of [`matrix`](Self::matrix).
+    pub fn matrix_mut<F: FnOnce(Option<&mut $rust_name>)>(&mut self, f: F) {
+        let tmp = &mut self.mat.matrix;
+        if tmp.data.is_null() {
+            f(None)
+        } else {
+            ::ffi::lend_mut(tmp as *mut _, |tmp: &mut $rust_name| f(Some(tmp)))
+        }
+    }
+}
+
+/// A read-only view of a matrix or of an array. It borrows its parent, so it can't outlive it.
+pub struct [<$rust_name ConstView>]<'a> {
+    mat: sys::[<$name _const_view>],
+    #[allow(dead_code)]
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> [<$rust_name ConstView>]<'a> {
+    /// Same as [`from_matrix`]([<$rust_name View>]::from_matrix), but for a read-only view.
+    #[doc(alias = $name _const_submatrix)]
+    pub fn from_matrix(m: &'a $rust_name, k1: usize, k2: usize, n1: usize, n2: usize) -> Self {
+        Self {
+            mat: unsafe { sys::[<$name _const_submatrix>](m.unwrap_shared(), k1, k2, n1, n2) },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Same as [`from_array`]([<$rust_name View>]::from_array), but for a read-only view.
+    #[doc(alias = $name _const_view_array)]
+    pub fn from_array(base: &'a [$complex], n1: usize, n2: usize) -> Self {
+        assert!(
+            n1 * n2 <= base.len(),
+            "n1 * n2 cannot be longer than base"
+        );
+        Self {
+            mat: unsafe { sys::[<$name _const_view_array>](base.as_ptr() as *const _, n1, n2) },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Gives the view as a matrix to `f`, or `None` if the view is out of the bounds of its
+    /// parent.
+    pub fn matrix<F: FnOnce(Option<&$rust_name>)>(&self, f: F) {
+        let tmp = &self.mat.matrix;
+        if tmp.data.is_null() {
+            f(None)
+        } else {
+            f(Some(&$rust_name::soft_wrap(tmp as *const _ as usize as *mut _)))
         }
     }
 }
@@ -360,3 +608,22 @@ gsl_matrix_complex!(
     ComplexF32,
     gsl_vector_complex_float
 );
+
+macro_rules! approx_eq {
+    ($rust_name:ident, $rust_ty:ident) => {
+        impl $rust_name {
+            /// Returns `true` if `self` and `other` have the same dimensions and the moduli of the
+            /// differences between their elements are at most `tol`.
+            pub fn approx_eq(&self, other: &$rust_name, tol: $rust_ty) -> bool {
+                self.size1() == other.size1()
+                    && self.size2() == other.size2()
+                    && (0..self.size1()).all(|i| {
+                        (0..self.size2()).all(|j| self.get(i, j).sub(&other.get(i, j)).abs() <= tol)
+                    })
+            }
+        }
+    };
+}
+
+approx_eq!(MatrixComplexF64, f64);
+approx_eq!(MatrixComplexF32, f32);
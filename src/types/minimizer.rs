@@ -96,16 +96,59 @@ somewhere in the interval. If a valid initial interval is used then these algori
 provided the function is well-behaved.
 !*/
 
-use ffi::FFI;
+use crate::{ConvergenceStatus, Error, Value};
+use ffi::{Function, FFI};
+use std::fmt;
 use sys;
 
+/// The state of a [`Minimizer`] at the end of [`Minimizer::minimize`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Minimum {
+    /// The best estimate of the location of the minimum.
+    pub x: f64,
+    /// The value of the function at `x`.
+    pub f: f64,
+    /// The bracketing interval `(x_lower, x_upper)`.
+    pub interval: (f64, f64),
+    /// The number of iterations done.
+    pub iterations: usize,
+}
+
+/// The error returned by [`Minimizer::minimize`], with the state of the minimizer when it
+/// stopped.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MinimizeError {
+    /// Why the iteration stopped: [`Value::MaxIteration`] if the minimum wasn't found within the
+    /// allowed number of iterations.
+    pub error: Error,
+    /// The last estimate of the minimum.
+    pub minimum: Minimum,
+}
+
+impl fmt::Display for MinimizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} after {} iterations (minimum estimate: {}, interval: [{}, {}])",
+            self.error,
+            self.minimum.iterations,
+            self.minimum.x,
+            self.minimum.interval.0,
+            self.minimum.interval.1
+        )
+    }
+}
+
+impl ::std::error::Error for MinimizeError {}
+
 ffi_wrapper!(
-    Minimizer,
+    Minimizer<'a>,
     *mut sys::gsl_min_fminimizer,
-    gsl_min_fminimizer_free
+    gsl_min_fminimizer_free,
+    Function<'a>
 );
 
-impl Minimizer {
+impl<'a> Minimizer<'a> {
     /// This function returns a pointer to a newly allocated instance of a minimizer of type T. For
     /// example, the following code creates an instance of a golden section minimizer,
     ///
@@ -119,7 +162,7 @@ impl Minimizer {
     /// If there is insufficient memory to create the minimizer then the function returns a null
     /// pointer and the error handler is invoked with an error code of ::NoMem.
     #[doc(alias = "gsl_min_fminimizer_alloc")]
-    pub fn new(t: MinimizerType) -> Option<Minimizer> {
+    pub fn new(t: MinimizerType) -> Option<Minimizer<'a>> {
         let ptr = unsafe { sys::gsl_min_fminimizer_alloc(t.unwrap_shared()) };
 
         if ptr.is_null() {
@@ -135,29 +178,32 @@ impl Minimizer {
     /// If the interval given does not contain a minimum, then the function returns an error code of
     /// ::Value::Invalid.
     #[doc(alias = "gsl_min_fminimizer_set")]
-    pub fn set<F: Fn(f64) -> f64>(
+    pub fn set<F: FnMut(f64) -> f64 + 'a>(
         &mut self,
         f: F,
         x_minimum: f64,
         x_lower: f64,
         x_upper: f64,
-    ) -> ::Value {
-        let mut function = wrap_callback!(f, F);
-        ::Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let mut function = Function::new(f);
+        let ret = unsafe {
             sys::gsl_min_fminimizer_set(
-                self.unwrap_unique(),
-                &mut function,
+                self.inner,
+                function.as_mut_ptr(),
                 x_minimum,
                 x_lower,
                 x_upper,
             )
-        })
+        };
+        // GSL keeps a pointer to it, and the previous one (if any) isn't used anymore.
+        self.function = Some(function);
+        result_handler!(ret, (), "gsl_min_fminimizer_set")
     }
 
     /// This function is equivalent to gsl_min_fminimizer_set but uses the values f_minimum, f_lower
     /// and f_upper instead of computing f(x_minimum), f(x_lower) and f(x_upper).
     #[doc(alias = "gsl_min_fminimizer_set_with_values")]
-    pub fn set_with_values<F: Fn(f64) -> f64>(
+    pub fn set_with_values<F: FnMut(f64) -> f64 + 'a>(
         &mut self,
         f: F,
         x_minimum: f64,
@@ -166,12 +212,12 @@ impl Minimizer {
         f_lower: f64,
         x_upper: f64,
         f_upper: f64,
-    ) -> ::Value {
-        let mut function = wrap_callback!(f, F);
-        ::Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let mut function = Function::new(f);
+        let ret = unsafe {
             sys::gsl_min_fminimizer_set_with_values(
-                self.unwrap_unique(),
-                &mut function,
+                self.inner,
+                function.as_mut_ptr(),
                 x_minimum,
                 f_minimum,
                 x_lower,
@@ -179,7 +225,10 @@ impl Minimizer {
                 x_upper,
                 f_upper,
             )
-        })
+        };
+        // GSL keeps a pointer to it, and the previous one (if any) isn't used anymore.
+        self.function = Some(function);
+        result_handler!(ret, (), "gsl_min_fminimizer_set_with_values")
     }
 
     #[doc(alias = "gsl_min_fminimizer_name")]
@@ -235,9 +284,9 @@ impl Minimizer {
     }
 
     /// This function performs a single iteration of the minimizer s. If the iteration encounters an
-    /// unexpected problem then an error code will be returned,
+    /// unexpected problem then an error is returned, with the value:
     ///
-    /// ::Value::BadFunc
+    /// ::Value::BadFunction
     /// the iteration encountered a singular point where the function evaluated to Inf or NaN.
     ///
     /// ::Value::Failure
@@ -247,22 +296,85 @@ impl Minimizer {
     /// and the current interval bounding the minimum. This information can be accessed with the
     /// following auxiliary functions,
     #[doc(alias = "gsl_min_fminimizer_iterate")]
-    pub fn iterate(&mut self) -> ::Value {
-        ::Value::from(unsafe { sys::gsl_min_fminimizer_iterate(self.unwrap_unique()) })
+    pub fn iterate(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_min_fminimizer_iterate(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_min_fminimizer_iterate")
+    }
+
+    /// Iterates the minimizer until its bracketing interval satisfies
+    /// [`test_interval`](crate::minimizer::test_interval) with `epsabs` and `epsrel`, and returns
+    /// the minimum. It fails if an iteration fails or if the minimum isn't found after `max_iter`
+    /// iterations.
+    ///
+    /// ```
+    /// use rgsl::{Minimizer, MinimizerType};
+    ///
+    /// let mut s = Minimizer::new(MinimizerType::brent()).unwrap();
+    /// s.set(|x| x.cos() + 1., 2., 0., 6.).unwrap();
+    /// let minimum = s.minimize(100, 1e-3, 0.).unwrap();
+    /// assert!((minimum.x - std::f64::consts::PI).abs() < 1e-3);
+    /// assert!(minimum.f.abs() < 1e-6);
+    /// ```
+    pub fn minimize(
+        &mut self,
+        max_iter: usize,
+        epsabs: f64,
+        epsrel: f64,
+    ) -> Result<Minimum, MinimizeError> {
+        for iter in 1..=max_iter {
+            let status = self.iterate().and_then(|()| {
+                ::minimizer::test_interval(self.x_lower(), self.x_upper(), epsabs, epsrel)
+            });
+            match status {
+                Ok(ConvergenceStatus::Converged) => return Ok(self.current_minimum(iter)),
+                Ok(ConvergenceStatus::Continue) => {}
+                Err(error) => {
+                    return Err(MinimizeError {
+                        error,
+                        minimum: self.current_minimum(iter),
+                    })
+                }
+            }
+        }
+        Err(MinimizeError {
+            error: Error::new(Value::MaxIteration, "gsl_min_fminimizer_iterate"),
+            minimum: self.current_minimum(max_iter),
+        })
+    }
+
+    fn current_minimum(&self, iterations: usize) -> Minimum {
+        Minimum {
+            x: self.x_minimum(),
+            f: self.f_minimum(),
+            interval: (self.x_lower(), self.x_upper()),
+            iterations,
+        }
     }
 }
 
 ffi_wrapper!(MinimizerType, *const sys::gsl_min_fminimizer_type);
 
+unsafe impl Send for MinimizerType {}
+unsafe impl Sync for MinimizerType {}
+
 impl MinimizerType {
+    /// The golden section algorithm is the simplest method of bracketing the minimum of a
+    /// function. It is the slowest algorithm provided by the library, with linear convergence.
+    #[doc(alias = "gsl_min_fminimizer_goldensection")]
     pub fn goldensection() -> Self {
         ffi_wrap!(gsl_min_fminimizer_goldensection)
     }
 
+    /// The Brent minimization algorithm combines a parabolic interpolation with the golden
+    /// section algorithm. This produces a fast algorithm which is still robust.
+    #[doc(alias = "gsl_min_fminimizer_brent")]
     pub fn brent() -> Self {
         ffi_wrap!(gsl_min_fminimizer_brent)
     }
 
+    /// This is a variant of Brent's algorithm which uses the safeguarded step-length algorithm of
+    /// Gill and Murray.
+    #[doc(alias = "gsl_min_fminimizer_quad_golden")]
     pub fn quad_golden() -> Self {
         ffi_wrap!(gsl_min_fminimizer_quad_golden)
     }
@@ -2,109 +2,297 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
+#[cfg(feature = "std")]
 pub use self::basis_spline::BSpLineWorkspace;
 
+#[cfg(feature = "std")]
 pub use self::chebyshev::ChebSeries;
+#[cfg(feature = "std")]
 pub use self::combination::Combination;
 pub use self::complex::{ComplexF32, ComplexF64};
+#[cfg(feature = "std")]
 pub use self::discrete_hankel::DiscreteHankel;
+#[cfg(feature = "std")]
 pub use self::eigen_symmetric_workspace::{
     EigenGenHermVWorkspace, EigenGenHermWorkspace, EigenGenSymmVWorkspace, EigenGenSymmWorkspace,
     EigenGenVWorkspace, EigenGenWorkspace, EigenHermitianVWorkspace, EigenHermitianWorkspace,
     EigenNonSymmetricVWorkspace, EigenNonSymmetricWorkspace, EigenSymmetricVWorkspace,
     EigenSymmetricWorkspace,
 };
+#[cfg(feature = "std")]
 pub use self::fast_fourier_transforms::{
     FftComplexF32WaveTable, FftComplexF32Workspace, FftComplexF64WaveTable, FftComplexF64Workspace,
+    FftHalfComplexF32WaveTable, FftHalfComplexF64WaveTable, FftRealF32WaveTable,
+    FftRealF32Workspace, FftRealF64WaveTable, FftRealF64Workspace,
 };
-#[cfg(feature = "v2_5")]
-#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[cfg(all(feature = "std", feature = "v2_5"))]
+#[cfg_attr(feature = "dox", doc(cfg(all(feature = "std", feature = "v2_5"))))]
 pub use self::filter::{
     FilterGaussianWorkspace, FilterImpulseWorkspace, FilterMedianWorkspace, FilterRMedianWorkspace,
 };
-pub use self::histograms::{Histogram, Histogram2D, Histogram2DPdf, HistogramPdf};
+#[cfg(feature = "std")]
+pub use self::histograms::{
+    Histogram, Histogram2D, Histogram2DPdf, HistogramBuilder, HistogramIter, HistogramPdf,
+};
+#[cfg(all(feature = "std", feature = "v2_5"))]
+#[cfg_attr(feature = "dox", doc(cfg(all(feature = "std", feature = "v2_5"))))]
+pub use self::integration::RombergWorkspace;
+#[cfg(feature = "std")]
 pub use self::integration::{
     CquadWorkspace, GLFixedTable, IntegrationFixedType, IntegrationFixedWorkspace,
     IntegrationQawoTable, IntegrationQawsTable, IntegrationWorkspace,
 };
-pub use self::interpolation::{Interp, InterpAccel, InterpType, Spline};
+#[cfg(feature = "std")]
+pub use self::interpolation::{
+    Interp, InterpAccel, InterpDataError, InterpType, OwnedInterp, Spline,
+};
+#[cfg(feature = "std")]
 pub use self::mathieu::MathieuWorkspace;
+#[cfg(feature = "std")]
 pub use self::matrix::{
-    MatrixF32, MatrixF32View, MatrixF64, MatrixF64View, MatrixI32, MatrixI32View, MatrixU32,
-    MatrixU32View,
+    MatrixF32, MatrixF32ConstView, MatrixF32View, MatrixF64, MatrixF64ConstView, MatrixF64View,
+    MatrixI32, MatrixI32ConstView, MatrixI32View, MatrixU32, MatrixU32ConstView, MatrixU32View,
+};
+#[cfg(feature = "std")]
+pub use self::matrix_complex::{
+    MatrixComplexF32, MatrixComplexF32ConstView, MatrixComplexF32View, MatrixComplexF64,
+    MatrixComplexF64ConstView, MatrixComplexF64View,
 };
-pub use self::matrix_complex::{MatrixComplexF32, MatrixComplexF64};
-pub use self::minimizer::{Minimizer, MinimizerType};
+#[cfg(feature = "std")]
+pub use self::minimizer::{MinimizeError, Minimizer, MinimizerType, Minimum};
+#[cfg(feature = "std")]
 pub use self::monte_carlo::{
     MiserMonteCarlo, MiserParams, PlainMonteCarlo, VegasMonteCarlo, VegasParams,
 };
-pub use self::multifit_linear::MultifitLinearWorkspace;
+#[cfg(all(feature = "std", feature = "v2_5"))]
+#[cfg_attr(feature = "dox", doc(cfg(all(feature = "std", feature = "v2_5"))))]
+pub use self::movstat::MovStatWorkspace;
+#[cfg(feature = "std")]
+pub use self::multifit_linear::{FitResult, MultifitLinearWorkspace};
+#[cfg(all(feature = "std", feature = "v2_2"))]
+pub use self::multifit_nlinear::{
+    MultiFitNLinear, MultiFitNLinearConvergence, MultiFitNLinearFunction,
+    MultiFitNLinearParameters, MultiFitNLinearScale, MultiFitNLinearSolver, MultiFitNLinearTrs,
+    MultiFitNLinearType,
+};
+#[cfg(feature = "std")]
+pub use self::multifit_robust::{MultifitRobustType, MultifitRobustWorkspace, RobustStats};
+#[cfg(feature = "std")]
 pub use self::multifit_solver::{
     MultiFitFdfSolver, MultiFitFdfSolverType, MultiFitFunction, MultiFitFunctionFdf,
 };
-#[cfg(feature = "v2_1")]
-#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
+#[cfg(all(feature = "std", feature = "v2_1"))]
+#[cfg_attr(feature = "dox", doc(cfg(all(feature = "std", feature = "v2_1"))))]
 pub use self::multilarge_linear::{MultilargeLinearType, MultilargeLinearWorkspace};
+#[cfg(feature = "std")]
+pub use self::multimin::{
+    MultiMinError, MultiMinFMinimizer, MultiMinFMinimizerType, MultiMinFdfMinimizer,
+    MultiMinFdfMinimizerType, MultiMinFunctionFdf,
+};
+#[cfg(feature = "std")]
+pub use self::multiroot::{
+    MultiRootFSolver, MultiRootFSolverType, MultiRootFdfSolver, MultiRootFdfSolverType,
+    MultiRootFunctionFdf, MultiRootSolveError, MultiRootTest,
+};
+#[cfg(feature = "std")]
 pub use self::multiset::MultiSet;
-pub use self::n_tuples::{ReadNTuples, WriteNTuples};
+#[cfg(feature = "std")]
+pub use self::n_tuples::{NTuple, ReadNTuples, WriteNTuples};
+#[cfg(feature = "std")]
 pub use self::ordinary_differential_equations::{
     ODEiv2Control, ODEiv2Driver, ODEiv2Evolve, ODEiv2Step, ODEiv2StepType, ODEiv2System,
 };
+#[cfg(feature = "std")]
 pub use self::permutation::Permutation;
-pub use self::polynomial::PolyComplexWorkspace;
+#[cfg(feature = "std")]
+pub use self::polynomial::{PolyComplexWorkspace, Polynomial};
+#[cfg(feature = "std")]
 pub use self::qrng::{QRng, QRngType};
+#[cfg(feature = "std")]
 pub use self::ran_discrete::RanDiscrete;
 pub use self::result::{Result, ResultE10};
-pub use self::rng::{Rng, RngType};
-pub use self::roots::{RootFSolver, RootFSolverType, RootFdfSolver, RootFdfSolverType};
+#[cfg(feature = "std")]
+pub use self::rng::{Rng, RngIter, RngType, SampleRange};
+#[cfg(feature = "std")]
+pub use self::roots::{
+    RootFSolver, RootFSolverType, RootFdfSolver, RootFdfSolverType, RootSolveError,
+};
+#[cfg(feature = "std")]
 pub use self::rstat::{RStatQuantileWorkspace, RStatWorkspace};
+#[cfg(feature = "std")]
 pub use self::series_acceleration::{LevinUTruncWorkspace, LevinUWorkspace};
+#[cfg(feature = "std")]
 pub use self::siman::{SimAnnealing, SimAnnealingParams};
+#[cfg(feature = "std")]
+pub use self::sparse_linear_algebra::{SpIterSolver, SpIterSolverType};
+#[cfg(feature = "std")]
+pub use self::sparse_matrix::SparseMatrix;
+#[cfg(feature = "std")]
 pub use self::vector::{
-    VectorF32, VectorF32View, VectorF64, VectorF64View, VectorI32, VectorI32View, VectorU32,
-    VectorU32View,
+    VectorF32, VectorF32ConstView, VectorF32View, VectorF64, VectorF64ConstView, VectorF64View,
+    VectorI32, VectorI32ConstView, VectorI32View, VectorU32, VectorU32ConstView, VectorU32View,
 };
+#[cfg(feature = "std")]
 pub use self::vector_complex::{
-    VectorComplexF32, VectorComplexF32View, VectorComplexF64, VectorComplexF64View,
+    VectorComplexF32, VectorComplexF32ConstView, VectorComplexF32View, VectorComplexF64,
+    VectorComplexF64ConstView, VectorComplexF64View,
 };
+#[cfg(feature = "std")]
 pub use self::wavelet_transforms::{Wavelet, WaveletType, WaveletWorkspace};
 
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod basis_spline;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod chebyshev;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod combination;
 pub mod complex;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod discrete_hankel;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod eigen_symmetric_workspace;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod fast_fourier_transforms;
-#[cfg(feature = "v2_5")]
-#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[cfg(all(feature = "std", feature = "v2_5"))]
+#[cfg_attr(feature = "dox", doc(cfg(all(feature = "std", feature = "v2_5"))))]
 pub mod filter;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod histograms;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod integration;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod interpolation;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod mathieu;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod matrix;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod matrix_complex;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod minimizer;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod monte_carlo;
+#[cfg(all(feature = "std", feature = "v2_5"))]
+#[cfg_attr(feature = "dox", doc(cfg(all(feature = "std", feature = "v2_5"))))]
+pub mod movstat;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod multifit_linear;
+#[cfg(all(feature = "std", feature = "v2_2"))]
+#[cfg_attr(feature = "dox", doc(cfg(all(feature = "std", feature = "v2_2"))))]
+pub mod multifit_nlinear;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub mod multifit_robust;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod multifit_solver;
-#[cfg(feature = "v2_1")]
-#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
+#[cfg(all(feature = "std", feature = "v2_1"))]
+#[cfg_attr(feature = "dox", doc(cfg(all(feature = "std", feature = "v2_1"))))]
 pub mod multilarge_linear;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub mod multimin;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub mod multiroot;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod multiset;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod n_tuples;
+#[cfg(all(feature = "std", feature = "nalgebra"))]
+mod nalgebra_conversions;
+#[cfg(all(feature = "std", feature = "ndarray"))]
+mod ndarray_conversions;
+#[cfg(all(feature = "std", feature = "num-complex"))]
+mod num_complex_conversions;
+#[cfg(feature = "std")]
+mod ops;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod ordinary_differential_equations;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod permutation;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod polynomial;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod qrng;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod ran_discrete;
 pub mod result;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod rng;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod roots;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod rstat;
+#[cfg(all(feature = "std", feature = "serde"))]
+mod serde_impls;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod series_acceleration;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod siman;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub mod sparse_linear_algebra;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
+pub mod sparse_matrix;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod vector;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod vector_complex;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "std")))]
 pub mod wavelet_transforms;
+
+#[cfg(feature = "std")]
+#[test]
+fn send_to_threads() {
+    use std::thread;
+
+    let mut rng = Rng::new(RngType::default()).unwrap();
+    let v = VectorF64::from_slice(&[1., 2., 3.]).unwrap();
+    let w = IntegrationWorkspace::new(100).unwrap();
+    let handles = (0..4)
+        .map(|i| {
+            let mut rng = rng.clone();
+            rng.set(i);
+            thread::spawn(move || rng.uniform())
+        })
+        .collect::<Vec<_>>();
+    let handle = thread::spawn(move || (v.get(1), w));
+    for handle in handles {
+        let x = handle.join().unwrap();
+        assert!((0. ..1.).contains(&x));
+    }
+    assert_eq!(handle.join().unwrap().0, 2.);
+    rng.uniform();
+}
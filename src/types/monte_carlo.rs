@@ -73,13 +73,11 @@ current estimate has zero error, previous estimates had zero error
 The estimates are averaged using the arithmetic mean, but no error is computed.
 !*/
 
-use ffi::FFI;
+use crate::{Error, Value};
+use ffi::{MonteFunction, FFI};
 use std::marker::PhantomData;
-use std::mem::transmute;
-use std::os::raw::c_void;
-use std::slice;
 
-ffi_wrapper!(PlainMonteCarlo, *mut sys::gsl_monte_plain_state, gsl_monte_plain_free,
+ffi_wrapper!(PlainMonteCarlo<'a>, *mut sys::gsl_monte_plain_state, gsl_monte_plain_free, MonteFunction<'a>,
 "The plain Monte Carlo algorithm samples points randomly from the integration region to estimate
 the integral and its error. Using this algorithm the estimate of the integral E(f; N) for N
 randomly distributed points x_i is given by,
@@ -100,10 +98,10 @@ variance of the function over the integration region. The error estimate itself
 `sigma(f)/sqrt{N}`. The familiar law of errors decreasing as `1/sqrt{N}` applies-to reduce the
 error by a factor of 10 requires a 100-fold increase in the number of sample points.");
 
-impl PlainMonteCarlo {
+impl<'a> PlainMonteCarlo<'a> {
     /// This function allocates and initializes a workspace for Monte Carlo integration in dim dimensions.
     #[doc(alias = "gsl_monte_plain_alloc")]
-    pub fn new(dim: usize) -> Option<PlainMonteCarlo> {
+    pub fn new(dim: usize) -> Option<PlainMonteCarlo<'a>> {
         let tmp = unsafe { sys::gsl_monte_plain_alloc(dim) };
 
         if tmp.is_null() {
@@ -116,8 +114,12 @@ impl PlainMonteCarlo {
     /// This function initializes a previously allocated integration state. This allows an existing workspace to be reused for different
     /// integrations.
     #[doc(alias = "gsl_monte_plain_init")]
-    pub fn init(&mut self) -> ::Value {
-        ::Value::from(unsafe { sys::gsl_monte_plain_init(self.unwrap_unique()) })
+    pub fn init(&mut self) -> Result<(), Error> {
+        result_handler!(
+            unsafe { sys::gsl_monte_plain_init(self.unwrap_unique()) },
+            (),
+            "gsl_monte_plain_init"
+        )
     }
 
     /// This routines uses the plain Monte Carlo algorithm to integrate the function f over the dim-dimensional hypercubic region defined
@@ -126,31 +128,49 @@ impl PlainMonteCarlo {
     /// The result of the integration is returned in result, with an estimated absolute error abserr.
     ///
     /// In C, the function takes a `gsl_monte_function` as first argument. In here, you have to
-    /// pass the `dim` argument and the function pointer (which became a closure) directly to the
-    /// function.
+    /// pass the function (which became a closure) directly, `dim` being the length of `xl`. The
+    /// closure is kept by the workspace, so the same integrand can be integrated again with
+    /// [`integrate_again`](Self::integrate_again).
     ///
     /// It returns either Ok((result, abserr)) or Err(Value).
     #[doc(alias = "gsl_monte_plain_integrate")]
-    pub fn integrate<F: FnMut(&[f64]) -> f64>(
+    pub fn integrate<F: FnMut(&[f64]) -> f64 + 'a>(
         &mut self,
         f: F,
         xl: &[f64],
         xu: &[f64],
         t_calls: usize,
         r: &mut ::Rng,
-    ) -> (::Value, f64, f64) {
-        assert!(xl.len() == xu.len());
+    ) -> Result<(f64, f64), Error> {
+        self.function = Some(MonteFunction::new(xl.len(), f));
+        self.integrate_again(xl, xu, t_calls, r)
+    }
+
+    /// This function integrates again the function given to the last call to
+    /// [`integrate`](Self::integrate), over the region defined by `xl` and `xu`, giving a new
+    /// independent estimate of the integral.
+    ///
+    /// It returns an error if [`integrate`](Self::integrate) wasn't called before.
+    #[doc(alias = "gsl_monte_plain_integrate")]
+    pub fn integrate_again(
+        &mut self,
+        xl: &[f64],
+        xu: &[f64],
+        t_calls: usize,
+        r: &mut ::Rng,
+    ) -> Result<(f64, f64), Error> {
+        let func = match self.function {
+            Some(ref mut function) => {
+                assert!(xl.len() == function.dim() && xu.len() == function.dim());
+                function.as_mut_ptr()
+            }
+            None => return Err(Error::new(Value::Invalid, "gsl_monte_plain_integrate")),
+        };
         let mut result = 0f64;
         let mut abserr = 0f64;
-        let f: Box<F> = Box::new(f);
         let ret = unsafe {
-            let func = sys::gsl_monte_function {
-                f: transmute(monte_trampoline::<F> as usize),
-                dim: xl.len() as _,
-                params: Box::into_raw(f) as *mut _,
-            };
             sys::gsl_monte_plain_integrate(
-                &func,
+                func,
                 xl.as_ptr(),
                 xu.as_ptr(),
                 xl.len() as _,
@@ -161,12 +181,11 @@ impl PlainMonteCarlo {
                 &mut abserr,
             )
         };
-
-        (::Value::from(ret), result, abserr)
+        result_handler!(ret, (result, abserr), "gsl_monte_plain_integrate")
     }
 }
 
-ffi_wrapper!(MiserMonteCarlo, *mut sys::gsl_monte_miser_state, gsl_monte_miser_free,
+ffi_wrapper!(MiserMonteCarlo<'a>, *mut sys::gsl_monte_miser_state, gsl_monte_miser_free, MonteFunction<'a>,
 "The MISER algorithm of Press and Farrar is based on recursive stratified sampling. This technique
 aims to reduce the overall integration error by concentrating integration points in the regions of
 highest variance.
@@ -200,11 +219,11 @@ user-specified depth where each sub-region is integrated using a plain Monte Car
 individual values and their error estimates are then combined upwards to give an overall result and
 an estimate of its error.");
 
-impl MiserMonteCarlo {
+impl<'a> MiserMonteCarlo<'a> {
     /// This function allocates and initializes a workspace for Monte Carlo integration in dim dimensions. The workspace is used to maintain
     /// the state of the integration.
     #[doc(alias = "gsl_monte_miser_alloc")]
-    pub fn new(dim: usize) -> Option<MiserMonteCarlo> {
+    pub fn new(dim: usize) -> Option<MiserMonteCarlo<'a>> {
         let tmp = unsafe { sys::gsl_monte_miser_alloc(dim) };
 
         if tmp.is_null() {
@@ -216,8 +235,12 @@ impl MiserMonteCarlo {
 
     /// This function initializes a previously allocated integration state. This allows an existing workspace to be reused for different integrations.
     #[doc(alias = "gsl_monte_miser_init")]
-    pub fn init(&mut self) -> ::Value {
-        ::Value::from(unsafe { sys::gsl_monte_miser_init(self.unwrap_unique()) })
+    pub fn init(&mut self) -> Result<(), Error> {
+        result_handler!(
+            unsafe { sys::gsl_monte_miser_init(self.unwrap_unique()) },
+            (),
+            "gsl_monte_miser_init"
+        )
     }
 
     /// This routines uses the MISER Monte Carlo algorithm to integrate the function f over the dim-dimensional hypercubic region defined by
@@ -226,31 +249,49 @@ impl MiserMonteCarlo {
     /// of the integration is returned in result, with an estimated absolute error abserr.
     ///
     /// In C, the function takes a `gsl_monte_function` as first argument. In here, you have to
-    /// pass the `dim` argument and the function pointer (which became a closure) directly to the
-    /// function.
+    /// pass the function (which became a closure) directly, `dim` being the length of `xl`. The
+    /// closure is kept by the workspace, so the same integrand can be integrated again with
+    /// [`integrate_again`](Self::integrate_again).
     ///
     /// It returns either Ok((result, abserr)) or Err(Value).
     #[doc(alias = "gsl_monte_miser_integrate")]
-    pub fn integrate<F: FnMut(&[f64]) -> f64>(
+    pub fn integrate<F: FnMut(&[f64]) -> f64 + 'a>(
         &mut self,
         f: F,
         xl: &[f64],
         xu: &[f64],
         t_calls: usize,
         r: &mut ::Rng,
-    ) -> (::Value, f64, f64) {
-        assert!(xl.len() == xu.len());
+    ) -> Result<(f64, f64), Error> {
+        self.function = Some(MonteFunction::new(xl.len(), f));
+        self.integrate_again(xl, xu, t_calls, r)
+    }
+
+    /// This function integrates again the function given to the last call to
+    /// [`integrate`](Self::integrate), over the region defined by `xl` and `xu`, giving a new
+    /// independent estimate of the integral.
+    ///
+    /// It returns an error if [`integrate`](Self::integrate) wasn't called before.
+    #[doc(alias = "gsl_monte_miser_integrate")]
+    pub fn integrate_again(
+        &mut self,
+        xl: &[f64],
+        xu: &[f64],
+        t_calls: usize,
+        r: &mut ::Rng,
+    ) -> Result<(f64, f64), Error> {
+        let func = match self.function {
+            Some(ref mut function) => {
+                assert!(xl.len() == function.dim() && xu.len() == function.dim());
+                function.as_mut_ptr()
+            }
+            None => return Err(Error::new(Value::Invalid, "gsl_monte_miser_integrate")),
+        };
         let mut result = 0f64;
         let mut abserr = 0f64;
-        let f: Box<F> = Box::new(f);
         let ret = unsafe {
-            let mut func = sys::gsl_monte_function {
-                f: transmute(monte_trampoline::<F> as usize),
-                dim: xl.len() as _,
-                params: Box::into_raw(f) as *mut _,
-            };
             sys::gsl_monte_miser_integrate(
-                &mut func,
+                func,
                 xl.as_ptr(),
                 xu.as_ptr(),
                 xl.len() as _,
@@ -261,7 +302,7 @@ impl MiserMonteCarlo {
                 &mut abserr,
             )
         };
-        (::Value::from(ret), result, abserr)
+        result_handler!(ret, (result, abserr), "gsl_monte_miser_integrate")
     }
 
     /// This function copies the parameters of the integrator state into the user-supplied params structure.
@@ -294,7 +335,7 @@ impl MiserMonteCarlo {
 #[repr(C)]
 pub struct MiserParams(pub sys::gsl_monte_miser_params);
 
-ffi_wrapper!(VegasMonteCarlo, *mut sys::gsl_monte_vegas_state, gsl_monte_vegas_free,
+ffi_wrapper!(VegasMonteCarlo<'a>, *mut sys::gsl_monte_vegas_state, gsl_monte_vegas_free, MonteFunction<'a>,
 "The VEGAS algorithm of Lepage is based on importance sampling. It samples points from the probability
 distribution described by the function |f|, so that the points are concentrated in the regions that
 make the largest contribution to the integral.
@@ -354,11 +395,11 @@ current estimate has zero error, previous estimates had zero error
 
 * The estimates are averaged using the arithmetic mean, but no error is computed.");
 
-impl VegasMonteCarlo {
+impl<'a> VegasMonteCarlo<'a> {
     /// This function allocates and initializes a workspace for Monte Carlo integration in dim dimensions.
     /// The workspace is used to maintain the state of the integration.
     #[doc(alias = "gsl_monte_vegas_alloc")]
-    pub fn new(dim: usize) -> Option<VegasMonteCarlo> {
+    pub fn new(dim: usize) -> Option<VegasMonteCarlo<'a>> {
         let tmp = unsafe { sys::gsl_monte_vegas_alloc(dim) };
 
         if tmp.is_null() {
@@ -371,8 +412,12 @@ impl VegasMonteCarlo {
     /// This function initializes a previously allocated integration state. This allows an existing workspace
     /// to be reused for different integrations.
     #[doc(alias = "gsl_monte_vegas_init")]
-    pub fn init(&mut self) -> ::Value {
-        ::Value::from(unsafe { sys::gsl_monte_vegas_init(self.unwrap_unique()) })
+    pub fn init(&mut self) -> Result<(), Error> {
+        result_handler!(
+            unsafe { sys::gsl_monte_vegas_init(self.unwrap_unique()) },
+            (),
+            "gsl_monte_vegas_init"
+        )
     }
 
     /// This routines uses the VEGAS Monte Carlo algorithm to integrate the function f over the dim-dimensional
@@ -385,31 +430,51 @@ impl VegasMonteCarlo {
     /// with 1 for the weighted average to be reliable.
     ///
     /// In C, the function takes a `gsl_monte_function` as first argument. In here, you have to
-    /// pass the `dim` argument and the function pointer (which became a closure) directly to the
-    /// function.
+    /// pass the function (which became a closure) directly, `dim` being the length of `xl`. The
+    /// closure is kept by the workspace, so the same integrand can be integrated again with
+    /// [`integrate_again`](Self::integrate_again).
     ///
     /// It returns either Ok((result, abserr)) or Err(Value).
     #[doc(alias = "gsl_monte_vegas_integrate")]
-    pub fn integrate<F: FnMut(&[f64]) -> f64>(
+    pub fn integrate<F: FnMut(&[f64]) -> f64 + 'a>(
         &mut self,
         f: F,
         xl: &[f64],
         xu: &[f64],
         t_calls: usize,
         r: &mut ::Rng,
-    ) -> (::Value, f64, f64) {
-        assert!(xl.len() == xu.len());
+    ) -> Result<(f64, f64), Error> {
+        self.function = Some(MonteFunction::new(xl.len(), f));
+        self.integrate_again(xl, xu, t_calls, r)
+    }
+
+    /// This function integrates again the function given to the last call to
+    /// [`integrate`](Self::integrate), over the region defined by `xl` and `xu`. Depending on
+    /// the `stage` parameter (see [`VegasParams::set_stage`]), it continues the current run:
+    /// the grid and the accumulated estimates are kept, which is how a VEGAS integration is
+    /// usually refined until [`chisq`](Self::chisq) is consistent with 1.
+    ///
+    /// It returns an error if [`integrate`](Self::integrate) wasn't called before.
+    #[doc(alias = "gsl_monte_vegas_integrate")]
+    pub fn integrate_again(
+        &mut self,
+        xl: &[f64],
+        xu: &[f64],
+        t_calls: usize,
+        r: &mut ::Rng,
+    ) -> Result<(f64, f64), Error> {
+        let func = match self.function {
+            Some(ref mut function) => {
+                assert!(xl.len() == function.dim() && xu.len() == function.dim());
+                function.as_mut_ptr()
+            }
+            None => return Err(Error::new(Value::Invalid, "gsl_monte_vegas_integrate")),
+        };
         let mut result = 0f64;
         let mut abserr = 0f64;
-        let f: Box<F> = Box::new(f);
         let ret = unsafe {
-            let mut func = sys::gsl_monte_function {
-                f: transmute(monte_trampoline::<F> as usize),
-                dim: xl.len() as _,
-                params: Box::into_raw(f) as *mut _,
-            };
             sys::gsl_monte_vegas_integrate(
-                &mut func,
+                func,
                 xl.as_ptr() as usize as *mut _,
                 xu.as_ptr() as usize as *mut _,
                 xl.len() as _,
@@ -420,7 +485,7 @@ impl VegasMonteCarlo {
                 &mut abserr,
             )
         };
-        (::Value::from(ret), result, abserr)
+        result_handler!(ret, (result, abserr), "gsl_monte_vegas_integrate")
     }
 
     /// This function returns the chi-squared per degree of freedom for the weighted estimate of the integral.
@@ -444,8 +509,9 @@ impl VegasMonteCarlo {
         (result, sigma)
     }
 
+    /// This function returns the current parameters of the integration.
     #[doc(alias = "gsl_monte_vegas_params_get")]
-    pub fn get_params(&self) -> VegasParams {
+    pub fn get_params(&self) -> VegasParams<'_> {
         let mut params = VegasParams::default();
         unsafe {
             sys::gsl_monte_vegas_params_get(self.unwrap_shared(), &mut params.inner as *mut _);
@@ -453,6 +519,44 @@ impl VegasMonteCarlo {
         params
     }
 
+    /// This function sets the integrator parameters based on values provided in `params`, which
+    /// are used by the next calls to [`integrate`](Self::integrate) and
+    /// [`integrate_again`](Self::integrate_again).
+    ///
+    /// ```
+    /// use rgsl::{Rng, RngType, VegasMonteCarlo, VegasParams};
+    /// use std::f64::consts::PI;
+    ///
+    /// let scale = 1. / (PI * PI * PI);
+    /// let mut r = Rng::new(RngType::default()).unwrap();
+    /// let mut s = VegasMonteCarlo::new(3).unwrap();
+    ///
+    /// // Warm-up, to tune the grid.
+    /// s.integrate(
+    ///     |k| scale / (1. - k[0].cos() * k[1].cos() * k[2].cos()),
+    ///     &[0.; 3],
+    ///     &[PI; 3],
+    ///     10_000,
+    ///     &mut r,
+    /// )
+    /// .unwrap();
+    ///
+    /// // Keep the tuned grid but discard the warm-up estimates.
+    /// let mut params = VegasParams::default();
+    /// params.set_stage(1);
+    /// s.set_params(&params);
+    /// let (mut result, mut abserr) = s.integrate_again(&[0.; 3], &[PI; 3], 100_000, &mut r).unwrap();
+    ///
+    /// // Then keep refining until the estimates are consistent.
+    /// params.set_stage(3);
+    /// s.set_params(&params);
+    /// while (s.chisq() - 1.).abs() > 0.5 {
+    ///     let (res, err) = s.integrate_again(&[0.; 3], &[PI; 3], 100_000, &mut r).unwrap();
+    ///     result = res;
+    ///     abserr = err;
+    /// }
+    /// println!("result = {} +/- {}", result, abserr);
+    /// ```
     #[doc(alias = "gsl_monte_vegas_params_set")]
     pub fn set_params(&mut self, params: &VegasParams) {
         unsafe {
@@ -498,7 +602,7 @@ impl<'a> VegasParams<'a> {
         mode: ::VegasMode,
         verbosity: VegasVerbosity,
         stream: Option<&'a mut ::IOStream>,
-    ) -> Result<VegasParams, String> {
+    ) -> Result<VegasParams<'a>, String> {
         if !verbosity.is_off() && stream.is_none() {
             return Err(
                 "rust-GSL: need to provide an input stream for Vegas Monte Carlo \
@@ -535,6 +639,59 @@ impl<'a> VegasParams<'a> {
     }
 }
 
+impl<'a> VegasParams<'a> {
+    /// The parameter `alpha` controls the stiffness of the rebinning algorithm. It is typically
+    /// set between one and two. A value of zero prevents rebinning of the grid. The default value
+    /// is 1.5.
+    pub fn alpha(&self) -> f64 {
+        self.inner.alpha
+    }
+
+    pub fn set_alpha(&mut self, alpha: f64) -> &mut VegasParams<'a> {
+        self.inner.alpha = alpha;
+        self
+    }
+
+    /// The number of iterations to perform for each call to the routine. The default value is 5
+    /// iterations.
+    pub fn iterations(&self) -> usize {
+        self.inner.iterations
+    }
+
+    pub fn set_iterations(&mut self, iterations: usize) -> &mut VegasParams<'a> {
+        self.inner.iterations = iterations;
+        self
+    }
+
+    /// Setting this determines the stage of the calculation. Normally, `stage` = 0 which begins
+    /// with a new uniform grid and empty weighted average. Calling VEGAS with `stage` = 1 retains
+    /// the grid from the previous run but discards the weighted average, so that one can "tune"
+    /// the grid using a relatively small number of points and then do a large run with `stage`
+    /// = 1 on the optimized grid. Setting `stage` = 2 keeps the grid and the weighted average
+    /// from the previous run, but may increase (or decrease) the number of histogram bins in the
+    /// grid depending on the number of calls available. Choosing `stage` = 3 enters at the main
+    /// loop, so that nothing is changed, and is equivalent to performing additional iterations in
+    /// a previous call.
+    pub fn stage(&self) -> i32 {
+        self.inner.stage
+    }
+
+    pub fn set_stage(&mut self, stage: i32) -> &mut VegasParams<'a> {
+        self.inner.stage = stage;
+        self
+    }
+
+    /// The sampling method, see [`VegasMode`](crate::VegasMode).
+    pub fn mode(&self) -> ::VegasMode {
+        ::VegasMode::from(self.inner.mode)
+    }
+
+    pub fn set_mode(&mut self, mode: ::VegasMode) -> &mut VegasParams<'a> {
+        self.inner.mode = mode.into();
+        self
+    }
+}
+
 impl<'a> ::std::default::Default for VegasParams<'a> {
     fn default() -> VegasParams<'a> {
         VegasParams {
@@ -578,15 +735,6 @@ impl VegasVerbosity {
     }
 }
 
-unsafe extern "C" fn monte_trampoline<F: FnMut(&[f64]) -> f64>(
-    x: *mut f64,
-    dim: usize,
-    param: *mut c_void,
-) -> f64 {
-    let f: &mut F = &mut *(param as *mut F);
-    f(slice::from_raw_parts(x, dim))
-}
-
 // The following tests have been made and tested against the following C code:
 //
 // ```ignore
@@ -677,7 +825,7 @@ fn plain() {
     {
         let mut s = PlainMonteCarlo::new(3).unwrap();
 
-        let (_, res, err) = s.integrate(g, &xl, &xu, calls, &mut r);
+        let (res, err) = s.integrate(g, &xl, &xu, calls, &mut r).unwrap();
         assert_eq!(&format!("{:.6}", res), "1.412209");
         assert_eq!(&format!("{:.6}", err), "0.013436");
     }
@@ -703,7 +851,7 @@ fn miser() {
     {
         let mut s = MiserMonteCarlo::new(3).unwrap();
 
-        let (_, res, err) = s.integrate(g, &xl, &xu, calls, &mut r);
+        let (res, err) = s.integrate(g, &xl, &xu, calls, &mut r).unwrap();
         assert_eq!(&format!("{:.6}", res), "1.389530");
         assert_eq!(&format!("{:.6}", err), "0.005011");
     }
@@ -724,17 +872,19 @@ fn miser_closure() {
     {
         let mut s = MiserMonteCarlo::new(3).unwrap();
 
-        let (_, res, err) = s.integrate(
-            |k| {
-                let a = 1f64 / (PI * PI * PI);
-
-                a / (1.0 - k[0].cos() * k[1].cos() * k[2].cos())
-            },
-            &xl,
-            &xu,
-            calls,
-            &mut r,
-        );
+        let (res, err) = s
+            .integrate(
+                |k| {
+                    let a = 1f64 / (PI * PI * PI);
+
+                    a / (1.0 - k[0].cos() * k[1].cos() * k[2].cos())
+                },
+                &xl,
+                &xu,
+                calls,
+                &mut r,
+            )
+            .unwrap();
         assert_eq!(&format!("{:.6}", res), "1.389530");
         assert_eq!(&format!("{:.6}", err), "0.005011");
     }
@@ -758,7 +908,7 @@ fn vegas_warm_up() {
     {
         let mut s = VegasMonteCarlo::new(3).unwrap();
 
-        let (_, res, err) = s.integrate(g, &xl, &xu, 10000, &mut r);
+        let (res, err) = s.integrate(g, &xl, &xu, 10000, &mut r).unwrap();
         assert_eq!(&format!("{:.6}", res), "1.385603");
         assert_eq!(&format!("{:.6}", err), "0.002212");
     }
@@ -784,11 +934,11 @@ fn vegas() {
     {
         let mut s = VegasMonteCarlo::new(3).unwrap();
 
-        s.integrate(g, &xl, &xu, 10000, &mut r);
+        s.integrate(g, &xl, &xu, 10000, &mut r).unwrap();
         let mut res;
         let mut err;
         loop {
-            let (_, _res, _err) = s.integrate(g, &xl, &xu, calls / 5, &mut r);
+            let (_res, _err) = s.integrate(g, &xl, &xu, calls / 5, &mut r).unwrap();
             res = _res;
             err = _err;
             println!(
@@ -805,3 +955,29 @@ fn vegas() {
         assert_eq!(&format!("{:.6}", err), "0.000335");
     }
 }
+
+#[test]
+fn plain_keeps_closure() {
+    let mut calls = 0;
+    let mut r = ::Rng::new(::RngType::default()).unwrap();
+    let mut s = PlainMonteCarlo::new(1).unwrap();
+
+    assert!(s.integrate_again(&[0.], &[2.], 10, &mut r).is_err());
+    let (res, _) = s
+        .integrate(
+            |_| {
+                calls += 1;
+                1.
+            },
+            &[0.],
+            &[2.],
+            1000,
+            &mut r,
+        )
+        .unwrap();
+    assert_eq!(res, 2.);
+    let (res, _) = s.integrate_again(&[0.], &[4.], 1000, &mut r).unwrap();
+    assert_eq!(res, 4.);
+    drop(s);
+    assert_eq!(calls, 2000);
+}
@@ -0,0 +1,288 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Moving Window Statistics
+
+This chapter describes routines for computing moving window statistics (also called rolling
+statistics and running statistics), using a window around a sample which is used to calculate
+various local statistical properties of an input data stream. The window is then slid forward by
+one sample to process the next data point and so on.
+
+The window around the sample x_i is made of the H samples before it, x_i itself and the J samples
+after it. [`MovStatWorkspace::new`] creates a symmetric window of `K` samples (H = J = K / 2),
+[`MovStatWorkspace::with_window`] lets you choose H and J.
+
+```
+use rgsl::{MovStatEnd, MovStatWorkspace, VectorF64};
+
+let x = VectorF64::from_slice(&[1., 2., 3., 4., 5.]).unwrap();
+let mut y = VectorF64::new(5).unwrap();
+let mut w = MovStatWorkspace::new(3).unwrap();
+
+w.mean(MovStatEnd::Truncate, &x, &mut y).unwrap();
+assert_eq!(y.as_slice().unwrap(), &[1.5, 2., 3., 4., 4.5]);
+
+// User-defined statistics only have to be computed from the window.
+w.apply(MovStatEnd::PadZero, |window| window.iter().cloned().fold(0., f64::max), &x, &mut y)
+    .unwrap();
+assert_eq!(y.as_slice().unwrap(), &[2., 3., 4., 5., 5.]);
+```
+!*/
+
+use crate::paste::paste;
+use crate::{Error, MovStatEnd, VectorF64};
+use ffi::FFI;
+use std::os::raw::c_void;
+
+ffi_wrapper!(
+    MovStatWorkspace,
+    *mut sys::gsl_movstat_workspace,
+    gsl_movstat_free
+);
+
+unsafe impl Send for MovStatWorkspace {}
+
+// `gsl_movstat_function` is opaque in the bindings.
+#[repr(C)]
+struct RawFunction {
+    function: unsafe extern "C" fn(usize, *mut f64, *mut c_void) -> f64,
+    params: *mut c_void,
+}
+
+macro_rules! movstat {
+    ($(#[$attr:meta])* $name:ident, $y:ident) => {
+        paste! {
+            $(#[$attr])*
+            #[doc(alias = gsl_movstat_ $name)]
+            pub fn $name(&mut self, endtype: MovStatEnd, x: &VectorF64, $y: &mut VectorF64) -> Result<(), Error> {
+                let ret = unsafe {
+                    sys::[<gsl_movstat_ $name>](
+                        endtype.into(),
+                        x.unwrap_shared(),
+                        $y.unwrap_unique(),
+                        self.unwrap_unique(),
+                    )
+                };
+                result_handler!(ret, (), concat!("gsl_movstat_", stringify!($name)))
+            }
+        }
+    };
+}
+
+impl MovStatWorkspace {
+    /// This function allocates a workspace for computing symmetric moving window statistics
+    /// with a window of `K` samples. If `K` is even, it is rounded up to the next odd number.
+    #[doc(alias = "gsl_movstat_alloc")]
+    pub fn new(K: usize) -> Option<MovStatWorkspace> {
+        let tmp = unsafe { sys::gsl_movstat_alloc(K) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function allocates a workspace for computing moving window statistics with a window
+    /// made of the `H` samples before the current one and the `J` samples after it.
+    #[doc(alias = "gsl_movstat_alloc2")]
+    pub fn with_window(H: usize, J: usize) -> Option<MovStatWorkspace> {
+        let tmp = unsafe { sys::gsl_movstat_alloc2(H, J) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    movstat!(
+        /// This function computes the moving window mean of the input vector `x`, storing the
+        /// output in `y`. It is allowed to have `x` = `y` for an in-place moving mean.
+        mean,
+        y
+    );
+
+    movstat!(
+        /// This function computes the moving window variance of the input vector `x`, storing
+        /// the output in `y`.
+        variance,
+        y
+    );
+
+    movstat!(
+        /// This function computes the moving window standard deviation of the input vector `x`,
+        /// storing the output in `y`.
+        sd,
+        y
+    );
+
+    movstat!(
+        /// This function computes the moving median of the input vector `x`, storing the output
+        /// in `y`.
+        median,
+        y
+    );
+
+    movstat!(
+        /// This function computes the moving minimum of the input vector `x`, storing the output
+        /// in `y`.
+        min,
+        y
+    );
+
+    movstat!(
+        /// This function computes the moving maximum of the input vector `x`, storing the output
+        /// in `y`.
+        max,
+        y
+    );
+
+    movstat!(
+        /// This function computes the moving window sum of the input vector `x`, storing the
+        /// output in `y`.
+        sum,
+        y
+    );
+
+    movstat!(
+        /// This function computes the moving window Q_n statistic of the input vector `x`,
+        /// storing the output in `xscale`.
+        Qn,
+        xscale
+    );
+
+    movstat!(
+        /// This function computes the moving window S_n statistic of the input vector `x`,
+        /// storing the output in `xscale`.
+        Sn,
+        xscale
+    );
+
+    /// This function computes the moving minimum and maximum of the input vector `x`, storing
+    /// the window minimums in `y_min` and the window maximums in `y_max`.
+    #[doc(alias = "gsl_movstat_minmax")]
+    pub fn minmax(
+        &mut self,
+        endtype: MovStatEnd,
+        x: &VectorF64,
+        y_min: &mut VectorF64,
+        y_max: &mut VectorF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_movstat_minmax(
+                endtype.into(),
+                x.unwrap_shared(),
+                y_min.unwrap_unique(),
+                y_max.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, (), "gsl_movstat_minmax")
+    }
+
+    /// This function computes the moving MAD of the input vector `x` and stores the result in
+    /// `xmad`. The medians of each window are stored in `xmedian` on output. The MAD is scaled
+    /// by 1.4826 to be an unbiased estimator of the standard deviation of Gaussian data.
+    #[doc(alias = "gsl_movstat_mad")]
+    pub fn mad(
+        &mut self,
+        endtype: MovStatEnd,
+        x: &VectorF64,
+        xmedian: &mut VectorF64,
+        xmad: &mut VectorF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_movstat_mad(
+                endtype.into(),
+                x.unwrap_shared(),
+                xmedian.unwrap_unique(),
+                xmad.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, (), "gsl_movstat_mad")
+    }
+
+    /// Same as [`mad`](Self::mad), but without the scale factor.
+    #[doc(alias = "gsl_movstat_mad0")]
+    pub fn mad0(
+        &mut self,
+        endtype: MovStatEnd,
+        x: &VectorF64,
+        xmedian: &mut VectorF64,
+        xmad: &mut VectorF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_movstat_mad0(
+                endtype.into(),
+                x.unwrap_shared(),
+                xmedian.unwrap_unique(),
+                xmad.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, (), "gsl_movstat_mad0")
+    }
+
+    /// This function computes the moving window q-quantile range (QQR) of the input vector
+    /// `x`, storing the output in `xqqr`. The quantile parameter `q` must be between 0 and 0.5.
+    /// The input `q` = 0.25 corresponds to the interquartile range.
+    #[doc(alias = "gsl_movstat_qqr")]
+    pub fn qqr(
+        &mut self,
+        endtype: MovStatEnd,
+        x: &VectorF64,
+        q: f64,
+        xqqr: &mut VectorF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_movstat_qqr(
+                endtype.into(),
+                x.unwrap_shared(),
+                q,
+                xqqr.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, (), "gsl_movstat_qqr")
+    }
+
+    /// This function applies the user-defined moving window statistic `f` to the input vector
+    /// `x`, storing the output in `y`. `f` is given the samples of each window, which it may
+    /// reorder, and returns the statistic of the window.
+    #[doc(alias = "gsl_movstat_apply")]
+    pub fn apply<F: FnMut(&mut [f64]) -> f64>(
+        &mut self,
+        endtype: MovStatEnd,
+        mut f: F,
+        x: &VectorF64,
+        y: &mut VectorF64,
+    ) -> Result<(), Error> {
+        unsafe extern "C" fn trampoline<F: FnMut(&mut [f64]) -> f64>(
+            n: usize,
+            x: *mut f64,
+            params: *mut c_void,
+        ) -> f64 {
+            let f: &mut F = &mut *(params as *mut F);
+            ::error::catch_callback(f64::NAN, || f(::std::slice::from_raw_parts_mut(x, n)))
+        }
+
+        let function = RawFunction {
+            function: trampoline::<F>,
+            params: &mut f as *mut F as *mut _,
+        };
+        let ret = unsafe {
+            sys::gsl_movstat_apply(
+                endtype.into(),
+                &function as *const RawFunction as *const _,
+                x.unwrap_shared(),
+                y.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, (), "gsl_movstat_apply")
+    }
+}
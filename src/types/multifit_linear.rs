@@ -2,15 +2,56 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::{MatrixF64, Value, VectorF64};
+use crate::{Error, MatrixF64, Value, VectorF64};
 use ffi::FFI;
 
+/// The best-fit parameters of a multiparameter linear fit, as returned by
+/// [`MultifitLinearWorkspace::fit`] and its variants.
+#[derive(Debug)]
+pub struct FitResult {
+    /// The best-fit parameters.
+    pub c: VectorF64,
+    /// The variance-covariance matrix of the parameters.
+    pub cov: MatrixF64,
+    /// The (weighted) sum of squares of the residuals.
+    pub chisq: f64,
+    /// The effective rank of the predictor matrix, for the SVD based fits which truncate small
+    /// singular values.
+    pub rank: Option<usize>,
+}
+
+impl FitResult {
+    fn alloc(X: &MatrixF64) -> Result<(VectorF64, MatrixF64), Error> {
+        let p = X.size2();
+        match (VectorF64::new(p), MatrixF64::new(p, p)) {
+            (Some(c), Some(cov)) => Ok((c, cov)),
+            _ => Err(Error::new(Value::NoMemory, "gsl_multifit_linear")),
+        }
+    }
+
+    /// Computes the fitted value and its standard deviation for the predictors `x`.
+    ///
+    /// Returns `(y, y_err)`.
+    #[doc(alias = "gsl_multifit_linear_est")]
+    pub fn est(&self, x: &VectorF64) -> Result<(f64, f64), Error> {
+        ::multilinear::linear_est(x, &self.c, &self.cov)
+    }
+
+    /// Computes the residuals `r = y - X c` of the fit for the observations `y`.
+    #[doc(alias = "gsl_multifit_linear_residuals")]
+    pub fn residuals(&self, X: &MatrixF64, y: &VectorF64, r: &mut VectorF64) -> Result<(), Error> {
+        ::multilinear::linear_residuals(X, y, &self.c, r)
+    }
+}
+
 ffi_wrapper!(
     MultifitLinearWorkspace,
     *mut sys::gsl_multifit_linear_workspace,
     gsl_multifit_linear_free
 );
 
+unsafe impl Send for MultifitLinearWorkspace {}
+
 impl MultifitLinearWorkspace {
     #[doc(alias = "gsl_multifit_linear_alloc")]
     pub fn new(n: usize, p: usize) -> Option<Self> {
@@ -22,7 +63,7 @@ impl MultifitLinearWorkspace {
         }
     }
 
-    /// Returns `(Value, chisq)`.
+    /// Returns `chisq`.
     #[doc(alias = "gsl_multifit_linear")]
     pub fn linear(
         &mut self,
@@ -30,7 +71,7 @@ impl MultifitLinearWorkspace {
         y: &VectorF64,
         c: &mut VectorF64,
         cov: &mut MatrixF64,
-    ) -> (Value, f64) {
+    ) -> Result<f64, Error> {
         let mut chisq = 0.;
         let ret = unsafe {
             sys::gsl_multifit_linear(
@@ -42,10 +83,79 @@ impl MultifitLinearWorkspace {
                 self.unwrap_unique(),
             )
         };
-        (Value::from(ret), chisq)
+        result_handler!(ret, chisq, "gsl_multifit_linear")
+    }
+
+    /// Same as [`linear`](#method.linear), but allocates the parameter vector and the covariance
+    /// matrix.
+    #[doc(alias = "gsl_multifit_linear")]
+    pub fn fit(&mut self, x: &MatrixF64, y: &VectorF64) -> Result<FitResult, Error> {
+        let (mut c, mut cov) = FitResult::alloc(x)?;
+        let chisq = self.linear(x, y, &mut c, &mut cov)?;
+        Ok(FitResult {
+            c,
+            cov,
+            chisq,
+            rank: None,
+        })
     }
 
-    /// Returns `(Value, chisq, rank)`.
+    /// Same as [`wlinear`](#method.wlinear), but allocates the parameter vector and the
+    /// covariance matrix.
+    #[doc(alias = "gsl_multifit_wlinear")]
+    pub fn wfit(
+        &mut self,
+        x: &MatrixF64,
+        w: &VectorF64,
+        y: &VectorF64,
+    ) -> Result<FitResult, Error> {
+        let (mut c, mut cov) = FitResult::alloc(x)?;
+        let chisq = self.wlinear(x, w, y, &mut c, &mut cov)?;
+        Ok(FitResult {
+            c,
+            cov,
+            chisq,
+            rank: None,
+        })
+    }
+
+    /// Same as [`linear_tsvd`](#method.linear_tsvd), but allocates the parameter vector and the
+    /// covariance matrix. Singular values below `tol` times the largest one are discarded.
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
+    #[doc(alias = "gsl_multifit_linear_tsvd")]
+    pub fn fit_tsvd(&mut self, x: &MatrixF64, y: &VectorF64, tol: f64) -> Result<FitResult, Error> {
+        let (mut c, mut cov) = FitResult::alloc(x)?;
+        let (chisq, rank) = self.linear_tsvd(x, y, tol, &mut c, &mut cov)?;
+        Ok(FitResult {
+            c,
+            cov,
+            chisq,
+            rank: Some(rank),
+        })
+    }
+
+    /// Same as [`wlinear_svd`](#method.wlinear_svd), but allocates the parameter vector and the
+    /// covariance matrix. Singular values below `tol` times the largest one are discarded.
+    #[doc(alias = "gsl_multifit_wlinear_svd")]
+    pub fn wfit_svd(
+        &mut self,
+        x: &MatrixF64,
+        w: &VectorF64,
+        y: &VectorF64,
+        tol: f64,
+    ) -> Result<FitResult, Error> {
+        let (mut c, mut cov) = FitResult::alloc(x)?;
+        let (rank, chisq) = self.wlinear_svd(x, w, y, tol, &mut c, &mut cov)?;
+        Ok(FitResult {
+            c,
+            cov,
+            chisq,
+            rank: Some(rank),
+        })
+    }
+
+    /// Returns `(chisq, rank)`.
     #[cfg(feature = "v2_3")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     #[doc(alias = "gsl_multifit_linear_tsvd")]
@@ -56,7 +166,7 @@ impl MultifitLinearWorkspace {
         tol: f64,
         c: &mut VectorF64,
         cov: &mut MatrixF64,
-    ) -> (Value, f64, usize) {
+    ) -> Result<(f64, usize), Error> {
         let mut chisq = 0.;
         let mut rank = 0;
         let ret = unsafe {
@@ -71,27 +181,19 @@ impl MultifitLinearWorkspace {
                 self.unwrap_unique(),
             )
         };
-        (Value::from(ret), chisq, rank)
+        result_handler!(ret, (chisq, rank), "gsl_multifit_linear_tsvd")
     }
 
     #[doc(alias = "gsl_multifit_linear_svd")]
-    pub fn linear_svd(&mut self, x: &MatrixF64) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multifit_linear_svd(
-                x.unwrap_shared(),
-                self.unwrap_unique(),
-            ))
-        }
+    pub fn linear_svd(&mut self, x: &MatrixF64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_multifit_linear_svd(x.unwrap_shared(), self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_multifit_linear_svd")
     }
 
     #[doc(alias = "gsl_multifit_linear_bsvd")]
-    pub fn linear_linear_bsvd(&mut self, x: &MatrixF64) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multifit_linear_bsvd(
-                x.unwrap_shared(),
-                self.unwrap_unique(),
-            ))
-        }
+    pub fn linear_bsvd(&mut self, x: &MatrixF64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_multifit_linear_bsvd(x.unwrap_shared(), self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_multifit_linear_bsvd")
     }
 
     #[cfg(feature = "v2_3")]
@@ -101,7 +203,7 @@ impl MultifitLinearWorkspace {
         unsafe { sys::gsl_multifit_linear_rank(tol, self.unwrap_shared()) }
     }
 
-    /// Returns `(Value, rnorm, snorm)`.
+    /// Returns `(rnorm, snorm)`.
     #[doc(alias = "gsl_multifit_linear_solve")]
     pub fn linear_solve(
         &mut self,
@@ -109,7 +211,7 @@ impl MultifitLinearWorkspace {
         x: &MatrixF64,
         y: &VectorF64,
         c: &mut VectorF64,
-    ) -> (Value, f64, f64) {
+    ) -> Result<(f64, f64), Error> {
         let mut rnorm = 0.;
         let mut snorm = 0.;
         let ret = unsafe {
@@ -123,7 +225,7 @@ impl MultifitLinearWorkspace {
                 self.unwrap_unique(),
             )
         };
-        (Value::from(ret), rnorm, snorm)
+        result_handler!(ret, (rnorm, snorm), "gsl_multifit_linear_solve")
     }
 
     #[doc(alias = "gsl_multifit_linear_stdform1")]
@@ -134,17 +236,18 @@ impl MultifitLinearWorkspace {
         y: &VectorF64,
         xs: &mut MatrixF64,
         ys: &mut VectorF64,
-    ) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multifit_linear_stdform1(
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multifit_linear_stdform1(
                 l.unwrap_shared(),
                 x.unwrap_shared(),
                 y.unwrap_shared(),
                 xs.unwrap_unique(),
                 ys.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multifit_linear_stdform1")
     }
 
     #[doc(alias = "gsl_multifit_linear_wstdform1")]
@@ -156,9 +259,9 @@ impl MultifitLinearWorkspace {
         y: &VectorF64,
         xs: &mut MatrixF64,
         ys: &mut VectorF64,
-    ) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multifit_linear_wstdform1(
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multifit_linear_wstdform1(
                 l.unwrap_shared(),
                 x.unwrap_shared(),
                 w.unwrap_shared(),
@@ -166,8 +269,9 @@ impl MultifitLinearWorkspace {
                 xs.unwrap_unique(),
                 ys.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multifit_linear_wstdform1")
     }
 
     #[doc(alias = "gsl_multifit_linear_stdform2")]
@@ -180,9 +284,9 @@ impl MultifitLinearWorkspace {
         xs: &mut MatrixF64,
         ys: &mut VectorF64,
         m: &mut MatrixF64,
-    ) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multifit_linear_stdform2(
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multifit_linear_stdform2(
                 lqr.unwrap_shared(),
                 ltau.unwrap_shared(),
                 x.unwrap_shared(),
@@ -191,8 +295,9 @@ impl MultifitLinearWorkspace {
                 ys.unwrap_unique(),
                 m.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multifit_linear_stdform2")
     }
 
     #[doc(alias = "gsl_multifit_linear_wstdform2")]
@@ -206,9 +311,9 @@ impl MultifitLinearWorkspace {
         xs: &mut MatrixF64,
         ys: &mut VectorF64,
         m: &mut MatrixF64,
-    ) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multifit_linear_wstdform2(
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multifit_linear_wstdform2(
                 lqr.unwrap_shared(),
                 ltau.unwrap_shared(),
                 x.unwrap_shared(),
@@ -218,20 +323,27 @@ impl MultifitLinearWorkspace {
                 ys.unwrap_unique(),
                 m.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multifit_linear_wstdform2")
     }
 
     #[doc(alias = "gsl_multifit_linear_genform1")]
-    pub fn linear_genform1(&mut self, l: &VectorF64, cs: &VectorF64, c: &mut VectorF64) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multifit_linear_genform1(
+    pub fn linear_genform1(
+        &mut self,
+        l: &VectorF64,
+        cs: &VectorF64,
+        c: &mut VectorF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multifit_linear_genform1(
                 l.unwrap_shared(),
                 cs.unwrap_shared(),
                 c.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multifit_linear_genform1")
     }
 
     #[doc(alias = "gsl_multifit_linear_genform2")]
@@ -244,9 +356,9 @@ impl MultifitLinearWorkspace {
         cs: &VectorF64,
         m: &MatrixF64,
         c: &mut VectorF64,
-    ) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multifit_linear_genform2(
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multifit_linear_genform2(
                 lqr.unwrap_shared(),
                 ltau.unwrap_shared(),
                 x.unwrap_shared(),
@@ -255,8 +367,9 @@ impl MultifitLinearWorkspace {
                 m.unwrap_shared(),
                 c.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multifit_linear_genform2")
     }
 
     #[doc(alias = "gsl_multifit_linear_wgenform2")]
@@ -270,9 +383,9 @@ impl MultifitLinearWorkspace {
         cs: &VectorF64,
         m: &MatrixF64,
         c: &mut VectorF64,
-    ) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multifit_linear_wgenform2(
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multifit_linear_wgenform2(
                 lqr.unwrap_shared(),
                 ltau.unwrap_shared(),
                 x.unwrap_shared(),
@@ -282,8 +395,9 @@ impl MultifitLinearWorkspace {
                 m.unwrap_shared(),
                 c.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multifit_linear_wgenform2")
     }
 
     #[doc(alias = "gsl_multifit_linear_lcurve")]
@@ -293,16 +407,17 @@ impl MultifitLinearWorkspace {
         reg_param: &mut VectorF64,
         rho: &mut VectorF64,
         eta: &mut VectorF64,
-    ) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multifit_linear_lcurve(
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multifit_linear_lcurve(
                 y.unwrap_shared(),
                 reg_param.unwrap_unique(),
                 rho.unwrap_unique(),
                 eta.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multifit_linear_lcurve")
     }
 
     #[doc(alias = "gsl_multifit_linear_Lsobolev")]
@@ -312,19 +427,20 @@ impl MultifitLinearWorkspace {
         kmax: usize,
         alpha: &VectorF64,
         l: &mut MatrixF64,
-    ) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multifit_linear_Lsobolev(
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multifit_linear_Lsobolev(
                 p,
                 kmax,
                 alpha.unwrap_shared(),
                 l.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multifit_linear_Lsobolev")
     }
 
-    /// Returns `(Value, chisq)`.
+    /// Returns `chisq`.
     #[doc(alias = "gsl_multifit_wlinear")]
     pub fn wlinear(
         &mut self,
@@ -333,7 +449,7 @@ impl MultifitLinearWorkspace {
         y: &VectorF64,
         c: &mut VectorF64,
         cov: &mut MatrixF64,
-    ) -> (Value, f64) {
+    ) -> Result<f64, Error> {
         let mut chisq = 0.;
         let ret = unsafe {
             sys::gsl_multifit_wlinear(
@@ -346,10 +462,10 @@ impl MultifitLinearWorkspace {
                 self.unwrap_unique(),
             )
         };
-        (Value::from(ret), chisq)
+        result_handler!(ret, chisq, "gsl_multifit_wlinear")
     }
 
-    /// Returns `(Value, chisq, rank)`.
+    /// Returns `(chisq, rank)`.
     #[cfg(feature = "v2_3")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     #[doc(alias = "gsl_multifit_wlinear_tsvd")]
@@ -361,7 +477,7 @@ impl MultifitLinearWorkspace {
         tol: f64,
         c: &mut VectorF64,
         cov: &mut MatrixF64,
-    ) -> (Value, f64, usize) {
+    ) -> Result<(f64, usize), Error> {
         let mut chisq = 0.;
         let mut rank = 0;
         let ret = unsafe {
@@ -377,10 +493,10 @@ impl MultifitLinearWorkspace {
                 self.unwrap_unique(),
             )
         };
-        (Value::from(ret), chisq, rank)
+        result_handler!(ret, (chisq, rank), "gsl_multifit_wlinear_tsvd")
     }
 
-    /// Returns `(Value, rank, chisq)`.
+    /// Returns `(rank, chisq)`.
     #[doc(alias = "gsl_multifit_wlinear_svd")]
     pub fn wlinear_svd(
         &mut self,
@@ -390,7 +506,7 @@ impl MultifitLinearWorkspace {
         tol: f64,
         c: &mut VectorF64,
         cov: &mut MatrixF64,
-    ) -> (Value, usize, f64) {
+    ) -> Result<(usize, f64), Error> {
         let mut rank = 0;
         let mut chisq = 0.;
         let ret = unsafe {
@@ -406,10 +522,10 @@ impl MultifitLinearWorkspace {
                 self.unwrap_unique(),
             )
         };
-        (Value::from(ret), rank, chisq)
+        result_handler!(ret, (rank, chisq), "gsl_multifit_wlinear_svd")
     }
 
-    /// Returns `(Value, rank, chisq)`.
+    /// Returns `(rank, chisq)`.
     #[doc(alias = "gsl_multifit_wlinear_usvd")]
     pub fn wlinear_usvd(
         &mut self,
@@ -419,7 +535,7 @@ impl MultifitLinearWorkspace {
         tol: f64,
         c: &mut VectorF64,
         cov: &mut MatrixF64,
-    ) -> (Value, usize, f64) {
+    ) -> Result<(usize, f64), Error> {
         let mut rank = 0;
         let mut chisq = 0.;
         let ret = unsafe {
@@ -435,7 +551,7 @@ impl MultifitLinearWorkspace {
                 self.unwrap_unique(),
             )
         };
-        (Value::from(ret), rank, chisq)
+        result_handler!(ret, (rank, chisq), "gsl_multifit_wlinear_usvd")
     }
 
     #[cfg(feature = "v2_1")]
@@ -445,14 +561,14 @@ impl MultifitLinearWorkspace {
         unsafe { sys::gsl_multifit_linear_rcond(self.unwrap_unique()) }
     }
 
-    /// Returns `(Value, delta0)`.
+    /// Returns `delta0`.
     #[doc(alias = "gsl_multifit_linear_gcv_init")]
     pub fn linear_gcv_init(
         &mut self,
         y: &VectorF64,
         reg_param: &mut VectorF64,
         UTy: &mut VectorF64,
-    ) -> (Value, f64) {
+    ) -> Result<f64, Error> {
         let mut delta0 = 0.;
         let ret = unsafe {
             sys::gsl_multifit_linear_gcv_init(
@@ -463,7 +579,7 @@ impl MultifitLinearWorkspace {
                 self.unwrap_unique(),
             )
         };
-        (Value::from(ret), delta0)
+        result_handler!(ret, delta0, "gsl_multifit_linear_gcv_init")
     }
 
     #[doc(alias = "gsl_multifit_linear_gcv_curve")]
@@ -473,19 +589,20 @@ impl MultifitLinearWorkspace {
         UTy: &VectorF64,
         delta0: f64,
         g: &mut VectorF64,
-    ) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multifit_linear_gcv_curve(
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multifit_linear_gcv_curve(
                 reg_param.unwrap_shared(),
                 UTy.unwrap_shared(),
                 delta0,
                 g.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multifit_linear_gcv_curve")
     }
 
-    /// Returns `(Value, lambda)`.
+    /// Returns `lambda`.
     #[doc(alias = "gsl_multifit_linear_gcv_min")]
     pub fn linear_gcv_min(
         &mut self,
@@ -493,7 +610,7 @@ impl MultifitLinearWorkspace {
         UTy: &VectorF64,
         g: &VectorF64,
         delta0: f64,
-    ) -> (Value, f64) {
+    ) -> Result<f64, Error> {
         let mut lambda = 0.;
         let ret = unsafe {
             sys::gsl_multifit_linear_gcv_min(
@@ -505,7 +622,7 @@ impl MultifitLinearWorkspace {
                 self.unwrap_unique(),
             )
         };
-        (Value::from(ret), lambda)
+        result_handler!(ret, lambda, "gsl_multifit_linear_gcv_min")
     }
 
     #[doc(alias = "gsl_multifit_linear_gcv_calc")]
@@ -520,14 +637,14 @@ impl MultifitLinearWorkspace {
         }
     }
 
-    /// Returns `(Value, lambda, g_lambda)`.
+    /// Returns `(lambda, g_lambda)`.
     #[doc(alias = "gsl_multifit_linear_gcv")]
     pub fn linear_gcv(
         &mut self,
         y: &VectorF64,
         reg_param: &mut VectorF64,
         g: &mut VectorF64,
-    ) -> (Value, f64, f64) {
+    ) -> Result<(f64, f64), Error> {
         let mut lambda = 0.;
         let mut g_lambda = 0.;
         let ret = unsafe {
@@ -540,6 +657,30 @@ impl MultifitLinearWorkspace {
                 self.unwrap_unique(),
             )
         };
-        (Value::from(ret), lambda, g_lambda)
+        result_handler!(ret, (lambda, g_lambda), "gsl_multifit_linear_gcv")
+    }
+}
+
+#[test]
+fn fit_result() {
+    // y = 1 + 2 t sampled at t = 0..4.
+    let mut X = MatrixF64::new(4, 2).unwrap();
+    let mut y = VectorF64::new(4).unwrap();
+    for i in 0..4 {
+        X.set(i, 0, 1.);
+        X.set(i, 1, i as f64);
+        y.set(i, 1. + 2. * i as f64);
     }
+
+    let mut w = MultifitLinearWorkspace::new(4, 2).unwrap();
+    let f = w.fit(&X, &y).unwrap();
+    assert!((f.c.get(0) - 1.).abs() < 1e-10 && (f.c.get(1) - 2.).abs() < 1e-10);
+    assert!(f.chisq < 1e-20);
+    assert_eq!(f.rank, None);
+
+    let x = VectorF64::from_slice(&[1., 10.]).unwrap();
+    assert!((f.est(&x).unwrap().0 - 21.).abs() < 1e-10);
+    let mut r = VectorF64::new(4).unwrap();
+    f.residuals(&X, &y, &mut r).unwrap();
+    assert!((0..4).all(|i| r.get(i).abs() < 1e-10));
 }
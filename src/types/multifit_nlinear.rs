@@ -0,0 +1,710 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Nonlinear Least-Squares Fitting
+
+This chapter describes functions for multidimensional nonlinear least-squares fitting, using the
+trust region methods of `gsl_multifit_nlinear`. The model is given by a residual function
+f_i(x) (and optionally its Jacobian J_ij = d f_i / d x_j), and the solver looks for the `p`
+parameters `x` minimizing the sum of the squares of the `n` residuals.
+
+The Jacobian is approximated with finite differences when it isn't provided (see
+[`MultiFitNLinearParameters::fdtype`]).
+
+## Example
+
+Fitting the model Y(t) = A exp(-lambda t) + b to some data:
+
+```
+use rgsl::{
+    MultiFitNLinear, MultiFitNLinearFunction, MultiFitNLinearParameters, MultiFitNLinearType,
+    Value, VectorF64,
+};
+
+let n = 40;
+let t: Vec<f64> = (0..n).map(|i| i as f64 * 3. / (n - 1) as f64).collect();
+let y: Vec<f64> = t.iter().map(|t| 5. * (-1.5 * t).exp() + 1.).collect();
+
+let fdf = MultiFitNLinearFunction::new(n, 3, |x, f| {
+    let (a, lambda, b) = (x.get(0), x.get(1), x.get(2));
+    for i in 0..n {
+        f.set(i, a * (-lambda * t[i]).exp() + b - y[i]);
+    }
+    Value::Success
+})
+.with_jacobian(|x, j| {
+    let (a, lambda) = (x.get(0), x.get(1));
+    for i in 0..n {
+        let e = (-lambda * t[i]).exp();
+        j.set(i, 0, e);
+        j.set(i, 1, -t[i] * a * e);
+        j.set(i, 2, 1.);
+    }
+    Value::Success
+});
+
+let mut w = MultiFitNLinear::new(
+    MultiFitNLinearType::trust(),
+    &MultiFitNLinearParameters::default(),
+    n,
+    3,
+)
+.unwrap();
+w.init(&VectorF64::from_slice(&[1., 1., 0.]).unwrap(), fdf)
+    .unwrap();
+w.driver(100, 1e-8, 1e-8, 0., |iter, w| {
+    println!("iter {}: chisq = {}", iter, w.chisq());
+})
+.unwrap();
+
+let x = w.position(|x| x.clone());
+assert!((x.get(0) - 5.).abs() < 1e-6);
+assert!((x.get(1) - 1.5).abs() < 1e-6);
+let covar = w.covar(0.).unwrap();
+println!("A = {} +/- {}", x.get(0), covar.get(0, 0).sqrt());
+```
+!*/
+
+use crate::{Error, MatrixF64, MultiFitNLinearFdType, Value, VectorF64};
+use ffi::FFI;
+use std::os::raw::{c_int, c_void};
+
+ffi_wrapper!(
+    MultiFitNLinearType,
+    *const sys::gsl_multifit_nlinear_type,
+    "The type of a nonlinear least-squares solver."
+);
+
+unsafe impl Send for MultiFitNLinearType {}
+unsafe impl Sync for MultiFitNLinearType {}
+
+impl MultiFitNLinearType {
+    /// The trust region method. It is currently the only type provided by GSL, the actual
+    /// algorithm is chosen with [`MultiFitNLinearTrs`].
+    #[doc(alias = "gsl_multifit_nlinear_trust")]
+    pub fn trust() -> MultiFitNLinearType {
+        ffi_wrap!(gsl_multifit_nlinear_trust)
+    }
+}
+
+ffi_wrapper!(
+    MultiFitNLinearTrs,
+    *const sys::gsl_multifit_nlinear_trs,
+    "The trust region subproblem method."
+);
+
+unsafe impl Send for MultiFitNLinearTrs {}
+unsafe impl Sync for MultiFitNLinearTrs {}
+
+impl MultiFitNLinearTrs {
+    /// Levenberg-Marquardt algorithm.
+    #[doc(alias = "gsl_multifit_nlinear_trs_lm")]
+    pub fn lm() -> MultiFitNLinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_lm)
+    }
+
+    /// Levenberg-Marquardt algorithm with geodesic acceleration. It uses the second directional
+    /// derivative of the residuals (see [`MultiFitNLinearFunction::with_fvv`]).
+    #[doc(alias = "gsl_multifit_nlinear_trs_lmaccel")]
+    pub fn lmaccel() -> MultiFitNLinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_lmaccel)
+    }
+
+    /// Powell's dogleg algorithm.
+    #[doc(alias = "gsl_multifit_nlinear_trs_dogleg")]
+    pub fn dogleg() -> MultiFitNLinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_dogleg)
+    }
+
+    /// Double dogleg algorithm.
+    #[doc(alias = "gsl_multifit_nlinear_trs_ddogleg")]
+    pub fn ddogleg() -> MultiFitNLinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_ddogleg)
+    }
+
+    /// Two dimensional subspace algorithm.
+    #[doc(alias = "gsl_multifit_nlinear_trs_subspace2D")]
+    pub fn subspace2D() -> MultiFitNLinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_subspace2D)
+    }
+}
+
+ffi_wrapper!(
+    MultiFitNLinearScale,
+    *const sys::gsl_multifit_nlinear_scale,
+    "The scaling matrix D of the trust region."
+);
+
+unsafe impl Send for MultiFitNLinearScale {}
+unsafe impl Sync for MultiFitNLinearScale {}
+
+impl MultiFitNLinearScale {
+    /// Levenberg scaling: D is the identity matrix. It isn't invariant under a rescaling of the
+    /// parameters.
+    #[doc(alias = "gsl_multifit_nlinear_scale_levenberg")]
+    pub fn levenberg() -> MultiFitNLinearScale {
+        ffi_wrap!(gsl_multifit_nlinear_scale_levenberg)
+    }
+
+    /// Marquardt scaling: D is made of the diagonal elements of J^T J.
+    #[doc(alias = "gsl_multifit_nlinear_scale_marquardt")]
+    pub fn marquardt() -> MultiFitNLinearScale {
+        ffi_wrap!(gsl_multifit_nlinear_scale_marquardt)
+    }
+
+    /// Moré scaling: like Marquardt scaling, but D only grows across the iterations.
+    #[doc(alias = "gsl_multifit_nlinear_scale_more")]
+    pub fn more() -> MultiFitNLinearScale {
+        ffi_wrap!(gsl_multifit_nlinear_scale_more)
+    }
+}
+
+ffi_wrapper!(
+    MultiFitNLinearSolver,
+    *const sys::gsl_multifit_nlinear_solver,
+    "The method used to solve the linear least squares system of each iteration."
+);
+
+unsafe impl Send for MultiFitNLinearSolver {}
+unsafe impl Sync for MultiFitNLinearSolver {}
+
+impl MultiFitNLinearSolver {
+    /// Cholesky decomposition of the normal equations. It is the fastest but the least
+    /// accurate method on ill-conditioned problems.
+    #[doc(alias = "gsl_multifit_nlinear_solver_cholesky")]
+    pub fn cholesky() -> MultiFitNLinearSolver {
+        ffi_wrap!(gsl_multifit_nlinear_solver_cholesky)
+    }
+
+    /// Modified Cholesky decomposition, which can handle matrices which aren't positive
+    /// definite.
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
+    #[doc(alias = "gsl_multifit_nlinear_solver_mcholesky")]
+    pub fn mcholesky() -> MultiFitNLinearSolver {
+        ffi_wrap!(gsl_multifit_nlinear_solver_mcholesky)
+    }
+
+    /// QR decomposition of the Jacobian.
+    #[doc(alias = "gsl_multifit_nlinear_solver_qr")]
+    pub fn qr() -> MultiFitNLinearSolver {
+        ffi_wrap!(gsl_multifit_nlinear_solver_qr)
+    }
+
+    /// Singular value decomposition of the Jacobian. It is the slowest but the most accurate
+    /// method.
+    #[doc(alias = "gsl_multifit_nlinear_solver_svd")]
+    pub fn svd() -> MultiFitNLinearSolver {
+        ffi_wrap!(gsl_multifit_nlinear_solver_svd)
+    }
+}
+
+/// The parameters of a [`MultiFitNLinear`] workspace.
+#[derive(Clone, Copy)]
+pub struct MultiFitNLinearParameters {
+    /// Trust region subproblem method.
+    pub trs: MultiFitNLinearTrs,
+    /// Scaling method.
+    pub scale: MultiFitNLinearScale,
+    /// Solver method.
+    pub solver: MultiFitNLinearSolver,
+    /// Finite difference method used to approximate the Jacobian if it isn't provided.
+    pub fdtype: MultiFitNLinearFdType,
+    /// Factor by which the trust region radius is increased after a successful step.
+    pub factor_up: f64,
+    /// Factor by which the trust region radius is decreased after a rejected step.
+    pub factor_down: f64,
+    /// Maximum allowed ratio of the acceleration and velocity terms, for
+    /// [`MultiFitNLinearTrs::lmaccel`].
+    pub avmax: f64,
+    /// Step size used for the finite differences approximation of the Jacobian.
+    pub h_df: f64,
+    /// Step size used for the finite differences approximation of the second directional
+    /// derivative.
+    pub h_fvv: f64,
+}
+
+impl Default for MultiFitNLinearParameters {
+    /// The parameters returned by `gsl_multifit_nlinear_default_parameters`: a Levenberg-Marquardt
+    /// algorithm with Moré scaling and a QR solver.
+    #[doc(alias = "gsl_multifit_nlinear_default_parameters")]
+    fn default() -> MultiFitNLinearParameters {
+        MultiFitNLinearParameters {
+            trs: MultiFitNLinearTrs::lm(),
+            scale: MultiFitNLinearScale::more(),
+            solver: MultiFitNLinearSolver::qr(),
+            fdtype: MultiFitNLinearFdType::Forward,
+            factor_up: 3.,
+            factor_down: 2.,
+            avmax: 0.75,
+            h_df: f64::EPSILON.sqrt(),
+            h_fvv: 0.02,
+        }
+    }
+}
+
+// `gsl_multifit_nlinear_parameters` and `gsl_multifit_nlinear_fdf` are opaque in the bindings,
+// so they are declared here.
+#[repr(C)]
+struct RawParameters {
+    trs: *const sys::gsl_multifit_nlinear_trs,
+    scale: *const sys::gsl_multifit_nlinear_scale,
+    solver: *const sys::gsl_multifit_nlinear_solver,
+    fdtype: sys::gsl_multifit_nlinear_fdtype,
+    factor_up: f64,
+    factor_down: f64,
+    avmax: f64,
+    h_df: f64,
+    h_fvv: f64,
+}
+
+#[repr(C)]
+struct RawFdf {
+    f: Option<
+        unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void, *mut sys::gsl_vector) -> c_int,
+    >,
+    df: Option<
+        unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void, *mut sys::gsl_matrix) -> c_int,
+    >,
+    fvv: Option<
+        unsafe extern "C" fn(
+            *const sys::gsl_vector,
+            *const sys::gsl_vector,
+            *mut c_void,
+            *mut sys::gsl_vector,
+        ) -> c_int,
+    >,
+    n: usize,
+    p: usize,
+    params: *mut c_void,
+    nevalf: usize,
+    nevaldf: usize,
+    nevalfvv: usize,
+}
+
+type ResidualFn<'a> = Box<dyn FnMut(&VectorF64, &mut VectorF64) -> Value + 'a>;
+type JacobianFn<'a> = Box<dyn FnMut(&VectorF64, &mut MatrixF64) -> Value + 'a>;
+type FvvFn<'a> = Box<dyn FnMut(&VectorF64, &VectorF64, &mut VectorF64) -> Value + 'a>;
+
+/// The function to fit: `n` residuals of `p` parameters, with their (optional) derivatives.
+pub struct MultiFitNLinearFunction<'a> {
+    n: usize,
+    p: usize,
+    f: ResidualFn<'a>,
+    df: Option<JacobianFn<'a>>,
+    fvv: Option<FvvFn<'a>>,
+}
+
+impl<'a> MultiFitNLinearFunction<'a> {
+    /// `f(x, f)` stores the `n` residuals for the parameters `x` into `f`.
+    pub fn new<F: FnMut(&VectorF64, &mut VectorF64) -> Value + 'a>(
+        n: usize,
+        p: usize,
+        f: F,
+    ) -> MultiFitNLinearFunction<'a> {
+        MultiFitNLinearFunction {
+            n,
+            p,
+            f: Box::new(f),
+            df: None,
+            fvv: None,
+        }
+    }
+
+    /// `df(x, J)` stores the `n`-by-`p` Jacobian matrix for the parameters `x` into `J`. Without
+    /// it, the Jacobian is approximated with finite differences.
+    pub fn with_jacobian<F: FnMut(&VectorF64, &mut MatrixF64) -> Value + 'a>(
+        mut self,
+        df: F,
+    ) -> MultiFitNLinearFunction<'a> {
+        self.df = Some(Box::new(df));
+        self
+    }
+
+    /// `fvv(x, v, fvv)` stores the second directional derivative of the residuals along `v`
+    /// into `fvv`. It is only used by [`MultiFitNLinearTrs::lmaccel`], which approximates it
+    /// with finite differences if it isn't provided.
+    pub fn with_fvv<F: FnMut(&VectorF64, &VectorF64, &mut VectorF64) -> Value + 'a>(
+        mut self,
+        fvv: F,
+    ) -> MultiFitNLinearFunction<'a> {
+        self.fvv = Some(Box::new(fvv));
+        self
+    }
+}
+
+/// Owns the closures and the `gsl_multifit_nlinear_fdf` pointing to them, which GSL keeps a
+/// pointer to.
+struct Fdf<'a> {
+    raw: Box<RawFdf>,
+    _function: Box<MultiFitNLinearFunction<'a>>,
+}
+
+impl<'a> Fdf<'a> {
+    fn new(function: MultiFitNLinearFunction<'a>) -> Fdf<'a> {
+        unsafe extern "C" fn f(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            f: *mut sys::gsl_vector,
+        ) -> c_int {
+            let function = &mut *(params as *mut MultiFitNLinearFunction);
+            ::error::catch_callback(Value::BadFunction, || {
+                ::ffi::lend_mut(f, |f| (function.f)(&VectorF64::soft_wrap(x as *mut _), f))
+            })
+            .into()
+        }
+        unsafe extern "C" fn df(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            J: *mut sys::gsl_matrix,
+        ) -> c_int {
+            let function = &mut *(params as *mut MultiFitNLinearFunction);
+            match function.df {
+                Some(ref mut df) => ::error::catch_callback(Value::BadFunction, || {
+                    ::ffi::lend_mut(J, |J| df(&VectorF64::soft_wrap(x as *mut _), J))
+                })
+                .into(),
+                None => Value::Success.into(),
+            }
+        }
+        unsafe extern "C" fn fvv(
+            x: *const sys::gsl_vector,
+            v: *const sys::gsl_vector,
+            params: *mut c_void,
+            fvv: *mut sys::gsl_vector,
+        ) -> c_int {
+            let function = &mut *(params as *mut MultiFitNLinearFunction);
+            match function.fvv {
+                Some(ref mut f) => ::error::catch_callback(Value::BadFunction, || {
+                    ::ffi::lend_mut(fvv, |fvv| {
+                        f(
+                            &VectorF64::soft_wrap(x as *mut _),
+                            &VectorF64::soft_wrap(v as *mut _),
+                            fvv,
+                        )
+                    })
+                })
+                .into(),
+                None => Value::Success.into(),
+            }
+        }
+
+        let mut function = Box::new(function);
+        let raw = Box::new(RawFdf {
+            f: Some(f),
+            // GSL uses finite differences when they are `NULL`.
+            df: if function.df.is_some() {
+                Some(df)
+            } else {
+                None
+            },
+            fvv: if function.fvv.is_some() {
+                Some(fvv)
+            } else {
+                None
+            },
+            n: function.n,
+            p: function.p,
+            params: &mut *function as *mut MultiFitNLinearFunction as *mut _,
+            nevalf: 0,
+            nevaldf: 0,
+            nevalfvv: 0,
+        });
+        Fdf {
+            raw,
+            _function: function,
+        }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut sys::gsl_multifit_nlinear_fdf {
+        &mut *self.raw as *mut RawFdf as *mut _
+    }
+}
+
+/// Why [`MultiFitNLinear::driver`] or [`MultiFitNLinear::test`] considered that the method
+/// converged.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MultiFitNLinearConvergence {
+    /// The step is smaller than `xtol`.
+    SmallStep,
+    /// The gradient is smaller than `gtol`.
+    SmallGradient,
+    /// The change of the residuals is smaller than `ftol`.
+    SmallResidualChange,
+}
+
+impl MultiFitNLinearConvergence {
+    fn from_info(info: c_int) -> MultiFitNLinearConvergence {
+        match info {
+            1 => Self::SmallStep,
+            2 => Self::SmallGradient,
+            3 => Self::SmallResidualChange,
+            _ => panic!("Unknown gsl_multifit_nlinear convergence info"),
+        }
+    }
+}
+
+ffi_wrapper!(
+    MultiFitNLinear<'a>,
+    *mut sys::gsl_multifit_nlinear_workspace,
+    gsl_multifit_nlinear_free,
+    Fdf<'a>
+);
+
+impl<'a> MultiFitNLinear<'a> {
+    /// This function allocates a workspace for a solver of type `t` with the parameters
+    /// `params`, for `n` observations and `p` parameters. The number of observations `n` must be
+    /// greater than or equal to the number of parameters `p`.
+    #[doc(alias = "gsl_multifit_nlinear_alloc")]
+    pub fn new(
+        t: MultiFitNLinearType,
+        params: &MultiFitNLinearParameters,
+        n: usize,
+        p: usize,
+    ) -> Option<MultiFitNLinear<'a>> {
+        // GSL copies the parameters.
+        let raw = RawParameters {
+            trs: params.trs.unwrap_shared(),
+            scale: params.scale.unwrap_shared(),
+            solver: params.solver.unwrap_shared(),
+            fdtype: params.fdtype.into(),
+            factor_up: params.factor_up,
+            factor_down: params.factor_down,
+            avmax: params.avmax,
+            h_df: params.h_df,
+            h_fvv: params.h_fvv,
+        };
+        let tmp = unsafe {
+            sys::gsl_multifit_nlinear_alloc(
+                t.unwrap_shared(),
+                &raw as *const RawParameters as *const _,
+                n,
+                p,
+            )
+        };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function initializes the workspace to use the function `fdf` and the initial guess
+    /// `x`.
+    #[doc(alias = "gsl_multifit_nlinear_init")]
+    pub fn init(&mut self, x: &VectorF64, fdf: MultiFitNLinearFunction<'a>) -> Result<(), Error> {
+        let mut fdf = Fdf::new(fdf);
+        let ret = unsafe {
+            sys::gsl_multifit_nlinear_init(
+                x.unwrap_shared(),
+                fdf.as_mut_ptr(),
+                self.unwrap_unique(),
+            )
+        };
+        // GSL keeps a pointer to it, and the previous one (if any) isn't used anymore.
+        self.function = Some(fdf);
+        result_handler!(ret, (), "gsl_multifit_nlinear_init")
+    }
+
+    /// Same as [`init`](Self::init), but for a weighted least squares problem: `wts` contains
+    /// the weight of each residual (usually 1 / sigma_i^2).
+    #[doc(alias = "gsl_multifit_nlinear_winit")]
+    pub fn winit(
+        &mut self,
+        x: &VectorF64,
+        wts: &VectorF64,
+        fdf: MultiFitNLinearFunction<'a>,
+    ) -> Result<(), Error> {
+        let mut fdf = Fdf::new(fdf);
+        let ret = unsafe {
+            sys::gsl_multifit_nlinear_winit(
+                x.unwrap_shared(),
+                wts.unwrap_shared(),
+                fdf.as_mut_ptr(),
+                self.unwrap_unique(),
+            )
+        };
+        self.function = Some(fdf);
+        result_handler!(ret, (), "gsl_multifit_nlinear_winit")
+    }
+
+    /// This function performs a single iteration of the solver.
+    #[doc(alias = "gsl_multifit_nlinear_iterate")]
+    pub fn iterate(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_multifit_nlinear_iterate(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_multifit_nlinear_iterate")
+    }
+
+    /// This function tests for convergence of the current iterate (see
+    /// [`driver`](Self::driver) for the meaning of the tolerances).
+    ///
+    /// Returns `None` if the method didn't converge yet.
+    #[doc(alias = "gsl_multifit_nlinear_test")]
+    pub fn test(
+        &self,
+        xtol: f64,
+        gtol: f64,
+        ftol: f64,
+    ) -> Result<Option<MultiFitNLinearConvergence>, Error> {
+        let mut info = 0;
+        let ret = unsafe {
+            sys::gsl_multifit_nlinear_test(xtol, gtol, ftol, &mut info, self.unwrap_shared())
+        };
+        match Value::from(ret) {
+            Value::Success => Ok(Some(MultiFitNLinearConvergence::from_info(info))),
+            Value::Continue => Ok(None),
+            v => Err(Error::new(v, "gsl_multifit_nlinear_test")),
+        }
+    }
+
+    /// This function iterates the solver for a maximum of `max_iter` iterations, until it
+    /// converges:
+    ///
+    /// * `xtol`: the step dx is small enough, |dx_i| <= xtol (|x_i| + xtol).
+    /// * `gtol`: the gradient is small enough, max_i |g_i * max(x_i, 1)| <= gtol * 0.5 |f|^2.
+    /// * `ftol`: the change of the residuals is small enough, |f(x + dx)| - |f(x)| <= ftol
+    ///   max(|f(x)|, 1).
+    ///
+    /// `callback(iter, w)` is called after each iteration.
+    ///
+    /// A `Value::MaxIteration` error is returned if the method didn't converge after `max_iter`
+    /// iterations.
+    #[doc(alias = "gsl_multifit_nlinear_driver")]
+    pub fn driver<F: FnMut(usize, &MultiFitNLinear<'a>)>(
+        &mut self,
+        max_iter: usize,
+        xtol: f64,
+        gtol: f64,
+        ftol: f64,
+        callback: F,
+    ) -> Result<MultiFitNLinearConvergence, Error> {
+        unsafe extern "C" fn trampoline<'a, F: FnMut(usize, &MultiFitNLinear<'a>)>(
+            iter: usize,
+            params: *mut c_void,
+            _w: *const sys::gsl_multifit_nlinear_workspace,
+        ) {
+            let (callback, w) = &mut *(params as *mut (F, *const MultiFitNLinear<'a>));
+            ::error::catch_callback((), || callback(iter, &**w));
+        }
+
+        let w = self.unwrap_unique();
+        let mut params = (callback, self as *const MultiFitNLinear<'a>);
+        let mut info = 0;
+        let ret = unsafe {
+            sys::gsl_multifit_nlinear_driver(
+                max_iter,
+                xtol,
+                gtol,
+                ftol,
+                Some(trampoline::<F>),
+                &mut params as *mut (F, *const MultiFitNLinear<'a>) as *mut _,
+                &mut info,
+                w,
+            )
+        };
+        result_handler!(
+            ret,
+            MultiFitNLinearConvergence::from_info(info),
+            "gsl_multifit_nlinear_driver"
+        )
+    }
+
+    /// This function returns the name of the solver.
+    #[doc(alias = "gsl_multifit_nlinear_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multifit_nlinear_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// This function returns the name of the trust region subproblem method.
+    #[doc(alias = "gsl_multifit_nlinear_trs_name")]
+    pub fn trs_name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multifit_nlinear_trs_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// Calls `f` with the current best-fit parameters.
+    #[doc(alias = "gsl_multifit_nlinear_position")]
+    pub fn position<R, F: FnOnce(&VectorF64) -> R>(&self, f: F) -> R {
+        f(&VectorF64::soft_wrap(unsafe {
+            sys::gsl_multifit_nlinear_position(self.unwrap_shared())
+        }))
+    }
+
+    /// Calls `f` with the current residual vector.
+    #[doc(alias = "gsl_multifit_nlinear_residual")]
+    pub fn residual<R, F: FnOnce(&VectorF64) -> R>(&self, f: F) -> R {
+        f(&VectorF64::soft_wrap(unsafe {
+            sys::gsl_multifit_nlinear_residual(self.unwrap_shared())
+        }))
+    }
+
+    /// Calls `f` with the current Jacobian matrix.
+    #[doc(alias = "gsl_multifit_nlinear_jac")]
+    pub fn jac<R, F: FnOnce(&MatrixF64) -> R>(&self, f: F) -> R {
+        f(&MatrixF64::soft_wrap(unsafe {
+            sys::gsl_multifit_nlinear_jac(self.unwrap_shared())
+        }))
+    }
+
+    /// Returns the sum of the squares of the current residuals, chi^2 = |f|^2.
+    pub fn chisq(&self) -> f64 {
+        self.residual(|f| {
+            let norm = unsafe { sys::gsl_blas_dnrm2(f.unwrap_shared()) };
+            norm * norm
+        })
+    }
+
+    /// This function computes the covariance matrix of the best-fit parameters from the
+    /// current Jacobian. `epsrel` is used to remove linear-dependent columns when J is rank
+    /// deficient.
+    #[doc(alias = "gsl_multifit_nlinear_covar")]
+    pub fn covar(&self, epsrel: f64) -> Result<MatrixF64, Error> {
+        let p = self.position(|x| x.len());
+        let mut covar =
+            MatrixF64::new(p, p).ok_or_else(|| Error::new(Value::NoMemory, "gsl_matrix_calloc"))?;
+        let ret = self.jac(|J| unsafe {
+            sys::gsl_multifit_nlinear_covar(J.unwrap_shared(), epsrel, covar.unwrap_unique())
+        });
+        result_handler!(ret, covar, "gsl_multifit_nlinear_covar")
+    }
+
+    /// This function returns the number of iterations done.
+    #[doc(alias = "gsl_multifit_nlinear_niter")]
+    pub fn niter(&self) -> usize {
+        unsafe { sys::gsl_multifit_nlinear_niter(self.unwrap_shared()) }
+    }
+
+    /// This function returns the reciprocal condition number of the current Jacobian.
+    #[doc(alias = "gsl_multifit_nlinear_rcond")]
+    pub fn rcond(&self) -> Result<f64, Error> {
+        let mut rcond = 0.;
+        let ret = unsafe { sys::gsl_multifit_nlinear_rcond(&mut rcond, self.unwrap_shared()) };
+        result_handler!(ret, rcond, "gsl_multifit_nlinear_rcond")
+    }
+
+    /// This function returns the ratio |a| / |v| of the acceleration and velocity terms of the
+    /// current step, for [`MultiFitNLinearTrs::lmaccel`].
+    #[doc(alias = "gsl_multifit_nlinear_avratio")]
+    pub fn avratio(&self) -> f64 {
+        unsafe { sys::gsl_multifit_nlinear_avratio(self.unwrap_shared()) }
+    }
+
+    /// Returns the number of evaluations of the residuals, Jacobian and second directional
+    /// derivative, as `(nevalf, nevaldf, nevalfvv)`.
+    pub fn nevals(&self) -> (usize, usize, usize) {
+        match self.function {
+            Some(ref fdf) => (fdf.raw.nevalf, fdf.raw.nevaldf, fdf.raw.nevalfvv),
+            None => (0, 0, 0),
+        }
+    }
+}
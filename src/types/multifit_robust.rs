@@ -0,0 +1,329 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Robust linear regression
+
+Ordinary least squares fits are very sensitive to outliers. Robust regression reduces their
+influence by iteratively reweighting the residuals with a weighting function `psi`, chosen via
+[`MultifitRobustType`]. Each method has a tuning constant which is set to a sensible default and
+can be changed with [`MultifitRobustWorkspace::tune`].
+!*/
+
+use crate::{Error, MatrixF64, VectorF64};
+use ffi::FFI;
+
+ffi_wrapper!(MultifitRobustType, *const sys::gsl_multifit_robust_type);
+
+unsafe impl Send for MultifitRobustType {}
+unsafe impl Sync for MultifitRobustType {}
+
+impl MultifitRobustType {
+    /// The bisquare weighting function, which is the default.
+    #[doc(alias = "gsl_multifit_robust_default")]
+    pub fn default() -> MultifitRobustType {
+        ffi_wrap!(gsl_multifit_robust_default)
+    }
+
+    /// Tukey's biweight (bisquare) function, `w(e) = (1 - e^2)^2` for `|e| <= 1` and `0`
+    /// otherwise. Its tuning constant is 4.685.
+    #[doc(alias = "gsl_multifit_robust_bisquare")]
+    pub fn bisquare() -> MultifitRobustType {
+        ffi_wrap!(gsl_multifit_robust_bisquare)
+    }
+
+    /// Cauchy's function, `w(e) = 1 / (1 + e^2)`. Its tuning constant is 2.385.
+    #[doc(alias = "gsl_multifit_robust_cauchy")]
+    pub fn cauchy() -> MultifitRobustType {
+        ffi_wrap!(gsl_multifit_robust_cauchy)
+    }
+
+    /// The fair function, `w(e) = 1 / (1 + |e|)`. Its tuning constant is 1.400.
+    #[doc(alias = "gsl_multifit_robust_fair")]
+    pub fn fair() -> MultifitRobustType {
+        ffi_wrap!(gsl_multifit_robust_fair)
+    }
+
+    /// Huber's function, `w(e) = 1` for `|e| <= 1` and `1 / |e|` otherwise. Its tuning constant
+    /// is 1.345.
+    #[doc(alias = "gsl_multifit_robust_huber")]
+    pub fn huber() -> MultifitRobustType {
+        ffi_wrap!(gsl_multifit_robust_huber)
+    }
+
+    /// Ordinary least squares, `w(e) = 1`. This is mostly useful to compare against the other
+    /// methods.
+    #[doc(alias = "gsl_multifit_robust_ols")]
+    pub fn ols() -> MultifitRobustType {
+        ffi_wrap!(gsl_multifit_robust_ols)
+    }
+
+    /// The Welsch function, `w(e) = exp(-e^2)`. Its tuning constant is 2.985.
+    #[doc(alias = "gsl_multifit_robust_welsch")]
+    pub fn welsch() -> MultifitRobustType {
+        ffi_wrap!(gsl_multifit_robust_welsch)
+    }
+}
+
+// `gsl_multifit_robust_stats` is opaque in the bindings.
+#[repr(C)]
+struct RobustStatsHeader {
+    sigma_ols: f64,
+    sigma_mad: f64,
+    sigma_rob: f64,
+    sigma: f64,
+    Rsq: f64,
+    adj_Rsq: f64,
+    rmse: f64,
+    sse: f64,
+    dof: usize,
+    numit: usize,
+    weights: *mut sys::gsl_vector,
+    r: *mut sys::gsl_vector,
+}
+
+// `gsl_multifit_robust_workspace` is opaque in the bindings.
+#[repr(C)]
+struct RobustWorkspaceHeader {
+    n: usize,
+    p: usize,
+    numit: usize,
+    maxiter: usize,
+    type_: *const sys::gsl_multifit_robust_type,
+    tune: f64,
+    r: *mut sys::gsl_vector,
+    weights: *mut sys::gsl_vector,
+    c_prev: *mut sys::gsl_vector,
+    resfac: *mut sys::gsl_vector,
+    psi: *mut sys::gsl_vector,
+    dpsi: *mut sys::gsl_vector,
+    QSI: *mut sys::gsl_matrix,
+    D: *mut sys::gsl_vector,
+    workn: *mut sys::gsl_vector,
+    stats: RobustStatsHeader,
+    multifit_p: *mut sys::gsl_multifit_linear_workspace,
+}
+
+fn vector_to_vec(v: *const sys::gsl_vector) -> Vec<f64> {
+    if v.is_null() {
+        return Vec::new();
+    }
+    unsafe {
+        let v = &*v;
+        (0..v.size).map(|i| *v.data.add(i * v.stride)).collect()
+    }
+}
+
+/// Statistics of the last robust fit, as returned by [`MultifitRobustWorkspace::statistics`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RobustStats {
+    /// Standard deviation of the residuals of the ordinary least squares fit.
+    pub sigma_ols: f64,
+    /// Estimate of sigma from the median absolute deviation of the residuals.
+    pub sigma_mad: f64,
+    /// Robust estimate of sigma.
+    pub sigma_rob: f64,
+    /// Final estimate of sigma, the maximum of `sigma_rob` and a weighted average of
+    /// `sigma_ols` and `sigma_rob`.
+    pub sigma: f64,
+    /// Coefficient of determination.
+    pub Rsq: f64,
+    /// Adjusted coefficient of determination.
+    pub adj_Rsq: f64,
+    /// Root mean squared error of the final residuals.
+    pub rmse: f64,
+    /// Residual sum of squares.
+    pub sse: f64,
+    /// Degrees of freedom, `n - p`.
+    pub dof: usize,
+    /// Number of iterations performed.
+    pub numit: usize,
+    /// Final weight of each observation.
+    pub weights: Vec<f64>,
+    /// Final residual of each observation.
+    pub r: Vec<f64>,
+}
+
+ffi_wrapper!(
+    MultifitRobustWorkspace,
+    *mut sys::gsl_multifit_robust_workspace,
+    gsl_multifit_robust_free
+);
+
+unsafe impl Send for MultifitRobustWorkspace {}
+
+impl MultifitRobustWorkspace {
+    /// This function allocates a workspace for fitting a model to `n` observations using `p`
+    /// parameters, with the weighting function `t`.
+    #[doc(alias = "gsl_multifit_robust_alloc")]
+    pub fn new(t: MultifitRobustType, n: usize, p: usize) -> Option<Self> {
+        let s = unsafe { sys::gsl_multifit_robust_alloc(t.unwrap_shared(), n, p) };
+        if s.is_null() {
+            None
+        } else {
+            Some(Self::wrap(s))
+        }
+    }
+
+    /// This function sets the tuning constant used to adjust the residuals at each iteration.
+    /// Decreasing it increases the downweighting of large residuals.
+    #[doc(alias = "gsl_multifit_robust_tune")]
+    pub fn tune(&mut self, tune: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_multifit_robust_tune(tune, self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_multifit_robust_tune")
+    }
+
+    /// This function sets the maximum number of iterations of the iteratively reweighted least
+    /// squares algorithm. The default is 100.
+    #[doc(alias = "gsl_multifit_robust_maxiter")]
+    pub fn maxiter(&mut self, maxiter: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_multifit_robust_maxiter(maxiter, self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_multifit_robust_maxiter")
+    }
+
+    #[doc(alias = "gsl_multifit_robust_name")]
+    pub fn name(&self) -> Option<String> {
+        let n = unsafe { sys::gsl_multifit_robust_name(self.unwrap_shared()) };
+        if n.is_null() {
+            return None;
+        }
+        let s = unsafe { ::std::ffi::CStr::from_ptr(n) };
+        s.to_str().ok().map(|x| x.to_owned())
+    }
+
+    /// This function computes the vector of weights `wts` corresponding to the residuals `r`,
+    /// using the weighting function and tuning constant of the workspace.
+    #[doc(alias = "gsl_multifit_robust_weights")]
+    pub fn weights(&mut self, r: &VectorF64, wts: &mut VectorF64) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multifit_robust_weights(
+                r.unwrap_shared(),
+                wts.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, (), "gsl_multifit_robust_weights")
+    }
+
+    /// This function computes the best-fit parameters `c` of the model y = X c for the
+    /// observations `y` and the matrix of predictor variables `X`, while limiting the influence
+    /// of outliers. The variance-covariance matrix of the parameters is stored in `cov`.
+    ///
+    /// GSL returns [`Value::MaxIteration`](crate::Value::MaxIteration) if the iteration limit is
+    /// reached. In that case `c` and `cov` still hold the latest estimate.
+    #[doc(alias = "gsl_multifit_robust")]
+    pub fn robust(
+        &mut self,
+        X: &MatrixF64,
+        y: &VectorF64,
+        c: &mut VectorF64,
+        cov: &mut MatrixF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multifit_robust(
+                X.unwrap_shared(),
+                y.unwrap_shared(),
+                c.unwrap_unique(),
+                cov.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, (), "gsl_multifit_robust")
+    }
+
+    /// This function computes the studentized residuals `r` of the fit `c` for the observations
+    /// `y` and predictors `X`, using the weights of the last call to
+    /// [`robust`](#method.robust).
+    #[doc(alias = "gsl_multifit_robust_residuals")]
+    pub fn residuals(
+        &mut self,
+        X: &MatrixF64,
+        y: &VectorF64,
+        c: &VectorF64,
+        r: &mut VectorF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multifit_robust_residuals(
+                X.unwrap_shared(),
+                y.unwrap_shared(),
+                c.unwrap_shared(),
+                r.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, (), "gsl_multifit_robust_residuals")
+    }
+
+    /// Returns the statistics of the last call to [`robust`](#method.robust).
+    #[doc(alias = "gsl_multifit_robust_statistics")]
+    pub fn statistics(&self) -> RobustStats {
+        // `gsl_multifit_robust_statistics` returns the struct by value, which the bindings can't
+        // express, so it is read from the workspace instead.
+        let s = unsafe { &(*(self.unwrap_shared() as *const RobustWorkspaceHeader)).stats };
+        RobustStats {
+            sigma_ols: s.sigma_ols,
+            sigma_mad: s.sigma_mad,
+            sigma_rob: s.sigma_rob,
+            sigma: s.sigma,
+            Rsq: s.Rsq,
+            adj_Rsq: s.adj_Rsq,
+            rmse: s.rmse,
+            sse: s.sse,
+            dof: s.dof,
+            numit: s.numit,
+            weights: vector_to_vec(s.weights),
+            r: vector_to_vec(s.r),
+        }
+    }
+}
+
+/// This function uses the best-fit robust regression coefficients `c` and their covariance
+/// matrix `cov` to compute the fitted function value and its standard deviation for the
+/// predictors `x`.
+///
+/// Returns `(y, y_err)`.
+#[doc(alias = "gsl_multifit_robust_est")]
+pub fn robust_est(x: &VectorF64, c: &VectorF64, cov: &MatrixF64) -> Result<(f64, f64), Error> {
+    let mut y = 0.;
+    let mut y_err = 0.;
+    let ret = unsafe {
+        sys::gsl_multifit_robust_est(
+            x.unwrap_shared(),
+            c.unwrap_shared(),
+            cov.unwrap_shared(),
+            &mut y,
+            &mut y_err,
+        )
+    };
+    result_handler!(ret, (y, y_err), "gsl_multifit_robust_est")
+}
+
+#[test]
+fn robust_line() {
+    // y = 1 + 2 t, with one gross outlier.
+    let n = 10;
+    let mut X = MatrixF64::new(n, 2).unwrap();
+    let mut y = VectorF64::new(n).unwrap();
+    for i in 0..n {
+        X.set(i, 0, 1.);
+        X.set(i, 1, i as f64);
+        y.set(i, 1. + 2. * i as f64);
+    }
+    y.set(7, 100.);
+
+    let mut w = MultifitRobustWorkspace::new(MultifitRobustType::bisquare(), n, 2).unwrap();
+    assert_eq!(w.name().as_deref(), Some("bisquare"));
+    let mut c = VectorF64::new(2).unwrap();
+    let mut cov = MatrixF64::new(2, 2).unwrap();
+    w.robust(&X, &y, &mut c, &mut cov).unwrap();
+    assert!((c.get(0) - 1.).abs() < 1e-6 && (c.get(1) - 2.).abs() < 1e-6);
+
+    let stats = w.statistics();
+    assert_eq!(stats.dof, n - 2);
+    assert_eq!(stats.weights.len(), n);
+    assert!(stats.weights[7] < 1e-6);
+
+    let x = VectorF64::from_slice(&[1., 20.]).unwrap();
+    assert!((robust_est(&x, &c, &cov).unwrap().0 - 41.).abs() < 1e-4);
+}
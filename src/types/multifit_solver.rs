@@ -213,9 +213,20 @@ int main(void) {
 ```
 */
 
+use crate::{ConvergenceStatus, Error, Value};
 use ffi::{self, FFI};
 use std::os::raw::{c_int, c_void};
-use VectorF64;
+use {VectorF64, VectorF64ConstView};
+
+// Borrows one of the vectors of a solver, which stay owned by GSL. `sqrt_wts` is null when the
+// fit isn't weighted, which gives an empty view.
+unsafe fn solver_vector<'a>(v: *const sys::gsl_vector) -> VectorF64ConstView<'a> {
+    if v.is_null() {
+        VectorF64ConstView::wrap(::std::mem::zeroed())
+    } else {
+        VectorF64ConstView::wrap(sys::gsl_vector_const_subvector(v, 0, (*v).size))
+    }
+}
 
 ffi_wrapper!(MultiFitFSolverType, *mut sys::gsl_multifit_fsolver_type);
 
@@ -229,11 +240,16 @@ ffi_wrapper!(MultiFitFSolverType, *mut sys::gsl_multifit_fsolver_type);
 
 pub struct MultiFitFunction(pub sys::gsl_multifit_function);
 
-ffi_wrapper!(
-    MultiFitFSolver,
-    *mut sys::gsl_multifit_fsolver,
-    gsl_multifit_fsolver_free
-);
+// Not `Send`: GSL keeps a pointer to the function given to `set`, which isn't tied to the
+// solver's lifetime.
+ffi_wrapper!(MultiFitFSolver, *mut sys::gsl_multifit_fsolver);
+
+impl Drop for MultiFitFSolver {
+    fn drop(&mut self) {
+        unsafe { sys::gsl_multifit_fsolver_free(self.inner) };
+        self.inner = ::std::ptr::null_mut();
+    }
+}
 
 impl MultiFitFSolver {
     /// This function returns a pointer to a newly allocated instance of a solver of type T for n
@@ -254,21 +270,26 @@ impl MultiFitFSolver {
     }
 
     #[doc(alias = "gsl_multifit_fsolver_set")]
-    pub fn set(&mut self, f: &mut MultiFitFunction, x: &mut VectorF64) -> ::Value {
+    pub fn set(&mut self, f: &mut MultiFitFunction, x: &mut VectorF64) -> Result<(), Error> {
         // unsafe {
         //     let func = (*self.0).function;
         //     if !func.is_null() {
         //         Box::from_raw((*func).params);
         //     }
         // }
-        ::Value::from(unsafe {
-            sys::gsl_multifit_fsolver_set(self.unwrap_unique(), &mut f.0, x.unwrap_shared())
-        })
+        result_handler!(
+            unsafe {
+                sys::gsl_multifit_fsolver_set(self.unwrap_unique(), &mut f.0, x.unwrap_shared())
+            },
+            (),
+            "gsl_multifit_fsolver_set"
+        )
     }
 
     #[doc(alias = "gsl_multifit_fsolver_iterate")]
-    pub fn iterate(&mut self) -> ::Value {
-        ::Value::from(unsafe { sys::gsl_multifit_fsolver_iterate(self.unwrap_unique()) })
+    pub fn iterate(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_multifit_fsolver_iterate(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_multifit_fsolver_iterate")
     }
 
     #[doc(alias = "gsl_multifit_fsolver_name")]
@@ -281,16 +302,21 @@ impl MultiFitFSolver {
     }
 
     #[doc(alias = "gsl_multifit_fsolver_position")]
-    pub fn position(&self) -> VectorF64 {
-        unsafe { ffi::FFI::wrap(sys::gsl_multifit_fsolver_position(self.unwrap_shared())) }
+    pub fn position(&self) -> VectorF64ConstView<'_> {
+        unsafe { solver_vector(sys::gsl_multifit_fsolver_position(self.unwrap_shared())) }
     }
 }
 
-ffi_wrapper!(
-    MultiFitFdfSolver,
-    *mut sys::gsl_multifit_fdfsolver,
-    gsl_multifit_fdfsolver_free
-);
+// Not `Send`: GSL keeps a pointer to the function given to `set`, which isn't tied to the
+// solver's lifetime.
+ffi_wrapper!(MultiFitFdfSolver, *mut sys::gsl_multifit_fdfsolver);
+
+impl Drop for MultiFitFdfSolver {
+    fn drop(&mut self) {
+        unsafe { sys::gsl_multifit_fdfsolver_free(self.inner) };
+        self.inner = ::std::ptr::null_mut();
+    }
+}
 
 impl MultiFitFdfSolver {
     /// This function returns a pointer to a newly allocated instance of a solver of type T for n
@@ -309,30 +335,34 @@ impl MultiFitFdfSolver {
     /// This function initializes, or reinitializes, an existing solver s to use the function f and
     /// the initial guess x.
     #[doc(alias = "gsl_multifit_fdfsolver_set")]
-    pub fn set(&mut self, f: &mut MultiFitFunctionFdf, x: &::VectorF64) -> ::Value {
-        ::Value::from(unsafe {
-            sys::gsl_multifit_fdfsolver_set(self.unwrap_unique(), f.to_raw(), x.unwrap_shared())
-        })
+    pub fn set(&mut self, f: &mut MultiFitFunctionFdf, x: &::VectorF64) -> Result<(), Error> {
+        result_handler!(
+            unsafe {
+                sys::gsl_multifit_fdfsolver_set(self.unwrap_unique(), f.to_raw(), x.unwrap_shared())
+            },
+            (),
+            "gsl_multifit_fdfsolver_set"
+        )
     }
 
-    pub fn x(&self) -> ::VectorF64 {
-        unsafe { ffi::FFI::soft_wrap((*self.unwrap_shared()).x) }
+    pub fn x(&self) -> VectorF64ConstView<'_> {
+        unsafe { solver_vector((*self.unwrap_shared()).x) }
     }
 
-    pub fn f(&self) -> ::VectorF64 {
-        unsafe { ffi::FFI::soft_wrap((*self.unwrap_shared()).f) }
+    pub fn f(&self) -> VectorF64ConstView<'_> {
+        unsafe { solver_vector((*self.unwrap_shared()).f) }
     }
 
-    pub fn dx(&self) -> ::VectorF64 {
-        unsafe { ffi::FFI::soft_wrap((*self.unwrap_shared()).dx) }
+    pub fn dx(&self) -> VectorF64ConstView<'_> {
+        unsafe { solver_vector((*self.unwrap_shared()).dx) }
     }
 
-    pub fn g(&self) -> ::VectorF64 {
-        unsafe { ffi::FFI::soft_wrap((*self.unwrap_shared()).g) }
+    pub fn g(&self) -> VectorF64ConstView<'_> {
+        unsafe { solver_vector((*self.unwrap_shared()).g) }
     }
 
-    pub fn sqrt_wts(&self) -> ::VectorF64 {
-        unsafe { ffi::FFI::soft_wrap((*self.unwrap_shared()).sqrt_wts) }
+    pub fn sqrt_wts(&self) -> VectorF64ConstView<'_> {
+        unsafe { solver_vector((*self.unwrap_shared()).sqrt_wts) }
     }
 
     #[doc(alias = "gsl_multifit_fdfsolver_name")]
@@ -348,46 +378,38 @@ impl MultiFitFdfSolver {
     /// unexpected problem then an error code will be returned. The solver maintains a current
     /// estimate of the best-fit parameters at all times.
     #[doc(alias = "gsl_multifit_fdfsolver_iterate")]
-    pub fn iterate(&mut self) -> ::Value {
-        ::Value::from(unsafe { sys::gsl_multifit_fdfsolver_iterate(self.unwrap_unique()) })
+    pub fn iterate(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_multifit_fdfsolver_iterate(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_multifit_fdfsolver_iterate")
     }
 
     /// This function returns the current position (i.e. best-fit parameters) s->x of the solver s.
     #[doc(alias = "gsl_multifit_fdfsolver_position")]
-    pub fn position(&self) -> ::VectorF64 {
-        unsafe { ffi::FFI::wrap(sys::gsl_multifit_fdfsolver_position(self.unwrap_shared())) }
+    pub fn position(&self) -> VectorF64ConstView<'_> {
+        unsafe { solver_vector(sys::gsl_multifit_fdfsolver_position(self.unwrap_shared())) }
     }
 
     /// These functions iterate the solver s for a maximum of maxiter iterations. After each
     /// iteration, the system is tested for convergence using gsl_multifit_test_delta with the
     /// error tolerances epsabs and epsrel.
-    #[allow(unused_assignments)]
+    ///
+    /// A `Value::MaxIteration` error is returned if the method didn't converge after `max_iter`
+    /// iterations.
     #[doc(alias = "gsl_multifit_test_delta")]
-    pub fn driver(&mut self, max_iter: usize, epsabs: f64, epsrel: f64) -> ::Value {
-        let mut status = ::Value::Failure;
-        let ptr = self.unwrap_shared();
-
-        if !ptr.is_null() {
-            let mut iter = 0usize;
-            loop {
-                status = self.iterate();
-
-                if status != ::Value::Success {
-                    break;
-                }
-
-                /* test for convergence */
-                status = ::Value::from(unsafe {
-                    sys::gsl_multifit_test_delta((*ptr).dx, (*ptr).x, epsabs, epsrel)
-                });
-                iter += 1;
-                if status != ::Value::Continue || iter >= max_iter {
-                    break;
-                }
+    pub fn driver(&mut self, max_iter: usize, epsabs: f64, epsrel: f64) -> Result<(), Error> {
+        for _ in 0..max_iter {
+            self.iterate()?;
+
+            let ptr = self.unwrap_shared();
+            let status = ConvergenceStatus::from_test(
+                unsafe { sys::gsl_multifit_test_delta((*ptr).dx, (*ptr).x, epsabs, epsrel) },
+                "gsl_multifit_test_delta",
+            )?;
+            if status.is_converged() {
+                return Ok(());
             }
         }
-
-        status
+        Err(Error::new(Value::MaxIteration, "gsl_multifit_test_delta"))
     }
 }
 
@@ -396,6 +418,9 @@ ffi_wrapper!(
     *const sys::gsl_multifit_fdfsolver_type
 );
 
+unsafe impl Send for MultiFitFdfSolverType {}
+unsafe impl Sync for MultiFitFdfSolverType {}
+
 impl MultiFitFdfSolverType {
     pub fn lmder() -> MultiFitFdfSolverType {
         ffi_wrap!(gsl_multifit_fdfsolver_lmder)
@@ -407,9 +432,9 @@ impl MultiFitFdfSolverType {
 }
 
 pub struct MultiFitFunctionFdf {
-    pub f: Option<Box<dyn Fn(::VectorF64, ::VectorF64) -> ::Value>>,
-    pub df: Option<Box<dyn Fn(::VectorF64, ::MatrixF64) -> ::Value>>,
-    pub fdf: Option<Box<dyn Fn(::VectorF64, ::VectorF64, ::MatrixF64) -> ::Value>>,
+    pub f: Option<Box<dyn Fn(&::VectorF64, &mut ::VectorF64) -> ::Value>>,
+    pub df: Option<Box<dyn Fn(&::VectorF64, &mut ::MatrixF64) -> ::Value>>,
+    pub fdf: Option<Box<dyn Fn(&::VectorF64, &mut ::VectorF64, &mut ::MatrixF64) -> ::Value>>,
     pub n: usize,
     pub p: usize,
     intern: sys::gsl_multifit_function_fdf,
@@ -452,10 +477,9 @@ unsafe extern "C" fn f(
 ) -> c_int {
     let t = params as *mut MultiFitFunctionFdf;
     if let Some(ref i_f) = (*t).f {
-        i_f(
-            ffi::FFI::soft_wrap(x as usize as *mut _),
-            ffi::FFI::soft_wrap(pf),
-        )
+        ::error::catch_callback(::Value::BadFunction, || {
+            ffi::lend_mut(pf, |f| i_f(&ffi::FFI::soft_wrap(x as usize as *mut _), f))
+        })
         .into()
     } else {
         ::Value::Success.into()
@@ -469,10 +493,11 @@ unsafe extern "C" fn df(
 ) -> c_int {
     let t = params as *mut MultiFitFunctionFdf;
     if let Some(ref i_df) = (*t).df {
-        i_df(
-            ffi::FFI::soft_wrap(x as usize as *mut _),
-            ffi::FFI::soft_wrap(pdf),
-        )
+        ::error::catch_callback(::Value::BadFunction, || {
+            ffi::lend_mut(pdf, |df| {
+                i_df(&ffi::FFI::soft_wrap(x as usize as *mut _), df)
+            })
+        })
         .into()
     } else {
         ::Value::Success.into()
@@ -487,11 +512,13 @@ unsafe extern "C" fn fdf(
 ) -> c_int {
     let t = params as *mut MultiFitFunctionFdf;
     if let Some(ref i_fdf) = (*t).fdf {
-        i_fdf(
-            ffi::FFI::soft_wrap(x as usize as *mut _),
-            ffi::FFI::soft_wrap(pf),
-            ffi::FFI::soft_wrap(pdf),
-        )
+        ::error::catch_callback(::Value::BadFunction, || {
+            ffi::lend_mut(pf, |f| {
+                ffi::lend_mut(pdf, |df| {
+                    i_fdf(&ffi::FFI::soft_wrap(x as usize as *mut _), f, df)
+                })
+            })
+        })
         .into()
     } else {
         ::Value::Success.into()
@@ -2,16 +2,34 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::{MatrixF64, Value, VectorF64};
+/*!
+# Large dense linear least squares
+
+These functions solve linear least squares systems which are too large to be held in memory at
+once. The rows of the system are accumulated in blocks with
+[`MultilargeLinearWorkspace::accumulate`], and the system is solved once all the rows have been
+seen, optionally with Tikhonov regularization.
+!*/
+
+use crate::{Error, MatrixF64, VectorF64};
 use ffi::FFI;
 
 ffi_wrapper!(MultilargeLinearType, *const sys::gsl_multilarge_linear_type);
 
+unsafe impl Send for MultilargeLinearType {}
+unsafe impl Sync for MultilargeLinearType {}
+
 impl MultilargeLinearType {
+    /// Solves the system with the normal equations `X^T X c = X^T y`. This is the fastest method
+    /// and needs `O(p^2)` memory, but squares the condition number of the problem.
+    #[doc(alias = "gsl_multilarge_linear_normal")]
     pub fn normal() -> MultilargeLinearType {
         ffi_wrap!(gsl_multilarge_linear_normal)
     }
 
+    /// Solves the system with a Tall Skinny QR decomposition of `X`. It is slower than
+    /// [`normal`](#method.normal) but numerically stable for ill-conditioned problems.
+    #[doc(alias = "gsl_multilarge_linear_tsqr")]
     pub fn tsqr() -> MultilargeLinearType {
         ffi_wrap!(gsl_multilarge_linear_tsqr)
     }
@@ -23,7 +41,11 @@ ffi_wrapper!(
     gsl_multilarge_linear_free
 );
 
+unsafe impl Send for MultilargeLinearWorkspace {}
+
 impl MultilargeLinearWorkspace {
+    /// This function allocates a workspace for solving a large linear least squares system with
+    /// `p` parameters, using the method `t`.
     #[doc(alias = "gsl_multilarge_linear_alloc")]
     pub fn new(t: MultilargeLinearType, p: usize) -> Option<Self> {
         let s = unsafe { sys::gsl_multilarge_linear_alloc(t.unwrap_shared(), p) };
@@ -51,25 +73,34 @@ impl MultilargeLinearWorkspace {
         ::std::str::from_utf8(slice).ok().map(|x| x.to_owned())
     }
 
+    /// This function resets the workspace so it can accumulate a new system.
     #[doc(alias = "gsl_multilarge_linear_reset")]
-    pub fn reset(&mut self) -> Value {
-        unsafe { Value::from(sys::gsl_multilarge_linear_reset(self.unwrap_unique())) }
+    pub fn reset(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_multilarge_linear_reset(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_multilarge_linear_reset")
     }
 
+    /// This function adds the block of rows `(x, y)` to the least squares system. `x` must have
+    /// `p` columns and as many rows as `y` has elements. Both are used as scratch space and their
+    /// content is not preserved.
     #[doc(alias = "gsl_multilarge_linear_accumulate")]
-    pub fn accumulate(&mut self, x: &mut MatrixF64, y: &mut VectorF64) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multilarge_linear_accumulate(
+    pub fn accumulate(&mut self, x: &mut MatrixF64, y: &mut VectorF64) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multilarge_linear_accumulate(
                 x.unwrap_unique(),
                 y.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multilarge_linear_accumulate")
     }
 
-    /// Returns `(Value, rnorm, snorm)`.
+    /// This function solves the accumulated system with the regularization parameter `lambda`
+    /// (use `0` for an ordinary least squares fit) and stores the coefficients in `c`.
+    ///
+    /// Returns `(rnorm, snorm)`, the residual norm `||y - X c||` and the solution norm `||c||`.
     #[doc(alias = "gsl_multilarge_linear_solve")]
-    pub fn solve(&mut self, lambda: f64, c: &mut VectorF64) -> (Value, f64, f64) {
+    pub fn solve(&mut self, lambda: f64, c: &mut VectorF64) -> Result<(f64, f64), Error> {
         let mut rnorm = 0.;
         let mut snorm = 0.;
         let ret = unsafe {
@@ -81,34 +112,38 @@ impl MultilargeLinearWorkspace {
                 self.unwrap_unique(),
             )
         };
-        (Value::from(ret), rnorm, snorm)
+        result_handler!(ret, (rnorm, snorm), "gsl_multilarge_linear_solve")
     }
 
-    /// Returns `(Value, rcond)`.
+    /// Returns the reciprocal condition number of the accumulated least squares matrix.
     #[doc(alias = "gsl_multilarge_linear_rcond")]
-    pub fn rcond(&mut self) -> (Value, f64) {
+    pub fn rcond(&mut self) -> Result<f64, Error> {
         let mut rcond = 0.;
         let ret = unsafe { sys::gsl_multilarge_linear_rcond(&mut rcond, self.unwrap_unique()) };
-        (Value::from(ret), rcond)
+        result_handler!(ret, rcond, "gsl_multilarge_linear_rcond")
     }
 
     #[cfg(feature = "v2_2")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    /// This function computes the L-curve of the accumulated system: the residual norms `rho`
+    /// and solution norms `eta` for the regularization parameters stored in `reg_param`,
+    /// which the three vectors must have the same length as.
     #[doc(alias = "gsl_multilarge_linear_lcurve")]
     pub fn lcurve(
         &mut self,
         reg_param: &mut VectorF64,
         rho: &mut VectorF64,
         eta: &mut VectorF64,
-    ) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multilarge_linear_lcurve(
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multilarge_linear_lcurve(
                 reg_param.unwrap_unique(),
                 rho.unwrap_unique(),
                 eta.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multilarge_linear_lcurve")
     }
 
     #[doc(alias = "gsl_multilarge_linear_wstdform1")]
@@ -120,9 +155,9 @@ impl MultilargeLinearWorkspace {
         y: &VectorF64,
         Xs: &mut MatrixF64,
         ys: &mut VectorF64,
-    ) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multilarge_linear_wstdform1(
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multilarge_linear_wstdform1(
                 L.unwrap_shared(),
                 X.unwrap_shared(),
                 w.unwrap_shared(),
@@ -130,8 +165,9 @@ impl MultilargeLinearWorkspace {
                 Xs.unwrap_unique(),
                 ys.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multilarge_linear_wstdform1")
     }
 
     #[doc(alias = "gsl_multilarge_linear_stdform1")]
@@ -142,17 +178,18 @@ impl MultilargeLinearWorkspace {
         y: &VectorF64,
         Xs: &mut MatrixF64,
         ys: &mut VectorF64,
-    ) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multilarge_linear_stdform1(
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multilarge_linear_stdform1(
                 L.unwrap_shared(),
                 X.unwrap_shared(),
                 y.unwrap_shared(),
                 Xs.unwrap_unique(),
                 ys.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multilarge_linear_stdform1")
     }
 
     #[doc(alias = "gsl_multilarge_linear_wstdform2")]
@@ -165,9 +202,9 @@ impl MultilargeLinearWorkspace {
         y: &VectorF64,
         Xs: &mut MatrixF64,
         ys: &mut VectorF64,
-    ) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multilarge_linear_wstdform2(
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multilarge_linear_wstdform2(
                 LQR.unwrap_shared(),
                 Ltau.unwrap_shared(),
                 X.unwrap_shared(),
@@ -176,8 +213,9 @@ impl MultilargeLinearWorkspace {
                 Xs.unwrap_unique(),
                 ys.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multilarge_linear_wstdform2")
     }
 
     #[doc(alias = "gsl_multilarge_linear_stdform2")]
@@ -189,9 +227,9 @@ impl MultilargeLinearWorkspace {
         y: &VectorF64,
         Xs: &mut MatrixF64,
         ys: &mut VectorF64,
-    ) -> Value {
-        unsafe {
-            Value::from(sys::gsl_multilarge_linear_stdform2(
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_multilarge_linear_stdform2(
                 LQR.unwrap_shared(),
                 Ltau.unwrap_shared(),
                 X.unwrap_shared(),
@@ -199,20 +237,27 @@ impl MultilargeLinearWorkspace {
                 Xs.unwrap_unique(),
                 ys.unwrap_unique(),
                 self.unwrap_unique(),
-            ))
-        }
+            )
+        };
+        result_handler!(ret, (), "gsl_multilarge_linear_stdform2")
     }
 
     #[doc(alias = "gsl_multilarge_linear_genform1")]
-    pub fn genform1(&mut self, L: &VectorF64, cs: &VectorF64, c: &mut VectorF64) -> Value {
-        Value::from(unsafe {
+    pub fn genform1(
+        &mut self,
+        L: &VectorF64,
+        cs: &VectorF64,
+        c: &mut VectorF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_multilarge_linear_genform1(
                 L.unwrap_shared(),
                 cs.unwrap_shared(),
                 c.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_multilarge_linear_genform1")
     }
 
     #[doc(alias = "gsl_multilarge_linear_genform2")]
@@ -222,8 +267,8 @@ impl MultilargeLinearWorkspace {
         Ltau: &VectorF64,
         cs: &VectorF64,
         c: &mut VectorF64,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_multilarge_linear_genform2(
                 LQR.unwrap_shared(),
                 Ltau.unwrap_shared(),
@@ -231,7 +276,8 @@ impl MultilargeLinearWorkspace {
                 c.unwrap_unique(),
                 self.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_multilarge_linear_genform2")
     }
 
     #[cfg(feature = "v2_7")]
@@ -252,3 +298,30 @@ impl MultilargeLinearWorkspace {
         }))
     }
 }
+
+#[test]
+fn multilarge_blocks() {
+    // y = 1 + 2 t, fed in two blocks of rows.
+    let types: [fn() -> MultilargeLinearType; 2] =
+        [MultilargeLinearType::normal, MultilargeLinearType::tsqr];
+    for t in types.iter() {
+        let mut w = MultilargeLinearWorkspace::new(t(), 2).unwrap();
+        for block in 0..2 {
+            let mut X = MatrixF64::new(3, 2).unwrap();
+            let mut y = VectorF64::new(3).unwrap();
+            for i in 0..3 {
+                let t = (block * 3 + i) as f64;
+                X.set(i, 0, 1.);
+                X.set(i, 1, t);
+                y.set(i, 1. + 2. * t);
+            }
+            w.accumulate(&mut X, &mut y).unwrap();
+        }
+
+        let mut c = VectorF64::new(2).unwrap();
+        let (rnorm, _) = w.solve(0., &mut c).unwrap();
+        assert!(rnorm < 1e-8);
+        assert!((c.get(0) - 1.).abs() < 1e-8 && (c.get(1) - 2.).abs() < 1e-8);
+        assert!(w.rcond().unwrap() > 0.);
+    }
+}
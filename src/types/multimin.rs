@@ -0,0 +1,570 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Multidimensional Minimization
+
+This chapter describes routines for finding minima of arbitrary multidimensional functions. The
+library provides low level components for a variety of iterative minimizers and convergence
+tests. These can be combined by the user to achieve the desired solution, while providing full
+access to the intermediate steps of the algorithms.
+
+The problem of multidimensional minimization requires finding a point x such that the scalar
+function f(x_1, ..., x_n) takes a value which is lower than at any neighboring point. The
+algorithms using only the function values are held in a [`MultiMinFMinimizer`], the ones using
+its gradient as well are held in a [`MultiMinFdfMinimizer`].
+
+```
+use rgsl::{MultiMinFMinimizer, MultiMinFMinimizerType, VectorF64};
+
+// A paraboloid centered on (1, 2).
+let mut s = MultiMinFMinimizer::new(&MultiMinFMinimizerType::nmsimplex2(), 2).unwrap();
+let x = VectorF64::from_slice(&[5., 7.]).unwrap();
+let step_size = VectorF64::from_slice(&[1., 1.]).unwrap();
+s.set(
+    |x| 10. * (x.get(0) - 1.).powi(2) + 20. * (x.get(1) - 2.).powi(2) + 30.,
+    &x,
+    &step_size,
+)
+.unwrap();
+let x = s.minimize(100, 1e-4).unwrap();
+assert!((x.get(0) - 1.).abs() < 1e-2 && (x.get(1) - 2.).abs() < 1e-2);
+assert!((s.minimum() - 30.).abs() < 1e-4);
+```
+!*/
+
+use crate::{ConvergenceStatus, Error, Value, VectorF64};
+use ffi::FFI;
+use std::fmt;
+use std::os::raw::c_void;
+
+/// The error returned by [`MultiMinFMinimizer::minimize`] and
+/// [`MultiMinFdfMinimizer::minimize`]. The minimizer keeps the state it stopped in.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MultiMinError {
+    /// Why the iteration stopped: [`Value::MaxIteration`] if the minimum wasn't found within
+    /// the allowed number of iterations.
+    pub error: Error,
+    /// The number of iterations done.
+    pub iterations: usize,
+}
+
+impl fmt::Display for MultiMinError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} after {} iterations", self.error, self.iterations)
+    }
+}
+
+impl ::std::error::Error for MultiMinError {}
+
+// `gsl_multimin_function` is opaque in the bindings.
+#[repr(C)]
+struct RawFunction {
+    f: unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void) -> f64,
+    n: usize,
+    params: *mut c_void,
+}
+
+/// Owns the closure and the `gsl_multimin_function` pointing to it, which GSL keeps a pointer
+/// to.
+struct Function<'a> {
+    raw: Box<RawFunction>,
+    _callback: Box<dyn FnMut(&VectorF64) -> f64 + 'a>,
+}
+
+impl<'a> Function<'a> {
+    fn new<F: FnMut(&VectorF64) -> f64 + 'a>(n: usize, f: F) -> Function<'a> {
+        unsafe extern "C" fn trampoline<F: FnMut(&VectorF64) -> f64>(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+        ) -> f64 {
+            let callback: &mut F = &mut *(params as *mut F);
+            ::error::catch_callback(f64::NAN, || callback(&VectorF64::soft_wrap(x as *mut _)))
+        }
+
+        let mut callback = Box::new(f);
+        let raw = Box::new(RawFunction {
+            f: trampoline::<F>,
+            n,
+            params: &mut *callback as *mut F as *mut _,
+        });
+        Function {
+            raw,
+            _callback: callback,
+        }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut sys::gsl_multimin_function {
+        &mut *self.raw as *mut RawFunction as *mut _
+    }
+}
+
+type ObjectiveFn<'a> = Box<dyn FnMut(&VectorF64) -> f64 + 'a>;
+type GradientFn<'a> = Box<dyn FnMut(&VectorF64, &mut VectorF64) + 'a>;
+type ObjectiveGradientFn<'a> = Box<dyn FnMut(&VectorF64, &mut VectorF64) -> f64 + 'a>;
+
+/// The function minimized by a [`MultiMinFdfMinimizer`], with its gradient.
+pub struct MultiMinFunctionFdf<'a> {
+    f: ObjectiveFn<'a>,
+    df: GradientFn<'a>,
+    fdf: Option<ObjectiveGradientFn<'a>>,
+}
+
+impl<'a> MultiMinFunctionFdf<'a> {
+    /// `f(x)` returns the value of the function at `x`, `df(x, g)` stores its gradient into
+    /// `g`.
+    pub fn new<F: FnMut(&VectorF64) -> f64 + 'a, DF: FnMut(&VectorF64, &mut VectorF64) + 'a>(
+        f: F,
+        df: DF,
+    ) -> MultiMinFunctionFdf<'a> {
+        MultiMinFunctionFdf {
+            f: Box::new(f),
+            df: Box::new(df),
+            fdf: None,
+        }
+    }
+
+    /// `fdf(x, g)` stores the gradient into `g` and returns the value of the function, which is
+    /// usually faster than separate calls to `f` and `df`. Without it, they are called one
+    /// after the other.
+    pub fn with_fdf<FDF: FnMut(&VectorF64, &mut VectorF64) -> f64 + 'a>(
+        mut self,
+        fdf: FDF,
+    ) -> MultiMinFunctionFdf<'a> {
+        self.fdf = Some(Box::new(fdf));
+        self
+    }
+}
+
+// `gsl_multimin_function_fdf` is opaque in the bindings.
+#[repr(C)]
+struct RawFunctionFdf {
+    f: unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void) -> f64,
+    df: unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void, *mut sys::gsl_vector),
+    fdf: unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void, *mut f64, *mut sys::gsl_vector),
+    n: usize,
+    params: *mut c_void,
+}
+
+/// Same as [`Function`] but for `gsl_multimin_function_fdf`.
+struct FunctionFdf<'a> {
+    raw: Box<RawFunctionFdf>,
+    _function: Box<MultiMinFunctionFdf<'a>>,
+}
+
+impl<'a> FunctionFdf<'a> {
+    fn new(n: usize, function: MultiMinFunctionFdf<'a>) -> FunctionFdf<'a> {
+        unsafe extern "C" fn f(x: *const sys::gsl_vector, params: *mut c_void) -> f64 {
+            let function = &mut *(params as *mut MultiMinFunctionFdf);
+            ::error::catch_callback(f64::NAN, || {
+                (function.f)(&VectorF64::soft_wrap(x as *mut _))
+            })
+        }
+        unsafe extern "C" fn df(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            g: *mut sys::gsl_vector,
+        ) {
+            let function = &mut *(params as *mut MultiMinFunctionFdf);
+            ::error::catch_callback((), || {
+                ::ffi::lend_mut(g, |g| (function.df)(&VectorF64::soft_wrap(x as *mut _), g))
+            })
+        }
+        unsafe extern "C" fn fdf(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            f: *mut f64,
+            g: *mut sys::gsl_vector,
+        ) {
+            let function = &mut *(params as *mut MultiMinFunctionFdf);
+            let x = VectorF64::soft_wrap(x as *mut _);
+            *f = ::error::catch_callback(f64::NAN, || {
+                ::ffi::lend_mut(g, |g: &mut VectorF64| match function.fdf {
+                    Some(ref mut fdf) => fdf(&x, g),
+                    None => {
+                        (function.df)(&x, g);
+                        (function.f)(&x)
+                    }
+                })
+            });
+        }
+
+        let mut function = Box::new(function);
+        let raw = Box::new(RawFunctionFdf {
+            f,
+            df,
+            fdf,
+            n,
+            params: &mut *function as *mut MultiMinFunctionFdf as *mut _,
+        });
+        FunctionFdf {
+            raw,
+            _function: function,
+        }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut sys::gsl_multimin_function_fdf {
+        &mut *self.raw as *mut RawFunctionFdf as *mut _
+    }
+}
+
+ffi_wrapper!(
+    MultiMinFMinimizerType,
+    *const sys::gsl_multimin_fminimizer_type,
+    "The algorithms described in this section use only the value of the function at each
+evaluation point."
+);
+
+unsafe impl Send for MultiMinFMinimizerType {}
+unsafe impl Sync for MultiMinFMinimizerType {}
+
+impl MultiMinFMinimizerType {
+    /// This is the Simplex algorithm of Nelder and Mead. It constructs n vectors p_i from the
+    /// starting vector x and the vector step_size, which form the n+1 vertices of a simplex in
+    /// n dimensions. On each iteration the algorithm uses simple geometrical transformations to
+    /// update the vector corresponding to the highest function value.
+    #[doc(alias = "gsl_multimin_fminimizer_nmsimplex")]
+    pub fn nmsimplex() -> MultiMinFMinimizerType {
+        ffi_wrap!(gsl_multimin_fminimizer_nmsimplex)
+    }
+
+    /// This is the same algorithm as `nmsimplex`, using O(n) operations per iteration instead
+    /// of O(n^2). The size of the simplex is the root mean square distance of its vertices from
+    /// its center, which is a more reliable stopping criterion.
+    #[doc(alias = "gsl_multimin_fminimizer_nmsimplex2")]
+    pub fn nmsimplex2() -> MultiMinFMinimizerType {
+        ffi_wrap!(gsl_multimin_fminimizer_nmsimplex2)
+    }
+
+    /// This is the same algorithm as `nmsimplex2`, with the initial simplex built around a
+    /// randomly-oriented set of basis vectors instead of the fixed coordinate axes.
+    #[doc(alias = "gsl_multimin_fminimizer_nmsimplex2rand")]
+    pub fn nmsimplex2rand() -> MultiMinFMinimizerType {
+        ffi_wrap!(gsl_multimin_fminimizer_nmsimplex2rand)
+    }
+}
+
+ffi_wrapper!(
+    MultiMinFMinimizer<'a>,
+    *mut sys::gsl_multimin_fminimizer,
+    gsl_multimin_fminimizer_free,
+    Function<'a>
+);
+
+impl<'a> MultiMinFMinimizer<'a> {
+    /// This function returns a newly allocated instance of a minimizer of type `t` for an
+    /// `n`-dimension function.
+    #[doc(alias = "gsl_multimin_fminimizer_alloc")]
+    pub fn new(t: &MultiMinFMinimizerType, n: usize) -> Option<MultiMinFMinimizer<'a>> {
+        let tmp = unsafe { sys::gsl_multimin_fminimizer_alloc(t.unwrap_shared(), n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function initializes the minimizer to minimize the function `f`, starting from the
+    /// initial point `x`. The size of the initial trial steps is given in `step_size`.
+    ///
+    /// The minimizer keeps `f` until it is reinitialized or dropped.
+    #[doc(alias = "gsl_multimin_fminimizer_set")]
+    pub fn set<F: FnMut(&VectorF64) -> f64 + 'a>(
+        &mut self,
+        f: F,
+        x: &VectorF64,
+        step_size: &VectorF64,
+    ) -> Result<(), Error> {
+        let mut function = Function::new(x.len(), f);
+        let ret = unsafe {
+            sys::gsl_multimin_fminimizer_set(
+                self.unwrap_unique(),
+                function.as_mut_ptr(),
+                x.unwrap_shared(),
+                step_size.unwrap_shared(),
+            )
+        };
+        // GSL keeps a pointer to it, and the previous one (if any) isn't used anymore.
+        self.function = Some(function);
+        result_handler!(ret, (), "gsl_multimin_fminimizer_set")
+    }
+
+    /// This function performs a single iteration of the minimizer. If the iteration encounters
+    /// an unexpected problem then an error is returned.
+    #[doc(alias = "gsl_multimin_fminimizer_iterate")]
+    pub fn iterate(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_multimin_fminimizer_iterate(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_multimin_fminimizer_iterate")
+    }
+
+    /// Returns the minimizer type name.
+    #[doc(alias = "gsl_multimin_fminimizer_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multimin_fminimizer_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// Calls `f` with the current best estimate of the location of the minimum.
+    #[doc(alias = "gsl_multimin_fminimizer_x")]
+    pub fn x<R, F: FnOnce(&VectorF64) -> R>(&self, f: F) -> R {
+        f(&VectorF64::soft_wrap(unsafe {
+            sys::gsl_multimin_fminimizer_x(self.unwrap_shared())
+        }))
+    }
+
+    /// Returns the value of the function at the current best estimate of the minimum.
+    #[doc(alias = "gsl_multimin_fminimizer_minimum")]
+    pub fn minimum(&self) -> f64 {
+        unsafe { sys::gsl_multimin_fminimizer_minimum(self.unwrap_shared()) }
+    }
+
+    /// Returns the minimizer-specific characteristic size, like the size of the simplex.
+    #[doc(alias = "gsl_multimin_fminimizer_size")]
+    pub fn size(&self) -> f64 {
+        unsafe { sys::gsl_multimin_fminimizer_size(self.unwrap_shared()) }
+    }
+
+    /// Iterates the minimizer until its [`size`](Self::size) is smaller than `epsabs` (see
+    /// [`multimin::test_size`](crate::multimin::test_size)), and returns a copy of the location
+    /// of the minimum. It fails if an iteration fails or if the minimum isn't found after
+    /// `max_iter` iterations.
+    pub fn minimize(&mut self, max_iter: usize, epsabs: f64) -> Result<VectorF64, MultiMinError> {
+        for iterations in 1..=max_iter {
+            let status = self
+                .iterate()
+                .and_then(|()| ::multimin::test_size(self.size(), epsabs));
+            match status {
+                Ok(ConvergenceStatus::Converged) => {
+                    return self.x(|x| x.try_clone()).ok_or(MultiMinError {
+                        error: Error::new(Value::NoMemory, "gsl_vector_alloc"),
+                        iterations,
+                    })
+                }
+                Ok(ConvergenceStatus::Continue) => {}
+                Err(error) => return Err(MultiMinError { error, iterations }),
+            }
+        }
+        Err(MultiMinError {
+            error: Error::new(Value::MaxIteration, "gsl_multimin_fminimizer_iterate"),
+            iterations: max_iter,
+        })
+    }
+}
+
+ffi_wrapper!(
+    MultiMinFdfMinimizerType,
+    *const sys::gsl_multimin_fdfminimizer_type,
+    "The algorithms described in this section use the value of the function and its gradient at
+each evaluation point."
+);
+
+unsafe impl Send for MultiMinFdfMinimizerType {}
+unsafe impl Sync for MultiMinFdfMinimizerType {}
+
+impl MultiMinFdfMinimizerType {
+    /// This is the Fletcher-Reeves conjugate gradient algorithm. The conjugate gradient
+    /// algorithm proceeds as a succession of line minimizations. The sequence of search
+    /// directions is used to build up an approximation to the curvature of the function in the
+    /// neighborhood of the minimum.
+    #[doc(alias = "gsl_multimin_fdfminimizer_conjugate_fr")]
+    pub fn conjugate_fr() -> MultiMinFdfMinimizerType {
+        ffi_wrap!(gsl_multimin_fdfminimizer_conjugate_fr)
+    }
+
+    /// This is the Polak-Ribiere conjugate gradient algorithm. It is similar to the
+    /// Fletcher-Reeves method, differing only in the choice of the coefficient beta. Both
+    /// methods work well when the evaluation point is close enough to the minimum of the
+    /// objective function that it is well approximated by a quadratic hypersurface.
+    #[doc(alias = "gsl_multimin_fdfminimizer_conjugate_pr")]
+    pub fn conjugate_pr() -> MultiMinFdfMinimizerType {
+        ffi_wrap!(gsl_multimin_fdfminimizer_conjugate_pr)
+    }
+
+    /// This is the vector Broyden-Fletcher-Goldfarb-Shanno (BFGS) conjugate gradient
+    /// algorithm. It is a quasi-Newton method which builds up an approximation to the second
+    /// derivatives of the function f using the difference between successive gradient vectors.
+    #[doc(alias = "gsl_multimin_fdfminimizer_vector_bfgs")]
+    pub fn vector_bfgs() -> MultiMinFdfMinimizerType {
+        ffi_wrap!(gsl_multimin_fdfminimizer_vector_bfgs)
+    }
+
+    /// This is a more efficient version of the BFGS algorithm, using the line minimization of
+    /// Fletcher. It is the most efficient of the gradient minimizers, and the recommended
+    /// default.
+    #[doc(alias = "gsl_multimin_fdfminimizer_vector_bfgs2")]
+    pub fn vector_bfgs2() -> MultiMinFdfMinimizerType {
+        ffi_wrap!(gsl_multimin_fdfminimizer_vector_bfgs2)
+    }
+
+    /// The steepest descent algorithm follows the downhill gradient of the function at each
+    /// step. When a downhill step is successful the step-size is increased by a factor of two.
+    /// If the downhill step leads to a higher function value then the algorithm backtracks and
+    /// the step size is decreased using the parameter `tol`. It is mainly useful for testing
+    /// purposes.
+    #[doc(alias = "gsl_multimin_fdfminimizer_steepest_descent")]
+    pub fn steepest_descent() -> MultiMinFdfMinimizerType {
+        ffi_wrap!(gsl_multimin_fdfminimizer_steepest_descent)
+    }
+}
+
+ffi_wrapper!(
+    MultiMinFdfMinimizer<'a>,
+    *mut sys::gsl_multimin_fdfminimizer,
+    gsl_multimin_fdfminimizer_free,
+    FunctionFdf<'a>
+);
+
+impl<'a> MultiMinFdfMinimizer<'a> {
+    /// This function returns a newly allocated instance of a minimizer of type `t` for an
+    /// `n`-dimension function.
+    #[doc(alias = "gsl_multimin_fdfminimizer_alloc")]
+    pub fn new(t: &MultiMinFdfMinimizerType, n: usize) -> Option<MultiMinFdfMinimizer<'a>> {
+        let tmp = unsafe { sys::gsl_multimin_fdfminimizer_alloc(t.unwrap_shared(), n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function initializes the minimizer to minimize the function `fdf`, starting from
+    /// the initial point `x`. The size of the first trial step is given by `step_size`. The
+    /// accuracy of the line minimization is specified by `tol`: each line minimization stops
+    /// when the gradient is such that p . g < tol |p| |g|. A value of 0.1 is suitable for most
+    /// purposes.
+    ///
+    /// The minimizer keeps `fdf` until it is reinitialized or dropped.
+    #[doc(alias = "gsl_multimin_fdfminimizer_set")]
+    pub fn set(
+        &mut self,
+        fdf: MultiMinFunctionFdf<'a>,
+        x: &VectorF64,
+        step_size: f64,
+        tol: f64,
+    ) -> Result<(), Error> {
+        let mut function = FunctionFdf::new(x.len(), fdf);
+        let ret = unsafe {
+            sys::gsl_multimin_fdfminimizer_set(
+                self.unwrap_unique(),
+                function.as_mut_ptr(),
+                x.unwrap_shared(),
+                step_size,
+                tol,
+            )
+        };
+        // GSL keeps a pointer to it, and the previous one (if any) isn't used anymore.
+        self.function = Some(function);
+        result_handler!(ret, (), "gsl_multimin_fdfminimizer_set")
+    }
+
+    /// This function performs a single iteration of the minimizer. If the iteration encounters
+    /// an unexpected problem then an error is returned. An error with the value
+    /// [`Value::NoProgress`] signifies that the minimizer is unable to improve on its current
+    /// estimate, either due to numerical difficulty or because a genuine local minimum has
+    /// been reached.
+    #[doc(alias = "gsl_multimin_fdfminimizer_iterate")]
+    pub fn iterate(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_multimin_fdfminimizer_iterate(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_multimin_fdfminimizer_iterate")
+    }
+
+    /// This function resets the minimizer to use the current point as a new starting point.
+    #[doc(alias = "gsl_multimin_fdfminimizer_restart")]
+    pub fn restart(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_multimin_fdfminimizer_restart(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_multimin_fdfminimizer_restart")
+    }
+
+    /// Returns the minimizer type name.
+    #[doc(alias = "gsl_multimin_fdfminimizer_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multimin_fdfminimizer_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// Calls `f` with the current best estimate of the location of the minimum.
+    #[doc(alias = "gsl_multimin_fdfminimizer_x")]
+    pub fn x<R, F: FnOnce(&VectorF64) -> R>(&self, f: F) -> R {
+        f(&VectorF64::soft_wrap(unsafe {
+            sys::gsl_multimin_fdfminimizer_x(self.unwrap_shared())
+        }))
+    }
+
+    /// Calls `f` with the gradient of the function at the current best estimate of the
+    /// minimum.
+    #[doc(alias = "gsl_multimin_fdfminimizer_gradient")]
+    pub fn gradient<R, F: FnOnce(&VectorF64) -> R>(&self, f: F) -> R {
+        f(&VectorF64::soft_wrap(unsafe {
+            sys::gsl_multimin_fdfminimizer_gradient(self.unwrap_shared())
+        }))
+    }
+
+    /// Calls `f` with the last step taken by the minimizer.
+    #[doc(alias = "gsl_multimin_fdfminimizer_dx")]
+    pub fn dx<R, F: FnOnce(&VectorF64) -> R>(&self, f: F) -> R {
+        f(&VectorF64::soft_wrap(unsafe {
+            sys::gsl_multimin_fdfminimizer_dx(self.unwrap_shared())
+        }))
+    }
+
+    /// Returns the value of the function at the current best estimate of the minimum.
+    #[doc(alias = "gsl_multimin_fdfminimizer_minimum")]
+    pub fn minimum(&self) -> f64 {
+        unsafe { sys::gsl_multimin_fdfminimizer_minimum(self.unwrap_shared()) }
+    }
+
+    /// Iterates the minimizer until the norm of the [`gradient`](Self::gradient) is smaller
+    /// than `epsabs` (see [`multimin::test_gradient`](crate::multimin::test_gradient)), and
+    /// returns a copy of the location of the minimum. It fails if an iteration fails or if the
+    /// minimum isn't found after `max_iter` iterations.
+    pub fn minimize(&mut self, max_iter: usize, epsabs: f64) -> Result<VectorF64, MultiMinError> {
+        for iterations in 1..=max_iter {
+            let status = self
+                .iterate()
+                .and_then(|()| self.gradient(|g| ::multimin::test_gradient(g, epsabs)));
+            match status {
+                Ok(ConvergenceStatus::Converged) => {
+                    return self.x(|x| x.try_clone()).ok_or(MultiMinError {
+                        error: Error::new(Value::NoMemory, "gsl_vector_alloc"),
+                        iterations,
+                    })
+                }
+                Ok(ConvergenceStatus::Continue) => {}
+                Err(error) => return Err(MultiMinError { error, iterations }),
+            }
+        }
+        Err(MultiMinError {
+            error: Error::new(Value::MaxIteration, "gsl_multimin_fdfminimizer_iterate"),
+            iterations: max_iter,
+        })
+    }
+}
+
+#[test]
+fn multimin_fdfminimizer_paraboloid() {
+    let center = [1., 2.];
+    let fdf = MultiMinFunctionFdf::new(
+        |x| 10. * (x.get(0) - center[0]).powi(2) + 20. * (x.get(1) - center[1]).powi(2) + 30.,
+        |x, g| {
+            g.set(0, 20. * (x.get(0) - center[0]));
+            g.set(1, 40. * (x.get(1) - center[1]));
+        },
+    );
+    let mut s = MultiMinFdfMinimizer::new(&MultiMinFdfMinimizerType::vector_bfgs2(), 2).unwrap();
+    s.set(fdf, &VectorF64::from_slice(&[5., 7.]).unwrap(), 0.01, 1e-4)
+        .unwrap();
+    let x = s.minimize(100, 1e-3).unwrap();
+    assert!((x.get(0) - 1.).abs() < 1e-4);
+    assert!((x.get(1) - 2.).abs() < 1e-4);
+    assert!((s.minimum() - 30.).abs() < 1e-6);
+    s.gradient(|g| assert!(g.get(0).abs() < 1e-3));
+}
@@ -0,0 +1,570 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Multidimensional Root-Finding
+
+This chapter describes functions for multidimensional root-finding (solving nonlinear systems
+with n equations in n unknowns). The library provides low level components for a variety of
+iterative solvers and convergence tests. These can be combined by the user to achieve the desired
+solution, with full access to the intermediate steps of the iteration.
+
+The problem of multidimensional root finding requires the simultaneous solution of n equations,
+f_i, in n variables, x_i,
+
+f_i (x_1, ..., x_n) = 0 for i = 1 ... n.
+
+The algorithms are iterative and start from an initial guess. The solvers using only the
+function values are held in a [`MultiRootFSolver`], the ones using its Jacobian matrix as well
+are held in a [`MultiRootFdfSolver`].
+
+```
+use rgsl::{MultiRootFSolver, MultiRootFSolverType, MultiRootTest, Value, VectorF64};
+
+// The Rosenbrock system, which has a single root at (1, 1).
+let mut s = MultiRootFSolver::new(&MultiRootFSolverType::hybrids(), 2).unwrap();
+let x = VectorF64::from_slice(&[-10., -5.]).unwrap();
+s.set(
+    |x, f| {
+        f.set(0, 1. - x.get(0));
+        f.set(1, 10. * (x.get(1) - x.get(0) * x.get(0)));
+        Value::Success
+    },
+    &x,
+)
+.unwrap();
+let root = s.solve(1000, MultiRootTest::Residual { epsabs: 1e-7 }).unwrap();
+assert!((root.get(0) - 1.).abs() < 1e-6 && (root.get(1) - 1.).abs() < 1e-6);
+```
+!*/
+
+use crate::paste::paste;
+use crate::{ConvergenceStatus, Error, MatrixF64, Value, VectorF64};
+use ffi::FFI;
+use std::fmt;
+use std::os::raw::{c_int, c_void};
+
+/// The convergence test used by [`MultiRootFSolver::solve`] and [`MultiRootFdfSolver::solve`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MultiRootTest {
+    /// The last step dx is small enough compared to the root estimate x (see
+    /// [`multiroot::test_delta`](crate::multiroot::test_delta)).
+    Delta { epsabs: f64, epsrel: f64 },
+    /// The residual f(x) is small enough (see
+    /// [`multiroot::test_residual`](crate::multiroot::test_residual)).
+    Residual { epsabs: f64 },
+}
+
+impl MultiRootTest {
+    fn check(
+        self,
+        x: *const sys::gsl_vector,
+        dx: *const sys::gsl_vector,
+        f: *const sys::gsl_vector,
+    ) -> Result<ConvergenceStatus, Error> {
+        match self {
+            Self::Delta { epsabs, epsrel } => ConvergenceStatus::from_test(
+                unsafe { sys::gsl_multiroot_test_delta(dx, x, epsabs, epsrel) },
+                "gsl_multiroot_test_delta",
+            ),
+            Self::Residual { epsabs } => ConvergenceStatus::from_test(
+                unsafe { sys::gsl_multiroot_test_residual(f, epsabs) },
+                "gsl_multiroot_test_residual",
+            ),
+        }
+    }
+}
+
+/// The error returned by [`MultiRootFSolver::solve`] and [`MultiRootFdfSolver::solve`]. The
+/// solver keeps the state it stopped in.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MultiRootSolveError {
+    /// Why the iteration stopped: [`Value::MaxIteration`] if the root wasn't found within the
+    /// allowed number of iterations.
+    pub error: Error,
+    /// The number of iterations done.
+    pub iterations: usize,
+}
+
+impl fmt::Display for MultiRootSolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} after {} iterations", self.error, self.iterations)
+    }
+}
+
+impl ::std::error::Error for MultiRootSolveError {}
+
+// `gsl_multiroot_function` is opaque in the bindings.
+#[repr(C)]
+struct RawFunction {
+    f: unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void, *mut sys::gsl_vector) -> c_int,
+    n: usize,
+    params: *mut c_void,
+}
+
+/// Owns the closure and the `gsl_multiroot_function` pointing to it, which GSL keeps a pointer
+/// to.
+struct Function<'a> {
+    raw: Box<RawFunction>,
+    _callback: Box<dyn FnMut(&VectorF64, &mut VectorF64) -> Value + 'a>,
+}
+
+impl<'a> Function<'a> {
+    fn new<F: FnMut(&VectorF64, &mut VectorF64) -> Value + 'a>(n: usize, f: F) -> Function<'a> {
+        unsafe extern "C" fn trampoline<F: FnMut(&VectorF64, &mut VectorF64) -> Value>(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            f: *mut sys::gsl_vector,
+        ) -> c_int {
+            let callback: &mut F = &mut *(params as *mut F);
+            ::error::catch_callback(Value::BadFunction, || {
+                ::ffi::lend_mut(f, |f| callback(&VectorF64::soft_wrap(x as *mut _), f))
+            })
+            .into()
+        }
+
+        let mut callback = Box::new(f);
+        let raw = Box::new(RawFunction {
+            f: trampoline::<F>,
+            n,
+            params: &mut *callback as *mut F as *mut _,
+        });
+        Function {
+            raw,
+            _callback: callback,
+        }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut sys::gsl_multiroot_function {
+        &mut *self.raw as *mut RawFunction as *mut _
+    }
+}
+
+type SystemFn<'a> = Box<dyn FnMut(&VectorF64, &mut VectorF64) -> Value + 'a>;
+type JacobianFn<'a> = Box<dyn FnMut(&VectorF64, &mut MatrixF64) -> Value + 'a>;
+type SystemJacobianFn<'a> =
+    Box<dyn FnMut(&VectorF64, &mut VectorF64, &mut MatrixF64) -> Value + 'a>;
+
+/// The system of equations solved by a [`MultiRootFdfSolver`], with its Jacobian matrix.
+pub struct MultiRootFunctionFdf<'a> {
+    f: SystemFn<'a>,
+    df: JacobianFn<'a>,
+    fdf: Option<SystemJacobianFn<'a>>,
+}
+
+impl<'a> MultiRootFunctionFdf<'a> {
+    /// `f(x, f)` stores the values of the functions at `x` into `f`, `df(x, J)` stores their
+    /// Jacobian matrix J_ij = d f_i / d x_j into `J`.
+    pub fn new<
+        F: FnMut(&VectorF64, &mut VectorF64) -> Value + 'a,
+        DF: FnMut(&VectorF64, &mut MatrixF64) -> Value + 'a,
+    >(
+        f: F,
+        df: DF,
+    ) -> MultiRootFunctionFdf<'a> {
+        MultiRootFunctionFdf {
+            f: Box::new(f),
+            df: Box::new(df),
+            fdf: None,
+        }
+    }
+
+    /// `fdf(x, f, J)` computes both the values and the Jacobian matrix, which is usually faster
+    /// than separate calls to `f` and `df`. Without it, they are called one after the other.
+    pub fn with_fdf<FDF: FnMut(&VectorF64, &mut VectorF64, &mut MatrixF64) -> Value + 'a>(
+        mut self,
+        fdf: FDF,
+    ) -> MultiRootFunctionFdf<'a> {
+        self.fdf = Some(Box::new(fdf));
+        self
+    }
+}
+
+// `gsl_multiroot_function_fdf` is opaque in the bindings.
+#[repr(C)]
+struct RawFunctionFdf {
+    f: unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void, *mut sys::gsl_vector) -> c_int,
+    df: unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void, *mut sys::gsl_matrix) -> c_int,
+    fdf: unsafe extern "C" fn(
+        *const sys::gsl_vector,
+        *mut c_void,
+        *mut sys::gsl_vector,
+        *mut sys::gsl_matrix,
+    ) -> c_int,
+    n: usize,
+    params: *mut c_void,
+}
+
+/// Same as [`Function`] but for `gsl_multiroot_function_fdf`.
+struct FunctionFdf<'a> {
+    raw: Box<RawFunctionFdf>,
+    _function: Box<MultiRootFunctionFdf<'a>>,
+}
+
+impl<'a> FunctionFdf<'a> {
+    fn new(n: usize, function: MultiRootFunctionFdf<'a>) -> FunctionFdf<'a> {
+        unsafe extern "C" fn f(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            f: *mut sys::gsl_vector,
+        ) -> c_int {
+            let function = &mut *(params as *mut MultiRootFunctionFdf);
+            ::error::catch_callback(Value::BadFunction, || {
+                ::ffi::lend_mut(f, |f| (function.f)(&VectorF64::soft_wrap(x as *mut _), f))
+            })
+            .into()
+        }
+        unsafe extern "C" fn df(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            J: *mut sys::gsl_matrix,
+        ) -> c_int {
+            let function = &mut *(params as *mut MultiRootFunctionFdf);
+            ::error::catch_callback(Value::BadFunction, || {
+                ::ffi::lend_mut(J, |J| (function.df)(&VectorF64::soft_wrap(x as *mut _), J))
+            })
+            .into()
+        }
+        unsafe extern "C" fn fdf(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            f: *mut sys::gsl_vector,
+            J: *mut sys::gsl_matrix,
+        ) -> c_int {
+            let function = &mut *(params as *mut MultiRootFunctionFdf);
+            let x = VectorF64::soft_wrap(x as *mut _);
+            ::error::catch_callback(Value::BadFunction, || {
+                ::ffi::lend_mut(f, |f: &mut VectorF64| {
+                    ::ffi::lend_mut(J, |J: &mut MatrixF64| match function.fdf {
+                        Some(ref mut fdf) => fdf(&x, f, J),
+                        None => match (function.f)(&x, f) {
+                            Value::Success => (function.df)(&x, J),
+                            status => status,
+                        },
+                    })
+                })
+            })
+            .into()
+        }
+
+        let mut function = Box::new(function);
+        let raw = Box::new(RawFunctionFdf {
+            f,
+            df,
+            fdf,
+            n,
+            params: &mut *function as *mut MultiRootFunctionFdf as *mut _,
+        });
+        FunctionFdf {
+            raw,
+            _function: function,
+        }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut sys::gsl_multiroot_function_fdf {
+        &mut *self.raw as *mut RawFunctionFdf as *mut _
+    }
+}
+
+// The methods shared by both solvers, `$name` being `gsl_multiroot_fsolver` or
+// `gsl_multiroot_fdfsolver`.
+macro_rules! solver_methods {
+    ($name:ident) => {
+        paste! {
+            /// This function performs a single iteration of the solver. If the iteration
+            /// encounters an unexpected problem then an error is returned, with the value:
+            ///
+            /// * [`Value::BadFunction`]: the iteration encountered a singular point where the
+            ///   function or its derivative evaluated to `Inf` or `NaN`.
+            /// * [`Value::NoProgress`]: the iteration is not making any progress, preventing
+            ///   the algorithm from continuing.
+            pub fn iterate(&mut self) -> Result<(), Error> {
+                let ret = unsafe { sys::[<$name _iterate>](self.unwrap_unique()) };
+                result_handler!(ret, (), stringify!([<$name _iterate>]))
+            }
+
+            /// Returns the solver type name.
+            pub fn name(&self) -> String {
+                unsafe {
+                    let tmp = sys::[<$name _name>](self.unwrap_shared());
+
+                    String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes())
+                        .to_string()
+                }
+            }
+
+            /// Calls `f` with the current estimate of the root.
+            pub fn root<R, F: FnOnce(&VectorF64) -> R>(&self, f: F) -> R {
+                f(&VectorF64::soft_wrap(unsafe {
+                    sys::[<$name _root>](self.unwrap_shared())
+                }))
+            }
+
+            /// Calls `f` with the function values at the current estimate of the root.
+            pub fn f<R, F: FnOnce(&VectorF64) -> R>(&self, f: F) -> R {
+                f(&VectorF64::soft_wrap(unsafe {
+                    sys::[<$name _f>](self.unwrap_shared())
+                }))
+            }
+
+            /// Calls `f` with the last step taken by the solver.
+            pub fn dx<R, F: FnOnce(&VectorF64) -> R>(&self, f: F) -> R {
+                f(&VectorF64::soft_wrap(unsafe {
+                    sys::[<$name _dx>](self.unwrap_shared())
+                }))
+            }
+
+            /// Iterates the solver until `test` succeeds, and returns a copy of the root. It
+            /// fails if an iteration fails or if the root isn't found after `max_iter`
+            /// iterations.
+            pub fn solve(
+                &mut self,
+                max_iter: usize,
+                test: MultiRootTest,
+            ) -> Result<VectorF64, MultiRootSolveError> {
+                for iterations in 1..=max_iter {
+                    let status = self.iterate().and_then(|()| unsafe {
+                        let s = self.unwrap_shared();
+                        test.check(
+                            sys::[<$name _root>](s),
+                            sys::[<$name _dx>](s),
+                            sys::[<$name _f>](s),
+                        )
+                    });
+                    match status {
+                        Ok(ConvergenceStatus::Converged) => {
+                            return self.root(|x| x.try_clone()).ok_or(MultiRootSolveError {
+                                error: Error::new(Value::NoMemory, "gsl_vector_alloc"),
+                                iterations,
+                            })
+                        }
+                        Ok(ConvergenceStatus::Continue) => {}
+                        Err(error) => return Err(MultiRootSolveError { error, iterations }),
+                    }
+                }
+                Err(MultiRootSolveError {
+                    error: Error::new(Value::MaxIteration, stringify!([<$name _iterate>])),
+                    iterations: max_iter,
+                })
+            }
+        }
+    };
+}
+
+ffi_wrapper!(
+    MultiRootFSolverType,
+    *const sys::gsl_multiroot_fsolver_type,
+    "The root finding algorithms described in this section do not require any derivative
+information to be supplied by the user. Any derivatives needed are approximated by finite
+differences. Note that if the finite-differencing step size chosen by these routines is
+inappropriate, an explicit user-supplied numerical derivative can always be used with the
+algorithms of [`MultiRootFdfSolverType`]."
+);
+
+unsafe impl Send for MultiRootFSolverType {}
+unsafe impl Sync for MultiRootFSolverType {}
+
+impl MultiRootFSolverType {
+    /// This is a version of the Hybrid algorithm which replaces calls to the Jacobian function
+    /// by its finite difference approximation. The finite difference approximation is computed
+    /// with a step size of sqrt(eps) |x_j|.
+    #[doc(alias = "gsl_multiroot_fsolver_hybrids")]
+    pub fn hybrids() -> MultiRootFSolverType {
+        ffi_wrap!(gsl_multiroot_fsolver_hybrids)
+    }
+
+    /// This is a finite difference version of the Hybrid algorithm without internal scaling.
+    #[doc(alias = "gsl_multiroot_fsolver_hybrid")]
+    pub fn hybrid() -> MultiRootFSolverType {
+        ffi_wrap!(gsl_multiroot_fsolver_hybrid)
+    }
+
+    /// The discrete Newton algorithm is the simplest method of solving a multidimensional
+    /// system. It uses the Newton iteration x -> x - J^{-1} f(x) where the Jacobian matrix J is
+    /// approximated by taking finite differences of the function f.
+    #[doc(alias = "gsl_multiroot_fsolver_dnewton")]
+    pub fn dnewton() -> MultiRootFSolverType {
+        ffi_wrap!(gsl_multiroot_fsolver_dnewton)
+    }
+
+    /// The Broyden algorithm is a version of the discrete Newton algorithm which attempts to
+    /// avoid the expensive update of the Jacobian matrix on each iteration. This algorithm is
+    /// included only for demonstration purposes, and is not recommended for serious use.
+    #[doc(alias = "gsl_multiroot_fsolver_broyden")]
+    pub fn broyden() -> MultiRootFSolverType {
+        ffi_wrap!(gsl_multiroot_fsolver_broyden)
+    }
+}
+
+ffi_wrapper!(
+    MultiRootFSolver<'a>,
+    *mut sys::gsl_multiroot_fsolver,
+    gsl_multiroot_fsolver_free,
+    Function<'a>
+);
+
+impl<'a> MultiRootFSolver<'a> {
+    /// This function returns a newly allocated instance of a solver of type `t` for a system of
+    /// `n` dimensions.
+    #[doc(alias = "gsl_multiroot_fsolver_alloc")]
+    pub fn new(t: &MultiRootFSolverType, n: usize) -> Option<MultiRootFSolver<'a>> {
+        let tmp = unsafe { sys::gsl_multiroot_fsolver_alloc(t.unwrap_shared(), n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function initializes, or reinitializes, the solver to use the system `f` and the
+    /// initial guess `x`. `f(x, f)` stores the values of the functions at `x` into `f`.
+    ///
+    /// The solver keeps `f` until it is reinitialized or dropped.
+    #[doc(alias = "gsl_multiroot_fsolver_set")]
+    pub fn set<F: FnMut(&VectorF64, &mut VectorF64) -> Value + 'a>(
+        &mut self,
+        f: F,
+        x: &VectorF64,
+    ) -> Result<(), Error> {
+        let mut function = Function::new(x.len(), f);
+        let ret = unsafe {
+            sys::gsl_multiroot_fsolver_set(
+                self.unwrap_unique(),
+                function.as_mut_ptr(),
+                x.unwrap_shared(),
+            )
+        };
+        // GSL keeps a pointer to it, and the previous one (if any) isn't used anymore.
+        self.function = Some(function);
+        result_handler!(ret, (), "gsl_multiroot_fsolver_set")
+    }
+
+    solver_methods!(gsl_multiroot_fsolver);
+}
+
+ffi_wrapper!(
+    MultiRootFdfSolverType,
+    *const sys::gsl_multiroot_fdfsolver_type,
+    "The root finding algorithms described in this section make use of both the function and its
+derivative. They require an initial guess for the location of the root, but there is no absolute
+guarantee of convergence—the function must be suitable for this technique and the initial guess
+must be sufficiently close to the root for it to work."
+);
+
+unsafe impl Send for MultiRootFdfSolverType {}
+unsafe impl Sync for MultiRootFdfSolverType {}
+
+impl MultiRootFdfSolverType {
+    /// This is a modified version of Powell's Hybrid method as implemented in the HYBRJ
+    /// algorithm in MINPACK. The Hybrid algorithm retains the fast convergence of Newton's
+    /// method but will also reduce the residual when Newton's method is unreliable. It uses a
+    /// trust region, with the variables scaled by the norms of the columns of the Jacobian.
+    #[doc(alias = "gsl_multiroot_fdfsolver_hybridsj")]
+    pub fn hybridsj() -> MultiRootFdfSolverType {
+        ffi_wrap!(gsl_multiroot_fdfsolver_hybridsj)
+    }
+
+    /// This algorithm is an unscaled version of `hybridsj`. The steps are controlled by a
+    /// spherical trust region instead of a generalized region.
+    #[doc(alias = "gsl_multiroot_fdfsolver_hybridj")]
+    pub fn hybridj() -> MultiRootFdfSolverType {
+        ffi_wrap!(gsl_multiroot_fdfsolver_hybridj)
+    }
+
+    /// Newton's Method is the standard root-polishing algorithm. It uses the Newton iteration
+    /// x -> x - J^{-1} f(x).
+    #[doc(alias = "gsl_multiroot_fdfsolver_newton")]
+    pub fn newton() -> MultiRootFdfSolverType {
+        ffi_wrap!(gsl_multiroot_fdfsolver_newton)
+    }
+
+    /// This is a modified version of Newton's method which attempts to improve global
+    /// convergence by requiring every step to reduce the Euclidean norm of the residual,
+    /// |f(x)|. If the Newton step leads to an increase in the norm then a reduced step is
+    /// taken instead.
+    #[doc(alias = "gsl_multiroot_fdfsolver_gnewton")]
+    pub fn gnewton() -> MultiRootFdfSolverType {
+        ffi_wrap!(gsl_multiroot_fdfsolver_gnewton)
+    }
+}
+
+ffi_wrapper!(
+    MultiRootFdfSolver<'a>,
+    *mut sys::gsl_multiroot_fdfsolver,
+    gsl_multiroot_fdfsolver_free,
+    FunctionFdf<'a>
+);
+
+impl<'a> MultiRootFdfSolver<'a> {
+    /// This function returns a newly allocated instance of a derivative solver of type `t` for
+    /// a system of `n` dimensions.
+    #[doc(alias = "gsl_multiroot_fdfsolver_alloc")]
+    pub fn new(t: &MultiRootFdfSolverType, n: usize) -> Option<MultiRootFdfSolver<'a>> {
+        let tmp = unsafe { sys::gsl_multiroot_fdfsolver_alloc(t.unwrap_shared(), n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function initializes, or reinitializes, the solver to use the system `fdf` and the
+    /// initial guess `x`.
+    ///
+    /// The solver keeps `fdf` until it is reinitialized or dropped.
+    #[doc(alias = "gsl_multiroot_fdfsolver_set")]
+    pub fn set(&mut self, fdf: MultiRootFunctionFdf<'a>, x: &VectorF64) -> Result<(), Error> {
+        let mut function = FunctionFdf::new(x.len(), fdf);
+        let ret = unsafe {
+            sys::gsl_multiroot_fdfsolver_set(
+                self.unwrap_unique(),
+                function.as_mut_ptr(),
+                x.unwrap_shared(),
+            )
+        };
+        // GSL keeps a pointer to it, and the previous one (if any) isn't used anymore.
+        self.function = Some(function);
+        result_handler!(ret, (), "gsl_multiroot_fdfsolver_set")
+    }
+
+    solver_methods!(gsl_multiroot_fdfsolver);
+}
+
+#[test]
+fn multiroot_fdfsolver_rosenbrock() {
+    let mut s = MultiRootFdfSolver::new(&MultiRootFdfSolverType::gnewton(), 2).unwrap();
+    // The closures own their data: it would be freed if the solver didn't keep them.
+    let (a, b) = (Box::new(1.), Box::new(10.));
+    let b2 = b.clone();
+    let fdf = MultiRootFunctionFdf::new(
+        move |x, f| {
+            f.set(0, *a * (1. - x.get(0)));
+            f.set(1, *b * (x.get(1) - x.get(0) * x.get(0)));
+            Value::Success
+        },
+        move |x, J| {
+            J.set(0, 0, -1.);
+            J.set(0, 1, 0.);
+            J.set(1, 0, -2. * *b2 * x.get(0));
+            J.set(1, 1, *b2);
+            Value::Success
+        },
+    );
+    s.set(fdf, &VectorF64::from_slice(&[-10., -5.]).unwrap())
+        .unwrap();
+    let root = s
+        .solve(
+            1000,
+            MultiRootTest::Delta {
+                epsabs: 0.,
+                epsrel: 1e-10,
+            },
+        )
+        .unwrap();
+    assert!((root.get(0) - 1.).abs() < 1e-8);
+    assert!((root.get(1) - 1.).abs() < 1e-8);
+    s.f(|f| assert!(f.get(0).abs() < 1e-8));
+}
@@ -11,12 +11,15 @@ vector with replacement. In mathematical terms, n is the cardinality of the mult
 Multisets are useful, for example, when iterating over the indices of a k-th order symmetric tensor in n-space.
 !*/
 
-use crate::Value;
+use crate::Error;
 use ffi::FFI;
+use std::fmt::{self, Debug, Formatter};
 use std::io;
 
 ffi_wrapper!(MultiSet, *mut sys::gsl_multiset, gsl_multiset_free);
 
+unsafe impl Send for MultiSet {}
+
 impl MultiSet {
     /// This function allocates memory for a new multiset with parameters n, k. The multiset is not
     /// initialized and its elements are undefined. Use the function [`Self::new_with_init`] if you
@@ -65,8 +68,9 @@ impl MultiSet {
     /// This function copies the elements of the multiset `self` into the multiset dest. The two
     /// multisets must have the same size.
     #[doc(alias = "gsl_multiset_memcpy")]
-    pub fn copy(&self, dest: &mut MultiSet) -> Value {
-        Value::from(unsafe { sys::gsl_multiset_memcpy(dest.unwrap_unique(), self.unwrap_shared()) })
+    pub fn copy(&self, dest: &mut MultiSet) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_multiset_memcpy(dest.unwrap_unique(), self.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_multiset_memcpy")
     }
 
     /// This function returns the value of the i-th element of the multiset c. If i lies outside the
@@ -106,34 +110,219 @@ impl MultiSet {
         }
     }
 
+    /// Same as [`MultiSet::data`], named like [`Combination::as_slice`](crate::Combination::as_slice).
+    #[doc(alias = "gsl_multiset_data")]
+    pub fn as_slice(&self) -> &[usize] {
+        self.data()
+    }
+
+    /// Same as [`MultiSet::data_mut`].
+    #[doc(alias = "gsl_multiset_data")]
+    pub fn as_mut_slice(&mut self) -> &mut [usize] {
+        self.data_mut()
+    }
+
+    /// Returns a copy of the elements of the multiset.
+    pub fn to_vec(&self) -> Vec<usize> {
+        self.data().to_vec()
+    }
+
+    /// Creates a multiset of k = `data.len()` elements taken from the range 0 to n-1 and
+    /// initializes it with `data`. Returns `None` if the allocation failed or if `data` isn't a
+    /// valid multiset (elements must lie in the range 0 to n-1 and be in non-decreasing order).
+    pub fn from_slice(n: usize, data: &[usize]) -> Option<Self> {
+        if data.iter().any(|&v| v >= n) || data.windows(2).any(|w| w[0] > w[1]) {
+            return None;
+        }
+        let mut m = Self::new(n, data.len())?;
+        m.data_mut().copy_from_slice(data);
+        Some(m)
+    }
+
     /// This function checks that the multiset self is valid. The k elements should lie in the range
     /// 0 to n-1, with each value occurring in non-decreasing order.
     #[doc(alias = "gsl_multiset_valid")]
-    pub fn valid(&self) -> Value {
+    pub fn valid(&self) -> Result<(), Error> {
         // Little trick here: the function is expecting a mutable pointer whereas it doesn't need
         // to be...
-        Value::from(unsafe { sys::gsl_multiset_valid(self.inner) })
+        let ret = unsafe { sys::gsl_multiset_valid(self.inner) };
+        result_handler!(ret, (), "gsl_multiset_valid")
+    }
+
+    /// Same as [`MultiSet::valid`], but returns a `bool` like [`Combination::is_valid`](crate::Combination::is_valid).
+    #[doc(alias = "gsl_multiset_valid")]
+    pub fn is_valid(&self) -> bool {
+        self.valid().is_ok()
     }
 
     /// This function advances the multiset self to the next multiset element in lexicographic order
-    /// and returns [`Value::Success`]. If no further multisets elements are available it returns
-    /// [`Value::Failure`] and leaves self unmodified. Starting with the first multiset and
+    /// and returns `Ok(())`. If no further multisets elements are available it returns a
+    /// [`Value::Failure`] error and leaves self unmodified. Starting with the first multiset and
     /// repeatedly applying this function will iterate through all possible multisets of a given
     /// order.
     #[doc(alias = "gsl_multiset_next")]
-    pub fn next(&mut self) -> Value {
-        Value::from(unsafe { sys::gsl_multiset_next(self.unwrap_unique()) })
+    pub fn next(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_multiset_next(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_multiset_next")
     }
 
     /// This function steps backwards from the multiset self to the previous multiset element in
-    /// lexicographic order, returning [`Value::Success`]. If no previous multiset is available it
-    /// returns [`Value::Failure`] and leaves self unmodified.
+    /// lexicographic order, returning `Ok(())`. If no previous multiset is available it
+    /// returns a [`Value::Failure`] error and leaves self unmodified.
     #[doc(alias = "gsl_multiset_prev")]
-    pub fn prev(&mut self) -> Value {
-        Value::from(unsafe { sys::gsl_multiset_prev(self.unwrap_unique()) })
+    pub fn prev(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_multiset_prev(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_multiset_prev")
     }
 
     pub fn print<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
         write!(writer, "{:?}", self.data())
     }
+
+    /// Writes the elements of the multiset, each one followed by a new line, like
+    /// `gsl_multiset_fprintf` does with the `"%zu\n"` format.
+    #[doc(alias = "gsl_multiset_fprintf")]
+    pub fn fprintf<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        for v in self.data() {
+            writeln!(writer, "{}", v)?;
+        }
+        Ok(())
+    }
+
+    /// Returns an iterator yielding the current multiset followed by all the next ones in
+    /// lexicographic order (using [`MultiSet::next`]).
+    pub fn iter(&mut self) -> MultiSetIter<'_> {
+        MultiSetIter {
+            c: self,
+            forward: true,
+            done: false,
+        }
+    }
+
+    /// Returns an iterator yielding the current multiset followed by all the previous ones in
+    /// lexicographic order (using [`MultiSet::prev`]).
+    pub fn iter_rev(&mut self) -> MultiSetIter<'_> {
+        MultiSetIter {
+            c: self,
+            forward: false,
+            done: false,
+        }
+    }
+}
+
+impl Clone for MultiSet {
+    fn clone(&self) -> Self {
+        let mut m = Self::new(self.n(), self.k()).expect("MultiSet::new failed");
+        self.copy(&mut m).expect("gsl_multiset_memcpy failed");
+        m
+    }
+}
+
+impl Debug for MultiSet {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.data())
+    }
+}
+
+/// Iterator over successive multisets, created by [`MultiSet::iter`] and [`MultiSet::iter_rev`].
+pub struct MultiSetIter<'a> {
+    c: &'a mut MultiSet,
+    forward: bool,
+    done: bool,
+}
+
+impl<'a> Iterator for MultiSetIter<'a> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let current = self.c.to_vec();
+        let ret = if self.forward {
+            self.c.next()
+        } else {
+            self.c.prev()
+        };
+        self.done = ret.is_err();
+        Some(current)
+    }
+}
+
+/// Owning iterator over successive multisets, created by calling `into_iter` on a [`MultiSet`].
+pub struct MultiSetIntoIter {
+    c: MultiSet,
+    done: bool,
+}
+
+impl Iterator for MultiSetIntoIter {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let current = self.c.to_vec();
+        self.done = self.c.next().is_err();
+        Some(current)
+    }
+}
+
+impl IntoIterator for MultiSet {
+    type Item = Vec<usize>;
+    type IntoIter = MultiSetIntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MultiSetIntoIter {
+            c: self,
+            done: false,
+        }
+    }
+}
+
+/// Returns an iterator over all the multisets of k elements taken from `{0, ..., n-1}` in
+/// lexicographic order.
+///
+/// ```
+/// use rgsl::multiset::all_multisets;
+///
+/// let all: Vec<Vec<usize>> = all_multisets(3, 2).collect();
+/// assert_eq!(all.len(), 6);
+/// assert_eq!(all[1], vec![0, 1]);
+/// assert_eq!(all[5], vec![2, 2]);
+/// ```
+pub fn all_multisets(n: usize, k: usize) -> MultiSetIntoIter {
+    MultiSet::new_with_init(n, k)
+        .expect("MultiSet::new_with_init failed")
+        .into_iter()
+}
+
+impl From<MultiSet> for Vec<usize> {
+    fn from(m: MultiSet) -> Self {
+        m.to_vec()
+    }
+}
+
+#[test]
+fn multiset_iter() {
+    let mut m = MultiSet::new_with_init(3, 2).unwrap();
+    let all: Vec<Vec<usize>> = m.iter().collect();
+    assert_eq!(
+        all,
+        vec![
+            vec![0, 0],
+            vec![0, 1],
+            vec![0, 2],
+            vec![1, 1],
+            vec![1, 2],
+            vec![2, 2]
+        ]
+    );
+    assert_eq!(m.iter_rev().count(), 6);
+    assert_eq!(m.clone().to_vec(), vec![0, 0]);
+
+    assert!(MultiSet::from_slice(3, &[0, 2, 2]).unwrap().is_valid());
+    assert!(MultiSet::from_slice(3, &[1, 0]).is_none());
+    assert!(MultiSet::from_slice(3, &[3]).is_none());
+    assert_eq!(all_multisets(4, 3).count(), 20);
 }
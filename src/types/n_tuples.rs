@@ -28,13 +28,166 @@ The selection function determines which ntuple rows are selected for histogrammi
 Further information on the use of ntuples can be found in the documentation for the CERN packages PAW and HBOOK (available online).
 !*/
 
-use crate::Value;
+use crate::{Error, Value};
 use ffi::FFI;
 use std::ffi::CString;
-use std::mem::MaybeUninit;
+use std::mem::{size_of, MaybeUninit};
 use std::os::raw::{c_char, c_void};
 use std::path::Path;
 
+/// An ntuple file whose rows are values of type `T`.
+///
+/// The rows are read and written as their bytes (like with `fread` and `fwrite`), so `T` should
+/// be a plain data type, ideally `#[repr(C)]` to be compatible with the C programs handling the
+/// same files. The files don't have a header: when opening one, its size is only checked to be a
+/// multiple of the size of `T`.
+///
+/// ```no_run
+/// use rgsl::{Histogram, NTuple};
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Event {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// let mut ntuple = NTuple::<Event>::create("events.dat").unwrap();
+/// for i in 0..100 {
+///     ntuple.write(&Event { x: i as f64, y: (i % 10) as f64 }).unwrap();
+/// }
+/// drop(ntuple);
+///
+/// let mut h = Histogram::new(10).unwrap();
+/// h.set_ranges_uniform(0., 100.).unwrap();
+/// let mut ntuple = NTuple::<Event>::open("events.dat").unwrap();
+/// ntuple.project(&mut h, |e| e.x, |e| e.y < 5.).unwrap();
+/// ```
+pub struct NTuple<T: Copy> {
+    n: *mut sys::gsl_ntuple,
+    // GSL reads and writes the rows through this buffer.
+    row: Box<MaybeUninit<T>>,
+}
+
+impl<T: Copy> NTuple<T> {
+    fn from_path<P: AsRef<Path>>(
+        filename: P,
+        open: unsafe extern "C" fn(*mut c_char, *mut c_void, usize) -> *mut sys::gsl_ntuple,
+        function: &'static str,
+    ) -> Result<NTuple<T>, Error> {
+        if size_of::<T>() == 0 {
+            return Err(Error::new(Value::BadLength, function));
+        }
+        let filename = filename.as_ref();
+        let filename = filename.to_str().expect("Failed to convert path to str");
+        let c_str = CString::new(filename.as_bytes()).unwrap();
+        let mut row = Box::new(MaybeUninit::<T>::uninit());
+        let tmp = unsafe {
+            open(
+                c_str.as_ptr() as *mut c_char,
+                row.as_mut_ptr() as *mut _,
+                size_of::<T>(),
+            )
+        };
+
+        if tmp.is_null() {
+            Err(Error::new(Value::Failure, function))
+        } else {
+            Ok(NTuple { n: tmp, row })
+        }
+    }
+
+    /// This function creates a new write-only ntuple file `filename` for rows of type `T`. Any
+    /// existing file with the same name is truncated to zero length and overwritten.
+    #[doc(alias = "gsl_ntuple_create")]
+    pub fn create<P: AsRef<Path>>(filename: P) -> Result<NTuple<T>, Error> {
+        Self::from_path(filename, sys::gsl_ntuple_create, "gsl_ntuple_create")
+    }
+
+    /// This function opens an existing ntuple file `filename` for reading. It fails with
+    /// [`Value::BadLength`] if the size of the file isn't a multiple of the size of `T`.
+    #[doc(alias = "gsl_ntuple_open")]
+    pub fn open<P: AsRef<Path>>(filename: P) -> Result<NTuple<T>, Error> {
+        if let Ok(metadata) = ::std::fs::metadata(filename.as_ref()) {
+            if size_of::<T>() != 0 && metadata.len() % size_of::<T>() as u64 != 0 {
+                return Err(Error::new(Value::BadLength, "gsl_ntuple_open"));
+            }
+        }
+        Self::from_path(filename, sys::gsl_ntuple_open, "gsl_ntuple_open")
+    }
+
+    /// This function writes the row `row` to the file.
+    #[doc(alias = "gsl_ntuple_write")]
+    pub fn write(&mut self, row: &T) -> Result<(), Error> {
+        *self.row = MaybeUninit::new(*row);
+        let ret = unsafe { sys::gsl_ntuple_write(self.n) };
+        result_handler!(ret, (), "gsl_ntuple_write")
+    }
+
+    /// This function reads the next row of the file. It returns `None` at the end of the file.
+    #[doc(alias = "gsl_ntuple_read")]
+    pub fn read(&mut self) -> Result<Option<T>, Error> {
+        match Value::from(unsafe { sys::gsl_ntuple_read(self.n) }) {
+            Value::Success => Ok(Some(unsafe { (*self.row).assume_init() })),
+            Value::EOF => Ok(None),
+            ret => Err(Error::new(ret, "gsl_ntuple_read")),
+        }
+    }
+
+    /// This function updates the histogram `h` from the rows of the file (from the current one to
+    /// the end) using the functions `value_func` and `select_func`. For each row where
+    /// `select_func` returns `true` the corresponding value of that row is computed using
+    /// `value_func` and added to the histogram. New entries are added to the histogram, so
+    /// subsequent calls can be used to accumulate further data in the same histogram.
+    #[doc(alias = "gsl_ntuple_project")]
+    pub fn project<V: FnMut(&T) -> f64, S: FnMut(&T) -> bool>(
+        &mut self,
+        h: &mut ::Histogram,
+        mut value_func: V,
+        mut select_func: S,
+    ) -> Result<(), Error> {
+        unsafe extern "C" fn value_trampoline<T, F: FnMut(&T) -> f64>(
+            x: *mut c_void,
+            params: *mut c_void,
+        ) -> f64 {
+            let f: &mut F = &mut *(params as *mut F);
+            ::error::catch_callback(f64::NAN, || f(&*(x as *const T)))
+        }
+        unsafe extern "C" fn select_trampoline<T, F: FnMut(&T) -> bool>(
+            x: *mut c_void,
+            params: *mut c_void,
+        ) -> i32 {
+            let f: &mut F = &mut *(params as *mut F);
+            ::error::catch_callback(false, || f(&*(x as *const T))) as i32
+        }
+
+        let mut value_function = sys::gsl_ntuple_value_fn {
+            function: Some(value_trampoline::<T, V>),
+            params: &mut value_func as *mut V as *mut _,
+        };
+        let mut select_function = sys::gsl_ntuple_select_fn {
+            function: Some(select_trampoline::<T, S>),
+            params: &mut select_func as *mut S as *mut _,
+        };
+        let ret = unsafe {
+            sys::gsl_ntuple_project(
+                h.unwrap_unique(),
+                self.n,
+                &mut value_function,
+                &mut select_function,
+            )
+        };
+        result_handler!(ret, (), "gsl_ntuple_project")
+    }
+}
+
+impl<T: Copy> Drop for NTuple<T> {
+    #[doc(alias = "gsl_ntuple_close")]
+    fn drop(&mut self) {
+        unsafe { sys::gsl_ntuple_close(self.n) };
+    }
+}
+
 pub struct WriteNTuples {
     n: *mut sys::gsl_ntuple,
 }
@@ -63,22 +216,24 @@ impl WriteNTuples {
     /// This function writes the current ntuple ntuple->ntuple_data of size ntuple->size to the
     /// corresponding file.
     #[doc(alias = "gsl_ntuple_write")]
-    pub fn write<T: Sized>(&mut self, data: &T) -> Value {
-        Value::from(unsafe {
+    pub fn write<T: Sized>(&mut self, data: &T) -> Result<(), Error> {
+        let ret = unsafe {
             (*self.n).ntuple_data = data as *const T as usize as *mut _;
             (*self.n).size = ::std::mem::size_of::<T>() as _;
             sys::gsl_ntuple_write(self.n)
-        })
+        };
+        result_handler!(ret, (), "gsl_ntuple_write")
     }
 
     /// This function is a synonym for NTuples::write.
     #[doc(alias = "gsl_ntuple_bookdata")]
-    pub fn bookdata<T: Sized>(&mut self, data: &T) -> Value {
-        Value::from(unsafe {
+    pub fn bookdata<T: Sized>(&mut self, data: &T) -> Result<(), Error> {
+        let ret = unsafe {
             (*self.n).ntuple_data = data as *const T as usize as *mut _;
             (*self.n).size = ::std::mem::size_of::<T>() as _;
             sys::gsl_ntuple_bookdata(self.n)
-        })
+        };
+        result_handler!(ret, (), "gsl_ntuple_bookdata")
     }
 }
 
@@ -117,7 +272,7 @@ impl ReadNTuples {
     /// This function reads the current row of the ntuple file for ntuple and stores the values in
     /// ntuple->data.
     #[doc(alias = "gsl_ntuple_read")]
-    pub fn read<T: Sized>(&mut self) -> (Value, T) {
+    pub fn read<T: Sized>(&mut self) -> Result<T, Error> {
         let mut data = MaybeUninit::<T>::uninit();
 
         let ret = unsafe {
@@ -125,7 +280,7 @@ impl ReadNTuples {
             (*self.n).size = ::std::mem::size_of::<T>() as _;
             sys::gsl_ntuple_read(self.n)
         };
-        (::Value::from(ret), unsafe { data.assume_init() })
+        result_handler!(ret, unsafe { data.assume_init() }, "gsl_ntuple_read")
     }
 }
 
@@ -146,51 +301,50 @@ macro_rules! impl_project {
             /// `false` are ignored. New entries are added to the histogram, so subsequent calls can be used
             /// to accumulate further data in the same histogram.
             #[doc(alias = "gsl_ntuple_project")]
-            pub fn project<T: Sized, V: Fn(&T) -> f64, S: Fn(&T) -> bool>(
+            pub fn project<T: Sized, V: FnMut(&T) -> f64, S: FnMut(&T) -> bool>(
                 &self,
                 h: &mut ::Histogram,
-                value_func: V,
-                select_func: S,
-            ) -> Value {
-                unsafe extern "C" fn value_trampoline<T: Sized, F: Fn(&T) -> f64>(
+                mut value_func: V,
+                mut select_func: S,
+            ) -> Result<(), Error> {
+                unsafe extern "C" fn value_trampoline<T: Sized, F: FnMut(&T) -> f64>(
                     x: *mut c_void,
                     params: *mut c_void,
                 ) -> f64 {
-                    let f: &F = &*(params as *const F);
+                    let f: &mut F = &mut *(params as *mut F);
                     let x: &T = &*(x as *const T);
-                    f(x)
+                    ::error::catch_callback(f64::NAN, || f(x))
                 }
-                unsafe extern "C" fn select_trampoline<T: Sized, F: Fn(&T) -> bool>(
+                unsafe extern "C" fn select_trampoline<T: Sized, F: FnMut(&T) -> bool>(
                     x: *mut c_void,
                     params: *mut c_void,
                 ) -> i32 {
-                    let f: &F = &*(params as *const F);
+                    let f: &mut F = &mut *(params as *mut F);
                     let x: &T = &*(x as *const T);
-                    if f(x) {
+                    if ::error::catch_callback(false, || f(x)) {
                         1
                     } else {
                         0
                     }
                 }
 
-                let f: Box<V> = Box::new(value_func);
                 let mut value_function = sys::gsl_ntuple_value_fn {
-                    function: unsafe { ::std::mem::transmute(value_trampoline::<T, V> as usize) },
-                    params: Box::into_raw(f) as *mut _,
+                    function: Some(value_trampoline::<T, V>),
+                    params: &mut value_func as *mut V as *mut _,
                 };
-                let f: Box<S> = Box::new(select_func);
                 let mut select_function = sys::gsl_ntuple_select_fn {
-                    function: unsafe { ::std::mem::transmute(select_trampoline::<T, S> as usize) },
-                    params: Box::into_raw(f) as *mut _,
+                    function: Some(select_trampoline::<T, S>),
+                    params: &mut select_func as *mut S as *mut _,
                 };
-                Value::from(unsafe {
+                let ret = unsafe {
                     sys::gsl_ntuple_project(
                         h.unwrap_unique(),
                         self.n,
                         &mut value_function,
                         &mut select_function,
                     )
-                })
+                };
+                result_handler!(ret, (), "gsl_ntuple_project")
             }
         }
     };
@@ -198,3 +352,35 @@ macro_rules! impl_project {
 
 impl_project!(WriteNTuples);
 impl_project!(ReadNTuples);
+
+#[test]
+fn ntuple_round_trip() {
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct Event {
+        x: f64,
+        n: u32,
+    }
+
+    let path = ::std::env::temp_dir().join("rgsl_ntuple_round_trip.dat");
+    let mut ntuple = NTuple::<Event>::create(&path).unwrap();
+    for n in 0..10 {
+        ntuple
+            .write(&Event {
+                x: n as f64 / 10.,
+                n,
+            })
+            .unwrap();
+    }
+    drop(ntuple);
+
+    let mut ntuple = NTuple::<Event>::open(&path).unwrap();
+    assert_eq!(ntuple.read().unwrap().map(|e| e.n), Some(0));
+    let mut h = ::Histogram::new(2).unwrap();
+    h.set_ranges_uniform(0., 1.).unwrap();
+    ntuple.project(&mut h, |e| e.x, |e| e.n % 2 == 1).unwrap();
+    assert_eq!(h.get(0) + h.get(1), 5.);
+    assert!(ntuple.read().unwrap().is_none());
+    assert!(NTuple::<[u8; 3]>::open(&path).is_err());
+    ::std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,94 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+// Conversions between the vectors and matrices and the `nalgebra` ones.
+//
+// `nalgebra` stores its matrices in column-major order while GSL uses row-major order, so the
+// elements are always copied.
+
+use crate::{Error, Value};
+use nalgebra::{DMatrix, DVector, Dim, Matrix, Storage, U1};
+use std::convert::TryFrom;
+
+macro_rules! nalgebra_vector {
+    ($rust_name:ident, $rust_ty:ident, $alloc:expr) => {
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+        impl<'a> From<&'a ::$rust_name> for DVector<$rust_ty> {
+            fn from(v: &'a ::$rust_name) -> DVector<$rust_ty> {
+                DVector::from_fn(v.len(), |i, _| v.get(i))
+            }
+        }
+
+        /// Fails if the vector can't be allocated (GSL doesn't allow empty vectors).
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+        impl<'a, R: Dim, S: Storage<$rust_ty, R, U1>> TryFrom<&'a Matrix<$rust_ty, R, U1, S>>
+            for ::$rust_name
+        {
+            type Error = Error;
+
+            fn try_from(a: &'a Matrix<$rust_ty, R, U1, S>) -> Result<::$rust_name, Error> {
+                let mut v = ::$rust_name::new(a.nrows())
+                    .ok_or_else(|| Error::new(Value::NoMemory, $alloc))?;
+                for (i, x) in a.iter().enumerate() {
+                    v.set(i, *x);
+                }
+                Ok(v)
+            }
+        }
+    };
+}
+
+macro_rules! nalgebra_matrix {
+    ($rust_name:ident, $rust_ty:ident, $alloc:expr) => {
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+        impl<'a> From<&'a ::$rust_name> for DMatrix<$rust_ty> {
+            fn from(m: &'a ::$rust_name) -> DMatrix<$rust_ty> {
+                DMatrix::from_fn(m.size1(), m.size2(), |i, j| m.get(i, j))
+            }
+        }
+
+        /// Fails if the matrix can't be allocated (GSL doesn't allow empty matrices).
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+        impl<'a, R: Dim, C: Dim, S: Storage<$rust_ty, R, C>> TryFrom<&'a Matrix<$rust_ty, R, C, S>>
+            for ::$rust_name
+        {
+            type Error = Error;
+
+            fn try_from(a: &'a Matrix<$rust_ty, R, C, S>) -> Result<::$rust_name, Error> {
+                let mut m = ::$rust_name::new(a.nrows(), a.ncols())
+                    .ok_or_else(|| Error::new(Value::NoMemory, $alloc))?;
+                for i in 0..a.nrows() {
+                    for j in 0..a.ncols() {
+                        m.set(i, j, a[(i, j)]);
+                    }
+                }
+                Ok(m)
+            }
+        }
+    };
+}
+
+nalgebra_vector!(VectorF32, f32, "gsl_vector_float_calloc");
+nalgebra_vector!(VectorF64, f64, "gsl_vector_calloc");
+nalgebra_vector!(VectorI32, i32, "gsl_vector_int_calloc");
+nalgebra_vector!(VectorU32, u32, "gsl_vector_uint_calloc");
+nalgebra_matrix!(MatrixF32, f32, "gsl_matrix_float_calloc");
+nalgebra_matrix!(MatrixF64, f64, "gsl_matrix_calloc");
+nalgebra_matrix!(MatrixI32, i32, "gsl_matrix_int_calloc");
+nalgebra_matrix!(MatrixU32, u32, "gsl_matrix_uint_calloc");
+
+#[test]
+fn nalgebra_round_trip() {
+    use nalgebra::{Matrix2x3, Vector2};
+    use {MatrixF64, VectorF64};
+
+    let a = Matrix2x3::new(1., 2., 3., 4., 5., 6.);
+    let m = MatrixF64::try_from(&a).unwrap();
+    assert_eq!(m.get(1, 0), 4.);
+    assert_eq!(DMatrix::from(&m), a);
+
+    let v = VectorF64::try_from(&Vector2::new(2., 5.)).unwrap();
+    assert_eq!(v.get(1), 5.);
+    assert_eq!(DVector::from(&v), DVector::from_vec(vec![2., 5.]));
+}
@@ -0,0 +1,130 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+// Conversions between the vectors and matrices and the `ndarray` arrays.
+//
+// The views share the memory of the GSL objects (using their stride or tda), the `From` and
+// `TryFrom` implementations copy it since GSL and `ndarray` can't free each other's allocations.
+
+use crate::{Error, Value};
+use ffi::FFI;
+use ndarray::{
+    Array1, Array2, ArrayBase, ArrayView1, ArrayView2, ArrayViewMut1, ArrayViewMut2, Data, Ix1,
+    Ix2, ShapeBuilder,
+};
+use std::convert::TryFrom;
+
+macro_rules! ndarray_vector {
+    ($rust_name:ident, $rust_ty:ident, $alloc:expr) => {
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+        impl ::$rust_name {
+            /// Returns an `ndarray` view of the vector, sharing its memory.
+            pub fn as_array_view(&self) -> ArrayView1<'_, $rust_ty> {
+                unsafe {
+                    let v = &*self.unwrap_shared();
+                    ArrayView1::from_shape_ptr((v.size,).strides((v.stride,)), v.data)
+                }
+            }
+
+            /// Returns a mutable `ndarray` view of the vector, sharing its memory.
+            pub fn as_array_view_mut(&mut self) -> ArrayViewMut1<'_, $rust_ty> {
+                unsafe {
+                    let v = &*self.unwrap_unique();
+                    ArrayViewMut1::from_shape_ptr((v.size,).strides((v.stride,)), v.data)
+                }
+            }
+        }
+
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+        impl<'a> From<&'a ::$rust_name> for Array1<$rust_ty> {
+            fn from(v: &'a ::$rust_name) -> Array1<$rust_ty> {
+                v.as_array_view().to_owned()
+            }
+        }
+
+        /// Fails if the vector can't be allocated (GSL doesn't allow empty vectors).
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+        impl<'a, S: Data<Elem = $rust_ty>> TryFrom<&'a ArrayBase<S, Ix1>> for ::$rust_name {
+            type Error = Error;
+
+            fn try_from(a: &'a ArrayBase<S, Ix1>) -> Result<::$rust_name, Error> {
+                let mut v = ::$rust_name::new(a.len())
+                    .ok_or_else(|| Error::new(Value::NoMemory, $alloc))?;
+                v.as_array_view_mut().assign(a);
+                Ok(v)
+            }
+        }
+    };
+}
+
+macro_rules! ndarray_matrix {
+    ($rust_name:ident, $rust_ty:ident, $alloc:expr) => {
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+        impl ::$rust_name {
+            /// Returns an `ndarray` view of the matrix, sharing its memory.
+            pub fn as_array_view(&self) -> ArrayView2<'_, $rust_ty> {
+                unsafe {
+                    let m = &*self.unwrap_shared();
+                    ArrayView2::from_shape_ptr((m.size1, m.size2).strides((m.tda, 1)), m.data)
+                }
+            }
+
+            /// Returns a mutable `ndarray` view of the matrix, sharing its memory.
+            pub fn as_array_view_mut(&mut self) -> ArrayViewMut2<'_, $rust_ty> {
+                unsafe {
+                    let m = &*self.unwrap_unique();
+                    ArrayViewMut2::from_shape_ptr((m.size1, m.size2).strides((m.tda, 1)), m.data)
+                }
+            }
+        }
+
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+        impl<'a> From<&'a ::$rust_name> for Array2<$rust_ty> {
+            fn from(m: &'a ::$rust_name) -> Array2<$rust_ty> {
+                m.as_array_view().to_owned()
+            }
+        }
+
+        /// Fails if the matrix can't be allocated (GSL doesn't allow empty matrices).
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+        impl<'a, S: Data<Elem = $rust_ty>> TryFrom<&'a ArrayBase<S, Ix2>> for ::$rust_name {
+            type Error = Error;
+
+            fn try_from(a: &'a ArrayBase<S, Ix2>) -> Result<::$rust_name, Error> {
+                let (n1, n2) = a.dim();
+                let mut m =
+                    ::$rust_name::new(n1, n2).ok_or_else(|| Error::new(Value::NoMemory, $alloc))?;
+                m.as_array_view_mut().assign(a);
+                Ok(m)
+            }
+        }
+    };
+}
+
+ndarray_vector!(VectorF32, f32, "gsl_vector_float_calloc");
+ndarray_vector!(VectorF64, f64, "gsl_vector_calloc");
+ndarray_vector!(VectorI32, i32, "gsl_vector_int_calloc");
+ndarray_vector!(VectorU32, u32, "gsl_vector_uint_calloc");
+ndarray_matrix!(MatrixF32, f32, "gsl_matrix_float_calloc");
+ndarray_matrix!(MatrixF64, f64, "gsl_matrix_calloc");
+ndarray_matrix!(MatrixI32, i32, "gsl_matrix_int_calloc");
+ndarray_matrix!(MatrixU32, u32, "gsl_matrix_uint_calloc");
+
+#[test]
+fn ndarray_round_trip() {
+    use ndarray::array;
+    use MatrixF64;
+
+    let a = array![[1., 2., 3.], [4., 5., 6.]];
+    let mut m = MatrixF64::try_from(&a).unwrap();
+    assert_eq!(m.get(1, 0), 4.);
+    assert_eq!(m.as_array_view(), a);
+
+    m.as_array_view_mut()[[0, 2]] = -1.;
+    assert_eq!(m.get(0, 2), -1.);
+    assert_eq!(Array2::from(&m).row(0), array![1., 2., -1.]);
+
+    let v = ::VectorF64::try_from(&a.column(1)).unwrap();
+    assert_eq!(Array1::from(&v), array![2., 5.]);
+}
@@ -0,0 +1,41 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+// Conversions between the complex numbers and the `num-complex` ones. Both are a pair of
+// (real, imaginary) floats so the conversions are free.
+
+use num_complex::{Complex32, Complex64};
+use types::{ComplexF32, ComplexF64};
+
+macro_rules! num_complex {
+    ($rust_name:ident, $num_name:ident) => {
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "num-complex")))]
+        impl From<$num_name> for $rust_name {
+            fn from(z: $num_name) -> $rust_name {
+                $rust_name { dat: [z.re, z.im] }
+            }
+        }
+
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "num-complex")))]
+        impl From<$rust_name> for $num_name {
+            fn from(z: $rust_name) -> $num_name {
+                $num_name::new(z.dat[0], z.dat[1])
+            }
+        }
+    };
+}
+
+num_complex!(ComplexF64, Complex64);
+num_complex!(ComplexF32, Complex32);
+
+#[test]
+fn num_complex_round_trip() {
+    let z = Complex64::new(1.5, -2.);
+    let c = ComplexF64::from(z);
+    assert_eq!(c.dat, [1.5, -2.]);
+    assert_eq!(Complex64::from(c), z);
+
+    let z = Complex32::new(0.25, 4.);
+    assert_eq!(Complex32::from(ComplexF32::from(z)), z);
+}
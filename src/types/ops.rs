@@ -0,0 +1,570 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+// Operator overloading for the complex numbers, vectors and matrices.
+//
+// The arithmetic operators work on references (`&a + &b`) and allocate their result, the
+// assignment operators (`a += &b`) work in place. The operations on vectors are element-wise,
+// `*` between matrices is the matrix product. Taking references also avoids shadowing the
+// inherent `add`, `sub`... methods of the complex numbers, which take `&self`. Like the slice ones, the operators panic if the
+// dimensions don't match or if an index is out of bounds.
+
+use crate::paste::paste;
+use ffi::FFI;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+use types::complex::CFFI;
+
+macro_rules! vector_index {
+    ($rust_name:ident, $rust_ty:ident) => {
+        impl Index<usize> for ::$rust_name {
+            type Output = $rust_ty;
+
+            fn index(&self, i: usize) -> &$rust_ty {
+                let v = unsafe { &*self.unwrap_shared() };
+                assert!(i < v.size, "index {} out of bounds (size {})", i, v.size);
+                unsafe { &*v.data.add(i * v.stride) }
+            }
+        }
+
+        impl IndexMut<usize> for ::$rust_name {
+            fn index_mut(&mut self, i: usize) -> &mut $rust_ty {
+                let v = unsafe { &*self.unwrap_unique() };
+                assert!(i < v.size, "index {} out of bounds (size {})", i, v.size);
+                unsafe { &mut *v.data.add(i * v.stride) }
+            }
+        }
+    };
+}
+
+macro_rules! matrix_index {
+    ($rust_name:ident, $rust_ty:ident) => {
+        impl Index<(usize, usize)> for ::$rust_name {
+            type Output = $rust_ty;
+
+            fn index(&self, (i, j): (usize, usize)) -> &$rust_ty {
+                let m = unsafe { &*self.unwrap_shared() };
+                assert!(
+                    i < m.size1 && j < m.size2,
+                    "index ({}, {}) out of bounds (size {}x{})",
+                    i,
+                    j,
+                    m.size1,
+                    m.size2
+                );
+                unsafe { &*m.data.add(i * m.tda + j) }
+            }
+        }
+
+        impl IndexMut<(usize, usize)> for ::$rust_name {
+            fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut $rust_ty {
+                let m = unsafe { &*self.unwrap_unique() };
+                assert!(
+                    i < m.size1 && j < m.size2,
+                    "index ({}, {}) out of bounds (size {}x{})",
+                    i,
+                    j,
+                    m.size1,
+                    m.size2
+                );
+                unsafe { &mut *m.data.add(i * m.tda + j) }
+            }
+        }
+    };
+}
+
+vector_index!(VectorF32, f32);
+vector_index!(VectorF64, f64);
+vector_index!(VectorI32, i32);
+vector_index!(VectorU32, u32);
+matrix_index!(MatrixF32, f32);
+matrix_index!(MatrixF64, f64);
+matrix_index!(MatrixI32, i32);
+matrix_index!(MatrixU32, u32);
+
+// GSL stores the complex elements as pairs of floats, which is the layout of `ComplexF32` and
+// `ComplexF64`.
+macro_rules! complex_vector_index {
+    ($rust_name:ident, $complex:ident) => {
+        impl Index<usize> for ::$rust_name {
+            type Output = ::$complex;
+
+            fn index(&self, i: usize) -> &::$complex {
+                let v = unsafe { &*self.unwrap_shared() };
+                assert!(i < v.size, "index {} out of bounds (size {})", i, v.size);
+                unsafe { &*(v.data.add(2 * i * v.stride) as *const ::$complex) }
+            }
+        }
+
+        impl IndexMut<usize> for ::$rust_name {
+            fn index_mut(&mut self, i: usize) -> &mut ::$complex {
+                let v = unsafe { &*self.unwrap_unique() };
+                assert!(i < v.size, "index {} out of bounds (size {})", i, v.size);
+                unsafe { &mut *(v.data.add(2 * i * v.stride) as *mut ::$complex) }
+            }
+        }
+    };
+}
+
+macro_rules! complex_matrix_index {
+    ($rust_name:ident, $complex:ident) => {
+        impl Index<(usize, usize)> for ::$rust_name {
+            type Output = ::$complex;
+
+            fn index(&self, (i, j): (usize, usize)) -> &::$complex {
+                let m = unsafe { &*self.unwrap_shared() };
+                assert!(
+                    i < m.size1 && j < m.size2,
+                    "index ({}, {}) out of bounds (size {}x{})",
+                    i,
+                    j,
+                    m.size1,
+                    m.size2
+                );
+                unsafe { &*(m.data.add(2 * (i * m.tda + j)) as *const ::$complex) }
+            }
+        }
+
+        impl IndexMut<(usize, usize)> for ::$rust_name {
+            fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut ::$complex {
+                let m = unsafe { &*self.unwrap_unique() };
+                assert!(
+                    i < m.size1 && j < m.size2,
+                    "index ({}, {}) out of bounds (size {}x{})",
+                    i,
+                    j,
+                    m.size1,
+                    m.size2
+                );
+                unsafe { &mut *(m.data.add(2 * (i * m.tda + j)) as *mut ::$complex) }
+            }
+        }
+    };
+}
+
+complex_vector_index!(VectorComplexF32, ComplexF32);
+complex_vector_index!(VectorComplexF64, ComplexF64);
+complex_matrix_index!(MatrixComplexF32, ComplexF32);
+complex_matrix_index!(MatrixComplexF64, ComplexF64);
+
+macro_rules! vector_op {
+    ($rust_name:ident, $trait:ident, $method:ident, $assign_trait:ident, $assign:ident, $sys:ident) => {
+        impl<'a> $assign_trait<&'a ::$rust_name> for ::$rust_name {
+            fn $assign(&mut self, other: &'a ::$rust_name) {
+                assert_eq!(self.len(), other.len(), "vectors of different lengths");
+                unsafe { sys::$sys(self.unwrap_unique(), other.unwrap_shared()) };
+            }
+        }
+
+        impl<'a, 'b> $trait<&'b ::$rust_name> for &'a ::$rust_name {
+            type Output = ::$rust_name;
+
+            fn $method(self, other: &'b ::$rust_name) -> ::$rust_name {
+                let mut out = ::$rust_name::clone(self);
+                $assign_trait::$assign(&mut out, other);
+                out
+            }
+        }
+    };
+}
+
+macro_rules! vector_arith {
+    ($rust_name:ident, $rust_ty:ident, $name:ident) => {
+        paste! {
+            vector_op!($rust_name, Add, add, AddAssign, add_assign, [<$name _add>]);
+            vector_op!($rust_name, Sub, sub, SubAssign, sub_assign, [<$name _sub>]);
+            vector_op!($rust_name, Mul, mul, MulAssign, mul_assign, [<$name _mul>]);
+            vector_op!($rust_name, Div, div, DivAssign, div_assign, [<$name _div>]);
+
+            impl MulAssign<$rust_ty> for ::$rust_name {
+                fn mul_assign(&mut self, x: $rust_ty) {
+                    unsafe { sys::[<$name _scale>](self.unwrap_unique(), x) };
+                }
+            }
+
+            impl<'a> Mul<$rust_ty> for &'a ::$rust_name {
+                type Output = ::$rust_name;
+
+                fn mul(self, x: $rust_ty) -> ::$rust_name {
+                    let mut out = ::$rust_name::clone(self);
+                    out *= x;
+                    out
+                }
+            }
+
+            impl<'a> Neg for &'a ::$rust_name {
+                type Output = ::$rust_name;
+
+                fn neg(self) -> ::$rust_name {
+                    self * -1.
+                }
+            }
+        }
+    };
+}
+
+macro_rules! matrix_op {
+    ($rust_name:ident, $trait:ident, $method:ident, $assign_trait:ident, $assign:ident, $sys:ident) => {
+        impl<'a> $assign_trait<&'a ::$rust_name> for ::$rust_name {
+            fn $assign(&mut self, other: &'a ::$rust_name) {
+                assert_eq!(
+                    (self.size1(), self.size2()),
+                    (other.size1(), other.size2()),
+                    "matrices of different sizes"
+                );
+                unsafe { sys::$sys(self.unwrap_unique(), other.unwrap_shared()) };
+            }
+        }
+
+        impl<'a, 'b> $trait<&'b ::$rust_name> for &'a ::$rust_name {
+            type Output = ::$rust_name;
+
+            fn $method(self, other: &'b ::$rust_name) -> ::$rust_name {
+                let mut out = ::$rust_name::clone(self);
+                $assign_trait::$assign(&mut out, other);
+                out
+            }
+        }
+    };
+}
+
+macro_rules! matrix_arith {
+    ($rust_name:ident, $vector:ident, $rust_ty:ident, $name:ident, $gemm:ident, $gemv:ident) => {
+        paste! {
+            matrix_op!($rust_name, Add, add, AddAssign, add_assign, [<$name _add>]);
+            matrix_op!($rust_name, Sub, sub, SubAssign, sub_assign, [<$name _sub>]);
+
+            impl MulAssign<$rust_ty> for ::$rust_name {
+                fn mul_assign(&mut self, x: $rust_ty) {
+                    // `gsl_matrix_float_scale` takes a `f64` as well.
+                    unsafe { sys::[<$name _scale>](self.unwrap_unique(), x as f64) };
+                }
+            }
+
+            impl<'a> Mul<$rust_ty> for &'a ::$rust_name {
+                type Output = ::$rust_name;
+
+                fn mul(self, x: $rust_ty) -> ::$rust_name {
+                    let mut out = ::$rust_name::clone(self);
+                    out *= x;
+                    out
+                }
+            }
+
+            impl<'a> Neg for &'a ::$rust_name {
+                type Output = ::$rust_name;
+
+                fn neg(self) -> ::$rust_name {
+                    self * -1.
+                }
+            }
+
+            /// The matrix product, computed with `gemm`.
+            impl<'a, 'b> Mul<&'b ::$rust_name> for &'a ::$rust_name {
+                type Output = ::$rust_name;
+
+                fn mul(self, other: &'b ::$rust_name) -> ::$rust_name {
+                    assert_eq!(self.size2(), other.size1(), "incompatible matrix sizes");
+                    let mut out = ::$rust_name::new(self.size1(), other.size2())
+                        .expect("matrix allocation failed");
+                    unsafe {
+                        sys::$gemm(
+                            sys::CBLAS_TRANSPOSE_CblasNoTrans,
+                            sys::CBLAS_TRANSPOSE_CblasNoTrans,
+                            1.,
+                            self.unwrap_shared(),
+                            other.unwrap_shared(),
+                            0.,
+                            out.unwrap_unique(),
+                        )
+                    };
+                    out
+                }
+            }
+
+            /// The matrix-vector product, computed with `gemv`.
+            impl<'a, 'b> Mul<&'b ::$vector> for &'a ::$rust_name {
+                type Output = ::$vector;
+
+                fn mul(self, x: &'b ::$vector) -> ::$vector {
+                    assert_eq!(self.size2(), x.len(), "incompatible matrix and vector sizes");
+                    let mut out = ::$vector::new(self.size1()).expect("vector allocation failed");
+                    unsafe {
+                        sys::$gemv(
+                            sys::CBLAS_TRANSPOSE_CblasNoTrans,
+                            1.,
+                            self.unwrap_shared(),
+                            x.unwrap_shared(),
+                            0.,
+                            out.unwrap_unique(),
+                        )
+                    };
+                    out
+                }
+            }
+        }
+    };
+}
+
+macro_rules! complex_scale {
+    ($rust_name:ident, $complex:ident, $sys:ident) => {
+        impl<'a> MulAssign<&'a ::$complex> for ::$rust_name {
+            fn mul_assign(&mut self, x: &'a ::$complex) {
+                unsafe { sys::$sys(self.unwrap_unique(), x.unwrap()) };
+            }
+        }
+
+        impl<'a> Neg for &'a ::$rust_name {
+            type Output = ::$rust_name;
+
+            fn neg(self) -> ::$rust_name {
+                let mut out = ::$rust_name::clone(self);
+                out *= &::$complex { dat: [-1., 0.] };
+                out
+            }
+        }
+    };
+}
+
+// Only the assignment operators are implemented where the complex vectors and matrices have an
+// inherent method of the same name: `Add` on `&VectorComplexF64` would be picked before the
+// inherent `add(&mut self)` method by `v.add(&w)`.
+macro_rules! complex_assign_op {
+    ($rust_name:ident, $assign_trait:ident, $assign:ident, $sys:ident, $same_size:ident) => {
+        impl<'a> $assign_trait<&'a ::$rust_name> for ::$rust_name {
+            fn $assign(&mut self, other: &'a ::$rust_name) {
+                $same_size(self, other);
+                unsafe { sys::$sys(self.unwrap_unique(), other.unwrap_shared()) };
+            }
+        }
+    };
+}
+
+macro_rules! complex_vector_arith {
+    ($rust_name:ident, $complex:ident, $name:ident) => {
+        paste! {
+            fn [<$name _same_len>](a: &::$rust_name, b: &::$rust_name) {
+                assert_eq!(a.len(), b.len(), "vectors of different lengths");
+            }
+
+            complex_assign_op!($rust_name, AddAssign, add_assign, [<$name _add>], [<$name _same_len>]);
+            complex_assign_op!($rust_name, SubAssign, sub_assign, [<$name _sub>], [<$name _same_len>]);
+            complex_assign_op!($rust_name, MulAssign, mul_assign, [<$name _mul>], [<$name _same_len>]);
+            complex_assign_op!($rust_name, DivAssign, div_assign, [<$name _div>], [<$name _same_len>]);
+            complex_scale!($rust_name, $complex, [<$name _scale>]);
+        }
+    };
+}
+
+macro_rules! complex_matrix_arith {
+    ($rust_name:ident, $vector:ident, $complex:ident, $name:ident, $gemm:ident, $gemv:ident) => {
+        paste! {
+            fn [<$name _same_size>](a: &::$rust_name, b: &::$rust_name) {
+                assert_eq!(
+                    (a.size1(), a.size2()),
+                    (b.size1(), b.size2()),
+                    "matrices of different sizes"
+                );
+            }
+
+            complex_assign_op!($rust_name, AddAssign, add_assign, [<$name _add>], [<$name _same_size>]);
+            complex_assign_op!($rust_name, SubAssign, sub_assign, [<$name _sub>], [<$name _same_size>]);
+            complex_scale!($rust_name, $complex, [<$name _scale>]);
+
+            impl<'a, 'b> Mul<&'b ::$complex> for &'a ::$rust_name {
+                type Output = ::$rust_name;
+
+                fn mul(self, x: &'b ::$complex) -> ::$rust_name {
+                    let mut out = ::$rust_name::clone(self);
+                    out *= x;
+                    out
+                }
+            }
+
+            /// The matrix product, computed with `gemm`.
+            impl<'a, 'b> Mul<&'b ::$rust_name> for &'a ::$rust_name {
+                type Output = ::$rust_name;
+
+                fn mul(self, other: &'b ::$rust_name) -> ::$rust_name {
+                    assert_eq!(self.size2(), other.size1(), "incompatible matrix sizes");
+                    let mut out = ::$rust_name::new(self.size1(), other.size2())
+                        .expect("matrix allocation failed");
+                    unsafe {
+                        sys::$gemm(
+                            sys::CBLAS_TRANSPOSE_CblasNoTrans,
+                            sys::CBLAS_TRANSPOSE_CblasNoTrans,
+                            ::$complex { dat: [1., 0.] }.unwrap(),
+                            self.unwrap_shared(),
+                            other.unwrap_shared(),
+                            ::$complex::default().unwrap(),
+                            out.unwrap_unique(),
+                        )
+                    };
+                    out
+                }
+            }
+
+            /// The matrix-vector product, computed with `gemv`.
+            impl<'a, 'b> Mul<&'b ::$vector> for &'a ::$rust_name {
+                type Output = ::$vector;
+
+                fn mul(self, x: &'b ::$vector) -> ::$vector {
+                    assert_eq!(self.size2(), x.len(), "incompatible matrix and vector sizes");
+                    let mut out = ::$vector::new(self.size1()).expect("vector allocation failed");
+                    unsafe {
+                        sys::$gemv(
+                            sys::CBLAS_TRANSPOSE_CblasNoTrans,
+                            ::$complex { dat: [1., 0.] }.unwrap(),
+                            self.unwrap_shared(),
+                            x.unwrap_shared(),
+                            ::$complex::default().unwrap(),
+                            out.unwrap_unique(),
+                        )
+                    };
+                    out
+                }
+            }
+        }
+    };
+}
+
+vector_arith!(VectorF32, f32, gsl_vector_float);
+vector_arith!(VectorF64, f64, gsl_vector);
+matrix_arith!(
+    MatrixF32,
+    VectorF32,
+    f32,
+    gsl_matrix_float,
+    gsl_blas_sgemm,
+    gsl_blas_sgemv
+);
+matrix_arith!(
+    MatrixF64,
+    VectorF64,
+    f64,
+    gsl_matrix,
+    gsl_blas_dgemm,
+    gsl_blas_dgemv
+);
+complex_vector_arith!(VectorComplexF32, ComplexF32, gsl_vector_complex_float);
+complex_vector_arith!(VectorComplexF64, ComplexF64, gsl_vector_complex);
+complex_matrix_arith!(
+    MatrixComplexF32,
+    VectorComplexF32,
+    ComplexF32,
+    gsl_matrix_complex_float,
+    gsl_blas_cgemm,
+    gsl_blas_cgemv
+);
+complex_matrix_arith!(
+    MatrixComplexF64,
+    VectorComplexF64,
+    ComplexF64,
+    gsl_matrix_complex,
+    gsl_blas_zgemm,
+    gsl_blas_zgemv
+);
+
+macro_rules! complex_op {
+    ($rust_name:ident, $trait:ident, $method:ident, $assign_trait:ident, $assign:ident) => {
+        impl<'a, 'b> $trait<&'b ::$rust_name> for &'a ::$rust_name {
+            type Output = ::$rust_name;
+
+            fn $method(self, other: &'b ::$rust_name) -> ::$rust_name {
+                ::$rust_name::$method(self, other)
+            }
+        }
+
+        impl<'a> $assign_trait<&'a ::$rust_name> for ::$rust_name {
+            fn $assign(&mut self, other: &'a ::$rust_name) {
+                *self = ::$rust_name::$method(self, other);
+            }
+        }
+    };
+}
+
+macro_rules! complex_arith {
+    ($rust_name:ident) => {
+        complex_op!($rust_name, Add, add, AddAssign, add_assign);
+        complex_op!($rust_name, Sub, sub, SubAssign, sub_assign);
+        complex_op!($rust_name, Mul, mul, MulAssign, mul_assign);
+        complex_op!($rust_name, Div, div, DivAssign, div_assign);
+
+        impl<'a> Neg for &'a ::$rust_name {
+            type Output = ::$rust_name;
+
+            fn neg(self) -> ::$rust_name {
+                self.negative()
+            }
+        }
+    };
+}
+
+complex_arith!(ComplexF32);
+complex_arith!(ComplexF64);
+
+#[test]
+fn operators() {
+    use {MatrixF64, VectorF64};
+
+    let a = VectorF64::from_slice(&[1., 2., 3.]).unwrap();
+    let mut b = VectorF64::from_slice(&[4., 5., 6.]).unwrap();
+    assert_eq!((&a + &b).as_slice(), Some(&[5., 7., 9.][..]));
+    assert_eq!((&a * &b).as_slice(), Some(&[4., 10., 18.][..]));
+    assert_eq!((-&a).as_slice(), Some(&[-1., -2., -3.][..]));
+    b -= &a;
+    b[0] = 0.;
+    assert_eq!(b.as_slice(), Some(&[0., 3., 3.][..]));
+
+    let mut m = MatrixF64::new(2, 3).unwrap();
+    for i in 0..2 {
+        for j in 0..3 {
+            m[(i, j)] = (i * 3 + j) as f64;
+        }
+    }
+    assert_eq!((&m * &a).as_slice(), Some(&[8., 26.][..]));
+    let mut id = MatrixF64::new(3, 3).unwrap();
+    id.set_identity();
+    let p = &m * &id;
+    assert!(p.equal(&m));
+    assert!((&p - &m).is_null());
+    assert_eq!((&m * 2.)[(1, 2)], 10.);
+}
+
+#[test]
+fn complex_operators() {
+    use ComplexF64;
+
+    let a = ComplexF64 { dat: [1., 2.] };
+    let mut b = ComplexF64 { dat: [3., -1.] };
+    assert_eq!((&a + &b).dat, [4., 1.]);
+    assert_eq!((&a * &b).dat, [5., 5.]);
+    assert_eq!((-&a).dat, [-1., -2.]);
+    assert_eq!(a.add(&b), &a + &b);
+    b /= &a;
+    assert!((b.dat[0] - 0.2).abs() < 1e-15 && (b.dat[1] + 1.4).abs() < 1e-15);
+}
+
+#[test]
+fn complex_vector_matrix_operators() {
+    use {ComplexF64, MatrixComplexF64, VectorComplexF64};
+
+    let mut v = VectorComplexF64::from_interleaved(&[1., 1., 2., 0.]).unwrap();
+    let w = VectorComplexF64::from_interleaved(&[0., 1., 1., 1.]).unwrap();
+    v += &w;
+    assert_eq!(v[1], ComplexF64 { dat: [3., 1.] });
+    v[0] = ComplexF64 { dat: [0., 1.] };
+    assert_eq!((-&v).as_slice(), Some(&[0., -1., -3., -1.][..]));
+
+    let mut m = MatrixComplexF64::new(2, 2).unwrap();
+    m.set_identity();
+    m[(0, 1)] = ComplexF64 { dat: [0., 2.] };
+    let i = ComplexF64 { dat: [0., 1.] };
+    assert_eq!(
+        (&m * &w).as_complex_slice(),
+        Some(&[m[(0, 1)].mul(&w[1]).add(&i), w[1]][..])
+    );
+    assert_eq!((&m * &i)[(0, 1)], ComplexF64 { dat: [-2., 0.] });
+}
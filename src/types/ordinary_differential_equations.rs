@@ -59,7 +59,7 @@ A. C. Hindmarsh, P. N. Brown, K. E. Grant, S. L. Lee, R. Serban, D. E. Shumaker
 Differential/Algebraic Equation Solvers.”, ACM Trans. Math. Software 31, 363–396, 2005.
 !*/
 
-use crate::Value;
+use crate::{Error, Value};
 use ffi::FFI;
 use std::os::raw::{c_int, c_void};
 
@@ -71,9 +71,12 @@ use std::os::raw::{c_int, c_void};
 ///
 /// Some methods require the jacobian function, which calculates the matrix dfdy and the vector dfdt. The matrix dfdy conforms
 /// to the GSL standard, being a continuous range of floating point values, in row-order.
+///
+/// The implicit steppers (`rk1imp`, `rk2imp`, `rk4imp`, `bsimp` and `msbdf`) fail with
+/// [`Value::BadFunction`] if the system has no jacobian.
 pub struct ODEiv2System<'a> {
-    function: &'a mut dyn FnMut(f64, &[f64], &mut [f64]) -> Value,
-    jacobian: Option<&'a mut dyn FnMut(f64, &[f64], &mut [f64], &mut [f64]) -> Value>,
+    function: Box<dyn FnMut(f64, &[f64], &mut [f64]) -> Value + 'a>,
+    jacobian: Option<Box<dyn FnMut(f64, &[f64], &mut [f64], &mut [f64]) -> Value + 'a>>,
     dimension: usize,
 }
 
@@ -84,7 +87,7 @@ impl<'a> ODEiv2System<'a> {
         function: &'a mut dyn FnMut(f64, &[f64], &mut [f64]) -> Value,
     ) -> ODEiv2System<'a> {
         ODEiv2System {
-            function,
+            function: Box::new(function),
             jacobian: None,
             dimension,
         }
@@ -97,22 +100,65 @@ impl<'a> ODEiv2System<'a> {
         jacobian: &'a mut dyn FnMut(f64, &[f64], &mut [f64], &mut [f64]) -> Value,
     ) -> ODEiv2System<'a> {
         ODEiv2System {
-            function,
-            jacobian: Some(jacobian),
+            function: Box::new(function),
+            jacobian: Some(Box::new(jacobian)),
+            dimension,
+        }
+    }
+
+    /// Same as [`new`](Self::new), but the system owns the right-hand side closure
+    /// `function(t, y, dydt)`, so it can be given to [`ODEiv2Driver::new`].
+    pub fn from_closure<F: FnMut(f64, &[f64], &mut [f64]) -> Value + 'a>(
+        dimension: usize,
+        function: F,
+    ) -> ODEiv2System<'a> {
+        ODEiv2System {
+            function: Box::new(function),
+            jacobian: None,
             dimension,
         }
     }
 
+    /// Sets the jacobian closure `jacobian(t, y, dfdy, dfdt)`, required by the implicit stepping
+    /// functions. It must store the derivative of `f_i` with respect to `y_j` in
+    /// `dfdy[i * dimension + j]`, and the derivative of `f_i` with respect to `t` in `dfdt[i]`.
+    ///
+    /// ```
+    /// use rgsl::{ODEiv2Driver, ODEiv2StepType, ODEiv2System, Value};
+    ///
+    /// // A stiff equation: y' = -1000 (y - cos(t)).
+    /// let system = ODEiv2System::from_closure(1, |t, y, dydt| {
+    ///     dydt[0] = -1000. * (y[0] - t.cos());
+    ///     Value::Success
+    /// })
+    /// .jacobian(|t, _y, dfdy, dfdt| {
+    ///     dfdy[0] = -1000.;
+    ///     dfdt[0] = -1000. * t.sin();
+    ///     Value::Success
+    /// });
+    /// let mut driver = ODEiv2Driver::new(system, &ODEiv2StepType::msbdf(), 1e-6, 1e-8, 0.).unwrap();
+    ///
+    /// let mut t = 0.;
+    /// let mut y = [0.];
+    /// driver.apply(&mut t, 1., &mut y).unwrap();
+    /// assert!((y[0] - 1f64.cos()).abs() < 1e-2);
+    /// ```
+    pub fn jacobian<J: FnMut(f64, &[f64], &mut [f64], &mut [f64]) -> Value + 'a>(
+        mut self,
+        jacobian: J,
+    ) -> ODEiv2System<'a> {
+        self.jacobian = Some(Box::new(jacobian));
+        self
+    }
+
     /// Returns `sys::gsl_odeiv2_system` structure.
     #[allow(clippy::wrong_self_convention)]
     fn to_raw(&mut self) -> sys::gsl_odeiv2_system {
         sys::gsl_odeiv2_system {
             function: Some(function_handler),
-            jacobian: if self.jacobian.is_some() {
-                Some(jacobian_handler)
-            } else {
-                None
-            },
+            // Always given: GSL calls the jacobian of the implicit steppers without checking it,
+            // and the handler reports a missing one as `GSL_EBADFUNC`.
+            jacobian: Some(jacobian_handler),
             dimension: self.dimension,
             params: self as *mut _ as *mut c_void,
         }
@@ -131,7 +177,7 @@ extern "C" fn function_handler(
     let t_y = unsafe { ::std::slice::from_raw_parts(t_y, n) };
     let t_f = unsafe { ::std::slice::from_raw_parts_mut(t_f, n) };
 
-    (sys.function)(t, t_y, t_f).into()
+    ::error::catch_callback(Value::BadFunction, || (sys.function)(t, t_y, t_f)).into()
 }
 
 /// Default handler for calling the jacobian closure.
@@ -149,7 +195,9 @@ extern "C" fn jacobian_handler(
     let t_dfdt = unsafe { ::std::slice::from_raw_parts_mut(t_dfdt, n) };
 
     match sys.jacobian {
-        Some(ref mut j) => j(t, t_y, t_dfdy, t_dfdt),
+        Some(ref mut j) => {
+            ::error::catch_callback(Value::BadFunction, || j(t, t_y, t_dfdy, t_dfdt))
+        }
         None => Value::BadFunction,
     }
     .into()
@@ -157,6 +205,8 @@ extern "C" fn jacobian_handler(
 
 ffi_wrapper!(ODEiv2Step, *mut sys::gsl_odeiv2_step, gsl_odeiv2_step_free);
 
+unsafe impl Send for ODEiv2Step {}
+
 impl ODEiv2Step {
     /// This function returns a pointer to a newly allocated instance of a stepping function of type T for a system of dim dimensions.
     /// Please note that if you use a stepper method that requires access to a driver object, it is advisable to use a driver allocation
@@ -175,8 +225,9 @@ impl ODEiv2Step {
     /// This function resets the stepping function s. It should be used whenever the next use of s will not be a continuation of a previous
     /// step.
     #[doc(alias = "gsl_odeiv2_step_reset")]
-    pub fn reset(&mut self) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_step_reset(self.unwrap_unique()) })
+    pub fn reset(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_odeiv2_step_reset(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_odeiv2_step_reset")
     }
 
     /// This function returns a pointer to the name of the stepping function. For example,
@@ -211,8 +262,12 @@ impl ODEiv2Step {
     /// the driver object. This is a requirement for some steppers, to get the desired error level for internal iteration of stepper.
     /// Allocation of a driver object calls this function automatically.
     #[doc(alias = "gsl_odeiv2_step_set_driver")]
-    pub fn set_driver(&mut self, d: &ODEiv2Driver) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_step_set_driver(self.unwrap_unique(), d.d) })
+    pub fn set_driver(&mut self, d: &ODEiv2Driver) -> Result<(), Error> {
+        result_handler!(
+            unsafe { sys::gsl_odeiv2_step_set_driver(self.unwrap_unique(), d.d) },
+            (),
+            "gsl_odeiv2_step_set_driver"
+        )
     }
 
     /// This function applies the stepping function s to the system of equations defined by sys, using the step-size h to advance the system
@@ -222,14 +277,16 @@ impl ODEiv2Step {
     /// the reuse of existing derivative information. On output the new derivatives of the system at time t+h will be stored in dydt_out
     /// if it is not null.
     ///
-    /// The stepping function returns Value::Failure if it is unable to compute the requested step. Also, if the user-supplied functions defined
+    /// The stepping function returns a Value::Failure error if it is unable to compute the requested step. Also, if the user-supplied functions defined
     /// in the system sys return a status other than ::Value::Success the step will be aborted. In that case, the elements of y will be restored
-    /// to their pre-step values and the error code from the user-supplied function will be returned. Failure may be due to a singularity in
+    /// to their pre-step values and an error with the code from the user-supplied function will be returned. Failure may be due to a singularity in
     /// the system or too large step-size h. In that case the step should be attempted again with a smaller step-size, e.g. h/2.
     ///
     /// If the driver object is not appropriately set via gsl_odeiv2_step_set_driver for those steppers that need it, the stepping function
-    /// returns ::Fault. If the user-supplied functions defined in the system sys returns Value::BadFunc, the function returns
-    /// immediately with the same return code. In this case the user must call gsl_odeiv2_step_reset before calling this function again.
+    /// returns a Value::Fault error. If the user-supplied functions defined in the system sys returns Value::BadFunction, the function returns
+    /// immediately with the same error code. In this case the user must call gsl_odeiv2_step_reset before calling this function again.
+    ///
+    /// All the slices must have the dimension of the system.
     #[doc(alias = "gsl_odeiv2_step_apply")]
     pub fn apply(
         &mut self,
@@ -237,10 +294,33 @@ impl ODEiv2Step {
         h: f64,
         y: &mut [f64],
         yerr: &mut [f64],
-        dydt_in: &[f64],
-        dydt_out: &mut [f64],
+        dydt_in: Option<&[f64]>,
+        dydt_out: Option<&mut [f64]>,
         sys: &mut ODEiv2System,
-    ) -> Value {
+    ) -> Result<(), Error> {
+        let dim = sys.dimension;
+        assert_eq!(y.len(), dim, "y doesn't have the dimension of the system");
+        assert_eq!(
+            yerr.len(),
+            dim,
+            "yerr doesn't have the dimension of the system"
+        );
+        let dydt_in = dydt_in.map_or(::std::ptr::null(), |d| {
+            assert_eq!(
+                d.len(),
+                dim,
+                "dydt_in doesn't have the dimension of the system"
+            );
+            d.as_ptr()
+        });
+        let dydt_out = dydt_out.map_or(::std::ptr::null_mut(), |d| {
+            assert_eq!(
+                d.len(),
+                dim,
+                "dydt_out doesn't have the dimension of the system"
+            );
+            d.as_mut_ptr()
+        });
         let sys_raw = sys.to_raw();
 
         let ret = unsafe {
@@ -250,64 +330,81 @@ impl ODEiv2Step {
                 h,
                 y.as_mut_ptr(),
                 yerr.as_mut_ptr(),
-                dydt_in.as_ptr(),
-                dydt_out.as_mut_ptr(),
+                dydt_in,
+                dydt_out,
                 &sys_raw as *const sys::gsl_odeiv2_system,
             )
         };
-        Value::from(ret)
+        result_handler!(ret, (), "gsl_odeiv2_step_apply")
     }
 }
 
 ffi_wrapper!(ODEiv2StepType, *const sys::gsl_odeiv2_step_type);
 
+unsafe impl Send for ODEiv2StepType {}
+unsafe impl Sync for ODEiv2StepType {}
+
 impl ODEiv2StepType {
     /// Explicit embedded Runge-Kutta (2, 3) method.
+    #[doc(alias = "gsl_odeiv2_step_rk2")]
     pub fn rk2() -> ODEiv2StepType {
         ffi_wrap!(gsl_odeiv2_step_rk2)
     }
 
     /// Explicit 4th order (classical) Runge-Kutta. Error estimation is carried out by the step doubling method. For more efficient
     /// estimate of the error, use the embedded methods described below.
+    #[doc(alias = "gsl_odeiv2_step_rk4")]
     pub fn rk4() -> ODEiv2StepType {
         ffi_wrap!(gsl_odeiv2_step_rk4)
     }
 
     /// Explicit embedded Runge-Kutta-Fehlberg (4, 5) method. This method is a good general-purpose integrator.
-    pub fn rk45() -> ODEiv2StepType {
+    #[doc(alias = "gsl_odeiv2_step_rkf45")]
+    pub fn rkf45() -> ODEiv2StepType {
         ffi_wrap!(gsl_odeiv2_step_rkf45)
     }
 
+    /// Same as [`rkf45`](Self::rkf45).
+    pub fn rk45() -> ODEiv2StepType {
+        Self::rkf45()
+    }
+
     /// Explicit embedded Runge-Kutta Cash-Karp (4, 5) method.
+    #[doc(alias = "gsl_odeiv2_step_rkck")]
     pub fn rkck() -> ODEiv2StepType {
         ffi_wrap!(gsl_odeiv2_step_rkck)
     }
 
     /// Explicit embedded Runge-Kutta Prince-Dormand (8, 9) method.
+    #[doc(alias = "gsl_odeiv2_step_rk8pd")]
     pub fn rk8pd() -> ODEiv2StepType {
         ffi_wrap!(gsl_odeiv2_step_rk8pd)
     }
 
     /// Implicit Gaussian first order Runge-Kutta. Also known as implicit Euler or backward Euler method. Error estimation is carried out by
     /// the step doubling method. This algorithm requires the Jacobian and access to the driver object via gsl_odeiv2_step_set_driver.
+    #[doc(alias = "gsl_odeiv2_step_rk1imp")]
     pub fn rk1imp() -> ODEiv2StepType {
         ffi_wrap!(gsl_odeiv2_step_rk1imp)
     }
 
     /// Implicit Gaussian second order Runge-Kutta. Also known as implicit mid-point rule. Error estimation is carried out by the step doubling
     /// method. This stepper requires the Jacobian and access to the driver object via gsl_odeiv2_step_set_driver.
+    #[doc(alias = "gsl_odeiv2_step_rk2imp")]
     pub fn rk2imp() -> ODEiv2StepType {
         ffi_wrap!(gsl_odeiv2_step_rk2imp)
     }
 
     /// Implicit Gaussian 4th order Runge-Kutta. Error estimation is carried out by the step doubling method. This algorithm requires the
     /// Jacobian and access to the driver object via gsl_odeiv2_step_set_driver.
+    #[doc(alias = "gsl_odeiv2_step_rk4imp")]
     pub fn rk4imp() -> ODEiv2StepType {
         ffi_wrap!(gsl_odeiv2_step_rk4imp)
     }
 
     /// Implicit Bulirsch-Stoer method of Bader and Deuflhard. The method is generally suitable for stiff problems. This stepper requires
     /// the Jacobian.
+    #[doc(alias = "gsl_odeiv2_step_bsimp")]
     pub fn bsimp() -> ODEiv2StepType {
         ffi_wrap!(gsl_odeiv2_step_bsimp)
     }
@@ -315,6 +412,7 @@ impl ODEiv2StepType {
     /// A variable-coefficient linear multistep Adams method in Nordsieck form. This stepper uses explicit Adams-Bashforth (predictor) and
     /// implicit Adams-Moulton (corrector) methods in P(EC)^m functional iteration mode. Method order varies dynamically between 1 and 12.
     /// This stepper requires the access to the driver object via gsl_odeiv2_step_set_driver.
+    #[doc(alias = "gsl_odeiv2_step_msadams")]
     pub fn msadams() -> ODEiv2StepType {
         ffi_wrap!(gsl_odeiv2_step_msadams)
     }
@@ -323,6 +421,7 @@ impl ODEiv2StepType {
     /// BDF formula as predictor and implicit BDF formula as corrector. A modified Newton iteration method is used to solve the system of
     /// non-linear equations. Method order varies dynamically between 1 and 5. The method is generally suitable for stiff problems. This
     /// stepper requires the Jacobian and the access to the driver object via gsl_odeiv2_step_set_driver.
+    #[doc(alias = "gsl_odeiv2_step_msbdf")]
     pub fn msbdf() -> ODEiv2StepType {
         ffi_wrap!(gsl_odeiv2_step_msbdf)
     }
@@ -334,6 +433,8 @@ ffi_wrapper!(
     gsl_odeiv2_control_free
 );
 
+unsafe impl Send for ODEiv2Control {}
+
 impl ODEiv2Control {
     /// The standard control object is a four parameter heuristic based on absolute and relative errors eps_abs and eps_rel, and scaling
     /// factors a_y and a_dydt for the system state y(t) and derivatives y'(t) respectively.
@@ -446,10 +547,14 @@ impl ODEiv2Control {
     /// This function initializes the control function c with the parameters eps_abs (absolute error), eps_rel (relative error), a_y
     /// (scaling factor for y) and a_dydt (scaling factor for derivatives).
     #[doc(alias = "gsl_odeiv2_control_init")]
-    pub fn init(&mut self, eps_abs: f64, eps_rel: f64, a_y: f64, a_dydt: f64) -> Value {
-        Value::from(unsafe {
-            sys::gsl_odeiv2_control_init(self.unwrap_unique(), eps_abs, eps_rel, a_y, a_dydt)
-        })
+    pub fn init(&mut self, eps_abs: f64, eps_rel: f64, a_y: f64, a_dydt: f64) -> Result<(), Error> {
+        result_handler!(
+            unsafe {
+                sys::gsl_odeiv2_control_init(self.unwrap_unique(), eps_abs, eps_rel, a_y, a_dydt)
+            },
+            (),
+            "gsl_odeiv2_control_init"
+        )
     }
 
     /// This function adjusts the step-size h using the control function c, and the current values of y, yerr and dydt. The stepping function
@@ -503,25 +608,32 @@ impl ODEiv2Control {
     /// requires the value (y) and value of the derivative (dydt) of the component, and the current
     /// step size h.
     ///
-    /// Returns `(Value, errlev)`.
+    /// Returns `errlev`.
     #[doc(alias = "gsl_odeiv2_control_errlevel")]
-    pub fn errlevel(&mut self, y: f64, dydt: f64, h: f64, ind: usize) -> (Value, f64) {
+    pub fn errlevel(&mut self, y: f64, dydt: f64, h: f64, ind: usize) -> Result<f64, Error> {
         let mut errlev = 0.;
         let ret = unsafe {
             sys::gsl_odeiv2_control_errlevel(self.unwrap_unique(), y, dydt, h, ind, &mut errlev)
         };
-        (Value::from(ret), errlev)
+        result_handler!(ret, errlev, "gsl_odeiv2_control_errlevel")
     }
 
     /// This function sets a pointer of the driver object d for control object c.
     #[doc(alias = "gsl_odeiv2_control_set_driver")]
-    pub fn set_driver(&mut self, d: &ODEiv2Driver) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_control_set_driver(self.unwrap_unique(), d.d) })
+    pub fn set_driver(&mut self, d: &ODEiv2Driver) -> Result<(), Error> {
+        result_handler!(
+            unsafe { sys::gsl_odeiv2_control_set_driver(self.unwrap_unique(), d.d) },
+            (),
+            "gsl_odeiv2_control_set_driver"
+        )
     }
 }
 
 ffi_wrapper!(ODEiv2ControlType, *const sys::gsl_odeiv2_control_type);
 
+unsafe impl Send for ODEiv2ControlType {}
+unsafe impl Sync for ODEiv2ControlType {}
+
 // TODO!!!
 // impl ODEiv2ControlType {
 //     pub fn scaled() -> ODEiv2ControlType {
@@ -547,6 +659,8 @@ ffi_wrapper!(
     gsl_odeiv2_evolve_free
 );
 
+unsafe impl Send for ODEiv2Evolve {}
+
 impl ODEiv2Evolve {
     /// This function returns a pointer to a newly allocated instance of an evolution function for a system of dim dimensions.
     #[doc(alias = "gsl_odeiv2_evolve_alloc")]
@@ -568,12 +682,13 @@ impl ODEiv2Evolve {
     /// with a decreased step-size. This process is continued until an acceptable step-size is found. An estimate of the local error for
     /// the step can be obtained from the components of the array e->yerr[].
     ///
-    /// If the user-supplied functions defined in the system sys returns Value::BadFunc, the function returns immediately with the same
-    /// return code. In this case the user must call gsl_odeiv2_step_reset and gsl_odeiv2_evolve_reset before calling this function again.
+    /// If the user-supplied functions defined in the system sys returns Value::BadFunction, the function returns immediately with the same
+    /// error code. In this case the user must call gsl_odeiv2_step_reset and gsl_odeiv2_evolve_reset before calling this function again.
     ///
     /// Otherwise, if the user-supplied functions defined in the system sys or the stepping function step return a status other than
-    /// ::Value::Success, the step is retried with a decreased step-size. If the step-size decreases below machine precision, a status of
-    /// ::Failuer is returned if the user functions returned ::Value::Success. Otherwise the value returned by user function is returned.
+    /// ::Value::Success, the step is retried with a decreased step-size. If the step-size decreases below machine precision, a
+    /// Value::Failure error is returned if the user functions returned ::Value::Success. Otherwise an error with the value returned by
+    /// the user function is returned.
     /// If no acceptable step can be made, t and y will be restored to their pre-step values and h contains the final attempted step-size.
     ///
     /// If the step is successful the function returns a suggested step-size for the next step in h. The maximum time t1 is guaranteed not
@@ -588,7 +703,12 @@ impl ODEiv2Evolve {
         t1: f64,
         h: &mut f64,
         y: &mut [f64],
-    ) -> Value {
+    ) -> Result<(), Error> {
+        assert_eq!(
+            y.len(),
+            sys.dimension,
+            "y doesn't have the dimension of the system"
+        );
         let sys_raw = sys.to_raw();
         let psys = &sys_raw as *const _;
 
@@ -604,12 +724,12 @@ impl ODEiv2Evolve {
                 y.as_mut_ptr(),
             )
         };
-        Value::from(ret)
+        result_handler!(ret, (), "gsl_odeiv2_evolve_apply")
     }
 
     /// This function advances the ODE-system (e, sys, con) from time t and position y using the stepping function step by a specified step
     /// size h. If the local error estimated by the stepping function exceeds the desired error level, the step is not taken and the function
-    /// returns Value::Failure. Otherwise the value returned by user function is returned.
+    /// returns a Value::Failure error. Otherwise an error is returned if the user function didn't return Value::Success.
     #[doc(alias = "gsl_odeiv2_evolve_apply_fixed_step")]
     pub fn apply_fixed_step(
         &mut self,
@@ -619,7 +739,12 @@ impl ODEiv2Evolve {
         t: &mut f64,
         h: f64,
         y: &mut [f64],
-    ) -> Value {
+    ) -> Result<(), Error> {
+        assert_eq!(
+            y.len(),
+            sys.dimension,
+            "y doesn't have the dimension of the system"
+        );
         let sys_raw = sys.to_raw();
         let psys = &sys_raw as *const _;
 
@@ -634,14 +759,15 @@ impl ODEiv2Evolve {
                 y.as_mut_ptr(),
             )
         };
-        Value::from(ret)
+        result_handler!(ret, (), "gsl_odeiv2_evolve_apply_fixed_step")
     }
 
     /// This function resets the evolution function e. It should be used whenever the next use of e will not be a continuation of a previous
     /// step.
     #[doc(alias = "gsl_odeiv2_evolve_reset")]
-    pub fn reset(&mut self) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_evolve_reset(self.unwrap_unique()) })
+    pub fn reset(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_odeiv2_evolve_reset(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_odeiv2_evolve_reset")
     }
 
     /// This function sets a pointer of the driver object d for evolve object e.
@@ -650,22 +776,78 @@ impl ODEiv2Evolve {
     /// in sequence. For example, if a step-change in an external driving force occurs at times t_a, t_b and t_c then evolution should be carried
     /// out over the ranges (t_0,t_a), (t_a,t_b), (t_b,t_c), and (t_c,t_1) separately and not directly over the range (t_0,t_1).
     #[doc(alias = "gsl_odeiv2_evolve_set_driver")]
-    pub fn set_driver(&mut self, d: &ODEiv2Driver) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_evolve_set_driver(self.unwrap_unique(), d.d) })
+    pub fn set_driver(&mut self, d: &ODEiv2Driver) -> Result<(), Error> {
+        result_handler!(
+            unsafe { sys::gsl_odeiv2_evolve_set_driver(self.unwrap_unique(), d.d) },
+            (),
+            "gsl_odeiv2_evolve_set_driver"
+        )
     }
 }
 
 pub struct ODEiv2Driver<'a> {
     d: *mut sys::gsl_odeiv2_driver,
     /// `sys::gsl_odeiv2_system` provided when constructing `d`.
-    #[allow(dead_code)]
     raw_system: Box<sys::gsl_odeiv2_system>,
+    /// The system given to `new`, which `raw_system` points to.
+    #[allow(dead_code)]
+    system: Option<Box<ODEiv2System<'a>>>,
     /// `PhantomData` to bind lifetime of this struct to the lifetime
     /// of the provided ODEiv2System.
     phantom: ::std::marker::PhantomData<&'a ODEiv2System<'a>>,
 }
 
 impl<'a> ODEiv2Driver<'a> {
+    /// Creates a driver for `system` using the stepper type `t`, with the initial step size
+    /// `hstart` and a (`gsl_odeiv2_control_y_new`) control keeping the local error on each step
+    /// within `epsabs + epsrel * |y|`.
+    ///
+    /// Unlike [`alloc_y_new`](Self::alloc_y_new), the driver takes ownership of the system.
+    ///
+    /// ```
+    /// use rgsl::{ODEiv2Driver, ODEiv2StepType, ODEiv2System, Value};
+    ///
+    /// // y'' = -y
+    /// let system = ODEiv2System::from_closure(2, |_t, y, dydt| {
+    ///     dydt[0] = y[1];
+    ///     dydt[1] = -y[0];
+    ///     Value::Success
+    /// });
+    /// let mut driver = ODEiv2Driver::new(system, &ODEiv2StepType::rk8pd(), 1e-6, 1e-8, 0.).unwrap();
+    ///
+    /// let mut t = 0.;
+    /// let mut y = [0., 1.];
+    /// driver.apply(&mut t, ::std::f64::consts::PI / 2., &mut y).unwrap();
+    /// assert!((y[0] - 1.).abs() < 1e-6);
+    /// ```
+    #[doc(alias = "gsl_odeiv2_driver_alloc_y_new")]
+    pub fn new(
+        system: ODEiv2System<'a>,
+        t: &ODEiv2StepType,
+        hstart: f64,
+        epsabs: f64,
+        epsrel: f64,
+    ) -> Option<ODEiv2Driver<'a>> {
+        // The system is boxed so the pointer to it in `sys_raw` stays valid.
+        let mut system = Box::new(system);
+        let sys_raw = Box::new(system.to_raw());
+        let psys = &*sys_raw as *const _;
+        let tmp = unsafe {
+            sys::gsl_odeiv2_driver_alloc_y_new(psys, t.unwrap_shared(), hstart, epsabs, epsrel)
+        };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(ODEiv2Driver {
+                d: tmp,
+                raw_system: sys_raw,
+                system: Some(system),
+                phantom: ::std::marker::PhantomData,
+            })
+        }
+    }
+
     /// These functions return a pointer to a newly allocated instance of a driver object. The functions automatically allocate and initialise
     /// the evolve, control and stepper objects for ODE system sys using stepper type T. The initial step size is given in hstart. The rest
     /// of the arguments follow the syntax and semantics of the control functions with same name (gsl_odeiv2_control_*_new).
@@ -689,6 +871,7 @@ impl<'a> ODEiv2Driver<'a> {
             Some(ODEiv2Driver {
                 d: tmp,
                 raw_system: sys_raw,
+                system: None,
                 phantom: ::std::marker::PhantomData,
             })
         }
@@ -717,6 +900,7 @@ impl<'a> ODEiv2Driver<'a> {
             Some(ODEiv2Driver {
                 d: tmp,
                 raw_system: sys_raw,
+                system: None,
                 phantom: ::std::marker::PhantomData,
             })
         }
@@ -755,6 +939,7 @@ impl<'a> ODEiv2Driver<'a> {
             Some(ODEiv2Driver {
                 d: tmp,
                 raw_system: sys_raw,
+                system: None,
                 phantom: ::std::marker::PhantomData,
             })
         }
@@ -795,6 +980,7 @@ impl<'a> ODEiv2Driver<'a> {
             Some(ODEiv2Driver {
                 d: tmp,
                 raw_system: sys_raw,
+                system: None,
                 phantom: ::std::marker::PhantomData,
             })
         }
@@ -802,55 +988,87 @@ impl<'a> ODEiv2Driver<'a> {
 
     /// The function sets a minimum for allowed step size hmin for driver self. Default value is 0.
     #[doc(alias = "gsl_odeiv2_driver_set_hmin")]
-    pub fn set_hmin(&mut self, hmin: f64) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_driver_set_hmin(self.d, hmin) })
+    pub fn set_hmin(&mut self, hmin: f64) -> Result<(), Error> {
+        result_handler!(
+            unsafe { sys::gsl_odeiv2_driver_set_hmin(self.d, hmin) },
+            (),
+            "gsl_odeiv2_driver_set_hmin"
+        )
     }
 
     /// The function sets a maximum for allowed step size hmax for driver self. Default value is ::DBL_MAX.
     #[doc(alias = "gsl_odeiv2_driver_set_hmax")]
-    pub fn set_hmax(&mut self, hmax: f64) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_driver_set_hmax(self.d, hmax) })
+    pub fn set_hmax(&mut self, hmax: f64) -> Result<(), Error> {
+        result_handler!(
+            unsafe { sys::gsl_odeiv2_driver_set_hmax(self.d, hmax) },
+            (),
+            "gsl_odeiv2_driver_set_hmax"
+        )
     }
 
     /// The function sets a maximum for allowed number of steps nmax for driver self. Default value of 0 sets no limit for steps.
     #[doc(alias = "gsl_odeiv2_driver_set_nmax")]
-    pub fn set_nmax(&mut self, nmax: usize) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_driver_set_nmax(self.d, nmax as _) })
+    pub fn set_nmax(&mut self, nmax: usize) -> Result<(), Error> {
+        result_handler!(
+            unsafe { sys::gsl_odeiv2_driver_set_nmax(self.d, nmax as _) },
+            (),
+            "gsl_odeiv2_driver_set_nmax"
+        )
     }
 
     /// This function evolves the driver system d from t to t1. Initially vector y should contain the values of dependent variables at
-    /// point t. If the function is unable to complete the calculation, an error code from gsl_odeiv2_evolve_apply is returned, and t and
+    /// point t. If the function is unable to complete the calculation, the error from gsl_odeiv2_evolve_apply is returned, and t and
     /// y contain the values from last successful step.
     ///
-    /// If maximum number of steps is reached, a value of Value::MaxIteration is returned. If the step size drops below minimum value, the
-    /// function returns with ::NoProg. If the user-supplied functions defined in the system sys returns Value::BadFunc, the function
-    /// returns immediately with the same return code. In this case the user must call gsl_odeiv2_driver_reset before calling this
+    /// If maximum number of steps is reached, a Value::MaxIteration error is returned. If the step size drops below minimum value, the
+    /// function returns a Value::NoProgress error. If the user-supplied functions defined in the system sys returns Value::BadFunction, the
+    /// function returns immediately with the same error code. In this case the user must call gsl_odeiv2_driver_reset before calling this
     /// function again.
     #[doc(alias = "gsl_odeiv2_driver_apply")]
-    pub fn apply(&mut self, t: &mut f64, t1: f64, y: &mut [f64]) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_driver_apply(self.d, t, t1, y.as_mut_ptr()) })
+    pub fn apply(&mut self, t: &mut f64, t1: f64, y: &mut [f64]) -> Result<(), Error> {
+        self.check_dimension(y);
+        let ret = unsafe { sys::gsl_odeiv2_driver_apply(self.d, t, t1, y.as_mut_ptr()) };
+        result_handler!(ret, (), "gsl_odeiv2_driver_apply")
     }
 
     /// This function evolves the driver system d from t with n steps of size h. If the function is unable to complete the calculation, an
     /// error code from gsl_odeiv2_evolve_apply_fixed_step is returned, and t and y contain the values from last successful step.
     #[doc(alias = "gsl_odeiv2_driver_apply_fixed_step")]
-    pub fn apply_fixed_step(&mut self, t: &mut f64, h: f64, n: usize, y: &mut [f64]) -> Value {
-        Value::from(unsafe {
+    pub fn apply_fixed_step(
+        &mut self,
+        t: &mut f64,
+        h: f64,
+        n: usize,
+        y: &mut [f64],
+    ) -> Result<(), Error> {
+        self.check_dimension(y);
+        let ret = unsafe {
             sys::gsl_odeiv2_driver_apply_fixed_step(self.d, t, h, n as _, y.as_mut_ptr())
-        })
+        };
+        result_handler!(ret, (), "gsl_odeiv2_driver_apply_fixed_step")
+    }
+
+    fn check_dimension(&self, y: &[f64]) {
+        assert_eq!(
+            y.len(),
+            self.raw_system.dimension,
+            "y doesn't have the dimension of the system"
+        );
     }
 
     /// This function resets the evolution and stepper objects.
     #[doc(alias = "gsl_odeiv2_driver_reset")]
-    pub fn reset(&mut self) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_driver_reset(self.d) })
+    pub fn reset(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_odeiv2_driver_reset(self.d) };
+        result_handler!(ret, (), "gsl_odeiv2_driver_reset")
     }
 
     /// The routine resets the evolution and stepper objects and sets new initial step size to hstart. This function can be used e.g. to
     /// change the direction of integration.
     #[doc(alias = "gsl_odeiv2_driver_reset_hstart")]
-    pub fn reset_hstart(&mut self, hstart: f64) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_driver_reset_hstart(self.d, hstart) })
+    pub fn reset_hstart(&mut self, hstart: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_odeiv2_driver_reset_hstart(self.d, hstart) };
+        result_handler!(ret, (), "gsl_odeiv2_driver_reset_hstart")
     }
 }
 
@@ -4,14 +4,20 @@
 
 #[cfg(feature = "v2_2")]
 use crate::MatrixF64;
-use crate::Value;
-use crate::{MatrixComplexF32, MatrixComplexF64, MatrixF32, VectorF64};
+use crate::{Error, Value};
+use crate::{
+    MatrixComplexF32, MatrixComplexF64, MatrixF32, VectorComplexF32, VectorComplexF64, VectorF32,
+    VectorF64,
+};
 use ffi::FFI;
 use std::fmt;
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Debug, Display, Formatter};
+use utilities::Preview;
 
 ffi_wrapper!(Permutation, *mut sys::gsl_permutation, gsl_permutation_free);
 
+unsafe impl Send for Permutation {}
+
 /// ##Permutations in cyclic form
 ///
 /// A permutation can be represented in both linear and cyclic notations. The functions described in this section convert between the two forms.
@@ -73,10 +79,10 @@ impl Permutation {
 
     /// This function copies the elements of the permutation src into the permutation dest. The two permutations must have the same size.
     #[doc(alias = "gsl_permutation_memcpy")]
-    pub fn copy(&self, dest: &mut Permutation) -> Value {
-        Value::from(unsafe {
-            sys::gsl_permutation_memcpy(dest.unwrap_unique(), self.unwrap_shared())
-        })
+    pub fn copy(&self, dest: &mut Permutation) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_permutation_memcpy(dest.unwrap_unique(), self.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_permutation_memcpy")
     }
 
     /// This function returns the value of the i-th element of the permutation p. If i lies outside the allowed range of 0 to n-1 then
@@ -88,8 +94,9 @@ impl Permutation {
 
     /// This function exchanges the i-th and j-th elements of the permutation p.
     #[doc(alias = "gsl_permutation_swap")]
-    pub fn swap(&mut self, i: usize, j: usize) -> Value {
-        Value::from(unsafe { sys::gsl_permutation_swap(self.unwrap_unique(), i, j) })
+    pub fn swap(&mut self, i: usize, j: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_permutation_swap(self.unwrap_unique(), i, j) };
+        result_handler!(ret, (), "gsl_permutation_swap")
     }
 
     /// This function returns the size of the permutation p.
@@ -128,114 +135,345 @@ impl Permutation {
 
     /// This function computes the inverse of the permutation p, storing the result in inv.
     #[doc(alias = "gsl_permutation_inverse")]
-    pub fn inverse(&self, inv: &mut Permutation) -> Value {
-        Value::from(unsafe {
-            sys::gsl_permutation_inverse(inv.unwrap_unique(), self.unwrap_shared())
-        })
+    pub fn inverse(&self, inv: &mut Permutation) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_permutation_inverse(inv.unwrap_unique(), self.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_permutation_inverse")
     }
 
-    /// This function advances the permutation p to the next permutation in lexicographic order and returns GSL_SUCCESS. If no further
-    /// permutations are available it returns GSL_FAILURE and leaves p unmodified. Starting with the identity permutation and repeatedly
+    /// This function advances the permutation p to the next permutation in lexicographic order and returns `Ok(())`. If no further
+    /// permutations are available it returns a `Value::Failure` error and leaves p unmodified. Starting with the identity permutation and repeatedly
     /// applying this function will iterate through all possible permutations of a given order.
     #[doc(alias = "gsl_permutation_next")]
-    pub fn next(&mut self) -> Value {
-        Value::from(unsafe { sys::gsl_permutation_next(self.unwrap_unique()) })
+    pub fn next(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_permutation_next(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_permutation_next")
     }
 
-    /// This function steps backwards from the permutation p to the previous permutation in lexicographic order, returning GSL_SUCCESS.
-    /// If no previous permutation is available it returns GSL_FAILURE and leaves p unmodified.
+    /// This function steps backwards from the permutation p to the previous permutation in lexicographic order, returning `Ok(())`.
+    /// If no previous permutation is available it returns a `Value::Failure` error and leaves p unmodified.
     #[doc(alias = "gsl_permutation_prev")]
-    pub fn prev(&mut self) -> Value {
-        Value::from(unsafe { sys::gsl_permutation_prev(self.unwrap_unique()) })
+    pub fn prev(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_permutation_prev(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_permutation_prev")
     }
 
-    /// This function applies the permutation to the array data of size n with stride stride.
-    #[doc(alias = "gsl_permutation_data")]
-    pub fn permute(&mut self, data: &mut [f64], stride: usize) -> Value {
-        Value::from(unsafe {
-            let data_ptr = sys::gsl_permutation_data(self.unwrap_shared());
-            sys::gsl_permute(data_ptr, data.as_mut_ptr(), stride, data.len() as _)
-        })
+    /// Returns an iterator yielding the current permutation followed by all the next ones in
+    /// lexicographic order (using `Permutation::next`).
+    pub fn iter(&mut self) -> PermutationIter<'_> {
+        PermutationIter {
+            p: self,
+            forward: true,
+            done: false,
+        }
     }
 
-    /// This function applies the inverse of the permutation p to the array data of size n with stride stride.
-    #[doc(alias = "gsl_permutation_data")]
-    pub fn permute_inverse(&mut self, data: &mut [f64], stride: usize) -> Value {
-        Value::from(unsafe {
-            let data_ptr = sys::gsl_permutation_data(self.unwrap_shared());
-            sys::gsl_permute_inverse(data_ptr, data.as_mut_ptr(), stride, data.len() as _)
-        })
+    /// Returns an iterator yielding the current permutation followed by all the previous ones in
+    /// lexicographic order (using `Permutation::prev`).
+    pub fn iter_rev(&mut self) -> PermutationIter<'_> {
+        PermutationIter {
+            p: self,
+            forward: false,
+            done: false,
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<usize> {
+        self.as_slice().to_vec()
+    }
+
+    /// Creates a permutation from its linear representation. Returns `None` if `data` doesn't
+    /// contain each of the numbers 0 to n-1 exactly once.
+    pub fn from_slice(data: &[usize]) -> Option<Self> {
+        let mut p = Self::new(data.len())?;
+        p.as_mut_slice().copy_from_slice(data);
+        if p.is_valid() {
+            Some(p)
+        } else {
+            None
+        }
+    }
+
+    /// Applies the permutation to `data` in place, such that `data[i]` becomes the former
+    /// `data[p[i]]`. This is the same operation as [`Permutation::permute`], but for any element
+    /// type.
+    ///
+    /// Panics if the length of `data` isn't the size of the permutation.
+    ///
+    /// ```
+    /// use rgsl::Permutation;
+    ///
+    /// let p = Permutation::from_slice(&[2, 0, 1]).unwrap();
+    /// let mut data = ["a", "b", "c"];
+    /// p.permute_slice(&mut data);
+    /// assert_eq!(data, ["c", "a", "b"]);
+    /// p.permute_slice_inverse(&mut data);
+    /// assert_eq!(data, ["a", "b", "c"]);
+    /// ```
+    pub fn permute_slice<T>(&self, data: &mut [T]) {
+        let p = self.as_slice();
+        assert_eq!(
+            data.len(),
+            p.len(),
+            "slice length doesn't match the permutation"
+        );
+        // Same algorithm as `gsl_permute`: each cycle is rotated starting from its smallest index.
+        for i in 0..p.len() {
+            let mut k = p[i];
+            while k > i {
+                k = p[k];
+            }
+            if k < i {
+                continue;
+            }
+            while p[k] != i {
+                data.swap(k, p[k]);
+                k = p[k];
+            }
+        }
+    }
+
+    /// Applies the inverse of the permutation to `data` in place, such that `data[p[i]]` becomes
+    /// the former `data[i]`. This is the same operation as [`Permutation::permute_inverse`], but
+    /// for any element type.
+    ///
+    /// Panics if the length of `data` isn't the size of the permutation.
+    pub fn permute_slice_inverse<T>(&self, data: &mut [T]) {
+        let p = self.as_slice();
+        assert_eq!(
+            data.len(),
+            p.len(),
+            "slice length doesn't match the permutation"
+        );
+        for i in 0..p.len() {
+            let mut k = p[i];
+            while k > i {
+                k = p[k];
+            }
+            if k < i {
+                continue;
+            }
+            while p[k] != i {
+                data.swap(i, p[k]);
+                k = p[k];
+            }
+        }
+    }
+
+    /// This function applies the permutation to the array data of size n with stride stride, where
+    /// n is the size of the permutation.
+    ///
+    /// Panics if `data` is too short to hold n elements with the given stride.
+    #[doc(alias = "gsl_permute")]
+    pub fn permute(&self, data: &mut [f64], stride: usize) -> Result<(), Error> {
+        let n = self.check_strided_len(data.len(), stride, 1);
+        let ret =
+            unsafe { sys::gsl_permute(self.as_slice().as_ptr(), data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, (), "gsl_permute")
+    }
+
+    /// This function applies the inverse of the permutation p to the array data of size n with
+    /// stride stride, where n is the size of the permutation.
+    ///
+    /// Panics if `data` is too short to hold n elements with the given stride.
+    #[doc(alias = "gsl_permute_inverse")]
+    pub fn permute_inverse(&self, data: &mut [f64], stride: usize) -> Result<(), Error> {
+        let n = self.check_strided_len(data.len(), stride, 1);
+        let ret = unsafe {
+            sys::gsl_permute_inverse(self.as_slice().as_ptr(), data.as_mut_ptr(), stride, n)
+        };
+        result_handler!(ret, (), "gsl_permute_inverse")
+    }
+
+    /// Same as [`Permutation::permute`] but for single precision data.
+    #[doc(alias = "gsl_permute_float")]
+    pub fn permute_float(&self, data: &mut [f32], stride: usize) -> Result<(), Error> {
+        let n = self.check_strided_len(data.len(), stride, 1);
+        let ret = unsafe {
+            sys::gsl_permute_float(self.as_slice().as_ptr(), data.as_mut_ptr(), stride, n)
+        };
+        result_handler!(ret, (), "gsl_permute_float")
+    }
+
+    /// Same as [`Permutation::permute_inverse`] but for single precision data.
+    #[doc(alias = "gsl_permute_float_inverse")]
+    pub fn permute_float_inverse(&self, data: &mut [f32], stride: usize) -> Result<(), Error> {
+        let n = self.check_strided_len(data.len(), stride, 1);
+        let ret = unsafe {
+            sys::gsl_permute_float_inverse(self.as_slice().as_ptr(), data.as_mut_ptr(), stride, n)
+        };
+        result_handler!(ret, (), "gsl_permute_float_inverse")
+    }
+
+    /// This function applies the permutation to the complex array data of size n with stride
+    /// stride. `data` is a packed array of (real, imaginary) pairs and the stride is counted in
+    /// complex elements, like in the FFT functions.
+    ///
+    /// Panics if `data` is too short to hold n complex elements with the given stride.
+    #[doc(alias = "gsl_permute_complex")]
+    pub fn permute_complex(&self, data: &mut [f64], stride: usize) -> Result<(), Error> {
+        let n = self.check_strided_len(data.len(), stride, 2);
+        let ret = unsafe {
+            sys::gsl_permute_complex(self.as_slice().as_ptr(), data.as_mut_ptr(), stride, n)
+        };
+        result_handler!(ret, (), "gsl_permute_complex")
+    }
+
+    /// This function applies the inverse of the permutation to the complex array data of size n
+    /// with stride stride. See [`Permutation::permute_complex`] for the layout of `data`.
+    #[doc(alias = "gsl_permute_complex_inverse")]
+    pub fn permute_complex_inverse(&self, data: &mut [f64], stride: usize) -> Result<(), Error> {
+        let n = self.check_strided_len(data.len(), stride, 2);
+        let ret = unsafe {
+            sys::gsl_permute_complex_inverse(self.as_slice().as_ptr(), data.as_mut_ptr(), stride, n)
+        };
+        result_handler!(ret, (), "gsl_permute_complex_inverse")
+    }
+
+    // Returns the permutation size after checking that `len` values are enough to hold it with the
+    // given stride, `width` being the number of values per element.
+    fn check_strided_len(&self, len: usize, stride: usize, width: usize) -> usize {
+        let n = self.size();
+        assert!(stride > 0, "stride must be greater than 0");
+        assert!(
+            n == 0
+                || (n - 1)
+                    .checked_mul(stride)
+                    .and_then(|last| last.checked_add(1))
+                    .and_then(|count| count.checked_mul(width))
+                    .is_some_and(|needed| len >= needed),
+            "data is too short for a permutation of size {} with stride {}",
+            n,
+            stride
+        );
+        n
     }
 
     /// This function applies the permutation p to the elements of the vector v, considered as a row-vector acted on by a permutation
     /// matrix from the right, v' = v P. The j-th column of the permutation matrix P is given by the p_j-th column of the identity matrix.
     /// The permutation p and the vector v must have the same length.
     #[doc(alias = "gsl_permute_vector")]
-    pub fn permute_vector(&mut self, v: &mut VectorF64) -> Value {
-        Value::from(unsafe { sys::gsl_permute_vector(self.unwrap_unique(), v.unwrap_unique()) })
+    pub fn permute_vector(&self, v: &mut VectorF64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_permute_vector(self.unwrap_shared(), v.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_permute_vector")
     }
 
     /// This function applies the inverse of the permutation p to the elements of the vector v, considered as a row-vector acted on by an inverse permutation
     /// matrix from the right, v' = v P^T. Note that for permutation matrices the inverse is the same as the transpose. The j-th column of the permutation
     /// matrix P is given by the p_j-th column of the identity matrix. The permutation p and the vector v must have the same length.
     #[doc(alias = "gsl_permute_vector_inverse")]
-    pub fn permute_vector_inverse(&self, v: &mut VectorF64) -> Value {
-        Value::from(unsafe {
-            sys::gsl_permute_vector_inverse(self.unwrap_shared(), v.unwrap_unique())
-        })
+    pub fn permute_vector_inverse(&self, v: &mut VectorF64) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_permute_vector_inverse(self.unwrap_shared(), v.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_permute_vector_inverse")
+    }
+
+    #[doc(alias = "gsl_permute_vector_float")]
+    pub fn permute_vector_float(&self, v: &mut VectorF32) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_permute_vector_float(self.unwrap_shared(), v.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_permute_vector_float")
+    }
+
+    #[doc(alias = "gsl_permute_vector_float_inverse")]
+    pub fn permute_vector_float_inverse(&self, v: &mut VectorF32) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_permute_vector_float_inverse(self.unwrap_shared(), v.unwrap_unique())
+        };
+        result_handler!(ret, (), "gsl_permute_vector_float_inverse")
+    }
+
+    /// This function applies the permutation p to the elements of the complex vector v.
+    #[doc(alias = "gsl_permute_vector_complex")]
+    pub fn permute_vector_complex(&self, v: &mut VectorComplexF64) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_permute_vector_complex(self.unwrap_shared(), v.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_permute_vector_complex")
+    }
+
+    /// This function applies the inverse of the permutation p to the elements of the complex
+    /// vector v.
+    #[doc(alias = "gsl_permute_vector_complex_inverse")]
+    pub fn permute_vector_complex_inverse(&self, v: &mut VectorComplexF64) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_permute_vector_complex_inverse(self.unwrap_shared(), v.unwrap_unique())
+        };
+        result_handler!(ret, (), "gsl_permute_vector_complex_inverse")
+    }
+
+    #[doc(alias = "gsl_permute_vector_complex_float")]
+    pub fn permute_vector_complex_float(&self, v: &mut VectorComplexF32) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_permute_vector_complex_float(self.unwrap_shared(), v.unwrap_unique())
+        };
+        result_handler!(ret, (), "gsl_permute_vector_complex_float")
+    }
+
+    #[doc(alias = "gsl_permute_vector_complex_float_inverse")]
+    pub fn permute_vector_complex_float_inverse(
+        &self,
+        v: &mut VectorComplexF32,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_permute_vector_complex_float_inverse(self.unwrap_shared(), v.unwrap_unique())
+        };
+        result_handler!(ret, (), "gsl_permute_vector_complex_float_inverse")
     }
 
     #[cfg(feature = "v2_2")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     #[doc(alias = "gsl_permute_matrix")]
-    pub fn permute_matrix(&self, A: &mut MatrixF64) -> Value {
-        Value::from(unsafe { sys::gsl_permute_matrix(self.unwrap_shared(), A.unwrap_unique()) })
+    pub fn permute_matrix(&self, A: &mut MatrixF64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_permute_matrix(self.unwrap_shared(), A.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_permute_matrix")
     }
 
     #[doc(alias = "gsl_permute_matrix_float")]
-    pub fn permute_matrix_float(&self, A: &mut MatrixF32) -> Value {
-        Value::from(unsafe {
-            sys::gsl_permute_matrix_float(self.unwrap_shared(), A.unwrap_unique())
-        })
+    pub fn permute_matrix_float(&self, A: &mut MatrixF32) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_permute_matrix_float(self.unwrap_shared(), A.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_permute_matrix_float")
     }
 
     #[doc(alias = "gsl_permute_matrix_complex")]
-    pub fn permute_matrix_complex(&self, A: &mut MatrixComplexF64) -> Value {
-        Value::from(unsafe {
-            sys::gsl_permute_matrix_complex(self.unwrap_shared(), A.unwrap_unique())
-        })
+    pub fn permute_matrix_complex(&self, A: &mut MatrixComplexF64) -> Result<(), Error> {
+        let ret =
+            unsafe { sys::gsl_permute_matrix_complex(self.unwrap_shared(), A.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_permute_matrix_complex")
     }
 
     #[doc(alias = "gsl_permute_matrix_complex_float")]
-    pub fn permute_matrix_complex_float(&self, A: &mut MatrixComplexF32) -> Value {
-        Value::from(unsafe {
+    pub fn permute_matrix_complex_float(&self, A: &mut MatrixComplexF32) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_permute_matrix_complex_float(self.unwrap_shared(), A.unwrap_unique())
-        })
+        };
+        result_handler!(ret, (), "gsl_permute_matrix_complex_float")
     }
 
     /// This function combines the two permutations pa and pb into a single permutation p, where p = pa * pb. The permutation p is equivalent to applying pb
     /// first and then pa.
     #[doc(alias = "gsl_permutation_mul")]
-    pub fn mul(&mut self, pa: &Permutation, pb: &Permutation) -> Value {
-        Value::from(unsafe {
+    pub fn mul(&mut self, pa: &Permutation, pb: &Permutation) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_permutation_mul(self.unwrap_unique(), pa.unwrap_shared(), pb.unwrap_shared())
-        })
+        };
+        result_handler!(ret, (), "gsl_permutation_mul")
     }
 
     /// This function computes the canonical form of the permutation self and stores it in the output argument q.
     #[doc(alias = "gsl_permutation_linear_to_canonical")]
-    pub fn linear_to_canonical(&self, q: &mut Permutation) -> Value {
-        Value::from(unsafe {
+    pub fn linear_to_canonical(&self, q: &mut Permutation) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_permutation_linear_to_canonical(q.unwrap_unique(), self.unwrap_shared())
-        })
+        };
+        result_handler!(ret, (), "gsl_permutation_linear_to_canonical")
     }
 
     /// This function converts the self permutation in canonical form back into linear form storing it in the output argument p.
     #[doc(alias = "gsl_permutation_canonical_to_linear")]
-    pub fn canonical_to_linear(&self, p: &mut Permutation) -> Value {
-        Value::from(unsafe {
+    pub fn canonical_to_linear(&self, p: &mut Permutation) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_permutation_canonical_to_linear(p.unwrap_unique(), self.unwrap_shared())
-        })
+        };
+        result_handler!(ret, (), "gsl_permutation_canonical_to_linear")
     }
 
     /// This function counts the number of inversions in the self permutation. An inversion is any pair of elements that are not in order. For example, the
@@ -258,12 +496,167 @@ impl Permutation {
     }
 }
 
+/// Iterator over successive permutations, created by [`Permutation::iter`] and
+/// [`Permutation::iter_rev`].
+pub struct PermutationIter<'a> {
+    p: &'a mut Permutation,
+    forward: bool,
+    done: bool,
+}
+
+impl<'a> Iterator for PermutationIter<'a> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let current = self.p.to_vec();
+        let ret = if self.forward {
+            self.p.next()
+        } else {
+            self.p.prev()
+        };
+        self.done = ret.is_err();
+        Some(current)
+    }
+}
+
+/// Owning iterator over successive permutations, created by [`all_permutations`] or by calling
+/// `into_iter` on a [`Permutation`].
+pub struct PermutationIntoIter {
+    p: Permutation,
+    done: bool,
+}
+
+impl Iterator for PermutationIntoIter {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let current = self.p.to_vec();
+        self.done = self.p.next().is_err();
+        Some(current)
+    }
+}
+
+impl IntoIterator for Permutation {
+    type Item = Vec<usize>;
+    type IntoIter = PermutationIntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PermutationIntoIter {
+            p: self,
+            done: false,
+        }
+    }
+}
+
+/// Returns an iterator over all the permutations of `{0, ..., n-1}` in lexicographic order,
+/// starting with the identity.
+///
+/// ```
+/// use rgsl::permutation::all_permutations;
+///
+/// let perms: Vec<Vec<usize>> = all_permutations(3).collect();
+/// assert_eq!(perms.len(), 6);
+/// assert_eq!(perms[1], vec![0, 2, 1]);
+/// assert_eq!(perms[5], vec![2, 1, 0]);
+/// ```
+pub fn all_permutations(n: usize) -> PermutationIntoIter {
+    Permutation::new_with_init(n)
+        .expect("Permutation::new_with_init failed")
+        .into_iter()
+}
+
 impl Debug for Permutation {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         if self.unwrap_shared().is_null() {
             write!(f, "<null>")
         } else {
-            write!(f, "{:?}", self.as_slice())
+            f.debug_struct("Permutation")
+                .field("size", &self.size())
+                .field("data", &Preview(self.size(), |i| self.get(i)))
+                .finish()
         }
     }
 }
+
+impl Clone for Permutation {
+    /// Returns a new permutation holding a copy of `self`.
+    fn clone(&self) -> Permutation {
+        let mut p = Permutation::new(self.size()).expect("gsl_permutation_alloc failed");
+        self.copy(&mut p).expect("gsl_permutation_memcpy failed");
+        p
+    }
+}
+
+impl PartialEq for Permutation {
+    fn eq(&self, other: &Permutation) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl Eq for Permutation {}
+
+/// Formats the permutation in linear notation, e.g. `(2 4 3 0 1)`.
+impl Display for Permutation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        for (pos, v) in self.as_slice().iter().enumerate() {
+            if pos == 0 {
+                write!(f, "{}", v)?;
+            } else {
+                write!(f, " {}", v)?;
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+#[test]
+fn permutation_algebra() {
+    let mut p = Permutation::new(5).unwrap();
+    p.as_mut_slice().copy_from_slice(&[2, 4, 3, 0, 1]);
+    assert_eq!(format!("{}", p), "(2 4 3 0 1)");
+
+    let mut q = Permutation::new(5).unwrap();
+    p.linear_to_canonical(&mut q).unwrap();
+    assert_eq!(q.as_slice(), &[1, 4, 0, 2, 3]);
+    assert_eq!(q.canonical_cycles(), 2);
+    assert_eq!(p.linear_cycles(), 2);
+
+    let mut inv = Permutation::new(5).unwrap();
+    p.inverse(&mut inv).unwrap();
+    let mut id = Permutation::new(5).unwrap();
+    id.mul(&p, &inv).unwrap();
+    assert_eq!(id.as_slice(), &[0, 1, 2, 3, 4]);
+    assert_eq!(id.inversions(), 0);
+
+    let mut data = [10., -1., 11., -1., 12., -1., 13., -1., 14., -1.];
+    p.permute(&mut data, 2).unwrap();
+    assert_eq!(data, [12., -1., 14., -1., 13., -1., 10., -1., 11., -1.]);
+    p.permute_complex_inverse(&mut data, 1).unwrap();
+    assert_eq!(data, [10., -1., 11., -1., 12., -1., 13., -1., 14., -1.]);
+}
+
+#[test]
+fn permutation_iter_and_slices() {
+    let mut p = Permutation::new_with_init(4).unwrap();
+    assert_eq!(p.iter().count(), 24);
+    assert_eq!(p.as_slice(), &[3, 2, 1, 0]);
+    assert_eq!(p.iter_rev().last(), Some(vec![0, 1, 2, 3]));
+
+    let p = Permutation::from_slice(&[2, 4, 3, 0, 1]).unwrap();
+    assert!(Permutation::from_slice(&[0, 0, 1]).is_none());
+    let mut data = vec![10., 11., 12., 13., 14.];
+    let mut generic = vec![10, 11, 12, 13, 14];
+    p.permute(&mut data, 1).unwrap();
+    p.permute_slice(&mut generic);
+    assert_eq!(generic, [12, 14, 13, 10, 11]);
+    assert!(data.iter().zip(&generic).all(|(a, b)| *a == *b as f64));
+    p.permute_slice_inverse(&mut generic);
+    assert_eq!(generic, [10, 11, 12, 13, 14]);
+}
@@ -9,8 +9,13 @@ The roots of polynomial equations cannot be found analytically beyond the specia
 described in this section uses an iterative method to find the approximate locations of roots of higher order polynomials.
 !*/
 
-use crate::Value;
+use crate::{Error, Value};
 use ffi::FFI;
+use polynomials::divided_difference_representation::{
+    poly_dd_hermite_init, poly_dd_init, poly_dd_taylor,
+};
+use polynomials::evaluation::{poly_complex_eval, poly_eval, poly_eval_derivs};
+use types::ComplexF64;
 
 ffi_wrapper!(
     PolyComplexWorkspace,
@@ -18,6 +23,8 @@ ffi_wrapper!(
     gsl_poly_complex_workspace_free
 );
 
+unsafe impl Send for PolyComplexWorkspace {}
+
 impl PolyComplexWorkspace {
     /// This function allocates space for a gsl_poly_complex_workspace struct and a workspace suitable for solving a polynomial with n coefficients
     /// using the routine gsl_poly_complex_solve.
@@ -45,14 +52,130 @@ impl PolyComplexWorkspace {
     /// accuracy. The solution of polynomials with higher-order roots requires specialized algorithms that take the multiplicity structure into
     /// account (see e.g. Z. Zeng, Algorithm 835, ACM Transactions on Mathematical Software, Volume 30, Issue 2 (2004), pp 218–236).
     #[doc(alias = "gsl_poly_complex_solve")]
-    pub fn solve(&mut self, a: &[f64], z: &mut [f64]) -> Value {
-        Value::from(unsafe {
+    pub fn solve(&mut self, a: &[f64], z: &mut [f64]) -> Result<(), Error> {
+        assert!(!a.is_empty() && z.len() >= 2 * (a.len() - 1));
+        let ret = unsafe {
             sys::gsl_poly_complex_solve(
                 a.as_ptr(),
                 a.len() as _,
                 self.unwrap_unique(),
                 z.as_mut_ptr(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_poly_complex_solve")
+    }
+}
+
+/// A polynomial with real coefficients, `P(x) = c[0] + c[1] x + ... + c[n] x^n`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polynomial {
+    coefficients: Vec<f64>,
+}
+
+impl Polynomial {
+    /// Creates a polynomial from its coefficients, lowest order first.
+    pub fn new(coefficients: &[f64]) -> Polynomial {
+        Polynomial {
+            coefficients: coefficients.to_vec(),
+        }
+    }
+
+    /// Builds the interpolating polynomial through the points `(xa[i], ya[i])` using its
+    /// divided-difference representation.
+    #[doc(alias = "gsl_poly_dd_init")]
+    #[doc(alias = "gsl_poly_dd_taylor")]
+    pub fn interpolate(xa: &[f64], ya: &[f64]) -> Result<Polynomial, Error> {
+        let mut dd = vec![0.; xa.len()];
+        poly_dd_init(&mut dd, xa, ya)?;
+        Self::from_divided_differences(&dd, xa)
+    }
+
+    /// Builds the Hermite interpolating polynomial matching both the values `ya` and the first
+    /// derivatives `dya` at the points `xa`. The result has degree `2 * xa.len() - 1`.
+    #[doc(alias = "gsl_poly_dd_hermite_init")]
+    #[doc(alias = "gsl_poly_dd_taylor")]
+    pub fn hermite(xa: &[f64], ya: &[f64], dya: &[f64]) -> Result<Polynomial, Error> {
+        let mut dd = vec![0.; 2 * xa.len()];
+        let mut za = vec![0.; 2 * xa.len()];
+        poly_dd_hermite_init(&mut dd, &mut za, xa, ya, dya)?;
+        Self::from_divided_differences(&dd, &za)
+    }
+
+    fn from_divided_differences(dd: &[f64], xa: &[f64]) -> Result<Polynomial, Error> {
+        let mut c = vec![0.; dd.len()];
+        let mut w = vec![0.; dd.len()];
+        poly_dd_taylor(&mut c, 0., dd, xa, &mut w)?;
+        Ok(Polynomial { coefficients: c })
+    }
+
+    /// Returns the coefficients, lowest order first.
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Returns the degree of the polynomial, ignoring trailing zero coefficients. The zero
+    /// polynomial has degree 0.
+    pub fn degree(&self) -> usize {
+        self.coefficients
+            .iter()
+            .rposition(|&c| c != 0.)
+            .unwrap_or(0)
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's method.
+    #[doc(alias = "gsl_poly_eval")]
+    pub fn eval(&self, x: f64) -> f64 {
+        poly_eval(&self.coefficients, x)
+    }
+
+    /// Evaluates the polynomial at the complex point `z`.
+    #[doc(alias = "gsl_poly_complex_eval")]
+    pub fn complex_eval(&self, z: &ComplexF64) -> ComplexF64 {
+        poly_complex_eval(&self.coefficients, z)
+    }
+
+    /// Returns `[P(x), P'(x), ..., P^(n)(x)]`.
+    #[doc(alias = "gsl_poly_eval_derivs")]
+    pub fn eval_derivs(&self, x: f64, n: usize) -> Result<Vec<f64>, Error> {
+        let mut res = vec![0.; n + 1];
+        poly_eval_derivs(&self.coefficients, x, &mut res)?;
+        Ok(res)
+    }
+
+    /// Returns the `degree()` complex roots of the polynomial, computed by balanced-QR reduction
+    /// of the companion matrix. See [`PolyComplexWorkspace::solve`].
+    #[doc(alias = "gsl_poly_complex_solve")]
+    pub fn roots(&self) -> Result<Vec<ComplexF64>, Error> {
+        let n = self.degree() + 1;
+        if n < 2 {
+            return Ok(Vec::new());
+        }
+        let mut w = PolyComplexWorkspace::new(n)
+            .ok_or_else(|| Error::new(Value::NoMemory, "gsl_poly_complex_workspace_alloc"))?;
+        let mut z = vec![0.; 2 * (n - 1)];
+        w.solve(&self.coefficients[..n], &mut z)?;
+        Ok(z.chunks(2)
+            .map(|c| ComplexF64 { dat: [c[0], c[1]] })
+            .collect())
+    }
+}
+
+#[test]
+fn polynomial() {
+    // (x - 1) (x - 2) (x - 3)
+    let p = Polynomial::new(&[-6., 11., -6., 1., 0.]);
+    assert_eq!(p.degree(), 3);
+    assert_eq!(p.eval(2.), 0.);
+    assert_eq!(p.eval_derivs(0., 2).unwrap(), vec![-6., 11., -12.]);
+
+    let mut roots: Vec<f64> = p.roots().unwrap().iter().map(|z| z.dat[0]).collect();
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    for (r, e) in roots.iter().zip(&[1., 2., 3.]) {
+        assert!((r - e).abs() < 1e-10);
+    }
+
+    let q = Polynomial::interpolate(&[1., 2., 3., 4.], &[0., 0., 0., 6.]).unwrap();
+    for (c, e) in q.coefficients().iter().zip(p.coefficients()) {
+        assert!((c - e).abs() < 1e-10);
     }
 }
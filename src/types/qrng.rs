@@ -16,13 +16,35 @@ The implementations of the quasi-random sequence routines are based on the algor
 
 P. Bratley and B.L. Fox and H. Niederreiter, “Algorithm 738: Programs to Generate Niederreiter’s Low-discrepancy Sequences”, ACM
 Transactions on Mathematical Software, Vol. 20, No. 4, December, 1994, p. 494–495.
+
+##Example
+
+[`QRng`] is an iterator over the points of its sequence:
+
+```
+use rgsl::{QRng, QRngType};
+
+let q = QRng::new(QRngType::sobol(), 2).unwrap();
+for point in q.take(5) {
+    println!("{:.5} {:.5}", point[0], point[1]);
+}
+```
 !*/
 
-use crate::Value;
+use crate::Error;
 use ffi::FFI;
 
+// `gsl_qrng` is opaque in the bindings.
+#[repr(C)]
+struct QRngHeader {
+    type_: *const sys::gsl_qrng_type,
+    dimension: u32,
+}
+
 ffi_wrapper!(QRng, *mut sys::gsl_qrng, gsl_qrng_free);
 
+unsafe impl Send for QRng {}
+
 impl QRng {
     /// This function returns a pointer to a newly-created instance of a quasi-random sequence
     /// generator of type T and dimension d. If there is insufficient memory to create the generator
@@ -49,9 +71,21 @@ impl QRng {
     /// This function stores the next point from the sequence generator self in the array x. The
     /// space available for x must match the dimension of the generator. The point x will lie in the
     /// range 0 < x_i < 1 for each x_i.
+    ///
+    /// Panics if `x` is shorter than the dimension of the generator.
     #[doc(alias = "gsl_qrng_get")]
-    pub fn get(&self, x: &mut [f64]) -> Value {
-        Value::from(unsafe { sys::gsl_qrng_get(self.unwrap_shared(), x.as_mut_ptr()) })
+    pub fn get(&mut self, x: &mut [f64]) -> Result<(), Error> {
+        assert!(
+            x.len() >= self.dimension(),
+            "`x` is shorter than the dimension of the generator"
+        );
+        let ret = unsafe { sys::gsl_qrng_get(self.unwrap_shared(), x.as_mut_ptr()) };
+        result_handler!(ret, (), "gsl_qrng_get")
+    }
+
+    /// Returns the dimension of the points generated.
+    pub fn dimension(&self) -> usize {
+        unsafe { (*(self.unwrap_shared() as *const QRngHeader)).dimension as usize }
     }
 
     /// This function returns a pointer to the name of the generator.
@@ -104,8 +138,9 @@ impl QRng {
     /// `dest`, making dest into an exact copy of `self`. The two generators must be of the same
     /// type.
     #[doc(alias = "gsl_qrng_memcpy")]
-    pub fn copy(&self, dest: &mut QRng) -> Value {
-        Value::from(unsafe { sys::gsl_qrng_memcpy(dest.unwrap_unique(), self.unwrap_shared()) })
+    pub fn copy(&self, dest: &mut QRng) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_qrng_memcpy(dest.unwrap_unique(), self.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_qrng_memcpy")
     }
 }
 
@@ -118,8 +153,26 @@ impl Clone for QRng {
     }
 }
 
+/// Yields the points of the sequence, until the generator fails (for example when the Sobol or
+/// Niederreiter sequences are exhausted).
+///
+/// The failure is reported to the GSL error handler first, and the default one aborts the
+/// program. To have the iterator end with `None` instead, install a handler which returns, for
+/// example with [`set_error_handler_off`](crate::error::set_error_handler_off).
+impl Iterator for QRng {
+    type Item = Vec<f64>;
+
+    fn next(&mut self) -> Option<Vec<f64>> {
+        let mut x = vec![0.; self.dimension()];
+        self.get(&mut x).ok().map(|()| x)
+    }
+}
+
 ffi_wrapper!(QRngType, *const sys::gsl_qrng_type);
 
+unsafe impl Send for QRngType {}
+unsafe impl Sync for QRngType {}
+
 impl QRngType {
     /// This generator uses the algorithm described in Bratley, Fox, Niederreiter, ACM Trans. Model.
     /// Comp. Sim. 2, 195 (1992). It is valid up to 12 dimensions.
@@ -144,3 +197,20 @@ impl QRngType {
         ffi_wrap!(gsl_qrng_reversehalton)
     }
 }
+
+#[test]
+fn qrng_iterator() {
+    let mut q = QRng::new(QRngType::halton(), 3).unwrap();
+    assert_eq!(q.dimension(), 3);
+
+    let c = q.clone();
+    let points: Vec<Vec<f64>> = q.by_ref().take(4).collect();
+    assert_eq!(points.len(), 4);
+    assert!(points
+        .iter()
+        .all(|p| p.len() == 3 && p.iter().all(|&x| 0. < x && x < 1.)));
+    assert_eq!(c.take(4).collect::<Vec<_>>(), points);
+
+    q.init();
+    assert_eq!(q.next().as_ref(), points.first());
+}
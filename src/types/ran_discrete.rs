@@ -24,12 +24,22 @@ This method can be used to speed up some of the discrete random number generator
 use ffi::FFI;
 use types::Rng;
 
+// `gsl_ran_discrete_t` is opaque in the bindings.
+#[repr(C)]
+struct RanDiscreteTable {
+    K: usize,
+    A: *mut usize,
+    F: *mut f64,
+}
+
 ffi_wrapper!(
     RanDiscrete,
     *mut sys::gsl_ran_discrete_t,
     gsl_ran_discrete_free
 );
 
+unsafe impl Send for RanDiscrete {}
+
 impl RanDiscrete {
     /// This function returns a pointer to a structure that contains the lookup table for the discrete random number generator. The array P[] contains the probabilities of the discrete events;
     /// these array elements must all be positive, but they needn’t add up to one (so you can think of them more generally as “weights”)—the preprocessor will normalize appropriately.
@@ -51,6 +61,26 @@ impl RanDiscrete {
         unsafe { sys::gsl_ran_discrete(r.unwrap_unique(), self.unwrap_shared()) }
     }
 
+    /// Fills `out` with discrete random numbers, see [`discrete`](#method.discrete).
+    #[doc(alias = "gsl_ran_discrete")]
+    pub fn discrete_fill(&self, r: &mut Rng, out: &mut [usize]) {
+        let (r, g) = (r.unwrap_unique(), self.unwrap_shared());
+        for k in out.iter_mut() {
+            *k = unsafe { sys::gsl_ran_discrete(r, g) };
+        }
+    }
+
+    /// Returns the number of events K in the lookup table.
+    pub fn len(&self) -> usize {
+        unsafe { (*(self.unwrap_shared() as *const RanDiscreteTable)).K }
+    }
+
+    /// Returns `true` if the lookup table has no events. GSL refuses to build such a table, so
+    /// this is only here for completeness.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Returns the probability `P[k]` of observing the variable k. Since `P[k]` is not
     /// stored as part of the lookup table, it must be recomputed; this computation takes O(K),
     /// so if K is large and you care about the original array `P[k]` used to create the lookup
@@ -60,3 +90,18 @@ impl RanDiscrete {
         unsafe { sys::gsl_ran_discrete_pdf(k, self.unwrap_shared()) }
     }
 }
+
+#[test]
+fn ran_discrete() {
+    use types::RngType;
+
+    let d = RanDiscrete::new(&[1., 0., 3.]).unwrap();
+    assert_eq!(d.len(), 3);
+    assert!((d.discrete_pdf(2) - 0.75).abs() < 1e-12);
+    assert_eq!(d.discrete_pdf(1), 0.);
+
+    let mut r = Rng::new(RngType::default()).unwrap();
+    let mut out = [0; 32];
+    d.discrete_fill(&mut r, &mut out);
+    assert!(out.iter().all(|&k| k == 0 || k == 2));
+}
@@ -2,7 +2,7 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use std::default::Default;
+use core::default::Default;
 
 /// The error handling form of the special functions always calculate an error estimate along with the value of the result.
 /// Therefore, structures are provided for amalgamating a value and error estimate.
@@ -64,6 +64,25 @@ impl ResultE10 {
             e10: 0i32,
         }
     }
+
+    /// Multiplies the value and error by `10^e10`, returning a plain [`Result`]. This fails with an
+    /// overflow or underflow error if the scaled value can't be represented by a `f64`.
+    #[doc(alias = "gsl_sf_result_smash_e")]
+    pub fn smash(&self) -> ::core::result::Result<Result, ::Error> {
+        let re = ::sys::gsl_sf_result_e10 {
+            val: self.val,
+            err: self.err,
+            e10: self.e10,
+        };
+        let mut result = ::core::mem::MaybeUninit::<::sys::gsl_sf_result>::uninit();
+        let ret = unsafe { ::sys::gsl_sf_result_smash_e(&re, result.as_mut_ptr()) };
+
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_result_smash_e"
+        )
+    }
 }
 
 impl From<::sys::gsl_sf_result_e10> for ResultE10 {
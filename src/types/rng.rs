@@ -70,12 +70,18 @@ http://csrc.nist.gov/rng/
 Thanks to Makoto Matsumoto, Takuji Nishimura and Yoshiharu Kurita for making the source code to their generators (MT19937, MM&TN; TT800, MM&YK) available under the GNU General Public License. Thanks to Martin Lüscher for providing notes and source code for the RANLXS and RANLXD generators.
 !*/
 
-use crate::Value;
+use crate::{Error, Value};
 use ffi::FFI;
+use std::ops::Range;
 use std::os::raw::c_ulong;
+use std::slice;
 
 ffi_wrapper!(Rng, *mut sys::gsl_rng, gsl_rng_free);
 
+// The only non-owning `Rng` is the one given to the step function of `siman::solve`, which is
+// only lent to it.
+unsafe impl Send for Rng {}
+
 impl Rng {
     /// This function returns a pointer to a newly-created instance of a random number generator of type T. For example, the following code creates an instance of the Tausworthe generator,
     ///
@@ -146,6 +152,47 @@ impl Rng {
         unsafe { sys::gsl_rng_uniform_int(self.unwrap_unique(), n as c_ulong) as _ }
     }
 
+    /// Returns a value drawn uniformly from `range`: see [`SampleRange`] for the supported types.
+    ///
+    /// ```
+    /// use rgsl::{Rng, RngType};
+    ///
+    /// let mut r = Rng::new(RngType::default()).unwrap();
+    /// let x = r.gen_range(-1.0..1.0);
+    /// assert!(-1. <= x && x < 1.);
+    /// let i = r.gen_range(10..20);
+    /// assert!(10 <= i && i < 20);
+    /// ```
+    pub fn gen_range<R: SampleRange>(&mut self, range: R) -> R::Item {
+        range.sample(self)
+    }
+
+    /// Returns an infinite iterator over values drawn with `f`. It is the iterator version of
+    /// the distribution methods:
+    ///
+    /// ```
+    /// use rgsl::{Rng, RngType};
+    ///
+    /// let mut r = Rng::new(RngType::default()).unwrap();
+    /// let xs: Vec<f64> = r.iter_with(|r| r.gaussian(2.)).take(100).collect();
+    /// assert_eq!(xs.len(), 100);
+    /// ```
+    pub fn iter_with<T, F: FnMut(&mut Rng) -> T>(&mut self, f: F) -> RngIter<'_, F> {
+        RngIter { rng: self, f }
+    }
+
+    /// Returns an infinite iterator over values from [`uniform`](#method.uniform).
+    #[doc(alias = "gsl_rng_uniform")]
+    pub fn iter_uniform(&mut self) -> RngIter<'_, impl FnMut(&mut Rng) -> f64> {
+        self.iter_with(Rng::uniform)
+    }
+
+    /// Returns an infinite iterator over values from [`uniform_int`](#method.uniform_int).
+    #[doc(alias = "gsl_rng_uniform_int")]
+    pub fn iter_uniform_int(&mut self, n: usize) -> RngIter<'_, impl FnMut(&mut Rng) -> usize> {
+        self.iter_with(move |r| r.uniform_int(n))
+    }
+
     /// This function returns a pointer to the name of the generator. For example,
     ///
     /// ```Rust
@@ -205,8 +252,9 @@ impl Rng {
 
     /// This function copies the random number generator src into the pre-existing generator dest, making dest into an exact copy of src. The two generators must be of the same type.
     #[doc(alias = "gsl_rng_memcpy")]
-    pub fn copy(&self, other: &mut Rng) -> Value {
-        Value::from(unsafe { sys::gsl_rng_memcpy(other.unwrap_unique(), self.unwrap_shared()) })
+    pub fn copy(&self, other: &mut Rng) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_rng_memcpy(other.unwrap_unique(), self.unwrap_shared()) };
+        result_handler!(ret, (), "gsl_rng_memcpy")
     }
 
     /// This function returns the size of the state of generator r. You can use this information to access the state directly. For example, the following code will write the state of a generator to a stream,
@@ -221,6 +269,47 @@ impl Rng {
         unsafe { sys::gsl_rng_size(self.unwrap_shared()) }
     }
 
+    /// Returns a copy of the state of the generator, the bytes `gsl_rng_fwrite` would write.
+    ///
+    /// Like with `gsl_rng_fwrite`, the state is in the machine representation so it can only be
+    /// restored (with [`set_state_bytes`](#method.set_state_bytes)) by a generator of the same
+    /// type on the same architecture.
+    ///
+    /// ```
+    /// use rgsl::{Rng, RngType};
+    ///
+    /// let mut r = Rng::new(RngType::default()).unwrap();
+    /// let checkpoint = r.state_bytes();
+    /// let x = r.uniform();
+    /// r.set_state_bytes(&checkpoint).unwrap();
+    /// assert_eq!(r.uniform(), x);
+    /// ```
+    #[doc(alias = "gsl_rng_fwrite")]
+    pub fn state_bytes(&self) -> Vec<u8> {
+        let state = unsafe { sys::gsl_rng_state(self.unwrap_shared()) };
+        if state.is_null() {
+            return Vec::new();
+        }
+        unsafe { slice::from_raw_parts(state as *const u8, self.size()) }.to_vec()
+    }
+
+    /// Restores the state returned by [`state_bytes`](#method.state_bytes), like
+    /// `gsl_rng_fread` does.
+    ///
+    /// Returns `Value::BadLength` if `state` isn't [`size`](#method.size) bytes long.
+    #[doc(alias = "gsl_rng_fread")]
+    pub fn set_state_bytes(&mut self, state: &[u8]) -> Result<(), Error> {
+        let ptr = unsafe { sys::gsl_rng_state(self.unwrap_unique()) };
+        if state.len() != self.size() || (ptr.is_null() && !state.is_empty()) {
+            return Err(Error::new(Value::BadLength, "gsl_rng_fread"));
+        }
+        if !state.is_empty() {
+            unsafe { slice::from_raw_parts_mut(ptr as *mut u8, state.len()) }
+                .copy_from_slice(state);
+        }
+        Ok(())
+    }
+
     /// Equivalent to DefaultRngSeed
     pub fn default_seed() -> usize {
         unsafe { sys::gsl_rng_default_seed as _ }
@@ -271,10 +360,12 @@ impl Rng {
     ///
     /// gsl_ran_choose (r, a, 3, b, 100, sizeof (double));
     /// ```
+    ///
+    /// The elements are copied bytewise, which is why `T` has to be `Copy`.
     #[doc(alias = "gsl_ran_choose")]
-    pub fn choose<T>(&mut self, src: &[T], dest: &mut [T]) -> Value {
-        assert!(src.len() <= dest.len());
-        Value::from(unsafe {
+    pub fn choose<T: Copy>(&mut self, src: &[T], dest: &mut [T]) -> Result<(), Error> {
+        assert!(dest.len() <= src.len());
+        let ret = unsafe {
             sys::gsl_ran_choose(
                 self.unwrap_unique(),
                 dest.as_mut_ptr() as *mut _,
@@ -283,14 +374,17 @@ impl Rng {
                 src.len() as _,
                 ::std::mem::size_of::<T>() as _,
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_ran_choose")
     }
 
     /// This function is like gsl_ran_choose but samples k items from the original array of n items src with replacement, so the same object can appear more
     /// than once in the output sequence dest. There is no requirement that k be less than n in this case.
+    ///
+    /// The elements are copied bytewise, which is why `T` has to be `Copy`.
     #[doc(alias = "gsl_ran_sample")]
-    pub fn sample<T>(&mut self, src: &[T], dest: &mut [T]) {
-        assert!(src.len() <= dest.len());
+    pub fn sample<T: Copy>(&mut self, src: &[T], dest: &mut [T]) {
+        assert!(dest.is_empty() || !src.is_empty());
         unsafe {
             sys::gsl_ran_sample(
                 self.unwrap_unique(),
@@ -383,6 +477,20 @@ impl Rng {
         unsafe { sys::gsl_ran_binomial(self.unwrap_unique(), p, n) }
     }
 
+    /// This function returns a binomial variate using the algorithm from Knuth (vol 2). It is
+    /// slower than [`binomial`](#method.binomial) but kept for reproducing older results.
+    #[doc(alias = "gsl_ran_binomial_knuth")]
+    pub fn binomial_knuth(&mut self, p: f64, n: u32) -> u32 {
+        unsafe { sys::gsl_ran_binomial_knuth(self.unwrap_unique(), p, n) }
+    }
+
+    /// This function returns a binomial variate using the TPE algorithm of Kachitvichyanukul and
+    /// Schmeiser. [`binomial`](#method.binomial) uses this method as well.
+    #[doc(alias = "gsl_ran_binomial_tpe")]
+    pub fn binomial_tpe(&mut self, p: f64, n: u32) -> u32 {
+        unsafe { sys::gsl_ran_binomial_tpe(self.unwrap_unique(), p, n) }
+    }
+
     /// This function generates a pair of correlated Gaussian variates, with mean zero, correlation coefficient rho and standard deviations sigma_x and sigma_y in the x and y directions.
     /// The probability distribution for bivariate Gaussian random variates is,
     ///
@@ -496,6 +604,31 @@ impl Rng {
         unsafe { sys::gsl_ran_gamma_knuth(self.unwrap_unique(), a, b) }
     }
 
+    /// This function returns a gamma variate using the Marsaglia-Tsang method. It is the same as
+    /// [`gamma`](#method.gamma), under its former name.
+    #[doc(alias = "gsl_ran_gamma_mt")]
+    pub fn gamma_mt(&mut self, a: f64, b: f64) -> f64 {
+        unsafe { sys::gsl_ran_gamma_mt(self.unwrap_unique(), a, b) }
+    }
+
+    /// This function returns a gamma variate with unit scale and integer order a, obtained as a
+    /// sum of exponential variates for small a.
+    #[doc(alias = "gsl_ran_gamma_int")]
+    pub fn gamma_int(&mut self, a: u32) -> f64 {
+        unsafe { sys::gsl_ran_gamma_int(self.unwrap_unique(), a) }
+    }
+
+    /// This function returns a random variate from the Erlang distribution, which is the gamma
+    /// distribution with an integer order n and scale a.
+    ///
+    /// p(x) dx = {x^{n-1} \over (n-1)! a^n} \exp(-x/a) dx
+    ///
+    /// for x > 0.
+    #[doc(alias = "gsl_ran_erlang")]
+    pub fn erlang(&mut self, a: f64, n: f64) -> f64 {
+        unsafe { sys::gsl_ran_erlang(self.unwrap_unique(), a, n) }
+    }
+
     /// This function returns a Gaussian random variate, with mean zero and standard deviation sigma.
     /// The probability distribution for Gaussian random variates is,
     ///
@@ -720,6 +853,13 @@ impl Rng {
         unsafe { sys::gsl_ran_poisson(self.unwrap_unique(), mu) }
     }
 
+    /// This function fills `out` with Poisson variates of mean mu. All the values share the same
+    /// mean, which lets the underlying generator reuse its setup between draws.
+    #[doc(alias = "gsl_ran_poisson_array")]
+    pub fn poisson_fill(&mut self, mu: f64, out: &mut [u32]) {
+        unsafe { sys::gsl_ran_poisson_array(self.unwrap_unique(), out.len(), out.as_mut_ptr(), mu) }
+    }
+
     /// This function returns a random variate from the Rayleigh distribution with scale parameter sigma. The distribution is,
     ///
     /// p(x) dx = {x \over \sigma^2} \exp(- x^2/(2 \sigma^2)) dx
@@ -798,6 +938,55 @@ impl Rng {
         unsafe { sys::gsl_ran_dir_nd(self.unwrap_unique(), x.len() as _, x.as_mut_ptr()) }
     }
 
+    /// This function generates a random vector from the multivariate Gaussian distribution with
+    /// mean `mu` and variance-covariance matrix `Sigma = L L^T`, where `L` is the lower triangular
+    /// Cholesky factor of `Sigma`. The result is stored in `result`, which must have the same
+    /// length as `mu`.
+    #[cfg(feature = "v2_2")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    #[doc(alias = "gsl_ran_multivariate_gaussian")]
+    pub fn multivariate_gaussian(
+        &mut self,
+        mu: &::VectorF64,
+        L: &::MatrixF64,
+        result: &mut ::VectorF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_ran_multivariate_gaussian(
+                self.unwrap_unique(),
+                mu.unwrap_shared(),
+                L.unwrap_shared(),
+                result.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, (), "gsl_ran_multivariate_gaussian")
+    }
+
+    /// This function generates a random `p x p` matrix from the Wishart distribution with `df`
+    /// degrees of freedom and scale matrix `V = L L^T`, where `L` is the lower triangular Cholesky
+    /// factor of `V`. `work` is a `p x p` workspace.
+    #[cfg(feature = "v2_5")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+    #[doc(alias = "gsl_ran_wishart")]
+    pub fn wishart(
+        &mut self,
+        df: f64,
+        L: &::MatrixF64,
+        result: &mut ::MatrixF64,
+        work: &mut ::MatrixF64,
+    ) -> Result<(), Error> {
+        let ret = unsafe {
+            sys::gsl_ran_wishart(
+                self.unwrap_unique(),
+                df,
+                L.unwrap_shared(),
+                result.unwrap_unique(),
+                work.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, (), "gsl_ran_wishart")
+    }
+
     /// This function returns a random variate from the t-distribution. The distribution function is,
     ///
     /// p(x) dx = {Gamma((\nu + 1)/2) \over \sqrt{\pi \nu} Gamma(\nu/2)}
@@ -821,6 +1010,94 @@ impl Rng {
     }
 }
 
+// Bulk variants of the samplers above. The generator pointer is fetched once and every value is
+// drawn directly through the C function, which saves a call per value compared to looping over
+// the single-value methods.
+macro_rules! rng_fill {
+    ($($(#[$attr:meta])* $name:ident => $sys:ident($($arg:ident: $arg_ty:ty),*) -> $ty:ty;)*) => {
+        impl Rng {
+            $(
+                $(#[$attr])*
+                pub fn $name(&mut self, $($arg: $arg_ty,)* out: &mut [$ty]) {
+                    let r = self.unwrap_unique();
+                    for x in out.iter_mut() {
+                        *x = unsafe { sys::$sys(r, $($arg),*) };
+                    }
+                }
+            )*
+        }
+    };
+}
+
+rng_fill! {
+    /// Fills `out` with values from [`uniform`](#method.uniform).
+    #[doc(alias = "gsl_rng_uniform")]
+    uniform_fill => gsl_rng_uniform() -> f64;
+    /// Fills `out` with values from [`uniform_pos`](#method.uniform_pos).
+    #[doc(alias = "gsl_rng_uniform_pos")]
+    uniform_pos_fill => gsl_rng_uniform_pos() -> f64;
+    /// Fills `out` with values from [`bernoulli`](#method.bernoulli).
+    #[doc(alias = "gsl_ran_bernoulli")]
+    bernoulli_fill => gsl_ran_bernoulli(p: f64) -> u32;
+    /// Fills `out` with values from [`beta`](#method.beta).
+    #[doc(alias = "gsl_ran_beta")]
+    beta_fill => gsl_ran_beta(a: f64, b: f64) -> f64;
+    /// Fills `out` with values from [`binomial`](#method.binomial).
+    #[doc(alias = "gsl_ran_binomial")]
+    binomial_fill => gsl_ran_binomial(p: f64, n: u32) -> u32;
+    /// Fills `out` with values from [`cauchy`](#method.cauchy).
+    #[doc(alias = "gsl_ran_cauchy")]
+    cauchy_fill => gsl_ran_cauchy(a: f64) -> f64;
+    /// Fills `out` with values from [`chisq`](#method.chisq).
+    #[doc(alias = "gsl_ran_chisq")]
+    chisq_fill => gsl_ran_chisq(nu: f64) -> f64;
+    /// Fills `out` with values from [`exponential`](#method.exponential).
+    #[doc(alias = "gsl_ran_exponential")]
+    exponential_fill => gsl_ran_exponential(mu: f64) -> f64;
+    /// Fills `out` with values from [`fdist`](#method.fdist).
+    #[doc(alias = "gsl_ran_fdist")]
+    fdist_fill => gsl_ran_fdist(nu1: f64, nu2: f64) -> f64;
+    /// Fills `out` with values from [`flat`](#method.flat).
+    #[doc(alias = "gsl_ran_flat")]
+    flat_fill => gsl_ran_flat(a: f64, b: f64) -> f64;
+    /// Fills `out` with values from [`gamma`](#method.gamma).
+    #[doc(alias = "gsl_ran_gamma")]
+    gamma_fill => gsl_ran_gamma(a: f64, b: f64) -> f64;
+    /// Fills `out` with values from [`gaussian`](#method.gaussian).
+    #[doc(alias = "gsl_ran_gaussian")]
+    gaussian_fill => gsl_ran_gaussian(sigma: f64) -> f64;
+    /// Fills `out` with values from [`gaussian_ziggurat`](#method.gaussian_ziggurat).
+    #[doc(alias = "gsl_ran_gaussian_ziggurat")]
+    gaussian_ziggurat_fill => gsl_ran_gaussian_ziggurat(sigma: f64) -> f64;
+    /// Fills `out` with values from [`geometric`](#method.geometric).
+    #[doc(alias = "gsl_ran_geometric")]
+    geometric_fill => gsl_ran_geometric(p: f64) -> u32;
+    /// Fills `out` with values from [`laplace`](#method.laplace).
+    #[doc(alias = "gsl_ran_laplace")]
+    laplace_fill => gsl_ran_laplace(a: f64) -> f64;
+    /// Fills `out` with values from [`logistic`](#method.logistic).
+    #[doc(alias = "gsl_ran_logistic")]
+    logistic_fill => gsl_ran_logistic(a: f64) -> f64;
+    /// Fills `out` with values from [`lognormal`](#method.lognormal).
+    #[doc(alias = "gsl_ran_lognormal")]
+    lognormal_fill => gsl_ran_lognormal(zeta: f64, sigma: f64) -> f64;
+    /// Fills `out` with values from [`pareto`](#method.pareto).
+    #[doc(alias = "gsl_ran_pareto")]
+    pareto_fill => gsl_ran_pareto(a: f64, b: f64) -> f64;
+    /// Fills `out` with values from [`rayleigh`](#method.rayleigh).
+    #[doc(alias = "gsl_ran_rayleigh")]
+    rayleigh_fill => gsl_ran_rayleigh(sigma: f64) -> f64;
+    /// Fills `out` with values from [`tdist`](#method.tdist).
+    #[doc(alias = "gsl_ran_tdist")]
+    tdist_fill => gsl_ran_tdist(nu: f64) -> f64;
+    /// Fills `out` with values from [`ugaussian`](#method.ugaussian).
+    #[doc(alias = "gsl_ran_ugaussian")]
+    ugaussian_fill => gsl_ran_ugaussian() -> f64;
+    /// Fills `out` with values from [`weibull`](#method.weibull).
+    #[doc(alias = "gsl_ran_weibull")]
+    weibull_fill => gsl_ran_weibull(a: f64, b: f64) -> f64;
+}
+
 impl Clone for Rng {
     /// This function returns a pointer to a newly created generator which is an exact copy of the generator r.
     #[doc(alias = "gsl_rng_clone")]
@@ -829,8 +1106,118 @@ impl Clone for Rng {
     }
 }
 
+/// Infinite iterator over values drawn from a generator, returned by [`Rng::iter_with`],
+/// [`Rng::iter_uniform`] and [`Rng::iter_uniform_int`].
+pub struct RngIter<'a, F> {
+    rng: &'a mut Rng,
+    f: F,
+}
+
+impl<'a, T, F: FnMut(&mut Rng) -> T> Iterator for RngIter<'a, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        Some((self.f)(self.rng))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// Ranges which can be passed to [`Rng::gen_range`].
+///
+/// * `Range<f64>` values are drawn with [`Rng::flat`].
+/// * `Range<usize>` values are drawn with [`Rng::uniform_int`], so the length of the range must
+///   not be greater than the range of the generator (see [`Rng::min`] and [`Rng::max`]).
+///
+/// In both cases, the range must not be empty.
+pub trait SampleRange {
+    type Item;
+
+    fn sample(self, r: &mut Rng) -> Self::Item;
+}
+
+impl SampleRange for Range<f64> {
+    type Item = f64;
+
+    fn sample(self, r: &mut Rng) -> f64 {
+        assert!(self.start < self.end, "empty range");
+        r.flat(self.start, self.end)
+    }
+}
+
+impl SampleRange for Range<usize> {
+    type Item = usize;
+
+    fn sample(self, r: &mut Rng) -> usize {
+        assert!(self.start < self.end, "empty range");
+        self.start + r.uniform_int(self.end - self.start)
+    }
+}
+
+/// Makes the GSL generators usable with the crates of the `rand` ecosystem.
+///
+/// As GSL generators don't all return 32 random bits (see [`Rng::min`] and [`Rng::max`]), the
+/// output of the generators which don't is combined from several calls to
+/// [`Rng::uniform_int`].
+#[cfg(feature = "rand")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "rand")))]
+impl rand_core::RngCore for Rng {
+    fn next_u32(&mut self) -> u32 {
+        let range = (self.max() - self.min()) as u64;
+        if range >= u32::MAX as u64 {
+            return (self.get() - self.min()) as u32;
+        }
+        // Number of random bits returned by each call.
+        let bits = 63 - (range + 1).leading_zeros();
+        let mut x = 0u32;
+        let mut filled = 0;
+        while filled < 32 {
+            x = (x << bits) | self.uniform_int(1 << bits) as u32;
+            filled += bits;
+        }
+        x
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Creates a generator of the default type (see [`RngType::default`]), seeded with the seed
+/// (read as a little-endian integer) passed to [`Rng::set`].
+#[cfg(feature = "rand")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "rand")))]
+impl rand_core::SeedableRng for Rng {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: [u8; 8]) -> Rng {
+        Self::seed_from_u64(u64::from_le_bytes(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> Rng {
+        let mut rng = Rng::new(RngType::default()).expect("gsl_rng_alloc failed");
+        rng.set(state as usize);
+        rng
+    }
+}
+
 ffi_wrapper!(RngType, *const sys::gsl_rng_type);
 
+// The generator types are immutable statics.
+unsafe impl Send for RngType {}
+unsafe impl Sync for RngType {}
+
 impl RngType {
     /// wrapper for name element
     pub fn name(&self) -> String {
@@ -1475,3 +1862,77 @@ pub mod other {
         ffi_wrap!(gsl_rng_coveyou)
     }
 }
+
+#[cfg(feature = "rand")]
+#[test]
+fn rand_core_full_range() {
+    use rand_core::{RngCore, SeedableRng};
+
+    // ranlux only returns 24 bits per call.
+    let mut r = Rng::new(algorithms::ranlux()).unwrap();
+    let mut high = 0;
+    for _ in 0..100 {
+        high |= r.next_u32() >> 24;
+    }
+    assert_eq!(high, 0xff);
+
+    let mut a = Rng::seed_from_u64(12);
+    let mut b = Rng::from_seed(12u64.to_le_bytes());
+    assert_eq!(a.next_u64(), b.next_u64());
+}
+
+#[test]
+fn fill_matches_single_draws() {
+    let mut a = Rng::new(RngType::default()).unwrap();
+    let mut b = a.clone();
+    let mut out = [0.; 16];
+    a.gaussian_fill(2., &mut out);
+    for x in out.iter() {
+        assert_eq!(*x, b.gaussian(2.));
+    }
+
+    let mut counts = [0; 8];
+    a.binomial_fill(0.5, 10, &mut counts);
+    assert!(counts.iter().all(|&k| k <= 10));
+    a.poisson_fill(3., &mut counts);
+    assert!(a.erlang(1., 3.) > 0.);
+}
+
+#[test]
+fn choose_and_sample() {
+    let mut r = Rng::new(RngType::default()).unwrap();
+    let src = [1, 2, 3, 4, 5];
+
+    let mut dest = [0; 3];
+    r.choose(&src, &mut dest).unwrap();
+    assert!(dest.windows(2).all(|w| w[0] < w[1]));
+
+    // Sampling is done with replacement so the output can be longer than the input.
+    let mut dest = [0; 8];
+    r.sample(&src, &mut dest);
+    assert!(dest.iter().all(|x| src.contains(x)));
+
+    let mut names = vec!["a".to_owned(), "b".to_owned(), "c".to_owned()];
+    r.shuffle(&mut names);
+    names.sort();
+    assert_eq!(names, ["a", "b", "c"]);
+}
+
+#[test]
+fn iterators_ranges_and_state() {
+    let mut a = Rng::new(RngType::default()).unwrap();
+    let checkpoint = a.state_bytes();
+    assert_eq!(checkpoint.len(), a.size());
+
+    let xs: Vec<f64> = a.iter_uniform().take(5).collect();
+    assert!(xs.iter().all(|&x| (0. ..1.).contains(&x)));
+    assert!(a.iter_uniform_int(3).take(20).all(|i| i < 3));
+    assert!((0..20).all(|_| (5..8).contains(&a.gen_range(5..8))));
+    assert!((0..20).all(|_| (-2. ..-1.).contains(&a.gen_range(-2. ..-1.))));
+
+    let mut b = Rng::new(RngType::default()).unwrap();
+    b.set(7);
+    b.set_state_bytes(&checkpoint).unwrap();
+    assert_eq!(b.iter_uniform().take(5).collect::<Vec<_>>(), xs);
+    assert!(b.set_state_bytes(&checkpoint[1..]).is_err());
+}
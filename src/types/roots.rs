@@ -41,11 +41,40 @@ solvers is held in a gsl_root_fdfsolver struct. The updates require both the fun
 its derivative (hence the name fdf) to be supplied by the user.
 !*/
 
-use ffi::FFI;
-use std::os::raw::{c_double, c_void};
+use crate::{ConvergenceStatus, Error, Value};
+use ffi::{Function, FunctionFdf, FFI};
+use std::fmt;
+
+/// The error returned by [`RootFSolver::solve`] and [`RootFdfSolver::solve`], with the state of
+/// the solver when it stopped.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RootSolveError {
+    /// Why the iteration stopped: [`Value::MaxIteration`] if the root wasn't found within the
+    /// allowed number of iterations.
+    pub error: Error,
+    /// The number of iterations done.
+    pub iterations: usize,
+    /// The last estimate of the root.
+    pub root: f64,
+    /// The last bracketing interval `(x_lower, x_upper)`. Only the bracketing solvers have one.
+    pub interval: Option<(f64, f64)>,
+}
+
+impl fmt::Display for RootSolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} after {} iterations (root estimate: {}",
+            self.error, self.iterations, self.root
+        )?;
+        if let Some((x_lower, x_upper)) = self.interval {
+            write!(f, ", interval: [{}, {}]", x_lower, x_upper)?;
+        }
+        write!(f, ")")
+    }
+}
 
-use std::boxed::Box;
-use std::mem::transmute;
+impl ::std::error::Error for RootSolveError {}
 
 ffi_wrapper!(
     RootFSolverType,
@@ -60,6 +89,9 @@ Note that a bracketing algorithm cannot find roots of even degree, since these d
 cross the x-axis."
 );
 
+unsafe impl Send for RootFSolverType {}
+unsafe impl Sync for RootFSolverType {}
+
 impl RootFSolverType {
     /// The bisection algorithm is the simplest method of bracketing the roots of a function.
     /// It is the slowest algorithm provided by the library, with linear convergence.
@@ -110,18 +142,19 @@ impl RootFSolverType {
 }
 
 ffi_wrapper!(
-    RootFSolver,
+    RootFSolver<'a>,
     *mut sys::gsl_root_fsolver,
-    gsl_root_fsolver_free
+    gsl_root_fsolver_free,
+    Function<'a>
 );
 
-impl RootFSolver {
+impl<'a> RootFSolver<'a> {
     /// This function returns a pointer to a newly allocated instance of a solver of type T.
     ///
     /// If there is insufficient memory to create the solver then the function returns a null
     /// pointer and the error handler is invoked with an error code of `Value::NoMemory`.
     #[doc(alias = "gsl_root_fsolver_alloc")]
-    pub fn new(t: &RootFSolverType) -> Option<RootFSolver> {
+    pub fn new(t: &RootFSolverType) -> Option<RootFSolver<'a>> {
         let tmp = unsafe { sys::gsl_root_fsolver_alloc(t.unwrap_shared()) };
 
         if tmp.is_null() {
@@ -133,31 +166,22 @@ impl RootFSolver {
 
     /// This function initializes, or reinitializes, an existing solver s to use the function f and
     /// the initial search interval [x lower, x upper].
+    ///
+    /// The solver keeps `f` until it is reinitialized or dropped.
     #[doc(alias = "gsl_root_fsolver_set")]
-    pub fn set<F: Fn(f64) -> f64>(&mut self, f: F, x_lower: f64, x_upper: f64) -> ::Value {
-        unsafe extern "C" fn inner<F: Fn(f64) -> f64>(
-            x: c_double,
-            params: *mut c_void,
-        ) -> c_double {
-            let params: &F = &*(params as *const F);
-            params(x)
-        }
-        ::Value::from(unsafe {
-            let f: Box<F> = Box::new(f);
-            let params = Box::into_raw(f);
-
-            let mut func = sys::gsl_function {
-                function: Some(transmute::<
-                    _,
-                    unsafe extern "C" fn(c_double, *mut c_void) -> c_double,
-                >(inner::<F> as *const ())),
-                params: params as *mut _,
-            };
-            let r = sys::gsl_root_fsolver_set(self.unwrap_unique(), &mut func, x_lower, x_upper);
-            // We free the closure now that we're done using it.
-            Box::from_raw(params);
-            r
-        })
+    pub fn set<F: FnMut(f64) -> f64 + 'a>(
+        &mut self,
+        f: F,
+        x_lower: f64,
+        x_upper: f64,
+    ) -> Result<(), Error> {
+        let mut function = Function::new(f);
+        let ret = unsafe {
+            sys::gsl_root_fsolver_set(self.inner, function.as_mut_ptr(), x_lower, x_upper)
+        };
+        // GSL keeps a pointer to it, and the previous one (if any) isn't used anymore.
+        self.function = Some(function);
+        result_handler!(ret, (), "gsl_root_fsolver_set")
     }
 
     /// The following function drives the iteration of each algorithm. Each function performs one
@@ -171,8 +195,9 @@ impl RootFSolver {
     /// The solver maintains a current best estimate of the root at all times. The bracketing
     /// solvers also keep track of the current best interval bounding the root.
     #[doc(alias = "gsl_root_fsolver_iterate")]
-    pub fn iterate(&mut self) -> ::Value {
-        ::Value::from(unsafe { sys::gsl_root_fsolver_iterate(self.unwrap_unique()) })
+    pub fn iterate(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_root_fsolver_iterate(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_root_fsolver_iterate")
     }
 
     /// Returns the solver type name.
@@ -202,6 +227,50 @@ impl RootFSolver {
     pub fn x_upper(&self) -> f64 {
         unsafe { sys::gsl_root_fsolver_x_upper(self.unwrap_shared()) }
     }
+
+    /// Iterates the solver until the current bracketing interval satisfies
+    /// [`test_interval`](crate::roots::test_interval) with `epsabs` and `epsrel`, and returns the
+    /// root. It fails if an iteration fails or if the root isn't found after `max_iter`
+    /// iterations.
+    ///
+    /// ```
+    /// use rgsl::{RootFSolver, RootFSolverType};
+    ///
+    /// let mut s = RootFSolver::new(&RootFSolverType::brent()).unwrap();
+    /// s.set(|x| x * x - 5., 0., 5.).unwrap();
+    /// let root = s.solve(100, 0., 1e-9).unwrap();
+    /// assert!((root - 5f64.sqrt()).abs() < 1e-8);
+    /// ```
+    pub fn solve(
+        &mut self,
+        max_iter: usize,
+        epsabs: f64,
+        epsrel: f64,
+    ) -> Result<f64, RootSolveError> {
+        for iter in 1..=max_iter {
+            let status = self.iterate().and_then(|()| {
+                ::roots::test_interval(self.x_lower(), self.x_upper(), epsabs, epsrel)
+            });
+            match status {
+                Ok(ConvergenceStatus::Converged) => return Ok(self.root()),
+                Ok(ConvergenceStatus::Continue) => {}
+                Err(error) => return Err(self.solve_error(error, iter)),
+            }
+        }
+        Err(self.solve_error(
+            Error::new(Value::MaxIteration, "gsl_root_fsolver_iterate"),
+            max_iter,
+        ))
+    }
+
+    fn solve_error(&self, error: Error, iterations: usize) -> RootSolveError {
+        RootSolveError {
+            error,
+            iterations,
+            root: self.root(),
+            interval: Some((self.x_lower(), self.x_upper())),
+        }
+    }
 }
 
 ffi_wrapper!(
@@ -215,6 +284,9 @@ for it to work. When these conditions are satisfied then convergence is quadrati
 These algorithms make use of both the function and its derivative."
 );
 
+unsafe impl Send for RootFdfSolverType {}
+unsafe impl Sync for RootFdfSolverType {}
+
 impl RootFdfSolverType {
     /// Newton’s Method is the standard root-polishing algorithm. The algorithm begins
     /// with an initial guess for the location of the root. On each iteration, a line tangent to
@@ -238,19 +310,20 @@ impl RootFdfSolverType {
 }
 
 ffi_wrapper!(
-    RootFdfSolver,
+    RootFdfSolver<'a>,
     *mut sys::gsl_root_fdfsolver,
-    gsl_root_fdfsolver_free
+    gsl_root_fdfsolver_free,
+    FunctionFdf<'a>
 );
 
-impl RootFdfSolver {
+impl<'a> RootFdfSolver<'a> {
     /// This function returns a pointer to a newly allocated instance of a derivative-based
     /// solver of type T.
     ///
     /// If there is insufficient memory to create the solver then the function returns a null
     /// pointer and the error handler is invoked with an error code of `Value::NoMemory`.
     #[doc(alias = "gsl_root_fdfsolver_alloc")]
-    pub fn new(t: &RootFdfSolverType) -> Option<RootFdfSolver> {
+    pub fn new(t: &RootFdfSolverType) -> Option<RootFdfSolver<'a>> {
         let tmp = unsafe { sys::gsl_root_fdfsolver_alloc(t.unwrap_shared()) };
 
         if tmp.is_null() {
@@ -262,78 +335,84 @@ impl RootFdfSolver {
 
     /// This function initializes, or reinitializes, an existing solver s to use the function and
     /// derivative fdf and the initial guess root.
+    ///
+    /// The solver keeps the functions until it is reinitialized or dropped. See
+    /// [`set_fdf`](Self::set_fdf) to give a single closure computing both the function and its
+    /// derivative, and [`set_numerical_derivative`](Self::set_numerical_derivative) when there's
+    /// no analytic derivative.
     #[doc(alias = "gsl_root_fdfsolver_set")]
-    pub fn set<F: Fn(f64) -> f64, DF: Fn(f64) -> f64, FDF: Fn(f64, &mut f64, &mut f64)>(
+    pub fn set<
+        F: FnMut(f64) -> f64 + 'a,
+        DF: FnMut(f64) -> f64 + 'a,
+        FDF: FnMut(f64, &mut f64, &mut f64) + 'a,
+    >(
         &mut self,
         f: F,
         df: DF,
         fdf: FDF,
         root: f64,
-    ) -> ::Value {
-        unsafe extern "C" fn inner_f<F: Fn(f64) -> f64>(
-            x: c_double,
-            params: *mut c_void,
-        ) -> c_double {
-            let params: &(*const F, *const (), *const ()) =
-                &*(params as *const (*const F, *const (), *const ()));
-            let f = &*params.0;
-            f(x)
-        }
-        unsafe extern "C" fn inner_df<DF: Fn(f64) -> f64>(
-            x: c_double,
-            params: *mut c_void,
-        ) -> c_double {
-            let params: &(*const (), *const DF, *const ()) =
-                &*(params as *const (*const (), *const DF, *const ()));
-            let df = &*params.1;
-            df(x)
-        }
-        unsafe extern "C" fn inner_fdf<FDF: Fn(f64, &mut f64, &mut f64)>(
-            x: c_double,
-            params: *mut c_void,
-            y: *mut c_double,
-            dy: *mut c_double,
-        ) {
-            let params: &(*const (), *const (), *const FDF) =
-                &*(params as *const (*const (), *const (), *const FDF));
-            let fdf = &*params.2;
-            fdf(x, &mut *y, &mut *dy);
-        }
+    ) -> Result<(), Error> {
+        self.set_function(FunctionFdf::new(f, df, fdf), root)
+    }
 
-        ::Value::from(unsafe {
-            let f: Box<F> = Box::new(f);
-            let f = Box::into_raw(f);
-            let df: Box<DF> = Box::new(df);
-            let df = Box::into_raw(df);
-            let fdf: Box<FDF> = Box::new(fdf);
-            let fdf = Box::into_raw(fdf);
-
-            let params = Box::new((f, df, fdf));
-            let params = Box::into_raw(params);
-
-            let mut func = sys::gsl_function_fdf {
-                f: Some(transmute::<
-                    _,
-                    unsafe extern "C" fn(c_double, *mut c_void) -> c_double,
-                >(inner_f::<F> as *const ())),
-                df: Some(transmute::<
-                    _,
-                    unsafe extern "C" fn(c_double, *mut c_void) -> c_double,
-                >(inner_df::<DF> as *const ())),
-                fdf: Some(transmute::<
-                    _,
-                    unsafe extern "C" fn(c_double, *mut c_void, *mut c_double, *mut c_double),
-                >(inner_fdf::<FDF> as *const ())),
-                params: params as *mut _,
-            };
-            let r = sys::gsl_root_fdfsolver_set(self.unwrap_unique(), &mut func, root);
-            // We free the closure now that we're done using it.
-            let tmp = Box::from_raw(params);
-            Box::from_raw(tmp.0);
-            Box::from_raw(tmp.1);
-            Box::from_raw(tmp.2);
-            r
-        })
+    /// Same as [`set`](Self::set), but with a single closure returning both `f(x)` and its
+    /// derivative `f'(x)`, so the two can share their captured state and intermediate results.
+    /// It's also called when the solver only needs one of the two values.
+    ///
+    /// ```
+    /// use rgsl::{RootFdfSolver, RootFdfSolverType};
+    ///
+    /// let mut calls = 0;
+    /// let mut s = RootFdfSolver::new(&RootFdfSolverType::newton()).unwrap();
+    /// s.set_fdf(
+    ///     |x| {
+    ///         calls += 1;
+    ///         (x * x - 5., 2. * x)
+    ///     },
+    ///     5.,
+    /// )
+    /// .unwrap();
+    /// let root = s.solve(100, 0., 1e-9).unwrap();
+    /// assert!((root - 5f64.sqrt()).abs() < 1e-8);
+    /// drop(s);
+    /// assert!(calls > 0);
+    /// ```
+    #[doc(alias = "gsl_root_fdfsolver_set")]
+    pub fn set_fdf<FDF: FnMut(f64) -> (f64, f64) + 'a>(
+        &mut self,
+        fdf: FDF,
+        root: f64,
+    ) -> Result<(), Error> {
+        self.set_function(FunctionFdf::from_fdf(fdf), root)
+    }
+
+    /// Same as [`set`](Self::set), for functions without an analytic derivative: it's computed by
+    /// central differences with
+    /// [`numerical_differentiation::derivative`](crate::numerical_differentiation::derivative).
+    ///
+    /// ```
+    /// use rgsl::{RootFdfSolver, RootFdfSolverType};
+    ///
+    /// let mut s = RootFdfSolver::new(&RootFdfSolverType::steffenson()).unwrap();
+    /// s.set_numerical_derivative(|x| x.cos() - x, 1.).unwrap();
+    /// let root = s.solve(100, 0., 1e-10).unwrap();
+    /// assert!((root.cos() - root).abs() < 1e-9);
+    /// ```
+    #[doc(alias = "gsl_root_fdfsolver_set")]
+    #[doc(alias = "gsl_deriv_central")]
+    pub fn set_numerical_derivative<F: FnMut(f64) -> f64 + 'a>(
+        &mut self,
+        f: F,
+        root: f64,
+    ) -> Result<(), Error> {
+        self.set_function(FunctionFdf::with_numerical_derivative(f), root)
+    }
+
+    fn set_function(&mut self, mut function: FunctionFdf<'a>, root: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_root_fdfsolver_set(self.inner, function.as_mut_ptr(), root) };
+        // GSL keeps a pointer to them, and the previous ones (if any) aren't used anymore.
+        self.function = Some(function);
+        result_handler!(ret, (), "gsl_root_fdfsolver_set")
     }
 
     /// The following function drives the iteration of each algorithm. Each function performs one
@@ -347,8 +426,9 @@ impl RootFdfSolver {
     /// The solver maintains a current best estimate of the root at all times. The bracketing
     /// solvers also keep track of the current best interval bounding the root.
     #[doc(alias = "gsl_root_fdfsolver_iterate")]
-    pub fn iterate(&mut self) -> ::Value {
-        ::Value::from(unsafe { sys::gsl_root_fdfsolver_iterate(self.unwrap_unique()) })
+    pub fn iterate(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_root_fdfsolver_iterate(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_root_fdfsolver_iterate")
     }
 
     /// Returns the solver type name.
@@ -376,4 +456,130 @@ impl RootFdfSolver {
     pub fn root(&self) -> f64 {
         unsafe { sys::gsl_root_fdfsolver_root(self.unwrap_shared()) }
     }
+
+    /// Iterates the solver until two successive estimates of the root satisfy
+    /// [`test_delta`](crate::roots::test_delta) with `epsabs` and `epsrel`, and returns the root.
+    /// It fails if an iteration fails or if the root isn't found after `max_iter` iterations.
+    pub fn solve(
+        &mut self,
+        max_iter: usize,
+        epsabs: f64,
+        epsrel: f64,
+    ) -> Result<f64, RootSolveError> {
+        let mut x = self.root();
+        for iter in 1..=max_iter {
+            let x0 = x;
+            let status = self.iterate().and_then(|()| {
+                x = self.root();
+                ::roots::test_delta(x, x0, epsabs, epsrel)
+            });
+            match status {
+                Ok(ConvergenceStatus::Converged) => return Ok(x),
+                Ok(ConvergenceStatus::Continue) => {}
+                Err(error) => return Err(self.solve_error(error, iter)),
+            }
+        }
+        Err(self.solve_error(
+            Error::new(Value::MaxIteration, "gsl_root_fdfsolver_iterate"),
+            max_iter,
+        ))
+    }
+
+    fn solve_error(&self, error: Error, iterations: usize) -> RootSolveError {
+        RootSolveError {
+            error,
+            iterations,
+            root: self.root(),
+            interval: None,
+        }
+    }
+}
+
+#[test]
+fn root_fsolver_keeps_closure() {
+    let mut solver = RootFSolver::new(&RootFSolverType::brent()).unwrap();
+    // The closure owns its data: it would be freed if the solver didn't keep it.
+    let coeffs = Box::new([-5., 0., 1.]);
+    solver
+        .set(
+            move |x| coeffs[0] + coeffs[1] * x + coeffs[2] * x * x,
+            0.,
+            5.,
+        )
+        .unwrap();
+
+    let mut iter = 0;
+    loop {
+        iter += 1;
+        solver.iterate().unwrap();
+        let status = ::roots::test_interval(solver.x_lower(), solver.x_upper(), 0., 1e-10);
+        if status == Ok(ConvergenceStatus::Converged) {
+            break;
+        }
+        assert_eq!(status, Ok(ConvergenceStatus::Continue));
+        assert!(iter < 100, "didn't converge");
+    }
+    assert!((solver.root() - 5f64.sqrt()).abs() < 1e-9);
+
+    // Reinitializing replaces the closure.
+    solver.set(|x| x - 1., 0., 5.).unwrap();
+    assert!((solver.solve(100, 0., 1e-10).unwrap() - 1.).abs() < 1e-9);
+}
+
+#[test]
+fn root_fdfsolver_keeps_closures() {
+    let mut solver = RootFdfSolver::new(&RootFdfSolverType::newton()).unwrap();
+    let a = Box::new(2.);
+    let b = a.clone();
+    solver
+        .set(
+            move |x| x * x - *a,
+            move |x| 2. * x,
+            move |x, y, dy| {
+                *y = x * x - *b;
+                *dy = 2. * x;
+            },
+            5.,
+        )
+        .unwrap();
+
+    let mut x = solver.root();
+    let mut iter = 0;
+    loop {
+        iter += 1;
+        solver.iterate().unwrap();
+        let x0 = x;
+        x = solver.root();
+        if ::roots::test_delta(x, x0, 0., 1e-10)
+            .unwrap()
+            .is_converged()
+        {
+            break;
+        }
+        assert!(iter < 100, "didn't converge");
+    }
+    assert!((x - 2f64.sqrt()).abs() < 1e-9);
+}
+
+#[test]
+fn root_fdfsolver_single_closure() {
+    let mut solver = RootFdfSolver::new(&RootFdfSolverType::newton()).unwrap();
+    let mut evaluations = 0;
+    solver
+        .set_fdf(
+            |x| {
+                evaluations += 1;
+                (x * x * x - 8., 3. * x * x)
+            },
+            5.,
+        )
+        .unwrap();
+    assert!((solver.solve(100, 0., 1e-12).unwrap() - 2.).abs() < 1e-10);
+
+    solver
+        .set_numerical_derivative(|x| x.exp() - 2., 0.)
+        .unwrap();
+    assert!((solver.solve(100, 0., 1e-12).unwrap() - 2f64.ln()).abs() < 1e-10);
+    drop(solver);
+    assert!(evaluations > 1);
 }
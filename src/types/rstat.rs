@@ -2,7 +2,33 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::Value;
+/*!
+# Running Statistics
+
+This chapter describes routines for computing running statistics, also known as online
+statistics, of data. These routines are suitable for handling large datasets for which it may be
+inconvenient or impractical to store in memory all at once. The data can be processed in a
+single pass, one point at a time. Each time a data point is added to the accumulator, internal
+parameters are updated in order to compute the current mean, variance, standard deviation,
+skewness, and kurtosis. These statistics are exact, and are updated with numerically stable
+single-pass algorithms. The median and arbitrary quantiles are also available, however these
+calculations use algorithms which provide approximations, and grow more accurate as more data is
+added to the accumulator.
+
+```
+use rgsl::RStatWorkspace;
+
+let mut rstat = RStatWorkspace::new().unwrap();
+for &x in &[17.2, 18.1, 16.5, 18.3, 12.6] {
+    rstat.add(x);
+}
+assert_eq!(rstat.n(), 5);
+assert_eq!(rstat.max(), 18.3);
+println!("mean = {}, sd = {}", rstat.mean(), rstat.sd());
+```
+!*/
+
+use crate::Error;
 use ffi::FFI;
 
 ffi_wrapper!(
@@ -11,7 +37,11 @@ ffi_wrapper!(
     gsl_rstat_quantile_free
 );
 
+unsafe impl Send for RStatQuantileWorkspace {}
+
 impl RStatQuantileWorkspace {
+    /// This function allocates a workspace for the dynamic estimation of the `p`-quantile, where
+    /// `p` is between 0 and 1. The median corresponds to `p` = 0.5.
     #[doc(alias = "gsl_rstat_quantile_alloc")]
     pub fn new(p: f64) -> Option<Self> {
         let s = unsafe { sys::gsl_rstat_quantile_alloc(p) };
@@ -22,18 +52,24 @@ impl RStatQuantileWorkspace {
         }
     }
 
+    /// This function resets the workspace to its initial state, so it can begin working on a new
+    /// set of data.
     #[cfg(feature = "v2_2")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     #[doc(alias = "gsl_rstat_quantile_reset")]
-    pub fn reset(&mut self) -> Value {
-        Value::from(unsafe { sys::gsl_rstat_quantile_reset(self.unwrap_unique()) })
+    pub fn reset(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_rstat_quantile_reset(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_rstat_quantile_reset")
     }
 
+    /// This function updates the estimate of the p-quantile with the new data point `x`.
     #[doc(alias = "gsl_rstat_quantile_add")]
-    pub fn add(&mut self, x: f64) -> Value {
-        Value::from(unsafe { sys::gsl_rstat_quantile_add(x, self.unwrap_unique()) })
+    pub fn add(&mut self, x: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_rstat_quantile_add(x, self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_rstat_quantile_add")
     }
 
+    /// This function returns the current estimate of the p-quantile.
     #[doc(alias = "gsl_rstat_quantile_get")]
     pub fn get(&mut self) -> f64 {
         unsafe { sys::gsl_rstat_quantile_get(self.unwrap_unique()) }
@@ -46,7 +82,11 @@ ffi_wrapper!(
     gsl_rstat_free
 );
 
+unsafe impl Send for RStatWorkspace {}
+
 impl RStatWorkspace {
+    /// This function allocates a workspace for computing running statistics. The size of the
+    /// workspace is O(1).
     #[doc(alias = "gsl_rstat_alloc")]
     pub fn new() -> Option<Self> {
         let s = unsafe { sys::gsl_rstat_alloc() };
@@ -57,41 +97,52 @@ impl RStatWorkspace {
         }
     }
 
+    /// This function returns the number of data so far added to the accumulator.
     #[doc(alias = "gsl_rstat_n")]
     pub fn n(&self) -> usize {
         unsafe { sys::gsl_rstat_n(self.unwrap_shared()) }
     }
 
+    /// This function adds the data point `x` to the statistical accumulator, updating calculations
+    /// of the mean, variance, skewness, kurtosis, and median.
     #[doc(alias = "gsl_rstat_add")]
-    pub fn add(&mut self, x: f64) -> Value {
-        Value::from(unsafe { sys::gsl_rstat_add(x, self.unwrap_unique()) })
+    pub fn add(&mut self, x: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_rstat_add(x, self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_rstat_add")
     }
 
+    /// This function returns the minimum value added to the accumulator.
     #[doc(alias = "gsl_rstat_min")]
     pub fn min(&self) -> f64 {
         unsafe { sys::gsl_rstat_min(self.unwrap_shared()) }
     }
 
+    /// This function returns the maximum value added to the accumulator.
     #[doc(alias = "gsl_rstat_max")]
     pub fn max(&self) -> f64 {
         unsafe { sys::gsl_rstat_max(self.unwrap_shared()) }
     }
 
+    /// This function returns the mean of all data added to the accumulator.
     #[doc(alias = "gsl_rstat_mean")]
     pub fn mean(&self) -> f64 {
         unsafe { sys::gsl_rstat_mean(self.unwrap_shared()) }
     }
 
+    /// This function returns the variance of all data added to the accumulator.
     #[doc(alias = "gsl_rstat_variance")]
     pub fn variance(&self) -> f64 {
         unsafe { sys::gsl_rstat_variance(self.unwrap_shared()) }
     }
 
+    /// This function returns the standard deviation of all data added to the accumulator, defined
+    /// as the square root of the variance.
     #[doc(alias = "gsl_rstat_sd")]
     pub fn sd(&self) -> f64 {
         unsafe { sys::gsl_rstat_sd(self.unwrap_shared()) }
     }
 
+    /// This function returns the root mean square of all data added to the accumulator.
     #[cfg(feature = "v2_2")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
     #[doc(alias = "gsl_rstat_rms")]
@@ -99,28 +150,36 @@ impl RStatWorkspace {
         unsafe { sys::gsl_rstat_rms(self.unwrap_shared()) }
     }
 
+    /// This function returns the standard deviation of the mean, defined as sd / sqrt(n).
     #[doc(alias = "gsl_rstat_sd_mean")]
     pub fn sd_mean(&self) -> f64 {
         unsafe { sys::gsl_rstat_sd_mean(self.unwrap_shared()) }
     }
 
+    /// This function returns an estimate of the median of the data added to the accumulator, using
+    /// the P^2 algorithm.
     #[doc(alias = "gsl_rstat_median")]
     pub fn median(&mut self) -> f64 {
         unsafe { sys::gsl_rstat_median(self.unwrap_unique()) }
     }
 
+    /// This function returns the skewness of all data added to the accumulator.
     #[doc(alias = "gsl_rstat_skew")]
     pub fn skew(&self) -> f64 {
         unsafe { sys::gsl_rstat_skew(self.unwrap_shared()) }
     }
 
+    /// This function returns the kurtosis of all data added to the accumulator.
     #[doc(alias = "gsl_rstat_kurtosis")]
     pub fn kurtosis(&self) -> f64 {
         unsafe { sys::gsl_rstat_kurtosis(self.unwrap_shared()) }
     }
 
+    /// This function resets the workspace to its initial state, so it can begin working on a new
+    /// set of data.
     #[doc(alias = "gsl_rstat_reset")]
-    pub fn reset(&mut self) -> Value {
-        Value::from(unsafe { sys::gsl_rstat_reset(self.unwrap_unique()) })
+    pub fn reset(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_rstat_reset(self.unwrap_unique()) };
+        result_handler!(ret, (), "gsl_rstat_reset")
     }
 }
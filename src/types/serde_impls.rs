@@ -0,0 +1,225 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+// `serde` support for the vectors, matrices, histograms and random number generators.
+//
+// They are (de)serialized through plain data structs:
+//
+// * vectors as the sequence of their elements,
+// * matrices as `{ size1, size2, data }`, `data` being the elements in row-major order,
+// * histograms as `{ range, bin }` and 2D histograms as `{ xrange, yrange, bin }`,
+// * generators as `{ name, state }`, `state` being the bytes written by `gsl_rng_fwrite`.
+
+use crate::{Histogram, Histogram2D, Rng, RngType};
+use ffi::FFI;
+use serde::de::Error as DeError;
+use serde::ser::Error as SerError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::slice;
+
+macro_rules! serde_vector {
+    ($rust_name:ident, $rust_ty:ident) => {
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "serde")))]
+        impl Serialize for ::$rust_name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq((0..self.len()).map(|i| self.get(i)))
+            }
+        }
+
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "serde")))]
+        impl<'de> Deserialize<'de> for ::$rust_name {
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<::$rust_name, D::Error> {
+                let data = Vec::<$rust_ty>::deserialize(deserializer)?;
+                ::$rust_name::from_slice(&data)
+                    .ok_or_else(|| D::Error::custom("vector allocation failed"))
+            }
+        }
+    };
+}
+
+#[derive(Serialize, Deserialize)]
+struct MatrixData<T> {
+    size1: usize,
+    size2: usize,
+    data: Vec<T>,
+}
+
+macro_rules! serde_matrix {
+    ($rust_name:ident, $rust_ty:ident) => {
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "serde")))]
+        impl Serialize for ::$rust_name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let (size1, size2) = (self.size1(), self.size2());
+                let mut data = Vec::with_capacity(size1 * size2);
+                for i in 0..size1 {
+                    for j in 0..size2 {
+                        data.push(self.get(i, j));
+                    }
+                }
+                MatrixData { size1, size2, data }.serialize(serializer)
+            }
+        }
+
+        #[cfg_attr(feature = "dox", doc(cfg(feature = "serde")))]
+        impl<'de> Deserialize<'de> for ::$rust_name {
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<::$rust_name, D::Error> {
+                let m = MatrixData::<$rust_ty>::deserialize(deserializer)?;
+                if m.data.len() != m.size1 * m.size2 {
+                    return Err(D::Error::invalid_length(
+                        m.data.len(),
+                        &"size1 * size2 matrix elements",
+                    ));
+                }
+                let mut matrix = ::$rust_name::new(m.size1, m.size2)
+                    .ok_or_else(|| D::Error::custom("matrix allocation failed"))?;
+                for (k, x) in m.data.into_iter().enumerate() {
+                    matrix.set(k / m.size2, k % m.size2, x);
+                }
+                Ok(matrix)
+            }
+        }
+    };
+}
+
+serde_vector!(VectorF32, f32);
+serde_vector!(VectorF64, f64);
+serde_vector!(VectorI32, i32);
+serde_vector!(VectorU32, u32);
+serde_matrix!(MatrixF32, f32);
+serde_matrix!(MatrixF64, f64);
+serde_matrix!(MatrixI32, i32);
+serde_matrix!(MatrixU32, u32);
+
+#[derive(Serialize, Deserialize)]
+struct HistogramData {
+    range: Vec<f64>,
+    bin: Vec<f64>,
+}
+
+#[cfg_attr(feature = "dox", doc(cfg(feature = "serde")))]
+impl Serialize for Histogram {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let h = unsafe { &*self.unwrap_shared() };
+        HistogramData {
+            range: unsafe { slice::from_raw_parts(h.range, h.n + 1) }.to_vec(),
+            bin: unsafe { slice::from_raw_parts(h.bin, h.n) }.to_vec(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg_attr(feature = "dox", doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Histogram {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Histogram, D::Error> {
+        let data = HistogramData::deserialize(deserializer)?;
+        if data.range.len() != data.bin.len() + 1 {
+            return Err(D::Error::invalid_length(
+                data.range.len(),
+                &"one more range than bins",
+            ));
+        }
+        let mut h = Histogram::new(data.bin.len())
+            .ok_or_else(|| D::Error::custom("histogram allocation failed"))?;
+        h.set_ranges(&data.range).map_err(D::Error::custom)?;
+        unsafe {
+            let raw = &mut *h.unwrap_unique();
+            slice::from_raw_parts_mut(raw.bin, raw.n).copy_from_slice(&data.bin);
+        }
+        Ok(h)
+    }
+}
+
+// `gsl_histogram2d` is opaque in the bindings.
+#[repr(C)]
+struct RawHistogram2D {
+    nx: usize,
+    ny: usize,
+    xrange: *mut f64,
+    yrange: *mut f64,
+    bin: *mut f64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Histogram2DData {
+    xrange: Vec<f64>,
+    yrange: Vec<f64>,
+    bin: Vec<f64>,
+}
+
+#[cfg_attr(feature = "dox", doc(cfg(feature = "serde")))]
+impl Serialize for Histogram2D {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let h = unsafe { &*(self.unwrap_shared() as *const RawHistogram2D) };
+        Histogram2DData {
+            xrange: unsafe { slice::from_raw_parts(h.xrange, h.nx + 1) }.to_vec(),
+            yrange: unsafe { slice::from_raw_parts(h.yrange, h.ny + 1) }.to_vec(),
+            bin: unsafe { slice::from_raw_parts(h.bin, h.nx * h.ny) }.to_vec(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg_attr(feature = "dox", doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Histogram2D {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Histogram2D, D::Error> {
+        let data = Histogram2DData::deserialize(deserializer)?;
+        let nx = data.xrange.len().saturating_sub(1);
+        let ny = data.yrange.len().saturating_sub(1);
+        if data.bin.len() != nx * ny {
+            return Err(D::Error::invalid_length(data.bin.len(), &"nx * ny bins"));
+        }
+        let mut h = Histogram2D::new(nx, ny)
+            .ok_or_else(|| D::Error::custom("histogram allocation failed"))?;
+        h.set_ranges(&data.xrange, &data.yrange)
+            .map_err(D::Error::custom)?;
+        unsafe {
+            let raw = &mut *(h.unwrap_unique() as *mut RawHistogram2D);
+            slice::from_raw_parts_mut(raw.bin, nx * ny).copy_from_slice(&data.bin);
+        }
+        Ok(h)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct RngData {
+    name: String,
+    state: Vec<u8>,
+}
+
+/// Only the generators provided by GSL (listed by [`RngType::types_setup`]) can be deserialized.
+/// The state is in the machine representation, like the one of `gsl_rng_fwrite`, so it can
+/// only be read back on the same architecture.
+#[cfg_attr(feature = "dox", doc(cfg(feature = "serde")))]
+impl Serialize for Rng {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if unsafe { sys::gsl_rng_state(self.unwrap_shared()) }.is_null() {
+            return Err(S::Error::custom("generator without state"));
+        }
+        RngData {
+            name: self.get_name(),
+            state: self.state_bytes(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg_attr(feature = "dox", doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Rng {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Rng, D::Error> {
+        let data = RngData::deserialize(deserializer)?;
+        let t = RngType::types_setup()
+            .into_iter()
+            .find(|t| t.name() == data.name)
+            .ok_or_else(|| D::Error::custom(format!("unknown generator `{}`", data.name)))?;
+        let mut rng = Rng::new(t).ok_or_else(|| D::Error::custom("generator allocation failed"))?;
+        rng.set_state_bytes(&data.state).map_err(|_| {
+            D::Error::invalid_length(data.state.len(), &"the state size of the generator")
+        })?;
+        Ok(rng)
+    }
+}
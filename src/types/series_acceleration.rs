@@ -49,7 +49,7 @@ A review paper on the Levin Transform is available online,
 Herbert H. H. Homeier, Scalar Levin-Type Sequence Transformations, http://arxiv.org/abs/math/0005209.
 !*/
 
-use crate::Value;
+use crate::{Error, Value};
 use ffi::FFI;
 
 ffi_wrapper!(
@@ -59,6 +59,8 @@ ffi_wrapper!(
     "Workspace for Levin U Transform with error estimation."
 );
 
+unsafe impl Send for LevinUWorkspace {}
+
 impl LevinUWorkspace {
     /// This function allocates a workspace for a Levin u-transform of n terms. The size of the workspace is O(2n^2 + 3n).
     #[doc(alias = "gsl_sum_levin_u_alloc")]
@@ -78,11 +80,17 @@ impl LevinUWorkspace {
     /// truncation error (the difference between two successive extrapolations) and round-off error (propagated from the individual terms)
     /// to choose an optimal number of terms for the extrapolation. All the terms of the series passed in through array should be non-zero.
     ///
-    /// Returns `(Value, sum_accel, abserr)`.
+    /// It returns [`Value::BadLength`] if there are more terms than the size of the workspace.
+    ///
+    /// Returns `(sum_accel, abserr)`.
     #[doc(alias = "gsl_sum_levin_u_accel")]
-    pub fn accel(&mut self, array: &[f64]) -> (Value, f64, f64) {
+    pub fn accel(&mut self, array: &[f64]) -> Result<(f64, f64), Error> {
         let mut sum_accel = 0.;
         let mut abserr = 0.;
+        // GSL doesn't check it and would write past the workspace.
+        if array.len() > self.size() {
+            return Err(Error::new(Value::BadLength, "gsl_sum_levin_u_accel"));
+        }
         let ret = unsafe {
             sys::gsl_sum_levin_u_accel(
                 array.as_ptr(),
@@ -92,17 +100,20 @@ impl LevinUWorkspace {
                 &mut abserr,
             )
         };
-        (Value::from(ret), sum_accel, abserr)
+        result_handler!(ret, (sum_accel, abserr), "gsl_sum_levin_u_accel")
     }
 
+    /// Returns the term-by-term sum of the series given to the last [`accel`](Self::accel) call.
     pub fn sum_plain(&self) -> f64 {
         unsafe { (*self.unwrap_shared()).sum_plain }
     }
 
+    /// Returns the number of terms used by the last [`accel`](Self::accel) call.
     pub fn terms_used(&self) -> usize {
         unsafe { (*self.unwrap_shared()).terms_used }
     }
 
+    /// Returns the maximum number of terms the workspace can handle.
     pub fn size(&self) -> usize {
         unsafe { (*self.unwrap_shared()).size }
     }
@@ -113,6 +124,8 @@ ffi_wrapper!(LevinUTruncWorkspace, *mut sys::gsl_sum_levin_utrunc_workspace, gsl
 `O(N)` storage instead of `O(N^2)`. This may be useful for summing many similar series where the
 size of the error has already been estimated reliably and is not expected to change.");
 
+unsafe impl Send for LevinUTruncWorkspace {}
+
 impl LevinUTruncWorkspace {
     /// This function allocates a workspace for a Levin u-transform of n terms, without error estimation. The size of the workspace is O(3n).
     #[doc(alias = "gsl_sum_levin_utrunc_alloc")]
@@ -133,11 +146,17 @@ impl LevinUTruncWorkspace {
     /// in abserr_trunc. To improve the reliability of the algorithm the extrapolated values are replaced by moving averages when
     /// calculating the truncation error, smoothing out any fluctuations.
     ///
-    /// Returns `(Value, sum_accel, abserr_trunc)`.
+    /// It returns [`Value::BadLength`] if there are more terms than the size of the workspace.
+    ///
+    /// Returns `(sum_accel, abserr_trunc)`.
     #[doc(alias = "gsl_sum_levin_utrunc_accel")]
-    pub fn accel(&mut self, array: &[f64]) -> (Value, f64, f64) {
+    pub fn accel(&mut self, array: &[f64]) -> Result<(f64, f64), Error> {
         let mut sum_accel = 0.;
         let mut abserr_trunc = 0.;
+        // GSL doesn't check it and would write past the workspace.
+        if array.len() > self.size() {
+            return Err(Error::new(Value::BadLength, "gsl_sum_levin_utrunc_accel"));
+        }
         let ret = unsafe {
             sys::gsl_sum_levin_utrunc_accel(
                 array.as_ptr(),
@@ -147,18 +166,36 @@ impl LevinUTruncWorkspace {
                 &mut abserr_trunc,
             )
         };
-        (Value::from(ret), sum_accel, abserr_trunc)
+        result_handler!(ret, (sum_accel, abserr_trunc), "gsl_sum_levin_utrunc_accel")
     }
 
+    /// Returns the term-by-term sum of the series given to the last [`accel`](Self::accel) call.
     pub fn sum_plain(&self) -> f64 {
         unsafe { (*self.unwrap_shared()).sum_plain }
     }
 
+    /// Returns the number of terms used by the last [`accel`](Self::accel) call.
     pub fn terms_used(&self) -> usize {
         unsafe { (*self.unwrap_shared()).terms_used }
     }
 
+    /// Returns the maximum number of terms the workspace can handle.
     pub fn size(&self) -> usize {
         unsafe { (*self.unwrap_shared()).size }
     }
 }
+
+#[test]
+fn levin_u_zeta_2() {
+    let terms: Vec<f64> = (1..=20).map(|n| 1. / (n * n) as f64).collect();
+    let zeta_2 = ::std::f64::consts::PI.powi(2) / 6.;
+
+    let mut w = LevinUWorkspace::new(20).unwrap();
+    let (sum, abserr) = w.accel(&terms).unwrap();
+    assert!((sum - zeta_2).abs() < 1e-8 && abserr < 1e-6);
+    assert!((zeta_2 - w.sum_plain()) > 0.04);
+    assert!(w.terms_used() <= 20);
+
+    let mut w = LevinUTruncWorkspace::new(10).unwrap();
+    assert!(w.accel(&terms).is_err());
+}
@@ -35,6 +35,9 @@ The slight probability of taking a step that gives higher energy is what allows
 annealing to frequently get out of local minima.
 !*/
 
+use ffi::FFI;
+use std::os::raw::{c_int, c_void};
+
 const GSL_LOG_DBL_MIN: f64 = -7.0839641853226408e+02;
 
 pub struct SimAnnealing<T: Clone> {
@@ -232,7 +235,7 @@ where
     /// and the output of the function print position itself.
     pub fn solve(&self, rng: &mut ::Rng) -> T {
         let mut x = self.x0_p.clone();
-        let mut new_x = self.x0_p.clone();
+        let mut new_x;
         let mut best_x = self.x0_p.clone();
 
         let mut n_evals = 0_usize;
@@ -261,7 +264,7 @@ where
             //let mut n_eless = 0;
 
             for _ in 0..self.params.iters_fixed_T {
-                x = new_x.clone();
+                new_x = x.clone();
 
                 (self.step_t)(rng, &mut new_x, self.params.step_size);
                 let new_E = (self.Efunc_t)(&new_x);
@@ -522,3 +525,148 @@ impl SimAnnealingParams {
         }
     }
 }
+
+// `gsl_siman_params_t` is opaque in the bindings.
+#[repr(C)]
+struct RawParams {
+    n_tries: c_int,
+    iters_fixed_T: c_int,
+    step_size: f64,
+    k: f64,
+    t_initial: f64,
+    mu_t: f64,
+    t_min: f64,
+}
+
+// The bindings declare `gsl_siman_solve` with the opaque `gsl_siman_params_t` passed by value, so
+// it is called through this signature instead.
+type RawSolve = unsafe extern "C" fn(
+    *const sys::gsl_rng,
+    *mut c_void,
+    sys::gsl_siman_Efunc_t,
+    sys::gsl_siman_step_t,
+    sys::gsl_siman_metric_t,
+    sys::gsl_siman_print_t,
+    sys::gsl_siman_copy_t,
+    sys::gsl_siman_copy_construct_t,
+    sys::gsl_siman_destroy_t,
+    usize,
+    RawParams,
+);
+
+/// The configurations handled by GSL: the callbacks don't get any parameter besides the
+/// configuration, so each one carries the closures.
+struct State<'a, T, E, S> {
+    x: T,
+    energy: &'a E,
+    step: &'a S,
+}
+
+unsafe extern "C" fn state_energy<T, E: Fn(&T) -> f64, S>(xp: *mut c_void) -> f64 {
+    let state = &*(xp as *const State<T, E, S>);
+    ::error::catch_callback(f64::NAN, || (state.energy)(&state.x))
+}
+
+unsafe extern "C" fn state_step<T, E, S: Fn(&mut T, &mut ::Rng, f64)>(
+    r: *const sys::gsl_rng,
+    xp: *mut c_void,
+    step_size: f64,
+) {
+    let state = &mut *(xp as *mut State<T, E, S>);
+    // The generator belongs to the caller of `solve`: `lend_mut` doesn't free it.
+    ::error::catch_callback((), || {
+        ::ffi::lend_mut(r as *mut _, |rng| {
+            (state.step)(&mut state.x, rng, step_size)
+        })
+    })
+}
+
+unsafe extern "C" fn state_copy<T: Clone, E, S>(source: *mut c_void, dest: *mut c_void) {
+    let source = &*(source as *const State<T, E, S>);
+    let dest = &mut *(dest as *mut State<T, E, S>);
+    ::error::catch_callback((), || dest.x.clone_from(&source.x));
+}
+
+unsafe extern "C" fn state_copy_construct<T: Clone, E, S>(xp: *mut c_void) -> *mut c_void {
+    let state = &*(xp as *const State<T, E, S>);
+    // GSL doesn't check the returned pointer, so there is no way to report a panic here.
+    let x = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| state.x.clone()))
+        .unwrap_or_else(|_| ::std::process::abort());
+    Box::into_raw(Box::new(State {
+        x,
+        energy: state.energy,
+        step: state.step,
+    })) as *mut _
+}
+
+unsafe extern "C" fn state_destroy<T, E, S>(xp: *mut c_void) {
+    drop(Box::from_raw(xp as *mut State<T, E, S>));
+}
+
+/// This function performs a simulated annealing search with `gsl_siman_solve`, starting from
+/// the configuration `initial`. `energy(x)` returns the energy of the configuration `x`, and
+/// `step(x, rng, step_size)` modifies `x` with a random step (of size up to `step_size`) drawn
+/// from `rng`.
+///
+/// Unlike [`SimAnnealing::solve`], which reimplements the algorithm, the configurations are
+/// handled by GSL through the copy, copy constructor and destructor callbacks, so `T` can be
+/// any `Clone` type.
+///
+/// The best configuration found during the search is returned.
+///
+/// ```
+/// use rgsl::{siman, Rng, RngType, SimAnnealingParams};
+///
+/// let mut rng = Rng::new(RngType::default()).unwrap();
+/// let params = SimAnnealingParams::new(200, 1000, 1., 1., 0.008, 1.003, 2e-6);
+/// let x = siman::solve(
+///     &mut rng,
+///     15.5,
+///     |x: &f64| (-(x - 1.).powi(2)).exp() * (8. * x).sin(),
+///     |x, rng, step_size| *x += (2. * rng.uniform() - 1.) * step_size,
+///     params,
+/// );
+/// assert!((x - 1.36).abs() < 0.01);
+/// ```
+#[doc(alias = "gsl_siman_solve")]
+pub fn solve<T: Clone, E: Fn(&T) -> f64, S: Fn(&mut T, &mut ::Rng, f64)>(
+    rng: &mut ::Rng,
+    initial: T,
+    energy: E,
+    step: S,
+    params: SimAnnealingParams,
+) -> T {
+    let mut x0 = Box::new(State {
+        x: initial,
+        energy: &energy,
+        step: &step,
+    });
+    let params = RawParams {
+        n_tries: params.n_tries as _,
+        iters_fixed_T: params.iters_fixed_T as _,
+        step_size: params.step_size,
+        k: params.k,
+        t_initial: params.t_initial,
+        mu_t: params.mu_t,
+        t_min: params.t_min,
+    };
+    unsafe {
+        let raw_solve: RawSolve = ::std::mem::transmute(sys::gsl_siman_solve as *const ());
+        // GSL copies the best configuration back into `x0`.
+        raw_solve(
+            rng.unwrap_unique(),
+            &mut *x0 as *mut State<T, E, S> as *mut _,
+            Some(state_energy::<T, E, S>),
+            Some(state_step::<T, E, S>),
+            None,
+            None,
+            Some(state_copy::<T, E, S>),
+            Some(state_copy_construct::<T, E, S>),
+            Some(state_destroy::<T, E, S>),
+            0,
+            params,
+        );
+    }
+    ::error::resume_callback_panic();
+    x0.x
+}
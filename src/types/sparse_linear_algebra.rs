@@ -0,0 +1,158 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Sparse Linear Algebra
+
+This chapter describes functions for solving sparse linear systems A x = b with iterative
+methods. They don't modify `A` and only need it to compute matrix-vector products, which makes
+them well suited to large sparse systems.
+
+GSL currently provides the GMRES (Generalized Minimum Residual) method, through the
+[`SpIterSolver`] workspace. The matrix must be in compressed column format
+([`SparseMatrix::to_ccs`](crate::SparseMatrix::to_ccs)).
+
+```
+use rgsl::{SparseMatrix, SpIterSolver, SpIterSolverType, VectorF64};
+
+// A tridiagonal system.
+let n = 10;
+let mut t = SparseMatrix::new(n, n).unwrap();
+for i in 0..n {
+    t.set(i, i, 4.).unwrap();
+    if i > 0 {
+        t.set(i, i - 1, -1.).unwrap();
+    }
+    if i + 1 < n {
+        t.set(i, i + 1, -1.).unwrap();
+    }
+}
+let a = t.to_ccs().unwrap();
+let b = VectorF64::from_slice(&[1.; 10]).unwrap();
+let mut x = VectorF64::new(n).unwrap();
+
+let mut solver = SpIterSolver::new(SpIterSolverType::gmres(), n, 0).unwrap();
+let iterations = solver.solve(&a, &b, 1e-8, &mut x, 100).unwrap();
+println!("converged after {} iterations, |r| = {}", iterations, solver.normr());
+```
+!*/
+
+use crate::{ConvergenceStatus, Error, Value};
+use ffi::FFI;
+
+ffi_wrapper!(
+    SpIterSolverType,
+    *const sys::gsl_splinalg_itersolve_type,
+    "The type of a sparse iterative linear solver."
+);
+
+unsafe impl Send for SpIterSolverType {}
+unsafe impl Sync for SpIterSolverType {}
+
+impl SpIterSolverType {
+    /// This specifies the Generalized Minimum Residual Method (GMRES). This is a projection
+    /// method using K = K_m and L = A K_m where K_m is the m-th Krylov subspace. It can be used
+    /// on any nonsingular square matrix.
+    #[doc(alias = "gsl_splinalg_itersolve_gmres")]
+    pub fn gmres() -> SpIterSolverType {
+        ffi_wrap!(gsl_splinalg_itersolve_gmres)
+    }
+}
+
+ffi_wrapper!(
+    SpIterSolver,
+    *mut sys::gsl_splinalg_itersolve,
+    gsl_splinalg_itersolve_free
+);
+
+unsafe impl Send for SpIterSolver {}
+
+impl SpIterSolver {
+    /// This function allocates a workspace for the iterative solution of `n`-by-`n` sparse
+    /// matrix systems. The iterative solver type is specified by `t`. The argument `m` specifies
+    /// the size of the solution candidate subspace K_m. The dimension `m` may be set to 0 in
+    /// which case a reasonable default value is chosen.
+    #[doc(alias = "gsl_splinalg_itersolve_alloc")]
+    pub fn new(t: SpIterSolverType, n: usize, m: usize) -> Option<SpIterSolver> {
+        let tmp = unsafe { sys::gsl_splinalg_itersolve_alloc(t.unwrap_shared(), n, m) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function returns the name of the solver.
+    #[doc(alias = "gsl_splinalg_itersolve_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_splinalg_itersolve_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// This function performs one iteration of the iterative method for the sparse matrix
+    /// system A x = b. On input, `x` must be set to an initial guess for the solution. On
+    /// output, `x` is updated to give the current solution estimate. The parameter `tol`
+    /// specifies the relative tolerance between the residual norm and norm of `b` in order to
+    /// check for convergence. When the following condition is satisfied:
+    ///
+    /// || A x - b || <= tol * || b ||
+    ///
+    /// the method has converged and [`ConvergenceStatus::Converged`] is returned. Otherwise
+    /// [`ConvergenceStatus::Continue`] is returned.
+    #[doc(alias = "gsl_splinalg_itersolve_iterate")]
+    pub fn iterate(
+        &mut self,
+        A: &::SparseMatrix,
+        b: &::VectorF64,
+        tol: f64,
+        x: &mut ::VectorF64,
+    ) -> Result<ConvergenceStatus, Error> {
+        let ret = unsafe {
+            sys::gsl_splinalg_itersolve_iterate(
+                A.unwrap_shared(),
+                b.unwrap_shared(),
+                tol,
+                x.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        };
+        ConvergenceStatus::from_test(ret, "gsl_splinalg_itersolve_iterate")
+    }
+
+    /// Calls [`iterate`](Self::iterate) until the method converges, at most `max_iter` times.
+    /// `x` must be set to an initial guess for the solution and contains the solution on
+    /// output.
+    ///
+    /// Returns the number of iterations done. A `Value::MaxIteration` error is returned if the
+    /// method didn't converge after `max_iter` iterations.
+    pub fn solve(
+        &mut self,
+        A: &::SparseMatrix,
+        b: &::VectorF64,
+        tol: f64,
+        x: &mut ::VectorF64,
+        max_iter: usize,
+    ) -> Result<usize, Error> {
+        for iter in 1..=max_iter {
+            if self.iterate(A, b, tol, x)?.is_converged() {
+                return Ok(iter);
+            }
+        }
+        Err(Error::new(
+            Value::MaxIteration,
+            "gsl_splinalg_itersolve_iterate",
+        ))
+    }
+
+    /// This function returns the current residual norm ||r|| = ||A x - b||, which is updated
+    /// after each call to [`iterate`](Self::iterate).
+    #[doc(alias = "gsl_splinalg_itersolve_normr")]
+    pub fn normr(&self) -> f64 {
+        unsafe { sys::gsl_splinalg_itersolve_normr(self.unwrap_shared()) }
+    }
+}
@@ -0,0 +1,275 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Sparse Matrices
+
+This chapter describes functions for the construction and manipulation of sparse matrices,
+matrices which are populated primarily with zeros and contain only a few non-zero elements.
+Sparse matrices often appear in the solution of partial differential equations. It is
+beneficial to use specialized data structures and algorithms for storing and working with
+sparse matrices, since dense matrix algorithms and structures can be prohibitively slow and use
+huge amounts of memory when applied to sparse matrices.
+
+A sparse matrix is first built in the [`Triplet`](crate::SparseFormat::Triplet) format, where
+elements are added one by one with [`SparseMatrix::set`]. It can then be compressed into the
+column ([`SparseMatrix::to_ccs`]) or row ([`SparseMatrix::to_crs`]) formats, which are required by
+most of the operations (like the sparse BLAS functions in [`spblas`](crate::spblas) or the
+iterative solvers in [`sparse_linear_algebra`](crate::types::sparse_linear_algebra)).
+
+```
+use rgsl::{MatrixF64, SparseMatrix};
+
+let mut a = SparseMatrix::new(3, 3).unwrap();
+a.set(0, 0, 2.).unwrap();
+a.set(1, 2, -1.).unwrap();
+assert_eq!(a.nnz(), 2);
+
+let dense = a.to_dense().unwrap();
+assert_eq!(dense.get(1, 2), -1.);
+assert_eq!(SparseMatrix::from_dense(&dense).unwrap(), a);
+```
+!*/
+
+use crate::{Error, SparseFormat, Value};
+use ffi::FFI;
+
+// `gsl_spmatrix` is opaque in the bindings, but its dimensions are its first fields in all the
+// GSL versions.
+#[repr(C)]
+struct SparseMatrixHeader {
+    size1: usize,
+    size2: usize,
+}
+
+/// A sparse matrix of `f64`.
+pub struct SparseMatrix {
+    inner: *mut sys::gsl_spmatrix,
+    // Where the storage type is stored in `gsl_spmatrix` changed between GSL versions, so it is
+    // kept here instead.
+    format: SparseFormat,
+}
+
+unsafe impl Send for SparseMatrix {}
+
+impl Drop for SparseMatrix {
+    #[doc(alias = "gsl_spmatrix_free")]
+    fn drop(&mut self) {
+        unsafe { sys::gsl_spmatrix_free(self.inner) };
+        self.inner = ::std::ptr::null_mut();
+    }
+}
+
+impl SparseMatrix {
+    fn wrap(inner: *mut sys::gsl_spmatrix, format: SparseFormat) -> Option<SparseMatrix> {
+        if inner.is_null() {
+            None
+        } else {
+            Some(SparseMatrix { inner, format })
+        }
+    }
+
+    pub(crate) fn with_format(
+        n1: usize,
+        n2: usize,
+        nzmax: usize,
+        format: SparseFormat,
+    ) -> Option<SparseMatrix> {
+        // GSL doesn't accept a `nzmax` of 0.
+        let tmp = unsafe { sys::gsl_spmatrix_alloc_nzmax(n1, n2, nzmax.max(1), format.into()) };
+        Self::wrap(tmp, format)
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_shared(&self) -> *const sys::gsl_spmatrix {
+        self.inner
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_unique(&mut self) -> *mut sys::gsl_spmatrix {
+        self.inner
+    }
+
+    /// This function allocates a sparse matrix of size `n1`-by-`n2` in the triplet format. All
+    /// its elements are zero.
+    #[doc(alias = "gsl_spmatrix_alloc")]
+    pub fn new(n1: usize, n2: usize) -> Option<SparseMatrix> {
+        Self::wrap(
+            unsafe { sys::gsl_spmatrix_alloc(n1, n2) },
+            SparseFormat::Triplet,
+        )
+    }
+
+    /// This function allocates a sparse matrix of size `n1`-by-`n2` in the triplet format, with
+    /// enough room for `nzmax` non-zero elements. The matrix grows if more elements are set.
+    #[doc(alias = "gsl_spmatrix_alloc_nzmax")]
+    pub fn with_capacity(n1: usize, n2: usize, nzmax: usize) -> Option<SparseMatrix> {
+        Self::with_format(n1, n2, nzmax, SparseFormat::Triplet)
+    }
+
+    /// Creates a sparse matrix in the triplet format containing the non-zero elements of `dense`.
+    #[doc(alias = "gsl_spmatrix_d2sp")]
+    pub fn from_dense(dense: &::MatrixF64) -> Result<SparseMatrix, Error> {
+        let mut m = Self::new(dense.size1(), dense.size2())
+            .ok_or_else(|| Error::new(Value::NoMemory, "gsl_spmatrix_alloc"))?;
+        let ret = unsafe { sys::gsl_spmatrix_d2sp(m.unwrap_unique(), dense.unwrap_shared()) };
+        result_handler!(ret, m, "gsl_spmatrix_d2sp")
+    }
+
+    /// Returns a dense copy of the matrix.
+    #[doc(alias = "gsl_spmatrix_sp2d")]
+    pub fn to_dense(&self) -> Result<::MatrixF64, Error> {
+        let mut dense = ::MatrixF64::new(self.size1(), self.size2())
+            .ok_or_else(|| Error::new(Value::NoMemory, "gsl_matrix_calloc"))?;
+        let ret = unsafe { sys::gsl_spmatrix_sp2d(dense.unwrap_unique(), self.inner) };
+        result_handler!(ret, dense, "gsl_spmatrix_sp2d")
+    }
+
+    /// Returns the number of rows of the matrix.
+    pub fn size1(&self) -> usize {
+        unsafe { (*(self.inner as *const SparseMatrixHeader)).size1 }
+    }
+
+    /// Returns the number of columns of the matrix.
+    pub fn size2(&self) -> usize {
+        unsafe { (*(self.inner as *const SparseMatrixHeader)).size2 }
+    }
+
+    /// Returns the storage format of the matrix.
+    pub fn format(&self) -> SparseFormat {
+        self.format
+    }
+
+    /// This function returns the number of non-zero elements in the matrix.
+    #[doc(alias = "gsl_spmatrix_nnz")]
+    pub fn nnz(&self) -> usize {
+        unsafe { sys::gsl_spmatrix_nnz(self.inner) }
+    }
+
+    /// This function returns element (`i`, `j`) of the matrix, 0 if it isn't stored.
+    ///
+    /// Panics if (`i`, `j`) is outside of the matrix.
+    #[doc(alias = "gsl_spmatrix_get")]
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        assert!(i < self.size1() && j < self.size2(), "index out of bounds");
+        unsafe { sys::gsl_spmatrix_get(self.inner, i, j) }
+    }
+
+    /// This function sets element (`i`, `j`) of the matrix to `x`. If the element is already
+    /// stored, its value is replaced.
+    ///
+    /// Only matrices in the triplet format can be modified: an error is returned otherwise.
+    ///
+    /// Panics if (`i`, `j`) is outside of the matrix.
+    #[doc(alias = "gsl_spmatrix_set")]
+    pub fn set(&mut self, i: usize, j: usize, x: f64) -> Result<(), Error> {
+        assert!(i < self.size1() && j < self.size2(), "index out of bounds");
+        let ret = unsafe { sys::gsl_spmatrix_set(self.inner, i, j, x) };
+        result_handler!(ret, (), "gsl_spmatrix_set")
+    }
+
+    /// This function sets (or resets) all the elements of the matrix to zero.
+    #[doc(alias = "gsl_spmatrix_set_zero")]
+    pub fn set_zero(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_spmatrix_set_zero(self.inner) };
+        result_handler!(ret, (), "gsl_spmatrix_set_zero")
+    }
+
+    /// This function scales all the elements of the matrix by `x`.
+    #[doc(alias = "gsl_spmatrix_scale")]
+    pub fn scale(&mut self, x: f64) -> Result<(), Error> {
+        let ret = unsafe { sys::gsl_spmatrix_scale(self.inner, x) };
+        result_handler!(ret, (), "gsl_spmatrix_scale")
+    }
+
+    /// This function returns the minimum and maximum elements of the matrix, as `(min, max)`.
+    /// Only the stored elements are considered.
+    #[doc(alias = "gsl_spmatrix_minmax")]
+    pub fn minmax(&self) -> Result<(f64, f64), Error> {
+        let mut min = 0.;
+        let mut max = 0.;
+        let ret = unsafe { sys::gsl_spmatrix_minmax(self.inner, &mut min, &mut max) };
+        result_handler!(ret, (min, max), "gsl_spmatrix_minmax")
+    }
+
+    /// Returns a copy of the matrix, which must be in the triplet format, compressed in the
+    /// column format.
+    #[doc(alias = "gsl_spmatrix_compcol")]
+    #[doc(alias = "gsl_spmatrix_ccs")]
+    pub fn to_ccs(&self) -> Option<SparseMatrix> {
+        Self::wrap(
+            unsafe { sys::gsl_spmatrix_compcol(self.inner) },
+            SparseFormat::CompressedColumn,
+        )
+    }
+
+    /// Returns a copy of the matrix, which must be in the triplet format, compressed in the row
+    /// format.
+    #[cfg(feature = "v2_2")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    #[doc(alias = "gsl_spmatrix_crs")]
+    pub fn to_crs(&self) -> Option<SparseMatrix> {
+        Self::wrap(
+            unsafe { sys::gsl_spmatrix_crs(self.inner) },
+            SparseFormat::CompressedRow,
+        )
+    }
+
+    /// Returns the transpose of the matrix, in the same format.
+    #[doc(alias = "gsl_spmatrix_transpose_memcpy")]
+    pub fn transpose(&self) -> Result<SparseMatrix, Error> {
+        let mut t = Self::with_format(self.size2(), self.size1(), self.nnz(), self.format)
+            .ok_or_else(|| Error::new(Value::NoMemory, "gsl_spmatrix_alloc_nzmax"))?;
+        let ret = unsafe { sys::gsl_spmatrix_transpose_memcpy(t.unwrap_unique(), self.inner) };
+        result_handler!(ret, t, "gsl_spmatrix_transpose_memcpy")
+    }
+
+    /// Returns the sum of `self` and `other`, which must have the same dimensions and both be in
+    /// the compressed column format.
+    #[doc(alias = "gsl_spmatrix_add")]
+    pub fn add(&self, other: &SparseMatrix) -> Result<SparseMatrix, Error> {
+        let mut c = Self::with_format(
+            self.size1(),
+            self.size2(),
+            self.nnz() + other.nnz(),
+            self.format,
+        )
+        .ok_or_else(|| Error::new(Value::NoMemory, "gsl_spmatrix_alloc_nzmax"))?;
+        let ret = unsafe { sys::gsl_spmatrix_add(c.unwrap_unique(), self.inner, other.inner) };
+        result_handler!(ret, c, "gsl_spmatrix_add")
+    }
+}
+
+impl Clone for SparseMatrix {
+    #[doc(alias = "gsl_spmatrix_memcpy")]
+    fn clone(&self) -> SparseMatrix {
+        let mut m = Self::with_format(self.size1(), self.size2(), self.nnz(), self.format)
+            .expect("gsl_spmatrix_alloc_nzmax failed");
+        let ret = unsafe { sys::gsl_spmatrix_memcpy(m.unwrap_unique(), self.inner) };
+        assert_eq!(ret, sys::GSL_SUCCESS, "gsl_spmatrix_memcpy failed");
+        m
+    }
+}
+
+impl PartialEq for SparseMatrix {
+    /// Two matrices are equal if they have the same format, dimensions and elements.
+    #[doc(alias = "gsl_spmatrix_equal")]
+    fn eq(&self, other: &SparseMatrix) -> bool {
+        self.format == other.format
+            && self.size1() == other.size1()
+            && self.size2() == other.size2()
+            && unsafe { sys::gsl_spmatrix_equal(self.inner, other.inner) } == 1
+    }
+}
+
+impl ::std::fmt::Debug for SparseMatrix {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct("SparseMatrix")
+            .field("size1", &self.size1())
+            .field("size2", &self.size2())
+            .field("nnz", &self.nnz())
+            .field("format", &self.format)
+            .finish()
+    }
+}
@@ -30,11 +30,12 @@ vector points to a block owned by another object then the owner field is zero an
 vector.
 !*/
 
-use crate::Value;
+use crate::Error;
 use ffi::FFI;
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
+use utilities::Preview;
 
 use crate::paste::paste;
 
@@ -47,6 +48,11 @@ pub struct $rust_name {
     can_free: bool,
 }
 
+// The non-owning instances (see `soft_wrap`) are only lent to closures, by shared reference or
+// through `ffi::lend_mut` which aborts if they are moved out: an instance held by value owns its
+// data.
+unsafe impl Send for $rust_name {}
+
 impl Drop for $rust_name {
     #[doc(alias = $name _free)]
     fn drop(&mut self) {
@@ -63,11 +69,30 @@ impl Debug for $rust_name {
         if ptr.is_null() {
             write!(f, "<null>")
         } else {
-            write!(f, "{:?}", self.as_slice().expect("conversion to slice failed"))
+            f.debug_struct(stringify!($rust_name))
+                .field("len", &self.len())
+                .field("data", &Preview(self.len(), |i| self.get(i)))
+                .finish()
         }
     }
 }
 
+impl Clone for $rust_name {
+    /// Returns a new vector holding a copy of the elements of `self`.
+    ///
+    /// Panics if the allocation fails: use [`try_clone`](Self::try_clone) to handle it.
+    fn clone(&self) -> Self {
+        self.try_clone().expect(concat!(stringify!($name), "_alloc failed"))
+    }
+}
+
+/// Two vectors are equal if they have the same length and the same elements.
+impl PartialEq for $rust_name {
+    fn eq(&self, other: &$rust_name) -> bool {
+        self.len() == other.len() && self.equal(other)
+    }
+}
+
 impl FFI<sys::$name> for $rust_name {
     fn wrap(vec: *mut sys::$name) -> Self {
         Self {
@@ -188,84 +213,101 @@ impl $rust_name {
     /// This function copies the elements of the other vector into the self vector. The two vectors
     /// must have the same length.
     #[doc(alias = $name _memcpy)]
-    pub fn copy_from(&mut self, other: &$rust_name) -> Value {
-        Value::from(
-            unsafe { sys::[<$name _memcpy>](
-                self.unwrap_unique(),
-                other.unwrap_shared()) })
+    pub fn copy_from(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _memcpy>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _memcpy>]))
     }
 
     /// This function copies the elements of the self vector into the other vector. The two vectors
     /// must have the same length.
     #[doc(alias = $name _memcpy)]
-    pub fn copy_to(&self, other: &mut $rust_name) -> Value {
-        Value::from(unsafe { sys::[<$name _memcpy>](other.unwrap_unique(), self.unwrap_shared()) })
+    pub fn copy_to(&self, other: &mut $rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _memcpy>](other.unwrap_unique(), self.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _memcpy>]))
     }
 
     /// This function exchanges the elements of the vectors by copying. The two vectors must have
     /// the same length.
     #[doc(alias = $name _swap)]
-    pub fn swap(&mut self, other: &mut $rust_name) -> Value {
-        Value::from(unsafe { sys::[<$name _swap>](other.unwrap_unique(), self.unwrap_unique()) })
+    pub fn swap(&mut self, other: &mut $rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _swap>](other.unwrap_unique(), self.unwrap_unique()) };
+        result_handler!(ret, (), stringify!([<$name _swap>]))
     }
 
     /// This function exchanges the i-th and j-th elements of the vector v in-place.
     #[doc(alias = $name _swap_elements)]
-    pub fn swap_elements(&mut self, i: usize, j: usize) -> Value {
-        Value::from(unsafe { sys::[<$name _swap_elements>](self.unwrap_unique(), i, j) })
+    pub fn swap_elements(&mut self, i: usize, j: usize) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _swap_elements>](self.unwrap_unique(), i, j) };
+        result_handler!(ret, (), stringify!([<$name _swap_elements>]))
     }
 
     /// This function reverses the order of the elements of the vector v.
     #[doc(alias = $name _reverse)]
-    pub fn reverse(&mut self) -> Value {
-        Value::from(unsafe { sys::[<$name _reverse>](self.unwrap_unique()) })
+    pub fn reverse(&mut self) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _reverse>](self.unwrap_unique()) };
+        result_handler!(ret, (), stringify!([<$name _reverse>]))
     }
 
     /// This function adds the elements of the other vector to the elements of the self vector.
     /// The result a_i <- a_i + b_i is stored in self and other remains unchanged. The two vectors
     /// must have the same length.
     #[doc(alias = $name _add)]
-    pub fn add(&mut self, other: &$rust_name) -> Value {
-        Value::from(unsafe { sys::[<$name _add>](self.unwrap_unique(), other.unwrap_shared()) })
+    pub fn add(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _add>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _add>]))
     }
 
     /// This function subtracts the elements of the self vector from the elements of the other
     /// vector. The result a_i <- a_i - b_i is stored in self and other remains unchanged. The two
     /// vectors must have the same length.
     #[doc(alias = $name _sub)]
-    pub fn sub(&mut self, other: &$rust_name) -> Value {
-        Value::from(unsafe { sys::[<$name _sub>](self.unwrap_unique(), other.unwrap_shared()) })
+    pub fn sub(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _sub>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _sub>]))
     }
 
     /// This function multiplies the elements of the self vector a by the elements of the other
     /// vector. The result `a_i <- a_i * b_i` is stored in self and other remains unchanged. The two
     /// vectors must have the same length.
     #[doc(alias = $name _mul)]
-    pub fn mul(&mut self, other: &$rust_name) -> Value {
-        Value::from(unsafe { sys::[<$name _mul>](self.unwrap_unique(), other.unwrap_shared()) })
+    pub fn mul(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _mul>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _mul>]))
     }
 
     /// This function divides the elements of the self vector by the elements of the other vector.
     /// The result `a_i <- a_i / b_i` is stored in self and other remains unchanged. The two vectors
     /// must have the same length.
     #[doc(alias = $name _div)]
-    pub fn div(&mut self, other: &$rust_name) -> Value {
-        Value::from(unsafe { sys::[<$name _div>](self.unwrap_unique(), other.unwrap_shared()) })
+    pub fn div(&mut self, other: &$rust_name) -> Result<(), Error> {
+        let ret = unsafe { sys::[<$name _div>](self.unwrap_unique(), other.unwrap_shared()) };
+        result_handler!(ret, (), stringify!([<$name _div>]))
     }
 
     /// This function multiplies the elements of the self vector by the constant factor x. The
-    /// result `a_i <- a_i` is stored in `self`.
+    /// result `a_i <- x a_i` is stored in `self`.
     #[doc(alias = $name _scale)]
-    pub fn scale(&mut self, x: $rust_ty) -> Value {
-        Value::from(unsafe { sys::[<$name _scale>](self.unwrap_unique(), x) })
+    pub fn scale(&mut self, x: $rust_ty) -> &mut $rust_name {
+        // It can't fail.
+        unsafe { sys::[<$name _scale>](self.unwrap_unique(), x) };
+        self
     }
 
     /// This function adds the constant value x to the elements of the self vector. The result
     /// `a_i <- a_i + x` is stored in `self`.
     #[doc(alias = $name _add_constant)]
-    pub fn add_constant(&mut self, x: f64) -> Value {
+    pub fn add_constant(&mut self, x: f64) -> &mut $rust_name {
         // Funny bug: here it expects a f64 and not a f32 for gsl_vector_float...
-        Value::from(unsafe { sys::[<$name _add_constant>](self.unwrap_unique(), x) })
+        unsafe { sys::[<$name _add_constant>](self.unwrap_unique(), x) };
+        self
+    }
+
+    /// This function returns the sum of the elements of the self vector.
+    #[cfg(feature = "v2_7")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    #[doc(alias = $name _sum)]
+    pub fn sum(&self) -> $rust_ty {
+        unsafe { sys::[<$name _sum>](self.unwrap_shared()) }
     }
 
     /// This function returns the maximum value in the self vector.
@@ -347,13 +389,15 @@ impl $rust_name {
         unsafe { sys::[<$name _equal>](self.unwrap_shared(), other.unwrap_shared()) == 1 }
     }
 
-    pub fn clone(&self) -> Option<$rust_name> {
+    /// Returns a new vector holding a copy of the elements of `self`, or `None` if the allocation
+    /// failed.
+    pub fn try_clone(&self) -> Option<$rust_name> {
         if self.unwrap_shared().is_null() {
             None
         } else {
             match $rust_name::new(self.len()) {
                 Some(mut v) => {
-                    v.copy_from(self);
+                    v.copy_from(self).ok()?;
                     Some(v)
                 }
                 None => None,
@@ -365,6 +409,111 @@ impl $rust_name {
     pub fn subvector<'a>(&'a mut self, offset: usize, n: usize) -> [<$rust_name View>]<'a> {
         [<$rust_name View>]::from_vector(self, offset, n)
     }
+
+    #[doc(alias = $name _subvector_with_stride)]
+    pub fn subvector_with_stride<'a>(
+        &'a mut self,
+        offset: usize,
+        stride: usize,
+        n: usize,
+    ) -> [<$rust_name View>]<'a> {
+        [<$rust_name View>]::from_vector_with_stride(self, offset, stride, n)
+    }
+
+    /// Same as [`subvector`](Self::subvector), but only borrows the vector so the view can't
+    /// modify it.
+    #[doc(alias = $name _const_subvector)]
+    pub fn const_subvector<'a>(&'a self, offset: usize, n: usize) -> [<$rust_name ConstView>]<'a> {
+        [<$rust_name ConstView>]::from_vector(self, offset, n)
+    }
+
+    /// Same as [`subvector_with_stride`](Self::subvector_with_stride), but only borrows the
+    /// vector so the view can't modify it.
+    #[doc(alias = $name _const_subvector_with_stride)]
+    pub fn const_subvector_with_stride<'a>(
+        &'a self,
+        offset: usize,
+        stride: usize,
+        n: usize,
+    ) -> [<$rust_name ConstView>]<'a> {
+        [<$rust_name ConstView>]::from_vector_with_stride(self, offset, stride, n)
+    }
+
+    /// Writes the elements of the vector to `writer` in the native binary format of the
+    /// architecture, so it can be read back with [`read_binary`](Self::read_binary) (or by a C
+    /// program using GSL) on the same architecture.
+    #[doc(alias = $name _fwrite)]
+    pub fn to_binary_writer<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        ::utilities::write_through_file(writer, stringify!([<$name _fwrite>]), |file| unsafe {
+            sys::[<$name _fwrite>](file, self.unwrap_shared())
+        })
+    }
+
+    /// Reads the elements of the vector from `reader`, in the format written by
+    /// [`to_binary_writer`](Self::to_binary_writer). Exactly as many bytes as the vector holds
+    /// are read.
+    #[doc(alias = $name _fread)]
+    pub fn read_binary<R: ::std::io::Read>(&mut self, reader: &mut R) -> ::std::io::Result<()> {
+        let len = self.len() * ::std::mem::size_of::<$rust_ty>();
+        ::utilities::read_exact_through_file(
+            reader,
+            len,
+            stringify!([<$name _fread>]),
+            |file| unsafe { sys::[<$name _fread>](file, self.unwrap_unique()) },
+        )
+    }
+
+    /// Writes the elements of the vector to `writer` as text, one per line, with the printf
+    /// `format` (like `"%g"`).
+    ///
+    /// Returns an error of kind [`InvalidInput`](::std::io::ErrorKind::InvalidInput) if `format`
+    /// isn't a printf format for a single element.
+    #[doc(alias = $name _fprintf)]
+    pub fn to_text_writer<W: ::std::io::Write>(
+        &self,
+        writer: &mut W,
+        format: &str,
+    ) -> ::std::io::Result<()> {
+        let format = ::utilities::printf_format(format, stringify!($rust_ty))?;
+        ::utilities::write_through_file(writer, stringify!([<$name _fprintf>]), |file| unsafe {
+            sys::[<$name _fprintf>](file, self.unwrap_shared(), format.as_ptr())
+        })
+    }
+
+    /// Reads the elements of the vector from `reader`, written as text separated by whitespace
+    /// (like [`to_text_writer`](Self::to_text_writer) does). `reader` is read until its end.
+    #[doc(alias = $name _fscanf)]
+    pub fn read_text<R: ::std::io::Read>(&mut self, reader: &mut R) -> ::std::io::Result<()> {
+        ::utilities::read_to_end_through_file(reader, stringify!([<$name _fscanf>]), |file| unsafe {
+            sys::[<$name _fscanf>](file, self.unwrap_unique())
+        })
+    }
+
+    /// Creates a vector of size `n` and reads its elements from `reader` with
+    /// [`read_binary`](Self::read_binary).
+    pub fn from_binary_reader<R: ::std::io::Read>(
+        reader: &mut R,
+        n: usize,
+    ) -> ::std::io::Result<$rust_name> {
+        let mut ret = $rust_name::new(n).ok_or_else(|| {
+            ::std::io::Error::other(::Error::new(::Value::NoMemory, stringify!([<$name _calloc>])))
+        })?;
+        ret.read_binary(reader)?;
+        Ok(ret)
+    }
+
+    /// Creates a vector of size `n` and reads its elements from `reader` with
+    /// [`read_text`](Self::read_text).
+    pub fn from_text_reader<R: ::std::io::Read>(
+        reader: &mut R,
+        n: usize,
+    ) -> ::std::io::Result<$rust_name> {
+        let mut ret = $rust_name::new(n).ok_or_else(|| {
+            ::std::io::Error::other(::Error::new(::Value::NoMemory, stringify!([<$name _calloc>])))
+        })?;
+        ret.read_text(reader)?;
+        Ok(ret)
+    }
 }
 
 pub struct [<$rust_name View>]<'a> {
@@ -532,14 +681,69 @@ impl<'a> [<$rust_name View>]<'a> {
 
     pub fn vector_mut<F: FnOnce(Option<&mut $rust_name>)>(&mut self, f: F) {
         let v = &mut self.v.vector;
-        let mut tmp = $rust_name::soft_wrap(v as *mut _);
-        if tmp.as_slice().is_none() {
+        ::ffi::lend_mut(v as *mut _, |tmp: &mut $rust_name| {
+            if tmp.as_slice().is_none() {
+                f(None)
+            } else {
+                f(Some(tmp))
+            }
+        })
+    }
+} // end of impl block
+
+/// A read-only view of a vector or of an array. It borrows its parent, so it can't outlive it.
+pub struct [<$rust_name ConstView>]<'a> {
+    v: sys::[<$name _const_view>],
+    #[allow(dead_code)]
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> [<$rust_name ConstView>]<'a> {
+    #[doc(hidden)]
+    pub(crate) fn wrap(v: sys::[<$name _const_view>]) -> Self {
+        Self {
+            v,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Same as [`from_vector`]([<$rust_name View>]::from_vector), but for a read-only view.
+    #[doc(alias = $name _const_subvector)]
+    pub fn from_vector(v: &'a $rust_name, offset: usize, n: usize) -> Self {
+        Self::wrap(unsafe { sys::[<$name _const_subvector>](v.unwrap_shared(), offset, n) })
+    }
+
+    /// Same as [`from_vector_with_stride`]([<$rust_name View>]::from_vector_with_stride), but for
+    /// a read-only view.
+    #[doc(alias = $name _const_subvector_with_stride)]
+    pub fn from_vector_with_stride(
+        v: &'a $rust_name,
+        offset: usize,
+        stride: usize,
+        n: usize,
+    ) -> Self {
+        Self::wrap(unsafe {
+            sys::[<$name _const_subvector_with_stride>](v.unwrap_shared(), offset, stride, n)
+        })
+    }
+
+    /// Same as [`from_array`]([<$rust_name View>]::from_array), but for a read-only view.
+    #[doc(alias = $name _const_view_array)]
+    pub fn from_array(base: &'a [$rust_ty]) -> Self {
+        Self::wrap(unsafe { sys::[<$name _const_view_array>](base.as_ptr(), base.len()) })
+    }
+
+    /// Gives the view as a vector to `f`, or `None` if the view is out of the bounds of its
+    /// parent.
+    pub fn vector<F: FnOnce(Option<&$rust_name>)>(&self, f: F) {
+        let v = &self.v.vector;
+        if v.data.is_null() {
             f(None)
         } else {
-            f(Some(&mut tmp))
+            f(Some(&$rust_name::soft_wrap(v as *const _ as usize as *mut _)))
         }
     }
-} // end of impl block
+}
 
 } // end of paste! block
 ); // end of gsl_vec macro
@@ -549,3 +753,56 @@ gsl_vec!(VectorF32, gsl_vector_float, f32);
 gsl_vec!(VectorF64, gsl_vector, f64);
 gsl_vec!(VectorI32, gsl_vector_int, i32);
 gsl_vec!(VectorU32, gsl_vector_uint, u32);
+
+macro_rules! float_vec {
+    ($rust_name:ident, $rust_ty:ident, $nrm2:ident, $asum:ident, $nrm2_c:expr, $asum_c:expr) => {
+        impl $rust_name {
+            /// This function returns the Euclidean norm ||x||_2 = \sqrt {\sum x_i^2} of the
+            /// vector.
+            #[doc(alias = $nrm2_c)]
+            pub fn norm2(&self) -> $rust_ty {
+                ::blas::level1::$nrm2(self)
+            }
+
+            /// This function returns the absolute sum \sum |x_i| of the elements of the vector.
+            #[doc(alias = $asum_c)]
+            pub fn asum(&self) -> $rust_ty {
+                ::blas::level1::$asum(self)
+            }
+
+            /// Returns `true` if `self` and `other` have the same length and all their elements
+            /// differ by at most `tol`.
+            pub fn approx_eq(&self, other: &$rust_name, tol: $rust_ty) -> bool {
+                self.len() == other.len()
+                    && (0..self.len()).all(|i| (self.get(i) - other.get(i)).abs() <= tol)
+            }
+        }
+    };
+}
+
+float_vec!(
+    VectorF32,
+    f32,
+    snrm2,
+    sasum,
+    "gsl_blas_snrm2",
+    "gsl_blas_sasum"
+);
+float_vec!(
+    VectorF64,
+    f64,
+    dnrm2,
+    dasum,
+    "gsl_blas_dnrm2",
+    "gsl_blas_dasum"
+);
+
+#[test]
+fn reductions() {
+    let mut v = VectorF64::from_slice(&[3., -4.]).unwrap();
+    assert_eq!(v.norm2(), 5.);
+    assert_eq!(v.asum(), 7.);
+    v.scale(2.).add_constant(1.);
+    assert_eq!(v.as_slice(), Some(&[7., -7.][..]));
+    assert_eq!(v.minmax(), (-7., 7.));
+}
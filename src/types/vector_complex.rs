@@ -3,23 +3,28 @@
 //
 
 use crate::paste::paste;
-use crate::Value;
+use crate::Error;
 use ffi::FFI;
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
+use types::complex::CFFI;
+use utilities::Preview;
 
 macro_rules! gsl_vec_complex {
-    ($rust_name:ident, $name:ident, $complex:ident, $rust_ty:ident) => {
+    ($rust_name:ident, $name:ident, $complex:ident, $rust_ty:ident, $real:ident, $real_c:ident) => {
         paste! {
 
-        use types::$complex;
+        use types::{$complex, [<$real View>], [<$real ConstView>]};
 
         pub struct $rust_name {
             vec: *mut sys::$name,
             can_free: bool,
         }
 
+        // Same as for the real vectors: the non-owning instances are never held by value.
+        unsafe impl Send for $rust_name {}
+
         impl Drop for $rust_name {
             #[doc(alias = $name _free)]
             fn drop(&mut self) {
@@ -36,11 +41,30 @@ macro_rules! gsl_vec_complex {
                 if ptr.is_null() {
                     write!(f, "<null>")
                 } else {
-                    write!(f, "{:?}", self.as_slice().expect("conversion to slice failed"))
+                    f.debug_struct(stringify!($rust_name))
+                        .field("len", &self.len())
+                        .field("data", &Preview(self.len(), |i| self.get(i)))
+                        .finish()
                 }
             }
         }
 
+        impl Clone for $rust_name {
+            /// Returns a new vector holding a copy of the elements of `self`.
+            ///
+            /// Panics if the allocation fails: use [`try_clone`](Self::try_clone) to handle it.
+            fn clone(&self) -> Self {
+                self.try_clone().expect(concat!(stringify!($name), "_alloc failed"))
+            }
+        }
+
+        /// Two vectors are equal if they have the same length and the same elements.
+        impl PartialEq for $rust_name {
+            fn eq(&self, other: &$rust_name) -> bool {
+                self.len() == other.len() && self.equal(other)
+            }
+        }
+
         impl FFI<sys::$name> for $rust_name {
             fn wrap(vec: *mut sys::$name) -> Self {
                 Self {
@@ -94,6 +118,22 @@ macro_rules! gsl_vec_complex {
                 }
             }
 
+            /// Creates a vector from interleaved `(real, imaginary)` pairs, the layout GSL uses
+            /// for its packed complex arrays.
+            ///
+            /// Panics if `data` has an odd length.
+            #[doc(alias = $name _alloc)]
+            pub fn from_interleaved(data: &[$rust_ty]) -> Option<Self> {
+                assert!(data.len() % 2 == 0, "interleaved data must have an even length");
+                let mut v = Self::wrap(unsafe { sys::[<$name _alloc>](data.len() / 2) });
+                if v.unwrap_shared().is_null() {
+                    None
+                } else {
+                    v.as_slice_mut()?.copy_from_slice(data);
+                    Some(v)
+                }
+            }
+
             pub fn len(&self) -> usize {
                 let ptr = self.unwrap_shared();
                 if ptr.is_null() {
@@ -103,21 +143,45 @@ macro_rules! gsl_vec_complex {
                 }
             }
 
+            /// Returns the elements as interleaved `(real, imaginary)` pairs, so the slice is
+            /// twice as long as the vector.
             pub fn as_slice(&self) -> Option<&[$rust_ty]> {
                 let ptr = unsafe { (*self.unwrap_shared()).data };
                 if ptr.is_null() {
                     None
                 } else {
-                    Some(unsafe { ::std::slice::from_raw_parts(ptr, self.len()) })
+                    Some(unsafe { ::std::slice::from_raw_parts(ptr, 2 * self.len()) })
                 }
             }
 
+            /// Mutable version of [`as_slice`](Self::as_slice).
             pub fn as_slice_mut(&mut self) -> Option<&mut [$rust_ty]> {
                 let ptr = unsafe { (*self.unwrap_shared()).data };
                 if ptr.is_null() {
                     None
                 } else {
-                    Some(unsafe { ::std::slice::from_raw_parts_mut(ptr, self.len()) })
+                    Some(unsafe { ::std::slice::from_raw_parts_mut(ptr, 2 * self.len()) })
+                }
+            }
+
+            #[doc = "Returns the elements as a slice of [`" $complex "`]."]
+            pub fn as_complex_slice(&self) -> Option<&[$complex]> {
+                let ptr = unsafe { (*self.unwrap_shared()).data };
+                if ptr.is_null() {
+                    None
+                } else {
+                    // `$complex` has the same layout as a pair of floats.
+                    Some(unsafe { ::std::slice::from_raw_parts(ptr as *const _, self.len()) })
+                }
+            }
+
+            /// Mutable version of [`as_complex_slice`](Self::as_complex_slice).
+            pub fn as_complex_slice_mut(&mut self) -> Option<&mut [$complex]> {
+                let ptr = unsafe { (*self.unwrap_shared()).data };
+                if ptr.is_null() {
+                    None
+                } else {
+                    Some(unsafe { ::std::slice::from_raw_parts_mut(ptr as *mut _, self.len()) })
                 }
             }
 
@@ -125,7 +189,7 @@ macro_rules! gsl_vec_complex {
             /// 0 to n-1 then the error handler is invoked and 0 is returned.
             #[doc(alias = $name _get)]
             pub fn get(&self, i: usize) -> $complex {
-                unsafe { ::std::mem::transmute(sys::[<$name _get>](self.unwrap_shared(), i)) }
+                unsafe { $complex::wrap(sys::[<$name _get>](self.unwrap_shared(), i)) }
             }
 
             /// This function sets the value of the i-th element of a vector v to x. If i lies outside the
@@ -133,7 +197,7 @@ macro_rules! gsl_vec_complex {
             #[doc(alias = $name _set)]
             pub fn set(&mut self, i: usize, x: &$complex) -> &Self {
                 unsafe {
-                    sys::[<$name _set>](self.unwrap_unique(), i, ::std::mem::transmute(*x))
+                    sys::[<$name _set>](self.unwrap_unique(), i, x.unwrap())
                 };
                 self
             }
@@ -142,7 +206,7 @@ macro_rules! gsl_vec_complex {
             #[doc(alias = $name _set_all)]
             pub fn set_all(&mut self, x: &$complex) -> &Self {
                 unsafe {
-                    sys::[<$name _set_all>](self.unwrap_unique(), ::std::mem::transmute(*x))
+                    sys::[<$name _set_all>](self.unwrap_unique(), x.unwrap())
                 };
                 self
             }
@@ -165,103 +229,91 @@ macro_rules! gsl_vec_complex {
             /// This function copies the elements of the other vector into the self vector. The two vectors
             /// must have the same length.
             #[doc(alias = $name _memcpy)]
-            pub fn copy_from(&mut self, other: &$rust_name) -> Value {
-                Value::from(unsafe {
-                    sys::[<$name _memcpy>](self.unwrap_unique(), other.unwrap_shared())
-                })
+            pub fn copy_from(&mut self, other: &$rust_name) -> Result<(), Error> {
+                let ret = unsafe { sys::[<$name _memcpy>](self.unwrap_unique(), other.unwrap_shared()) };
+                result_handler!(ret, (), stringify!([<$name _memcpy>]))
             }
 
             /// This function copies the elements of the self vector into the other vector. The two vectors
             /// must have the same length.
             #[doc(alias = $name _memcpy)]
-            pub fn copy_to(&self, other: &mut $rust_name) -> Value {
-                Value::from(unsafe {
-                    sys::[<$name _memcpy>](other.unwrap_unique(), self.unwrap_shared())
-                })
+            pub fn copy_to(&self, other: &mut $rust_name) -> Result<(), Error> {
+                let ret = unsafe { sys::[<$name _memcpy>](other.unwrap_unique(), self.unwrap_shared()) };
+                result_handler!(ret, (), stringify!([<$name _memcpy>]))
             }
 
             /// This function exchanges the elements of the vectors by copying. The two vectors must have
             /// the same length.
             #[doc(alias = $name _swap)]
-            pub fn swap(&mut self, other: &mut $rust_name) -> Value {
-                Value::from(unsafe {
-                    sys::[<$name _swap>](other.unwrap_unique(), self.unwrap_unique())
-                })
+            pub fn swap(&mut self, other: &mut $rust_name) -> Result<(), Error> {
+                let ret = unsafe { sys::[<$name _swap>](other.unwrap_unique(), self.unwrap_unique()) };
+                result_handler!(ret, (), stringify!([<$name _swap>]))
             }
 
             /// This function exchanges the i-th and j-th elements of the vector v in-place.
             #[doc(alias = $name _swap_elements)]
-            pub fn swap_elements(&mut self, i: usize, j: usize) -> Value {
-                Value::from(unsafe {
-                    sys::[<$name _swap_elements>](self.unwrap_unique(), i, j)
-                })
+            pub fn swap_elements(&mut self, i: usize, j: usize) -> Result<(), Error> {
+                let ret = unsafe { sys::[<$name _swap_elements>](self.unwrap_unique(), i, j) };
+                result_handler!(ret, (), stringify!([<$name _swap_elements>]))
             }
 
             /// This function reverses the order of the elements of the vector v.
             #[doc(alias = $name _reverse)]
-            pub fn reverse(&mut self) -> Value {
-                Value::from(unsafe { sys::[<$name _reverse>](self.unwrap_unique()) })
+            pub fn reverse(&mut self) -> Result<(), Error> {
+                let ret = unsafe { sys::[<$name _reverse>](self.unwrap_unique()) };
+                result_handler!(ret, (), stringify!([<$name _reverse>]))
             }
 
             /// This function adds the elements of the other vector to the elements of the `self` vector.
             /// The result a_i <- a_i + b_i is stored in self and other remains unchanged. The two vectors
             /// must have the same length.
             #[doc(alias = $name _add)]
-            pub fn add(&mut self, other: &$rust_name) -> Value {
-                Value::from(unsafe {
-                    sys::[<$name _add>](self.unwrap_unique(), other.unwrap_shared())
-                })
+            pub fn add(&mut self, other: &$rust_name) -> Result<(), Error> {
+                let ret = unsafe { sys::[<$name _add>](self.unwrap_unique(), other.unwrap_shared()) };
+                result_handler!(ret, (), stringify!([<$name _add>]))
             }
 
             /// This function subtracts the elements of the self vector from the elements of the other
             /// vector. The result a_i <- a_i - b_i is stored in self and other remains unchanged. The two
             /// vectors must have the same length.
             #[doc(alias = $name _sub)]
-            pub fn sub(&mut self, other: &$rust_name) -> Value {
-                Value::from(unsafe {
-                    sys::[<$name _sub>](self.unwrap_unique(), other.unwrap_shared())
-                })
+            pub fn sub(&mut self, other: &$rust_name) -> Result<(), Error> {
+                let ret = unsafe { sys::[<$name _sub>](self.unwrap_unique(), other.unwrap_shared()) };
+                result_handler!(ret, (), stringify!([<$name _sub>]))
             }
 
             /// This function multiplies the elements of the self vector a by the elements of the other
             /// vector. The result a_i <- a_i * b_i is stored in self and other remains unchanged. The two
             /// vectors must have the same length.
             #[doc(alias = $name _mul)]
-            pub fn mul(&mut self, other: &$rust_name) -> Value {
-                Value::from(unsafe {
-                    sys::[<$name _mul>](self.unwrap_unique(), other.unwrap_shared())
-                })
+            pub fn mul(&mut self, other: &$rust_name) -> Result<(), Error> {
+                let ret = unsafe { sys::[<$name _mul>](self.unwrap_unique(), other.unwrap_shared()) };
+                result_handler!(ret, (), stringify!([<$name _mul>]))
             }
 
             /// This function divides the elements of the self vector by the elements of the other vector.
             /// The result a_i <- a_i / b_i is stored in self and other remains unchanged. The two vectors
             /// must have the same length.
             #[doc(alias = $name _div)]
-            pub fn div(&mut self, other: &$rust_name) -> Value {
-                Value::from(unsafe {
-                    sys::[<$name _div>](self.unwrap_unique(), other.unwrap_shared())
-                })
+            pub fn div(&mut self, other: &$rust_name) -> Result<(), Error> {
+                let ret = unsafe { sys::[<$name _div>](self.unwrap_unique(), other.unwrap_shared()) };
+                result_handler!(ret, (), stringify!([<$name _div>]))
             }
 
             /// This function multiplies the elements of the self vector by the constant factor x. The
             /// result a_i <- a_i is stored in self.
             #[doc(alias = $name _scale)]
-            pub fn scale(&mut self, x: &$complex) -> Value {
-                Value::from(unsafe {
-                    sys::[<$name _scale>](self.unwrap_unique(), ::std::mem::transmute(*x))
-                })
+            pub fn scale(&mut self, x: &$complex) -> Result<(), Error> {
+                let ret = unsafe { sys::[<$name _scale>](self.unwrap_unique(), x.unwrap()) };
+                result_handler!(ret, (), stringify!([<$name _scale>]))
             }
 
             /// This function adds the constant value x to the elements of the self vector. The result
             /// a_i <- a_i + x is stored in self.
             #[doc(alias = $name _add_constant)]
-            pub fn add_constant(&mut self, x: &$complex) -> Value {
-                Value::from(unsafe {
-                    sys::[<$name _add_constant>](
-                        self.unwrap_unique(),
-                        ::std::mem::transmute(*x),
-                    )
-                })
+            pub fn add_constant(&mut self, x: &$complex) -> Result<(), Error> {
+                let ret = unsafe { sys::[<$name _add_constant>](self.unwrap_unique(), x.unwrap()) };
+                result_handler!(ret, (), stringify!([<$name _add_constant>]))
             }
 
             /// This function returns true if all the elements of the self vector are equal to 0.
@@ -295,19 +347,92 @@ macro_rules! gsl_vec_complex {
                 }
             }
 
-            pub fn clone(&self) -> Option<Self> {
+            /// Returns a new vector holding a copy of the elements of `self`, or `None` if the
+            /// allocation failed.
+            pub fn try_clone(&self) -> Option<Self> {
                 if self.unwrap_shared().is_null() {
                     None
                 } else {
                     match Self::new(self.len()) {
                         Some(mut v) => {
-                            v.copy_from(self);
+                            v.copy_from(self).ok()?;
                             Some(v)
                         }
                         None => None,
                     }
                 }
             }
+
+            /// Returns `true` if `self` and `other` have the same length and the moduli of the
+            /// differences between their elements are at most `tol`.
+            pub fn approx_eq(&self, other: &$rust_name, tol: $rust_ty) -> bool {
+                self.len() == other.len()
+                    && (0..self.len()).all(|i| self.get(i).sub(&other.get(i)).abs() <= tol)
+            }
+
+            /// Replaces every element by its complex conjugate.
+            pub fn conjugate(&mut self) {
+                let mut imag = unsafe { sys::[<$name _imag>](self.unwrap_unique()) };
+                unsafe { sys::[<$real_c _scale>](&mut imag.vector, -1.) };
+            }
+
+            /// Gives a view of the real parts of the elements to `f`.
+            #[doc(alias = $name _real)]
+            pub fn real<F: FnOnce(Option<[<$real View>]>)>(&mut self, f: F) {
+                [<$real View>]::wrap(unsafe { sys::[<$name _real>](self.unwrap_unique()) }, f)
+            }
+
+            /// Gives a view of the imaginary parts of the elements to `f`.
+            #[doc(alias = $name _imag)]
+            pub fn imag<F: FnOnce(Option<[<$real View>]>)>(&mut self, f: F) {
+                [<$real View>]::wrap(unsafe { sys::[<$name _imag>](self.unwrap_unique()) }, f)
+            }
+
+            /// Returns a read-only view of the real parts of the elements.
+            #[doc(alias = $name _const_real)]
+            pub fn const_real(&self) -> [<$real ConstView>]<'_> {
+                [<$real ConstView>]::wrap(unsafe { sys::[<$name _const_real>](self.unwrap_shared()) })
+            }
+
+            /// Returns a read-only view of the imaginary parts of the elements.
+            #[doc(alias = $name _const_imag)]
+            pub fn const_imag(&self) -> [<$real ConstView>]<'_> {
+                [<$real ConstView>]::wrap(unsafe { sys::[<$name _const_imag>](self.unwrap_shared()) })
+            }
+
+            #[doc(alias = $name _subvector)]
+            pub fn subvector(&mut self, offset: usize, n: usize) -> [<$rust_name View>]<'_> {
+                [<$rust_name View>]::from_vector(self, offset, n)
+            }
+
+            #[doc(alias = $name _subvector_with_stride)]
+            pub fn subvector_with_stride(
+                &mut self,
+                offset: usize,
+                stride: usize,
+                n: usize,
+            ) -> [<$rust_name View>]<'_> {
+                [<$rust_name View>]::from_vector_with_stride(self, offset, stride, n)
+            }
+
+            /// Same as [`subvector`](Self::subvector), but only borrows the vector so the view
+            /// can't modify it.
+            #[doc(alias = $name _const_subvector)]
+            pub fn const_subvector(&self, offset: usize, n: usize) -> [<$rust_name ConstView>]<'_> {
+                [<$rust_name ConstView>]::from_vector(self, offset, n)
+            }
+
+            /// Same as [`subvector_with_stride`](Self::subvector_with_stride), but only borrows
+            /// the vector so the view can't modify it.
+            #[doc(alias = $name _const_subvector_with_stride)]
+            pub fn const_subvector_with_stride(
+                &self,
+                offset: usize,
+                stride: usize,
+                n: usize,
+            ) -> [<$rust_name ConstView>]<'_> {
+                [<$rust_name ConstView>]::from_vector_with_stride(self, offset, stride, n)
+            }
         }
 
         pub struct [<$rust_name View>]<'a> {
@@ -425,10 +550,10 @@ macro_rules! gsl_vec_complex {
             /// The function gsl_vector_const_view_array is equivalent to gsl_vector_view_array but can be
             /// used for arrays which are declared const.
             #[doc(alias = $name _view_array)]
-            pub fn from_array(base: &'a mut [f64]) -> Self {
+            pub fn from_array(base: &'a mut [$complex]) -> Self {
                 unsafe {
                     Self {
-                        v: sys::[<$name _view_array>](base.as_mut_ptr() as _, base.len() as _),
+                        v: sys::[<$name _view_array>](base.as_mut_ptr() as *mut _, base.len()),
                         phantom: PhantomData,
                     }
                 }
@@ -450,13 +575,14 @@ macro_rules! gsl_vec_complex {
             /// The function gsl_vector_const_view_array_with_stride is equivalent to
             /// gsl_vector_view_array_with_stride but can be used for arrays which are declared const.
             #[doc(alias = $name _view_array_with_stride)]
-            pub fn from_array_with_stride(base: &'a mut [$rust_ty], stride: usize) -> Self {
+            pub fn from_array_with_stride(base: &'a mut [$complex], stride: usize) -> Self {
+                assert!(stride > 0, "stride must be positive");
                 unsafe {
                     Self {
                         v: sys::[<$name _view_array_with_stride>](
-                            base.as_mut_ptr(),
+                            base.as_mut_ptr() as *mut _,
                             stride,
-                            base.len() as _,
+                            base.len().div_ceil(stride),
                         ),
                         phantom: PhantomData,
                     }
@@ -475,18 +601,120 @@ macro_rules! gsl_vec_complex {
 
             pub fn vector_mut<F: FnOnce(Option<&mut $rust_name>)>(&mut self, f: F) {
                 let v = &mut self.v.vector;
-                let mut tmp = $rust_name::soft_wrap(v as *mut _);
-                if tmp.as_slice().is_none() {
+                ::ffi::lend_mut(v as *mut _, |tmp: &mut $rust_name| {
+                    if tmp.as_slice().is_none() {
+                        f(None)
+                    } else {
+                        f(Some(tmp))
+                    }
+                })
+            }
+        } // end of impl block
+
+        /// A read-only view of a vector or of an array. It borrows its parent, so it can't outlive
+        /// it.
+        pub struct [<$rust_name ConstView>]<'a> {
+            v: sys::[<$name _const_view>],
+            #[allow(dead_code)]
+            phantom: PhantomData<&'a ()>,
+        }
+
+        impl<'a> [<$rust_name ConstView>]<'a> {
+            #[doc(hidden)]
+            pub(crate) fn wrap(v: sys::[<$name _const_view>]) -> Self {
+                Self {
+                    v,
+                    phantom: PhantomData,
+                }
+            }
+
+            /// Same as [`from_vector`]([<$rust_name View>]::from_vector), but for a read-only view.
+            #[doc(alias = $name _const_subvector)]
+            pub fn from_vector(v: &'a $rust_name, offset: usize, n: usize) -> Self {
+                Self::wrap(unsafe { sys::[<$name _const_subvector>](v.unwrap_shared(), offset, n) })
+            }
+
+            /// Same as [`from_vector_with_stride`]([<$rust_name View>]::from_vector_with_stride),
+            /// but for a read-only view.
+            #[doc(alias = $name _const_subvector_with_stride)]
+            pub fn from_vector_with_stride(
+                v: &'a $rust_name,
+                offset: usize,
+                stride: usize,
+                n: usize,
+            ) -> Self {
+                Self::wrap(unsafe {
+                    sys::[<$name _const_subvector_with_stride>](v.unwrap_shared(), offset, stride, n)
+                })
+            }
+
+            /// Same as [`from_array`]([<$rust_name View>]::from_array), but for a read-only view.
+            #[doc(alias = $name _const_view_array)]
+            pub fn from_array(base: &'a [$complex]) -> Self {
+                Self::wrap(unsafe {
+                    sys::[<$name _const_view_array>](base.as_ptr() as *const _, base.len())
+                })
+            }
+
+            /// Gives the view as a vector to `f`, or `None` if the view is out of the bounds of its
+            /// parent.
+            pub fn vector<F: FnOnce(Option<&$rust_name>)>(&self, f: F) {
+                let v = &self.v.vector;
+                if v.data.is_null() {
                     f(None)
                 } else {
-                    f(Some(&mut tmp))
+                    f(Some(&$rust_name::soft_wrap(v as *const _ as usize as *mut _)))
                 }
             }
-        } // end of impl block
+        }
 
         } // end of paste! block
     }; // end of macro block
 }
 
-gsl_vec_complex!(VectorComplexF32, gsl_vector_complex_float, ComplexF32, f32);
-gsl_vec_complex!(VectorComplexF64, gsl_vector_complex, ComplexF64, f64);
+gsl_vec_complex!(
+    VectorComplexF32,
+    gsl_vector_complex_float,
+    ComplexF32,
+    f32,
+    VectorF32,
+    gsl_vector_float
+);
+gsl_vec_complex!(
+    VectorComplexF64,
+    gsl_vector_complex,
+    ComplexF64,
+    f64,
+    VectorF64,
+    gsl_vector
+);
+
+#[test]
+fn complex_parts() {
+    use {ComplexF64, MatrixComplexF64};
+
+    let mut v = VectorComplexF64::from_interleaved(&[1., 2., 3., 4., 5., 6.]).unwrap();
+    assert_eq!(v.len(), 3);
+    assert_eq!(v.get(1), ComplexF64 { dat: [3., 4.] });
+    v.conjugate();
+    v.imag(|imag| {
+        imag.unwrap().vector_mut(|imag| {
+            imag.unwrap().set(0, 0.);
+        })
+    });
+    assert_eq!(v.as_slice(), Some(&[1., 0., 3., -4., 5., -6.][..]));
+    v.const_real().vector(|real| {
+        let real = real.unwrap();
+        assert_eq!((real.len(), real.get(2)), (3, 5.));
+    });
+    v.const_subvector(1, 2)
+        .vector(|s| assert_eq!(s.unwrap().get(0), ComplexF64 { dat: [3., -4.] }));
+
+    let mut m = MatrixComplexF64::new(2, 3).unwrap();
+    m.set(0, 2, &ComplexF64 { dat: [1., 1.] });
+    let a = m.adjoint().unwrap();
+    assert_eq!((a.size1(), a.size2()), (3, 2));
+    assert_eq!(a.get(2, 0), ComplexF64 { dat: [1., -1.] });
+    m.const_row(0)
+        .vector(|r| assert_eq!(r.unwrap().get(2), ComplexF64 { dat: [1., 1.] }));
+}
@@ -62,6 +62,8 @@ ffi_wrapper!(
 offset parameters."
 );
 
+unsafe impl Send for Wavelet {}
+
 impl Wavelet {
     /// This function allocates and initializes a wavelet object of type T. The parameter k selects the specific member of the wavelet
     /// family. A null pointer is returned if insufficient memory is available or if a unsupported member is selected.
@@ -98,6 +100,9 @@ ffi_wrapper!(WaveletType, *const sys::gsl_wavelet_type,
 the resulting visualization of the coefficients of the wavelet transform in the phase plane is
 easier to understand.");
 
+unsafe impl Send for WaveletType {}
+unsafe impl Sync for WaveletType {}
+
 impl WaveletType {
     /// This is the Daubechies wavelet family of maximum phase with k/2 vanishing moments. The implemented wavelets are k=4, 6, …, 20, with
     /// k even.
@@ -134,10 +139,19 @@ impl WaveletType {
     }
 }
 
+// `gsl_wavelet_workspace` is opaque in the bindings.
+#[repr(C)]
+struct WorkspaceHeader {
+    scratch: *mut f64,
+    n: usize,
+}
+
 ffi_wrapper!(WaveletWorkspace, *mut sys::gsl_wavelet_workspace, gsl_wavelet_workspace_free,
 "The WaveletWorkspace structure contains scratch space of the same size as the input data and is
 used to hold intermediate results during the transform.");
 
+unsafe impl Send for WaveletWorkspace {}
+
 impl WaveletWorkspace {
     /// This function allocates a workspace for the discrete wavelet transform. To perform a one-dimensional transform on n elements, a
     /// workspace of size n must be provided. For two-dimensional transforms of n-by-n matrices it is sufficient to allocate a workspace
@@ -153,4 +167,9 @@ impl WaveletWorkspace {
             Some(WaveletWorkspace::wrap(tmp))
         }
     }
+
+    /// Returns the number of elements the workspace was allocated for.
+    pub fn size(&self) -> usize {
+        unsafe { (*(self.unwrap_shared() as *const WorkspaceHeader)).n }
+    }
 }
@@ -1,12 +1,21 @@
 /// Utilities for interfacing with GSL/C
+#[cfg(feature = "std")]
 use std::ffi::CString;
-use std::io;
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
+#[cfg(feature = "std")]
 use std::ops::Drop;
-use std::os::raw::c_char;
+#[cfg(feature = "std")]
+use std::os::raw::{c_char, c_int, c_void};
+#[cfg(feature = "std")]
 use std::path::Path;
 
-use sys::libc::{fclose, fopen, FILE};
+#[cfg(feature = "std")]
+use sys::libc::{fclose, ferror, fflush, fopen, fread, fwrite, rewind, tmpfile, FILE};
 
+#[cfg(feature = "std")]
 #[allow(dead_code)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Mode {
@@ -15,11 +24,13 @@ enum Mode {
 }
 
 /// A wrapper to handle I/O operations between GSL and rust
+#[cfg(feature = "std")]
 pub struct IOStream {
     inner: *mut FILE,
     mode: Mode,
 }
 
+#[cfg(feature = "std")]
 impl IOStream {
     /// Open a file in write mode.
     pub fn fwrite_handle<P: AsRef<Path>>(file: &P) -> io::Result<IOStream> {
@@ -47,6 +58,7 @@ impl IOStream {
     }
 }
 
+#[cfg(feature = "std")]
 impl Drop for IOStream {
     fn drop(&mut self) {
         unsafe {
@@ -55,3 +67,218 @@ impl Drop for IOStream {
         }
     }
 }
+
+/// A temporary C file, giving a `FILE` stream to the GSL I/O functions.
+#[cfg(feature = "std")]
+struct TmpFile(*mut FILE);
+
+#[cfg(feature = "std")]
+impl TmpFile {
+    fn new() -> io::Result<TmpFile> {
+        let ptr = unsafe { tmpfile() };
+        if ptr.is_null() {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(TmpFile(ptr))
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for TmpFile {
+    fn drop(&mut self) {
+        unsafe { fclose(self.0) };
+    }
+}
+
+#[cfg(feature = "std")]
+fn gsl_to_io_error(ret: c_int, function: &'static str) -> io::Result<()> {
+    result_handler!(ret, (), function).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Calls `f` with a C stream, then copies everything it wrote to `writer`.
+#[cfg(feature = "std")]
+pub(crate) fn write_through_file<W: Write + ?Sized, F: FnOnce(*mut FILE) -> c_int>(
+    writer: &mut W,
+    function: &'static str,
+    f: F,
+) -> io::Result<()> {
+    let file = TmpFile::new()?;
+    gsl_to_io_error(f(file.0), function)?;
+    unsafe {
+        if fflush(file.0) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        rewind(file.0);
+    }
+    let mut buf = [0u8; 8192];
+    loop {
+        let n = unsafe { fread(buf.as_mut_ptr() as *mut c_void, 1, buf.len(), file.0) };
+        if n == 0 {
+            break;
+        }
+        writer.write_all(&buf[..n])?;
+    }
+    if unsafe { ferror(file.0) } != 0 {
+        return Err(io::Error::other("failed to read the temporary file"));
+    }
+    Ok(())
+}
+
+/// Copies `data` into a C stream and calls `f` with it.
+#[cfg(feature = "std")]
+pub(crate) fn read_through_file<F: FnOnce(*mut FILE) -> c_int>(
+    data: &[u8],
+    function: &'static str,
+    f: F,
+) -> io::Result<()> {
+    let file = TmpFile::new()?;
+    unsafe {
+        if fwrite(data.as_ptr() as *const c_void, 1, data.len(), file.0) != data.len()
+            || fflush(file.0) != 0
+        {
+            return Err(io::Error::last_os_error());
+        }
+        rewind(file.0);
+    }
+    gsl_to_io_error(f(file.0), function)
+}
+
+/// Reads exactly `len` bytes from `reader` and gives them to `f` through a C stream.
+#[cfg(feature = "std")]
+pub(crate) fn read_exact_through_file<R: Read + ?Sized, F: FnOnce(*mut FILE) -> c_int>(
+    reader: &mut R,
+    len: usize,
+    function: &'static str,
+    f: F,
+) -> io::Result<()> {
+    let mut data = vec![0; len];
+    reader.read_exact(&mut data)?;
+    read_through_file(&data, function, f)
+}
+
+/// Reads `reader` until its end and gives its content to `f` through a C stream.
+#[cfg(feature = "std")]
+pub(crate) fn read_to_end_through_file<R: Read + ?Sized, F: FnOnce(*mut FILE) -> c_int>(
+    reader: &mut R,
+    function: &'static str,
+    f: F,
+) -> io::Result<()> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+    read_through_file(&data, function, f)
+}
+
+/// Checks that `format` is a printf format for a single value of type `ty` ("f64", "f32", "i32"
+/// or "u32"), since GSL gives it to `fprintf` with each element.
+#[cfg(feature = "std")]
+pub(crate) fn printf_format(format: &str, ty: &str) -> io::Result<CString> {
+    let conversions: &[u8] = match ty {
+        "f64" | "f32" => b"eEfFgGaA",
+        "i32" => b"di",
+        _ => b"uxXo",
+    };
+    let invalid = || {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "`{}` is not a printf format for a single `{}` value",
+                format, ty
+            ),
+        )
+    };
+    let bytes = format.as_bytes();
+    let mut specs = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'%' {
+            i += 1;
+            continue;
+        }
+        i += 1;
+        if bytes.get(i) == Some(&b'%') {
+            i += 1;
+            continue;
+        }
+        while i < bytes.len() && b"-+ #0".contains(&bytes[i]) {
+            i += 1;
+        }
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if bytes.get(i) == Some(&b'.') {
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+        }
+        match bytes.get(i) {
+            Some(c) if conversions.contains(c) => specs += 1,
+            _ => return Err(invalid()),
+        }
+        i += 1;
+    }
+    if specs != 1 {
+        return Err(invalid());
+    }
+    CString::new(format).map_err(|_| invalid())
+}
+
+/// Maximum number of elements (or rows) printed by the `Debug` implementations of the vector,
+/// matrix, permutation and histogram types.
+#[cfg(feature = "std")]
+pub(crate) const DEBUG_PREVIEW_LEN: usize = 8;
+
+/// Formats the `len` values returned by `get` as a list, printing only the first
+/// [`DEBUG_PREVIEW_LEN`] ones followed by `...`.
+#[cfg(feature = "std")]
+pub(crate) struct Preview<F>(pub usize, pub F);
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug, F: Fn(usize) -> T> fmt::Debug for Preview<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut list = f.debug_list();
+        list.entries((0..self.0.min(DEBUG_PREVIEW_LEN)).map(&self.1));
+        if self.0 > DEBUG_PREVIEW_LEN {
+            list.entry(&format_args!("..."));
+        }
+        list.finish()
+    }
+}
+
+/// Stores `f(x)` in `out` for every `x` of `xs`: this is the loop of the `_slice` versions of
+/// the special functions.
+#[inline]
+pub(crate) fn map_slice<F: Fn(f64) -> f64>(xs: &[f64], out: &mut [f64], f: F) {
+    assert_eq!(
+        xs.len(),
+        out.len(),
+        "the input and output slices must have the same length"
+    );
+    for (o, &x) in out.iter_mut().zip(xs) {
+        *o = f(x);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn printf_formats() {
+    assert!(printf_format("%g", "f64").is_ok());
+    assert!(printf_format("%-12.5e%%", "f32").is_ok());
+    assert!(printf_format("%u", "u32").is_ok());
+    assert!(printf_format("%d", "f64").is_err());
+    assert!(printf_format("%g %g", "f64").is_err());
+    assert!(printf_format("%s", "i32").is_err());
+    assert!(printf_format("%*d", "i32").is_err());
+    assert!(printf_format("value", "f64").is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn debug_preview() {
+    assert_eq!(format!("{:?}", Preview(3, |i| i)), "[0, 1, 2]");
+    assert_eq!(
+        format!("{:?}", Preview(20, |i| i)),
+        "[0, 1, 2, 3, 4, 5, 6, 7, ...]"
+    );
+}
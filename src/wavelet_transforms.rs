@@ -23,12 +23,87 @@ level of the transform.
 /// where the first element is the smoothing coefficient s_{-1,0}, followed by the detail coefficients d_{j,k} for each level j. The
 /// backward transform inverts these coefficients to obtain the original data.
 ///
-/// These functions return a status of ::Value::Success upon successful completion. ::Inval is returned if n is not an integer power of
-/// 2 or if insufficient workspace is provided.
+/// These functions return a `Value::Invalid` error if n is not an integer power of 2 or if
+/// insufficient workspace is provided. The `_checked` variants derive n from the slice and
+/// validate it beforehand, returning an [`Error`](crate::Error) instead.
 pub mod one_dimension {
-    use crate::Value;
+    use crate::{Error, Value};
     use ffi::FFI;
 
+    /// Returns the number of elements of data used by a transform with the given stride, after
+    /// checking that GSL won't reject them.
+    fn checked_len(
+        data: &[f64],
+        stride: usize,
+        work: &::WaveletWorkspace,
+        c_name: &'static str,
+    ) -> Result<usize, Error> {
+        if stride == 0 {
+            return Err(Error::new(Value::Invalid, c_name));
+        }
+        let n = data.len().div_ceil(stride);
+        if n == 0 || !n.is_power_of_two() {
+            return Err(Error::new(Value::Invalid, c_name));
+        }
+        if work.size() < n {
+            return Err(Error::new(Value::BadLength, c_name));
+        }
+        Ok(n)
+    }
+
+    /// Checked version of [`transform`]: the length n of the transform is the number of elements
+    /// of data with the given stride, which must be a power of two, and the workspace must be
+    /// large enough for it. These conditions are checked before calling GSL, so they return an
+    /// error instead of invoking the GSL error handler.
+    ///
+    /// ```
+    /// use rgsl::wavelet_transforms::one_dimension;
+    /// use rgsl::{Wavelet, WaveletDirection, WaveletType, WaveletWorkspace};
+    ///
+    /// let w = Wavelet::new(WaveletType::haar(), 2).unwrap();
+    /// let mut work = WaveletWorkspace::new(4).unwrap();
+    /// let mut data = [1., 2., 3.];
+    /// assert!(one_dimension::transform_checked(&w, &mut data, 1, WaveletDirection::Forward, &mut work).is_err());
+    ///
+    /// let mut data = [1., 2., 3., 4.];
+    /// one_dimension::transform_checked(&w, &mut data, 1, WaveletDirection::Forward, &mut work).unwrap();
+    /// ```
+    #[doc(alias = "gsl_wavelet_transform")]
+    pub fn transform_checked(
+        w: &::Wavelet,
+        data: &mut [f64],
+        stride: usize,
+        dir: ::WaveletDirection,
+        work: &mut ::WaveletWorkspace,
+    ) -> Result<(), Error> {
+        let n = checked_len(data, stride, work, "gsl_wavelet_transform")?;
+        transform(w, data, stride, n, dir, work)
+    }
+
+    /// Checked version of [`transform_forward`], see [`transform_checked`].
+    #[doc(alias = "gsl_wavelet_transform_forward")]
+    pub fn transform_forward_checked(
+        w: &::Wavelet,
+        data: &mut [f64],
+        stride: usize,
+        work: &mut ::WaveletWorkspace,
+    ) -> Result<(), Error> {
+        let n = checked_len(data, stride, work, "gsl_wavelet_transform_forward")?;
+        transform_forward(w, data, stride, n, work)
+    }
+
+    /// Checked version of [`transform_inverse`], see [`transform_checked`].
+    #[doc(alias = "gsl_wavelet_transform_inverse")]
+    pub fn transform_inverse_checked(
+        w: &::Wavelet,
+        data: &mut [f64],
+        stride: usize,
+        work: &mut ::WaveletWorkspace,
+    ) -> Result<(), Error> {
+        let n = checked_len(data, stride, work, "gsl_wavelet_transform_inverse")?;
+        transform_inverse(w, data, stride, n, work)
+    }
+
     #[doc(alias = "gsl_wavelet_transform")]
     pub fn transform(
         w: &::Wavelet,
@@ -37,8 +112,8 @@ pub mod one_dimension {
         n: usize,
         dir: ::WaveletDirection,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet_transform(
                 w.unwrap_shared(),
                 data.as_mut_ptr(),
@@ -47,7 +122,8 @@ pub mod one_dimension {
                 dir.into(),
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet_transform")
     }
 
     #[doc(alias = "gsl_wavelet_transform_forward")]
@@ -57,8 +133,8 @@ pub mod one_dimension {
         stride: usize,
         n: usize,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet_transform_forward(
                 w.unwrap_shared(),
                 data.as_mut_ptr(),
@@ -66,7 +142,8 @@ pub mod one_dimension {
                 n,
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet_transform_forward")
     }
 
     #[doc(alias = "gsl_wavelet_transform_inverse")]
@@ -76,8 +153,8 @@ pub mod one_dimension {
         stride: usize,
         n: usize,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet_transform_inverse(
                 w.unwrap_shared(),
                 data.as_mut_ptr(),
@@ -85,7 +162,122 @@ pub mod one_dimension {
                 n,
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet_transform_inverse")
+    }
+    /// Returns the number of levels J = log2(n) of packed coefficients of length n, panicking if
+    /// n isn't a power of two.
+    fn levels_count(coeffs: &[f64]) -> usize {
+        assert!(
+            coeffs.len().is_power_of_two(),
+            "the number of wavelet coefficients must be a power of two"
+        );
+        coeffs.len().trailing_zeros() as usize
+    }
+
+    /// Returns the detail coefficients d_{j,k}, k = 0 ... (2^j)-1, of the level `j` of the
+    /// coefficients computed by a forward transform (with a stride of 1), i.e. the elements
+    /// `2^j .. 2^(j+1)` of the packed layout. The smoothing coefficient s_{-1,0} is `coeffs[0]`.
+    ///
+    /// Panics if the length of `coeffs` isn't a power of two or if `j` isn't smaller than the
+    /// number of levels J = log2(n).
+    ///
+    /// ```
+    /// use rgsl::wavelet_transforms::one_dimension;
+    ///
+    /// let coeffs = [0., 1., 2., 3., 4., 5., 6., 7.];
+    /// assert_eq!(one_dimension::level(&coeffs, 0), [1.]);
+    /// assert_eq!(one_dimension::level(&coeffs, 2), [4., 5., 6., 7.]);
+    /// ```
+    pub fn level(coeffs: &[f64], j: usize) -> &[f64] {
+        assert!(j < levels_count(coeffs), "invalid wavelet level");
+        &coeffs[1 << j..2 << j]
+    }
+
+    /// Mutable version of [`level`].
+    pub fn level_mut(coeffs: &mut [f64], j: usize) -> &mut [f64] {
+        assert!(j < levels_count(coeffs), "invalid wavelet level");
+        &mut coeffs[1 << j..2 << j]
+    }
+
+    /// Returns the detail coefficients of every level j = 0 ... J-1, see [`level`].
+    pub fn levels(coeffs: &[f64]) -> Vec<&[f64]> {
+        (0..levels_count(coeffs))
+            .map(|j| &coeffs[1 << j..2 << j])
+            .collect()
+    }
+
+    /// Returns the energy (the sum of the squares) of the detail coefficients of every level
+    /// j = 0 ... J-1, see [`level`]. For an orthogonal wavelet, the sum of these energies and of
+    /// `coeffs[0] * coeffs[0]` is the energy of the original signal.
+    pub fn level_energies(coeffs: &[f64]) -> Vec<f64> {
+        levels(coeffs)
+            .into_iter()
+            .map(|d| d.iter().map(|x| x * x).sum())
+            .collect()
+    }
+
+    /// Sets to zero the detail coefficients whose absolute value is below `lambda`. The
+    /// smoothing coefficient `coeffs[0]` is left untouched.
+    ///
+    /// ```
+    /// use rgsl::wavelet_transforms::one_dimension;
+    ///
+    /// let mut coeffs = [5., -0.5, 2., 0.1];
+    /// one_dimension::threshold_hard(&mut coeffs, 1.);
+    /// assert_eq!(coeffs, [5., 0., 2., 0.]);
+    /// ```
+    pub fn threshold_hard(coeffs: &mut [f64], lambda: f64) {
+        for x in coeffs.iter_mut().skip(1) {
+            if x.abs() < lambda {
+                *x = 0.;
+            }
+        }
+    }
+
+    /// Shrinks the detail coefficients towards zero by `lambda`: they are replaced by
+    /// `sign(x) * max(|x| - lambda, 0)`. The smoothing coefficient `coeffs[0]` is left
+    /// untouched.
+    ///
+    /// ```
+    /// use rgsl::wavelet_transforms::one_dimension;
+    ///
+    /// let mut coeffs = [5., -0.5, 2., -3.];
+    /// one_dimension::threshold_soft(&mut coeffs, 1.);
+    /// assert_eq!(coeffs, [5., 0., 1., -2.]);
+    /// ```
+    pub fn threshold_soft(coeffs: &mut [f64], lambda: f64) {
+        for x in coeffs.iter_mut().skip(1) {
+            *x = x.signum() * (x.abs() - lambda).max(0.);
+        }
+    }
+
+    /// Returns the threshold which keeps the `k` detail coefficients with the largest absolute
+    /// values, to be given to [`threshold_hard`]. Coefficients with the same absolute value as
+    /// the `k`-th one are kept too. Returns 0 (keeping everything) if there are no more than `k`
+    /// detail coefficients and infinity (keeping none) if `k` is 0.
+    ///
+    /// This is the compression of the GSL manual example:
+    ///
+    /// ```
+    /// use rgsl::wavelet_transforms::one_dimension;
+    ///
+    /// let mut coeffs = [5., -4., 0.5, 3., -0.1, 1., 2., 0.];
+    /// let lambda = one_dimension::largest_threshold(&coeffs, 3);
+    /// one_dimension::threshold_hard(&mut coeffs, lambda);
+    /// assert_eq!(coeffs, [5., -4., 0., 3., 0., 0., 2., 0.]);
+    /// ```
+    pub fn largest_threshold(coeffs: &[f64], k: usize) -> f64 {
+        if k == 0 {
+            return f64::INFINITY;
+        }
+        let mut magnitudes = coeffs.iter().skip(1).map(|x| x.abs()).collect::<Vec<_>>();
+        if magnitudes.len() <= k {
+            return 0.;
+        }
+        let (_, kth, _) =
+            magnitudes.select_nth_unstable_by(k - 1, |a, b| b.partial_cmp(a).unwrap());
+        *kth
     }
 }
 
@@ -102,7 +294,7 @@ pub mod one_dimension {
 /// the rows and columns of the data for the subsequent levels of the transform, until the full discrete wavelet transform is complete.
 /// The non-standard form of the discrete wavelet transform is typically used in image analysis.
 pub mod two_dimension {
-    use crate::Value;
+    use crate::Error;
     use ffi::FFI;
 
     /// These functions compute two-dimensional in-place forward and inverse discrete wavelet transforms in standard form on the array
@@ -111,8 +303,8 @@ pub mod two_dimension {
     /// forward (+1) or backward (-1). A workspace work of the appropriate size must be provided. On exit, the appropriate elements of
     /// the array data are replaced by their two-dimensional wavelet transform.
     ///
-    /// The functions return a status of ::Value::Success upon successful completion. ::Inval is returned if size1 and size2 are not
-    /// equal and integer powers of 2, or if insufficient workspace is provided.
+    /// The functions return a `Value::Invalid` error if size1 and size2 are not equal and integer
+    /// powers of 2, or if insufficient workspace is provided.
     #[doc(alias = "gsl_wavelet2d_transform")]
     pub fn transform(
         w: &::Wavelet,
@@ -122,8 +314,8 @@ pub mod two_dimension {
         size2: usize,
         dir: ::WaveletDirection,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet2d_transform(
                 w.unwrap_shared(),
                 data.as_mut_ptr(),
@@ -133,7 +325,8 @@ pub mod two_dimension {
                 dir.into(),
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet2d_transform")
     }
 
     /// These functions compute two-dimensional in-place forward and inverse discrete wavelet transforms in standard form on the array
@@ -142,8 +335,8 @@ pub mod two_dimension {
     /// forward (+1) or backward (-1). A workspace work of the appropriate size must be provided. On exit, the appropriate elements of
     /// the array data are replaced by their two-dimensional wavelet transform.
     ///
-    /// The functions return a status of ::Value::Success upon successful completion. ::Inval is returned if size1 and size2 are not
-    /// equal and integer powers of 2, or if insufficient workspace is provided.
+    /// The functions return a `Value::Invalid` error if size1 and size2 are not equal and integer
+    /// powers of 2, or if insufficient workspace is provided.
     #[doc(alias = "gsl_wavelet2d_transform_forward")]
     pub fn transform_forward(
         w: &::Wavelet,
@@ -152,8 +345,8 @@ pub mod two_dimension {
         size1: usize,
         size2: usize,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet2d_transform_forward(
                 w.unwrap_shared(),
                 data.as_mut_ptr(),
@@ -162,7 +355,8 @@ pub mod two_dimension {
                 size2,
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet2d_transform_forward")
     }
 
     /// These functions compute two-dimensional in-place forward and inverse discrete wavelet transforms in standard form on the array
@@ -171,8 +365,8 @@ pub mod two_dimension {
     /// forward (+1) or backward (-1). A workspace work of the appropriate size must be provided. On exit, the appropriate elements of
     /// the array data are replaced by their two-dimensional wavelet transform.
     ///
-    /// The functions return a status of ::Value::Success upon successful completion. ::Inval is returned if size1 and size2 are not
-    /// equal and integer powers of 2, or if insufficient workspace is provided.
+    /// The functions return a `Value::Invalid` error if size1 and size2 are not equal and integer
+    /// powers of 2, or if insufficient workspace is provided.
     #[doc(alias = "gsl_wavelet2d_transform_inverse")]
     pub fn transform_inverse(
         w: &::Wavelet,
@@ -181,8 +375,8 @@ pub mod two_dimension {
         size1: usize,
         size2: usize,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet2d_transform_inverse(
                 w.unwrap_shared(),
                 data.as_mut_ptr(),
@@ -191,7 +385,8 @@ pub mod two_dimension {
                 size2,
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet2d_transform_inverse")
     }
 
     /// These functions compute the two-dimensional in-place wavelet transform on a matrix a.
@@ -201,15 +396,16 @@ pub mod two_dimension {
         m: &mut ::MatrixF64,
         dir: ::WaveletDirection,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet2d_transform_matrix(
                 w.unwrap_shared(),
                 m.unwrap_unique(),
                 dir.into(),
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet2d_transform_matrix")
     }
 
     /// These functions compute the two-dimensional in-place wavelet transform on a matrix a.
@@ -218,14 +414,15 @@ pub mod two_dimension {
         w: &::Wavelet,
         m: &mut ::MatrixF64,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet2d_transform_matrix_forward(
                 w.unwrap_shared(),
                 m.unwrap_unique(),
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet2d_transform_matrix_forward")
     }
 
     /// These functions compute the two-dimensional in-place wavelet transform on a matrix a.
@@ -234,14 +431,15 @@ pub mod two_dimension {
         w: &::Wavelet,
         m: &mut ::MatrixF64,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet2d_transform_matrix_inverse(
                 w.unwrap_shared(),
                 m.unwrap_unique(),
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet2d_transform_matrix_inverse")
     }
 
     /// These functions compute the two-dimensional wavelet transform in non-standard form.
@@ -254,8 +452,8 @@ pub mod two_dimension {
         size2: usize,
         dir: ::WaveletDirection,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet2d_nstransform(
                 w.unwrap_shared(),
                 data.as_mut_ptr(),
@@ -265,7 +463,8 @@ pub mod two_dimension {
                 dir.into(),
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet2d_nstransform")
     }
 
     /// These functions compute the two-dimensional wavelet transform in non-standard form.
@@ -277,8 +476,8 @@ pub mod two_dimension {
         size1: usize,
         size2: usize,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet2d_nstransform_forward(
                 w.unwrap_shared(),
                 data.as_mut_ptr(),
@@ -287,7 +486,8 @@ pub mod two_dimension {
                 size2,
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet2d_nstransform_forward")
     }
 
     /// These functions compute the two-dimensional wavelet transform in non-standard form.
@@ -299,8 +499,8 @@ pub mod two_dimension {
         size1: usize,
         size2: usize,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet2d_nstransform_inverse(
                 w.unwrap_shared(),
                 data.as_mut_ptr(),
@@ -309,7 +509,8 @@ pub mod two_dimension {
                 size2,
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet2d_nstransform_inverse")
     }
 
     /// These functions compute the non-standard form of the two-dimensional in-place wavelet transform on a matrix a.
@@ -319,15 +520,16 @@ pub mod two_dimension {
         m: &mut ::MatrixF64,
         dir: ::WaveletDirection,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet2d_nstransform_matrix(
                 w.unwrap_shared(),
                 m.unwrap_unique(),
                 dir.into(),
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet2d_nstransform_matrix")
     }
 
     /// These functions compute the non-standard form of the two-dimensional in-place wavelet transform on a matrix a.
@@ -336,14 +538,15 @@ pub mod two_dimension {
         w: &::Wavelet,
         m: &mut ::MatrixF64,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet2d_nstransform_matrix_forward(
                 w.unwrap_shared(),
                 m.unwrap_unique(),
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet2d_nstransform_matrix_forward")
     }
 
     /// These functions compute the non-standard form of the two-dimensional in-place wavelet transform on a matrix a.
@@ -352,13 +555,62 @@ pub mod two_dimension {
         w: &::Wavelet,
         m: &mut ::MatrixF64,
         work: &mut ::WaveletWorkspace,
-    ) -> Value {
-        Value::from(unsafe {
+    ) -> Result<(), Error> {
+        let ret = unsafe {
             sys::gsl_wavelet2d_nstransform_matrix_inverse(
                 w.unwrap_shared(),
                 m.unwrap_unique(),
                 work.unwrap_unique(),
             )
-        })
+        };
+        result_handler!(ret, (), "gsl_wavelet2d_nstransform_matrix_inverse")
+    }
+}
+
+#[test]
+fn nstransform_matrix_round_trip() {
+    let w = ::Wavelet::new(::WaveletType::haar(), 2).unwrap();
+    let mut work = ::WaveletWorkspace::new(4).unwrap();
+    let mut m = ::MatrixF64::new(4, 4).unwrap();
+    for i in 0..4 {
+        for j in 0..4 {
+            m.set(i, j, (i * 4 + j) as f64);
+        }
     }
+
+    two_dimension::nstransform_matrix_forward(&w, &mut m, &mut work).unwrap();
+    // The smoothing coefficient of the Haar transform is the scaled mean.
+    assert!((m.get(0, 0) - 30.).abs() < 1e-12);
+    two_dimension::nstransform_matrix_inverse(&w, &mut m, &mut work).unwrap();
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!((m.get(i, j) - (i * 4 + j) as f64).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn coefficient_levels() {
+    let w = ::Wavelet::new(::WaveletType::haar(), 2).unwrap();
+    let mut work = ::WaveletWorkspace::new(8).unwrap();
+    let signal = [1., 3., 2., 8., -1., 0., 4., 4.];
+    let mut coeffs = signal;
+    one_dimension::transform_forward_checked(&w, &mut coeffs, 1, &mut work).unwrap();
+
+    let levels = one_dimension::levels(&coeffs);
+    assert_eq!(levels.len(), 3);
+    assert_eq!(levels[2], one_dimension::level(&coeffs, 2));
+    assert_eq!(levels[2].len(), 4);
+
+    // The Haar wavelet is orthogonal, so the energy is preserved.
+    let energy = coeffs[0] * coeffs[0] + one_dimension::level_energies(&coeffs).iter().sum::<f64>();
+    let expected = signal.iter().map(|x| x * x).sum::<f64>();
+    assert!((energy - expected).abs() < 1e-12);
+
+    // The last pair of the signal is constant, so its finest detail coefficient is zero.
+    assert!(one_dimension::level(&coeffs, 2)[3].abs() < 1e-12);
+    one_dimension::level_mut(&mut coeffs, 2)
+        .iter_mut()
+        .for_each(|x| *x = 0.);
+    assert_eq!(one_dimension::level_energies(&coeffs)[2], 0.);
 }
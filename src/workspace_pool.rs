@@ -0,0 +1,318 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Workspace pool
+
+Allocating and freeing a GSL workspace on every call can take more time than the computation
+itself when it is done in a hot loop. The [`WorkspacePool`] keeps the workspaces once they have
+been used so the next call with the same size can reuse them.
+
+The pool is thread-local: every thread has its own workspaces, which are freed when the thread
+exits (or with [`WorkspacePool::clear`]).
+
+The high-level functions like [`integration::qags`](crate::integration::qags),
+[`fft::mixed_radix::forward`](crate::fft::mixed_radix::forward),
+[`eigen::symm`](crate::eigen::symm) or [`multifit::linear`](crate::multifit::linear) take their
+workspace from the pool. If you prefer managing your own workspace, call the method with the
+same name on it instead (like [`IntegrationWorkspace::qags`](crate::IntegrationWorkspace::qags)).
+
+```
+use rgsl::{IntegrationWorkspace, WorkspacePool};
+
+for i in 0..10 {
+    // The workspace is only allocated during the first iteration.
+    let (result, _) = WorkspacePool::<IntegrationWorkspace>::with(100, |w| {
+        w.qags(|x| x.powi(i), 0., 1., 0., 1e-7, 100)
+    })
+    .and_then(|r| r)
+    .unwrap();
+    assert!((result - 1. / (i as f64 + 1.)).abs() < 1e-7);
+}
+```
+
+To share the workspaces between threads instead (between the tasks of a thread pool for
+example), use a [`SharedWorkspacePool`]:
+
+```
+use rgsl::{IntegrationWorkspace, SharedWorkspacePool};
+
+let pool = SharedWorkspacePool::<IntegrationWorkspace>::new();
+std::thread::scope(|s| {
+    for i in 0..4 {
+        let pool = &pool;
+        s.spawn(move || {
+            let (result, _) = pool
+                .with(100, |w| w.qags(|x| x.powi(i), 0., 1., 0., 1e-7, 100))
+                .and_then(|r| r)
+                .unwrap();
+            assert!((result - 1. / (i as f64 + 1.)).abs() < 1e-7);
+        });
+    }
+});
+// At most one workspace per thread was allocated.
+assert!(pool.len(100) <= 4);
+```
+!*/
+
+use crate::{Error, Value};
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::{Mutex, MutexGuard};
+
+/// A workspace which can be stored into a [`WorkspacePool`].
+pub trait PooledWorkspace: Sized + 'static {
+    /// What the workspace is allocated for: two workspaces with the same `Size` can be used
+    /// interchangeably.
+    type Size: Copy + Eq + Hash + 'static;
+
+    /// The GSL function allocating the workspace, used in the error returned if it fails.
+    const ALLOC: &'static str;
+
+    /// Allocates a new workspace.
+    fn alloc(size: Self::Size) -> Option<Self>;
+}
+
+thread_local! {
+    // Maps the `TypeId` of each workspace type to its `HashMap<T::Size, Vec<T>>`.
+    static POOLS: RefCell<HashMap<TypeId, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// The thread-local cache of the workspaces of type `T`.
+pub struct WorkspacePool<T: PooledWorkspace> {
+    phantom: PhantomData<T>,
+}
+
+impl<T: PooledWorkspace> WorkspacePool<T> {
+    fn take(size: T::Size) -> Option<T> {
+        POOLS.with(|pools| {
+            pools
+                .borrow_mut()
+                .get_mut(&TypeId::of::<T>())
+                .and_then(|pool| pool.downcast_mut::<HashMap<T::Size, Vec<T>>>())
+                .and_then(|pool| pool.get_mut(&size))
+                .and_then(|workspaces| workspaces.pop())
+        })
+    }
+
+    fn give_back(size: T::Size, workspace: T) {
+        POOLS.with(|pools| {
+            pools
+                .borrow_mut()
+                .entry(TypeId::of::<T>())
+                .or_insert_with(|| Box::new(HashMap::<T::Size, Vec<T>>::new()))
+                .downcast_mut::<HashMap<T::Size, Vec<T>>>()
+                .expect("invalid workspace pool")
+                .entry(size)
+                .or_default()
+                .push(workspace)
+        })
+    }
+
+    /// Calls `f` with a workspace of the given `size`, reused from a previous call if possible.
+    /// The workspace goes back to the pool once `f` returns.
+    ///
+    /// Returns an error if no workspace was available and its allocation failed.
+    pub fn with<R, F: FnOnce(&mut T) -> R>(size: T::Size, f: F) -> Result<R, Error> {
+        let mut workspace = match Self::take(size) {
+            Some(w) => w,
+            None => T::alloc(size).ok_or_else(|| Error::new(Value::NoMemory, T::ALLOC))?,
+        };
+        // The pool isn't borrowed while `f` runs, so `f` can use it as well.
+        let ret = f(&mut workspace);
+        Self::give_back(size, workspace);
+        Ok(ret)
+    }
+
+    /// Returns the number of workspaces of the given `size` currently in the pool of this thread.
+    pub fn len(size: T::Size) -> usize {
+        POOLS.with(|pools| {
+            pools
+                .borrow()
+                .get(&TypeId::of::<T>())
+                .and_then(|pool| pool.downcast_ref::<HashMap<T::Size, Vec<T>>>())
+                .and_then(|pool| pool.get(&size))
+                .map_or(0, |workspaces| workspaces.len())
+        })
+    }
+
+    /// Frees all the workspaces of type `T` in the pool of this thread.
+    pub fn clear() {
+        let pool = POOLS.with(|pools| pools.borrow_mut().remove(&TypeId::of::<T>()));
+        // Dropped outside of the borrow, just in case.
+        drop(pool);
+    }
+}
+
+/// A cache of workspaces of type `T` which can be shared between threads.
+///
+/// Unlike [`WorkspacePool`], the workspaces aren't tied to the thread which allocated them: a
+/// workspace given back by a thread can be reused by any other one. Only taking a workspace from
+/// the pool and giving it back lock it, so the threads don't wait for each other while they
+/// use their workspaces.
+pub struct SharedWorkspacePool<T: PooledWorkspace + Send> {
+    workspaces: Mutex<HashMap<T::Size, Vec<T>>>,
+}
+
+impl<T: PooledWorkspace + Send> SharedWorkspacePool<T> {
+    /// Creates an empty pool.
+    pub fn new() -> SharedWorkspacePool<T> {
+        SharedWorkspacePool {
+            workspaces: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<T::Size, Vec<T>>> {
+        // A panic in `with` can't leave the map itself in an invalid state.
+        self.workspaces
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Calls `f` with a workspace of the given `size`, reused from a previous call if possible.
+    /// The workspace goes back to the pool once `f` returns.
+    ///
+    /// Returns an error if no workspace was available and its allocation failed.
+    pub fn with<R, F: FnOnce(&mut T) -> R>(&self, size: T::Size, f: F) -> Result<R, Error> {
+        let workspace = self.lock().get_mut(&size).and_then(|w| w.pop());
+        let mut workspace = match workspace {
+            Some(w) => w,
+            None => T::alloc(size).ok_or_else(|| Error::new(Value::NoMemory, T::ALLOC))?,
+        };
+        let ret = f(&mut workspace);
+        self.lock().entry(size).or_default().push(workspace);
+        Ok(ret)
+    }
+
+    /// Returns the number of workspaces of the given `size` currently in the pool.
+    pub fn len(&self, size: T::Size) -> usize {
+        self.lock()
+            .get(&size)
+            .map_or(0, |workspaces| workspaces.len())
+    }
+
+    /// Frees all the workspaces in the pool.
+    pub fn clear(&self) {
+        let workspaces = ::std::mem::take(&mut *self.lock());
+        drop(workspaces);
+    }
+}
+
+impl<T: PooledWorkspace + Send> Default for SharedWorkspacePool<T> {
+    fn default() -> SharedWorkspacePool<T> {
+        SharedWorkspacePool::new()
+    }
+}
+
+macro_rules! pooled_workspace {
+    ($name:ident, $alloc:expr) => {
+        impl PooledWorkspace for ::$name {
+            type Size = usize;
+
+            const ALLOC: &'static str = $alloc;
+
+            fn alloc(size: usize) -> Option<Self> {
+                ::$name::new(size)
+            }
+        }
+    };
+}
+
+pooled_workspace!(IntegrationWorkspace, "gsl_integration_workspace_alloc");
+pooled_workspace!(CquadWorkspace, "gsl_integration_cquad_workspace_alloc");
+#[cfg(feature = "v2_5")]
+pooled_workspace!(RombergWorkspace, "gsl_integration_romberg_alloc");
+pooled_workspace!(FftComplexF64WaveTable, "gsl_fft_complex_wavetable_alloc");
+pooled_workspace!(FftComplexF64Workspace, "gsl_fft_complex_workspace_alloc");
+pooled_workspace!(
+    FftComplexF32WaveTable,
+    "gsl_fft_complex_wavetable_float_alloc"
+);
+pooled_workspace!(
+    FftComplexF32Workspace,
+    "gsl_fft_complex_workspace_float_alloc"
+);
+pooled_workspace!(FftRealF64WaveTable, "gsl_fft_real_wavetable_alloc");
+pooled_workspace!(
+    FftHalfComplexF64WaveTable,
+    "gsl_fft_halfcomplex_wavetable_alloc"
+);
+pooled_workspace!(FftRealF64Workspace, "gsl_fft_real_workspace_alloc");
+pooled_workspace!(EigenSymmetricWorkspace, "gsl_eigen_symm_alloc");
+pooled_workspace!(EigenSymmetricVWorkspace, "gsl_eigen_symmv_alloc");
+pooled_workspace!(EigenHermitianWorkspace, "gsl_eigen_herm_alloc");
+pooled_workspace!(EigenHermitianVWorkspace, "gsl_eigen_hermv_alloc");
+pooled_workspace!(EigenNonSymmetricWorkspace, "gsl_eigen_nonsymm_alloc");
+pooled_workspace!(EigenNonSymmetricVWorkspace, "gsl_eigen_nonsymmv_alloc");
+pooled_workspace!(EigenGenSymmWorkspace, "gsl_eigen_gensymm_alloc");
+pooled_workspace!(EigenGenSymmVWorkspace, "gsl_eigen_gensymmv_alloc");
+pooled_workspace!(EigenGenHermWorkspace, "gsl_eigen_genherm_alloc");
+pooled_workspace!(EigenGenHermVWorkspace, "gsl_eigen_genhermv_alloc");
+pooled_workspace!(EigenGenWorkspace, "gsl_eigen_gen_alloc");
+pooled_workspace!(EigenGenVWorkspace, "gsl_eigen_genv_alloc");
+pooled_workspace!(WaveletWorkspace, "gsl_wavelet_workspace_alloc");
+
+impl PooledWorkspace for ::MultifitLinearWorkspace {
+    /// `(n, p)`: the number of observations and of parameters.
+    type Size = (usize, usize);
+
+    const ALLOC: &'static str = "gsl_multifit_linear_alloc";
+
+    fn alloc((n, p): (usize, usize)) -> Option<Self> {
+        ::MultifitLinearWorkspace::new(n, p)
+    }
+}
+
+impl PooledWorkspace for ::InterpAccel {
+    /// The accelerators don't depend on the interpolation they are used with.
+    type Size = ();
+
+    const ALLOC: &'static str = "gsl_interp_accel_alloc";
+
+    fn alloc(_: ()) -> Option<Self> {
+        Some(::InterpAccel::new())
+    }
+}
+
+#[test]
+fn workspace_pool_reuse() {
+    use IntegrationWorkspace;
+
+    WorkspacePool::<IntegrationWorkspace>::clear();
+    WorkspacePool::<IntegrationWorkspace>::with(10, |_| {
+        // Nested calls get their own workspace.
+        WorkspacePool::<IntegrationWorkspace>::with(10, |_| ()).unwrap();
+        assert_eq!(WorkspacePool::<IntegrationWorkspace>::len(10), 1);
+    })
+    .unwrap();
+    assert_eq!(WorkspacePool::<IntegrationWorkspace>::len(10), 2);
+    assert_eq!(WorkspacePool::<IntegrationWorkspace>::len(20), 0);
+    WorkspacePool::<IntegrationWorkspace>::clear();
+    assert_eq!(WorkspacePool::<IntegrationWorkspace>::len(10), 0);
+}
+
+#[test]
+fn shared_workspace_pool() {
+    use {InterpAccel, WaveletWorkspace};
+
+    fn assert_sync<T: Sync>(_: &T) {}
+
+    let pool = SharedWorkspacePool::<WaveletWorkspace>::new();
+    assert_sync(&pool);
+    ::std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| pool.with(16, |_| ()).unwrap());
+        }
+    });
+    assert!(pool.len(16) >= 1 && pool.len(16) <= 4);
+    pool.clear();
+    assert_eq!(pool.len(16), 0);
+
+    let accels = SharedWorkspacePool::<InterpAccel>::new();
+    accels.with((), |acc| acc.reset()).unwrap();
+    assert_eq!(accels.len(()), 1);
+}
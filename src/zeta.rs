@@ -6,8 +6,8 @@
 
 /// The Riemann zeta function is defined by the infinite sum \zeta(s) = \sum_{k=1}^\infty k^{-s}.
 pub mod riemann {
-    use crate::Value;
-    use std::mem::MaybeUninit;
+    use crate::Error;
+    use core::mem::MaybeUninit;
 
     /// This routine computes the Riemann zeta function \zeta(n) for integer n, n \ne 1.
     #[doc(alias = "gsl_sf_zeta_int")]
@@ -17,11 +17,15 @@ pub mod riemann {
 
     /// This routine computes the Riemann zeta function \zeta(n) for integer n, n \ne 1.
     #[doc(alias = "gsl_sf_zeta_int_e")]
-    pub fn zeta_int_e(n: i32) -> (Value, ::types::Result) {
+    pub fn zeta_int_e(n: i32) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_zeta_int_e(n, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_zeta_int_e"
+        )
     }
 
     /// This routine computes the Riemann zeta function \zeta(s) for arbitrary s, s \ne 1.
@@ -32,19 +36,19 @@ pub mod riemann {
 
     /// This routine computes the Riemann zeta function \zeta(s) for arbitrary s, s \ne 1.
     #[doc(alias = "gsl_sf_zeta_e")]
-    pub fn zeta_e(x: f64) -> (Value, ::types::Result) {
+    pub fn zeta_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_zeta_e(x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(ret, unsafe { result.assume_init() }.into(), "gsl_sf_zeta_e")
     }
 }
 
 /// For large positive argument, the Riemann zeta function approaches one.
 /// In this region the fractional part is interesting, and therefore we need a function to evaluate it explicitly.
 pub mod riemann_mins_one {
-    use crate::Value;
-    use std::mem::MaybeUninit;
+    use crate::Error;
+    use core::mem::MaybeUninit;
 
     /// This routine computes the Riemann zeta function \zeta(n) for integer n, n \ne 1.
     #[doc(alias = "gsl_sf_zetam1_int")]
@@ -54,11 +58,15 @@ pub mod riemann_mins_one {
 
     /// This routine computes the Riemann zeta function \zeta(n) for integer n, n \ne 1.
     #[doc(alias = "gsl_sf_zetam1_int_e")]
-    pub fn zetam1_int_e(n: i32) -> (Value, ::types::Result) {
+    pub fn zetam1_int_e(n: i32) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_zetam1_int_e(n, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_zetam1_int_e"
+        )
     }
 
     /// This routine computes \zeta(s) - 1 for arbitrary s, s \ne 1.
@@ -69,18 +77,22 @@ pub mod riemann_mins_one {
 
     /// This routine computes \zeta(s) - 1 for arbitrary s, s \ne 1.
     #[doc(alias = "gsl_sf_zetam1_e")]
-    pub fn zetam1_e(x: f64) -> (Value, ::types::Result) {
+    pub fn zetam1_e(x: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_zetam1_e(x, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_zetam1_e"
+        )
     }
 }
 
 /// The Hurwitz zeta function is defined by \zeta(s,q) = \sum_0^\infty (k+q)^{-s}.
 pub mod hurwitz {
-    use crate::Value;
-    use std::mem::MaybeUninit;
+    use crate::Error;
+    use core::mem::MaybeUninit;
 
     /// This routine computes the Hurwitz zeta function \zeta(s,q) for s > 1, q > 0.
     #[doc(alias = "gsl_sf_hzeta")]
@@ -90,18 +102,22 @@ pub mod hurwitz {
 
     /// This routine computes the Hurwitz zeta function \zeta(s,q) for s > 1, q > 0.
     #[doc(alias = "gsl_sf_hzeta_e")]
-    pub fn hzeta_e(s: f64, q: f64) -> (Value, ::types::Result) {
+    pub fn hzeta_e(s: f64, q: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_hzeta_e(s, q, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_hzeta_e"
+        )
     }
 }
 
 /// The eta function is defined by \eta(s) = (1-2^{1-s}) \zeta(s).
 pub mod eta {
-    use crate::Value;
-    use std::mem::MaybeUninit;
+    use crate::Error;
+    use core::mem::MaybeUninit;
 
     /// This routine computes the eta function \eta(n) for integer n.
     #[doc(alias = "gsl_sf_eta_int")]
@@ -111,11 +127,15 @@ pub mod eta {
 
     /// This routine computes the eta function \eta(n) for integer n.
     #[doc(alias = "gsl_sf_eta_int_e")]
-    pub fn eta_int_e(n: i32) -> (Value, ::types::Result) {
+    pub fn eta_int_e(n: i32) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_eta_int_e(n, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(
+            ret,
+            unsafe { result.assume_init() }.into(),
+            "gsl_sf_eta_int_e"
+        )
     }
 
     /// This routine computes the eta function \eta(s) for arbitrary s.
@@ -126,10 +146,10 @@ pub mod eta {
 
     /// This routine computes the eta function \eta(s) for arbitrary s.
     #[doc(alias = "gsl_sf_eta_e")]
-    pub fn eta_e(s: f64) -> (Value, ::types::Result) {
+    pub fn eta_e(s: f64) -> Result<::types::Result, Error> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
         let ret = unsafe { sys::gsl_sf_eta_e(s, result.as_mut_ptr()) };
 
-        (::Value::from(ret), unsafe { result.assume_init() }.into())
+        result_handler!(ret, unsafe { result.assume_init() }.into(), "gsl_sf_eta_e")
     }
 }